@@ -0,0 +1,79 @@
+/*!
+Generates the binary blobs of the embedded datasets, and their compressed versions, from their source tables.
+
+Each dataset has a source table next to its blob, with the same name, and a `.tsv` extension: a text file with tab
+separated fields, with the column keys on its first line, which is empty for datasets without keys, followed by a line
+for each row of values. For each table given as argument, this writes the blob, with a `.bin` extension, and its
+compressed version, with a `.bin.z` extension:
+
+```text
+cargo run --example blobs --features compress -- src/illuminants/cie_fl3.tsv scot-tm30/src/ces.tsv
+```
+*/
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use scot::blob::Blob;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let tables: Vec<String> = std::env::args().skip(1).collect();
+    if tables.is_empty() {
+        return Err("usage: blobs <table.tsv>...".into());
+    }
+    for table in &tables {
+        let path = Path::new(table);
+        let blob =
+            read_table(&fs::read_to_string(path)?).map_err(|e| format!("{}: {}", table, e))?;
+        fs::write(path.with_extension("bin"), blob.to_bytes())?;
+        fs::write(path.with_extension("bin.z"), blob.to_compressed_bytes())?;
+        println!("{}: {} rows, {} columns", table, blob.nrows, blob.ncols);
+    }
+    Ok(())
+}
+
+/// Blob from a source table, with the keys on the first line, and a line for each row.
+fn read_table(text: &str) -> Result<Blob, String> {
+    let mut lines = text.lines();
+    let header = lines.next().ok_or("no data")?;
+    let keys: Vec<&str> = if header.is_empty() {
+        Vec::new()
+    } else {
+        header.split('\t').collect()
+    };
+    let mut ncols = 0;
+    let mut rows: Vec<Vec<f64>> = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let row = line
+            .split('\t')
+            .map(|v| {
+                v.parse::<f64>()
+                    .map_err(|_| format!("line {}: invalid number \"{}\"", i + 2, v))
+            })
+            .collect::<Result<Vec<f64>, String>>()?;
+        if rows.is_empty() {
+            ncols = row.len();
+        } else if row.len() != ncols {
+            return Err(format!(
+                "line {}: expected {} values, found {}",
+                i + 2,
+                ncols,
+                row.len()
+            ));
+        }
+        rows.push(row);
+    }
+    let nrows = rows.len();
+    let data = (0..ncols)
+        .flat_map(|j| rows.iter().map(move |r| r[j]))
+        .collect();
+    let blob = Blob::new(nrows, ncols, data);
+    if keys.is_empty() {
+        Ok(blob)
+    } else if keys.len() == ncols {
+        Ok(blob.set_keys(&keys))
+    } else {
+        Err(format!("expected {} keys, found {}", ncols, keys.len()))
+    }
+}
@@ -0,0 +1,187 @@
+/*!
+The CIE 13.3-1995 color rendering index, Ra, and the special color rendering indices R1 to R15.
+
+The color rendering index compares the colors of the test color samples, illuminated by a test source, with their
+colors under a reference illuminant with the same correlated color temperature: a Planckian radiator below 5000K, and
+a CIE daylight illuminant above.
+The sample colors under the test source are adapted to the reference illuminant with a von Kries type transform in the
+CIE 1960 UCS diagram, and their differences are calculated in the CIE 1964 U\*V\*W\* color space.
+Each special color rendering index is calculated from the color difference &Delta;E<sub>i</sub> of a sample as
+R<sub>i</sub> = 100 - 4.6 &Delta;E<sub>i</sub>, and the general color rendering index Ra is the mean of the first
+eight special indices.
+
+All calculations use the CIE 1931 standard observer, and the test color sample domain, from 360 to 830nm, in steps of
+5nm.
+The method is only valid for sources with chromaticities close to the Planckian locus, with a distance less than
+5.4E-3 in the CIE 1960 UCS diagram; use `Cri::is_valid` to check this.
+
+The gamut area index GAI, of Rea and Freyssinier, is calculated alongside the color rendering indices, as a simple
+measure of color saturation: it is the area of the polygon formed by the chromaticities of the first eight test
+color samples in the CIE 1976 u'v' diagram, illuminated by the source, without chromatic adaptation, relative to the
+area of the same polygon under an equal energy illuminant, multiplied by 100.
+Sources with a high GAI render colors more saturated, and, in combination with a high Ra, more vivid.
+*/
+
+use nalgebra::{DMatrix, DVector, Matrix3xX, SMatrix};
+use scot::illuminants::{CctDuvCalc, Ohno2014Cascade, ReferenceIlluminant, ReferenceSelection};
+use scot::models::CieYuv1960;
+use scot::observers::{CieObs1931, StandardObserver};
+use scot::{Domain, SpectralDistribution, WavelengthStep, NM5};
+
+use crate::tcs::Tcs;
+
+/// Maximum distance to the Planckian locus, in the CIE 1960 UCS diagram, for which the color rendering index is valid.
+pub const CRI_DUV_MAX: f64 = 5.4E-3;
+
+/**
+Color rendering indices of a light source, calculated according to CIE 13.3-1995.
+
+Only the first spectral distribution of a source is used.
+
+# Examples
+```
+use scot::illuminants::FL;
+use scot_cri::Cri;
+
+let cri = Cri::new(&FL::<2>);
+assert_eq!(cri.ra.round(), 64.0);
+println!("Ra {:.1}, R9 {:.1}, GAI {:.1}", cri.ra, cri.ri[8], cri.gai);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cri {
+	/// General color rendering index.
+	pub ra: f64,
+	/// Special color rendering indices R1 to R15.
+	pub ri: [f64; 15],
+	/// Correlated color temperature of the source, in Kelvin, which is also the temperature of the reference.
+	pub cct: f64,
+	/// Distance of the source to the Planckian locus, in the CIE 1960 UCS diagram.
+	pub duv: f64,
+	/// Gamut area index.
+	pub gai: f64,
+}
+
+impl Cri {
+	pub fn new<S>(source: &S) -> Self
+	where
+		S: SpectralDistribution<StepType = WavelengthStep>,
+	{
+		let d = Domain::new(360 / 5, 830 / 5, NM5);
+		let r = Tcs.spd().1.into_owned();
+		let cmf = CieObs1931::values(&d);
+		let s = source.map_domain(d.clone()).column(0).into_owned();
+
+		let [_, u_k, v_k] = white_yuv(&cmf, &s);
+		let yuv = CieYuv1960::<CieObs1931>::new(Matrix3xX::from_column_slice(&[1.0, u_k, v_k]));
+		let td = Ohno2014Cascade::<CieObs1931>::new()
+			.cct_duv(yuv)
+			.into_iter()
+			.next()
+			.unwrap();
+
+		let reference = ReferenceIlluminant::new(td.t, ReferenceSelection::Cri).set_domain(d.clone());
+		let s_r = reference.spd().1.column(0).into_owned();
+		let [_, u_r, v_r] = white_yuv(&cmf, &s_r);
+
+		let test = samples_yuv(&cmf, &s, &r);
+		let refs = samples_yuv(&cmf, &s_r, &r);
+
+		// von Kries chromatic adaptation of the samples under the test source to the reference illuminant
+		let (c_k, d_k) = cd(u_k, v_k);
+		let (c_r, d_r) = cd(u_r, v_r);
+		let mut ri = [0.0; 15];
+		for (i, r) in ri.iter_mut().enumerate() {
+			let [y, u, v] = test[i];
+			let (c, dd) = cd(u, v);
+			let den = 16.518 + 1.481 * c_r / c_k * c - d_r / d_k * dd;
+			let u_a = (10.872 + 0.404 * c_r / c_k * c - 4.0 * d_r / d_k * dd) / den;
+			let v_a = 5.520 / den;
+			let [w_t, u_t, v_t] = uvw(y, u_a, v_a, u_r, v_r);
+			let [y, u, v] = refs[i];
+			let [w_s, u_s, v_s] = uvw(y, u, v, u_r, v_r);
+			let de = ((u_t - u_s).powi(2) + (v_t - v_s).powi(2) + (w_t - w_s).powi(2)).sqrt();
+			*r = 100.0 - 4.6 * de;
+		}
+		let ra = ri[0..8].iter().sum::<f64>() / 8.0;
+		let e = DVector::repeat(s.len(), 1.0);
+		let gai = gamut_area(&samples_yuv(&cmf, &s, &r)) / gamut_area(&samples_yuv(&cmf, &e, &r)) * 100.0;
+		Self {
+			ra,
+			ri,
+			cct: td.t,
+			duv: td.d,
+			gai,
+		}
+	}
+
+	/// True if the distance of the source to the Planckian locus is less than `CRI_DUV_MAX`.
+	pub fn is_valid(&self) -> bool {
+		self.duv.abs() < CRI_DUV_MAX
+	}
+}
+
+/// Y, u, and v values of a source, with Y normalized to 100.
+fn white_yuv(cmf: &Matrix3xX<f64>, s: &DVector<f64>) -> [f64; 3] {
+	let xyz = cmf * s;
+	yuv(xyz.x, xyz.y, xyz.z, 100.0 / xyz.y)
+}
+
+/// Y, u, and v values of the test color samples, for a source with a luminance normalized to 100.
+fn samples_yuv(cmf: &Matrix3xX<f64>, s: &DVector<f64>, r: &SMatrix<f64, 95, 15>) -> Vec<[f64; 3]> {
+	let k = 100.0 / (cmf.row(1) * s)[0];
+	let sr = DMatrix::from_fn(r.nrows(), r.ncols(), |i, j| s[i] * r[(i, j)]);
+	let xyz = cmf * sr;
+	xyz.column_iter().map(|c| yuv(c.x, c.y, c.z, k)).collect()
+}
+
+/// Area of the polygon formed by the first eight test color samples, in the CIE 1976 u'v' diagram.
+fn gamut_area(yuv: &[[f64; 3]]) -> f64 {
+	let mut area = 0.0;
+	for i in 0..8 {
+		let [_, u, v] = yuv[i];
+		let [_, u_n, v_n] = yuv[(i + 1) % 8];
+		area += 1.5 * (u * v_n - u_n * v);
+	}
+	area / 2.0
+}
+
+fn yuv(x: f64, y: f64, z: f64, k: f64) -> [f64; 3] {
+	let den = x + 15.0 * y + 3.0 * z;
+	[k * y, 4.0 * x / den, 6.0 * y / den]
+}
+
+fn cd(u: f64, v: f64) -> (f64, f64) {
+	((4.0 - u - 10.0 * v) / v, (1.708 * v + 0.404 - 1.481 * u) / v)
+}
+
+/// CIE 1964 W\*, U\*, and V\* values, for a white point with chromaticity coordinates `u_n`, and `v_n`.
+fn uvw(y: f64, u: f64, v: f64, u_n: f64, v_n: f64) -> [f64; 3] {
+	let w = 25.0 * y.powf(1.0 / 3.0) - 17.0;
+	[w, 13.0 * w * (u - u_n), 13.0 * w * (v - v_n)]
+}
+
+#[test]
+fn test_cri() {
+	use approx::assert_abs_diff_eq;
+	use scot::illuminants::{CieIllA, FL};
+
+	// general color rendering indices, as listed in CIE 15:2004, Table T.8
+	assert_eq!(Cri::new(&FL::<1>).ra.round(), 76.0);
+	assert_eq!(Cri::new(&FL::<3>).ra.round(), 57.0);
+	assert_eq!(Cri::new(&FL::<4>).ra.round(), 51.0);
+	assert_eq!(Cri::new(&FL::<7>).ra.round(), 90.0);
+	assert_eq!(Cri::new(&FL::<11>).ra.round(), 83.0);
+
+	// illuminant A is a Planckian radiator, and its own reference
+	let a = Cri::new(&CieIllA);
+	assert!(a.is_valid());
+	assert_abs_diff_eq!(a.cct, 2856.0, epsilon = 1.0);
+	for r in a.ri.iter() {
+		assert_abs_diff_eq!(*r, 100.0, epsilon = 0.05);
+	}
+
+	// incandescent light desaturates colors, in comparison with daylight
+	let d65 = Cri::new(&scot::illuminants::CieIllD65);
+	assert!(a.gai < 60.0 && d65.gai > 90.0, "{} {}", a.gai, d65.gai);
+}
@@ -3,3 +3,6 @@
 
 pub mod tcs;
 pub use tcs::*;
+
+pub mod cri;
+pub use cri::*;
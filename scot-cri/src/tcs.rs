@@ -140,9 +140,9 @@ static TCS_DATA: [f64;N*M] = [
 
 #[test]
 fn test_tcs(){
-	use crate::models::CieLab;
-	use crate::illuminants::{D50};
-	use crate::observers::CieObs1931;
+	use scot::models::CieLab;
+	use scot::illuminants::{D50};
+	use scot::observers::CieObs1931;
 
 	let tcs_lab: CieLab<D50, CieObs1931> = Tcs::default().into();
 
@@ -24,7 +24,8 @@ From [Paul Centore]'s article:
  #![allow(clippy::approx_constant)]
 
 use scot::{Domain, Meter, SpectralDistribution, Step, Unit, WavelengthStep, illuminants::Illuminant, models::CieLab, observers::StandardObserver, swatches::Swatch};
-use nalgebra::{DMatrix, SMatrix};
+use nalgebra::DMatrix;
+use scot::blob::LazyBlob;
 use matt_splines::MUNSELL_MATT;
 use spliny::CubicSpline;
 
@@ -46,35 +47,113 @@ pub use renotation::*;
 #[derive(Debug)]
 
 struct CardinalSplineData<const R: usize, const C: usize, const N: usize> {
-    keys: [(&'static str, usize); C],
     knots: [f64; N],
-    coef: SMatrix<f64, R, C>,
+    /// Coefficients, with R rows, and a column for each of the C keys, sorted by key.
+    coef: LazyBlob,
 }
 
+#[derive(Debug)]
 pub struct CardinalSplineSpectrum<const R: usize, const C: usize, const N: usize> {
     data: &'static CardinalSplineData<R, C, N>,
     pub keys: Vec<String>,
     pub domain: Domain<WavelengthStep>,
 }
 
+/**
+Error returned for Munsell keys which are not in a collection, with, for each of the unknown keys, the nearest valid
+key, if the key can be parsed as a Munsell notation.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct MunsellKeyError {
+    pub unknown: Vec<(String, Option<String>)>,
+}
+
+impl std::fmt::Display for MunsellKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (key, nearest)) in self.unknown.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match nearest {
+                Some(n) => write!(f, "unknown Munsell key \"{}\", did you mean \"{}\"?", key, n)?,
+                None => write!(f, "unknown Munsell key \"{}\"", key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MunsellKeyError {}
+
+const HUE_NAMES: [&str; 10] = ["R", "YR", "Y", "GY", "G", "BG", "B", "PB", "P", "RP"];
+
+/// Hue, on a 0 to 100 scale, starting at 0R, value, and chroma, of a Munsell notation such as "7.5RP5/12".
+fn parse_munsell(key: &str) -> Option<(f64, f64, f64)> {
+    let key: String = key.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase();
+    let name_start = key.find(|c: char| c.is_ascii_alphabetic())?;
+    let name_end = name_start + key[name_start..].find(|c: char| !c.is_ascii_alphabetic())?;
+    let (value, chroma) = key[name_end..].split_once('/')?;
+    let hue_index = HUE_NAMES.iter().position(|&h| h == &key[name_start..name_end])?;
+    let hue = key[..name_start].parse::<f64>().ok()? + 10.0 * hue_index as f64;
+    Some((hue, value.parse().ok()?, chroma.parse().ok()?))
+}
+
+impl<const R: usize, const C: usize, const N: usize> CardinalSplineSpectrum<R, C, N> {
+    /**
+    Checks if all the keys of the spectrum are in its collection.
+
+    For each unknown key, the nearest valid key is suggested, considering a difference of a 2.5 hue step, a value step,
+    and a 2 chroma step, to be equally large.
+    */
+    pub fn check_keys(&self) -> Result<(), MunsellKeyError> {
+        let unknown: Vec<(String, Option<String>)> = self
+            .keys
+            .iter()
+            .filter(|k| self.data.coef.keys().binary_search(k).is_err())
+            .map(|k| (k.clone(), self.nearest_key(k)))
+            .collect();
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(MunsellKeyError { unknown })
+        }
+    }
+
+    fn nearest_key(&self, key: &str) -> Option<String> {
+        let (h, v, c) = parse_munsell(key)?;
+        self.data
+            .coef
+            .keys()
+            .iter()
+            .filter_map(|k| {
+                let (hk, vk, ck) = parse_munsell(k)?;
+                let dh = (h - hk).abs() % 100.0;
+                let d = dh.min(100.0 - dh) / 2.5 + (v - vk).abs() + (c - ck).abs() / 2.0;
+                Some((d, k))
+            })
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+            .map(|(_, k)| k.to_string())
+    }
+}
+
 impl<const R: usize, const C: usize, const N: usize> SpectralDistribution for CardinalSplineSpectrum<R, C, N> {
     type MatrixType = DMatrix<f64>;
     type StepType = WavelengthStep;
 
+    /// Spectral distributions of the keys.
+    ///
+    /// Panics if any of the keys is not in the collection; use `check_keys` to validate the keys first.
     fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
-        let n = self.domain.len();
+        if let Err(e) = self.check_keys() {
+            panic!("{}", e);
+        }
         let w: Vec<f64> = self.domain.iter().map(|v|v.value()).collect();
         let mut v:Vec<f64> = Vec::with_capacity(w.len() * self.keys.len());
         for k in self.keys.iter() {
-            let index = self.data.keys.binary_search_by_key(k, |(a,b)|a.to_string());
-            match index {
-                Ok(i) => {
-                    let sp = CubicSpline::new(self.data.knots.to_vec(), self.data.coef.column(i).clone_owned().as_slice().to_vec());
-                    let val = sp.evaluate(&w).expect("Could not calculate SPD values");
-                    v.extend(val);
-                },
-                Err(_i) => v.extend(vec![f64::NAN;n])
-            } 
+            let i = self.data.coef.keys().binary_search(k).unwrap();
+            let sp = CubicSpline::new(self.data.knots.to_vec(), self.data.coef.data()[i * R..(i + 1) * R].to_vec());
+            let val = sp.evaluate(&w).expect("Could not calculate SPD values");
+            v.extend(val);
         }
         (self.domain.clone(), DMatrix::from_vec(w.len(), self.keys.len(), v))
     }
@@ -84,10 +163,71 @@ impl<const R: usize, const C: usize, const N: usize> SpectralDistribution for Ca
     }
 }
 
+#[derive(Debug)]
 pub struct MunsellMattCardinalSpline<const R: usize, const C: usize, const N: usize> (
     CardinalSplineSpectrum<R,C,N>
 );
 
+/**
+Spectral reflectance distributions of matt Munsell chips, calculated from cardinal spline fits of their measured
+spectra, for a set of Munsell keys, such as "7.5RP5/12".
+
+# Examples
+```
+use scot_munsell::MunsellMattSpline;
+
+let chips = MunsellMattSpline::new(["7.5RP5/12", "5Y6/6"]).unwrap();
+
+let err = MunsellMattSpline::new(["7.5RP 5/12"]).unwrap_err();
+assert_eq!(err.to_string(), "unknown Munsell key \"7.5RP 5/12\", did you mean \"7.5RP5/12\"?");
+```
+*/
+pub type MunsellMattSpline = MunsellMattCardinalSpline<44, 1269, 48>;
+
+impl MunsellMattSpline {
+    /// Spline spectra for a set of Munsell keys, on the default domain, from 380 to 780nm, in steps of 1nm, with an
+    /// error listing the unknown keys, and the nearest valid key for each of them.
+    pub fn new(keys: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, MunsellKeyError> {
+        let spectrum = CardinalSplineSpectrum {
+            data: &MUNSELL_MATT,
+            keys: keys.into_iter().map(|s| s.as_ref().to_string()).collect(),
+            domain: Domain::default(),
+        };
+        spectrum.check_keys()?;
+        Ok(Self(spectrum))
+    }
+}
+
+impl<const R: usize, const C: usize, const N: usize> SpectralDistribution for MunsellMattCardinalSpline<R, C, N> {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        self.0.spd()
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        Some(self.0.keys.clone())
+    }
+}
+
+impl<const R: usize, const C: usize, const N: usize> Swatch for MunsellMattCardinalSpline<R, C, N> {}
+
+impl<I, C, const R: usize, const M: usize, const N: usize> From<MunsellMattCardinalSpline<R, M, N>> for CieLab<I,C>
+  where
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+    I: Illuminant,
+    C: StandardObserver
+  {
+    fn from(mms: MunsellMattCardinalSpline<R, M, N>) -> Self {
+        mms.lab()
+    }
+}
+
 
 
 /* 
@@ -146,10 +286,37 @@ impl<I, C> From<MunsellMattSpline> for CieLab<I,C>
 #[test]
 fn test_munsell_matt(){
     use scot::models::CieLab;
-    let m = MunsellMattSpline::new(["7.5RP5/12", "5Y6/6", "2.5BG9/2"]);
+    let m = MunsellMattSpline::new(["7.5RP5/12", "5Y6/6", "2.5BG9/2"]).unwrap();
     let (_dom, spd) = m.spd();
     println!("{}", spd);
     let lab: CieLab<scot::illuminants::CieIllC, scot::observers::CieObs1931> = CieLab::from(m);
     println!("{}",lab.data);
+
+    // measured chips are close to their renotation reference values
+    for (j, key) in ["7.5RP5/12", "5Y6/6", "2.5BG9/2"].iter().enumerate() {
+        let [l, a, b] = MunsellRenotation::reference_lab(key).unwrap();
+        let c = lab.data.column(j);
+        let de = ((c.x - l).powi(2) + (c.y - a).powi(2) + (c.z - b).powi(2)).sqrt();
+        assert!(de < 10.0, "{}: {}", key, de);
+    }
+}
+ 
+
+#[test]
+fn test_check_keys() {
+    let spectrum = CardinalSplineSpectrum {
+        data: &MUNSELL_MATT,
+        keys: vec!["7.5RP5/12".to_string(), "7.5RP 5/12".to_string(), "5y6/6".to_string(), "purple".to_string()],
+        domain: Domain::default(),
+    };
+    let err = spectrum.check_keys().unwrap_err();
+    assert_eq!(
+        err.unknown,
+        vec![
+            ("7.5RP 5/12".to_string(), Some("7.5RP5/12".to_string())),
+            ("5y6/6".to_string(), Some("5Y6/6".to_string())),
+            ("purple".to_string(), None),
+        ]
+    );
+    assert!(err.to_string().contains("did you mean \"7.5RP5/12\""));
 }
- 
\ No newline at end of file
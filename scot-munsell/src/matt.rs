@@ -13,6 +13,8 @@ chips.
 */
 
 use scot::swatches::swatch;
+use scot::blob::LazyBlob;
+use scot::include_blob;
 
 // Munsell Gloss Spectral Data
 // 380-780-1nm
@@ -20,7715 +22,16 @@ use scot::swatches::swatch;
 const N: usize = 81;
 const M: usize = 1269;
 
-swatch!(MunsellMattSwatch, N, M, "Munsell Matt Swatch {}", scot::Domain::new(380/5, 780/5, scot::NM5), MUNSELL_MATT_DATA);
-swatch!(MunsellMatt, N, M, "Munsell Matt", scot::Domain::new(380/5, 780/5, scot::NM5), MUNSELL_MATT_DATA, MUNSELL_MATT_KEYS);
+swatch!(MunsellMattSwatch, N, M, "Munsell Matt Swatch {}", scot::Domain::new(380/5, 780/5, scot::NM5), MUNSELL_MATT.data());
+swatch!(MunsellMatt, N, M, "Munsell Matt", scot::Domain::new(380/5, 780/5, scot::NM5), MUNSELL_MATT.data(), MUNSELL_MATT.keys());
 
+static MUNSELL_MATT: LazyBlob = LazyBlob::new(include_blob!("matt.bin"));
 
-static MUNSELL_MATT_KEYS: [&str; M] =  [
-	"2.5R9/2", "2.5R8/2", "2.5R7/2", "2.5R6/2", "2.5R5/2", "2.5R4/2", "2.5R3/2", "2.5R2.5/2", "2.5R8/4", "2.5R7/4", "2.5R6/4", "2.5R5/4", "2.5R4/4", "2.5R3/4",
-	"2.5R7/6", "2.5R6/6", "2.5R5/6", "2.5R4/6", "2.5R3/6", "2.5R7/8", "2.5R6/8", "2.5R5/8", "2.5R4/8", "2.5R7/10", "2.5R6/10", "2.5R5/10", "2.5R4/10", "2.5R6/12",
-	"2.5R5/12", "2.5R4/12", "5R9/1", "5R8/1", "5R7/1", "5R6/1", "5R5/1", "5R4/1", "5R3/1", "5R2.5/1", "5R9/2", "5R8/2", "5R7/2", "5R6/2", "5R5/2", "5R4/2", "5R3/2",
-	"5R2.5/2", "5R8/4", "5R7/4", "5R6/4", "5R5/4", "5R4/4", "5R3/4", "5R7/6", "5R6/6", "5R5/6", "5R4/6", "5R3/6", "5R7/8", "5R6/8", "5R5/8", "5R4/8", "5R7/10",
-	"5R6/10", "5R5/10", "5R4/10", "5R6/12", "5R5/12", "5R4/12", "5R6/14", "5R5/14", "5R4/14", "7.5R9/2", "7.5R8/2", "7.5R7/2", "7.5R6/2", "7.5R5/2", "7.5R4/2",
-	"7.5R3/2", "7.5R2.5/2", "7.5R8/4", "7.5R7/4", "7.5R6/4", "7.5R5/4", "7.5R4/4", "7.5R3/4", "7.5R7/6", "7.5R6/6", "7.5R5/6", "7.5R4/6", "7.5R3/6", "7.5R7/8",
-	"7.5R6/8", "7.5R5/8", "7.5R4/8", "7.5R7/10", "7.5R6/10", "7.5R5/10", "7.5R4/10", "7.5R6/12", "7.5R5/12", "7.5R4/12", "10R9/1", "10R8/1", "10R7/1", "10R6/1",
-	"10R5/1", "10R4/1", "10R3/1", "10R2.5/1", "10R9/2", "10R8/2", "10R7/2", "10R6/2", "10R5/2", "10R4/2", "10R3/2", "10R2.5/2", "10R8/4", "10R7/4", "10R6/4",
-	"10R5/4", "10R4/4", "10R3/4", "10R8/6", "10R7/6", "10R6/6", "10R5/6", "10R4/6", "10R8/8", "10R7/8", "10R6/8", "10R5/8", "10R4/8", "10R7/10", "10R6/10",
-	"10R5/10", "10R7/12", "10R6/12", "10R5/12", "2.5YR9/2", "2.5YR8/2", "2.5YR7/2", "2.5YR6/2", "2.5YR5/2", "2.5YR4/2", "2.5YR3/2", "2.5YR2.5/2", "2.5YR8/4",
-	"2.5YR7/4", "2.5YR6/4", "2.5YR5/4", "2.5YR4/4", "2.5YR8/6", "2.5YR7/6", "2.5YR6/6", "2.5YR5/6", "2.5YR4/6", "2.5YR8/8", "2.5YR7/8", "2.5YR6/8", "2.5YR5/8",
-	"2.5YR4/8", "2.5YR7/10", "2.5YR6/10", "2.5YR5/10", "2.5YR7/12", "2.5YR6/12", "2.5YR6/14", "5YR9/1", "5YR8/1", "5YR7/1", "5YR6/1", "5YR5/1", "5YR4/1", "5YR3/1",
-	"5YR2.5/1", "5YR9/2", "5YR8/2", "5YR7/2", "5YR6/2", "5YR5/2", "5YR4/2", "5YR3/2", "5YR9/4", "5YR8/4", "5YR7/4", "5YR6/4", "5YR5/4", "5YR4/4", "5YR8/6",
-	"5YR7/6", "5YR6/6", "5YR5/6", "5YR4/6", "5YR8/8", "5YR7/8", "5YR6/8", "5YR5/8", "5YR7/10", "5YR6/10", "5YR7/12", "5YR6/12", "7.5YR9/2", "7.5YR8/2", "7.5YR7/2",
-	"7.5YR6/2", "7.5YR5/2", "7.5YR4/2", "7.5YR3/2", "7.5YR9/4", "7.5YR8/4", "7.5YR7/4", "7.5YR6/4", "7.5YR5/4", "7.5YR4/4", "7.5YR8/6", "7.5YR7/6", "7.5YR6/6",
-	"7.5YR5/6", "7.5YR4/6", "7.5YR8/8", "7.5YR7/8", "7.5YR6/8", "7.5YR5/8", "7.5YR8/10", "7.5YR7/10", "7.5YR6/10", "7.5YR7/12", "10YR9/1", "10YR8/1", "10YR7/1",
-	"10YR6/1", "10YR5/1", "10YR4/1", "10YR3/1", "10YR2.5/1", "10YR9/2", "10YR8/2", "10YR7/2", "10YR6/2", "10YR5/2", "10YR4/2", "10YR3/2", "10YR9/4", "10YR8/4",
-	"10YR7/4", "10YR6/4", "10YR5/4", "10YR4/4", "10YR8/6", "10YR7/6", "10YR6/6", "10YR5/6", "10YR8/8", "10YR7/8", "10YR6/8", "10YR5/8", "10YR8/10", "10YR7/10",
-	"10YR6/10", "10YR7/12", "2.5Y9/2", "2.5Y8.5/2", "2.5Y8/2", "2.5Y7/2", "2.5Y6/2", "2.5Y5/2", "2.5Y4/2", "2.5Y3/2", "2.5Y9/4", "2.5Y8.5/4", "2.5Y8/4", "2.5Y7/4",
-	"2.5Y6/4", "2.5Y5/4", "2.5Y4/4", "2.5Y9/6", "2.5Y8.5/6", "2.5Y8/6", "2.5Y7/6", "2.5Y6/6", "2.5Y5/6", "2.5Y8.5/8", "2.5Y8/8", "2.5Y7/8", "2.5Y6/8", "2.5Y8.5/10",
-	"2.5Y8/10", "2.5Y7/10", "2.5Y8/12", "2.5Y7/12", "5Y9/1", "5Y8.5/1", "5Y8/1", "5Y7/1", "5Y6/1", "5Y5/1", "5Y4/1", "5Y3/1", "5Y2.5/1", "5Y9/2", "5Y8.5/2",
-	"5Y8/2", "5Y7/2", "5Y6/2", "5Y5/2", "5Y4/2", "5Y3/2", "5Y9/4", "5Y8.5/4", "5Y8/4", "5Y7/4", "5Y6/4", "5Y5/4", "5Y4/4", "5Y9/6", "5Y8.5/6", "5Y8/6", "5Y7/6",
-	"5Y6/6", "5Y5/6", "5Y9/8", "5Y8.5/8", "5Y8/8", "5Y7/8", "5Y6/8", "5Y8.5/10", "5Y8/10", "5Y7/10", "5Y8.5/12", "5Y8/12", "5Y7/12", "7.5Y9/2", "7.5Y8.5/2",
-	"7.5Y8/2", "7.5Y7/2", "7.5Y6/2", "7.5Y5/2", "7.5Y4/2", "7.5Y3/2", "7.5Y9/4", "7.5Y8.5/4", "7.5Y8/4", "7.5Y7/4", "7.5Y6/4", "7.5Y5/4", "7.5Y4/4", "7.5Y9/6",
-	"7.5Y8.5/6", "7.5Y8/6", "7.5Y7/6", "7.5Y6/6", "7.5Y5/6", "7.5Y9/8", "7.5Y8.5/8", "7.5Y8/8", "7.5Y7/8", "7.5Y6/8", "7.5Y9/10", "7.5Y8.5/10", "7.5Y8/10",
-	"7.5Y7/10", "7.5Y8.5/12", "7.5Y8/12", "10Y9/1", "10Y8.5/1", "10Y8/1", "10Y7/1", "10Y6/1", "10Y5/1", "10Y4/1", "10Y3/1", "10Y2.5/1", "10Y9/2", "10Y8.5/2",
-	"10Y8/2", "10Y7/2", "10Y6/2", "10Y5/2", "10Y4/2", "10Y3/2", "10Y9/4", "10Y8.5/4", "10Y8/4", "10Y7/4", "10Y6/4", "10Y5/4", "10Y4/4", "10Y9/6", "10Y8.5/6",
-	"10Y8/6", "10Y7/6", "10Y6/6", "10Y5/6", "10Y9/8", "10Y8.5/8", "10Y8/8", "10Y7/8", "10Y6/8", "10Y9/10", "10Y8.5/10", "10Y8/10", "10Y7/10", "10Y8/12", "2.5GY9/2",
-	"2.5GY8.5/2", "2.5GY8/2", "2.5GY7/2", "2.5GY6/2", "2.5GY5/2", "2.5GY4/2", "2.5GY3/2", "2.5GY9/4", "2.5GY8.5/4", "2.5GY8/4", "2.5GY7/4", "2.5GY6/4", "2.5GY5/4",
-	"2.5GY4/4", "2.5GY9/6", "2.5GY8.5/6", "2.5GY8/6", "2.5GY7/6", "2.5GY6/6", "2.5GY5/6", "2.5GY9/8", "2.5GY8.5/8", "2.5GY8/8", "2.5GY7/8", "2.5GY6/8",
-	"2.5GY8.5/10", "2.5GY8/10", "2.5GY7/10", "5GY9/1", "5GY8.5/1", "5GY8/1", "5GY7/1", "5GY6/1", "5GY5/1", "5GY4/1", "5GY3/1", "5GY2.5/1", "5GY9/2", "5GY8.5/2",
-	"5GY8/2", "5GY7/2", "5GY6/2", "5GY5/2", "5GY4/2", "5GY3/2", "5GY9/4", "5GY8.5/4", "5GY8/4", "5GY7/4", "5GY6/4", "5GY5/4", "5GY4/4", "5GY9/6", "5GY8.5/6",
-	"5GY8/6", "5GY7/6", "5GY6/6", "5GY5/6", "5GY8.5/8", "5GY8/8", "5GY7/8", "5GY6/8", "5GY5/8", "5GY8.5/10", "5GY8/10", "7.5GY9/2", "7.5GY8.5/2", "7.5GY8/2",
-	"7.5GY7/2", "7.5GY6/2", "7.5GY5/2", "7.5GY4/2", "7.5GY3/2", "7.5GY9/4", "7.5GY8.5/4", "7.5GY8/4", "7.5GY7/4", "7.5GY6/4", "7.5GY5/4", "7.5GY4/4", "7.5GY8.5/6",
-	"7.5GY8/6", "7.5GY7/6", "7.5GY6/6", "7.5GY5/6", "7.5GY8/8", "7.5GY7/8", "7.5GY6/8", "7.5GY5/8", "7.5GY7/10", "7.5GY6/10", "10GY9/1", "10GY8.5/1", "10GY8/1",
-	"10GY7/1", "10GY6/1", "10GY5/1", "10GY4/1", "10GY3/1", "10GY2.5/1", "10GY9/2", "10GY8.5/2", "10GY8/2", "10GY7/2", "10GY6/2", "10GY5/2", "10GY4/2", "10GY3/2",
-	"10GY2.5/2", "10GY9/4", "10GY8.5/4", "10GY8/4", "10GY7/4", "10GY6/4", "10GY5/4", "10GY4/4", "10GY8.5/6", "10GY8/6", "10GY7/6", "10GY6/6", "10GY5/6", "10GY7/8",
-	"10GY6/8", "10GY5/8", "10GY7/10", "10GY6/10", "2.5G9/2", "2.5G8/2", "2.5G7/2", "2.5G6/2", "2.5G5/2", "2.5G4/2", "2.5G3/2", "2.5G2.5/2", "2.5G8/4", "2.5G7/4",
-	"2.5G6/4", "2.5G5/4", "2.5G4/4", "2.5G3/4", "2.5G8/6", "2.5G7/6", "2.5G6/6", "2.5G5/6", "2.5G4/6", "2.5G7/8", "2.5G6/8", "2.5G5/8", "2.5G7/10", "2.5G6/10",
-	"2.5G5/10", "2.5G6/12", "5G9/1", "5G8/1", "5G7/1", "5G6/1", "5G5/1", "5G4/1", "5G3/1", "5G2.5/1", "5G9/2", "5G8/2", "5G7/2", "5G6/2", "5G5/2", "5G4/2", "5G3/2",
-	"5G2.5/2", "5G8/4", "5G7/4", "5G6/4", "5G5/4", "5G4/4", "5G3/4", "5G8/6", "5G7/6", "5G6/6", "5G5/6", "5G4/6", "5G7/8", "5G6/8", "5G5/8", "5G4/8", "5G7/10",
-	"5G6/10", "7.5G9/2", "7.5G8/2", "7.5G7/2", "7.5G6/2", "7.5G5/2", "7.5G4/2", "7.5G3/2", "7.5G2.5/2", "7.5G8/4", "7.5G7/4", "7.5G6/4", "7.5G5/4", "7.5G4/4",
-	"7.5G3/4", "7.5G8/6", "7.5G7/6", "7.5G6/6", "7.5G5/6", "7.5G4/6", "7.5G7/8", "7.5G6/8", "7.5G5/8", "7.5G4/8", "7.5G7/10", "7.5G6/10", "10G9/1", "10G8/1",
-	"10G7/1", "10G6/1", "10G5/1", "10G4/1", "10G3/1", "10G2.5/1", "10G9/2", "10G8/2", "10G7/2", "10G6/2", "10G5/2", "10G4/2", "10G3/2", "10G2.5/2", "10G8/4",
-	"10G7/4", "10G6/4", "10G5/4", "10G4/4", "10G3/4", "10G7/6", "10G6/6", "10G5/6", "10G4/6", "10G7/8", "10G6/8", "10G5/8", "10G4/8", "10G6/10", "2.5BG9/2",
-	"2.5BG8/2", "2.5BG7/2", "2.5BG6/2", "2.5BG5/2", "2.5BG4/2", "2.5BG3/2", "2.5BG2.5/2", "2.5BG8/4", "2.5BG7/4", "2.5BG6/4", "2.5BG5/4", "2.5BG4/4", "2.5BG3/4",
-	"2.5BG7/6", "2.5BG6/6", "2.5BG5/6", "2.5BG4/6", "2.5BG3/6", "2.5BG7/8", "2.5BG6/8", "2.5BG5/8", "2.5BG4/8", "2.5BG6/10", "5BG9/1", "5BG8/1", "5BG7/1", "5BG6/1",
-	"5BG5/1", "5BG4/1", "5BG3/1", "5BG2.5/1", "5BG9/2", "5BG8/2", "5BG7/2", "5BG6/2", "5BG5/2", "5BG4/2", "5BG3/2", "5BG2.5/2", "5BG8/4", "5BG7/4", "5BG6/4",
-	"5BG5/4", "5BG4/4", "5BG3/4", "5BG7/6", "5BG6/6", "5BG5/6", "5BG4/6", "5BG7/8", "5BG6/8", "5BG5/8", "5BG4/8", "7.5BG9/2", "7.5BG8/2", "7.5BG7/2", "7.5BG6/2",
-	"7.5BG5/2", "7.5BG4/2", "7.5BG3/2", "7.5BG2.5/2", "7.5BG8/4", "7.5BG7/4", "7.5BG6/4", "7.5BG5/4", "7.5BG4/4", "7.5BG3/4", "7.5BG7/6", "7.5BG6/6", "7.5BG5/6",
-	"7.5BG4/6", "7.5BG7/8", "7.5BG6/8", "7.5BG5/8", "7.5BG4/8", "10BG9/1", "10BG8/1", "10BG7/1", "10BG6/1", "10BG5/1", "10BG4/1", "10BG3/1", "10BG2.5/1", "10BG9/2",
-	"10BG8/2", "10BG7/2", "10BG6/2", "10BG5/2", "10BG4/2", "10BG3/2", "10BG2.5/2", "10BG8/4", "10BG7/4", "10BG6/4", "10BG5/4", "10BG4/4", "10BG3/4", "10BG7/6",
-	"10BG6/6", "10BG5/6", "10BG4/6", "10BG7/8", "10BG6/8", "10BG5/8", "10BG4/8", "2.5B9/2", "2.5B8/2", "2.5B7/2", "2.5B6/2", "2.5B5/2", "2.5B4/2", "2.5B3/2",
-	"2.5B2.5/2", "2.5B8/4", "2.5B7/4", "2.5B6/4", "2.5B5/4", "2.5B4/4", "2.5B3/4", "2.5B7/6", "2.5B6/6", "2.5B5/6", "2.5B4/6", "2.5B7/8", "2.5B6/8", "2.5B5/8",
-	"2.5B4/8", "5B9/1", "5B8/1", "5B7/1", "5B6/1", "5B5/1", "5B4/1", "5B3/1", "5B2.5/1", "5B9/2", "5B8/2", "5B7/2", "5B6/2", "5B5/2", "5B4/2", "5B3/2", "5B2.5/2",
-	"5B8/4", "5B7/4", "5B6/4", "5B5/4", "5B4/4", "5B3/4", "5B7/6", "5B6/6", "5B5/6", "5B4/6", "5B3/6", "5B7/8", "5B6/8", "5B5/8", "5B4/8", "7.5B9/2", "7.5B8/2",
-	"7.5B7/2", "7.5B6/2", "7.5B5/2", "7.5B4/2", "7.5B3/2", "7.5B2.5/2", "7.5B8/4", "7.5B7/4", "7.5B6/4", "7.5B5/4", "7.5B4/4", "7.5B3/4", "7.5B7/6", "7.5B6/6",
-	"7.5B5/6", "7.5B4/6", "7.5B3/6", "7.5B7/8", "7.5B6/8", "7.5B5/8", "7.5B4/8", "7.5B6/10", "7.5B5/10", "10B9/1", "10B8/1", "10B7/1", "10B6/1", "10B5/1", "10B4/1",
-	"10B3/1", "10B2.5/1", "10B9/2", "10B8/2", "10B7/2", "10B6/2", "10B5/2", "10B4/2", "10B3/2", "10B2.5/2", "10B8/4", "10B7/4", "10B6/4", "10B5/4", "10B4/4",
-	"10B3/4", "10B2.5/4", "10B7/6", "10B6/6", "10B5/6", "10B4/6", "10B3/6", "10B7/8", "10B6/8", "10B5/8", "10B4/8", "10B6/10", "10B5/10", "2.5PB9/2", "2.5PB8/2",
-	"2.5PB7/2", "2.5PB6/2", "2.5PB5/2", "2.5PB4/2", "2.5PB3/2", "2.5PB2.5/2", "2.5PB8/4", "2.5PB7/4", "2.5PB6/4", "2.5PB5/4", "2.5PB4/4", "2.5PB3/4", "2.5PB2.5/4",
-	"2.5PB8/6", "2.5PB7/6", "2.5PB6/6", "2.5PB5/6", "2.5PB4/6", "2.5PB3/6", "2.5PB7/8", "2.5PB6/8", "2.5PB5/8", "2.5PB4/8", "2.5PB6/10", "2.5PB5/10", "2.5PB4/10",
-	"5PB9/1", "5PB8/1", "5PB7/1", "5PB6/1", "5PB5/1", "5PB4/1", "5PB3/1", "5PB2.5/1", "5PB9/2", "5PB8/2", "5PB7/2", "5PB6/2", "5PB5/2", "5PB4/2", "5PB3/2",
-	"5PB2.5/2", "5PB8/4", "5PB7/4", "5PB6/4", "5PB5/4", "5PB4/4", "5PB3/4", "5PB2.5/4", "5PB8/6", "5PB7/6", "5PB6/6", "5PB5/6", "5PB4/6", "5PB3/6", "5PB7/8",
-	"5PB6/8", "5PB5/8", "5PB4/8", "5PB3/8", "5PB6/10", "5PB5/10", "5PB4/10", "5PB5/12", "5PB4/12", "7.5PB9/2", "7.5PB8/2", "7.5PB7/2", "7.5PB6/2", "7.5PB5/2",
-	"7.5PB4/2", "7.5PB3/2", "7.5PB2.5/2", "7.5PB8/4", "7.5PB7/4", "7.5PB6/4", "7.5PB5/4", "7.5PB4/4", "7.5PB3/4", "7.5PB2.5/4", "7.5PB8/6", "7.5PB7/6", "7.5PB6/6",
-	"7.5PB5/6", "7.5PB4/6", "7.5PB3/6", "7.5PB2.5/6", "7.5PB7/8", "7.5PB6/8", "7.5PB5/8", "7.5PB4/8", "7.5PB3/8", "7.5PB6/10", "7.5PB5/10", "7.5PB4/10",
-	"7.5PB3/10", "7.5PB5/12", "7.5PB4/12", "10PB9/1", "10PB8/1", "10PB7/1", "10PB6/1", "10PB5/1", "10PB4/1", "10PB3/1", "10PB2.5/1", "10PB9/2", "10PB8/2",
-	"10PB7/2", "10PB6/2", "10PB5/2", "10PB4/2", "10PB3/2", "10PB2.5/2", "10PB8/4", "10PB7/4", "10PB6/4", "10PB5/4", "10PB4/4", "10PB3/4", "10PB2.5/4", "10PB7/6",
-	"10PB6/6", "10PB5/6", "10PB4/6", "10PB3/6", "10PB2.5/6", "10PB7/8", "10PB6/8", "10PB5/8", "10PB4/8", "10PB3/8", "10PB6/10", "10PB5/10", "10PB4/10", "2.5P9/2",
-	"2.5P8/2", "2.5P7/2", "2.5P6/2", "2.5P5/2", "2.5P4/2", "2.5P3/2", "2.5P2.5/2", "2.5P8/4", "2.5P7/4", "2.5P6/4", "2.5P5/4", "2.5P4/4", "2.5P3/4", "2.5P2.5/4",
-	"2.5P7/6", "2.5P6/6", "2.5P5/6", "2.5P4/6", "2.5P3/6", "2.5P7/8", "2.5P6/8", "2.5P5/8", "2.5P4/8", "2.5P3/8", "2.5P6/10", "2.5P5/10", "2.5P4/10", "5P9/1",
-	"5P8/1", "5P7/1", "5P6/1", "5P5/1", "5P4/1", "5P3/1", "5P2.5/1", "5P9/2", "5P8/2", "5P7/2", "5P6/2", "5P5/2", "5P4/2", "5P3/2", "5P2.5/2", "5P8/4", "5P7/4",
-	"5P6/4", "5P5/4", "5P4/4", "5P3/4", "5P2.5/4", "5P7/6", "5P6/6", "5P5/6", "5P4/6", "5P3/6", "5P2.5/6", "5P7/8", "5P6/8", "5P5/8", "5P4/8", "5P3/8", "5P5/10",
-	"5P4/10", "7.5P9/2", "7.5P8/2", "7.5P7/2", "7.5P6/2", "7.5P5/2", "7.5P4/2", "7.5P3/2", "7.5P2.5/2", "7.5P8/4", "7.5P7/4", "7.5P6/4", "7.5P5/4", "7.5P4/4",
-	"7.5P3/4", "7.5P2.5/4", "7.5P8/6", "7.5P7/6", "7.5P6/6", "7.5P5/6", "7.5P4/6", "7.5P3/6", "7.5P7/8", "7.5P6/8", "7.5P5/8", "7.5P4/8", "7.5P3/8", "7.5P6/10",
-	"7.5P5/10", "7.5P4/10", "10P9/1", "10P8/1", "10P7/1", "10P6/1", "10P5/1", "10P4/1", "10P3/1", "10P2.5/1", "10P9/2", "10P8/2", "10P7/2", "10P6/2", "10P5/2",
-	"10P4/2", "10P3/2", "10P2.5/2", "10P8/4", "10P7/4", "10P6/4", "10P5/4", "10P4/4", "10P3/4", "10P2.5/4", "10P8/6", "10P7/6", "10P6/6", "10P5/6", "10P4/6",
-	"10P3/6", "10P7/8", "10P6/8", "10P5/8", "10P4/8", "10P3/8", "10P6/10", "10P5/10", "10P4/10", "10P5/12", "2.5RP9/2", "2.5RP8/2", "2.5RP7/2", "2.5RP6/2",
-	"2.5RP5/2", "2.5RP4/2", "2.5RP3/2", "2.5RP2.5/2", "2.5RP8/4", "2.5RP7/4", "2.5RP6/4", "2.5RP5/4", "2.5RP4/4", "2.5RP3/4", "2.5RP2.5/4", "2.5RP8/6", "2.5RP7/6",
-	"2.5RP6/6", "2.5RP5/6", "2.5RP4/6", "2.5RP3/6", "2.5RP7/8", "2.5RP6/8", "2.5RP5/8", "2.5RP4/8", "2.5RP7/10", "2.5RP6/10", "2.5RP5/10", "2.5RP4/10", "2.5RP6/12",
-	"2.5RP5/12", "5RP9/1", "5RP8/1", "5RP7/1", "5RP6/1", "5RP5/1", "5RP4/1", "5RP3/1", "5RP2.5/1", "5RP9/2", "5RP8/2", "5RP7/2", "5RP6/2", "5RP5/2", "5RP4/2",
-	"5RP3/2", "5RP2.5/2", "5RP8/4", "5RP7/4", "5RP6/4", "5RP5/4", "5RP4/4", "5RP3/4", "5RP2.5/4", "5RP8/6", "5RP7/6", "5RP6/6", "5RP5/6", "5RP4/6", "5RP3/6",
-	"5RP7/8", "5RP6/8", "5RP5/8", "5RP4/8", "5RP6/10", "5RP5/10", "5RP4/10", "5RP5/12", "5RP4/12", "7.5RP9/2", "7.5RP8/2", "7.5RP7/2", "7.5RP6/2", "7.5RP5/2",
-	"7.5RP4/2", "7.5RP3/2", "7.5RP2.5/2", "7.5RP8/4", "7.5RP7/4", "7.5RP6/4", "7.5RP5/4", "7.5RP4/4", "7.5RP3/4", "7.5RP2.5/4", "7.5RP8/6", "7.5RP7/6", "7.5RP6/6",
-	"7.5RP5/6", "7.5RP4/6", "7.5RP3/6", "7.5RP7/8", "7.5RP6/8", "7.5RP5/8", "7.5RP4/8", "7.5RP6/10", "7.5RP5/10", "7.5RP4/10", "7.5RP5/12", "7.5RP4/12", "10RP9/1",
-	"10RP8/1", "10RP7/1", "10RP6/1", "10RP5/1", "10RP4/1", "10RP3/1", "10RP2.5/1", "10RP9/2", "10RP8/2", "10RP7/2", "10RP6/2", "10RP5/2", "10RP4/2", "10RP3/2",
-	"10RP2.5/2", "10RP8/4", "10RP7/4", "10RP6/4", "10RP5/4", "10RP4/4", "10RP3/4", "10RP2.5/4", "10RP8/6", "10RP7/6", "10RP6/6", "10RP5/6", "10RP4/6", "10RP3/6",
-	"10RP7/8", "10RP6/8", "10RP5/8", "10RP4/8", "10RP6/10", "10RP5/10", "10RP4/10", "10RP5/12", "10RP4/12" 
-];
-
-static MUNSELL_MATT_DATA: [f64; N*M] = [
-//1
-0.13734, 0.1673, 0.22526, 0.31188, 0.42586, 0.54516, 0.63772, 0.68288, 0.69536, 0.69984, 0.69976, 0.7004, 0.70142, 0.69752, 0.69482, 0.69434, 0.69538, 0.6948,
-0.69628, 0.6932, 0.68776, 0.68066, 0.67228, 0.66784, 0.66962, 0.67186, 0.67224, 0.66932, 0.66152, 0.65276, 0.64812, 0.64788, 0.65462, 0.67238, 0.69556, 0.71622,
-0.73632, 0.7488, 0.75698, 0.76368, 0.76372, 0.7676, 0.76944, 0.76596, 0.7669, 0.76692, 0.765, 0.76516, 0.76512, 0.7634, 0.76486, 0.76436, 0.76138, 0.76272,
-0.7608, 0.75892, 0.76124, 0.75996, 0.76002, 0.76062, 0.75896, 0.75644, 0.7571, 0.7585, 0.75576, 0.75978, 0.75958, 0.7556, 0.75664, 0.75696, 0.75556, 0.75768,
-0.75902, 0.75492, 0.75578, 0.75566, 0.7554, 0.75408, 0.75548, 0.7489, 0.75638, 
-//2
-0.14274, 0.17232, 0.22862, 0.3085, 0.4001, 0.47594, 0.51786, 0.531, 0.53058, 0.52508, 0.52294, 0.52116, 0.51958, 0.51602, 0.51286, 0.50946, 0.5112, 0.5123,
-0.51326, 0.51218, 0.50646, 0.49858, 0.49164, 0.48702, 0.48752, 0.49148, 0.49246, 0.48824, 0.48144, 0.47236, 0.46642, 0.46616, 0.47386, 0.49078, 0.51732,
-0.54244, 0.55552, 0.56942, 0.57578, 0.5773, 0.57846, 0.57882, 0.57856, 0.57846, 0.5783, 0.5755, 0.57628, 0.5749, 0.5734, 0.57362, 0.57328, 0.5715, 0.57022,
-0.56918, 0.56774, 0.56688, 0.56752, 0.56522, 0.56622, 0.56742, 0.56406, 0.56356, 0.5625, 0.56112, 0.56122, 0.56198, 0.56046, 0.55842, 0.55848, 0.55822, 0.558,
-0.55708, 0.55736, 0.55494, 0.55516, 0.55434, 0.55484, 0.55386, 0.55334, 0.54774, 0.54914, 
-//3
-0.13508, 0.1645, 0.21464, 0.27672, 0.33586, 0.3745, 0.39034, 0.38998, 0.38674, 0.38234, 0.3798, 0.37882, 0.37802, 0.37386, 0.37214, 0.37024, 0.3695, 0.37002,
-0.3702, 0.36832, 0.3626, 0.3543, 0.34918, 0.34694, 0.34912, 0.35172, 0.35364, 0.35144, 0.34498, 0.33728, 0.33306, 0.3327, 0.33872, 0.35402, 0.37536, 0.3962,
-0.40798, 0.42144, 0.42728, 0.43072, 0.43254, 0.4319, 0.43384, 0.43234, 0.43162, 0.43332, 0.43218, 0.43042, 0.43008, 0.43102, 0.42904, 0.42614, 0.42628, 0.42406,
-0.4232, 0.42244, 0.4227, 0.42162, 0.42248, 0.42216, 0.42066, 0.41904, 0.41666, 0.41706, 0.41786, 0.4159, 0.41636, 0.4156, 0.41448, 0.41612, 0.41626, 0.41462,
-0.41468, 0.41294, 0.41276, 0.411, 0.41282, 0.41156, 0.4101, 0.40834, 0.40916, 
-//4
-0.13074, 0.15166, 0.1898, 0.22956, 0.2596, 0.27328, 0.27728, 0.27368, 0.26926, 0.26642, 0.26274, 0.26256, 0.26158, 0.25824, 0.25534, 0.2521, 0.25154, 0.25202,
-0.25256, 0.25084, 0.24698, 0.2429, 0.23708, 0.23336, 0.23416, 0.23592, 0.2367, 0.233, 0.22732, 0.22074, 0.21804, 0.2195, 0.22434, 0.23746, 0.25752, 0.27576,
-0.28828, 0.30096, 0.30416, 0.30658, 0.30594, 0.30458, 0.30648, 0.30554, 0.306, 0.30554, 0.30488, 0.3035, 0.30244, 0.30086, 0.29832, 0.2978, 0.29668, 0.29634,
-0.29642, 0.29518, 0.29584, 0.29352, 0.29266, 0.29228, 0.29082, 0.28686, 0.2879, 0.28958, 0.28888, 0.28856, 0.28666, 0.28774, 0.28692, 0.2852, 0.2865, 0.28628,
-0.28604, 0.2847, 0.28534, 0.28402, 0.2828, 0.28406, 0.28194, 0.28072, 0.28456, 
-//5
-0.10958, 0.12556, 0.1482, 0.16892, 0.18104, 0.18508, 0.1855, 0.18166, 0.1782, 0.17538, 0.17306, 0.17174, 0.1718, 0.16904, 0.165, 0.1633, 0.16358, 0.1639,
-0.16544, 0.16514, 0.16154, 0.15694, 0.15378, 0.15098, 0.1504, 0.1513, 0.1511, 0.14846, 0.14638, 0.1432, 0.14274, 0.14356, 0.14756, 0.15484, 0.16726, 0.17766,
-0.18284, 0.19524, 0.19912, 0.20184, 0.20452, 0.20556, 0.20568, 0.20564, 0.20462, 0.20438, 0.20572, 0.20476, 0.20422, 0.2029, 0.20308, 0.2013, 0.20098, 0.19868,
-0.19742, 0.19728, 0.19856, 0.1949, 0.19562, 0.1979, 0.19626, 0.19376, 0.1933, 0.19428, 0.19356, 0.19302, 0.19188, 0.19372, 0.19388, 0.19388, 0.19562, 0.19562,
-0.19564, 0.1939, 0.19382, 0.19104, 0.19052, 0.1915, 0.19196, 0.19056, 0.19308, 
-//6
-0.09222, 0.09902, 0.10968, 0.1144, 0.11722, 0.1168, 0.11476, 0.11276, 0.11134, 0.1089, 0.10822, 0.1083, 0.10832, 0.10664, 0.1054, 0.1038, 0.10452, 0.10398,
-0.10258, 0.10168, 0.09994, 0.09666, 0.09524, 0.09448, 0.09482, 0.09476, 0.09496, 0.09232, 0.09044, 0.08954, 0.08906, 0.09022, 0.09248, 0.09772, 0.10474,
-0.10894, 0.10796, 0.11386, 0.1164, 0.1227, 0.13108, 0.13838, 0.14292, 0.1447, 0.14574, 0.14462, 0.14406, 0.14366, 0.1431, 0.1422, 0.14226, 0.14238, 0.14228,
-0.1414, 0.14104, 0.14014, 0.13922, 0.13638, 0.13686, 0.13828, 0.13706, 0.13594, 0.13528, 0.13734, 0.13758, 0.1371, 0.1364, 0.13678, 0.13692, 0.1374, 0.13938,
-0.13892, 0.13744, 0.13816, 0.1392, 0.13832, 0.13906, 0.13842, 0.13816, 0.13786, 0.13752, 
-//7
-0.06496, 0.06512, 0.0665, 0.06766, 0.067, 0.0679, 0.06906, 0.0667, 0.06564, 0.06496, 0.06468, 0.06388, 0.06422, 0.06298, 0.06106, 0.06234, 0.06224, 0.061,
-0.06096, 0.06044, 0.05916, 0.0583, 0.05812, 0.05712, 0.0567, 0.05518, 0.05458, 0.05412, 0.05392, 0.05432, 0.05616, 0.0572, 0.05854, 0.06032, 0.0598, 0.059,
-0.05514, 0.06238, 0.06574, 0.07048, 0.07514, 0.08002, 0.08626, 0.0895, 0.09076, 0.09372, 0.09272, 0.09402, 0.0935, 0.09148, 0.09128, 0.08892, 0.08738, 0.0862,
-0.08682, 0.08528, 0.08662, 0.08612, 0.08702, 0.08712, 0.08702, 0.08292, 0.08292, 0.0855, 0.08634, 0.08576, 0.08718, 0.08618, 0.08698, 0.0883, 0.0889, 0.08926,
-0.09008, 0.08942, 0.08926, 0.08838, 0.08876, 0.08928, 0.09038, 0.09066, 0.09228, 
-//8
-0.04922, 0.0496, 0.05042, 0.05036, 0.04908, 0.0483, 0.0466, 0.0468, 0.04774, 0.04694, 0.04772, 0.04864, 0.04996, 0.04666, 0.04448, 0.0427, 0.04282, 0.0417,
-0.04224, 0.04156, 0.04186, 0.04092, 0.04062, 0.04096, 0.04028, 0.03968, 0.03912, 0.03892, 0.03878, 0.03856, 0.03992, 0.04036, 0.0413, 0.04254, 0.0421, 0.04302,
-0.03758, 0.04748, 0.04894, 0.05046, 0.05078, 0.04938, 0.05018, 0.0517, 0.05264, 0.05574, 0.05992, 0.06466, 0.06986, 0.07362, 0.07922, 0.0845, 0.0888, 0.09282,
-0.09848, 0.10064, 0.104, 0.1037, 0.1073, 0.11022, 0.11116, 0.10756, 0.10982, 0.11318, 0.11428, 0.11724, 0.11948, 0.12244, 0.12502, 0.12738, 0.12938, 0.13172,
-0.13338, 0.13366, 0.13508, 0.13536, 0.13788, 0.13906, 0.14034, 0.14088, 0.14592, 
-//9
-0.13992, 0.1681, 0.22448, 0.3026, 0.39084, 0.46242, 0.49928, 0.5089, 0.50446, 0.49918, 0.49446, 0.49148, 0.4879, 0.48392, 0.47874, 0.47496, 0.47646, 0.47718,
-0.47952, 0.47842, 0.4692, 0.45648, 0.44606, 0.44162, 0.4409, 0.44438, 0.44668, 0.44076, 0.43166, 0.42034, 0.41296, 0.41234, 0.42248, 0.44644, 0.48186, 0.52254,
-0.55114, 0.57836, 0.5964, 0.6193, 0.6414, 0.65688, 0.66602, 0.67168, 0.67412, 0.6727, 0.67426, 0.6747, 0.67276, 0.67494, 0.6744, 0.6727, 0.6719, 0.67196,
-0.67024, 0.6694, 0.67084, 0.66818, 0.66732, 0.66948, 0.66732, 0.66598, 0.66362, 0.6656, 0.66548, 0.66654, 0.66574, 0.6656, 0.66466, 0.66508, 0.6631, 0.6619,
-0.66388, 0.66124, 0.65982, 0.66364, 0.66204, 0.66144, 0.66546, 0.66236, 0.66194, 
-//10
-0.13456, 0.16392, 0.21448, 0.27688, 0.33158, 0.36444, 0.3786, 0.37646, 0.3716, 0.3649, 0.36106, 0.35734, 0.35544, 0.35042, 0.3456, 0.34424, 0.34454, 0.34606,
-0.34778, 0.34574, 0.33882, 0.32764, 0.31928, 0.31236, 0.31322, 0.31696, 0.31872, 0.31348, 0.30656, 0.29764, 0.29206, 0.29264, 0.3008, 0.31966, 0.35268, 0.38766,
-0.41474, 0.44046, 0.45496, 0.47168, 0.486, 0.49302, 0.49868, 0.50312, 0.50264, 0.50342, 0.50406, 0.502, 0.50016, 0.499, 0.49756, 0.49726, 0.49696, 0.49522,
-0.49396, 0.49426, 0.49276, 0.49116, 0.4901, 0.48992, 0.48748, 0.48674, 0.4853, 0.48546, 0.48466, 0.48362, 0.48346, 0.48352, 0.48362, 0.48328, 0.48306, 0.4814,
-0.4815, 0.47818, 0.47974, 0.47764, 0.47796, 0.47754, 0.47754, 0.47512, 0.47716, 
-//11
-0.13524, 0.15832, 0.19476, 0.2331, 0.2586, 0.2706, 0.27246, 0.2672, 0.26236, 0.25774, 0.2552, 0.25056, 0.24904, 0.24418, 0.23914, 0.23766, 0.23812, 0.23888,
-0.24166, 0.24028, 0.2339, 0.22616, 0.21784, 0.21244, 0.2133, 0.21402, 0.21486, 0.2115, 0.20522, 0.1997, 0.1957, 0.19584, 0.20108, 0.21532, 0.23848, 0.26304,
-0.28042, 0.2986, 0.31192, 0.33002, 0.35104, 0.36494, 0.37528, 0.3793, 0.38208, 0.3821, 0.3816, 0.38004, 0.37822, 0.37684, 0.3776, 0.37614, 0.37598, 0.3752,
-0.3746, 0.37218, 0.37044, 0.3676, 0.36716, 0.36604, 0.36502, 0.36374, 0.3627, 0.36386, 0.36268, 0.36036, 0.36132, 0.36016, 0.36024, 0.36074, 0.35964, 0.35754,
-0.3588, 0.35696, 0.35632, 0.3559, 0.35646, 0.35472, 0.3548, 0.35128, 0.35058, 
-//12
-0.10392, 0.12144, 0.14582, 0.16422, 0.1745, 0.17622, 0.17584, 0.1703, 0.16676, 0.16252, 0.1615, 0.16112, 0.16096, 0.15858, 0.1558, 0.15436, 0.15394, 0.15486,
-0.1547, 0.15356, 0.1482, 0.1436, 0.13798, 0.1359, 0.13594, 0.13656, 0.13706, 0.1334, 0.12986, 0.12582, 0.12426, 0.12356, 0.1269, 0.13518, 0.14724, 0.1599,
-0.1647, 0.17406, 0.18422, 0.20034, 0.22068, 0.23818, 0.25002, 0.2548, 0.25938, 0.25998, 0.26128, 0.26128, 0.26082, 0.25976, 0.25978, 0.25834, 0.25742, 0.25884,
-0.25698, 0.25504, 0.25522, 0.25278, 0.25354, 0.25372, 0.25354, 0.25084, 0.24936, 0.25168, 0.25014, 0.25064, 0.25084, 0.25104, 0.25164, 0.25096, 0.25228, 0.2523,
-0.25108, 0.24958, 0.25012, 0.24904, 0.24914, 0.24808, 0.24948, 0.2481, 0.2505, 
-//13
-0.0896, 0.09722, 0.10614, 0.11176, 0.11168, 0.11078, 0.10914, 0.10662, 0.105, 0.10336, 0.10176, 0.10206, 0.10162, 0.09922, 0.09698, 0.09614, 0.09552, 0.09634,
-0.09618, 0.09416, 0.09258, 0.0904, 0.08704, 0.08528, 0.08476, 0.08312, 0.0829, 0.08206, 0.08022, 0.08108, 0.0824, 0.08344, 0.08488, 0.08766, 0.09092, 0.09316,
-0.09316, 0.09716, 0.10094, 0.11084, 0.12822, 0.14858, 0.16638, 0.17698, 0.18386, 0.18692, 0.18832, 0.18848, 0.18902, 0.18784, 0.18714, 0.1874, 0.18658, 0.18488,
-0.18458, 0.1835, 0.18298, 0.18138, 0.18096, 0.18096, 0.1802, 0.17844, 0.17912, 0.17936, 0.17858, 0.17746, 0.17776, 0.17826, 0.17764, 0.17916, 0.17924, 0.18004,
-0.1805, 0.17764, 0.17696, 0.1768, 0.17706, 0.17628, 0.1778, 0.17626, 0.17758, 
-//14
-0.05666, 0.05798, 0.05786, 0.05814, 0.05934, 0.058, 0.0576, 0.05756, 0.05686, 0.05632, 0.05594, 0.05634, 0.05496, 0.0536, 0.05342, 0.0542, 0.05394, 0.05216,
-0.05218, 0.0508, 0.05018, 0.04792, 0.0456, 0.04504, 0.04458, 0.04578, 0.04554, 0.0462, 0.0469, 0.04632, 0.0471, 0.04666, 0.04566, 0.04454, 0.04666, 0.0458,
-0.04416, 0.0516, 0.0558, 0.06088, 0.067, 0.07462, 0.08306, 0.09184, 0.09998, 0.1092, 0.11766, 0.12466, 0.1313, 0.1355, 0.13918, 0.14248, 0.14324, 0.14474,
-0.14528, 0.146, 0.14454, 0.14276, 0.14384, 0.14462, 0.14594, 0.144, 0.14444, 0.1465, 0.14842, 0.14806, 0.14936, 0.15062, 0.1511, 0.1528, 0.15322, 0.15294,
-0.1535, 0.15316, 0.1547, 0.154, 0.15726, 0.1566, 0.15816, 0.1604, 0.16074, 
-//15
-0.13684, 0.16606, 0.21474, 0.27136, 0.32232, 0.35066, 0.3581, 0.3544, 0.34806, 0.34016, 0.33678, 0.33428, 0.3318, 0.32694, 0.3234, 0.32044, 0.32006, 0.32114,
-0.32138, 0.31898, 0.312, 0.30106, 0.2905, 0.28478, 0.2847, 0.28634, 0.28676, 0.28142, 0.27322, 0.2635, 0.25816, 0.25804, 0.26502, 0.28402, 0.31226, 0.34292,
-0.36548, 0.38446, 0.40354, 0.43666, 0.4808, 0.52038, 0.54888, 0.56634, 0.57704, 0.58118, 0.58402, 0.58634, 0.5843, 0.58658, 0.58588, 0.58506, 0.58332, 0.5835,
-0.58456, 0.58124, 0.58142, 0.58058, 0.5797, 0.5802, 0.57894, 0.57742, 0.5751, 0.57622, 0.57618, 0.57496, 0.57514, 0.57466, 0.57328, 0.5741, 0.5732, 0.57136,
-0.57288, 0.57158, 0.572, 0.57052, 0.56876, 0.57228, 0.5686, 0.56544, 0.5668, 
-//16
-0.12794, 0.14994, 0.18364, 0.21926, 0.24356, 0.25224, 0.2534, 0.24706, 0.24192, 0.23544, 0.2315, 0.22972, 0.22736, 0.2248, 0.22182, 0.2205, 0.21952, 0.21886,
-0.21978, 0.21704, 0.21068, 0.20304, 0.19516, 0.19094, 0.19092, 0.19204, 0.19122, 0.18572, 0.17994, 0.17396, 0.17084, 0.17216, 0.17692, 0.18778, 0.2069, 0.22608,
-0.23818, 0.24986, 0.26332, 0.29034, 0.32928, 0.37062, 0.40158, 0.42138, 0.43104, 0.43698, 0.44004, 0.44058, 0.43972, 0.43962, 0.44056, 0.4392, 0.43882, 0.43768,
-0.43726, 0.43572, 0.43552, 0.43316, 0.43316, 0.43308, 0.43256, 0.43, 0.42824, 0.43024, 0.42976, 0.42728, 0.42852, 0.42772, 0.4267, 0.42514, 0.42508, 0.42542,
-0.42592, 0.42302, 0.4231, 0.4217, 0.42252, 0.41882, 0.42056, 0.41772, 0.41794, 
-//17
-0.10838, 0.12608, 0.1472, 0.1628, 0.17072, 0.1726, 0.17072, 0.16536, 0.16172, 0.15696, 0.15504, 0.15342, 0.15262, 0.15114, 0.14892, 0.14716, 0.1469, 0.14696,
-0.14662, 0.14442, 0.1416, 0.13482, 0.12966, 0.1276, 0.12716, 0.1267, 0.12554, 0.12264, 0.1199, 0.11638, 0.11572, 0.116, 0.12, 0.12696, 0.13814, 0.148, 0.15282,
-0.16064, 0.17176, 0.19454, 0.2288, 0.264, 0.2896, 0.30624, 0.31606, 0.32114, 0.3236, 0.32334, 0.32238, 0.3228, 0.32288, 0.32184, 0.32156, 0.32152, 0.32002,
-0.31856, 0.31838, 0.31592, 0.31562, 0.31656, 0.31626, 0.31436, 0.31302, 0.31288, 0.31288, 0.31196, 0.31294, 0.31184, 0.31214, 0.31174, 0.31034, 0.30988,
-0.30976, 0.3087, 0.30992, 0.3108, 0.30858, 0.30652, 0.30768, 0.30558, 0.30586, 
-//18
-0.0923, 0.09686, 0.10574, 0.11042, 0.11154, 0.11112, 0.1106, 0.10692, 0.10448, 0.10232, 0.10062, 0.09994, 0.10008, 0.09888, 0.0986, 0.09766, 0.09724, 0.09624,
-0.09472, 0.09306, 0.09136, 0.08838, 0.08666, 0.08566, 0.08522, 0.08398, 0.08262, 0.08154, 0.07928, 0.07962, 0.0804, 0.08268, 0.08508, 0.088, 0.0912, 0.09246,
-0.09164, 0.09558, 0.09934, 0.10926, 0.12712, 0.14724, 0.16522, 0.17662, 0.184, 0.18736, 0.18774, 0.1887, 0.1879, 0.18674, 0.1867, 0.18564, 0.1856, 0.18402,
-0.18322, 0.18264, 0.18128, 0.1801, 0.18018, 0.18008, 0.17954, 0.17846, 0.17844, 0.17814, 0.178, 0.17704, 0.17712, 0.17714, 0.17862, 0.17864, 0.17874, 0.17782,
-0.17764, 0.17634, 0.17664, 0.17638, 0.17574, 0.17712, 0.17732, 0.17622, 0.17542, 
-//19
-0.05526, 0.05544, 0.05536, 0.0549, 0.05448, 0.0549, 0.0549, 0.0549, 0.05488, 0.05312, 0.05268, 0.05176, 0.05314, 0.05342, 0.05366, 0.05208, 0.05128, 0.04914,
-0.04688, 0.04512, 0.04406, 0.04354, 0.04326, 0.04258, 0.043, 0.04212, 0.04096, 0.03964, 0.03966, 0.04084, 0.0416, 0.04106, 0.04156, 0.04202, 0.04214, 0.04064,
-0.03954, 0.0461, 0.0507, 0.05498, 0.06132, 0.06882, 0.07704, 0.08504, 0.09552, 0.10824, 0.12454, 0.1412, 0.15522, 0.16794, 0.1781, 0.18476, 0.19152, 0.19542,
-0.19934, 0.2032, 0.2064, 0.20652, 0.20802, 0.20972, 0.21134, 0.21058, 0.21244, 0.21572, 0.21536, 0.2179, 0.21812, 0.21926, 0.22138, 0.22412, 0.22614, 0.22592,
-0.22778, 0.2274, 0.22828, 0.22824, 0.22832, 0.232, 0.2341, 0.233, 0.23422, 
-//20
-0.14262, 0.1696, 0.21872, 0.27152, 0.3174, 0.34354, 0.35016, 0.34424, 0.33678, 0.32966, 0.32562, 0.3216, 0.31794, 0.31468, 0.30916, 0.30648, 0.3073, 0.30978,
-0.30976, 0.30704, 0.29636, 0.28458, 0.27612, 0.2699, 0.26976, 0.27284, 0.27256, 0.26666, 0.25726, 0.24754, 0.24094, 0.24246, 0.25088, 0.26778, 0.29784, 0.32888,
-0.35026, 0.37186, 0.399, 0.44548, 0.50824, 0.56992, 0.62334, 0.66208, 0.68708, 0.70492, 0.71452, 0.72196, 0.72418, 0.72688, 0.7296, 0.7285, 0.72704, 0.72852,
-0.72858, 0.72846, 0.72924, 0.7287, 0.7273, 0.72786, 0.72586, 0.72666, 0.72494, 0.72852, 0.72672, 0.72816, 0.7287, 0.7264, 0.72586, 0.72958, 0.72872, 0.72952,
-0.73056, 0.72898, 0.72986, 0.72926, 0.72846, 0.72856, 0.72646, 0.72858, 0.72886, 
-//21
-0.1103, 0.1369, 0.17094, 0.20348, 0.22424, 0.23036, 0.22988, 0.22512, 0.21828, 0.21172, 0.2082, 0.20708, 0.20592, 0.20264, 0.1997, 0.19862, 0.19832, 0.19996,
-0.20092, 0.1969, 0.18972, 0.1796, 0.17184, 0.16634, 0.16676, 0.16846, 0.16698, 0.16188, 0.15518, 0.1479, 0.14524, 0.14678, 0.15362, 0.1658, 0.18206, 0.19598,
-0.20192, 0.21336, 0.2357, 0.27658, 0.33288, 0.38948, 0.43446, 0.46476, 0.4827, 0.49376, 0.49998, 0.5037, 0.5054, 0.50646, 0.50652, 0.50452, 0.50438, 0.50318,
-0.50324, 0.50316, 0.50444, 0.50272, 0.50242, 0.50318, 0.49958, 0.4979, 0.49892, 0.49944, 0.49886, 0.49728, 0.49748, 0.49652, 0.49678, 0.4984, 0.49842, 0.49706,
-0.49868, 0.49534, 0.49564, 0.4929, 0.495, 0.49354, 0.49416, 0.49038, 0.4938, 
-//22
-0.115, 0.12988, 0.14622, 0.15756, 0.162, 0.16118, 0.15972, 0.1564, 0.1525, 0.15012, 0.14674, 0.14452, 0.14166, 0.13978, 0.13862, 0.13708, 0.13836, 0.13818,
-0.1384, 0.13358, 0.12802, 0.1224, 0.11842, 0.11606, 0.1167, 0.1171, 0.11544, 0.11216, 0.10578, 0.1039, 0.10436, 0.10726, 0.11112, 0.11494, 0.1226, 0.12784,
-0.12344, 0.12778, 0.13684, 0.15866, 0.19612, 0.24404, 0.29342, 0.33746, 0.37, 0.3916, 0.40536, 0.41248, 0.41734, 0.41932, 0.42092, 0.41958, 0.4184, 0.4189,
-0.42012, 0.41858, 0.41658, 0.4162, 0.41536, 0.41404, 0.40984, 0.4087, 0.409, 0.40992, 0.41032, 0.40938, 0.40768, 0.4087, 0.40658, 0.40594, 0.40616, 0.40688,
-0.40716, 0.40542, 0.40562, 0.40512, 0.40534, 0.40186, 0.40394, 0.40434, 0.40412, 
-//23
-0.08596, 0.09054, 0.09562, 0.09814, 0.09778, 0.09642, 0.09458, 0.09184, 0.09116, 0.08992, 0.08976, 0.08894, 0.08864, 0.088, 0.08648, 0.08424, 0.08394, 0.08242,
-0.08092, 0.07876, 0.0767, 0.07302, 0.07062, 0.06994, 0.06844, 0.06818, 0.06756, 0.0669, 0.066, 0.06598, 0.06616, 0.06696, 0.06746, 0.06854, 0.0707, 0.07134,
-0.06986, 0.07506, 0.07788, 0.08336, 0.09664, 0.119, 0.14982, 0.18822, 0.22746, 0.26124, 0.28772, 0.30638, 0.31922, 0.32656, 0.33162, 0.33452, 0.33606, 0.33662,
-0.33714, 0.33626, 0.33762, 0.33662, 0.3371, 0.33708, 0.33598, 0.33438, 0.3368, 0.337, 0.33754, 0.33732, 0.33632, 0.33448, 0.33644, 0.33644, 0.33746, 0.33886,
-0.34026, 0.33876, 0.33882, 0.33728, 0.33784, 0.33822, 0.34032, 0.33838, 0.33962, 
-//24
-0.12396, 0.15002, 0.19632, 0.24822, 0.2907, 0.31092, 0.31366, 0.30762, 0.29932, 0.29268, 0.28896, 0.28728, 0.28498, 0.28136, 0.27716, 0.2754, 0.27528, 0.27674,
-0.27798, 0.27446, 0.2648, 0.2518, 0.2411, 0.23432, 0.23412, 0.23634, 0.23572, 0.22882, 0.2199, 0.21096, 0.2072, 0.20766, 0.2158, 0.23234, 0.25558, 0.27578,
-0.28622, 0.30192, 0.3305, 0.3837, 0.45448, 0.53096, 0.59934, 0.65758, 0.7, 0.7334, 0.75576, 0.7699, 0.77802, 0.78668, 0.79122, 0.79152, 0.793, 0.79558, 0.79552,
-0.79606, 0.79916, 0.79872, 0.79806, 0.80242, 0.80332, 0.8016, 0.80244, 0.80622, 0.8079, 0.80598, 0.8083, 0.81126, 0.81054, 0.81176, 0.81214, 0.81178, 0.81502,
-0.80978, 0.81356, 0.81316, 0.81546, 0.81376, 0.81752, 0.81358, 0.81552, 
-//25
-0.12748, 0.1508, 0.18136, 0.20932, 0.22506, 0.228, 0.226, 0.22022, 0.21396, 0.2074, 0.20564, 0.20374, 0.2016, 0.19876, 0.19632, 0.19316, 0.1929, 0.1928,
-0.19256, 0.1894, 0.18424, 0.17496, 0.16806, 0.16518, 0.16394, 0.16296, 0.162, 0.15744, 0.15374, 0.14956, 0.14728, 0.1483, 0.15122, 0.15928, 0.17148, 0.18314,
-0.18768, 0.19856, 0.2111, 0.2438, 0.29976, 0.37082, 0.44402, 0.511, 0.5641, 0.60054, 0.6283, 0.64072, 0.64696, 0.65426, 0.65902, 0.66092, 0.6615, 0.66306,
-0.66388, 0.6638, 0.6642, 0.66398, 0.66346, 0.66422, 0.66346, 0.66274, 0.66112, 0.66486, 0.66482, 0.6646, 0.6629, 0.66202, 0.66124, 0.66512, 0.66636, 0.664,
-0.66682, 0.66318, 0.6634, 0.66458, 0.66586, 0.66754, 0.66512, 0.66276, 0.6664, 
-//26
-0.1066, 0.11902, 0.13394, 0.14388, 0.1467, 0.14686, 0.14424, 0.13892, 0.13608, 0.13258, 0.13194, 0.13262, 0.1313, 0.12978, 0.12778, 0.12528, 0.1243, 0.12338,
-0.12274, 0.11996, 0.11692, 0.11174, 0.107, 0.10462, 0.10376, 0.10154, 0.09948, 0.0974, 0.09594, 0.09374, 0.09324, 0.09378, 0.0967, 0.09922, 0.10226, 0.10344,
-0.1009, 0.10548, 0.10898, 0.12314, 0.15328, 0.2002, 0.25934, 0.3211, 0.37706, 0.42566, 0.46064, 0.48602, 0.50244, 0.51592, 0.52406, 0.52728, 0.5304, 0.5312,
-0.53154, 0.531, 0.5318, 0.53138, 0.53346, 0.53376, 0.53358, 0.53248, 0.5327, 0.53556, 0.53504, 0.53236, 0.5347, 0.53216, 0.53358, 0.53514, 0.53508, 0.53404,
-0.53716, 0.53518, 0.53544, 0.5313, 0.53354, 0.53216, 0.53566, 0.53312, 0.53272, 
-//27
-0.08398, 0.0862, 0.09174, 0.09164, 0.09162, 0.08946, 0.08754, 0.08646, 0.08704, 0.08496, 0.08516, 0.08536, 0.08474, 0.08202, 0.08124, 0.07992, 0.08072, 0.07782,
-0.07544, 0.07136, 0.06916, 0.06564, 0.06342, 0.06174, 0.06106, 0.06088, 0.06108, 0.05932, 0.0588, 0.05898, 0.05928, 0.05806, 0.05936, 0.06204, 0.06316, 0.06366,
-0.05962, 0.06744, 0.07292, 0.07762, 0.088, 0.10502, 0.1346, 0.17326, 0.22194, 0.27552, 0.32582, 0.37144, 0.40946, 0.4404, 0.463, 0.47694, 0.48752, 0.49464,
-0.50082, 0.50504, 0.50984, 0.51378, 0.51424, 0.51872, 0.52148, 0.52008, 0.52154, 0.52634, 0.52916, 0.53216, 0.53466, 0.53756, 0.53884, 0.54164, 0.54136,
-0.54328, 0.54672, 0.55068, 0.54844, 0.55006, 0.55512, 0.5551, 0.55656, 0.55802, 0.5627, 
-//28
-0.11882, 0.1402, 0.16896, 0.19388, 0.2076, 0.21024, 0.20862, 0.20296, 0.19686, 0.19164, 0.18928, 0.18874, 0.18754, 0.18476, 0.18138, 0.17966, 0.17796, 0.17822,
-0.17746, 0.17638, 0.17014, 0.1627, 0.15696, 0.1512, 0.15026, 0.14898, 0.14674, 0.1425, 0.13826, 0.13432, 0.13264, 0.1335, 0.13636, 0.14288, 0.1517, 0.15872,
-0.15712, 0.16858, 0.18152, 0.21164, 0.26574, 0.33658, 0.4145, 0.4893, 0.55794, 0.61342, 0.65718, 0.69398, 0.71626, 0.73206, 0.74412, 0.75148, 0.75538, 0.75908,
-0.7649, 0.76514, 0.76708, 0.76582, 0.7651, 0.76646, 0.7678, 0.77032, 0.7697, 0.775, 0.7751, 0.7749, 0.77642, 0.77928, 0.77774, 0.78054, 0.78294, 0.78432,
-0.78594, 0.7833, 0.78606, 0.7838, 0.78806, 0.789, 0.7926, 0.78484, 0.79314, 
-//29
-0.10426, 0.1141, 0.12604, 0.13274, 0.13644, 0.13352, 0.13096, 0.12768, 0.12552, 0.12216, 0.12078, 0.12112, 0.11868, 0.11488, 0.1173, 0.11524, 0.11506, 0.11278,
-0.1125, 0.10738, 0.10262, 0.09756, 0.09408, 0.09162, 0.09096, 0.08894, 0.0869, 0.08326, 0.08088, 0.07838, 0.07886, 0.08084, 0.08258, 0.0855, 0.08706, 0.08602,
-0.08148, 0.08682, 0.09246, 0.1122, 0.1471, 0.20046, 0.26564, 0.33842, 0.4099, 0.47682, 0.53308, 0.57954, 0.6159, 0.64382, 0.66072, 0.67172, 0.67982, 0.68658,
-0.68898, 0.69128, 0.69476, 0.69548, 0.69892, 0.69976, 0.7009, 0.70126, 0.70264, 0.7091, 0.71184, 0.7121, 0.7144, 0.71608, 0.71812, 0.71956, 0.72134, 0.72254,
-0.72692, 0.72428, 0.72956, 0.7279, 0.73068, 0.73122, 0.73198, 0.72868, 0.73222, 
-//30
-0.07016, 0.0753, 0.0804, 0.08264, 0.07984, 0.07814, 0.07716, 0.0752, 0.07444, 0.07462, 0.07606, 0.07524, 0.07666, 0.07442, 0.073, 0.07104, 0.06928, 0.06638,
-0.064, 0.06252, 0.05982, 0.05482, 0.05292, 0.05106, 0.04904, 0.04728, 0.04734, 0.04694, 0.04666, 0.04834, 0.04746, 0.04614, 0.04546, 0.04412, 0.04532, 0.04576,
-0.04446, 0.05078, 0.05404, 0.0604, 0.07058, 0.08694, 0.11502, 0.15308, 0.20216, 0.25828, 0.31358, 0.3687, 0.42008, 0.4658, 0.50268, 0.53156, 0.55412, 0.57176,
-0.5844, 0.59584, 0.60434, 0.60928, 0.61414, 0.62094, 0.6235, 0.62808, 0.6348, 0.64484, 0.65102, 0.65854, 0.66382, 0.67128, 0.67614, 0.68168, 0.68704, 0.6905,
-0.6983, 0.70082, 0.70808, 0.71046, 0.71428, 0.71794, 0.72096, 0.72424, 0.72876, 
-//31
-0.14254, 0.17566, 0.23662, 0.32538, 0.43772, 0.5474, 0.62352, 0.66128, 0.6722, 0.6766, 0.67772, 0.67788, 0.67726, 0.6749, 0.6746, 0.67312, 0.6749, 0.67576,
-0.67528, 0.67414, 0.67066, 0.66714, 0.662, 0.66086, 0.6628, 0.66236, 0.66228, 0.66094, 0.65694, 0.65224, 0.64884, 0.64836, 0.65052, 0.65896, 0.67258, 0.68458,
-0.69764, 0.70552, 0.70862, 0.71098, 0.71088, 0.71188, 0.71314, 0.71276, 0.7137, 0.71266, 0.71222, 0.71386, 0.71224, 0.71314, 0.7137, 0.7148, 0.71546, 0.716,
-0.71588, 0.71424, 0.71524, 0.71442, 0.71536, 0.7181, 0.71626, 0.71616, 0.71508, 0.71866, 0.7177, 0.71934, 0.7181, 0.7197, 0.71926, 0.72204, 0.72206, 0.7242,
-0.72586, 0.72154, 0.72342, 0.7225, 0.72452, 0.72272, 0.72224, 0.71928, 0.7217, 
-//32
-0.12936, 0.15716, 0.2087, 0.28038, 0.36534, 0.43796, 0.48058, 0.4939, 0.4934, 0.48824, 0.4875, 0.48728, 0.48568, 0.48318, 0.48174, 0.4796, 0.48142, 0.48246,
-0.48316, 0.4821, 0.4778, 0.4713, 0.46672, 0.4645, 0.46686, 0.47036, 0.47292, 0.47064, 0.46588, 0.45948, 0.45586, 0.45574, 0.46282, 0.4752, 0.4915, 0.5065,
-0.51522, 0.51896, 0.52044, 0.52186, 0.52114, 0.52048, 0.52136, 0.52012, 0.52034, 0.51906, 0.5186, 0.51722, 0.51668, 0.5154, 0.51388, 0.51298, 0.51168, 0.51042,
-0.51042, 0.50858, 0.50878, 0.50876, 0.50852, 0.50994, 0.507, 0.50612, 0.5048, 0.50534, 0.50454, 0.50478, 0.50304, 0.50218, 0.50102, 0.5031, 0.5029, 0.50384,
-0.50472, 0.50014, 0.49714, 0.49856, 0.49928, 0.49702, 0.49764, 0.49376, 0.49574, 
-//33
-0.12736, 0.15338, 0.19948, 0.25906, 0.31472, 0.35118, 0.36708, 0.37106, 0.3681, 0.36448, 0.36344, 0.36104, 0.35994, 0.35732, 0.3558, 0.35426, 0.35478, 0.3559,
-0.35646, 0.35586, 0.3528, 0.3476, 0.34332, 0.34206, 0.34328, 0.34526, 0.34826, 0.34582, 0.34124, 0.33434, 0.33092, 0.33076, 0.33554, 0.3468, 0.36194, 0.37556,
-0.386, 0.39054, 0.3925, 0.39178, 0.39186, 0.39072, 0.39196, 0.3905, 0.39082, 0.38928, 0.39048, 0.38886, 0.38774, 0.38696, 0.38702, 0.38668, 0.3841, 0.38472,
-0.3833, 0.38112, 0.38146, 0.37978, 0.3793, 0.37974, 0.37886, 0.37794, 0.3761, 0.37698, 0.37636, 0.37694, 0.37522, 0.37484, 0.37434, 0.37502, 0.37562, 0.37464,
-0.37554, 0.37496, 0.3749, 0.37402, 0.3748, 0.37342, 0.37276, 0.37008, 0.37092, 
-//34
-0.1224, 0.1446, 0.17802, 0.21364, 0.2433, 0.25806, 0.26258, 0.2616, 0.25734, 0.25596, 0.25246, 0.25178, 0.25106, 0.24876, 0.24696, 0.24558, 0.24564, 0.24684,
-0.24612, 0.24564, 0.2421, 0.23858, 0.2337, 0.23166, 0.2327, 0.23604, 0.23742, 0.2361, 0.23322, 0.22928, 0.2271, 0.2273, 0.23078, 0.23974, 0.2523, 0.26336,
-0.27114, 0.27712, 0.27736, 0.27788, 0.27758, 0.27688, 0.27652, 0.27504, 0.27546, 0.27392, 0.27394, 0.27292, 0.2723, 0.2716, 0.27098, 0.27012, 0.26842, 0.26864,
-0.2664, 0.26458, 0.26438, 0.26272, 0.26302, 0.26242, 0.262, 0.26072, 0.26008, 0.26104, 0.26166, 0.26136, 0.26014, 0.26048, 0.25828, 0.26004, 0.25818, 0.25874,
-0.25846, 0.25668, 0.25816, 0.257, 0.25704, 0.25748, 0.25592, 0.25602, 0.25678, 
-//35
-0.09808, 0.11276, 0.13322, 0.15282, 0.16464, 0.17112, 0.1722, 0.17038, 0.1687, 0.1666, 0.16592, 0.16526, 0.16438, 0.16344, 0.16156, 0.16078, 0.16096, 0.16102,
-0.1617, 0.16048, 0.1574, 0.15334, 0.1498, 0.14996, 0.14962, 0.15238, 0.15358, 0.15222, 0.14898, 0.14494, 0.14306, 0.14346, 0.1481, 0.15622, 0.16706, 0.17566,
-0.17994, 0.18546, 0.18626, 0.18646, 0.185, 0.1852, 0.1845, 0.1839, 0.18186, 0.18122, 0.18046, 0.18014, 0.17986, 0.1799, 0.17952, 0.17896, 0.17828, 0.17862,
-0.17844, 0.17734, 0.17756, 0.17464, 0.17488, 0.17472, 0.17426, 0.17448, 0.17398, 0.17416, 0.17284, 0.17292, 0.17354, 0.17376, 0.17406, 0.17522, 0.17498,
-0.17492, 0.17614, 0.17582, 0.1764, 0.17514, 0.17494, 0.17522, 0.17568, 0.17416, 0.17488, 
-//36
-0.09012, 0.09722, 0.10738, 0.1131, 0.1158, 0.1159, 0.11474, 0.11306, 0.11064, 0.10856, 0.10766, 0.10708, 0.10682, 0.10502, 0.10458, 0.10412, 0.10428, 0.10444,
-0.1045, 0.10378, 0.10144, 0.0998, 0.09832, 0.09576, 0.09654, 0.09662, 0.09664, 0.095, 0.0945, 0.09236, 0.09284, 0.09436, 0.09752, 0.10208, 0.1092, 0.11438,
-0.11746, 0.12054, 0.12136, 0.12248, 0.12306, 0.12364, 0.12398, 0.12352, 0.12306, 0.12364, 0.12318, 0.12302, 0.12266, 0.1222, 0.12162, 0.12066, 0.12012, 0.11964,
-0.11846, 0.11706, 0.11666, 0.11452, 0.11476, 0.11598, 0.1157, 0.1158, 0.11466, 0.11434, 0.11538, 0.11544, 0.11608, 0.11636, 0.11672, 0.1167, 0.11794, 0.11832,
-0.11962, 0.11874, 0.11842, 0.11814, 0.11926, 0.1176, 0.11688, 0.1164, 0.117, 
-//37
-0.05614, 0.05594, 0.05546, 0.0579, 0.05856, 0.05878, 0.05938, 0.05924, 0.05954, 0.0588, 0.05942, 0.05902, 0.0592, 0.05928, 0.05856, 0.0563, 0.0568, 0.0559,
-0.05564, 0.05512, 0.05528, 0.05404, 0.05458, 0.05444, 0.05456, 0.05422, 0.05396, 0.0535, 0.05336, 0.05416, 0.05336, 0.0528, 0.0527, 0.05288, 0.05358, 0.05366,
-0.05462, 0.05854, 0.06124, 0.06502, 0.06798, 0.07046, 0.07242, 0.07232, 0.07138, 0.07084, 0.07022, 0.07054, 0.0703, 0.06984, 0.06994, 0.06958, 0.0694, 0.06888,
-0.06898, 0.0691, 0.0691, 0.06754, 0.06732, 0.0689, 0.06756, 0.06744, 0.06816, 0.06764, 0.06844, 0.069, 0.0694, 0.07, 0.07112, 0.07076, 0.07186, 0.07184, 0.0735,
-0.07346, 0.07362, 0.07324, 0.07434, 0.0743, 0.07424, 0.07288, 0.07258, 
-//38
-0.04892, 0.04948, 0.0486, 0.04862, 0.0475, 0.04768, 0.04664, 0.0476, 0.04764, 0.04652, 0.04652, 0.04708, 0.04728, 0.04568, 0.04602, 0.04626, 0.04516, 0.04488,
-0.04542, 0.04426, 0.04338, 0.0425, 0.0416, 0.04078, 0.04242, 0.04282, 0.0426, 0.04286, 0.0432, 0.04352, 0.04354, 0.04272, 0.0427, 0.04302, 0.04416, 0.04394,
-0.04532, 0.04786, 0.05054, 0.05264, 0.05444, 0.05536, 0.05714, 0.0577, 0.05808, 0.05828, 0.05884, 0.0591, 0.05854, 0.0586, 0.05874, 0.05882, 0.05834, 0.05746,
-0.05732, 0.05708, 0.05762, 0.05466, 0.05608, 0.05792, 0.05844, 0.05844, 0.05862, 0.06004, 0.05976, 0.06032, 0.06086, 0.0615, 0.06316, 0.06388, 0.0649, 0.06464,
-0.06714, 0.0678, 0.06802, 0.06872, 0.07012, 0.06988, 0.0701, 0.07052, 0.0707, 
-//39
-0.14092, 0.17064, 0.22694, 0.30984, 0.4186, 0.53252, 0.61284, 0.65428, 0.66398, 0.6634, 0.66092, 0.65952, 0.65818, 0.65678, 0.65388, 0.65116, 0.65166, 0.65338,
-0.65394, 0.65352, 0.64814, 0.63934, 0.63212, 0.62908, 0.6307, 0.63278, 0.6357, 0.63212, 0.62496, 0.61656, 0.61076, 0.6094, 0.61796, 0.63972, 0.67044, 0.701,
-0.72542, 0.74102, 0.74802, 0.75326, 0.75408, 0.75408, 0.75708, 0.75674, 0.75712, 0.75628, 0.75484, 0.75556, 0.7549, 0.75508, 0.75482, 0.75556, 0.75332, 0.75514,
-0.75386, 0.75278, 0.75424, 0.7511, 0.7501, 0.7505, 0.74938, 0.75086, 0.75094, 0.75194, 0.75334, 0.7541, 0.75382, 0.75332, 0.75214, 0.75182, 0.75278, 0.75606,
-0.75716, 0.75276, 0.75388, 0.7539, 0.7518, 0.7555, 0.75454, 0.74938, 0.7514, 
-//40
-0.13118, 0.16062, 0.21032, 0.28376, 0.36926, 0.44068, 0.4797, 0.48996, 0.4904, 0.48544, 0.48478, 0.48116, 0.47976, 0.47666, 0.47448, 0.47208, 0.4721, 0.47314,
-0.4741, 0.47298, 0.46724, 0.46016, 0.4525, 0.4479, 0.45044, 0.45422, 0.4571, 0.45426, 0.44676, 0.43814, 0.43054, 0.4298, 0.43734, 0.45592, 0.484, 0.51172,
-0.53112, 0.5451, 0.55136, 0.55232, 0.55256, 0.5513, 0.55386, 0.55296, 0.55258, 0.54956, 0.54978, 0.54912, 0.54708, 0.54708, 0.5464, 0.54458, 0.54386, 0.54398,
-0.5432, 0.54272, 0.54292, 0.53984, 0.54048, 0.54182, 0.54066, 0.54038, 0.53874, 0.53882, 0.53804, 0.53712, 0.53552, 0.53456, 0.53494, 0.53734, 0.53574, 0.533,
-0.5341, 0.5316, 0.53364, 0.5317, 0.53174, 0.53056, 0.53014, 0.5282, 0.52862, 
-//41
-0.135, 0.16156, 0.2044, 0.25962, 0.31164, 0.34494, 0.35672, 0.35726, 0.35448, 0.34746, 0.34692, 0.34396, 0.34236, 0.33878, 0.3364, 0.33656, 0.33578, 0.33648,
-0.3379, 0.33634, 0.3324, 0.3247, 0.31638, 0.31452, 0.31546, 0.3196, 0.32152, 0.32078, 0.31524, 0.30872, 0.3038, 0.30228, 0.30804, 0.3237, 0.34714, 0.37212,
-0.39046, 0.40474, 0.40738, 0.40986, 0.40878, 0.41012, 0.40958, 0.40796, 0.407, 0.4071, 0.40662, 0.4055, 0.40426, 0.4041, 0.40494, 0.40308, 0.40074, 0.39962,
-0.39712, 0.39536, 0.39428, 0.39176, 0.39058, 0.39194, 0.38998, 0.38904, 0.39168, 0.39092, 0.3883, 0.38694, 0.38618, 0.38626, 0.38704, 0.38606, 0.38658, 0.38602,
-0.38616, 0.38446, 0.38386, 0.38364, 0.38338, 0.38328, 0.38214, 0.38082, 0.38098, 
-//42
-0.11984, 0.14294, 0.1782, 0.21698, 0.24666, 0.26176, 0.26472, 0.26358, 0.2587, 0.25516, 0.25238, 0.25004, 0.24746, 0.24706, 0.24518, 0.24362, 0.2443, 0.2445,
-0.245, 0.24436, 0.24038, 0.23388, 0.22868, 0.22562, 0.22624, 0.22784, 0.22944, 0.22536, 0.22072, 0.21588, 0.21246, 0.21228, 0.21728, 0.22986, 0.24958, 0.26948,
-0.28486, 0.29242, 0.29708, 0.2991, 0.29938, 0.29996, 0.30024, 0.29848, 0.29876, 0.29792, 0.29868, 0.2975, 0.29606, 0.29462, 0.29342, 0.29318, 0.29054, 0.29106,
-0.28978, 0.28882, 0.28886, 0.28616, 0.2865, 0.28708, 0.28674, 0.28666, 0.28236, 0.28364, 0.28404, 0.28406, 0.28426, 0.2831, 0.28256, 0.2844, 0.28338, 0.28244,
-0.28416, 0.28198, 0.2831, 0.28118, 0.28088, 0.27888, 0.2798, 0.27712, 0.27692, 
-//43
-0.10554, 0.11998, 0.13932, 0.15862, 0.16884, 0.17514, 0.17502, 0.17242, 0.169, 0.16646, 0.1649, 0.1625, 0.16248, 0.15896, 0.15766, 0.15532, 0.15608, 0.15628,
-0.15756, 0.15708, 0.15378, 0.14884, 0.14564, 0.1442, 0.1449, 0.14616, 0.14638, 0.14426, 0.14068, 0.13724, 0.13522, 0.13494, 0.13934, 0.14848, 0.16124, 0.17458,
-0.18544, 0.1943, 0.19744, 0.19938, 0.20068, 0.20178, 0.20076, 0.20068, 0.20144, 0.19934, 0.19822, 0.19806, 0.19648, 0.19614, 0.19652, 0.1946, 0.19552, 0.19496,
-0.1935, 0.19252, 0.19228, 0.191, 0.19118, 0.19162, 0.19002, 0.18866, 0.18976, 0.1906, 0.18894, 0.1881, 0.18786, 0.18736, 0.18726, 0.18898, 0.189, 0.1884,
-0.18952, 0.18888, 0.18884, 0.18868, 0.18754, 0.18872, 0.18682, 0.18482, 0.18608, 
-//44
-0.08942, 0.09586, 0.10472, 0.11026, 0.11282, 0.11288, 0.111, 0.10888, 0.10746, 0.10668, 0.10478, 0.10432, 0.1036, 0.10276, 0.10204, 0.1011, 0.10054, 0.1006,
-0.1004, 0.0989, 0.09702, 0.09368, 0.09264, 0.09128, 0.09214, 0.09166, 0.09176, 0.0905, 0.0887, 0.08748, 0.08762, 0.08998, 0.09184, 0.09706, 0.10364, 0.1088,
-0.11154, 0.11602, 0.11836, 0.1249, 0.13282, 0.13802, 0.14184, 0.144, 0.14496, 0.1449, 0.14446, 0.14408, 0.1436, 0.1425, 0.143, 0.14132, 0.14116, 0.1399,
-0.14026, 0.13858, 0.13788, 0.13722, 0.13802, 0.13932, 0.1379, 0.13812, 0.13786, 0.13864, 0.13734, 0.13804, 0.13752, 0.13814, 0.13874, 0.13802, 0.13942, 0.13928,
-0.14056, 0.13988, 0.14032, 0.14116, 0.14022, 0.14084, 0.14, 0.1394, 0.1381, 
-//45
-0.05764, 0.0577, 0.05804, 0.05982, 0.0601, 0.05932, 0.05848, 0.05884, 0.05836, 0.05732, 0.0568, 0.05712, 0.05734, 0.05596, 0.05626, 0.0562, 0.05592, 0.05628,
-0.05556, 0.05506, 0.05482, 0.05352, 0.05308, 0.05212, 0.05186, 0.051, 0.05042, 0.05054, 0.05006, 0.05, 0.05042, 0.05152, 0.05212, 0.05276, 0.05344, 0.05454,
-0.056, 0.05854, 0.06252, 0.06842, 0.07576, 0.08268, 0.08792, 0.0899, 0.0899, 0.08954, 0.08918, 0.0883, 0.08814, 0.08726, 0.08718, 0.08696, 0.08682, 0.08586,
-0.0861, 0.08554, 0.08518, 0.08444, 0.08416, 0.08482, 0.0843, 0.0832, 0.08394, 0.08364, 0.08422, 0.08336, 0.08388, 0.08454, 0.08558, 0.08532, 0.08578, 0.0867,
-0.08672, 0.08702, 0.0875, 0.08708, 0.08848, 0.08842, 0.08768, 0.0878, 0.08688, 
-//46
-0.04598, 0.04478, 0.04614, 0.04484, 0.04646, 0.04756, 0.04694, 0.04664, 0.0472, 0.04618, 0.0454, 0.04456, 0.04442, 0.04326, 0.04338, 0.04212, 0.04206, 0.04078,
-0.0408, 0.0408, 0.042, 0.04134, 0.03972, 0.0406, 0.04018, 0.04014, 0.0397, 0.03882, 0.0399, 0.03834, 0.03872, 0.03934, 0.03808, 0.03932, 0.03978, 0.04138,
-0.04298, 0.04506, 0.047, 0.04928, 0.05214, 0.05356, 0.05586, 0.0576, 0.05948, 0.06024, 0.06486, 0.06606, 0.0701, 0.07036, 0.07244, 0.07272, 0.07192, 0.07326,
-0.07404, 0.0737, 0.07392, 0.07304, 0.07498, 0.07696, 0.07804, 0.07862, 0.07924, 0.08058, 0.08224, 0.08248, 0.08324, 0.08436, 0.08502, 0.08522, 0.08632, 0.08678,
-0.0894, 0.0897, 0.09184, 0.09254, 0.09348, 0.09534, 0.09528, 0.09604, 0.09766, 
-//47
-0.14022, 0.1701, 0.22314, 0.29578, 0.37696, 0.4409, 0.47114, 0.47634, 0.4713, 0.46446, 0.45978, 0.45792, 0.4549, 0.4497, 0.44534, 0.44286, 0.44298, 0.4453,
-0.44754, 0.4456, 0.43844, 0.42646, 0.41624, 0.41042, 0.41162, 0.41612, 0.41914, 0.4139, 0.4039, 0.39248, 0.38536, 0.38546, 0.3947, 0.4201, 0.46212, 0.51404,
-0.56188, 0.59682, 0.61766, 0.63188, 0.64122, 0.64602, 0.65122, 0.65346, 0.65588, 0.65446, 0.65358, 0.65428, 0.65406, 0.65242, 0.65058, 0.65124, 0.65028,
-0.64872, 0.64934, 0.64894, 0.6492, 0.64778, 0.6466, 0.64632, 0.6456, 0.64526, 0.64358, 0.6448, 0.6451, 0.6448, 0.64402, 0.64292, 0.64336, 0.64298, 0.64222,
-0.64336, 0.64582, 0.64148, 0.64198, 0.64064, 0.64202, 0.63936, 0.64118, 0.641, 0.64006, 
-//48
-0.12, 0.1465, 0.1894, 0.24566, 0.29974, 0.33356, 0.34582, 0.34358, 0.3376, 0.33096, 0.32766, 0.3252, 0.32368, 0.32016, 0.3173, 0.31494, 0.31524, 0.3182,
-0.32042, 0.31702, 0.31262, 0.30052, 0.29, 0.28582, 0.28642, 0.29078, 0.29246, 0.28716, 0.27798, 0.26898, 0.26334, 0.26346, 0.27424, 0.29688, 0.3311, 0.36792,
-0.39728, 0.41904, 0.436, 0.45078, 0.46356, 0.47268, 0.47688, 0.47778, 0.47938, 0.47998, 0.47848, 0.47776, 0.47622, 0.4751, 0.47632, 0.47502, 0.4735, 0.47406,
-0.47212, 0.47104, 0.47096, 0.47038, 0.4686, 0.46868, 0.46742, 0.46624, 0.46584, 0.46438, 0.46394, 0.46338, 0.46406, 0.46278, 0.4625, 0.4635, 0.46278, 0.4628,
-0.46284, 0.4615, 0.46048, 0.45996, 0.4588, 0.45834, 0.45902, 0.45514, 0.4547, 
-//49
-0.13038, 0.15206, 0.18318, 0.21592, 0.23912, 0.24836, 0.24918, 0.2433, 0.23802, 0.23222, 0.22856, 0.22582, 0.22472, 0.2206, 0.21814, 0.21532, 0.21616, 0.21712,
-0.2186, 0.21694, 0.2122, 0.20464, 0.197, 0.19368, 0.1939, 0.19564, 0.1958, 0.19272, 0.18614, 0.18084, 0.17708, 0.1781, 0.18452, 0.19888, 0.2221, 0.25172,
-0.27614, 0.29354, 0.30616, 0.31916, 0.33124, 0.34006, 0.34626, 0.34868, 0.34924, 0.34796, 0.34774, 0.34692, 0.34652, 0.34538, 0.34478, 0.344, 0.34244, 0.34114,
-0.34016, 0.33924, 0.33836, 0.33624, 0.33526, 0.3355, 0.3335, 0.3328, 0.33252, 0.33124, 0.331, 0.3305, 0.33018, 0.32802, 0.32888, 0.33022, 0.32908, 0.32704,
-0.3284, 0.32606, 0.32762, 0.32668, 0.32418, 0.32348, 0.32462, 0.32264, 0.3226, 
-//50
-0.10824, 0.1229, 0.13848, 0.15162, 0.15794, 0.15816, 0.15636, 0.15314, 0.14906, 0.146, 0.1431, 0.1431, 0.14184, 0.13944, 0.13946, 0.13742, 0.1365, 0.13722,
-0.13736, 0.13584, 0.1319, 0.12686, 0.12228, 0.12148, 0.12118, 0.12262, 0.12278, 0.12086, 0.11784, 0.11416, 0.11364, 0.11474, 0.11906, 0.12806, 0.14128, 0.1563,
-0.16816, 0.17472, 0.1826, 0.19566, 0.21152, 0.22644, 0.23616, 0.24116, 0.24456, 0.24484, 0.24498, 0.24358, 0.2435, 0.2429, 0.24158, 0.24056, 0.2399, 0.24016,
-0.23846, 0.23804, 0.23748, 0.23702, 0.23668, 0.2366, 0.23656, 0.23556, 0.23452, 0.23422, 0.23442, 0.23378, 0.23336, 0.23386, 0.2321, 0.23318, 0.23348, 0.23326,
-0.23416, 0.23316, 0.23302, 0.23218, 0.23338, 0.23244, 0.23186, 0.23018, 0.23112, 
-//51
-0.08312, 0.0882, 0.09478, 0.10004, 0.09968, 0.09956, 0.09844, 0.09666, 0.0943, 0.09142, 0.09104, 0.08894, 0.08946, 0.08698, 0.08616, 0.08628, 0.08586, 0.0859,
-0.0855, 0.08474, 0.08256, 0.08154, 0.07966, 0.07928, 0.07916, 0.07854, 0.07848, 0.07622, 0.07344, 0.07338, 0.07358, 0.07572, 0.07918, 0.08412, 0.09004, 0.0952,
-0.09686, 0.0986, 0.10224, 0.11108, 0.1277, 0.14634, 0.1611, 0.17124, 0.1764, 0.17922, 0.1795, 0.1797, 0.17858, 0.17826, 0.17838, 0.17774, 0.17754, 0.1766,
-0.17588, 0.1758, 0.17624, 0.17488, 0.17448, 0.17556, 0.17398, 0.17328, 0.17282, 0.1731, 0.17174, 0.17246, 0.17208, 0.17228, 0.17314, 0.1729, 0.17426, 0.17388,
-0.17466, 0.17342, 0.1729, 0.17232, 0.1743, 0.17226, 0.17264, 0.17164, 0.17268, 
-//52
-0.05458, 0.0547, 0.05512, 0.05492, 0.05544, 0.05486, 0.05436, 0.05344, 0.05296, 0.0518, 0.0513, 0.05026, 0.05094, 0.05006, 0.05086, 0.04944, 0.04992, 0.04948,
-0.0492, 0.0482, 0.04816, 0.04826, 0.0469, 0.04578, 0.04588, 0.04466, 0.04462, 0.04476, 0.04446, 0.04484, 0.04526, 0.04592, 0.0457, 0.04636, 0.04772, 0.0495,
-0.05016, 0.05302, 0.05648, 0.06172, 0.06906, 0.07808, 0.09032, 0.1015, 0.11212, 0.11866, 0.12172, 0.12276, 0.1222, 0.12206, 0.12118, 0.11884, 0.11704, 0.11708,
-0.11552, 0.11454, 0.11428, 0.11208, 0.11242, 0.11296, 0.11256, 0.11242, 0.11224, 0.11304, 0.11228, 0.11208, 0.11046, 0.11242, 0.1126, 0.11262, 0.11304, 0.1147,
-0.11574, 0.11532, 0.1159, 0.1163, 0.1172, 0.11654, 0.11602, 0.11714, 0.11782, 
-//53
-0.13188, 0.15792, 0.1986, 0.25006, 0.2939, 0.31862, 0.32404, 0.31928, 0.31268, 0.3056, 0.30128, 0.2987, 0.29614, 0.29204, 0.28886, 0.28544, 0.28594, 0.28746,
-0.28858, 0.28664, 0.28002, 0.26844, 0.25872, 0.254, 0.2547, 0.25806, 0.26002, 0.25546, 0.2465, 0.23784, 0.23262, 0.2329, 0.24098, 0.26076, 0.2946, 0.33686,
-0.37416, 0.40172, 0.42594, 0.45424, 0.48582, 0.51378, 0.53414, 0.54534, 0.55314, 0.5558, 0.5576, 0.55898, 0.55754, 0.55714, 0.55614, 0.55546, 0.554, 0.5536,
-0.55366, 0.55144, 0.55162, 0.5515, 0.5511, 0.55122, 0.55076, 0.54992, 0.5476, 0.5482, 0.54772, 0.54736, 0.54702, 0.54512, 0.5459, 0.54842, 0.54606, 0.5447,
-0.54404, 0.54392, 0.5429, 0.5432, 0.54398, 0.54366, 0.5432, 0.54186, 0.5402, 
-//54
-0.12184, 0.14148, 0.17132, 0.20094, 0.22062, 0.22684, 0.22594, 0.22122, 0.21498, 0.20932, 0.20632, 0.20392, 0.20172, 0.19798, 0.19466, 0.19222, 0.19176,
-0.19292, 0.19424, 0.19292, 0.1876, 0.18002, 0.1737, 0.17062, 0.17158, 0.17256, 0.17368, 0.17006, 0.16378, 0.15748, 0.1552, 0.1563, 0.16214, 0.17532, 0.19764,
-0.22674, 0.25162, 0.26946, 0.28804, 0.3138, 0.34466, 0.36934, 0.3866, 0.39746, 0.40414, 0.40624, 0.4075, 0.40804, 0.40734, 0.4066, 0.40594, 0.40496, 0.40304,
-0.40238, 0.40044, 0.39936, 0.39942, 0.39622, 0.39706, 0.39804, 0.39622, 0.39438, 0.39406, 0.39418, 0.39344, 0.3918, 0.39274, 0.39228, 0.3908, 0.39242, 0.39186,
-0.39026, 0.3914, 0.38948, 0.38866, 0.386, 0.38652, 0.38768, 0.38816, 0.38666, 0.3884, 
-//55
-0.1002, 0.1101, 0.12472, 0.13686, 0.14136, 0.14166, 0.14044, 0.13718, 0.13324, 0.12816, 0.12782, 0.1269, 0.12606, 0.12254, 0.12186, 0.12068, 0.12006, 0.11924,
-0.1203, 0.11904, 0.11638, 0.1109, 0.10804, 0.10618, 0.10654, 0.1077, 0.1072, 0.10562, 0.1026, 0.09924, 0.09876, 0.10012, 0.1031, 0.11012, 0.12148, 0.13506,
-0.14596, 0.15732, 0.16832, 0.18954, 0.21842, 0.24762, 0.26962, 0.28402, 0.29348, 0.29766, 0.2986, 0.29882, 0.29882, 0.29972, 0.29998, 0.29998, 0.29964, 0.29792,
-0.29848, 0.29726, 0.29694, 0.29522, 0.29462, 0.29458, 0.29456, 0.29226, 0.29204, 0.2919, 0.29372, 0.29194, 0.29194, 0.29122, 0.29136, 0.29356, 0.2938, 0.29376,
-0.29274, 0.29332, 0.29516, 0.29342, 0.2924, 0.28964, 0.29154, 0.2919, 0.29092, 
-//56
-0.07732, 0.0808, 0.08528, 0.08718, 0.08698, 0.08636, 0.08476, 0.08406, 0.08256, 0.08078, 0.08098, 0.08094, 0.08018, 0.07856, 0.07762, 0.07728, 0.0767, 0.07544,
-0.07482, 0.07388, 0.07174, 0.07052, 0.0695, 0.0698, 0.07126, 0.07076, 0.07026, 0.06896, 0.06782, 0.068, 0.06966, 0.07204, 0.07576, 0.07688, 0.08034, 0.08234,
-0.08356, 0.0853, 0.08816, 0.09634, 0.11298, 0.13722, 0.16498, 0.19008, 0.20806, 0.21914, 0.22692, 0.23082, 0.23142, 0.2332, 0.23264, 0.23186, 0.23126, 0.23118,
-0.22968, 0.22862, 0.2274, 0.2245, 0.22418, 0.22472, 0.22406, 0.22306, 0.22256, 0.22386, 0.22284, 0.22248, 0.22242, 0.22304, 0.22268, 0.22352, 0.22302, 0.22262,
-0.22352, 0.22224, 0.22148, 0.22062, 0.22004, 0.22028, 0.22254, 0.22176, 0.22082, 
-//57
-0.05268, 0.05032, 0.0488, 0.04904, 0.04742, 0.04666, 0.04602, 0.04546, 0.04466, 0.044, 0.04406, 0.04474, 0.04502, 0.04438, 0.045, 0.04348, 0.04424, 0.04314,
-0.04286, 0.04244, 0.04224, 0.04106, 0.03926, 0.03832, 0.03902, 0.03862, 0.03938, 0.03874, 0.03972, 0.0387, 0.04016, 0.04062, 0.04012, 0.04132, 0.04142, 0.04248,
-0.04354, 0.0449, 0.04684, 0.05114, 0.05606, 0.06466, 0.07416, 0.08728, 0.1044, 0.12318, 0.14112, 0.15524, 0.16908, 0.17914, 0.18598, 0.1904, 0.19368, 0.19676,
-0.19816, 0.19946, 0.2022, 0.20206, 0.2048, 0.20868, 0.20972, 0.21076, 0.21136, 0.21296, 0.21478, 0.21748, 0.21944, 0.222, 0.2237, 0.22582, 0.22806, 0.2311,
-0.2325, 0.23288, 0.23514, 0.23844, 0.23902, 0.23894, 0.24242, 0.24194, 0.24412, 
-//58
-0.13664, 0.16286, 0.2045, 0.24986, 0.2863, 0.3038, 0.30742, 0.30098, 0.29384, 0.2863, 0.28072, 0.27764, 0.27478, 0.27028, 0.26596, 0.26144, 0.26166, 0.2635,
-0.26414, 0.26304, 0.25738, 0.2462, 0.2386, 0.2329, 0.23334, 0.23702, 0.23848, 0.23328, 0.22486, 0.2169, 0.21158, 0.2108, 0.2187, 0.23768, 0.26958, 0.31148,
-0.3487, 0.37896, 0.40674, 0.44622, 0.49816, 0.55176, 0.59354, 0.625, 0.64688, 0.6572, 0.6651, 0.6697, 0.67006, 0.67104, 0.67132, 0.67256, 0.67176, 0.67118,
-0.67172, 0.6721, 0.6709, 0.67034, 0.6703, 0.67036, 0.6691, 0.66902, 0.66818, 0.66858, 0.67044, 0.67154, 0.67092, 0.66998, 0.66986, 0.67452, 0.67412, 0.67446,
-0.67462, 0.67128, 0.6733, 0.6725, 0.6721, 0.66906, 0.67124, 0.6715, 0.67156, 
-//59
-0.11862, 0.13808, 0.16538, 0.19072, 0.20714, 0.21148, 0.20992, 0.20548, 0.19808, 0.19186, 0.1896, 0.18782, 0.18622, 0.1822, 0.1792, 0.1773, 0.1774, 0.17786,
-0.17924, 0.17748, 0.17176, 0.16456, 0.15836, 0.15658, 0.15686, 0.15778, 0.15818, 0.15454, 0.14918, 0.1437, 0.14014, 0.14152, 0.1473, 0.15932, 0.1796, 0.20372,
-0.22324, 0.23884, 0.25678, 0.29082, 0.33862, 0.38904, 0.43184, 0.46022, 0.48038, 0.49104, 0.49794, 0.50022, 0.50202, 0.50326, 0.50266, 0.50198, 0.50044,
-0.50198, 0.50162, 0.50028, 0.49982, 0.49926, 0.49848, 0.4987, 0.49796, 0.49834, 0.4958, 0.49566, 0.49702, 0.4949, 0.49476, 0.49684, 0.4927, 0.49558, 0.49642,
-0.49616, 0.49654, 0.49212, 0.49408, 0.4932, 0.49152, 0.4892, 0.48954, 0.4886, 0.48754, 
-//60
-0.10034, 0.11064, 0.12326, 0.12962, 0.13316, 0.13204, 0.13044, 0.12618, 0.1239, 0.12058, 0.11806, 0.1177, 0.11638, 0.11438, 0.11286, 0.1111, 0.11102, 0.11154,
-0.11222, 0.10956, 0.10644, 0.1031, 0.0992, 0.09916, 0.09928, 0.10054, 0.09968, 0.09754, 0.09552, 0.09302, 0.09442, 0.09528, 0.09928, 0.10642, 0.11576, 0.12612,
-0.1325, 0.14066, 0.15186, 0.1741, 0.2101, 0.25576, 0.29886, 0.33176, 0.35588, 0.36998, 0.37452, 0.3783, 0.37986, 0.37964, 0.38194, 0.38044, 0.37804, 0.3785,
-0.37812, 0.37758, 0.37684, 0.37556, 0.3764, 0.37666, 0.37492, 0.374, 0.37362, 0.3725, 0.37224, 0.37152, 0.37078, 0.37032, 0.37032, 0.36948, 0.36912, 0.36854,
-0.3711, 0.36872, 0.3687, 0.36762, 0.36888, 0.3671, 0.36704, 0.3656, 0.36722, 
-//61
-0.07106, 0.0728, 0.07404, 0.07456, 0.07496, 0.0734, 0.07344, 0.07196, 0.07174, 0.07184, 0.07078, 0.07076, 0.07056, 0.07076, 0.06838, 0.06848, 0.06822, 0.06698,
-0.0664, 0.06536, 0.06462, 0.06344, 0.06402, 0.06412, 0.06496, 0.06398, 0.06144, 0.06022, 0.05968, 0.06078, 0.06368, 0.0644, 0.0659, 0.0685, 0.07124, 0.07098,
-0.07188, 0.07406, 0.07496, 0.07878, 0.0967, 0.1146, 0.13948, 0.17388, 0.20932, 0.24142, 0.26548, 0.285, 0.29742, 0.30546, 0.31178, 0.31436, 0.31684, 0.31764,
-0.319, 0.31918, 0.3185, 0.3183, 0.31968, 0.32158, 0.31976, 0.3201, 0.32328, 0.32424, 0.32508, 0.32432, 0.32704, 0.32664, 0.327, 0.32984, 0.33148, 0.33074,
-0.33372, 0.33344, 0.33554, 0.33844, 0.33948, 0.33672, 0.33706, 0.33742, 0.33766, 
-//62
-0.12126, 0.14472, 0.18224, 0.2253, 0.25568, 0.27052, 0.2699, 0.26442, 0.2568, 0.2501, 0.24628, 0.24434, 0.24392, 0.2398, 0.23568, 0.23384, 0.23498, 0.23642,
-0.23838, 0.23526, 0.22676, 0.21588, 0.2065, 0.20146, 0.2033, 0.20568, 0.20634, 0.2014, 0.1935, 0.1865, 0.18132, 0.1832, 0.19254, 0.21096, 0.24044, 0.27186,
-0.29754, 0.31982, 0.3506, 0.39916, 0.46324, 0.53042, 0.596, 0.64936, 0.69142, 0.72028, 0.7423, 0.75612, 0.76316, 0.7717, 0.77452, 0.77814, 0.77922, 0.78118,
-0.78316, 0.78396, 0.78342, 0.78008, 0.77772, 0.78146, 0.78314, 0.7854, 0.78704, 0.7904, 0.79142, 0.7908, 0.79338, 0.79092, 0.79456, 0.79608, 0.79796, 0.79892,
-0.80334, 0.79826, 0.80116, 0.8031, 0.80414, 0.80218, 0.8044, 0.80096, 0.8043, 
-//63
-0.1214, 0.1381, 0.15984, 0.17666, 0.18594, 0.1878, 0.18798, 0.18322, 0.17964, 0.17666, 0.17324, 0.17178, 0.17088, 0.16856, 0.16376, 0.16022, 0.15734, 0.15512,
-0.1523, 0.14732, 0.142, 0.13618, 0.12968, 0.1264, 0.12468, 0.12458, 0.12554, 0.12336, 0.12056, 0.11896, 0.1168, 0.11612, 0.11788, 0.12354, 0.1349, 0.1556,
-0.18888, 0.23378, 0.28344, 0.33762, 0.39414, 0.44424, 0.48308, 0.51182, 0.53144, 0.54456, 0.55276, 0.55602, 0.56018, 0.56068, 0.563, 0.56216, 0.56208, 0.56282,
-0.56186, 0.5632, 0.5636, 0.56344, 0.56294, 0.56522, 0.56468, 0.5652, 0.5647, 0.56394, 0.56498, 0.56628, 0.56814, 0.5655, 0.56634, 0.56682, 0.56732, 0.56838,
-0.56806, 0.56604, 0.56794, 0.56768, 0.5664, 0.56792, 0.56782, 0.56594, 0.57106, 
-//64
-0.09506, 0.10232, 0.11126, 0.1159, 0.11658, 0.11552, 0.11268, 0.11028, 0.10802, 0.10502, 0.1039, 0.10352, 0.10292, 0.10018, 0.09848, 0.09702, 0.09698, 0.09742,
-0.09642, 0.0951, 0.09332, 0.0913, 0.08994, 0.08748, 0.08848, 0.08942, 0.08816, 0.08494, 0.08356, 0.08256, 0.08396, 0.08576, 0.08994, 0.09462, 0.10238, 0.1061,
-0.10916, 0.11436, 0.11944, 0.13674, 0.16988, 0.21684, 0.27294, 0.32856, 0.37764, 0.41424, 0.44126, 0.45994, 0.47158, 0.47974, 0.48444, 0.48656, 0.48768,
-0.48956, 0.48938, 0.48986, 0.49142, 0.4906, 0.48954, 0.4906, 0.48954, 0.48988, 0.4878, 0.49082, 0.49048, 0.49046, 0.49022, 0.4911, 0.4915, 0.49452, 0.495,
-0.49436, 0.49568, 0.49388, 0.49382, 0.4953, 0.49486, 0.49158, 0.49372, 0.4967, 0.49678, 
-//65
-0.0678, 0.0639, 0.06408, 0.0642, 0.0633, 0.06256, 0.0623, 0.06188, 0.06176, 0.0611, 0.06118, 0.0613, 0.062, 0.06128, 0.0602, 0.05976, 0.05854, 0.05774, 0.05656,
-0.05546, 0.05566, 0.0537, 0.05434, 0.05504, 0.05602, 0.05584, 0.05474, 0.05426, 0.05456, 0.05656, 0.05772, 0.05984, 0.06156, 0.0628, 0.06462, 0.0648, 0.06578,
-0.0684, 0.07064, 0.0745, 0.08462, 0.10044, 0.12554, 0.16132, 0.20508, 0.2552, 0.3054, 0.3542, 0.3948, 0.42884, 0.45414, 0.47058, 0.48448, 0.49492, 0.50086,
-0.50746, 0.51308, 0.51588, 0.52014, 0.52528, 0.52712, 0.53114, 0.53504, 0.53788, 0.54094, 0.5451, 0.54854, 0.55126, 0.5549, 0.55832, 0.5631, 0.5644, 0.56998,
-0.56936, 0.57326, 0.57632, 0.57962, 0.58208, 0.58294, 0.58784, 0.59304, 
-//66
-0.10702, 0.12174, 0.14232, 0.15874, 0.16774, 0.1701, 0.16858, 0.1661, 0.16392, 0.16066, 0.15908, 0.15794, 0.1563, 0.15326, 0.1505, 0.14666, 0.14252, 0.13858,
-0.1344, 0.1312, 0.12512, 0.11922, 0.11282, 0.10928, 0.10782, 0.10682, 0.10658, 0.1056, 0.10464, 0.10258, 0.1001, 0.09882, 0.09962, 0.10348, 0.11122, 0.12774,
-0.15538, 0.19532, 0.24396, 0.30484, 0.3712, 0.43724, 0.49844, 0.5462, 0.58548, 0.61308, 0.63296, 0.64438, 0.65182, 0.6562, 0.6611, 0.66366, 0.66498, 0.6673,
-0.66906, 0.67032, 0.6713, 0.67004, 0.67076, 0.67342, 0.67266, 0.67716, 0.67844, 0.68044, 0.6816, 0.68204, 0.6827, 0.68532, 0.68582, 0.68958, 0.69078, 0.68938,
-0.69178, 0.69156, 0.69494, 0.69414, 0.69306, 0.6945, 0.6985, 0.69392, 0.69588, 
-//67
-0.08712, 0.09198, 0.0985, 0.10114, 0.10196, 0.10046, 0.09912, 0.09764, 0.09536, 0.0932, 0.09302, 0.09234, 0.09186, 0.09032, 0.08918, 0.08756, 0.08784, 0.08816,
-0.08692, 0.08634, 0.08344, 0.08174, 0.08098, 0.08014, 0.08102, 0.08054, 0.0801, 0.07836, 0.07694, 0.07646, 0.07776, 0.08058, 0.08406, 0.08688, 0.09152, 0.09452,
-0.09544, 0.09746, 0.09996, 0.11062, 0.1351, 0.1755, 0.2294, 0.29278, 0.35942, 0.42422, 0.48522, 0.5397, 0.5853, 0.62498, 0.65232, 0.67268, 0.68464, 0.69526,
-0.70312, 0.70822, 0.71418, 0.71484, 0.7156, 0.71696, 0.71612, 0.71788, 0.72116, 0.73, 0.73374, 0.73852, 0.7428, 0.74588, 0.74746, 0.74926, 0.75162, 0.75484,
-0.757, 0.7571, 0.75982, 0.7611, 0.76284, 0.76208, 0.76732, 0.7651, 0.76686, 
-//68
-0.05396, 0.05286, 0.05232, 0.0518, 0.05198, 0.0514, 0.05114, 0.0501, 0.05094, 0.05136, 0.05082, 0.05118, 0.05242, 0.05154, 0.05148, 0.0503, 0.04962, 0.0491,
-0.04852, 0.04692, 0.047, 0.04546, 0.04576, 0.04592, 0.04578, 0.04594, 0.04548, 0.04606, 0.0473, 0.04714, 0.04812, 0.04904, 0.05026, 0.04984, 0.05042, 0.05092,
-0.05174, 0.05428, 0.05916, 0.06294, 0.06984, 0.08008, 0.09756, 0.12328, 0.16302, 0.2216, 0.295, 0.37414, 0.44482, 0.50474, 0.54842, 0.57994, 0.59774, 0.61408,
-0.62674, 0.63544, 0.6422, 0.64802, 0.65146, 0.65656, 0.66064, 0.6667, 0.6711, 0.67582, 0.68052, 0.68384, 0.68798, 0.6904, 0.6945, 0.69954, 0.70476, 0.70774,
-0.71188, 0.7127, 0.71514, 0.71962, 0.72296, 0.72502, 0.72736, 0.72586, 0.73112, 
-//69
-0.10796, 0.1252, 0.14282, 0.15716, 0.16286, 0.1633, 0.1623, 0.1593, 0.15554, 0.15266, 0.15026, 0.14982, 0.1481, 0.14504, 0.14192, 0.13846, 0.13508, 0.1321,
-0.1281, 0.12414, 0.11928, 0.1134, 0.10864, 0.10386, 0.10266, 0.10128, 0.10052, 0.09954, 0.09718, 0.09584, 0.09402, 0.09326, 0.0938, 0.09718, 0.10528, 0.11986,
-0.14388, 0.17796, 0.22074, 0.27696, 0.34572, 0.41854, 0.49188, 0.55652, 0.60752, 0.64956, 0.68154, 0.70438, 0.71896, 0.72836, 0.73596, 0.7407, 0.74428, 0.74802,
-0.75096, 0.75158, 0.75404, 0.75158, 0.75164, 0.75412, 0.75744, 0.7584, 0.75958, 0.76442, 0.76494, 0.76692, 0.7684, 0.76868, 0.76906, 0.77144, 0.7733, 0.771,
-0.7756, 0.77422, 0.77752, 0.777, 0.77964, 0.77818, 0.78134, 0.78072, 0.78086, 
-//70
-0.09026, 0.09472, 0.09836, 0.10084, 0.10198, 0.10116, 0.09972, 0.09894, 0.0982, 0.09702, 0.096, 0.09558, 0.09496, 0.09354, 0.09098, 0.08732, 0.08524, 0.08178,
-0.07956, 0.07638, 0.0752, 0.07212, 0.06906, 0.0677, 0.06632, 0.0655, 0.06514, 0.06532, 0.06542, 0.06518, 0.06496, 0.06552, 0.0665, 0.0685, 0.07184, 0.07626,
-0.08436, 0.09526, 0.1099, 0.13476, 0.17206, 0.22536, 0.28854, 0.3596, 0.4305, 0.49646, 0.54946, 0.58862, 0.61534, 0.6347, 0.64666, 0.65516, 0.66018, 0.66614,
-0.67168, 0.67492, 0.67582, 0.67846, 0.67826, 0.68372, 0.68658, 0.68848, 0.69156, 0.6947, 0.69722, 0.69694, 0.69782, 0.70058, 0.70062, 0.70404, 0.70534, 0.70712,
-0.71052, 0.7083, 0.71144, 0.71022, 0.71402, 0.71648, 0.72144, 0.714, 0.71968, 
-//71
-0.05192, 0.05152, 0.05066, 0.0502, 0.04974, 0.04948, 0.04944, 0.04934, 0.05008, 0.05004, 0.05004, 0.04906, 0.0489, 0.0489, 0.04654, 0.0451, 0.04356, 0.04282,
-0.04144, 0.04116, 0.04046, 0.03944, 0.03944, 0.03922, 0.03956, 0.03926, 0.0398, 0.04016, 0.04018, 0.03972, 0.03954, 0.03984, 0.03934, 0.03916, 0.0403, 0.04134,
-0.04272, 0.0466, 0.04972, 0.05442, 0.06178, 0.07404, 0.09544, 0.1279, 0.17804, 0.24456, 0.3224, 0.4044, 0.47452, 0.5328, 0.57746, 0.60908, 0.63124, 0.6482,
-0.66298, 0.67336, 0.68346, 0.68982, 0.69256, 0.70112, 0.70736, 0.71178, 0.7167, 0.7222, 0.72816, 0.73244, 0.73478, 0.73718, 0.74018, 0.74304, 0.74774, 0.75044,
-0.7529, 0.7565, 0.75846, 0.76034, 0.75978, 0.76418, 0.7661, 0.76158, 0.76668, 
-//72
-0.13832, 0.16774, 0.22518, 0.30958, 0.41754, 0.52842, 0.61414, 0.6537, 0.66654, 0.66632, 0.66586, 0.66576, 0.66456, 0.66024, 0.65916, 0.6584, 0.66012, 0.66266,
-0.66402, 0.66272, 0.65846, 0.64884, 0.6429, 0.64058, 0.6434, 0.64878, 0.65166, 0.64986, 0.64332, 0.63466, 0.62932, 0.63034, 0.63896, 0.65884, 0.68842, 0.71782,
-0.73882, 0.75164, 0.75822, 0.7631, 0.76224, 0.76432, 0.76398, 0.76414, 0.76546, 0.7652, 0.76604, 0.76688, 0.76582, 0.76542, 0.76618, 0.7659, 0.76466, 0.76796,
-0.76594, 0.76138, 0.7641, 0.7631, 0.76016, 0.76272, 0.76222, 0.76254, 0.75904, 0.76108, 0.76158, 0.75986, 0.75908, 0.75888, 0.75824, 0.7619, 0.7625, 0.76058,
-0.76192, 0.7582, 0.76034, 0.7612, 0.76072, 0.7601, 0.7611, 0.76054, 0.75726, 
-//73
-0.1415, 0.1718, 0.22376, 0.3017, 0.38242, 0.44962, 0.48578, 0.49418, 0.49408, 0.49066, 0.48826, 0.4873, 0.48596, 0.48376, 0.4805, 0.4775, 0.4782, 0.4781,
-0.47956, 0.47954, 0.47508, 0.4684, 0.46324, 0.46096, 0.46406, 0.47068, 0.47554, 0.4762, 0.4726, 0.46794, 0.46072, 0.45958, 0.4657, 0.48192, 0.50548, 0.53066,
-0.54808, 0.55916, 0.56316, 0.56504, 0.56544, 0.5656, 0.5665, 0.56412, 0.56642, 0.56624, 0.56402, 0.5629, 0.56198, 0.56066, 0.56004, 0.5582, 0.5559, 0.5558,
-0.55474, 0.55426, 0.55348, 0.55254, 0.55114, 0.55132, 0.54914, 0.54894, 0.5468, 0.54768, 0.54554, 0.54524, 0.54542, 0.54556, 0.54332, 0.54482, 0.54452, 0.54384,
-0.54474, 0.5411, 0.54196, 0.54016, 0.541, 0.54124, 0.53768, 0.54014, 0.53778, 
-//74
-0.13272, 0.16226, 0.20722, 0.26426, 0.3181, 0.35056, 0.36208, 0.36226, 0.35798, 0.35322, 0.35116, 0.35044, 0.3481, 0.34484, 0.34178, 0.34016, 0.34138, 0.34288,
-0.34452, 0.34352, 0.33824, 0.33086, 0.32408, 0.32034, 0.32218, 0.32694, 0.33076, 0.32884, 0.32222, 0.31386, 0.30874, 0.31008, 0.31916, 0.3387, 0.36404, 0.38856,
-0.4056, 0.4157, 0.41948, 0.42044, 0.42028, 0.4203, 0.42038, 0.4186, 0.41738, 0.4163, 0.4142, 0.41416, 0.41334, 0.41218, 0.41168, 0.41028, 0.40876, 0.40658,
-0.4064, 0.40512, 0.4047, 0.40366, 0.40282, 0.40188, 0.40032, 0.40032, 0.39894, 0.39872, 0.39742, 0.39746, 0.39696, 0.39594, 0.39504, 0.3958, 0.39486, 0.39482,
-0.39558, 0.39358, 0.39348, 0.39276, 0.39396, 0.39434, 0.3937, 0.39242, 0.39176, 
-//75
-0.11878, 0.14004, 0.17382, 0.21028, 0.23876, 0.2516, 0.2551, 0.25182, 0.24832, 0.24418, 0.24256, 0.24022, 0.23958, 0.23678, 0.23478, 0.23408, 0.23442, 0.23548,
-0.23632, 0.23568, 0.23192, 0.22528, 0.21904, 0.21656, 0.21808, 0.22182, 0.22438, 0.2222, 0.2166, 0.21046, 0.20674, 0.20736, 0.21478, 0.23026, 0.25254, 0.27334,
-0.28856, 0.29656, 0.30086, 0.30274, 0.30292, 0.30208, 0.30212, 0.30136, 0.3014, 0.29924, 0.29924, 0.29788, 0.29718, 0.29646, 0.2959, 0.29484, 0.29366, 0.29224,
-0.29226, 0.29036, 0.29032, 0.28994, 0.28978, 0.28884, 0.288, 0.28688, 0.2858, 0.28624, 0.2858, 0.28612, 0.2856, 0.28506, 0.28382, 0.28386, 0.2839, 0.28366,
-0.28382, 0.28244, 0.28376, 0.2827, 0.28164, 0.28014, 0.2813, 0.27934, 0.28092, 
-//76
-0.11554, 0.1307, 0.14926, 0.16572, 0.1748, 0.17744, 0.17674, 0.17396, 0.17066, 0.16768, 0.1651, 0.16364, 0.1621, 0.16012, 0.158, 0.1566, 0.1568, 0.15738,
-0.15722, 0.157, 0.15464, 0.1503, 0.14766, 0.1449, 0.14566, 0.14662, 0.14672, 0.14472, 0.14062, 0.13694, 0.1363, 0.13698, 0.14148, 0.15092, 0.1661, 0.18222,
-0.19522, 0.20318, 0.207, 0.20816, 0.20974, 0.20934, 0.20998, 0.20896, 0.20804, 0.2075, 0.20648, 0.20498, 0.20302, 0.20214, 0.20262, 0.20198, 0.20012, 0.19972,
-0.19932, 0.19768, 0.19764, 0.1963, 0.19568, 0.19714, 0.19548, 0.1946, 0.1942, 0.19348, 0.19402, 0.19294, 0.19186, 0.19114, 0.19122, 0.19152, 0.19252, 0.19192,
-0.19206, 0.19116, 0.19156, 0.1914, 0.1924, 0.19334, 0.19274, 0.19058, 0.19032, 
-//77
-0.0881, 0.0939, 0.10132, 0.10772, 0.1101, 0.10924, 0.10776, 0.1052, 0.10466, 0.10256, 0.102, 0.10148, 0.10104, 0.09976, 0.0991, 0.09858, 0.09854, 0.09894,
-0.09866, 0.09748, 0.0951, 0.09316, 0.09016, 0.08866, 0.0894, 0.09068, 0.09022, 0.08952, 0.0874, 0.0863, 0.08654, 0.0881, 0.09176, 0.09728, 0.10594, 0.11326,
-0.11864, 0.12122, 0.12468, 0.12806, 0.13356, 0.13726, 0.13982, 0.14096, 0.14126, 0.14118, 0.14086, 0.14022, 0.1396, 0.13914, 0.1388, 0.1384, 0.13742, 0.13764,
-0.13698, 0.13556, 0.13576, 0.13444, 0.13386, 0.13424, 0.13456, 0.13346, 0.13422, 0.13498, 0.13322, 0.13376, 0.13408, 0.13358, 0.13478, 0.1344, 0.13532, 0.13508,
-0.13578, 0.1356, 0.13632, 0.136, 0.1365, 0.13568, 0.13596, 0.1361, 0.13594, 
-//78
-0.05462, 0.0559, 0.05644, 0.05706, 0.0566, 0.05586, 0.05634, 0.05644, 0.05638, 0.05508, 0.0558, 0.05586, 0.05612, 0.05572, 0.05542, 0.0545, 0.0547, 0.05384,
-0.05314, 0.05336, 0.05314, 0.0521, 0.05178, 0.05158, 0.0515, 0.05152, 0.05148, 0.05116, 0.05108, 0.05112, 0.05114, 0.05196, 0.05248, 0.05272, 0.054, 0.05536,
-0.0571, 0.0599, 0.06408, 0.0696, 0.07614, 0.08216, 0.08688, 0.08866, 0.0898, 0.0891, 0.08868, 0.08828, 0.08756, 0.0872, 0.08686, 0.08598, 0.0861, 0.08542,
-0.08452, 0.08448, 0.08384, 0.08254, 0.0831, 0.083, 0.08208, 0.08194, 0.08178, 0.0823, 0.08242, 0.08266, 0.0829, 0.08316, 0.08352, 0.08376, 0.08472, 0.08528,
-0.08524, 0.0847, 0.08516, 0.08492, 0.08516, 0.085, 0.08582, 0.08642, 0.08566, 
-//79
-0.05204, 0.04968, 0.04936, 0.04882, 0.04814, 0.04776, 0.04736, 0.04748, 0.04698, 0.04626, 0.0464, 0.04564, 0.04548, 0.04524, 0.04452, 0.0435, 0.04322, 0.04274,
-0.04242, 0.0423, 0.04264, 0.0427, 0.04232, 0.04176, 0.0417, 0.04168, 0.04246, 0.04224, 0.04158, 0.04186, 0.0421, 0.04216, 0.04286, 0.04326, 0.0445, 0.0452,
-0.04586, 0.04872, 0.05074, 0.05262, 0.05532, 0.05794, 0.06172, 0.06458, 0.067, 0.06904, 0.0707, 0.07208, 0.07326, 0.07474, 0.07502, 0.0747, 0.07538, 0.07546,
-0.07574, 0.07596, 0.07566, 0.07524, 0.0757, 0.07628, 0.07654, 0.0772, 0.07816, 0.07908, 0.0798, 0.08014, 0.08192, 0.08362, 0.08506, 0.08578, 0.08666, 0.08782,
-0.08896, 0.08852, 0.09026, 0.09072, 0.0914, 0.09304, 0.0945, 0.0948, 0.09564, 
-//80
-0.14, 0.16996, 0.22192, 0.29096, 0.36252, 0.41468, 0.43728, 0.44068, 0.4356, 0.42802, 0.4254, 0.42246, 0.42116, 0.41568, 0.41246, 0.41052, 0.41124, 0.41402,
-0.41642, 0.41552, 0.40946, 0.39884, 0.39012, 0.38594, 0.38866, 0.39474, 0.39984, 0.39778, 0.38912, 0.37776, 0.37002, 0.36968, 0.38088, 0.408, 0.4515, 0.50272,
-0.55244, 0.59198, 0.61524, 0.62828, 0.6355, 0.64038, 0.64386, 0.64546, 0.64748, 0.64732, 0.64732, 0.64732, 0.64614, 0.64736, 0.64812, 0.64836, 0.64758, 0.64932,
-0.6492, 0.64812, 0.64842, 0.64816, 0.64634, 0.6487, 0.648, 0.648, 0.64756, 0.6483, 0.64766, 0.64872, 0.64986, 0.65144, 0.65118, 0.65248, 0.65286, 0.65356,
-0.65668, 0.65184, 0.65566, 0.65632, 0.6607, 0.65674, 0.6591, 0.65692, 0.65678, 
-//81
-0.13186, 0.15926, 0.20544, 0.25986, 0.30684, 0.3329, 0.34034, 0.33672, 0.33014, 0.32298, 0.31778, 0.31656, 0.31398, 0.30952, 0.30598, 0.3025, 0.3046, 0.30836,
-0.31008, 0.3088, 0.30244, 0.29178, 0.28242, 0.27704, 0.27906, 0.28472, 0.28734, 0.2823, 0.2713, 0.26088, 0.255, 0.25692, 0.26862, 0.29558, 0.33762, 0.38538,
-0.42576, 0.45412, 0.46862, 0.4765, 0.47934, 0.48138, 0.4817, 0.48108, 0.48124, 0.48002, 0.4799, 0.47798, 0.4765, 0.47534, 0.47522, 0.473, 0.47204, 0.47172,
-0.47132, 0.46986, 0.46944, 0.46712, 0.46682, 0.46666, 0.46576, 0.46408, 0.463, 0.46352, 0.46274, 0.4611, 0.46164, 0.46012, 0.46064, 0.4618, 0.46148, 0.4613,
-0.46112, 0.4589, 0.4588, 0.46006, 0.45758, 0.45722, 0.45904, 0.45552, 0.45764, 
-//82
-0.12446, 0.14508, 0.17622, 0.20692, 0.22854, 0.23598, 0.23744, 0.23218, 0.227, 0.22048, 0.21772, 0.21536, 0.2142, 0.21062, 0.20806, 0.20592, 0.20672, 0.20774,
-0.2095, 0.2092, 0.20438, 0.19712, 0.19076, 0.18714, 0.18844, 0.19104, 0.19198, 0.18886, 0.18328, 0.17738, 0.1738, 0.17426, 0.18152, 0.19792, 0.22682, 0.2616,
-0.29542, 0.32106, 0.33572, 0.34446, 0.34734, 0.34906, 0.351, 0.35136, 0.35086, 0.35006, 0.34906, 0.34962, 0.34776, 0.34652, 0.34668, 0.34634, 0.34438, 0.34368,
-0.34272, 0.34192, 0.34194, 0.34078, 0.33888, 0.33882, 0.33734, 0.33644, 0.3362, 0.3359, 0.33626, 0.33506, 0.3355, 0.33452, 0.33364, 0.33444, 0.3342, 0.33252,
-0.33316, 0.3322, 0.3301, 0.32966, 0.3308, 0.3303, 0.32918, 0.32998, 0.32952, 
-//83
-0.10604, 0.11884, 0.13614, 0.14936, 0.15634, 0.15826, 0.15482, 0.15178, 0.1474, 0.14374, 0.1424, 0.1408, 0.13922, 0.13786, 0.13692, 0.13496, 0.13514, 0.13582,
-0.13658, 0.13514, 0.13218, 0.12742, 0.12324, 0.12116, 0.12146, 0.1226, 0.12244, 0.12082, 0.117, 0.11446, 0.113, 0.11398, 0.11994, 0.13014, 0.14754, 0.16722,
-0.18404, 0.19554, 0.20538, 0.21678, 0.22588, 0.23394, 0.2394, 0.24068, 0.24278, 0.24262, 0.24156, 0.24102, 0.24044, 0.24014, 0.24038, 0.23862, 0.23812, 0.23792,
-0.23704, 0.23508, 0.2356, 0.23498, 0.23402, 0.23396, 0.23206, 0.23228, 0.23146, 0.23026, 0.2304, 0.23058, 0.23022, 0.22902, 0.22942, 0.23056, 0.23088, 0.2298,
-0.23158, 0.22944, 0.2299, 0.22924, 0.22916, 0.22974, 0.23064, 0.229, 0.22642, 
-//84
-0.0837, 0.08582, 0.09158, 0.09386, 0.09502, 0.0929, 0.09216, 0.0896, 0.08818, 0.08682, 0.0856, 0.08524, 0.08444, 0.0847, 0.08334, 0.08258, 0.08284, 0.08192,
-0.08218, 0.07994, 0.07884, 0.07654, 0.07532, 0.07496, 0.07516, 0.0757, 0.07604, 0.07474, 0.07286, 0.07274, 0.0741, 0.07562, 0.07858, 0.08362, 0.09026, 0.0968,
-0.10076, 0.10476, 0.10996, 0.12142, 0.13684, 0.15326, 0.16464, 0.17144, 0.17504, 0.1761, 0.1762, 0.17648, 0.17536, 0.1746, 0.17398, 0.17332, 0.17326, 0.17336,
-0.17226, 0.1717, 0.1711, 0.16996, 0.17022, 0.17014, 0.169, 0.16846, 0.16896, 0.16866, 0.16838, 0.16818, 0.16854, 0.16806, 0.16814, 0.16812, 0.16862, 0.16754,
-0.16802, 0.16822, 0.16816, 0.16858, 0.16878, 0.16902, 0.16814, 0.16702, 0.16852, 
-//85
-0.05158, 0.05098, 0.04992, 0.05004, 0.0495, 0.04892, 0.04836, 0.04834, 0.04754, 0.04662, 0.04664, 0.04602, 0.04612, 0.04592, 0.04528, 0.04506, 0.04546, 0.04552,
-0.04396, 0.04468, 0.0446, 0.04424, 0.0434, 0.04346, 0.04332, 0.04332, 0.0436, 0.0435, 0.04398, 0.04484, 0.04548, 0.04582, 0.0469, 0.0482, 0.04908, 0.05046,
-0.05296, 0.05782, 0.06086, 0.06514, 0.06936, 0.07512, 0.08306, 0.0915, 0.10178, 0.11106, 0.11934, 0.12568, 0.12914, 0.1325, 0.1341, 0.135, 0.1349, 0.13434,
-0.13448, 0.13534, 0.13492, 0.13306, 0.13514, 0.136, 0.13606, 0.13664, 0.13766, 0.13956, 0.14116, 0.14162, 0.14196, 0.14452, 0.14472, 0.14598, 0.14738, 0.14864,
-0.15122, 0.15122, 0.15228, 0.15284, 0.15594, 0.15818, 0.1602, 0.16048, 0.16012, 
-//86
-0.13852, 0.16402, 0.20406, 0.25164, 0.28792, 0.30572, 0.3087, 0.30364, 0.297, 0.28986, 0.28606, 0.28354, 0.28106, 0.27732, 0.2737, 0.27112, 0.27164, 0.2732,
-0.27524, 0.2735, 0.26776, 0.25852, 0.24974, 0.24536, 0.24604, 0.25062, 0.2531, 0.2494, 0.24114, 0.23184, 0.22774, 0.22838, 0.23608, 0.2561, 0.29348, 0.3458,
-0.40192, 0.45362, 0.48786, 0.5104, 0.52454, 0.53524, 0.54226, 0.54398, 0.54678, 0.54774, 0.5484, 0.54796, 0.54706, 0.54622, 0.54698, 0.54524, 0.54494, 0.54434,
-0.5437, 0.54264, 0.5435, 0.54234, 0.5431, 0.54312, 0.54144, 0.53978, 0.53994, 0.53964, 0.54006, 0.53908, 0.53938, 0.53994, 0.53932, 0.53984, 0.54018, 0.53906,
-0.5408, 0.5371, 0.54096, 0.54074, 0.54028, 0.54022, 0.5435, 0.54278, 0.54108, 
-//87
-0.12328, 0.1416, 0.16824, 0.19414, 0.20814, 0.21166, 0.20928, 0.20424, 0.19904, 0.1918, 0.1898, 0.18706, 0.18452, 0.18156, 0.17876, 0.17716, 0.1773, 0.1797,
-0.1809, 0.18016, 0.17406, 0.16788, 0.16234, 0.15898, 0.15922, 0.16136, 0.16184, 0.15894, 0.15346, 0.14844, 0.14642, 0.14778, 0.15548, 0.17026, 0.19598, 0.23242,
-0.26844, 0.29882, 0.32098, 0.344, 0.36666, 0.38272, 0.39316, 0.39838, 0.40252, 0.40354, 0.40438, 0.40542, 0.40448, 0.40364, 0.40432, 0.40276, 0.40116, 0.4005,
-0.39932, 0.39744, 0.39598, 0.39528, 0.39444, 0.39478, 0.39168, 0.39076, 0.39156, 0.38932, 0.3895, 0.3888, 0.38904, 0.38814, 0.3883, 0.38894, 0.38904, 0.38776,
-0.38622, 0.38526, 0.38546, 0.38304, 0.38396, 0.38178, 0.38406, 0.38306, 0.382, 
-//88
-0.09566, 0.1051, 0.11744, 0.12734, 0.1298, 0.13, 0.1274, 0.1247, 0.12074, 0.1178, 0.11578, 0.11462, 0.11406, 0.11228, 0.11068, 0.10944, 0.10948, 0.11034,
-0.11126, 0.10986, 0.10678, 0.10178, 0.0988, 0.09748, 0.09782, 0.09896, 0.0992, 0.09702, 0.09348, 0.09104, 0.0902, 0.09192, 0.0978, 0.10836, 0.12522, 0.14406,
-0.15916, 0.17222, 0.1864, 0.20852, 0.23468, 0.2555, 0.27082, 0.27866, 0.284, 0.28622, 0.28738, 0.28714, 0.287, 0.2864, 0.28628, 0.28606, 0.28538, 0.28504,
-0.28502, 0.28338, 0.28402, 0.28414, 0.28336, 0.28322, 0.28188, 0.2827, 0.2815, 0.28276, 0.28266, 0.28208, 0.28172, 0.28106, 0.28186, 0.2824, 0.28246, 0.28308,
-0.28288, 0.28412, 0.28576, 0.28528, 0.28634, 0.28668, 0.2872, 0.28584, 0.28594, 
-//89
-0.06772, 0.07004, 0.0727, 0.0737, 0.07332, 0.0723, 0.07162, 0.06966, 0.06808, 0.0676, 0.06722, 0.0665, 0.06644, 0.06606, 0.06586, 0.06534, 0.065, 0.06514,
-0.06476, 0.06458, 0.06316, 0.06192, 0.0615, 0.0618, 0.0619, 0.06168, 0.06076, 0.06006, 0.05948, 0.05998, 0.06168, 0.06402, 0.06726, 0.07154, 0.07644, 0.07972,
-0.0815, 0.08384, 0.08886, 0.1012, 0.12092, 0.14498, 0.16738, 0.18414, 0.1938, 0.20032, 0.20384, 0.20544, 0.2053, 0.20544, 0.20534, 0.20494, 0.20464, 0.20436,
-0.20398, 0.20336, 0.20282, 0.20282, 0.20304, 0.20288, 0.20296, 0.20192, 0.2023, 0.20214, 0.2017, 0.20322, 0.20352, 0.20344, 0.20326, 0.20454, 0.20474, 0.2053,
-0.20518, 0.20526, 0.2066, 0.2064, 0.2075, 0.20848, 0.20922, 0.2082, 0.20644, 
-//90
-0.04298, 0.0426, 0.0409, 0.0406, 0.04012, 0.04012, 0.03958, 0.0395, 0.03946, 0.03918, 0.03876, 0.03862, 0.03902, 0.03894, 0.0387, 0.03826, 0.03832, 0.03802,
-0.0378, 0.03798, 0.0386, 0.03864, 0.0377, 0.03766, 0.03778, 0.03808, 0.03846, 0.03886, 0.03946, 0.04008, 0.0407, 0.04104, 0.04092, 0.04184, 0.04324, 0.0449,
-0.04676, 0.05028, 0.05378, 0.05748, 0.0612, 0.06792, 0.07676, 0.08926, 0.10338, 0.11768, 0.13162, 0.14224, 0.15012, 0.15556, 0.15926, 0.16096, 0.16276, 0.16414,
-0.16426, 0.1649, 0.16574, 0.1664, 0.1675, 0.16896, 0.1692, 0.1699, 0.17128, 0.17308, 0.17424, 0.1752, 0.17704, 0.17916, 0.18076, 0.18262, 0.18378, 0.18634,
-0.18824, 0.18922, 0.1909, 0.19366, 0.19592, 0.19662, 0.19694, 0.19922, 0.20156, 
-//91
-0.13414, 0.15858, 0.19462, 0.23522, 0.26356, 0.2765, 0.2771, 0.27082, 0.26294, 0.25566, 0.2514, 0.24836, 0.24634, 0.24308, 0.2387, 0.2365, 0.2364, 0.23876,
-0.24062, 0.23886, 0.23278, 0.22446, 0.21522, 0.21234, 0.21276, 0.21664, 0.21886, 0.21534, 0.20746, 0.19964, 0.1953, 0.1957, 0.20396, 0.22466, 0.26428, 0.32186,
-0.38858, 0.4506, 0.50064, 0.5396, 0.56992, 0.59294, 0.61094, 0.62184, 0.6299, 0.63462, 0.63584, 0.63782, 0.6385, 0.63748, 0.63782, 0.63764, 0.63664, 0.6372,
-0.63784, 0.63598, 0.63658, 0.63728, 0.63564, 0.63788, 0.63454, 0.63436, 0.6335, 0.63388, 0.633, 0.6329, 0.63274, 0.6321, 0.63136, 0.63438, 0.63356, 0.63382,
-0.63598, 0.63246, 0.63194, 0.63182, 0.6326, 0.63284, 0.63342, 0.6296, 0.63156, 
-//92
-0.10668, 0.12454, 0.14782, 0.16862, 0.1814, 0.18216, 0.1802, 0.17376, 0.16954, 0.16434, 0.16082, 0.15994, 0.15806, 0.1555, 0.15378, 0.15218, 0.1525, 0.15404,
-0.15576, 0.15464, 0.14998, 0.14264, 0.13748, 0.13498, 0.13538, 0.13834, 0.1392, 0.13592, 0.1307, 0.12584, 0.12386, 0.12654, 0.13386, 0.14886, 0.17586, 0.20984,
-0.24428, 0.27298, 0.3009, 0.33564, 0.37328, 0.40684, 0.42974, 0.4441, 0.45146, 0.45636, 0.45766, 0.4597, 0.45926, 0.45834, 0.45846, 0.45936, 0.45746, 0.45742,
-0.45788, 0.45802, 0.45968, 0.458, 0.45552, 0.45604, 0.45514, 0.45382, 0.45308, 0.45226, 0.45256, 0.45244, 0.45256, 0.4515, 0.45022, 0.452, 0.45336, 0.45252,
-0.4527, 0.451, 0.4527, 0.452, 0.45286, 0.45198, 0.45268, 0.45142, 0.45186, 
-//93
-0.0847, 0.09354, 0.1039, 0.10864, 0.10928, 0.10888, 0.1069, 0.10362, 0.10076, 0.09756, 0.0972, 0.0965, 0.09624, 0.09462, 0.0937, 0.09156, 0.0927, 0.09308,
-0.09312, 0.09276, 0.0897, 0.0861, 0.08426, 0.08326, 0.08404, 0.08526, 0.0848, 0.08286, 0.08088, 0.07976, 0.08008, 0.08182, 0.0865, 0.09514, 0.10758, 0.12344,
-0.13616, 0.14698, 0.16266, 0.18896, 0.22552, 0.26396, 0.29648, 0.31784, 0.33178, 0.33946, 0.34374, 0.34552, 0.34676, 0.34768, 0.3468, 0.3468, 0.34648, 0.34618,
-0.34656, 0.34596, 0.34722, 0.34592, 0.34534, 0.3459, 0.34512, 0.3446, 0.34232, 0.34138, 0.34134, 0.34084, 0.34038, 0.34118, 0.34162, 0.34326, 0.34336, 0.3421,
-0.34332, 0.34332, 0.34124, 0.34318, 0.34224, 0.34372, 0.34184, 0.34388, 0.3411, 
-//94
-0.06172, 0.0614, 0.0618, 0.0619, 0.06092, 0.06074, 0.061, 0.06098, 0.061, 0.06, 0.06038, 0.06074, 0.06082, 0.06046, 0.06082, 0.06012, 0.06022, 0.05962, 0.05844,
-0.05842, 0.05788, 0.05802, 0.059, 0.0604, 0.06176, 0.06078, 0.06058, 0.0593, 0.06012, 0.06174, 0.06494, 0.06816, 0.0714, 0.07394, 0.07716, 0.07834, 0.07864,
-0.07906, 0.08102, 0.086, 0.09646, 0.11454, 0.13982, 0.17152, 0.20562, 0.23876, 0.26858, 0.29196, 0.30766, 0.31894, 0.32738, 0.3322, 0.33432, 0.33704, 0.33868,
-0.3398, 0.34188, 0.34198, 0.34304, 0.3454, 0.34516, 0.34702, 0.34708, 0.34956, 0.34978, 0.35046, 0.35204, 0.35376, 0.35476, 0.35834, 0.3605, 0.36044, 0.36222,
-0.36502, 0.36502, 0.3691, 0.3696, 0.37158, 0.37192, 0.3708, 0.37384, 
-//95
-0.1245, 0.14758, 0.18006, 0.21246, 0.23348, 0.24066, 0.23902, 0.23378, 0.2269, 0.2201, 0.21642, 0.21438, 0.21184, 0.20818, 0.205, 0.203, 0.20434, 0.20642,
-0.20894, 0.20788, 0.2022, 0.19312, 0.18608, 0.18236, 0.18336, 0.18702, 0.18904, 0.18566, 0.1787, 0.1711, 0.1677, 0.16874, 0.17814, 0.19936, 0.23734, 0.29058,
-0.3495, 0.40108, 0.4465, 0.49444, 0.54576, 0.59612, 0.63878, 0.67268, 0.69864, 0.71538, 0.72798, 0.73602, 0.74092, 0.74402, 0.74628, 0.74794, 0.75042, 0.7505,
-0.75178, 0.75284, 0.75254, 0.75214, 0.74986, 0.75276, 0.7514, 0.75348, 0.75512, 0.7595, 0.76046, 0.75976, 0.76148, 0.76162, 0.76256, 0.7662, 0.76686, 0.76472,
-0.7671, 0.7639, 0.76888, 0.7662, 0.76992, 0.77156, 0.76968, 0.7679, 0.76768, 
-//96
-0.1052, 0.11952, 0.13926, 0.15252, 0.15908, 0.15966, 0.15658, 0.15098, 0.14584, 0.14152, 0.13918, 0.13778, 0.13586, 0.13432, 0.13158, 0.13038, 0.13218, 0.13346,
-0.13524, 0.134, 0.1294, 0.12412, 0.1181, 0.11668, 0.11784, 0.11984, 0.12056, 0.11728, 0.11258, 0.10772, 0.10636, 0.10892, 0.11652, 0.13186, 0.1577, 0.18948,
-0.2199, 0.24636, 0.27642, 0.32058, 0.37554, 0.4318, 0.4791, 0.51264, 0.53622, 0.55076, 0.55808, 0.56264, 0.56468, 0.56594, 0.56766, 0.56926, 0.56796, 0.57026,
-0.5714, 0.57006, 0.57044, 0.57054, 0.57154, 0.5728, 0.57188, 0.57086, 0.57214, 0.57176, 0.57308, 0.57334, 0.575, 0.574, 0.57554, 0.57674, 0.57678, 0.57688,
-0.57882, 0.57712, 0.57742, 0.58024, 0.57828, 0.57808, 0.57876, 0.5764, 0.57682, 
-//97
-0.08818, 0.0919, 0.0964, 0.09662, 0.09654, 0.09376, 0.09344, 0.09086, 0.08874, 0.08704, 0.08686, 0.08684, 0.08604, 0.08542, 0.08472, 0.08432, 0.08474, 0.08518,
-0.08542, 0.08388, 0.08196, 0.08024, 0.07962, 0.08012, 0.081, 0.08218, 0.08172, 0.0807, 0.08086, 0.08132, 0.08432, 0.08812, 0.09302, 0.09954, 0.10894, 0.11968,
-0.12942, 0.1367, 0.14646, 0.16898, 0.2064, 0.25652, 0.3107, 0.36148, 0.40366, 0.43566, 0.4567, 0.47042, 0.47868, 0.48518, 0.48872, 0.49024, 0.49054, 0.49166,
-0.49258, 0.49354, 0.49502, 0.49586, 0.4948, 0.49498, 0.49484, 0.4945, 0.49602, 0.4972, 0.49708, 0.49724, 0.49934, 0.49868, 0.49954, 0.50272, 0.50264, 0.50424,
-0.50606, 0.50482, 0.50644, 0.50732, 0.50742, 0.50892, 0.5105, 0.50882, 0.51032, 
-//98
-0.05116, 0.05088, 0.04954, 0.04898, 0.04966, 0.04988, 0.05092, 0.05056, 0.05118, 0.05046, 0.05038, 0.0503, 0.0505, 0.04924, 0.04896, 0.04804, 0.04814, 0.04748,
-0.04764, 0.04742, 0.04738, 0.04794, 0.04818, 0.04878, 0.04932, 0.05016, 0.0517, 0.05132, 0.05106, 0.0511, 0.05158, 0.05364, 0.05548, 0.05796, 0.06138, 0.06564,
-0.0699, 0.07638, 0.08226, 0.09044, 0.10524, 0.12728, 0.15858, 0.19876, 0.2429, 0.28312, 0.31468, 0.33674, 0.35126, 0.36132, 0.36936, 0.37476, 0.37786, 0.38186,
-0.38576, 0.38946, 0.3932, 0.39524, 0.39988, 0.40516, 0.4075, 0.40984, 0.4114, 0.41506, 0.41792, 0.42068, 0.42394, 0.42734, 0.429, 0.43254, 0.43588, 0.43702,
-0.4416, 0.44248, 0.44644, 0.44838, 0.44958, 0.45186, 0.45264, 0.45396, 0.45466, 
-//99
-0.10186, 0.11272, 0.12584, 0.1328, 0.13452, 0.13216, 0.12882, 0.12444, 0.1206, 0.1166, 0.11444, 0.11296, 0.11226, 0.11072, 0.10952, 0.10894, 0.10922, 0.11062,
-0.11104, 0.11094, 0.1096, 0.10176, 0.09816, 0.0966, 0.09808, 0.09892, 0.09846, 0.09582, 0.09622, 0.09136, 0.09084, 0.09386, 0.1009, 0.11436, 0.1364, 0.16612,
-0.19588, 0.22176, 0.25158, 0.2973, 0.3576, 0.42458, 0.48728, 0.54014, 0.58222, 0.61158, 0.63078, 0.64202, 0.649, 0.65386, 0.65722, 0.65956, 0.6604, 0.6634,
-0.66426, 0.66622, 0.6681, 0.6668, 0.66692, 0.6698, 0.67034, 0.66924, 0.67358, 0.675, 0.67642, 0.67738, 0.6769, 0.6779, 0.67934, 0.68306, 0.68826, 0.68872,
-0.68956, 0.6906, 0.69368, 0.69488, 0.69806, 0.69658, 0.6981, 0.69944, 0.69832, 
-//100
-0.0784, 0.08052, 0.08118, 0.08158, 0.08162, 0.08104, 0.08014, 0.0786, 0.07734, 0.07668, 0.07626, 0.07644, 0.07584, 0.07628, 0.07594, 0.07634, 0.07678, 0.07732,
-0.0767, 0.0765, 0.0766, 0.07484, 0.07468, 0.07636, 0.07664, 0.07716, 0.076, 0.07518, 0.07556, 0.07716, 0.08046, 0.08442, 0.089, 0.09556, 0.10226, 0.10962,
-0.11496, 0.1182, 0.1261, 0.14184, 0.17268, 0.21932, 0.2764, 0.33896, 0.40158, 0.4597, 0.50928, 0.5491, 0.57678, 0.59742, 0.61182, 0.62166, 0.62762, 0.63328,
-0.63586, 0.63542, 0.63862, 0.63982, 0.63918, 0.63908, 0.63716, 0.6376, 0.6404, 0.64398, 0.64676, 0.65052, 0.65362, 0.65496, 0.65594, 0.65988, 0.65994, 0.66206,
-0.66456, 0.66338, 0.6662, 0.6697, 0.67182, 0.66978, 0.66872, 0.66864, 0.67122, 
-//101
-0.04472, 0.04514, 0.04426, 0.04302, 0.0435, 0.04238, 0.04246, 0.04238, 0.04272, 0.04242, 0.0433, 0.0431, 0.0434, 0.0429, 0.04356, 0.04268, 0.04318, 0.0434,
-0.04324, 0.0427, 0.0426, 0.04288, 0.04282, 0.04306, 0.04348, 0.04396, 0.04404, 0.0441, 0.04472, 0.04606, 0.04628, 0.04696, 0.0473, 0.04856, 0.05058, 0.05298,
-0.05584, 0.0608, 0.06778, 0.07804, 0.0979, 0.12892, 0.17132, 0.21876, 0.26586, 0.30892, 0.34466, 0.3714, 0.38946, 0.40142, 0.409, 0.41504, 0.41738, 0.4198,
-0.4211, 0.42284, 0.42468, 0.42576, 0.42552, 0.42636, 0.4254, 0.42614, 0.4254, 0.42712, 0.42734, 0.42854, 0.43026, 0.43102, 0.43226, 0.4354, 0.43714, 0.43784,
-0.43964, 0.43898, 0.43932, 0.44148, 0.44282, 0.4429, 0.44402, 0.4417, 0.4444, 
-//102
-0.1304, 0.16182, 0.21742, 0.29934, 0.40844, 0.52004, 0.60494, 0.6472, 0.66094, 0.66204, 0.66516, 0.6664, 0.66674, 0.66632, 0.6656, 0.66476, 0.66584, 0.66906,
-0.67052, 0.66918, 0.6677, 0.65936, 0.65398, 0.65448, 0.65718, 0.66212, 0.66612, 0.66654, 0.66102, 0.65498, 0.65074, 0.65082, 0.65964, 0.6757, 0.69304, 0.70804,
-0.716, 0.72274, 0.72636, 0.72916, 0.72962, 0.73088, 0.73264, 0.72812, 0.72816, 0.72696, 0.72658, 0.72742, 0.72634, 0.72502, 0.7259, 0.72548, 0.72408, 0.72276,
-0.72566, 0.7239, 0.72474, 0.72412, 0.72294, 0.72356, 0.72086, 0.7211, 0.72046, 0.72316, 0.72288, 0.72274, 0.72192, 0.7215, 0.72118, 0.72456, 0.72574, 0.72338,
-0.7267, 0.72146, 0.72152, 0.72358, 0.7232, 0.72254, 0.72614, 0.72466, 0.72182, 
-//103
-0.13296, 0.16396, 0.22144, 0.29742, 0.38188, 0.45138, 0.48482, 0.4968, 0.49744, 0.49504, 0.4956, 0.49616, 0.49606, 0.49258, 0.49408, 0.49154, 0.49146, 0.4942,
-0.49516, 0.494, 0.49074, 0.48708, 0.48148, 0.4813, 0.48346, 0.48848, 0.493, 0.4937, 0.48984, 0.484, 0.48066, 0.47978, 0.48402, 0.49644, 0.51316, 0.52758,
-0.53696, 0.54366, 0.54556, 0.54588, 0.5444, 0.54504, 0.54368, 0.54306, 0.54382, 0.54212, 0.54226, 0.54258, 0.54096, 0.5393, 0.53866, 0.53932, 0.53852, 0.5407,
-0.53972, 0.53824, 0.53938, 0.53708, 0.53616, 0.53676, 0.5361, 0.53472, 0.53298, 0.53454, 0.5339, 0.53412, 0.53408, 0.53272, 0.53248, 0.53334, 0.53276, 0.53218,
-0.53214, 0.53074, 0.52946, 0.53094, 0.5296, 0.52864, 0.53156, 0.52728, 0.53114, 
-//104
-0.13966, 0.16774, 0.21346, 0.26742, 0.31704, 0.34768, 0.35914, 0.36164, 0.36154, 0.3601, 0.361, 0.36216, 0.35856, 0.35738, 0.35662, 0.3577, 0.35768, 0.35726,
-0.35766, 0.35928, 0.36056, 0.35822, 0.35746, 0.36012, 0.36058, 0.35962, 0.35916, 0.35832, 0.35842, 0.35812, 0.3583, 0.35778, 0.35994, 0.36094, 0.36904, 0.37772,
-0.38526, 0.39814, 0.4054, 0.4103, 0.41322, 0.41622, 0.41622, 0.41464, 0.41492, 0.4151, 0.4155, 0.41408, 0.4124, 0.41026, 0.4086, 0.40678, 0.40534, 0.40448,
-0.40474, 0.40178, 0.4029, 0.40006, 0.3975, 0.3971, 0.39664, 0.39162, 0.38982, 0.3926, 0.39398, 0.39134, 0.39162, 0.39078, 0.38878, 0.3907, 0.38892, 0.38744,
-0.38866, 0.38482, 0.38444, 0.38408, 0.38302, 0.3804, 0.3846, 0.3816, 0.38176, 
-//105
-0.12192, 0.1433, 0.17922, 0.2159, 0.24332, 0.2583, 0.26256, 0.26024, 0.25874, 0.25604, 0.25352, 0.2525, 0.25282, 0.25062, 0.24988, 0.24744, 0.24818, 0.24708,
-0.24774, 0.24782, 0.24438, 0.2413, 0.2369, 0.23564, 0.23766, 0.24122, 0.2457, 0.2434, 0.24194, 0.23908, 0.2364, 0.23692, 0.24012, 0.24782, 0.26108, 0.27108,
-0.27372, 0.28644, 0.28806, 0.28738, 0.2886, 0.28596, 0.28648, 0.28448, 0.28582, 0.28382, 0.28326, 0.28346, 0.28104, 0.28104, 0.28054, 0.27918, 0.27722, 0.27798,
-0.27784, 0.27514, 0.27576, 0.27358, 0.27316, 0.27188, 0.2731, 0.26944, 0.26742, 0.2716, 0.26972, 0.27022, 0.27036, 0.26964, 0.26994, 0.271, 0.26906, 0.269,
-0.26936, 0.2664, 0.26764, 0.26602, 0.26606, 0.26786, 0.26496, 0.26296, 0.26442, 
-//106
-0.11008, 0.1258, 0.148, 0.16646, 0.178, 0.18048, 0.18094, 0.17984, 0.17792, 0.17656, 0.17522, 0.17456, 0.17302, 0.1697, 0.16976, 0.16822, 0.16914, 0.16988,
-0.16982, 0.16936, 0.16784, 0.16364, 0.1603, 0.15942, 0.1615, 0.1634, 0.16694, 0.16632, 0.16484, 0.16072, 0.15852, 0.15846, 0.16188, 0.16922, 0.18044, 0.18844,
-0.19334, 0.20006, 0.20086, 0.20096, 0.19992, 0.19874, 0.1992, 0.19896, 0.19826, 0.19716, 0.19706, 0.19576, 0.19464, 0.1941, 0.19374, 0.19348, 0.19338, 0.1925,
-0.19162, 0.19028, 0.191, 0.1884, 0.18824, 0.18842, 0.18824, 0.18616, 0.18576, 0.18704, 0.18684, 0.18776, 0.18866, 0.18662, 0.18754, 0.18726, 0.18722, 0.18686,
-0.1871, 0.18692, 0.18804, 0.18738, 0.18632, 0.18546, 0.18754, 0.18492, 0.18598, 
-//107
-0.0911, 0.09778, 0.10728, 0.11428, 0.11578, 0.11598, 0.1163, 0.1133, 0.11232, 0.11054, 0.11032, 0.10944, 0.10928, 0.10626, 0.10506, 0.10488, 0.10512, 0.10494,
-0.10572, 0.10558, 0.10328, 0.10094, 0.09882, 0.0988, 0.09908, 0.09944, 0.0994, 0.09846, 0.09682, 0.09512, 0.0948, 0.09594, 0.09872, 0.10518, 0.11306, 0.12002,
-0.12278, 0.1289, 0.13034, 0.12968, 0.1289, 0.1293, 0.12954, 0.12988, 0.13028, 0.12896, 0.12886, 0.12724, 0.1259, 0.12512, 0.12542, 0.12478, 0.12504, 0.1248,
-0.1246, 0.12418, 0.12348, 0.12128, 0.12184, 0.12244, 0.12308, 0.12088, 0.1213, 0.12232, 0.12212, 0.1214, 0.12264, 0.12282, 0.12232, 0.12294, 0.124, 0.1232,
-0.1238, 0.12428, 0.12352, 0.12318, 0.12324, 0.12376, 0.1245, 0.1236, 0.1236, 
-//108
-0.06204, 0.06264, 0.06462, 0.06534, 0.06636, 0.06528, 0.06452, 0.06398, 0.0629, 0.06268, 0.0629, 0.0624, 0.0625, 0.06186, 0.06176, 0.06066, 0.06106, 0.06146,
-0.06, 0.05954, 0.05912, 0.05826, 0.05762, 0.05862, 0.05914, 0.05756, 0.05822, 0.05778, 0.05824, 0.05838, 0.05974, 0.0606, 0.06314, 0.06548, 0.0661, 0.066,
-0.06472, 0.06868, 0.06984, 0.07308, 0.07578, 0.07866, 0.08194, 0.08286, 0.08266, 0.08246, 0.08274, 0.0809, 0.0787, 0.07704, 0.0774, 0.07678, 0.07642, 0.0768,
-0.07676, 0.07642, 0.07574, 0.0726, 0.073, 0.07446, 0.07442, 0.07312, 0.07322, 0.0749, 0.07414, 0.07438, 0.07464, 0.07552, 0.07666, 0.07758, 0.07748, 0.07844,
-0.07834, 0.07714, 0.07644, 0.07784, 0.07868, 0.07926, 0.08042, 0.0797, 0.07932, 
-//109
-0.05034, 0.0495, 0.0481, 0.04858, 0.04714, 0.04566, 0.045, 0.0465, 0.04742, 0.04636, 0.0465, 0.04456, 0.04386, 0.04352, 0.0423, 0.04358, 0.0444, 0.04422, 0.044,
-0.0442, 0.044, 0.04284, 0.0413, 0.0411, 0.04238, 0.04302, 0.04422, 0.04396, 0.044, 0.04336, 0.04304, 0.04182, 0.04266, 0.044, 0.04584, 0.04584, 0.04052,
-0.04642, 0.04844, 0.05174, 0.05486, 0.0554, 0.05694, 0.0587, 0.05804, 0.05904, 0.05916, 0.06104, 0.06144, 0.06324, 0.06396, 0.0631, 0.06418, 0.06288, 0.06298,
-0.06406, 0.06482, 0.06392, 0.06628, 0.06842, 0.06752, 0.06544, 0.06828, 0.07126, 0.07092, 0.07062, 0.0732, 0.07258, 0.0747, 0.07782, 0.07868, 0.0799, 0.08166,
-0.08082, 0.08042, 0.08152, 0.08268, 0.08334, 0.08542, 0.08688, 0.08734, 
-//110
-0.12864, 0.16054, 0.21686, 0.30102, 0.40852, 0.51616, 0.59228, 0.62566, 0.6363, 0.63804, 0.6376, 0.63762, 0.6392, 0.63382, 0.63428, 0.63404, 0.63498, 0.64038,
-0.64322, 0.6427, 0.6372, 0.62818, 0.6198, 0.61812, 0.62488, 0.63162, 0.6386, 0.63814, 0.6295, 0.6202, 0.6151, 0.61522, 0.63054, 0.65618, 0.69008, 0.7193,
-0.73818, 0.75124, 0.75752, 0.75986, 0.76218, 0.7654, 0.76514, 0.76532, 0.7634, 0.7606, 0.76328, 0.76256, 0.7616, 0.76262, 0.76318, 0.76462, 0.76058, 0.76116,
-0.75756, 0.75692, 0.75702, 0.75726, 0.75544, 0.75792, 0.75896, 0.75528, 0.75622, 0.75834, 0.75696, 0.75798, 0.7595, 0.75758, 0.75772, 0.75992, 0.76152, 0.75886,
-0.76194, 0.76088, 0.75862, 0.75864, 0.761, 0.76004, 0.76118, 0.75424, 0.75928, 
-//111
-0.1362, 0.16998, 0.2274, 0.30554, 0.38842, 0.45312, 0.48596, 0.4966, 0.49636, 0.4922, 0.49236, 0.49062, 0.48842, 0.4865, 0.48526, 0.48352, 0.48646, 0.48814,
-0.49134, 0.48928, 0.485, 0.47708, 0.47082, 0.46878, 0.47128, 0.47846, 0.48332, 0.48228, 0.4758, 0.46694, 0.46154, 0.46076, 0.4697, 0.48812, 0.51752, 0.5458,
-0.56232, 0.57782, 0.58262, 0.58326, 0.58616, 0.58592, 0.58642, 0.58744, 0.58728, 0.58466, 0.58374, 0.58286, 0.58154, 0.58186, 0.58372, 0.5832, 0.58074, 0.58002,
-0.57766, 0.5773, 0.57828, 0.57672, 0.57696, 0.57824, 0.57596, 0.57318, 0.57282, 0.57244, 0.57276, 0.57214, 0.57004, 0.57196, 0.57038, 0.57168, 0.57086, 0.5705,
-0.57012, 0.5689, 0.56696, 0.56722, 0.56576, 0.5654, 0.56774, 0.56584, 0.56694, 
-//112
-0.1423, 0.16856, 0.21282, 0.2641, 0.30808, 0.3356, 0.34732, 0.3486, 0.35016, 0.34842, 0.34658, 0.3459, 0.34564, 0.34346, 0.3448, 0.34648, 0.3479, 0.34922,
-0.34862, 0.34858, 0.34978, 0.34762, 0.34818, 0.34916, 0.351, 0.35062, 0.35006, 0.34844, 0.34614, 0.34638, 0.34606, 0.34714, 0.352, 0.35754, 0.3672, 0.37992,
-0.39238, 0.41388, 0.42936, 0.43992, 0.44752, 0.45338, 0.45474, 0.45552, 0.45536, 0.4548, 0.45568, 0.45486, 0.45326, 0.45104, 0.45178, 0.44884, 0.4465, 0.44646,
-0.44538, 0.4434, 0.44296, 0.44132, 0.44024, 0.43978, 0.43776, 0.43472, 0.43378, 0.43404, 0.43352, 0.43334, 0.43252, 0.4319, 0.43134, 0.43052, 0.4283, 0.42794,
-0.42924, 0.42606, 0.42624, 0.42498, 0.42458, 0.42256, 0.42346, 0.42278, 0.42176, 
-//113
-0.12584, 0.14772, 0.1807, 0.21368, 0.24178, 0.25472, 0.25618, 0.25512, 0.25266, 0.24756, 0.2453, 0.24378, 0.24212, 0.23996, 0.23892, 0.23852, 0.23912, 0.24078,
-0.24096, 0.2393, 0.23516, 0.22872, 0.22416, 0.22346, 0.22514, 0.22928, 0.23462, 0.2337, 0.22968, 0.22524, 0.22146, 0.22176, 0.228, 0.24122, 0.26194, 0.28512,
-0.29892, 0.31142, 0.3165, 0.31776, 0.31684, 0.31772, 0.3182, 0.31706, 0.31666, 0.31508, 0.31484, 0.31464, 0.31284, 0.31196, 0.3126, 0.3102, 0.30884, 0.31008,
-0.30722, 0.3057, 0.3071, 0.30528, 0.30406, 0.3053, 0.30378, 0.3015, 0.3027, 0.30194, 0.30146, 0.30154, 0.29986, 0.29984, 0.30066, 0.2997, 0.30226, 0.30088,
-0.30024, 0.29854, 0.2969, 0.2967, 0.2965, 0.29614, 0.2948, 0.29792, 0.29634, 
-//114
-0.10092, 0.1135, 0.13142, 0.14692, 0.15648, 0.16044, 0.16224, 0.16112, 0.15836, 0.15802, 0.15694, 0.15714, 0.15944, 0.1584, 0.15678, 0.15564, 0.15506, 0.15356,
-0.15406, 0.15402, 0.15386, 0.15418, 0.15414, 0.15244, 0.15132, 0.15164, 0.15134, 0.1504, 0.15136, 0.15128, 0.15226, 0.15408, 0.15328, 0.15698, 0.16282, 0.1708,
-0.1796, 0.19446, 0.2033, 0.21098, 0.2146, 0.21806, 0.2202, 0.21852, 0.21988, 0.21938, 0.21806, 0.2177, 0.21802, 0.21526, 0.2161, 0.21474, 0.21318, 0.21328,
-0.2131, 0.21174, 0.21274, 0.21004, 0.21036, 0.21028, 0.20902, 0.20624, 0.20616, 0.20684, 0.20658, 0.20746, 0.20822, 0.20808, 0.20898, 0.20812, 0.2069, 0.2058,
-0.20624, 0.2043, 0.2055, 0.20424, 0.20512, 0.20558, 0.2039, 0.202, 0.20288, 
-//115
-0.08942, 0.09478, 0.10106, 0.10588, 0.107, 0.10726, 0.10792, 0.1058, 0.10482, 0.10078, 0.09928, 0.0987, 0.0954, 0.09708, 0.09614, 0.09564, 0.0959, 0.09578,
-0.0954, 0.09324, 0.09144, 0.0891, 0.08918, 0.08824, 0.0908, 0.09076, 0.08924, 0.0871, 0.08534, 0.08584, 0.08682, 0.08966, 0.09362, 0.0994, 0.1083, 0.11594,
-0.12214, 0.12872, 0.13208, 0.13436, 0.13818, 0.14154, 0.14354, 0.14434, 0.1427, 0.14204, 0.14066, 0.14014, 0.13924, 0.13818, 0.13806, 0.13672, 0.13588, 0.13506,
-0.13388, 0.1332, 0.13298, 0.13188, 0.13282, 0.13308, 0.13232, 0.13014, 0.1315, 0.13026, 0.12994, 0.13128, 0.13102, 0.1316, 0.13202, 0.13264, 0.13264, 0.13182,
-0.13194, 0.13124, 0.13194, 0.13158, 0.13288, 0.13232, 0.13256, 0.13218, 0.13036, 
-//116
-0.06008, 0.06, 0.0595, 0.05924, 0.05822, 0.05814, 0.0586, 0.05804, 0.05634, 0.05608, 0.05608, 0.05734, 0.05354, 0.05314, 0.05322, 0.0565, 0.06066, 0.0587,
-0.05444, 0.05264, 0.05238, 0.05952, 0.06162, 0.05542, 0.05592, 0.05634, 0.05528, 0.05318, 0.05486, 0.05622, 0.0588, 0.06088, 0.06236, 0.06442, 0.06476, 0.06564,
-0.06242, 0.06808, 0.06954, 0.07252, 0.07904, 0.08738, 0.09452, 0.10024, 0.10258, 0.10158, 0.09946, 0.09832, 0.09774, 0.09694, 0.09566, 0.09484, 0.09476,
-0.09364, 0.0913, 0.08962, 0.08882, 0.08624, 0.08844, 0.08946, 0.08996, 0.08638, 0.0867, 0.08768, 0.08738, 0.0867, 0.08852, 0.08728, 0.0886, 0.08984, 0.09004,
-0.08992, 0.08882, 0.08814, 0.09684, 0.09066, 0.0875, 0.09306, 0.08492, 0.08844, 0.09474, 
-//117
-0.0453, 0.04456, 0.04448, 0.04334, 0.04344, 0.04208, 0.04252, 0.04202, 0.0415, 0.04286, 0.04386, 0.04318, 0.04312, 0.0416, 0.04108, 0.04018, 0.0414, 0.04262,
-0.04222, 0.04298, 0.0417, 0.04014, 0.0377, 0.03884, 0.04042, 0.04062, 0.04026, 0.04126, 0.04004, 0.03904, 0.03956, 0.03912, 0.04106, 0.04298, 0.04442, 0.04412,
-0.04174, 0.04536, 0.04762, 0.05254, 0.05858, 0.06554, 0.07192, 0.07426, 0.0771, 0.079, 0.07828, 0.07854, 0.07858, 0.07578, 0.07498, 0.07394, 0.0735, 0.07316,
-0.07374, 0.07386, 0.07266, 0.0713, 0.07224, 0.07268, 0.07246, 0.07156, 0.07188, 0.07434, 0.07322, 0.07494, 0.07448, 0.07508, 0.07588, 0.07772, 0.07838, 0.07888,
-0.07876, 0.07898, 0.07934, 0.08008, 0.08138, 0.08204, 0.0821, 0.0817, 0.08248, 
-//118
-0.13964, 0.17062, 0.22568, 0.29794, 0.36686, 0.41054, 0.42812, 0.429, 0.42466, 0.42124, 0.41822, 0.41682, 0.41752, 0.41484, 0.41188, 0.41158, 0.41436, 0.41648,
-0.42088, 0.42206, 0.41578, 0.40606, 0.39554, 0.39358, 0.39928, 0.40938, 0.41942, 0.4205, 0.41404, 0.4006, 0.39202, 0.39264, 0.4044, 0.4366, 0.48794, 0.54636,
-0.59816, 0.6366, 0.656, 0.66882, 0.67508, 0.67866, 0.68342, 0.68384, 0.68498, 0.68542, 0.68672, 0.68456, 0.68462, 0.68422, 0.6853, 0.68502, 0.68364, 0.68452,
-0.68554, 0.6838, 0.6848, 0.68564, 0.68538, 0.68658, 0.68334, 0.68234, 0.67944, 0.68556, 0.6833, 0.68358, 0.68314, 0.68444, 0.68594, 0.68812, 0.68798, 0.68648,
-0.69072, 0.68628, 0.68576, 0.68554, 0.69056, 0.6884, 0.68848, 0.68496, 0.68552, 
-//119
-0.12152, 0.147, 0.18288, 0.22582, 0.26094, 0.28056, 0.28906, 0.2896, 0.29162, 0.29082, 0.29104, 0.28878, 0.289, 0.2898, 0.29018, 0.29196, 0.2937, 0.29522,
-0.29722, 0.29688, 0.29618, 0.29648, 0.29764, 0.29868, 0.3003, 0.30128, 0.29906, 0.29894, 0.29728, 0.29746, 0.3002, 0.3008, 0.30746, 0.31526, 0.3288, 0.34688,
-0.3689, 0.39688, 0.42908, 0.45702, 0.47958, 0.48598, 0.48872, 0.49356, 0.5019, 0.50188, 0.50354, 0.50374, 0.5013, 0.5005, 0.50156, 0.49938, 0.49864, 0.49904,
-0.49822, 0.49776, 0.4978, 0.49562, 0.49446, 0.49512, 0.4934, 0.49212, 0.49134, 0.49068, 0.48936, 0.4867, 0.48754, 0.48646, 0.48614, 0.48708, 0.48644, 0.48574,
-0.48562, 0.48238, 0.48186, 0.48146, 0.48328, 0.48408, 0.4837, 0.48172, 0.4802, 
-//120
-0.12108, 0.14074, 0.16786, 0.1942, 0.21268, 0.21806, 0.21872, 0.2147, 0.21068, 0.20634, 0.20284, 0.20126, 0.20056, 0.19884, 0.19672, 0.19596, 0.19692, 0.1973,
-0.19882, 0.19766, 0.19322, 0.18838, 0.18492, 0.18206, 0.18478, 0.18972, 0.19592, 0.19722, 0.1933, 0.1877, 0.18548, 0.18636, 0.19292, 0.21026, 0.23904, 0.2758,
-0.30964, 0.33636, 0.34954, 0.35654, 0.36012, 0.36326, 0.36476, 0.36438, 0.3634, 0.36348, 0.3612, 0.36082, 0.36062, 0.35872, 0.35928, 0.35884, 0.35634, 0.35544,
-0.35512, 0.35324, 0.353, 0.35356, 0.35252, 0.35196, 0.35168, 0.34754, 0.34644, 0.3487, 0.3471, 0.34498, 0.34466, 0.34304, 0.34172, 0.34514, 0.34362, 0.34192,
-0.34396, 0.34048, 0.34144, 0.34144, 0.34086, 0.33806, 0.3392, 0.3364, 0.33532, 
-//121
-0.0924, 0.10318, 0.11514, 0.1256, 0.13138, 0.13236, 0.13266, 0.13124, 0.1296, 0.13028, 0.1292, 0.12946, 0.13044, 0.12908, 0.12856, 0.12766, 0.12594, 0.12704,
-0.12698, 0.12758, 0.1288, 0.12952, 0.12724, 0.12634, 0.12674, 0.12548, 0.12634, 0.12678, 0.1268, 0.12754, 0.12856, 0.12894, 0.13056, 0.13582, 0.14386, 0.15704,
-0.1721, 0.19476, 0.21448, 0.2319, 0.24464, 0.25438, 0.2604, 0.26234, 0.2638, 0.26358, 0.26328, 0.26376, 0.26282, 0.26114, 0.26122, 0.26004, 0.25842, 0.25726,
-0.2571, 0.2548, 0.25558, 0.25408, 0.25386, 0.25384, 0.25264, 0.25008, 0.24972, 0.25044, 0.24966, 0.24956, 0.24976, 0.24824, 0.24882, 0.24864, 0.24756, 0.24722,
-0.2465, 0.24708, 0.24938, 0.24642, 0.24438, 0.24328, 0.24392, 0.24238, 0.24256, 
-//122
-0.0748, 0.07776, 0.08216, 0.08424, 0.08516, 0.08296, 0.07992, 0.07816, 0.07816, 0.07698, 0.07666, 0.07794, 0.07672, 0.07528, 0.07388, 0.07324, 0.07302, 0.07514,
-0.07466, 0.0745, 0.07336, 0.07104, 0.0689, 0.06848, 0.07052, 0.07012, 0.07134, 0.06972, 0.06864, 0.07034, 0.07112, 0.07224, 0.07812, 0.08358, 0.0923, 0.10046,
-0.1001, 0.1097, 0.11392, 0.12538, 0.14248, 0.15718, 0.1688, 0.17508, 0.17846, 0.1788, 0.17836, 0.17688, 0.17634, 0.17622, 0.17696, 0.17576, 0.17682, 0.17468,
-0.17268, 0.17202, 0.17156, 0.16996, 0.17068, 0.17204, 0.17224, 0.17038, 0.1688, 0.16944, 0.17116, 0.1712, 0.17086, 0.1711, 0.16998, 0.16976, 0.17226, 0.17124,
-0.17242, 0.17374, 0.17304, 0.17174, 0.17002, 0.16998, 0.17124, 0.17102, 0.17254, 
-//123
-0.04794, 0.04514, 0.04354, 0.04308, 0.04326, 0.04206, 0.04222, 0.0425, 0.04358, 0.04304, 0.04246, 0.04316, 0.0432, 0.04216, 0.0407, 0.0416, 0.04324, 0.04276,
-0.0441, 0.04326, 0.04286, 0.04284, 0.04022, 0.0423, 0.04398, 0.04416, 0.0441, 0.04442, 0.04446, 0.04224, 0.04282, 0.0445, 0.04552, 0.04846, 0.04994, 0.05076,
-0.05174, 0.05688, 0.06338, 0.06956, 0.08078, 0.0953, 0.1064, 0.1149, 0.1193, 0.12114, 0.12078, 0.1193, 0.11882, 0.11714, 0.11882, 0.11588, 0.11424, 0.11472,
-0.11156, 0.11058, 0.11036, 0.10942, 0.10864, 0.1098, 0.10898, 0.10642, 0.10478, 0.1072, 0.1074, 0.10646, 0.10698, 0.10814, 0.10734, 0.10762, 0.1078, 0.10748,
-0.10894, 0.10854, 0.10782, 0.10824, 0.10874, 0.10786, 0.10852, 0.10688, 0.10782, 
-//124
-0.12426, 0.15298, 0.19866, 0.25622, 0.30888, 0.33918, 0.34818, 0.34848, 0.34304, 0.33828, 0.3357, 0.33448, 0.33406, 0.32898, 0.32632, 0.32584, 0.32736, 0.33084,
-0.33512, 0.3343, 0.3282, 0.31656, 0.30772, 0.30578, 0.31264, 0.3234, 0.33416, 0.33576, 0.3284, 0.31748, 0.3102, 0.3111, 0.327, 0.36246, 0.41838, 0.489, 0.55488,
-0.61714, 0.6599, 0.68528, 0.69988, 0.71096, 0.7196, 0.72296, 0.7276, 0.7273, 0.72748, 0.72842, 0.7278, 0.72742, 0.72964, 0.72876, 0.72848, 0.72986, 0.7292,
-0.72698, 0.72632, 0.7242, 0.72344, 0.72478, 0.72612, 0.7248, 0.72432, 0.72942, 0.72946, 0.72916, 0.73102, 0.72872, 0.72896, 0.73186, 0.73448, 0.73606, 0.73568,
-0.73478, 0.73586, 0.73332, 0.73342, 0.73402, 0.73508, 0.73116, 0.73382, 
-//125
-0.1184, 0.14378, 0.17892, 0.21694, 0.2478, 0.2624, 0.26304, 0.26016, 0.25354, 0.24906, 0.24614, 0.24332, 0.24046, 0.23738, 0.23616, 0.23702, 0.23778, 0.2396,
-0.24166, 0.24158, 0.23446, 0.22422, 0.21896, 0.21678, 0.22078, 0.2281, 0.23304, 0.23218, 0.22352, 0.21574, 0.21066, 0.21254, 0.22572, 0.2536, 0.29978, 0.35834,
-0.41644, 0.47014, 0.5023, 0.52298, 0.5327, 0.53734, 0.54194, 0.542, 0.54452, 0.54352, 0.54202, 0.5446, 0.54438, 0.54312, 0.54302, 0.5407, 0.53876, 0.53924,
-0.5393, 0.53836, 0.53852, 0.53828, 0.53644, 0.5368, 0.53472, 0.5319, 0.53138, 0.5337, 0.53402, 0.53356, 0.53406, 0.53078, 0.53166, 0.53316, 0.53298, 0.53124,
-0.53252, 0.5302, 0.52788, 0.53062, 0.52846, 0.5285, 0.53134, 0.5267, 0.52642, 
-//126
-0.11304, 0.13072, 0.15436, 0.17132, 0.18206, 0.18256, 0.18092, 0.17714, 0.17236, 0.16822, 0.16528, 0.164, 0.1627, 0.15796, 0.15768, 0.1575, 0.15768, 0.15932,
-0.16072, 0.15884, 0.15498, 0.14914, 0.14488, 0.14392, 0.14714, 0.14966, 0.15288, 0.15192, 0.1475, 0.1431, 0.1417, 0.1423, 0.1504, 0.16738, 0.19588, 0.237,
-0.28268, 0.328, 0.35626, 0.37352, 0.38166, 0.38876, 0.39316, 0.3935, 0.3964, 0.39588, 0.3957, 0.39636, 0.39356, 0.39236, 0.3917, 0.38876, 0.38874, 0.38906,
-0.38658, 0.38552, 0.38554, 0.38232, 0.38116, 0.38252, 0.38058, 0.37748, 0.37508, 0.37628, 0.3766, 0.37796, 0.3754, 0.37542, 0.37436, 0.37316, 0.37326, 0.37224,
-0.37386, 0.37268, 0.3708, 0.36916, 0.36934, 0.36874, 0.36842, 0.36544, 0.36604, 
-//127
-0.08202, 0.08822, 0.09542, 0.10132, 0.10362, 0.10412, 0.10396, 0.10132, 0.09894, 0.09952, 0.09962, 0.09904, 0.09858, 0.09842, 0.09614, 0.09464, 0.09426,
-0.09418, 0.09606, 0.09516, 0.09482, 0.09482, 0.09384, 0.09306, 0.0932, 0.09502, 0.09684, 0.09792, 0.09878, 0.09928, 0.1007, 0.10184, 0.10434, 0.10918, 0.11968,
-0.13276, 0.1495, 0.17698, 0.20338, 0.22726, 0.2502, 0.26652, 0.2782, 0.2871, 0.29044, 0.29232, 0.29358, 0.2917, 0.2904, 0.29042, 0.29044, 0.29024, 0.28984,
-0.28746, 0.28574, 0.28278, 0.28192, 0.27926, 0.2807, 0.28018, 0.27918, 0.27868, 0.27732, 0.27612, 0.2762, 0.27422, 0.27464, 0.27428, 0.27284, 0.27434, 0.27364,
-0.27218, 0.27278, 0.2706, 0.27164, 0.27132, 0.26956, 0.26938, 0.26792, 0.26684, 0.26804, 
-//128
-0.06116, 0.06032, 0.0612, 0.06332, 0.06388, 0.06326, 0.06188, 0.06058, 0.05988, 0.05908, 0.05964, 0.05976, 0.06038, 0.06026, 0.06136, 0.05996, 0.0598, 0.05962,
-0.05942, 0.05928, 0.05884, 0.05864, 0.05892, 0.06046, 0.06156, 0.0606, 0.06044, 0.0598, 0.06114, 0.06354, 0.06654, 0.06966, 0.07388, 0.07778, 0.08438, 0.09042,
-0.09138, 0.09696, 0.0999, 0.10902, 0.12756, 0.1522, 0.17644, 0.19704, 0.21054, 0.21838, 0.22174, 0.22436, 0.22486, 0.22424, 0.225, 0.22354, 0.22256, 0.22062,
-0.22, 0.2194, 0.2191, 0.2182, 0.21892, 0.2211, 0.21932, 0.21628, 0.21606, 0.2177, 0.21622, 0.21776, 0.21932, 0.21858, 0.21866, 0.21776, 0.21762, 0.21718,
-0.21724, 0.21754, 0.21916, 0.21944, 0.2189, 0.21656, 0.21808, 0.21672, 0.21568, 
-//129
-0.13142, 0.15918, 0.20272, 0.25292, 0.29308, 0.31594, 0.32174, 0.31688, 0.31252, 0.30434, 0.30048, 0.29848, 0.29594, 0.2941, 0.29268, 0.2929, 0.29364, 0.29598,
-0.29912, 0.29636, 0.29118, 0.28206, 0.27574, 0.2741, 0.2793, 0.2907, 0.30182, 0.3053, 0.29992, 0.28992, 0.28476, 0.28722, 0.30362, 0.3392, 0.39958, 0.47428,
-0.5538, 0.62722, 0.6802, 0.72016, 0.74624, 0.76492, 0.78104, 0.7903, 0.79468, 0.79946, 0.8027, 0.80344, 0.8032, 0.80294, 0.80568, 0.80768, 0.80744, 0.8075,
-0.8089, 0.80882, 0.80792, 0.80612, 0.8067, 0.80792, 0.8091, 0.81016, 0.80884, 0.80904, 0.81262, 0.8107, 0.81354, 0.81288, 0.81358, 0.81618, 0.8163, 0.82084,
-0.82824, 0.83198, 0.83676, 0.83378, 0.83686, 0.8394, 0.83854, 0.83874, 0.83912, 
-//130
-0.11852, 0.13672, 0.16564, 0.19636, 0.21604, 0.22264, 0.22316, 0.21824, 0.21224, 0.20674, 0.20352, 0.20366, 0.20394, 0.20138, 0.20018, 0.19768, 0.1989, 0.20014,
-0.2024, 0.20106, 0.1988, 0.19226, 0.18692, 0.18412, 0.18798, 0.19382, 0.2019, 0.2033, 0.19994, 0.19398, 0.19138, 0.19118, 0.20086, 0.22242, 0.26402, 0.325,
-0.39376, 0.4642, 0.51182, 0.54484, 0.56834, 0.58474, 0.59834, 0.60662, 0.61306, 0.6135, 0.61704, 0.61746, 0.61666, 0.6153, 0.61686, 0.61602, 0.61526, 0.61608,
-0.61536, 0.6144, 0.61578, 0.61432, 0.61624, 0.61638, 0.61232, 0.61094, 0.61064, 0.6131, 0.61286, 0.61304, 0.61372, 0.6132, 0.61256, 0.61224, 0.6141, 0.61338,
-0.61624, 0.61536, 0.61474, 0.61492, 0.61106, 0.61304, 0.61404, 0.6118, 0.61242, 
-//131
-0.1067, 0.11654, 0.13572, 0.14778, 0.15368, 0.15466, 0.15434, 0.14866, 0.14396, 0.14118, 0.13878, 0.13864, 0.13944, 0.1373, 0.13708, 0.13434, 0.13494, 0.13526,
-0.13786, 0.13784, 0.13468, 0.13098, 0.1278, 0.1253, 0.12746, 0.13254, 0.13598, 0.1363, 0.13356, 0.12902, 0.12878, 0.1308, 0.13674, 0.15242, 0.1808, 0.21984,
-0.2672, 0.3221, 0.35998, 0.38862, 0.40956, 0.42624, 0.43888, 0.44998, 0.45736, 0.46072, 0.46332, 0.46622, 0.46768, 0.46942, 0.47182, 0.4713, 0.4721, 0.47374,
-0.47498, 0.47398, 0.47638, 0.47642, 0.47812, 0.48072, 0.4813, 0.47824, 0.47838, 0.48232, 0.48254, 0.48576, 0.48712, 0.48706, 0.48794, 0.4912, 0.4913, 0.49278,
-0.49552, 0.49464, 0.49492, 0.49774, 0.49862, 0.4997, 0.50118, 0.49888, 0.5004, 
-//132
-0.07714, 0.07892, 0.08062, 0.0821, 0.08186, 0.08266, 0.08224, 0.0825, 0.08366, 0.08188, 0.08062, 0.07892, 0.07914, 0.07866, 0.07944, 0.07938, 0.0791, 0.07882,
-0.07902, 0.07744, 0.07806, 0.07768, 0.07858, 0.07874, 0.08022, 0.08284, 0.08374, 0.0845, 0.08586, 0.08692, 0.08876, 0.09064, 0.09448, 0.09882, 0.10718, 0.12228,
-0.13976, 0.17282, 0.20676, 0.24136, 0.27426, 0.3035, 0.32492, 0.33984, 0.35032, 0.35718, 0.36128, 0.36318, 0.36208, 0.35984, 0.36042, 0.3595, 0.35832, 0.35648,
-0.35686, 0.35512, 0.3533, 0.35132, 0.35006, 0.35, 0.34944, 0.34718, 0.34562, 0.34498, 0.34484, 0.34436, 0.34352, 0.34408, 0.34216, 0.3426, 0.34256, 0.34008,
-0.34254, 0.33918, 0.33968, 0.33896, 0.3386, 0.3365, 0.33844, 0.33544, 0.33648, 
-//133
-0.04814, 0.05008, 0.04986, 0.04914, 0.049, 0.0509, 0.05036, 0.04802, 0.04812, 0.05014, 0.05108, 0.0454, 0.04902, 0.05262, 0.05142, 0.04912, 0.04878, 0.04856,
-0.04886, 0.05012, 0.05162, 0.05192, 0.05174, 0.0527, 0.05118, 0.0518, 0.05288, 0.05362, 0.05332, 0.05726, 0.0564, 0.05414, 0.0611, 0.05472, 0.06526, 0.07326,
-0.08152, 0.09704, 0.12132, 0.14496, 0.16876, 0.19066, 0.21034, 0.22568, 0.2402, 0.24046, 0.24352, 0.24882, 0.24932, 0.24866, 0.25318, 0.25122, 0.25114, 0.25426,
-0.25024, 0.25352, 0.2457, 0.24782, 0.25162, 0.24956, 0.2502, 0.24772, 0.24566, 0.2513, 0.25036, 0.25098, 0.25072, 0.2511, 0.2499, 0.25336, 0.25294, 0.252,
-0.25322, 0.25348, 0.25554, 0.25674, 0.25462, 0.25522, 0.25724, 0.25214, 0.2571, 
-//134
-0.10986, 0.12808, 0.14972, 0.17174, 0.18494, 0.18752, 0.1858, 0.18182, 0.17712, 0.17146, 0.16994, 0.16776, 0.1682, 0.16594, 0.1648, 0.16394, 0.16606, 0.16738,
-0.16894, 0.16756, 0.16396, 0.1578, 0.15284, 0.15198, 0.1554, 0.16058, 0.16514, 0.16408, 0.15968, 0.15314, 0.15026, 0.15374, 0.16414, 0.1878, 0.23136, 0.29498,
-0.37092, 0.45474, 0.52208, 0.57336, 0.6104, 0.6405, 0.6615, 0.67434, 0.68578, 0.69178, 0.6959, 0.69876, 0.69944, 0.69978, 0.7025, 0.70448, 0.70414, 0.70432,
-0.70602, 0.70564, 0.70736, 0.70532, 0.70624, 0.70584, 0.70616, 0.70592, 0.70526, 0.70778, 0.70884, 0.71028, 0.71038, 0.7098, 0.7103, 0.71388, 0.71518, 0.7152,
-0.7155, 0.71314, 0.7146, 0.71596, 0.71672, 0.71438, 0.7199, 0.71578, 0.71572, 
-//135
-0.08994, 0.10032, 0.11376, 0.12112, 0.12394, 0.12332, 0.12022, 0.11584, 0.1123, 0.10794, 0.10764, 0.1078, 0.108, 0.10614, 0.10488, 0.10382, 0.10434, 0.10438,
-0.10614, 0.10688, 0.10402, 0.10044, 0.09754, 0.09632, 0.09754, 0.09912, 0.10144, 0.09964, 0.09696, 0.09526, 0.09566, 0.09812, 0.10458, 0.11856, 0.14518,
-0.18968, 0.24698, 0.31512, 0.37292, 0.41938, 0.4498, 0.47278, 0.4901, 0.50172, 0.50976, 0.51434, 0.5173, 0.5182, 0.51966, 0.51882, 0.52036, 0.52024, 0.52166,
-0.52208, 0.5213, 0.52274, 0.52362, 0.5224, 0.52254, 0.52526, 0.52392, 0.52286, 0.52098, 0.52524, 0.52502, 0.525, 0.5258, 0.52698, 0.52568, 0.52828, 0.52976,
-0.52918, 0.53102, 0.52964, 0.53146, 0.53044, 0.5325, 0.53338, 0.53356, 0.5315, 0.5353, 
-//136
-0.06562, 0.06594, 0.06684, 0.06742, 0.06702, 0.06504, 0.06408, 0.06288, 0.0627, 0.06326, 0.06454, 0.06546, 0.06632, 0.06474, 0.06446, 0.06372, 0.06464, 0.06458,
-0.06552, 0.06532, 0.06482, 0.06626, 0.06588, 0.06648, 0.06802, 0.0686, 0.06912, 0.0689, 0.07014, 0.07252, 0.07572, 0.07866, 0.0841, 0.09136, 0.10102, 0.116,
-0.13364, 0.16502, 0.1976, 0.23228, 0.26684, 0.30214, 0.33532, 0.36452, 0.38788, 0.40256, 0.41388, 0.42146, 0.42672, 0.42928, 0.4333, 0.43674, 0.4373, 0.43852,
-0.43956, 0.4418, 0.44382, 0.44426, 0.44682, 0.44796, 0.44978, 0.44886, 0.44906, 0.45384, 0.45696, 0.46012, 0.46112, 0.46376, 0.46542, 0.4676, 0.46992, 0.4722,
-0.47692, 0.47692, 0.47662, 0.47788, 0.4808, 0.48124, 0.48494, 0.48566, 0.48896, 
-//137
-0.10176, 0.11774, 0.13764, 0.15302, 0.16028, 0.1608, 0.15858, 0.15358, 0.14996, 0.14674, 0.14508, 0.1438, 0.14242, 0.141, 0.13898, 0.13792, 0.13978, 0.1429,
-0.1468, 0.14586, 0.14062, 0.13426, 0.12862, 0.12722, 0.1295, 0.1384, 0.14486, 0.14362, 0.13816, 0.13074, 0.12916, 0.13216, 0.14258, 0.16832, 0.21636, 0.28428,
-0.36424, 0.45666, 0.5325, 0.59418, 0.64104, 0.67712, 0.70644, 0.7259, 0.74106, 0.75028, 0.75506, 0.76008, 0.76368, 0.76414, 0.76692, 0.76936, 0.7709, 0.77168,
-0.77412, 0.77604, 0.77734, 0.77558, 0.77396, 0.77586, 0.77686, 0.77886, 0.78088, 0.7831, 0.7819, 0.78288, 0.78376, 0.78584, 0.78628, 0.79194, 0.79202, 0.79204,
-0.79186, 0.7907, 0.79202, 0.79318, 0.79322, 0.79676, 0.79898, 0.7965, 0.79798, 
-//138
-0.08222, 0.08416, 0.08716, 0.08778, 0.08806, 0.0873, 0.08552, 0.0839, 0.08386, 0.08162, 0.08178, 0.0823, 0.08282, 0.08118, 0.0812, 0.08142, 0.0818, 0.08206,
-0.08162, 0.08202, 0.08248, 0.08052, 0.08124, 0.08216, 0.08498, 0.08778, 0.0902, 0.0913, 0.09208, 0.09258, 0.09432, 0.09876, 0.10502, 0.11454, 0.1342, 0.16632,
-0.21032, 0.27274, 0.33022, 0.38116, 0.42542, 0.46866, 0.50868, 0.54252, 0.5713, 0.5906, 0.60514, 0.61258, 0.61802, 0.62278, 0.6244, 0.62766, 0.63008, 0.63308,
-0.6357, 0.63716, 0.6409, 0.64016, 0.64058, 0.64292, 0.6425, 0.64432, 0.64486, 0.64898, 0.64928, 0.65274, 0.65574, 0.65648, 0.65888, 0.66024, 0.66316, 0.6635,
-0.669, 0.6674, 0.6695, 0.66998, 0.67246, 0.67268, 0.67762, 0.67498, 0.67674, 
-//139
-0.04944, 0.04898, 0.049, 0.04834, 0.04878, 0.04816, 0.04954, 0.04966, 0.04962, 0.05204, 0.0515, 0.05118, 0.05154, 0.05168, 0.05314, 0.05326, 0.054, 0.05428,
-0.05344, 0.053, 0.0529, 0.05358, 0.05508, 0.05766, 0.05902, 0.06, 0.05958, 0.05812, 0.05958, 0.06176, 0.06436, 0.06826, 0.074, 0.0795, 0.0878, 0.1011, 0.11924,
-0.14988, 0.18174, 0.22038, 0.26198, 0.30704, 0.34908, 0.38534, 0.41508, 0.43612, 0.45078, 0.45918, 0.46716, 0.47444, 0.47886, 0.48162, 0.48668, 0.49038,
-0.49152, 0.49386, 0.49594, 0.49796, 0.49958, 0.50208, 0.50298, 0.50312, 0.50582, 0.50986, 0.51292, 0.5141, 0.51792, 0.51962, 0.52062, 0.52538, 0.52786, 0.5299,
-0.53288, 0.53502, 0.53754, 0.53894, 0.542, 0.54344, 0.5449, 0.548, 0.54914, 
-//140
-0.14816, 0.18142, 0.24082, 0.32328, 0.42398, 0.51384, 0.5685, 0.58982, 0.59512, 0.5948, 0.59572, 0.59528, 0.5952, 0.59434, 0.59586, 0.59542, 0.59634, 0.5996,
-0.60194, 0.60136, 0.59884, 0.59382, 0.58954, 0.58918, 0.59584, 0.60538, 0.6171, 0.6242, 0.6213, 0.6159, 0.61362, 0.61302, 0.622, 0.6434, 0.67182, 0.70036,
-0.7227, 0.73448, 0.74132, 0.7467, 0.7488, 0.75026, 0.75376, 0.75328, 0.75352, 0.75612, 0.75652, 0.75582, 0.75676, 0.75842, 0.7578, 0.75862, 0.7588, 0.7606,
-0.76, 0.75968, 0.7608, 0.76006, 0.7595, 0.76026, 0.7581, 0.75998, 0.75832, 0.76026, 0.76216, 0.76192, 0.76242, 0.7624, 0.7656, 0.76468, 0.76804, 0.76802,
-0.76922, 0.7683, 0.77196, 0.77128, 0.77194, 0.77088, 0.77064, 0.7697, 0.77064, 
-//141
-0.14464, 0.17464, 0.22946, 0.30098, 0.37904, 0.43706, 0.4646, 0.47154, 0.47158, 0.46702, 0.46518, 0.46412, 0.463, 0.45964, 0.4592, 0.46128, 0.45974, 0.4598,
-0.46046, 0.45926, 0.45532, 0.4502, 0.44622, 0.4448, 0.45004, 0.46168, 0.47282, 0.47974, 0.47892, 0.47518, 0.47072, 0.47046, 0.47788, 0.4942, 0.5192, 0.54544,
-0.56216, 0.57334, 0.57826, 0.58058, 0.58134, 0.58134, 0.58176, 0.57992, 0.57862, 0.5788, 0.57838, 0.57696, 0.5754, 0.57536, 0.57364, 0.57226, 0.57202, 0.56984,
-0.56944, 0.5691, 0.56932, 0.56666, 0.5667, 0.5651, 0.56248, 0.56226, 0.56206, 0.56248, 0.56164, 0.56014, 0.5599, 0.55962, 0.55942, 0.5597, 0.55854, 0.558,
-0.55922, 0.55476, 0.55618, 0.55372, 0.55498, 0.55346, 0.55358, 0.55104, 0.5526, 
-//142
-0.12364, 0.14966, 0.19366, 0.24968, 0.29966, 0.3277, 0.33812, 0.33578, 0.33078, 0.3249, 0.32228, 0.31922, 0.31886, 0.31662, 0.31366, 0.31154, 0.31264, 0.31312,
-0.3141, 0.31338, 0.3093, 0.30116, 0.29582, 0.29264, 0.29686, 0.30492, 0.31428, 0.31706, 0.31346, 0.30416, 0.29962, 0.30124, 0.31272, 0.33646, 0.36626, 0.39088,
-0.40394, 0.41464, 0.4169, 0.41776, 0.41662, 0.41522, 0.41258, 0.41002, 0.4083, 0.40514, 0.4039, 0.40316, 0.39992, 0.39814, 0.39558, 0.39216, 0.3896, 0.38986,
-0.3882, 0.38632, 0.38462, 0.38278, 0.38036, 0.37804, 0.37608, 0.37446, 0.37294, 0.37262, 0.371, 0.36978, 0.36872, 0.36722, 0.36598, 0.36726, 0.3647, 0.36482,
-0.3626, 0.35994, 0.35934, 0.3582, 0.35666, 0.35776, 0.35838, 0.35474, 0.35128, 
-//143
-0.11, 0.13098, 0.16296, 0.19482, 0.21676, 0.22746, 0.2313, 0.22758, 0.222, 0.21908, 0.21744, 0.21682, 0.21634, 0.21556, 0.21406, 0.21284, 0.21298, 0.21272,
-0.21478, 0.2156, 0.211, 0.20564, 0.20278, 0.20192, 0.20378, 0.20958, 0.2152, 0.2165, 0.21306, 0.2084, 0.20474, 0.20598, 0.21474, 0.23214, 0.25398, 0.27314,
-0.2846, 0.29264, 0.29594, 0.29688, 0.2964, 0.29606, 0.29528, 0.2952, 0.29472, 0.29236, 0.29288, 0.29204, 0.29052, 0.289, 0.28972, 0.2885, 0.28608, 0.28658,
-0.28614, 0.28528, 0.2858, 0.28306, 0.28158, 0.28224, 0.28122, 0.28002, 0.27926, 0.28076, 0.28032, 0.27974, 0.28014, 0.2796, 0.27874, 0.28032, 0.28062, 0.2784,
-0.27952, 0.27846, 0.27768, 0.27726, 0.27736, 0.27664, 0.27754, 0.27528, 0.27436, 
-//144
-0.09856, 0.10976, 0.1243, 0.13648, 0.14418, 0.14558, 0.14574, 0.14374, 0.14242, 0.1435, 0.14404, 0.1447, 0.1451, 0.14362, 0.14374, 0.14344, 0.14506, 0.1455,
-0.14736, 0.14902, 0.14848, 0.14728, 0.14686, 0.14722, 0.1491, 0.14926, 0.14958, 0.1496, 0.14972, 0.14806, 0.1471, 0.14858, 0.15076, 0.15464, 0.1624, 0.17,
-0.17686, 0.19026, 0.1981, 0.2054, 0.21032, 0.21436, 0.21422, 0.214, 0.21292, 0.2117, 0.21138, 0.21194, 0.21064, 0.20948, 0.21028, 0.20918, 0.20558, 0.20524,
-0.20508, 0.20368, 0.20414, 0.2036, 0.2025, 0.20176, 0.20064, 0.19734, 0.19716, 0.19956, 0.19936, 0.197, 0.19856, 0.19876, 0.19782, 0.19892, 0.19786, 0.19812,
-0.199, 0.19738, 0.19734, 0.19682, 0.19788, 0.19634, 0.19562, 0.19434, 0.19518, 
-//145
-0.07122, 0.07376, 0.0796, 0.08248, 0.08514, 0.0858, 0.08772, 0.08708, 0.0866, 0.08628, 0.08576, 0.08558, 0.08716, 0.08762, 0.0884, 0.08834, 0.089, 0.08808,
-0.08782, 0.08864, 0.08838, 0.08824, 0.08828, 0.08932, 0.08932, 0.08912, 0.08942, 0.08846, 0.08874, 0.09024, 0.09084, 0.09242, 0.0945, 0.09778, 0.10244, 0.10702,
-0.1108, 0.11688, 0.12166, 0.12768, 0.13254, 0.1363, 0.14066, 0.14158, 0.14264, 0.14368, 0.14534, 0.14478, 0.14488, 0.14486, 0.14418, 0.14414, 0.1444, 0.144,
-0.14538, 0.14474, 0.14474, 0.14408, 0.14456, 0.14546, 0.1448, 0.14426, 0.14294, 0.14498, 0.14576, 0.1459, 0.14684, 0.14834, 0.14814, 0.14846, 0.1481, 0.14712,
-0.14794, 0.14834, 0.14942, 0.14896, 0.14944, 0.15118, 0.15026, 0.14754, 0.1491, 
-//146
-0.04884, 0.04954, 0.04978, 0.0505, 0.05076, 0.0495, 0.04954, 0.04976, 0.04982, 0.05056, 0.05148, 0.05142, 0.05076, 0.05068, 0.05022, 0.0503, 0.05112, 0.05164,
-0.05186, 0.05232, 0.05234, 0.05094, 0.0506, 0.05156, 0.05166, 0.05254, 0.05314, 0.05296, 0.05328, 0.05322, 0.0538, 0.05368, 0.05524, 0.05722, 0.05892, 0.06146,
-0.06422, 0.06818, 0.07276, 0.07776, 0.0821, 0.08434, 0.08634, 0.0876, 0.0875, 0.08842, 0.0891, 0.08946, 0.08942, 0.09088, 0.09102, 0.09014, 0.08986, 0.0901,
-0.08966, 0.09018, 0.0909, 0.09042, 0.0917, 0.0926, 0.0918, 0.0909, 0.09292, 0.09378, 0.09386, 0.0943, 0.09508, 0.096, 0.09654, 0.09854, 0.09876, 0.09972,
-0.10108, 0.10074, 0.1014, 0.1019, 0.1022, 0.10272, 0.10314, 0.1036, 0.10454, 
-//147
-0.04266, 0.04102, 0.0416, 0.04052, 0.03982, 0.04038, 0.04026, 0.03948, 0.04058, 0.04116, 0.04048, 0.04066, 0.03994, 0.03848, 0.03872, 0.0393, 0.03998, 0.0402,
-0.04098, 0.03974, 0.03918, 0.03876, 0.03826, 0.03894, 0.03982, 0.04086, 0.04106, 0.04058, 0.04018, 0.03914, 0.0399, 0.04114, 0.04142, 0.04222, 0.0439, 0.04418,
-0.04522, 0.04926, 0.0521, 0.056, 0.06158, 0.06588, 0.06958, 0.07202, 0.07246, 0.07122, 0.07062, 0.07034, 0.06898, 0.06854, 0.0675, 0.06618, 0.06556, 0.0659,
-0.0651, 0.06438, 0.06438, 0.06332, 0.0634, 0.06368, 0.06326, 0.06232, 0.06328, 0.06384, 0.06418, 0.0642, 0.06508, 0.06532, 0.06602, 0.06692, 0.06804, 0.06784,
-0.06808, 0.06816, 0.06922, 0.06908, 0.0708, 0.07032, 0.07008, 0.07036, 0.07108, 
-//148
-0.12466, 0.15142, 0.1978, 0.25934, 0.31978, 0.35728, 0.37394, 0.37594, 0.37342, 0.36742, 0.36622, 0.36622, 0.36504, 0.36434, 0.3642, 0.3629, 0.36332, 0.36636,
-0.369, 0.36736, 0.3644, 0.35874, 0.35098, 0.34986, 0.3568, 0.36796, 0.38114, 0.3888, 0.3856, 0.37728, 0.37332, 0.3764, 0.3927, 0.4263, 0.4728, 0.5199, 0.55866,
-0.5888, 0.60596, 0.61558, 0.6237, 0.62776, 0.63094, 0.6339, 0.63664, 0.63718, 0.63906, 0.64044, 0.63842, 0.637, 0.63966, 0.63966, 0.63742, 0.6388, 0.64048,
-0.63906, 0.63936, 0.63984, 0.63764, 0.63936, 0.63874, 0.63896, 0.63704, 0.63896, 0.6399, 0.63788, 0.6406, 0.64272, 0.64112, 0.64362, 0.64216, 0.64272, 0.64236,
-0.64142, 0.64292, 0.64378, 0.64382, 0.6433, 0.64462, 0.6411, 0.64176, 
-//149
-0.12884, 0.15358, 0.19398, 0.2374, 0.2714, 0.28792, 0.2919, 0.2893, 0.28602, 0.28156, 0.28052, 0.27904, 0.27646, 0.27486, 0.274, 0.27234, 0.2739, 0.27566,
-0.2773, 0.27608, 0.27238, 0.26722, 0.26168, 0.25984, 0.26616, 0.27788, 0.28974, 0.29576, 0.29402, 0.28756, 0.28296, 0.28394, 0.2934, 0.31452, 0.35228, 0.39796,
-0.43706, 0.46666, 0.4808, 0.4891, 0.49306, 0.49494, 0.49774, 0.49772, 0.49704, 0.4963, 0.49594, 0.49356, 0.49226, 0.49218, 0.4923, 0.49052, 0.48886, 0.48962,
-0.48766, 0.48694, 0.4865, 0.48522, 0.4856, 0.48502, 0.48224, 0.48126, 0.4796, 0.47974, 0.47958, 0.47976, 0.47918, 0.47814, 0.47836, 0.47806, 0.47626, 0.47556,
-0.476, 0.47312, 0.47438, 0.4738, 0.47316, 0.47172, 0.47252, 0.46952, 0.46994, 
-//150
-0.11416, 0.13128, 0.15476, 0.17614, 0.18894, 0.19356, 0.19458, 0.1914, 0.18912, 0.18514, 0.1816, 0.18062, 0.18008, 0.17812, 0.17682, 0.1771, 0.17706, 0.1781,
-0.1788, 0.17764, 0.17508, 0.1704, 0.16714, 0.1651, 0.16924, 0.17594, 0.18322, 0.18546, 0.1828, 0.17926, 0.17632, 0.17602, 0.18376, 0.1988, 0.22632, 0.26024,
-0.28938, 0.31378, 0.32416, 0.32978, 0.33284, 0.33452, 0.33592, 0.3354, 0.33498, 0.33398, 0.33246, 0.3309, 0.32946, 0.3296, 0.32918, 0.32802, 0.32732, 0.32548,
-0.3258, 0.32306, 0.3231, 0.32188, 0.32082, 0.32098, 0.32016, 0.3173, 0.31658, 0.31814, 0.31706, 0.31688, 0.31622, 0.31544, 0.3157, 0.3144, 0.31492, 0.31434,
-0.31448, 0.31308, 0.31262, 0.31114, 0.31056, 0.31062, 0.31234, 0.3125, 0.3132, 
-//151
-0.09628, 0.10374, 0.11464, 0.11854, 0.12128, 0.12176, 0.1213, 0.11998, 0.11716, 0.11294, 0.11216, 0.10962, 0.11016, 0.10964, 0.10984, 0.10952, 0.10954, 0.10962,
-0.10952, 0.1086, 0.1073, 0.10378, 0.10214, 0.1013, 0.10436, 0.10678, 0.10944, 0.1082, 0.10614, 0.10482, 0.10424, 0.10714, 0.11346, 0.1259, 0.14702, 0.17468,
-0.199, 0.22272, 0.23224, 0.23604, 0.23792, 0.23778, 0.23916, 0.2392, 0.23898, 0.23744, 0.23576, 0.23434, 0.23282, 0.2317, 0.23252, 0.232, 0.23134, 0.23016,
-0.229, 0.22728, 0.22582, 0.22552, 0.22556, 0.22628, 0.22546, 0.22284, 0.22232, 0.22358, 0.2225, 0.22286, 0.2239, 0.22322, 0.22284, 0.2236, 0.22306, 0.22184,
-0.22212, 0.2222, 0.22182, 0.22218, 0.2217, 0.22068, 0.22102, 0.21844, 0.21794, 
-//152
-0.0687, 0.0685, 0.07182, 0.07178, 0.07088, 0.06996, 0.07078, 0.07032, 0.0692, 0.06914, 0.06942, 0.06788, 0.06866, 0.06928, 0.06956, 0.06898, 0.06892, 0.0688,
-0.06864, 0.06814, 0.06906, 0.06844, 0.06852, 0.06822, 0.07006, 0.06984, 0.06916, 0.06862, 0.06884, 0.0715, 0.07374, 0.07752, 0.08116, 0.08624, 0.0948, 0.10532,
-0.1129, 0.12486, 0.13244, 0.14028, 0.1505, 0.1591, 0.1671, 0.17258, 0.17504, 0.1754, 0.17548, 0.17528, 0.1731, 0.17292, 0.17402, 0.17372, 0.17218, 0.17106,
-0.17014, 0.16922, 0.16912, 0.16752, 0.1697, 0.17086, 0.16984, 0.1677, 0.16566, 0.16876, 0.16928, 0.1694, 0.17054, 0.17014, 0.17032, 0.1699, 0.17006, 0.17118,
-0.17274, 0.17232, 0.17228, 0.17076, 0.17058, 0.1708, 0.17016, 0.17162, 0.17278, 
-//153
-0.12152, 0.14776, 0.19522, 0.24854, 0.29472, 0.32236, 0.33128, 0.32848, 0.32348, 0.32014, 0.31722, 0.31634, 0.31542, 0.3123, 0.30978, 0.31138, 0.31202, 0.31478,
-0.3168, 0.31686, 0.31184, 0.30328, 0.29736, 0.29698, 0.30458, 0.31924, 0.33452, 0.34124, 0.33894, 0.33024, 0.32552, 0.32986, 0.34818, 0.38622, 0.44468, 0.51404,
-0.57586, 0.62744, 0.66058, 0.68186, 0.69446, 0.70402, 0.71044, 0.71346, 0.7156, 0.71772, 0.7176, 0.71762, 0.7187, 0.71962, 0.72038, 0.71968, 0.7199, 0.72024,
-0.7187, 0.72104, 0.72316, 0.7225, 0.72182, 0.72194, 0.72228, 0.72268, 0.72116, 0.72202, 0.72342, 0.72672, 0.72784, 0.72696, 0.72496, 0.7304, 0.72982, 0.72806,
-0.73328, 0.72928, 0.72974, 0.73588, 0.73644, 0.73016, 0.73468, 0.73632, 0.73256, 
-//154
-0.12022, 0.13914, 0.1702, 0.19756, 0.21738, 0.22516, 0.2259, 0.22104, 0.21834, 0.21442, 0.21196, 0.21182, 0.2124, 0.20962, 0.20916, 0.20934, 0.20918, 0.21058,
-0.21132, 0.21164, 0.20906, 0.20468, 0.19978, 0.1991, 0.20376, 0.2156, 0.22894, 0.23758, 0.23786, 0.23236, 0.22798, 0.22908, 0.23732, 0.26052, 0.30172, 0.35792,
-0.41322, 0.46178, 0.4918, 0.50986, 0.52116, 0.5282, 0.53238, 0.53382, 0.53652, 0.53644, 0.5356, 0.53642, 0.5362, 0.53524, 0.5316, 0.53088, 0.5307, 0.52882,
-0.52924, 0.52978, 0.52932, 0.52778, 0.5275, 0.528, 0.5256, 0.52446, 0.52234, 0.52308, 0.52366, 0.5263, 0.5224, 0.52114, 0.52104, 0.521, 0.5206, 0.52168,
-0.52168, 0.51986, 0.51972, 0.51832, 0.51654, 0.51748, 0.51666, 0.51452, 0.51644, 
-//155
-0.10592, 0.11826, 0.1327, 0.14356, 0.14946, 0.15204, 0.15216, 0.14936, 0.14808, 0.14438, 0.14282, 0.14124, 0.14238, 0.14146, 0.14032, 0.1411, 0.14168, 0.14196,
-0.14268, 0.1418, 0.14068, 0.13824, 0.1371, 0.13706, 0.1405, 0.14708, 0.15396, 0.15764, 0.15768, 0.15676, 0.15586, 0.15746, 0.16418, 0.17994, 0.2071, 0.24516,
-0.28172, 0.31788, 0.34212, 0.35962, 0.37018, 0.37732, 0.38428, 0.38746, 0.38994, 0.39214, 0.39478, 0.39506, 0.39382, 0.39314, 0.394, 0.3924, 0.39312, 0.39322,
-0.39348, 0.39258, 0.39206, 0.39034, 0.3891, 0.39108, 0.39098, 0.39002, 0.38762, 0.3889, 0.38954, 0.38924, 0.3897, 0.39008, 0.3897, 0.39016, 0.38954, 0.39024,
-0.39032, 0.38836, 0.38982, 0.38882, 0.3874, 0.38682, 0.38816, 0.38692, 0.38736, 
-//156
-0.08582, 0.09154, 0.0963, 0.10036, 0.10164, 0.10004, 0.09974, 0.09784, 0.09422, 0.09132, 0.09118, 0.09134, 0.0914, 0.0909, 0.0903, 0.08976, 0.08868, 0.08974,
-0.09074, 0.09016, 0.0891, 0.08722, 0.0866, 0.08636, 0.08826, 0.09012, 0.09308, 0.09342, 0.09238, 0.09196, 0.09342, 0.09616, 0.10172, 0.1114, 0.13098, 0.15888,
-0.18912, 0.224, 0.248, 0.26204, 0.2696, 0.27622, 0.27974, 0.2809, 0.2813, 0.28146, 0.28174, 0.28052, 0.27916, 0.27766, 0.2771, 0.2765, 0.27552, 0.27452, 0.2745,
-0.27256, 0.27208, 0.27006, 0.26896, 0.26936, 0.26976, 0.2674, 0.26688, 0.2678, 0.26746, 0.26598, 0.26672, 0.26768, 0.26794, 0.26678, 0.26652, 0.26588, 0.26734,
-0.265, 0.2659, 0.26586, 0.26488, 0.26296, 0.26428, 0.26294, 0.26262, 
-//157
-0.04766, 0.04504, 0.04448, 0.04488, 0.04586, 0.04514, 0.0467, 0.04624, 0.04624, 0.04524, 0.04454, 0.0459, 0.04616, 0.0461, 0.04628, 0.0455, 0.04652, 0.04616,
-0.04728, 0.048, 0.04854, 0.04798, 0.04866, 0.04902, 0.05048, 0.05142, 0.0539, 0.05414, 0.05506, 0.05518, 0.0556, 0.05654, 0.0583, 0.0635, 0.07042, 0.0794,
-0.08956, 0.10942, 0.12934, 0.14926, 0.16556, 0.17766, 0.185, 0.1906, 0.19354, 0.19438, 0.19446, 0.19388, 0.19292, 0.1901, 0.18892, 0.18692, 0.18518, 0.18498,
-0.18442, 0.18258, 0.18084, 0.17806, 0.17768, 0.17738, 0.17662, 0.17494, 0.17252, 0.17468, 0.17328, 0.17298, 0.17254, 0.17178, 0.17116, 0.17138, 0.17128,
-0.17116, 0.17114, 0.17012, 0.16914, 0.16876, 0.16952, 0.16986, 0.17144, 0.16908, 0.1675, 
-//158
-0.1189, 0.1429, 0.18242, 0.22416, 0.25608, 0.27336, 0.2777, 0.27516, 0.2701, 0.26624, 0.26218, 0.26124, 0.26176, 0.25966, 0.25878, 0.25904, 0.25904, 0.26042,
-0.26214, 0.26258, 0.26062, 0.25452, 0.25088, 0.24886, 0.2543, 0.27068, 0.2888, 0.30168, 0.3032, 0.29622, 0.29154, 0.29568, 0.31398, 0.3532, 0.41682, 0.4964,
-0.5715, 0.64248, 0.69602, 0.7313, 0.75432, 0.77146, 0.7823, 0.78798, 0.79278, 0.79552, 0.79786, 0.79836, 0.79846, 0.79996, 0.79944, 0.80114, 0.80206, 0.80206,
-0.80226, 0.80298, 0.80328, 0.80378, 0.80812, 0.8099, 0.81128, 0.81042, 0.8085, 0.80888, 0.80898, 0.81158, 0.81264, 0.81014, 0.80984, 0.81432, 0.8209, 0.82004,
-0.8233, 0.81928, 0.82078, 0.81764, 0.82088, 0.8218, 0.82378, 0.82162, 0.82174, 
-//159
-0.11218, 0.12858, 0.14932, 0.1685, 0.1812, 0.1846, 0.18324, 0.17954, 0.17742, 0.17384, 0.17336, 0.17304, 0.17386, 0.17278, 0.17056, 0.17, 0.17106, 0.17224,
-0.17342, 0.17428, 0.17166, 0.1692, 0.16592, 0.16406, 0.17092, 0.18366, 0.1987, 0.20876, 0.20992, 0.20642, 0.20232, 0.20458, 0.21558, 0.23692, 0.2782, 0.33706,
-0.4012, 0.464, 0.50844, 0.53878, 0.55762, 0.5729, 0.5836, 0.59016, 0.59318, 0.59564, 0.59664, 0.59746, 0.59758, 0.59736, 0.59714, 0.59512, 0.5953, 0.59518,
-0.59474, 0.59504, 0.59474, 0.59402, 0.59302, 0.5931, 0.59234, 0.59016, 0.59116, 0.59282, 0.59248, 0.5916, 0.59162, 0.591, 0.59078, 0.59246, 0.59292, 0.59176,
-0.59132, 0.58906, 0.59168, 0.58984, 0.59176, 0.59258, 0.59248, 0.58796, 0.59188, 
-//160
-0.09372, 0.10226, 0.11144, 0.11746, 0.12068, 0.12148, 0.12086, 0.11874, 0.11686, 0.1147, 0.11368, 0.11244, 0.11166, 0.1133, 0.11478, 0.11322, 0.11474, 0.11362,
-0.11316, 0.11364, 0.1133, 0.11138, 0.11034, 0.11182, 0.11406, 0.11962, 0.12784, 0.13252, 0.13284, 0.13216, 0.13228, 0.13282, 0.14072, 0.15508, 0.1825, 0.22424,
-0.27382, 0.32584, 0.3628, 0.38894, 0.40542, 0.42044, 0.42966, 0.43688, 0.4429, 0.44424, 0.44546, 0.44494, 0.44612, 0.4451, 0.44726, 0.44722, 0.44602, 0.4452,
-0.44496, 0.44358, 0.44446, 0.44302, 0.44468, 0.44626, 0.4448, 0.4429, 0.44142, 0.44232, 0.44368, 0.44368, 0.44382, 0.44308, 0.44258, 0.4443, 0.44416, 0.44396,
-0.44546, 0.4435, 0.44494, 0.44262, 0.44286, 0.4411, 0.44194, 0.44098, 0.4426, 
-//161
-0.06624, 0.06754, 0.06728, 0.06978, 0.07004, 0.06958, 0.06968, 0.06872, 0.06774, 0.0661, 0.06614, 0.06758, 0.06862, 0.06826, 0.06762, 0.06724, 0.06682, 0.06778,
-0.06904, 0.06774, 0.06858, 0.06796, 0.0677, 0.06782, 0.07064, 0.07428, 0.07622, 0.0781, 0.0789, 0.08034, 0.08126, 0.08382, 0.08978, 0.09892, 0.1159, 0.14164,
-0.17198, 0.21062, 0.24236, 0.26602, 0.28178, 0.2928, 0.29978, 0.30408, 0.3065, 0.30804, 0.3081, 0.30906, 0.30768, 0.30638, 0.30558, 0.30432, 0.30258, 0.30274,
-0.302, 0.30118, 0.30126, 0.29828, 0.29814, 0.2991, 0.2984, 0.29576, 0.29572, 0.2971, 0.29654, 0.2954, 0.29574, 0.29722, 0.29774, 0.29754, 0.29712, 0.29662,
-0.29756, 0.29646, 0.29858, 0.29658, 0.29764, 0.29634, 0.29788, 0.29682, 0.29438, 
-//162
-0.04556, 0.04714, 0.0464, 0.04434, 0.04352, 0.04338, 0.04324, 0.04338, 0.0441, 0.0455, 0.04476, 0.04624, 0.04582, 0.04646, 0.0465, 0.04666, 0.04706, 0.04676,
-0.04616, 0.04628, 0.04684, 0.04714, 0.04846, 0.04964, 0.05002, 0.05032, 0.051, 0.05152, 0.0516, 0.05362, 0.05616, 0.05856, 0.06194, 0.06646, 0.07748, 0.09172,
-0.10924, 0.13316, 0.15338, 0.16988, 0.18252, 0.19244, 0.20174, 0.20924, 0.21506, 0.22062, 0.22466, 0.22534, 0.22656, 0.22938, 0.23112, 0.23298, 0.23574,
-0.23658, 0.2384, 0.24034, 0.24256, 0.24272, 0.24626, 0.24968, 0.25074, 0.25064, 0.25262, 0.25874, 0.26104, 0.26342, 0.26632, 0.2683, 0.27064, 0.27406, 0.27716,
-0.2804, 0.2832, 0.28442, 0.28604, 0.28826, 0.29118, 0.2934, 0.29532, 0.29714, 0.29772, 
-//163
-0.09672, 0.109, 0.12598, 0.13792, 0.14418, 0.14412, 0.14352, 0.14014, 0.13994, 0.13604, 0.13544, 0.13524, 0.13436, 0.1331, 0.1336, 0.13436, 0.13448, 0.13558,
-0.13664, 0.13562, 0.13392, 0.13268, 0.12992, 0.13094, 0.13574, 0.14544, 0.15722, 0.16588, 0.16838, 0.1642, 0.16244, 0.1657, 0.17636, 0.20246, 0.24924, 0.31358,
-0.38376, 0.45954, 0.51576, 0.5584, 0.58682, 0.61074, 0.62652, 0.6362, 0.64472, 0.64926, 0.65444, 0.65736, 0.6599, 0.66102, 0.66096, 0.66234, 0.66414, 0.6653,
-0.66684, 0.66784, 0.67108, 0.66926, 0.66974, 0.67206, 0.67224, 0.67088, 0.67092, 0.67552, 0.67636, 0.67794, 0.67854, 0.67952, 0.68006, 0.6843, 0.68614, 0.68554,
-0.68748, 0.68562, 0.68812, 0.69054, 0.6926, 0.69316, 0.69354, 0.69238, 0.69676, 
-//164
-0.07742, 0.0802, 0.08582, 0.08604, 0.08556, 0.08638, 0.0861, 0.08516, 0.08496, 0.08312, 0.08242, 0.08152, 0.08298, 0.08262, 0.08292, 0.08334, 0.08372, 0.0828,
-0.08296, 0.08396, 0.0825, 0.08326, 0.084, 0.0856, 0.0892, 0.09404, 0.10136, 0.10814, 0.11074, 0.10972, 0.1103, 0.11284, 0.11952, 0.1321, 0.15794, 0.198,
-0.24826, 0.31072, 0.35992, 0.39826, 0.42838, 0.44944, 0.46752, 0.48204, 0.49126, 0.49638, 0.49792, 0.4993, 0.4991, 0.49972, 0.50096, 0.50224, 0.50188, 0.5021,
-0.50188, 0.50018, 0.5015, 0.50284, 0.50084, 0.50396, 0.50426, 0.5, 0.50096, 0.504, 0.50476, 0.5042, 0.50444, 0.50438, 0.50488, 0.50656, 0.50802, 0.5083,
-0.51034, 0.50924, 0.509, 0.5082, 0.50926, 0.5098, 0.51174, 0.50988, 0.50982, 
-//165
-0.05352, 0.05168, 0.05428, 0.05494, 0.05444, 0.05372, 0.05392, 0.05318, 0.05144, 0.05046, 0.05138, 0.0507, 0.05208, 0.05196, 0.05162, 0.05022, 0.05222, 0.05216,
-0.05196, 0.05262, 0.05188, 0.05034, 0.04882, 0.05208, 0.05342, 0.05542, 0.0583, 0.05858, 0.05756, 0.05724, 0.05816, 0.06232, 0.0681, 0.07922, 0.09782, 0.12598,
-0.1635, 0.21072, 0.24418, 0.27076, 0.28992, 0.30344, 0.3141, 0.32166, 0.3267, 0.32984, 0.33264, 0.3347, 0.33648, 0.33836, 0.34018, 0.34064, 0.34122, 0.3439,
-0.34614, 0.34676, 0.34798, 0.34712, 0.34862, 0.35108, 0.35306, 0.3524, 0.3535, 0.35722, 0.3592, 0.36162, 0.36402, 0.36564, 0.36698, 0.36812, 0.37052, 0.37282,
-0.37618, 0.376, 0.37868, 0.38194, 0.38246, 0.3822, 0.3835, 0.38536, 0.38778, 
-//166
-0.08196, 0.09056, 0.09976, 0.10728, 0.1121, 0.11278, 0.11176, 0.10892, 0.1063, 0.104, 0.1025, 0.10344, 0.10354, 0.1028, 0.10404, 0.10248, 0.10274, 0.10474,
-0.10612, 0.1067, 0.10648, 0.10348, 0.10132, 0.1017, 0.10594, 0.11648, 0.12802, 0.13752, 0.13916, 0.13578, 0.13448, 0.1376, 0.15164, 0.181, 0.23148, 0.30382,
-0.38752, 0.47524, 0.5454, 0.60074, 0.6408, 0.66964, 0.69068, 0.7058, 0.71556, 0.72066, 0.72446, 0.72764, 0.72938, 0.73266, 0.73496, 0.73556, 0.73782, 0.73872,
-0.74054, 0.7425, 0.74354, 0.74146, 0.74144, 0.74374, 0.74476, 0.744, 0.74438, 0.74724, 0.75038, 0.7533, 0.75362, 0.7536, 0.75436, 0.75832, 0.75828, 0.76008,
-0.76276, 0.75924, 0.76148, 0.76246, 0.76174, 0.76652, 0.76842, 0.76318, 0.76682, 
-//167
-0.0524, 0.0534, 0.05474, 0.05496, 0.05652, 0.05544, 0.05748, 0.05672, 0.05528, 0.05506, 0.05658, 0.05612, 0.05606, 0.05664, 0.05718, 0.05496, 0.0558, 0.05714,
-0.0583, 0.05894, 0.05976, 0.05916, 0.05896, 0.06148, 0.06556, 0.0738, 0.08636, 0.1004, 0.11124, 0.1171, 0.123, 0.12746, 0.13268, 0.14244, 0.15646, 0.17556,
-0.20074, 0.24534, 0.2915, 0.34428, 0.39484, 0.44262, 0.4811, 0.51364, 0.53914, 0.55538, 0.56812, 0.57882, 0.585, 0.58754, 0.59168, 0.59304, 0.59278, 0.59534,
-0.5991, 0.6003, 0.60294, 0.60374, 0.60438, 0.60536, 0.60498, 0.6063, 0.60424, 0.60788, 0.6113, 0.61248, 0.61328, 0.61652, 0.61556, 0.61914, 0.62226, 0.62158,
-0.62358, 0.62328, 0.62516, 0.62594, 0.62694, 0.62574, 0.62806, 0.62912, 0.62876, 
-//168
-0.04142, 0.04178, 0.04208, 0.0411, 0.04196, 0.04194, 0.04148, 0.04078, 0.04158, 0.04136, 0.04298, 0.04288, 0.0435, 0.04208, 0.04142, 0.04196, 0.04414, 0.04498,
-0.04498, 0.04572, 0.04548, 0.04448, 0.04492, 0.049, 0.0535, 0.06118, 0.07418, 0.08828, 0.10088, 0.11128, 0.11812, 0.12386, 0.12946, 0.13772, 0.15034, 0.16848,
-0.19442, 0.24128, 0.28966, 0.34238, 0.39486, 0.44648, 0.49202, 0.52712, 0.5574, 0.57796, 0.5936, 0.60466, 0.6113, 0.61726, 0.6195, 0.62242, 0.62476, 0.6291,
-0.6312, 0.6346, 0.63762, 0.637, 0.63766, 0.64102, 0.6445, 0.64482, 0.64218, 0.64838, 0.6507, 0.6539, 0.6558, 0.65732, 0.65656, 0.66076, 0.66266, 0.66314,
-0.66626, 0.66698, 0.66864, 0.67038, 0.67204, 0.67146, 0.6719, 0.67018, 0.6748, 
-//169
-0.1328, 0.16438, 0.21894, 0.30274, 0.40822, 0.50936, 0.58002, 0.61114, 0.62046, 0.62068, 0.62312, 0.6233, 0.62384, 0.62298, 0.62182, 0.62146, 0.62346, 0.6255,
-0.62706, 0.62658, 0.62446, 0.61946, 0.61486, 0.61658, 0.62188, 0.63216, 0.64224, 0.64696, 0.64762, 0.64424, 0.64158, 0.64224, 0.6493, 0.66312, 0.68084, 0.69518,
-0.70474, 0.71174, 0.71434, 0.71634, 0.7152, 0.71404, 0.71568, 0.7151, 0.71458, 0.7142, 0.71524, 0.71268, 0.71072, 0.71202, 0.71372, 0.7118, 0.7108, 0.70976,
-0.70914, 0.70802, 0.708, 0.70732, 0.70682, 0.70758, 0.70708, 0.70502, 0.7052, 0.70876, 0.70812, 0.70704, 0.70752, 0.70876, 0.70774, 0.70992, 0.7103, 0.70976,
-0.71004, 0.70908, 0.70888, 0.70986, 0.70884, 0.70978, 0.7088, 0.71036, 0.708, 
-//170
-0.14494, 0.1738, 0.22648, 0.3017, 0.38108, 0.44674, 0.48108, 0.48896, 0.48958, 0.4859, 0.48374, 0.48308, 0.48446, 0.48336, 0.48162, 0.47952, 0.47874, 0.47898,
-0.48114, 0.48038, 0.47822, 0.47358, 0.47102, 0.46996, 0.4749, 0.48426, 0.49596, 0.50192, 0.50398, 0.50128, 0.4986, 0.49912, 0.5023, 0.51224, 0.52584, 0.54052,
-0.54612, 0.5526, 0.5541, 0.554, 0.55422, 0.55362, 0.55432, 0.55076, 0.55096, 0.54932, 0.54866, 0.54802, 0.54764, 0.54524, 0.54406, 0.5426, 0.54172, 0.5414,
-0.54076, 0.538, 0.53916, 0.53532, 0.53446, 0.53478, 0.53458, 0.53156, 0.52914, 0.53234, 0.53064, 0.5289, 0.52922, 0.52804, 0.52786, 0.52766, 0.52766, 0.52594,
-0.52722, 0.52464, 0.5237, 0.52312, 0.52108, 0.52112, 0.52252, 0.51932, 0.51948, 
-//171
-0.13542, 0.16492, 0.20958, 0.2632, 0.31042, 0.33746, 0.3485, 0.34982, 0.34742, 0.34338, 0.34266, 0.3405, 0.33854, 0.33634, 0.33472, 0.3339, 0.33426, 0.33504,
-0.33422, 0.33244, 0.33018, 0.3269, 0.32332, 0.32386, 0.3272, 0.33412, 0.34332, 0.3477, 0.34892, 0.34536, 0.34306, 0.34268, 0.34518, 0.35534, 0.37118, 0.3843,
-0.3912, 0.3983, 0.40046, 0.40082, 0.40104, 0.40034, 0.39956, 0.39796, 0.39626, 0.39312, 0.39398, 0.39272, 0.39088, 0.39022, 0.38894, 0.38694, 0.38496, 0.384,
-0.38448, 0.38306, 0.38122, 0.3791, 0.37738, 0.37744, 0.37592, 0.37482, 0.37232, 0.37396, 0.37454, 0.37384, 0.37224, 0.36958, 0.36932, 0.3697, 0.37008, 0.3714,
-0.37062, 0.3679, 0.36604, 0.36746, 0.36594, 0.3655, 0.36476, 0.36392, 0.36252, 
-//172
-0.1192, 0.1414, 0.17346, 0.20724, 0.23208, 0.24314, 0.24628, 0.24682, 0.2437, 0.24082, 0.23996, 0.23878, 0.23722, 0.23586, 0.23652, 0.23536, 0.23536, 0.23528,
-0.23492, 0.23354, 0.2326, 0.2296, 0.22562, 0.22418, 0.22776, 0.23346, 0.23896, 0.24228, 0.24322, 0.23928, 0.23628, 0.23694, 0.2396, 0.24782, 0.26044, 0.27312,
-0.27676, 0.2858, 0.28804, 0.28696, 0.28762, 0.28788, 0.28696, 0.28504, 0.28488, 0.28262, 0.28386, 0.28362, 0.28126, 0.28, 0.2799, 0.27732, 0.2763, 0.27686,
-0.27574, 0.27528, 0.27496, 0.27216, 0.27212, 0.27284, 0.27164, 0.26884, 0.26744, 0.27022, 0.26894, 0.26726, 0.26902, 0.26902, 0.26806, 0.2689, 0.26742, 0.26496,
-0.26698, 0.26586, 0.26564, 0.2655, 0.26556, 0.26386, 0.26332, 0.26232, 0.2628, 
-//173
-0.10572, 0.12, 0.13704, 0.15344, 0.16494, 0.1689, 0.16894, 0.16566, 0.16478, 0.16082, 0.15996, 0.15958, 0.15974, 0.15752, 0.15686, 0.15554, 0.15434, 0.15526,
-0.1566, 0.1555, 0.1536, 0.15188, 0.15002, 0.14682, 0.14932, 0.15416, 0.15842, 0.15982, 0.15842, 0.1558, 0.15464, 0.15542, 0.1599, 0.16448, 0.17448, 0.18356,
-0.18432, 0.1923, 0.19384, 0.19294, 0.19084, 0.19082, 0.19134, 0.19096, 0.19128, 0.19056, 0.1888, 0.18624, 0.1844, 0.18456, 0.18578, 0.18376, 0.18446, 0.18248,
-0.1816, 0.1795, 0.17806, 0.1767, 0.17804, 0.17928, 0.17748, 0.17484, 0.175, 0.17588, 0.17492, 0.17522, 0.17554, 0.17524, 0.17624, 0.1752, 0.17418, 0.17374,
-0.17418, 0.17512, 0.17504, 0.174, 0.1754, 0.17402, 0.17424, 0.1719, 0.17304, 
-//174
-0.07772, 0.08158, 0.08886, 0.09596, 0.09796, 0.09924, 0.09976, 0.09828, 0.09678, 0.09478, 0.09564, 0.0959, 0.09548, 0.09486, 0.09388, 0.09268, 0.09176, 0.09266,
-0.09272, 0.09178, 0.09232, 0.09064, 0.09056, 0.08954, 0.09166, 0.09296, 0.09514, 0.0974, 0.09742, 0.0965, 0.09662, 0.09816, 0.09906, 0.10054, 0.10422, 0.10608,
-0.10562, 0.11456, 0.11866, 0.1197, 0.1213, 0.11984, 0.11974, 0.11992, 0.12122, 0.12002, 0.11862, 0.11814, 0.11784, 0.11582, 0.11636, 0.11586, 0.11596, 0.11576,
-0.11558, 0.1134, 0.1114, 0.11086, 0.11162, 0.1136, 0.11358, 0.11098, 0.11048, 0.11314, 0.11204, 0.1119, 0.1119, 0.112, 0.1139, 0.11322, 0.1136, 0.11338,
-0.11368, 0.11318, 0.11452, 0.1145, 0.11402, 0.11518, 0.11438, 0.11242, 0.11266, 
-//175
-0.0536, 0.05164, 0.05446, 0.0555, 0.057, 0.05594, 0.05614, 0.05494, 0.05396, 0.0527, 0.05454, 0.05428, 0.05546, 0.05426, 0.05278, 0.05096, 0.05176, 0.05294,
-0.05236, 0.05326, 0.05394, 0.05304, 0.05152, 0.0518, 0.05294, 0.05338, 0.0546, 0.0545, 0.05436, 0.05482, 0.05518, 0.05622, 0.05712, 0.05748, 0.05924, 0.06132,
-0.0606, 0.0688, 0.07134, 0.0719, 0.07278, 0.0713, 0.0732, 0.07228, 0.0736, 0.07246, 0.0717, 0.07056, 0.06894, 0.06914, 0.0697, 0.07012, 0.0702, 0.07008,
-0.06844, 0.0669, 0.0671, 0.06534, 0.0671, 0.06942, 0.06858, 0.06544, 0.06472, 0.06832, 0.06902, 0.0697, 0.06982, 0.06836, 0.06864, 0.06996, 0.0702, 0.07152,
-0.07304, 0.07244, 0.07196, 0.07192, 0.07184, 0.07264, 0.0736, 0.07358, 0.07378, 
-//176
-0.0494, 0.04624, 0.04442, 0.04264, 0.04358, 0.0449, 0.04444, 0.0443, 0.04472, 0.04296, 0.04268, 0.04366, 0.04414, 0.04324, 0.043, 0.04308, 0.04288, 0.04214,
-0.04292, 0.0429, 0.04378, 0.04314, 0.04206, 0.04114, 0.04216, 0.0426, 0.0435, 0.04372, 0.04396, 0.0433, 0.04304, 0.04264, 0.04394, 0.04556, 0.04706, 0.04734,
-0.04466, 0.05034, 0.05294, 0.05474, 0.05652, 0.0555, 0.05734, 0.05842, 0.05776, 0.05778, 0.05686, 0.05492, 0.05426, 0.05406, 0.055, 0.0541, 0.0548, 0.0542,
-0.05398, 0.0522, 0.05262, 0.05072, 0.05296, 0.05342, 0.05306, 0.05036, 0.05202, 0.05414, 0.054, 0.05514, 0.05478, 0.05402, 0.05726, 0.057, 0.05706, 0.05756,
-0.05732, 0.0575, 0.058, 0.05866, 0.06014, 0.06004, 0.06086, 0.06, 0.05918, 
-//177
-0.1436, 0.17844, 0.23776, 0.32292, 0.422, 0.5119, 0.5637, 0.58326, 0.58874, 0.58746, 0.58638, 0.5869, 0.58864, 0.58722, 0.58846, 0.5895, 0.58988, 0.59216,
-0.59388, 0.59362, 0.59158, 0.58758, 0.58336, 0.5837, 0.59186, 0.60544, 0.62346, 0.6353, 0.63776, 0.63312, 0.63026, 0.63068, 0.6399, 0.6623, 0.69404, 0.72434,
-0.74382, 0.7577, 0.76184, 0.76746, 0.76918, 0.76878, 0.77302, 0.77158, 0.77286, 0.77108, 0.77206, 0.7724, 0.77206, 0.7717, 0.77258, 0.77296, 0.77454, 0.77464,
-0.77604, 0.77708, 0.77724, 0.77726, 0.77696, 0.77838, 0.7765, 0.7753, 0.77464, 0.77564, 0.77828, 0.77552, 0.7735, 0.77656, 0.77714, 0.78068, 0.78026, 0.7819,
-0.78348, 0.78118, 0.78266, 0.7826, 0.78174, 0.78506, 0.78502, 0.7838, 0.78236, 
-//178
-0.13214, 0.15996, 0.21126, 0.28062, 0.35474, 0.40972, 0.43626, 0.44168, 0.44174, 0.43882, 0.43928, 0.43826, 0.43736, 0.43588, 0.43462, 0.4339, 0.43554, 0.43808,
-0.43948, 0.43872, 0.43584, 0.42956, 0.42512, 0.42558, 0.43462, 0.44576, 0.45956, 0.46884, 0.46806, 0.46408, 0.46102, 0.4613, 0.47012, 0.48832, 0.51384, 0.5373,
-0.55066, 0.56286, 0.56536, 0.56802, 0.56898, 0.56824, 0.56856, 0.56712, 0.56648, 0.56522, 0.56442, 0.5645, 0.56432, 0.56278, 0.56244, 0.56128, 0.55972, 0.55974,
-0.5608, 0.55892, 0.5592, 0.5566, 0.55222, 0.55232, 0.55164, 0.54852, 0.54686, 0.54892, 0.54758, 0.5454, 0.54516, 0.54556, 0.54622, 0.54642, 0.54618, 0.54302,
-0.54426, 0.5438, 0.54196, 0.54054, 0.5428, 0.54082, 0.54204, 0.53418, 0.53676, 
-//179
-0.12884, 0.15378, 0.19998, 0.24964, 0.2934, 0.3186, 0.32652, 0.3259, 0.32318, 0.32042, 0.31868, 0.31742, 0.31742, 0.31484, 0.31296, 0.31224, 0.31254, 0.31452,
-0.31488, 0.31396, 0.3101, 0.30424, 0.30002, 0.30034, 0.30688, 0.31626, 0.32788, 0.33432, 0.33272, 0.32788, 0.32564, 0.3272, 0.33356, 0.3478, 0.37306, 0.39372,
-0.40932, 0.42294, 0.42656, 0.4296, 0.42956, 0.42794, 0.42764, 0.43078, 0.42576, 0.4237, 0.42416, 0.42354, 0.42136, 0.4204, 0.4187, 0.41856, 0.4162, 0.41646,
-0.41726, 0.41514, 0.41342, 0.41034, 0.41088, 0.41122, 0.4105, 0.40752, 0.40458, 0.4074, 0.40804, 0.40674, 0.4076, 0.40782, 0.4053, 0.40656, 0.40512, 0.4037,
-0.4052, 0.40264, 0.40374, 0.40276, 0.4023, 0.3995, 0.39906, 0.39848, 0.39996, 
-//180
-0.11928, 0.1407, 0.1707, 0.19712, 0.2163, 0.22306, 0.22408, 0.22068, 0.217, 0.21526, 0.21486, 0.2127, 0.2109, 0.20964, 0.20692, 0.20608, 0.2077, 0.20796,
-0.20874, 0.20792, 0.2049, 0.20004, 0.1962, 0.19676, 0.20282, 0.2097, 0.21814, 0.22266, 0.22158, 0.2177, 0.21634, 0.2178, 0.22308, 0.23594, 0.25428, 0.2716,
-0.28468, 0.29774, 0.30108, 0.30142, 0.30032, 0.30002, 0.301, 0.29968, 0.29842, 0.29768, 0.29714, 0.29438, 0.29272, 0.29346, 0.29348, 0.29102, 0.28934, 0.28804,
-0.2859, 0.2851, 0.2851, 0.28284, 0.28298, 0.28242, 0.28114, 0.27766, 0.2774, 0.2794, 0.27804, 0.27744, 0.27728, 0.27594, 0.27706, 0.27794, 0.27564, 0.27466,
-0.27504, 0.27356, 0.27298, 0.27264, 0.27374, 0.27244, 0.27284, 0.26934, 0.26898, 
-//181
-0.09228, 0.10778, 0.12332, 0.13724, 0.14486, 0.14568, 0.14338, 0.1429, 0.14078, 0.13958, 0.13868, 0.13814, 0.13624, 0.13474, 0.13436, 0.13342, 0.13428, 0.13616,
-0.1365, 0.13506, 0.13368, 0.12866, 0.12908, 0.12884, 0.1312, 0.13606, 0.14036, 0.14142, 0.1407, 0.13834, 0.13828, 0.139, 0.14296, 0.15248, 0.16682, 0.18134,
-0.18962, 0.2018, 0.20484, 0.20412, 0.20456, 0.20376, 0.20432, 0.20216, 0.20274, 0.20168, 0.20164, 0.20038, 0.19922, 0.1987, 0.19708, 0.19778, 0.19702, 0.19516,
-0.1947, 0.19374, 0.19342, 0.19146, 0.19192, 0.19376, 0.19336, 0.19048, 0.18912, 0.1904, 0.19024, 0.19164, 0.19012, 0.1906, 0.19052, 0.19064, 0.1896, 0.18872,
-0.18988, 0.18868, 0.1897, 0.18862, 0.1893, 0.18808, 0.1886, 0.1858, 0.18732, 
-//182
-0.06816, 0.07416, 0.08044, 0.08454, 0.08692, 0.08594, 0.0858, 0.08474, 0.08516, 0.08406, 0.08434, 0.08406, 0.08276, 0.08208, 0.08284, 0.08098, 0.08226, 0.0828,
-0.08284, 0.0818, 0.08028, 0.08136, 0.08128, 0.0822, 0.08356, 0.0846, 0.08682, 0.08868, 0.0909, 0.09102, 0.09292, 0.0946, 0.09442, 0.09718, 0.10024, 0.10508,
-0.10864, 0.12106, 0.12718, 0.13138, 0.1345, 0.13682, 0.1377, 0.13664, 0.13644, 0.13412, 0.1349, 0.13398, 0.13476, 0.13382, 0.13284, 0.13314, 0.13138, 0.13152,
-0.13148, 0.1308, 0.13098, 0.12826, 0.12818, 0.12866, 0.12904, 0.12692, 0.12552, 0.12876, 0.1291, 0.13014, 0.12954, 0.12872, 0.12824, 0.1283, 0.12984, 0.13156,
-0.13202, 0.1313, 0.13184, 0.1301, 0.12904, 0.13036, 0.1309, 0.13006, 0.1317, 
-//183
-0.04294, 0.0454, 0.04612, 0.0448, 0.04466, 0.04596, 0.04418, 0.04318, 0.0449, 0.04536, 0.04584, 0.045, 0.04596, 0.04436, 0.04402, 0.04448, 0.04642, 0.04572,
-0.04668, 0.04568, 0.04598, 0.04492, 0.04516, 0.04646, 0.04726, 0.04778, 0.04774, 0.04632, 0.046, 0.04738, 0.04846, 0.04784, 0.04996, 0.0514, 0.0545, 0.0568,
-0.05516, 0.0658, 0.07344, 0.07788, 0.08164, 0.08324, 0.08446, 0.0837, 0.0823, 0.0823, 0.08206, 0.08236, 0.081, 0.07934, 0.07836, 0.0772, 0.07738, 0.07742,
-0.07772, 0.07668, 0.07552, 0.07208, 0.07168, 0.07414, 0.07448, 0.07228, 0.07256, 0.07282, 0.07334, 0.07454, 0.07468, 0.07542, 0.07614, 0.07622, 0.07636,
-0.07464, 0.0761, 0.07766, 0.07866, 0.07852, 0.07808, 0.0768, 0.07678, 0.07724, 0.07812, 
-//184
-0.14656, 0.17786, 0.2309, 0.30612, 0.38992, 0.45584, 0.4903, 0.50138, 0.50156, 0.4985, 0.49736, 0.49648, 0.49654, 0.49586, 0.49496, 0.49476, 0.49534, 0.49646,
-0.49704, 0.49728, 0.49472, 0.49092, 0.4882, 0.4891, 0.4995, 0.51994, 0.5467, 0.56954, 0.58102, 0.5823, 0.5816, 0.58318, 0.5931, 0.61704, 0.65706, 0.70182,
-0.73968, 0.77202, 0.78844, 0.79796, 0.80408, 0.8077, 0.81138, 0.81086, 0.8131, 0.81204, 0.81244, 0.81354, 0.8125, 0.81242, 0.81354, 0.81278, 0.81146, 0.81374,
-0.81302, 0.81094, 0.80998, 0.80602, 0.80306, 0.80394, 0.80826, 0.81116, 0.8111, 0.81464, 0.81578, 0.8163, 0.81624, 0.8157, 0.81456, 0.81788, 0.81946, 0.81778,
-0.82158, 0.8182, 0.8181, 0.81888, 0.81892, 0.81658, 0.81876, 0.81652, 0.82196, 
-//185
-0.1315, 0.15932, 0.20548, 0.26142, 0.31306, 0.34502, 0.35722, 0.35872, 0.35642, 0.35366, 0.35318, 0.35188, 0.3507, 0.35026, 0.34932, 0.34918, 0.35018, 0.3519,
-0.35238, 0.35114, 0.34868, 0.34474, 0.34052, 0.34244, 0.35092, 0.36688, 0.3898, 0.40808, 0.41424, 0.41326, 0.40912, 0.40968, 0.41808, 0.44282, 0.48452, 0.5311,
-0.57082, 0.60322, 0.61752, 0.62594, 0.63098, 0.63284, 0.63664, 0.63648, 0.63632, 0.63592, 0.63626, 0.6349, 0.63322, 0.63374, 0.63512, 0.63292, 0.63152, 0.631,
-0.62982, 0.63, 0.6309, 0.6299, 0.6294, 0.6296, 0.6258, 0.62336, 0.624, 0.62672, 0.62546, 0.6242, 0.62374, 0.62282, 0.62188, 0.6235, 0.62478, 0.62266, 0.62506,
-0.6204, 0.62064, 0.61968, 0.61948, 0.62244, 0.62148, 0.61894, 0.62154, 
-//186
-0.12922, 0.15088, 0.18508, 0.22058, 0.24746, 0.25962, 0.2631, 0.26094, 0.25878, 0.25582, 0.25518, 0.25394, 0.25312, 0.25198, 0.25116, 0.24996, 0.25122, 0.25298,
-0.2537, 0.25266, 0.24986, 0.24518, 0.24196, 0.243, 0.24974, 0.26296, 0.27956, 0.29064, 0.2934, 0.2893, 0.2861, 0.2875, 0.2955, 0.31672, 0.35012, 0.38948,
-0.42344, 0.44748, 0.45962, 0.4647, 0.4674, 0.46812, 0.46938, 0.46954, 0.46938, 0.46776, 0.46806, 0.46786, 0.46624, 0.4653, 0.46466, 0.46338, 0.46166, 0.46174,
-0.4607, 0.45868, 0.45898, 0.45864, 0.456, 0.45642, 0.45506, 0.45438, 0.45384, 0.45302, 0.4519, 0.45164, 0.45248, 0.4509, 0.44988, 0.4518, 0.4502, 0.44822,
-0.4495, 0.44838, 0.44766, 0.44666, 0.44698, 0.44576, 0.44544, 0.44176, 0.44368, 
-//187
-0.10166, 0.11424, 0.13312, 0.14874, 0.15932, 0.1618, 0.16376, 0.16164, 0.16076, 0.15958, 0.16032, 0.16172, 0.1624, 0.16156, 0.1631, 0.1624, 0.16366, 0.16368,
-0.16626, 0.16742, 0.16556, 0.16324, 0.16218, 0.16322, 0.16748, 0.17732, 0.18828, 0.19476, 0.1977, 0.19756, 0.1974, 0.19912, 0.20682, 0.22084, 0.24064, 0.2633,
-0.27852, 0.29708, 0.30888, 0.31652, 0.323, 0.3288, 0.33252, 0.33504, 0.33698, 0.33754, 0.33838, 0.33888, 0.34012, 0.3394, 0.33988, 0.33964, 0.33812, 0.3381,
-0.33836, 0.33858, 0.33902, 0.33828, 0.33728, 0.33688, 0.33616, 0.33512, 0.33572, 0.336, 0.33756, 0.3378, 0.33804, 0.33914, 0.33968, 0.33932, 0.33834, 0.33852,
-0.33936, 0.33664, 0.3381, 0.33766, 0.33806, 0.33754, 0.3377, 0.3355, 0.33404, 
-//188
-0.0855, 0.09388, 0.10316, 0.10984, 0.11326, 0.11184, 0.11104, 0.10828, 0.10552, 0.10424, 0.10542, 0.10388, 0.10342, 0.10228, 0.10176, 0.10136, 0.10204, 0.10266,
-0.10282, 0.10294, 0.10146, 0.09836, 0.09764, 0.09776, 0.10254, 0.10766, 0.11386, 0.11646, 0.11686, 0.1148, 0.11438, 0.11722, 0.12342, 0.13492, 0.15328, 0.1773,
-0.19616, 0.21748, 0.22588, 0.2293, 0.2307, 0.2314, 0.23294, 0.23114, 0.23202, 0.23124, 0.2301, 0.22832, 0.22718, 0.22608, 0.22528, 0.22452, 0.22516, 0.22406,
-0.22352, 0.22164, 0.21898, 0.21788, 0.21946, 0.22016, 0.21966, 0.21694, 0.2156, 0.21726, 0.21772, 0.21812, 0.21736, 0.21654, 0.21598, 0.21684, 0.21608, 0.21682,
-0.21748, 0.21572, 0.21528, 0.21596, 0.21428, 0.2153, 0.21542, 0.21558, 0.21584, 
-//189
-0.06048, 0.06044, 0.06058, 0.06018, 0.05956, 0.06124, 0.06198, 0.06102, 0.06208, 0.06124, 0.06044, 0.05912, 0.0603, 0.0596, 0.06, 0.06102, 0.06056, 0.05972,
-0.05984, 0.06008, 0.06124, 0.06106, 0.06164, 0.06178, 0.06318, 0.06666, 0.07112, 0.07348, 0.07536, 0.07584, 0.07698, 0.07738, 0.07904, 0.08356, 0.09038,
-0.09912, 0.1065, 0.12276, 0.137, 0.14898, 0.15612, 0.16086, 0.16386, 0.16484, 0.16486, 0.16392, 0.16542, 0.16484, 0.1637, 0.16172, 0.15994, 0.15952, 0.15946,
-0.15856, 0.15838, 0.15606, 0.1563, 0.15274, 0.15272, 0.1528, 0.15378, 0.15116, 0.15046, 0.1515, 0.15256, 0.15212, 0.15228, 0.15076, 0.15204, 0.15238, 0.15278,
-0.15318, 0.15214, 0.15186, 0.15196, 0.15166, 0.1526, 0.15216, 0.15134, 0.15068, 0.15142, 
-//190
-0.1177, 0.13936, 0.17662, 0.2199, 0.25492, 0.2726, 0.27686, 0.27482, 0.27452, 0.27228, 0.2714, 0.2714, 0.27034, 0.27014, 0.2702, 0.26912, 0.27164, 0.27446,
-0.27694, 0.27634, 0.27574, 0.26954, 0.26612, 0.26934, 0.27968, 0.29792, 0.32254, 0.3431, 0.35072, 0.34892, 0.34874, 0.35198, 0.36836, 0.40096, 0.45184, 0.5103,
-0.56212, 0.60742, 0.63388, 0.65298, 0.66294, 0.6695, 0.67532, 0.67786, 0.6801, 0.68222, 0.68524, 0.68412, 0.68272, 0.684, 0.68634, 0.68544, 0.6862, 0.68694,
-0.68502, 0.68548, 0.68684, 0.68492, 0.68406, 0.68582, 0.68534, 0.68712, 0.68956, 0.68932, 0.6899, 0.69194, 0.69178, 0.6913, 0.69278, 0.69234, 0.69412, 0.69606,
-0.6977, 0.69578, 0.69568, 0.69674, 0.69684, 0.69536, 0.69698, 0.69534, 0.69864, 
-//191
-0.11554, 0.134, 0.1618, 0.18358, 0.19774, 0.20286, 0.20306, 0.19938, 0.19946, 0.19688, 0.19666, 0.19556, 0.19544, 0.19406, 0.1948, 0.19498, 0.19456, 0.19576,
-0.19758, 0.19778, 0.19422, 0.19058, 0.18978, 0.19112, 0.1989, 0.21474, 0.23404, 0.24838, 0.25534, 0.25676, 0.25336, 0.25466, 0.26536, 0.28676, 0.32734, 0.378,
-0.42532, 0.46832, 0.49204, 0.5034, 0.5099, 0.514, 0.51876, 0.51896, 0.5207, 0.52038, 0.51926, 0.5202, 0.51878, 0.51776, 0.51788, 0.51676, 0.51548, 0.51518,
-0.51406, 0.51172, 0.5113, 0.51208, 0.51126, 0.51122, 0.50868, 0.50712, 0.50668, 0.50816, 0.50802, 0.50664, 0.5074, 0.50696, 0.50748, 0.5069, 0.506, 0.50532,
-0.50562, 0.50282, 0.50426, 0.5023, 0.5032, 0.50366, 0.50434, 0.4995, 0.50208, 
-//192
-0.09256, 0.10246, 0.115, 0.12344, 0.12834, 0.12758, 0.12622, 0.1252, 0.12428, 0.12232, 0.12274, 0.12194, 0.12154, 0.11998, 0.11974, 0.12032, 0.12158, 0.12354,
-0.12346, 0.1225, 0.1205, 0.11774, 0.11806, 0.11968, 0.12504, 0.13492, 0.1476, 0.15554, 0.15784, 0.15676, 0.1568, 0.15806, 0.16658, 0.183, 0.2126, 0.252,
-0.29032, 0.32612, 0.34788, 0.35774, 0.36338, 0.36662, 0.36886, 0.37026, 0.37198, 0.37038, 0.37, 0.3688, 0.36662, 0.36718, 0.3666, 0.36566, 0.36562, 0.36384,
-0.36342, 0.36178, 0.36126, 0.36038, 0.35992, 0.36008, 0.35926, 0.35702, 0.35608, 0.3573, 0.35754, 0.35804, 0.357, 0.3568, 0.35666, 0.3555, 0.35688, 0.35692,
-0.35714, 0.35484, 0.3556, 0.35324, 0.35206, 0.35324, 0.35376, 0.35234, 0.35162, 
-//193
-0.06626, 0.06978, 0.07486, 0.07542, 0.07608, 0.07388, 0.07342, 0.07302, 0.07348, 0.07148, 0.07184, 0.0718, 0.07102, 0.07012, 0.06922, 0.07126, 0.07204, 0.07262,
-0.07224, 0.07152, 0.07034, 0.0691, 0.07206, 0.07386, 0.07622, 0.08044, 0.08734, 0.09208, 0.09298, 0.09376, 0.09618, 0.09764, 0.10388, 0.11366, 0.13236, 0.15954,
-0.18648, 0.21802, 0.23562, 0.24752, 0.25256, 0.25564, 0.2582, 0.25842, 0.25634, 0.25832, 0.25746, 0.25642, 0.25596, 0.25504, 0.25288, 0.25266, 0.25242, 0.25264,
-0.25078, 0.24886, 0.24876, 0.24472, 0.24592, 0.24786, 0.24854, 0.24604, 0.24486, 0.24732, 0.24686, 0.24644, 0.24614, 0.24488, 0.24738, 0.24614, 0.2469, 0.24656,
-0.24812, 0.24656, 0.24566, 0.24556, 0.24766, 0.24868, 0.24678, 0.24618, 0.24612, 
-//194
-0.04418, 0.04574, 0.04676, 0.04636, 0.04638, 0.04496, 0.04484, 0.04416, 0.0452, 0.04712, 0.04604, 0.04584, 0.04572, 0.04386, 0.04466, 0.04448, 0.04678, 0.04756,
-0.0474, 0.0476, 0.04664, 0.04554, 0.04752, 0.049, 0.05288, 0.05618, 0.06006, 0.06296, 0.06486, 0.06606, 0.06826, 0.07094, 0.0738, 0.07672, 0.0837, 0.0914,
-0.10116, 0.12278, 0.13916, 0.15282, 0.16388, 0.1715, 0.17736, 0.17892, 0.18086, 0.1805, 0.17936, 0.17814, 0.17724, 0.17588, 0.176, 0.17584, 0.17336, 0.17144,
-0.17204, 0.17154, 0.17178, 0.16846, 0.169, 0.1685, 0.16694, 0.165, 0.16436, 0.16768, 0.16718, 0.16686, 0.16708, 0.16514, 0.16688, 0.16748, 0.16762, 0.16716,
-0.16776, 0.16658, 0.16588, 0.16656, 0.16738, 0.1677, 0.16822, 0.16652, 0.16632, 
-//195
-0.126, 0.14604, 0.17394, 0.1998, 0.21796, 0.2247, 0.22414, 0.2222, 0.2205, 0.21772, 0.2172, 0.2167, 0.21636, 0.21642, 0.2175, 0.21644, 0.21734, 0.21828,
-0.21882, 0.2184, 0.21854, 0.21668, 0.2161, 0.2179, 0.22678, 0.2474, 0.27908, 0.3117, 0.33256, 0.34098, 0.34276, 0.34646, 0.35908, 0.38844, 0.43594, 0.49858,
-0.56428, 0.62142, 0.66536, 0.6939, 0.71298, 0.72958, 0.74392, 0.74974, 0.75466, 0.75834, 0.75976, 0.76172, 0.76346, 0.76464, 0.76584, 0.76776, 0.7674, 0.76768,
-0.77082, 0.77026, 0.77164, 0.77204, 0.77054, 0.77196, 0.77194, 0.77238, 0.77218, 0.77398, 0.77446, 0.77478, 0.77646, 0.77628, 0.7769, 0.78074, 0.78112, 0.78176,
-0.78266, 0.7804, 0.78142, 0.78444, 0.7852, 0.78782, 0.79008, 0.78428, 0.784, 
-//196
-0.1053, 0.1172, 0.13214, 0.14306, 0.15078, 0.15194, 0.1506, 0.14958, 0.14762, 0.14488, 0.14596, 0.1452, 0.14432, 0.14398, 0.1442, 0.14252, 0.144, 0.14682,
-0.14802, 0.14718, 0.1455, 0.14258, 0.14262, 0.14494, 0.15358, 0.16868, 0.19108, 0.2105, 0.221, 0.2243, 0.22548, 0.2292, 0.2397, 0.26352, 0.3055, 0.36344,
-0.41996, 0.47314, 0.50726, 0.52764, 0.54034, 0.54874, 0.55526, 0.55768, 0.55916, 0.56072, 0.56116, 0.56266, 0.56124, 0.55954, 0.55892, 0.5583, 0.5583, 0.55756,
-0.5587, 0.55662, 0.55548, 0.5549, 0.55562, 0.5562, 0.5545, 0.55396, 0.55244, 0.5528, 0.55536, 0.55284, 0.55176, 0.55228, 0.5518, 0.5532, 0.55418, 0.554,
-0.55548, 0.55228, 0.5519, 0.55112, 0.55284, 0.55084, 0.55062, 0.54758, 0.55202, 
-//197
-0.08024, 0.08484, 0.0905, 0.0948, 0.09676, 0.09614, 0.09588, 0.09454, 0.09396, 0.09272, 0.09324, 0.09276, 0.09112, 0.09136, 0.09122, 0.09204, 0.09398, 0.094,
-0.0939, 0.0932, 0.0919, 0.09186, 0.09268, 0.09634, 0.10094, 0.11002, 0.12308, 0.13412, 0.13974, 0.14128, 0.1417, 0.1445, 0.15212, 0.1675, 0.19848, 0.24382,
-0.28982, 0.336, 0.365, 0.38168, 0.39336, 0.39936, 0.4029, 0.40676, 0.41038, 0.40914, 0.40892, 0.40728, 0.4058, 0.40498, 0.40592, 0.40524, 0.4047, 0.40222,
-0.40076, 0.3994, 0.40028, 0.3991, 0.39996, 0.3997, 0.3988, 0.39576, 0.39504, 0.39772, 0.39828, 0.39648, 0.39578, 0.39488, 0.39428, 0.39746, 0.39822, 0.39702,
-0.39586, 0.3945, 0.39462, 0.3935, 0.39556, 0.39596, 0.39684, 0.39564, 0.39284, 
-//198
-0.05194, 0.05014, 0.05196, 0.05178, 0.05158, 0.05176, 0.05354, 0.05346, 0.05396, 0.0535, 0.05316, 0.05296, 0.05424, 0.05458, 0.05602, 0.05622, 0.0555, 0.05386,
-0.0554, 0.05498, 0.0557, 0.05782, 0.05812, 0.05994, 0.0631, 0.06912, 0.0766, 0.0838, 0.08736, 0.08784, 0.08984, 0.0925, 0.0969, 0.10736, 0.12608, 0.15322,
-0.18744, 0.22456, 0.24908, 0.26486, 0.2737, 0.27992, 0.28596, 0.2884, 0.28894, 0.28922, 0.28878, 0.28622, 0.28654, 0.2869, 0.28712, 0.28672, 0.28538, 0.28332,
-0.28278, 0.28324, 0.28434, 0.28302, 0.28448, 0.2838, 0.2826, 0.28192, 0.28212, 0.28434, 0.28466, 0.28372, 0.28444, 0.28424, 0.285, 0.28636, 0.28768, 0.2891,
-0.28904, 0.28802, 0.28806, 0.28876, 0.28968, 0.2902, 0.29256, 0.29086, 0.2911, 
-//199
-0.07846, 0.08638, 0.09662, 0.1051, 0.11, 0.10976, 0.10954, 0.10796, 0.10676, 0.10656, 0.10602, 0.10672, 0.10618, 0.10506, 0.10728, 0.10788, 0.10978, 0.10976,
-0.1097, 0.10988, 0.10924, 0.11074, 0.11154, 0.11426, 0.1202, 0.1333, 0.15308, 0.17428, 0.18776, 0.19122, 0.1922, 0.19718, 0.20802, 0.23584, 0.28312, 0.34604,
-0.4097, 0.4708, 0.51304, 0.54256, 0.56274, 0.57716, 0.58716, 0.595, 0.60054, 0.60134, 0.60386, 0.6058, 0.60678, 0.60838, 0.61138, 0.61092, 0.60956, 0.61234,
-0.61214, 0.6118, 0.61498, 0.61454, 0.61422, 0.6152, 0.61532, 0.61418, 0.61516, 0.6181, 0.61918, 0.61892, 0.62114, 0.62026, 0.62004, 0.62342, 0.62502, 0.62548,
-0.62914, 0.62632, 0.62546, 0.62658, 0.62654, 0.62798, 0.62968, 0.62744, 0.63128, 
-//200
-0.05806, 0.06006, 0.06312, 0.06496, 0.06506, 0.06388, 0.06312, 0.06258, 0.06406, 0.06316, 0.0625, 0.06262, 0.06188, 0.06178, 0.0625, 0.06322, 0.06336, 0.0635,
-0.06416, 0.06426, 0.06488, 0.065, 0.06448, 0.06582, 0.06984, 0.0773, 0.09124, 0.10454, 0.11078, 0.11112, 0.11192, 0.11372, 0.12418, 0.14636, 0.18246, 0.2341,
-0.28986, 0.34284, 0.37898, 0.40556, 0.41926, 0.42714, 0.4323, 0.43524, 0.4351, 0.43766, 0.43878, 0.43916, 0.43932, 0.43718, 0.437, 0.43752, 0.4397, 0.43886,
-0.4381, 0.43816, 0.43876, 0.43674, 0.43856, 0.43942, 0.43976, 0.43654, 0.436, 0.43678, 0.43854, 0.43904, 0.44088, 0.4409, 0.4418, 0.44158, 0.44314, 0.44422,
-0.4451, 0.44366, 0.44606, 0.44422, 0.44672, 0.44606, 0.44722, 0.44486, 0.44562, 
-//201
-0.06902, 0.06954, 0.07228, 0.07574, 0.07788, 0.07842, 0.07764, 0.0757, 0.07522, 0.0747, 0.0761, 0.07604, 0.07658, 0.0768, 0.0762, 0.07546, 0.07736, 0.07826,
-0.07918, 0.0786, 0.07902, 0.08008, 0.08088, 0.08486, 0.0915, 0.10426, 0.12594, 0.15086, 0.1724, 0.18486, 0.18962, 0.19396, 0.20462, 0.22692, 0.26676, 0.32682,
-0.39448, 0.46342, 0.51908, 0.56148, 0.59114, 0.61646, 0.63666, 0.64868, 0.6584, 0.66404, 0.66894, 0.67108, 0.67442, 0.67646, 0.6788, 0.67878, 0.67976, 0.6811,
-0.68332, 0.68392, 0.68804, 0.68544, 0.6868, 0.68892, 0.68666, 0.6871, 0.68752, 0.6907, 0.6922, 0.6939, 0.69642, 0.69608, 0.69928, 0.70366, 0.70298, 0.70432,
-0.70608, 0.70262, 0.70526, 0.70726, 0.70862, 0.70718, 0.71, 0.70824, 0.70948, 
-//202
-0.05304, 0.05282, 0.05218, 0.05082, 0.05234, 0.0527, 0.05242, 0.05314, 0.05332, 0.05216, 0.0507, 0.05256, 0.05382, 0.05328, 0.05434, 0.05334, 0.05416, 0.05406,
-0.05516, 0.05544, 0.05692, 0.05696, 0.05662, 0.05934, 0.06522, 0.07182, 0.08364, 0.09964, 0.1128, 0.11924, 0.12584, 0.13226, 0.14146, 0.15662, 0.18548, 0.22734,
-0.27726, 0.33254, 0.37094, 0.39792, 0.41926, 0.43644, 0.4493, 0.46234, 0.47268, 0.47628, 0.47956, 0.48336, 0.48638, 0.48998, 0.49438, 0.49708, 0.49832, 0.50134,
-0.50546, 0.50678, 0.50938, 0.51114, 0.51126, 0.5164, 0.51818, 0.51854, 0.52046, 0.52774, 0.52844, 0.53048, 0.53314, 0.5358, 0.53764, 0.54132, 0.54414, 0.5458,
-0.54912, 0.55202, 0.55278, 0.55546, 0.5586, 0.5591, 0.56074, 0.56108, 0.56812, 
-//203
-0.1463, 0.17898, 0.23558, 0.31506, 0.40978, 0.4949, 0.54306, 0.56084, 0.56464, 0.5625, 0.56066, 0.56084, 0.56034, 0.55996, 0.56094, 0.5608, 0.5621, 0.56292,
-0.5625, 0.56324, 0.55982, 0.55712, 0.55516, 0.55562, 0.56596, 0.58544, 0.6103, 0.6298, 0.64054, 0.64308, 0.642, 0.64474, 0.65376, 0.67136, 0.69854, 0.7259,
-0.7453, 0.76132, 0.76746, 0.77144, 0.77206, 0.7731, 0.77484, 0.77308, 0.7736, 0.77344, 0.7741, 0.77338, 0.77128, 0.77092, 0.77112, 0.77198, 0.7715, 0.77186,
-0.77212, 0.76804, 0.76966, 0.76828, 0.76628, 0.76876, 0.76996, 0.76854, 0.76856, 0.77212, 0.77038, 0.76968, 0.77172, 0.77168, 0.77038, 0.7738, 0.773, 0.77146,
-0.7753, 0.77348, 0.77204, 0.77268, 0.77304, 0.77342, 0.77366, 0.77254, 0.7745, 
-//204
-0.1384, 0.16666, 0.2155, 0.27608, 0.33628, 0.38056, 0.40138, 0.4057, 0.40632, 0.40776, 0.40876, 0.41034, 0.41248, 0.4129, 0.41352, 0.41508, 0.4173, 0.41866,
-0.42096, 0.42172, 0.41916, 0.41788, 0.41848, 0.4213, 0.42862, 0.4372, 0.44756, 0.45484, 0.46016, 0.46512, 0.46816, 0.47016, 0.47726, 0.48538, 0.49756, 0.50804,
-0.51582, 0.52922, 0.53544, 0.54314, 0.54762, 0.54942, 0.55354, 0.55728, 0.56096, 0.56022, 0.56208, 0.56338, 0.56222, 0.56148, 0.56486, 0.566, 0.56562, 0.56622,
-0.56758, 0.56586, 0.56662, 0.56586, 0.56792, 0.5697, 0.56886, 0.56756, 0.56876, 0.56968, 0.56912, 0.57086, 0.57332, 0.57232, 0.57132, 0.5739, 0.5745, 0.57374,
-0.57532, 0.57592, 0.57758, 0.57576, 0.57668, 0.57672, 0.57856, 0.57506, 0.58022, 
-//205
-0.1305, 0.15614, 0.1976, 0.24356, 0.27992, 0.29888, 0.30472, 0.30414, 0.30306, 0.29966, 0.29698, 0.29618, 0.2946, 0.29254, 0.2916, 0.29156, 0.29208, 0.29248,
-0.2917, 0.29102, 0.29032, 0.28528, 0.28302, 0.2831, 0.28914, 0.3005, 0.31602, 0.32816, 0.33116, 0.32834, 0.32558, 0.32694, 0.3342, 0.34772, 0.36876, 0.38902,
-0.40132, 0.41268, 0.41632, 0.41784, 0.41892, 0.41846, 0.41674, 0.41558, 0.41522, 0.41354, 0.41274, 0.4135, 0.41056, 0.40892, 0.40776, 0.40576, 0.40506, 0.4055,
-0.40482, 0.4025, 0.40074, 0.3986, 0.39698, 0.39846, 0.39706, 0.39418, 0.39264, 0.39346, 0.39342, 0.39344, 0.39384, 0.39102, 0.3902, 0.39074, 0.3909, 0.38872,
-0.38974, 0.38808, 0.38698, 0.388, 0.38732, 0.38546, 0.38554, 0.38368, 0.38148, 
-//206
-0.11166, 0.1304, 0.1591, 0.1834, 0.19886, 0.20558, 0.20678, 0.20498, 0.20416, 0.20286, 0.20174, 0.19842, 0.19726, 0.19704, 0.19692, 0.1974, 0.19756, 0.19866,
-0.19862, 0.1972, 0.19508, 0.19274, 0.19014, 0.18878, 0.1936, 0.20392, 0.2138, 0.21928, 0.22072, 0.21838, 0.21566, 0.21488, 0.22226, 0.23454, 0.25146, 0.26688,
-0.275, 0.28742, 0.29248, 0.29164, 0.29276, 0.29158, 0.28998, 0.28944, 0.2897, 0.29016, 0.28906, 0.2873, 0.28482, 0.28282, 0.28312, 0.28298, 0.28242, 0.28216,
-0.28068, 0.2778, 0.27726, 0.2753, 0.27568, 0.27674, 0.27606, 0.27234, 0.27092, 0.27246, 0.27364, 0.27312, 0.27342, 0.27074, 0.2694, 0.27066, 0.27074, 0.26984,
-0.27088, 0.26934, 0.26906, 0.2687, 0.26938, 0.26802, 0.26766, 0.26644, 0.26558, 
-//207
-0.09702, 0.10682, 0.12236, 0.13564, 0.14338, 0.1457, 0.1441, 0.14246, 0.1415, 0.1389, 0.13874, 0.13774, 0.1375, 0.13508, 0.13552, 0.1355, 0.13672, 0.13674,
-0.13622, 0.1346, 0.13256, 0.1319, 0.13182, 0.13186, 0.13524, 0.1387, 0.1439, 0.14932, 0.14984, 0.14748, 0.14812, 0.14776, 0.15144, 0.15994, 0.175, 0.18692,
-0.19346, 0.20472, 0.2071, 0.20412, 0.20594, 0.20604, 0.20706, 0.20642, 0.20526, 0.20216, 0.20008, 0.20118, 0.20086, 0.20082, 0.20036, 0.19878, 0.19888, 0.19674,
-0.19584, 0.19608, 0.19496, 0.19252, 0.1939, 0.19434, 0.19356, 0.19096, 0.1909, 0.19254, 0.19286, 0.19322, 0.1941, 0.19152, 0.19046, 0.19118, 0.1925, 0.1913,
-0.193, 0.19096, 0.1892, 0.1889, 0.19074, 0.18946, 0.19026, 0.1901, 0.19138, 
-//208
-0.07212, 0.07724, 0.082, 0.08474, 0.08472, 0.08434, 0.08372, 0.08324, 0.08218, 0.08096, 0.0813, 0.08074, 0.08076, 0.08032, 0.07954, 0.0785, 0.0793, 0.08002,
-0.08034, 0.0801, 0.07924, 0.07698, 0.0771, 0.0777, 0.07978, 0.08338, 0.08684, 0.0873, 0.08672, 0.08686, 0.08712, 0.08884, 0.09326, 0.10032, 0.10936, 0.11998,
-0.12776, 0.13348, 0.13562, 0.13598, 0.13516, 0.13456, 0.13388, 0.13184, 0.13236, 0.1323, 0.13214, 0.1304, 0.12944, 0.12804, 0.12806, 0.12766, 0.12772, 0.12696,
-0.12676, 0.12516, 0.12484, 0.12422, 0.12474, 0.12478, 0.12448, 0.1243, 0.12342, 0.12396, 0.12424, 0.12484, 0.12454, 0.12436, 0.12464, 0.1249, 0.12492, 0.12544,
-0.1257, 0.1251, 0.12646, 0.12626, 0.12544, 0.12574, 0.1264, 0.1247, 0.12554, 
-//209
-0.0453, 0.04436, 0.04296, 0.04244, 0.0435, 0.04392, 0.04574, 0.04786, 0.04582, 0.04306, 0.04348, 0.04402, 0.0458, 0.04566, 0.04688, 0.04688, 0.04398, 0.04434,
-0.0452, 0.0443, 0.04536, 0.0457, 0.04576, 0.04542, 0.04834, 0.0486, 0.04676, 0.0462, 0.0467, 0.04758, 0.05238, 0.05516, 0.05698, 0.05818, 0.06132, 0.06178,
-0.05548, 0.06248, 0.06394, 0.06724, 0.073, 0.07882, 0.08204, 0.08142, 0.08104, 0.0809, 0.07964, 0.07876, 0.07796, 0.0762, 0.07588, 0.0755, 0.07516, 0.07374,
-0.07358, 0.0725, 0.07166, 0.06978, 0.07258, 0.07392, 0.0718, 0.06832, 0.07134, 0.07444, 0.07272, 0.07182, 0.07432, 0.07442, 0.07576, 0.07708, 0.0776, 0.07692,
-0.07682, 0.07768, 0.07882, 0.07958, 0.07998, 0.07882, 0.08054, 0.08084, 0.0811, 
-//210
-0.12938, 0.16014, 0.2098, 0.27578, 0.34628, 0.39804, 0.4219, 0.43048, 0.4296, 0.42842, 0.4283, 0.42784, 0.42724, 0.42744, 0.4288, 0.43032, 0.43138, 0.43422,
-0.43452, 0.43504, 0.43428, 0.4318, 0.42966, 0.43362, 0.44806, 0.47292, 0.50748, 0.54144, 0.56118, 0.56946, 0.57266, 0.58018, 0.59512, 0.62346, 0.66538, 0.71056,
-0.74398, 0.7742, 0.78804, 0.79494, 0.79898, 0.80302, 0.80422, 0.80356, 0.8033, 0.80438, 0.8039, 0.80514, 0.80512, 0.80384, 0.805, 0.80582, 0.80494, 0.80592,
-0.80624, 0.80432, 0.80098, 0.79782, 0.79568, 0.799, 0.80204, 0.8048, 0.80522, 0.80448, 0.80676, 0.80784, 0.80862, 0.80814, 0.80828, 0.81012, 0.80956, 0.8104,
-0.81456, 0.811, 0.80948, 0.81168, 0.81086, 0.81046, 0.81298, 0.80998, 0.80938, 
-//211
-0.126, 0.15172, 0.1915, 0.24088, 0.2849, 0.3117, 0.32166, 0.323, 0.32172, 0.31856, 0.31804, 0.3178, 0.3193, 0.32016, 0.32066, 0.3204, 0.3221, 0.3241, 0.32384,
-0.32458, 0.3228, 0.3187, 0.31704, 0.32162, 0.33342, 0.35286, 0.3815, 0.4058, 0.41918, 0.42532, 0.4293, 0.43206, 0.44326, 0.46348, 0.49338, 0.52566, 0.5516,
-0.57518, 0.58592, 0.59438, 0.6003, 0.60314, 0.6101, 0.61068, 0.6122, 0.6131, 0.61382, 0.61394, 0.61322, 0.61448, 0.61456, 0.61366, 0.61252, 0.61446, 0.61316,
-0.61226, 0.61336, 0.61044, 0.60932, 0.6141, 0.6142, 0.61048, 0.60896, 0.61298, 0.61186, 0.61188, 0.61376, 0.61192, 0.61042, 0.61272, 0.61422, 0.61328, 0.61466,
-0.61342, 0.6108, 0.61124, 0.61406, 0.61234, 0.61072, 0.60878, 0.60948, 
-//212
-0.11896, 0.14006, 0.16678, 0.1953, 0.21658, 0.22908, 0.23056, 0.22922, 0.2269, 0.22292, 0.22192, 0.22078, 0.22086, 0.21804, 0.21828, 0.2192, 0.21776, 0.21832,
-0.22, 0.22098, 0.2174, 0.21346, 0.21286, 0.21336, 0.22388, 0.23894, 0.25656, 0.27254, 0.27888, 0.28008, 0.27966, 0.28194, 0.29024, 0.30748, 0.33842, 0.37116,
-0.39576, 0.41782, 0.4246, 0.42808, 0.43138, 0.42966, 0.43018, 0.43286, 0.43158, 0.42938, 0.42822, 0.42858, 0.4255, 0.42564, 0.42606, 0.42504, 0.42306, 0.42404,
-0.4219, 0.4175, 0.4177, 0.41786, 0.41612, 0.4178, 0.41602, 0.41512, 0.41204, 0.4109, 0.41318, 0.41276, 0.41266, 0.41176, 0.41064, 0.41162, 0.40906, 0.41094,
-0.41282, 0.40868, 0.40752, 0.40946, 0.40556, 0.4077, 0.40574, 0.40232, 0.39794, 
-//213
-0.11106, 0.12382, 0.14064, 0.15506, 0.16314, 0.16436, 0.1657, 0.16488, 0.16234, 0.15878, 0.1563, 0.15604, 0.15662, 0.15686, 0.15726, 0.15606, 0.15624, 0.15588,
-0.15636, 0.15676, 0.15646, 0.15492, 0.15292, 0.15394, 0.15882, 0.17116, 0.18592, 0.19728, 0.2031, 0.20286, 0.2027, 0.20494, 0.21032, 0.2246, 0.247, 0.27422,
-0.29638, 0.31474, 0.32244, 0.32636, 0.32788, 0.32714, 0.32878, 0.32924, 0.32814, 0.32658, 0.32582, 0.32404, 0.32208, 0.32162, 0.32328, 0.323, 0.32014, 0.31792,
-0.31678, 0.31616, 0.31614, 0.31494, 0.31604, 0.31504, 0.3121, 0.30768, 0.31, 0.31774, 0.31308, 0.31014, 0.31244, 0.31016, 0.30754, 0.31298, 0.31018, 0.31054,
-0.3102, 0.30762, 0.30736, 0.30896, 0.3099, 0.31014, 0.30938, 0.3079, 0.31168, 
-//214
-0.0859, 0.08688, 0.09222, 0.09676, 0.10008, 0.10134, 0.1021, 0.10004, 0.09688, 0.095, 0.09534, 0.09576, 0.09692, 0.09624, 0.0958, 0.09396, 0.09478, 0.09482,
-0.09736, 0.09654, 0.09562, 0.09486, 0.09436, 0.0948, 0.09944, 0.10644, 0.11434, 0.1194, 0.12322, 0.12254, 0.12274, 0.12392, 0.12886, 0.13994, 0.15956, 0.18302,
-0.20028, 0.21608, 0.22314, 0.22506, 0.22548, 0.22598, 0.22704, 0.22514, 0.22428, 0.22398, 0.22296, 0.22188, 0.22132, 0.22156, 0.21988, 0.21884, 0.21748,
-0.21616, 0.21656, 0.21548, 0.21638, 0.2154, 0.21514, 0.21414, 0.21252, 0.21132, 0.21108, 0.21212, 0.21094, 0.21164, 0.21192, 0.2116, 0.21176, 0.21286, 0.2112,
-0.21088, 0.2126, 0.21052, 0.20986, 0.20992, 0.21094, 0.2112, 0.20956, 0.20932, 0.20636, 
-//215
-0.05096, 0.04988, 0.0497, 0.0497, 0.05102, 0.05132, 0.05228, 0.0507, 0.04942, 0.05022, 0.05126, 0.05242, 0.05362, 0.0532, 0.05162, 0.05042, 0.05234, 0.05204,
-0.05276, 0.05302, 0.05224, 0.05158, 0.0531, 0.05578, 0.05836, 0.06016, 0.06282, 0.06322, 0.06534, 0.06774, 0.06982, 0.07236, 0.0757, 0.08274, 0.09412, 0.10948,
-0.12348, 0.1404, 0.1485, 0.15176, 0.15188, 0.151, 0.15128, 0.15012, 0.14914, 0.14768, 0.14558, 0.1446, 0.1447, 0.14282, 0.14268, 0.14118, 0.13878, 0.1377,
-0.13858, 0.1377, 0.13792, 0.13552, 0.13468, 0.13472, 0.13332, 0.13278, 0.13076, 0.13482, 0.13382, 0.13502, 0.13334, 0.13452, 0.13412, 0.13536, 0.13416, 0.13454,
-0.1343, 0.13404, 0.13436, 0.13526, 0.13632, 0.1354, 0.13402, 0.13256, 0.13388, 
-//216
-0.12708, 0.14986, 0.18342, 0.21838, 0.2459, 0.25896, 0.26334, 0.26368, 0.26328, 0.25906, 0.25802, 0.25788, 0.25864, 0.2601, 0.261, 0.26222, 0.26172, 0.26298,
-0.26536, 0.26532, 0.26508, 0.26224, 0.26182, 0.26434, 0.27682, 0.29946, 0.33246, 0.36328, 0.38338, 0.39098, 0.39468, 0.39974, 0.41324, 0.43992, 0.48292,
-0.53592, 0.58076, 0.61552, 0.63506, 0.64666, 0.65438, 0.65848, 0.66284, 0.66444, 0.66504, 0.66488, 0.6674, 0.66776, 0.6668, 0.66616, 0.66692, 0.66594, 0.66422,
-0.66648, 0.66554, 0.6636, 0.66454, 0.66482, 0.66446, 0.66652, 0.66646, 0.6635, 0.66258, 0.6653, 0.66662, 0.66576, 0.66656, 0.66478, 0.66522, 0.66598, 0.66672,
-0.66722, 0.66868, 0.66428, 0.66626, 0.66452, 0.66668, 0.66614, 0.66938, 0.66328, 0.66546, 
-//217
-0.10906, 0.12352, 0.14378, 0.16444, 0.17576, 0.1798, 0.17868, 0.1769, 0.17446, 0.17184, 0.1707, 0.17194, 0.17124, 0.17034, 0.17026, 0.17054, 0.17132, 0.17204,
-0.17244, 0.17144, 0.1707, 0.16954, 0.16978, 0.17306, 0.1809, 0.19612, 0.21982, 0.24366, 0.2592, 0.2635, 0.26546, 0.268, 0.27708, 0.29942, 0.33704, 0.38336,
-0.42208, 0.45392, 0.47124, 0.48062, 0.48404, 0.48704, 0.48882, 0.48942, 0.4885, 0.48888, 0.48858, 0.4864, 0.48426, 0.4839, 0.48494, 0.48338, 0.48012, 0.48106,
-0.4795, 0.4791, 0.479, 0.4781, 0.47684, 0.477, 0.47506, 0.47228, 0.47184, 0.47312, 0.47336, 0.4731, 0.4714, 0.46874, 0.46942, 0.47106, 0.4711, 0.47018, 0.46956,
-0.46784, 0.46606, 0.46696, 0.46798, 0.4664, 0.4655, 0.46106, 0.46604, 
-//218
-0.08896, 0.09628, 0.10718, 0.1139, 0.11722, 0.1166, 0.11536, 0.11332, 0.11366, 0.11326, 0.11332, 0.11326, 0.11252, 0.11106, 0.11238, 0.11236, 0.11578, 0.11544,
-0.11558, 0.11482, 0.11394, 0.1117, 0.11234, 0.11666, 0.12268, 0.13446, 0.15242, 0.16756, 0.17616, 0.1789, 0.1796, 0.18092, 0.18988, 0.20874, 0.23964, 0.277,
-0.3099, 0.342, 0.35696, 0.36216, 0.36554, 0.36852, 0.37122, 0.37092, 0.3717, 0.37138, 0.37142, 0.3691, 0.36816, 0.36706, 0.36838, 0.36646, 0.36442, 0.36494,
-0.36496, 0.36318, 0.36248, 0.36042, 0.3601, 0.3612, 0.3615, 0.36014, 0.35682, 0.35818, 0.3586, 0.36048, 0.36022, 0.35718, 0.35646, 0.35866, 0.3583, 0.35856,
-0.36022, 0.3573, 0.35688, 0.36114, 0.35808, 0.35674, 0.35642, 0.35548, 0.35594, 
-//219
-0.06084, 0.06316, 0.06616, 0.06768, 0.06964, 0.06878, 0.06876, 0.06692, 0.06668, 0.0665, 0.06702, 0.06708, 0.06596, 0.06726, 0.06804, 0.06766, 0.06748, 0.06778,
-0.06822, 0.06832, 0.069, 0.06982, 0.07016, 0.07216, 0.07392, 0.08132, 0.09282, 0.10176, 0.1078, 0.10858, 0.10812, 0.10912, 0.11642, 0.13012, 0.15154, 0.18058,
-0.2061, 0.23096, 0.24332, 0.2476, 0.25114, 0.2526, 0.25386, 0.25428, 0.25318, 0.25212, 0.25072, 0.25, 0.2505, 0.24898, 0.2481, 0.24592, 0.2442, 0.24326,
-0.24352, 0.24392, 0.2441, 0.24112, 0.24074, 0.24014, 0.23958, 0.23738, 0.23608, 0.23814, 0.23782, 0.23696, 0.2387, 0.2393, 0.2379, 0.23878, 0.2384, 0.2374,
-0.23818, 0.2376, 0.23862, 0.23706, 0.23724, 0.2354, 0.23344, 0.23398, 0.23624, 
-//220
-0.04608, 0.04582, 0.044, 0.04208, 0.042, 0.04304, 0.04362, 0.044, 0.0434, 0.04288, 0.04378, 0.04314, 0.0445, 0.04412, 0.0441, 0.04348, 0.04354, 0.04474,
-0.04492, 0.04564, 0.04504, 0.0441, 0.04554, 0.04674, 0.04994, 0.05372, 0.05874, 0.06208, 0.06332, 0.06564, 0.06792, 0.0688, 0.07518, 0.0832, 0.09522, 0.11046,
-0.12264, 0.13738, 0.14584, 0.15098, 0.15652, 0.15962, 0.16106, 0.16262, 0.16452, 0.16442, 0.16512, 0.16522, 0.16458, 0.1635, 0.16366, 0.16452, 0.16448, 0.1643,
-0.16432, 0.16274, 0.16336, 0.16312, 0.16414, 0.1659, 0.16616, 0.16312, 0.16448, 0.16678, 0.16768, 0.16848, 0.16862, 0.1684, 0.16996, 0.17096, 0.1721, 0.17124,
-0.17274, 0.1733, 0.17462, 0.17452, 0.17476, 0.17528, 0.17394, 0.1757, 0.177, 
-//221
-0.104, 0.1193, 0.14552, 0.1727, 0.18976, 0.19654, 0.19822, 0.1951, 0.19298, 0.19292, 0.19314, 0.1939, 0.19402, 0.19398, 0.1922, 0.19324, 0.1963, 0.19798,
-0.20026, 0.20048, 0.20008, 0.1978, 0.1974, 0.20064, 0.21122, 0.23296, 0.2664, 0.30526, 0.3366, 0.35434, 0.36296, 0.37168, 0.39218, 0.43286, 0.49202, 0.55728,
-0.6131, 0.65884, 0.6859, 0.70076, 0.70824, 0.7151, 0.7188, 0.72082, 0.72386, 0.72396, 0.72528, 0.72464, 0.7258, 0.72744, 0.72754, 0.7273, 0.7264, 0.72906,
-0.72922, 0.72952, 0.73242, 0.73132, 0.72928, 0.73118, 0.7303, 0.73134, 0.73046, 0.73414, 0.73554, 0.73552, 0.73642, 0.73764, 0.73896, 0.74044, 0.74112, 0.74244,
-0.74492, 0.74204, 0.74456, 0.7445, 0.74724, 0.74724, 0.74798, 0.74398, 0.74494, 
-//222
-0.0961, 0.10668, 0.11962, 0.12974, 0.13514, 0.13654, 0.13608, 0.1353, 0.13452, 0.13186, 0.1312, 0.13132, 0.13302, 0.13506, 0.13466, 0.13414, 0.13408, 0.13488,
-0.1359, 0.13574, 0.13606, 0.13548, 0.13472, 0.13714, 0.14662, 0.16642, 0.19484, 0.22468, 0.2477, 0.25672, 0.26126, 0.26598, 0.2776, 0.30442, 0.34394, 0.3975,
-0.44688, 0.49222, 0.51734, 0.53046, 0.53872, 0.5431, 0.54656, 0.54914, 0.55006, 0.5496, 0.54988, 0.5491, 0.54896, 0.549, 0.54936, 0.54902, 0.54656, 0.54682,
-0.54582, 0.54604, 0.54778, 0.5452, 0.54524, 0.54492, 0.54412, 0.54168, 0.54086, 0.5433, 0.54376, 0.54356, 0.54406, 0.54392, 0.5429, 0.5444, 0.54346, 0.54312,
-0.54774, 0.54408, 0.5442, 0.54298, 0.5464, 0.54614, 0.54712, 0.54498, 0.54356, 
-//223
-0.07436, 0.07438, 0.07814, 0.07962, 0.07924, 0.0791, 0.08068, 0.07962, 0.0792, 0.07636, 0.07664, 0.07782, 0.07842, 0.07796, 0.07898, 0.07904, 0.07964, 0.07908,
-0.08028, 0.0817, 0.08132, 0.08012, 0.08082, 0.0827, 0.08998, 0.10424, 0.12352, 0.14392, 0.15766, 0.16334, 0.1676, 0.17224, 0.18274, 0.2024, 0.23128, 0.26546,
-0.29356, 0.32336, 0.3434, 0.35526, 0.36458, 0.37026, 0.37608, 0.37904, 0.3827, 0.38514, 0.38884, 0.39014, 0.39088, 0.39024, 0.3933, 0.39348, 0.395, 0.39718,
-0.39828, 0.39634, 0.39742, 0.39692, 0.39928, 0.40254, 0.40276, 0.40068, 0.40372, 0.40708, 0.40866, 0.40846, 0.41156, 0.41242, 0.41328, 0.41696, 0.41818,
-0.41742, 0.4192, 0.41868, 0.4209, 0.4207, 0.42386, 0.4259, 0.42578, 0.42234, 0.42614, 
-//224
-0.05068, 0.04936, 0.0491, 0.0478, 0.04862, 0.0462, 0.04716, 0.04744, 0.049, 0.04938, 0.04754, 0.0466, 0.04606, 0.04676, 0.04852, 0.04768, 0.0498, 0.04928,
-0.04826, 0.04854, 0.04882, 0.05066, 0.05106, 0.05246, 0.05528, 0.062, 0.0716, 0.07986, 0.08742, 0.0889, 0.08948, 0.09326, 0.10252, 0.11808, 0.14344, 0.17686,
-0.20902, 0.24146, 0.25844, 0.26812, 0.27124, 0.27296, 0.27308, 0.27282, 0.2745, 0.2752, 0.27526, 0.27268, 0.27124, 0.26912, 0.26948, 0.271, 0.27106, 0.2709,
-0.27076, 0.26884, 0.26862, 0.26664, 0.26812, 0.26938, 0.26948, 0.26722, 0.26422, 0.26858, 0.26952, 0.27014, 0.27182, 0.2711, 0.26942, 0.27212, 0.273, 0.27288,
-0.27292, 0.27296, 0.2733, 0.27278, 0.27442, 0.27564, 0.27528, 0.273, 0.2748, 
-//225
-0.09022, 0.10072, 0.11672, 0.12772, 0.13606, 0.1406, 0.14112, 0.13986, 0.13816, 0.13682, 0.13616, 0.13738, 0.13866, 0.13884, 0.13856, 0.1382, 0.13938, 0.14206,
-0.1439, 0.14434, 0.1451, 0.14396, 0.14272, 0.14612, 0.15614, 0.17724, 0.21218, 0.25498, 0.29162, 0.3151, 0.33164, 0.34706, 0.37048, 0.41202, 0.47306, 0.544,
-0.61124, 0.66658, 0.69962, 0.72232, 0.73272, 0.74024, 0.74596, 0.74864, 0.75128, 0.75148, 0.75352, 0.7545, 0.7551, 0.75618, 0.7566, 0.75916, 0.75764, 0.759,
-0.76166, 0.76302, 0.76184, 0.75962, 0.759, 0.7597, 0.75974, 0.76284, 0.7629, 0.7667, 0.76712, 0.76682, 0.76734, 0.7689, 0.7686, 0.77136, 0.77288, 0.77182,
-0.77434, 0.77484, 0.77694, 0.77616, 0.77532, 0.77874, 0.77588, 0.7747, 0.77976, 
-//226
-0.0695, 0.0744, 0.08034, 0.08338, 0.08452, 0.08612, 0.08796, 0.08682, 0.08598, 0.08548, 0.08506, 0.08472, 0.08628, 0.0867, 0.08774, 0.08582, 0.08802, 0.0884,
-0.08996, 0.09042, 0.09062, 0.0908, 0.09038, 0.09328, 0.10248, 0.11902, 0.14436, 0.17398, 0.19942, 0.21544, 0.22602, 0.2351, 0.25168, 0.28138, 0.32676, 0.37958,
-0.42832, 0.47726, 0.50608, 0.5253, 0.53682, 0.54566, 0.55182, 0.55552, 0.5605, 0.56412, 0.56508, 0.56706, 0.56632, 0.56534, 0.5665, 0.5685, 0.56788, 0.56902,
-0.57036, 0.56978, 0.56966, 0.5688, 0.57068, 0.57292, 0.57148, 0.5706, 0.56862, 0.57474, 0.5758, 0.57646, 0.57714, 0.57932, 0.57734, 0.58064, 0.581, 0.58314,
-0.58616, 0.5854, 0.58584, 0.58412, 0.58838, 0.5891, 0.58902, 0.58362, 0.58292, 
-//227
-0.04612, 0.04716, 0.04772, 0.04808, 0.0512, 0.04936, 0.04918, 0.04894, 0.04902, 0.0509, 0.05052, 0.05088, 0.05286, 0.05042, 0.04946, 0.051, 0.05338, 0.0529,
-0.05336, 0.05352, 0.05402, 0.05396, 0.05592, 0.05934, 0.06408, 0.07402, 0.09076, 0.1103, 0.12834, 0.13856, 0.14384, 0.14998, 0.15994, 0.18108, 0.21394, 0.25678,
-0.2961, 0.3336, 0.35794, 0.37504, 0.38782, 0.39602, 0.4013, 0.40478, 0.40692, 0.40914, 0.41222, 0.41406, 0.4158, 0.41462, 0.41498, 0.4156, 0.41692, 0.41732,
-0.4182, 0.41958, 0.42048, 0.4187, 0.42102, 0.4227, 0.42274, 0.42114, 0.42204, 0.42546, 0.42712, 0.42554, 0.42872, 0.43054, 0.43182, 0.43444, 0.43476, 0.4358,
-0.4363, 0.43672, 0.43832, 0.43892, 0.43906, 0.44226, 0.44116, 0.43964, 0.4429, 
-//228
-0.05764, 0.05674, 0.0582, 0.05726, 0.05764, 0.05812, 0.05806, 0.05788, 0.05794, 0.05722, 0.0571, 0.05604, 0.05734, 0.05738, 0.05738, 0.05702, 0.05774, 0.05852,
-0.0594, 0.0602, 0.06094, 0.05972, 0.06104, 0.06308, 0.06974, 0.08212, 0.10572, 0.1364, 0.16882, 0.19386, 0.21136, 0.22366, 0.24396, 0.27854, 0.32968, 0.38684,
-0.44034, 0.49374, 0.52444, 0.5453, 0.55978, 0.56956, 0.57662, 0.58092, 0.58728, 0.58968, 0.59134, 0.59328, 0.5932, 0.59324, 0.59616, 0.60014, 0.60032, 0.6019,
-0.60408, 0.60366, 0.60456, 0.60542, 0.60696, 0.60968, 0.60956, 0.60934, 0.6087, 0.61232, 0.61436, 0.61652, 0.61904, 0.61752, 0.61788, 0.62274, 0.62398, 0.62426,
-0.6292, 0.62626, 0.62538, 0.62912, 0.6299, 0.62968, 0.63004, 0.63, 0.63286, 
-//229
-0.13434, 0.1636, 0.21814, 0.30032, 0.3995, 0.49614, 0.55822, 0.58258, 0.58938, 0.58982, 0.59282, 0.59306, 0.59448, 0.59388, 0.59254, 0.59402, 0.59908, 0.59902,
-0.59874, 0.60062, 0.59818, 0.59472, 0.59372, 0.59544, 0.60168, 0.61422, 0.6297, 0.64186, 0.6492, 0.6489, 0.64938, 0.6486, 0.6531, 0.66214, 0.67458, 0.68644,
-0.69228, 0.69732, 0.69878, 0.69992, 0.70078, 0.70044, 0.70014, 0.69882, 0.69928, 0.69928, 0.69984, 0.69876, 0.6977, 0.69868, 0.6991, 0.69792, 0.69732, 0.6976,
-0.6981, 0.69662, 0.69788, 0.69664, 0.69586, 0.69712, 0.6957, 0.69594, 0.69458, 0.69438, 0.69442, 0.69494, 0.69434, 0.69452, 0.69466, 0.69652, 0.69678, 0.69654,
-0.6981, 0.69472, 0.6941, 0.6957, 0.69592, 0.69528, 0.69544, 0.69364, 0.69584, 
-//230
-0.13216, 0.1626, 0.21512, 0.28688, 0.36346, 0.4208, 0.45094, 0.4612, 0.46282, 0.46094, 0.45974, 0.45842, 0.45834, 0.45666, 0.45696, 0.45592, 0.45688, 0.45632,
-0.45732, 0.45616, 0.45378, 0.45122, 0.448, 0.4497, 0.45502, 0.46766, 0.48348, 0.49418, 0.49682, 0.49502, 0.49418, 0.49452, 0.49882, 0.50888, 0.52052, 0.52994,
-0.5364, 0.5413, 0.54198, 0.54264, 0.54208, 0.53988, 0.53952, 0.53792, 0.5391, 0.53718, 0.53856, 0.53574, 0.53468, 0.53324, 0.53308, 0.53142, 0.531, 0.53074,
-0.52944, 0.52826, 0.52762, 0.52518, 0.52534, 0.52658, 0.52424, 0.52196, 0.51958, 0.52136, 0.52124, 0.5215, 0.52302, 0.52082, 0.51952, 0.52002, 0.51726, 0.51894,
-0.52172, 0.51894, 0.51844, 0.51588, 0.5158, 0.51324, 0.51714, 0.51324, 0.51294, 
-//231
-0.12892, 0.1544, 0.1965, 0.24744, 0.29338, 0.3225, 0.3343, 0.33576, 0.33186, 0.32944, 0.3291, 0.32836, 0.32752, 0.32602, 0.3239, 0.32362, 0.32436, 0.3253,
-0.32576, 0.32562, 0.32294, 0.31836, 0.31648, 0.31738, 0.32314, 0.33292, 0.34372, 0.35018, 0.35172, 0.35196, 0.3508, 0.3506, 0.35336, 0.36198, 0.37292, 0.38204,
-0.38566, 0.3919, 0.39196, 0.39198, 0.39148, 0.39022, 0.38986, 0.38922, 0.38692, 0.38536, 0.3856, 0.38456, 0.38218, 0.3809, 0.37986, 0.37792, 0.37848, 0.37788,
-0.37732, 0.3744, 0.37378, 0.3708, 0.37122, 0.37334, 0.37218, 0.36928, 0.36662, 0.36942, 0.36812, 0.36834, 0.36814, 0.36502, 0.36388, 0.36532, 0.36636, 0.36508,
-0.36392, 0.36178, 0.36152, 0.36196, 0.36204, 0.36128, 0.3599, 0.35708, 0.35696, 
-//232
-0.11848, 0.1384, 0.16898, 0.20004, 0.22352, 0.23324, 0.23408, 0.23216, 0.23186, 0.22886, 0.22956, 0.2302, 0.22804, 0.22554, 0.22456, 0.22386, 0.22556, 0.22516,
-0.22604, 0.2251, 0.22076, 0.21982, 0.21974, 0.21904, 0.22362, 0.23078, 0.23908, 0.2454, 0.24698, 0.24678, 0.24552, 0.24596, 0.24852, 0.2539, 0.2638, 0.2721,
-0.27422, 0.27884, 0.28048, 0.2801, 0.27744, 0.2763, 0.2763, 0.2753, 0.2749, 0.27428, 0.27392, 0.27132, 0.271, 0.27032, 0.26904, 0.26872, 0.26718, 0.26576,
-0.2645, 0.26476, 0.26482, 0.26112, 0.26024, 0.26078, 0.25966, 0.2575, 0.25622, 0.25938, 0.2587, 0.25746, 0.25828, 0.25804, 0.25836, 0.25764, 0.2571, 0.255,
-0.25612, 0.25584, 0.25616, 0.25594, 0.2552, 0.2535, 0.25186, 0.25288, 0.25304, 
-//233
-0.10298, 0.11356, 0.1282, 0.13658, 0.14172, 0.14486, 0.14628, 0.1462, 0.1476, 0.14746, 0.1457, 0.14548, 0.14766, 0.1478, 0.14948, 0.15122, 0.15254, 0.1535,
-0.15594, 0.15918, 0.15898, 0.15974, 0.1601, 0.1602, 0.16318, 0.16572, 0.16534, 0.1635, 0.163, 0.1615, 0.16146, 0.16392, 0.16496, 0.1664, 0.16888, 0.17148,
-0.17384, 0.18312, 0.18702, 0.18912, 0.1901, 0.19062, 0.19136, 0.18866, 0.18978, 0.19174, 0.19002, 0.188, 0.18742, 0.18492, 0.18464, 0.1858, 0.18628, 0.18552,
-0.18236, 0.181, 0.17982, 0.17826, 0.17908, 0.18068, 0.17992, 0.1771, 0.176, 0.17752, 0.17724, 0.17894, 0.17954, 0.17744, 0.1765, 0.17642, 0.17784, 0.1786,
-0.18092, 0.17966, 0.17826, 0.1757, 0.17774, 0.17638, 0.17796, 0.17784, 0.17784, 
-//234
-0.08042, 0.08576, 0.09262, 0.09428, 0.09708, 0.09658, 0.0955, 0.09502, 0.09698, 0.0954, 0.09416, 0.09294, 0.09254, 0.09094, 0.09192, 0.09258, 0.09256, 0.09212,
-0.0908, 0.09084, 0.09016, 0.08968, 0.08916, 0.08992, 0.09184, 0.09404, 0.09776, 0.09886, 0.09962, 0.09886, 0.09896, 0.10052, 0.10318, 0.10746, 0.11224, 0.11724,
-0.11714, 0.12292, 0.1218, 0.12172, 0.12162, 0.12078, 0.12006, 0.12052, 0.1211, 0.11872, 0.11758, 0.11824, 0.11728, 0.11692, 0.11704, 0.11584, 0.1157, 0.1159,
-0.11572, 0.11498, 0.11442, 0.11246, 0.11256, 0.11366, 0.11504, 0.11374, 0.112, 0.1135, 0.11432, 0.115, 0.11544, 0.11476, 0.11426, 0.11452, 0.11532, 0.1168,
-0.11786, 0.11674, 0.11644, 0.11634, 0.11596, 0.11778, 0.11862, 0.11764, 0.11756, 
-//235
-0.05324, 0.0542, 0.05306, 0.05232, 0.05344, 0.0541, 0.0557, 0.05682, 0.05734, 0.05164, 0.05116, 0.05384, 0.05312, 0.052, 0.05254, 0.04802, 0.05446, 0.051,
-0.05374, 0.05204, 0.05134, 0.05398, 0.0524, 0.05306, 0.05652, 0.05664, 0.05654, 0.05926, 0.05996, 0.05966, 0.05974, 0.05864, 0.05726, 0.05968, 0.0635, 0.06344,
-0.06434, 0.06756, 0.06998, 0.07144, 0.07306, 0.07286, 0.07228, 0.07068, 0.0696, 0.07006, 0.07046, 0.0701, 0.06962, 0.06894, 0.06834, 0.06864, 0.0675, 0.0688,
-0.06846, 0.06754, 0.06718, 0.06702, 0.06746, 0.06708, 0.06754, 0.06462, 0.06608, 0.06844, 0.06872, 0.06752, 0.06838, 0.06966, 0.07118, 0.07148, 0.07114,
-0.07044, 0.07054, 0.07292, 0.07434, 0.07334, 0.07376, 0.0736, 0.07396, 0.0734, 0.07396, 
-//236
-0.04014, 0.03984, 0.03978, 0.0383, 0.03642, 0.03768, 0.03834, 0.03796, 0.03874, 0.03784, 0.03802, 0.03796, 0.03868, 0.03744, 0.03778, 0.03788, 0.03616, 0.0372,
-0.03924, 0.03876, 0.03772, 0.03714, 0.0368, 0.03684, 0.03964, 0.0403, 0.04128, 0.03954, 0.03946, 0.04068, 0.04156, 0.04162, 0.04242, 0.0434, 0.04454, 0.04408,
-0.04298, 0.04874, 0.04954, 0.05218, 0.05222, 0.0517, 0.05154, 0.05104, 0.04982, 0.05034, 0.05098, 0.05088, 0.04976, 0.04916, 0.04842, 0.04868, 0.0494, 0.04966,
-0.05036, 0.0496, 0.04804, 0.04688, 0.04822, 0.0494, 0.05016, 0.04814, 0.0468, 0.04894, 0.05046, 0.05218, 0.0521, 0.05196, 0.05252, 0.05236, 0.05328, 0.0549,
-0.05568, 0.0552, 0.05532, 0.0553, 0.05628, 0.05648, 0.05744, 0.05742, 0.05712, 
-//237
-0.13852, 0.1712, 0.2296, 0.30756, 0.39288, 0.46052, 0.49562, 0.5071, 0.50992, 0.51006, 0.51014, 0.5114, 0.513, 0.5135, 0.51482, 0.51564, 0.5181, 0.51916,
-0.51986, 0.51898, 0.5196, 0.51764, 0.5163, 0.51842, 0.52958, 0.55116, 0.58018, 0.60884, 0.62548, 0.63086, 0.63194, 0.63584, 0.64192, 0.65838, 0.68106, 0.70092,
-0.7138, 0.72244, 0.72592, 0.7272, 0.72726, 0.73068, 0.73072, 0.72936, 0.72836, 0.73006, 0.73018, 0.73056, 0.73084, 0.73304, 0.73598, 0.7343, 0.73478, 0.73636,
-0.73538, 0.73556, 0.73608, 0.73448, 0.73412, 0.73832, 0.73798, 0.7338, 0.73232, 0.73238, 0.734, 0.73208, 0.73488, 0.7349, 0.73582, 0.73578, 0.73838, 0.73724,
-0.7385, 0.73766, 0.73698, 0.73852, 0.7396, 0.74304, 0.74092, 0.7399, 0.73916, 
-//238
-0.12158, 0.15196, 0.1994, 0.26358, 0.32604, 0.37002, 0.38892, 0.39484, 0.3949, 0.39378, 0.3936, 0.39326, 0.39164, 0.39126, 0.39092, 0.39178, 0.39226, 0.39364,
-0.39488, 0.39418, 0.39174, 0.38722, 0.38646, 0.39002, 0.39858, 0.41506, 0.43834, 0.45778, 0.46814, 0.47164, 0.47322, 0.4724, 0.48058, 0.49394, 0.51194, 0.5262,
-0.53348, 0.54398, 0.5464, 0.54828, 0.54974, 0.548, 0.54786, 0.54578, 0.54622, 0.54614, 0.54536, 0.5446, 0.54222, 0.54152, 0.54096, 0.54038, 0.53854, 0.53884,
-0.53956, 0.53488, 0.53606, 0.53438, 0.5347, 0.53484, 0.53392, 0.53028, 0.53176, 0.53358, 0.5335, 0.53066, 0.53076, 0.53222, 0.53012, 0.53084, 0.53116, 0.52824,
-0.5291, 0.52876, 0.52854, 0.52672, 0.528, 0.52688, 0.52426, 0.5238, 0.52314, 
-//239
-0.12614, 0.15024, 0.18692, 0.22908, 0.26334, 0.28306, 0.28854, 0.28814, 0.28704, 0.28322, 0.28182, 0.28212, 0.2818, 0.28088, 0.2804, 0.27952, 0.28046, 0.28102,
-0.28244, 0.28164, 0.27914, 0.27554, 0.27278, 0.27516, 0.28346, 0.29764, 0.31572, 0.32962, 0.33764, 0.33944, 0.34044, 0.34306, 0.34728, 0.35718, 0.37092, 0.3842,
-0.3922, 0.40016, 0.40278, 0.4046, 0.40338, 0.40302, 0.4025, 0.40012, 0.39926, 0.4, 0.4003, 0.39812, 0.39618, 0.3939, 0.39546, 0.39338, 0.39282, 0.39264,
-0.39082, 0.3899, 0.38916, 0.38648, 0.38738, 0.38822, 0.38734, 0.3833, 0.381, 0.38388, 0.3846, 0.38336, 0.38446, 0.38056, 0.3786, 0.38136, 0.38516, 0.38454,
-0.38556, 0.38376, 0.38404, 0.38416, 0.38294, 0.38442, 0.38274, 0.37906, 0.37992, 
-//240
-0.1174, 0.13448, 0.15798, 0.18204, 0.19842, 0.20464, 0.20534, 0.20364, 0.20264, 0.19968, 0.19918, 0.19886, 0.19904, 0.1982, 0.19874, 0.19658, 0.1959, 0.19632,
-0.19784, 0.19798, 0.19816, 0.19416, 0.19158, 0.1913, 0.19894, 0.2093, 0.22506, 0.23702, 0.24188, 0.24132, 0.2411, 0.24382, 0.24816, 0.25754, 0.27054, 0.2814,
-0.28644, 0.29618, 0.29774, 0.29736, 0.29876, 0.2984, 0.2976, 0.29512, 0.294, 0.29322, 0.29282, 0.29396, 0.29178, 0.28958, 0.28882, 0.2872, 0.28772, 0.28646,
-0.2869, 0.28592, 0.2842, 0.28114, 0.28112, 0.28224, 0.28142, 0.2799, 0.27668, 0.27916, 0.27892, 0.27812, 0.2775, 0.27656, 0.27644, 0.27768, 0.27706, 0.27696,
-0.27704, 0.2751, 0.27526, 0.27486, 0.2758, 0.27552, 0.2744, 0.27236, 0.27374, 
-//241
-0.09582, 0.10378, 0.11674, 0.12596, 0.13078, 0.1327, 0.13092, 0.12888, 0.12816, 0.12598, 0.12646, 0.12728, 0.12738, 0.1248, 0.12416, 0.12222, 0.1228, 0.12564,
-0.12582, 0.12552, 0.12384, 0.12138, 0.12042, 0.12228, 0.12656, 0.13238, 0.14186, 0.14784, 0.15262, 0.15134, 0.1511, 0.1529, 0.15652, 0.1623, 0.17458, 0.18688,
-0.1891, 0.19862, 0.19986, 0.19892, 0.20096, 0.19952, 0.20052, 0.1971, 0.1961, 0.19646, 0.19488, 0.19632, 0.19564, 0.19312, 0.19098, 0.1902, 0.1915, 0.19032,
-0.19006, 0.18946, 0.18832, 0.18412, 0.18558, 0.18748, 0.18608, 0.18356, 0.18268, 0.185, 0.18374, 0.18344, 0.18482, 0.1853, 0.18662, 0.18548, 0.18502, 0.18358,
-0.1841, 0.18374, 0.18404, 0.18502, 0.1844, 0.18366, 0.18396, 0.18216, 0.18332, 
-//242
-0.06864, 0.07344, 0.0779, 0.07916, 0.07952, 0.07882, 0.078, 0.07694, 0.07698, 0.07706, 0.0774, 0.07594, 0.07528, 0.0749, 0.07534, 0.07486, 0.07608, 0.0756,
-0.07528, 0.07466, 0.07572, 0.07428, 0.0738, 0.0749, 0.07712, 0.0823, 0.08812, 0.0909, 0.09208, 0.09266, 0.09228, 0.09266, 0.09712, 0.10416, 0.11306, 0.12174,
-0.12316, 0.13068, 0.1319, 0.13236, 0.13196, 0.13176, 0.13106, 0.12926, 0.12846, 0.12744, 0.12772, 0.12806, 0.12768, 0.12608, 0.12568, 0.12326, 0.12254, 0.12354,
-0.12426, 0.12482, 0.1234, 0.11954, 0.12022, 0.12132, 0.12262, 0.1206, 0.11924, 0.1212, 0.1223, 0.12344, 0.12422, 0.12418, 0.12362, 0.12354, 0.12366, 0.1256,
-0.12592, 0.12418, 0.12514, 0.12398, 0.12526, 0.12516, 0.12608, 0.12532, 0.12444, 
-//243
-0.04056, 0.04132, 0.04236, 0.0422, 0.04234, 0.04156, 0.04008, 0.04088, 0.0425, 0.04138, 0.04126, 0.04098, 0.04066, 0.0408, 0.04196, 0.0419, 0.04106, 0.04184,
-0.04108, 0.04028, 0.04204, 0.04316, 0.0421, 0.04248, 0.04436, 0.04562, 0.0485, 0.05176, 0.05262, 0.05306, 0.05298, 0.05178, 0.05386, 0.05552, 0.05908, 0.06118,
-0.0596, 0.06778, 0.07132, 0.07468, 0.07488, 0.07586, 0.07704, 0.07472, 0.0748, 0.07582, 0.07474, 0.07408, 0.0728, 0.07288, 0.07174, 0.07216, 0.07372, 0.07234,
-0.0707, 0.06992, 0.06996, 0.06762, 0.06994, 0.07186, 0.07098, 0.0676, 0.0668, 0.07134, 0.07176, 0.07158, 0.07168, 0.07048, 0.071, 0.0737, 0.07506, 0.07444,
-0.07524, 0.07424, 0.07454, 0.07456, 0.07594, 0.07608, 0.0768, 0.0766, 0.07572, 
-//244
-0.14022, 0.16752, 0.21432, 0.27982, 0.34612, 0.39108, 0.41156, 0.41476, 0.41286, 0.41036, 0.41124, 0.40914, 0.41096, 0.41032, 0.41068, 0.41058, 0.41246,
-0.41404, 0.4141, 0.41234, 0.4128, 0.41178, 0.4119, 0.41712, 0.43112, 0.45926, 0.50232, 0.55268, 0.59528, 0.62282, 0.64028, 0.65106, 0.66192, 0.68202, 0.71436,
-0.74398, 0.76638, 0.78504, 0.79296, 0.79686, 0.80036, 0.80358, 0.80464, 0.8037, 0.80436, 0.8022, 0.80196, 0.80526, 0.80308, 0.80506, 0.8061, 0.80394, 0.8033,
-0.80502, 0.80526, 0.80424, 0.80306, 0.8007, 0.79802, 0.8011, 0.80324, 0.80462, 0.80452, 0.80538, 0.80672, 0.80566, 0.80644, 0.80722, 0.80714, 0.80984, 0.81096,
-0.81014, 0.81086, 0.80962, 0.81148, 0.81046, 0.8104, 0.81316, 0.81312, 0.8088, 0.8102, 
-//245
-0.12272, 0.14792, 0.188, 0.2351, 0.27592, 0.3014, 0.30942, 0.31066, 0.31016, 0.30768, 0.30632, 0.30376, 0.30368, 0.30498, 0.30532, 0.30668, 0.30768, 0.30744,
-0.30712, 0.30696, 0.3067, 0.30424, 0.30446, 0.30796, 0.31968, 0.34262, 0.37738, 0.41392, 0.4385, 0.4505, 0.4567, 0.46278, 0.47346, 0.49032, 0.51944, 0.548,
-0.5664, 0.58414, 0.59178, 0.59194, 0.59304, 0.59388, 0.59548, 0.59526, 0.5952, 0.5934, 0.59246, 0.59096, 0.59072, 0.59032, 0.59068, 0.59074, 0.58882, 0.5874,
-0.58616, 0.58586, 0.5863, 0.58466, 0.58396, 0.58526, 0.58318, 0.5809, 0.5804, 0.582, 0.58308, 0.58148, 0.58044, 0.57818, 0.57628, 0.57948, 0.57984, 0.58008,
-0.58054, 0.57756, 0.5755, 0.57292, 0.57772, 0.57636, 0.57698, 0.57602, 0.57598, 
-//246
-0.1185, 0.1389, 0.16816, 0.19358, 0.21276, 0.22052, 0.22096, 0.21984, 0.2184, 0.21616, 0.21474, 0.21478, 0.2141, 0.2145, 0.2155, 0.21356, 0.21496, 0.21484,
-0.21504, 0.21536, 0.21608, 0.21432, 0.21226, 0.21526, 0.2239, 0.24138, 0.27032, 0.2996, 0.31996, 0.32768, 0.33122, 0.33504, 0.3436, 0.36056, 0.38456, 0.408,
-0.42372, 0.437, 0.44258, 0.4422, 0.44256, 0.44418, 0.4438, 0.44216, 0.44232, 0.44024, 0.4404, 0.44004, 0.4386, 0.43752, 0.43672, 0.43476, 0.43242, 0.43212,
-0.43208, 0.43136, 0.43262, 0.429, 0.42744, 0.42886, 0.42656, 0.42478, 0.4235, 0.42536, 0.4242, 0.42264, 0.42186, 0.4235, 0.42274, 0.42356, 0.4212, 0.41888,
-0.4198, 0.41894, 0.4182, 0.41884, 0.4188, 0.41808, 0.4187, 0.414, 0.41422, 
-//247
-0.10076, 0.11022, 0.12502, 0.13612, 0.14322, 0.14606, 0.14632, 0.14496, 0.14252, 0.14036, 0.14068, 0.14204, 0.14276, 0.142, 0.14252, 0.14112, 0.1422, 0.1437,
-0.14314, 0.14432, 0.14318, 0.14022, 0.14012, 0.14226, 0.15004, 0.16288, 0.18246, 0.1978, 0.2077, 0.21184, 0.21262, 0.21486, 0.22156, 0.23416, 0.25324, 0.27384,
-0.28956, 0.30348, 0.30802, 0.31006, 0.31044, 0.3091, 0.31102, 0.30944, 0.31004, 0.30908, 0.309, 0.3054, 0.3048, 0.30462, 0.30496, 0.3046, 0.30352, 0.30194,
-0.30048, 0.29948, 0.29904, 0.2979, 0.29856, 0.2999, 0.29658, 0.29364, 0.29202, 0.29614, 0.29606, 0.29594, 0.29474, 0.29526, 0.29392, 0.29458, 0.2968, 0.29588,
-0.29598, 0.29356, 0.29316, 0.2916, 0.29134, 0.29346, 0.29246, 0.29064, 0.28914, 
-//248
-0.07548, 0.08122, 0.08666, 0.08958, 0.09088, 0.08922, 0.08842, 0.0869, 0.08846, 0.08792, 0.0888, 0.0867, 0.08546, 0.0853, 0.08544, 0.08612, 0.08684, 0.08808,
-0.08744, 0.08692, 0.08682, 0.08696, 0.08758, 0.08934, 0.09466, 0.1024, 0.1153, 0.12832, 0.13462, 0.13718, 0.13842, 0.13858, 0.14482, 0.1557, 0.17192, 0.18792,
-0.19806, 0.21132, 0.21642, 0.2161, 0.21722, 0.21474, 0.21514, 0.21522, 0.21484, 0.21468, 0.2136, 0.21232, 0.2098, 0.20888, 0.21002, 0.20904, 0.20862, 0.20834,
-0.20648, 0.20522, 0.2055, 0.20384, 0.20352, 0.20488, 0.20348, 0.20018, 0.20086, 0.20356, 0.20372, 0.20268, 0.20334, 0.2036, 0.20248, 0.20326, 0.20424, 0.2034,
-0.20312, 0.20458, 0.20584, 0.20388, 0.20386, 0.20324, 0.20098, 0.20232, 0.20348, 
-//249
-0.04772, 0.0485, 0.04998, 0.05098, 0.05162, 0.04952, 0.04856, 0.04926, 0.0495, 0.05036, 0.05258, 0.0525, 0.05038, 0.04862, 0.05088, 0.05034, 0.05226, 0.05324,
-0.05234, 0.05044, 0.05076, 0.05274, 0.05374, 0.05654, 0.05998, 0.06322, 0.06972, 0.07618, 0.07946, 0.08106, 0.0832, 0.08454, 0.08778, 0.09596, 0.1103, 0.12238,
-0.1322, 0.14578, 0.14998, 0.15092, 0.14972, 0.14794, 0.1488, 0.1472, 0.14822, 0.14512, 0.14526, 0.14368, 0.14466, 0.1433, 0.14192, 0.1417, 0.13982, 0.13978,
-0.1399, 0.14074, 0.13906, 0.1368, 0.13702, 0.13758, 0.13802, 0.1359, 0.13446, 0.13852, 0.1396, 0.14004, 0.13928, 0.14066, 0.13674, 0.13994, 0.14044, 0.14138,
-0.14058, 0.13904, 0.13928, 0.14302, 0.14378, 0.142, 0.14156, 0.1415, 0.14114, 
-//250
-0.12264, 0.14318, 0.17088, 0.20388, 0.22784, 0.2382, 0.24222, 0.24092, 0.23838, 0.23638, 0.23614, 0.23728, 0.23802, 0.23748, 0.23844, 0.23792, 0.24048, 0.24234,
-0.2424, 0.2418, 0.24014, 0.23938, 0.23888, 0.2437, 0.25758, 0.28182, 0.32208, 0.36718, 0.4046, 0.42636, 0.44006, 0.4491, 0.46152, 0.48506, 0.52126, 0.561,
-0.59274, 0.61924, 0.6308, 0.63742, 0.6393, 0.6394, 0.63966, 0.6395, 0.64108, 0.64016, 0.63994, 0.6393, 0.6376, 0.63742, 0.6377, 0.63904, 0.63662, 0.63714,
-0.6356, 0.6322, 0.63636, 0.63548, 0.63422, 0.6357, 0.63422, 0.63168, 0.6288, 0.63158, 0.63224, 0.6308, 0.6317, 0.6323, 0.63038, 0.63226, 0.63178, 0.63144,
-0.63318, 0.63194, 0.63388, 0.63312, 0.63372, 0.63234, 0.63208, 0.63168, 0.6344, 
-//251
-0.10488, 0.11874, 0.1363, 0.15094, 0.15972, 0.16076, 0.16146, 0.16076, 0.1598, 0.15846, 0.15882, 0.1573, 0.15788, 0.15918, 0.15972, 0.16008, 0.1606, 0.16088,
-0.161, 0.16232, 0.16232, 0.16116, 0.16156, 0.16362, 0.17332, 0.1944, 0.22574, 0.25878, 0.28452, 0.29782, 0.30306, 0.30962, 0.3218, 0.34278, 0.375, 0.41166,
-0.43758, 0.46168, 0.47274, 0.47592, 0.4779, 0.48042, 0.48226, 0.48218, 0.48068, 0.47846, 0.47776, 0.4784, 0.47822, 0.47574, 0.4765, 0.47422, 0.47278, 0.47386,
-0.47484, 0.47334, 0.47206, 0.46978, 0.4676, 0.4696, 0.4687, 0.46872, 0.4659, 0.46674, 0.46676, 0.46606, 0.46726, 0.46712, 0.46606, 0.46578, 0.46544, 0.46534,
-0.4646, 0.46566, 0.46502, 0.46384, 0.4633, 0.46164, 0.46218, 0.46082, 0.46212, 
-//252
-0.0857, 0.09024, 0.09616, 0.09858, 0.10144, 0.1023, 0.10186, 0.102, 0.10262, 0.1007, 0.09986, 0.09894, 0.09986, 0.101, 0.1022, 0.10252, 0.10298, 0.10266,
-0.10184, 0.10258, 0.10414, 0.10244, 0.10358, 0.10582, 0.1117, 0.12782, 0.15012, 0.17294, 0.1889, 0.1953, 0.19892, 0.2031, 0.21276, 0.22744, 0.2529, 0.28306,
-0.3035, 0.3252, 0.33386, 0.33742, 0.33838, 0.34046, 0.34086, 0.34044, 0.33992, 0.33872, 0.33774, 0.33708, 0.33724, 0.3361, 0.33538, 0.3347, 0.33392, 0.3335,
-0.33118, 0.3317, 0.33074, 0.32774, 0.32976, 0.33034, 0.3302, 0.32718, 0.32658, 0.3268, 0.32738, 0.32596, 0.32638, 0.32674, 0.32708, 0.32632, 0.3266, 0.32702,
-0.3257, 0.32524, 0.3255, 0.32524, 0.32418, 0.32292, 0.3229, 0.32284, 0.32376, 
-//253
-0.0545, 0.05428, 0.0558, 0.05688, 0.05704, 0.0562, 0.0562, 0.05596, 0.0566, 0.05626, 0.05688, 0.05598, 0.05462, 0.05642, 0.0579, 0.0576, 0.05816, 0.05716,
-0.05662, 0.05678, 0.05952, 0.06002, 0.06146, 0.06258, 0.06666, 0.07538, 0.09, 0.10566, 0.11614, 0.12196, 0.1232, 0.1245, 0.13128, 0.1471, 0.16554, 0.19124,
-0.20858, 0.2259, 0.23408, 0.23718, 0.23852, 0.2379, 0.2383, 0.23822, 0.23702, 0.23628, 0.23652, 0.233, 0.23294, 0.23322, 0.23272, 0.2309, 0.2313, 0.22952,
-0.22758, 0.22684, 0.22814, 0.22616, 0.22698, 0.22706, 0.22554, 0.2222, 0.22426, 0.22616, 0.22648, 0.22528, 0.22526, 0.2251, 0.22498, 0.22578, 0.22656, 0.22562,
-0.2249, 0.22444, 0.22462, 0.22542, 0.22694, 0.2255, 0.22586, 0.22506, 0.22398, 
-//254
-0.1073, 0.12286, 0.14318, 0.15972, 0.1702, 0.17384, 0.17516, 0.17288, 0.16996, 0.16892, 0.16766, 0.1684, 0.1708, 0.16982, 0.16996, 0.16868, 0.17072, 0.17082,
-0.17156, 0.1738, 0.17348, 0.17172, 0.1724, 0.17682, 0.18874, 0.21316, 0.2536, 0.3042, 0.3539, 0.39202, 0.41694, 0.4333, 0.45292, 0.48164, 0.5204, 0.5655,
-0.60618, 0.63956, 0.65628, 0.66674, 0.67112, 0.6748, 0.67892, 0.67966, 0.6816, 0.68106, 0.68172, 0.68022, 0.68194, 0.68314, 0.68132, 0.6808, 0.68096, 0.68214,
-0.68138, 0.6829, 0.68444, 0.68206, 0.67936, 0.68256, 0.683, 0.6801, 0.67892, 0.68392, 0.68232, 0.68418, 0.68352, 0.68494, 0.68468, 0.68924, 0.68702, 0.68626,
-0.68848, 0.68444, 0.68724, 0.68904, 0.69104, 0.69038, 0.69044, 0.69128, 0.69324, 
-//255
-0.08698, 0.09178, 0.10008, 0.11004, 0.11366, 0.11338, 0.11352, 0.11132, 0.10946, 0.11004, 0.11106, 0.11052, 0.11058, 0.11002, 0.10926, 0.11038, 0.11272,
-0.11224, 0.11258, 0.11284, 0.11424, 0.1128, 0.11452, 0.11776, 0.12686, 0.14348, 0.17372, 0.21212, 0.24672, 0.26996, 0.28366, 0.29216, 0.30536, 0.3287, 0.36438,
-0.404, 0.43652, 0.46162, 0.47426, 0.48174, 0.48448, 0.48666, 0.48974, 0.4878, 0.4872, 0.488, 0.48674, 0.48718, 0.4875, 0.48608, 0.48556, 0.48602, 0.48352,
-0.4827, 0.4847, 0.48362, 0.48302, 0.48032, 0.4796, 0.48132, 0.48106, 0.47944, 0.4785, 0.4794, 0.48078, 0.48054, 0.48154, 0.4817, 0.4802, 0.48018, 0.48192,
-0.4818, 0.4816, 0.48112, 0.48204, 0.4804, 0.48184, 0.48334, 0.48216, 0.47982, 0.47894, 
-//256
-0.06082, 0.06342, 0.06612, 0.0674, 0.06664, 0.06428, 0.06454, 0.06622, 0.067, 0.06646, 0.06746, 0.06468, 0.065, 0.0647, 0.06666, 0.06702, 0.06718, 0.0671,
-0.06718, 0.06722, 0.06936, 0.07026, 0.07048, 0.07372, 0.07976, 0.09342, 0.1151, 0.14224, 0.1656, 0.17828, 0.18668, 0.19356, 0.20354, 0.22216, 0.25218, 0.28302,
-0.30808, 0.33816, 0.34774, 0.35112, 0.35458, 0.35564, 0.36004, 0.35952, 0.35994, 0.35614, 0.3552, 0.35628, 0.35626, 0.3542, 0.35494, 0.35166, 0.35188, 0.35012,
-0.35236, 0.35092, 0.3499, 0.34726, 0.34652, 0.34726, 0.34832, 0.34612, 0.34554, 0.34824, 0.34674, 0.34684, 0.34768, 0.34814, 0.34732, 0.34956, 0.34854, 0.34734,
-0.35002, 0.34928, 0.34942, 0.34948, 0.3485, 0.34766, 0.34846, 0.3497, 0.35008, 
-//257
-0.04596, 0.04376, 0.04494, 0.04526, 0.04552, 0.04608, 0.04516, 0.04446, 0.04494, 0.0456, 0.04608, 0.047, 0.0478, 0.045, 0.04648, 0.04738, 0.0485, 0.04784,
-0.04802, 0.04706, 0.04724, 0.04932, 0.05132, 0.05394, 0.05924, 0.06748, 0.08296, 0.10212, 0.11936, 0.12906, 0.13444, 0.13936, 0.14392, 0.15492, 0.17148,
-0.18934, 0.20192, 0.21892, 0.22716, 0.2357, 0.24314, 0.24622, 0.2508, 0.2533, 0.25494, 0.25652, 0.2587, 0.25852, 0.2591, 0.25884, 0.25944, 0.25986, 0.26118,
-0.26174, 0.26168, 0.26056, 0.26118, 0.26144, 0.2635, 0.26574, 0.2659, 0.26378, 0.26242, 0.26832, 0.26922, 0.269, 0.27068, 0.2714, 0.2703, 0.27154, 0.27532,
-0.27644, 0.2784, 0.27704, 0.27662, 0.27596, 0.27848, 0.27908, 0.28136, 0.27994, 0.27942, 
-//258
-0.0843, 0.0902, 0.1036, 0.1125, 0.11954, 0.11942, 0.11816, 0.11718, 0.11634, 0.1168, 0.11762, 0.11966, 0.12016, 0.11808, 0.11964, 0.1204, 0.12176, 0.12348,
-0.12374, 0.12348, 0.12376, 0.12672, 0.12716, 0.1313, 0.14186, 0.16126, 0.19806, 0.24898, 0.30394, 0.34894, 0.38372, 0.41056, 0.43616, 0.47442, 0.52462, 0.5717,
-0.60968, 0.64608, 0.66594, 0.67978, 0.68774, 0.69224, 0.69584, 0.6995, 0.70256, 0.7028, 0.70522, 0.70686, 0.70382, 0.7056, 0.70824, 0.71074, 0.70864, 0.71084,
-0.70994, 0.7094, 0.71114, 0.70984, 0.70868, 0.71204, 0.7114, 0.71128, 0.71098, 0.71698, 0.71726, 0.71548, 0.71606, 0.7182, 0.71866, 0.72236, 0.72314, 0.7232,
-0.72434, 0.72322, 0.72714, 0.72686, 0.72522, 0.72594, 0.72802, 0.72382, 0.72608, 
-//259
-0.07022, 0.07116, 0.07448, 0.07628, 0.0782, 0.07648, 0.07686, 0.076, 0.07708, 0.0778, 0.0765, 0.0749, 0.07498, 0.07576, 0.07626, 0.07854, 0.07952, 0.07882,
-0.07768, 0.07782, 0.07858, 0.08078, 0.08148, 0.08592, 0.09176, 0.10734, 0.1359, 0.17626, 0.2196, 0.25228, 0.27462, 0.29032, 0.30808, 0.3345, 0.37314, 0.41806,
-0.45608, 0.48834, 0.50322, 0.51182, 0.51568, 0.52174, 0.52216, 0.52246, 0.52294, 0.5231, 0.52298, 0.5232, 0.52308, 0.52122, 0.52234, 0.52192, 0.52248, 0.52126,
-0.52174, 0.522, 0.52092, 0.51994, 0.52126, 0.52246, 0.52178, 0.5194, 0.51978, 0.52146, 0.52164, 0.5232, 0.52436, 0.52364, 0.52312, 0.52554, 0.52394, 0.52552,
-0.52794, 0.52662, 0.5288, 0.52662, 0.5301, 0.52904, 0.53016, 0.52982, 0.5321, 
-//260
-0.04658, 0.04428, 0.04452, 0.04402, 0.04434, 0.04536, 0.04534, 0.044, 0.043, 0.04454, 0.04518, 0.04464, 0.04576, 0.0449, 0.04402, 0.04358, 0.0458, 0.04804,
-0.0482, 0.04772, 0.04688, 0.04632, 0.04896, 0.05256, 0.05822, 0.06732, 0.0847, 0.1108, 0.1407, 0.16524, 0.18284, 0.1956, 0.20928, 0.22924, 0.25742, 0.2852,
-0.30534, 0.3303, 0.34388, 0.35186, 0.35668, 0.3634, 0.36856, 0.3708, 0.3746, 0.37578, 0.37646, 0.3753, 0.37652, 0.37828, 0.37872, 0.37866, 0.37848, 0.37966,
-0.37996, 0.37996, 0.3808, 0.37912, 0.38082, 0.38326, 0.38272, 0.37962, 0.37906, 0.384, 0.38436, 0.38638, 0.3875, 0.38844, 0.38844, 0.38978, 0.39038, 0.3906,
-0.39348, 0.39258, 0.3948, 0.39256, 0.3925, 0.39356, 0.39462, 0.39222, 0.39676, 
-//261
-0.0467, 0.04506, 0.04632, 0.04676, 0.04792, 0.04706, 0.04662, 0.04698, 0.04752, 0.0483, 0.05024, 0.04922, 0.04704, 0.0473, 0.04792, 0.04902, 0.05048, 0.05072,
-0.04954, 0.05004, 0.05154, 0.05318, 0.05504, 0.05752, 0.0629, 0.07486, 0.0994, 0.13744, 0.18202, 0.22762, 0.26438, 0.29384, 0.32236, 0.35676, 0.39418, 0.43402,
-0.46276, 0.4891, 0.5071, 0.52006, 0.5288, 0.53654, 0.54186, 0.5468, 0.54926, 0.5512, 0.5543, 0.55674, 0.55892, 0.55908, 0.56058, 0.5619, 0.56254, 0.56436,
-0.56718, 0.56786, 0.56808, 0.56686, 0.56866, 0.57156, 0.57214, 0.5703, 0.5705, 0.5751, 0.5773, 0.57766, 0.58106, 0.58144, 0.58194, 0.58396, 0.58838, 0.58868,
-0.58816, 0.58838, 0.59, 0.59224, 0.59478, 0.59516, 0.59862, 0.59352, 0.59526, 
-//262
-0.13682, 0.16566, 0.2205, 0.29832, 0.38974, 0.46646, 0.50868, 0.52208, 0.52552, 0.5254, 0.52522, 0.52742, 0.52962, 0.5292, 0.52902, 0.52952, 0.53124, 0.53334,
-0.53244, 0.53296, 0.53048, 0.52756, 0.5287, 0.53488, 0.5463, 0.57042, 0.60454, 0.6377, 0.66448, 0.68126, 0.68708, 0.69116, 0.69638, 0.705, 0.7168, 0.72688,
-0.73196, 0.73754, 0.7386, 0.73842, 0.7384, 0.73834, 0.73996, 0.73894, 0.73908, 0.738, 0.73742, 0.73832, 0.73488, 0.73442, 0.7365, 0.73744, 0.73424, 0.7355,
-0.73706, 0.73354, 0.73448, 0.73368, 0.73398, 0.73318, 0.73336, 0.73392, 0.73244, 0.73414, 0.7339, 0.73506, 0.73556, 0.7346, 0.73418, 0.73688, 0.73852, 0.73856,
-0.7394, 0.73592, 0.73622, 0.73608, 0.73866, 0.73926, 0.7381, 0.73924, 0.7415, 
-//263
-0.1335, 0.16268, 0.21466, 0.28072, 0.3533, 0.40874, 0.43616, 0.44202, 0.44426, 0.44246, 0.44164, 0.4399, 0.44174, 0.44296, 0.44218, 0.4454, 0.44598, 0.44608,
-0.44554, 0.4472, 0.4454, 0.44362, 0.44234, 0.448, 0.45846, 0.48, 0.50976, 0.53688, 0.55538, 0.56582, 0.57124, 0.57366, 0.57874, 0.5886, 0.59926, 0.60678,
-0.61154, 0.61874, 0.61974, 0.61984, 0.61802, 0.61856, 0.61702, 0.61686, 0.61752, 0.61708, 0.61556, 0.61368, 0.61224, 0.61194, 0.61092, 0.61124, 0.61128,
-0.60992, 0.6086, 0.60834, 0.60842, 0.60782, 0.60706, 0.60746, 0.60462, 0.60292, 0.60236, 0.60498, 0.6047, 0.60266, 0.60108, 0.6024, 0.5996, 0.6012, 0.6022,
-0.60094, 0.60266, 0.5999, 0.60068, 0.59928, 0.5982, 0.5996, 0.5982, 0.59544, 0.595, 
-//264
-0.14532, 0.17116, 0.21926, 0.2813, 0.34058, 0.37618, 0.39374, 0.39674, 0.39534, 0.39316, 0.39162, 0.3923, 0.39096, 0.38936, 0.39192, 0.39218, 0.39182, 0.3905,
-0.39048, 0.39014, 0.38854, 0.38824, 0.38892, 0.39194, 0.40052, 0.42058, 0.45094, 0.47994, 0.50188, 0.51176, 0.51708, 0.5186, 0.52234, 0.53028, 0.53884, 0.54604,
-0.54954, 0.55376, 0.5543, 0.55294, 0.55296, 0.55296, 0.5526, 0.55104, 0.54906, 0.54634, 0.54538, 0.54574, 0.54438, 0.54266, 0.54044, 0.54068, 0.53868, 0.53716,
-0.53624, 0.53528, 0.53448, 0.53118, 0.53258, 0.53228, 0.53002, 0.52706, 0.52612, 0.52846, 0.5274, 0.5254, 0.52654, 0.5242, 0.52164, 0.523, 0.5243, 0.52228,
-0.52396, 0.52028, 0.5213, 0.5173, 0.51984, 0.5196, 0.51792, 0.51728, 0.51416, 
-//265
-0.12418, 0.14872, 0.1866, 0.22476, 0.25422, 0.2687, 0.27328, 0.27232, 0.2732, 0.27122, 0.26984, 0.27048, 0.27168, 0.27244, 0.27216, 0.27396, 0.2753, 0.27492,
-0.27448, 0.27494, 0.27612, 0.27286, 0.27102, 0.27318, 0.28238, 0.29872, 0.32242, 0.34368, 0.35558, 0.35734, 0.35818, 0.35988, 0.36484, 0.3739, 0.38798, 0.39744,
-0.40044, 0.40564, 0.40636, 0.40614, 0.40532, 0.40388, 0.404, 0.40194, 0.40182, 0.40016, 0.39918, 0.39874, 0.39738, 0.39642, 0.39576, 0.3939, 0.3922, 0.39308,
-0.39324, 0.39108, 0.38996, 0.38932, 0.38864, 0.38702, 0.38668, 0.38578, 0.38434, 0.3849, 0.3848, 0.38418, 0.38422, 0.38364, 0.38258, 0.38362, 0.383, 0.38102,
-0.38206, 0.37994, 0.37944, 0.38072, 0.38186, 0.37824, 0.37796, 0.37652, 0.37544, 
-//266
-0.11504, 0.13206, 0.15458, 0.17468, 0.1882, 0.19336, 0.19588, 0.19328, 0.19256, 0.19088, 0.18924, 0.18718, 0.18758, 0.1875, 0.18714, 0.18746, 0.18846, 0.18804,
-0.18688, 0.1863, 0.1861, 0.18488, 0.18442, 0.18646, 0.19184, 0.20152, 0.21732, 0.23232, 0.24156, 0.24368, 0.24468, 0.2464, 0.25004, 0.2563, 0.26604, 0.2744,
-0.27738, 0.28394, 0.28508, 0.28274, 0.28188, 0.2813, 0.28188, 0.28032, 0.2796, 0.27924, 0.27756, 0.27672, 0.27614, 0.2759, 0.27494, 0.27332, 0.27208, 0.27198,
-0.27088, 0.26918, 0.26862, 0.26698, 0.26724, 0.2666, 0.26604, 0.26426, 0.26286, 0.26356, 0.26226, 0.26308, 0.26284, 0.26158, 0.2615, 0.26196, 0.26136, 0.26144,
-0.26256, 0.26182, 0.26142, 0.25928, 0.25836, 0.25782, 0.25954, 0.25806, 0.25844, 
-//267
-0.09548, 0.10222, 0.11562, 0.12562, 0.1321, 0.13242, 0.13176, 0.13056, 0.12904, 0.12732, 0.12738, 0.1274, 0.12726, 0.12528, 0.12584, 0.1256, 0.12584, 0.12636,
-0.12572, 0.12442, 0.1232, 0.12378, 0.1241, 0.12634, 0.1296, 0.1373, 0.14716, 0.15584, 0.16122, 0.1635, 0.16404, 0.16428, 0.1669, 0.17168, 0.18094, 0.18898,
-0.19138, 0.1962, 0.19628, 0.19608, 0.19472, 0.19398, 0.19482, 0.19448, 0.19418, 0.19176, 0.19046, 0.19048, 0.18988, 0.18866, 0.18938, 0.18788, 0.18616, 0.1853,
-0.18542, 0.18536, 0.185, 0.18306, 0.18354, 0.1826, 0.18184, 0.18076, 0.17934, 0.18202, 0.1819, 0.18108, 0.1805, 0.18164, 0.18148, 0.18078, 0.1811, 0.18162,
-0.18206, 0.18168, 0.18074, 0.18054, 0.17962, 0.18128, 0.18114, 0.18018, 0.18128, 
-//268
-0.06762, 0.0682, 0.07126, 0.07348, 0.07424, 0.075, 0.07396, 0.07224, 0.0728, 0.07318, 0.0733, 0.07282, 0.07174, 0.0706, 0.0708, 0.07124, 0.0721, 0.07258,
-0.07136, 0.071, 0.07074, 0.072, 0.0725, 0.07374, 0.07718, 0.08374, 0.08728, 0.0943, 0.09902, 0.1016, 0.10282, 0.10206, 0.1036, 0.10534, 0.1083, 0.1103, 0.10984,
-0.11732, 0.11992, 0.12132, 0.1235, 0.12306, 0.12226, 0.1212, 0.12254, 0.12274, 0.1209, 0.12104, 0.11928, 0.11866, 0.11786, 0.1196, 0.11928, 0.11898, 0.11746,
-0.11572, 0.11504, 0.11418, 0.11624, 0.11752, 0.117, 0.1131, 0.11298, 0.11512, 0.11628, 0.11714, 0.11794, 0.11626, 0.11562, 0.1163, 0.11888, 0.11916, 0.11936,
-0.11932, 0.1184, 0.11698, 0.12036, 0.11958, 0.1209, 0.11752, 0.11966, 
-//269
-0.04208, 0.04206, 0.04392, 0.0424, 0.04152, 0.04074, 0.04066, 0.04104, 0.04186, 0.04254, 0.04248, 0.04146, 0.04162, 0.04114, 0.04164, 0.04126, 0.04218, 0.04226,
-0.04282, 0.04254, 0.0429, 0.04214, 0.0426, 0.04296, 0.04568, 0.04938, 0.05364, 0.05638, 0.05798, 0.05852, 0.05896, 0.06032, 0.06196, 0.06292, 0.06462, 0.06576,
-0.06656, 0.07332, 0.07648, 0.07636, 0.07786, 0.07762, 0.07756, 0.07614, 0.07634, 0.07646, 0.07708, 0.07562, 0.0739, 0.07366, 0.07422, 0.07372, 0.07456, 0.07456,
-0.07332, 0.07232, 0.0725, 0.07118, 0.0722, 0.07334, 0.07388, 0.07204, 0.0729, 0.07328, 0.0732, 0.07426, 0.07516, 0.0759, 0.0765, 0.07586, 0.07632, 0.0766,
-0.0777, 0.07774, 0.07986, 0.07998, 0.0798, 0.07842, 0.08016, 0.08064, 0.0809, 
-//270
-0.13982, 0.16922, 0.21966, 0.28316, 0.34542, 0.3897, 0.4096, 0.41434, 0.41452, 0.41164, 0.40982, 0.40936, 0.4118, 0.41172, 0.41278, 0.41334, 0.41324, 0.41348,
-0.41326, 0.41316, 0.41274, 0.41212, 0.41104, 0.41654, 0.43152, 0.46146, 0.50936, 0.5664, 0.61986, 0.6625, 0.69264, 0.71224, 0.72528, 0.7383, 0.7525, 0.7637,
-0.7686, 0.7745, 0.77762, 0.77986, 0.7797, 0.77902, 0.78152, 0.77812, 0.77726, 0.77846, 0.77788, 0.77758, 0.77598, 0.7761, 0.77864, 0.77624, 0.77756, 0.77768,
-0.7755, 0.77364, 0.77474, 0.7745, 0.77276, 0.77518, 0.77554, 0.77388, 0.77474, 0.7754, 0.77614, 0.77426, 0.7768, 0.77628, 0.7773, 0.77966, 0.77914, 0.7801,
-0.77978, 0.7772, 0.77916, 0.77952, 0.78044, 0.7791, 0.78224, 0.7806, 0.78294, 
-//271
-0.12998, 0.15488, 0.1974, 0.24776, 0.29644, 0.3259, 0.33782, 0.33908, 0.33938, 0.3366, 0.33542, 0.33578, 0.33794, 0.337, 0.33812, 0.33874, 0.34032, 0.3412,
-0.34194, 0.3418, 0.3408, 0.3382, 0.33914, 0.3452, 0.35954, 0.3879, 0.43224, 0.48158, 0.52444, 0.55254, 0.5708, 0.58, 0.58908, 0.60254, 0.62044, 0.6371, 0.64614,
-0.6543, 0.6565, 0.65678, 0.6562, 0.65836, 0.65736, 0.65742, 0.65628, 0.6547, 0.65488, 0.65312, 0.65302, 0.65156, 0.65362, 0.65142, 0.64932, 0.64966, 0.64962,
-0.64926, 0.64938, 0.64838, 0.6474, 0.64832, 0.64704, 0.64454, 0.6427, 0.64632, 0.64712, 0.64638, 0.6465, 0.6451, 0.64326, 0.6454, 0.64588, 0.6454, 0.6469,
-0.64272, 0.64422, 0.6434, 0.64488, 0.64516, 0.64692, 0.64298, 0.6422, 
-//272
-0.12316, 0.14822, 0.1857, 0.22994, 0.26478, 0.28472, 0.29096, 0.29092, 0.2905, 0.2901, 0.29104, 0.28934, 0.28882, 0.28976, 0.29082, 0.29184, 0.29446, 0.295,
-0.29374, 0.29234, 0.29348, 0.29084, 0.29188, 0.29652, 0.30868, 0.33504, 0.37546, 0.42012, 0.45556, 0.47714, 0.48796, 0.497, 0.50754, 0.51962, 0.5367, 0.553,
-0.55978, 0.56852, 0.57024, 0.57076, 0.57152, 0.57134, 0.57148, 0.56896, 0.5683, 0.56814, 0.56722, 0.56634, 0.56526, 0.56424, 0.56308, 0.56252, 0.56092, 0.56218,
-0.55992, 0.55772, 0.5584, 0.55796, 0.55822, 0.55906, 0.5566, 0.55432, 0.55358, 0.55466, 0.55494, 0.5536, 0.55258, 0.55042, 0.55098, 0.55162, 0.5511, 0.55186,
-0.55116, 0.54674, 0.5481, 0.54886, 0.54938, 0.54786, 0.54566, 0.5465, 0.54624, 
-//273
-0.11806, 0.13584, 0.1621, 0.18558, 0.19988, 0.2049, 0.20638, 0.20552, 0.20338, 0.20148, 0.20152, 0.19976, 0.20152, 0.20166, 0.20256, 0.20172, 0.2008, 0.20154,
-0.20184, 0.2014, 0.20234, 0.20228, 0.20004, 0.2011, 0.21244, 0.23242, 0.26304, 0.29674, 0.3229, 0.33576, 0.34234, 0.34872, 0.35638, 0.3695, 0.38688, 0.40228,
-0.41074, 0.42062, 0.42298, 0.42286, 0.4223, 0.42194, 0.42152, 0.41916, 0.42022, 0.4194, 0.41622, 0.41498, 0.41432, 0.41444, 0.41378, 0.41098, 0.4092, 0.408,
-0.40722, 0.40734, 0.40796, 0.40472, 0.4034, 0.40272, 0.40256, 0.40052, 0.39902, 0.40088, 0.39908, 0.39692, 0.39806, 0.39724, 0.39686, 0.39814, 0.39698, 0.3945,
-0.39478, 0.39484, 0.39444, 0.39358, 0.3936, 0.39276, 0.39256, 0.38966, 0.39268, 
-//274
-0.098, 0.10668, 0.12116, 0.13082, 0.13564, 0.13662, 0.13684, 0.13654, 0.13612, 0.1349, 0.13424, 0.13276, 0.13298, 0.13308, 0.1335, 0.13562, 0.13534, 0.13426,
-0.135, 0.13494, 0.13466, 0.13542, 0.13584, 0.13762, 0.14478, 0.1602, 0.18342, 0.20882, 0.22676, 0.23604, 0.24158, 0.24584, 0.25236, 0.26184, 0.27664, 0.28908,
-0.29512, 0.30392, 0.30486, 0.30666, 0.30762, 0.30732, 0.30622, 0.30578, 0.30404, 0.30236, 0.30186, 0.30182, 0.3015, 0.29888, 0.29848, 0.29882, 0.29724, 0.2958,
-0.29474, 0.29398, 0.29204, 0.29066, 0.29316, 0.29334, 0.29102, 0.28766, 0.28852, 0.28944, 0.2896, 0.2878, 0.28974, 0.28818, 0.28736, 0.28768, 0.2888, 0.28808,
-0.28914, 0.28676, 0.28576, 0.28514, 0.2855, 0.28592, 0.2848, 0.2841, 0.28408, 
-//275
-0.06822, 0.07148, 0.07664, 0.08042, 0.0813, 0.08134, 0.08156, 0.08118, 0.08064, 0.08052, 0.08038, 0.0798, 0.07998, 0.08134, 0.08232, 0.0813, 0.08168, 0.08104,
-0.08168, 0.08154, 0.08206, 0.08366, 0.08324, 0.08394, 0.08894, 0.1013, 0.11782, 0.1348, 0.1471, 0.14992, 0.15164, 0.15432, 0.1589, 0.16726, 0.1797, 0.191,
-0.1971, 0.20556, 0.2072, 0.20706, 0.2069, 0.20812, 0.20752, 0.20704, 0.20726, 0.20386, 0.20276, 0.20302, 0.20376, 0.20292, 0.20282, 0.2007, 0.20042, 0.19976,
-0.19974, 0.19902, 0.19916, 0.19758, 0.19784, 0.19762, 0.19842, 0.19612, 0.19552, 0.19676, 0.19704, 0.19612, 0.19764, 0.1981, 0.19738, 0.19806, 0.19754, 0.19802,
-0.19858, 0.19736, 0.1986, 0.19712, 0.19794, 0.19764, 0.1979, 0.19798, 0.1976, 
-//276
-0.04166, 0.04012, 0.04104, 0.04194, 0.04248, 0.04266, 0.0414, 0.04142, 0.04078, 0.04224, 0.0421, 0.04228, 0.04194, 0.0398, 0.0425, 0.04392, 0.04468, 0.04394,
-0.04332, 0.04254, 0.04444, 0.0458, 0.0462, 0.04484, 0.05194, 0.05502, 0.06376, 0.07802, 0.08938, 0.0962, 0.09972, 0.09852, 0.1007, 0.10404, 0.10868, 0.11286,
-0.1159, 0.1236, 0.129, 0.13308, 0.13566, 0.13672, 0.13688, 0.13682, 0.13378, 0.1327, 0.13496, 0.13296, 0.13296, 0.13304, 0.13178, 0.13256, 0.13258, 0.13194,
-0.13328, 0.12792, 0.12814, 0.12802, 0.1294, 0.12904, 0.12816, 0.12454, 0.1261, 0.12984, 0.12906, 0.1276, 0.12838, 0.1284, 0.12988, 0.13184, 0.1325, 0.13084,
-0.13046, 0.13016, 0.13152, 0.13128, 0.13206, 0.1331, 0.13212, 0.13104, 0.13164, 
-//277
-0.11998, 0.14368, 0.18334, 0.22896, 0.2661, 0.28746, 0.29556, 0.29586, 0.29488, 0.2917, 0.29228, 0.29206, 0.29468, 0.29698, 0.29892, 0.29946, 0.3002, 0.30106,
-0.30158, 0.303, 0.30378, 0.30418, 0.30536, 0.30994, 0.32576, 0.35656, 0.4064, 0.4698, 0.53458, 0.5923, 0.63808, 0.67356, 0.70038, 0.72496, 0.74666, 0.7628,
-0.7725, 0.78352, 0.78366, 0.7868, 0.79014, 0.78916, 0.79198, 0.79238, 0.79106, 0.78886, 0.78992, 0.7912, 0.79046, 0.79226, 0.79142, 0.79164, 0.79226, 0.79304,
-0.794, 0.7921, 0.78896, 0.78462, 0.78268, 0.78654, 0.78832, 0.7895, 0.79136, 0.79486, 0.79372, 0.79484, 0.79686, 0.79456, 0.79658, 0.79836, 0.80038, 0.79928,
-0.80106, 0.80068, 0.8004, 0.80088, 0.80204, 0.80254, 0.80272, 0.7988, 0.80302, 
-//278
-0.124, 0.14568, 0.17942, 0.21442, 0.23996, 0.25404, 0.26008, 0.26012, 0.25826, 0.25496, 0.2548, 0.25488, 0.25572, 0.25634, 0.258, 0.25844, 0.25734, 0.26016,
-0.26022, 0.26008, 0.26016, 0.25848, 0.25878, 0.26334, 0.2782, 0.30564, 0.35258, 0.41254, 0.47362, 0.52596, 0.56546, 0.59078, 0.6088, 0.6288, 0.65042, 0.66882,
-0.67834, 0.6933, 0.6964, 0.69462, 0.69596, 0.69494, 0.6989, 0.6989, 0.69886, 0.69654, 0.6955, 0.6967, 0.69574, 0.69696, 0.69602, 0.6962, 0.69408, 0.6949, 0.696,
-0.69692, 0.69778, 0.69638, 0.69454, 0.6966, 0.6945, 0.69204, 0.69414, 0.69776, 0.69572, 0.69436, 0.69536, 0.69486, 0.69516, 0.69824, 0.6995, 0.69828, 0.69822,
-0.696, 0.69756, 0.69762, 0.69802, 0.69754, 0.69994, 0.69512, 0.69552, 
-//279
-0.10764, 0.12208, 0.14834, 0.17244, 0.1907, 0.1989, 0.19952, 0.1964, 0.19552, 0.19438, 0.19588, 0.19528, 0.19634, 0.19636, 0.19782, 0.19844, 0.19954, 0.19946,
-0.1988, 0.19958, 0.20104, 0.2003, 0.20082, 0.20614, 0.21664, 0.24066, 0.28418, 0.3391, 0.3932, 0.43326, 0.46058, 0.47862, 0.49432, 0.5126, 0.5329, 0.5496,
-0.55916, 0.56914, 0.57268, 0.5755, 0.57442, 0.57384, 0.57358, 0.57264, 0.57244, 0.57214, 0.57346, 0.5703, 0.56906, 0.56848, 0.56942, 0.56868, 0.56746, 0.56724,
-0.56536, 0.56518, 0.56808, 0.56664, 0.565, 0.5651, 0.5637, 0.56156, 0.55954, 0.56332, 0.5646, 0.56312, 0.56214, 0.56324, 0.5618, 0.56284, 0.56432, 0.56196,
-0.56288, 0.5612, 0.5607, 0.56078, 0.56286, 0.5607, 0.5604, 0.55928, 0.56152, 
-//280
-0.10276, 0.111, 0.12518, 0.13794, 0.14538, 0.14732, 0.14698, 0.14552, 0.14332, 0.1426, 0.14314, 0.14274, 0.14232, 0.14168, 0.14232, 0.14372, 0.14492, 0.1455,
-0.14568, 0.14468, 0.14454, 0.1459, 0.14794, 0.15076, 0.16058, 0.1792, 0.21378, 0.25378, 0.29086, 0.31622, 0.33198, 0.34008, 0.35024, 0.36768, 0.38942, 0.41192,
-0.42438, 0.43606, 0.44052, 0.44052, 0.44062, 0.44084, 0.44072, 0.4416, 0.44266, 0.4373, 0.4358, 0.43668, 0.4357, 0.4354, 0.43536, 0.43188, 0.43188, 0.43042,
-0.4304, 0.42774, 0.4275, 0.42574, 0.42626, 0.4278, 0.42586, 0.4225, 0.4226, 0.42476, 0.4246, 0.42252, 0.42076, 0.42092, 0.42042, 0.42232, 0.42228, 0.42048,
-0.41982, 0.41794, 0.41868, 0.4171, 0.41816, 0.41874, 0.41716, 0.4168, 0.41416, 
-//281
-0.07558, 0.082, 0.08934, 0.09026, 0.09046, 0.08982, 0.0905, 0.091, 0.09014, 0.08972, 0.0894, 0.08914, 0.08932, 0.08904, 0.08932, 0.08908, 0.08952, 0.09084,
-0.09242, 0.09226, 0.0935, 0.09274, 0.09234, 0.09456, 0.1045, 0.12066, 0.14606, 0.1758, 0.20226, 0.22082, 0.23164, 0.23868, 0.24756, 0.26102, 0.27832, 0.2956,
-0.30612, 0.31902, 0.32372, 0.324, 0.32456, 0.32374, 0.32278, 0.321, 0.32224, 0.32214, 0.32124, 0.3191, 0.31648, 0.31542, 0.3168, 0.31626, 0.31604, 0.3154,
-0.31302, 0.31162, 0.31182, 0.31018, 0.30978, 0.31168, 0.311, 0.30906, 0.30696, 0.3087, 0.3097, 0.30836, 0.30956, 0.30904, 0.30904, 0.30758, 0.30832, 0.30814,
-0.31088, 0.3092, 0.30714, 0.3079, 0.30714, 0.30614, 0.30648, 0.30912, 0.30752, 
-//282
-0.0527, 0.05318, 0.05268, 0.05418, 0.05494, 0.05392, 0.05388, 0.05362, 0.05498, 0.05348, 0.05456, 0.0538, 0.05512, 0.05576, 0.05602, 0.05548, 0.05554, 0.0549,
-0.05562, 0.05608, 0.05806, 0.05778, 0.05956, 0.06136, 0.06458, 0.0772, 0.09474, 0.11544, 0.1324, 0.143, 0.1474, 0.15074, 0.15754, 0.16954, 0.18596, 0.20178,
-0.21212, 0.22306, 0.22616, 0.2266, 0.22602, 0.22706, 0.22662, 0.2251, 0.2244, 0.22332, 0.22264, 0.22196, 0.22266, 0.22254, 0.2206, 0.21878, 0.21786, 0.217,
-0.21748, 0.21794, 0.21938, 0.21694, 0.217, 0.2161, 0.21586, 0.21406, 0.2135, 0.21538, 0.21564, 0.21566, 0.21622, 0.21658, 0.21606, 0.21808, 0.21764, 0.21858,
-0.21748, 0.21782, 0.21758, 0.2193, 0.21948, 0.2185, 0.21988, 0.2171, 0.21692, 
-//283
-0.1149, 0.12958, 0.15258, 0.17096, 0.18458, 0.19086, 0.19304, 0.18984, 0.18816, 0.18586, 0.18602, 0.18594, 0.18878, 0.1899, 0.1886, 0.1877, 0.1892, 0.19014,
-0.19128, 0.19184, 0.19098, 0.19182, 0.19222, 0.195, 0.20812, 0.23578, 0.28102, 0.34412, 0.41584, 0.48556, 0.54188, 0.58662, 0.61828, 0.64348, 0.66892, 0.68594,
-0.6938, 0.70514, 0.70938, 0.71034, 0.7113, 0.7131, 0.71368, 0.71488, 0.71782, 0.71518, 0.71648, 0.71818, 0.7181, 0.71824, 0.7186, 0.71866, 0.71792, 0.71856,
-0.72042, 0.72154, 0.7205, 0.71902, 0.71718, 0.7192, 0.71918, 0.71878, 0.72016, 0.72364, 0.72376, 0.72526, 0.72384, 0.7274, 0.72596, 0.72926, 0.73238, 0.7311,
-0.73404, 0.73648, 0.73532, 0.73406, 0.73442, 0.73472, 0.7379, 0.7324, 0.73702, 
-//284
-0.10182, 0.11502, 0.13278, 0.14924, 0.15832, 0.1599, 0.1602, 0.15946, 0.15826, 0.15688, 0.15832, 0.1582, 0.15722, 0.1573, 0.1582, 0.15866, 0.16076, 0.16268,
-0.16216, 0.16054, 0.1611, 0.16096, 0.16238, 0.16744, 0.17964, 0.20428, 0.24834, 0.3076, 0.36986, 0.4265, 0.46902, 0.49896, 0.52238, 0.54592, 0.57156, 0.5935,
-0.6063, 0.62034, 0.62456, 0.62468, 0.62454, 0.6258, 0.62758, 0.62534, 0.62702, 0.62564, 0.62652, 0.62692, 0.62524, 0.62468, 0.62498, 0.62448, 0.62396, 0.62474,
-0.6247, 0.6221, 0.62178, 0.62192, 0.62364, 0.6229, 0.62372, 0.62322, 0.6204, 0.62184, 0.62194, 0.62318, 0.6227, 0.62258, 0.62228, 0.62386, 0.62482, 0.62412,
-0.62554, 0.62204, 0.62448, 0.6238, 0.62524, 0.62594, 0.62596, 0.62456, 0.62494, 
-//285
-0.07748, 0.08478, 0.09004, 0.09344, 0.0958, 0.09598, 0.09678, 0.09722, 0.09726, 0.09512, 0.09472, 0.09526, 0.09718, 0.0972, 0.09842, 0.0979, 0.09882, 0.09988,
-0.09912, 0.0994, 0.0999, 0.09994, 0.09978, 0.10482, 0.11606, 0.13444, 0.1681, 0.2135, 0.26206, 0.29882, 0.32642, 0.3438, 0.35746, 0.3743, 0.39864, 0.42312,
-0.4375, 0.4551, 0.45938, 0.46022, 0.45962, 0.45988, 0.46152, 0.46038, 0.4622, 0.4598, 0.45762, 0.45668, 0.4563, 0.45638, 0.4571, 0.45598, 0.45518, 0.45398,
-0.4548, 0.4535, 0.4538, 0.45204, 0.45174, 0.4525, 0.45192, 0.4508, 0.44816, 0.45236, 0.45148, 0.4498, 0.4506, 0.45222, 0.44986, 0.4529, 0.45338, 0.45256,
-0.45276, 0.45276, 0.45492, 0.45152, 0.44946, 0.45042, 0.45246, 0.45012, 0.4538, 
-//286
-0.05236, 0.05484, 0.05712, 0.05832, 0.05872, 0.05938, 0.05848, 0.05952, 0.06126, 0.06016, 0.05974, 0.05874, 0.05816, 0.05922, 0.06122, 0.06144, 0.062, 0.0614,
-0.0609, 0.06132, 0.06326, 0.0654, 0.06674, 0.0705, 0.07474, 0.08738, 0.11134, 0.1449, 0.17656, 0.20046, 0.21548, 0.2265, 0.2409, 0.25972, 0.28542, 0.30716,
-0.32076, 0.33476, 0.33672, 0.3386, 0.34268, 0.34196, 0.34174, 0.34006, 0.33868, 0.33716, 0.33748, 0.33622, 0.33558, 0.33518, 0.33442, 0.33374, 0.33278, 0.33276,
-0.3316, 0.33078, 0.33098, 0.32832, 0.33032, 0.33194, 0.33, 0.32634, 0.3287, 0.33156, 0.3291, 0.32676, 0.32848, 0.32874, 0.32934, 0.33022, 0.32962, 0.33032,
-0.32908, 0.32784, 0.3295, 0.3287, 0.32894, 0.3281, 0.32994, 0.32618, 0.3295, 
-//287
-0.09258, 0.104, 0.12, 0.13118, 0.1376, 0.1402, 0.14074, 0.1414, 0.14168, 0.14076, 0.14044, 0.14046, 0.14166, 0.14236, 0.14494, 0.14646, 0.14694, 0.14804,
-0.14822, 0.14912, 0.15058, 0.15088, 0.15234, 0.15744, 0.17064, 0.1977, 0.24318, 0.30914, 0.3858, 0.4646, 0.5377, 0.60272, 0.65268, 0.68676, 0.71122, 0.72532,
-0.73176, 0.74218, 0.74614, 0.74822, 0.74902, 0.7513, 0.75146, 0.7513, 0.75424, 0.75432, 0.75282, 0.75464, 0.75322, 0.75414, 0.75848, 0.75948, 0.75852, 0.7598,
-0.76024, 0.75844, 0.75858, 0.76, 0.75746, 0.76112, 0.76192, 0.7622, 0.76106, 0.76658, 0.76934, 0.76834, 0.77054, 0.76912, 0.76692, 0.76978, 0.77342, 0.77258,
-0.77382, 0.77514, 0.77512, 0.77592, 0.77846, 0.77776, 0.7772, 0.77646, 0.77514, 
-//288
-0.0767, 0.0858, 0.0942, 0.10062, 0.10432, 0.1056, 0.10562, 0.10532, 0.10698, 0.1046, 0.10466, 0.10424, 0.10626, 0.10752, 0.1095, 0.11016, 0.11008, 0.11038,
-0.11056, 0.11224, 0.11402, 0.11536, 0.11714, 0.12058, 0.12994, 0.15278, 0.1934, 0.2508, 0.32102, 0.39228, 0.45828, 0.5132, 0.55392, 0.5819, 0.60248, 0.61654,
-0.62328, 0.6352, 0.64276, 0.64872, 0.65472, 0.65672, 0.6615, 0.66252, 0.66284, 0.66584, 0.66658, 0.66766, 0.66732, 0.67006, 0.6702, 0.67022, 0.67278, 0.67314,
-0.67264, 0.67382, 0.6741, 0.67336, 0.67328, 0.67702, 0.67676, 0.67444, 0.6778, 0.67908, 0.68152, 0.68248, 0.68214, 0.6832, 0.6833, 0.68672, 0.6869, 0.68908,
-0.69006, 0.68746, 0.68892, 0.69082, 0.69122, 0.6941, 0.69274, 0.69092, 0.69516, 
-//289
-0.06424, 0.06542, 0.06634, 0.06728, 0.06768, 0.06894, 0.06752, 0.0679, 0.06868, 0.06642, 0.06722, 0.06928, 0.06924, 0.06914, 0.06926, 0.06942, 0.06854, 0.0704,
-0.07202, 0.0729, 0.07246, 0.07224, 0.07296, 0.07638, 0.08576, 0.10466, 0.13668, 0.18288, 0.237, 0.28886, 0.33036, 0.36132, 0.38422, 0.40596, 0.43072, 0.45376,
-0.46678, 0.48012, 0.48338, 0.48498, 0.48608, 0.48518, 0.48502, 0.4861, 0.487, 0.48426, 0.48344, 0.48422, 0.48416, 0.48316, 0.48348, 0.48228, 0.48118, 0.4811,
-0.48228, 0.4812, 0.48182, 0.4804, 0.47892, 0.48028, 0.48158, 0.47958, 0.47818, 0.4818, 0.48144, 0.48092, 0.48252, 0.4823, 0.48332, 0.48552, 0.48516, 0.48362,
-0.48474, 0.48322, 0.4847, 0.48612, 0.48696, 0.48522, 0.4867, 0.48508, 0.48932, 
-//290
-0.05972, 0.06198, 0.0621, 0.06186, 0.06192, 0.0622, 0.06488, 0.06426, 0.06542, 0.06496, 0.0656, 0.0659, 0.0673, 0.06864, 0.0706, 0.072, 0.07536, 0.0794, 0.0829,
-0.08568, 0.08938, 0.09162, 0.09592, 0.10286, 0.11614, 0.13798, 0.17434, 0.2293, 0.29406, 0.36526, 0.4349, 0.492, 0.53688, 0.5696, 0.59422, 0.60828, 0.62074,
-0.63742, 0.64406, 0.64922, 0.65604, 0.6598, 0.6655, 0.66876, 0.67264, 0.67314, 0.67406, 0.67804, 0.67746, 0.68066, 0.68518, 0.68712, 0.68642, 0.6904, 0.69302,
-0.6911, 0.6935, 0.69384, 0.69418, 0.69706, 0.69946, 0.70134, 0.69872, 0.70054, 0.70244, 0.7061, 0.70666, 0.70926, 0.71078, 0.7128, 0.71312, 0.7153, 0.71822,
-0.71918, 0.72172, 0.71928, 0.7207, 0.72086, 0.72336, 0.72584, 0.72616, 
-//291
-0.05268, 0.05046, 0.04762, 0.04694, 0.0476, 0.04834, 0.04768, 0.04866, 0.04944, 0.04882, 0.04858, 0.04842, 0.04958, 0.04878, 0.04886, 0.04886, 0.0494, 0.05086,
-0.0504, 0.05008, 0.04976, 0.0508, 0.0531, 0.05656, 0.06344, 0.07498, 0.09854, 0.13778, 0.19152, 0.25258, 0.31018, 0.35906, 0.3953, 0.41844, 0.4371, 0.45326,
-0.4614, 0.47894, 0.48504, 0.49154, 0.4958, 0.4994, 0.50206, 0.50152, 0.5012, 0.49868, 0.49908, 0.49838, 0.4969, 0.49958, 0.50138, 0.50192, 0.50332, 0.50238,
-0.50552, 0.50734, 0.51262, 0.51422, 0.51606, 0.5228, 0.5275, 0.5283, 0.52894, 0.53664, 0.5392, 0.54232, 0.54304, 0.54568, 0.54538, 0.54764, 0.55018, 0.5533,
-0.5587, 0.56298, 0.56742, 0.56852, 0.57102, 0.57364, 0.57578, 0.5776, 0.57632, 
-//292
-0.13566, 0.16532, 0.22162, 0.30262, 0.39978, 0.49008, 0.54844, 0.5719, 0.579, 0.57834, 0.57904, 0.5812, 0.58062, 0.58002, 0.58256, 0.58398, 0.58494, 0.58488,
-0.58578, 0.58434, 0.5825, 0.58244, 0.58278, 0.58506, 0.59426, 0.611, 0.63198, 0.6536, 0.6683, 0.676, 0.67966, 0.6821, 0.68438, 0.68544, 0.6886, 0.69174,
-0.69132, 0.6936, 0.69336, 0.6929, 0.69262, 0.6926, 0.69358, 0.6921, 0.69098, 0.69028, 0.6903, 0.6899, 0.68904, 0.68772, 0.68856, 0.68816, 0.68688, 0.68618,
-0.68578, 0.68496, 0.68428, 0.68454, 0.68436, 0.68402, 0.68312, 0.68204, 0.68062, 0.68274, 0.68328, 0.68162, 0.68156, 0.68162, 0.68122, 0.68334, 0.68346,
-0.68132, 0.68442, 0.68202, 0.68316, 0.68162, 0.68138, 0.68158, 0.67986, 0.6791, 0.67952, 
-//293
-0.13846, 0.16758, 0.22176, 0.29458, 0.37902, 0.44884, 0.48556, 0.49902, 0.50304, 0.50146, 0.50112, 0.5001, 0.50026, 0.498, 0.50068, 0.50004, 0.50058, 0.50122,
-0.5007, 0.49908, 0.49834, 0.49816, 0.4981, 0.50072, 0.5096, 0.52454, 0.54534, 0.5652, 0.57838, 0.58432, 0.58786, 0.59024, 0.5915, 0.59378, 0.59812, 0.60052,
-0.6002, 0.60598, 0.60468, 0.60204, 0.60058, 0.60046, 0.60046, 0.59904, 0.60062, 0.59866, 0.59636, 0.59616, 0.59464, 0.59256, 0.59334, 0.59298, 0.58978, 0.59044,
-0.58876, 0.58736, 0.58736, 0.58748, 0.58606, 0.58554, 0.58554, 0.58188, 0.57928, 0.58112, 0.58118, 0.57972, 0.57954, 0.58042, 0.5783, 0.57762, 0.57774, 0.57822,
-0.57804, 0.57604, 0.57594, 0.5735, 0.574, 0.57434, 0.57524, 0.57346, 0.5767, 
-//294
-0.1344, 0.1646, 0.21626, 0.28536, 0.35436, 0.40466, 0.42874, 0.43676, 0.43844, 0.43804, 0.43854, 0.43884, 0.43904, 0.43704, 0.43738, 0.43736, 0.43904, 0.44068,
-0.4402, 0.43828, 0.43866, 0.43672, 0.43666, 0.4392, 0.44754, 0.46026, 0.47904, 0.49722, 0.50474, 0.50832, 0.51094, 0.51086, 0.51276, 0.51726, 0.5225, 0.52486,
-0.5259, 0.52772, 0.52792, 0.52774, 0.52752, 0.52596, 0.52656, 0.52482, 0.5225, 0.5221, 0.52344, 0.52258, 0.52078, 0.51948, 0.5188, 0.51802, 0.51928, 0.51738,
-0.51568, 0.51656, 0.51576, 0.51352, 0.51338, 0.51384, 0.51392, 0.51188, 0.50968, 0.51152, 0.5105, 0.51204, 0.51258, 0.51034, 0.51008, 0.51318, 0.5122, 0.5111,
-0.5145, 0.5112, 0.51108, 0.51132, 0.51306, 0.5105, 0.5116, 0.51248, 0.51206, 
-//295
-0.1316, 0.15726, 0.19988, 0.24788, 0.29238, 0.31554, 0.32476, 0.32576, 0.32614, 0.3243, 0.32236, 0.32214, 0.32052, 0.31928, 0.31946, 0.31974, 0.31922, 0.31916,
-0.3193, 0.3178, 0.31604, 0.31556, 0.3143, 0.31572, 0.32256, 0.33334, 0.34772, 0.35946, 0.36836, 0.37036, 0.37136, 0.3723, 0.37408, 0.37754, 0.38374, 0.38598,
-0.3862, 0.38996, 0.38906, 0.38874, 0.38682, 0.38608, 0.3866, 0.38398, 0.38308, 0.3817, 0.38106, 0.3781, 0.3777, 0.37588, 0.37572, 0.37386, 0.37348, 0.37116,
-0.37034, 0.36974, 0.36832, 0.3659, 0.36536, 0.36546, 0.36438, 0.3623, 0.3606, 0.36164, 0.36032, 0.36008, 0.36076, 0.35862, 0.3577, 0.35798, 0.35622, 0.35584,
-0.35808, 0.35662, 0.3553, 0.35486, 0.35422, 0.3519, 0.35282, 0.35186, 0.35308, 
-//296
-0.12182, 0.14138, 0.16934, 0.19694, 0.21684, 0.2237, 0.22664, 0.22582, 0.22446, 0.22256, 0.22188, 0.22106, 0.21912, 0.21838, 0.2194, 0.21944, 0.21904, 0.21882,
-0.21798, 0.2162, 0.21536, 0.21594, 0.21518, 0.21682, 0.22064, 0.22824, 0.23876, 0.24922, 0.25516, 0.2562, 0.2573, 0.2577, 0.25882, 0.2612, 0.26578, 0.26978,
-0.27066, 0.27336, 0.27256, 0.27106, 0.2704, 0.26856, 0.26808, 0.26792, 0.26822, 0.26632, 0.2644, 0.263, 0.26214, 0.26112, 0.26064, 0.26062, 0.25912, 0.25694,
-0.255, 0.25458, 0.25442, 0.25266, 0.25254, 0.25328, 0.25216, 0.249, 0.24916, 0.24952, 0.24898, 0.24826, 0.24828, 0.24758, 0.24678, 0.24756, 0.24694, 0.24708,
-0.248, 0.24662, 0.24678, 0.24524, 0.24388, 0.24448, 0.24596, 0.24482, 0.24436, 
-//297
-0.10122, 0.11418, 0.12998, 0.14118, 0.14826, 0.15048, 0.15194, 0.15208, 0.1514, 0.14942, 0.14764, 0.14646, 0.14732, 0.14612, 0.14686, 0.14576, 0.14634, 0.1461,
-0.14554, 0.14566, 0.1449, 0.14418, 0.14286, 0.1425, 0.1481, 0.15432, 0.16358, 0.16878, 0.17214, 0.17216, 0.17194, 0.17296, 0.17528, 0.17938, 0.18186, 0.18342,
-0.18282, 0.1868, 0.18636, 0.18584, 0.18642, 0.18476, 0.18422, 0.18324, 0.18226, 0.18114, 0.18118, 0.18146, 0.18002, 0.1778, 0.17792, 0.17648, 0.17662, 0.1772,
-0.17692, 0.17474, 0.17284, 0.17046, 0.17148, 0.1724, 0.1728, 0.17012, 0.16896, 0.17018, 0.16944, 0.17124, 0.1723, 0.17172, 0.1707, 0.1712, 0.17102, 0.17072,
-0.1722, 0.17086, 0.17126, 0.17064, 0.17042, 0.17034, 0.17026, 0.16992, 0.17248, 
-//298
-0.0783, 0.08392, 0.08954, 0.09276, 0.09492, 0.09546, 0.09484, 0.0938, 0.09452, 0.09296, 0.09208, 0.09172, 0.0928, 0.09204, 0.092, 0.09138, 0.09158, 0.0915,
-0.09146, 0.09222, 0.09034, 0.0901, 0.08984, 0.08978, 0.09344, 0.09706, 0.10266, 0.10624, 0.1073, 0.10656, 0.1067, 0.10862, 0.10942, 0.1116, 0.11472, 0.1171,
-0.11566, 0.11862, 0.11868, 0.11898, 0.11922, 0.11802, 0.11718, 0.11654, 0.1159, 0.1151, 0.11556, 0.11614, 0.11512, 0.1132, 0.1129, 0.1124, 0.1124, 0.11344,
-0.1132, 0.11308, 0.11148, 0.10882, 0.11086, 0.11182, 0.11194, 0.111, 0.10836, 0.11124, 0.112, 0.11296, 0.11268, 0.11362, 0.11294, 0.11324, 0.11348, 0.1139,
-0.11476, 0.11472, 0.11536, 0.1141, 0.11372, 0.11432, 0.11568, 0.11416, 0.11528, 
-//299
-0.05364, 0.054, 0.05478, 0.05484, 0.05582, 0.05634, 0.05574, 0.05424, 0.05326, 0.05298, 0.05452, 0.05448, 0.0554, 0.05486, 0.05312, 0.05076, 0.05326, 0.0541,
-0.05366, 0.0541, 0.05416, 0.05316, 0.05158, 0.05362, 0.05634, 0.05834, 0.06206, 0.06276, 0.06424, 0.06452, 0.06442, 0.06564, 0.06526, 0.06488, 0.0661, 0.06624,
-0.06494, 0.07006, 0.07092, 0.07092, 0.06948, 0.06956, 0.0698, 0.06892, 0.06964, 0.06794, 0.06822, 0.06782, 0.0684, 0.06816, 0.06874, 0.06706, 0.06628, 0.06588,
-0.0676, 0.0672, 0.06824, 0.06634, 0.06564, 0.0656, 0.0665, 0.06554, 0.06466, 0.06712, 0.06804, 0.0693, 0.07, 0.0697, 0.06916, 0.0702, 0.07124, 0.0714, 0.07222,
-0.07216, 0.07284, 0.07306, 0.0736, 0.0742, 0.07578, 0.07304, 0.07342, 
-//300
-0.0422, 0.03838, 0.03846, 0.0397, 0.04214, 0.04146, 0.041, 0.03968, 0.03982, 0.03992, 0.04086, 0.04176, 0.04142, 0.04098, 0.03962, 0.03952, 0.04072, 0.04092,
-0.04128, 0.04048, 0.04024, 0.0394, 0.04008, 0.0418, 0.04226, 0.04228, 0.04178, 0.04132, 0.04202, 0.04264, 0.0442, 0.0463, 0.04704, 0.04892, 0.05126, 0.05348,
-0.05188, 0.05386, 0.05292, 0.05198, 0.05206, 0.05198, 0.05138, 0.0489, 0.04796, 0.0471, 0.04804, 0.04786, 0.04826, 0.04772, 0.04714, 0.0462, 0.04526, 0.04618,
-0.04692, 0.0466, 0.04616, 0.0434, 0.0445, 0.04594, 0.04632, 0.04566, 0.04494, 0.0478, 0.04842, 0.04846, 0.04774, 0.04854, 0.0497, 0.05066, 0.05188, 0.05144,
-0.05156, 0.0523, 0.05282, 0.0536, 0.05408, 0.0541, 0.0548, 0.05468, 0.0547, 
-//301
-0.13724, 0.16918, 0.22408, 0.3041, 0.38832, 0.45428, 0.49024, 0.5045, 0.50882, 0.5092, 0.51004, 0.51266, 0.5135, 0.51634, 0.52202, 0.52354, 0.52642, 0.52784,
-0.53112, 0.53306, 0.5347, 0.53518, 0.5378, 0.54294, 0.55418, 0.57904, 0.61514, 0.65048, 0.68212, 0.70318, 0.71658, 0.7219, 0.72486, 0.72894, 0.72996, 0.72846,
-0.72724, 0.73148, 0.7288, 0.7292, 0.72858, 0.73076, 0.73174, 0.72886, 0.72904, 0.7289, 0.72926, 0.72998, 0.72974, 0.7289, 0.72864, 0.72852, 0.728, 0.72892,
-0.72788, 0.72882, 0.72856, 0.72614, 0.7287, 0.72938, 0.7293, 0.72738, 0.72534, 0.72896, 0.72948, 0.72856, 0.72948, 0.72864, 0.7287, 0.7314, 0.73356, 0.73272,
-0.7333, 0.72934, 0.73096, 0.73196, 0.73216, 0.73422, 0.7341, 0.7328, 0.7336, 
-//302
-0.14118, 0.17078, 0.2209, 0.28854, 0.3617, 0.41422, 0.4378, 0.44522, 0.4463, 0.44268, 0.4423, 0.44276, 0.44568, 0.44466, 0.44252, 0.4441, 0.44484, 0.44466,
-0.44628, 0.44588, 0.4444, 0.44072, 0.44218, 0.4464, 0.45922, 0.48242, 0.51748, 0.55492, 0.58456, 0.60632, 0.6198, 0.62654, 0.62824, 0.63138, 0.63136, 0.62962,
-0.62786, 0.62972, 0.62824, 0.62844, 0.6282, 0.62692, 0.6267, 0.62484, 0.6238, 0.62418, 0.6224, 0.62186, 0.62042, 0.61922, 0.61816, 0.61624, 0.61642, 0.61568,
-0.6144, 0.61342, 0.61346, 0.61106, 0.6093, 0.61052, 0.60972, 0.60642, 0.6049, 0.60662, 0.60664, 0.60448, 0.60572, 0.6046, 0.60418, 0.60586, 0.60444, 0.60188,
-0.60148, 0.60104, 0.60058, 0.60022, 0.60198, 0.59896, 0.60008, 0.59748, 0.59826, 
-//303
-0.13026, 0.16056, 0.20766, 0.26948, 0.32864, 0.36818, 0.38688, 0.39178, 0.39236, 0.39026, 0.39102, 0.3894, 0.389, 0.38992, 0.39122, 0.39156, 0.3929, 0.39382,
-0.39274, 0.39206, 0.39254, 0.39104, 0.38916, 0.39292, 0.40532, 0.42644, 0.45758, 0.4889, 0.51064, 0.522, 0.52972, 0.53392, 0.5369, 0.54218, 0.54724, 0.54858,
-0.5468, 0.55296, 0.55304, 0.55174, 0.55086, 0.54802, 0.5474, 0.5469, 0.5474, 0.54434, 0.54622, 0.5465, 0.54214, 0.54076, 0.54184, 0.54042, 0.53928, 0.53794,
-0.53756, 0.53594, 0.53468, 0.53514, 0.5339, 0.53442, 0.53216, 0.53048, 0.52886, 0.53094, 0.52958, 0.52896, 0.53, 0.5291, 0.52756, 0.52524, 0.52556, 0.526,
-0.52618, 0.52178, 0.52522, 0.52434, 0.52224, 0.52192, 0.5229, 0.52222, 0.52124, 
-//304
-0.12504, 0.1471, 0.18396, 0.22062, 0.24932, 0.2637, 0.26844, 0.26716, 0.26704, 0.2669, 0.26536, 0.26588, 0.26874, 0.26908, 0.26892, 0.27084, 0.27226, 0.27108,
-0.27026, 0.26958, 0.26776, 0.26628, 0.26784, 0.27134, 0.28168, 0.2992, 0.32322, 0.34628, 0.3641, 0.37366, 0.37802, 0.3808, 0.38318, 0.38872, 0.3976, 0.40176,
-0.40068, 0.40642, 0.40562, 0.40458, 0.40446, 0.40256, 0.40134, 0.40072, 0.40176, 0.39968, 0.39824, 0.39766, 0.39562, 0.39366, 0.3933, 0.39362, 0.39212, 0.39054,
-0.39048, 0.38896, 0.38622, 0.38586, 0.38682, 0.38638, 0.38544, 0.38192, 0.38136, 0.38226, 0.37994, 0.38068, 0.37996, 0.37986, 0.37886, 0.38086, 0.38038,
-0.37726, 0.3788, 0.37704, 0.37852, 0.37666, 0.37598, 0.37672, 0.37324, 0.37018, 0.37272, 
-//305
-0.10564, 0.12014, 0.14412, 0.16524, 0.1796, 0.18642, 0.18696, 0.18484, 0.1826, 0.18194, 0.18198, 0.18338, 0.18274, 0.18184, 0.18254, 0.1821, 0.18254, 0.18346,
-0.18432, 0.1831, 0.18086, 0.18144, 0.1814, 0.183, 0.19102, 0.20326, 0.22054, 0.23622, 0.2469, 0.25324, 0.25634, 0.25582, 0.25806, 0.26242, 0.26814, 0.2722,
-0.27098, 0.27712, 0.27544, 0.27688, 0.27786, 0.2777, 0.27486, 0.27366, 0.27464, 0.27178, 0.27194, 0.272, 0.27026, 0.26782, 0.26706, 0.2673, 0.26742, 0.2677,
-0.26582, 0.26364, 0.26288, 0.26072, 0.26192, 0.26344, 0.26244, 0.25788, 0.25542, 0.25996, 0.26076, 0.25892, 0.25706, 0.2579, 0.25682, 0.25806, 0.26078, 0.25934,
-0.25872, 0.25646, 0.2566, 0.25532, 0.25602, 0.25632, 0.2573, 0.25718, 0.25332, 
-//306
-0.08734, 0.0946, 0.10616, 0.1147, 0.12122, 0.12444, 0.12518, 0.12366, 0.1218, 0.12154, 0.12172, 0.12142, 0.1225, 0.12216, 0.12146, 0.12108, 0.12252, 0.1236,
-0.12322, 0.1234, 0.12272, 0.11984, 0.1203, 0.12342, 0.12918, 0.13826, 0.14994, 0.1601, 0.16614, 0.1708, 0.1726, 0.17448, 0.17688, 0.17878, 0.18168, 0.1835,
-0.18276, 0.18918, 0.19042, 0.18832, 0.18754, 0.18526, 0.18654, 0.1864, 0.18472, 0.18546, 0.18528, 0.18392, 0.18234, 0.18102, 0.18316, 0.18114, 0.18092, 0.18126,
-0.17914, 0.17896, 0.17874, 0.17652, 0.17722, 0.17756, 0.17722, 0.17522, 0.17444, 0.1762, 0.17688, 0.17678, 0.17996, 0.1775, 0.17588, 0.17666, 0.17832, 0.17826,
-0.17742, 0.17856, 0.17986, 0.1768, 0.17728, 0.17774, 0.17868, 0.17712, 0.1791, 
-//307
-0.0624, 0.06544, 0.06868, 0.07146, 0.07222, 0.0731, 0.07228, 0.07058, 0.07074, 0.0701, 0.07052, 0.0704, 0.069, 0.06976, 0.06916, 0.06978, 0.07048, 0.07068,
-0.07038, 0.06954, 0.07, 0.07138, 0.06966, 0.07166, 0.0754, 0.08082, 0.08784, 0.09556, 0.10102, 0.10266, 0.1039, 0.10352, 0.10392, 0.10706, 0.11162, 0.11586,
-0.11296, 0.11676, 0.11802, 0.11746, 0.11742, 0.11622, 0.11506, 0.11518, 0.11432, 0.11364, 0.11364, 0.11382, 0.1143, 0.11164, 0.11246, 0.11124, 0.1118, 0.11214,
-0.11162, 0.10982, 0.10964, 0.10826, 0.11012, 0.11174, 0.1105, 0.10632, 0.1083, 0.11116, 0.1111, 0.10946, 0.11118, 0.1112, 0.1108, 0.11336, 0.11436, 0.11348,
-0.113, 0.11298, 0.11326, 0.11408, 0.11604, 0.1143, 0.11554, 0.11426, 0.11454, 
-//308
-0.04364, 0.04064, 0.04164, 0.04068, 0.04156, 0.04236, 0.04244, 0.04294, 0.04092, 0.04134, 0.04056, 0.04146, 0.04284, 0.0427, 0.04308, 0.04052, 0.04052, 0.04212,
-0.04284, 0.0423, 0.04382, 0.04394, 0.04184, 0.043, 0.04592, 0.04914, 0.0529, 0.05678, 0.0585, 0.06082, 0.06006, 0.06088, 0.06176, 0.06268, 0.06312, 0.06192,
-0.05942, 0.06824, 0.06842, 0.06928, 0.06976, 0.06762, 0.06844, 0.06912, 0.0682, 0.06764, 0.06684, 0.06552, 0.06556, 0.06582, 0.06718, 0.06752, 0.06678, 0.06482,
-0.0631, 0.06492, 0.06496, 0.0638, 0.0653, 0.06526, 0.06476, 0.06166, 0.06264, 0.06786, 0.06738, 0.06674, 0.06668, 0.06892, 0.0701, 0.07048, 0.0715, 0.0706,
-0.07024, 0.07084, 0.0718, 0.07238, 0.07274, 0.0728, 0.07196, 0.07328, 0.0737, 
-//309
-0.11776, 0.14106, 0.1827, 0.23522, 0.28248, 0.31382, 0.326, 0.32854, 0.33178, 0.33428, 0.33818, 0.34296, 0.34792, 0.35186, 0.35848, 0.37074, 0.38402, 0.3996,
-0.41804, 0.4357, 0.4564, 0.47844, 0.50634, 0.53472, 0.56316, 0.58684, 0.60586, 0.61856, 0.62724, 0.63238, 0.63508, 0.63824, 0.64112, 0.6452, 0.6559, 0.66746,
-0.67904, 0.69734, 0.71174, 0.72174, 0.73092, 0.73534, 0.7382, 0.74188, 0.74202, 0.7425, 0.7472, 0.747, 0.74926, 0.7487, 0.75, 0.74924, 0.74972, 0.75192,
-0.75154, 0.75084, 0.75258, 0.75204, 0.75362, 0.7534, 0.75454, 0.7543, 0.7538, 0.75968, 0.76292, 0.76346, 0.767, 0.76672, 0.7663, 0.76782, 0.76842, 0.76854,
-0.77292, 0.76906, 0.77178, 0.77156, 0.7757, 0.7722, 0.7764, 0.77236, 0.77508, 
-//310
-0.11922, 0.14194, 0.18382, 0.23036, 0.27116, 0.29484, 0.30334, 0.30444, 0.30446, 0.30408, 0.30664, 0.30948, 0.3109, 0.31186, 0.31406, 0.3185, 0.32256, 0.3289,
-0.33166, 0.33396, 0.33582, 0.3384, 0.34144, 0.34988, 0.3661, 0.3931, 0.43728, 0.48816, 0.53562, 0.57402, 0.60092, 0.61594, 0.62418, 0.62978, 0.63138, 0.63246,
-0.62952, 0.63432, 0.634, 0.63332, 0.63022, 0.63096, 0.63068, 0.62978, 0.63028, 0.62926, 0.628, 0.62806, 0.6269, 0.6254, 0.62536, 0.62438, 0.62298, 0.6235,
-0.62504, 0.62314, 0.62436, 0.62096, 0.6203, 0.61976, 0.61938, 0.61652, 0.61474, 0.62, 0.61896, 0.61992, 0.61918, 0.6185, 0.61774, 0.62, 0.61938, 0.61722,
-0.6187, 0.61646, 0.61982, 0.61726, 0.61772, 0.61886, 0.61644, 0.6136, 0.61538, 
-//311
-0.12046, 0.14008, 0.17402, 0.21348, 0.24696, 0.26468, 0.2705, 0.27098, 0.26928, 0.26828, 0.2704, 0.27124, 0.2732, 0.27446, 0.2775, 0.27816, 0.2802, 0.28456,
-0.28608, 0.28872, 0.28948, 0.28944, 0.2912, 0.29848, 0.31268, 0.33842, 0.3795, 0.42838, 0.47146, 0.5037, 0.52626, 0.53936, 0.54318, 0.54634, 0.54722, 0.54504,
-0.5428, 0.54856, 0.546, 0.54506, 0.54506, 0.5426, 0.54476, 0.54386, 0.54292, 0.54194, 0.54094, 0.53924, 0.5382, 0.5375, 0.53752, 0.5373, 0.53558, 0.5347,
-0.53444, 0.5332, 0.53306, 0.53146, 0.53108, 0.53292, 0.52922, 0.52802, 0.52588, 0.53046, 0.52972, 0.528, 0.52754, 0.52622, 0.52588, 0.52736, 0.52818, 0.52592,
-0.5263, 0.52572, 0.5243, 0.52426, 0.52382, 0.52308, 0.52354, 0.52118, 0.52272, 
-//312
-0.10932, 0.12478, 0.14876, 0.16938, 0.1845, 0.19248, 0.19544, 0.19422, 0.19254, 0.19046, 0.18958, 0.1906, 0.1933, 0.19432, 0.19548, 0.19524, 0.19408, 0.1954,
-0.19632, 0.19754, 0.19758, 0.19752, 0.19744, 0.20142, 0.21236, 0.23394, 0.2667, 0.30422, 0.33816, 0.3605, 0.3747, 0.38228, 0.38888, 0.39314, 0.39846, 0.40106,
-0.39874, 0.4061, 0.40592, 0.40436, 0.40392, 0.40226, 0.4041, 0.40322, 0.40354, 0.3998, 0.39874, 0.39686, 0.39648, 0.3962, 0.39622, 0.39536, 0.39404, 0.3917,
-0.39068, 0.39024, 0.39146, 0.38988, 0.3891, 0.38966, 0.38784, 0.38422, 0.38366, 0.38648, 0.3878, 0.38568, 0.38546, 0.38418, 0.38372, 0.38302, 0.38606, 0.3839,
-0.3853, 0.38464, 0.38196, 0.38146, 0.38116, 0.37936, 0.38176, 0.37972, 0.37856, 
-//313
-0.0901, 0.09964, 0.11106, 0.12158, 0.1277, 0.13032, 0.13102, 0.12858, 0.12808, 0.12914, 0.12792, 0.12844, 0.12982, 0.12894, 0.12816, 0.1294, 0.13334, 0.1337,
-0.13544, 0.13446, 0.13294, 0.13212, 0.13424, 0.13856, 0.14918, 0.16504, 0.18838, 0.21604, 0.2392, 0.2551, 0.26354, 0.26998, 0.27384, 0.27828, 0.28378, 0.28744,
-0.28706, 0.29302, 0.29208, 0.29154, 0.29148, 0.29062, 0.29218, 0.29026, 0.28944, 0.28782, 0.288, 0.28678, 0.28688, 0.2857, 0.28462, 0.28432, 0.284, 0.28274,
-0.28312, 0.28238, 0.28116, 0.27772, 0.27752, 0.279, 0.28072, 0.27668, 0.27422, 0.27706, 0.27734, 0.27744, 0.27808, 0.27752, 0.2768, 0.27732, 0.27566, 0.27612,
-0.27944, 0.27732, 0.27596, 0.27528, 0.27574, 0.27404, 0.2751, 0.27492, 0.27314, 
-//314
-0.07058, 0.07236, 0.07754, 0.08134, 0.0811, 0.08134, 0.08088, 0.07968, 0.07924, 0.07918, 0.08114, 0.08074, 0.08044, 0.07926, 0.0786, 0.0791, 0.0801, 0.08188,
-0.08224, 0.08244, 0.08088, 0.08076, 0.0823, 0.0846, 0.09222, 0.10372, 0.12052, 0.1414, 0.15756, 0.16808, 0.17298, 0.17744, 0.18106, 0.1861, 0.1927, 0.19716,
-0.19734, 0.20404, 0.2043, 0.20368, 0.2024, 0.20094, 0.2015, 0.19918, 0.2006, 0.19904, 0.19758, 0.19728, 0.19728, 0.19494, 0.19468, 0.19524, 0.19382, 0.1929,
-0.19188, 0.19176, 0.19222, 0.19102, 0.19008, 0.1898, 0.18928, 0.18824, 0.18648, 0.1892, 0.18972, 0.18982, 0.19006, 0.1895, 0.18854, 0.1897, 0.19052, 0.18936,
-0.19184, 0.19044, 0.18948, 0.1894, 0.19128, 0.19026, 0.19106, 0.18964, 0.18938, 
-//315
-0.04886, 0.04794, 0.04842, 0.04604, 0.04708, 0.0485, 0.04994, 0.04826, 0.0482, 0.04812, 0.04698, 0.04764, 0.04968, 0.04942, 0.05088, 0.04938, 0.04954, 0.0489,
-0.04816, 0.0494, 0.0517, 0.05184, 0.05258, 0.05236, 0.0583, 0.06462, 0.07762, 0.0891, 0.0974, 0.10014, 0.10228, 0.10242, 0.10508, 0.1114, 0.11898, 0.12446,
-0.12482, 0.13056, 0.13016, 0.12988, 0.13042, 0.13096, 0.13076, 0.12882, 0.12748, 0.12802, 0.12738, 0.12688, 0.127, 0.12664, 0.12558, 0.12566, 0.1248, 0.12438,
-0.1245, 0.124, 0.12302, 0.12154, 0.12278, 0.1249, 0.12466, 0.12216, 0.1221, 0.12396, 0.12462, 0.12486, 0.12552, 0.12496, 0.12494, 0.12646, 0.12754, 0.1279,
-0.12878, 0.12806, 0.12892, 0.12786, 0.12794, 0.1293, 0.12992, 0.13, 0.13016, 
-//316
-0.12038, 0.13924, 0.17232, 0.20554, 0.23032, 0.2422, 0.24822, 0.24846, 0.24992, 0.25252, 0.25416, 0.25666, 0.26126, 0.26478, 0.27192, 0.27864, 0.28906, 0.29704,
-0.3068, 0.31624, 0.32588, 0.3368, 0.34696, 0.36198, 0.38206, 0.41558, 0.46458, 0.52676, 0.58708, 0.63818, 0.6797, 0.70652, 0.72236, 0.73206, 0.73752, 0.7398,
-0.74004, 0.74316, 0.74448, 0.74444, 0.74432, 0.746, 0.74768, 0.74688, 0.7457, 0.74718, 0.74772, 0.7485, 0.74806, 0.74764, 0.74886, 0.74744, 0.7479, 0.74998,
-0.74994, 0.75032, 0.74906, 0.74552, 0.74728, 0.7479, 0.74912, 0.74814, 0.74718, 0.74884, 0.74782, 0.7507, 0.7518, 0.75226, 0.75022, 0.7532, 0.75528, 0.7536,
-0.75644, 0.75356, 0.75568, 0.75522, 0.75588, 0.75666, 0.76004, 0.76216, 0.76758, 
-//317
-0.10858, 0.12814, 0.15478, 0.18146, 0.19906, 0.20942, 0.21326, 0.21308, 0.21366, 0.21492, 0.21668, 0.21706, 0.22172, 0.22534, 0.22908, 0.23346, 0.24034,
-0.24712, 0.25422, 0.26132, 0.26802, 0.27406, 0.28008, 0.28932, 0.30786, 0.33972, 0.38778, 0.44812, 0.50722, 0.55592, 0.59334, 0.61944, 0.63386, 0.6422, 0.6481,
-0.64962, 0.64794, 0.65266, 0.65168, 0.65202, 0.65172, 0.65066, 0.65184, 0.65218, 0.65046, 0.64922, 0.64858, 0.65066, 0.64922, 0.64906, 0.64812, 0.64822,
-0.64656, 0.6463, 0.64804, 0.64642, 0.64624, 0.64534, 0.64432, 0.64446, 0.64442, 0.64222, 0.64106, 0.64364, 0.64464, 0.64344, 0.64512, 0.6451, 0.64336, 0.64622,
-0.6487, 0.64434, 0.6465, 0.64584, 0.64792, 0.64414, 0.64536, 0.6429, 0.6478, 0.645, 0.64492, 
-//318
-0.1088, 0.12378, 0.14884, 0.1707, 0.18626, 0.19318, 0.19352, 0.19384, 0.19354, 0.19316, 0.19428, 0.19664, 0.19832, 0.20044, 0.204, 0.20766, 0.21134, 0.21574,
-0.21918, 0.22186, 0.22604, 0.2301, 0.23544, 0.243, 0.25712, 0.28428, 0.32882, 0.38454, 0.43974, 0.48684, 0.51902, 0.53818, 0.54862, 0.55624, 0.55996, 0.56096,
-0.55962, 0.56166, 0.56016, 0.56196, 0.56148, 0.55978, 0.56026, 0.55882, 0.55854, 0.5567, 0.55684, 0.55754, 0.55514, 0.55384, 0.55428, 0.5533, 0.55238, 0.55224,
-0.55138, 0.55, 0.54928, 0.5488, 0.54838, 0.55018, 0.54836, 0.54504, 0.54558, 0.54762, 0.54676, 0.54596, 0.54402, 0.54366, 0.54256, 0.54554, 0.5464, 0.54566,
-0.5468, 0.54316, 0.5433, 0.5428, 0.54196, 0.54138, 0.5414, 0.53948, 0.54054, 
-//319
-0.08636, 0.09522, 0.11204, 0.12554, 0.1339, 0.13664, 0.13656, 0.13372, 0.13288, 0.13288, 0.13428, 0.1345, 0.13674, 0.13624, 0.13638, 0.13658, 0.13884, 0.1386,
-0.13962, 0.14032, 0.14084, 0.14054, 0.1418, 0.14672, 0.15686, 0.17744, 0.21182, 0.25742, 0.3068, 0.35052, 0.38342, 0.40282, 0.41388, 0.41994, 0.4216, 0.42314,
-0.42166, 0.42334, 0.42248, 0.42168, 0.41998, 0.41948, 0.41868, 0.41612, 0.4141, 0.41004, 0.409, 0.4083, 0.4064, 0.40376, 0.4032, 0.40154, 0.39874, 0.39754,
-0.39806, 0.39626, 0.39588, 0.39306, 0.39408, 0.39452, 0.39428, 0.39258, 0.3933, 0.39306, 0.39378, 0.3939, 0.39402, 0.39228, 0.39036, 0.39116, 0.3883, 0.38926,
-0.391, 0.3911, 0.39102, 0.39202, 0.39336, 0.3933, 0.39562, 0.39332, 0.39374, 
-//320
-0.0695, 0.07312, 0.08058, 0.08356, 0.08482, 0.08408, 0.0837, 0.08372, 0.0854, 0.0838, 0.0835, 0.0837, 0.08422, 0.08338, 0.08548, 0.08628, 0.08672, 0.08592,
-0.08598, 0.08664, 0.08764, 0.08732, 0.08984, 0.09492, 0.10122, 0.11534, 0.14144, 0.17906, 0.21598, 0.24166, 0.25922, 0.27068, 0.27696, 0.28424, 0.29282,
-0.29862, 0.29854, 0.30454, 0.3044, 0.30252, 0.30302, 0.30136, 0.3012, 0.30022, 0.3002, 0.29996, 0.30106, 0.29788, 0.2958, 0.2962, 0.29678, 0.29482, 0.2954,
-0.29496, 0.29374, 0.2913, 0.292, 0.29144, 0.29136, 0.29316, 0.29198, 0.2877, 0.28668, 0.29008, 0.29178, 0.29048, 0.29006, 0.28924, 0.28788, 0.28908, 0.29002,
-0.29098, 0.293, 0.29132, 0.29036, 0.2874, 0.28948, 0.29086, 0.29192, 0.29056, 0.2896, 
-//321
-0.0507, 0.04932, 0.04714, 0.04736, 0.04758, 0.0469, 0.04934, 0.05002, 0.05228, 0.05076, 0.04812, 0.0485, 0.05, 0.04998, 0.05198, 0.0502, 0.0504, 0.04916,
-0.05054, 0.05152, 0.05192, 0.05332, 0.05348, 0.05578, 0.06254, 0.07434, 0.09262, 0.11846, 0.14294, 0.16008, 0.17104, 0.17772, 0.18468, 0.19066, 0.19894,
-0.20528, 0.20418, 0.2135, 0.21192, 0.2115, 0.21158, 0.21152, 0.2116, 0.20966, 0.20958, 0.20662, 0.20584, 0.20618, 0.20604, 0.20384, 0.20276, 0.20234, 0.20458,
-0.20426, 0.20264, 0.20192, 0.20062, 0.19878, 0.20092, 0.2028, 0.20278, 0.20052, 0.19886, 0.20154, 0.20306, 0.20382, 0.20424, 0.20284, 0.20172, 0.20368, 0.20494,
-0.20474, 0.20602, 0.20686, 0.20688, 0.20576, 0.20548, 0.20696, 0.20574, 0.2078, 0.20668, 
-//322
-0.0959, 0.11186, 0.13396, 0.15338, 0.1667, 0.173, 0.17496, 0.176, 0.17714, 0.17926, 0.18046, 0.1825, 0.18598, 0.19118, 0.1979, 0.20742, 0.21926, 0.22964,
-0.2425, 0.2572, 0.2724, 0.29166, 0.3099, 0.33408, 0.36168, 0.39736, 0.44736, 0.50942, 0.57242, 0.62572, 0.66918, 0.70012, 0.71878, 0.73058, 0.73904, 0.74536,
-0.74644, 0.75306, 0.75422, 0.7554, 0.7562, 0.75816, 0.76088, 0.76206, 0.76334, 0.76112, 0.76156, 0.76066, 0.76228, 0.76264, 0.76558, 0.76686, 0.76524, 0.7658,
-0.76634, 0.76644, 0.76916, 0.76764, 0.76678, 0.76808, 0.76846, 0.76782, 0.76598, 0.77046, 0.77104, 0.77248, 0.772, 0.77358, 0.7727, 0.77534, 0.77752, 0.77774,
-0.77986, 0.77918, 0.78028, 0.7783, 0.78032, 0.78288, 0.78372, 0.7801, 0.78098, 
-//323
-0.08828, 0.09806, 0.11466, 0.12616, 0.13556, 0.1399, 0.14002, 0.14008, 0.1392, 0.13896, 0.14114, 0.1461, 0.15124, 0.15452, 0.15926, 0.16334, 0.17034, 0.17948,
-0.18878, 0.20082, 0.21134, 0.21946, 0.22908, 0.24344, 0.2654, 0.29814, 0.34954, 0.41136, 0.47742, 0.53722, 0.58534, 0.61878, 0.64102, 0.65386, 0.66156, 0.66682,
-0.66536, 0.67194, 0.67188, 0.673, 0.67162, 0.67248, 0.67384, 0.67274, 0.67284, 0.67276, 0.67254, 0.67296, 0.67276, 0.67432, 0.67416, 0.67274, 0.6732, 0.67426,
-0.67318, 0.67518, 0.6769, 0.67414, 0.67204, 0.67264, 0.67164, 0.67206, 0.6693, 0.67514, 0.67378, 0.67622, 0.67676, 0.67594, 0.6754, 0.6803, 0.68218, 0.68038,
-0.68242, 0.68082, 0.68218, 0.6825, 0.68364, 0.68662, 0.68488, 0.6824, 0.68534, 
-//324
-0.09072, 0.09866, 0.1091, 0.11872, 0.12306, 0.12334, 0.1266, 0.12764, 0.12624, 0.12672, 0.12836, 0.12854, 0.1315, 0.13554, 0.1406, 0.14532, 0.15012, 0.1551,
-0.16096, 0.16982, 0.17714, 0.18536, 0.19176, 0.20222, 0.21902, 0.24824, 0.29382, 0.35434, 0.41538, 0.47018, 0.51078, 0.5412, 0.56018, 0.56954, 0.57418, 0.57558,
-0.57348, 0.5815, 0.58046, 0.5799, 0.57812, 0.57908, 0.5814, 0.58006, 0.58106, 0.57996, 0.5806, 0.57634, 0.5767, 0.57786, 0.5768, 0.57734, 0.57744, 0.57476,
-0.5733, 0.57376, 0.57588, 0.57334, 0.57316, 0.5757, 0.57176, 0.56896, 0.56874, 0.57346, 0.57246, 0.57234, 0.57312, 0.5724, 0.5716, 0.5743, 0.57462, 0.57144,
-0.57348, 0.57322, 0.574, 0.57356, 0.57564, 0.573, 0.57426, 0.57132, 0.57286, 
-//325
-0.0691, 0.07488, 0.08092, 0.08464, 0.08672, 0.08932, 0.08826, 0.0868, 0.08738, 0.08628, 0.08776, 0.08854, 0.08906, 0.08788, 0.0875, 0.0881, 0.08782, 0.09004,
-0.0925, 0.09316, 0.09452, 0.0933, 0.09352, 0.09716, 0.1085, 0.1268, 0.1597, 0.20722, 0.26144, 0.31618, 0.36434, 0.40106, 0.42264, 0.43522, 0.44372, 0.44414,
-0.43848, 0.44518, 0.44826, 0.44546, 0.44486, 0.44082, 0.44062, 0.43606, 0.43342, 0.43068, 0.4278, 0.42464, 0.4203, 0.41838, 0.41442, 0.4131, 0.41368, 0.41092,
-0.41016, 0.40928, 0.40658, 0.40386, 0.40512, 0.40924, 0.411, 0.40802, 0.40912, 0.41294, 0.4138, 0.41486, 0.41486, 0.41186, 0.41254, 0.41394, 0.41228, 0.4125,
-0.41594, 0.41544, 0.4185, 0.42144, 0.42496, 0.42566, 0.42712, 0.4261, 0.4273, 
-//326
-0.04658, 0.04746, 0.04746, 0.0479, 0.04746, 0.05104, 0.05102, 0.04958, 0.04876, 0.04824, 0.04918, 0.05002, 0.052, 0.05206, 0.05042, 0.04978, 0.0499, 0.05078,
-0.05188, 0.05226, 0.0549, 0.05388, 0.05458, 0.05666, 0.06466, 0.07784, 0.10222, 0.13958, 0.18232, 0.2236, 0.25826, 0.2817, 0.29556, 0.3036, 0.31136, 0.31498,
-0.31436, 0.321, 0.3197, 0.31878, 0.31876, 0.31816, 0.31724, 0.31302, 0.31076, 0.30848, 0.30792, 0.30748, 0.30616, 0.30524, 0.30542, 0.3029, 0.30202, 0.30158,
-0.30006, 0.29984, 0.29938, 0.2972, 0.29848, 0.2995, 0.30052, 0.29794, 0.29892, 0.30218, 0.30074, 0.30004, 0.3011, 0.30224, 0.30108, 0.30286, 0.30364, 0.30326,
-0.30166, 0.30356, 0.30594, 0.30792, 0.30894, 0.30982, 0.31066, 0.30946, 0.31232, 
-//327
-0.06386, 0.06906, 0.07612, 0.07906, 0.08078, 0.0817, 0.0853, 0.08396, 0.08578, 0.08544, 0.08722, 0.08874, 0.09398, 0.09692, 0.1033, 0.10822, 0.1136, 0.12192,
-0.13276, 0.14462, 0.1581, 0.17306, 0.18756, 0.20328, 0.22756, 0.26438, 0.31622, 0.3811, 0.45068, 0.5136, 0.56346, 0.60116, 0.62584, 0.64012, 0.65006, 0.65608,
-0.65566, 0.66392, 0.66692, 0.66768, 0.66916, 0.66972, 0.67204, 0.6704, 0.6721, 0.67154, 0.673, 0.674, 0.67218, 0.67344, 0.67522, 0.677, 0.67812, 0.6783,
-0.67852, 0.67844, 0.68118, 0.68054, 0.67974, 0.6817, 0.67996, 0.67866, 0.6817, 0.6859, 0.68488, 0.68686, 0.68922, 0.68692, 0.68734, 0.69282, 0.69562, 0.69582,
-0.69756, 0.69748, 0.69788, 0.69838, 0.69888, 0.69966, 0.70212, 0.70062, 0.7016, 
-//328
-0.0602, 0.06144, 0.0644, 0.06672, 0.0687, 0.07004, 0.07154, 0.07188, 0.07112, 0.07136, 0.07388, 0.07596, 0.0788, 0.08058, 0.08476, 0.08954, 0.09454, 0.10178,
-0.10944, 0.11922, 0.12858, 0.13814, 0.1518, 0.16808, 0.19038, 0.22144, 0.26694, 0.3258, 0.38974, 0.451, 0.50042, 0.53488, 0.55786, 0.57052, 0.57876, 0.58306,
-0.58358, 0.58982, 0.59014, 0.5908, 0.59394, 0.59346, 0.5952, 0.59562, 0.59894, 0.59616, 0.59414, 0.59632, 0.59746, 0.59802, 0.5997, 0.5993, 0.59956, 0.59944,
-0.60158, 0.6012, 0.60054, 0.5993, 0.60054, 0.6025, 0.6024, 0.6004, 0.59888, 0.6024, 0.60236, 0.60438, 0.60582, 0.60628, 0.60452, 0.60964, 0.61244, 0.61042,
-0.61474, 0.61222, 0.6158, 0.61528, 0.6181, 0.6169, 0.61806, 0.61922, 0.62016, 
-//329
-0.04814, 0.05168, 0.05236, 0.05166, 0.05284, 0.05454, 0.05594, 0.05482, 0.05512, 0.05432, 0.05476, 0.05402, 0.05444, 0.05494, 0.05532, 0.05434, 0.05416,
-0.05632, 0.05774, 0.05796, 0.05912, 0.0588, 0.06124, 0.0634, 0.072, 0.08752, 0.11486, 0.15672, 0.21076, 0.27268, 0.3333, 0.38448, 0.4231, 0.44826, 0.46338,
-0.46874, 0.46668, 0.47664, 0.47622, 0.47182, 0.46724, 0.46266, 0.45864, 0.44936, 0.44088, 0.43124, 0.42272, 0.41312, 0.40812, 0.40444, 0.40072, 0.39692, 0.3929,
-0.38888, 0.38602, 0.3839, 0.38436, 0.38258, 0.38412, 0.3891, 0.39316, 0.39562, 0.39818, 0.40522, 0.40902, 0.41138, 0.41008, 0.41104, 0.40974, 0.40678, 0.40796,
-0.40978, 0.41152, 0.41612, 0.42354, 0.42942, 0.43144, 0.43478, 0.43792, 0.43726, 0.4428, 
-//330
-0.0529, 0.05174, 0.05368, 0.05252, 0.05394, 0.05228, 0.05366, 0.05392, 0.05614, 0.05524, 0.05708, 0.05724, 0.06038, 0.0653, 0.0683, 0.0704, 0.07964, 0.08448,
-0.09586, 0.10886, 0.12316, 0.13964, 0.16102, 0.19088, 0.23016, 0.28212, 0.34634, 0.4165, 0.48886, 0.54768, 0.59656, 0.63018, 0.6528, 0.66494, 0.675, 0.68474,
-0.6862, 0.69608, 0.70176, 0.70668, 0.70842, 0.71358, 0.71548, 0.71758, 0.71794, 0.72112, 0.7227, 0.72328, 0.72766, 0.73068, 0.73314, 0.7334, 0.7367, 0.73926,
-0.73956, 0.74198, 0.74642, 0.74454, 0.74276, 0.74562, 0.74706, 0.74638, 0.74876, 0.7525, 0.75502, 0.75544, 0.75866, 0.76026, 0.761, 0.76404, 0.76568, 0.76698,
-0.77292, 0.7707, 0.77328, 0.77494, 0.773, 0.7754, 0.77756, 0.77742, 0.77764, 
-//331
-0.044, 0.04124, 0.03936, 0.03886, 0.04364, 0.04398, 0.04396, 0.04402, 0.0421, 0.04384, 0.04606, 0.04718, 0.05058, 0.05368, 0.05562, 0.05818, 0.06358, 0.07196,
-0.08042, 0.09244, 0.10772, 0.12402, 0.14734, 0.17904, 0.22244, 0.27032, 0.32582, 0.37796, 0.42012, 0.45212, 0.47536, 0.49214, 0.50418, 0.5135, 0.52448, 0.53416,
-0.54006, 0.55528, 0.56488, 0.57316, 0.58136, 0.58844, 0.59652, 0.59946, 0.60144, 0.60422, 0.6076, 0.6096, 0.61186, 0.61596, 0.61752, 0.62064, 0.62272, 0.62482,
-0.62718, 0.62904, 0.63194, 0.63212, 0.63254, 0.63654, 0.63644, 0.63402, 0.636, 0.64186, 0.64276, 0.64496, 0.64676, 0.6503, 0.6501, 0.65574, 0.65998, 0.66128,
-0.66314, 0.66114, 0.66264, 0.66532, 0.66562, 0.66618, 0.67086, 0.67256, 0.67306, 
-//332
-0.04208, 0.03958, 0.0394, 0.03914, 0.03964, 0.03982, 0.04074, 0.04076, 0.04024, 0.03968, 0.04114, 0.04084, 0.04202, 0.0414, 0.04094, 0.03952, 0.04244, 0.04354,
-0.04434, 0.04456, 0.0448, 0.04256, 0.04478, 0.04758, 0.05728, 0.071, 0.09726, 0.13888, 0.197, 0.26282, 0.33182, 0.39328, 0.4422, 0.4757, 0.50088, 0.5135,
-0.51598, 0.52278, 0.52008, 0.51294, 0.5035, 0.49426, 0.4816, 0.46568, 0.45118, 0.43138, 0.41738, 0.40352, 0.39284, 0.38724, 0.38062, 0.37482, 0.36984, 0.36402,
-0.35956, 0.35638, 0.35738, 0.35522, 0.35942, 0.36454, 0.37048, 0.37514, 0.382, 0.3933, 0.40088, 0.4044, 0.40858, 0.41016, 0.40432, 0.40368, 0.40118, 0.40544,
-0.4162, 0.42448, 0.43576, 0.44464, 0.45102, 0.45472, 0.46316, 0.47172, 0.48378, 
-//333
-0.13736, 0.16958, 0.22286, 0.2907, 0.36492, 0.42152, 0.4524, 0.45932, 0.46278, 0.46442, 0.46722, 0.46894, 0.4719, 0.47266, 0.47528, 0.48394, 0.4996, 0.52092,
-0.54854, 0.57862, 0.60562, 0.62512, 0.6402, 0.65138, 0.6621, 0.66718, 0.67132, 0.67168, 0.6693, 0.66356, 0.65986, 0.66048, 0.66366, 0.67198, 0.68864, 0.70104,
-0.70398, 0.71476, 0.71786, 0.7196, 0.71682, 0.71662, 0.71848, 0.71682, 0.71744, 0.71978, 0.71984, 0.71668, 0.71414, 0.71452, 0.7142, 0.71234, 0.71354, 0.71494,
-0.71448, 0.70958, 0.71032, 0.70964, 0.70652, 0.70906, 0.7063, 0.70442, 0.69902, 0.704, 0.70404, 0.70338, 0.7031, 0.7042, 0.70418, 0.70546, 0.70382, 0.70254,
-0.7063, 0.70236, 0.70294, 0.70372, 0.70376, 0.70464, 0.70482, 0.70372, 0.70574, 
-//334
-0.12498, 0.15172, 0.19948, 0.26206, 0.32522, 0.36846, 0.38796, 0.39316, 0.3957, 0.3956, 0.3973, 0.39794, 0.40046, 0.40338, 0.40466, 0.4097, 0.42194, 0.43828,
-0.46274, 0.4897, 0.51356, 0.53144, 0.54268, 0.55172, 0.55704, 0.56234, 0.56686, 0.56536, 0.5629, 0.5576, 0.55236, 0.55154, 0.5556, 0.56834, 0.58412, 0.59578,
-0.60186, 0.60988, 0.61118, 0.6117, 0.6097, 0.61116, 0.61162, 0.60968, 0.60826, 0.60732, 0.60666, 0.60536, 0.60572, 0.60616, 0.60644, 0.60362, 0.60126, 0.60086,
-0.60078, 0.59874, 0.60046, 0.59772, 0.59782, 0.59614, 0.5971, 0.5926, 0.5909, 0.59354, 0.59376, 0.59188, 0.59256, 0.59416, 0.59382, 0.59268, 0.59226, 0.59442,
-0.59626, 0.59344, 0.59138, 0.59124, 0.59134, 0.59128, 0.5929, 0.59214, 0.59082, 
-//335
-0.12984, 0.15638, 0.20048, 0.26048, 0.3186, 0.35816, 0.37378, 0.3771, 0.37706, 0.37498, 0.37496, 0.37642, 0.37714, 0.37766, 0.37802, 0.38026, 0.3817, 0.38298,
-0.38336, 0.38214, 0.38136, 0.38138, 0.38228, 0.3875, 0.39976, 0.42118, 0.45054, 0.48122, 0.50474, 0.51916, 0.53022, 0.5354, 0.53718, 0.5364, 0.5366, 0.534,
-0.53138, 0.53588, 0.53366, 0.5305, 0.5309, 0.53016, 0.53004, 0.52868, 0.52658, 0.52264, 0.52088, 0.51894, 0.5173, 0.51676, 0.51654, 0.51406, 0.51314, 0.51158,
-0.51036, 0.50866, 0.50892, 0.50724, 0.50508, 0.50764, 0.50842, 0.50548, 0.50454, 0.5067, 0.50614, 0.50412, 0.50506, 0.50494, 0.50114, 0.49996, 0.49988, 0.49984,
-0.50236, 0.50202, 0.5045, 0.50146, 0.50156, 0.50118, 0.50408, 0.50556, 0.5034, 
-//336
-0.10876, 0.1336, 0.1682, 0.20704, 0.23612, 0.25144, 0.25582, 0.2586, 0.2587, 0.25856, 0.25976, 0.2586, 0.25738, 0.25712, 0.26008, 0.26062, 0.26254, 0.26374,
-0.2627, 0.26184, 0.26112, 0.26034, 0.26338, 0.26534, 0.27612, 0.29212, 0.31328, 0.33696, 0.35582, 0.3659, 0.37088, 0.3745, 0.37604, 0.3741, 0.37536, 0.37456,
-0.36932, 0.3737, 0.37378, 0.37234, 0.36882, 0.36836, 0.37052, 0.36854, 0.36902, 0.3679, 0.36656, 0.36394, 0.3631, 0.36362, 0.36272, 0.36026, 0.3608, 0.36014,
-0.35836, 0.35728, 0.35948, 0.35672, 0.35496, 0.35594, 0.35392, 0.35148, 0.34884, 0.35228, 0.35284, 0.35252, 0.352, 0.3531, 0.35002, 0.34822, 0.35058, 0.35166,
-0.35194, 0.34964, 0.3496, 0.34902, 0.3474, 0.34604, 0.34604, 0.34668, 0.345, 
-//337
-0.10628, 0.11662, 0.1356, 0.14996, 0.16162, 0.16706, 0.16638, 0.16556, 0.16356, 0.16368, 0.16556, 0.16686, 0.1689, 0.16826, 0.16944, 0.17248, 0.17976, 0.18992,
-0.203, 0.21884, 0.2286, 0.23356, 0.24128, 0.24586, 0.24984, 0.25072, 0.25156, 0.2481, 0.2452, 0.24408, 0.24172, 0.2407, 0.24356, 0.24932, 0.25898, 0.26672,
-0.26734, 0.27838, 0.2796, 0.27894, 0.2791, 0.2772, 0.2765, 0.27744, 0.27908, 0.27644, 0.27462, 0.27318, 0.27088, 0.27154, 0.2714, 0.27242, 0.27172, 0.27136,
-0.26812, 0.26556, 0.26712, 0.26346, 0.26522, 0.2663, 0.26518, 0.26094, 0.25944, 0.26438, 0.26406, 0.26282, 0.26256, 0.26064, 0.261, 0.26254, 0.26228, 0.2618,
-0.25964, 0.25996, 0.2614, 0.25954, 0.2607, 0.25924, 0.25856, 0.25682, 0.25842, 
-//338
-0.08986, 0.09922, 0.1117, 0.12228, 0.12634, 0.12608, 0.12556, 0.12328, 0.1211, 0.12468, 0.12476, 0.1252, 0.1227, 0.1206, 0.12178, 0.12294, 0.12454, 0.12402,
-0.12602, 0.12476, 0.12296, 0.1236, 0.12328, 0.12682, 0.13176, 0.14112, 0.15428, 0.16696, 0.17682, 0.18106, 0.1821, 0.1821, 0.18396, 0.18552, 0.18844, 0.19018,
-0.18616, 0.1886, 0.18852, 0.18836, 0.18976, 0.18808, 0.18706, 0.18594, 0.18496, 0.1823, 0.18442, 0.1856, 0.18506, 0.18398, 0.18248, 0.18092, 0.18122, 0.18208,
-0.1826, 0.18246, 0.18112, 0.17674, 0.17744, 0.1792, 0.17944, 0.17636, 0.1754, 0.17628, 0.1768, 0.17778, 0.1782, 0.18002, 0.17898, 0.1784, 0.1771, 0.1781,
-0.17914, 0.17882, 0.17968, 0.17956, 0.17878, 0.18006, 0.17908, 0.17774, 0.17654, 
-//339
-0.05874, 0.063, 0.06648, 0.06892, 0.06902, 0.06936, 0.06884, 0.06944, 0.07098, 0.07074, 0.07012, 0.06838, 0.06746, 0.0677, 0.07052, 0.06956, 0.0725, 0.0713,
-0.06986, 0.06976, 0.07066, 0.07176, 0.07184, 0.07332, 0.07658, 0.08348, 0.09322, 0.10106, 0.10728, 0.11206, 0.11176, 0.1128, 0.11356, 0.11586, 0.1165, 0.11598,
-0.1133, 0.11854, 0.11622, 0.11622, 0.11456, 0.1159, 0.11512, 0.11394, 0.11348, 0.11262, 0.11222, 0.11236, 0.1132, 0.113, 0.11184, 0.11048, 0.1106, 0.1122,
-0.11028, 0.11044, 0.11062, 0.1069, 0.1073, 0.10786, 0.11076, 0.10762, 0.10638, 0.1095, 0.11066, 0.10986, 0.11014, 0.10866, 0.10974, 0.11068, 0.1121, 0.11124,
-0.11196, 0.11138, 0.11268, 0.11198, 0.11308, 0.11172, 0.1115, 0.1116, 0.11208, 
-//340
-0.04158, 0.03918, 0.03776, 0.0398, 0.04096, 0.0407, 0.03878, 0.0388, 0.03868, 0.03962, 0.03948, 0.04032, 0.03874, 0.03922, 0.04044, 0.03772, 0.0402, 0.04062,
-0.04124, 0.03992, 0.03956, 0.04012, 0.04156, 0.04124, 0.04362, 0.04632, 0.04954, 0.05446, 0.05916, 0.06072, 0.06038, 0.0598, 0.0596, 0.05928, 0.0629, 0.06504,
-0.05934, 0.0641, 0.06426, 0.06492, 0.06594, 0.06414, 0.06112, 0.0615, 0.06208, 0.06344, 0.0629, 0.06092, 0.06138, 0.06042, 0.06196, 0.06194, 0.06244, 0.06064,
-0.0602, 0.06014, 0.06048, 0.05814, 0.061, 0.0631, 0.06144, 0.0579, 0.06126, 0.06486, 0.06384, 0.06178, 0.06276, 0.06492, 0.06648, 0.06768, 0.0688, 0.06808,
-0.06796, 0.0678, 0.06918, 0.07014, 0.07192, 0.07122, 0.07132, 0.0716, 0.07098, 
-//341
-0.12782, 0.15424, 0.1955, 0.24536, 0.29104, 0.31634, 0.32828, 0.3293, 0.32988, 0.3305, 0.33332, 0.33526, 0.33926, 0.33998, 0.34268, 0.35044, 0.36528, 0.38738,
-0.42152, 0.46434, 0.5092, 0.54952, 0.58412, 0.61176, 0.63124, 0.64686, 0.65834, 0.6616, 0.6597, 0.65468, 0.65122, 0.65074, 0.65724, 0.67074, 0.69048, 0.71016,
-0.7214, 0.73024, 0.73358, 0.73562, 0.73752, 0.73782, 0.73828, 0.73968, 0.74002, 0.73898, 0.73836, 0.73806, 0.7357, 0.73832, 0.73674, 0.73478, 0.73354, 0.73514,
-0.73538, 0.73382, 0.73622, 0.73248, 0.7291, 0.73146, 0.73042, 0.7309, 0.72896, 0.73124, 0.73224, 0.72998, 0.73128, 0.73206, 0.73192, 0.73172, 0.73496, 0.734,
-0.73464, 0.73286, 0.73548, 0.73678, 0.73682, 0.73586, 0.73146, 0.73272, 0.73942, 
-//342
-0.11354, 0.13736, 0.17246, 0.21594, 0.25142, 0.27264, 0.28126, 0.2831, 0.28202, 0.28262, 0.2837, 0.28402, 0.28746, 0.29116, 0.29414, 0.29896, 0.31174, 0.33064,
-0.3598, 0.39802, 0.43958, 0.4784, 0.51004, 0.53428, 0.5506, 0.56292, 0.5729, 0.57372, 0.57014, 0.5642, 0.5613, 0.56016, 0.56816, 0.58348, 0.60236, 0.6187,
-0.62822, 0.6357, 0.6373, 0.64032, 0.63868, 0.64046, 0.64262, 0.64176, 0.63974, 0.63848, 0.63806, 0.63824, 0.63738, 0.63822, 0.63782, 0.63516, 0.63356, 0.63362,
-0.63388, 0.63294, 0.63552, 0.63256, 0.6323, 0.63194, 0.62748, 0.6257, 0.62496, 0.6284, 0.6298, 0.62736, 0.62692, 0.62756, 0.62588, 0.62984, 0.63178, 0.63224,
-0.6333, 0.62662, 0.62792, 0.62704, 0.62982, 0.62948, 0.62854, 0.62302, 0.62964, 
-//343
-0.12334, 0.14522, 0.18112, 0.2208, 0.2531, 0.27114, 0.27588, 0.2761, 0.27522, 0.2741, 0.27392, 0.27514, 0.27688, 0.27598, 0.27806, 0.27864, 0.28256, 0.28278,
-0.28442, 0.2828, 0.28128, 0.28232, 0.28542, 0.2913, 0.30604, 0.33208, 0.3737, 0.42282, 0.47172, 0.50758, 0.53166, 0.5457, 0.55312, 0.55426, 0.55494, 0.55624,
-0.55114, 0.55348, 0.551, 0.54942, 0.54442, 0.5396, 0.53726, 0.53104, 0.52794, 0.52198, 0.5155, 0.50894, 0.5035, 0.50136, 0.49824, 0.49482, 0.49304, 0.48986,
-0.4856, 0.48236, 0.48368, 0.48004, 0.48322, 0.48716, 0.48616, 0.48328, 0.48422, 0.4906, 0.49296, 0.4942, 0.49396, 0.4915, 0.48962, 0.48748, 0.48774, 0.48668,
-0.49038, 0.49262, 0.49476, 0.49614, 0.495, 0.499, 0.50102, 0.50108, 0.50522, 
-//344
-0.10146, 0.1159, 0.14066, 0.16118, 0.17776, 0.18364, 0.1852, 0.18306, 0.18318, 0.18292, 0.18348, 0.18346, 0.18414, 0.18438, 0.18532, 0.1865, 0.18892, 0.18932,
-0.18942, 0.1895, 0.19124, 0.19234, 0.1924, 0.1965, 0.2071, 0.22732, 0.26016, 0.30378, 0.3411, 0.36732, 0.38476, 0.39328, 0.39616, 0.3974, 0.4005, 0.39926,
-0.3942, 0.3987, 0.39804, 0.39436, 0.39248, 0.38926, 0.38892, 0.3861, 0.38244, 0.37722, 0.37398, 0.3718, 0.36942, 0.3674, 0.36808, 0.36444, 0.3591, 0.35764,
-0.35658, 0.35488, 0.35468, 0.3546, 0.35476, 0.3545, 0.35408, 0.35194, 0.35384, 0.35586, 0.35642, 0.35502, 0.35484, 0.3552, 0.35298, 0.35234, 0.35138, 0.3513,
-0.35298, 0.3533, 0.35512, 0.35556, 0.35512, 0.35744, 0.36042, 0.35976, 0.3631, 
-//345
-0.08442, 0.08928, 0.09694, 0.10024, 0.10206, 0.10132, 0.10056, 0.10186, 0.10308, 0.10612, 0.1082, 0.10954, 0.1111, 0.113, 0.11828, 0.12476, 0.13352, 0.1409,
-0.14962, 0.15942, 0.17136, 0.18224, 0.19462, 0.2093, 0.22054, 0.2276, 0.23466, 0.23798, 0.24036, 0.24036, 0.24164, 0.24292, 0.24396, 0.248, 0.2542, 0.25764,
-0.26048, 0.27258, 0.27776, 0.2812, 0.2815, 0.28128, 0.28186, 0.2827, 0.28464, 0.28282, 0.2826, 0.281, 0.27908, 0.27738, 0.27744, 0.27734, 0.27776, 0.2755,
-0.27488, 0.27402, 0.27426, 0.27178, 0.27204, 0.27246, 0.2709, 0.26958, 0.26696, 0.27034, 0.27106, 0.27062, 0.27096, 0.27046, 0.2688, 0.2697, 0.27, 0.26886,
-0.27004, 0.26936, 0.26932, 0.26864, 0.26906, 0.26722, 0.26952, 0.2667, 0.26716, 
-//346
-0.07306, 0.07194, 0.0738, 0.07454, 0.0775, 0.07812, 0.07892, 0.07724, 0.076, 0.07512, 0.075, 0.07544, 0.07702, 0.07716, 0.07626, 0.07608, 0.07822, 0.07798,
-0.07966, 0.07914, 0.07782, 0.07782, 0.08114, 0.08442, 0.09212, 0.10342, 0.12166, 0.1428, 0.16372, 0.1792, 0.18844, 0.19368, 0.1948, 0.19484, 0.19278, 0.19182,
-0.18954, 0.1922, 0.19054, 0.18908, 0.18902, 0.18836, 0.18778, 0.1874, 0.1864, 0.18462, 0.1841, 0.18346, 0.18396, 0.18224, 0.18058, 0.18024, 0.18008, 0.18056,
-0.1794, 0.17814, 0.17748, 0.17512, 0.17632, 0.17886, 0.17856, 0.17618, 0.17458, 0.17756, 0.17834, 0.17832, 0.17732, 0.17588, 0.17534, 0.17576, 0.17784, 0.17808,
-0.18014, 0.17822, 0.17822, 0.17946, 0.17906, 0.1808, 0.18094, 0.1788, 0.17886, 
-//347
-0.04576, 0.04434, 0.04384, 0.04334, 0.0443, 0.04636, 0.04854, 0.0469, 0.0459, 0.04408, 0.04512, 0.046, 0.04682, 0.0484, 0.04852, 0.0451, 0.0455, 0.04608,
-0.04696, 0.04896, 0.05056, 0.05082, 0.05186, 0.05242, 0.0579, 0.06544, 0.0786, 0.09396, 0.10448, 0.11232, 0.11786, 0.11812, 0.11836, 0.11868, 0.11952, 0.11748,
-0.11352, 0.1201, 0.12074, 0.1203, 0.1198, 0.1226, 0.1221, 0.121, 0.11964, 0.11864, 0.11804, 0.1185, 0.11812, 0.11914, 0.11892, 0.1163, 0.11624, 0.11602,
-0.11734, 0.11576, 0.1151, 0.11292, 0.11496, 0.11596, 0.11624, 0.11434, 0.11518, 0.11742, 0.11638, 0.1147, 0.11554, 0.11698, 0.1175, 0.11858, 0.11944, 0.11918,
-0.11806, 0.118, 0.11864, 0.11934, 0.12068, 0.1204, 0.12112, 0.12142, 0.12092, 
-//348
-0.11402, 0.13078, 0.15996, 0.18964, 0.21102, 0.22146, 0.2244, 0.2245, 0.22504, 0.22684, 0.22914, 0.23116, 0.23314, 0.23398, 0.23846, 0.24444, 0.259, 0.28082,
-0.31248, 0.3547, 0.40382, 0.45474, 0.50254, 0.54534, 0.57776, 0.6017, 0.621, 0.63004, 0.63194, 0.63198, 0.6312, 0.63286, 0.63674, 0.65144, 0.67046, 0.68666,
-0.6967, 0.70986, 0.71394, 0.71538, 0.71384, 0.71468, 0.71618, 0.71682, 0.71846, 0.71744, 0.71858, 0.71878, 0.71878, 0.71776, 0.71886, 0.71952, 0.71814, 0.71788,
-0.72098, 0.71942, 0.72054, 0.71832, 0.71642, 0.717, 0.71828, 0.71928, 0.7165, 0.71932, 0.72074, 0.7228, 0.72304, 0.72168, 0.72294, 0.72512, 0.72404, 0.72606,
-0.72962, 0.72818, 0.72838, 0.73274, 0.72952, 0.73042, 0.73116, 0.72956, 0.73366, 
-//349
-0.10482, 0.12136, 0.14482, 0.1669, 0.18026, 0.18802, 0.18946, 0.18806, 0.18996, 0.19152, 0.19276, 0.19328, 0.19514, 0.19688, 0.19886, 0.2061, 0.21858, 0.2367,
-0.26408, 0.30404, 0.35114, 0.39962, 0.44434, 0.48204, 0.51126, 0.53256, 0.5463, 0.55244, 0.55098, 0.54528, 0.54254, 0.54374, 0.55116, 0.56888, 0.59444, 0.61564,
-0.6288, 0.6397, 0.6438, 0.64826, 0.64846, 0.64782, 0.64856, 0.64968, 0.64906, 0.64912, 0.64944, 0.64896, 0.64618, 0.64634, 0.64832, 0.64756, 0.6467, 0.6495,
-0.64758, 0.64562, 0.64592, 0.64434, 0.64504, 0.64738, 0.64562, 0.64282, 0.64254, 0.64326, 0.64628, 0.64618, 0.64688, 0.64826, 0.64808, 0.64932, 0.6531, 0.65402,
-0.65472, 0.65402, 0.6534, 0.6518, 0.65284, 0.65632, 0.65468, 0.65298, 0.65672, 
-//350
-0.11448, 0.13338, 0.15744, 0.17858, 0.19286, 0.19946, 0.1997, 0.19814, 0.19612, 0.19522, 0.19426, 0.194, 0.19574, 0.19708, 0.19744, 0.1969, 0.1989, 0.19948,
-0.19926, 0.19994, 0.20216, 0.2015, 0.2011, 0.20564, 0.21716, 0.24326, 0.28932, 0.35002, 0.41612, 0.47474, 0.52134, 0.55554, 0.57604, 0.58806, 0.59412, 0.59208,
-0.58706, 0.58616, 0.58446, 0.57848, 0.56994, 0.56094, 0.54964, 0.5368, 0.5225, 0.50912, 0.49732, 0.4863, 0.47856, 0.47194, 0.4669, 0.46262, 0.45584, 0.45102,
-0.4473, 0.44158, 0.4414, 0.44, 0.44242, 0.44482, 0.4473, 0.44896, 0.4531, 0.46146, 0.46582, 0.46926, 0.4708, 0.4693, 0.46632, 0.46758, 0.46738, 0.46938,
-0.47446, 0.4773, 0.48412, 0.4923, 0.49584, 0.5022, 0.50868, 0.51172, 0.51358, 
-//351
-0.08898, 0.09784, 0.10974, 0.12112, 0.12588, 0.1274, 0.12774, 0.1277, 0.12692, 0.12544, 0.12624, 0.12556, 0.12796, 0.12924, 0.13006, 0.12998, 0.13046, 0.13208,
-0.1328, 0.13328, 0.13598, 0.13588, 0.13604, 0.13888, 0.14906, 0.1712, 0.20754, 0.25638, 0.30814, 0.35266, 0.38696, 0.40672, 0.4208, 0.42734, 0.43172, 0.4293,
-0.42656, 0.42616, 0.42298, 0.4206, 0.41862, 0.41226, 0.4048, 0.39828, 0.39056, 0.38164, 0.37644, 0.37068, 0.36446, 0.36064, 0.35654, 0.35246, 0.3491, 0.34802,
-0.3438, 0.34228, 0.3401, 0.34074, 0.33972, 0.34242, 0.3436, 0.34458, 0.34616, 0.35154, 0.352, 0.3521, 0.35378, 0.35248, 0.34906, 0.35026, 0.35062, 0.35142,
-0.3531, 0.35366, 0.35848, 0.3613, 0.3664, 0.36834, 0.36854, 0.36864, 0.3714, 
-//352
-0.06688, 0.06914, 0.07286, 0.07748, 0.07722, 0.07702, 0.07718, 0.07768, 0.07812, 0.07782, 0.07788, 0.0772, 0.0769, 0.07916, 0.0789, 0.08042, 0.0821, 0.08048,
-0.07984, 0.081, 0.08232, 0.08354, 0.08588, 0.08962, 0.09604, 0.11156, 0.14, 0.1804, 0.22386, 0.26002, 0.2856, 0.29942, 0.30646, 0.31014, 0.3123, 0.31102,
-0.30668, 0.30822, 0.30476, 0.30076, 0.29818, 0.2951, 0.29148, 0.2866, 0.2805, 0.27238, 0.26666, 0.26202, 0.25862, 0.2555, 0.25318, 0.25094, 0.24768, 0.2438,
-0.24098, 0.23956, 0.23974, 0.23758, 0.23898, 0.24076, 0.24176, 0.24064, 0.2429, 0.2467, 0.24938, 0.25056, 0.2507, 0.24792, 0.24608, 0.24554, 0.24634, 0.24856,
-0.2492, 0.25184, 0.25134, 0.2545, 0.25652, 0.25972, 0.26072, 0.26214, 0.26284, 
-//353
-0.0425, 0.04186, 0.04064, 0.04136, 0.04316, 0.04262, 0.04224, 0.04162, 0.04162, 0.04048, 0.04236, 0.04426, 0.04616, 0.04318, 0.04274, 0.04114, 0.0418, 0.04376,
-0.04634, 0.04744, 0.04672, 0.04462, 0.04694, 0.0486, 0.05682, 0.06964, 0.08958, 0.1152, 0.1446, 0.16858, 0.18404, 0.19492, 0.19778, 0.20014, 0.20094, 0.19844,
-0.1921, 0.19834, 0.19754, 0.19524, 0.19284, 0.19068, 0.18888, 0.1862, 0.1873, 0.18424, 0.18242, 0.17958, 0.17818, 0.17578, 0.17504, 0.17366, 0.17242, 0.17112,
-0.16976, 0.16882, 0.17, 0.1678, 0.16808, 0.1692, 0.16882, 0.16694, 0.16716, 0.171, 0.17118, 0.17244, 0.17328, 0.17296, 0.17126, 0.17148, 0.17172, 0.1714,
-0.17328, 0.17436, 0.1758, 0.17628, 0.17822, 0.17832, 0.17988, 0.18008, 0.18096, 
-//354
-0.09522, 0.10798, 0.1251, 0.13966, 0.1508, 0.15624, 0.157, 0.15656, 0.15748, 0.15978, 0.16054, 0.16276, 0.16472, 0.16498, 0.16766, 0.17384, 0.18434, 0.2017,
-0.22796, 0.26406, 0.30886, 0.36462, 0.42466, 0.48244, 0.53698, 0.58284, 0.61732, 0.64162, 0.65888, 0.6672, 0.67342, 0.68034, 0.69036, 0.7051, 0.72466, 0.7405,
-0.74902, 0.75984, 0.76448, 0.76598, 0.76916, 0.77166, 0.77626, 0.77572, 0.77796, 0.77882, 0.78064, 0.78142, 0.78192, 0.7843, 0.78566, 0.78808, 0.78966, 0.79004,
-0.79066, 0.79248, 0.79362, 0.79108, 0.7924, 0.79534, 0.79378, 0.7945, 0.79546, 0.7978, 0.79788, 0.80002, 0.80166, 0.80176, 0.80204, 0.80258, 0.80594, 0.8068,
-0.81116, 0.80922, 0.8128, 0.8142, 0.81922, 0.81432, 0.8182, 0.81662, 0.81624, 
-//355
-0.08572, 0.0947, 0.10728, 0.11572, 0.12136, 0.12176, 0.12286, 0.12334, 0.12494, 0.12586, 0.1262, 0.12602, 0.12628, 0.12774, 0.13178, 0.13656, 0.14512, 0.16008,
-0.18454, 0.21798, 0.26272, 0.31676, 0.37014, 0.42284, 0.46974, 0.50518, 0.53108, 0.54684, 0.55336, 0.55212, 0.55374, 0.559, 0.5672, 0.58394, 0.60692, 0.62382,
-0.63428, 0.64696, 0.65168, 0.65398, 0.65582, 0.65694, 0.65742, 0.6582, 0.6607, 0.66128, 0.66144, 0.66288, 0.66224, 0.66134, 0.6648, 0.66546, 0.66528, 0.66692,
-0.66798, 0.66734, 0.6677, 0.66834, 0.66998, 0.66996, 0.66938, 0.66814, 0.66558, 0.6689, 0.67004, 0.67182, 0.67402, 0.6751, 0.67476, 0.6772, 0.6779, 0.67862,
-0.68218, 0.68118, 0.68356, 0.68244, 0.68006, 0.68156, 0.68962, 0.68524, 0.68718, 
-//356
-0.10006, 0.11034, 0.12402, 0.13436, 0.1414, 0.14376, 0.1444, 0.14172, 0.1404, 0.14016, 0.14086, 0.14218, 0.14332, 0.14308, 0.14456, 0.1448, 0.14608, 0.14766,
-0.14904, 0.14928, 0.15076, 0.14946, 0.15246, 0.1571, 0.17108, 0.19432, 0.2375, 0.29852, 0.37218, 0.44724, 0.51552, 0.5704, 0.61006, 0.63524, 0.64962, 0.65196,
-0.64584, 0.64448, 0.63526, 0.62294, 0.61152, 0.59532, 0.58068, 0.56224, 0.5444, 0.5242, 0.50856, 0.4981, 0.4878, 0.48084, 0.47722, 0.47098, 0.46422, 0.45952,
-0.45502, 0.45034, 0.45034, 0.44974, 0.4499, 0.4524, 0.45672, 0.45902, 0.46452, 0.47186, 0.47734, 0.47854, 0.4822, 0.47918, 0.47738, 0.47392, 0.47302, 0.47272,
-0.47704, 0.48312, 0.4904, 0.49722, 0.50328, 0.50922, 0.51332, 0.51598, 0.52668, 
-//357
-0.0684, 0.07234, 0.07818, 0.0825, 0.08422, 0.08308, 0.08224, 0.0819, 0.082, 0.08158, 0.0831, 0.08254, 0.08298, 0.08198, 0.08444, 0.08394, 0.0864, 0.08584,
-0.08638, 0.0866, 0.08784, 0.08788, 0.08946, 0.09458, 0.10242, 0.11952, 0.15232, 0.19814, 0.25548, 0.31572, 0.36722, 0.4068, 0.4333, 0.44856, 0.45724, 0.4603,
-0.45532, 0.4561, 0.4517, 0.4455, 0.43708, 0.43044, 0.42034, 0.40832, 0.39696, 0.38408, 0.37364, 0.3653, 0.35892, 0.35158, 0.34694, 0.34206, 0.3369, 0.33304,
-0.32958, 0.32678, 0.32532, 0.32358, 0.32456, 0.32678, 0.3309, 0.33374, 0.33424, 0.34148, 0.34536, 0.34842, 0.35002, 0.34848, 0.3442, 0.34314, 0.3418, 0.34102,
-0.34636, 0.35036, 0.3567, 0.36074, 0.3667, 0.36776, 0.36996, 0.37306, 0.37714, 
-//358
-0.04432, 0.04196, 0.0434, 0.0417, 0.0416, 0.04256, 0.04228, 0.04308, 0.04512, 0.04414, 0.04448, 0.04648, 0.04924, 0.05066, 0.05242, 0.05344, 0.0566, 0.06224,
-0.06814, 0.0739, 0.07986, 0.08514, 0.09082, 0.09984, 0.11576, 0.13594, 0.16688, 0.203, 0.23462, 0.2576, 0.27398, 0.28336, 0.28842, 0.29106, 0.29242, 0.28998,
-0.28506, 0.29142, 0.29118, 0.28996, 0.28866, 0.28792, 0.28796, 0.28788, 0.2886, 0.28744, 0.2861, 0.28778, 0.28498, 0.2861, 0.2865, 0.28552, 0.28444, 0.28508,
-0.28604, 0.28662, 0.28712, 0.28526, 0.28484, 0.28654, 0.28746, 0.28554, 0.28424, 0.28836, 0.2887, 0.2901, 0.29188, 0.2924, 0.29226, 0.29278, 0.29434, 0.29556,
-0.29838, 0.29742, 0.2973, 0.29782, 0.29866, 0.29902, 0.30242, 0.29886, 0.30118, 
-//359
-0.07786, 0.0827, 0.09054, 0.09456, 0.09864, 0.09908, 0.09994, 0.09996, 0.10084, 0.10186, 0.1046, 0.10776, 0.11202, 0.11546, 0.12318, 0.13094, 0.14096, 0.15346,
-0.169, 0.1885, 0.21126, 0.24044, 0.27742, 0.32398, 0.38032, 0.44474, 0.51092, 0.57588, 0.62972, 0.67242, 0.70292, 0.7226, 0.73232, 0.73948, 0.7462, 0.74798,
-0.74788, 0.75478, 0.75522, 0.75604, 0.75754, 0.75816, 0.75942, 0.76214, 0.76066, 0.75992, 0.76164, 0.76162, 0.75984, 0.76286, 0.76618, 0.76558, 0.76508, 0.7671,
-0.76764, 0.7682, 0.77026, 0.7688, 0.76636, 0.76952, 0.76792, 0.76728, 0.7692, 0.77308, 0.7742, 0.77408, 0.77378, 0.77478, 0.77458, 0.77972, 0.78126, 0.78002,
-0.78262, 0.7794, 0.77798, 0.78038, 0.78136, 0.78334, 0.78946, 0.78372, 0.78776, 
-//360
-0.07326, 0.07528, 0.08024, 0.08142, 0.08232, 0.08386, 0.0841, 0.08452, 0.08462, 0.08206, 0.0839, 0.08498, 0.08872, 0.08924, 0.09178, 0.09296, 0.09738, 0.1068,
-0.12602, 0.15256, 0.19162, 0.23978, 0.29664, 0.35606, 0.41384, 0.46712, 0.51024, 0.53788, 0.55472, 0.5649, 0.57226, 0.57936, 0.59082, 0.6081, 0.62826, 0.64338,
-0.65314, 0.66368, 0.66832, 0.67302, 0.6757, 0.67674, 0.67988, 0.6838, 0.6844, 0.68512, 0.68572, 0.68472, 0.68616, 0.69054, 0.69402, 0.69494, 0.69462, 0.69598,
-0.69738, 0.6994, 0.70142, 0.70038, 0.70064, 0.70302, 0.7011, 0.6982, 0.69802, 0.70284, 0.70484, 0.70802, 0.70912, 0.70924, 0.71032, 0.71288, 0.71752, 0.71908,
-0.72344, 0.71942, 0.7229, 0.72486, 0.727, 0.72422, 0.72872, 0.72716, 0.72772, 
-//361
-0.0718, 0.07256, 0.07652, 0.07888, 0.0817, 0.08314, 0.0849, 0.0834, 0.08136, 0.08124, 0.08236, 0.08456, 0.08754, 0.08842, 0.09108, 0.0916, 0.09488, 0.09742,
-0.10128, 0.10562, 0.10838, 0.11064, 0.1149, 0.12062, 0.13294, 0.15782, 0.20124, 0.26424, 0.33978, 0.42134, 0.49968, 0.57002, 0.6225, 0.6548, 0.67432, 0.68086,
-0.67672, 0.67484, 0.66522, 0.65106, 0.63546, 0.6201, 0.60198, 0.57846, 0.55668, 0.53386, 0.51506, 0.50098, 0.49008, 0.48124, 0.47682, 0.47164, 0.46368, 0.45684,
-0.45262, 0.45074, 0.4482, 0.44836, 0.45086, 0.45444, 0.45856, 0.46338, 0.47004, 0.48176, 0.4888, 0.49282, 0.49308, 0.49358, 0.48928, 0.48762, 0.4876, 0.4891,
-0.49452, 0.50098, 0.51124, 0.5193, 0.5272, 0.53334, 0.5412, 0.5491, 0.55836, 
-//362
-0.03648, 0.03656, 0.03504, 0.0367, 0.03702, 0.03888, 0.03902, 0.03774, 0.0373, 0.03776, 0.04034, 0.04228, 0.04566, 0.047, 0.0489, 0.05202, 0.05734, 0.0645,
-0.07358, 0.08448, 0.09692, 0.10926, 0.12758, 0.14964, 0.17776, 0.21226, 0.25332, 0.29626, 0.33424, 0.36244, 0.37896, 0.38774, 0.39408, 0.39714, 0.3997, 0.4011,
-0.39936, 0.4033, 0.40556, 0.40768, 0.4093, 0.4098, 0.4114, 0.41152, 0.41262, 0.41352, 0.4162, 0.4166, 0.41592, 0.42004, 0.4201, 0.42102, 0.42296, 0.42406,
-0.42426, 0.42604, 0.4277, 0.42628, 0.429, 0.43138, 0.43084, 0.42814, 0.4306, 0.433, 0.43512, 0.43808, 0.44142, 0.44258, 0.44586, 0.44738, 0.4502, 0.45406,
-0.45532, 0.45612, 0.45666, 0.45952, 0.46144, 0.46174, 0.466, 0.4669, 0.46796, 
-//363
-0.04256, 0.04026, 0.03978, 0.0381, 0.03816, 0.0389, 0.04056, 0.04204, 0.04278, 0.04156, 0.04218, 0.04416, 0.0463, 0.0491, 0.0534, 0.05888, 0.0661, 0.07398,
-0.08828, 0.1053, 0.12674, 0.1527, 0.18532, 0.22752, 0.28154, 0.34606, 0.4134, 0.47648, 0.52878, 0.56596, 0.59048, 0.60648, 0.6169, 0.62288, 0.62774, 0.63024,
-0.63108, 0.63704, 0.64102, 0.64164, 0.6442, 0.64722, 0.64866, 0.65118, 0.65376, 0.65538, 0.65582, 0.65774, 0.65842, 0.65798, 0.66236, 0.66486, 0.66672, 0.66834,
-0.67026, 0.6708, 0.6724, 0.67316, 0.67484, 0.67756, 0.67794, 0.67662, 0.67692, 0.68004, 0.68182, 0.68408, 0.68626, 0.6871, 0.6854, 0.6896, 0.6901, 0.69228,
-0.69732, 0.69662, 0.70084, 0.69818, 0.69842, 0.70224, 0.70406, 0.7021, 0.70332, 
-//364
-0.05026, 0.04842, 0.0474, 0.0472, 0.04674, 0.04794, 0.04848, 0.04978, 0.05006, 0.05086, 0.05156, 0.05168, 0.05392, 0.05582, 0.0577, 0.0597, 0.06524, 0.06972,
-0.07454, 0.0796, 0.08692, 0.09186, 0.09982, 0.11092, 0.12864, 0.15632, 0.20028, 0.26446, 0.34224, 0.43, 0.51588, 0.58668, 0.63778, 0.66784, 0.68236, 0.68456,
-0.68398, 0.6808, 0.67274, 0.66292, 0.6519, 0.63794, 0.62004, 0.59692, 0.57542, 0.5537, 0.53572, 0.52088, 0.51004, 0.50304, 0.49708, 0.48988, 0.48584, 0.48168,
-0.47526, 0.4692, 0.46976, 0.47062, 0.4749, 0.48002, 0.48572, 0.49274, 0.50126, 0.51096, 0.51924, 0.52742, 0.53074, 0.52942, 0.5261, 0.5241, 0.52424, 0.52634,
-0.53562, 0.54404, 0.55554, 0.56866, 0.57268, 0.5825, 0.5938, 0.59542, 0.60788, 
-//365
-0.13482, 0.16392, 0.2189, 0.29944, 0.39512, 0.48268, 0.53598, 0.55756, 0.56506, 0.56702, 0.56866, 0.57084, 0.5725, 0.57374, 0.57612, 0.57906, 0.58758, 0.60122,
-0.6182, 0.63574, 0.6508, 0.66074, 0.66626, 0.67072, 0.67544, 0.67842, 0.68022, 0.67906, 0.67692, 0.6734, 0.67142, 0.67164, 0.67424, 0.68092, 0.6878, 0.69292,
-0.69496, 0.69828, 0.69806, 0.69886, 0.69858, 0.69832, 0.6993, 0.69726, 0.69774, 0.6977, 0.69752, 0.6971, 0.69568, 0.69554, 0.69568, 0.69402, 0.69356, 0.69342,
-0.69294, 0.69382, 0.69252, 0.69194, 0.69038, 0.69166, 0.69006, 0.68958, 0.68816, 0.68988, 0.68978, 0.68888, 0.69058, 0.68956, 0.69068, 0.6913, 0.6906, 0.6927,
-0.69176, 0.68872, 0.68884, 0.68958, 0.69042, 0.69024, 0.69216, 0.68872, 0.68948, 
-//366
-0.14186, 0.17158, 0.225, 0.29786, 0.37726, 0.43918, 0.47354, 0.48606, 0.49042, 0.49198, 0.49518, 0.4971, 0.50316, 0.5077, 0.51214, 0.5182, 0.52574, 0.53408,
-0.54442, 0.55406, 0.56222, 0.56848, 0.57532, 0.58278, 0.58982, 0.59536, 0.60002, 0.59948, 0.59818, 0.59294, 0.59122, 0.59162, 0.59282, 0.60002, 0.60742,
-0.61346, 0.6183, 0.62076, 0.6205, 0.62008, 0.61772, 0.61838, 0.61828, 0.6179, 0.61688, 0.61676, 0.61664, 0.61612, 0.61418, 0.61454, 0.6138, 0.61174, 0.61,
-0.61084, 0.60992, 0.60786, 0.60858, 0.6065, 0.60666, 0.60714, 0.60638, 0.6046, 0.60328, 0.60458, 0.604, 0.60372, 0.60136, 0.6014, 0.60044, 0.59986, 0.60068,
-0.59996, 0.60322, 0.6003, 0.59996, 0.5996, 0.60006, 0.59782, 0.59838, 0.59768, 0.59684, 
-//367
-0.13358, 0.16126, 0.21346, 0.28144, 0.35218, 0.40362, 0.42758, 0.43506, 0.43598, 0.43488, 0.43636, 0.43748, 0.43826, 0.43892, 0.43956, 0.43994, 0.44036,
-0.44004, 0.44004, 0.43948, 0.43874, 0.43828, 0.4387, 0.44188, 0.44998, 0.46434, 0.4845, 0.50164, 0.51388, 0.52078, 0.52482, 0.52612, 0.5266, 0.52518, 0.52534,
-0.52388, 0.52252, 0.52234, 0.52104, 0.52036, 0.51968, 0.51794, 0.51732, 0.51562, 0.51448, 0.51264, 0.51124, 0.51114, 0.50814, 0.50676, 0.507, 0.5055, 0.50468,
-0.50402, 0.50266, 0.50004, 0.5001, 0.49954, 0.49848, 0.49912, 0.49714, 0.4968, 0.4953, 0.49554, 0.49538, 0.49432, 0.4949, 0.4928, 0.49218, 0.49286, 0.49196,
-0.49164, 0.49238, 0.49084, 0.49066, 0.4907, 0.49038, 0.48888, 0.4907, 0.48872, 0.4896, 
-//368
-0.1267, 0.15182, 0.1921, 0.2385, 0.28138, 0.30622, 0.31614, 0.31776, 0.31874, 0.31464, 0.31378, 0.3145, 0.31534, 0.31558, 0.31546, 0.31496, 0.31718, 0.31816,
-0.3182, 0.31694, 0.31384, 0.31198, 0.31304, 0.31652, 0.32412, 0.33748, 0.35266, 0.366, 0.3751, 0.38038, 0.3825, 0.38328, 0.38242, 0.38204, 0.38194, 0.38144,
-0.3792, 0.37922, 0.3793, 0.37814, 0.37654, 0.3756, 0.37556, 0.37466, 0.37288, 0.37196, 0.37062, 0.36876, 0.3677, 0.36638, 0.36684, 0.36542, 0.36552, 0.36478,
-0.36274, 0.3611, 0.36118, 0.35856, 0.35812, 0.3592, 0.35798, 0.35624, 0.35566, 0.35736, 0.35638, 0.3548, 0.3552, 0.35378, 0.35336, 0.35344, 0.35356, 0.35336,
-0.35278, 0.35126, 0.3509, 0.35164, 0.34956, 0.34802, 0.3491, 0.34708, 0.34818, 
-//369
-0.11904, 0.13772, 0.1672, 0.19374, 0.2121, 0.22088, 0.22422, 0.22392, 0.22202, 0.22148, 0.2205, 0.22024, 0.21992, 0.21938, 0.21806, 0.21846, 0.21826, 0.21704,
-0.21672, 0.21608, 0.21618, 0.21564, 0.21584, 0.21734, 0.22182, 0.2315, 0.24364, 0.25436, 0.26104, 0.26354, 0.26528, 0.26598, 0.26608, 0.2668, 0.26856, 0.26902,
-0.26772, 0.26966, 0.26784, 0.2663, 0.26526, 0.265, 0.26486, 0.26344, 0.26268, 0.2621, 0.26104, 0.26026, 0.25862, 0.25722, 0.25632, 0.25552, 0.25484, 0.25418,
-0.25288, 0.25102, 0.25072, 0.2493, 0.24968, 0.24934, 0.24788, 0.2457, 0.2451, 0.24502, 0.24514, 0.2442, 0.244, 0.24392, 0.24372, 0.24378, 0.24364, 0.24302,
-0.2436, 0.24302, 0.24262, 0.24056, 0.24104, 0.2396, 0.2408, 0.23966, 0.23904, 
-//370
-0.0974, 0.10954, 0.12398, 0.13686, 0.14484, 0.14688, 0.14672, 0.14506, 0.14492, 0.144, 0.14332, 0.1419, 0.14272, 0.14272, 0.14232, 0.1422, 0.14266, 0.1417,
-0.14104, 0.14082, 0.14038, 0.139, 0.13958, 0.14086, 0.1442, 0.15152, 0.16018, 0.16806, 0.17266, 0.1734, 0.1737, 0.17342, 0.17242, 0.1737, 0.17544, 0.17556,
-0.17478, 0.17762, 0.17736, 0.17592, 0.17626, 0.1742, 0.1742, 0.17284, 0.17236, 0.17144, 0.17114, 0.1713, 0.16938, 0.16952, 0.16866, 0.16802, 0.168, 0.16674,
-0.16602, 0.16524, 0.1649, 0.16348, 0.16388, 0.1652, 0.16498, 0.16366, 0.16242, 0.1639, 0.16348, 0.16352, 0.16336, 0.16222, 0.1623, 0.16298, 0.1644, 0.16404,
-0.1646, 0.16452, 0.16398, 0.16422, 0.16506, 0.16324, 0.16348, 0.1633, 0.16396, 
-//371
-0.07218, 0.07576, 0.08094, 0.08358, 0.08654, 0.08556, 0.08572, 0.08544, 0.0853, 0.08516, 0.0847, 0.0852, 0.08442, 0.0844, 0.08404, 0.08394, 0.08384, 0.08276,
-0.08372, 0.08396, 0.08514, 0.08456, 0.0848, 0.08614, 0.08702, 0.09274, 0.09856, 0.10226, 0.10652, 0.1091, 0.1093, 0.10968, 0.1099, 0.1096, 0.11162, 0.11064,
-0.1092, 0.11058, 0.10946, 0.10944, 0.1087, 0.109, 0.1086, 0.10866, 0.10824, 0.10876, 0.10804, 0.10726, 0.10724, 0.1062, 0.10608, 0.1047, 0.10386, 0.10484,
-0.10444, 0.10496, 0.10448, 0.1033, 0.10462, 0.10476, 0.1043, 0.10288, 0.10318, 0.10464, 0.10552, 0.10558, 0.10552, 0.10488, 0.1052, 0.10676, 0.1069, 0.10682,
-0.10704, 0.10806, 0.10812, 0.1082, 0.10908, 0.10878, 0.10764, 0.10854, 0.10922, 
-//372
-0.0525, 0.05148, 0.05248, 0.05324, 0.05304, 0.05272, 0.05222, 0.05222, 0.05208, 0.05032, 0.0516, 0.05226, 0.0525, 0.0519, 0.052, 0.05158, 0.05162, 0.0516,
-0.05074, 0.05114, 0.0504, 0.05014, 0.05088, 0.0527, 0.05448, 0.05618, 0.05862, 0.06098, 0.06168, 0.06148, 0.06254, 0.06244, 0.06316, 0.06526, 0.06742, 0.06818,
-0.06618, 0.06722, 0.06726, 0.06616, 0.06648, 0.06564, 0.06612, 0.06526, 0.06532, 0.06454, 0.06408, 0.06366, 0.06262, 0.06262, 0.06288, 0.0623, 0.06352, 0.06274,
-0.06298, 0.06272, 0.06336, 0.06004, 0.06096, 0.062, 0.06248, 0.06132, 0.0616, 0.06302, 0.06362, 0.06344, 0.06366, 0.06436, 0.06552, 0.06634, 0.0676, 0.06776,
-0.0696, 0.06854, 0.06902, 0.06878, 0.06838, 0.06872, 0.07092, 0.07008, 0.07048, 
-//373
-0.04174, 0.04172, 0.04252, 0.0418, 0.04246, 0.04142, 0.04002, 0.03946, 0.03996, 0.0401, 0.04156, 0.04126, 0.0416, 0.04154, 0.04104, 0.04162, 0.04036, 0.0406,
-0.03988, 0.04002, 0.03996, 0.04112, 0.0422, 0.0419, 0.04296, 0.04348, 0.04598, 0.04652, 0.04684, 0.04686, 0.04694, 0.04702, 0.04788, 0.04968, 0.04986, 0.04946,
-0.04672, 0.05018, 0.04964, 0.04866, 0.04912, 0.04796, 0.04812, 0.0469, 0.0476, 0.04622, 0.04594, 0.04632, 0.04704, 0.04716, 0.04768, 0.0474, 0.04708, 0.04636,
-0.04646, 0.04556, 0.04604, 0.04406, 0.04568, 0.04724, 0.04766, 0.04672, 0.0466, 0.04872, 0.04856, 0.04856, 0.0488, 0.0505, 0.05054, 0.0512, 0.05228, 0.05294,
-0.05402, 0.05448, 0.05478, 0.0549, 0.0542, 0.05426, 0.056, 0.0557, 0.05586, 
-//374
-0.13554, 0.16546, 0.21776, 0.28856, 0.36298, 0.41572, 0.44494, 0.45698, 0.46208, 0.46564, 0.4705, 0.47788, 0.48622, 0.49308, 0.5046, 0.51678, 0.53178, 0.5474,
-0.5658, 0.58332, 0.5987, 0.6147, 0.63078, 0.6498, 0.66744, 0.68212, 0.69152, 0.6961, 0.6974, 0.6952, 0.69486, 0.69512, 0.6963, 0.70216, 0.70942, 0.71582,
-0.71894, 0.72202, 0.7228, 0.72298, 0.72294, 0.72206, 0.72176, 0.72172, 0.72286, 0.7221, 0.72234, 0.72186, 0.7232, 0.72242, 0.7217, 0.72176, 0.72044, 0.72094,
-0.7208, 0.72074, 0.72192, 0.72194, 0.72192, 0.72378, 0.72102, 0.71978, 0.71864, 0.72046, 0.72116, 0.7217, 0.7222, 0.72186, 0.72156, 0.72382, 0.7233, 0.72276,
-0.72278, 0.72252, 0.72538, 0.72616, 0.7244, 0.72336, 0.72544, 0.72552, 0.72324, 
-//375
-0.13472, 0.16294, 0.21108, 0.26854, 0.32792, 0.3674, 0.3868, 0.3942, 0.3969, 0.39934, 0.40342, 0.40848, 0.4151, 0.41954, 0.42758, 0.4371, 0.44788, 0.46288,
-0.47782, 0.49454, 0.50896, 0.5235, 0.538, 0.55354, 0.56898, 0.58004, 0.58864, 0.59168, 0.59124, 0.58818, 0.58702, 0.58824, 0.58858, 0.59356, 0.6022, 0.60924,
-0.61252, 0.6162, 0.61758, 0.6172, 0.61582, 0.6155, 0.61516, 0.61426, 0.61276, 0.61244, 0.61198, 0.61078, 0.61032, 0.60866, 0.60836, 0.60736, 0.60642, 0.60542,
-0.60498, 0.6022, 0.60348, 0.60272, 0.602, 0.60316, 0.60148, 0.5992, 0.5977, 0.59968, 0.60052, 0.59944, 0.59872, 0.59834, 0.5974, 0.59746, 0.59764, 0.59714,
-0.5972, 0.59534, 0.59794, 0.59528, 0.5964, 0.59696, 0.5955, 0.59348, 0.59482, 
-//376
-0.1298, 0.1601, 0.2061, 0.265, 0.32098, 0.35854, 0.37382, 0.3785, 0.37942, 0.37752, 0.3776, 0.37802, 0.37986, 0.3798, 0.38114, 0.38132, 0.38374, 0.38384,
-0.38478, 0.38518, 0.38382, 0.38208, 0.38438, 0.38912, 0.40078, 0.42392, 0.4556, 0.48948, 0.5154, 0.53178, 0.54108, 0.54552, 0.5486, 0.54802, 0.54868, 0.54772,
-0.5438, 0.54362, 0.54306, 0.5414, 0.5393, 0.5367, 0.53388, 0.52968, 0.5262, 0.52204, 0.5179, 0.51352, 0.50864, 0.50704, 0.5062, 0.5033, 0.50148, 0.49992,
-0.49932, 0.49672, 0.49466, 0.49308, 0.49306, 0.4959, 0.49704, 0.49558, 0.49444, 0.496, 0.498, 0.49776, 0.49736, 0.496, 0.4941, 0.49396, 0.493, 0.49228, 0.49518,
-0.4941, 0.4944, 0.49744, 0.49872, 0.49798, 0.49974, 0.49462, 0.4986, 
-//377
-0.1192, 0.14352, 0.17868, 0.2179, 0.2507, 0.2663, 0.27266, 0.2723, 0.27222, 0.26952, 0.26966, 0.26976, 0.27132, 0.272, 0.27312, 0.27426, 0.27556, 0.27564,
-0.2759, 0.27536, 0.27492, 0.27336, 0.27558, 0.27896, 0.28928, 0.30732, 0.33134, 0.3554, 0.37388, 0.3844, 0.3898, 0.39274, 0.39362, 0.39294, 0.3924, 0.39244,
-0.38796, 0.3909, 0.3886, 0.38514, 0.38508, 0.38182, 0.38062, 0.37858, 0.37732, 0.37434, 0.37238, 0.36874, 0.36612, 0.36466, 0.3636, 0.3606, 0.35834, 0.35606,
-0.35502, 0.3539, 0.35252, 0.35098, 0.35126, 0.3524, 0.35056, 0.34952, 0.34918, 0.35044, 0.35052, 0.34998, 0.35034, 0.34934, 0.34736, 0.34608, 0.34786, 0.34622,
-0.34818, 0.3459, 0.34502, 0.3474, 0.3475, 0.34796, 0.35012, 0.35042, 0.35104, 
-//378
-0.10878, 0.12664, 0.1499, 0.16882, 0.1824, 0.18778, 0.18976, 0.18764, 0.18694, 0.1846, 0.18578, 0.18484, 0.18566, 0.18542, 0.1859, 0.18578, 0.18648, 0.18722,
-0.18732, 0.18592, 0.18624, 0.1846, 0.1852, 0.18802, 0.19626, 0.20958, 0.22864, 0.2467, 0.26056, 0.26888, 0.27238, 0.27414, 0.27422, 0.27318, 0.2727, 0.2722,
-0.26926, 0.27166, 0.27108, 0.26968, 0.26856, 0.26638, 0.26442, 0.26172, 0.26042, 0.25708, 0.256, 0.2547, 0.2536, 0.25226, 0.25134, 0.25028, 0.24778, 0.24544,
-0.24554, 0.24326, 0.24302, 0.24096, 0.24206, 0.24284, 0.2423, 0.24082, 0.24004, 0.24126, 0.24138, 0.24056, 0.23964, 0.2392, 0.23768, 0.23792, 0.23954, 0.23948,
-0.23918, 0.23972, 0.24082, 0.24098, 0.23972, 0.24184, 0.24156, 0.23902, 0.24052, 
-//379
-0.08532, 0.09228, 0.10308, 0.111, 0.11628, 0.11794, 0.11936, 0.1188, 0.11878, 0.118, 0.11724, 0.11788, 0.1162, 0.11634, 0.11756, 0.11618, 0.1173, 0.11824,
-0.11856, 0.11856, 0.11926, 0.11948, 0.11794, 0.12018, 0.12534, 0.13432, 0.14846, 0.16328, 0.17468, 0.18044, 0.18384, 0.18424, 0.1852, 0.18298, 0.18222, 0.1809,
-0.17924, 0.18194, 0.18042, 0.17912, 0.17842, 0.17728, 0.17662, 0.17602, 0.17464, 0.17212, 0.17226, 0.1714, 0.17172, 0.1712, 0.17004, 0.16834, 0.16824, 0.16626,
-0.16548, 0.1643, 0.1642, 0.16212, 0.16424, 0.16518, 0.16402, 0.1636, 0.1621, 0.16388, 0.16398, 0.16182, 0.1605, 0.16144, 0.1609, 0.16102, 0.16306, 0.16176,
-0.16328, 0.16316, 0.1643, 0.16646, 0.16554, 0.16666, 0.16902, 0.16688, 0.1696, 
-//380
-0.0664, 0.06682, 0.06988, 0.0705, 0.07194, 0.0718, 0.07138, 0.07064, 0.07112, 0.07192, 0.07206, 0.0719, 0.07286, 0.07214, 0.07126, 0.07118, 0.07092, 0.07092,
-0.07108, 0.07082, 0.0728, 0.07304, 0.0721, 0.07526, 0.07916, 0.08598, 0.09616, 0.10524, 0.112, 0.115, 0.11678, 0.11712, 0.117, 0.11686, 0.1166, 0.11512,
-0.11296, 0.11394, 0.1143, 0.11336, 0.11064, 0.10998, 0.11092, 0.10994, 0.10926, 0.10894, 0.10898, 0.10844, 0.10818, 0.10732, 0.10698, 0.10546, 0.10598, 0.10686,
-0.10686, 0.10554, 0.10692, 0.10464, 0.1052, 0.1067, 0.1055, 0.10324, 0.1026, 0.10602, 0.10648, 0.1059, 0.10704, 0.10738, 0.10744, 0.1072, 0.10708, 0.10624,
-0.10738, 0.10822, 0.11038, 0.11066, 0.11034, 0.11006, 0.11092, 0.11066, 0.11012, 
-//381
-0.04396, 0.04166, 0.0407, 0.04022, 0.04086, 0.04096, 0.04244, 0.04222, 0.04286, 0.04292, 0.0421, 0.043, 0.0422, 0.04082, 0.03886, 0.03936, 0.04098, 0.042,
-0.0422, 0.04352, 0.04262, 0.04224, 0.04316, 0.04322, 0.04556, 0.04922, 0.05508, 0.0608, 0.06578, 0.06766, 0.06842, 0.06782, 0.06814, 0.06724, 0.06668, 0.0638,
-0.06338, 0.06494, 0.06506, 0.0638, 0.06304, 0.06318, 0.06396, 0.0653, 0.06408, 0.06322, 0.06322, 0.06284, 0.06234, 0.06028, 0.062, 0.06244, 0.06114, 0.06282,
-0.06226, 0.0625, 0.06216, 0.06026, 0.0616, 0.06174, 0.06196, 0.06188, 0.0621, 0.06388, 0.06392, 0.0638, 0.06472, 0.0651, 0.06564, 0.06702, 0.06778, 0.06844,
-0.06894, 0.0699, 0.0701, 0.06834, 0.07006, 0.06934, 0.07086, 0.07084, 0.07222, 
-//382
-0.12778, 0.15332, 0.19402, 0.24046, 0.28098, 0.30404, 0.3164, 0.32056, 0.32266, 0.32396, 0.32894, 0.33364, 0.34028, 0.34786, 0.35986, 0.37344, 0.3892, 0.4069,
-0.42756, 0.45158, 0.47334, 0.4991, 0.52844, 0.56234, 0.59708, 0.62992, 0.65238, 0.66818, 0.6764, 0.67918, 0.68112, 0.68266, 0.68518, 0.689, 0.69586, 0.70222,
-0.70452, 0.70834, 0.71032, 0.71098, 0.71164, 0.7096, 0.71072, 0.70884, 0.70726, 0.70764, 0.70868, 0.70752, 0.7067, 0.70632, 0.70708, 0.70558, 0.70324, 0.70276,
-0.70274, 0.70216, 0.70294, 0.70368, 0.70276, 0.70436, 0.70458, 0.70424, 0.7054, 0.70738, 0.70722, 0.70686, 0.70742, 0.70512, 0.70672, 0.70914, 0.70942, 0.70942,
-0.7136, 0.71062, 0.71182, 0.71224, 0.71336, 0.70974, 0.71368, 0.71028, 0.7118, 
-//383
-0.12616, 0.14934, 0.18114, 0.2159, 0.24478, 0.2601, 0.2651, 0.26544, 0.26778, 0.2711, 0.27448, 0.28084, 0.2865, 0.29368, 0.30234, 0.31232, 0.32672, 0.34302,
-0.36284, 0.38336, 0.40636, 0.4292, 0.45748, 0.48884, 0.52114, 0.54964, 0.56982, 0.58142, 0.58698, 0.5883, 0.59004, 0.589, 0.59174, 0.59734, 0.6056, 0.61286,
-0.61486, 0.61902, 0.6203, 0.62294, 0.62278, 0.62256, 0.62422, 0.62222, 0.62002, 0.6199, 0.61938, 0.6205, 0.61814, 0.6173, 0.61834, 0.61798, 0.61764, 0.61742,
-0.61716, 0.61548, 0.6158, 0.61672, 0.61634, 0.61538, 0.61358, 0.61104, 0.61066, 0.61116, 0.61016, 0.60962, 0.61038, 0.60812, 0.60618, 0.60652, 0.607, 0.6058,
-0.60918, 0.60678, 0.6085, 0.60794, 0.60592, 0.60534, 0.60742, 0.60372, 0.6055, 
-//384
-0.11622, 0.13958, 0.17624, 0.21608, 0.24894, 0.2662, 0.27184, 0.27376, 0.2724, 0.2704, 0.26826, 0.26962, 0.2708, 0.27266, 0.27466, 0.27652, 0.27868, 0.28054,
-0.2809, 0.28138, 0.2818, 0.27854, 0.28152, 0.2863, 0.30208, 0.3291, 0.3719, 0.4215, 0.47118, 0.51096, 0.53588, 0.5496, 0.55798, 0.55866, 0.55988, 0.55792,
-0.554, 0.55438, 0.5497, 0.54548, 0.53884, 0.5325, 0.52574, 0.51826, 0.50876, 0.49956, 0.49078, 0.48288, 0.4771, 0.4734, 0.4708, 0.46656, 0.462, 0.45778,
-0.45412, 0.45208, 0.45038, 0.44798, 0.45048, 0.45324, 0.4545, 0.4567, 0.45636, 0.4617, 0.46252, 0.46426, 0.46442, 0.46488, 0.46278, 0.4598, 0.45882, 0.45676,
-0.46092, 0.46286, 0.46588, 0.46998, 0.47492, 0.4758, 0.47844, 0.4793, 0.48212, 
-//385
-0.10784, 0.12342, 0.14694, 0.16908, 0.1833, 0.19018, 0.19078, 0.18922, 0.18916, 0.18874, 0.19024, 0.19144, 0.1925, 0.19344, 0.19292, 0.19414, 0.19564, 0.19574,
-0.19926, 0.20238, 0.20254, 0.20246, 0.20424, 0.21006, 0.22176, 0.24352, 0.27926, 0.32138, 0.35778, 0.3858, 0.40556, 0.41816, 0.42246, 0.4241, 0.42484, 0.42114,
-0.41484, 0.41794, 0.41554, 0.4111, 0.40682, 0.40168, 0.39794, 0.39308, 0.384, 0.37884, 0.37144, 0.3678, 0.35938, 0.35348, 0.35054, 0.34688, 0.34334, 0.34042,
-0.33906, 0.3368, 0.33552, 0.33266, 0.3354, 0.33682, 0.33706, 0.33682, 0.34074, 0.34274, 0.34544, 0.34678, 0.34868, 0.34394, 0.34052, 0.33876, 0.33934, 0.33778,
-0.3407, 0.34302, 0.34564, 0.34862, 0.35074, 0.35044, 0.35456, 0.35548, 0.35584, 
-//386
-0.08836, 0.09502, 0.10784, 0.11634, 0.1218, 0.1239, 0.124, 0.12384, 0.12458, 0.12392, 0.12472, 0.12514, 0.12512, 0.12518, 0.12572, 0.12644, 0.12704, 0.12864,
-0.12948, 0.13032, 0.13092, 0.13166, 0.13264, 0.13694, 0.14466, 0.15974, 0.18694, 0.21906, 0.24734, 0.26856, 0.28272, 0.29024, 0.29292, 0.29416, 0.2932, 0.29276,
-0.28974, 0.29278, 0.2897, 0.2882, 0.28566, 0.2817, 0.2795, 0.27522, 0.2717, 0.26566, 0.26074, 0.25596, 0.25192, 0.24894, 0.24666, 0.24516, 0.24376, 0.24104,
-0.24014, 0.23772, 0.23674, 0.23406, 0.23482, 0.23608, 0.2378, 0.23756, 0.23656, 0.2406, 0.24278, 0.24272, 0.24268, 0.2419, 0.24016, 0.23972, 0.23752, 0.23714,
-0.23998, 0.24166, 0.24486, 0.24682, 0.2477, 0.24902, 0.24954, 0.25084, 0.25214, 
-//387
-0.0695, 0.07194, 0.0763, 0.07912, 0.07958, 0.0788, 0.0803, 0.07924, 0.0799, 0.07816, 0.07846, 0.07744, 0.07698, 0.07744, 0.0784, 0.07794, 0.07922, 0.08056,
-0.0817, 0.08104, 0.08256, 0.0802, 0.08024, 0.08364, 0.09036, 0.10296, 0.12322, 0.14914, 0.17006, 0.1855, 0.19418, 0.19928, 0.20016, 0.19918, 0.1981, 0.19862,
-0.1943, 0.19426, 0.19382, 0.19272, 0.19074, 0.18916, 0.18706, 0.1834, 0.17942, 0.1759, 0.17276, 0.17094, 0.16918, 0.16794, 0.16662, 0.16576, 0.16462, 0.1625,
-0.16098, 0.159, 0.15758, 0.1544, 0.15616, 0.15868, 0.16012, 0.15798, 0.1597, 0.16184, 0.16188, 0.16308, 0.16308, 0.16204, 0.16066, 0.15996, 0.16026, 0.16124,
-0.16344, 0.164, 0.16506, 0.16928, 0.17048, 0.17208, 0.17312, 0.17128, 0.17392, 
-//388
-0.04848, 0.0475, 0.0496, 0.04888, 0.04834, 0.04784, 0.04646, 0.04722, 0.04786, 0.04776, 0.0496, 0.04912, 0.04926, 0.04844, 0.0493, 0.04858, 0.0481, 0.04842,
-0.04784, 0.04926, 0.0503, 0.05032, 0.05308, 0.05456, 0.05944, 0.06676, 0.08094, 0.095, 0.10686, 0.11638, 0.12048, 0.12324, 0.1239, 0.12224, 0.122, 0.11938,
-0.11504, 0.11832, 0.11784, 0.11688, 0.11574, 0.11432, 0.1139, 0.11198, 0.11012, 0.10792, 0.10658, 0.1065, 0.10536, 0.10614, 0.1059, 0.10482, 0.10488, 0.10346,
-0.10334, 0.10078, 0.1005, 0.09898, 0.101, 0.1032, 0.1047, 0.10246, 0.10182, 0.1054, 0.10676, 0.10794, 0.10886, 0.10772, 0.10774, 0.10866, 0.1091, 0.10954,
-0.11016, 0.11158, 0.11208, 0.11508, 0.11654, 0.11648, 0.11688, 0.11666, 0.11838, 
-//389
-0.11568, 0.13418, 0.16294, 0.18742, 0.20898, 0.21768, 0.22194, 0.22372, 0.22592, 0.22802, 0.23168, 0.23534, 0.24064, 0.247, 0.25676, 0.2674, 0.28444, 0.30258,
-0.32382, 0.3459, 0.37194, 0.40262, 0.4378, 0.48104, 0.52826, 0.57594, 0.6181, 0.65046, 0.67348, 0.68864, 0.69862, 0.70398, 0.7073, 0.70942, 0.70882, 0.7101,
-0.70688, 0.70934, 0.7073, 0.7066, 0.70548, 0.70594, 0.70672, 0.70728, 0.70848, 0.70616, 0.7086, 0.7093, 0.70724, 0.70802, 0.71184, 0.71214, 0.71302, 0.71128,
-0.71334, 0.71388, 0.71586, 0.71546, 0.71326, 0.71784, 0.71516, 0.7144, 0.7108, 0.71492, 0.71818, 0.7164, 0.71344, 0.71218, 0.71372, 0.7187, 0.72062, 0.71852,
-0.72394, 0.72002, 0.71996, 0.71974, 0.721, 0.72014, 0.72404, 0.72038, 0.7213, 
-//390
-0.10654, 0.12076, 0.14174, 0.16192, 0.1767, 0.1827, 0.18466, 0.18648, 0.18766, 0.18998, 0.19314, 0.19688, 0.20204, 0.2078, 0.21634, 0.22782, 0.24138, 0.25686,
-0.27718, 0.29914, 0.3193, 0.34382, 0.37862, 0.41982, 0.46382, 0.5087, 0.54662, 0.57624, 0.59154, 0.6021, 0.61262, 0.617, 0.61628, 0.61872, 0.61704, 0.61902,
-0.62132, 0.6283, 0.63142, 0.63048, 0.62858, 0.62936, 0.62788, 0.62468, 0.61918, 0.619, 0.62028, 0.61908, 0.61844, 0.61858, 0.62004, 0.62334, 0.62548, 0.6261,
-0.62744, 0.6269, 0.62314, 0.62248, 0.62102, 0.62076, 0.61904, 0.62062, 0.6235, 0.62166, 0.62342, 0.62412, 0.62316, 0.62144, 0.62022, 0.62122, 0.6227, 0.6218,
-0.6237, 0.62112, 0.61938, 0.61914, 0.6229, 0.62328, 0.62502, 0.62376, 0.6247, 
-//391
-0.11072, 0.12726, 0.15208, 0.17612, 0.19282, 0.20006, 0.20188, 0.20042, 0.19972, 0.19734, 0.19704, 0.1975, 0.19814, 0.20074, 0.20298, 0.20412, 0.20612, 0.20754,
-0.2082, 0.20846, 0.2085, 0.20824, 0.20958, 0.21622, 0.23, 0.25824, 0.30328, 0.36366, 0.4301, 0.49174, 0.54344, 0.58084, 0.60456, 0.61676, 0.62072, 0.61994,
-0.61508, 0.60948, 0.60164, 0.59246, 0.58124, 0.56876, 0.55216, 0.53442, 0.516, 0.49752, 0.48428, 0.4718, 0.46316, 0.45594, 0.44918, 0.44192, 0.4367, 0.43174,
-0.42544, 0.42008, 0.42002, 0.41988, 0.42162, 0.42642, 0.42948, 0.4345, 0.44008, 0.44518, 0.45064, 0.45314, 0.45392, 0.45016, 0.44834, 0.44794, 0.44358, 0.44614,
-0.45126, 0.45734, 0.46606, 0.4691, 0.47272, 0.47492, 0.47984, 0.4842, 0.49272, 
-//392
-0.09216, 0.10266, 0.11626, 0.12622, 0.13162, 0.13374, 0.13524, 0.13316, 0.13316, 0.13088, 0.13064, 0.13082, 0.13164, 0.13208, 0.13292, 0.13522, 0.13678,
-0.13728, 0.13814, 0.138, 0.13846, 0.13714, 0.13946, 0.14318, 0.15478, 0.17716, 0.21148, 0.26108, 0.31386, 0.36152, 0.3977, 0.41778, 0.43084, 0.43612, 0.44018,
-0.43652, 0.43192, 0.42882, 0.42478, 0.41828, 0.41362, 0.40354, 0.39392, 0.3839, 0.37298, 0.3628, 0.3525, 0.34442, 0.33762, 0.33324, 0.32998, 0.32466, 0.31996,
-0.31502, 0.31108, 0.30876, 0.30802, 0.30496, 0.30772, 0.31108, 0.3136, 0.31466, 0.31756, 0.32286, 0.32588, 0.32906, 0.32652, 0.32318, 0.322, 0.31974, 0.31894,
-0.31868, 0.32252, 0.32568, 0.32892, 0.3329, 0.3362, 0.33818, 0.34118, 0.3409, 0.34588, 
-//393
-0.06184, 0.06722, 0.07254, 0.0755, 0.0764, 0.07654, 0.07668, 0.07656, 0.07602, 0.07628, 0.07724, 0.07798, 0.07782, 0.07734, 0.07854, 0.07786, 0.07758, 0.07888,
-0.08014, 0.0818, 0.08148, 0.08314, 0.08434, 0.08792, 0.09574, 0.10868, 0.135, 0.1707, 0.2124, 0.24902, 0.27718, 0.29514, 0.30416, 0.31008, 0.31104, 0.30838,
-0.3032, 0.30452, 0.30082, 0.29682, 0.2918, 0.28546, 0.28108, 0.27182, 0.2649, 0.25348, 0.24604, 0.2392, 0.23136, 0.22752, 0.22376, 0.21932, 0.21496, 0.2114,
-0.21028, 0.20778, 0.2091, 0.2092, 0.21318, 0.21608, 0.2176, 0.21922, 0.22184, 0.22576, 0.23132, 0.23194, 0.23264, 0.23064, 0.2306, 0.2283, 0.23154, 0.23322,
-0.23602, 0.24102, 0.24576, 0.24748, 0.25108, 0.25352, 0.25624, 0.25508, 0.25758, 
-//394
-0.04686, 0.04854, 0.04808, 0.04838, 0.04792, 0.04834, 0.04732, 0.04732, 0.04726, 0.04632, 0.04632, 0.0476, 0.04814, 0.04866, 0.04836, 0.04832, 0.04854, 0.0491,
-0.05034, 0.04874, 0.04982, 0.0499, 0.0523, 0.05508, 0.05974, 0.07118, 0.08918, 0.11716, 0.1489, 0.17564, 0.19574, 0.2075, 0.21352, 0.21534, 0.21578, 0.21496,
-0.20936, 0.20882, 0.2063, 0.20318, 0.19924, 0.19542, 0.19038, 0.18438, 0.17774, 0.17082, 0.16372, 0.15764, 0.15388, 0.1499, 0.1476, 0.1455, 0.14398, 0.14164,
-0.14018, 0.13872, 0.1386, 0.13724, 0.13806, 0.13994, 0.14208, 0.14288, 0.147, 0.15002, 0.15308, 0.15364, 0.1548, 0.15332, 0.15224, 0.15296, 0.15376, 0.15682,
-0.15948, 0.16438, 0.1679, 0.1697, 0.1729, 0.17492, 0.1747, 0.17582, 0.1797, 
-//395
-0.08232, 0.09352, 0.10692, 0.12068, 0.12936, 0.13098, 0.13322, 0.13298, 0.13486, 0.13446, 0.13698, 0.1411, 0.14656, 0.15266, 0.1643, 0.17488, 0.19266, 0.21334,
-0.2397, 0.27224, 0.30898, 0.351, 0.39656, 0.44954, 0.50292, 0.5551, 0.60274, 0.63844, 0.6637, 0.68086, 0.6901, 0.69572, 0.69756, 0.69988, 0.70296, 0.70386,
-0.70462, 0.70792, 0.70698, 0.70786, 0.70826, 0.7091, 0.7103, 0.7121, 0.71256, 0.71198, 0.71312, 0.7127, 0.71556, 0.71572, 0.71776, 0.71794, 0.71698, 0.71962,
-0.72068, 0.71842, 0.72, 0.71844, 0.71852, 0.71878, 0.71828, 0.71892, 0.71762, 0.7209, 0.724, 0.72424, 0.72514, 0.7268, 0.72688, 0.72768, 0.7307, 0.7315,
-0.73164, 0.72884, 0.7308, 0.73146, 0.7316, 0.73282, 0.73534, 0.73132, 0.73412, 
-//396
-0.08964, 0.09686, 0.10688, 0.1134, 0.1185, 0.11944, 0.11958, 0.11874, 0.12004, 0.12078, 0.1217, 0.12572, 0.12814, 0.13006, 0.1327, 0.13762, 0.1476, 0.16226,
-0.18652, 0.22024, 0.2619, 0.3107, 0.35978, 0.4105, 0.45812, 0.49952, 0.53232, 0.55442, 0.57014, 0.58032, 0.58612, 0.59032, 0.58988, 0.5918, 0.59364, 0.59322,
-0.59262, 0.59796, 0.59774, 0.59788, 0.59772, 0.59698, 0.59878, 0.59782, 0.59834, 0.59598, 0.5983, 0.5969, 0.59732, 0.59788, 0.59942, 0.599, 0.59906, 0.6014,
-0.60014, 0.59872, 0.60042, 0.59968, 0.60074, 0.60126, 0.59964, 0.59806, 0.59722, 0.60034, 0.60056, 0.60258, 0.60458, 0.60454, 0.6024, 0.60546, 0.60636, 0.60646,
-0.60964, 0.60966, 0.61068, 0.61212, 0.6141, 0.61538, 0.61174, 0.6096, 0.61056, 
-//397
-0.08078, 0.08368, 0.08894, 0.09386, 0.09674, 0.09894, 0.09966, 0.1002, 0.09932, 0.10014, 0.1029, 0.10518, 0.10884, 0.11338, 0.12004, 0.12802, 0.13842, 0.15038,
-0.16568, 0.18248, 0.20438, 0.229, 0.26266, 0.30322, 0.35178, 0.40276, 0.4491, 0.4874, 0.51416, 0.53054, 0.5401, 0.54476, 0.54854, 0.55038, 0.55148, 0.55156,
-0.54774, 0.54912, 0.54596, 0.54436, 0.54224, 0.54004, 0.53824, 0.53498, 0.5306, 0.52694, 0.52402, 0.51944, 0.51612, 0.51176, 0.50906, 0.5073, 0.50554, 0.50422,
-0.49996, 0.49914, 0.4988, 0.49644, 0.49764, 0.5005, 0.50046, 0.50136, 0.50492, 0.50822, 0.5093, 0.50956, 0.51092, 0.50892, 0.50618, 0.5049, 0.50436, 0.50412,
-0.51008, 0.5121, 0.51932, 0.52052, 0.5246, 0.53006, 0.53072, 0.529, 0.53064, 
-//398
-0.0762, 0.07968, 0.08352, 0.08742, 0.0878, 0.08834, 0.08668, 0.08532, 0.08584, 0.08482, 0.08698, 0.08732, 0.08802, 0.08794, 0.08958, 0.08944, 0.08932, 0.08902,
-0.08924, 0.09, 0.09216, 0.09254, 0.09544, 0.09928, 0.109, 0.12714, 0.1583, 0.20782, 0.26812, 0.33154, 0.38858, 0.4335, 0.46208, 0.47786, 0.4856, 0.48524,
-0.47702, 0.47104, 0.4634, 0.45246, 0.4416, 0.4284, 0.41162, 0.39482, 0.3772, 0.35938, 0.3447, 0.33408, 0.32368, 0.31668, 0.31328, 0.30616, 0.30274, 0.29846,
-0.2932, 0.2877, 0.2848, 0.2841, 0.28718, 0.29084, 0.29292, 0.29496, 0.2965, 0.30316, 0.30622, 0.30868, 0.31292, 0.30958, 0.30824, 0.30564, 0.3065, 0.30624,
-0.3089, 0.31406, 0.32026, 0.32802, 0.33398, 0.33778, 0.34222, 0.34324, 0.35018, 
-//399
-0.05142, 0.0499, 0.05084, 0.05194, 0.05216, 0.05248, 0.0524, 0.0537, 0.05326, 0.05198, 0.05306, 0.0515, 0.053, 0.05254, 0.05306, 0.05178, 0.05304, 0.05462,
-0.0569, 0.05652, 0.05746, 0.05642, 0.05764, 0.06008, 0.06708, 0.08012, 0.10574, 0.1446, 0.1924, 0.2409, 0.2843, 0.31406, 0.33472, 0.34306, 0.34594, 0.34242,
-0.3361, 0.3341, 0.32886, 0.32046, 0.31358, 0.30288, 0.2918, 0.2781, 0.26638, 0.25384, 0.24484, 0.23522, 0.2289, 0.22728, 0.22202, 0.21996, 0.21446, 0.21068,
-0.20794, 0.2042, 0.204, 0.20216, 0.2032, 0.20764, 0.20892, 0.20892, 0.2131, 0.21884, 0.2213, 0.22268, 0.22318, 0.22076, 0.22058, 0.21842, 0.22022, 0.22006,
-0.22366, 0.22654, 0.2315, 0.23332, 0.23774, 0.23926, 0.24214, 0.24636, 0.25156, 
-//400
-0.06954, 0.0715, 0.07598, 0.0782, 0.08114, 0.07994, 0.08064, 0.08104, 0.08222, 0.08238, 0.0853, 0.0891, 0.09292, 0.09736, 0.10342, 0.11202, 0.12412, 0.1411,
-0.1654, 0.19546, 0.23084, 0.27372, 0.32136, 0.37708, 0.43928, 0.5048, 0.56832, 0.62434, 0.66856, 0.69992, 0.71934, 0.7315, 0.73736, 0.741, 0.74262, 0.7436,
-0.74248, 0.74532, 0.7425, 0.74248, 0.74106, 0.73986, 0.73654, 0.73278, 0.7287, 0.71898, 0.7144, 0.71056, 0.70722, 0.70612, 0.70666, 0.70404, 0.7024, 0.70392,
-0.69968, 0.69164, 0.6949, 0.6926, 0.69476, 0.6981, 0.7011, 0.7078, 0.71122, 0.7199, 0.72504, 0.72974, 0.73166, 0.73372, 0.73206, 0.73414, 0.73394, 0.7329,
-0.7419, 0.74374, 0.74936, 0.755, 0.76108, 0.76102, 0.76386, 0.7638, 0.76974, 
-//401
-0.07834, 0.07978, 0.08384, 0.0869, 0.08894, 0.08982, 0.09124, 0.09156, 0.09216, 0.09206, 0.09316, 0.09382, 0.09566, 0.09498, 0.09578, 0.1006, 0.1088, 0.1228,
-0.1444, 0.17556, 0.2188, 0.2702, 0.32866, 0.38946, 0.44692, 0.50168, 0.54718, 0.58198, 0.60714, 0.62422, 0.63512, 0.6444, 0.64636, 0.64644, 0.65024, 0.6507,
-0.65114, 0.65608, 0.6589, 0.66012, 0.66124, 0.66098, 0.65974, 0.65718, 0.65514, 0.6499, 0.6455, 0.6423, 0.6401, 0.63968, 0.63972, 0.6386, 0.63656, 0.63632,
-0.63454, 0.63136, 0.63238, 0.63248, 0.63404, 0.6385, 0.64126, 0.64444, 0.6497, 0.65444, 0.65948, 0.66046, 0.66188, 0.66058, 0.65972, 0.66158, 0.66492, 0.66582,
-0.66944, 0.6741, 0.68024, 0.68174, 0.68416, 0.68716, 0.68958, 0.68608, 0.6902, 
-//402
-0.05472, 0.05558, 0.05878, 0.05936, 0.05928, 0.0602, 0.05986, 0.05972, 0.06142, 0.06268, 0.0649, 0.06662, 0.07078, 0.0729, 0.0767, 0.08152, 0.08872, 0.09816,
-0.11008, 0.12582, 0.14556, 0.1688, 0.19766, 0.23768, 0.28628, 0.34448, 0.40178, 0.45404, 0.49626, 0.52702, 0.54792, 0.55992, 0.5663, 0.57042, 0.57356, 0.57438,
-0.57156, 0.57448, 0.57292, 0.57288, 0.57004, 0.56534, 0.56108, 0.55338, 0.54478, 0.53536, 0.52812, 0.52282, 0.5165, 0.5112, 0.50954, 0.50664, 0.5043, 0.50184,
-0.49954, 0.4959, 0.49848, 0.50104, 0.50564, 0.51252, 0.51862, 0.52114, 0.52536, 0.53164, 0.53748, 0.54028, 0.54254, 0.54102, 0.539, 0.541, 0.54188, 0.54742,
-0.55452, 0.55832, 0.56624, 0.5709, 0.5766, 0.57868, 0.5855, 0.58258, 0.58612, 
-//403
-0.0362, 0.03574, 0.03626, 0.03502, 0.03488, 0.03508, 0.03562, 0.03658, 0.03812, 0.03592, 0.04012, 0.0422, 0.04448, 0.04604, 0.04868, 0.0506, 0.05656, 0.0638,
-0.07286, 0.0835, 0.09858, 0.11728, 0.13972, 0.1695, 0.21048, 0.25312, 0.29796, 0.33604, 0.3662, 0.38464, 0.3974, 0.40462, 0.40858, 0.4104, 0.41196, 0.40948,
-0.40608, 0.40968, 0.40926, 0.40756, 0.40812, 0.40736, 0.40618, 0.403, 0.40066, 0.39696, 0.39242, 0.38792, 0.38368, 0.3828, 0.38012, 0.38134, 0.37962, 0.37974,
-0.37816, 0.37708, 0.3773, 0.37714, 0.37884, 0.38122, 0.38288, 0.38654, 0.38998, 0.39462, 0.39676, 0.39926, 0.39604, 0.3926, 0.39192, 0.3932, 0.39466, 0.39696,
-0.4003, 0.40314, 0.40888, 0.4125, 0.41492, 0.41754, 0.4216, 0.42154, 0.42544, 
-//404
-0.0483, 0.0479, 0.0482, 0.04884, 0.0487, 0.0479, 0.04826, 0.04944, 0.0492, 0.051, 0.05288, 0.05478, 0.05674, 0.05722, 0.06066, 0.0648, 0.07022, 0.0768, 0.0856,
-0.09594, 0.10634, 0.1177, 0.13192, 0.14764, 0.16846, 0.19956, 0.24702, 0.31074, 0.38518, 0.46122, 0.52862, 0.57864, 0.6117, 0.62964, 0.638, 0.63916, 0.63602,
-0.6346, 0.62704, 0.6176, 0.6069, 0.5903, 0.57166, 0.54814, 0.52236, 0.4953, 0.471, 0.45102, 0.43568, 0.42614, 0.41978, 0.41332, 0.40756, 0.4006, 0.39812,
-0.39418, 0.39494, 0.39774, 0.40244, 0.41238, 0.42126, 0.4326, 0.44184, 0.45564, 0.46446, 0.47136, 0.47508, 0.47486, 0.4731, 0.47376, 0.47618, 0.48424, 0.49384,
-0.50766, 0.52374, 0.53648, 0.55018, 0.5563, 0.56454, 0.56604, 0.57482, 
-//405
-0.13238, 0.1608, 0.21252, 0.28302, 0.35984, 0.42028, 0.45188, 0.4625, 0.46368, 0.46426, 0.46676, 0.46918, 0.47304, 0.4736, 0.47708, 0.48456, 0.4978, 0.51876,
-0.54658, 0.5784, 0.60896, 0.63654, 0.65676, 0.67308, 0.68436, 0.69194, 0.69668, 0.6968, 0.69658, 0.69412, 0.69294, 0.69226, 0.695, 0.69762, 0.70244, 0.70606,
-0.70482, 0.7076, 0.70778, 0.7095, 0.70884, 0.70926, 0.70946, 0.70798, 0.70788, 0.70792, 0.7084, 0.706, 0.70452, 0.70344, 0.70584, 0.70466, 0.70336, 0.70206,
-0.7024, 0.7007, 0.70092, 0.69936, 0.6987, 0.69894, 0.69686, 0.69542, 0.69332, 0.69292, 0.69242, 0.6931, 0.69514, 0.69342, 0.6941, 0.69552, 0.69652, 0.69468,
-0.69684, 0.69462, 0.69592, 0.6943, 0.69656, 0.69494, 0.6992, 0.69478, 0.69566, 
-//406
-0.12822, 0.15526, 0.20176, 0.26148, 0.32118, 0.36338, 0.38104, 0.38748, 0.38712, 0.3888, 0.39032, 0.39396, 0.39632, 0.397, 0.4006, 0.4065, 0.4182, 0.4359,
-0.4608, 0.48908, 0.51458, 0.53548, 0.5511, 0.56124, 0.56906, 0.57404, 0.57772, 0.5789, 0.57542, 0.57418, 0.57216, 0.57072, 0.57146, 0.57544, 0.58076, 0.5843,
-0.58482, 0.58776, 0.58778, 0.588, 0.58794, 0.58658, 0.58578, 0.58558, 0.58416, 0.5833, 0.58146, 0.5811, 0.58058, 0.57826, 0.578, 0.5775, 0.57508, 0.57586,
-0.57408, 0.57212, 0.57284, 0.57126, 0.57166, 0.57272, 0.57198, 0.56924, 0.56984, 0.57104, 0.57078, 0.56958, 0.56948, 0.56722, 0.5657, 0.56432, 0.5645, 0.56324,
-0.56404, 0.56234, 0.56138, 0.56272, 0.56216, 0.56146, 0.56132, 0.55824, 0.56096, 
-//407
-0.11978, 0.1439, 0.185, 0.23362, 0.27812, 0.3054, 0.31656, 0.32146, 0.32542, 0.32548, 0.32848, 0.33178, 0.33648, 0.34032, 0.34604, 0.35256, 0.36242, 0.37488,
-0.38736, 0.40056, 0.41446, 0.4272, 0.44042, 0.455, 0.46876, 0.48136, 0.49244, 0.5002, 0.506, 0.5079, 0.51022, 0.51116, 0.51006, 0.50872, 0.50878, 0.50862,
-0.50558, 0.50522, 0.50374, 0.50316, 0.49974, 0.50138, 0.50154, 0.5002, 0.50066, 0.49944, 0.499, 0.49678, 0.4963, 0.49424, 0.4926, 0.4921, 0.4922, 0.49212,
-0.49226, 0.49066, 0.49072, 0.4877, 0.4859, 0.4869, 0.48538, 0.48264, 0.48272, 0.484, 0.48368, 0.4832, 0.48328, 0.48216, 0.48182, 0.4817, 0.48204, 0.47906,
-0.4815, 0.4785, 0.4787, 0.48048, 0.47804, 0.47898, 0.47772, 0.4749, 0.47508, 
-//408
-0.12762, 0.147, 0.1798, 0.211, 0.23434, 0.24636, 0.25242, 0.25346, 0.25458, 0.25488, 0.2565, 0.25852, 0.26156, 0.26538, 0.26926, 0.27534, 0.28286, 0.29074,
-0.29812, 0.30596, 0.31318, 0.3191, 0.3259, 0.33336, 0.34296, 0.35406, 0.36668, 0.37596, 0.38236, 0.38576, 0.38706, 0.38768, 0.38738, 0.38574, 0.38394, 0.38322,
-0.38026, 0.3819, 0.38172, 0.38038, 0.37962, 0.37962, 0.37824, 0.37696, 0.37556, 0.37366, 0.37256, 0.37166, 0.37106, 0.36942, 0.36974, 0.36754, 0.36774, 0.36576,
-0.3646, 0.36214, 0.36058, 0.35894, 0.35868, 0.35906, 0.35698, 0.35516, 0.35514, 0.3547, 0.35552, 0.35562, 0.3553, 0.35372, 0.35256, 0.35248, 0.3522, 0.35174,
-0.35186, 0.34954, 0.34936, 0.34808, 0.34912, 0.3469, 0.34874, 0.34796, 0.34654, 
-//409
-0.1081, 0.11946, 0.13812, 0.15244, 0.1618, 0.16746, 0.1679, 0.16756, 0.16762, 0.16658, 0.1681, 0.16872, 0.16936, 0.16898, 0.17088, 0.17494, 0.18258, 0.19254,
-0.20576, 0.2229, 0.23524, 0.245, 0.24986, 0.25378, 0.25626, 0.257, 0.2596, 0.25898, 0.25806, 0.25636, 0.25566, 0.25526, 0.25488, 0.2576, 0.25876, 0.25934,
-0.25994, 0.26254, 0.26168, 0.26078, 0.26058, 0.2605, 0.26024, 0.2587, 0.25824, 0.25576, 0.25506, 0.25414, 0.25288, 0.25238, 0.25178, 0.25128, 0.25036, 0.24864,
-0.24834, 0.24702, 0.2452, 0.2422, 0.24108, 0.24284, 0.24242, 0.24216, 0.2388, 0.2403, 0.2403, 0.24136, 0.24174, 0.24072, 0.24086, 0.24144, 0.23998, 0.23972,
-0.24084, 0.23868, 0.23864, 0.23784, 0.23832, 0.23844, 0.23984, 0.2387, 0.23912, 
-//410
-0.0924, 0.1015, 0.1121, 0.12046, 0.12494, 0.12582, 0.12578, 0.12502, 0.12474, 0.12384, 0.12352, 0.12366, 0.12498, 0.12382, 0.12506, 0.1254, 0.12554, 0.1248,
-0.12574, 0.1257, 0.12628, 0.12418, 0.12622, 0.12912, 0.13454, 0.1442, 0.15774, 0.1705, 0.18006, 0.18448, 0.18628, 0.18734, 0.18798, 0.18662, 0.18786, 0.18666,
-0.18142, 0.1829, 0.18216, 0.17988, 0.17936, 0.17808, 0.17782, 0.17518, 0.17256, 0.1693, 0.16588, 0.16328, 0.16094, 0.15956, 0.15908, 0.15742, 0.15668, 0.1562,
-0.15524, 0.15342, 0.15168, 0.14956, 0.15002, 0.15066, 0.15236, 0.15128, 0.1516, 0.15368, 0.1548, 0.15504, 0.15464, 0.15448, 0.1541, 0.15316, 0.15318, 0.15302,
-0.154, 0.15698, 0.15778, 0.1595, 0.1616, 0.1618, 0.16374, 0.16392, 0.16682, 
-//411
-0.06852, 0.06958, 0.07054, 0.07172, 0.07322, 0.07162, 0.07184, 0.07082, 0.07072, 0.07156, 0.07162, 0.0733, 0.07408, 0.07348, 0.07456, 0.07552, 0.07884, 0.08258,
-0.08838, 0.09488, 0.10106, 0.10582, 0.10906, 0.11154, 0.11274, 0.11262, 0.11332, 0.11142, 0.11032, 0.10838, 0.10814, 0.1077, 0.10838, 0.11038, 0.11264, 0.1128,
-0.1114, 0.11372, 0.11356, 0.11348, 0.11318, 0.11208, 0.11164, 0.11056, 0.11086, 0.10908, 0.10878, 0.10898, 0.10856, 0.10882, 0.10936, 0.1087, 0.1078, 0.10664,
-0.10692, 0.10568, 0.1051, 0.1036, 0.1043, 0.1061, 0.10666, 0.10482, 0.1027, 0.10474, 0.1053, 0.10684, 0.10718, 0.10758, 0.10874, 0.108, 0.1088, 0.10868,
-0.10872, 0.10904, 0.1102, 0.10898, 0.10882, 0.10996, 0.11038, 0.11052, 0.11246, 
-//412
-0.03934, 0.03866, 0.03928, 0.03996, 0.04116, 0.04158, 0.04124, 0.0412, 0.04058, 0.03934, 0.0402, 0.04014, 0.04088, 0.0405, 0.04186, 0.04116, 0.0419, 0.04176,
-0.04104, 0.04164, 0.04146, 0.04056, 0.04318, 0.04342, 0.04676, 0.05042, 0.05712, 0.06274, 0.06598, 0.06708, 0.06766, 0.0668, 0.06578, 0.06536, 0.06574, 0.06364,
-0.06114, 0.0642, 0.06334, 0.06224, 0.06204, 0.06094, 0.06228, 0.06124, 0.0601, 0.05938, 0.05716, 0.05672, 0.0552, 0.05496, 0.05422, 0.0548, 0.0559, 0.05528,
-0.05614, 0.05502, 0.05516, 0.0521, 0.0534, 0.05466, 0.05508, 0.055, 0.05604, 0.05724, 0.0574, 0.05858, 0.05956, 0.06052, 0.06158, 0.06126, 0.06174, 0.06254,
-0.0632, 0.06392, 0.06344, 0.06402, 0.06488, 0.06544, 0.06704, 0.0678, 0.06872, 
-//413
-0.12356, 0.14676, 0.18588, 0.23402, 0.27602, 0.30176, 0.31288, 0.31492, 0.31544, 0.3173, 0.32014, 0.32352, 0.32684, 0.32968, 0.33364, 0.34024, 0.35566, 0.37816,
-0.41332, 0.4576, 0.50528, 0.55374, 0.5928, 0.62852, 0.65284, 0.66844, 0.67882, 0.68478, 0.68712, 0.6898, 0.69154, 0.6936, 0.69288, 0.69236, 0.69282, 0.6931,
-0.6927, 0.69366, 0.69382, 0.69384, 0.69276, 0.69452, 0.69328, 0.693, 0.6922, 0.69264, 0.69218, 0.69062, 0.6895, 0.69046, 0.691, 0.6913, 0.68978, 0.689, 0.68888,
-0.68778, 0.68894, 0.68622, 0.68658, 0.68758, 0.68566, 0.68574, 0.68538, 0.68728, 0.68622, 0.68726, 0.68736, 0.688, 0.68716, 0.69042, 0.6908, 0.691, 0.69256,
-0.68964, 0.69034, 0.68856, 0.69082, 0.69266, 0.6919, 0.69174, 0.69166, 
-//414
-0.1123, 0.13544, 0.17104, 0.21276, 0.24842, 0.27028, 0.27908, 0.28242, 0.28086, 0.27986, 0.28236, 0.28354, 0.28622, 0.28924, 0.29112, 0.29752, 0.31, 0.33126,
-0.36054, 0.39834, 0.43986, 0.48032, 0.51466, 0.54332, 0.56316, 0.57678, 0.58372, 0.58788, 0.59094, 0.5914, 0.59108, 0.59162, 0.59062, 0.58876, 0.59062, 0.58934,
-0.58608, 0.58628, 0.585, 0.5848, 0.58472, 0.5834, 0.58456, 0.58294, 0.5808, 0.5796, 0.57928, 0.5781, 0.57578, 0.57644, 0.57562, 0.57562, 0.57528, 0.5744,
-0.57542, 0.57356, 0.5741, 0.5742, 0.57308, 0.5726, 0.5711, 0.57126, 0.56882, 0.56922, 0.56984, 0.56984, 0.57024, 0.56878, 0.56742, 0.57, 0.57034, 0.56826,
-0.56924, 0.56702, 0.56978, 0.56792, 0.56846, 0.56952, 0.56938, 0.5662, 0.56582, 
-//415
-0.10834, 0.12914, 0.15662, 0.1871, 0.21026, 0.22288, 0.22666, 0.22858, 0.23024, 0.23276, 0.23706, 0.24144, 0.24852, 0.25448, 0.2645, 0.27564, 0.2886, 0.30482,
-0.32204, 0.34106, 0.3622, 0.38444, 0.40936, 0.43708, 0.46414, 0.48738, 0.5047, 0.5157, 0.52128, 0.52498, 0.52132, 0.52042, 0.51918, 0.5176, 0.51802, 0.51814,
-0.51512, 0.51638, 0.51524, 0.51474, 0.51546, 0.5135, 0.5129, 0.51096, 0.51076, 0.50702, 0.50608, 0.5026, 0.49842, 0.49824, 0.49724, 0.49708, 0.4949, 0.49486,
-0.493, 0.49258, 0.49264, 0.4893, 0.48982, 0.49082, 0.49008, 0.49084, 0.49222, 0.49232, 0.49362, 0.49322, 0.49432, 0.49138, 0.48916, 0.48872, 0.48976, 0.49032,
-0.49236, 0.49102, 0.49308, 0.49192, 0.49458, 0.49242, 0.49534, 0.49662, 0.4951, 
-//416
-0.10126, 0.10978, 0.12366, 0.13634, 0.1447, 0.14728, 0.14972, 0.15046, 0.15216, 0.15406, 0.15734, 0.16102, 0.16602, 0.17028, 0.17764, 0.18684, 0.19812, 0.2102,
-0.22596, 0.2419, 0.25906, 0.27624, 0.29522, 0.31726, 0.3372, 0.3548, 0.36666, 0.37264, 0.37786, 0.37782, 0.37774, 0.37692, 0.37542, 0.37268, 0.36982, 0.3682,
-0.36736, 0.36988, 0.36782, 0.3665, 0.3639, 0.36352, 0.36344, 0.36316, 0.3633, 0.36264, 0.36112, 0.35974, 0.35818, 0.3565, 0.3562, 0.35606, 0.35438, 0.3542,
-0.3547, 0.35304, 0.35292, 0.35146, 0.3513, 0.35196, 0.34968, 0.34538, 0.34514, 0.34762, 0.34658, 0.34742, 0.34844, 0.34876, 0.34592, 0.34416, 0.34268, 0.34202,
-0.34206, 0.34076, 0.34198, 0.34016, 0.34, 0.33854, 0.33942, 0.3394, 0.33798, 
-//417
-0.08576, 0.09252, 0.1, 0.10694, 0.10792, 0.10864, 0.10836, 0.10818, 0.10928, 0.1097, 0.1105, 0.11296, 0.11408, 0.11494, 0.11672, 0.11904, 0.12652, 0.13732,
-0.155, 0.17646, 0.20012, 0.22038, 0.23802, 0.2513, 0.25872, 0.26422, 0.26634, 0.2664, 0.26694, 0.26582, 0.26634, 0.26708, 0.26674, 0.26602, 0.26658, 0.26494,
-0.26144, 0.26372, 0.26446, 0.26378, 0.2629, 0.26286, 0.26064, 0.25988, 0.25924, 0.25798, 0.25814, 0.25728, 0.25744, 0.25726, 0.25682, 0.257, 0.2549, 0.2539,
-0.25236, 0.2505, 0.25086, 0.24872, 0.2503, 0.25176, 0.25106, 0.24964, 0.24804, 0.24908, 0.24874, 0.24856, 0.24944, 0.25234, 0.25136, 0.25174, 0.25272, 0.25114,
-0.25174, 0.24942, 0.24808, 0.24816, 0.24894, 0.24884, 0.24958, 0.24944, 0.24894, 
-//418
-0.07214, 0.07496, 0.07844, 0.08118, 0.08316, 0.08258, 0.08292, 0.0817, 0.08118, 0.07984, 0.07982, 0.07998, 0.0808, 0.08144, 0.08226, 0.0816, 0.0835, 0.08324,
-0.08426, 0.08432, 0.08432, 0.08362, 0.08538, 0.08874, 0.09498, 0.10866, 0.12882, 0.15318, 0.17456, 0.18942, 0.19824, 0.2, 0.20282, 0.2022, 0.20134, 0.19974,
-0.19482, 0.19462, 0.19324, 0.1888, 0.18624, 0.18348, 0.1789, 0.17414, 0.16922, 0.16328, 0.15772, 0.15306, 0.1495, 0.1462, 0.14448, 0.14296, 0.14086, 0.13862,
-0.13734, 0.13592, 0.13482, 0.13346, 0.1351, 0.13608, 0.13842, 0.13796, 0.14, 0.1439, 0.14568, 0.14742, 0.14878, 0.14778, 0.14668, 0.14664, 0.14692, 0.14688,
-0.14784, 0.14986, 0.1546, 0.15668, 0.15874, 0.16224, 0.16422, 0.16564, 0.16646, 
-//419
-0.04692, 0.04392, 0.04456, 0.04422, 0.04474, 0.04542, 0.04558, 0.04614, 0.0463, 0.04674, 0.04702, 0.04622, 0.04674, 0.047, 0.04576, 0.04626, 0.0474, 0.04854,
-0.04932, 0.05032, 0.0519, 0.05116, 0.0515, 0.05432, 0.0594, 0.06716, 0.08168, 0.09904, 0.11396, 0.12528, 0.13088, 0.13222, 0.13292, 0.13204, 0.1301, 0.12742,
-0.12544, 0.1262, 0.1231, 0.12068, 0.1178, 0.11542, 0.11204, 0.1093, 0.10584, 0.1027, 0.09968, 0.09688, 0.0948, 0.09192, 0.09156, 0.09112, 0.08852, 0.0876,
-0.08714, 0.0872, 0.08696, 0.08544, 0.08806, 0.09036, 0.09144, 0.09084, 0.09302, 0.09554, 0.09762, 0.09698, 0.09708, 0.0961, 0.09614, 0.09622, 0.097, 0.09708,
-0.1, 0.1015, 0.10398, 0.1059, 0.10628, 0.1072, 0.10812, 0.10802, 0.10948, 
-//420
-0.11052, 0.12784, 0.1592, 0.18812, 0.21226, 0.2237, 0.22886, 0.2298, 0.22994, 0.22934, 0.23288, 0.23474, 0.2385, 0.2392, 0.24268, 0.24894, 0.26268, 0.28566,
-0.31922, 0.36254, 0.41378, 0.46984, 0.52348, 0.5716, 0.611, 0.6406, 0.6621, 0.67424, 0.68392, 0.69036, 0.69492, 0.6986, 0.69888, 0.69816, 0.6999, 0.70098,
-0.69822, 0.70414, 0.7037, 0.69598, 0.6961, 0.69122, 0.68932, 0.68462, 0.67884, 0.6691, 0.66312, 0.65676, 0.65152, 0.64802, 0.64466, 0.64072, 0.63798, 0.63558,
-0.6339, 0.63148, 0.63168, 0.63126, 0.63288, 0.63882, 0.64054, 0.64222, 0.64558, 0.6514, 0.65294, 0.65648, 0.65682, 0.65532, 0.65386, 0.65546, 0.6552, 0.65622,
-0.6625, 0.6632, 0.67034, 0.67308, 0.6753, 0.6774, 0.68106, 0.68096, 0.68862, 
-//421
-0.10648, 0.1235, 0.14788, 0.17192, 0.18742, 0.19424, 0.19832, 0.1985, 0.19794, 0.19838, 0.20188, 0.20422, 0.20608, 0.20704, 0.20996, 0.21674, 0.22744, 0.2485,
-0.27888, 0.3194, 0.36762, 0.42012, 0.47054, 0.51348, 0.54728, 0.57066, 0.58624, 0.59612, 0.60138, 0.60466, 0.60738, 0.6101, 0.61074, 0.61168, 0.6132, 0.6113,
-0.60666, 0.60972, 0.60898, 0.60704, 0.60516, 0.60336, 0.60052, 0.59718, 0.59396, 0.58968, 0.58474, 0.58216, 0.57822, 0.5769, 0.5743, 0.5728, 0.57278, 0.56986,
-0.56678, 0.56658, 0.56572, 0.56686, 0.566, 0.5671, 0.5666, 0.56734, 0.56914, 0.5708, 0.57282, 0.57336, 0.57496, 0.5729, 0.5716, 0.57032, 0.56978, 0.57016,
-0.57492, 0.57426, 0.57816, 0.58012, 0.58394, 0.58446, 0.58976, 0.58544, 0.59072, 
-//422
-0.10684, 0.11854, 0.13464, 0.14834, 0.15628, 0.1602, 0.16076, 0.15974, 0.1609, 0.16122, 0.1634, 0.16426, 0.16704, 0.16838, 0.1706, 0.1767, 0.1872, 0.2055,
-0.23304, 0.27042, 0.31502, 0.35996, 0.40334, 0.43744, 0.46536, 0.4848, 0.49748, 0.50658, 0.5094, 0.5109, 0.51292, 0.51278, 0.5125, 0.51242, 0.51362, 0.51264,
-0.5089, 0.50958, 0.51034, 0.508, 0.50602, 0.50478, 0.5025, 0.50068, 0.49738, 0.49494, 0.49196, 0.48834, 0.48428, 0.48198, 0.48108, 0.4794, 0.4781, 0.47602,
-0.47356, 0.47242, 0.4721, 0.47164, 0.47152, 0.47226, 0.47286, 0.47254, 0.47504, 0.47828, 0.4781, 0.47946, 0.48068, 0.47732, 0.47806, 0.4776, 0.47958, 0.47978,
-0.48224, 0.48444, 0.48632, 0.48956, 0.49044, 0.49014, 0.49416, 0.4933, 0.49448, 
-//423
-0.07804, 0.08488, 0.095, 0.10212, 0.10694, 0.10802, 0.10794, 0.10714, 0.10786, 0.10908, 0.11106, 0.11236, 0.11488, 0.1134, 0.11582, 0.11936, 0.1264, 0.1383,
-0.1556, 0.18332, 0.2172, 0.25394, 0.28982, 0.32234, 0.34528, 0.3615, 0.37178, 0.37694, 0.37976, 0.38124, 0.38184, 0.38168, 0.38186, 0.38166, 0.38244, 0.3812,
-0.37904, 0.38174, 0.38148, 0.37938, 0.37916, 0.37726, 0.37678, 0.37422, 0.373, 0.3706, 0.36936, 0.36746, 0.3647, 0.36262, 0.36138, 0.35998, 0.35856, 0.35684,
-0.35694, 0.35516, 0.35544, 0.35344, 0.35476, 0.35594, 0.35602, 0.35554, 0.35422, 0.35696, 0.3575, 0.35778, 0.35802, 0.3575, 0.35654, 0.35656, 0.35726, 0.3581,
-0.359, 0.36012, 0.36312, 0.36264, 0.36504, 0.36578, 0.36658, 0.3673, 0.36882, 
-//424
-0.05902, 0.059, 0.05864, 0.05926, 0.06046, 0.06102, 0.06186, 0.06088, 0.0627, 0.06402, 0.06476, 0.06518, 0.0676, 0.06924, 0.07282, 0.07734, 0.084, 0.09234,
-0.1012, 0.11222, 0.12492, 0.14106, 0.16034, 0.18202, 0.20546, 0.22896, 0.24858, 0.26184, 0.27074, 0.2733, 0.27612, 0.27762, 0.27658, 0.2748, 0.27416, 0.27054,
-0.26718, 0.26828, 0.26668, 0.26514, 0.26318, 0.25968, 0.25734, 0.25346, 0.2507, 0.24664, 0.2443, 0.24132, 0.24096, 0.23916, 0.23838, 0.23598, 0.23426, 0.233,
-0.2299, 0.22806, 0.22758, 0.22506, 0.2264, 0.22778, 0.22814, 0.2288, 0.22946, 0.23128, 0.23288, 0.23352, 0.233, 0.23106, 0.2302, 0.23014, 0.2299, 0.23072,
-0.23206, 0.23172, 0.23514, 0.2375, 0.24032, 0.23972, 0.24308, 0.24296, 0.24774, 
-//425
-0.05258, 0.0505, 0.05112, 0.051, 0.05106, 0.05036, 0.04936, 0.04964, 0.04896, 0.04984, 0.05018, 0.05086, 0.0522, 0.05136, 0.05236, 0.05168, 0.05156, 0.05156,
-0.05148, 0.05214, 0.0544, 0.05432, 0.05658, 0.05898, 0.06502, 0.07614, 0.0975, 0.12766, 0.16014, 0.1883, 0.208, 0.2199, 0.2256, 0.2266, 0.2258, 0.22226,
-0.21522, 0.21342, 0.2074, 0.2018, 0.1958, 0.18768, 0.18048, 0.17008, 0.16048, 0.15258, 0.14572, 0.14114, 0.13618, 0.13214, 0.13012, 0.1279, 0.12566, 0.12318,
-0.12166, 0.11948, 0.11788, 0.11762, 0.11986, 0.12206, 0.12444, 0.12556, 0.1257, 0.1311, 0.1337, 0.13578, 0.13716, 0.13614, 0.13596, 0.13576, 0.13612, 0.13652,
-0.13952, 0.14074, 0.14492, 0.14842, 0.15248, 0.15366, 0.15768, 0.15962, 0.16468, 
-//426
-0.10486, 0.11468, 0.13228, 0.14474, 0.15254, 0.15658, 0.15748, 0.15736, 0.15766, 0.1579, 0.16048, 0.16286, 0.165, 0.16776, 0.17022, 0.17534, 0.18644, 0.20528,
-0.23418, 0.27484, 0.32684, 0.38372, 0.44268, 0.49854, 0.54778, 0.59192, 0.62612, 0.65018, 0.66956, 0.68042, 0.68944, 0.69216, 0.69442, 0.69582, 0.69746,
-0.69686, 0.69504, 0.69752, 0.69608, 0.6927, 0.68784, 0.67986, 0.6704, 0.66112, 0.64932, 0.63886, 0.63074, 0.61864, 0.61024, 0.6064, 0.60134, 0.59636, 0.59114,
-0.58792, 0.5854, 0.5819, 0.58138, 0.58464, 0.59022, 0.5923, 0.59084, 0.58466, 0.584, 0.5925, 0.59992, 0.60422, 0.60776, 0.6069, 0.60528, 0.60654, 0.60484,
-0.6061, 0.61232, 0.61924, 0.62556, 0.63634, 0.64308, 0.64658, 0.6477, 0.65014, 0.66076, 
-//427
-0.09212, 0.10282, 0.11242, 0.12152, 0.12764, 0.12882, 0.12878, 0.12848, 0.1299, 0.13114, 0.13232, 0.1341, 0.1364, 0.13556, 0.13482, 0.13824, 0.14742, 0.16318,
-0.18794, 0.22176, 0.26728, 0.3212, 0.37642, 0.4326, 0.48504, 0.52828, 0.56096, 0.5806, 0.5938, 0.60312, 0.61258, 0.61982, 0.6221, 0.62178, 0.62056, 0.62148,
-0.6207, 0.62114, 0.61954, 0.61774, 0.61154, 0.60386, 0.5981, 0.5889, 0.58044, 0.56892, 0.5619, 0.55236, 0.54508, 0.54112, 0.53638, 0.53232, 0.53046, 0.5288,
-0.52368, 0.52044, 0.52028, 0.52084, 0.524, 0.53108, 0.5297, 0.53304, 0.54016, 0.55128, 0.55218, 0.5527, 0.55306, 0.55466, 0.54976, 0.55228, 0.55388, 0.55586,
-0.5598, 0.5632, 0.5699, 0.5784, 0.58266, 0.58816, 0.59136, 0.59442, 0.59746, 
-//428
-0.08574, 0.09142, 0.09788, 0.10448, 0.1068, 0.10916, 0.1103, 0.1102, 0.11056, 0.11086, 0.11168, 0.11272, 0.11422, 0.11426, 0.11662, 0.12142, 0.13112, 0.14628,
-0.16902, 0.20124, 0.24472, 0.2943, 0.34608, 0.39564, 0.43898, 0.47378, 0.4994, 0.51666, 0.52852, 0.5347, 0.53852, 0.54026, 0.54112, 0.54148, 0.5416, 0.53954,
-0.53584, 0.53756, 0.53644, 0.53284, 0.52988, 0.52422, 0.51788, 0.51004, 0.50108, 0.48884, 0.47884, 0.4699, 0.46374, 0.45934, 0.45794, 0.45338, 0.44808, 0.44462,
-0.44016, 0.43716, 0.43548, 0.4338, 0.43646, 0.44, 0.44214, 0.44398, 0.44872, 0.45472, 0.45848, 0.4616, 0.46448, 0.46412, 0.46226, 0.46258, 0.46434, 0.4669,
-0.47382, 0.4776, 0.4821, 0.48534, 0.48974, 0.49454, 0.49834, 0.4989, 0.50702, 
-//429
-0.0634, 0.06352, 0.06502, 0.06472, 0.06524, 0.06448, 0.06504, 0.06452, 0.06546, 0.0678, 0.06972, 0.0713, 0.0744, 0.07654, 0.08052, 0.08534, 0.09172, 0.09996,
-0.11066, 0.12452, 0.14086, 0.16046, 0.18658, 0.22004, 0.2588, 0.3015, 0.34128, 0.37358, 0.3962, 0.40914, 0.41834, 0.42254, 0.4243, 0.42548, 0.42552, 0.42276,
-0.4191, 0.41858, 0.41594, 0.41198, 0.40594, 0.39808, 0.39092, 0.381, 0.3723, 0.36176, 0.35278, 0.34452, 0.33942, 0.33444, 0.32978, 0.32546, 0.32152, 0.31732,
-0.31344, 0.3103, 0.30872, 0.30786, 0.30906, 0.31438, 0.31564, 0.31954, 0.32132, 0.32724, 0.33104, 0.33404, 0.33438, 0.33364, 0.33164, 0.33152, 0.33212, 0.33394,
-0.3382, 0.34436, 0.35182, 0.35604, 0.36186, 0.3644, 0.36958, 0.37218, 0.37882, 
-//430
-0.05484, 0.05466, 0.05392, 0.05452, 0.05424, 0.05448, 0.0544, 0.0555, 0.05724, 0.0563, 0.05678, 0.05744, 0.05822, 0.05756, 0.05682, 0.05572, 0.05702, 0.0575,
-0.05872, 0.05946, 0.06172, 0.06284, 0.06262, 0.06598, 0.0721, 0.08582, 0.11142, 0.15278, 0.20414, 0.2575, 0.30312, 0.33712, 0.35598, 0.3639, 0.36358, 0.35956,
-0.34834, 0.34212, 0.33172, 0.31842, 0.30312, 0.28668, 0.27084, 0.25218, 0.2324, 0.21704, 0.203, 0.19394, 0.18578, 0.18012, 0.17456, 0.17064, 0.16622, 0.16234,
-0.1583, 0.15524, 0.15554, 0.15504, 0.1586, 0.16196, 0.16646, 0.16906, 0.17286, 0.17746, 0.18144, 0.18496, 0.18636, 0.18342, 0.18168, 0.18134, 0.18086, 0.18318,
-0.18818, 0.19354, 0.20002, 0.20396, 0.2093, 0.21242, 0.21724, 0.22154, 0.22956, 
-//431
-0.07092, 0.0733, 0.07868, 0.08112, 0.08372, 0.08398, 0.0836, 0.0837, 0.08404, 0.08334, 0.08232, 0.0832, 0.08578, 0.08576, 0.08698, 0.09014, 0.09554, 0.106,
-0.12272, 0.14746, 0.18368, 0.23018, 0.28588, 0.35036, 0.41404, 0.47434, 0.5248, 0.56036, 0.58712, 0.60524, 0.6185, 0.626, 0.632, 0.63324, 0.63536, 0.63384,
-0.63208, 0.63164, 0.62736, 0.62336, 0.61814, 0.60676, 0.59526, 0.58148, 0.56546, 0.54948, 0.53442, 0.5217, 0.51194, 0.50564, 0.50272, 0.4984, 0.49426, 0.49076,
-0.486, 0.48132, 0.48096, 0.4791, 0.48342, 0.49098, 0.4971, 0.50282, 0.51256, 0.52064, 0.52576, 0.53144, 0.53542, 0.53424, 0.53282, 0.53254, 0.52988, 0.53286,
-0.53992, 0.54942, 0.55818, 0.56778, 0.57612, 0.5796, 0.58764, 0.59256, 0.60138, 
-//432
-0.06418, 0.06648, 0.06936, 0.06878, 0.0705, 0.0708, 0.07302, 0.07352, 0.07492, 0.07636, 0.07678, 0.07728, 0.07928, 0.08202, 0.08518, 0.09162, 0.09796, 0.10616,
-0.11604, 0.1285, 0.14078, 0.1533, 0.17066, 0.19102, 0.21838, 0.25688, 0.31136, 0.37932, 0.45288, 0.52634, 0.58486, 0.62486, 0.64612, 0.654, 0.65444, 0.64806,
-0.63634, 0.62474, 0.60876, 0.59284, 0.57538, 0.55458, 0.53062, 0.50278, 0.4786, 0.45298, 0.4322, 0.4168, 0.4061, 0.39818, 0.39364, 0.38714, 0.38092, 0.37444,
-0.36942, 0.36404, 0.3631, 0.36056, 0.36444, 0.36866, 0.3745, 0.38008, 0.38626, 0.39406, 0.40158, 0.40332, 0.40768, 0.40634, 0.40218, 0.39992, 0.39822, 0.39934,
-0.40836, 0.41688, 0.42516, 0.43648, 0.44466, 0.45094, 0.45822, 0.46254, 0.4751, 
-//433
-0.04754, 0.044, 0.04314, 0.0432, 0.04168, 0.04304, 0.0447, 0.04566, 0.04644, 0.04774, 0.04868, 0.05008, 0.05256, 0.05328, 0.05526, 0.05956, 0.06474, 0.07336,
-0.08276, 0.09366, 0.10768, 0.12502, 0.14796, 0.17764, 0.21796, 0.26332, 0.31024, 0.35264, 0.38448, 0.40736, 0.42098, 0.42748, 0.43126, 0.4311, 0.43078, 0.42788,
-0.42238, 0.42174, 0.4184, 0.41256, 0.40638, 0.39722, 0.38764, 0.374, 0.36002, 0.34472, 0.33146, 0.32056, 0.313, 0.30678, 0.30166, 0.29728, 0.29416, 0.2893,
-0.28486, 0.28128, 0.28132, 0.28166, 0.28482, 0.29, 0.29524, 0.30062, 0.30684, 0.31574, 0.32238, 0.32718, 0.33008, 0.32722, 0.32522, 0.32348, 0.3245, 0.32636,
-0.33524, 0.34194, 0.35102, 0.35926, 0.36616, 0.37306, 0.38034, 0.38322, 0.39198, 
-//434
-0.14446, 0.17596, 0.2315, 0.30942, 0.40476, 0.49084, 0.54362, 0.56418, 0.57142, 0.57342, 0.57528, 0.57956, 0.58114, 0.58222, 0.58572, 0.59044, 0.59972, 0.61314,
-0.63226, 0.6508, 0.66732, 0.6781, 0.6869, 0.6931, 0.69834, 0.70066, 0.7003, 0.70048, 0.7008, 0.7006, 0.70118, 0.70132, 0.69978, 0.69838, 0.69942, 0.699,
-0.69546, 0.69616, 0.69654, 0.69498, 0.69562, 0.69276, 0.69346, 0.69284, 0.69308, 0.69072, 0.68958, 0.68876, 0.68798, 0.6871, 0.68734, 0.68604, 0.68554, 0.685,
-0.6839, 0.68198, 0.68162, 0.68024, 0.67956, 0.68154, 0.68052, 0.67822, 0.6789, 0.68108, 0.67922, 0.67852, 0.67868, 0.67766, 0.67896, 0.6801, 0.68248, 0.68182,
-0.6813, 0.67816, 0.67708, 0.67776, 0.6804, 0.68058, 0.6817, 0.67806, 0.6803, 
-//435
-0.1281, 0.15608, 0.20746, 0.27758, 0.35732, 0.4228, 0.4576, 0.47102, 0.47618, 0.47648, 0.47682, 0.48018, 0.48236, 0.48276, 0.4803, 0.48476, 0.49386, 0.5078,
-0.52452, 0.54352, 0.55704, 0.56682, 0.57476, 0.57826, 0.58194, 0.58408, 0.58544, 0.58322, 0.5847, 0.58272, 0.5832, 0.58292, 0.5823, 0.58142, 0.5816, 0.58428,
-0.5818, 0.5825, 0.58276, 0.58274, 0.58098, 0.58054, 0.5801, 0.57854, 0.57866, 0.57696, 0.57742, 0.57566, 0.57194, 0.57186, 0.5734, 0.57234, 0.56434, 0.5662,
-0.567, 0.56574, 0.56528, 0.56692, 0.56592, 0.56288, 0.56208, 0.56136, 0.5587, 0.56018, 0.56004, 0.56094, 0.56018, 0.55966, 0.55968, 0.55952, 0.55854, 0.5593,
-0.55978, 0.55392, 0.55528, 0.5563, 0.55618, 0.55566, 0.55688, 0.5533, 0.55256, 
-//436
-0.12304, 0.15024, 0.1969, 0.25874, 0.3231, 0.3716, 0.3948, 0.40404, 0.40664, 0.4066, 0.40888, 0.4116, 0.41442, 0.41878, 0.4234, 0.43004, 0.4374, 0.44456,
-0.45404, 0.46192, 0.46978, 0.47588, 0.4835, 0.4918, 0.49956, 0.50606, 0.5082, 0.50964, 0.50832, 0.50664, 0.50778, 0.50382, 0.50326, 0.5038, 0.50582, 0.50774,
-0.50504, 0.50798, 0.5072, 0.50502, 0.50382, 0.50358, 0.50242, 0.50134, 0.50062, 0.49982, 0.49956, 0.49846, 0.49668, 0.4962, 0.49524, 0.49216, 0.49064, 0.48956,
-0.4896, 0.4894, 0.48854, 0.4865, 0.486, 0.48672, 0.48598, 0.48356, 0.48204, 0.48246, 0.48068, 0.4794, 0.48046, 0.47912, 0.47952, 0.4821, 0.48194, 0.4812,
-0.48296, 0.48196, 0.48446, 0.47946, 0.47622, 0.47554, 0.4768, 0.4719, 0.47456, 
-//437
-0.12, 0.14316, 0.18118, 0.22544, 0.26174, 0.28274, 0.29076, 0.29218, 0.29252, 0.2934, 0.29624, 0.29448, 0.29668, 0.29892, 0.3017, 0.3021, 0.30636, 0.31638,
-0.32818, 0.33932, 0.35, 0.35544, 0.36054, 0.36324, 0.3656, 0.36682, 0.36696, 0.366, 0.36492, 0.36232, 0.36088, 0.35978, 0.36082, 0.36066, 0.36116, 0.36278,
-0.36228, 0.36308, 0.36324, 0.36344, 0.36404, 0.36162, 0.36128, 0.35908, 0.3586, 0.3588, 0.35632, 0.3556, 0.35224, 0.3505, 0.35276, 0.35254, 0.35134, 0.35054,
-0.35014, 0.34992, 0.35076, 0.34902, 0.3476, 0.34514, 0.34526, 0.34348, 0.34376, 0.34278, 0.34242, 0.34086, 0.34158, 0.34076, 0.34188, 0.34238, 0.34218, 0.34174,
-0.34178, 0.3412, 0.34148, 0.3394, 0.33982, 0.3391, 0.33806, 0.33512, 0.33618, 
-//438
-0.11764, 0.1378, 0.16464, 0.19142, 0.21082, 0.21854, 0.22154, 0.2216, 0.22052, 0.21912, 0.21932, 0.21796, 0.2194, 0.2188, 0.21856, 0.2174, 0.21854, 0.21758,
-0.21748, 0.21692, 0.21562, 0.21564, 0.21558, 0.2169, 0.22278, 0.23148, 0.24262, 0.25156, 0.25848, 0.26066, 0.26234, 0.2627, 0.26328, 0.26156, 0.26176, 0.25946,
-0.25668, 0.2588, 0.25836, 0.25558, 0.25456, 0.25262, 0.25168, 0.24956, 0.24788, 0.24422, 0.24348, 0.24086, 0.23948, 0.23848, 0.23692, 0.23466, 0.23092, 0.2304,
-0.22776, 0.22712, 0.22698, 0.22474, 0.22504, 0.22696, 0.22566, 0.22476, 0.2238, 0.2263, 0.22562, 0.22422, 0.22224, 0.22242, 0.22036, 0.22056, 0.2207, 0.22022,
-0.2222, 0.22192, 0.22374, 0.22464, 0.22702, 0.22632, 0.2237, 0.2242, 0.22474, 
-//439
-0.09724, 0.1076, 0.12456, 0.13862, 0.14784, 0.1516, 0.15156, 0.15228, 0.15258, 0.1494, 0.14912, 0.14838, 0.14854, 0.14926, 0.14926, 0.14916, 0.14942, 0.14914,
-0.1495, 0.14898, 0.14856, 0.14788, 0.14632, 0.14926, 0.15192, 0.15792, 0.1667, 0.17452, 0.17896, 0.18094, 0.18192, 0.1812, 0.18018, 0.1795, 0.17842, 0.17736,
-0.17726, 0.17724, 0.1758, 0.17538, 0.17356, 0.17334, 0.17288, 0.17094, 0.17042, 0.16926, 0.1668, 0.16714, 0.16496, 0.1623, 0.16216, 0.16138, 0.15994, 0.16054,
-0.16024, 0.159, 0.15916, 0.15722, 0.15732, 0.1578, 0.15716, 0.1553, 0.1557, 0.15774, 0.15768, 0.15832, 0.1594, 0.15752, 0.15898, 0.15836, 0.15686, 0.15708,
-0.15818, 0.15742, 0.15874, 0.15956, 0.16018, 0.1619, 0.16214, 0.16212, 0.16294, 
-//440
-0.081, 0.08492, 0.08958, 0.09398, 0.09672, 0.09752, 0.09696, 0.09594, 0.09634, 0.0959, 0.09638, 0.09676, 0.09694, 0.09642, 0.09558, 0.09446, 0.0949, 0.09446,
-0.09508, 0.09584, 0.09552, 0.09584, 0.09604, 0.09788, 0.09978, 0.10322, 0.10864, 0.11288, 0.11606, 0.11598, 0.11804, 0.1181, 0.11804, 0.11772, 0.11762, 0.11654,
-0.11514, 0.11658, 0.1162, 0.11482, 0.1132, 0.11352, 0.11122, 0.10786, 0.10838, 0.10804, 0.10702, 0.10554, 0.10468, 0.10418, 0.10368, 0.10202, 0.10098, 0.10024,
-0.09938, 0.09876, 0.09828, 0.09804, 0.09894, 0.0996, 0.10004, 0.0995, 0.09782, 0.09904, 0.10066, 0.10112, 0.10152, 0.10116, 0.10208, 0.10214, 0.10234, 0.10272,
-0.10292, 0.1029, 0.10378, 0.10558, 0.10766, 0.1081, 0.10798, 0.10808, 0.11038, 
-//441
-0.05322, 0.05332, 0.05396, 0.05234, 0.05444, 0.05406, 0.0542, 0.05304, 0.05398, 0.05328, 0.05218, 0.05248, 0.05282, 0.05294, 0.05358, 0.0533, 0.05396, 0.0546,
-0.05288, 0.0539, 0.0527, 0.0536, 0.05294, 0.0542, 0.05546, 0.0574, 0.06136, 0.06492, 0.06664, 0.06626, 0.06692, 0.06694, 0.06606, 0.06534, 0.06466, 0.06466,
-0.06226, 0.06492, 0.06484, 0.06404, 0.06286, 0.0632, 0.06178, 0.0609, 0.0604, 0.06076, 0.06094, 0.06174, 0.06124, 0.05954, 0.06002, 0.05986, 0.0587, 0.05848,
-0.05764, 0.05782, 0.05798, 0.05768, 0.05896, 0.06, 0.06094, 0.06046, 0.06018, 0.06198, 0.06338, 0.06238, 0.06308, 0.06346, 0.06274, 0.06388, 0.06402, 0.0657,
-0.0666, 0.067, 0.06848, 0.06874, 0.06984, 0.069, 0.07, 0.06964, 0.0698, 
-//442
-0.04304, 0.04126, 0.03998, 0.03902, 0.0397, 0.03962, 0.04016, 0.03982, 0.04068, 0.04048, 0.04072, 0.0401, 0.03956, 0.03876, 0.03724, 0.03842, 0.03896, 0.03956,
-0.04056, 0.0403, 0.0416, 0.04096, 0.03994, 0.03974, 0.0401, 0.04248, 0.04454, 0.04642, 0.04814, 0.04844, 0.04912, 0.0484, 0.04894, 0.0475, 0.04606, 0.0453,
-0.04286, 0.0465, 0.04584, 0.0451, 0.04448, 0.0438, 0.04406, 0.04504, 0.04446, 0.04476, 0.04476, 0.04492, 0.04434, 0.04334, 0.04258, 0.04298, 0.04244, 0.0429,
-0.04332, 0.04392, 0.04406, 0.04192, 0.04326, 0.0447, 0.04458, 0.0433, 0.04338, 0.04542, 0.04678, 0.04754, 0.04802, 0.04844, 0.04892, 0.04916, 0.04958, 0.04956,
-0.05016, 0.05104, 0.05288, 0.05308, 0.05512, 0.0549, 0.0558, 0.05544, 0.05598, 
-//443
-0.1277, 0.15636, 0.2099, 0.27962, 0.35984, 0.42228, 0.45572, 0.46776, 0.47234, 0.47354, 0.47628, 0.48144, 0.48408, 0.48776, 0.49222, 0.49792, 0.5123, 0.53476,
-0.5641, 0.59554, 0.62288, 0.64738, 0.66586, 0.68264, 0.69322, 0.69858, 0.70316, 0.70346, 0.70374, 0.70384, 0.7036, 0.70374, 0.70176, 0.7017, 0.70138, 0.70196,
-0.70026, 0.70126, 0.69942, 0.69866, 0.69716, 0.69592, 0.6963, 0.69144, 0.68838, 0.68342, 0.67934, 0.67284, 0.67164, 0.66776, 0.66626, 0.66288, 0.66106, 0.65936,
-0.65936, 0.65826, 0.65856, 0.66024, 0.65964, 0.66384, 0.6642, 0.66056, 0.66224, 0.6659, 0.6663, 0.66704, 0.66994, 0.6697, 0.66542, 0.6676, 0.66466, 0.66658,
-0.67046, 0.67096, 0.67472, 0.66764, 0.66924, 0.6703, 0.66986, 0.66906, 0.67218, 
-//444
-0.13886, 0.16532, 0.21536, 0.2783, 0.34376, 0.38986, 0.41164, 0.4173, 0.41728, 0.41842, 0.42108, 0.42422, 0.4265, 0.42798, 0.43016, 0.43682, 0.45014, 0.46948,
-0.49516, 0.52202, 0.54916, 0.56982, 0.5866, 0.59616, 0.60316, 0.6061, 0.60736, 0.6077, 0.60728, 0.60828, 0.60808, 0.60804, 0.60626, 0.6054, 0.60516, 0.60206,
-0.59926, 0.60016, 0.59864, 0.59682, 0.59588, 0.59382, 0.59244, 0.58798, 0.58356, 0.5802, 0.57496, 0.57298, 0.56878, 0.567, 0.56422, 0.5624, 0.56008, 0.5576,
-0.55426, 0.55378, 0.55214, 0.54862, 0.5486, 0.55218, 0.54976, 0.552, 0.55262, 0.55332, 0.55466, 0.55466, 0.55404, 0.55416, 0.55228, 0.55106, 0.54924, 0.54882,
-0.55208, 0.54994, 0.5529, 0.55546, 0.55584, 0.55496, 0.55712, 0.55548, 0.55688, 
-//445
-0.12414, 0.14716, 0.18892, 0.23914, 0.28436, 0.31554, 0.32726, 0.3311, 0.33046, 0.33286, 0.33782, 0.34036, 0.3444, 0.34588, 0.34974, 0.3553, 0.36636, 0.38544,
-0.40928, 0.4371, 0.45886, 0.4801, 0.4992, 0.50444, 0.50938, 0.51264, 0.51564, 0.51354, 0.51472, 0.51488, 0.51702, 0.51734, 0.51308, 0.51418, 0.51396, 0.51136,
-0.50434, 0.50622, 0.50592, 0.50446, 0.50152, 0.49966, 0.49896, 0.49652, 0.49762, 0.49584, 0.49402, 0.49256, 0.49118, 0.48848, 0.4877, 0.48742, 0.48696, 0.48564,
-0.48486, 0.48312, 0.48276, 0.47968, 0.47944, 0.48244, 0.48236, 0.48052, 0.4785, 0.48086, 0.48092, 0.48108, 0.47758, 0.47598, 0.47522, 0.47522, 0.4766, 0.47588,
-0.4788, 0.47412, 0.47478, 0.47948, 0.47968, 0.47686, 0.47908, 0.48012, 0.4785, 
-//446
-0.11024, 0.1311, 0.16212, 0.19612, 0.223, 0.23502, 0.24062, 0.24118, 0.24258, 0.24174, 0.24438, 0.24552, 0.24864, 0.25104, 0.25326, 0.25902, 0.26814, 0.28116,
-0.298, 0.31816, 0.33528, 0.34754, 0.35754, 0.36366, 0.3698, 0.37218, 0.37476, 0.37358, 0.37564, 0.37308, 0.37192, 0.37, 0.3706, 0.36914, 0.36978, 0.37288,
-0.36802, 0.36928, 0.36926, 0.36868, 0.36834, 0.36476, 0.36492, 0.36368, 0.3638, 0.36126, 0.36058, 0.36134, 0.36028, 0.36166, 0.36086, 0.36046, 0.35968, 0.35764,
-0.35706, 0.35546, 0.35636, 0.35516, 0.35628, 0.35658, 0.35578, 0.35452, 0.35496, 0.35502, 0.35512, 0.35516, 0.35538, 0.35422, 0.35568, 0.35672, 0.35434,
-0.35434, 0.35594, 0.35364, 0.3543, 0.3521, 0.35378, 0.35144, 0.3521, 0.35184, 0.35226, 
-//447
-0.10418, 0.12094, 0.14238, 0.16308, 0.17906, 0.18838, 0.1904, 0.18878, 0.18838, 0.18694, 0.18776, 0.18656, 0.18866, 0.1871, 0.18702, 0.1904, 0.19156, 0.19262,
-0.1941, 0.19528, 0.19608, 0.19474, 0.1956, 0.19784, 0.20428, 0.21762, 0.23414, 0.2528, 0.26516, 0.27378, 0.27882, 0.28028, 0.2796, 0.2785, 0.27698, 0.27578,
-0.27276, 0.27482, 0.27084, 0.26818, 0.26566, 0.26174, 0.25798, 0.25416, 0.24958, 0.24486, 0.24006, 0.23606, 0.23324, 0.22934, 0.22838, 0.224, 0.2212, 0.22064,
-0.21788, 0.21604, 0.21686, 0.21536, 0.21684, 0.21898, 0.21988, 0.21982, 0.22112, 0.2244, 0.22562, 0.2265, 0.22624, 0.22528, 0.22314, 0.22204, 0.2212, 0.22232,
-0.22464, 0.22704, 0.23012, 0.23204, 0.23172, 0.23334, 0.23364, 0.23368, 0.23406, 
-//448
-0.08884, 0.09966, 0.10978, 0.1209, 0.12538, 0.12952, 0.13016, 0.13032, 0.13122, 0.12938, 0.13028, 0.12878, 0.13046, 0.12922, 0.12882, 0.13024, 0.1312, 0.13316,
-0.13314, 0.13374, 0.13478, 0.13396, 0.1348, 0.13626, 0.14114, 0.15032, 0.16366, 0.177, 0.18834, 0.19408, 0.1959, 0.19542, 0.19566, 0.1947, 0.19432, 0.1923,
-0.18966, 0.19154, 0.19024, 0.18774, 0.18614, 0.18428, 0.18136, 0.17782, 0.17344, 0.17004, 0.16542, 0.16338, 0.16104, 0.1581, 0.1585, 0.15638, 0.15448, 0.15246,
-0.15046, 0.14886, 0.14868, 0.14698, 0.14942, 0.151, 0.15116, 0.15178, 0.15154, 0.15332, 0.15326, 0.15464, 0.1559, 0.15438, 0.15492, 0.1543, 0.15456, 0.15486,
-0.15694, 0.15792, 0.15984, 0.15952, 0.16226, 0.16284, 0.16498, 0.16472, 0.1677, 
-//449
-0.07066, 0.07264, 0.07538, 0.07802, 0.07826, 0.07814, 0.07854, 0.0777, 0.07662, 0.07694, 0.07924, 0.0755, 0.07974, 0.0779, 0.07866, 0.07708, 0.07758, 0.07764,
-0.07728, 0.0777, 0.0793, 0.07922, 0.08068, 0.0813, 0.0858, 0.09132, 0.09976, 0.10874, 0.11468, 0.11742, 0.11952, 0.12152, 0.12072, 0.11896, 0.11932, 0.11738,
-0.1133, 0.11442, 0.11322, 0.11284, 0.11094, 0.10946, 0.10752, 0.10578, 0.10408, 0.10138, 0.09864, 0.09598, 0.09432, 0.09316, 0.09434, 0.09232, 0.09382, 0.09192,
-0.0911, 0.0901, 0.08918, 0.08692, 0.08752, 0.0901, 0.09008, 0.08962, 0.09208, 0.09494, 0.09378, 0.09344, 0.0947, 0.0941, 0.09408, 0.09402, 0.0948, 0.09532,
-0.09716, 0.09892, 0.09948, 0.1001, 0.1014, 0.10242, 0.10382, 0.1022, 0.10408, 
-//450
-0.05148, 0.04792, 0.04784, 0.04716, 0.04704, 0.0461, 0.04598, 0.0467, 0.0466, 0.0461, 0.04702, 0.04724, 0.04738, 0.0483, 0.04736, 0.04578, 0.04586, 0.04598,
-0.0469, 0.04772, 0.04848, 0.0485, 0.04902, 0.0497, 0.05272, 0.0563, 0.0625, 0.06744, 0.07112, 0.07276, 0.07346, 0.0745, 0.0731, 0.07246, 0.07226, 0.07044,
-0.06684, 0.06942, 0.06892, 0.06788, 0.06694, 0.06528, 0.0639, 0.06084, 0.0621, 0.0604, 0.06052, 0.06008, 0.0597, 0.05954, 0.0583, 0.05772, 0.05778, 0.0575,
-0.0561, 0.05534, 0.05868, 0.05602, 0.05752, 0.05918, 0.05946, 0.0587, 0.05898, 0.0597, 0.0616, 0.06202, 0.06194, 0.06264, 0.06362, 0.0649, 0.0664, 0.06684,
-0.06728, 0.06716, 0.0677, 0.0681, 0.06924, 0.07008, 0.0714, 0.07216, 0.0732, 
-//451
-0.12244, 0.14948, 0.19106, 0.24416, 0.29136, 0.32234, 0.33602, 0.33876, 0.34044, 0.33918, 0.34278, 0.34618, 0.3487, 0.35014, 0.3544, 0.36176, 0.37664, 0.40062,
-0.43572, 0.47796, 0.52594, 0.57328, 0.61246, 0.6443, 0.67046, 0.68856, 0.70034, 0.70768, 0.71046, 0.7126, 0.71248, 0.71232, 0.71074, 0.71114, 0.7102, 0.70874,
-0.70704, 0.70444, 0.70308, 0.6992, 0.6947, 0.69144, 0.68566, 0.6774, 0.66788, 0.65856, 0.65076, 0.64284, 0.63662, 0.63168, 0.62802, 0.6251, 0.62068, 0.61694,
-0.6132, 0.61016, 0.6098, 0.60884, 0.61172, 0.61452, 0.619, 0.62314, 0.62632, 0.63112, 0.63542, 0.63638, 0.63932, 0.63784, 0.63634, 0.63616, 0.6351, 0.63358,
-0.63628, 0.6406, 0.64412, 0.65008, 0.65252, 0.65238, 0.65848, 0.65736, 0.6622, 
-//452
-0.1299, 0.15146, 0.1894, 0.2307, 0.26592, 0.28396, 0.29118, 0.29154, 0.29098, 0.2905, 0.29438, 0.29748, 0.30102, 0.3039, 0.3075, 0.3138, 0.32796, 0.35102,
-0.38238, 0.42186, 0.46442, 0.50322, 0.537, 0.56244, 0.57954, 0.59228, 0.5987, 0.60194, 0.604, 0.60356, 0.6036, 0.60344, 0.6038, 0.6034, 0.60172, 0.60124,
-0.59686, 0.59664, 0.59332, 0.59132, 0.58668, 0.58456, 0.5813, 0.57396, 0.56694, 0.55904, 0.55228, 0.54676, 0.54188, 0.5367, 0.5342, 0.52946, 0.52668, 0.52432,
-0.5213, 0.51726, 0.5165, 0.51314, 0.51452, 0.51722, 0.51716, 0.51726, 0.5182, 0.52226, 0.52492, 0.52772, 0.52516, 0.52448, 0.52276, 0.52426, 0.5226, 0.52332,
-0.52708, 0.5272, 0.53092, 0.53472, 0.53758, 0.54094, 0.54088, 0.54054, 0.54626, 
-//453
-0.11872, 0.13804, 0.1706, 0.20616, 0.233, 0.24884, 0.2528, 0.25374, 0.25484, 0.25474, 0.25588, 0.25732, 0.26112, 0.26164, 0.26528, 0.27314, 0.28494, 0.30454,
-0.33274, 0.369, 0.40668, 0.4416, 0.47006, 0.491, 0.50632, 0.51646, 0.52204, 0.52506, 0.52658, 0.52504, 0.5255, 0.52432, 0.52338, 0.52218, 0.5207, 0.52074,
-0.51718, 0.51888, 0.51788, 0.51464, 0.51256, 0.5097, 0.50794, 0.50426, 0.5005, 0.4933, 0.48808, 0.48472, 0.48028, 0.47664, 0.47702, 0.47274, 0.46964, 0.4679,
-0.46448, 0.4622, 0.46192, 0.46076, 0.46198, 0.4634, 0.46454, 0.46292, 0.46502, 0.46818, 0.4697, 0.47048, 0.4701, 0.46826, 0.4665, 0.46586, 0.46512, 0.46592,
-0.46796, 0.46836, 0.47106, 0.47316, 0.47498, 0.47592, 0.47532, 0.4743, 0.47804, 
-//454
-0.10218, 0.11484, 0.13184, 0.14686, 0.15828, 0.1609, 0.16274, 0.16236, 0.16356, 0.16558, 0.16878, 0.17266, 0.17844, 0.18338, 0.19052, 0.19736, 0.20722, 0.21808,
-0.23246, 0.24882, 0.26538, 0.28504, 0.30508, 0.32572, 0.34636, 0.36176, 0.37286, 0.37868, 0.38074, 0.3795, 0.3802, 0.38164, 0.38008, 0.3782, 0.3778, 0.37608,
-0.37094, 0.37134, 0.36824, 0.36474, 0.36036, 0.35564, 0.35328, 0.34748, 0.34398, 0.33948, 0.3368, 0.33342, 0.33086, 0.3296, 0.32746, 0.32444, 0.32312, 0.3231,
-0.32186, 0.32212, 0.32224, 0.322, 0.32342, 0.32584, 0.32556, 0.32448, 0.32534, 0.32756, 0.32868, 0.32786, 0.32732, 0.32606, 0.3251, 0.32526, 0.32616, 0.3273,
-0.32994, 0.3326, 0.33382, 0.33278, 0.3343, 0.33474, 0.33356, 0.33208, 0.33142, 
-//455
-0.0847, 0.0982, 0.11268, 0.12356, 0.1297, 0.13306, 0.1339, 0.1334, 0.1301, 0.12982, 0.1295, 0.13068, 0.13166, 0.13344, 0.13542, 0.1358, 0.13716, 0.13714,
-0.13886, 0.13856, 0.13972, 0.1409, 0.14188, 0.14642, 0.1555, 0.17184, 0.19816, 0.22814, 0.25516, 0.27364, 0.28568, 0.29152, 0.2938, 0.29506, 0.29382, 0.28852,
-0.28506, 0.2831, 0.27696, 0.27226, 0.26706, 0.2597, 0.25194, 0.2419, 0.23208, 0.22158, 0.21212, 0.20428, 0.19794, 0.19322, 0.18986, 0.18792, 0.18418, 0.18148,
-0.17874, 0.17618, 0.17614, 0.1743, 0.17714, 0.18056, 0.18236, 0.18376, 0.18918, 0.19272, 0.19736, 0.1999, 0.19944, 0.1964, 0.19594, 0.1949, 0.19456, 0.1965,
-0.19972, 0.20318, 0.20926, 0.21006, 0.2137, 0.21512, 0.21758, 0.22228, 0.22576, 
-//456
-0.07356, 0.07634, 0.08112, 0.08454, 0.08654, 0.08676, 0.08758, 0.08772, 0.08714, 0.08664, 0.08674, 0.08624, 0.08624, 0.08582, 0.08634, 0.08682, 0.08866, 0.0898,
-0.09052, 0.09158, 0.09156, 0.09246, 0.09262, 0.09538, 0.10152, 0.11404, 0.13458, 0.1586, 0.1785, 0.1926, 0.20022, 0.20356, 0.20412, 0.20284, 0.1997, 0.19664,
-0.19192, 0.1918, 0.18856, 0.184, 0.17866, 0.17406, 0.16882, 0.16114, 0.15352, 0.14716, 0.1421, 0.13692, 0.13392, 0.13174, 0.12898, 0.1279, 0.12486, 0.12326,
-0.12038, 0.1185, 0.11782, 0.1174, 0.11884, 0.12094, 0.12228, 0.12208, 0.1234, 0.1286, 0.12878, 0.13022, 0.13102, 0.12956, 0.12828, 0.1272, 0.12838, 0.12892,
-0.13188, 0.13428, 0.13798, 0.14014, 0.1412, 0.14314, 0.14416, 0.14596, 0.14834, 
-//457
-0.05376, 0.05556, 0.05286, 0.05428, 0.05578, 0.0544, 0.0545, 0.05458, 0.05418, 0.0539, 0.05562, 0.0553, 0.05568, 0.05696, 0.05602, 0.055, 0.05648, 0.05586,
-0.05544, 0.05684, 0.05858, 0.0612, 0.06084, 0.06282, 0.06738, 0.07672, 0.0926, 0.10972, 0.12586, 0.13622, 0.14324, 0.14702, 0.14608, 0.14508, 0.14692, 0.1352,
-0.13098, 0.12828, 0.12522, 0.12334, 0.1208, 0.11626, 0.11218, 0.10884, 0.10236, 0.0966, 0.0929, 0.0881, 0.08474, 0.0852, 0.08454, 0.08456, 0.08292, 0.08068,
-0.08194, 0.08, 0.07674, 0.07508, 0.0772, 0.08006, 0.08058, 0.08164, 0.082, 0.08562, 0.08702, 0.08812, 0.088, 0.08874, 0.08898, 0.08958, 0.09048, 0.09132,
-0.09352, 0.09468, 0.0976, 0.10012, 0.09904, 0.10194, 0.10328, 0.10334, 0.108, 
-//458
-0.11346, 0.13254, 0.16276, 0.19632, 0.22156, 0.23456, 0.23862, 0.23866, 0.23898, 0.2388, 0.2416, 0.24412, 0.24704, 0.24868, 0.25278, 0.25996, 0.273, 0.29556,
-0.3282, 0.3731, 0.4263, 0.48366, 0.54096, 0.59324, 0.6375, 0.67088, 0.69538, 0.7116, 0.7214, 0.72646, 0.72974, 0.73076, 0.72988, 0.72842, 0.72684, 0.72228,
-0.71708, 0.71722, 0.71206, 0.70314, 0.69758, 0.68768, 0.6786, 0.66422, 0.6528, 0.63666, 0.62292, 0.61378, 0.60472, 0.59998, 0.59444, 0.59028, 0.58578, 0.58126,
-0.57668, 0.57242, 0.5716, 0.57138, 0.57166, 0.5759, 0.57782, 0.58262, 0.5879, 0.59558, 0.60252, 0.60656, 0.60766, 0.60452, 0.60042, 0.59884, 0.59846, 0.60006,
-0.60762, 0.61122, 0.61554, 0.62164, 0.62854, 0.63032, 0.63414, 0.63656, 0.64442, 
-//459
-0.11844, 0.13586, 0.16466, 0.18396, 0.20028, 0.2068, 0.20874, 0.20996, 0.20808, 0.2085, 0.20972, 0.2136, 0.2154, 0.21816, 0.22134, 0.2282, 0.2422, 0.2655,
-0.2974, 0.33992, 0.38956, 0.4414, 0.4893, 0.53196, 0.5659, 0.5886, 0.6053, 0.61558, 0.62122, 0.624, 0.62536, 0.62572, 0.62314, 0.62066, 0.62114, 0.61858,
-0.61266, 0.61166, 0.6057, 0.60014, 0.59446, 0.58594, 0.57586, 0.56246, 0.54988, 0.53754, 0.52624, 0.51816, 0.51066, 0.50508, 0.49966, 0.49592, 0.48952, 0.48568,
-0.48134, 0.47548, 0.47214, 0.47248, 0.4772, 0.4805, 0.48228, 0.48438, 0.48688, 0.4919, 0.4954, 0.49854, 0.49974, 0.50004, 0.4974, 0.49656, 0.49698, 0.49768,
-0.50408, 0.50832, 0.51358, 0.51856, 0.52366, 0.52736, 0.52958, 0.53416, 0.5402, 
-//460
-0.10246, 0.11408, 0.1325, 0.146, 0.1588, 0.1631, 0.16546, 0.16618, 0.16684, 0.16858, 0.17218, 0.17708, 0.18158, 0.18718, 0.19612, 0.20518, 0.21816, 0.23232,
-0.2507, 0.27102, 0.29392, 0.32164, 0.35426, 0.39036, 0.42882, 0.4642, 0.49476, 0.5169, 0.53014, 0.53608, 0.54018, 0.54014, 0.5389, 0.53724, 0.53388, 0.52914,
-0.52238, 0.5182, 0.51068, 0.50376, 0.49904, 0.4889, 0.47906, 0.46778, 0.45918, 0.45022, 0.44436, 0.43794, 0.43324, 0.43024, 0.42812, 0.42758, 0.42516, 0.42386,
-0.42446, 0.42356, 0.42582, 0.42612, 0.43172, 0.43614, 0.4394, 0.44312, 0.44456, 0.45164, 0.45336, 0.4532, 0.4527, 0.44814, 0.44556, 0.44622, 0.44828, 0.45076,
-0.45778, 0.46136, 0.46556, 0.47328, 0.47508, 0.47546, 0.47908, 0.47842, 0.4762, 
-//461
-0.0809, 0.08758, 0.09926, 0.10838, 0.11344, 0.1153, 0.1151, 0.11508, 0.11398, 0.11512, 0.11674, 0.11738, 0.12126, 0.12108, 0.12378, 0.128, 0.13494, 0.148,
-0.16966, 0.19766, 0.23286, 0.27274, 0.30698, 0.33512, 0.35976, 0.37274, 0.38204, 0.3864, 0.38906, 0.3901, 0.3904, 0.39048, 0.38962, 0.3894, 0.38862, 0.38528,
-0.3817, 0.38242, 0.38032, 0.37764, 0.37306, 0.3667, 0.36092, 0.35112, 0.34434, 0.3351, 0.32736, 0.3214, 0.31644, 0.31268, 0.30942, 0.3057, 0.30158, 0.2994,
-0.29518, 0.29332, 0.29374, 0.2914, 0.29324, 0.29758, 0.2993, 0.30084, 0.30192, 0.30862, 0.31148, 0.31176, 0.31428, 0.3123, 0.31116, 0.30944, 0.3085, 0.31036,
-0.31408, 0.31652, 0.32282, 0.32558, 0.3256, 0.3268, 0.32926, 0.32838, 0.33404, 
-//462
-0.07544, 0.07762, 0.08566, 0.09062, 0.09258, 0.09284, 0.09266, 0.09264, 0.09242, 0.09222, 0.0942, 0.09484, 0.0964, 0.09554, 0.09572, 0.09598, 0.09672, 0.09714,
-0.09842, 0.10072, 0.1025, 0.10384, 0.10642, 0.10946, 0.11896, 0.13364, 0.1625, 0.20254, 0.2444, 0.2809, 0.30718, 0.3228, 0.33094, 0.33244, 0.33022, 0.32578,
-0.3152, 0.30864, 0.30126, 0.29154, 0.2822, 0.26876, 0.25644, 0.23992, 0.22354, 0.20786, 0.19504, 0.18442, 0.17808, 0.17312, 0.16948, 0.1667, 0.16284, 0.1592,
-0.15566, 0.15268, 0.15168, 0.15066, 0.1539, 0.1578, 0.1614, 0.16412, 0.1673, 0.17316, 0.17764, 0.18078, 0.18254, 0.18262, 0.18006, 0.17794, 0.17808, 0.1791,
-0.18302, 0.18782, 0.19294, 0.19812, 0.20202, 0.20492, 0.20986, 0.21252, 0.21806, 
-//463
-0.0483, 0.05072, 0.05392, 0.0543, 0.05624, 0.05748, 0.05682, 0.05616, 0.05658, 0.0555, 0.0551, 0.05524, 0.05638, 0.05638, 0.05742, 0.05796, 0.0587, 0.06012,
-0.0606, 0.06028, 0.06082, 0.06266, 0.06276, 0.06444, 0.07322, 0.08602, 0.10798, 0.1396, 0.17074, 0.19886, 0.21638, 0.22616, 0.23004, 0.22856, 0.2265, 0.22248,
-0.2138, 0.211, 0.20302, 0.19242, 0.1847, 0.17478, 0.1658, 0.15464, 0.14358, 0.13294, 0.12472, 0.1184, 0.11278, 0.1099, 0.1064, 0.10346, 0.10196, 0.09992,
-0.09922, 0.09732, 0.09832, 0.09684, 0.09798, 0.10096, 0.10238, 0.10326, 0.10624, 0.11048, 0.11412, 0.11562, 0.11698, 0.11644, 0.11684, 0.11544, 0.11516, 0.1163,
-0.11932, 0.12156, 0.12526, 0.12924, 0.13292, 0.13366, 0.13714, 0.14032, 0.14396, 
-//464
-0.09888, 0.10786, 0.11848, 0.1299, 0.1381, 0.14022, 0.14198, 0.14286, 0.1435, 0.14308, 0.14428, 0.14596, 0.1476, 0.14868, 0.14938, 0.15634, 0.16816, 0.18716,
-0.21506, 0.25482, 0.30496, 0.35864, 0.41796, 0.4731, 0.52118, 0.56128, 0.59138, 0.61322, 0.62788, 0.63256, 0.63958, 0.63894, 0.6387, 0.6384, 0.6355, 0.6318,
-0.62426, 0.6225, 0.6145, 0.60492, 0.5976, 0.58526, 0.56924, 0.55268, 0.53452, 0.51636, 0.50272, 0.49108, 0.48242, 0.47482, 0.4717, 0.46458, 0.4584, 0.45128,
-0.44492, 0.4421, 0.44068, 0.43866, 0.43628, 0.43984, 0.44484, 0.44734, 0.45318, 0.45784, 0.46616, 0.471, 0.47452, 0.47302, 0.47226, 0.4699, 0.47006, 0.47334,
-0.47894, 0.48588, 0.49492, 0.50268, 0.50956, 0.51488, 0.521, 0.52434, 0.53078, 
-//465
-0.09276, 0.09878, 0.10736, 0.11398, 0.1181, 0.11968, 0.1195, 0.12024, 0.12088, 0.12106, 0.12474, 0.12776, 0.1336, 0.13896, 0.14546, 0.15264, 0.1642, 0.17728,
-0.19386, 0.21352, 0.23598, 0.26246, 0.29724, 0.33948, 0.3897, 0.44292, 0.4935, 0.53308, 0.56048, 0.57856, 0.58592, 0.58544, 0.5837, 0.57902, 0.57356, 0.56476,
-0.5536, 0.54418, 0.53368, 0.52002, 0.5055, 0.49014, 0.47342, 0.45848, 0.44266, 0.43026, 0.4206, 0.41472, 0.41042, 0.406, 0.4026, 0.3997, 0.39796, 0.39314,
-0.39366, 0.39394, 0.39592, 0.40006, 0.40606, 0.41462, 0.42012, 0.4249, 0.43048, 0.43478, 0.43818, 0.43924, 0.43894, 0.43624, 0.43216, 0.4315, 0.4336, 0.43788,
-0.44712, 0.45574, 0.46738, 0.4747, 0.47884, 0.48612, 0.48898, 0.48658, 0.48766, 
-//466
-0.07552, 0.07582, 0.0785, 0.0792, 0.07994, 0.07944, 0.07912, 0.08116, 0.08108, 0.0809, 0.08132, 0.08442, 0.08504, 0.08466, 0.08462, 0.08692, 0.09368, 0.10374,
-0.12016, 0.14392, 0.17538, 0.21322, 0.25316, 0.29178, 0.32624, 0.35388, 0.3755, 0.38922, 0.39898, 0.40366, 0.40728, 0.40842, 0.40748, 0.4064, 0.40402, 0.40076,
-0.39684, 0.39654, 0.38898, 0.38364, 0.37536, 0.36776, 0.356, 0.341, 0.3275, 0.31218, 0.2998, 0.28986, 0.28304, 0.27608, 0.27114, 0.26704, 0.26306, 0.25824,
-0.25364, 0.25154, 0.2512, 0.25106, 0.25426, 0.25604, 0.26004, 0.26352, 0.26838, 0.27466, 0.2806, 0.285, 0.28574, 0.28502, 0.28302, 0.2821, 0.28128, 0.28386,
-0.28918, 0.29524, 0.3034, 0.3104, 0.3156, 0.3212, 0.3305, 0.33734, 0.34632, 
-//467
-0.055, 0.05616, 0.05758, 0.0598, 0.06024, 0.05892, 0.05916, 0.05884, 0.05872, 0.05724, 0.05688, 0.057, 0.05852, 0.05932, 0.05954, 0.06054, 0.0616, 0.06172,
-0.0619, 0.06224, 0.0623, 0.06302, 0.06496, 0.06752, 0.0753, 0.0897, 0.11766, 0.16018, 0.21296, 0.268, 0.31732, 0.35304, 0.37178, 0.37668, 0.37326, 0.36186,
-0.34444, 0.33064, 0.31348, 0.29396, 0.27538, 0.2536, 0.23418, 0.21206, 0.19372, 0.17718, 0.16424, 0.15584, 0.14934, 0.143, 0.13932, 0.13656, 0.133, 0.12826,
-0.12532, 0.12208, 0.12092, 0.12094, 0.12312, 0.12696, 0.12826, 0.1302, 0.1333, 0.13832, 0.14284, 0.14582, 0.14772, 0.14756, 0.14556, 0.14434, 0.14452, 0.14664,
-0.15104, 0.15532, 0.16118, 0.16656, 0.17172, 0.1748, 0.17906, 0.18266, 0.18934, 
-//468
-0.04432, 0.04134, 0.04114, 0.04318, 0.04334, 0.04342, 0.04494, 0.04408, 0.04492, 0.04436, 0.04416, 0.0439, 0.0445, 0.04492, 0.04486, 0.04556, 0.04652, 0.04678,
-0.04774, 0.04848, 0.04876, 0.04788, 0.04992, 0.05186, 0.05888, 0.07236, 0.09606, 0.12948, 0.16564, 0.19758, 0.21902, 0.23204, 0.23516, 0.23404, 0.22892, 0.2231,
-0.21246, 0.2076, 0.19894, 0.1888, 0.1789, 0.16832, 0.1561, 0.14428, 0.1336, 0.12384, 0.11552, 0.10906, 0.10484, 0.10144, 0.09972, 0.09678, 0.09552, 0.09442,
-0.09302, 0.0919, 0.09056, 0.09068, 0.09152, 0.09406, 0.09586, 0.0961, 0.10082, 0.10408, 0.10604, 0.1074, 0.10624, 0.10684, 0.10586, 0.10724, 0.10812, 0.1092,
-0.11322, 0.11564, 0.11934, 0.12222, 0.12546, 0.12676, 0.12968, 0.13402, 0.1364, 
-//469
-0.07556, 0.08078, 0.08692, 0.09386, 0.0978, 0.09758, 0.10014, 0.10004, 0.10062, 0.10112, 0.10348, 0.10476, 0.10712, 0.10724, 0.11076, 0.11148, 0.12086, 0.135,
-0.15806, 0.19228, 0.23546, 0.29014, 0.34964, 0.41296, 0.47302, 0.52726, 0.56958, 0.60192, 0.62832, 0.6438, 0.65336, 0.661, 0.66182, 0.65956, 0.66126, 0.65778,
-0.65096, 0.64298, 0.63262, 0.6213, 0.60576, 0.58886, 0.56852, 0.54242, 0.51626, 0.49232, 0.47076, 0.45124, 0.44238, 0.42904, 0.4225, 0.41824, 0.41268, 0.40614,
-0.40102, 0.39996, 0.39698, 0.39754, 0.40316, 0.41222, 0.4193, 0.42832, 0.43524, 0.447, 0.45782, 0.46492, 0.46716, 0.4675, 0.4675, 0.4584, 0.45686, 0.45714,
-0.4653, 0.47594, 0.48908, 0.4995, 0.51044, 0.51782, 0.5286, 0.53678, 0.55342, 
-//470
-0.07132, 0.07244, 0.07698, 0.07886, 0.07936, 0.08012, 0.08142, 0.0811, 0.08264, 0.08088, 0.08302, 0.08354, 0.08396, 0.08362, 0.08518, 0.08782, 0.0951, 0.107,
-0.1258, 0.15178, 0.18954, 0.23482, 0.2872, 0.34284, 0.39854, 0.4498, 0.4932, 0.52976, 0.55368, 0.56758, 0.5779, 0.58158, 0.5828, 0.58138, 0.57846, 0.57232,
-0.56218, 0.55728, 0.5483, 0.53588, 0.52324, 0.50752, 0.48872, 0.4653, 0.44264, 0.41964, 0.4002, 0.3854, 0.37482, 0.36492, 0.35992, 0.3537, 0.34626, 0.34062,
-0.33584, 0.33122, 0.33092, 0.32958, 0.33322, 0.3392, 0.34516, 0.34994, 0.35858, 0.36814, 0.37552, 0.3812, 0.38606, 0.3835, 0.38044, 0.37896, 0.37892, 0.3821,
-0.3876, 0.397, 0.40754, 0.41848, 0.42638, 0.43398, 0.44274, 0.45442, 0.46594, 
-//471
-0.13966, 0.16932, 0.2189, 0.28934, 0.36714, 0.43096, 0.46574, 0.47714, 0.48034, 0.48078, 0.48452, 0.48722, 0.49078, 0.493, 0.49606, 0.5028, 0.51476, 0.53466,
-0.56072, 0.5899, 0.61764, 0.63912, 0.65722, 0.66946, 0.67804, 0.6832, 0.68574, 0.6874, 0.68722, 0.68586, 0.6854, 0.68512, 0.68394, 0.6814, 0.68006, 0.67724,
-0.67466, 0.67198, 0.66888, 0.66446, 0.66016, 0.65368, 0.6447, 0.63638, 0.62754, 0.6181, 0.61, 0.60012, 0.59352, 0.58962, 0.58438, 0.57896, 0.57334, 0.5686,
-0.56598, 0.56102, 0.56016, 0.56148, 0.56212, 0.56728, 0.56794, 0.5697, 0.5738, 0.58006, 0.58424, 0.5875, 0.58986, 0.58884, 0.58558, 0.5853, 0.58472, 0.5851,
-0.5911, 0.59834, 0.608, 0.61178, 0.61496, 0.61958, 0.6231, 0.62322, 0.63028, 
-//472
-0.1384, 0.16702, 0.21542, 0.27972, 0.3437, 0.38956, 0.4117, 0.41798, 0.42058, 0.42056, 0.42284, 0.42536, 0.42804, 0.4301, 0.43284, 0.43896, 0.45138, 0.47078,
-0.49534, 0.52312, 0.54772, 0.56728, 0.58208, 0.59224, 0.5966, 0.59116, 0.59748, 0.60126, 0.60126, 0.59994, 0.59922, 0.59918, 0.59764, 0.59538, 0.5946, 0.5918,
-0.5888, 0.58764, 0.58454, 0.58136, 0.57756, 0.57252, 0.5662, 0.55976, 0.5522, 0.54114, 0.53482, 0.5265, 0.5213, 0.51688, 0.51322, 0.50872, 0.50434, 0.50092,
-0.4975, 0.49516, 0.4934, 0.49368, 0.49476, 0.49862, 0.49998, 0.5015, 0.50318, 0.50786, 0.5102, 0.5105, 0.5119, 0.51108, 0.50688, 0.50566, 0.50486, 0.50502,
-0.511, 0.51082, 0.51696, 0.51926, 0.5213, 0.52414, 0.5259, 0.52836, 0.53314, 
-//473
-0.13318, 0.15966, 0.20724, 0.26546, 0.32132, 0.35638, 0.37274, 0.37878, 0.37886, 0.37876, 0.38098, 0.385, 0.38786, 0.38962, 0.39294, 0.3989, 0.41006, 0.42616,
-0.449, 0.47184, 0.49156, 0.50684, 0.51702, 0.52468, 0.52928, 0.53158, 0.53498, 0.53346, 0.53318, 0.53182, 0.53122, 0.53088, 0.52934, 0.52768, 0.52516, 0.52456,
-0.5217, 0.52224, 0.51972, 0.5155, 0.51048, 0.50766, 0.50182, 0.49458, 0.48724, 0.47964, 0.47264, 0.4676, 0.46206, 0.45714, 0.4534, 0.4494, 0.4457, 0.4425,
-0.43806, 0.4347, 0.43504, 0.43294, 0.43564, 0.4372, 0.43854, 0.44102, 0.442, 0.44654, 0.4476, 0.45004, 0.45, 0.44796, 0.4443, 0.44428, 0.4436, 0.44494, 0.44736,
-0.4514, 0.45474, 0.45674, 0.46232, 0.46176, 0.46632, 0.46608, 0.47204, 
-//474
-0.12104, 0.14252, 0.17918, 0.21824, 0.24916, 0.26754, 0.27422, 0.27502, 0.2757, 0.2756, 0.27794, 0.28002, 0.282, 0.28214, 0.28538, 0.29026, 0.29762, 0.30852,
-0.32332, 0.3401, 0.35602, 0.36848, 0.3775, 0.3847, 0.3896, 0.39038, 0.3919, 0.39238, 0.3918, 0.39094, 0.3902, 0.38992, 0.38866, 0.38888, 0.38728, 0.3846,
-0.38172, 0.38246, 0.3809, 0.37828, 0.37426, 0.3721, 0.36848, 0.36456, 0.35926, 0.35402, 0.3482, 0.34434, 0.34108, 0.33798, 0.33656, 0.33292, 0.33036, 0.32796,
-0.32562, 0.32506, 0.32386, 0.3214, 0.32258, 0.32436, 0.32486, 0.3258, 0.32792, 0.33068, 0.33286, 0.33314, 0.33402, 0.33312, 0.33346, 0.33264, 0.3338, 0.33468,
-0.33598, 0.33566, 0.33702, 0.33846, 0.33998, 0.33874, 0.33978, 0.34088, 0.34278, 
-//475
-0.1129, 0.12678, 0.14996, 0.16892, 0.18144, 0.18542, 0.18716, 0.1876, 0.1884, 0.18884, 0.19034, 0.19102, 0.19382, 0.19402, 0.1953, 0.1985, 0.2052, 0.2144,
-0.22604, 0.24024, 0.25256, 0.2619, 0.26702, 0.27094, 0.27416, 0.27584, 0.27654, 0.27604, 0.27526, 0.27458, 0.27472, 0.27362, 0.27152, 0.26984, 0.26908, 0.26682,
-0.26418, 0.26528, 0.26344, 0.26068, 0.25816, 0.25546, 0.2529, 0.24972, 0.246, 0.24118, 0.23806, 0.23488, 0.2315, 0.22898, 0.2267, 0.22504, 0.2222, 0.21936,
-0.21758, 0.2154, 0.21484, 0.21218, 0.2129, 0.21448, 0.21482, 0.21548, 0.21546, 0.21816, 0.2192, 0.21922, 0.21772, 0.21718, 0.21602, 0.21506, 0.21464, 0.2158,
-0.21784, 0.21994, 0.2232, 0.22458, 0.22586, 0.22644, 0.22798, 0.22794, 0.23004, 
-//476
-0.09338, 0.10374, 0.11692, 0.12656, 0.13262, 0.1359, 0.13684, 0.13534, 0.13612, 0.1354, 0.13582, 0.1357, 0.13638, 0.13694, 0.13696, 0.13774, 0.13874, 0.13886,
-0.14002, 0.14094, 0.14148, 0.14176, 0.1427, 0.14438, 0.14986, 0.15862, 0.17026, 0.1808, 0.18844, 0.19224, 0.19418, 0.1943, 0.19346, 0.191, 0.18992, 0.18868,
-0.186, 0.18488, 0.18264, 0.181, 0.17802, 0.17388, 0.16952, 0.16444, 0.15916, 0.1544, 0.1497, 0.14556, 0.14318, 0.14048, 0.13894, 0.13666, 0.1351, 0.13326,
-0.13182, 0.13046, 0.12942, 0.12802, 0.1294, 0.13062, 0.13152, 0.13172, 0.1334, 0.13538, 0.13686, 0.13738, 0.13734, 0.13704, 0.13588, 0.1367, 0.13608, 0.13748,
-0.14002, 0.14058, 0.14334, 0.14506, 0.14694, 0.147, 0.15008, 0.14904, 0.15152, 
-//477
-0.06942, 0.0742, 0.07872, 0.08286, 0.08444, 0.08474, 0.08512, 0.0843, 0.08452, 0.08368, 0.08466, 0.08326, 0.08472, 0.08444, 0.08464, 0.08514, 0.08588, 0.0859,
-0.08716, 0.08828, 0.08886, 0.089, 0.08976, 0.09062, 0.09456, 0.09976, 0.10716, 0.11414, 0.11842, 0.1214, 0.12282, 0.12214, 0.12188, 0.12096, 0.1206, 0.11884,
-0.1162, 0.11598, 0.11532, 0.11312, 0.11084, 0.10896, 0.1067, 0.10308, 0.10044, 0.09656, 0.09358, 0.09178, 0.09012, 0.08856, 0.08844, 0.08714, 0.08612, 0.08522,
-0.08468, 0.0843, 0.08394, 0.08304, 0.0835, 0.08492, 0.08518, 0.08572, 0.08696, 0.08792, 0.08896, 0.08948, 0.09072, 0.09104, 0.0909, 0.09088, 0.09214, 0.09252,
-0.09348, 0.0945, 0.0959, 0.09754, 0.0987, 0.09876, 0.10006, 0.1003, 0.10272, 
-//478
-0.04976, 0.04828, 0.04836, 0.04784, 0.04842, 0.0478, 0.04816, 0.0486, 0.04866, 0.04846, 0.0485, 0.04884, 0.04914, 0.04958, 0.04924, 0.04938, 0.04984, 0.04998,
-0.04988, 0.04986, 0.04994, 0.05016, 0.05008, 0.05108, 0.05338, 0.05782, 0.0632, 0.0679, 0.07098, 0.07266, 0.07292, 0.07218, 0.07112, 0.0706, 0.06932, 0.0673,
-0.06578, 0.06638, 0.06448, 0.06322, 0.0615, 0.06006, 0.05776, 0.05688, 0.0553, 0.05418, 0.05278, 0.05208, 0.05212, 0.05154, 0.05188, 0.05092, 0.0511, 0.0512,
-0.05088, 0.05036, 0.04986, 0.04904, 0.05026, 0.0512, 0.05198, 0.05204, 0.05362, 0.05456, 0.05604, 0.05556, 0.0561, 0.05678, 0.05702, 0.0577, 0.05944, 0.06068,
-0.06164, 0.0623, 0.06446, 0.06538, 0.06592, 0.06532, 0.06776, 0.06704, 0.06978, 
-//479
-0.13696, 0.16268, 0.20944, 0.26894, 0.32658, 0.36514, 0.38262, 0.38858, 0.38922, 0.39004, 0.39282, 0.39584, 0.40022, 0.403, 0.40688, 0.41426, 0.43026, 0.455,
-0.48926, 0.53164, 0.57442, 0.61476, 0.6497, 0.679, 0.69938, 0.71376, 0.72298, 0.72712, 0.72902, 0.72814, 0.72858, 0.72612, 0.72328, 0.71966, 0.71494, 0.70994,
-0.70224, 0.69694, 0.68822, 0.67992, 0.66792, 0.65574, 0.64176, 0.62486, 0.60696, 0.58942, 0.57492, 0.56218, 0.55238, 0.54426, 0.53802, 0.53238, 0.52706,
-0.51944, 0.5144, 0.51026, 0.50886, 0.50796, 0.50828, 0.51208, 0.51572, 0.51952, 0.5234, 0.52984, 0.53532, 0.53898, 0.54184, 0.5406, 0.53736, 0.53774, 0.53496,
-0.53708, 0.54402, 0.5473, 0.5547, 0.56066, 0.56784, 0.57142, 0.57578, 0.57718, 0.58608, 
-//480
-0.13916, 0.16198, 0.20494, 0.2506, 0.29134, 0.3137, 0.32454, 0.3251, 0.32566, 0.3254, 0.32848, 0.33078, 0.33456, 0.33692, 0.34048, 0.34756, 0.36228, 0.38554,
-0.41788, 0.45838, 0.50014, 0.53948, 0.57382, 0.60012, 0.62028, 0.6317, 0.63886, 0.64166, 0.6449, 0.64452, 0.64358, 0.6425, 0.6401, 0.6364, 0.63328, 0.62824,
-0.62356, 0.61922, 0.61264, 0.60526, 0.59642, 0.58642, 0.57408, 0.55834, 0.54258, 0.5261, 0.5112, 0.4989, 0.48802, 0.48022, 0.47628, 0.46976, 0.4634, 0.45806,
-0.45276, 0.44826, 0.44834, 0.4472, 0.44936, 0.4524, 0.45594, 0.46212, 0.4671, 0.47328, 0.47846, 0.48282, 0.4832, 0.47862, 0.47574, 0.47504, 0.47256, 0.47468,
-0.48088, 0.48436, 0.49156, 0.49568, 0.50266, 0.50676, 0.51182, 0.51552, 0.52082, 
-//481
-0.13378, 0.15418, 0.18872, 0.22658, 0.25844, 0.2739, 0.27952, 0.2801, 0.28122, 0.28138, 0.28336, 0.28654, 0.28962, 0.2923, 0.2948, 0.3015, 0.31478, 0.33596,
-0.36502, 0.40136, 0.43888, 0.47272, 0.49794, 0.51858, 0.53198, 0.5401, 0.5449, 0.54564, 0.54552, 0.54574, 0.5453, 0.5418, 0.5395, 0.53752, 0.53402, 0.52934,
-0.52422, 0.52074, 0.51494, 0.50904, 0.5008, 0.49114, 0.48094, 0.46692, 0.45274, 0.43712, 0.4244, 0.41348, 0.40524, 0.39818, 0.39324, 0.38696, 0.38226, 0.3768,
-0.3716, 0.36732, 0.3664, 0.36556, 0.36772, 0.37128, 0.3739, 0.37708, 0.38064, 0.3878, 0.3918, 0.39258, 0.39376, 0.39264, 0.38936, 0.38642, 0.38632, 0.38646,
-0.39122, 0.39716, 0.40268, 0.4076, 0.41238, 0.41648, 0.42066, 0.42426, 0.4291, 
-//482
-0.12038, 0.13796, 0.16186, 0.18496, 0.20114, 0.2092, 0.21154, 0.2111, 0.211, 0.21108, 0.21232, 0.21456, 0.21666, 0.21782, 0.2203, 0.22516, 0.23408, 0.24816,
-0.26812, 0.29344, 0.32124, 0.3461, 0.3667, 0.38278, 0.39452, 0.40104, 0.40364, 0.40474, 0.4052, 0.40592, 0.40536, 0.4048, 0.40264, 0.4008, 0.3989, 0.3964,
-0.39068, 0.38968, 0.38546, 0.38008, 0.37482, 0.36768, 0.3601, 0.35016, 0.34, 0.32856, 0.3182, 0.31026, 0.30282, 0.29758, 0.29304, 0.2897, 0.28502, 0.2812,
-0.27752, 0.27406, 0.27308, 0.27116, 0.27292, 0.27554, 0.27694, 0.27992, 0.28222, 0.287, 0.28964, 0.29296, 0.29386, 0.2915, 0.29074, 0.28888, 0.28864, 0.29014,
-0.29306, 0.2958, 0.30174, 0.30512, 0.3089, 0.31146, 0.31476, 0.3165, 0.31968, 
-//483
-0.09212, 0.09996, 0.10902, 0.11782, 0.1219, 0.12318, 0.12346, 0.12348, 0.12338, 0.12538, 0.12788, 0.13144, 0.13578, 0.13876, 0.14512, 0.15076, 0.15944, 0.16946,
-0.18106, 0.19318, 0.20578, 0.21948, 0.23228, 0.2476, 0.2619, 0.27126, 0.27828, 0.28282, 0.28416, 0.2842, 0.28386, 0.28314, 0.28192, 0.27984, 0.2787, 0.27484,
-0.26914, 0.26824, 0.2645, 0.26032, 0.25478, 0.24906, 0.24332, 0.23626, 0.22842, 0.21874, 0.21164, 0.206, 0.20142, 0.19658, 0.19366, 0.19046, 0.18574, 0.18298,
-0.18032, 0.1774, 0.17534, 0.17306, 0.1748, 0.17772, 0.1793, 0.18022, 0.18208, 0.18608, 0.1875, 0.18908, 0.19062, 0.18924, 0.1893, 0.18874, 0.18818, 0.1889,
-0.19102, 0.1939, 0.19678, 0.19972, 0.20302, 0.20478, 0.20802, 0.20872, 0.2118, 
-//484
-0.0801, 0.08576, 0.09264, 0.09812, 0.10118, 0.10208, 0.1028, 0.10254, 0.1014, 0.101, 0.10138, 0.10186, 0.10314, 0.10304, 0.1033, 0.10496, 0.10624, 0.1081,
-0.10956, 0.11202, 0.11326, 0.11438, 0.11536, 0.11952, 0.12602, 0.13816, 0.1582, 0.17866, 0.1965, 0.20756, 0.21294, 0.21536, 0.2142, 0.21254, 0.20964, 0.20578,
-0.20044, 0.1969, 0.19168, 0.18582, 0.18014, 0.17206, 0.1642, 0.15458, 0.14582, 0.13796, 0.13194, 0.12722, 0.12302, 0.1203, 0.11832, 0.11616, 0.11396, 0.11228,
-0.10902, 0.10782, 0.10722, 0.10618, 0.10812, 0.10938, 0.11094, 0.11154, 0.11326, 0.11606, 0.1176, 0.1194, 0.12026, 0.12082, 0.11958, 0.11866, 0.11872, 0.1199,
-0.1223, 0.1239, 0.12672, 0.13066, 0.1321, 0.1336, 0.13672, 0.13632, 0.13946, 
-//485
-0.05402, 0.0553, 0.05866, 0.05952, 0.05978, 0.0598, 0.06048, 0.0617, 0.06058, 0.06012, 0.06158, 0.06136, 0.0626, 0.06336, 0.06364, 0.06368, 0.0654, 0.0657,
-0.06608, 0.06718, 0.06798, 0.06806, 0.06844, 0.07142, 0.07566, 0.08412, 0.09818, 0.1105, 0.12524, 0.13144, 0.13664, 0.13806, 0.13822, 0.1352, 0.1332, 0.13058,
-0.12518, 0.1251, 0.12114, 0.11612, 0.11258, 0.10698, 0.10206, 0.09472, 0.08822, 0.08282, 0.07846, 0.07522, 0.07186, 0.0696, 0.0686, 0.0668, 0.06504, 0.06372,
-0.06362, 0.06354, 0.06304, 0.06212, 0.06364, 0.0661, 0.06764, 0.06904, 0.07008, 0.07266, 0.07478, 0.07578, 0.07696, 0.0765, 0.07628, 0.07716, 0.0788, 0.07978,
-0.0822, 0.08496, 0.08768, 0.09038, 0.0926, 0.09442, 0.096, 0.09604, 0.09834, 
-//486
-0.11154, 0.1325, 0.16286, 0.19752, 0.2245, 0.23916, 0.2446, 0.24532, 0.24368, 0.24572, 0.24824, 0.25172, 0.25538, 0.25676, 0.2608, 0.26812, 0.28108, 0.30282,
-0.33502, 0.37882, 0.42824, 0.48116, 0.5305, 0.57326, 0.60768, 0.63284, 0.64872, 0.65756, 0.66296, 0.6648, 0.66442, 0.6627, 0.65948, 0.65554, 0.64928, 0.64058,
-0.63094, 0.62244, 0.6126, 0.59954, 0.58638, 0.56982, 0.55158, 0.52872, 0.50684, 0.48632, 0.46734, 0.45132, 0.4381, 0.43036, 0.42362, 0.41696, 0.41052, 0.40542,
-0.4, 0.39618, 0.39628, 0.39634, 0.4003, 0.40714, 0.4125, 0.4177, 0.42452, 0.43392, 0.4413, 0.44308, 0.44514, 0.44202, 0.43876, 0.43814, 0.437, 0.44018, 0.44596,
-0.45472, 0.46218, 0.47044, 0.47766, 0.48016, 0.48578, 0.48832, 0.49716, 
-//487
-0.12096, 0.13904, 0.16472, 0.19018, 0.20668, 0.214, 0.21634, 0.21572, 0.21534, 0.21556, 0.2182, 0.22032, 0.22314, 0.2266, 0.2309, 0.23798, 0.249, 0.26974,
-0.3012, 0.34098, 0.38512, 0.43604, 0.48238, 0.51794, 0.5422, 0.55852, 0.5705, 0.57604, 0.57892, 0.57928, 0.57864, 0.5765, 0.57296, 0.56682, 0.56222, 0.55494,
-0.545, 0.53816, 0.52752, 0.51644, 0.50354, 0.48818, 0.47008, 0.4503, 0.43074, 0.4119, 0.39526, 0.38154, 0.37012, 0.3615, 0.35542, 0.34858, 0.34294, 0.33638,
-0.33302, 0.32868, 0.32698, 0.32602, 0.32892, 0.33382, 0.33848, 0.34238, 0.3462, 0.35372, 0.35996, 0.36396, 0.36474, 0.36168, 0.35856, 0.35684, 0.3557, 0.35608,
-0.36222, 0.36856, 0.37528, 0.38158, 0.3889, 0.3936, 0.39798, 0.40456, 0.41312, 
-//488
-0.10328, 0.11202, 0.12816, 0.1389, 0.1461, 0.14746, 0.14748, 0.14744, 0.14794, 0.1477, 0.14912, 0.15016, 0.15256, 0.15214, 0.1541, 0.15956, 0.16908, 0.1857,
-0.20992, 0.24226, 0.27878, 0.31444, 0.34614, 0.3725, 0.39184, 0.40492, 0.41256, 0.41566, 0.41798, 0.41702, 0.41662, 0.41458, 0.41286, 0.41034, 0.40778, 0.40266,
-0.39704, 0.3923, 0.38562, 0.3795, 0.3697, 0.36004, 0.34834, 0.3347, 0.31958, 0.30554, 0.29392, 0.28378, 0.275, 0.26886, 0.26572, 0.25978, 0.25484, 0.2508,
-0.24554, 0.24152, 0.2406, 0.23792, 0.24116, 0.24318, 0.24512, 0.24772, 0.25044, 0.25506, 0.2595, 0.26266, 0.26348, 0.26294, 0.25906, 0.25866, 0.25828, 0.25898,
-0.26252, 0.26634, 0.27274, 0.2783, 0.28272, 0.28706, 0.29194, 0.2953, 0.2973, 
-//489
-0.07158, 0.07622, 0.08092, 0.0844, 0.08702, 0.08838, 0.08864, 0.08902, 0.08936, 0.0915, 0.0935, 0.09416, 0.09806, 0.10212, 0.10646, 0.11222, 0.1203, 0.13046,
-0.14206, 0.15658, 0.17304, 0.19092, 0.21142, 0.2355, 0.25934, 0.28178, 0.29438, 0.30574, 0.30936, 0.30984, 0.30872, 0.30626, 0.30182, 0.29852, 0.29232, 0.28472,
-0.27594, 0.27108, 0.2627, 0.25276, 0.24338, 0.23216, 0.22174, 0.20934, 0.1999, 0.19262, 0.1869, 0.18436, 0.18078, 0.17876, 0.17766, 0.1761, 0.17414, 0.17244,
-0.17198, 0.17276, 0.17426, 0.17572, 0.17984, 0.18336, 0.18592, 0.1884, 0.19188, 0.1946, 0.1959, 0.19712, 0.1976, 0.19464, 0.19332, 0.19352, 0.1954, 0.1985,
-0.20392, 0.20896, 0.21332, 0.21864, 0.2212, 0.2233, 0.2244, 0.22322, 0.22392, 
-//490
-0.0604, 0.06156, 0.06472, 0.06732, 0.0689, 0.06964, 0.07126, 0.07096, 0.07144, 0.07144, 0.07148, 0.07316, 0.07362, 0.07468, 0.0746, 0.0748, 0.07642, 0.07856,
-0.07908, 0.08072, 0.08244, 0.08418, 0.08548, 0.0894, 0.09642, 0.10944, 0.1327, 0.16434, 0.19694, 0.22264, 0.2382, 0.24562, 0.24636, 0.24322, 0.23694, 0.22932,
-0.2187, 0.2114, 0.20086, 0.19148, 0.179, 0.16562, 0.1524, 0.13816, 0.12512, 0.1129, 0.1035, 0.09628, 0.09186, 0.08834, 0.08542, 0.08278, 0.08154, 0.08078,
-0.07892, 0.07782, 0.07814, 0.07724, 0.08062, 0.08316, 0.08512, 0.0852, 0.09014, 0.09368, 0.09666, 0.0984, 0.09984, 0.09968, 0.09882, 0.09928, 0.10042, 0.10358,
-0.10632, 0.11232, 0.11722, 0.12064, 0.12554, 0.12804, 0.1305, 0.1331, 0.13624, 
-//491
-0.10462, 0.1167, 0.13306, 0.14386, 0.15274, 0.15542, 0.15614, 0.15558, 0.15618, 0.15704, 0.15908, 0.16182, 0.16466, 0.1662, 0.16894, 0.1747, 0.18586, 0.2045,
-0.23376, 0.27424, 0.32262, 0.37758, 0.43184, 0.48366, 0.52704, 0.55984, 0.58204, 0.59666, 0.60468, 0.60638, 0.6063, 0.60434, 0.59888, 0.58952, 0.58206, 0.5704,
-0.55508, 0.54454, 0.52898, 0.5126, 0.49446, 0.47374, 0.45168, 0.42626, 0.4025, 0.37958, 0.36088, 0.34476, 0.33374, 0.32496, 0.31818, 0.31244, 0.3057, 0.29958,
-0.29412, 0.2906, 0.28832, 0.28864, 0.29156, 0.29574, 0.30126, 0.30612, 0.313, 0.3198, 0.32746, 0.33158, 0.3315, 0.32998, 0.32588, 0.32204, 0.3215, 0.32346,
-0.32994, 0.3371, 0.34564, 0.35176, 0.359, 0.3652, 0.37086, 0.37854, 0.3892, 
-//492
-0.07384, 0.0817, 0.0896, 0.09816, 0.10206, 0.10332, 0.10534, 0.10556, 0.10624, 0.10666, 0.1074, 0.10868, 0.11032, 0.1116, 0.11296, 0.1169, 0.12428, 0.1369,
-0.15694, 0.18538, 0.22502, 0.2678, 0.31188, 0.35338, 0.3876, 0.41152, 0.42706, 0.43496, 0.4403, 0.44098, 0.44018, 0.43764, 0.43534, 0.429, 0.4226, 0.41548,
-0.40542, 0.39728, 0.38676, 0.37576, 0.3633, 0.34808, 0.33182, 0.31464, 0.29776, 0.27934, 0.26554, 0.25394, 0.24554, 0.23778, 0.2331, 0.2278, 0.22338, 0.2179,
-0.21464, 0.21198, 0.21138, 0.2109, 0.21304, 0.21646, 0.22074, 0.22384, 0.22772, 0.23282, 0.23754, 0.24126, 0.24336, 0.24214, 0.23972, 0.23704, 0.23468, 0.2374,
-0.24284, 0.2456, 0.2537, 0.25874, 0.26318, 0.2663, 0.26964, 0.27614, 0.28304, 
-//493
-0.05516, 0.05536, 0.05604, 0.05752, 0.05728, 0.05786, 0.05836, 0.0588, 0.0612, 0.06182, 0.06366, 0.06512, 0.06838, 0.07076, 0.07314, 0.07846, 0.08448, 0.09322,
-0.10294, 0.1151, 0.13034, 0.14924, 0.17348, 0.20326, 0.23964, 0.2748, 0.30426, 0.32602, 0.33798, 0.3424, 0.34274, 0.33792, 0.33074, 0.32134, 0.31286, 0.29924,
-0.28396, 0.27624, 0.26426, 0.24812, 0.23434, 0.21928, 0.20586, 0.19072, 0.18006, 0.17136, 0.1658, 0.16032, 0.15754, 0.15426, 0.15372, 0.15198, 0.1503, 0.1504,
-0.15044, 0.15022, 0.15232, 0.15384, 0.1594, 0.16414, 0.16738, 0.16908, 0.17386, 0.17748, 0.18052, 0.1798, 0.18046, 0.17742, 0.17572, 0.1766, 0.17826, 0.18128,
-0.18728, 0.19522, 0.20346, 0.20906, 0.21396, 0.21878, 0.22036, 0.21752, 0.21896, 
-//494
-0.05074, 0.04886, 0.05094, 0.0511, 0.05244, 0.05162, 0.05112, 0.05102, 0.0501, 0.0502, 0.04974, 0.0507, 0.05202, 0.05208, 0.05338, 0.05348, 0.05408, 0.0551,
-0.0566, 0.05696, 0.059, 0.05914, 0.06028, 0.06138, 0.06764, 0.07926, 0.10318, 0.1406, 0.18632, 0.23108, 0.26434, 0.28254, 0.28704, 0.28098, 0.26896, 0.25258,
-0.2333, 0.21758, 0.20004, 0.18248, 0.16556, 0.14826, 0.13208, 0.1168, 0.10332, 0.09164, 0.08488, 0.07862, 0.07534, 0.07246, 0.07068, 0.0702, 0.06888, 0.06704,
-0.06698, 0.06606, 0.06494, 0.06342, 0.066, 0.06934, 0.07066, 0.07202, 0.07378, 0.07944, 0.08128, 0.08252, 0.08344, 0.0842, 0.0847, 0.08414, 0.08482, 0.08722,
-0.09024, 0.09362, 0.09872, 0.10264, 0.10652, 0.1112, 0.11304, 0.11556, 0.11836, 
-//495
-0.06442, 0.0654, 0.0681, 0.06874, 0.0701, 0.07076, 0.0709, 0.07006, 0.07012, 0.07008, 0.07048, 0.0712, 0.072, 0.07204, 0.07362, 0.07722, 0.08242, 0.09196,
-0.10674, 0.129, 0.16064, 0.19954, 0.24586, 0.29662, 0.3454, 0.38728, 0.41966, 0.44106, 0.4546, 0.46058, 0.4636, 0.4642, 0.45966, 0.45202, 0.44366, 0.43162,
-0.41676, 0.4041, 0.3868, 0.37038, 0.35118, 0.32976, 0.30716, 0.2819, 0.26034, 0.23884, 0.22384, 0.21176, 0.20314, 0.19698, 0.19274, 0.1887, 0.18406, 0.1802,
-0.1764, 0.17342, 0.17192, 0.17036, 0.17338, 0.17592, 0.17912, 0.18356, 0.18774, 0.19578, 0.20158, 0.20532, 0.20658, 0.20664, 0.20332, 0.20078, 0.20056, 0.20214,
-0.20762, 0.21362, 0.22076, 0.22992, 0.23766, 0.24374, 0.24836, 0.25568, 0.26574, 
-//496
-0.0517, 0.0518, 0.05116, 0.05172, 0.05222, 0.05184, 0.05158, 0.05162, 0.05256, 0.05298, 0.05442, 0.05496, 0.05832, 0.0602, 0.0635, 0.06726, 0.0721, 0.0788,
-0.08746, 0.09868, 0.11232, 0.12968, 0.15238, 0.18212, 0.21974, 0.262, 0.3009, 0.33192, 0.35274, 0.36388, 0.36584, 0.3625, 0.35554, 0.34494, 0.33304, 0.31772,
-0.30108, 0.28746, 0.27148, 0.2551, 0.23782, 0.22128, 0.20532, 0.18942, 0.17748, 0.16756, 0.16118, 0.15614, 0.15284, 0.15114, 0.14834, 0.14658, 0.14452, 0.14298,
-0.1426, 0.14348, 0.14636, 0.15002, 0.15486, 0.16102, 0.16562, 0.16984, 0.17316, 0.17886, 0.18162, 0.18166, 0.18142, 0.1793, 0.1762, 0.17584, 0.17826, 0.18318,
-0.19206, 0.1992, 0.20748, 0.21658, 0.22366, 0.22812, 0.22886, 0.22962, 0.23108, 
-//497
-0.14308, 0.17626, 0.23216, 0.31292, 0.41236, 0.50734, 0.5694, 0.5961, 0.6051, 0.60748, 0.61116, 0.61386, 0.61726, 0.62062, 0.62028, 0.6254, 0.63362, 0.64488,
-0.66096, 0.67662, 0.689, 0.6956, 0.70376, 0.70802, 0.71244, 0.71304, 0.71474, 0.71466, 0.71524, 0.71454, 0.7139, 0.71272, 0.71082, 0.71038, 0.70912, 0.70596,
-0.7038, 0.70222, 0.70128, 0.6968, 0.6936, 0.68748, 0.68354, 0.67472, 0.66826, 0.65964, 0.65254, 0.64726, 0.6402, 0.63682, 0.63266, 0.6296, 0.62664, 0.62306,
-0.619, 0.6149, 0.61456, 0.61206, 0.61164, 0.61462, 0.61542, 0.61816, 0.62014, 0.62366, 0.62626, 0.62774, 0.62912, 0.6283, 0.62568, 0.62512, 0.62534, 0.6255,
-0.62976, 0.62992, 0.63562, 0.63938, 0.64126, 0.6449, 0.64432, 0.64384, 0.6477, 
-//498
-0.12972, 0.15892, 0.21156, 0.28804, 0.37904, 0.45996, 0.51006, 0.52828, 0.53474, 0.53644, 0.53796, 0.5396, 0.54014, 0.54206, 0.54316, 0.5459, 0.55264, 0.56424,
-0.57774, 0.5912, 0.60326, 0.61044, 0.61696, 0.62132, 0.62426, 0.62534, 0.6255, 0.6254, 0.62402, 0.62206, 0.62136, 0.6188, 0.61846, 0.61638, 0.61566, 0.61358,
-0.60998, 0.6104, 0.60774, 0.60444, 0.60174, 0.59846, 0.595, 0.58942, 0.58266, 0.57654, 0.57138, 0.56514, 0.56086, 0.55714, 0.55658, 0.55178, 0.5492, 0.54722,
-0.54444, 0.54228, 0.54126, 0.5397, 0.54024, 0.54306, 0.54256, 0.54338, 0.54464, 0.5459, 0.54836, 0.54782, 0.55024, 0.54846, 0.5455, 0.5452, 0.54496, 0.54506,
-0.54772, 0.5469, 0.5494, 0.55096, 0.55252, 0.55538, 0.55696, 0.5546, 0.55582, 
-//499
-0.12852, 0.15686, 0.20492, 0.2701, 0.34172, 0.39578, 0.42296, 0.43234, 0.43578, 0.43624, 0.43734, 0.44124, 0.4435, 0.44404, 0.44664, 0.4522, 0.45766, 0.46856,
-0.48166, 0.4929, 0.50208, 0.50816, 0.51212, 0.51442, 0.51704, 0.51698, 0.51784, 0.51652, 0.5163, 0.5152, 0.5145, 0.51364, 0.51182, 0.51016, 0.51032, 0.50768,
-0.50478, 0.5032, 0.5043, 0.49962, 0.49762, 0.49568, 0.493, 0.4887, 0.48498, 0.4786, 0.47332, 0.4698, 0.46498, 0.46266, 0.46006, 0.45734, 0.45536, 0.45238,
-0.45022, 0.44712, 0.44796, 0.4474, 0.44754, 0.45076, 0.45056, 0.45094, 0.45194, 0.45374, 0.45464, 0.45396, 0.45514, 0.45224, 0.45068, 0.44978, 0.45014, 0.45122,
-0.45338, 0.4514, 0.45336, 0.45686, 0.45846, 0.45666, 0.46162, 0.46158, 0.4613, 
-//500
-0.11804, 0.14084, 0.18082, 0.2328, 0.27908, 0.30822, 0.32166, 0.3249, 0.32642, 0.32608, 0.32832, 0.32814, 0.33048, 0.32976, 0.33076, 0.33274, 0.33718, 0.34352,
-0.3534, 0.36158, 0.36774, 0.37192, 0.3744, 0.37692, 0.37834, 0.37794, 0.37766, 0.37796, 0.37602, 0.37488, 0.37534, 0.37286, 0.37228, 0.37102, 0.3698, 0.37026,
-0.3678, 0.36778, 0.366, 0.36432, 0.36212, 0.36064, 0.35928, 0.3553, 0.35398, 0.3511, 0.34668, 0.3445, 0.34146, 0.33934, 0.33772, 0.33574, 0.33278, 0.33108,
-0.32912, 0.32682, 0.32654, 0.327, 0.32702, 0.329, 0.32786, 0.32896, 0.32928, 0.32972, 0.3308, 0.3303, 0.33098, 0.33018, 0.33024, 0.3302, 0.3304, 0.33052,
-0.33266, 0.33232, 0.334, 0.33484, 0.33594, 0.33438, 0.33514, 0.33368, 0.3339, 
-//501
-0.10432, 0.12204, 0.15084, 0.17984, 0.2003, 0.21076, 0.21696, 0.21718, 0.21872, 0.21762, 0.2191, 0.22046, 0.222, 0.22094, 0.22264, 0.22408, 0.22806, 0.23398,
-0.2415, 0.24816, 0.2546, 0.25846, 0.26124, 0.26342, 0.26468, 0.2648, 0.26472, 0.26422, 0.2636, 0.26234, 0.26256, 0.26036, 0.25916, 0.25814, 0.25702, 0.25652,
-0.25366, 0.2548, 0.25374, 0.25222, 0.2502, 0.24814, 0.24596, 0.24364, 0.24078, 0.23808, 0.23556, 0.23308, 0.23048, 0.22838, 0.22714, 0.22568, 0.22382, 0.22192,
-0.22094, 0.21914, 0.21802, 0.21714, 0.21784, 0.21854, 0.21926, 0.22006, 0.22058, 0.22182, 0.22316, 0.22292, 0.2239, 0.22326, 0.22276, 0.22176, 0.22224, 0.22278,
-0.22382, 0.22532, 0.22634, 0.22718, 0.22822, 0.22894, 0.22958, 0.2295, 0.2307, 
-//502
-0.09288, 0.10348, 0.11924, 0.13336, 0.14192, 0.1444, 0.14602, 0.1462, 0.14622, 0.14648, 0.14768, 0.14912, 0.15084, 0.15056, 0.15086, 0.15266, 0.15522, 0.15956,
-0.16516, 0.16984, 0.174, 0.17596, 0.17714, 0.1782, 0.17952, 0.17972, 0.1803, 0.18016, 0.17904, 0.17874, 0.17856, 0.17828, 0.1768, 0.17634, 0.17526, 0.17418,
-0.17132, 0.17268, 0.17208, 0.1709, 0.17052, 0.16882, 0.16838, 0.16634, 0.16552, 0.16318, 0.16094, 0.1595, 0.15762, 0.15578, 0.15448, 0.15338, 0.1525, 0.1508,
-0.15128, 0.14992, 0.15018, 0.14848, 0.14894, 0.15012, 0.14982, 0.15028, 0.15042, 0.1522, 0.15214, 0.15292, 0.15282, 0.15174, 0.1516, 0.15218, 0.15264, 0.15188,
-0.15288, 0.15368, 0.15484, 0.15594, 0.15704, 0.1573, 0.158, 0.1596, 0.1597, 
-//503
-0.07586, 0.0808, 0.08678, 0.0908, 0.09484, 0.09418, 0.09474, 0.09388, 0.09286, 0.09308, 0.09236, 0.09204, 0.09404, 0.09412, 0.09462, 0.09648, 0.09754, 0.10034,
-0.10496, 0.108, 0.1105, 0.11182, 0.11292, 0.11234, 0.11218, 0.11256, 0.11236, 0.11238, 0.1114, 0.11126, 0.1105, 0.11026, 0.11018, 0.11004, 0.1098, 0.1082,
-0.10662, 0.10918, 0.10738, 0.1066, 0.10536, 0.10358, 0.10356, 0.10246, 0.10182, 0.10092, 0.0995, 0.09836, 0.09776, 0.09792, 0.0986, 0.09628, 0.09498, 0.09398,
-0.09356, 0.0926, 0.09162, 0.0902, 0.09072, 0.0924, 0.09262, 0.09184, 0.09192, 0.09516, 0.094, 0.09456, 0.094, 0.09364, 0.09382, 0.09526, 0.0948, 0.0959, 0.097,
-0.0982, 0.09896, 0.0995, 0.1013, 0.10148, 0.102, 0.1018, 0.10226, 
-//504
-0.05734, 0.0587, 0.05886, 0.06048, 0.061, 0.06076, 0.06068, 0.06042, 0.05986, 0.0592, 0.059, 0.05856, 0.0595, 0.06018, 0.05912, 0.05912, 0.05986, 0.06072,
-0.06062, 0.06086, 0.06108, 0.06104, 0.06032, 0.06088, 0.06096, 0.06348, 0.06654, 0.06974, 0.0698, 0.07036, 0.0706, 0.07124, 0.07, 0.07034, 0.06924, 0.0686,
-0.06718, 0.06652, 0.0669, 0.06572, 0.0648, 0.06372, 0.06306, 0.06256, 0.06236, 0.06, 0.05996, 0.05762, 0.057, 0.05696, 0.05564, 0.0553, 0.05508, 0.05466,
-0.05482, 0.05496, 0.0541, 0.05466, 0.05524, 0.05606, 0.05724, 0.05662, 0.05682, 0.0585, 0.05868, 0.05922, 0.05988, 0.06066, 0.06078, 0.06118, 0.06142, 0.06234,
-0.06278, 0.06338, 0.06548, 0.066, 0.06772, 0.06792, 0.06806, 0.06988, 0.07082, 
-//505
-0.04872, 0.0463, 0.0463, 0.04436, 0.04414, 0.0442, 0.04508, 0.04436, 0.04478, 0.04478, 0.04502, 0.04532, 0.04584, 0.04564, 0.04568, 0.04516, 0.04528, 0.0453,
-0.045, 0.04528, 0.04502, 0.04514, 0.04498, 0.04652, 0.04674, 0.04908, 0.05102, 0.05282, 0.05372, 0.05342, 0.05348, 0.05288, 0.05144, 0.0508, 0.05014, 0.04912,
-0.04864, 0.04966, 0.04934, 0.04942, 0.04874, 0.04748, 0.04684, 0.04578, 0.045, 0.04454, 0.0437, 0.0434, 0.0434, 0.04448, 0.04474, 0.0449, 0.0452, 0.04562,
-0.04582, 0.04552, 0.04572, 0.04478, 0.04574, 0.04628, 0.0465, 0.04662, 0.04786, 0.04894, 0.04952, 0.05008, 0.05022, 0.05082, 0.05126, 0.05142, 0.05188, 0.05348,
-0.05394, 0.0552, 0.05584, 0.05666, 0.05842, 0.05878, 0.06022, 0.05962, 0.06172, 
-//506
-0.14324, 0.17218, 0.22868, 0.30362, 0.39296, 0.4691, 0.51366, 0.53064, 0.53696, 0.53836, 0.54198, 0.54462, 0.55004, 0.5511, 0.55432, 0.56184, 0.5745, 0.59278,
-0.61818, 0.64388, 0.6674, 0.6872, 0.7028, 0.71456, 0.72294, 0.728, 0.73094, 0.7306, 0.73162, 0.72954, 0.7281, 0.72606, 0.72398, 0.72136, 0.71822, 0.7143,
-0.70942, 0.7066, 0.70094, 0.69418, 0.68488, 0.6763, 0.6649, 0.6503, 0.6382, 0.62326, 0.61056, 0.60004, 0.59004, 0.58328, 0.57902, 0.5739, 0.56758, 0.5626,
-0.55802, 0.55334, 0.55372, 0.55102, 0.55264, 0.55596, 0.55958, 0.56234, 0.56786, 0.57468, 0.57884, 0.5825, 0.584, 0.58054, 0.5798, 0.57944, 0.57718, 0.57764,
-0.58376, 0.58736, 0.59612, 0.60042, 0.60222, 0.60882, 0.6106, 0.61272, 0.61974, 
-//507
-0.13164, 0.16008, 0.2108, 0.28268, 0.36316, 0.42784, 0.46412, 0.47674, 0.47988, 0.48026, 0.48284, 0.48604, 0.48914, 0.48928, 0.49358, 0.5, 0.50892, 0.52548,
-0.54662, 0.56998, 0.59192, 0.60822, 0.6219, 0.6316, 0.63854, 0.64176, 0.6438, 0.64386, 0.64314, 0.64212, 0.64074, 0.63926, 0.63742, 0.63358, 0.6309, 0.62846,
-0.62228, 0.6209, 0.61602, 0.6109, 0.60592, 0.5986, 0.591, 0.57982, 0.56912, 0.55886, 0.54942, 0.54134, 0.53306, 0.52804, 0.52452, 0.52046, 0.51568, 0.51254,
-0.5089, 0.5048, 0.50444, 0.5036, 0.50462, 0.50744, 0.50922, 0.5123, 0.51478, 0.51868, 0.52224, 0.52426, 0.52466, 0.523, 0.5187, 0.51816, 0.51938, 0.51696,
-0.5211, 0.52298, 0.5281, 0.53306, 0.53524, 0.53498, 0.53914, 0.5395, 0.54528, 
-//508
-0.12948, 0.15702, 0.20388, 0.26462, 0.32528, 0.36796, 0.38816, 0.39512, 0.39586, 0.39694, 0.399, 0.40122, 0.40254, 0.40462, 0.40922, 0.41284, 0.42244, 0.43712,
-0.45702, 0.47822, 0.49714, 0.51188, 0.5215, 0.53018, 0.53532, 0.53736, 0.53978, 0.53798, 0.53846, 0.53744, 0.53504, 0.53318, 0.53058, 0.52748, 0.525, 0.5199,
-0.51706, 0.51608, 0.51064, 0.50608, 0.50104, 0.49604, 0.48944, 0.48066, 0.47204, 0.4621, 0.45366, 0.44684, 0.4398, 0.43508, 0.43188, 0.42596, 0.42176, 0.41864,
-0.416, 0.41358, 0.41238, 0.4097, 0.4119, 0.41362, 0.41494, 0.41736, 0.41832, 0.42344, 0.4262, 0.4268, 0.42714, 0.42436, 0.42094, 0.4202, 0.41822, 0.41866,
-0.42182, 0.42256, 0.42754, 0.43218, 0.43472, 0.43514, 0.44014, 0.44106, 0.44422, 
-//509
-0.1302, 0.1509, 0.1872, 0.22522, 0.2588, 0.27692, 0.28344, 0.28488, 0.28744, 0.28894, 0.29128, 0.29476, 0.29842, 0.30262, 0.30982, 0.31726, 0.32566, 0.33544,
-0.34594, 0.35714, 0.36658, 0.375, 0.38338, 0.39214, 0.40006, 0.40408, 0.40626, 0.40616, 0.40676, 0.40568, 0.40382, 0.40184, 0.40078, 0.39864, 0.39572, 0.39178,
-0.38696, 0.38478, 0.37926, 0.37426, 0.36758, 0.36052, 0.35228, 0.34426, 0.33652, 0.32918, 0.3241, 0.31894, 0.31736, 0.31504, 0.31284, 0.31212, 0.30816, 0.30696,
-0.30698, 0.30644, 0.30728, 0.30794, 0.31078, 0.3126, 0.31374, 0.3141, 0.31604, 0.31902, 0.31974, 0.3205, 0.32046, 0.31984, 0.31768, 0.31824, 0.31836, 0.32088,
-0.32308, 0.32628, 0.3309, 0.33188, 0.33298, 0.33226, 0.33638, 0.3329, 0.33474, 
-//510
-0.09952, 0.11606, 0.13926, 0.1622, 0.17836, 0.1876, 0.18992, 0.19116, 0.19314, 0.19304, 0.1952, 0.19768, 0.19848, 0.1999, 0.20238, 0.20432, 0.21016, 0.21886,
-0.22952, 0.24132, 0.25152, 0.25912, 0.26494, 0.26796, 0.2714, 0.27276, 0.27416, 0.27386, 0.27284, 0.2719, 0.27126, 0.27004, 0.26976, 0.26752, 0.26456, 0.2635,
-0.26126, 0.26248, 0.2606, 0.25834, 0.25456, 0.25064, 0.24706, 0.24234, 0.2375, 0.23074, 0.22638, 0.2206, 0.21614, 0.213, 0.21042, 0.20816, 0.20574, 0.20288,
-0.20054, 0.19888, 0.19876, 0.199, 0.2009, 0.20264, 0.20526, 0.20692, 0.20666, 0.21144, 0.21208, 0.21302, 0.21302, 0.21264, 0.21146, 0.21142, 0.21168, 0.21348,
-0.21638, 0.21784, 0.22086, 0.22324, 0.22592, 0.22604, 0.22718, 0.22672, 0.22846, 
-//511
-0.09816, 0.1038, 0.11632, 0.12602, 0.13356, 0.13492, 0.13564, 0.137, 0.13622, 0.13652, 0.13842, 0.14, 0.14098, 0.14008, 0.14134, 0.14378, 0.14754, 0.15492,
-0.16238, 0.1719, 0.18012, 0.18548, 0.18792, 0.19112, 0.19308, 0.19444, 0.19464, 0.19414, 0.19372, 0.19162, 0.19058, 0.19012, 0.18816, 0.18718, 0.1864, 0.18468,
-0.181, 0.1833, 0.18198, 0.18022, 0.17756, 0.1744, 0.17106, 0.16724, 0.16312, 0.15696, 0.15356, 0.1513, 0.1484, 0.14586, 0.14334, 0.14288, 0.14096, 0.13928,
-0.13866, 0.1355, 0.13588, 0.13358, 0.13466, 0.13588, 0.13634, 0.13534, 0.13786, 0.14112, 0.14238, 0.14358, 0.14334, 0.14168, 0.14094, 0.13938, 0.1401, 0.14046,
-0.14288, 0.1457, 0.14694, 0.14874, 0.15106, 0.1518, 0.15484, 0.15494, 0.15712, 
-//512
-0.07074, 0.07194, 0.07528, 0.07726, 0.0789, 0.0805, 0.08082, 0.08024, 0.0796, 0.08044, 0.08104, 0.08234, 0.08202, 0.08246, 0.08368, 0.08512, 0.08746, 0.09124,
-0.0964, 0.10174, 0.10778, 0.1118, 0.11372, 0.11562, 0.11678, 0.11762, 0.11798, 0.11678, 0.1166, 0.11522, 0.11442, 0.1144, 0.11226, 0.11194, 0.11122, 0.10996,
-0.10772, 0.1083, 0.1072, 0.10624, 0.10472, 0.1035, 0.10182, 0.09926, 0.09688, 0.094, 0.0923, 0.09004, 0.0881, 0.0868, 0.08526, 0.08404, 0.08294, 0.0823,
-0.08136, 0.08086, 0.08064, 0.08024, 0.08094, 0.08206, 0.08248, 0.08246, 0.08342, 0.08574, 0.08528, 0.08708, 0.0872, 0.08698, 0.08726, 0.0869, 0.08688, 0.08712,
-0.0894, 0.09048, 0.09214, 0.09326, 0.0946, 0.09566, 0.09678, 0.09846, 0.09882, 
-//513
-0.05298, 0.05296, 0.05498, 0.05434, 0.05384, 0.05294, 0.05342, 0.05394, 0.05388, 0.05322, 0.05384, 0.05432, 0.0553, 0.05484, 0.05514, 0.05418, 0.05484, 0.05448,
-0.05468, 0.05496, 0.05562, 0.05532, 0.05692, 0.0576, 0.05918, 0.0626, 0.068, 0.07226, 0.0742, 0.07514, 0.07576, 0.0748, 0.07378, 0.07264, 0.07124, 0.06932,
-0.06646, 0.06706, 0.06614, 0.06438, 0.06318, 0.06134, 0.06052, 0.0588, 0.05688, 0.05606, 0.05456, 0.05398, 0.05272, 0.05282, 0.05082, 0.0507, 0.04996, 0.05086,
-0.05064, 0.05026, 0.05016, 0.05032, 0.0512, 0.05318, 0.05276, 0.05304, 0.05278, 0.05512, 0.05608, 0.05674, 0.05716, 0.05804, 0.05882, 0.05834, 0.05946, 0.05936,
-0.06114, 0.06176, 0.06302, 0.06466, 0.06556, 0.0665, 0.06588, 0.0671, 0.06958, 
-//514
-0.04552, 0.0431, 0.04444, 0.04328, 0.04308, 0.04256, 0.04364, 0.04264, 0.0433, 0.04266, 0.04146, 0.042, 0.04278, 0.04214, 0.0433, 0.04236, 0.04392, 0.04428,
-0.04534, 0.04548, 0.04588, 0.04578, 0.04504, 0.04592, 0.04596, 0.0487, 0.05184, 0.05534, 0.05672, 0.05812, 0.05762, 0.05758, 0.05594, 0.0562, 0.05494, 0.05392,
-0.05128, 0.051, 0.0499, 0.0482, 0.0481, 0.04794, 0.0465, 0.04528, 0.04456, 0.04308, 0.04292, 0.04298, 0.04176, 0.04152, 0.04114, 0.04116, 0.04098, 0.04154,
-0.04212, 0.04252, 0.04304, 0.04264, 0.04356, 0.04536, 0.04548, 0.04488, 0.04702, 0.04728, 0.04808, 0.04864, 0.04924, 0.04924, 0.0495, 0.05062, 0.0508, 0.05102,
-0.05268, 0.0537, 0.0554, 0.0557, 0.05688, 0.05816, 0.05944, 0.06042, 0.06132, 
-//515
-0.12552, 0.14992, 0.19782, 0.2612, 0.32644, 0.37338, 0.39704, 0.4042, 0.40768, 0.40964, 0.41292, 0.41806, 0.42336, 0.42544, 0.43118, 0.4395, 0.45588, 0.48114,
-0.51482, 0.55528, 0.59724, 0.63714, 0.67138, 0.69918, 0.72084, 0.737, 0.74646, 0.74908, 0.74828, 0.7484, 0.74562, 0.7423, 0.73642, 0.73, 0.72218, 0.70904,
-0.7023, 0.70248, 0.68874, 0.67206, 0.65654, 0.64238, 0.62574, 0.60526, 0.58316, 0.56286, 0.5448, 0.53094, 0.5187, 0.51016, 0.5039, 0.49988, 0.4916, 0.48702,
-0.48046, 0.47756, 0.47722, 0.47712, 0.47994, 0.48592, 0.48972, 0.49522, 0.50376, 0.51212, 0.5191, 0.52242, 0.52458, 0.52178, 0.51828, 0.51578, 0.51548, 0.515,
-0.52266, 0.53114, 0.53938, 0.54746, 0.55584, 0.55984, 0.56484, 0.56872, 0.57804, 
-//516
-0.12214, 0.14808, 0.19186, 0.24802, 0.30382, 0.3419, 0.35986, 0.36546, 0.3667, 0.36738, 0.37058, 0.37332, 0.37624, 0.37968, 0.38382, 0.38964, 0.40288, 0.4241,
-0.45334, 0.48956, 0.5274, 0.56254, 0.5922, 0.617, 0.63304, 0.64264, 0.64958, 0.65072, 0.65084, 0.65028, 0.64806, 0.64638, 0.6424, 0.6361, 0.63174, 0.62334,
-0.61598, 0.60922, 0.59836, 0.58904, 0.57568, 0.56196, 0.54852, 0.53094, 0.5129, 0.49436, 0.4793, 0.46622, 0.45542, 0.44866, 0.4433, 0.4365, 0.43082, 0.42668,
-0.42162, 0.41716, 0.41602, 0.4157, 0.4176, 0.42242, 0.42536, 0.43096, 0.43546, 0.4422, 0.4476, 0.45038, 0.45094, 0.44898, 0.444, 0.44196, 0.44004, 0.4411,
-0.44764, 0.4502, 0.45944, 0.46582, 0.46832, 0.47032, 0.4766, 0.47882, 0.48668, 
-//517
-0.13308, 0.15794, 0.1984, 0.24178, 0.28048, 0.30116, 0.31268, 0.31348, 0.31414, 0.31496, 0.31798, 0.32164, 0.32476, 0.32586, 0.33024, 0.3368, 0.3475, 0.36474,
-0.38964, 0.4202, 0.4523, 0.4831, 0.5088, 0.53086, 0.54656, 0.55782, 0.56388, 0.5662, 0.5671, 0.56708, 0.56608, 0.56502, 0.56114, 0.55732, 0.55236, 0.54564,
-0.53886, 0.53124, 0.5225, 0.5131, 0.501, 0.48694, 0.472, 0.45472, 0.43616, 0.41724, 0.4027, 0.39024, 0.3803, 0.37194, 0.36578, 0.35886, 0.3529, 0.347, 0.34046,
-0.33714, 0.33506, 0.33408, 0.33644, 0.3396, 0.34174, 0.34558, 0.35024, 0.35736, 0.3631, 0.36632, 0.36772, 0.36632, 0.36366, 0.3623, 0.36188, 0.36348, 0.36846,
-0.37398, 0.3826, 0.3889, 0.39508, 0.39792, 0.40324, 0.4075, 0.4141, 
-//518
-0.10202, 0.1208, 0.14838, 0.17586, 0.19438, 0.20488, 0.20928, 0.20982, 0.21172, 0.21306, 0.21628, 0.21948, 0.22498, 0.2297, 0.23688, 0.24514, 0.25692, 0.27038,
-0.2861, 0.3026, 0.3199, 0.3378, 0.35702, 0.3767, 0.3948, 0.40792, 0.41652, 0.42158, 0.42106, 0.41946, 0.4171, 0.41292, 0.40974, 0.40476, 0.39964, 0.39316,
-0.38468, 0.3795, 0.37122, 0.36154, 0.35168, 0.33882, 0.32712, 0.31474, 0.30432, 0.29542, 0.28872, 0.28334, 0.27892, 0.27796, 0.27574, 0.27364, 0.27094, 0.26998,
-0.26886, 0.26854, 0.2716, 0.27376, 0.27768, 0.283, 0.286, 0.28854, 0.2902, 0.29368, 0.29498, 0.29322, 0.29348, 0.2906, 0.2878, 0.2882, 0.28806, 0.29024, 0.2952,
-0.29686, 0.30176, 0.30428, 0.30666, 0.30708, 0.30878, 0.30618, 0.30398, 
-//519
-0.09322, 0.10534, 0.12102, 0.13448, 0.1433, 0.14756, 0.14902, 0.14908, 0.1507, 0.15058, 0.15276, 0.15452, 0.15618, 0.15812, 0.16094, 0.1639, 0.17188, 0.18412,
-0.20184, 0.22096, 0.24212, 0.25944, 0.27386, 0.28282, 0.29016, 0.29332, 0.29538, 0.29558, 0.29478, 0.29318, 0.29272, 0.29046, 0.28902, 0.28606, 0.28338,
-0.27998, 0.2747, 0.2739, 0.26936, 0.26256, 0.25684, 0.2496, 0.2411, 0.231, 0.21976, 0.2096, 0.20054, 0.19252, 0.18654, 0.18158, 0.1783, 0.17406, 0.17122,
-0.16734, 0.16512, 0.162, 0.16226, 0.1624, 0.16474, 0.16742, 0.17018, 0.1722, 0.17542, 0.17996, 0.18328, 0.18468, 0.1858, 0.1858, 0.18372, 0.1828, 0.18342,
-0.18488, 0.1873, 0.19112, 0.19574, 0.1994, 0.20196, 0.20448, 0.20646, 0.20838, 0.21252, 
-//520
-0.07728, 0.08336, 0.08988, 0.09472, 0.09742, 0.0983, 0.0984, 0.09748, 0.0986, 0.09844, 0.09976, 0.10028, 0.10202, 0.10238, 0.10334, 0.10608, 0.11112, 0.1195,
-0.13248, 0.14738, 0.16268, 0.17474, 0.1849, 0.19066, 0.19504, 0.19806, 0.1987, 0.19926, 0.19816, 0.19726, 0.19578, 0.19376, 0.19128, 0.18938, 0.18882, 0.18582,
-0.18038, 0.1786, 0.17626, 0.17256, 0.16858, 0.16216, 0.1571, 0.15046, 0.14326, 0.13584, 0.12942, 0.12484, 0.12008, 0.11684, 0.1143, 0.1126, 0.11104, 0.10838,
-0.10646, 0.10586, 0.1058, 0.10418, 0.10628, 0.10868, 0.10968, 0.11036, 0.11222, 0.11538, 0.11758, 0.11926, 0.12022, 0.11848, 0.11734, 0.11788, 0.11752, 0.11902,
-0.11988, 0.122, 0.12404, 0.12724, 0.12944, 0.13194, 0.13922, 0.13578, 0.13978, 
-//521
-0.05712, 0.05752, 0.0574, 0.05766, 0.05842, 0.05828, 0.05872, 0.05902, 0.0595, 0.05956, 0.05934, 0.06042, 0.06116, 0.0616, 0.06192, 0.06232, 0.06546, 0.0701,
-0.0776, 0.08654, 0.0985, 0.10888, 0.11794, 0.12522, 0.1295, 0.13168, 0.13328, 0.13338, 0.1321, 0.13134, 0.12986, 0.12746, 0.12564, 0.1231, 0.12182, 0.11918,
-0.1149, 0.11448, 0.11136, 0.10766, 0.10488, 0.1008, 0.09638, 0.09158, 0.0869, 0.0833, 0.08034, 0.07766, 0.07464, 0.07302, 0.07286, 0.07168, 0.07154, 0.07098,
-0.0699, 0.06958, 0.0696, 0.06856, 0.06894, 0.07066, 0.07056, 0.07054, 0.0729, 0.07406, 0.07478, 0.07528, 0.07748, 0.07774, 0.07752, 0.07748, 0.07844, 0.07896,
-0.08088, 0.08222, 0.08386, 0.08562, 0.08584, 0.08792, 0.08974, 0.09046, 0.09368, 
-//522
-0.1244, 0.14724, 0.18454, 0.22432, 0.25736, 0.27568, 0.28286, 0.28336, 0.28644, 0.28714, 0.28948, 0.2945, 0.29746, 0.30122, 0.30546, 0.31354, 0.3282, 0.353,
-0.38764, 0.43308, 0.48232, 0.53218, 0.57778, 0.61786, 0.64836, 0.66994, 0.68282, 0.6888, 0.69098, 0.6907, 0.68744, 0.68124, 0.67366, 0.6643, 0.65384, 0.6414,
-0.62594, 0.61292, 0.59564, 0.5782, 0.55832, 0.53798, 0.51578, 0.48946, 0.46476, 0.44114, 0.42152, 0.40626, 0.39332, 0.38426, 0.37838, 0.3713, 0.36316, 0.35868,
-0.35146, 0.34692, 0.34594, 0.34562, 0.34826, 0.35418, 0.3598, 0.3658, 0.3721, 0.38034, 0.38646, 0.39204, 0.39276, 0.3895, 0.38608, 0.38276, 0.38066, 0.38576,
-0.39096, 0.3964, 0.40676, 0.41284, 0.42078, 0.4266, 0.43308, 0.43794, 0.45032, 
-//523
-0.11214, 0.13234, 0.16506, 0.1997, 0.22596, 0.24046, 0.24714, 0.24808, 0.2491, 0.24922, 0.25108, 0.2542, 0.25738, 0.25944, 0.26326, 0.27044, 0.28384, 0.3048,
-0.3358, 0.37506, 0.41936, 0.46448, 0.50518, 0.53804, 0.56262, 0.57852, 0.58686, 0.59022, 0.59192, 0.58968, 0.58758, 0.58326, 0.57886, 0.57094, 0.56314, 0.55288,
-0.53908, 0.52828, 0.51478, 0.49964, 0.48186, 0.46238, 0.44156, 0.41814, 0.3933, 0.37056, 0.35126, 0.3367, 0.32522, 0.31582, 0.30944, 0.304, 0.29806, 0.29104,
-0.2861, 0.2827, 0.28178, 0.28204, 0.28612, 0.29184, 0.29764, 0.3038, 0.30938, 0.31712, 0.32398, 0.32658, 0.32948, 0.32828, 0.32352, 0.32174, 0.3206, 0.32388,
-0.32912, 0.3371, 0.3451, 0.34946, 0.3576, 0.36144, 0.36618, 0.3699, 0.38048, 
-//524
-0.09488, 0.10892, 0.12736, 0.146, 0.157, 0.16232, 0.1654, 0.16564, 0.16578, 0.16648, 0.1679, 0.1725, 0.17634, 0.1783, 0.18178, 0.18816, 0.19934, 0.21854,
-0.24562, 0.28, 0.31486, 0.34732, 0.37588, 0.39774, 0.4133, 0.42336, 0.42918, 0.43066, 0.4302, 0.42866, 0.42578, 0.4242, 0.42, 0.41556, 0.40952, 0.40234,
-0.39378, 0.38592, 0.37454, 0.36314, 0.35116, 0.33622, 0.32104, 0.30238, 0.28454, 0.26588, 0.25136, 0.23906, 0.22944, 0.22214, 0.21662, 0.21274, 0.2072, 0.20308,
-0.2007, 0.19722, 0.19736, 0.1975, 0.20044, 0.20588, 0.2091, 0.2134, 0.21734, 0.2229, 0.2277, 0.2309, 0.23254, 0.23172, 0.22966, 0.22898, 0.22996, 0.23286,
-0.23968, 0.24558, 0.25346, 0.25926, 0.2651, 0.2697, 0.27242, 0.27364, 0.27868, 
-//525
-0.07702, 0.08382, 0.09374, 0.10198, 0.10666, 0.10808, 0.10906, 0.11, 0.1104, 0.11036, 0.1121, 0.11376, 0.116, 0.11692, 0.11824, 0.12158, 0.12912, 0.14174,
-0.16066, 0.1853, 0.2137, 0.24126, 0.26514, 0.28406, 0.29748, 0.30426, 0.30822, 0.31028, 0.3096, 0.30846, 0.30656, 0.30456, 0.30132, 0.29818, 0.29432, 0.28876,
-0.28114, 0.2767, 0.26968, 0.26018, 0.25048, 0.23874, 0.22572, 0.21112, 0.19614, 0.18272, 0.1708, 0.16242, 0.15542, 0.15112, 0.14748, 0.14342, 0.14002, 0.13678,
-0.13398, 0.13204, 0.13098, 0.13128, 0.13246, 0.13636, 0.14028, 0.14226, 0.1468, 0.15152, 0.15468, 0.1579, 0.15866, 0.15794, 0.1563, 0.15568, 0.15568, 0.15752,
-0.16186, 0.16618, 0.17072, 0.17574, 0.1797, 0.18212, 0.18528, 0.18806, 0.1932, 
-//526
-0.0614, 0.063, 0.06678, 0.06978, 0.07214, 0.07294, 0.07426, 0.0727, 0.07434, 0.0742, 0.0753, 0.07558, 0.07712, 0.07782, 0.079, 0.08058, 0.08616, 0.09496,
-0.10798, 0.1253, 0.14658, 0.16676, 0.1842, 0.19866, 0.2079, 0.2132, 0.2157, 0.21676, 0.21604, 0.2141, 0.21254, 0.21028, 0.20844, 0.20578, 0.20256, 0.19804,
-0.19162, 0.18756, 0.18142, 0.1751, 0.16748, 0.15896, 0.1497, 0.139, 0.12784, 0.11796, 0.10996, 0.10342, 0.09932, 0.0959, 0.09452, 0.0925, 0.09062, 0.08846,
-0.087, 0.08536, 0.0852, 0.08458, 0.087, 0.08846, 0.0914, 0.09288, 0.09532, 0.09902, 0.10138, 0.10332, 0.10486, 0.10508, 0.10474, 0.1043, 0.10414, 0.1057,
-0.10822, 0.11174, 0.11542, 0.11812, 0.121, 0.12222, 0.12518, 0.12714, 0.13108, 
-//527
-0.0828, 0.0908, 0.10216, 0.11186, 0.11716, 0.12014, 0.1205, 0.12082, 0.12148, 0.1219, 0.12496, 0.12638, 0.12964, 0.13234, 0.13498, 0.14044, 0.15004, 0.16676,
-0.19048, 0.22386, 0.26362, 0.306, 0.3468, 0.38266, 0.41232, 0.43252, 0.44444, 0.45064, 0.45246, 0.45208, 0.44968, 0.4458, 0.44112, 0.43344, 0.4247, 0.41314,
-0.4, 0.38988, 0.37494, 0.35842, 0.34106, 0.32178, 0.30048, 0.2776, 0.25484, 0.23428, 0.2166, 0.20366, 0.19252, 0.18526, 0.1807, 0.1762, 0.1726, 0.16794, 0.1639,
-0.1622, 0.16234, 0.16186, 0.16656, 0.1719, 0.17686, 0.18176, 0.18792, 0.1941, 0.19916, 0.20238, 0.20478, 0.20438, 0.20152, 0.20076, 0.20216, 0.20572, 0.21308,
-0.2208, 0.22922, 0.2369, 0.24592, 0.24938, 0.2525, 0.2572, 0.26126, 
-//528
-0.0666, 0.0697, 0.07346, 0.07744, 0.0785, 0.07884, 0.07834, 0.07796, 0.07994, 0.0802, 0.08198, 0.08296, 0.08516, 0.08734, 0.0887, 0.0916, 0.09806, 0.10904,
-0.12654, 0.15138, 0.18278, 0.21694, 0.25194, 0.2834, 0.30698, 0.32364, 0.33364, 0.33846, 0.33916, 0.33748, 0.3355, 0.33092, 0.32532, 0.31806, 0.30962, 0.29914,
-0.28592, 0.27718, 0.26436, 0.25068, 0.23708, 0.22034, 0.20388, 0.18748, 0.17084, 0.15686, 0.14454, 0.13534, 0.12972, 0.12586, 0.1231, 0.1194, 0.11582, 0.113,
-0.11026, 0.10828, 0.10762, 0.10846, 0.11078, 0.11364, 0.11698, 0.11964, 0.123, 0.12806, 0.13238, 0.13518, 0.1367, 0.13594, 0.13478, 0.13308, 0.13338, 0.13396,
-0.13802, 0.14214, 0.14778, 0.15212, 0.1567, 0.15956, 0.16348, 0.16974, 0.17438, 
-//529
-0.0515, 0.05216, 0.0535, 0.0539, 0.05312, 0.05334, 0.05346, 0.05376, 0.05524, 0.05394, 0.055, 0.05466, 0.055, 0.05412, 0.05564, 0.05798, 0.06234, 0.06808,
-0.0771, 0.08934, 0.10824, 0.13008, 0.15602, 0.17972, 0.20218, 0.21842, 0.22872, 0.23418, 0.23618, 0.23606, 0.23528, 0.23142, 0.22634, 0.21984, 0.21212, 0.20382,
-0.18932, 0.18118, 0.17108, 0.15954, 0.14846, 0.13508, 0.12304, 0.11052, 0.09956, 0.0906, 0.08496, 0.07958, 0.0751, 0.07242, 0.07192, 0.07028, 0.06974, 0.06798,
-0.06736, 0.06674, 0.0669, 0.06632, 0.06738, 0.06934, 0.07102, 0.071, 0.07394, 0.07584, 0.07854, 0.08094, 0.08194, 0.08214, 0.08164, 0.08102, 0.08148, 0.08372,
-0.08474, 0.08836, 0.09176, 0.09558, 0.09918, 0.1022, 0.10728, 0.11172, 0.11786, 
-//530
-0.08168, 0.08658, 0.09512, 0.10074, 0.1029, 0.10402, 0.10422, 0.10302, 0.10418, 0.10348, 0.10516, 0.10564, 0.10784, 0.10942, 0.11216, 0.11562, 0.12582, 0.1407,
-0.16334, 0.19652, 0.23764, 0.28658, 0.33716, 0.38726, 0.4322, 0.46788, 0.49408, 0.50854, 0.51602, 0.51578, 0.51218, 0.50504, 0.4931, 0.47768, 0.4602, 0.4397,
-0.41786, 0.39754, 0.37526, 0.35368, 0.3304, 0.30658, 0.28122, 0.25682, 0.23412, 0.21248, 0.1967, 0.18426, 0.1755, 0.16914, 0.16526, 0.15918, 0.1545, 0.1501,
-0.14634, 0.14352, 0.14254, 0.14308, 0.14542, 0.1494, 0.1536, 0.15694, 0.16236, 0.16798, 0.1733, 0.17616, 0.17652, 0.17606, 0.17266, 0.1707, 0.17082, 0.17246,
-0.1777, 0.18268, 0.19076, 0.19706, 0.20262, 0.20696, 0.21128, 0.2186, 0.22822, 
-//531
-0.05362, 0.0545, 0.0554, 0.05708, 0.0574, 0.05784, 0.0579, 0.0588, 0.05844, 0.05944, 0.06086, 0.06186, 0.06404, 0.06632, 0.07074, 0.0751, 0.0817, 0.09046,
-0.10226, 0.11656, 0.1333, 0.15424, 0.18026, 0.21462, 0.25368, 0.29446, 0.3302, 0.35568, 0.3699, 0.37454, 0.37262, 0.36552, 0.35632, 0.3428, 0.32648, 0.3084,
-0.28868, 0.2711, 0.25258, 0.23294, 0.21394, 0.1936, 0.17552, 0.1557, 0.13972, 0.12502, 0.1147, 0.10728, 0.10164, 0.09718, 0.09468, 0.0926, 0.08992, 0.08748,
-0.08614, 0.08448, 0.0842, 0.08286, 0.08492, 0.08746, 0.08972, 0.09192, 0.09558, 0.09932, 0.10216, 0.10444, 0.10626, 0.10612, 0.10554, 0.10582, 0.10618, 0.10824,
-0.11226, 0.11802, 0.12414, 0.12944, 0.13308, 0.13628, 0.1396, 0.14338, 0.1467, 
-//532
-0.13036, 0.16052, 0.21296, 0.2897, 0.38264, 0.4654, 0.51818, 0.54074, 0.54734, 0.54936, 0.55348, 0.55664, 0.56028, 0.56276, 0.5672, 0.57446, 0.5849, 0.60226,
-0.62502, 0.6482, 0.67076, 0.68806, 0.70124, 0.71352, 0.72188, 0.72566, 0.72778, 0.72808, 0.7282, 0.72598, 0.72432, 0.7219, 0.71796, 0.71436, 0.71134, 0.70524,
-0.69636, 0.69102, 0.68344, 0.67362, 0.66354, 0.65154, 0.63922, 0.62366, 0.60726, 0.5903, 0.5761, 0.5633, 0.55272, 0.54652, 0.54202, 0.53724, 0.52976, 0.52544,
-0.52082, 0.51774, 0.51716, 0.5163, 0.51922, 0.5241, 0.5267, 0.53054, 0.5354, 0.5424, 0.5485, 0.55062, 0.55172, 0.55096, 0.5463, 0.54408, 0.54304, 0.54416,
-0.54882, 0.55136, 0.55882, 0.5643, 0.57074, 0.57212, 0.57912, 0.5814, 0.58918, 
-//533
-0.13792, 0.16492, 0.21094, 0.27358, 0.33538, 0.38034, 0.40044, 0.40692, 0.4091, 0.40976, 0.41278, 0.41618, 0.41902, 0.42074, 0.42344, 0.42854, 0.43906, 0.45418,
-0.475, 0.49544, 0.51252, 0.52548, 0.53358, 0.54028, 0.54434, 0.5467, 0.54662, 0.54698, 0.54694, 0.54456, 0.54308, 0.5409, 0.53894, 0.53662, 0.53536, 0.53114,
-0.52646, 0.5226, 0.51756, 0.51124, 0.50474, 0.49618, 0.48734, 0.47628, 0.46374, 0.45028, 0.43866, 0.42816, 0.41938, 0.41326, 0.40832, 0.40252, 0.39564, 0.39146,
-0.38646, 0.38296, 0.38228, 0.38314, 0.38626, 0.38978, 0.39358, 0.39748, 0.40152, 0.407, 0.41086, 0.41326, 0.41338, 0.4111, 0.40814, 0.40722, 0.40578, 0.40656,
-0.41142, 0.41538, 0.42102, 0.42444, 0.42962, 0.43208, 0.43782, 0.43876, 0.44604, 
-//534
-0.135, 0.1569, 0.19654, 0.24038, 0.27824, 0.29906, 0.30654, 0.30936, 0.31042, 0.31022, 0.31218, 0.31422, 0.31648, 0.3177, 0.3199, 0.32462, 0.33214, 0.3431,
-0.35714, 0.37088, 0.38178, 0.38946, 0.39356, 0.39834, 0.40124, 0.40184, 0.40184, 0.40178, 0.40116, 0.39858, 0.39782, 0.39634, 0.39408, 0.39114, 0.3899, 0.38602,
-0.38206, 0.37912, 0.37502, 0.37014, 0.36428, 0.35714, 0.34968, 0.33894, 0.32956, 0.31894, 0.3101, 0.30204, 0.29518, 0.29068, 0.28734, 0.2827, 0.27766, 0.27366,
-0.269, 0.26566, 0.26444, 0.26236, 0.263, 0.26468, 0.26604, 0.2678, 0.27014, 0.27372, 0.27666, 0.2785, 0.27878, 0.2777, 0.27552, 0.27606, 0.2737, 0.2746,
-0.27742, 0.27974, 0.28486, 0.2875, 0.2903, 0.29444, 0.29756, 0.29618, 0.3017, 
-//535
-0.11516, 0.1325, 0.15934, 0.18542, 0.20412, 0.21418, 0.21762, 0.21682, 0.2161, 0.21592, 0.21708, 0.2176, 0.21974, 0.2202, 0.22174, 0.22404, 0.23076, 0.23886,
-0.25024, 0.26172, 0.27056, 0.2772, 0.28134, 0.28418, 0.2859, 0.28612, 0.28584, 0.28424, 0.2842, 0.28316, 0.28078, 0.2794, 0.27778, 0.27676, 0.27534, 0.27138,
-0.2676, 0.26722, 0.26352, 0.25994, 0.25722, 0.25156, 0.2486, 0.24236, 0.23626, 0.2302, 0.2245, 0.21916, 0.21552, 0.21148, 0.2088, 0.20612, 0.2032, 0.20004,
-0.19812, 0.19634, 0.19546, 0.19358, 0.194, 0.1964, 0.1975, 0.19834, 0.19942, 0.20226, 0.20324, 0.2042, 0.20424, 0.2032, 0.2019, 0.20068, 0.20004, 0.19854,
-0.20058, 0.20328, 0.206, 0.20772, 0.20844, 0.21066, 0.21226, 0.2117, 0.21524, 
-//536
-0.10032, 0.1093, 0.12216, 0.1346, 0.14266, 0.146, 0.14746, 0.14674, 0.14752, 0.14626, 0.14758, 0.14758, 0.14886, 0.15, 0.15096, 0.15226, 0.15632, 0.16222,
-0.16928, 0.17788, 0.18562, 0.19098, 0.1943, 0.19664, 0.19838, 0.19896, 0.1992, 0.19744, 0.19702, 0.1954, 0.19404, 0.1922, 0.1917, 0.19132, 0.18912, 0.18742,
-0.1846, 0.1835, 0.18234, 0.17998, 0.17682, 0.17422, 0.17016, 0.16612, 0.16052, 0.15494, 0.15034, 0.14604, 0.14396, 0.14012, 0.13836, 0.136, 0.13382, 0.13274,
-0.13032, 0.12984, 0.12898, 0.12832, 0.12968, 0.13176, 0.13316, 0.13304, 0.1353, 0.13716, 0.13826, 0.13968, 0.14036, 0.14012, 0.13946, 0.13958, 0.14084, 0.14136,
-0.14256, 0.14444, 0.1469, 0.14834, 0.1502, 0.1515, 0.15242, 0.15382, 0.15446, 
-//537
-0.06986, 0.07516, 0.08002, 0.08376, 0.086, 0.08652, 0.08698, 0.0867, 0.0868, 0.08684, 0.087, 0.08736, 0.08744, 0.08818, 0.08836, 0.08888, 0.09234, 0.09592,
-0.10134, 0.10614, 0.11056, 0.11418, 0.11604, 0.1176, 0.11762, 0.1183, 0.11792, 0.11752, 0.1167, 0.11522, 0.1146, 0.11378, 0.1134, 0.1123, 0.11194, 0.11072,
-0.10738, 0.10852, 0.10748, 0.10534, 0.10364, 0.10198, 0.09944, 0.09608, 0.0936, 0.09044, 0.0871, 0.08488, 0.08338, 0.08258, 0.0825, 0.08082, 0.07996, 0.0792,
-0.07868, 0.07808, 0.07782, 0.07656, 0.07708, 0.07886, 0.07896, 0.07894, 0.08052, 0.08298, 0.08328, 0.08386, 0.08498, 0.08498, 0.08504, 0.08408, 0.08506,
-0.08486, 0.08626, 0.08848, 0.08904, 0.09086, 0.09236, 0.09372, 0.0939, 0.0951, 0.09562, 
-//538
-0.05134, 0.05198, 0.05354, 0.05386, 0.05494, 0.05378, 0.0537, 0.05372, 0.05358, 0.05326, 0.05436, 0.0547, 0.05576, 0.05576, 0.05642, 0.05752, 0.05896, 0.0613,
-0.06418, 0.06858, 0.0716, 0.07272, 0.07388, 0.07426, 0.07442, 0.07368, 0.07416, 0.07392, 0.07366, 0.07262, 0.0719, 0.07174, 0.07136, 0.07052, 0.07, 0.06912,
-0.06732, 0.0678, 0.06678, 0.066, 0.06524, 0.0636, 0.06162, 0.05894, 0.05772, 0.05404, 0.05466, 0.05154, 0.0542, 0.05202, 0.05018, 0.05142, 0.0513, 0.04992,
-0.05022, 0.05226, 0.0494, 0.05024, 0.04994, 0.05138, 0.05272, 0.05226, 0.0527, 0.0544, 0.05582, 0.05592, 0.05662, 0.05706, 0.05652, 0.05678, 0.05768, 0.05802,
-0.05924, 0.06032, 0.06194, 0.06242, 0.06338, 0.06414, 0.06526, 0.06624, 0.06768, 
-//539
-0.04134, 0.04052, 0.04004, 0.04004, 0.03938, 0.03986, 0.04068, 0.0409, 0.04136, 0.0415, 0.04186, 0.04306, 0.04348, 0.04416, 0.045, 0.04528, 0.04736, 0.04884,
-0.051, 0.05244, 0.05482, 0.05572, 0.0577, 0.05884, 0.0602, 0.06046, 0.06034, 0.06026, 0.05964, 0.0587, 0.0586, 0.0575, 0.05666, 0.05494, 0.05482, 0.05354,
-0.05128, 0.05252, 0.05152, 0.05036, 0.04962, 0.04854, 0.047, 0.04578, 0.04508, 0.04428, 0.04372, 0.04302, 0.0432, 0.04272, 0.04232, 0.04282, 0.04342, 0.04366,
-0.0436, 0.04342, 0.04406, 0.04326, 0.0446, 0.04624, 0.0463, 0.0459, 0.04696, 0.04688, 0.0482, 0.04842, 0.04956, 0.0497, 0.05086, 0.05126, 0.05254, 0.0534,
-0.05422, 0.05496, 0.0562, 0.05684, 0.05812, 0.05776, 0.05914, 0.05814, 0.05914, 
-//540
-0.13804, 0.16294, 0.20742, 0.26274, 0.31226, 0.34436, 0.35836, 0.36224, 0.36346, 0.3657, 0.36928, 0.3721, 0.37728, 0.3784, 0.3836, 0.3904, 0.40496, 0.42562,
-0.45286, 0.48296, 0.51154, 0.53588, 0.55444, 0.56864, 0.5792, 0.58486, 0.58892, 0.5884, 0.58788, 0.5849, 0.58066, 0.57632, 0.57206, 0.56366, 0.55788, 0.54884,
-0.53804, 0.52912, 0.51726, 0.50356, 0.48898, 0.47094, 0.4525, 0.42992, 0.409, 0.38922, 0.37258, 0.35918, 0.34902, 0.3408, 0.33426, 0.32822, 0.32232, 0.31542,
-0.3099, 0.30478, 0.30346, 0.30168, 0.3023, 0.30596, 0.30878, 0.31206, 0.31658, 0.32316, 0.32902, 0.3324, 0.33516, 0.3336, 0.33054, 0.33036, 0.3291, 0.32932,
-0.33398, 0.34138, 0.34748, 0.35464, 0.3603, 0.36394, 0.36762, 0.3732, 0.37804, 
-//541
-0.12402, 0.14354, 0.1751, 0.20802, 0.23148, 0.24314, 0.24948, 0.25012, 0.25088, 0.25098, 0.2534, 0.2556, 0.25798, 0.25998, 0.26326, 0.2695, 0.27952, 0.29502,
-0.3177, 0.34146, 0.3635, 0.3812, 0.39542, 0.4055, 0.4122, 0.41534, 0.41692, 0.41636, 0.41576, 0.4127, 0.4107, 0.40706, 0.40282, 0.3997, 0.39468, 0.38912,
-0.38198, 0.37552, 0.36778, 0.35906, 0.3473, 0.33606, 0.3235, 0.30756, 0.2927, 0.27676, 0.26376, 0.25308, 0.24458, 0.23756, 0.23284, 0.22812, 0.22288, 0.2184,
-0.21398, 0.21078, 0.20946, 0.2088, 0.21148, 0.2142, 0.2176, 0.22064, 0.22466, 0.229, 0.23288, 0.23562, 0.23644, 0.2344, 0.23136, 0.23034, 0.22912, 0.22946,
-0.23334, 0.2387, 0.24426, 0.24926, 0.25234, 0.257, 0.2601, 0.26596, 0.27236, 
-//542
-0.10384, 0.11722, 0.13632, 0.15288, 0.16354, 0.16906, 0.17008, 0.16968, 0.17064, 0.1713, 0.175, 0.17728, 0.17842, 0.1807, 0.18434, 0.18858, 0.1966, 0.20904,
-0.22538, 0.2439, 0.26104, 0.2753, 0.2866, 0.29366, 0.29846, 0.29728, 0.29932, 0.29954, 0.298, 0.2974, 0.29692, 0.2936, 0.28992, 0.28596, 0.28244, 0.27574,
-0.26792, 0.26426, 0.2573, 0.24976, 0.24016, 0.22898, 0.21814, 0.20586, 0.19274, 0.1801, 0.17084, 0.16316, 0.15674, 0.15302, 0.15032, 0.14744, 0.14336, 0.14,
-0.1383, 0.13542, 0.13244, 0.1325, 0.1331, 0.13506, 0.13612, 0.1369, 0.14024, 0.14426, 0.14718, 0.15018, 0.1517, 0.15104, 0.14964, 0.1493, 0.14956, 0.15124,
-0.15394, 0.15686, 0.15988, 0.16482, 0.16788, 0.17126, 0.17366, 0.1778, 0.18024, 
-//543
-0.07914, 0.08388, 0.0924, 0.0982, 0.10386, 0.1063, 0.10714, 0.10764, 0.1092, 0.11024, 0.11198, 0.11396, 0.1168, 0.12046, 0.1258, 0.13044, 0.1383, 0.14614,
-0.15396, 0.16202, 0.17032, 0.17844, 0.18596, 0.1925, 0.19914, 0.2032, 0.20476, 0.20548, 0.2047, 0.20326, 0.20136, 0.19924, 0.19574, 0.1934, 0.18872, 0.18344,
-0.17686, 0.17416, 0.16768, 0.16152, 0.1545, 0.14626, 0.13876, 0.1308, 0.12482, 0.11976, 0.1167, 0.11332, 0.11216, 0.11084, 0.10948, 0.10918, 0.10894, 0.10812,
-0.10732, 0.10804, 0.10948, 0.1097, 0.1117, 0.11458, 0.11696, 0.11876, 0.12016, 0.12416, 0.12512, 0.12564, 0.1255, 0.12444, 0.12352, 0.12248, 0.12324, 0.12518,
-0.12884, 0.13134, 0.13658, 0.14, 0.14196, 0.14366, 0.14344, 0.14508, 0.14436, 
-//544
-0.06458, 0.06572, 0.0684, 0.06976, 0.07056, 0.07022, 0.07218, 0.07184, 0.0726, 0.07182, 0.073, 0.07424, 0.07554, 0.07608, 0.07654, 0.079, 0.08224, 0.08758,
-0.0951, 0.10606, 0.11554, 0.12356, 0.13044, 0.13512, 0.13796, 0.14002, 0.1411, 0.14032, 0.13966, 0.13762, 0.13648, 0.13464, 0.13118, 0.12846, 0.12506, 0.12254,
-0.11602, 0.1142, 0.11058, 0.10444, 0.09958, 0.09462, 0.08848, 0.08288, 0.07712, 0.07218, 0.06962, 0.06608, 0.06578, 0.06448, 0.06382, 0.06288, 0.06184, 0.06174,
-0.06106, 0.05976, 0.0599, 0.05872, 0.05916, 0.0609, 0.06232, 0.06274, 0.06394, 0.06604, 0.06772, 0.0692, 0.06974, 0.06994, 0.06972, 0.06988, 0.07158, 0.0732,
-0.0742, 0.07524, 0.07692, 0.07908, 0.08046, 0.08034, 0.08276, 0.08424, 0.08686, 
-//545
-0.04256, 0.0405, 0.04198, 0.04548, 0.0424, 0.04342, 0.0435, 0.04346, 0.0445, 0.04452, 0.04572, 0.04594, 0.04666, 0.04818, 0.049, 0.05078, 0.0535, 0.05668,
-0.0609, 0.06602, 0.07036, 0.0741, 0.07878, 0.08384, 0.08612, 0.08736, 0.088, 0.0882, 0.08592, 0.08394, 0.08184, 0.07896, 0.07654, 0.07364, 0.07118, 0.06826,
-0.06336, 0.0642, 0.06112, 0.058, 0.05588, 0.05204, 0.0504, 0.04764, 0.04822, 0.04706, 0.0452, 0.04634, 0.0463, 0.04516, 0.04536, 0.04628, 0.04756, 0.04664,
-0.04762, 0.047, 0.04806, 0.04702, 0.0493, 0.05106, 0.05086, 0.05018, 0.0511, 0.05274, 0.05242, 0.05304, 0.05414, 0.05418, 0.055, 0.0557, 0.0576, 0.05894,
-0.06062, 0.0623, 0.0626, 0.06342, 0.0648, 0.06484, 0.06524, 0.06528, 0.06644, 
-//546
-0.12488, 0.14818, 0.18446, 0.22932, 0.26554, 0.28712, 0.29556, 0.29988, 0.30034, 0.30088, 0.30348, 0.3056, 0.3109, 0.31186, 0.31748, 0.3265, 0.3432, 0.36606,
-0.39586, 0.43272, 0.47244, 0.51248, 0.54806, 0.57846, 0.60188, 0.61784, 0.62408, 0.62632, 0.62406, 0.6173, 0.61232, 0.60324, 0.59188, 0.57836, 0.56414, 0.54778,
-0.52794, 0.51098, 0.49336, 0.47372, 0.4522, 0.43112, 0.40868, 0.3854, 0.3624, 0.34088, 0.32346, 0.30922, 0.29982, 0.29166, 0.28736, 0.27976, 0.27494, 0.26874,
-0.26478, 0.26134, 0.25738, 0.25748, 0.2595, 0.26306, 0.26668, 0.26978, 0.27488, 0.28062, 0.28528, 0.28928, 0.29066, 0.28742, 0.28454, 0.28222, 0.2797, 0.28028,
-0.2857, 0.28976, 0.29712, 0.30306, 0.30872, 0.3122, 0.31816, 0.32122, 0.33096, 
-//547
-0.11698, 0.13302, 0.15856, 0.18116, 0.19822, 0.2053, 0.20728, 0.20762, 0.20836, 0.20796, 0.2102, 0.2126, 0.21748, 0.2204, 0.22438, 0.23072, 0.2427, 0.2622,
-0.2889, 0.32094, 0.3538, 0.38168, 0.40542, 0.42454, 0.4379, 0.44516, 0.44842, 0.44808, 0.4473, 0.44456, 0.44082, 0.43618, 0.42954, 0.4212, 0.41318, 0.40202,
-0.38886, 0.3768, 0.36314, 0.34792, 0.33332, 0.31564, 0.29684, 0.27562, 0.2564, 0.23752, 0.22284, 0.21074, 0.20176, 0.19552, 0.1901, 0.18532, 0.17998, 0.175,
-0.17162, 0.16872, 0.1666, 0.16594, 0.16764, 0.17204, 0.17476, 0.17876, 0.18232, 0.18786, 0.1925, 0.19546, 0.19512, 0.194, 0.1914, 0.18968, 0.18962, 0.19058,
-0.19638, 0.20128, 0.20722, 0.21216, 0.21646, 0.2211, 0.22736, 0.23028, 0.23886, 
-//548
-0.09788, 0.11006, 0.12346, 0.13494, 0.13962, 0.14238, 0.14444, 0.14322, 0.14412, 0.14422, 0.14692, 0.14842, 0.15072, 0.1537, 0.15642, 0.16242, 0.17168, 0.18498,
-0.20528, 0.22902, 0.25352, 0.27498, 0.29404, 0.30774, 0.31768, 0.32304, 0.32648, 0.32692, 0.32584, 0.32312, 0.32108, 0.31642, 0.31072, 0.30338, 0.29584,
-0.28494, 0.2724, 0.26302, 0.25184, 0.23932, 0.22668, 0.21182, 0.19692, 0.18114, 0.16718, 0.1547, 0.14496, 0.13686, 0.1317, 0.1274, 0.1239, 0.1204, 0.11696,
-0.11434, 0.11198, 0.10914, 0.10792, 0.1063, 0.10788, 0.10958, 0.11138, 0.11258, 0.11556, 0.11858, 0.1217, 0.12458, 0.12412, 0.12574, 0.12664, 0.1261, 0.12574,
-0.1282, 0.13106, 0.1344, 0.13786, 0.14276, 0.1458, 0.14778, 0.1518, 0.1535, 0.15998, 
-//549
-0.07972, 0.08306, 0.08846, 0.09384, 0.0961, 0.09704, 0.0973, 0.09692, 0.09652, 0.09678, 0.09796, 0.09888, 0.10082, 0.10134, 0.10408, 0.10764, 0.1142, 0.12358,
-0.1379, 0.15486, 0.17346, 0.18926, 0.20322, 0.21468, 0.22238, 0.2274, 0.22934, 0.22986, 0.22804, 0.22456, 0.22238, 0.21828, 0.2143, 0.20808, 0.20294, 0.19404,
-0.18298, 0.17664, 0.16796, 0.1584, 0.1484, 0.13878, 0.1281, 0.11718, 0.10778, 0.09928, 0.09238, 0.08804, 0.08396, 0.08144, 0.07958, 0.07842, 0.0776, 0.0761,
-0.07466, 0.07418, 0.07398, 0.07206, 0.0732, 0.07562, 0.07654, 0.07598, 0.07874, 0.081, 0.08354, 0.08382, 0.08474, 0.08516, 0.08622, 0.08516, 0.08596, 0.08668,
-0.08814, 0.09024, 0.09282, 0.0964, 0.1001, 0.10126, 0.10434, 0.10592, 0.10894, 
-//550
-0.05624, 0.05478, 0.05616, 0.05604, 0.05678, 0.05648, 0.05722, 0.05682, 0.05716, 0.0575, 0.05694, 0.05808, 0.05794, 0.05892, 0.05916, 0.06026, 0.06434, 0.0695,
-0.07746, 0.0883, 0.10192, 0.11534, 0.1279, 0.13816, 0.14688, 0.15256, 0.15434, 0.1537, 0.15198, 0.14896, 0.14436, 0.1387, 0.13404, 0.12724, 0.1201, 0.11256,
-0.10358, 0.09732, 0.09022, 0.08478, 0.07908, 0.07332, 0.0677, 0.06312, 0.05926, 0.0564, 0.05464, 0.0529, 0.05182, 0.05132, 0.05016, 0.0502, 0.04902, 0.04996,
-0.04956, 0.04992, 0.05006, 0.0498, 0.0509, 0.05204, 0.05334, 0.0529, 0.05378, 0.05518, 0.0562, 0.05634, 0.05738, 0.05666, 0.05768, 0.05844, 0.06004, 0.06066,
-0.0621, 0.06348, 0.06554, 0.06672, 0.06828, 0.06988, 0.07038, 0.07226, 0.0743, 
-//551
-0.10034, 0.11344, 0.13362, 0.15266, 0.16648, 0.17174, 0.17502, 0.1753, 0.17614, 0.17728, 0.18, 0.18296, 0.18604, 0.18924, 0.1938, 0.20072, 0.21318, 0.23158,
-0.2599, 0.29504, 0.33562, 0.37618, 0.41274, 0.44526, 0.4696, 0.48398, 0.492, 0.49284, 0.49108, 0.48552, 0.47998, 0.47148, 0.4621, 0.44958, 0.43464, 0.4168,
-0.39704, 0.37958, 0.36046, 0.34002, 0.31898, 0.29536, 0.27206, 0.24768, 0.22514, 0.20382, 0.18846, 0.17618, 0.16898, 0.16238, 0.15846, 0.15302, 0.14892,
-0.14592, 0.14136, 0.13934, 0.1385, 0.13736, 0.13992, 0.14468, 0.14864, 0.1519, 0.15822, 0.16432, 0.16926, 0.17166, 0.17308, 0.17238, 0.1701, 0.1675, 0.16872,
-0.16946, 0.17528, 0.18116, 0.18848, 0.19426, 0.19952, 0.20304, 0.2077, 0.21392, 0.22098, 
-//552
-0.08976, 0.09686, 0.10604, 0.11184, 0.11794, 0.11924, 0.12006, 0.11828, 0.11974, 0.11984, 0.12128, 0.12334, 0.1249, 0.12768, 0.13106, 0.13566, 0.14462, 0.15888,
-0.17962, 0.20688, 0.23876, 0.27168, 0.30246, 0.32958, 0.35134, 0.365, 0.37024, 0.37044, 0.3689, 0.36398, 0.35712, 0.34782, 0.3369, 0.3247, 0.31016, 0.29416,
-0.277, 0.26098, 0.24534, 0.22874, 0.21374, 0.19606, 0.17986, 0.16368, 0.1478, 0.13508, 0.1259, 0.11894, 0.11432, 0.1101, 0.10806, 0.10524, 0.10276, 0.09994,
-0.09748, 0.09588, 0.09524, 0.0935, 0.09568, 0.09824, 0.09954, 0.1017, 0.10498, 0.10802, 0.11022, 0.11228, 0.11248, 0.11216, 0.11096, 0.11082, 0.11094, 0.11206,
-0.11552, 0.11832, 0.12218, 0.12686, 0.12968, 0.13318, 0.13528, 0.13702, 0.14236, 
-//553
-0.06506, 0.06794, 0.0715, 0.07474, 0.07586, 0.07724, 0.07776, 0.07796, 0.07822, 0.07828, 0.07946, 0.07998, 0.0806, 0.08164, 0.08292, 0.08556, 0.09048, 0.09934,
-0.11222, 0.13148, 0.15488, 0.18008, 0.20612, 0.22958, 0.24902, 0.26182, 0.26796, 0.26756, 0.26328, 0.25758, 0.25024, 0.2404, 0.22904, 0.21684, 0.20382, 0.18924,
-0.17414, 0.16284, 0.15048, 0.13766, 0.12642, 0.11538, 0.10492, 0.09504, 0.0868, 0.0805, 0.07548, 0.07264, 0.07066, 0.06888, 0.068, 0.0672, 0.06612, 0.06534,
-0.06398, 0.06322, 0.06256, 0.0615, 0.0628, 0.0639, 0.06576, 0.06624, 0.06834, 0.07022, 0.07214, 0.073, 0.07356, 0.07384, 0.07386, 0.07358, 0.07336, 0.07466,
-0.077, 0.07792, 0.07992, 0.08238, 0.0852, 0.0871, 0.0887, 0.0905, 0.09212, 
-//554
-0.0912, 0.1006, 0.11596, 0.12788, 0.13572, 0.1385, 0.14164, 0.14194, 0.14334, 0.14426, 0.1452, 0.14946, 0.15242, 0.15544, 0.15928, 0.16474, 0.17586, 0.19298,
-0.22012, 0.25596, 0.29878, 0.34616, 0.39636, 0.44394, 0.48576, 0.51728, 0.53442, 0.5404, 0.53662, 0.52802, 0.51582, 0.49842, 0.47912, 0.4565, 0.43174, 0.4067,
-0.37884, 0.35532, 0.33022, 0.30574, 0.28152, 0.2571, 0.23324, 0.20838, 0.18602, 0.16642, 0.1532, 0.14148, 0.13318, 0.12778, 0.12348, 0.11974, 0.11608, 0.11378,
-0.11146, 0.10894, 0.10894, 0.10852, 0.11062, 0.1144, 0.11646, 0.11792, 0.1214, 0.12666, 0.13152, 0.13432, 0.13582, 0.13564, 0.13528, 0.13538, 0.13578, 0.13772,
-0.1442, 0.14892, 0.15596, 0.16166, 0.16752, 0.17152, 0.17532, 0.17848, 0.18444, 
-//555
-0.07192, 0.07692, 0.08378, 0.08872, 0.09308, 0.09464, 0.09642, 0.09674, 0.0979, 0.0991, 0.10038, 0.10194, 0.1047, 0.10608, 0.10812, 0.1126, 0.12086, 0.13378,
-0.153, 0.17984, 0.21244, 0.25042, 0.28912, 0.33028, 0.36676, 0.3948, 0.40998, 0.4134, 0.4093, 0.39988, 0.38928, 0.37336, 0.35642, 0.33646, 0.3149, 0.2926,
-0.26894, 0.24946, 0.22782, 0.20786, 0.18798, 0.16882, 0.15058, 0.1327, 0.11828, 0.1051, 0.09674, 0.09, 0.08588, 0.0824, 0.08044, 0.07942, 0.07798, 0.0759,
-0.07408, 0.07378, 0.0734, 0.07328, 0.0753, 0.07756, 0.07936, 0.0806, 0.08494, 0.08792, 0.09106, 0.09194, 0.09316, 0.09322, 0.09192, 0.09242, 0.0929, 0.09502,
-0.099, 0.10276, 0.1086, 0.11324, 0.1183, 0.1209, 0.12542, 0.1269, 0.12858, 
-//556
-0.05582, 0.05728, 0.0618, 0.06718, 0.06166, 0.06266, 0.06254, 0.06298, 0.06394, 0.06396, 0.06642, 0.06814, 0.07024, 0.07172, 0.07408, 0.07636, 0.08214, 0.09124,
-0.10518, 0.12342, 0.14746, 0.17282, 0.20114, 0.2272, 0.25182, 0.26766, 0.27766, 0.27884, 0.27692, 0.27182, 0.26398, 0.25528, 0.24294, 0.2295, 0.21438, 0.19666,
-0.17798, 0.16534, 0.14934, 0.13544, 0.12228, 0.10822, 0.09646, 0.08442, 0.07516, 0.0664, 0.06144, 0.05708, 0.0542, 0.05226, 0.05008, 0.05008, 0.04818, 0.04754,
-0.04792, 0.04732, 0.04854, 0.04684, 0.0497, 0.0526, 0.05398, 0.05372, 0.05524, 0.05888, 0.06042, 0.06084, 0.06142, 0.06216, 0.06196, 0.06142, 0.06326, 0.06582,
-0.0699, 0.07256, 0.07656, 0.07888, 0.08202, 0.08414, 0.0863, 0.088, 0.0892, 
-//557
-0.07254, 0.0755, 0.07986, 0.08404, 0.08606, 0.08518, 0.08552, 0.08618, 0.08616, 0.08688, 0.08814, 0.08974, 0.09272, 0.09414, 0.09726, 0.10108, 0.10842, 0.12016,
-0.13854, 0.16466, 0.1981, 0.23668, 0.28046, 0.32634, 0.36854, 0.4017, 0.42024, 0.42532, 0.42158, 0.40962, 0.39542, 0.37736, 0.35574, 0.3313, 0.3067, 0.2807,
-0.25444, 0.23244, 0.21052, 0.19028, 0.17162, 0.15256, 0.13542, 0.11908, 0.10636, 0.095, 0.0868, 0.08112, 0.07742, 0.07434, 0.07302, 0.07116, 0.0696, 0.06884,
-0.0681, 0.0673, 0.06828, 0.06658, 0.06866, 0.07034, 0.07234, 0.07292, 0.07346, 0.07736, 0.0804, 0.08166, 0.08332, 0.08268, 0.08326, 0.08402, 0.08456, 0.0865,
-0.08922, 0.09242, 0.09694, 0.10032, 0.1034, 0.10792, 0.10992, 0.11168, 0.11598, 
-//558
-0.1339, 0.16378, 0.21862, 0.30076, 0.40346, 0.50832, 0.58024, 0.61514, 0.62826, 0.63246, 0.6349, 0.63942, 0.64364, 0.64554, 0.64674, 0.65122, 0.65798, 0.66778,
-0.67904, 0.69052, 0.69782, 0.70342, 0.70846, 0.7124, 0.71584, 0.7171, 0.7172, 0.71648, 0.7149, 0.71362, 0.71312, 0.71242, 0.7097, 0.70702, 0.7054, 0.70294,
-0.69958, 0.69696, 0.6928, 0.68772, 0.68208, 0.67578, 0.66804, 0.65828, 0.64804, 0.63682, 0.62744, 0.61766, 0.6101, 0.60492, 0.60108, 0.59772, 0.59256, 0.58864,
-0.58318, 0.58174, 0.58186, 0.5811, 0.58374, 0.5876, 0.58822, 0.59172, 0.5943, 0.5988, 0.60366, 0.60558, 0.60636, 0.60686, 0.60362, 0.60366, 0.60316, 0.60574,
-0.60992, 0.6128, 0.61892, 0.62342, 0.62924, 0.63112, 0.63196, 0.63534, 0.6357, 
-//559
-0.12672, 0.1554, 0.20694, 0.27728, 0.35854, 0.42574, 0.4646, 0.47752, 0.4824, 0.4835, 0.4845, 0.48564, 0.4869, 0.48706, 0.48636, 0.48966, 0.49556, 0.5029,
-0.51194, 0.5202, 0.52692, 0.53226, 0.53448, 0.5383, 0.53976, 0.53954, 0.53952, 0.5384, 0.53698, 0.53462, 0.533, 0.53146, 0.52914, 0.5274, 0.52598, 0.52422,
-0.52142, 0.51934, 0.51688, 0.51236, 0.50896, 0.50366, 0.49952, 0.49218, 0.48608, 0.47896, 0.4725, 0.4668, 0.46072, 0.45846, 0.45438, 0.45138, 0.44786, 0.44518,
-0.44174, 0.4375, 0.43714, 0.4362, 0.43716, 0.43884, 0.4397, 0.44128, 0.44398, 0.44614, 0.44736, 0.44728, 0.44768, 0.44686, 0.44402, 0.44334, 0.44144, 0.44104,
-0.44474, 0.4457, 0.4499, 0.45274, 0.45574, 0.45508, 0.45746, 0.45748, 0.45958, 
-//560
-0.12952, 0.15534, 0.19962, 0.25122, 0.29896, 0.3282, 0.3407, 0.34362, 0.34248, 0.34206, 0.34324, 0.34266, 0.34486, 0.34386, 0.34472, 0.34614, 0.35142, 0.35826,
-0.36744, 0.37664, 0.38336, 0.3881, 0.39072, 0.39288, 0.39418, 0.39298, 0.39244, 0.38998, 0.38934, 0.38682, 0.38602, 0.38394, 0.38218, 0.38104, 0.37832, 0.3762,
-0.3756, 0.37382, 0.3707, 0.36882, 0.3661, 0.36412, 0.36112, 0.3568, 0.35254, 0.34782, 0.34456, 0.34036, 0.33798, 0.33406, 0.33194, 0.32968, 0.32562, 0.32414,
-0.32142, 0.31976, 0.31826, 0.31728, 0.31722, 0.31886, 0.31904, 0.3194, 0.31996, 0.3193, 0.32124, 0.32174, 0.3205, 0.31954, 0.31886, 0.3173, 0.31758, 0.31738,
-0.3188, 0.31832, 0.32158, 0.32276, 0.32276, 0.32338, 0.32486, 0.3253, 0.3253, 
-//561
-0.12004, 0.14026, 0.17318, 0.20424, 0.22768, 0.2392, 0.24406, 0.24364, 0.24372, 0.24294, 0.24402, 0.24336, 0.24512, 0.24388, 0.24276, 0.24516, 0.24878, 0.25342,
-0.25914, 0.26552, 0.26926, 0.27048, 0.2731, 0.27406, 0.27508, 0.27528, 0.27386, 0.27314, 0.27216, 0.27058, 0.26976, 0.26882, 0.26708, 0.26546, 0.2637, 0.26242,
-0.26066, 0.26048, 0.25854, 0.25708, 0.25606, 0.25396, 0.25182, 0.24924, 0.24562, 0.2431, 0.2396, 0.23694, 0.23372, 0.23142, 0.22996, 0.2278, 0.2249, 0.2227,
-0.2214, 0.21978, 0.21912, 0.21816, 0.21888, 0.2188, 0.21852, 0.2185, 0.21832, 0.21816, 0.21844, 0.21834, 0.21812, 0.21734, 0.2154, 0.21614, 0.21582, 0.2145,
-0.21692, 0.2169, 0.21878, 0.2202, 0.22232, 0.22214, 0.22446, 0.22484, 0.22556, 
-//562
-0.10238, 0.11512, 0.13294, 0.14758, 0.15732, 0.16122, 0.16276, 0.16254, 0.1628, 0.16278, 0.16268, 0.1638, 0.16394, 0.16356, 0.16248, 0.16456, 0.16686, 0.17026,
-0.1754, 0.17882, 0.18204, 0.18322, 0.1835, 0.1849, 0.18578, 0.18616, 0.18586, 0.18516, 0.18452, 0.18258, 0.18324, 0.18324, 0.18168, 0.18026, 0.17936, 0.17862,
-0.17582, 0.17608, 0.17456, 0.1734, 0.17214, 0.17034, 0.16804, 0.16608, 0.1637, 0.16114, 0.15952, 0.15652, 0.15546, 0.1536, 0.15266, 0.1511, 0.1498, 0.14866,
-0.14846, 0.14586, 0.14522, 0.14396, 0.1436, 0.14458, 0.14404, 0.14554, 0.14554, 0.14468, 0.14714, 0.1464, 0.14602, 0.14554, 0.145, 0.144, 0.1455, 0.14532,
-0.1465, 0.1484, 0.14954, 0.15114, 0.15138, 0.15364, 0.15292, 0.1553, 0.15496, 
-//563
-0.0785, 0.08416, 0.09232, 0.09758, 0.10036, 0.10246, 0.10306, 0.10268, 0.10332, 0.10264, 0.10184, 0.1026, 0.10272, 0.1026, 0.10236, 0.10254, 0.1044, 0.10716,
-0.11008, 0.11244, 0.11448, 0.11646, 0.11672, 0.11688, 0.1176, 0.11754, 0.11772, 0.11742, 0.11692, 0.11586, 0.11532, 0.11416, 0.11316, 0.11246, 0.11148, 0.11084,
-0.1096, 0.10884, 0.10868, 0.1081, 0.1072, 0.10552, 0.10518, 0.10372, 0.1023, 0.10026, 0.09992, 0.09734, 0.09708, 0.0954, 0.09472, 0.09364, 0.09262, 0.09128,
-0.091, 0.09014, 0.09004, 0.08864, 0.08996, 0.09102, 0.09174, 0.09212, 0.0935, 0.09422, 0.09446, 0.09416, 0.0954, 0.09582, 0.09608, 0.095, 0.0966, 0.09614,
-0.09614, 0.09726, 0.0982, 0.09938, 0.09998, 0.10058, 0.10086, 0.10114, 0.1027, 
-//564
-0.04936, 0.05216, 0.05472, 0.05506, 0.0557, 0.05602, 0.05568, 0.05536, 0.05492, 0.0558, 0.05622, 0.0572, 0.05754, 0.05834, 0.05906, 0.0589, 0.06058, 0.06216,
-0.06374, 0.065, 0.066, 0.06686, 0.0666, 0.06622, 0.06714, 0.0669, 0.06662, 0.0651, 0.06538, 0.0647, 0.06522, 0.06532, 0.06508, 0.06402, 0.06422, 0.06272,
-0.06096, 0.06202, 0.06136, 0.06074, 0.0606, 0.059, 0.05844, 0.05812, 0.05768, 0.0561, 0.0566, 0.0557, 0.05482, 0.05494, 0.05416, 0.05324, 0.05306, 0.0525,
-0.0527, 0.05306, 0.0537, 0.0517, 0.0543, 0.05508, 0.05548, 0.05588, 0.05622, 0.05756, 0.05784, 0.0577, 0.05894, 0.05934, 0.05906, 0.06, 0.06018, 0.06066,
-0.06216, 0.06246, 0.06404, 0.0646, 0.0655, 0.06564, 0.06688, 0.06664, 0.06646, 
-//565
-0.0481, 0.04622, 0.04648, 0.04512, 0.04494, 0.04494, 0.045, 0.0466, 0.04606, 0.04608, 0.0463, 0.04762, 0.0474, 0.04766, 0.04878, 0.05004, 0.05034, 0.05158,
-0.05272, 0.0536, 0.05408, 0.05488, 0.0555, 0.055, 0.05576, 0.05548, 0.05536, 0.05508, 0.05518, 0.0539, 0.05346, 0.05316, 0.0531, 0.05258, 0.05226, 0.05134,
-0.04964, 0.04846, 0.04834, 0.048, 0.04792, 0.04784, 0.0471, 0.04732, 0.0468, 0.04646, 0.04658, 0.04702, 0.04646, 0.0467, 0.04664, 0.04712, 0.04654, 0.04736,
-0.04612, 0.0464, 0.04614, 0.04626, 0.04764, 0.04868, 0.04874, 0.04974, 0.05034, 0.05066, 0.05064, 0.05028, 0.0513, 0.05262, 0.05398, 0.05454, 0.05516, 0.0564,
-0.0574, 0.05824, 0.0596, 0.06034, 0.06136, 0.06162, 0.06154, 0.06158, 0.06232, 
-//566
-0.14756, 0.17918, 0.23576, 0.31684, 0.41598, 0.51218, 0.57614, 0.6047, 0.61614, 0.6196, 0.6219, 0.62708, 0.63022, 0.63354, 0.63688, 0.6435, 0.6544, 0.66868,
-0.68728, 0.70462, 0.72016, 0.73222, 0.7421, 0.75008, 0.75758, 0.76136, 0.76218, 0.76272, 0.76202, 0.75936, 0.75774, 0.75502, 0.74942, 0.74458, 0.7398, 0.73256,
-0.72382, 0.71736, 0.70656, 0.69782, 0.68664, 0.67178, 0.65644, 0.63924, 0.61886, 0.60204, 0.58588, 0.57394, 0.56178, 0.5543, 0.54832, 0.54214, 0.53382, 0.52874,
-0.52266, 0.51854, 0.51608, 0.51524, 0.51638, 0.52056, 0.52204, 0.52738, 0.53246, 0.53954, 0.54408, 0.54978, 0.54968, 0.54924, 0.54516, 0.54532, 0.54324,
-0.54496, 0.55234, 0.5577, 0.56386, 0.57092, 0.57774, 0.57884, 0.58502, 0.5902, 0.59722, 
-//567
-0.14344, 0.17242, 0.22322, 0.2903, 0.3622, 0.41492, 0.44042, 0.44858, 0.44944, 0.45134, 0.45388, 0.45654, 0.45848, 0.4603, 0.46306, 0.4689, 0.47896, 0.49332,
-0.5113, 0.53014, 0.54574, 0.55712, 0.56338, 0.56976, 0.57436, 0.57484, 0.57586, 0.57418, 0.57288, 0.5711, 0.56878, 0.56572, 0.56376, 0.55968, 0.55642, 0.55216,
-0.54708, 0.54352, 0.53696, 0.52898, 0.51954, 0.51082, 0.50012, 0.48632, 0.47266, 0.45748, 0.44444, 0.43346, 0.42472, 0.41854, 0.41184, 0.40698, 0.40054,
-0.39618, 0.39032, 0.387, 0.38408, 0.38352, 0.3842, 0.38698, 0.39086, 0.39636, 0.40074, 0.40544, 0.4104, 0.41136, 0.41032, 0.40986, 0.40476, 0.40398, 0.40248,
-0.4041, 0.40798, 0.41174, 0.41682, 0.4213, 0.42502, 0.4301, 0.4333, 0.43646, 0.44456, 
-//568
-0.13574, 0.16038, 0.20134, 0.24912, 0.29188, 0.3161, 0.32712, 0.32998, 0.32942, 0.3281, 0.32974, 0.33098, 0.3343, 0.33482, 0.33688, 0.33994, 0.34724, 0.35746,
-0.36994, 0.38194, 0.39278, 0.39948, 0.40422, 0.4082, 0.40932, 0.41092, 0.41066, 0.4096, 0.4075, 0.40488, 0.40418, 0.40294, 0.40076, 0.399, 0.3961, 0.39428,
-0.38954, 0.38546, 0.38232, 0.37818, 0.37188, 0.3664, 0.3588, 0.34944, 0.34118, 0.32992, 0.32108, 0.31358, 0.30778, 0.30266, 0.29888, 0.2945, 0.29048, 0.28638,
-0.28272, 0.27922, 0.27844, 0.27658, 0.27688, 0.28086, 0.2814, 0.28434, 0.28588, 0.28812, 0.29028, 0.2913, 0.29118, 0.28854, 0.28706, 0.28494, 0.2846, 0.28486,
-0.28676, 0.29068, 0.29472, 0.29808, 0.3015, 0.303, 0.30488, 0.30586, 0.31178, 
-//569
-0.12104, 0.13938, 0.16838, 0.1959, 0.21634, 0.22612, 0.23158, 0.23198, 0.23246, 0.23308, 0.23412, 0.23566, 0.23682, 0.23814, 0.2401, 0.24252, 0.24742, 0.25484,
-0.26462, 0.27368, 0.28024, 0.28382, 0.28814, 0.29202, 0.2929, 0.29378, 0.29354, 0.29244, 0.29222, 0.29022, 0.289, 0.28814, 0.28662, 0.28406, 0.28292, 0.27918,
-0.27586, 0.27462, 0.27024, 0.26654, 0.2626, 0.25686, 0.25046, 0.24276, 0.23446, 0.2249, 0.21684, 0.21058, 0.20486, 0.2008, 0.19824, 0.19494, 0.19202, 0.18934,
-0.1856, 0.183, 0.18192, 0.18126, 0.18282, 0.18592, 0.18724, 0.18886, 0.1905, 0.19248, 0.19516, 0.19614, 0.19704, 0.1963, 0.19356, 0.19294, 0.19224, 0.19342,
-0.19732, 0.19808, 0.20294, 0.2059, 0.20788, 0.21024, 0.21264, 0.21194, 0.21662, 
-//570
-0.09274, 0.10678, 0.1218, 0.13444, 0.14422, 0.14928, 0.14974, 0.15, 0.15022, 0.15018, 0.15124, 0.15154, 0.15344, 0.15362, 0.1547, 0.1568, 0.15994, 0.16598,
-0.17262, 0.1796, 0.18462, 0.18856, 0.19132, 0.1924, 0.19404, 0.19372, 0.1932, 0.19172, 0.19034, 0.18914, 0.18834, 0.18724, 0.18676, 0.1841, 0.18308, 0.1808,
-0.17862, 0.17888, 0.1765, 0.17414, 0.17134, 0.16738, 0.16338, 0.15846, 0.15286, 0.14652, 0.1421, 0.13876, 0.13502, 0.13346, 0.13174, 0.1301, 0.1268, 0.12544,
-0.12418, 0.12228, 0.12192, 0.1206, 0.12206, 0.12404, 0.1252, 0.12656, 0.12796, 0.13062, 0.13226, 0.1334, 0.1337, 0.13266, 0.13142, 0.13158, 0.13028, 0.13198,
-0.13406, 0.1357, 0.13794, 0.14166, 0.143, 0.14484, 0.1472, 0.14884, 0.1494, 
-//571
-0.0755, 0.08154, 0.08714, 0.0903, 0.09358, 0.09308, 0.0942, 0.09358, 0.0917, 0.09198, 0.09226, 0.09308, 0.09396, 0.0942, 0.09554, 0.09652, 0.09988, 0.10392,
-0.108, 0.11308, 0.11648, 0.11992, 0.11984, 0.12102, 0.12118, 0.12148, 0.12032, 0.1196, 0.1191, 0.11758, 0.1175, 0.11772, 0.11668, 0.1157, 0.11498, 0.11302,
-0.11192, 0.10948, 0.10862, 0.1069, 0.10372, 0.10246, 0.1004, 0.09696, 0.09332, 0.08932, 0.0864, 0.0835, 0.08284, 0.08068, 0.07962, 0.07842, 0.07772, 0.07728,
-0.07572, 0.07586, 0.07514, 0.07416, 0.07626, 0.07704, 0.07788, 0.0786, 0.0794, 0.08046, 0.08114, 0.0818, 0.08152, 0.0822, 0.0819, 0.08262, 0.08408, 0.08466,
-0.08508, 0.08702, 0.08946, 0.09046, 0.0922, 0.09276, 0.09516, 0.09542, 0.0959, 
-//572
-0.05268, 0.05124, 0.0514, 0.05136, 0.05196, 0.05086, 0.05078, 0.0515, 0.05262, 0.0534, 0.05362, 0.05506, 0.05594, 0.05678, 0.05802, 0.05908, 0.06142, 0.06364,
-0.0658, 0.0683, 0.06932, 0.07118, 0.07116, 0.0731, 0.07418, 0.07474, 0.07514, 0.07454, 0.07428, 0.07444, 0.0731, 0.07192, 0.0715, 0.07014, 0.06882, 0.06668,
-0.06544, 0.06374, 0.06288, 0.06132, 0.0601, 0.059, 0.05694, 0.05544, 0.0539, 0.05292, 0.05222, 0.05156, 0.05244, 0.05152, 0.05124, 0.05118, 0.05068, 0.0516,
-0.05222, 0.0527, 0.05256, 0.05234, 0.05372, 0.0555, 0.05658, 0.0565, 0.0568, 0.05712, 0.0585, 0.0587, 0.05886, 0.05936, 0.05978, 0.06026, 0.06262, 0.06424,
-0.06504, 0.06622, 0.06682, 0.06792, 0.06816, 0.06854, 0.0687, 0.06826, 0.06908, 
-//573
-0.04422, 0.04288, 0.04304, 0.04328, 0.043, 0.04334, 0.04334, 0.04374, 0.04364, 0.04354, 0.04354, 0.04414, 0.04458, 0.04422, 0.04482, 0.04616, 0.04822, 0.04984,
-0.0514, 0.05394, 0.05558, 0.0568, 0.05638, 0.05832, 0.05996, 0.05954, 0.05992, 0.05838, 0.05876, 0.05716, 0.0565, 0.05492, 0.05342, 0.05238, 0.05174, 0.05076,
-0.04954, 0.04874, 0.04716, 0.04654, 0.04536, 0.04492, 0.04388, 0.04402, 0.04386, 0.04272, 0.04316, 0.042, 0.04292, 0.0425, 0.04278, 0.04242, 0.04222, 0.04248,
-0.04234, 0.04134, 0.04218, 0.04134, 0.04332, 0.04492, 0.0455, 0.04544, 0.046, 0.04778, 0.04866, 0.04956, 0.04988, 0.04952, 0.04964, 0.05046, 0.05108, 0.05178,
-0.05338, 0.05358, 0.05612, 0.05706, 0.05786, 0.05788, 0.05854, 0.05858, 0.05936, 
-//574
-0.12468, 0.14934, 0.19644, 0.25274, 0.31058, 0.34824, 0.3696, 0.37638, 0.3797, 0.3809, 0.38458, 0.3876, 0.3925, 0.39638, 0.4006, 0.40874, 0.42022, 0.43756,
-0.45964, 0.48482, 0.50772, 0.52868, 0.54496, 0.55942, 0.56936, 0.57394, 0.57648, 0.57594, 0.57396, 0.57094, 0.56752, 0.56464, 0.55826, 0.5514, 0.5442, 0.53558,
-0.52532, 0.51366, 0.50316, 0.48828, 0.47148, 0.45452, 0.43658, 0.41454, 0.39356, 0.37104, 0.35392, 0.33778, 0.3264, 0.31614, 0.31092, 0.30382, 0.29774, 0.2924,
-0.28646, 0.28138, 0.28078, 0.28062, 0.28306, 0.28822, 0.29244, 0.29728, 0.30328, 0.30898, 0.31346, 0.31952, 0.321, 0.32122, 0.31754, 0.3179, 0.31786, 0.32082,
-0.32854, 0.33628, 0.3445, 0.35432, 0.36004, 0.36138, 0.36776, 0.3705, 0.37776, 
-//575
-0.11728, 0.14008, 0.17264, 0.2101, 0.24158, 0.2591, 0.26522, 0.26826, 0.26982, 0.2701, 0.27288, 0.27724, 0.28104, 0.28404, 0.28784, 0.29386, 0.30438, 0.3204,
-0.33962, 0.35978, 0.37798, 0.39158, 0.40284, 0.41188, 0.41768, 0.42, 0.42116, 0.42052, 0.41964, 0.41756, 0.41614, 0.41394, 0.4085, 0.40272, 0.39744, 0.39042,
-0.38182, 0.37352, 0.36352, 0.3534, 0.34144, 0.32886, 0.3138, 0.29508, 0.27986, 0.2632, 0.24924, 0.23942, 0.23074, 0.2238, 0.21804, 0.21266, 0.20792, 0.20308,
-0.19884, 0.197, 0.19496, 0.19372, 0.19506, 0.19894, 0.2017, 0.20484, 0.20742, 0.21406, 0.21818, 0.22208, 0.22332, 0.22254, 0.21982, 0.21958, 0.21796, 0.22036,
-0.22338, 0.2283, 0.23444, 0.2407, 0.24318, 0.24442, 0.25092, 0.25348, 0.26026, 
-//576
-0.0998, 0.11384, 0.1327, 0.1535, 0.1687, 0.17604, 0.1787, 0.17948, 0.18104, 0.18152, 0.18366, 0.18648, 0.1895, 0.19208, 0.1944, 0.1995, 0.20802, 0.21956,
-0.23502, 0.25112, 0.26576, 0.27758, 0.28664, 0.29354, 0.29878, 0.30046, 0.30004, 0.30026, 0.29784, 0.2965, 0.29586, 0.29294, 0.28996, 0.28606, 0.28092, 0.27608,
-0.2694, 0.263, 0.25646, 0.24684, 0.23812, 0.22718, 0.21534, 0.2012, 0.1864, 0.17342, 0.16236, 0.15524, 0.14762, 0.1433, 0.14066, 0.13798, 0.13468, 0.13062,
-0.1288, 0.1274, 0.1263, 0.12512, 0.12736, 0.13122, 0.13392, 0.136, 0.14016, 0.14416, 0.14742, 0.15054, 0.15172, 0.15118, 0.14944, 0.1485, 0.14904, 0.14854,
-0.15336, 0.15686, 0.16176, 0.16628, 0.16914, 0.17312, 0.17436, 0.17618, 0.1807, 
-//577
-0.08632, 0.09494, 0.1072, 0.11464, 0.11956, 0.12054, 0.12226, 0.12378, 0.12456, 0.12546, 0.12686, 0.12838, 0.13046, 0.13062, 0.13258, 0.136, 0.14168, 0.14904,
-0.16044, 0.17142, 0.18148, 0.18806, 0.19356, 0.19912, 0.2029, 0.2047, 0.20574, 0.20522, 0.2047, 0.20214, 0.20046, 0.19816, 0.19566, 0.1923, 0.18886, 0.18412,
-0.1782, 0.17494, 0.1684, 0.16298, 0.15558, 0.14672, 0.13914, 0.13018, 0.12088, 0.11142, 0.10542, 0.09912, 0.09544, 0.09224, 0.09088, 0.0892, 0.08838, 0.08668,
-0.0858, 0.08344, 0.08372, 0.08282, 0.08424, 0.0868, 0.08798, 0.08856, 0.0913, 0.09354, 0.09608, 0.0975, 0.09816, 0.0973, 0.09776, 0.09534, 0.09592, 0.09736,
-0.09964, 0.10188, 0.10542, 0.10844, 0.10924, 0.1126, 0.11616, 0.11862, 0.122, 
-//578
-0.05442, 0.06078, 0.0641, 0.06792, 0.07004, 0.0703, 0.07118, 0.07244, 0.07046, 0.07212, 0.07232, 0.07332, 0.0747, 0.07474, 0.0759, 0.07764, 0.08104, 0.08852,
-0.09556, 0.10418, 0.11278, 0.11842, 0.1245, 0.1279, 0.13218, 0.13204, 0.1321, 0.1307, 0.13008, 0.12838, 0.12686, 0.12538, 0.12228, 0.12014, 0.11818, 0.11426,
-0.10928, 0.10654, 0.10162, 0.09702, 0.09216, 0.08734, 0.08266, 0.07612, 0.07124, 0.06482, 0.06106, 0.05856, 0.0564, 0.05516, 0.05378, 0.05272, 0.0519, 0.051,
-0.04962, 0.0493, 0.04966, 0.0487, 0.05072, 0.05296, 0.05448, 0.05554, 0.0557, 0.05724, 0.05866, 0.0602, 0.06108, 0.0622, 0.06208, 0.06356, 0.06386, 0.06462,
-0.06702, 0.06922, 0.0716, 0.07246, 0.07492, 0.07492, 0.07688, 0.07878, 0.08, 
-//579
-0.04726, 0.04828, 0.04782, 0.04836, 0.04734, 0.0478, 0.0484, 0.04872, 0.04932, 0.0496, 0.05016, 0.0511, 0.0508, 0.05198, 0.05264, 0.05564, 0.05802, 0.06176,
-0.06668, 0.071, 0.07426, 0.079, 0.08204, 0.08542, 0.08872, 0.0896, 0.08994, 0.08866, 0.08766, 0.08458, 0.08278, 0.08112, 0.07798, 0.07428, 0.07138, 0.06834,
-0.06598, 0.06426, 0.06054, 0.05788, 0.0552, 0.05202, 0.05052, 0.04696, 0.04714, 0.04544, 0.04616, 0.04578, 0.04618, 0.04674, 0.04748, 0.0472, 0.04664, 0.04664,
-0.04764, 0.04812, 0.04746, 0.0461, 0.04832, 0.04982, 0.05002, 0.05078, 0.05116, 0.05244, 0.05316, 0.05432, 0.0547, 0.05544, 0.05622, 0.05706, 0.05824, 0.05954,
-0.0624, 0.06368, 0.06532, 0.06544, 0.0659, 0.06568, 0.06632, 0.06762, 0.06684, 
-//580
-0.13002, 0.1541, 0.19658, 0.24632, 0.2898, 0.31714, 0.33066, 0.33902, 0.34232, 0.34646, 0.3503, 0.35654, 0.36286, 0.36972, 0.37832, 0.38562, 0.40148, 0.4228,
-0.45204, 0.48612, 0.51822, 0.55152, 0.58106, 0.60896, 0.62994, 0.64386, 0.65114, 0.65228, 0.64972, 0.64418, 0.6365, 0.62778, 0.61462, 0.60064, 0.58406, 0.56514,
-0.54386, 0.52468, 0.50372, 0.48114, 0.45786, 0.43164, 0.40478, 0.37618, 0.34718, 0.32014, 0.29956, 0.28356, 0.27144, 0.26236, 0.25638, 0.2488, 0.2418, 0.23452,
-0.22936, 0.2264, 0.2253, 0.2251, 0.2291, 0.23466, 0.23976, 0.24524, 0.25238, 0.25964, 0.26812, 0.27248, 0.27688, 0.2762, 0.27282, 0.27032, 0.26998, 0.27382,
-0.28044, 0.28788, 0.29774, 0.30614, 0.31324, 0.3176, 0.32376, 0.3314, 0.33978, 
-//581
-0.11742, 0.13704, 0.16686, 0.19872, 0.22548, 0.23884, 0.24428, 0.24542, 0.24776, 0.24896, 0.25104, 0.25438, 0.2579, 0.26134, 0.26612, 0.27312, 0.28412, 0.30198,
-0.32522, 0.35296, 0.38082, 0.40408, 0.42552, 0.44394, 0.4581, 0.465, 0.46658, 0.46528, 0.46214, 0.45756, 0.45186, 0.445, 0.43738, 0.4273, 0.41588, 0.40144,
-0.38616, 0.37006, 0.35536, 0.3378, 0.3206, 0.30226, 0.28352, 0.26354, 0.24344, 0.22704, 0.2123, 0.20188, 0.19434, 0.1887, 0.18466, 0.17988, 0.17664, 0.17126,
-0.16754, 0.16438, 0.16252, 0.16086, 0.16246, 0.16678, 0.17026, 0.1732, 0.17746, 0.18162, 0.1858, 0.188, 0.1907, 0.18848, 0.18488, 0.1833, 0.1822, 0.18294,
-0.18674, 0.18988, 0.19588, 0.20142, 0.20504, 0.2095, 0.21314, 0.21664, 0.22288, 
-//582
-0.09974, 0.1116, 0.1289, 0.1456, 0.15646, 0.16122, 0.16366, 0.16466, 0.16622, 0.16722, 0.16982, 0.17182, 0.17408, 0.17592, 0.18016, 0.18652, 0.1948, 0.20788,
-0.22704, 0.24972, 0.27182, 0.29302, 0.31082, 0.32486, 0.33572, 0.34102, 0.34156, 0.33974, 0.33752, 0.33312, 0.32832, 0.32226, 0.31448, 0.30464, 0.29506,
-0.28434, 0.27148, 0.26148, 0.24794, 0.23372, 0.22116, 0.20566, 0.1901, 0.17466, 0.16006, 0.14754, 0.13676, 0.12932, 0.12342, 0.11974, 0.11718, 0.11486, 0.1135,
-0.11036, 0.10868, 0.1075, 0.10644, 0.10484, 0.10642, 0.10816, 0.10926, 0.11166, 0.1147, 0.11714, 0.11928, 0.12156, 0.1242, 0.12422, 0.1224, 0.12154, 0.1217,
-0.12302, 0.12564, 0.12832, 0.13316, 0.1362, 0.13796, 0.13984, 0.14162, 0.14546, 0.1498, 
-//583
-0.09044, 0.09534, 0.10198, 0.10826, 0.11, 0.11116, 0.11322, 0.1116, 0.1124, 0.11266, 0.11362, 0.1137, 0.11722, 0.12062, 0.12332, 0.12656, 0.1318, 0.14008,
-0.14964, 0.16154, 0.17328, 0.18308, 0.19226, 0.20026, 0.21004, 0.21678, 0.22382, 0.23054, 0.23548, 0.23922, 0.2402, 0.23804, 0.23284, 0.22388, 0.21372, 0.20228,
-0.19008, 0.1772, 0.1661, 0.15428, 0.14318, 0.13166, 0.11972, 0.10896, 0.09888, 0.09132, 0.08448, 0.08128, 0.07784, 0.07502, 0.07418, 0.07156, 0.07032, 0.06924,
-0.06894, 0.06862, 0.0694, 0.06774, 0.06874, 0.07218, 0.07276, 0.07334, 0.07544, 0.07772, 0.0793, 0.08096, 0.08222, 0.08132, 0.08166, 0.08104, 0.08144, 0.08242,
-0.08504, 0.08674, 0.09024, 0.0934, 0.09546, 0.09718, 0.09956, 0.1018, 0.1056, 
-//584
-0.0608, 0.05952, 0.06294, 0.06374, 0.06406, 0.0646, 0.06412, 0.06492, 0.06484, 0.06406, 0.06486, 0.06684, 0.06784, 0.06906, 0.07052, 0.07288, 0.07708, 0.08282,
-0.09194, 0.10406, 0.11576, 0.12984, 0.1417, 0.15182, 0.1611, 0.16478, 0.16708, 0.16458, 0.16106, 0.1571, 0.15262, 0.14668, 0.13968, 0.13246, 0.12282, 0.11406,
-0.10476, 0.09682, 0.08984, 0.08308, 0.07782, 0.07222, 0.06626, 0.06068, 0.05762, 0.05494, 0.05278, 0.05082, 0.05086, 0.04884, 0.04892, 0.04766, 0.04696,
-0.04696, 0.04668, 0.04774, 0.0483, 0.04728, 0.04872, 0.05084, 0.05172, 0.05188, 0.0522, 0.05256, 0.05398, 0.05584, 0.05776, 0.05758, 0.05746, 0.05794, 0.05844,
-0.0605, 0.06146, 0.06282, 0.0645, 0.06592, 0.0671, 0.0677, 0.06868, 0.06922, 0.0726, 
-//585
-0.11236, 0.12682, 0.1512, 0.17428, 0.19158, 0.20082, 0.20448, 0.20714, 0.20718, 0.20916, 0.21146, 0.2147, 0.21822, 0.22342, 0.23032, 0.23956, 0.25436, 0.27508,
-0.306, 0.34088, 0.3769, 0.41246, 0.44208, 0.47056, 0.49166, 0.5026, 0.50724, 0.5054, 0.50008, 0.49128, 0.48306, 0.47048, 0.45646, 0.44016, 0.4222, 0.4011,
-0.3781, 0.35674, 0.3371, 0.31564, 0.29298, 0.2717, 0.24922, 0.22582, 0.20314, 0.1843, 0.16996, 0.1578, 0.15072, 0.14282, 0.13938, 0.13494, 0.13188, 0.12802,
-0.12534, 0.12324, 0.123, 0.12278, 0.12464, 0.12784, 0.13112, 0.13442, 0.13818, 0.14334, 0.14824, 0.14916, 0.1509, 0.15154, 0.14828, 0.14664, 0.1463, 0.14758,
-0.15186, 0.15742, 0.16426, 0.16874, 0.17348, 0.17794, 0.18342, 0.18878, 0.19578, 
-//586
-0.08694, 0.0991, 0.11078, 0.12108, 0.12756, 0.12994, 0.13146, 0.1315, 0.13332, 0.13328, 0.13622, 0.13986, 0.14244, 0.14532, 0.15156, 0.15858, 0.16888, 0.18374,
-0.20576, 0.23312, 0.26184, 0.28802, 0.31412, 0.33564, 0.3519, 0.36128, 0.3625, 0.36216, 0.35674, 0.3503, 0.3435, 0.33366, 0.32198, 0.30706, 0.29012, 0.27274,
-0.25378, 0.23818, 0.22232, 0.2058, 0.18908, 0.17136, 0.15474, 0.13794, 0.12362, 0.11124, 0.10144, 0.0942, 0.09062, 0.08808, 0.08692, 0.08418, 0.08314, 0.08028,
-0.08032, 0.07882, 0.07722, 0.07556, 0.07736, 0.07988, 0.08126, 0.08224, 0.08726, 0.08822, 0.09164, 0.09418, 0.09446, 0.09378, 0.09324, 0.09266, 0.09196, 0.0932,
-0.09534, 0.0977, 0.10118, 0.10392, 0.10644, 0.11012, 0.11374, 0.11756, 0.12136, 
-//587
-0.07614, 0.08202, 0.08648, 0.08898, 0.0912, 0.09042, 0.09152, 0.09362, 0.0925, 0.09178, 0.09294, 0.0947, 0.0961, 0.09776, 0.10106, 0.1056, 0.11164, 0.12232,
-0.13684, 0.15624, 0.17688, 0.19926, 0.22202, 0.2439, 0.26392, 0.27428, 0.27906, 0.27858, 0.27416, 0.26552, 0.25618, 0.24414, 0.22958, 0.21376, 0.19816, 0.1816,
-0.16568, 0.15084, 0.13702, 0.1243, 0.11268, 0.10042, 0.0912, 0.08222, 0.0753, 0.0702, 0.06774, 0.06422, 0.06246, 0.06234, 0.06064, 0.06072, 0.05958, 0.05846,
-0.05846, 0.05718, 0.05786, 0.05588, 0.05694, 0.05946, 0.06004, 0.0615, 0.06238, 0.06404, 0.0671, 0.06874, 0.06944, 0.0695, 0.06928, 0.06964, 0.07076, 0.07234,
-0.0738, 0.0748, 0.07844, 0.07822, 0.0802, 0.08108, 0.08384, 0.0848, 0.08828, 
-//588
-0.05018, 0.0531, 0.05468, 0.05754, 0.05924, 0.05746, 0.05866, 0.05862, 0.05952, 0.06034, 0.06052, 0.0609, 0.06154, 0.06234, 0.06364, 0.06538, 0.07024, 0.0762,
-0.08384, 0.09348, 0.10436, 0.11698, 0.1336, 0.15082, 0.16934, 0.18084, 0.18446, 0.18244, 0.17648, 0.16928, 0.15872, 0.14792, 0.13694, 0.12362, 0.11284, 0.10164,
-0.09148, 0.08368, 0.07542, 0.0685, 0.06432, 0.05882, 0.05532, 0.04954, 0.04634, 0.04292, 0.04176, 0.04114, 0.04096, 0.04054, 0.04032, 0.04068, 0.04164, 0.04182,
-0.04226, 0.04168, 0.0434, 0.04154, 0.04302, 0.04448, 0.04494, 0.04504, 0.04516, 0.04768, 0.04798, 0.04784, 0.04848, 0.05008, 0.0513, 0.05162, 0.0536, 0.05514,
-0.05696, 0.0581, 0.05974, 0.06022, 0.06146, 0.0607, 0.06138, 0.05998, 0.06244, 
-//589
-0.09166, 0.1045, 0.12422, 0.14412, 0.15756, 0.16666, 0.1724, 0.17482, 0.1787, 0.18232, 0.18648, 0.19204, 0.19888, 0.20446, 0.21158, 0.22062, 0.23464, 0.25614,
-0.28432, 0.32016, 0.3576, 0.39496, 0.431, 0.46532, 0.49516, 0.5132, 0.51842, 0.51616, 0.50798, 0.49564, 0.48128, 0.46388, 0.44468, 0.42154, 0.39786, 0.37174,
-0.3472, 0.32524, 0.30094, 0.27846, 0.2559, 0.23288, 0.21076, 0.18858, 0.16936, 0.15242, 0.14062, 0.1321, 0.1257, 0.12092, 0.11714, 0.11466, 0.1115, 0.10832,
-0.10574, 0.10372, 0.10338, 0.10202, 0.10482, 0.10928, 0.1105, 0.11378, 0.11946, 0.12574, 0.12916, 0.13196, 0.13312, 0.13258, 0.13086, 0.12968, 0.12874, 0.12956,
-0.1338, 0.13914, 0.1447, 0.14992, 0.15362, 0.1567, 0.16184, 0.16666, 0.17218, 
-//590
-0.08982, 0.09714, 0.10998, 0.12112, 0.12742, 0.1285, 0.13136, 0.13186, 0.13246, 0.13388, 0.13376, 0.13708, 0.13884, 0.14214, 0.14694, 0.15328, 0.1627, 0.17808,
-0.19812, 0.2255, 0.25678, 0.29132, 0.32594, 0.3626, 0.39198, 0.40938, 0.41634, 0.4127, 0.40274, 0.389, 0.3728, 0.3549, 0.33474, 0.31302, 0.29074, 0.26778,
-0.24664, 0.22492, 0.20572, 0.18824, 0.17212, 0.15562, 0.14176, 0.12726, 0.11624, 0.10622, 0.10006, 0.09366, 0.09088, 0.08906, 0.08768, 0.08558, 0.0839, 0.08228,
-0.0798, 0.0783, 0.07902, 0.07632, 0.07804, 0.07864, 0.08116, 0.082, 0.08416, 0.08652, 0.08824, 0.08908, 0.08958, 0.08918, 0.08804, 0.08948, 0.08998, 0.09126,
-0.09288, 0.09646, 0.10012, 0.1022, 0.1041, 0.10582, 0.10686, 0.10942, 0.11154, 
-//591
-0.13158, 0.16014, 0.21276, 0.29082, 0.38962, 0.4883, 0.55828, 0.59128, 0.60438, 0.60914, 0.61398, 0.6187, 0.62474, 0.62672, 0.63104, 0.63814, 0.6472, 0.65938,
-0.67496, 0.69016, 0.7037, 0.71516, 0.7233, 0.73272, 0.73944, 0.74284, 0.74222, 0.7431, 0.7415, 0.73924, 0.73876, 0.73604, 0.7326, 0.72592, 0.72224, 0.71718,
-0.7078, 0.70092, 0.6915, 0.67952, 0.66672, 0.65422, 0.63698, 0.61714, 0.59812, 0.5758, 0.55948, 0.5449, 0.53138, 0.52288, 0.51636, 0.50904, 0.5028, 0.49698,
-0.49332, 0.4897, 0.48856, 0.48962, 0.49328, 0.4984, 0.50164, 0.50902, 0.51564, 0.5219, 0.52834, 0.5321, 0.53424, 0.53216, 0.53086, 0.52992, 0.52942, 0.53492,
-0.54176, 0.5493, 0.55742, 0.56426, 0.57284, 0.57592, 0.57988, 0.58286, 0.58732, 
-//592
-0.14138, 0.17104, 0.22256, 0.29038, 0.3627, 0.41614, 0.44612, 0.45442, 0.45798, 0.45836, 0.45942, 0.46242, 0.46478, 0.46708, 0.47002, 0.47402, 0.48242, 0.49612,
-0.51142, 0.52644, 0.53852, 0.54636, 0.55416, 0.55848, 0.56136, 0.5622, 0.56172, 0.56102, 0.55984, 0.55698, 0.55448, 0.55236, 0.5496, 0.5466, 0.54254, 0.53784,
-0.53178, 0.5279, 0.52122, 0.51276, 0.5047, 0.49544, 0.48318, 0.46852, 0.45412, 0.43944, 0.42522, 0.41396, 0.40446, 0.39756, 0.39384, 0.38742, 0.38144, 0.37622,
-0.36954, 0.36632, 0.36488, 0.36468, 0.36594, 0.3689, 0.37208, 0.37582, 0.38036, 0.38596, 0.39026, 0.39362, 0.39334, 0.39062, 0.38814, 0.385, 0.38484, 0.38576,
-0.39056, 0.39412, 0.40232, 0.4066, 0.40898, 0.4146, 0.41974, 0.42168, 0.42696, 
-//593
-0.1151, 0.14002, 0.17892, 0.22682, 0.27154, 0.2984, 0.3114, 0.31544, 0.31728, 0.31738, 0.31892, 0.32102, 0.32214, 0.3237, 0.32596, 0.32866, 0.33488, 0.34418,
-0.35454, 0.36558, 0.37516, 0.38204, 0.38638, 0.39048, 0.39352, 0.39454, 0.39452, 0.39346, 0.39254, 0.39014, 0.38782, 0.38496, 0.38356, 0.38156, 0.3795, 0.37628,
-0.3719, 0.36822, 0.36378, 0.35782, 0.3518, 0.34544, 0.33814, 0.3271, 0.3163, 0.30354, 0.2921, 0.28278, 0.27536, 0.26874, 0.26528, 0.26034, 0.256, 0.25208,
-0.24834, 0.24682, 0.24738, 0.2475, 0.24952, 0.25434, 0.25764, 0.26098, 0.26372, 0.26698, 0.26844, 0.27102, 0.27328, 0.2714, 0.27056, 0.27144, 0.2709, 0.27328,
-0.27798, 0.28202, 0.28632, 0.28946, 0.29552, 0.29512, 0.29646, 0.2983, 0.3002, 
-//594
-0.11728, 0.13556, 0.16612, 0.19346, 0.21494, 0.22606, 0.22924, 0.22992, 0.2307, 0.23048, 0.22966, 0.23132, 0.23254, 0.23248, 0.23338, 0.23702, 0.24218, 0.2497,
-0.25936, 0.26784, 0.2749, 0.27912, 0.28164, 0.2843, 0.28674, 0.28722, 0.28744, 0.28534, 0.28438, 0.28188, 0.28048, 0.27948, 0.27752, 0.27636, 0.27438, 0.27172,
-0.26816, 0.26534, 0.26126, 0.2567, 0.2523, 0.24726, 0.24254, 0.23466, 0.22724, 0.21854, 0.2123, 0.2055, 0.202, 0.1977, 0.1946, 0.19106, 0.1877, 0.18498,
-0.18332, 0.18076, 0.18038, 0.17858, 0.18052, 0.1823, 0.18306, 0.18494, 0.18772, 0.18986, 0.19082, 0.19174, 0.19226, 0.19054, 0.1876, 0.18756, 0.1868, 0.18716,
-0.19032, 0.19194, 0.19552, 0.19874, 0.202, 0.2018, 0.20524, 0.20552, 0.2078, 
-//595
-0.09442, 0.10518, 0.12312, 0.1382, 0.14672, 0.1506, 0.15286, 0.15234, 0.15216, 0.15278, 0.15378, 0.15604, 0.15692, 0.15616, 0.15796, 0.16008, 0.16416, 0.1694,
-0.17596, 0.18238, 0.18688, 0.18978, 0.19084, 0.19196, 0.1929, 0.19216, 0.19166, 0.18986, 0.18938, 0.18788, 0.18646, 0.1855, 0.1841, 0.18186, 0.18152, 0.17916,
-0.17736, 0.17532, 0.17176, 0.16888, 0.1653, 0.16058, 0.15602, 0.15024, 0.1454, 0.14174, 0.13936, 0.137, 0.1358, 0.13476, 0.13344, 0.133, 0.13174, 0.13132,
-0.13094, 0.13052, 0.13266, 0.13234, 0.13456, 0.13654, 0.13784, 0.13882, 0.1402, 0.14074, 0.14178, 0.14258, 0.14256, 0.14252, 0.14264, 0.14324, 0.1442, 0.1472,
-0.1498, 0.1513, 0.15372, 0.15562, 0.15608, 0.15638, 0.15564, 0.15608, 0.1562, 
-//596
-0.07854, 0.08258, 0.09012, 0.09432, 0.09794, 0.09698, 0.09794, 0.0979, 0.09768, 0.09692, 0.09808, 0.09836, 0.09982, 0.10002, 0.10144, 0.10208, 0.1048, 0.10816,
-0.11232, 0.11578, 0.11842, 0.1201, 0.11996, 0.12106, 0.12204, 0.12184, 0.12162, 0.12078, 0.12088, 0.1202, 0.12046, 0.11934, 0.1185, 0.11748, 0.11568, 0.11462,
-0.11162, 0.11038, 0.1081, 0.1051, 0.10246, 0.10104, 0.09734, 0.09396, 0.09008, 0.08764, 0.08432, 0.08168, 0.08038, 0.07772, 0.07672, 0.07502, 0.0739, 0.07216,
-0.07166, 0.07048, 0.07026, 0.06852, 0.07012, 0.07176, 0.0737, 0.0739, 0.07532, 0.0764, 0.0778, 0.07836, 0.07944, 0.0798, 0.07972, 0.0803, 0.0808, 0.08158,
-0.08236, 0.08382, 0.08614, 0.08722, 0.08858, 0.08926, 0.0909, 0.09188, 0.09498, 
-//597
-0.05314, 0.0544, 0.054, 0.05452, 0.05516, 0.0552, 0.05498, 0.0551, 0.05506, 0.05528, 0.05584, 0.05682, 0.05806, 0.05776, 0.05906, 0.05942, 0.06106, 0.06286,
-0.0663, 0.06918, 0.07248, 0.07304, 0.07446, 0.07432, 0.07548, 0.075, 0.07484, 0.0744, 0.07364, 0.07274, 0.07172, 0.07098, 0.06978, 0.0684, 0.06668, 0.06518,
-0.06364, 0.0625, 0.06108, 0.05938, 0.05892, 0.05602, 0.05434, 0.05268, 0.05248, 0.05186, 0.05142, 0.05138, 0.05108, 0.0516, 0.05138, 0.05084, 0.05178, 0.0516,
-0.05044, 0.05026, 0.05078, 0.0498, 0.05062, 0.05216, 0.05312, 0.05296, 0.05546, 0.0561, 0.0567, 0.05646, 0.05696, 0.05724, 0.05728, 0.0581, 0.05868, 0.05958,
-0.0617, 0.0627, 0.0648, 0.06586, 0.06644, 0.0667, 0.06682, 0.06756, 0.0679, 
-//598
-0.04124, 0.04126, 0.03912, 0.03948, 0.0396, 0.03888, 0.03848, 0.0389, 0.03868, 0.0387, 0.03918, 0.04038, 0.0417, 0.04202, 0.04376, 0.04534, 0.04682, 0.04866,
-0.04942, 0.05178, 0.05312, 0.0544, 0.05438, 0.05548, 0.0561, 0.05596, 0.0566, 0.0559, 0.0562, 0.0554, 0.05438, 0.05336, 0.05236, 0.05102, 0.04982, 0.04896,
-0.04714, 0.04658, 0.0454, 0.04534, 0.0442, 0.04242, 0.04046, 0.03984, 0.0382, 0.03808, 0.03826, 0.03866, 0.03858, 0.03896, 0.03928, 0.04014, 0.04006, 0.03952,
-0.03942, 0.03996, 0.04154, 0.03872, 0.04064, 0.0423, 0.04218, 0.04154, 0.04258, 0.04246, 0.04376, 0.04348, 0.04514, 0.04606, 0.04644, 0.04688, 0.04834, 0.05038,
-0.05178, 0.05228, 0.054, 0.05356, 0.05472, 0.0547, 0.05398, 0.05476, 0.05416, 
-//599
-0.12036, 0.1455, 0.19104, 0.25198, 0.31478, 0.35986, 0.38424, 0.39308, 0.39704, 0.39884, 0.4034, 0.40718, 0.41432, 0.41776, 0.42334, 0.42936, 0.44024, 0.45626,
-0.47658, 0.50032, 0.51916, 0.53762, 0.55242, 0.56672, 0.5772, 0.58346, 0.5849, 0.58254, 0.5814, 0.57848, 0.57518, 0.57062, 0.56484, 0.55676, 0.54972, 0.53994,
-0.52916, 0.5152, 0.50084, 0.48612, 0.46816, 0.44968, 0.42864, 0.40522, 0.38176, 0.35734, 0.3389, 0.32362, 0.31086, 0.3013, 0.29524, 0.28982, 0.28492, 0.27944,
-0.2756, 0.27188, 0.26894, 0.27148, 0.2759, 0.28224, 0.28666, 0.29272, 0.29854, 0.30536, 0.31148, 0.31538, 0.31626, 0.31492, 0.31094, 0.31044, 0.31154, 0.31414,
-0.32096, 0.33048, 0.33922, 0.34734, 0.35666, 0.3609, 0.36498, 0.36776, 0.37068, 
-//600
-0.11778, 0.14028, 0.1781, 0.21728, 0.25272, 0.27556, 0.28668, 0.29118, 0.29434, 0.29728, 0.29882, 0.3027, 0.30718, 0.3115, 0.3177, 0.3241, 0.33372, 0.34762,
-0.36396, 0.38288, 0.39808, 0.41058, 0.4219, 0.43018, 0.4347, 0.43706, 0.43734, 0.43518, 0.43258, 0.43054, 0.42772, 0.4242, 0.41862, 0.41364, 0.408, 0.39938,
-0.38914, 0.37958, 0.37058, 0.35906, 0.34748, 0.3333, 0.31792, 0.30006, 0.2804, 0.2627, 0.24748, 0.23622, 0.22708, 0.21838, 0.21338, 0.2088, 0.2029, 0.1984,
-0.1949, 0.19198, 0.19152, 0.1905, 0.19344, 0.19874, 0.20142, 0.20612, 0.21028, 0.21694, 0.22254, 0.22694, 0.2298, 0.22912, 0.22764, 0.22728, 0.22816, 0.22792,
-0.2327, 0.2372, 0.24604, 0.25014, 0.25394, 0.25646, 0.2615, 0.26456, 0.27244, 
-//601
-0.10948, 0.12698, 0.15046, 0.17376, 0.18842, 0.19638, 0.20042, 0.20216, 0.2039, 0.20546, 0.20674, 0.20908, 0.21126, 0.21368, 0.21784, 0.22234, 0.22868, 0.2395,
-0.2526, 0.26714, 0.27904, 0.2886, 0.29504, 0.30172, 0.30914, 0.30838, 0.30908, 0.30838, 0.30624, 0.30356, 0.30162, 0.29786, 0.29276, 0.2871, 0.28338, 0.27474,
-0.26678, 0.25954, 0.2521, 0.24072, 0.23156, 0.219, 0.20766, 0.19402, 0.17988, 0.1674, 0.15704, 0.14924, 0.14224, 0.13696, 0.13382, 0.13084, 0.12856, 0.12476,
-0.1227, 0.12006, 0.11994, 0.11782, 0.11936, 0.12148, 0.12276, 0.12456, 0.12698, 0.12904, 0.13076, 0.13434, 0.13596, 0.1354, 0.13582, 0.1359, 0.13572, 0.1379,
-0.14024, 0.1427, 0.1477, 0.15162, 0.15418, 0.15708, 0.16028, 0.16266, 0.16642, 
-//602
-0.08192, 0.09112, 0.10354, 0.11004, 0.11814, 0.12038, 0.12224, 0.1224, 0.12278, 0.12488, 0.12646, 0.12878, 0.13076, 0.13354, 0.13622, 0.14078, 0.14674, 0.1561,
-0.16628, 0.17686, 0.18556, 0.19368, 0.1967, 0.20144, 0.20262, 0.20332, 0.20184, 0.2008, 0.19998, 0.19876, 0.19578, 0.19284, 0.1901, 0.18454, 0.18058, 0.17546,
-0.16828, 0.16378, 0.157, 0.15046, 0.14294, 0.13538, 0.12814, 0.12028, 0.11406, 0.10986, 0.10582, 0.10282, 0.10134, 0.09918, 0.09764, 0.0967, 0.09578, 0.09628,
-0.09592, 0.09642, 0.09788, 0.09912, 0.10366, 0.10654, 0.10876, 0.10976, 0.11232, 0.11314, 0.11518, 0.11494, 0.11568, 0.1132, 0.11204, 0.1133, 0.11498, 0.11756,
-0.12084, 0.12566, 0.1304, 0.13424, 0.13696, 0.13736, 0.13914, 0.13824, 0.13776, 
-//603
-0.0632, 0.0662, 0.0731, 0.07716, 0.08088, 0.0823, 0.0826, 0.08244, 0.08144, 0.0833, 0.08214, 0.08284, 0.0846, 0.086, 0.08782, 0.08958, 0.09416, 0.1003, 0.1082,
-0.11506, 0.12388, 0.12964, 0.13276, 0.1364, 0.13848, 0.1385, 0.13772, 0.13618, 0.13502, 0.13394, 0.13184, 0.12934, 0.1266, 0.12498, 0.12234, 0.11776, 0.11232,
-0.10838, 0.10296, 0.09878, 0.09438, 0.08902, 0.08348, 0.0768, 0.0717, 0.06554, 0.06204, 0.05856, 0.05636, 0.05592, 0.05428, 0.05368, 0.05288, 0.05334, 0.0524,
-0.05308, 0.05382, 0.05242, 0.0538, 0.0552, 0.05672, 0.05694, 0.05624, 0.05836, 0.05914, 0.06106, 0.06188, 0.06228, 0.06202, 0.06296, 0.06392, 0.06508, 0.06796,
-0.06888, 0.07132, 0.07306, 0.07364, 0.07458, 0.07532, 0.07718, 0.07828, 
-//604
-0.0468, 0.04706, 0.04504, 0.04362, 0.04434, 0.04376, 0.04476, 0.0446, 0.04612, 0.04646, 0.0473, 0.04728, 0.0479, 0.0504, 0.0504, 0.05066, 0.05196, 0.05574,
-0.05932, 0.06474, 0.07302, 0.0808, 0.08704, 0.09376, 0.09598, 0.09664, 0.09638, 0.09326, 0.09094, 0.08626, 0.08294, 0.07618, 0.07166, 0.06624, 0.06118, 0.05616,
-0.05212, 0.04986, 0.04826, 0.04724, 0.04586, 0.0459, 0.04522, 0.04402, 0.04334, 0.04272, 0.04228, 0.04214, 0.04158, 0.04138, 0.04172, 0.04106, 0.0428, 0.04228,
-0.04386, 0.0432, 0.04386, 0.04372, 0.04504, 0.04724, 0.04716, 0.04608, 0.04802, 0.04852, 0.0488, 0.04826, 0.04912, 0.04996, 0.04952, 0.05034, 0.05172, 0.0524,
-0.05432, 0.05552, 0.05776, 0.05928, 0.06006, 0.06076, 0.06034, 0.06128, 0.06154, 
-//605
-0.1201, 0.14534, 0.19004, 0.24464, 0.29382, 0.32992, 0.34822, 0.35576, 0.35984, 0.3634, 0.36828, 0.3728, 0.37856, 0.3842, 0.39036, 0.39946, 0.41288, 0.43304,
-0.45816, 0.48944, 0.5195, 0.54946, 0.57994, 0.60714, 0.63, 0.64474, 0.6498, 0.64878, 0.64414, 0.63752, 0.62838, 0.61422, 0.60102, 0.58486, 0.5679, 0.54752,
-0.52444, 0.50206, 0.48126, 0.45788, 0.435, 0.40872, 0.38258, 0.35308, 0.32558, 0.29958, 0.27726, 0.2604, 0.24828, 0.23904, 0.23354, 0.22758, 0.22194, 0.21718,
-0.2136, 0.20998, 0.2104, 0.21132, 0.21548, 0.22166, 0.22696, 0.23304, 0.23842, 0.2445, 0.25102, 0.2544, 0.2557, 0.25312, 0.2518, 0.25026, 0.25092, 0.25438,
-0.26278, 0.27164, 0.28194, 0.2919, 0.29902, 0.30466, 0.3077, 0.31092, 0.3172, 
-//606
-0.10448, 0.12856, 0.16118, 0.19608, 0.2225, 0.2397, 0.24808, 0.25242, 0.25532, 0.25804, 0.26274, 0.26684, 0.2724, 0.27706, 0.28318, 0.29182, 0.30436, 0.3213,
-0.34328, 0.36708, 0.39068, 0.40952, 0.42798, 0.44382, 0.45528, 0.4608, 0.46236, 0.46054, 0.45782, 0.4534, 0.44752, 0.44108, 0.4326, 0.42298, 0.41258, 0.39872,
-0.38252, 0.36836, 0.35194, 0.33364, 0.31552, 0.29478, 0.2733, 0.25048, 0.22718, 0.207, 0.19042, 0.17788, 0.16892, 0.16236, 0.15814, 0.1534, 0.14918, 0.14526,
-0.14212, 0.13864, 0.13842, 0.1382, 0.14036, 0.14398, 0.14708, 0.15198, 0.1567, 0.16216, 0.16732, 0.17122, 0.17178, 0.17156, 0.16978, 0.16904, 0.16998, 0.1731,
-0.17972, 0.18628, 0.19464, 0.20128, 0.20716, 0.2119, 0.2162, 0.21936, 0.22388, 
-//607
-0.09464, 0.1095, 0.1287, 0.14664, 0.16062, 0.1684, 0.17168, 0.1741, 0.17672, 0.17802, 0.18096, 0.18398, 0.18846, 0.1924, 0.19748, 0.20384, 0.21326, 0.22728,
-0.24542, 0.26744, 0.28746, 0.30382, 0.31752, 0.32816, 0.33644, 0.33936, 0.33962, 0.33794, 0.3353, 0.3306, 0.32572, 0.31854, 0.31078, 0.30122, 0.29106, 0.27808,
-0.26564, 0.25398, 0.2413, 0.22492, 0.21088, 0.19392, 0.17858, 0.1618, 0.14638, 0.1336, 0.12236, 0.11506, 0.1097, 0.10652, 0.10372, 0.10076, 0.09806, 0.09652,
-0.09594, 0.0944, 0.09334, 0.09288, 0.09562, 0.0992, 0.10084, 0.10248, 0.10618, 0.11004, 0.11308, 0.11492, 0.11534, 0.1143, 0.11326, 0.11208, 0.1123, 0.11476,
-0.11844, 0.12286, 0.12662, 0.1313, 0.13418, 0.137, 0.13992, 0.1432, 0.14714, 
-//608
-0.0844, 0.09178, 0.10218, 0.11018, 0.11394, 0.11778, 0.11806, 0.1189, 0.12024, 0.12156, 0.1229, 0.12382, 0.12608, 0.1284, 0.13146, 0.13468, 0.14012, 0.14848,
-0.1613, 0.17672, 0.1917, 0.20388, 0.2172, 0.22854, 0.2366, 0.23998, 0.2415, 0.23856, 0.23548, 0.23076, 0.22676, 0.21992, 0.21266, 0.20476, 0.1943, 0.1834,
-0.17382, 0.16548, 0.1549, 0.14524, 0.13486, 0.12448, 0.11478, 0.1038, 0.09576, 0.0881, 0.08288, 0.07866, 0.07656, 0.07514, 0.07388, 0.07324, 0.0723, 0.07048,
-0.0702, 0.06976, 0.07042, 0.06764, 0.0696, 0.07102, 0.07168, 0.07222, 0.07282, 0.0751, 0.07676, 0.07896, 0.07992, 0.07972, 0.07968, 0.07932, 0.07996, 0.08138,
-0.08296, 0.08518, 0.08818, 0.08888, 0.09128, 0.09214, 0.09336, 0.09518, 0.09736, 
-//609
-0.0564, 0.05758, 0.06144, 0.06316, 0.06486, 0.06514, 0.06656, 0.06636, 0.06702, 0.06784, 0.06808, 0.06964, 0.06998, 0.07138, 0.07268, 0.07466, 0.08106, 0.09006,
-0.10128, 0.11738, 0.13512, 0.15198, 0.16394, 0.17344, 0.1771, 0.17692, 0.17488, 0.16982, 0.16398, 0.15664, 0.1494, 0.14064, 0.13144, 0.12132, 0.11146, 0.1021,
-0.09386, 0.08614, 0.08022, 0.0735, 0.06824, 0.06474, 0.06116, 0.057, 0.05492, 0.0529, 0.053, 0.05172, 0.05228, 0.0517, 0.05108, 0.0497, 0.05004, 0.04932, 0.05,
-0.05158, 0.05196, 0.05228, 0.05438, 0.05662, 0.0572, 0.05738, 0.05882, 0.06008, 0.06108, 0.06114, 0.06248, 0.06164, 0.061, 0.06168, 0.06258, 0.06542, 0.06648,
-0.06876, 0.07136, 0.07348, 0.07542, 0.0772, 0.07768, 0.0783, 0.07802, 
-//610
-0.1036, 0.12414, 0.15114, 0.18064, 0.2028, 0.2157, 0.225, 0.22964, 0.23258, 0.23492, 0.24142, 0.24748, 0.2527, 0.25896, 0.26732, 0.27806, 0.29176, 0.31188,
-0.33858, 0.36976, 0.40072, 0.4306, 0.46092, 0.48994, 0.5115, 0.52514, 0.53, 0.52758, 0.52042, 0.51096, 0.4991, 0.48508, 0.4678, 0.44914, 0.42884, 0.4061,
-0.38056, 0.35804, 0.33456, 0.3113, 0.2893, 0.26496, 0.23994, 0.2152, 0.19312, 0.17424, 0.15768, 0.14666, 0.13792, 0.13166, 0.12704, 0.12364, 0.11936, 0.1147,
-0.11186, 0.11042, 0.1088, 0.10922, 0.11198, 0.11624, 0.11996, 0.1232, 0.1275, 0.13192, 0.1364, 0.1396, 0.14156, 0.1416, 0.13998, 0.1387, 0.14008, 0.14178,
-0.14788, 0.15548, 0.16188, 0.1684, 0.17392, 0.17886, 0.18436, 0.1866, 0.1921, 
-//611
-0.0937, 0.10614, 0.12114, 0.1349, 0.1442, 0.1498, 0.15348, 0.1568, 0.15882, 0.16018, 0.16286, 0.16688, 0.17258, 0.17726, 0.18428, 0.192, 0.203, 0.21904, 0.2389,
-0.26242, 0.2864, 0.30914, 0.33344, 0.35736, 0.37454, 0.38416, 0.38796, 0.38472, 0.3797, 0.37102, 0.36076, 0.34922, 0.33542, 0.318, 0.2996, 0.2798, 0.2582,
-0.24056, 0.2217, 0.20384, 0.18744, 0.16904, 0.15268, 0.13596, 0.12106, 0.10814, 0.0989, 0.0926, 0.08674, 0.08312, 0.08162, 0.07906, 0.0774, 0.07604, 0.07504,
-0.07438, 0.07372, 0.07442, 0.07512, 0.07828, 0.0796, 0.0814, 0.0827, 0.08492, 0.08744, 0.08962, 0.09086, 0.09148, 0.09204, 0.09146, 0.0928, 0.0951, 0.09838,
-0.1021, 0.10664, 0.10986, 0.11302, 0.11518, 0.11882, 0.1206, 0.12456, 
-//612
-0.07154, 0.07574, 0.08116, 0.0857, 0.08978, 0.09104, 0.09442, 0.09386, 0.09526, 0.0965, 0.0986, 0.10088, 0.10286, 0.1054, 0.11074, 0.11588, 0.12636, 0.14212,
-0.16286, 0.18878, 0.22076, 0.2506, 0.27474, 0.29172, 0.29888, 0.29982, 0.29652, 0.28642, 0.27558, 0.2621, 0.24812, 0.23152, 0.21554, 0.19876, 0.18238, 0.16524,
-0.14952, 0.13558, 0.12394, 0.1131, 0.10248, 0.09398, 0.08628, 0.079, 0.075, 0.07124, 0.06946, 0.06782, 0.06588, 0.06476, 0.06362, 0.0637, 0.06348, 0.06284,
-0.0631, 0.0639, 0.06508, 0.0653, 0.06868, 0.07156, 0.07248, 0.07418, 0.0757, 0.07732, 0.07958, 0.07988, 0.08052, 0.08044, 0.07934, 0.08004, 0.0812, 0.08344,
-0.08642, 0.0896, 0.0938, 0.09776, 0.09976, 0.10202, 0.10262, 0.1021, 0.10392, 
-//613
-0.05652, 0.05676, 0.05656, 0.05772, 0.05714, 0.0575, 0.0583, 0.05724, 0.05862, 0.06022, 0.06064, 0.06146, 0.06228, 0.06464, 0.06674, 0.07116, 0.07634, 0.08442,
-0.0962, 0.1141, 0.13824, 0.1657, 0.19174, 0.21136, 0.22026, 0.2196, 0.2097, 0.19618, 0.17992, 0.16306, 0.1482, 0.13216, 0.11718, 0.10404, 0.09222, 0.0818,
-0.07284, 0.06822, 0.0641, 0.05956, 0.05554, 0.05258, 0.05058, 0.0479, 0.04684, 0.04486, 0.0449, 0.045, 0.04666, 0.04602, 0.04682, 0.0459, 0.04722, 0.04782,
-0.04896, 0.04916, 0.04944, 0.04884, 0.04974, 0.05128, 0.05206, 0.05202, 0.05342, 0.05348, 0.05394, 0.0541, 0.05516, 0.05436, 0.055, 0.05558, 0.05772, 0.05952,
-0.06146, 0.06428, 0.06544, 0.06782, 0.06944, 0.06898, 0.06934, 0.0696, 0.06998, 
-//614
-0.11242, 0.1286, 0.14994, 0.17, 0.18368, 0.19236, 0.19944, 0.20398, 0.20982, 0.21556, 0.22158, 0.22834, 0.23606, 0.2456, 0.25644, 0.27078, 0.28906, 0.31216,
-0.34382, 0.38024, 0.41788, 0.4523, 0.48426, 0.5143, 0.53768, 0.5495, 0.5514, 0.54498, 0.53532, 0.5212, 0.50618, 0.48678, 0.46386, 0.43904, 0.41436, 0.3885,
-0.36298, 0.33818, 0.31546, 0.2916, 0.26822, 0.24524, 0.22158, 0.19998, 0.18258, 0.16764, 0.15554, 0.14656, 0.1422, 0.1379, 0.13528, 0.13242, 0.12936, 0.1269,
-0.12514, 0.12376, 0.12486, 0.12454, 0.12874, 0.13358, 0.13792, 0.14258, 0.14914, 0.15576, 0.15966, 0.16312, 0.16392, 0.16226, 0.16002, 0.1592, 0.15904, 0.16172,
-0.16744, 0.1733, 0.18242, 0.19092, 0.19858, 0.20178, 0.20544, 0.21054, 0.21794, 
-//615
-0.08848, 0.09836, 0.11046, 0.11958, 0.12798, 0.13076, 0.13388, 0.13444, 0.13688, 0.13734, 0.14188, 0.14706, 0.15098, 0.1566, 0.16224, 0.17122, 0.1882, 0.21016,
-0.23908, 0.27764, 0.32232, 0.36962, 0.40748, 0.43604, 0.45044, 0.45224, 0.44434, 0.43172, 0.41342, 0.39248, 0.37008, 0.34598, 0.3217, 0.296, 0.27156, 0.24744,
-0.22512, 0.20376, 0.1859, 0.16884, 0.15322, 0.13846, 0.12566, 0.1159, 0.1072, 0.10168, 0.09734, 0.0942, 0.09232, 0.0903, 0.08896, 0.08846, 0.08648, 0.08522,
-0.08554, 0.08628, 0.0873, 0.0887, 0.09346, 0.0977, 0.1009, 0.103, 0.10576, 0.1086, 0.11112, 0.11104, 0.11142, 0.11002, 0.10852, 0.10682, 0.10794, 0.1113,
-0.11644, 0.12202, 0.12854, 0.13312, 0.13754, 0.13974, 0.14338, 0.14272, 0.14286, 
-//616
-0.1391, 0.16748, 0.22038, 0.2972, 0.39254, 0.49172, 0.56238, 0.59786, 0.61152, 0.61264, 0.6149, 0.61746, 0.62082, 0.62406, 0.62838, 0.6299, 0.63308, 0.63974,
-0.64866, 0.65566, 0.6622, 0.66904, 0.67242, 0.67636, 0.67588, 0.6776, 0.6792, 0.68024, 0.68062, 0.67886, 0.6781, 0.676, 0.6752, 0.67354, 0.67256, 0.67086,
-0.66476, 0.66092, 0.65914, 0.65266, 0.64494, 0.63618, 0.6296, 0.6188, 0.6097, 0.59958, 0.58416, 0.57464, 0.56706, 0.56188, 0.55894, 0.55446, 0.54968, 0.54512,
-0.54, 0.5368, 0.53586, 0.53542, 0.53724, 0.5406, 0.5408, 0.54368, 0.5488, 0.55224, 0.55662, 0.56092, 0.56432, 0.56314, 0.56168, 0.56054, 0.56014, 0.56032,
-0.56678, 0.57204, 0.5782, 0.58234, 0.58662, 0.5896, 0.59234, 0.59398, 0.60238, 
-//617
-0.12642, 0.15664, 0.20842, 0.28078, 0.36358, 0.43486, 0.475, 0.4891, 0.49324, 0.49394, 0.4958, 0.4963, 0.4985, 0.49714, 0.49966, 0.50022, 0.5049, 0.5104,
-0.51728, 0.5244, 0.52998, 0.53152, 0.53394, 0.5345, 0.53726, 0.5375, 0.53632, 0.53564, 0.53444, 0.5335, 0.53226, 0.53016, 0.52874, 0.52614, 0.52482, 0.52224,
-0.51882, 0.5178, 0.5141, 0.512, 0.50772, 0.50338, 0.49764, 0.49102, 0.4843, 0.4764, 0.46982, 0.4635, 0.45778, 0.45506, 0.4505, 0.4465, 0.44204, 0.43898,
-0.43604, 0.43326, 0.43296, 0.43206, 0.43354, 0.43632, 0.43808, 0.43926, 0.44094, 0.44234, 0.44468, 0.44562, 0.44448, 0.44282, 0.44072, 0.43816, 0.43926,
-0.43776, 0.44098, 0.44102, 0.44574, 0.44868, 0.45192, 0.45258, 0.45466, 0.45574, 0.46006, 
-//618
-0.11372, 0.13608, 0.1806, 0.2345, 0.28808, 0.32514, 0.34336, 0.34902, 0.35156, 0.3526, 0.35364, 0.35598, 0.35766, 0.35812, 0.35966, 0.3606, 0.36304, 0.36402,
-0.36672, 0.36956, 0.37066, 0.37352, 0.37532, 0.38042, 0.385, 0.3873, 0.3894, 0.38922, 0.38924, 0.38732, 0.38522, 0.38406, 0.38002, 0.37856, 0.37692, 0.37318,
-0.36788, 0.36866, 0.3647, 0.36144, 0.35696, 0.35236, 0.3481, 0.3411, 0.33682, 0.33254, 0.3297, 0.32556, 0.3222, 0.3208, 0.319, 0.31728, 0.31546, 0.3145,
-0.31362, 0.31214, 0.3143, 0.31258, 0.31472, 0.3173, 0.31624, 0.31704, 0.31536, 0.31876, 0.31774, 0.3175, 0.31724, 0.3156, 0.31334, 0.31362, 0.31278, 0.3123,
-0.31324, 0.31388, 0.31426, 0.31592, 0.3163, 0.31518, 0.3154, 0.31238, 0.3148, 
-//619
-0.1213, 0.14108, 0.17292, 0.2054, 0.23122, 0.2455, 0.25074, 0.25212, 0.25168, 0.2508, 0.25294, 0.25404, 0.25496, 0.25344, 0.2549, 0.25626, 0.25846, 0.2646,
-0.2694, 0.27266, 0.27726, 0.27804, 0.2791, 0.2786, 0.27874, 0.27952, 0.27852, 0.2779, 0.27692, 0.27598, 0.27524, 0.27284, 0.2718, 0.26858, 0.2684, 0.2666,
-0.26188, 0.26348, 0.26148, 0.25818, 0.25596, 0.25156, 0.24748, 0.24382, 0.24018, 0.23714, 0.23464, 0.23344, 0.23094, 0.2288, 0.22796, 0.22694, 0.22582, 0.22382,
-0.22274, 0.2242, 0.2222, 0.2217, 0.22242, 0.22358, 0.22532, 0.2238, 0.22432, 0.22598, 0.22644, 0.2265, 0.22676, 0.22634, 0.22624, 0.22702, 0.22684, 0.2271,
-0.22938, 0.23, 0.23038, 0.23178, 0.23134, 0.2336, 0.23524, 0.23262, 0.23318, 
-//620
-0.10816, 0.12184, 0.13784, 0.15486, 0.1651, 0.16978, 0.17038, 0.17064, 0.16986, 0.1693, 0.16918, 0.16968, 0.17044, 0.16996, 0.17068, 0.17222, 0.1749, 0.17814,
-0.18114, 0.18408, 0.18734, 0.18682, 0.187, 0.18726, 0.18738, 0.1862, 0.18584, 0.1854, 0.18434, 0.18294, 0.18174, 0.1812, 0.18192, 0.18026, 0.17918, 0.17834,
-0.17358, 0.17512, 0.17264, 0.17028, 0.16898, 0.16774, 0.16518, 0.1618, 0.16036, 0.15786, 0.15624, 0.15408, 0.15332, 0.15188, 0.14978, 0.14792, 0.14886, 0.147,
-0.1462, 0.14582, 0.14634, 0.14488, 0.146, 0.14848, 0.14884, 0.14824, 0.14776, 0.14848, 0.14916, 0.14768, 0.14892, 0.14832, 0.1485, 0.14886, 0.14938, 0.1516,
-0.1529, 0.15398, 0.15422, 0.15624, 0.1557, 0.15636, 0.1571, 0.15444, 0.15524, 
-//621
-0.07874, 0.08546, 0.09354, 0.099, 0.10316, 0.10528, 0.1052, 0.10428, 0.10562, 0.10438, 0.1055, 0.1051, 0.10576, 0.10576, 0.1048, 0.1051, 0.10676, 0.10962,
-0.11256, 0.11392, 0.11626, 0.11652, 0.11678, 0.116, 0.11742, 0.11728, 0.11622, 0.11628, 0.11544, 0.11396, 0.11384, 0.11236, 0.112, 0.11092, 0.11026, 0.10902,
-0.10422, 0.10712, 0.10736, 0.1043, 0.10284, 0.10158, 0.10076, 0.09848, 0.09642, 0.0952, 0.09476, 0.09314, 0.09252, 0.09188, 0.09252, 0.0904, 0.08868, 0.08844,
-0.08966, 0.08834, 0.08742, 0.0873, 0.08876, 0.09156, 0.092, 0.09048, 0.09128, 0.0922, 0.0928, 0.09228, 0.09278, 0.09406, 0.09478, 0.0944, 0.09662, 0.0977,
-0.09848, 0.09978, 0.1013, 0.10106, 0.10272, 0.10236, 0.10186, 0.10294, 0.10344, 
-//622
-0.05678, 0.05704, 0.05902, 0.06056, 0.06138, 0.06038, 0.05946, 0.05902, 0.0603, 0.05878, 0.0585, 0.05882, 0.06018, 0.0616, 0.06184, 0.06204, 0.0633, 0.06506,
-0.06606, 0.0668, 0.06774, 0.06754, 0.06652, 0.0654, 0.0659, 0.0669, 0.06632, 0.06518, 0.0648, 0.06588, 0.0653, 0.06408, 0.06428, 0.0646, 0.06314, 0.06244,
-0.0592, 0.06186, 0.06088, 0.05972, 0.0599, 0.05864, 0.0578, 0.05608, 0.05646, 0.05484, 0.05262, 0.05394, 0.05182, 0.05172, 0.0521, 0.05152, 0.0525, 0.05276,
-0.05324, 0.05342, 0.05356, 0.0527, 0.0539, 0.05568, 0.0557, 0.05356, 0.0549, 0.05662, 0.05754, 0.05776, 0.05872, 0.05872, 0.05954, 0.06038, 0.06106, 0.06204,
-0.06204, 0.06244, 0.0633, 0.064, 0.06492, 0.06398, 0.0653, 0.0658, 0.06722, 
-//623
-0.04366, 0.04038, 0.0403, 0.03972, 0.04098, 0.03924, 0.042, 0.04198, 0.04118, 0.04174, 0.04248, 0.04318, 0.04354, 0.04364, 0.04294, 0.04416, 0.04514, 0.04524,
-0.04654, 0.04834, 0.05004, 0.05086, 0.0519, 0.05132, 0.05226, 0.05226, 0.0526, 0.05144, 0.05104, 0.05086, 0.04946, 0.04848, 0.0478, 0.04602, 0.04508, 0.04444,
-0.0427, 0.04332, 0.04324, 0.04326, 0.043, 0.04254, 0.04156, 0.04048, 0.04024, 0.0408, 0.04012, 0.03946, 0.04004, 0.03918, 0.03942, 0.0386, 0.03952, 0.04004,
-0.04004, 0.0409, 0.04148, 0.0408, 0.04142, 0.04318, 0.04428, 0.04342, 0.04236, 0.04328, 0.04524, 0.04472, 0.04504, 0.04662, 0.04738, 0.048, 0.04906, 0.04972,
-0.05152, 0.05288, 0.05378, 0.05438, 0.05514, 0.05504, 0.05412, 0.05494, 0.0547, 
-//624
-0.1275, 0.1565, 0.21008, 0.28862, 0.3869, 0.48848, 0.56006, 0.59806, 0.61302, 0.61876, 0.6246, 0.6275, 0.63352, 0.63776, 0.6416, 0.64782, 0.65676, 0.6691,
-0.68196, 0.6958, 0.70836, 0.71544, 0.7249, 0.73172, 0.73778, 0.74056, 0.74254, 0.74134, 0.74092, 0.73748, 0.73554, 0.73306, 0.72826, 0.72322, 0.71912, 0.71158,
-0.70222, 0.69532, 0.68414, 0.67346, 0.66232, 0.64784, 0.63196, 0.61262, 0.59262, 0.57284, 0.5542, 0.53902, 0.5276, 0.5186, 0.51118, 0.50496, 0.49848, 0.49182,
-0.48556, 0.48188, 0.48246, 0.48264, 0.48582, 0.49216, 0.497, 0.50138, 0.50732, 0.51516, 0.52156, 0.5267, 0.52944, 0.52758, 0.52554, 0.52596, 0.52742, 0.52924,
-0.53752, 0.54502, 0.55358, 0.56158, 0.56742, 0.57244, 0.57518, 0.57674, 0.58348, 
-//625
-0.1365, 0.16798, 0.22092, 0.2937, 0.36862, 0.427, 0.45602, 0.46904, 0.47346, 0.47722, 0.48032, 0.4829, 0.4887, 0.49172, 0.49556, 0.501, 0.5087, 0.5185, 0.53086,
-0.54154, 0.5496, 0.55534, 0.56054, 0.56448, 0.56708, 0.56936, 0.56976, 0.56866, 0.56618, 0.56414, 0.5638, 0.56108, 0.55836, 0.55372, 0.55118, 0.54676, 0.53748,
-0.53444, 0.52724, 0.51894, 0.51026, 0.49806, 0.48564, 0.46952, 0.45302, 0.43696, 0.42222, 0.4101, 0.40028, 0.39318, 0.38682, 0.38154, 0.37664, 0.36986, 0.36488,
-0.36008, 0.35848, 0.35704, 0.3592, 0.36496, 0.36796, 0.37166, 0.37928, 0.3859, 0.39266, 0.39494, 0.39764, 0.39804, 0.395, 0.39326, 0.39302, 0.3956, 0.39968,
-0.40392, 0.4093, 0.41658, 0.42142, 0.42174, 0.428, 0.43178, 0.43668, 
-//626
-0.10612, 0.12826, 0.16972, 0.21912, 0.26464, 0.2962, 0.31116, 0.31832, 0.31952, 0.32196, 0.32524, 0.3276, 0.33102, 0.33224, 0.33628, 0.3395, 0.34516, 0.35104,
-0.35674, 0.36072, 0.36642, 0.37174, 0.37724, 0.38294, 0.38886, 0.39352, 0.39692, 0.39604, 0.39604, 0.393, 0.3911, 0.38762, 0.38442, 0.38088, 0.37764, 0.37302,
-0.3631, 0.3627, 0.35636, 0.34724, 0.33932, 0.3293, 0.31942, 0.30798, 0.29928, 0.2915, 0.28588, 0.28012, 0.27626, 0.27338, 0.27174, 0.26954, 0.2684, 0.26668,
-0.2667, 0.2668, 0.26822, 0.27, 0.27326, 0.27882, 0.2807, 0.28052, 0.28316, 0.28764, 0.2887, 0.28774, 0.28594, 0.28442, 0.28004, 0.28018, 0.28182, 0.28334,
-0.288, 0.29174, 0.29554, 0.29874, 0.30118, 0.3003, 0.30136, 0.30092, 0.3004, 
-//627
-0.10844, 0.1254, 0.1558, 0.18708, 0.2143, 0.22744, 0.2329, 0.23546, 0.23532, 0.23586, 0.23848, 0.2392, 0.24118, 0.24124, 0.24336, 0.24738, 0.2511, 0.2584,
-0.26522, 0.27304, 0.27848, 0.28376, 0.28576, 0.28736, 0.28898, 0.28756, 0.28934, 0.2866, 0.28642, 0.28434, 0.28228, 0.28018, 0.2774, 0.27504, 0.27272, 0.269,
-0.26266, 0.262, 0.25728, 0.2518, 0.24556, 0.2392, 0.23302, 0.2242, 0.21856, 0.2123, 0.20896, 0.2035, 0.2013, 0.19924, 0.19654, 0.19672, 0.19472, 0.19358,
-0.1936, 0.19334, 0.19534, 0.1958, 0.1974, 0.2022, 0.2035, 0.20288, 0.20288, 0.20788, 0.20958, 0.2082, 0.20804, 0.20666, 0.2056, 0.20528, 0.20824, 0.20896,
-0.21398, 0.2147, 0.2183, 0.22176, 0.22256, 0.22274, 0.22418, 0.22254, 0.22108, 
-//628
-0.09276, 0.10474, 0.11916, 0.13508, 0.1439, 0.1476, 0.15002, 0.14958, 0.14974, 0.15034, 0.15434, 0.15358, 0.15404, 0.1577, 0.16014, 0.16194, 0.166, 0.17068,
-0.17584, 0.1804, 0.18354, 0.18728, 0.18946, 0.1922, 0.19428, 0.19622, 0.19574, 0.19444, 0.1927, 0.19064, 0.18872, 0.1848, 0.1829, 0.17964, 0.17732, 0.1737,
-0.16988, 0.16844, 0.16676, 0.16234, 0.15664, 0.152, 0.14818, 0.14332, 0.13902, 0.13602, 0.13358, 0.13098, 0.13002, 0.12884, 0.12766, 0.12658, 0.12588, 0.1243,
-0.12392, 0.12224, 0.12232, 0.1201, 0.12128, 0.1239, 0.12526, 0.12302, 0.12632, 0.12778, 0.12802, 0.12746, 0.1262, 0.12616, 0.12522, 0.12526, 0.1245, 0.12632,
-0.12706, 0.12898, 0.1308, 0.13124, 0.13348, 0.13196, 0.13144, 0.13158, 0.13138, 
-//629
-0.0813, 0.08552, 0.09208, 0.09574, 0.09792, 0.09818, 0.09904, 0.09786, 0.09832, 0.0985, 0.1005, 0.09974, 0.09936, 0.101, 0.10144, 0.10314, 0.10556, 0.10864,
-0.11342, 0.11714, 0.12158, 0.12274, 0.12358, 0.12446, 0.12424, 0.12576, 0.1249, 0.1241, 0.12382, 0.12246, 0.12226, 0.1205, 0.11936, 0.11798, 0.11612, 0.11352,
-0.10826, 0.10834, 0.1064, 0.10342, 0.10182, 0.09828, 0.0954, 0.09176, 0.08888, 0.08664, 0.0847, 0.08294, 0.0816, 0.07946, 0.08012, 0.0789, 0.08006, 0.07884,
-0.07858, 0.07998, 0.08096, 0.07968, 0.08238, 0.08388, 0.08582, 0.08468, 0.08518, 0.08712, 0.08682, 0.08736, 0.08786, 0.08798, 0.08752, 0.0893, 0.09096, 0.09212,
-0.09338, 0.09524, 0.09684, 0.09766, 0.09864, 0.09818, 0.09822, 0.099, 0.09922, 
-//630
-0.05528, 0.05282, 0.05384, 0.05326, 0.05504, 0.0546, 0.05594, 0.05584, 0.05554, 0.05788, 0.05782, 0.0554, 0.05812, 0.0583, 0.05842, 0.0595, 0.06168, 0.06436,
-0.06704, 0.0701, 0.0725, 0.07306, 0.07352, 0.07568, 0.07706, 0.07946, 0.07692, 0.07532, 0.07446, 0.07272, 0.07222, 0.06968, 0.06798, 0.06766, 0.06588, 0.06228,
-0.0602, 0.06006, 0.06132, 0.05824, 0.05618, 0.0536, 0.05262, 0.05076, 0.04906, 0.04832, 0.04792, 0.04794, 0.04888, 0.04824, 0.04894, 0.04928, 0.04934, 0.04968,
-0.05056, 0.05056, 0.0505, 0.04892, 0.0512, 0.05224, 0.05314, 0.04994, 0.0524, 0.0546, 0.05502, 0.0545, 0.0552, 0.05644, 0.05582, 0.05724, 0.05962, 0.0602,
-0.06218, 0.063, 0.06412, 0.06612, 0.06692, 0.06718, 0.06738, 0.06518, 0.06704, 
-//631
-0.04552, 0.04014, 0.03946, 0.0403, 0.04096, 0.0415, 0.04142, 0.04128, 0.04172, 0.04258, 0.0441, 0.0439, 0.04442, 0.0452, 0.04554, 0.04632, 0.04854, 0.04892,
-0.0517, 0.05422, 0.05586, 0.05748, 0.05836, 0.0605, 0.06192, 0.0615, 0.06148, 0.06008, 0.05952, 0.05686, 0.05694, 0.05398, 0.05188, 0.05036, 0.04898, 0.04664,
-0.04432, 0.04432, 0.0449, 0.0416, 0.04136, 0.03998, 0.04024, 0.03882, 0.03912, 0.0389, 0.03998, 0.04056, 0.03976, 0.0401, 0.0401, 0.03974, 0.03934, 0.039,
-0.0395, 0.03948, 0.04114, 0.03952, 0.04106, 0.04248, 0.04406, 0.04294, 0.0429, 0.04562, 0.04536, 0.04484, 0.04582, 0.04632, 0.04582, 0.04664, 0.04936, 0.05042,
-0.0528, 0.05222, 0.05434, 0.05436, 0.05628, 0.05666, 0.05622, 0.05644, 0.05692, 
-//632
-0.12978, 0.1516, 0.19864, 0.26242, 0.32634, 0.37542, 0.4027, 0.4136, 0.41794, 0.42346, 0.42798, 0.43366, 0.4388, 0.44346, 0.4516, 0.45828, 0.46998, 0.48552,
-0.5057, 0.52754, 0.54412, 0.56002, 0.57146, 0.58356, 0.59088, 0.59456, 0.59688, 0.59428, 0.5938, 0.58874, 0.5845, 0.57884, 0.57128, 0.56376, 0.5544, 0.54144,
-0.52748, 0.5181, 0.50244, 0.48626, 0.46776, 0.4466, 0.42528, 0.4002, 0.3751, 0.35166, 0.3327, 0.31658, 0.30508, 0.2974, 0.29116, 0.2853, 0.27926, 0.27396,
-0.26986, 0.26498, 0.26404, 0.26412, 0.26822, 0.27374, 0.2778, 0.28346, 0.28952, 0.2996, 0.30646, 0.31068, 0.31096, 0.30962, 0.30546, 0.30246, 0.30134, 0.30412,
-0.31004, 0.316, 0.32466, 0.32972, 0.33834, 0.3411, 0.34808, 0.35294, 0.36074, 
-//633
-0.1263, 0.15116, 0.18578, 0.22838, 0.26388, 0.28496, 0.29562, 0.29766, 0.30156, 0.30254, 0.30792, 0.31188, 0.31656, 0.32096, 0.32468, 0.33274, 0.34504, 0.3613,
-0.3819, 0.40086, 0.4194, 0.42958, 0.43872, 0.4444, 0.44788, 0.44878, 0.44696, 0.44626, 0.4439, 0.43918, 0.43424, 0.42918, 0.42242, 0.41308, 0.40428, 0.3948,
-0.38042, 0.3715, 0.35978, 0.34464, 0.32984, 0.31322, 0.2968, 0.28044, 0.26618, 0.25504, 0.24624, 0.24046, 0.23534, 0.23132, 0.22974, 0.22616, 0.22466, 0.223,
-0.22258, 0.22278, 0.22566, 0.2295, 0.23588, 0.24242, 0.24718, 0.24872, 0.25462, 0.2589, 0.26076, 0.26124, 0.25942, 0.25676, 0.2537, 0.2538, 0.25508, 0.25904,
-0.26626, 0.27328, 0.28108, 0.28764, 0.29382, 0.29524, 0.29692, 0.29532, 0.29592, 
-//634
-0.1023, 0.11694, 0.14154, 0.1669, 0.18556, 0.19636, 0.20116, 0.20314, 0.20634, 0.2072, 0.21016, 0.2121, 0.21658, 0.2191, 0.22278, 0.22888, 0.2409, 0.25358,
-0.26918, 0.28498, 0.2998, 0.309, 0.31578, 0.32126, 0.3221, 0.32184, 0.3214, 0.3186, 0.31572, 0.31114, 0.30702, 0.30162, 0.29576, 0.2881, 0.28136, 0.27316,
-0.26218, 0.25318, 0.24232, 0.23128, 0.22, 0.20764, 0.19466, 0.18188, 0.17238, 0.16322, 0.15818, 0.1536, 0.15078, 0.14848, 0.14816, 0.14638, 0.1454, 0.14394,
-0.14354, 0.14492, 0.1465, 0.14728, 0.15204, 0.15604, 0.15888, 0.16012, 0.16278, 0.16712, 0.16904, 0.17024, 0.17044, 0.16772, 0.16684, 0.1667, 0.16766, 0.1721,
-0.17766, 0.18156, 0.18678, 0.19166, 0.19608, 0.19746, 0.19776, 0.19622, 0.19652, 
-//635
-0.09292, 0.09928, 0.11002, 0.12112, 0.1297, 0.13392, 0.13588, 0.13568, 0.13698, 0.14078, 0.14118, 0.14294, 0.1452, 0.14784, 0.15114, 0.15556, 0.1629, 0.17082,
-0.18232, 0.19206, 0.20206, 0.20894, 0.21236, 0.2146, 0.21808, 0.21686, 0.21726, 0.21606, 0.21458, 0.2098, 0.20786, 0.20402, 0.19774, 0.19262, 0.1853, 0.17988,
-0.17158, 0.16784, 0.15988, 0.15286, 0.14364, 0.13518, 0.12664, 0.11758, 0.11098, 0.10562, 0.10316, 0.09928, 0.09886, 0.09702, 0.09704, 0.09624, 0.09512,
-0.09508, 0.09456, 0.09472, 0.09722, 0.09674, 0.09886, 0.10278, 0.10478, 0.10414, 0.10638, 0.11024, 0.11216, 0.1113, 0.1118, 0.11072, 0.10948, 0.11114, 0.11218,
-0.11418, 0.11778, 0.1219, 0.12584, 0.12826, 0.13078, 0.13196, 0.1321, 0.13298, 0.13162, 
-//636
-0.07542, 0.0753, 0.0799, 0.0837, 0.08592, 0.08516, 0.08678, 0.08614, 0.08676, 0.08676, 0.08756, 0.08818, 0.0887, 0.09082, 0.0923, 0.09636, 0.10296, 0.10922,
-0.1185, 0.12848, 0.13834, 0.14442, 0.15076, 0.15554, 0.15578, 0.15504, 0.15386, 0.1509, 0.14792, 0.14496, 0.14156, 0.13532, 0.13108, 0.12404, 0.11876, 0.11256,
-0.10294, 0.0996, 0.0949, 0.0885, 0.08258, 0.07766, 0.07316, 0.0704, 0.067, 0.0659, 0.06372, 0.06334, 0.06486, 0.0631, 0.06356, 0.06272, 0.06276, 0.06214,
-0.06192, 0.06214, 0.06214, 0.061, 0.06334, 0.06486, 0.06752, 0.06626, 0.06964, 0.07034, 0.07088, 0.07084, 0.07094, 0.07138, 0.07276, 0.0739, 0.0754, 0.07712,
-0.0796, 0.08226, 0.08438, 0.0866, 0.08876, 0.08826, 0.09004, 0.0885, 0.0903, 
-//637
-0.04702, 0.04374, 0.04392, 0.04382, 0.04294, 0.04178, 0.04202, 0.0428, 0.04344, 0.04358, 0.04556, 0.04544, 0.04734, 0.04872, 0.05058, 0.05296, 0.05698, 0.06178,
-0.06658, 0.07296, 0.08052, 0.0847, 0.08932, 0.09218, 0.0977, 0.0988, 0.09696, 0.09526, 0.09148, 0.08764, 0.08166, 0.07584, 0.07166, 0.06584, 0.06002, 0.05558,
-0.0479, 0.04714, 0.04588, 0.04348, 0.04174, 0.04054, 0.03916, 0.03868, 0.03874, 0.03868, 0.03938, 0.03868, 0.03794, 0.03942, 0.03878, 0.03872, 0.03928, 0.03886,
-0.0375, 0.03878, 0.03896, 0.03826, 0.03962, 0.0413, 0.04272, 0.04196, 0.04316, 0.04366, 0.04356, 0.04514, 0.04662, 0.0457, 0.04716, 0.0485, 0.04974, 0.05138,
-0.0521, 0.05268, 0.0533, 0.05566, 0.05498, 0.05658, 0.057, 0.05736, 0.05812, 
-//638
-0.1183, 0.13962, 0.17002, 0.20958, 0.23688, 0.2565, 0.26512, 0.27052, 0.27482, 0.2792, 0.28276, 0.28658, 0.29512, 0.30024, 0.3068, 0.31686, 0.3276, 0.34346,
-0.36272, 0.38562, 0.40486, 0.42186, 0.4383, 0.45194, 0.464, 0.46934, 0.47166, 0.47006, 0.46626, 0.46098, 0.4538, 0.44544, 0.43576, 0.4227, 0.41072, 0.3951,
-0.37602, 0.36338, 0.34514, 0.32512, 0.30642, 0.28514, 0.2646, 0.24008, 0.21968, 0.2008, 0.18598, 0.1731, 0.16542, 0.15884, 0.15364, 0.14842, 0.14536, 0.14052,
-0.13686, 0.13342, 0.13262, 0.1308, 0.13288, 0.13716, 0.14004, 0.14282, 0.14806, 0.1536, 0.15768, 0.16084, 0.16328, 0.16338, 0.16274, 0.16108, 0.16228, 0.1649,
-0.16892, 0.1752, 0.18218, 0.1898, 0.195, 0.199, 0.20462, 0.20644, 0.2123, 
-//639
-0.1092, 0.11916, 0.13964, 0.1575, 0.17204, 0.17842, 0.18376, 0.18672, 0.18786, 0.18948, 0.1947, 0.199, 0.2024, 0.2065, 0.21404, 0.22162, 0.2361, 0.25414,
-0.2771, 0.30084, 0.32276, 0.33972, 0.35066, 0.35862, 0.36088, 0.36092, 0.35956, 0.35488, 0.34988, 0.34112, 0.33372, 0.32252, 0.31128, 0.29724, 0.28496, 0.2682,
-0.25252, 0.23914, 0.22526, 0.21008, 0.19548, 0.18006, 0.16486, 0.15156, 0.14208, 0.13368, 0.12838, 0.12404, 0.12044, 0.11968, 0.11668, 0.11484, 0.11452,
-0.11352, 0.11392, 0.1148, 0.11656, 0.11752, 0.12144, 0.12554, 0.1297, 0.13136, 0.1349, 0.13852, 0.14124, 0.142, 0.141, 0.13826, 0.13616, 0.13652, 0.13626,
-0.13964, 0.14588, 0.15222, 0.15838, 0.16414, 0.1695, 0.17222, 0.17212, 0.17258, 0.17288, 
-//640
-0.08898, 0.09296, 0.10244, 0.11034, 0.1151, 0.11942, 0.12086, 0.12048, 0.12204, 0.12344, 0.12534, 0.12748, 0.1304, 0.13502, 0.13858, 0.14548, 0.15684, 0.17068,
-0.18716, 0.20416, 0.22048, 0.23246, 0.23824, 0.24376, 0.24674, 0.24602, 0.24336, 0.23878, 0.2346, 0.22806, 0.22216, 0.21404, 0.206, 0.19498, 0.18508, 0.17388,
-0.159, 0.1519, 0.14192, 0.13128, 0.12106, 0.11236, 0.10248, 0.09356, 0.08678, 0.082, 0.07942, 0.07602, 0.07646, 0.07532, 0.07468, 0.07434, 0.07566, 0.07362,
-0.0737, 0.07444, 0.07594, 0.07422, 0.07726, 0.0813, 0.08358, 0.08298, 0.0846, 0.08698, 0.08956, 0.0897, 0.0909, 0.09006, 0.08988, 0.0904, 0.09216, 0.0942,
-0.096, 0.10136, 0.10476, 0.10752, 0.11048, 0.10968, 0.11228, 0.11176, 0.11202, 
-//641
-0.06166, 0.06314, 0.06428, 0.06562, 0.06924, 0.06996, 0.07032, 0.07114, 0.07352, 0.07356, 0.07434, 0.07542, 0.07768, 0.07924, 0.08252, 0.08578, 0.09476,
-0.10554, 0.1203, 0.13628, 0.15448, 0.17096, 0.1813, 0.18888, 0.191, 0.18904, 0.18496, 0.17752, 0.16994, 0.16044, 0.14912, 0.13884, 0.12808, 0.11786, 0.10752,
-0.0977, 0.0851, 0.08244, 0.07602, 0.07124, 0.066, 0.05996, 0.05646, 0.05378, 0.0513, 0.04964, 0.04888, 0.04754, 0.04926, 0.04814, 0.04896, 0.04942, 0.04964,
-0.04954, 0.05068, 0.05078, 0.05116, 0.04914, 0.0512, 0.05228, 0.05372, 0.05244, 0.05396, 0.05688, 0.05746, 0.05836, 0.05914, 0.05852, 0.05854, 0.05994, 0.06168,
-0.06382, 0.06636, 0.06802, 0.06968, 0.07072, 0.07312, 0.0733, 0.07454, 0.07406, 0.07454, 
-//642
-0.11342, 0.12984, 0.16018, 0.19018, 0.21572, 0.22848, 0.2367, 0.24144, 0.2446, 0.24878, 0.25556, 0.26124, 0.2677, 0.27566, 0.2847, 0.29746, 0.31732, 0.34286,
-0.37584, 0.41598, 0.45802, 0.4966, 0.52774, 0.54654, 0.5576, 0.55648, 0.5513, 0.53904, 0.5252, 0.50738, 0.48856, 0.46694, 0.44278, 0.41608, 0.39238, 0.36576,
-0.33894, 0.32048, 0.29822, 0.27502, 0.2535, 0.2319, 0.21248, 0.19484, 0.18324, 0.17274, 0.16582, 0.16176, 0.15894, 0.15534, 0.15364, 0.15146, 0.14988, 0.14738,
-0.14838, 0.14782, 0.15132, 0.15118, 0.15682, 0.16304, 0.16754, 0.17166, 0.1765, 0.1822, 0.18548, 0.18568, 0.18366, 0.18092, 0.17796, 0.17652, 0.17964, 0.18412,
-0.19154, 0.19916, 0.20888, 0.21696, 0.22282, 0.22524, 0.22784, 0.22668, 0.22742, 
-//643
-0.09456, 0.10318, 0.12158, 0.13372, 0.14322, 0.15206, 0.15298, 0.1557, 0.15724, 0.16054, 0.1659, 0.17058, 0.17566, 0.18082, 0.1901, 0.199, 0.21572, 0.23906,
-0.26606, 0.29818, 0.33184, 0.36142, 0.38194, 0.39468, 0.40238, 0.4011, 0.39664, 0.38814, 0.37656, 0.36364, 0.34878, 0.33124, 0.31282, 0.29276, 0.27332, 0.2511,
-0.22802, 0.215, 0.1979, 0.18098, 0.16752, 0.15208, 0.13866, 0.12592, 0.11728, 0.1098, 0.10564, 0.10096, 0.09764, 0.09638, 0.09608, 0.09308, 0.09414, 0.0924,
-0.09352, 0.09324, 0.096, 0.09544, 0.1002, 0.10562, 0.10828, 0.1081, 0.11012, 0.11504, 0.1171, 0.11756, 0.11736, 0.11692, 0.11588, 0.11464, 0.11724, 0.12034,
-0.12548, 0.12994, 0.13648, 0.14122, 0.14632, 0.14776, 0.14936, 0.14844, 0.1496, 
-//644
-0.07618, 0.08138, 0.08838, 0.09568, 0.09894, 0.1015, 0.1026, 0.1045, 0.10598, 0.10578, 0.10968, 0.11254, 0.11646, 0.12096, 0.12696, 0.13642, 0.14972, 0.16734,
-0.18958, 0.21694, 0.24716, 0.27268, 0.29082, 0.30204, 0.30648, 0.3031, 0.29558, 0.28604, 0.27406, 0.25904, 0.24494, 0.22796, 0.21188, 0.1937, 0.1771, 0.16088,
-0.14144, 0.13104, 0.1203, 0.11032, 0.10024, 0.09042, 0.08272, 0.07688, 0.0705, 0.06698, 0.06486, 0.06352, 0.06344, 0.06304, 0.06306, 0.06274, 0.0623, 0.06132,
-0.06196, 0.06386, 0.06414, 0.0632, 0.06568, 0.06878, 0.07052, 0.0693, 0.07214, 0.07576, 0.07636, 0.07704, 0.07754, 0.07694, 0.07642, 0.07638, 0.07812, 0.07982,
-0.08268, 0.08614, 0.08994, 0.0924, 0.0967, 0.09746, 0.09958, 0.1002, 0.09966, 
-//645
-0.05932, 0.05938, 0.0617, 0.06452, 0.06522, 0.06604, 0.06528, 0.06554, 0.06564, 0.06704, 0.06752, 0.06944, 0.07244, 0.07446, 0.07858, 0.08314, 0.0922, 0.10382,
-0.1207, 0.14374, 0.1719, 0.20116, 0.22424, 0.2386, 0.2422, 0.2354, 0.22438, 0.20746, 0.19094, 0.1728, 0.15734, 0.14042, 0.12488, 0.11146, 0.09936, 0.08788,
-0.0766, 0.07118, 0.0661, 0.06132, 0.0577, 0.05372, 0.05188, 0.0506, 0.05002, 0.04776, 0.04868, 0.04816, 0.04788, 0.04756, 0.04764, 0.04644, 0.04656, 0.04584,
-0.04562, 0.04664, 0.0479, 0.04626, 0.04954, 0.05136, 0.05144, 0.0514, 0.0522, 0.05524, 0.05534, 0.05642, 0.05604, 0.0557, 0.05596, 0.05688, 0.0581, 0.0592,
-0.06112, 0.0637, 0.06662, 0.0681, 0.06984, 0.0707, 0.07144, 0.07144, 0.0725, 
-//646
-0.09554, 0.1062, 0.12312, 0.13386, 0.14326, 0.14582, 0.15082, 0.15072, 0.15258, 0.15588, 0.16052, 0.16406, 0.16976, 0.17474, 0.1841, 0.19502, 0.214, 0.2387,
-0.27002, 0.30804, 0.35116, 0.38988, 0.4215, 0.44082, 0.44882, 0.44652, 0.43716, 0.42192, 0.40434, 0.38356, 0.36178, 0.3393, 0.31504, 0.28996, 0.26542, 0.24178,
-0.2181, 0.20016, 0.18178, 0.1643, 0.14966, 0.13656, 0.1237, 0.11274, 0.10496, 0.09896, 0.09662, 0.09344, 0.0928, 0.09176, 0.09002, 0.08884, 0.08724, 0.08626,
-0.08484, 0.08564, 0.08648, 0.08626, 0.0904, 0.09452, 0.0972, 0.09836, 0.10114, 0.1055, 0.10584, 0.10682, 0.10562, 0.10402, 0.10184, 0.10298, 0.10354, 0.10684,
-0.11244, 0.11784, 0.12438, 0.12838, 0.13342, 0.13598, 0.1364, 0.13632, 0.1365, 
-//647
-0.13732, 0.1685, 0.2272, 0.31152, 0.41108, 0.51062, 0.57634, 0.6074, 0.62018, 0.62628, 0.62946, 0.63552, 0.63996, 0.64424, 0.65016, 0.6576, 0.66878, 0.68304,
-0.69866, 0.71524, 0.73032, 0.74, 0.74762, 0.75326, 0.75572, 0.7556, 0.75504, 0.75328, 0.75124, 0.7472, 0.74258, 0.7379, 0.73066, 0.72264, 0.71392, 0.70294,
-0.69158, 0.67932, 0.66582, 0.65256, 0.63734, 0.62044, 0.60372, 0.58496, 0.57154, 0.55836, 0.54988, 0.5444, 0.53844, 0.53512, 0.53272, 0.5291, 0.52666, 0.52474,
-0.52346, 0.52578, 0.5294, 0.53534, 0.54158, 0.54894, 0.55524, 0.5605, 0.5649, 0.56984, 0.57174, 0.57336, 0.57202, 0.56618, 0.56318, 0.5622, 0.566, 0.56918,
-0.58022, 0.58602, 0.5969, 0.6054, 0.61098, 0.6133, 0.61216, 0.60908, 0.6135, 
-//648
-0.1315, 0.15904, 0.2112, 0.28072, 0.35768, 0.4202, 0.45344, 0.46648, 0.4705, 0.47204, 0.47734, 0.48002, 0.48466, 0.48726, 0.49198, 0.4972, 0.5057, 0.51668,
-0.52874, 0.5422, 0.55156, 0.55802, 0.5622, 0.5641, 0.56422, 0.56348, 0.56278, 0.56128, 0.55968, 0.55614, 0.55324, 0.55078, 0.54658, 0.54198, 0.53612, 0.52946,
-0.52196, 0.51498, 0.50592, 0.49636, 0.48516, 0.47198, 0.45884, 0.44502, 0.43368, 0.42276, 0.41562, 0.41008, 0.4061, 0.40278, 0.4005, 0.39642, 0.39434, 0.39314,
-0.39294, 0.3931, 0.39588, 0.39756, 0.40198, 0.40744, 0.41176, 0.41574, 0.41904, 0.42424, 0.42506, 0.42492, 0.4251, 0.42252, 0.41796, 0.41812, 0.4202, 0.42386,
-0.43078, 0.43628, 0.4435, 0.44616, 0.4523, 0.4538, 0.45514, 0.4526, 0.45266, 
-//649
-0.11716, 0.14382, 0.18586, 0.24064, 0.28964, 0.32226, 0.33672, 0.34226, 0.34386, 0.34374, 0.34652, 0.34904, 0.35006, 0.35292, 0.3552, 0.35896, 0.36622, 0.3743,
-0.38336, 0.39212, 0.40044, 0.40414, 0.40756, 0.4084, 0.40872, 0.40798, 0.4072, 0.40574, 0.40448, 0.40206, 0.39968, 0.39782, 0.39448, 0.39004, 0.38642, 0.3816,
-0.3755, 0.37152, 0.36576, 0.3574, 0.34998, 0.3402, 0.33112, 0.3204, 0.3117, 0.30388, 0.29852, 0.29428, 0.29004, 0.28812, 0.28646, 0.2839, 0.28098, 0.27974,
-0.27868, 0.27836, 0.28088, 0.28238, 0.28628, 0.29012, 0.29228, 0.29428, 0.29538, 0.3002, 0.3016, 0.30148, 0.3019, 0.29988, 0.2965, 0.2977, 0.29936, 0.30106,
-0.3062, 0.30924, 0.31394, 0.31664, 0.32084, 0.32086, 0.3215, 0.3185, 0.3188, 
-//650
-0.113, 0.1335, 0.16386, 0.1952, 0.21956, 0.23398, 0.23928, 0.24216, 0.2438, 0.24446, 0.24596, 0.24776, 0.25032, 0.252, 0.25454, 0.25662, 0.26114, 0.26746,
-0.27304, 0.2791, 0.2837, 0.2862, 0.28706, 0.28722, 0.2876, 0.28654, 0.2851, 0.2846, 0.28316, 0.28244, 0.28066, 0.27892, 0.27678, 0.27424, 0.272, 0.26786,
-0.26318, 0.26008, 0.25538, 0.25012, 0.24402, 0.23642, 0.22952, 0.2209, 0.21274, 0.20798, 0.20334, 0.20056, 0.19838, 0.19718, 0.19538, 0.1932, 0.19226, 0.19114,
-0.19102, 0.19126, 0.19352, 0.19388, 0.19726, 0.20056, 0.2021, 0.20308, 0.20522, 0.20682, 0.20848, 0.20888, 0.20988, 0.20868, 0.20712, 0.20814, 0.20948, 0.21246,
-0.21562, 0.21812, 0.222, 0.22416, 0.22642, 0.22544, 0.22716, 0.2271, 0.22842, 
-//651
-0.09588, 0.10732, 0.12492, 0.14154, 0.15278, 0.15818, 0.16012, 0.1611, 0.16128, 0.16124, 0.16232, 0.16422, 0.16544, 0.16578, 0.16816, 0.16882, 0.1732, 0.17714,
-0.1825, 0.18732, 0.19042, 0.19144, 0.19294, 0.19294, 0.19422, 0.19382, 0.1939, 0.19376, 0.19294, 0.192, 0.1904, 0.18802, 0.18642, 0.18468, 0.1826, 0.17942,
-0.17522, 0.17274, 0.16912, 0.1656, 0.15954, 0.15558, 0.15058, 0.14464, 0.14128, 0.13702, 0.134, 0.13232, 0.13062, 0.12942, 0.12772, 0.12682, 0.12564, 0.12484,
-0.12394, 0.12408, 0.12514, 0.1252, 0.12724, 0.13008, 0.131, 0.13136, 0.13388, 0.13656, 0.13786, 0.13788, 0.1382, 0.13694, 0.1367, 0.13662, 0.13808, 0.13982,
-0.14286, 0.1441, 0.14688, 0.14912, 0.15042, 0.15134, 0.15258, 0.15138, 0.15152, 
-//652
-0.0837, 0.0868, 0.09474, 0.10094, 0.103, 0.104, 0.10516, 0.105, 0.10546, 0.10566, 0.1061, 0.10702, 0.10812, 0.10856, 0.11028, 0.11138, 0.11414, 0.11722,
-0.12054, 0.12388, 0.12656, 0.12768, 0.1279, 0.12858, 0.12844, 0.12734, 0.12732, 0.12566, 0.12464, 0.12338, 0.12262, 0.12136, 0.11968, 0.11814, 0.11706, 0.11506,
-0.11174, 0.10924, 0.10702, 0.10498, 0.10118, 0.09812, 0.09488, 0.0912, 0.08838, 0.08748, 0.08586, 0.0842, 0.08318, 0.08214, 0.08204, 0.08126, 0.08046, 0.08086,
-0.08096, 0.07934, 0.0798, 0.07952, 0.08244, 0.08348, 0.08454, 0.08362, 0.08516, 0.08774, 0.08818, 0.0885, 0.08924, 0.08876, 0.0883, 0.08862, 0.08982, 0.09096,
-0.0935, 0.09522, 0.09678, 0.09784, 0.101, 0.10124, 0.10236, 0.10168, 0.10246, 
-//653
-0.05818, 0.05844, 0.05958, 0.05966, 0.06056, 0.06096, 0.061, 0.06094, 0.06174, 0.06166, 0.0625, 0.06392, 0.06404, 0.06476, 0.0654, 0.06574, 0.06888, 0.07106,
-0.0737, 0.07656, 0.07928, 0.07986, 0.07962, 0.0795, 0.07906, 0.07866, 0.07712, 0.07592, 0.07538, 0.07372, 0.07314, 0.07148, 0.06986, 0.06828, 0.06734, 0.06574,
-0.0634, 0.06292, 0.06112, 0.05956, 0.05764, 0.05638, 0.05384, 0.05146, 0.05194, 0.05036, 0.04954, 0.04994, 0.04962, 0.04972, 0.05036, 0.04984, 0.05044, 0.05044,
-0.05006, 0.0514, 0.05138, 0.0516, 0.05352, 0.05484, 0.0551, 0.055, 0.05488, 0.05592, 0.05604, 0.0574, 0.05792, 0.05802, 0.05842, 0.05942, 0.06066, 0.06232,
-0.064, 0.06514, 0.06622, 0.06744, 0.06802, 0.0685, 0.06908, 0.06796, 0.0694, 
-//654
-0.04548, 0.04414, 0.04406, 0.044, 0.04516, 0.04436, 0.0449, 0.04426, 0.04452, 0.04454, 0.0447, 0.04462, 0.04568, 0.04448, 0.04592, 0.04692, 0.04872, 0.05058,
-0.05294, 0.0549, 0.0576, 0.05918, 0.05952, 0.0606, 0.0612, 0.06082, 0.0609, 0.05924, 0.0571, 0.05504, 0.0532, 0.0511, 0.04992, 0.04794, 0.04598, 0.04484,
-0.04152, 0.04354, 0.04342, 0.04272, 0.04146, 0.04114, 0.0403, 0.03968, 0.03994, 0.03882, 0.0385, 0.03858, 0.03918, 0.03884, 0.03954, 0.03932, 0.03938, 0.04016,
-0.0407, 0.04062, 0.04198, 0.04134, 0.04302, 0.04462, 0.04546, 0.04412, 0.04518, 0.04642, 0.04678, 0.04664, 0.04798, 0.04724, 0.04808, 0.04968, 0.05088, 0.05126,
-0.05214, 0.05386, 0.05446, 0.05568, 0.05654, 0.05622, 0.05638, 0.05658, 0.05768, 
-//655
-0.13552, 0.16476, 0.21574, 0.2795, 0.3471, 0.39798, 0.425, 0.4367, 0.43974, 0.44414, 0.44752, 0.45256, 0.45938, 0.46488, 0.47196, 0.48238, 0.4984, 0.51794,
-0.54066, 0.56574, 0.58736, 0.6039, 0.61572, 0.6221, 0.6256, 0.62496, 0.62316, 0.61904, 0.6141, 0.6072, 0.5995, 0.589, 0.57702, 0.56362, 0.54984, 0.5335,
-0.51674, 0.49888, 0.48216, 0.4628, 0.443, 0.42164, 0.40058, 0.3808, 0.36416, 0.35044, 0.3406, 0.33482, 0.33032, 0.32538, 0.32344, 0.32096, 0.31654, 0.31442,
-0.31428, 0.31426, 0.31798, 0.32258, 0.32914, 0.33618, 0.34262, 0.34782, 0.35328, 0.35876, 0.36304, 0.3632, 0.36154, 0.35756, 0.35168, 0.35044, 0.35214, 0.35656,
-0.36526, 0.37432, 0.38598, 0.39304, 0.3989, 0.40288, 0.4069, 0.40324, 0.40352, 
-//656
-0.11388, 0.13736, 0.17616, 0.22316, 0.26664, 0.2945, 0.30858, 0.31222, 0.31496, 0.31744, 0.32122, 0.32394, 0.32922, 0.33378, 0.33916, 0.3467, 0.359, 0.37314,
-0.38984, 0.40732, 0.42122, 0.43392, 0.44118, 0.44534, 0.44746, 0.4474, 0.44562, 0.44278, 0.43892, 0.43378, 0.42782, 0.42142, 0.41418, 0.40594, 0.39636, 0.38478,
-0.37196, 0.3619, 0.34822, 0.3337, 0.31818, 0.30232, 0.28664, 0.27016, 0.25752, 0.24652, 0.23956, 0.23438, 0.23086, 0.22678, 0.22564, 0.22318, 0.22004, 0.21922,
-0.21948, 0.21962, 0.22246, 0.22498, 0.23044, 0.23636, 0.24084, 0.24444, 0.24766, 0.25214, 0.25388, 0.25406, 0.2537, 0.25014, 0.24654, 0.24684, 0.24864, 0.25184,
-0.25832, 0.26464, 0.27238, 0.2776, 0.2828, 0.28498, 0.28658, 0.28372, 0.2847, 
-//657
-0.10632, 0.1246, 0.14908, 0.17342, 0.19342, 0.20416, 0.20962, 0.21218, 0.21406, 0.21688, 0.22022, 0.22392, 0.22794, 0.23178, 0.23806, 0.24606, 0.25566, 0.2678,
-0.28114, 0.29386, 0.3047, 0.31078, 0.31454, 0.3164, 0.3173, 0.31686, 0.3148, 0.31188, 0.30968, 0.30462, 0.29972, 0.29478, 0.28882, 0.28152, 0.27396, 0.2636,
-0.25238, 0.24376, 0.23338, 0.2217, 0.2087, 0.19562, 0.18308, 0.17102, 0.16224, 0.15524, 0.15092, 0.14678, 0.1449, 0.14194, 0.14096, 0.1393, 0.1378, 0.13668,
-0.13666, 0.1368, 0.13896, 0.14028, 0.14392, 0.14798, 0.15198, 0.154, 0.15652, 0.15932, 0.16196, 0.16206, 0.16186, 0.16128, 0.15926, 0.15936, 0.16064, 0.16444,
-0.1705, 0.17692, 0.18196, 0.18676, 0.19002, 0.19178, 0.19168, 0.19136, 0.19222, 
-//658
-0.08444, 0.0961, 0.10914, 0.12228, 0.13076, 0.13578, 0.1384, 0.14114, 0.14246, 0.14478, 0.1492, 0.152, 0.15646, 0.16138, 0.16618, 0.17264, 0.18206, 0.18946,
-0.19698, 0.20428, 0.20984, 0.2138, 0.21756, 0.21978, 0.22202, 0.22292, 0.2224, 0.22006, 0.21822, 0.21496, 0.21108, 0.20654, 0.20096, 0.19452, 0.18934, 0.18152,
-0.17376, 0.16846, 0.16042, 0.1529, 0.1448, 0.13564, 0.12752, 0.11916, 0.11258, 0.10684, 0.10318, 0.09966, 0.09668, 0.09594, 0.09382, 0.09238, 0.092, 0.08988,
-0.08912, 0.0883, 0.0882, 0.08756, 0.0897, 0.09164, 0.093, 0.09384, 0.09526, 0.09758, 0.09952, 0.10018, 0.1011, 0.10118, 0.10162, 0.10148, 0.10378, 0.10534,
-0.10868, 0.11138, 0.11538, 0.11738, 0.11992, 0.1201, 0.12096, 0.12078, 0.12278, 
-//659
-0.06538, 0.06914, 0.0737, 0.0782, 0.08212, 0.08366, 0.08484, 0.08584, 0.08648, 0.08862, 0.09024, 0.09206, 0.0938, 0.09638, 0.1014, 0.10604, 0.1136, 0.12032,
-0.12678, 0.1331, 0.1364, 0.14056, 0.14362, 0.14438, 0.14596, 0.14504, 0.14496, 0.14298, 0.13946, 0.13678, 0.1324, 0.1287, 0.12422, 0.1186, 0.11334, 0.10708,
-0.10082, 0.09954, 0.0942, 0.08826, 0.08304, 0.07664, 0.0725, 0.06812, 0.06412, 0.0613, 0.06052, 0.0593, 0.05762, 0.05748, 0.05698, 0.05622, 0.05692, 0.0558,
-0.05654, 0.05616, 0.05592, 0.05388, 0.05608, 0.0575, 0.05894, 0.05764, 0.05826, 0.06122, 0.06144, 0.06294, 0.06258, 0.06258, 0.06396, 0.0647, 0.06652, 0.0688,
-0.07148, 0.0731, 0.07506, 0.0766, 0.07856, 0.07876, 0.07984, 0.07974, 0.08088, 
-//660
-0.0474, 0.04732, 0.04706, 0.04802, 0.0493, 0.05058, 0.04988, 0.0505, 0.05146, 0.05242, 0.05382, 0.0537, 0.05474, 0.05552, 0.05742, 0.05976, 0.06362, 0.06982,
-0.07734, 0.08608, 0.09694, 0.10324, 0.1098, 0.11164, 0.11278, 0.10984, 0.10616, 0.10164, 0.0967, 0.09112, 0.0848, 0.0783, 0.07232, 0.06602, 0.06024, 0.05542,
-0.04792, 0.04692, 0.04628, 0.04424, 0.04214, 0.04068, 0.03888, 0.03898, 0.03936, 0.03964, 0.04042, 0.0408, 0.0412, 0.0415, 0.04116, 0.04142, 0.0421, 0.04192,
-0.04306, 0.0426, 0.04204, 0.0415, 0.04378, 0.04504, 0.04632, 0.0457, 0.04756, 0.04898, 0.049, 0.04868, 0.04982, 0.05032, 0.05114, 0.05094, 0.05272, 0.05378,
-0.05506, 0.05598, 0.0576, 0.05778, 0.05924, 0.06002, 0.06, 0.06062, 0.0618, 
-//661
-0.12468, 0.14906, 0.18534, 0.22686, 0.2593, 0.2803, 0.29138, 0.29562, 0.30088, 0.30276, 0.30988, 0.3156, 0.32184, 0.33042, 0.33902, 0.35076, 0.36898, 0.39126,
-0.4178, 0.44532, 0.4718, 0.49416, 0.50564, 0.51328, 0.51626, 0.51506, 0.5108, 0.50584, 0.49846, 0.48862, 0.4774, 0.4622, 0.44818, 0.43076, 0.41258, 0.3921,
-0.36856, 0.3492, 0.33036, 0.30942, 0.28958, 0.2695, 0.25126, 0.23376, 0.2201, 0.21062, 0.20204, 0.19736, 0.1938, 0.191, 0.18856, 0.18624, 0.18374, 0.18112,
-0.1808, 0.18088, 0.18352, 0.18564, 0.19186, 0.19768, 0.2005, 0.2048, 0.2096, 0.21416, 0.21796, 0.21724, 0.21602, 0.21372, 0.2093, 0.20812, 0.20986, 0.2142,
-0.22072, 0.23042, 0.23994, 0.24612, 0.25268, 0.25658, 0.25678, 0.25486, 0.25682, 
-//662
-0.10362, 0.121, 0.1441, 0.16476, 0.18298, 0.19168, 0.19732, 0.20016, 0.20368, 0.20556, 0.21046, 0.2163, 0.22144, 0.23002, 0.23972, 0.2506, 0.26704, 0.28514,
-0.30212, 0.3203, 0.33644, 0.35044, 0.3618, 0.3702, 0.37508, 0.37588, 0.3742, 0.3679, 0.36048, 0.35174, 0.34284, 0.3311, 0.31846, 0.3032, 0.28874, 0.27224,
-0.25514, 0.24096, 0.22444, 0.20936, 0.19388, 0.17902, 0.16414, 0.14994, 0.14092, 0.13272, 0.129, 0.1241, 0.12074, 0.11908, 0.1172, 0.11462, 0.11384, 0.11138,
-0.1115, 0.1116, 0.11326, 0.11374, 0.11862, 0.1229, 0.12618, 0.12716, 0.13064, 0.1341, 0.1347, 0.13506, 0.13408, 0.13192, 0.13024, 0.1298, 0.1309, 0.13414,
-0.1374, 0.14306, 0.14922, 0.1535, 0.157, 0.1601, 0.1589, 0.15826, 0.15834, 
-//663
-0.07656, 0.0834, 0.09466, 0.10612, 0.1102, 0.11476, 0.1196, 0.12174, 0.12556, 0.13012, 0.13392, 0.13888, 0.14584, 0.15322, 0.16304, 0.1744, 0.1884, 0.20442,
-0.2195, 0.23152, 0.24128, 0.24938, 0.25338, 0.25644, 0.2573, 0.25646, 0.25498, 0.252, 0.24772, 0.2406, 0.23364, 0.22462, 0.21532, 0.20374, 0.19232, 0.17982,
-0.16582, 0.15624, 0.14504, 0.13378, 0.1226, 0.11154, 0.10164, 0.09394, 0.08546, 0.08204, 0.07818, 0.07532, 0.0733, 0.0707, 0.07122, 0.06958, 0.06844, 0.06722,
-0.06648, 0.0657, 0.06654, 0.06602, 0.06752, 0.07002, 0.07132, 0.0721, 0.07486, 0.07714, 0.07902, 0.07996, 0.0811, 0.08192, 0.08048, 0.08102, 0.08316, 0.08508,
-0.0879, 0.0914, 0.09548, 0.09872, 0.10238, 0.10398, 0.10626, 0.1074, 0.10826, 
-//664
-0.05708, 0.063, 0.06768, 0.07174, 0.0733, 0.0757, 0.07714, 0.07858, 0.08058, 0.08216, 0.08372, 0.0858, 0.0894, 0.09256, 0.09826, 0.10382, 0.11362, 0.12768,
-0.14208, 0.15828, 0.17526, 0.18748, 0.19842, 0.20062, 0.20086, 0.19652, 0.19034, 0.18254, 0.1742, 0.16408, 0.15446, 0.14244, 0.13196, 0.1203, 0.10952, 0.09848,
-0.08676, 0.08144, 0.07582, 0.0701, 0.06536, 0.0596, 0.05596, 0.05354, 0.0512, 0.05036, 0.05022, 0.05024, 0.04908, 0.04914, 0.04922, 0.04986, 0.0509, 0.05128,
-0.05258, 0.05372, 0.05386, 0.05336, 0.05548, 0.05696, 0.05774, 0.058, 0.05812, 0.05964, 0.06032, 0.06084, 0.05952, 0.05952, 0.06112, 0.06144, 0.06396, 0.06558,
-0.0672, 0.06946, 0.07188, 0.0726, 0.0742, 0.07494, 0.07656, 0.07638, 0.07754, 
-//665
-0.04464, 0.04332, 0.0454, 0.04522, 0.04612, 0.04678, 0.04778, 0.04884, 0.0488, 0.0492, 0.05062, 0.0516, 0.05212, 0.05304, 0.05564, 0.05782, 0.06272, 0.06964,
-0.07784, 0.09078, 0.10774, 0.1258, 0.1403, 0.1476, 0.14582, 0.14004, 0.13004, 0.11648, 0.10546, 0.09264, 0.08154, 0.0713, 0.06358, 0.0564, 0.05168, 0.0481,
-0.04164, 0.0415, 0.03974, 0.03764, 0.0366, 0.03618, 0.0353, 0.03502, 0.0359, 0.03542, 0.0372, 0.03786, 0.03918, 0.039, 0.04018, 0.04066, 0.04008, 0.04154,
-0.04214, 0.04298, 0.04344, 0.04264, 0.04406, 0.04574, 0.0457, 0.04416, 0.04488, 0.0461, 0.04718, 0.04546, 0.04746, 0.04816, 0.0491, 0.05112, 0.05188, 0.05284,
-0.05386, 0.0562, 0.05774, 0.05844, 0.0595, 0.05954, 0.0596, 0.05946, 0.0598, 
-//666
-0.11812, 0.14036, 0.17326, 0.20932, 0.23884, 0.2553, 0.26422, 0.26886, 0.2732, 0.2772, 0.2859, 0.29216, 0.3001, 0.3087, 0.32122, 0.33738, 0.36192, 0.39208,
-0.4258, 0.46552, 0.50754, 0.54328, 0.57018, 0.58392, 0.58916, 0.58462, 0.57426, 0.55878, 0.54208, 0.52132, 0.50072, 0.4782, 0.45198, 0.42438, 0.39776, 0.37004,
-0.34302, 0.32158, 0.2994, 0.27598, 0.25476, 0.23298, 0.21378, 0.19648, 0.18378, 0.17344, 0.16646, 0.16146, 0.15796, 0.15514, 0.15374, 0.15178, 0.15004, 0.1488,
-0.14828, 0.14902, 0.15164, 0.15236, 0.15774, 0.1649, 0.16846, 0.17142, 0.1764, 0.1813, 0.18356, 0.18466, 0.18356, 0.18026, 0.17738, 0.17584, 0.17752, 0.18172,
-0.18922, 0.19724, 0.20692, 0.21306, 0.22082, 0.22404, 0.22406, 0.22298, 0.22484, 
-//667
-0.09174, 0.10546, 0.12528, 0.14314, 0.15662, 0.16606, 0.1715, 0.17566, 0.18108, 0.1849, 0.1916, 0.19852, 0.20556, 0.2141, 0.2272, 0.24056, 0.26272, 0.2873,
-0.31536, 0.34512, 0.37496, 0.39926, 0.415, 0.42238, 0.42446, 0.42016, 0.4137, 0.40344, 0.39178, 0.37814, 0.3618, 0.34304, 0.32262, 0.30106, 0.28048, 0.25832,
-0.23448, 0.21652, 0.19816, 0.17924, 0.16326, 0.14654, 0.13194, 0.11882, 0.11062, 0.10324, 0.0994, 0.09614, 0.09406, 0.09228, 0.09158, 0.08966, 0.08856, 0.08896,
-0.08842, 0.08888, 0.09116, 0.09268, 0.09716, 0.1015, 0.10534, 0.10764, 0.111, 0.11522, 0.11712, 0.1184, 0.11774, 0.11598, 0.11312, 0.11416, 0.11478, 0.1186,
-0.12496, 0.13026, 0.13834, 0.1427, 0.14724, 0.14982, 0.15094, 0.14964, 0.1491, 
-//668
-0.0845, 0.09048, 0.09868, 0.10562, 0.11208, 0.11408, 0.11724, 0.1192, 0.12184, 0.1242, 0.13028, 0.13464, 0.13918, 0.14706, 0.15564, 0.16758, 0.18658, 0.21028,
-0.23642, 0.2654, 0.29428, 0.31948, 0.33438, 0.34202, 0.3411, 0.33442, 0.325, 0.31162, 0.29712, 0.28006, 0.26206, 0.2428, 0.22464, 0.20454, 0.18652, 0.16766,
-0.1489, 0.13676, 0.12536, 0.1136, 0.10272, 0.09426, 0.08456, 0.07776, 0.0739, 0.0698, 0.06774, 0.06636, 0.06578, 0.06464, 0.06506, 0.06406, 0.06444, 0.06524,
-0.06566, 0.06618, 0.06746, 0.06714, 0.07, 0.07198, 0.0735, 0.0729, 0.07524, 0.0769, 0.07806, 0.07912, 0.0789, 0.07866, 0.07816, 0.07976, 0.08122, 0.083,
-0.08578, 0.08952, 0.09316, 0.09566, 0.09806, 0.09946, 0.10058, 0.09976, 0.09922, 
-//669
-0.0616, 0.06398, 0.06716, 0.0697, 0.07118, 0.07132, 0.07326, 0.07376, 0.07516, 0.07716, 0.0789, 0.07978, 0.08254, 0.08604, 0.09042, 0.0976, 0.11034, 0.12668,
-0.14738, 0.17074, 0.19662, 0.22016, 0.2361, 0.24268, 0.23964, 0.23082, 0.21704, 0.20134, 0.18626, 0.16958, 0.1533, 0.1375, 0.12266, 0.10816, 0.09652, 0.08646,
-0.07576, 0.07252, 0.06598, 0.06272, 0.05736, 0.05438, 0.05036, 0.04894, 0.04778, 0.04702, 0.047, 0.04732, 0.04806, 0.04812, 0.04896, 0.04816, 0.04848, 0.04974,
-0.0501, 0.05038, 0.05162, 0.05, 0.05178, 0.0527, 0.054, 0.05296, 0.0553, 0.0566, 0.05756, 0.05728, 0.05868, 0.05786, 0.05862, 0.05976, 0.06086, 0.06276,
-0.06416, 0.0649, 0.06692, 0.0682, 0.06922, 0.06898, 0.0706, 0.0703, 0.07064, 
-//670
-0.08856, 0.09958, 0.1169, 0.13288, 0.14434, 0.15086, 0.15708, 0.16068, 0.16506, 0.16894, 0.17688, 0.18346, 0.19008, 0.20152, 0.21338, 0.22954, 0.2549, 0.28524,
-0.3186, 0.35768, 0.39818, 0.43506, 0.46046, 0.47366, 0.47488, 0.46852, 0.45514, 0.43662, 0.41706, 0.3943, 0.372, 0.34618, 0.32154, 0.29464, 0.26994, 0.2452,
-0.2209, 0.19914, 0.17998, 0.16186, 0.14516, 0.13002, 0.1162, 0.10456, 0.09646, 0.09022, 0.08736, 0.08404, 0.08332, 0.08142, 0.0807, 0.08102, 0.07926, 0.0784,
-0.07894, 0.07936, 0.08066, 0.08168, 0.08578, 0.0902, 0.0936, 0.0958, 0.09968, 0.10386, 0.10536, 0.1057, 0.10522, 0.10312, 0.10208, 0.10182, 0.10328, 0.10636,
-0.11118, 0.11712, 0.12324, 0.1293, 0.13296, 0.13628, 0.13782, 0.13794, 0.13866, 
-//671
-0.12996, 0.15868, 0.21196, 0.29084, 0.39338, 0.50134, 0.58224, 0.62446, 0.64138, 0.64812, 0.65536, 0.65902, 0.66192, 0.66386, 0.66782, 0.67212, 0.67678,
-0.68238, 0.68802, 0.69216, 0.69392, 0.69564, 0.69778, 0.69932, 0.70048, 0.70102, 0.70068, 0.69922, 0.6976, 0.69468, 0.69412, 0.69162, 0.68952, 0.6864, 0.68312,
-0.6783, 0.6741, 0.67166, 0.66608, 0.65802, 0.64978, 0.64118, 0.63158, 0.62042, 0.6109, 0.60158, 0.5966, 0.5922, 0.58904, 0.5867, 0.58444, 0.58122, 0.57986,
-0.57922, 0.57916, 0.57968, 0.58342, 0.58834, 0.59224, 0.59684, 0.59908, 0.6035, 0.60462, 0.60768, 0.60974, 0.61058, 0.60846, 0.60484, 0.60442, 0.60424, 0.6046,
-0.60832, 0.6157, 0.6171, 0.6246, 0.62592, 0.62994, 0.63018, 0.63128, 0.62772, 0.63072, 
-//672
-0.13052, 0.15842, 0.20876, 0.28042, 0.36238, 0.43448, 0.47558, 0.49012, 0.49642, 0.49802, 0.49944, 0.50116, 0.50334, 0.5024, 0.50418, 0.50738, 0.51134, 0.51692,
-0.5236, 0.52846, 0.53212, 0.53232, 0.53456, 0.53506, 0.53548, 0.53546, 0.53548, 0.53436, 0.53282, 0.52976, 0.529, 0.52746, 0.52432, 0.52264, 0.52112, 0.51812,
-0.51276, 0.50994, 0.50562, 0.49908, 0.49394, 0.4871, 0.47946, 0.47074, 0.46356, 0.45618, 0.45174, 0.44874, 0.44438, 0.44212, 0.44128, 0.43856, 0.43694, 0.43592,
-0.43424, 0.43402, 0.43688, 0.43902, 0.4406, 0.44466, 0.44596, 0.44618, 0.44906, 0.4519, 0.4512, 0.45126, 0.45182, 0.44854, 0.44816, 0.44736, 0.44904, 0.45018,
-0.4537, 0.45646, 0.46004, 0.46216, 0.46336, 0.46408, 0.46354, 0.46354, 0.46444, 
-//673
-0.1263, 0.154, 0.19774, 0.25668, 0.31148, 0.3482, 0.36266, 0.36812, 0.36998, 0.36904, 0.36968, 0.37036, 0.3721, 0.37158, 0.37214, 0.37382, 0.37716, 0.38134,
-0.38566, 0.38906, 0.39154, 0.39202, 0.39352, 0.39346, 0.3936, 0.39284, 0.39234, 0.3899, 0.38928, 0.38798, 0.38618, 0.3847, 0.3827, 0.38074, 0.37914, 0.37604,
-0.37268, 0.37186, 0.36854, 0.36498, 0.35968, 0.35424, 0.35058, 0.34368, 0.33816, 0.33412, 0.33074, 0.3279, 0.32532, 0.32394, 0.3216, 0.32112, 0.31958, 0.31738,
-0.31754, 0.31686, 0.31748, 0.31762, 0.31924, 0.32056, 0.32152, 0.3223, 0.32202, 0.32492, 0.32506, 0.32536, 0.32492, 0.32436, 0.32276, 0.3239, 0.3239, 0.3241,
-0.32728, 0.32996, 0.33142, 0.3334, 0.33394, 0.33458, 0.33432, 0.33062, 0.33206, 
-//674
-0.12396, 0.1466, 0.18056, 0.21606, 0.2476, 0.26264, 0.26828, 0.26924, 0.26904, 0.26872, 0.26952, 0.26936, 0.26936, 0.27022, 0.27026, 0.27162, 0.27508, 0.2778,
-0.28108, 0.284, 0.28608, 0.28622, 0.28656, 0.28612, 0.28538, 0.28534, 0.28498, 0.28276, 0.28142, 0.27992, 0.2784, 0.27624, 0.27628, 0.2747, 0.27238, 0.27114,
-0.26772, 0.26804, 0.2668, 0.26342, 0.25916, 0.25594, 0.25226, 0.24756, 0.24464, 0.24086, 0.23878, 0.23674, 0.23542, 0.23376, 0.23322, 0.23152, 0.22968, 0.2291,
-0.22824, 0.2284, 0.22804, 0.2285, 0.22884, 0.23044, 0.231, 0.22942, 0.23056, 0.23156, 0.2322, 0.23186, 0.23222, 0.23138, 0.23046, 0.23016, 0.23098, 0.23142,
-0.23318, 0.23436, 0.2351, 0.2355, 0.23706, 0.23734, 0.2372, 0.23538, 0.23722, 
-//675
-0.10326, 0.11638, 0.13592, 0.1535, 0.16606, 0.17206, 0.17308, 0.1739, 0.17398, 0.17282, 0.17236, 0.17312, 0.17428, 0.1746, 0.17494, 0.17518, 0.17698, 0.1795,
-0.18146, 0.18412, 0.1853, 0.186, 0.18586, 0.18486, 0.18524, 0.18448, 0.18466, 0.18356, 0.18268, 0.18138, 0.17982, 0.17838, 0.17794, 0.17734, 0.17594, 0.17504,
-0.17384, 0.1743, 0.17202, 0.17116, 0.16844, 0.16644, 0.16336, 0.16164, 0.15896, 0.15512, 0.15362, 0.1533, 0.15202, 0.15106, 0.15032, 0.14962, 0.14952, 0.14936,
-0.1485, 0.14832, 0.14828, 0.14724, 0.14942, 0.15028, 0.15156, 0.1499, 0.15056, 0.15242, 0.15232, 0.15174, 0.15286, 0.1516, 0.15108, 0.15094, 0.1526, 0.15344,
-0.15504, 0.15548, 0.15594, 0.15628, 0.157, 0.15704, 0.15854, 0.15776, 0.15724, 
-//676
-0.08736, 0.09414, 0.1022, 0.1087, 0.11376, 0.11478, 0.11492, 0.11476, 0.11434, 0.1137, 0.11322, 0.11238, 0.11266, 0.11282, 0.1132, 0.11334, 0.11434, 0.11726,
-0.1181, 0.12024, 0.12118, 0.12176, 0.12182, 0.12044, 0.12142, 0.12014, 0.12012, 0.11892, 0.1184, 0.1176, 0.11598, 0.11514, 0.11408, 0.11286, 0.11266, 0.11124,
-0.10848, 0.11064, 0.10864, 0.10784, 0.10554, 0.102, 0.09996, 0.09804, 0.09772, 0.09554, 0.09468, 0.09338, 0.09306, 0.09252, 0.09256, 0.09154, 0.09112, 0.09086,
-0.09038, 0.09088, 0.09088, 0.0891, 0.09078, 0.09282, 0.09232, 0.0907, 0.09104, 0.09228, 0.0935, 0.09384, 0.09404, 0.0946, 0.09554, 0.09602, 0.09734, 0.09846,
-0.1004, 0.1013, 0.10198, 0.1025, 0.10328, 0.10334, 0.10218, 0.10362, 0.10218, 
-//677
-0.0599, 0.0608, 0.06372, 0.06386, 0.06482, 0.06422, 0.06358, 0.06354, 0.06446, 0.0625, 0.06342, 0.06464, 0.06548, 0.06628, 0.06606, 0.0653, 0.06654, 0.0682,
-0.0695, 0.07036, 0.072, 0.07196, 0.07136, 0.07114, 0.07028, 0.07026, 0.0698, 0.06814, 0.0672, 0.06786, 0.06504, 0.06572, 0.06504, 0.06462, 0.06388, 0.06306,
-0.06064, 0.06156, 0.0605, 0.06006, 0.0577, 0.05648, 0.05486, 0.05368, 0.054, 0.0534, 0.05254, 0.05332, 0.05212, 0.05312, 0.05286, 0.05294, 0.05346, 0.0537,
-0.05386, 0.0533, 0.05348, 0.05286, 0.05392, 0.05496, 0.05512, 0.05456, 0.05568, 0.05556, 0.05706, 0.05768, 0.05764, 0.05754, 0.05916, 0.05906, 0.06028, 0.06174,
-0.06252, 0.06502, 0.06514, 0.0662, 0.06752, 0.06776, 0.06712, 0.06706, 0.06896, 
-//678
-0.04646, 0.0463, 0.04726, 0.04786, 0.04822, 0.04952, 0.04828, 0.04868, 0.04872, 0.0488, 0.04912, 0.04868, 0.04806, 0.04842, 0.04964, 0.04958, 0.05152, 0.0527,
-0.05472, 0.05606, 0.05738, 0.05786, 0.05808, 0.05768, 0.05862, 0.0579, 0.05698, 0.05694, 0.0551, 0.05464, 0.05332, 0.05178, 0.05118, 0.05076, 0.0485, 0.0468,
-0.04414, 0.04538, 0.04536, 0.04462, 0.04324, 0.04244, 0.04278, 0.0413, 0.044, 0.0423, 0.04186, 0.04186, 0.04226, 0.0421, 0.0412, 0.04144, 0.04182, 0.04114,
-0.0425, 0.0426, 0.0422, 0.04236, 0.0431, 0.04518, 0.0451, 0.04498, 0.04512, 0.04694, 0.04746, 0.04806, 0.04902, 0.04958, 0.05024, 0.05116, 0.0532, 0.0534,
-0.05498, 0.05552, 0.05702, 0.0567, 0.0573, 0.05756, 0.0572, 0.0562, 0.0583, 
-//679
-0.14756, 0.17884, 0.23674, 0.3235, 0.43156, 0.54072, 0.61948, 0.65832, 0.67512, 0.68204, 0.68802, 0.69378, 0.6987, 0.70364, 0.70928, 0.71688, 0.72682, 0.73912,
-0.74958, 0.75996, 0.76908, 0.77258, 0.77622, 0.77902, 0.78114, 0.78116, 0.7802, 0.77914, 0.77714, 0.77352, 0.77002, 0.76542, 0.7606, 0.75238, 0.74578, 0.73524,
-0.72456, 0.71598, 0.70354, 0.69068, 0.6761, 0.65992, 0.64464, 0.62778, 0.61236, 0.60052, 0.59158, 0.58562, 0.5804, 0.57682, 0.57404, 0.5705, 0.56842, 0.56664,
-0.56592, 0.56774, 0.57316, 0.57692, 0.58386, 0.59206, 0.59812, 0.6032, 0.60742, 0.61244, 0.61354, 0.6138, 0.6128, 0.6081, 0.60454, 0.60682, 0.60744, 0.61286,
-0.62162, 0.62924, 0.63868, 0.64594, 0.65316, 0.6568, 0.65758, 0.65452, 0.65538, 
-//680
-0.14426, 0.17484, 0.23006, 0.30342, 0.38346, 0.4491, 0.48658, 0.49992, 0.50458, 0.50718, 0.51146, 0.51418, 0.51714, 0.52124, 0.52428, 0.52934, 0.53776, 0.54748,
-0.55808, 0.56792, 0.57434, 0.5777, 0.58074, 0.58124, 0.58256, 0.58298, 0.58172, 0.57968, 0.57756, 0.57418, 0.57292, 0.56956, 0.56508, 0.55944, 0.5563, 0.55016,
-0.5401, 0.53548, 0.52636, 0.51626, 0.5044, 0.49156, 0.47782, 0.46306, 0.44932, 0.43868, 0.4289, 0.42298, 0.41852, 0.41478, 0.41176, 0.40836, 0.40548, 0.40252,
-0.4023, 0.40282, 0.40682, 0.41006, 0.415, 0.41958, 0.42566, 0.42804, 0.4305, 0.43518, 0.43652, 0.43696, 0.43546, 0.43276, 0.4301, 0.42842, 0.43062, 0.43326,
-0.44114, 0.44744, 0.45298, 0.45868, 0.4648, 0.4639, 0.46656, 0.46116, 0.461, 
-//681
-0.12214, 0.14678, 0.19026, 0.24226, 0.29178, 0.32336, 0.3391, 0.3451, 0.3462, 0.34652, 0.34762, 0.35072, 0.35344, 0.35642, 0.3587, 0.36232, 0.36962, 0.3765,
-0.38458, 0.39068, 0.3966, 0.39952, 0.40078, 0.4026, 0.40362, 0.40306, 0.40174, 0.401, 0.4001, 0.39728, 0.39598, 0.39388, 0.3898, 0.38584, 0.38118, 0.37562,
-0.36794, 0.3632, 0.35726, 0.34934, 0.34138, 0.3316, 0.32086, 0.30992, 0.3017, 0.29244, 0.287, 0.2823, 0.27936, 0.27668, 0.27524, 0.27378, 0.27046, 0.26944,
-0.26846, 0.26766, 0.2695, 0.27176, 0.27514, 0.27888, 0.28144, 0.2838, 0.28792, 0.29154, 0.29272, 0.29276, 0.29168, 0.29094, 0.28852, 0.2875, 0.28764, 0.29054,
-0.29578, 0.29976, 0.30488, 0.30742, 0.31156, 0.31162, 0.3127, 0.30884, 0.31036, 
-//682
-0.10918, 0.12912, 0.15968, 0.19448, 0.22326, 0.23858, 0.2463, 0.24798, 0.24964, 0.2495, 0.2499, 0.25224, 0.25356, 0.25464, 0.2574, 0.2608, 0.26508, 0.2699,
-0.27612, 0.28122, 0.2858, 0.28694, 0.28754, 0.28872, 0.2889, 0.289, 0.28716, 0.28556, 0.28494, 0.28172, 0.27978, 0.27682, 0.27552, 0.27162, 0.26824, 0.26574,
-0.26146, 0.25734, 0.25198, 0.24666, 0.24082, 0.23342, 0.22578, 0.21768, 0.21054, 0.20452, 0.2003, 0.1963, 0.19396, 0.19194, 0.19048, 0.1886, 0.18694, 0.18504,
-0.18388, 0.18436, 0.1844, 0.18534, 0.18888, 0.1918, 0.19422, 0.19516, 0.19666, 0.19778, 0.1997, 0.19994, 0.2009, 0.19918, 0.19918, 0.19912, 0.19982, 0.20276,
-0.2068, 0.20954, 0.21218, 0.21376, 0.2164, 0.21684, 0.21782, 0.2146, 0.2153, 
-//683
-0.10596, 0.1194, 0.13898, 0.15418, 0.16524, 0.17006, 0.17126, 0.1729, 0.17278, 0.1728, 0.1733, 0.17466, 0.17586, 0.17546, 0.17756, 0.17878, 0.18264, 0.18638,
-0.19012, 0.19376, 0.19516, 0.19612, 0.19636, 0.1962, 0.19708, 0.1968, 0.19628, 0.19542, 0.19388, 0.19182, 0.19096, 0.1897, 0.1863, 0.18466, 0.1832, 0.17966,
-0.17616, 0.1751, 0.1724, 0.16778, 0.1638, 0.1578, 0.1523, 0.14604, 0.14118, 0.13622, 0.1337, 0.13062, 0.12792, 0.12668, 0.12546, 0.12456, 0.12268, 0.12256,
-0.12182, 0.12184, 0.1243, 0.12362, 0.12682, 0.12968, 0.13166, 0.13152, 0.13218, 0.13516, 0.13532, 0.13676, 0.13702, 0.13584, 0.1347, 0.1351, 0.13572, 0.13666,
-0.1403, 0.14218, 0.14602, 0.14828, 0.14812, 0.15084, 0.15094, 0.15158, 0.1517, 
-//684
-0.08038, 0.086, 0.0941, 0.09906, 0.10398, 0.1048, 0.10638, 0.10572, 0.10616, 0.10598, 0.10686, 0.10922, 0.10934, 0.11006, 0.11116, 0.11252, 0.1152, 0.11802,
-0.12092, 0.12424, 0.1256, 0.12566, 0.12576, 0.12618, 0.12458, 0.12492, 0.12448, 0.12302, 0.1217, 0.12072, 0.11956, 0.1182, 0.1169, 0.1152, 0.11382, 0.11142,
-0.10688, 0.10586, 0.10366, 0.10052, 0.0973, 0.09414, 0.091, 0.08804, 0.08402, 0.08194, 0.08046, 0.0793, 0.07884, 0.07774, 0.07794, 0.07772, 0.0772, 0.07688,
-0.07612, 0.07676, 0.07706, 0.07684, 0.07754, 0.07896, 0.08134, 0.08, 0.08196, 0.0838, 0.08534, 0.08592, 0.0855, 0.08562, 0.08562, 0.0873, 0.08832, 0.08994,
-0.09142, 0.093, 0.09496, 0.09644, 0.0983, 0.09804, 0.09858, 0.09854, 0.09876, 
-//685
-0.0557, 0.0589, 0.06076, 0.06242, 0.06232, 0.06338, 0.06292, 0.06358, 0.06328, 0.06256, 0.06382, 0.0638, 0.06366, 0.06386, 0.06554, 0.06582, 0.06864, 0.07176,
-0.07274, 0.07518, 0.0767, 0.0772, 0.07768, 0.07692, 0.07686, 0.07636, 0.07638, 0.07492, 0.07406, 0.07232, 0.0715, 0.06974, 0.06746, 0.06532, 0.0645, 0.06186,
-0.05784, 0.05936, 0.05718, 0.056, 0.05482, 0.05288, 0.05184, 0.04982, 0.04972, 0.0485, 0.0482, 0.04796, 0.048, 0.04738, 0.0475, 0.0472, 0.04742, 0.047, 0.04754,
-0.04736, 0.04806, 0.04764, 0.04856, 0.04998, 0.05056, 0.05014, 0.05194, 0.05352, 0.05338, 0.05414, 0.05424, 0.0541, 0.05444, 0.05486, 0.05678, 0.05762, 0.05892,
-0.0606, 0.0622, 0.06262, 0.06402, 0.06404, 0.06494, 0.06538, 0.06704, 
-//686
-0.04648, 0.04656, 0.04634, 0.04652, 0.04718, 0.04748, 0.0466, 0.04748, 0.0468, 0.04634, 0.04752, 0.04718, 0.04796, 0.04682, 0.04744, 0.04826, 0.05034, 0.0542,
-0.05656, 0.05822, 0.06366, 0.0668, 0.06868, 0.06978, 0.0724, 0.07166, 0.06796, 0.0646, 0.0618, 0.05944, 0.05622, 0.053, 0.05012, 0.04712, 0.04578, 0.04352,
-0.03952, 0.04126, 0.04112, 0.0407, 0.04102, 0.04018, 0.04002, 0.03978, 0.03986, 0.03954, 0.03998, 0.04006, 0.03962, 0.03966, 0.03912, 0.04, 0.03976, 0.04014,
-0.04066, 0.04104, 0.04236, 0.04104, 0.04234, 0.044, 0.0441, 0.04424, 0.04438, 0.04584, 0.04592, 0.0463, 0.04672, 0.047, 0.04808, 0.049, 0.05022, 0.0515, 0.0533,
-0.05468, 0.05618, 0.05638, 0.05774, 0.05778, 0.05824, 0.05846, 0.05942, 
-//687
-0.1313, 0.15826, 0.20568, 0.27266, 0.34498, 0.40178, 0.43366, 0.44716, 0.45452, 0.45864, 0.46522, 0.47186, 0.4785, 0.48754, 0.49606, 0.5087, 0.52544, 0.54494,
-0.56452, 0.58358, 0.59856, 0.61072, 0.61844, 0.62192, 0.62328, 0.62274, 0.6191, 0.61512, 0.60994, 0.60102, 0.5926, 0.58286, 0.57058, 0.55694, 0.5438, 0.5276,
-0.50958, 0.49336, 0.4747, 0.45598, 0.43598, 0.41498, 0.3946, 0.37514, 0.35818, 0.34564, 0.33712, 0.33004, 0.32578, 0.32188, 0.31888, 0.3159, 0.31298, 0.31164,
-0.30922, 0.31162, 0.3156, 0.31834, 0.32516, 0.33284, 0.33868, 0.34378, 0.35036, 0.3556, 0.35896, 0.36058, 0.35858, 0.3542, 0.34966, 0.34872, 0.3504, 0.35542,
-0.3657, 0.37468, 0.38482, 0.3906, 0.39764, 0.39968, 0.40272, 0.3991, 0.39994, 
-//688
-0.12042, 0.14618, 0.18538, 0.23404, 0.27812, 0.3079, 0.32262, 0.32704, 0.332, 0.33472, 0.34016, 0.34596, 0.35118, 0.35722, 0.36408, 0.3734, 0.38626, 0.40184,
-0.41616, 0.42886, 0.43978, 0.4463, 0.44936, 0.4517, 0.45428, 0.45302, 0.45, 0.4465, 0.44166, 0.4362, 0.43148, 0.4237, 0.41494, 0.40428, 0.39376, 0.38202,
-0.36664, 0.35484, 0.34096, 0.32604, 0.31092, 0.29388, 0.27862, 0.26266, 0.2508, 0.24104, 0.23464, 0.22838, 0.22574, 0.22214, 0.21986, 0.21832, 0.21612, 0.2153,
-0.21398, 0.21396, 0.2161, 0.22002, 0.22334, 0.22974, 0.2344, 0.23706, 0.24282, 0.2463, 0.25036, 0.25012, 0.24852, 0.2441, 0.24234, 0.24006, 0.24146, 0.24554,
-0.25368, 0.26036, 0.26834, 0.27452, 0.27854, 0.28192, 0.28244, 0.27866, 0.28102, 
-//689
-0.10716, 0.12536, 0.15352, 0.18304, 0.20634, 0.22, 0.2271, 0.23014, 0.23296, 0.2352, 0.23858, 0.24418, 0.24736, 0.25178, 0.25782, 0.2637, 0.27422, 0.28532,
-0.29544, 0.3034, 0.3099, 0.31412, 0.31746, 0.31812, 0.31824, 0.3171, 0.31596, 0.31224, 0.31004, 0.3074, 0.30388, 0.29792, 0.29112, 0.28416, 0.27698, 0.26778,
-0.25568, 0.24826, 0.23782, 0.22658, 0.21406, 0.20058, 0.18848, 0.17516, 0.16538, 0.15722, 0.15226, 0.1484, 0.14564, 0.14386, 0.1422, 0.14, 0.13916, 0.13854,
-0.13888, 0.13844, 0.14086, 0.14258, 0.14704, 0.1513, 0.15444, 0.15688, 0.15908, 0.16304, 0.16578, 0.16668, 0.16658, 0.16418, 0.16328, 0.16326, 0.16458, 0.16852,
-0.17324, 0.17824, 0.18464, 0.18842, 0.19058, 0.19338, 0.19194, 0.19146, 0.19342, 
-//690
-0.09942, 0.11138, 0.1287, 0.14474, 0.1542, 0.16032, 0.16322, 0.16494, 0.1662, 0.1679, 0.17032, 0.17302, 0.17628, 0.17946, 0.18468, 0.19016, 0.1977, 0.2051,
-0.21144, 0.21794, 0.2226, 0.22478, 0.226, 0.22616, 0.22658, 0.2262, 0.22514, 0.2214, 0.21994, 0.21636, 0.21352, 0.20944, 0.20404, 0.19724, 0.19138, 0.18306,
-0.17326, 0.16788, 0.1597, 0.1509, 0.14232, 0.1331, 0.12492, 0.1165, 0.11068, 0.10602, 0.1034, 0.10062, 0.098, 0.09648, 0.09554, 0.09444, 0.09262, 0.0925,
-0.09138, 0.09218, 0.09334, 0.09416, 0.09658, 0.09942, 0.10184, 0.1028, 0.10356, 0.1064, 0.10734, 0.10852, 0.10794, 0.10794, 0.10644, 0.10768, 0.10892, 0.11154,
-0.11544, 0.11912, 0.1228, 0.12758, 0.12926, 0.12958, 0.13064, 0.13004, 0.1313, 
-//691
-0.07762, 0.08196, 0.08808, 0.09316, 0.09658, 0.09866, 0.10058, 0.10126, 0.10276, 0.10398, 0.10736, 0.10912, 0.1124, 0.11452, 0.11842, 0.12366, 0.13046, 0.13646,
-0.14186, 0.14616, 0.14946, 0.15046, 0.15086, 0.15088, 0.15122, 0.14886, 0.14812, 0.14558, 0.1429, 0.13984, 0.13604, 0.13162, 0.1273, 0.12232, 0.11614, 0.1106,
-0.10312, 0.09936, 0.0937, 0.08794, 0.08204, 0.07666, 0.07218, 0.06744, 0.06424, 0.06262, 0.06072, 0.0609, 0.0606, 0.05982, 0.0596, 0.05976, 0.05958, 0.05976,
-0.06002, 0.0608, 0.06164, 0.06172, 0.06394, 0.06526, 0.06602, 0.06622, 0.06738, 0.06848, 0.06956, 0.06908, 0.06936, 0.0693, 0.07024, 0.07058, 0.07236, 0.07408,
-0.0764, 0.0788, 0.08146, 0.08402, 0.08608, 0.0862, 0.08744, 0.08682, 0.08652, 
-//692
-0.05262, 0.05416, 0.05528, 0.05394, 0.05462, 0.05508, 0.05656, 0.05656, 0.05698, 0.05798, 0.05934, 0.06056, 0.06134, 0.06336, 0.06534, 0.0685, 0.07462, 0.08224,
-0.09102, 0.09986, 0.11008, 0.11624, 0.12064, 0.12032, 0.1165, 0.11244, 0.1065, 0.09934, 0.09274, 0.08604, 0.07968, 0.07304, 0.06668, 0.0612, 0.0575, 0.05398,
-0.04826, 0.04716, 0.04638, 0.04444, 0.04332, 0.04332, 0.04186, 0.04174, 0.0406, 0.04026, 0.04052, 0.0416, 0.04252, 0.04254, 0.04346, 0.04252, 0.04324, 0.04316,
-0.04344, 0.0447, 0.04394, 0.04338, 0.0447, 0.04666, 0.04738, 0.0465, 0.04828, 0.04892, 0.05008, 0.05172, 0.05154, 0.05222, 0.05256, 0.05438, 0.05532, 0.05646,
-0.05802, 0.05936, 0.0603, 0.06088, 0.0615, 0.0617, 0.06198, 0.06146, 0.06224, 
-//693
-0.12078, 0.14264, 0.18102, 0.22586, 0.26744, 0.29348, 0.3075, 0.31504, 0.32174, 0.32576, 0.3328, 0.3411, 0.34934, 0.35766, 0.36992, 0.38462, 0.40528, 0.42724,
-0.45032, 0.47234, 0.49188, 0.50456, 0.51372, 0.51848, 0.51862, 0.51594, 0.51148, 0.5044, 0.49606, 0.48486, 0.4742, 0.45954, 0.44434, 0.42712, 0.40934, 0.38886,
-0.36698, 0.348, 0.32828, 0.30814, 0.2889, 0.26792, 0.24948, 0.23084, 0.21684, 0.2064, 0.19794, 0.19338, 0.19048, 0.1873, 0.18502, 0.18282, 0.18088, 0.17882,
-0.17864, 0.18026, 0.18278, 0.18456, 0.1916, 0.19812, 0.20314, 0.2077, 0.21408, 0.21854, 0.22134, 0.2218, 0.22054, 0.21616, 0.21274, 0.21162, 0.2133, 0.21636,
-0.22516, 0.23428, 0.24248, 0.25146, 0.25672, 0.25986, 0.26076, 0.2598, 0.26036, 
-//694
-0.09916, 0.11694, 0.14062, 0.16738, 0.18722, 0.19904, 0.20782, 0.21196, 0.21792, 0.22492, 0.23146, 0.23946, 0.2468, 0.2539, 0.265, 0.27734, 0.29418, 0.31222,
-0.32776, 0.34146, 0.35366, 0.36158, 0.36578, 0.36694, 0.36672, 0.36464, 0.36112, 0.35642, 0.35006, 0.34216, 0.3334, 0.32294, 0.31086, 0.29798, 0.28308, 0.2675,
-0.24992, 0.23468, 0.21868, 0.2021, 0.18658, 0.17038, 0.1554, 0.1421, 0.13136, 0.12332, 0.11834, 0.11484, 0.1114, 0.1096, 0.10838, 0.10662, 0.10598, 0.1054,
-0.10574, 0.10644, 0.1095, 0.1119, 0.11674, 0.12174, 0.1262, 0.12872, 0.13348, 0.13676, 0.13908, 0.13974, 0.13946, 0.13712, 0.1361, 0.13578, 0.1366, 0.1401,
-0.14662, 0.15202, 0.15934, 0.16504, 0.16912, 0.17092, 0.17234, 0.17144, 0.17136, 
-//695
-0.09162, 0.10082, 0.11242, 0.12386, 0.13268, 0.13742, 0.14198, 0.14496, 0.14812, 0.15158, 0.15668, 0.16136, 0.16554, 0.1727, 0.18034, 0.1904, 0.20436, 0.2196,
-0.23204, 0.2434, 0.25168, 0.25622, 0.2593, 0.25994, 0.25924, 0.25612, 0.25262, 0.24768, 0.24176, 0.23506, 0.22842, 0.21934, 0.2091, 0.19796, 0.18706, 0.17438,
-0.1596, 0.15122, 0.14022, 0.12834, 0.11822, 0.10888, 0.09924, 0.0914, 0.08542, 0.08182, 0.07882, 0.07732, 0.0767, 0.07512, 0.0746, 0.07396, 0.07314, 0.0732,
-0.07314, 0.0734, 0.0745, 0.07356, 0.07632, 0.07962, 0.0819, 0.08216, 0.08618, 0.0884, 0.08836, 0.08886, 0.0901, 0.08938, 0.08828, 0.08842, 0.09108, 0.0923,
-0.0967, 0.09914, 0.10314, 0.10726, 0.11004, 0.1125, 0.11296, 0.11276, 0.11374, 
-//696
-0.06496, 0.06792, 0.07244, 0.07502, 0.079, 0.07976, 0.08268, 0.08378, 0.08452, 0.0868, 0.08974, 0.09302, 0.09562, 0.10096, 0.1079, 0.11622, 0.13002, 0.14626,
-0.16302, 0.17988, 0.1962, 0.2088, 0.21526, 0.21582, 0.21284, 0.20584, 0.19698, 0.18614, 0.17564, 0.161, 0.14872, 0.13556, 0.12236, 0.11072, 0.09994, 0.08962,
-0.08066, 0.07476, 0.06948, 0.06498, 0.06034, 0.05708, 0.0544, 0.05192, 0.05072, 0.04974, 0.05056, 0.0505, 0.05104, 0.05168, 0.05186, 0.0514, 0.05274, 0.05312,
-0.05336, 0.05328, 0.05454, 0.05402, 0.05534, 0.05674, 0.05668, 0.05622, 0.05828, 0.05934, 0.05994, 0.06072, 0.0608, 0.06164, 0.0618, 0.06338, 0.0646, 0.0669,
-0.06934, 0.07042, 0.07274, 0.074, 0.07668, 0.07706, 0.07702, 0.07746, 0.0776, 
-//697
-0.13474, 0.1556, 0.19108, 0.2306, 0.26302, 0.28374, 0.29466, 0.3023, 0.30798, 0.31322, 0.32308, 0.3321, 0.34182, 0.35418, 0.36864, 0.38936, 0.41678, 0.45086,
-0.48198, 0.51534, 0.54506, 0.56718, 0.58128, 0.58784, 0.5858, 0.5788, 0.56882, 0.55368, 0.53718, 0.51796, 0.49762, 0.47478, 0.4499, 0.42316, 0.3971, 0.37148,
-0.34464, 0.3233, 0.30076, 0.27816, 0.25728, 0.23576, 0.21576, 0.19662, 0.18216, 0.17234, 0.16418, 0.15998, 0.15558, 0.15204, 0.15112, 0.14834, 0.14652, 0.14402,
-0.14388, 0.14474, 0.14756, 0.1501, 0.1555, 0.1612, 0.1659, 0.16836, 0.1734, 0.17796, 0.18118, 0.18186, 0.18038, 0.17718, 0.17554, 0.17376, 0.17498, 0.17942,
-0.18604, 0.19368, 0.20304, 0.2102, 0.21488, 0.2182, 0.22014, 0.21942, 0.21924, 
-//698
-0.10702, 0.12112, 0.14358, 0.16574, 0.1831, 0.19326, 0.20084, 0.20656, 0.21052, 0.21658, 0.22428, 0.23228, 0.2422, 0.25522, 0.27144, 0.291, 0.31904, 0.35172,
-0.38182, 0.41212, 0.43936, 0.45924, 0.4709, 0.47366, 0.46896, 0.45922, 0.44706, 0.4299, 0.41258, 0.3925, 0.3711, 0.34936, 0.32576, 0.30182, 0.27704, 0.25478,
-0.2322, 0.21402, 0.197, 0.1807, 0.1651, 0.15068, 0.13784, 0.1259, 0.1174, 0.11116, 0.10712, 0.1037, 0.10158, 0.09984, 0.0987, 0.09702, 0.09628, 0.09598,
-0.09616, 0.09694, 0.09804, 0.0986, 0.10334, 0.1066, 0.1087, 0.11078, 0.11258, 0.11654, 0.1174, 0.11822, 0.1174, 0.11594, 0.11552, 0.11594, 0.11632, 0.11924,
-0.1248, 0.13122, 0.13808, 0.14416, 0.14934, 0.1518, 0.15362, 0.15254, 0.15364, 
-//699
-0.09112, 0.09996, 0.11044, 0.11996, 0.1278, 0.13254, 0.13472, 0.13716, 0.14168, 0.145, 0.15072, 0.15654, 0.16394, 0.17358, 0.18442, 0.20024, 0.22422, 0.25052,
-0.27758, 0.30442, 0.32908, 0.34922, 0.35862, 0.36134, 0.35652, 0.34596, 0.33334, 0.3168, 0.2988, 0.27994, 0.25978, 0.23982, 0.21846, 0.19612, 0.178, 0.15992,
-0.14102, 0.12958, 0.11792, 0.10762, 0.0988, 0.09156, 0.08416, 0.0791, 0.07516, 0.07298, 0.07042, 0.07048, 0.06984, 0.06978, 0.06882, 0.06872, 0.06922, 0.0683,
-0.06922, 0.06916, 0.06922, 0.0693, 0.07118, 0.07268, 0.07438, 0.07476, 0.07718, 0.07896, 0.07824, 0.07958, 0.07914, 0.07862, 0.0795, 0.08072, 0.08252, 0.08576,
-0.08968, 0.09326, 0.09804, 0.10248, 0.10474, 0.10688, 0.10854, 0.10698, 0.10704, 
-//700
-0.06814, 0.07066, 0.0745, 0.07656, 0.0787, 0.0797, 0.08044, 0.08142, 0.08324, 0.08466, 0.08858, 0.09148, 0.09568, 0.10088, 0.10704, 0.11698, 0.13222, 0.15234,
-0.17346, 0.19556, 0.21994, 0.23832, 0.24574, 0.24656, 0.23936, 0.2271, 0.2112, 0.19428, 0.17662, 0.15858, 0.14224, 0.12664, 0.11312, 0.09984, 0.08886, 0.0793,
-0.06872, 0.06524, 0.06226, 0.0581, 0.05554, 0.05296, 0.05118, 0.05018, 0.04946, 0.04928, 0.0486, 0.04968, 0.04974, 0.04882, 0.04918, 0.04982, 0.04974, 0.05048,
-0.05086, 0.05058, 0.05118, 0.05062, 0.05232, 0.0542, 0.05468, 0.05432, 0.05642, 0.05766, 0.05822, 0.05722, 0.05872, 0.05958, 0.0603, 0.0614, 0.06244, 0.06406,
-0.0659, 0.0681, 0.06996, 0.07202, 0.07464, 0.0757, 0.07628, 0.07484, 0.0779, 
-//701
-0.13142, 0.16082, 0.21558, 0.2975, 0.40214, 0.5099, 0.59158, 0.6356, 0.6564, 0.66486, 0.67408, 0.681, 0.68836, 0.6958, 0.7025, 0.71142, 0.72302, 0.73352,
-0.74244, 0.74994, 0.75238, 0.75598, 0.75828, 0.76084, 0.7625, 0.76328, 0.76358, 0.7616, 0.75778, 0.75396, 0.74838, 0.74342, 0.73768, 0.7285, 0.7189, 0.7083,
-0.69496, 0.68284, 0.67054, 0.6553, 0.63908, 0.61996, 0.60238, 0.58232, 0.56742, 0.55438, 0.54468, 0.53702, 0.53166, 0.52932, 0.52652, 0.52456, 0.52156, 0.51936,
-0.52002, 0.52064, 0.52742, 0.53288, 0.54074, 0.54852, 0.55506, 0.56204, 0.5662, 0.57286, 0.57532, 0.57652, 0.5739, 0.5706, 0.56558, 0.56532, 0.56568, 0.57012,
-0.58304, 0.59044, 0.6009, 0.60762, 0.61298, 0.6186, 0.62154, 0.61866, 0.61528, 
-//702
-0.14312, 0.17262, 0.2265, 0.30002, 0.38598, 0.457, 0.50014, 0.51664, 0.523, 0.52584, 0.52986, 0.53342, 0.53576, 0.53878, 0.5423, 0.54524, 0.55214, 0.5593,
-0.56366, 0.56788, 0.57006, 0.57102, 0.57004, 0.57242, 0.57126, 0.57062, 0.56968, 0.56628, 0.5657, 0.56186, 0.56032, 0.55678, 0.5535, 0.549, 0.5425, 0.53606,
-0.5285, 0.52274, 0.51284, 0.50216, 0.48992, 0.47724, 0.46338, 0.4502, 0.43714, 0.42676, 0.41992, 0.413, 0.4081, 0.40562, 0.40286, 0.39996, 0.39766, 0.39524,
-0.3936, 0.39482, 0.39918, 0.4006, 0.40708, 0.41212, 0.41608, 0.41866, 0.42066, 0.42454, 0.4258, 0.42552, 0.42448, 0.41998, 0.41764, 0.4183, 0.4183, 0.42362,
-0.43074, 0.43582, 0.44264, 0.44982, 0.45406, 0.45382, 0.45482, 0.45268, 0.45302, 
-//703
-0.13268, 0.15748, 0.20268, 0.25898, 0.313, 0.3487, 0.36696, 0.37402, 0.37658, 0.37788, 0.38062, 0.38274, 0.38434, 0.38718, 0.38978, 0.39342, 0.39876, 0.40298,
-0.40814, 0.41204, 0.41542, 0.41538, 0.41576, 0.41524, 0.41544, 0.4144, 0.41188, 0.41114, 0.40846, 0.4053, 0.40392, 0.40082, 0.39752, 0.3942, 0.3904, 0.38622,
-0.3788, 0.37498, 0.3672, 0.35926, 0.35116, 0.34184, 0.3313, 0.31996, 0.31032, 0.30142, 0.29474, 0.29, 0.28648, 0.28358, 0.28172, 0.27758, 0.2757, 0.27364,
-0.27338, 0.27426, 0.27608, 0.27858, 0.28318, 0.28788, 0.29328, 0.29504, 0.2977, 0.30034, 0.302, 0.3025, 0.30126, 0.29894, 0.2964, 0.29688, 0.29852, 0.3018,
-0.3056, 0.30966, 0.31558, 0.31884, 0.322, 0.32034, 0.32184, 0.32146, 0.32208, 
-//704
-0.10942, 0.13024, 0.16386, 0.20152, 0.23248, 0.25064, 0.25894, 0.26168, 0.26382, 0.26512, 0.26574, 0.26858, 0.26996, 0.27004, 0.2723, 0.27432, 0.27786, 0.281,
-0.283, 0.28434, 0.2868, 0.28588, 0.28634, 0.28718, 0.28676, 0.28604, 0.28536, 0.2847, 0.28418, 0.28202, 0.28126, 0.27908, 0.27596, 0.27448, 0.27042, 0.26744,
-0.26214, 0.26182, 0.25902, 0.25278, 0.24472, 0.23792, 0.2306, 0.2214, 0.21438, 0.20836, 0.2045, 0.19938, 0.19814, 0.19574, 0.19462, 0.1931, 0.1923, 0.19186,
-0.19144, 0.19212, 0.19374, 0.19472, 0.19912, 0.20298, 0.2053, 0.20616, 0.20778, 0.21088, 0.21176, 0.21338, 0.21318, 0.21218, 0.2106, 0.21186, 0.21214, 0.21394,
-0.21644, 0.22024, 0.2249, 0.22566, 0.22728, 0.22798, 0.22948, 0.22798, 0.22898, 
-//705
-0.09692, 0.11252, 0.13328, 0.1522, 0.16666, 0.1723, 0.17638, 0.17838, 0.17984, 0.18038, 0.18154, 0.18324, 0.18584, 0.1864, 0.18852, 0.18928, 0.1924, 0.19482,
-0.1961, 0.19788, 0.19914, 0.19862, 0.19806, 0.19744, 0.19852, 0.19632, 0.1963, 0.19482, 0.19442, 0.19362, 0.19098, 0.19016, 0.18848, 0.1869, 0.18442, 0.18076,
-0.1759, 0.17442, 0.17064, 0.1666, 0.1609, 0.15498, 0.15384, 0.14488, 0.13906, 0.13336, 0.13084, 0.12748, 0.12834, 0.12336, 0.12168, 0.12142, 0.11962, 0.12124,
-0.11974, 0.1201, 0.12124, 0.12054, 0.12328, 0.12686, 0.1282, 0.1292, 0.13086, 0.1341, 0.13564, 0.13538, 0.13446, 0.1339, 0.13148, 0.13268, 0.13396, 0.13494,
-0.1389, 0.14088, 0.14542, 0.14666, 0.14768, 0.14848, 0.15016, 0.1501, 0.1506, 
-//706
-0.0807, 0.08688, 0.09454, 0.10232, 0.1079, 0.11062, 0.1096, 0.11144, 0.1115, 0.1108, 0.11212, 0.11306, 0.11372, 0.1152, 0.11556, 0.11812, 0.12004, 0.12254,
-0.12384, 0.12502, 0.12636, 0.12584, 0.1251, 0.125, 0.12486, 0.1243, 0.1242, 0.12268, 0.12062, 0.12102, 0.1198, 0.11758, 0.11566, 0.11318, 0.11166, 0.11044,
-0.1038, 0.10682, 0.1037, 0.09854, 0.09574, 0.09156, 0.08746, 0.08348, 0.08116, 0.07988, 0.07794, 0.07706, 0.07648, 0.07534, 0.07482, 0.07374, 0.07404, 0.07354,
-0.0741, 0.07444, 0.0741, 0.07302, 0.0753, 0.0773, 0.07816, 0.0778, 0.08156, 0.0842, 0.08408, 0.08414, 0.08498, 0.08464, 0.08558, 0.08518, 0.08586, 0.0874,
-0.08942, 0.091, 0.09278, 0.09452, 0.09524, 0.09626, 0.09872, 0.0969, 0.09732, 
-//707
-0.05856, 0.05914, 0.06274, 0.06346, 0.06402, 0.06444, 0.0657, 0.06554, 0.06706, 0.0664, 0.06622, 0.06714, 0.06804, 0.06776, 0.06926, 0.06996, 0.0717, 0.0736,
-0.07546, 0.07704, 0.07778, 0.07736, 0.07652, 0.07722, 0.07814, 0.07664, 0.07604, 0.07506, 0.07372, 0.07294, 0.07104, 0.0694, 0.06714, 0.06498, 0.06274, 0.06024,
-0.05572, 0.05854, 0.05662, 0.05374, 0.05248, 0.05072, 0.04868, 0.04758, 0.04672, 0.04576, 0.04508, 0.0447, 0.04584, 0.04494, 0.04562, 0.04598, 0.04774, 0.04822,
-0.04738, 0.04938, 0.04918, 0.04796, 0.04966, 0.05172, 0.05222, 0.05084, 0.05214, 0.05348, 0.054, 0.05358, 0.05402, 0.05448, 0.05568, 0.0568, 0.05758, 0.05976,
-0.05972, 0.06088, 0.06238, 0.06324, 0.06526, 0.06444, 0.06598, 0.06488, 0.06638, 
-//708
-0.04862, 0.0474, 0.04608, 0.04668, 0.04636, 0.0461, 0.04648, 0.04618, 0.04606, 0.04668, 0.04736, 0.04794, 0.04956, 0.05024, 0.05044, 0.05292, 0.05494, 0.05832,
-0.06144, 0.06522, 0.06856, 0.07278, 0.0745, 0.07284, 0.07106, 0.06916, 0.0659, 0.06112, 0.05758, 0.05222, 0.04906, 0.04696, 0.04448, 0.0432, 0.04212, 0.04032,
-0.03634, 0.0375, 0.03656, 0.03708, 0.03678, 0.03674, 0.03676, 0.03754, 0.03766, 0.03718, 0.03872, 0.03804, 0.03928, 0.04022, 0.04032, 0.0408, 0.04162, 0.04154,
-0.04216, 0.04242, 0.0423, 0.04116, 0.04286, 0.04466, 0.04548, 0.04456, 0.0451, 0.04586, 0.04752, 0.04728, 0.04846, 0.0491, 0.05018, 0.05166, 0.05266, 0.0547,
-0.05588, 0.05688, 0.05732, 0.05838, 0.059, 0.05804, 0.05944, 0.05884, 0.05896, 
-//709
-0.12224, 0.1493, 0.20066, 0.27158, 0.35184, 0.41984, 0.46238, 0.48164, 0.49172, 0.49704, 0.50556, 0.51272, 0.51998, 0.53006, 0.53962, 0.55266, 0.57072, 0.58438,
-0.59696, 0.60762, 0.61394, 0.62078, 0.62098, 0.62244, 0.62182, 0.62056, 0.61678, 0.61286, 0.607, 0.5996, 0.5923, 0.58194, 0.57, 0.55692, 0.54296, 0.5264,
-0.50948, 0.49452, 0.47712, 0.45936, 0.44022, 0.4202, 0.40074, 0.38038, 0.36444, 0.3519, 0.343, 0.3356, 0.33046, 0.32778, 0.32484, 0.32214, 0.3172, 0.31566,
-0.31524, 0.31626, 0.32012, 0.32436, 0.33206, 0.34024, 0.34578, 0.35006, 0.3556, 0.36202, 0.36548, 0.36582, 0.36336, 0.35996, 0.35668, 0.35594, 0.3567, 0.3624,
-0.36996, 0.3802, 0.39094, 0.40026, 0.40622, 0.41004, 0.4105, 0.40604, 0.40606, 
-//710
-0.11808, 0.14518, 0.18712, 0.23886, 0.2915, 0.3266, 0.34406, 0.35438, 0.35878, 0.36108, 0.36702, 0.37216, 0.37834, 0.38508, 0.39276, 0.40244, 0.41402, 0.42652,
-0.43568, 0.44364, 0.44974, 0.45206, 0.45312, 0.45414, 0.45414, 0.45206, 0.4504, 0.44614, 0.44156, 0.43584, 0.43016, 0.42306, 0.41384, 0.40452, 0.39376, 0.37986,
-0.36636, 0.35744, 0.34246, 0.32678, 0.30942, 0.29272, 0.27688, 0.26062, 0.25072, 0.2395, 0.23334, 0.22648, 0.22358, 0.22148, 0.21912, 0.2166, 0.21472, 0.21268,
-0.21232, 0.21248, 0.21548, 0.21744, 0.22234, 0.22878, 0.2326, 0.2345, 0.239, 0.24464, 0.24672, 0.24674, 0.2463, 0.2442, 0.24134, 0.2405, 0.24246, 0.24622,
-0.25354, 0.259, 0.26804, 0.27276, 0.27766, 0.2802, 0.28008, 0.27856, 0.28004, 
-//711
-0.11128, 0.12822, 0.1607, 0.19384, 0.22062, 0.23684, 0.2448, 0.24858, 0.25368, 0.25664, 0.2605, 0.2642, 0.26884, 0.2743, 0.28206, 0.28996, 0.30112, 0.3111,
-0.31856, 0.3243, 0.32892, 0.33142, 0.332, 0.33146, 0.33116, 0.32984, 0.32676, 0.32348, 0.31934, 0.31432, 0.30938, 0.30322, 0.29508, 0.28518, 0.27614, 0.26598,
-0.25432, 0.24334, 0.23188, 0.21996, 0.20806, 0.19462, 0.1833, 0.1722, 0.16248, 0.15502, 0.15136, 0.14722, 0.14494, 0.1418, 0.14138, 0.13908, 0.13756, 0.13582,
-0.13606, 0.13518, 0.13818, 0.13874, 0.1419, 0.14674, 0.1504, 0.15236, 0.15538, 0.15864, 0.16076, 0.16032, 0.1611, 0.15808, 0.15654, 0.15554, 0.15742, 0.1607,
-0.16552, 0.1713, 0.17732, 0.18168, 0.18446, 0.18654, 0.1864, 0.18458, 0.18636, 
-//712
-0.10322, 0.11686, 0.13484, 0.15164, 0.16368, 0.17114, 0.17456, 0.1768, 0.17812, 0.18042, 0.18378, 0.18612, 0.18998, 0.19282, 0.19742, 0.20124, 0.20858, 0.21662,
-0.22094, 0.22528, 0.22908, 0.2296, 0.2303, 0.23026, 0.22992, 0.22766, 0.22658, 0.2247, 0.22166, 0.2182, 0.21374, 0.20796, 0.20192, 0.19456, 0.18736, 0.17704,
-0.16688, 0.16332, 0.1543, 0.14536, 0.13796, 0.12942, 0.12256, 0.1151, 0.10878, 0.10424, 0.10088, 0.09872, 0.09708, 0.09548, 0.09342, 0.09264, 0.09254, 0.09148,
-0.09236, 0.09274, 0.0945, 0.09394, 0.09724, 0.10006, 0.10102, 0.1015, 0.10202, 0.10454, 0.1054, 0.10564, 0.10498, 0.10502, 0.10436, 0.1056, 0.1074, 0.11036,
-0.11504, 0.11884, 0.1234, 0.12742, 0.13024, 0.13326, 0.1339, 0.13432, 0.1372, 
-//713
-0.08016, 0.08576, 0.09342, 0.0991, 0.10536, 0.10796, 0.11082, 0.11136, 0.11298, 0.11364, 0.1155, 0.11848, 0.1204, 0.12258, 0.1273, 0.13074, 0.13712, 0.14354,
-0.14882, 0.15324, 0.15796, 0.1579, 0.1578, 0.1588, 0.15696, 0.15544, 0.1537, 0.15094, 0.1475, 0.14264, 0.13748, 0.13258, 0.12534, 0.11846, 0.11208, 0.1044,
-0.09674, 0.09212, 0.0863, 0.0826, 0.07604, 0.073, 0.06924, 0.0653, 0.06456, 0.06204, 0.0618, 0.06208, 0.06098, 0.06042, 0.05958, 0.0606, 0.06092, 0.061,
-0.06092, 0.0604, 0.06, 0.0597, 0.06162, 0.06324, 0.06408, 0.0633, 0.06474, 0.0681, 0.0677, 0.06848, 0.06924, 0.06954, 0.06996, 0.07172, 0.07292, 0.07466,
-0.07712, 0.08034, 0.08398, 0.0848, 0.08846, 0.08816, 0.09048, 0.08962, 0.09258, 
-//714
-0.06008, 0.05812, 0.057, 0.05766, 0.0584, 0.05822, 0.05886, 0.05764, 0.0579, 0.05882, 0.0608, 0.06298, 0.0634, 0.0659, 0.06904, 0.07286, 0.08104, 0.08898,
-0.09928, 0.1102, 0.11936, 0.12538, 0.1285, 0.12612, 0.12092, 0.11418, 0.10624, 0.09738, 0.08812, 0.07906, 0.07152, 0.06526, 0.06086, 0.05522, 0.05258, 0.04952,
-0.04316, 0.04508, 0.04384, 0.04308, 0.04226, 0.0416, 0.04074, 0.04138, 0.04192, 0.04208, 0.04306, 0.04354, 0.04372, 0.04384, 0.0454, 0.04522, 0.04584, 0.04698,
-0.04584, 0.04614, 0.04684, 0.04598, 0.04696, 0.0494, 0.0499, 0.04886, 0.04988, 0.05074, 0.05132, 0.05196, 0.0532, 0.05398, 0.05574, 0.05732, 0.0591, 0.06102,
-0.06184, 0.0635, 0.06564, 0.06628, 0.06754, 0.06804, 0.06966, 0.06724, 0.06766, 
-//715
-0.1148, 0.13952, 0.1764, 0.22586, 0.27134, 0.30338, 0.31956, 0.32966, 0.33598, 0.34238, 0.35048, 0.3595, 0.36676, 0.37974, 0.39314, 0.40976, 0.43038, 0.45176,
-0.47036, 0.48464, 0.49636, 0.50384, 0.50776, 0.50862, 0.50678, 0.50268, 0.4957, 0.48842, 0.4789, 0.46638, 0.45244, 0.43736, 0.42046, 0.39988, 0.38096, 0.35966,
-0.33592, 0.32036, 0.30092, 0.28214, 0.26474, 0.2485, 0.23198, 0.21566, 0.2041, 0.19456, 0.18782, 0.183, 0.17948, 0.17616, 0.17506, 0.17262, 0.17156, 0.17052,
-0.17032, 0.17142, 0.17332, 0.176, 0.18104, 0.1861, 0.1891, 0.1907, 0.19268, 0.19488, 0.19744, 0.19662, 0.19722, 0.19488, 0.19436, 0.19598, 0.19886, 0.20502,
-0.2135, 0.22238, 0.23432, 0.24304, 0.2472, 0.2474, 0.25142, 0.24842, 0.24868, 
-//716
-0.106, 0.12098, 0.14698, 0.1778, 0.20084, 0.21712, 0.22642, 0.23188, 0.23718, 0.2423, 0.24944, 0.25524, 0.26294, 0.27244, 0.28514, 0.29848, 0.31752, 0.33676,
-0.35128, 0.36324, 0.37342, 0.37808, 0.38152, 0.38132, 0.3801, 0.37588, 0.369, 0.36214, 0.35314, 0.34298, 0.33034, 0.31706, 0.302, 0.28604, 0.26996, 0.2531,
-0.23334, 0.2186, 0.20232, 0.18908, 0.17648, 0.16322, 0.1507, 0.14102, 0.13232, 0.12628, 0.1209, 0.119, 0.11656, 0.11508, 0.1144, 0.11192, 0.11164, 0.11064,
-0.11038, 0.11094, 0.11348, 0.11428, 0.11746, 0.12254, 0.12518, 0.12602, 0.12864, 0.13006, 0.13194, 0.1304, 0.13136, 0.13042, 0.12978, 0.13108, 0.1348, 0.13926,
-0.14544, 0.1536, 0.1617, 0.1683, 0.17478, 0.17672, 0.1787, 0.17842, 0.17966, 
-//717
-0.09988, 0.111, 0.12732, 0.14446, 0.1543, 0.16128, 0.16588, 0.1689, 0.17244, 0.17622, 0.18178, 0.1874, 0.19442, 0.2023, 0.2101, 0.22024, 0.23432, 0.24834,
-0.26108, 0.27132, 0.27974, 0.28358, 0.2851, 0.28548, 0.28312, 0.27902, 0.27376, 0.26574, 0.2567, 0.24686, 0.236, 0.22376, 0.20898, 0.19466, 0.18022, 0.16408,
-0.1466, 0.1374, 0.12814, 0.11862, 0.1117, 0.10396, 0.09816, 0.0925, 0.08838, 0.08508, 0.08252, 0.081, 0.08008, 0.0808, 0.07922, 0.0771, 0.07782, 0.0775,
-0.07784, 0.07746, 0.07846, 0.07766, 0.07972, 0.08334, 0.08388, 0.08368, 0.0857, 0.08662, 0.08708, 0.0872, 0.08686, 0.08732, 0.0888, 0.08912, 0.09246, 0.09616,
-0.10022, 0.1064, 0.11332, 0.1188, 0.12354, 0.12738, 0.12794, 0.12868, 0.13084, 
-//718
-0.068, 0.07076, 0.07756, 0.0811, 0.08576, 0.0879, 0.08954, 0.09066, 0.09256, 0.09534, 0.09818, 0.10126, 0.1065, 0.11238, 0.1201, 0.13092, 0.14536, 0.1622,
-0.17652, 0.18986, 0.20112, 0.20926, 0.2115, 0.20996, 0.207, 0.19962, 0.18974, 0.179, 0.16848, 0.15502, 0.14164, 0.12932, 0.11632, 0.10546, 0.09522, 0.08424,
-0.07388, 0.06978, 0.06462, 0.06142, 0.0581, 0.0539, 0.05282, 0.05096, 0.0496, 0.0473, 0.0482, 0.04802, 0.0472, 0.04672, 0.04596, 0.04706, 0.04644, 0.04708,
-0.04696, 0.04734, 0.04874, 0.04708, 0.05044, 0.05166, 0.05306, 0.052, 0.0509, 0.05364, 0.05318, 0.0549, 0.05544, 0.05704, 0.05832, 0.0606, 0.06172, 0.06482,
-0.06836, 0.07038, 0.07434, 0.07768, 0.08066, 0.08148, 0.08182, 0.08366, 0.08398, 
-//719
-0.12656, 0.15186, 0.19182, 0.23882, 0.28184, 0.31252, 0.32936, 0.3393, 0.34478, 0.3525, 0.36036, 0.37024, 0.38178, 0.3941, 0.40608, 0.41914, 0.44816, 0.4794,
-0.50788, 0.53562, 0.55862, 0.57448, 0.5807, 0.58142, 0.57796, 0.56608, 0.553, 0.53446, 0.51402, 0.4923, 0.46938, 0.44472, 0.4176, 0.3906, 0.36282, 0.334,
-0.3082, 0.2864, 0.2661, 0.24836, 0.23132, 0.21614, 0.20252, 0.18888, 0.17876, 0.17038, 0.16574, 0.16112, 0.15804, 0.15636, 0.15388, 0.1524, 0.15094, 0.14954,
-0.15024, 0.1502, 0.15334, 0.15364, 0.15888, 0.162, 0.16398, 0.16476, 0.16644, 0.16884, 0.16862, 0.16796, 0.1671, 0.16752, 0.16822, 0.16974, 0.17378, 0.18082,
-0.19026, 0.20026, 0.21308, 0.2225, 0.2316, 0.23518, 0.23816, 0.23872, 0.23948, 
-//720
-0.10702, 0.12278, 0.15106, 0.17758, 0.1994, 0.21332, 0.22208, 0.2273, 0.23348, 0.23978, 0.24824, 0.25798, 0.26834, 0.28246, 0.29866, 0.31872, 0.34784, 0.37946,
-0.40974, 0.4359, 0.45996, 0.4783, 0.4843, 0.48574, 0.48042, 0.46872, 0.45314, 0.43332, 0.41284, 0.3891, 0.36628, 0.34078, 0.31494, 0.28954, 0.264, 0.23992,
-0.21562, 0.19732, 0.18074, 0.16536, 0.15198, 0.14038, 0.12956, 0.12018, 0.11264, 0.10764, 0.10412, 0.10084, 0.09946, 0.09746, 0.0979, 0.09602, 0.0955, 0.09532,
-0.09496, 0.0955, 0.09726, 0.0973, 0.09968, 0.10328, 0.10446, 0.10542, 0.1059, 0.10808, 0.10914, 0.1094, 0.10978, 0.11074, 0.11038, 0.11288, 0.11606, 0.12084,
-0.12934, 0.13798, 0.1465, 0.15452, 0.15882, 0.164, 0.16708, 0.1659, 0.16636, 
-//721
-0.08486, 0.09378, 0.10856, 0.12186, 0.13162, 0.13752, 0.14304, 0.14588, 0.1505, 0.15556, 0.16164, 0.17014, 0.17712, 0.18902, 0.20166, 0.21866, 0.24608, 0.27554,
-0.30348, 0.33192, 0.35734, 0.37594, 0.38406, 0.38216, 0.37364, 0.3589, 0.33996, 0.31792, 0.2951, 0.2711, 0.24766, 0.22446, 0.20182, 0.18004, 0.15972, 0.14,
-0.12274, 0.1116, 0.1009, 0.09276, 0.08616, 0.07996, 0.07476, 0.06996, 0.06804, 0.0646, 0.06438, 0.06258, 0.06282, 0.06278, 0.0621, 0.06222, 0.06194, 0.0618,
-0.06212, 0.06246, 0.06346, 0.06238, 0.06486, 0.06626, 0.06672, 0.06602, 0.0668, 0.06782, 0.06804, 0.06902, 0.06882, 0.07052, 0.07168, 0.07318, 0.07582, 0.07994,
-0.08564, 0.09088, 0.09788, 0.10364, 0.10738, 0.1102, 0.1132, 0.1136, 0.11322, 
-//722
-0.0638, 0.06674, 0.07288, 0.07738, 0.08088, 0.08386, 0.08644, 0.08746, 0.0907, 0.09424, 0.09714, 0.1026, 0.10712, 0.11472, 0.12474, 0.1387, 0.15882, 0.18268,
-0.20704, 0.23048, 0.25146, 0.26602, 0.272, 0.26818, 0.25772, 0.24266, 0.22456, 0.20486, 0.1843, 0.16428, 0.14482, 0.12762, 0.112, 0.09762, 0.08574, 0.07572,
-0.06626, 0.0612, 0.05608, 0.05392, 0.04986, 0.04804, 0.04688, 0.04492, 0.04422, 0.04356, 0.04412, 0.04396, 0.04432, 0.04468, 0.04534, 0.04468, 0.04456, 0.04582,
-0.04546, 0.04548, 0.04604, 0.04454, 0.04662, 0.0481, 0.04826, 0.04564, 0.04736, 0.04908, 0.05032, 0.05106, 0.0518, 0.05346, 0.05436, 0.05714, 0.05808, 0.06082,
-0.06334, 0.06692, 0.06986, 0.07286, 0.07604, 0.07726, 0.07928, 0.07872, 0.07912, 
-//723
-0.14428, 0.17708, 0.23484, 0.3208, 0.43084, 0.54386, 0.63134, 0.67688, 0.6956, 0.70372, 0.70898, 0.71126, 0.715, 0.71698, 0.71916, 0.72232, 0.72742, 0.73024,
-0.73224, 0.7338, 0.73364, 0.7321, 0.7329, 0.73358, 0.7343, 0.7387, 0.73428, 0.7316, 0.73, 0.72718, 0.72568, 0.72228, 0.71848, 0.71612, 0.71176, 0.70584,
-0.69968, 0.69648, 0.68862, 0.68274, 0.67834, 0.67132, 0.66292, 0.65284, 0.6461, 0.63912, 0.63244, 0.6281, 0.6249, 0.62238, 0.62132, 0.6196, 0.61744, 0.61746,
-0.61706, 0.61634, 0.61856, 0.6214, 0.6241, 0.62808, 0.6282, 0.631, 0.6338, 0.63542, 0.63688, 0.63866, 0.63908, 0.6361, 0.6347, 0.6373, 0.63942, 0.6415, 0.65034,
-0.65258, 0.65692, 0.66338, 0.6682, 0.66724, 0.66774, 0.66664, 0.66456, 
-//724
-0.12704, 0.1583, 0.21064, 0.28422, 0.37058, 0.44636, 0.48932, 0.50756, 0.5142, 0.51618, 0.51852, 0.52004, 0.51936, 0.51878, 0.51998, 0.52, 0.52336, 0.5269,
-0.52782, 0.52986, 0.53064, 0.52864, 0.5288, 0.52908, 0.52934, 0.52846, 0.5297, 0.5276, 0.5264, 0.52486, 0.52128, 0.5227, 0.51682, 0.51656, 0.51408, 0.51006,
-0.50602, 0.5064, 0.50086, 0.49828, 0.49234, 0.48574, 0.4804, 0.473, 0.46668, 0.46064, 0.45666, 0.45238, 0.45156, 0.44884, 0.44608, 0.44526, 0.44318, 0.43992,
-0.44132, 0.44112, 0.44336, 0.4439, 0.44608, 0.44886, 0.44878, 0.44852, 0.44892, 0.45078, 0.45108, 0.45298, 0.45332, 0.45086, 0.4499, 0.45006, 0.4502, 0.4517,
-0.45484, 0.45688, 0.45802, 0.45994, 0.46116, 0.46076, 0.463, 0.46106, 0.45842, 
-//725
-0.13308, 0.15992, 0.2074, 0.2668, 0.3227, 0.36082, 0.37728, 0.38358, 0.3852, 0.38476, 0.38486, 0.38678, 0.38644, 0.38572, 0.38626, 0.38584, 0.38914, 0.39204,
-0.39448, 0.39594, 0.39746, 0.39692, 0.39624, 0.39656, 0.39698, 0.3966, 0.3961, 0.39428, 0.39292, 0.39174, 0.3912, 0.38872, 0.38616, 0.38434, 0.3832, 0.37964,
-0.37458, 0.37592, 0.37378, 0.37002, 0.36642, 0.3625, 0.35808, 0.35348, 0.34904, 0.3432, 0.3408, 0.33766, 0.33512, 0.33252, 0.33066, 0.32882, 0.32596, 0.3259,
-0.32478, 0.3228, 0.32344, 0.32408, 0.32572, 0.32742, 0.32972, 0.32814, 0.3274, 0.3294, 0.33014, 0.32986, 0.3283, 0.32754, 0.32664, 0.32582, 0.32714, 0.32794,
-0.33092, 0.3316, 0.33112, 0.333, 0.33454, 0.33222, 0.33472, 0.33176, 0.33148, 
-//726
-0.12104, 0.14314, 0.17778, 0.2133, 0.2431, 0.26068, 0.26834, 0.26968, 0.27142, 0.27018, 0.2713, 0.27178, 0.27264, 0.27262, 0.27262, 0.27382, 0.27398, 0.27682,
-0.27708, 0.2775, 0.27862, 0.2773, 0.27772, 0.27702, 0.2762, 0.27548, 0.27598, 0.27502, 0.27414, 0.27304, 0.27134, 0.26956, 0.26784, 0.26626, 0.26584, 0.26332,
-0.25888, 0.26062, 0.25888, 0.25524, 0.25228, 0.2482, 0.24524, 0.2398, 0.23554, 0.23266, 0.22914, 0.2261, 0.22334, 0.22228, 0.2212, 0.21954, 0.21854, 0.21712,
-0.21568, 0.21646, 0.21672, 0.21666, 0.2178, 0.2201, 0.22144, 0.21994, 0.21946, 0.22172, 0.22192, 0.22198, 0.22212, 0.21988, 0.22148, 0.2208, 0.22274, 0.22374,
-0.22524, 0.2263, 0.22856, 0.23066, 0.23002, 0.23112, 0.23098, 0.23026, 0.23048, 
-//727
-0.0993, 0.11502, 0.13602, 0.15588, 0.17224, 0.17976, 0.182, 0.18312, 0.18238, 0.18126, 0.1815, 0.1822, 0.18278, 0.18226, 0.18206, 0.18136, 0.18226, 0.1835,
-0.1834, 0.1846, 0.1839, 0.18304, 0.18328, 0.18242, 0.18374, 0.18286, 0.18264, 0.18082, 0.18114, 0.17998, 0.17854, 0.17758, 0.17696, 0.17452, 0.17406, 0.17328,
-0.16798, 0.17102, 0.16958, 0.1679, 0.16494, 0.16142, 0.15992, 0.15644, 0.15552, 0.15262, 0.15136, 0.15088, 0.14878, 0.14844, 0.1486, 0.14632, 0.14492, 0.14596,
-0.1451, 0.14514, 0.14508, 0.1438, 0.14502, 0.14668, 0.14652, 0.14464, 0.14598, 0.14884, 0.1481, 0.1487, 0.14764, 0.14754, 0.14686, 0.1485, 0.14928, 0.15016,
-0.1512, 0.1518, 0.15234, 0.15376, 0.15526, 0.1548, 0.15484, 0.15492, 0.15446, 
-//728
-0.08344, 0.09274, 0.10334, 0.1114, 0.11726, 0.11974, 0.1184, 0.11812, 0.1175, 0.11782, 0.118, 0.11798, 0.1189, 0.11854, 0.11952, 0.11894, 0.11974, 0.12044,
-0.11968, 0.12142, 0.12204, 0.1198, 0.12048, 0.11932, 0.11866, 0.11854, 0.11826, 0.11656, 0.11552, 0.11566, 0.11536, 0.1146, 0.11414, 0.11268, 0.11288, 0.11242,
-0.10798, 0.11034, 0.10954, 0.10826, 0.10626, 0.1035, 0.10296, 0.1004, 0.09838, 0.09664, 0.0942, 0.09366, 0.09264, 0.09248, 0.09152, 0.09006, 0.08914, 0.08916,
-0.08978, 0.08866, 0.08976, 0.08882, 0.08966, 0.09218, 0.09304, 0.09172, 0.0923, 0.09404, 0.09464, 0.09508, 0.0959, 0.09568, 0.09478, 0.0957, 0.09718, 0.09798,
-0.09904, 0.1001, 0.10092, 0.10136, 0.10312, 0.1034, 0.1039, 0.10374, 0.1041, 
-//729
-0.0616, 0.06294, 0.06618, 0.06782, 0.06904, 0.06756, 0.06886, 0.06802, 0.06654, 0.06722, 0.06686, 0.0661, 0.06688, 0.06648, 0.06778, 0.06678, 0.06786, 0.06884,
-0.07026, 0.06956, 0.06974, 0.06962, 0.06728, 0.06834, 0.0687, 0.06816, 0.0671, 0.06652, 0.06606, 0.06516, 0.06568, 0.06478, 0.06286, 0.06212, 0.06216, 0.06144,
-0.05984, 0.06142, 0.06028, 0.05908, 0.0582, 0.05678, 0.0559, 0.0545, 0.05302, 0.05292, 0.05316, 0.05196, 0.05256, 0.0519, 0.05242, 0.05272, 0.05202, 0.05218,
-0.0533, 0.05312, 0.05292, 0.05068, 0.05278, 0.05456, 0.05452, 0.05378, 0.05368, 0.0561, 0.05654, 0.0575, 0.05842, 0.0583, 0.05852, 0.05936, 0.06072, 0.06114,
-0.06286, 0.0631, 0.06312, 0.06384, 0.06472, 0.06454, 0.06426, 0.0651, 0.06598, 
-//730
-0.05314, 0.0529, 0.0522, 0.0516, 0.05176, 0.05136, 0.05218, 0.05076, 0.05194, 0.05096, 0.0508, 0.05126, 0.05184, 0.05222, 0.05282, 0.05328, 0.05452, 0.05594,
-0.05654, 0.05726, 0.05822, 0.059, 0.05744, 0.05676, 0.05598, 0.05526, 0.05512, 0.0536, 0.05346, 0.0526, 0.05088, 0.05084, 0.0487, 0.04798, 0.04824, 0.04846,
-0.04452, 0.04638, 0.04626, 0.04564, 0.04584, 0.0449, 0.04514, 0.04488, 0.04362, 0.04418, 0.04378, 0.04438, 0.04376, 0.04394, 0.04416, 0.04366, 0.04462, 0.04478,
-0.04484, 0.0452, 0.04428, 0.04364, 0.04584, 0.04798, 0.04896, 0.04772, 0.04882, 0.05066, 0.05, 0.04952, 0.05144, 0.0512, 0.05118, 0.05208, 0.05408, 0.05516,
-0.05536, 0.05688, 0.05756, 0.0592, 0.05966, 0.06024, 0.061, 0.06028, 0.06242, 
-//731
-0.13076, 0.16168, 0.2192, 0.3073, 0.41898, 0.53112, 0.61342, 0.65776, 0.67834, 0.68568, 0.69446, 0.70116, 0.70554, 0.71266, 0.7198, 0.72826, 0.73656, 0.74562,
-0.7521, 0.75672, 0.76038, 0.7609, 0.76178, 0.76406, 0.76474, 0.76458, 0.76312, 0.76168, 0.75814, 0.75434, 0.74794, 0.73996, 0.73172, 0.7211, 0.71132, 0.69814,
-0.68362, 0.67432, 0.66218, 0.65148, 0.64026, 0.62776, 0.6143, 0.60084, 0.58776, 0.57706, 0.57166, 0.56754, 0.5649, 0.56542, 0.56576, 0.56586, 0.56548, 0.56666,
-0.5684, 0.5706, 0.57584, 0.58128, 0.5862, 0.59264, 0.59616, 0.59828, 0.60234, 0.6067, 0.60982, 0.60992, 0.6092, 0.60904, 0.60916, 0.61338, 0.61872, 0.62428,
-0.63748, 0.6472, 0.6574, 0.66634, 0.67274, 0.67776, 0.68258, 0.68184, 0.685, 
-//732
-0.1237, 0.15054, 0.2012, 0.27136, 0.3563, 0.4328, 0.48098, 0.50524, 0.51024, 0.51392, 0.51804, 0.52022, 0.52516, 0.5266, 0.53012, 0.53452, 0.53882, 0.5442,
-0.54862, 0.55152, 0.55198, 0.55148, 0.55194, 0.55162, 0.55274, 0.55162, 0.55026, 0.54862, 0.54574, 0.54346, 0.54002, 0.537, 0.53174, 0.52636, 0.52208, 0.51502,
-0.50654, 0.50242, 0.49416, 0.48226, 0.47332, 0.46124, 0.44876, 0.43542, 0.42444, 0.4157, 0.40828, 0.40336, 0.39896, 0.39736, 0.39474, 0.39164, 0.38988, 0.38874,
-0.38832, 0.38766, 0.39106, 0.3922, 0.39792, 0.40278, 0.40568, 0.40928, 0.41174, 0.41664, 0.41872, 0.4199, 0.4195, 0.41546, 0.41392, 0.41498, 0.41702, 0.41798,
-0.42406, 0.42842, 0.43526, 0.44094, 0.44212, 0.4418, 0.44292, 0.4421, 0.44238, 
-//733
-0.13274, 0.15956, 0.20372, 0.26122, 0.31932, 0.35782, 0.376, 0.385, 0.38696, 0.3869, 0.38886, 0.39172, 0.39398, 0.39756, 0.39912, 0.40134, 0.40604, 0.41134,
-0.41586, 0.41818, 0.41924, 0.41916, 0.41862, 0.41844, 0.4182, 0.4153, 0.41402, 0.41228, 0.4079, 0.40488, 0.40132, 0.39684, 0.39278, 0.38756, 0.38418, 0.37868,
-0.37128, 0.36868, 0.36224, 0.35498, 0.34614, 0.33506, 0.3256, 0.3146, 0.30548, 0.29838, 0.2919, 0.28632, 0.28136, 0.27988, 0.27786, 0.275, 0.27424, 0.2729,
-0.2709, 0.27182, 0.27378, 0.27492, 0.27896, 0.28314, 0.28648, 0.2874, 0.28916, 0.29218, 0.29352, 0.29348, 0.29292, 0.29062, 0.28932, 0.28816, 0.2905, 0.29056,
-0.29692, 0.30146, 0.30474, 0.30832, 0.30998, 0.31384, 0.31432, 0.31182, 0.312, 
-//734
-0.1098, 0.1298, 0.1655, 0.20052, 0.23436, 0.25372, 0.26304, 0.26662, 0.2675, 0.26872, 0.2697, 0.27046, 0.27236, 0.27366, 0.27614, 0.27792, 0.28062, 0.28344,
-0.28592, 0.28698, 0.28768, 0.28688, 0.28534, 0.28646, 0.28626, 0.28614, 0.28584, 0.28488, 0.28232, 0.28216, 0.27866, 0.27654, 0.2745, 0.27122, 0.26816, 0.26358,
-0.2582, 0.25574, 0.25106, 0.24536, 0.23894, 0.23094, 0.2233, 0.21436, 0.20672, 0.2013, 0.1967, 0.19234, 0.1907, 0.18848, 0.187, 0.18572, 0.18386, 0.18402,
-0.18206, 0.18204, 0.18312, 0.18334, 0.18674, 0.1894, 0.19136, 0.19276, 0.19346, 0.19666, 0.19792, 0.19814, 0.19728, 0.1963, 0.19562, 0.19476, 0.19518, 0.1965,
-0.19992, 0.2028, 0.20574, 0.2078, 0.20942, 0.21238, 0.21068, 0.20888, 0.20796, 
-//735
-0.09928, 0.11436, 0.13614, 0.15736, 0.17284, 0.1818, 0.1845, 0.18584, 0.18654, 0.18686, 0.18764, 0.18842, 0.1892, 0.19018, 0.1909, 0.19238, 0.19376, 0.19486,
-0.19574, 0.19678, 0.19692, 0.19646, 0.19692, 0.19592, 0.19608, 0.19496, 0.19424, 0.19266, 0.1913, 0.18996, 0.18818, 0.18728, 0.18394, 0.18132, 0.17922, 0.17552,
-0.17092, 0.17086, 0.16638, 0.1625, 0.1577, 0.15286, 0.14836, 0.14212, 0.13822, 0.13352, 0.13144, 0.12952, 0.12766, 0.12738, 0.12724, 0.12682, 0.12534, 0.12402,
-0.12376, 0.12388, 0.12576, 0.1251, 0.1276, 0.12896, 0.12986, 0.12902, 0.12966, 0.13142, 0.13284, 0.13224, 0.13236, 0.1324, 0.1323, 0.1333, 0.13568, 0.13734,
-0.14194, 0.14446, 0.14674, 0.14876, 0.15034, 0.15124, 0.15228, 0.153, 0.15196, 
-//736
-0.0757, 0.08392, 0.09494, 0.10306, 0.11002, 0.1135, 0.1155, 0.11524, 0.11512, 0.1159, 0.1159, 0.1181, 0.11798, 0.1195, 0.12142, 0.12162, 0.1234, 0.12502,
-0.12618, 0.12728, 0.12814, 0.12706, 0.12654, 0.1271, 0.12654, 0.12632, 0.12542, 0.1242, 0.12364, 0.12158, 0.12144, 0.1188, 0.1182, 0.11564, 0.1126, 0.10968,
-0.1054, 0.10446, 0.10058, 0.09766, 0.09358, 0.08974, 0.08658, 0.082, 0.07928, 0.07688, 0.07586, 0.07442, 0.07444, 0.07358, 0.07322, 0.07252, 0.0724, 0.073,
-0.07298, 0.07354, 0.07498, 0.0746, 0.07576, 0.07872, 0.07914, 0.07748, 0.07934, 0.08056, 0.0816, 0.08158, 0.08186, 0.08222, 0.08302, 0.08354, 0.08496, 0.08774,
-0.09192, 0.09532, 0.09854, 0.09992, 0.10214, 0.10188, 0.10376, 0.10298, 0.10312, 
-//737
-0.056, 0.06014, 0.06316, 0.06648, 0.06846, 0.06998, 0.06986, 0.0708, 0.0713, 0.071, 0.07348, 0.07328, 0.07374, 0.07542, 0.07582, 0.07634, 0.07822, 0.07908,
-0.08002, 0.08038, 0.08048, 0.08018, 0.0791, 0.07748, 0.07788, 0.07684, 0.07628, 0.07588, 0.07502, 0.07458, 0.07422, 0.07256, 0.07072, 0.06954, 0.06744, 0.06466,
-0.06014, 0.05986, 0.05812, 0.05668, 0.05588, 0.05256, 0.05004, 0.04842, 0.04736, 0.04684, 0.04714, 0.04686, 0.04674, 0.04646, 0.04684, 0.04702, 0.04666,
-0.04778, 0.04758, 0.04714, 0.04738, 0.04636, 0.0478, 0.04922, 0.04952, 0.04846, 0.0499, 0.05098, 0.0518, 0.05176, 0.053, 0.0539, 0.055, 0.05488, 0.05802,
-0.05934, 0.06186, 0.06412, 0.06724, 0.06728, 0.0674, 0.06822, 0.0688, 0.06942, 0.06878, 
-//738
-0.04866, 0.04756, 0.04842, 0.04842, 0.04888, 0.04856, 0.04956, 0.04902, 0.04902, 0.04936, 0.04832, 0.05058, 0.05144, 0.0533, 0.0539, 0.05644, 0.05944, 0.0621,
-0.06468, 0.06762, 0.07048, 0.07102, 0.0707, 0.06978, 0.06754, 0.06636, 0.06444, 0.06152, 0.05942, 0.0567, 0.05388, 0.05054, 0.04856, 0.04652, 0.04528, 0.0439,
-0.0416, 0.04236, 0.04136, 0.04132, 0.04004, 0.03966, 0.03944, 0.03874, 0.03806, 0.03814, 0.0374, 0.03756, 0.038, 0.0379, 0.03886, 0.0386, 0.0401, 0.03986,
-0.04038, 0.04092, 0.04144, 0.04104, 0.04206, 0.04412, 0.0445, 0.04342, 0.04368, 0.04492, 0.04596, 0.04612, 0.0471, 0.04848, 0.04952, 0.05028, 0.05122, 0.05256,
-0.05388, 0.05572, 0.05656, 0.05676, 0.05828, 0.058, 0.05832, 0.05856, 0.0585, 
-//739
-0.12822, 0.15406, 0.2048, 0.27624, 0.36206, 0.43406, 0.47902, 0.50054, 0.5126, 0.51836, 0.52782, 0.53708, 0.5446, 0.55372, 0.56618, 0.57842, 0.59486, 0.60974,
-0.62368, 0.63354, 0.64096, 0.64438, 0.64442, 0.64588, 0.64498, 0.63992, 0.63546, 0.62738, 0.61994, 0.609, 0.59814, 0.58478, 0.56956, 0.55266, 0.53464, 0.51536,
-0.49412, 0.47608, 0.45736, 0.4378, 0.42096, 0.40428, 0.38604, 0.36888, 0.35456, 0.3428, 0.33562, 0.32954, 0.3257, 0.32268, 0.3207, 0.31842, 0.316, 0.315,
-0.31624, 0.3179, 0.32148, 0.32452, 0.32904, 0.33466, 0.33756, 0.33896, 0.3419, 0.34468, 0.34392, 0.34356, 0.34192, 0.3402, 0.33882, 0.3416, 0.3476, 0.35768,
-0.37154, 0.3877, 0.40466, 0.41816, 0.42798, 0.43134, 0.43556, 0.43416, 0.43368, 
-//740
-0.13374, 0.15946, 0.20554, 0.26004, 0.31482, 0.3529, 0.37378, 0.382, 0.38692, 0.39088, 0.39608, 0.40006, 0.40446, 0.41088, 0.41812, 0.42614, 0.43596, 0.44588,
-0.45366, 0.4562, 0.46068, 0.46114, 0.46174, 0.46176, 0.46024, 0.459, 0.45472, 0.45142, 0.44756, 0.44182, 0.43474, 0.42672, 0.4186, 0.4064, 0.39484, 0.38056,
-0.36488, 0.35306, 0.33922, 0.32514, 0.30938, 0.29504, 0.2807, 0.26564, 0.2525, 0.24286, 0.23502, 0.23018, 0.22714, 0.22372, 0.22248, 0.22022, 0.2184, 0.21616,
-0.21562, 0.2169, 0.21898, 0.22062, 0.22536, 0.2294, 0.23248, 0.23434, 0.23896, 0.24124, 0.24126, 0.2416, 0.2405, 0.23946, 0.23896, 0.23996, 0.24118, 0.24876,
-0.25658, 0.2659, 0.27508, 0.28294, 0.2893, 0.29326, 0.2958, 0.29208, 0.29494, 
-//741
-0.11762, 0.13624, 0.1671, 0.20274, 0.23376, 0.25224, 0.26164, 0.26704, 0.26976, 0.2737, 0.2775, 0.28126, 0.28572, 0.29098, 0.29582, 0.3026, 0.31064, 0.3172,
-0.32212, 0.32558, 0.32836, 0.3278, 0.32774, 0.32638, 0.32594, 0.32406, 0.3218, 0.31844, 0.31538, 0.31018, 0.3049, 0.29888, 0.29126, 0.28218, 0.2739, 0.26314,
-0.2506, 0.2429, 0.2326, 0.22144, 0.21214, 0.20038, 0.18966, 0.17912, 0.17116, 0.16292, 0.15804, 0.15426, 0.15134, 0.14952, 0.148, 0.1463, 0.14488, 0.14392,
-0.1453, 0.14534, 0.14718, 0.1486, 0.15206, 0.1557, 0.1582, 0.1583, 0.16016, 0.1634, 0.16308, 0.16184, 0.16156, 0.1592, 0.15892, 0.16064, 0.16458, 0.1672,
-0.17438, 0.18172, 0.19096, 0.1982, 0.20328, 0.2075, 0.20906, 0.20972, 0.20766, 
-//742
-0.1094, 0.12392, 0.14732, 0.16822, 0.18368, 0.1932, 0.19862, 0.201, 0.20166, 0.20324, 0.20624, 0.20864, 0.21244, 0.21582, 0.21818, 0.22176, 0.22758, 0.23308,
-0.23726, 0.23822, 0.23958, 0.23998, 0.24012, 0.24012, 0.2379, 0.23668, 0.23374, 0.2305, 0.22682, 0.22212, 0.21754, 0.2112, 0.20414, 0.1958, 0.1868, 0.17672,
-0.16468, 0.1572, 0.14916, 0.14074, 0.13384, 0.12858, 0.12308, 0.11718, 0.11222, 0.10858, 0.10582, 0.1043, 0.10202, 0.1013, 0.10056, 0.09882, 0.0989, 0.09872,
-0.0989, 0.10008, 0.10036, 0.10018, 0.10238, 0.10424, 0.1056, 0.10412, 0.10464, 0.1042, 0.1044, 0.10444, 0.10482, 0.105, 0.1068, 0.1087, 0.11332, 0.11786,
-0.1246, 0.13152, 0.1387, 0.14506, 0.14992, 0.15348, 0.1544, 0.15422, 0.155, 
-//743
-0.07846, 0.08506, 0.09584, 0.1026, 0.10894, 0.11398, 0.11644, 0.11846, 0.12, 0.12232, 0.12492, 0.12784, 0.13106, 0.13354, 0.13838, 0.1429, 0.1481, 0.15284,
-0.1577, 0.16032, 0.16132, 0.16234, 0.16096, 0.16146, 0.16096, 0.15908, 0.1578, 0.15408, 0.15002, 0.14548, 0.14028, 0.13344, 0.12582, 0.11758, 0.1113, 0.10252,
-0.09188, 0.08922, 0.08458, 0.07928, 0.07578, 0.07154, 0.0675, 0.06374, 0.06162, 0.05974, 0.05938, 0.05894, 0.0587, 0.05876, 0.05846, 0.05902, 0.0593, 0.06,
-0.06096, 0.0618, 0.06266, 0.06102, 0.06186, 0.06286, 0.06466, 0.06396, 0.06304, 0.0638, 0.0659, 0.0646, 0.06638, 0.06638, 0.06724, 0.07032, 0.0727, 0.07558,
-0.07928, 0.08294, 0.08786, 0.09018, 0.09402, 0.09648, 0.0971, 0.0977, 0.09892, 
-//744
-0.05332, 0.05414, 0.05574, 0.0567, 0.05804, 0.05912, 0.05966, 0.05878, 0.06066, 0.06186, 0.06354, 0.06564, 0.0689, 0.07158, 0.07616, 0.08152, 0.09056, 0.10042,
-0.11192, 0.12164, 0.13148, 0.13726, 0.13728, 0.1326, 0.12632, 0.11752, 0.10656, 0.09756, 0.08736, 0.07858, 0.07062, 0.06358, 0.0586, 0.05362, 0.04972, 0.04612,
-0.04156, 0.04224, 0.04094, 0.03928, 0.03976, 0.03972, 0.03818, 0.03848, 0.03924, 0.0392, 0.03868, 0.03934, 0.03916, 0.03908, 0.03924, 0.03922, 0.03888, 0.03916,
-0.0396, 0.03924, 0.03938, 0.03814, 0.0394, 0.04162, 0.04192, 0.04032, 0.0419, 0.04406, 0.0445, 0.04594, 0.04652, 0.0473, 0.04816, 0.04872, 0.05086, 0.05222,
-0.05506, 0.05658, 0.0581, 0.0612, 0.06384, 0.06412, 0.066, 0.06626, 0.06662, 
-//745
-0.13726, 0.16222, 0.20534, 0.26004, 0.31382, 0.35182, 0.3737, 0.38516, 0.3921, 0.39812, 0.40658, 0.41688, 0.4247, 0.43638, 0.4479, 0.46424, 0.48444, 0.50386,
-0.51998, 0.5326, 0.54196, 0.54638, 0.54752, 0.54704, 0.54458, 0.53926, 0.53156, 0.52304, 0.51164, 0.4974, 0.48098, 0.46326, 0.44266, 0.41962, 0.39604, 0.37314,
-0.34762, 0.32898, 0.3095, 0.2913, 0.27526, 0.25828, 0.2438, 0.23028, 0.21708, 0.20696, 0.20126, 0.19628, 0.19258, 0.18968, 0.18698, 0.18496, 0.1834, 0.18318,
-0.18256, 0.18552, 0.18726, 0.18846, 0.1923, 0.19572, 0.19892, 0.19826, 0.1998, 0.2009, 0.20176, 0.20096, 0.2015, 0.20066, 0.20114, 0.20478, 0.20938, 0.21896,
-0.23112, 0.24432, 0.2565, 0.26858, 0.27746, 0.28288, 0.28768, 0.28546, 0.28766, 
-//746
-0.11822, 0.14062, 0.17098, 0.2047, 0.23264, 0.25246, 0.26326, 0.26908, 0.27376, 0.27896, 0.28642, 0.29388, 0.30298, 0.31288, 0.324, 0.33672, 0.35304, 0.37024,
-0.38404, 0.39422, 0.4005, 0.40312, 0.40382, 0.40244, 0.39872, 0.39236, 0.38484, 0.37394, 0.36338, 0.35128, 0.33614, 0.3203, 0.30384, 0.28598, 0.2673, 0.24752,
-0.22808, 0.21596, 0.20128, 0.18846, 0.17832, 0.16738, 0.15678, 0.147, 0.13814, 0.13272, 0.12724, 0.12446, 0.1206, 0.11912, 0.11832, 0.11608, 0.11606, 0.11506,
-0.11522, 0.11644, 0.11752, 0.11864, 0.12158, 0.1257, 0.12666, 0.12656, 0.12764, 0.12948, 0.12866, 0.12656, 0.12654, 0.1265, 0.12618, 0.12812, 0.13242, 0.13824,
-0.14668, 0.15668, 0.16716, 0.17986, 0.18918, 0.1937, 0.19772, 0.199, 0.19986, 
-//747
-0.10298, 0.11514, 0.13428, 0.15192, 0.16552, 0.17564, 0.18224, 0.1838, 0.18818, 0.19352, 0.19782, 0.20374, 0.21056, 0.21766, 0.2272, 0.23688, 0.24988, 0.26364,
-0.2743, 0.28284, 0.28856, 0.29106, 0.2915, 0.2888, 0.28668, 0.28132, 0.2751, 0.26662, 0.25748, 0.2473, 0.2359, 0.21834, 0.20426, 0.189, 0.17158, 0.1553,
-0.14072, 0.13258, 0.12262, 0.11358, 0.10748, 0.10116, 0.09532, 0.09032, 0.08554, 0.08204, 0.07954, 0.07852, 0.07826, 0.07718, 0.0769, 0.07626, 0.0755, 0.07676,
-0.07726, 0.07838, 0.07934, 0.07916, 0.08052, 0.0827, 0.08258, 0.0829, 0.08264, 0.0825, 0.083, 0.08332, 0.0835, 0.08416, 0.08558, 0.08834, 0.09304, 0.09678,
-0.10308, 0.11052, 0.12, 0.12632, 0.1323, 0.1353, 0.13726, 0.1383, 0.13988, 
-//748
-0.07028, 0.07794, 0.08404, 0.09032, 0.09808, 0.10192, 0.10712, 0.1087, 0.11122, 0.11478, 0.11894, 0.125, 0.13152, 0.13708, 0.14398, 0.15444, 0.1697, 0.18412,
-0.19824, 0.2105, 0.2201, 0.2244, 0.22452, 0.22224, 0.21732, 0.21, 0.20014, 0.18788, 0.1752, 0.16052, 0.14632, 0.13252, 0.11946, 0.106, 0.095, 0.08418, 0.0747,
-0.07032, 0.0663, 0.06186, 0.05762, 0.05578, 0.05284, 0.05044, 0.0487, 0.04768, 0.04752, 0.04674, 0.04646, 0.04542, 0.0478, 0.0493, 0.05004, 0.05054, 0.05062,
-0.04976, 0.0515, 0.0509, 0.05284, 0.05322, 0.05356, 0.05182, 0.05196, 0.05454, 0.05522, 0.05558, 0.0566, 0.05742, 0.05892, 0.06076, 0.06288, 0.0655, 0.06902,
-0.07344, 0.07896, 0.085, 0.08884, 0.09196, 0.0948, 0.0953, 0.0958, 
-//749
-0.12262, 0.14532, 0.18504, 0.23516, 0.2847, 0.32074, 0.34044, 0.35174, 0.3619, 0.37108, 0.38128, 0.39334, 0.40532, 0.4198, 0.4382, 0.45948, 0.48814, 0.5168,
-0.5401, 0.56158, 0.57856, 0.58822, 0.59252, 0.59268, 0.58946, 0.57784, 0.5615, 0.5422, 0.52308, 0.50092, 0.47706, 0.45196, 0.42512, 0.3974, 0.3707, 0.34142,
-0.3129, 0.28972, 0.2699, 0.25148, 0.23652, 0.22152, 0.2086, 0.19588, 0.18442, 0.17576, 0.17048, 0.16676, 0.1642, 0.16194, 0.16084, 0.15852, 0.15822, 0.15828,
-0.1574, 0.1595, 0.1633, 0.16436, 0.16874, 0.1724, 0.17416, 0.1746, 0.17674, 0.17734, 0.17742, 0.1765, 0.17662, 0.17688, 0.17954, 0.18254, 0.18982, 0.19928,
-0.2134, 0.22796, 0.246, 0.2597, 0.27136, 0.27816, 0.28276, 0.28042, 0.28354, 
-//750
-0.11784, 0.13576, 0.16796, 0.20162, 0.23002, 0.251, 0.2635, 0.26924, 0.2767, 0.28424, 0.2954, 0.3065, 0.31876, 0.33268, 0.35128, 0.37212, 0.40292, 0.43452,
-0.4636, 0.48776, 0.50894, 0.52, 0.52282, 0.51798, 0.50788, 0.49106, 0.46978, 0.4473, 0.42126, 0.39232, 0.36466, 0.3362, 0.30824, 0.28066, 0.25342, 0.2272,
-0.20296, 0.1869, 0.17112, 0.15886, 0.14826, 0.14, 0.13058, 0.12264, 0.11662, 0.11102, 0.10736, 0.10536, 0.10346, 0.10108, 0.10076, 0.0996, 0.09844, 0.09786,
-0.09894, 0.10026, 0.10148, 0.1009, 0.10376, 0.10698, 0.10796, 0.10786, 0.10772, 0.10852, 0.10882, 0.10682, 0.1072, 0.10774, 0.11002, 0.11332, 0.11824, 0.12442,
-0.13476, 0.14682, 0.1608, 0.17342, 0.18342, 0.1905, 0.19644, 0.19554, 0.19812, 
-//751
-0.09516, 0.10636, 0.1243, 0.14278, 0.15608, 0.16472, 0.17066, 0.17482, 0.1805, 0.18756, 0.19578, 0.20598, 0.21584, 0.22954, 0.2433, 0.26208, 0.29004, 0.31974,
-0.3469, 0.37306, 0.39256, 0.40202, 0.40414, 0.39754, 0.38666, 0.36872, 0.34716, 0.32108, 0.29586, 0.271, 0.24546, 0.22212, 0.19876, 0.1762, 0.15718, 0.13784,
-0.1169, 0.10986, 0.10082, 0.09306, 0.08602, 0.0821, 0.07778, 0.07394, 0.07186, 0.0689, 0.06796, 0.0692, 0.06906, 0.06794, 0.06832, 0.06804, 0.06824, 0.06872,
-0.0698, 0.06958, 0.07042, 0.06836, 0.06928, 0.0715, 0.07122, 0.0694, 0.07044, 0.07214, 0.07218, 0.07284, 0.07394, 0.07402, 0.07668, 0.07848, 0.0819, 0.08692,
-0.09298, 0.09934, 0.10762, 0.11592, 0.12244, 0.12686, 0.13074, 0.13148, 0.13326, 
-//752
-0.06536, 0.07006, 0.07708, 0.08306, 0.09008, 0.09462, 0.09826, 0.10008, 0.10422, 0.10766, 0.1119, 0.11904, 0.12592, 0.13484, 0.14654, 0.16056, 0.18234, 0.2073,
-0.23074, 0.2531, 0.2702, 0.28048, 0.28046, 0.2737, 0.26032, 0.24348, 0.2242, 0.20116, 0.18116, 0.1595, 0.14084, 0.12452, 0.1083, 0.0938, 0.08284, 0.07224,
-0.06234, 0.05908, 0.05574, 0.05178, 0.04968, 0.04852, 0.04608, 0.0445, 0.04448, 0.0423, 0.0436, 0.0438, 0.04456, 0.04492, 0.04456, 0.0453, 0.04556, 0.04446,
-0.04534, 0.04672, 0.04662, 0.04486, 0.04654, 0.04756, 0.04772, 0.0462, 0.04718, 0.05018, 0.04998, 0.05076, 0.052, 0.05332, 0.05466, 0.05674, 0.05878, 0.06092,
-0.0656, 0.06904, 0.07474, 0.07876, 0.08558, 0.08764, 0.08918, 0.0895, 0.09056, 
-//753
-0.12768, 0.15606, 0.21176, 0.29688, 0.4086, 0.52596, 0.62098, 0.67254, 0.69704, 0.7078, 0.71354, 0.72152, 0.72634, 0.73, 0.73642, 0.7393, 0.74542, 0.75002,
-0.75352, 0.75748, 0.75708, 0.75616, 0.75734, 0.75948, 0.75752, 0.75776, 0.75534, 0.75198, 0.75002, 0.74576, 0.73908, 0.73218, 0.72338, 0.71464, 0.70362,
-0.69118, 0.67784, 0.6667, 0.65352, 0.64196, 0.63214, 0.62274, 0.61378, 0.60372, 0.59512, 0.58712, 0.58142, 0.5783, 0.57606, 0.5747, 0.5762, 0.57378, 0.5746,
-0.57744, 0.57816, 0.58112, 0.58578, 0.58718, 0.58868, 0.5898, 0.5892, 0.58866, 0.58632, 0.5836, 0.5809, 0.57814, 0.57958, 0.5809, 0.58582, 0.59542, 0.60648,
-0.62162, 0.63774, 0.6571, 0.6762, 0.69086, 0.70162, 0.70856, 0.71174, 0.7131, 0.71856, 
-//754
-0.1239, 0.15226, 0.20566, 0.2822, 0.37042, 0.45272, 0.50354, 0.52632, 0.53412, 0.53782, 0.54066, 0.5418, 0.54312, 0.54478, 0.54738, 0.55012, 0.55262, 0.55488,
-0.55634, 0.55844, 0.55856, 0.55748, 0.5567, 0.55704, 0.55656, 0.55602, 0.55402, 0.55294, 0.55108, 0.54674, 0.54412, 0.53992, 0.53468, 0.53002, 0.5245, 0.51446,
-0.50526, 0.49942, 0.48956, 0.48044, 0.46934, 0.45856, 0.44946, 0.43928, 0.43152, 0.42406, 0.4178, 0.41256, 0.40912, 0.4067, 0.40616, 0.40346, 0.40302, 0.40328,
-0.40738, 0.4093, 0.41232, 0.4157, 0.41708, 0.41946, 0.41848, 0.41878, 0.42104, 0.42064, 0.41988, 0.41916, 0.41788, 0.41752, 0.42048, 0.42264, 0.42798, 0.43752,
-0.44698, 0.45504, 0.46464, 0.47284, 0.47868, 0.48072, 0.48134, 0.47932, 0.4795, 
-//755
-0.12788, 0.15684, 0.2056, 0.26834, 0.33274, 0.37754, 0.39966, 0.40758, 0.41182, 0.41224, 0.4152, 0.41704, 0.41872, 0.41938, 0.421, 0.42324, 0.42502, 0.42788,
-0.42916, 0.4306, 0.42954, 0.42916, 0.42818, 0.42676, 0.42646, 0.42456, 0.42308, 0.42152, 0.41988, 0.41674, 0.41388, 0.41102, 0.40714, 0.40256, 0.39662, 0.38928,
-0.3816, 0.37706, 0.37058, 0.36294, 0.35554, 0.34612, 0.33814, 0.32842, 0.3208, 0.313, 0.3074, 0.30474, 0.30016, 0.2989, 0.29828, 0.29754, 0.2976, 0.2964,
-0.29696, 0.29822, 0.30128, 0.3027, 0.30622, 0.30898, 0.31062, 0.31102, 0.3106, 0.31268, 0.31234, 0.31142, 0.31114, 0.31096, 0.31124, 0.31494, 0.31812, 0.32302,
-0.3301, 0.33488, 0.34148, 0.34634, 0.3495, 0.3515, 0.34956, 0.3479, 0.34752, 
-//756
-0.12786, 0.15184, 0.18778, 0.22664, 0.2582, 0.27684, 0.28664, 0.28908, 0.28964, 0.29042, 0.29244, 0.29404, 0.29644, 0.29616, 0.29734, 0.30036, 0.30224, 0.3025,
-0.30358, 0.30472, 0.30474, 0.30308, 0.30266, 0.30248, 0.30224, 0.30064, 0.29976, 0.29808, 0.2964, 0.29438, 0.29152, 0.2904, 0.28602, 0.28194, 0.27842, 0.27234,
-0.26508, 0.26268, 0.25674, 0.25102, 0.2455, 0.2396, 0.23406, 0.22828, 0.22226, 0.21682, 0.213, 0.21054, 0.2089, 0.20802, 0.20676, 0.2062, 0.20534, 0.2037,
-0.2042, 0.20566, 0.2072, 0.2082, 0.2102, 0.21218, 0.21252, 0.21112, 0.2092, 0.20948, 0.20882, 0.2083, 0.209, 0.20946, 0.21168, 0.21598, 0.22158, 0.22594, 0.231,
-0.23788, 0.24496, 0.24988, 0.25432, 0.255, 0.25672, 0.25498, 0.25584, 
-//757
-0.09582, 0.1121, 0.13446, 0.15844, 0.17568, 0.18458, 0.1893, 0.18996, 0.19062, 0.19152, 0.19198, 0.1924, 0.1932, 0.19304, 0.1928, 0.19406, 0.1952, 0.19586,
-0.1976, 0.19638, 0.19688, 0.19608, 0.19574, 0.19562, 0.19572, 0.19552, 0.19398, 0.19324, 0.19156, 0.18924, 0.18754, 0.18578, 0.1823, 0.17974, 0.17696, 0.17154,
-0.1649, 0.16432, 0.16092, 0.1561, 0.1521, 0.14792, 0.14384, 0.13886, 0.13492, 0.13204, 0.1282, 0.12676, 0.12524, 0.12376, 0.12264, 0.12314, 0.12354, 0.1235,
-0.1244, 0.12566, 0.12676, 0.12664, 0.12846, 0.12992, 0.1305, 0.129, 0.12842, 0.12714, 0.1283, 0.12704, 0.1282, 0.12912, 0.13088, 0.1346, 0.13768, 0.14186,
-0.14746, 0.15206, 0.15748, 0.16092, 0.16322, 0.16524, 0.16468, 0.16514, 0.16382, 
-//758
-0.0888, 0.0973, 0.1085, 0.11716, 0.1239, 0.12682, 0.12904, 0.12878, 0.12974, 0.12818, 0.12768, 0.1304, 0.1291, 0.12886, 0.13034, 0.13162, 0.13252, 0.13258,
-0.1338, 0.13386, 0.13376, 0.13406, 0.13172, 0.13262, 0.13236, 0.13136, 0.13104, 0.1297, 0.12778, 0.1258, 0.12444, 0.1221, 0.12004, 0.11696, 0.11432, 0.11196,
-0.10696, 0.10596, 0.1039, 0.1011, 0.09674, 0.0946, 0.09156, 0.08776, 0.08454, 0.08314, 0.08224, 0.08064, 0.08096, 0.0807, 0.08044, 0.0795, 0.0805, 0.08006,
-0.07986, 0.08076, 0.08134, 0.08108, 0.08126, 0.08342, 0.08388, 0.08184, 0.08178, 0.08348, 0.0841, 0.08358, 0.08466, 0.08548, 0.08582, 0.08782, 0.0899, 0.0916,
-0.09466, 0.09776, 0.09992, 0.1027, 0.10464, 0.10578, 0.10736, 0.10632, 0.10874, 
-//759
-0.06276, 0.06508, 0.0707, 0.0726, 0.07468, 0.07688, 0.0772, 0.0775, 0.07756, 0.07802, 0.07818, 0.0788, 0.0794, 0.08006, 0.07974, 0.07952, 0.0817, 0.0824,
-0.08308, 0.0844, 0.08322, 0.08266, 0.082, 0.08186, 0.082, 0.08146, 0.08142, 0.08076, 0.07972, 0.07748, 0.07614, 0.07412, 0.07198, 0.069, 0.06646, 0.06372,
-0.0611, 0.06154, 0.05994, 0.05852, 0.05668, 0.05538, 0.05392, 0.0519, 0.05048, 0.05038, 0.04994, 0.0496, 0.0505, 0.04952, 0.04954, 0.04996, 0.04928, 0.05038,
-0.05088, 0.0519, 0.05224, 0.05082, 0.05302, 0.05444, 0.05478, 0.05334, 0.05306, 0.05462, 0.0548, 0.056, 0.0572, 0.05846, 0.05988, 0.06118, 0.06306, 0.06436,
-0.0669, 0.06884, 0.07088, 0.0725, 0.07354, 0.07486, 0.0735, 0.07358, 0.07376, 
-//760
-0.04804, 0.04714, 0.05, 0.05036, 0.05238, 0.05116, 0.05248, 0.05212, 0.05386, 0.0529, 0.05282, 0.05498, 0.05366, 0.05514, 0.05614, 0.0574, 0.06034, 0.06278,
-0.06562, 0.0689, 0.07174, 0.07222, 0.07098, 0.07156, 0.06966, 0.06844, 0.0662, 0.06338, 0.06134, 0.05822, 0.05492, 0.05182, 0.0492, 0.04672, 0.04476, 0.04254,
-0.03954, 0.04242, 0.04294, 0.04182, 0.04106, 0.0399, 0.03878, 0.03814, 0.03804, 0.03794, 0.0376, 0.03862, 0.03872, 0.0392, 0.04026, 0.04086, 0.04168, 0.0428,
-0.04292, 0.0438, 0.04376, 0.0416, 0.04392, 0.04576, 0.04682, 0.04484, 0.04622, 0.04598, 0.04698, 0.04842, 0.0501, 0.05032, 0.0521, 0.05308, 0.05532, 0.057,
-0.05928, 0.05868, 0.06102, 0.0627, 0.06304, 0.06322, 0.06342, 0.06244, 0.06322, 
-//761
-0.1269, 0.15586, 0.2114, 0.2855, 0.37672, 0.46136, 0.51616, 0.54188, 0.55384, 0.55912, 0.56576, 0.57314, 0.58024, 0.58818, 0.59716, 0.60626, 0.61946, 0.62922,
-0.63708, 0.6435, 0.64676, 0.6497, 0.64856, 0.64872, 0.64688, 0.64264, 0.6381, 0.6309, 0.62294, 0.611, 0.60004, 0.58746, 0.57072, 0.55324, 0.53464, 0.51248,
-0.4918, 0.47222, 0.4539, 0.4368, 0.4207, 0.40572, 0.39228, 0.37826, 0.36604, 0.35486, 0.34858, 0.34336, 0.34114, 0.33804, 0.3373, 0.33566, 0.3344, 0.33492,
-0.33512, 0.33864, 0.34246, 0.34496, 0.34796, 0.35208, 0.35306, 0.35072, 0.35022, 0.34962, 0.34682, 0.34472, 0.34456, 0.34606, 0.3484, 0.3551, 0.36456, 0.38098,
-0.39886, 0.41814, 0.43876, 0.45582, 0.4686, 0.4757, 0.47676, 0.4774, 0.47832, 
-//762
-0.13552, 0.16146, 0.21146, 0.27242, 0.33294, 0.3758, 0.39768, 0.40768, 0.41428, 0.41892, 0.42274, 0.42726, 0.4313, 0.43718, 0.44204, 0.44664, 0.45464, 0.45966,
-0.4637, 0.46778, 0.4703, 0.46988, 0.46738, 0.4691, 0.46706, 0.46452, 0.4622, 0.4575, 0.45208, 0.4435, 0.43626, 0.42442, 0.41356, 0.39952, 0.38528, 0.3691,
-0.3517, 0.3372, 0.32352, 0.31004, 0.29762, 0.28638, 0.27532, 0.2628, 0.25362, 0.24568, 0.2404, 0.23528, 0.23352, 0.23134, 0.22842, 0.22582, 0.22504, 0.22466,
-0.22358, 0.22558, 0.22752, 0.22676, 0.23034, 0.23324, 0.23364, 0.23394, 0.2343, 0.23352, 0.23156, 0.23006, 0.22942, 0.22984, 0.23224, 0.23676, 0.24394, 0.25448,
-0.26884, 0.28272, 0.2977, 0.31216, 0.32362, 0.33084, 0.33538, 0.3339, 0.33426, 
-//763
-0.10748, 0.12994, 0.16606, 0.20798, 0.24512, 0.2696, 0.28182, 0.28636, 0.28952, 0.2914, 0.29626, 0.29966, 0.30282, 0.30678, 0.30958, 0.31422, 0.32032, 0.3248,
-0.32748, 0.32902, 0.3288, 0.32878, 0.32834, 0.32738, 0.32638, 0.32462, 0.32234, 0.31838, 0.31374, 0.3087, 0.3026, 0.29488, 0.28666, 0.27788, 0.26592, 0.25406,
-0.23968, 0.23074, 0.2196, 0.20924, 0.19894, 0.19098, 0.18224, 0.17398, 0.16714, 0.16006, 0.15688, 0.15414, 0.15348, 0.15108, 0.1502, 0.15026, 0.15054, 0.15144,
-0.15088, 0.15282, 0.15574, 0.15446, 0.15676, 0.15852, 0.15918, 0.15752, 0.15924, 0.15926, 0.1576, 0.1561, 0.15674, 0.1559, 0.15886, 0.1624, 0.16708, 0.1748,
-0.18492, 0.19602, 0.20782, 0.21794, 0.22584, 0.22954, 0.2336, 0.23304, 0.2333, 
-//764
-0.09114, 0.10766, 0.13018, 0.15458, 0.17258, 0.1836, 0.18896, 0.19096, 0.19414, 0.19562, 0.19892, 0.2013, 0.20384, 0.20516, 0.20708, 0.21152, 0.2145, 0.21798,
-0.22082, 0.22114, 0.22178, 0.2217, 0.22088, 0.22034, 0.21978, 0.21844, 0.21542, 0.21468, 0.21016, 0.20526, 0.20094, 0.19368, 0.18728, 0.1801, 0.17226, 0.16248,
-0.14988, 0.14404, 0.1373, 0.12976, 0.12286, 0.1179, 0.11292, 0.10716, 0.10132, 0.09806, 0.09514, 0.09404, 0.093, 0.09288, 0.09202, 0.09094, 0.0905, 0.09074,
-0.09164, 0.09308, 0.09374, 0.0931, 0.0955, 0.09704, 0.09632, 0.09638, 0.09648, 0.09776, 0.09686, 0.09598, 0.09576, 0.09746, 0.09946, 0.10378, 0.10814, 0.11452,
-0.12292, 0.13264, 0.14106, 0.1498, 0.1561, 0.15932, 0.16116, 0.16304, 0.16452, 
-//765
-0.07232, 0.08192, 0.09432, 0.10502, 0.11542, 0.11756, 0.12182, 0.1223, 0.1238, 0.12598, 0.12744, 0.13012, 0.13354, 0.13634, 0.13846, 0.14144, 0.14482, 0.14808,
-0.1506, 0.15138, 0.15132, 0.15094, 0.15088, 0.15044, 0.14924, 0.14782, 0.1449, 0.14172, 0.13944, 0.13444, 0.12922, 0.12392, 0.11796, 0.1115, 0.10406, 0.09742,
-0.08892, 0.0858, 0.07998, 0.07534, 0.07124, 0.06856, 0.06446, 0.0612, 0.0592, 0.05612, 0.05526, 0.0548, 0.0541, 0.05374, 0.0544, 0.05382, 0.05416, 0.05498,
-0.05568, 0.05666, 0.05718, 0.05638, 0.05876, 0.05988, 0.06048, 0.05864, 0.05904, 0.05908, 0.05876, 0.05966, 0.05984, 0.06102, 0.06236, 0.06444, 0.06806,
-0.07292, 0.07786, 0.0834, 0.09088, 0.09784, 0.1028, 0.10508, 0.10788, 0.11014, 0.10852, 
-//766
-0.05514, 0.05602, 0.05936, 0.0614, 0.06378, 0.06386, 0.06564, 0.06522, 0.06658, 0.06894, 0.06998, 0.07242, 0.07286, 0.07768, 0.08156, 0.08542, 0.09654, 0.10756,
-0.11746, 0.12866, 0.13626, 0.14086, 0.13988, 0.1356, 0.12824, 0.11854, 0.10876, 0.09736, 0.08818, 0.079, 0.07056, 0.0644, 0.05818, 0.05354, 0.04932, 0.04466,
-0.03978, 0.04166, 0.04134, 0.04122, 0.04102, 0.04032, 0.03956, 0.0393, 0.04036, 0.0399, 0.0413, 0.04096, 0.0418, 0.0416, 0.04164, 0.04306, 0.04282, 0.04332,
-0.0433, 0.0446, 0.04508, 0.04342, 0.04518, 0.0475, 0.04774, 0.04708, 0.04738, 0.04886, 0.05164, 0.05248, 0.05302, 0.05468, 0.0561, 0.05818, 0.05928, 0.06174,
-0.06198, 0.06248, 0.06572, 0.06742, 0.069, 0.06864, 0.0708, 0.06986, 0.07094, 
-//767
-0.13316, 0.1599, 0.20878, 0.27166, 0.33838, 0.3867, 0.41504, 0.42688, 0.4352, 0.44148, 0.44998, 0.4601, 0.46692, 0.47614, 0.48628, 0.49934, 0.51468, 0.52996,
-0.54072, 0.54842, 0.5517, 0.5549, 0.55346, 0.55162, 0.5493, 0.54256, 0.53352, 0.52296, 0.50974, 0.49212, 0.47558, 0.45644, 0.43572, 0.41268, 0.38842, 0.36272,
-0.33586, 0.31626, 0.29808, 0.28078, 0.26782, 0.25608, 0.24346, 0.23156, 0.2214, 0.21472, 0.20904, 0.20534, 0.2023, 0.19978, 0.19862, 0.19684, 0.1958, 0.19756,
-0.19844, 0.20122, 0.2034, 0.20432, 0.20828, 0.21024, 0.21096, 0.20964, 0.2088, 0.20892, 0.2072, 0.20388, 0.20436, 0.20564, 0.20918, 0.21588, 0.22508, 0.23904,
-0.25606, 0.27524, 0.29626, 0.31426, 0.32622, 0.33444, 0.33778, 0.33912, 0.34268, 
-//768
-0.11332, 0.13442, 0.17174, 0.21552, 0.25386, 0.28002, 0.29478, 0.30154, 0.30844, 0.31544, 0.32232, 0.33146, 0.34086, 0.35006, 0.35974, 0.37002, 0.38294,
-0.39554, 0.40408, 0.4098, 0.41322, 0.4138, 0.41238, 0.4109, 0.40732, 0.40064, 0.39328, 0.38456, 0.37116, 0.35788, 0.34318, 0.32588, 0.30638, 0.2877, 0.26836,
-0.24538, 0.22372, 0.20984, 0.19416, 0.18168, 0.17002, 0.15924, 0.15118, 0.14238, 0.13406, 0.1281, 0.12528, 0.1231, 0.12098, 0.12002, 0.11966, 0.11954, 0.11944,
-0.11942, 0.12054, 0.12136, 0.12482, 0.12554, 0.12694, 0.12888, 0.12904, 0.1264, 0.12586, 0.12628, 0.1243, 0.12376, 0.12242, 0.12412, 0.1268, 0.13106, 0.13808,
-0.14762, 0.16182, 0.17792, 0.19578, 0.21158, 0.22326, 0.23214, 0.2359, 0.23774, 0.2407, 
-//769
-0.09284, 0.11324, 0.13572, 0.1608, 0.18288, 0.19764, 0.20598, 0.20966, 0.21508, 0.21974, 0.22612, 0.23386, 0.24164, 0.2498, 0.2555, 0.26526, 0.27622, 0.28604,
-0.29364, 0.29858, 0.30214, 0.30278, 0.30236, 0.30038, 0.29754, 0.29178, 0.28546, 0.276, 0.26572, 0.25364, 0.24018, 0.2262, 0.21024, 0.19304, 0.17734, 0.16098,
-0.14192, 0.13308, 0.1218, 0.11296, 0.10604, 0.09984, 0.0937, 0.08982, 0.0846, 0.0822, 0.07934, 0.07776, 0.07732, 0.07578, 0.07482, 0.0741, 0.07526, 0.0763,
-0.07674, 0.0787, 0.07996, 0.0802, 0.08188, 0.08412, 0.0835, 0.08166, 0.08144, 0.08202, 0.0816, 0.08052, 0.08164, 0.08194, 0.0847, 0.08712, 0.0927, 0.09978,
-0.10798, 0.11914, 0.13118, 0.1445, 0.1555, 0.16136, 0.16492, 0.16652, 0.16826, 
-//770
-0.06588, 0.0752, 0.08936, 0.10094, 0.11116, 0.11668, 0.12224, 0.12552, 0.12968, 0.13494, 0.13944, 0.14616, 0.154, 0.1606, 0.16932, 0.17832, 0.18944, 0.20168,
-0.21028, 0.21756, 0.22228, 0.22274, 0.22186, 0.21866, 0.21392, 0.2062, 0.19754, 0.1872, 0.1754, 0.16234, 0.14954, 0.1353, 0.12238, 0.11024, 0.09688, 0.08628,
-0.0748, 0.06912, 0.06336, 0.05964, 0.05652, 0.0542, 0.0532, 0.05034, 0.04898, 0.04752, 0.04762, 0.04768, 0.04706, 0.04576, 0.04592, 0.04448, 0.04566, 0.04666,
-0.04754, 0.0484, 0.0488, 0.04762, 0.04998, 0.05138, 0.05126, 0.05048, 0.04888, 0.05096, 0.05094, 0.05166, 0.05336, 0.05448, 0.05606, 0.05886, 0.06154, 0.06552,
-0.07112, 0.0778, 0.08714, 0.09536, 0.10184, 0.10734, 0.10982, 0.1119, 0.1131, 
-//771
-0.13294, 0.15822, 0.2029, 0.26564, 0.3305, 0.3798, 0.40962, 0.41966, 0.43026, 0.43802, 0.44968, 0.46228, 0.47542, 0.4899, 0.50586, 0.52606, 0.5515, 0.5768,
-0.59806, 0.61518, 0.62724, 0.63556, 0.6361, 0.63002, 0.62022, 0.60624, 0.5865, 0.5643, 0.5393, 0.51064, 0.48444, 0.45658, 0.42948, 0.39872, 0.36986, 0.3405,
-0.31032, 0.29008, 0.27088, 0.2546, 0.24044, 0.22918, 0.21776, 0.20744, 0.19678, 0.18984, 0.18572, 0.18144, 0.179, 0.1776, 0.17744, 0.17716, 0.1771, 0.17728,
-0.17814, 0.18108, 0.18454, 0.1852, 0.18846, 0.19068, 0.19098, 0.18994, 0.18832, 0.18628, 0.18452, 0.18244, 0.18296, 0.18496, 0.18972, 0.19618, 0.20486, 0.21726,
-0.23674, 0.25794, 0.28074, 0.30372, 0.32286, 0.33276, 0.33992, 0.34292, 0.34544, 
-//772
-0.10818, 0.12886, 0.16406, 0.20486, 0.24226, 0.26794, 0.28428, 0.29226, 0.30296, 0.31268, 0.3223, 0.33668, 0.35184, 0.36882, 0.38648, 0.40722, 0.43462, 0.46344,
-0.48858, 0.50926, 0.52458, 0.53096, 0.52866, 0.52166, 0.5078, 0.49074, 0.4673, 0.4414, 0.4141, 0.38558, 0.3566, 0.32766, 0.2998, 0.27198, 0.2439, 0.21894,
-0.19338, 0.17672, 0.16134, 0.1493, 0.13952, 0.1303, 0.12158, 0.11576, 0.10928, 0.10466, 0.10196, 0.09948, 0.09848, 0.09808, 0.09764, 0.097, 0.09684, 0.09754,
-0.09818, 0.09908, 0.09976, 0.0998, 0.1022, 0.10364, 0.10436, 0.10218, 0.1025, 0.10068, 0.10024, 0.09926, 0.09998, 0.10182, 0.10404, 0.10872, 0.11432, 0.12432,
-0.13708, 0.15304, 0.1722, 0.19134, 0.20832, 0.21912, 0.22404, 0.22652, 0.23042, 
-//773
-0.09102, 0.10406, 0.12566, 0.14768, 0.1663, 0.17862, 0.18834, 0.19314, 0.2017, 0.20912, 0.21824, 0.23158, 0.2449, 0.261, 0.27688, 0.2965, 0.32366, 0.35264,
-0.3777, 0.39786, 0.4137, 0.42072, 0.41924, 0.4092, 0.39366, 0.37356, 0.35, 0.32392, 0.29752, 0.26954, 0.24478, 0.21982, 0.19674, 0.17342, 0.1526, 0.13278,
-0.1149, 0.10374, 0.09532, 0.08858, 0.08216, 0.07774, 0.07314, 0.06964, 0.0684, 0.0659, 0.06352, 0.06304, 0.0638, 0.06406, 0.06404, 0.0638, 0.064, 0.0643,
-0.06536, 0.06584, 0.0672, 0.0656, 0.06704, 0.06794, 0.06804, 0.06694, 0.06878, 0.06916, 0.06934, 0.06876, 0.06966, 0.06996, 0.07112, 0.07486, 0.0778, 0.08328,
-0.09096, 0.10172, 0.11394, 0.12668, 0.14006, 0.14696, 0.1539, 0.15714, 0.16018, 
-//774
-0.06878, 0.0761, 0.08716, 0.09726, 0.1059, 0.11236, 0.11794, 0.1206, 0.1264, 0.13248, 0.13674, 0.1472, 0.15594, 0.16798, 0.181, 0.1963, 0.21788, 0.24408,
-0.26604, 0.28446, 0.297, 0.3021, 0.29838, 0.2877, 0.27178, 0.25288, 0.2315, 0.20838, 0.18526, 0.16358, 0.14404, 0.1262, 0.11068, 0.09594, 0.08386, 0.07352,
-0.06354, 0.05996, 0.05622, 0.05234, 0.0503, 0.04788, 0.04718, 0.04634, 0.04624, 0.04558, 0.04572, 0.04632, 0.04638, 0.0468, 0.04716, 0.04658, 0.0474, 0.04768,
-0.04732, 0.04834, 0.04792, 0.04674, 0.04752, 0.04914, 0.04916, 0.04822, 0.0479, 0.04978, 0.05046, 0.05126, 0.05262, 0.05416, 0.0555, 0.0577, 0.06086, 0.06392,
-0.06764, 0.07408, 0.08048, 0.08816, 0.09492, 0.10024, 0.10438, 0.1061, 0.10734, 
-//775
-0.12944, 0.15754, 0.21318, 0.29524, 0.4058, 0.52466, 0.61906, 0.67298, 0.6934, 0.70138, 0.70914, 0.71168, 0.71334, 0.71522, 0.71756, 0.71892, 0.72058, 0.7225,
-0.72254, 0.72408, 0.72168, 0.72132, 0.72098, 0.7213, 0.72276, 0.7227, 0.72218, 0.72032, 0.71854, 0.71436, 0.71142, 0.70844, 0.70292, 0.69708, 0.6893, 0.68144,
-0.67406, 0.66904, 0.6618, 0.659, 0.65536, 0.6518, 0.65032, 0.6454, 0.6423, 0.63906, 0.63806, 0.6382, 0.6387, 0.64188, 0.64538, 0.64752, 0.6502, 0.65252, 0.6531,
-0.65408, 0.65598, 0.65532, 0.65354, 0.65222, 0.64806, 0.64542, 0.64352, 0.64494, 0.64708, 0.64866, 0.65124, 0.6546, 0.6549, 0.66142, 0.66554, 0.67156, 0.67602,
-0.68008, 0.68468, 0.69056, 0.69228, 0.69948, 0.70058, 0.69768, 0.70138, 
-//776
-0.12776, 0.1559, 0.21058, 0.28354, 0.37254, 0.45516, 0.50646, 0.52814, 0.53358, 0.5367, 0.53706, 0.53506, 0.53528, 0.5344, 0.53432, 0.5328, 0.53318, 0.5331,
-0.53436, 0.53236, 0.53044, 0.53, 0.52676, 0.52786, 0.52886, 0.52802, 0.52622, 0.52506, 0.52462, 0.52278, 0.52114, 0.51904, 0.5144, 0.51238, 0.50892, 0.50644,
-0.50338, 0.50112, 0.49678, 0.4931, 0.489, 0.4843, 0.48072, 0.47614, 0.47318, 0.46768, 0.46394, 0.46032, 0.45836, 0.45784, 0.45704, 0.45622, 0.45392, 0.45386,
-0.45424, 0.4531, 0.45558, 0.45498, 0.45516, 0.4565, 0.45412, 0.45088, 0.44954, 0.45034, 0.45008, 0.44736, 0.44792, 0.44824, 0.44978, 0.45136, 0.45414, 0.45856,
-0.46454, 0.46682, 0.46834, 0.47022, 0.4737, 0.4716, 0.47358, 0.4699, 0.47156, 
-//777
-0.1328, 0.16136, 0.21172, 0.27278, 0.33272, 0.37388, 0.39144, 0.3961, 0.39664, 0.39634, 0.39542, 0.39522, 0.39508, 0.39372, 0.39344, 0.39246, 0.39246, 0.39222,
-0.3932, 0.39386, 0.39262, 0.39076, 0.39214, 0.39286, 0.39338, 0.3937, 0.39198, 0.39052, 0.38976, 0.38832, 0.38636, 0.38464, 0.38416, 0.3811, 0.38016, 0.37592,
-0.37346, 0.37478, 0.37226, 0.3712, 0.3686, 0.3645, 0.36206, 0.35796, 0.35364, 0.34912, 0.34576, 0.3423, 0.33978, 0.33824, 0.33746, 0.33678, 0.33524, 0.33452,
-0.33204, 0.33062, 0.33046, 0.32938, 0.3307, 0.33286, 0.3335, 0.33242, 0.3316, 0.33328, 0.33384, 0.3337, 0.33186, 0.33028, 0.32934, 0.32978, 0.3312, 0.3315,
-0.33412, 0.33458, 0.33602, 0.3363, 0.3379, 0.34004, 0.33724, 0.33658, 0.33876, 
-//778
-0.12456, 0.15084, 0.1881, 0.22808, 0.2622, 0.28024, 0.28806, 0.28996, 0.28876, 0.2879, 0.28804, 0.28864, 0.28656, 0.28768, 0.28664, 0.28648, 0.28618, 0.28526,
-0.2844, 0.28314, 0.28126, 0.2807, 0.27922, 0.27848, 0.2772, 0.27636, 0.27702, 0.27534, 0.27358, 0.26952, 0.2689, 0.2666, 0.26494, 0.26446, 0.263, 0.26064,
-0.25836, 0.25856, 0.25608, 0.25434, 0.2517, 0.24848, 0.2448, 0.2407, 0.23808, 0.23398, 0.23112, 0.2291, 0.22736, 0.22454, 0.22324, 0.22272, 0.22132, 0.221,
-0.21984, 0.21832, 0.21794, 0.21654, 0.21742, 0.21922, 0.21992, 0.21794, 0.21716, 0.21632, 0.21726, 0.21682, 0.216, 0.21646, 0.2146, 0.21716, 0.21782, 0.2198,
-0.22088, 0.22234, 0.2223, 0.2243, 0.22598, 0.22516, 0.22572, 0.22422, 0.22392, 
-//779
-0.10362, 0.11946, 0.14232, 0.16318, 0.17956, 0.18624, 0.18886, 0.18942, 0.1885, 0.1872, 0.18742, 0.18736, 0.18628, 0.18622, 0.18524, 0.18468, 0.18444, 0.1836,
-0.1843, 0.18272, 0.18282, 0.18134, 0.18098, 0.18048, 0.18012, 0.1808, 0.17974, 0.1783, 0.17756, 0.1764, 0.1756, 0.1743, 0.1728, 0.17126, 0.17008, 0.16802,
-0.1664, 0.1684, 0.16696, 0.165, 0.16318, 0.16176, 0.1597, 0.1568, 0.15478, 0.15298, 0.15028, 0.14832, 0.14776, 0.14664, 0.14706, 0.14646, 0.14678, 0.14616,
-0.14572, 0.14518, 0.14464, 0.14366, 0.14478, 0.14638, 0.147, 0.14524, 0.14706, 0.1484, 0.14786, 0.147, 0.14736, 0.14684, 0.14642, 0.14628, 0.14792, 0.14826,
-0.1494, 0.14944, 0.1511, 0.15334, 0.15372, 0.15464, 0.15416, 0.15538, 0.15486, 
-//780
-0.08672, 0.09352, 0.1058, 0.1101, 0.11462, 0.11466, 0.11508, 0.11514, 0.11498, 0.11464, 0.11428, 0.11522, 0.11534, 0.11486, 0.11484, 0.11342, 0.11422, 0.1136,
-0.11358, 0.11304, 0.11242, 0.11262, 0.11264, 0.11452, 0.11436, 0.11402, 0.11338, 0.11318, 0.11184, 0.11132, 0.11028, 0.11018, 0.10922, 0.10882, 0.10664, 0.1048,
-0.10314, 0.10666, 0.1064, 0.1044, 0.10132, 0.10004, 0.09918, 0.09722, 0.09728, 0.09594, 0.09534, 0.09436, 0.093, 0.09266, 0.0917, 0.09284, 0.09202, 0.0922,
-0.09216, 0.0922, 0.09284, 0.09042, 0.09172, 0.09354, 0.09318, 0.0931, 0.09306, 0.09524, 0.09548, 0.0955, 0.09562, 0.09576, 0.09722, 0.09752, 0.09852, 0.10092,
-0.10172, 0.10258, 0.10486, 0.10468, 0.10708, 0.10736, 0.1079, 0.1074, 0.10694, 
-//781
-0.0609, 0.06258, 0.06548, 0.06792, 0.07026, 0.07092, 0.0712, 0.07218, 0.07288, 0.07062, 0.06714, 0.06642, 0.06698, 0.0672, 0.06698, 0.06654, 0.06704, 0.06678,
-0.06634, 0.06648, 0.06718, 0.06624, 0.06512, 0.0639, 0.06538, 0.06584, 0.06634, 0.0657, 0.06492, 0.06408, 0.0641, 0.06304, 0.06274, 0.06154, 0.06098, 0.05958,
-0.0582, 0.05962, 0.05878, 0.05794, 0.05736, 0.05558, 0.0553, 0.0537, 0.05366, 0.05224, 0.05216, 0.05164, 0.05202, 0.05178, 0.05212, 0.05314, 0.05316, 0.05354,
-0.05378, 0.05364, 0.05494, 0.0534, 0.0542, 0.05496, 0.05526, 0.0544, 0.05436, 0.05678, 0.05676, 0.05748, 0.05748, 0.05902, 0.05972, 0.06044, 0.06168, 0.06276,
-0.06428, 0.06542, 0.06616, 0.06656, 0.06714, 0.06868, 0.06888, 0.06888, 0.0689, 
-//782
-0.0519, 0.05122, 0.05298, 0.05468, 0.05428, 0.05504, 0.0567, 0.05626, 0.05658, 0.05796, 0.05606, 0.05552, 0.05556, 0.05606, 0.05648, 0.0544, 0.05468, 0.0543,
-0.05316, 0.05348, 0.05184, 0.05224, 0.05064, 0.05152, 0.05208, 0.05182, 0.05202, 0.05116, 0.05208, 0.05122, 0.05054, 0.04952, 0.04886, 0.04814, 0.04796,
-0.04502, 0.04314, 0.04584, 0.04426, 0.04502, 0.04364, 0.0435, 0.0433, 0.04254, 0.043, 0.04228, 0.04178, 0.04162, 0.04174, 0.04138, 0.04158, 0.04178, 0.04206,
-0.04298, 0.04306, 0.04416, 0.04412, 0.0424, 0.04466, 0.04522, 0.04594, 0.04456, 0.04472, 0.04652, 0.04712, 0.04724, 0.04894, 0.05032, 0.05106, 0.05162, 0.05242,
-0.05478, 0.05588, 0.056, 0.05712, 0.05808, 0.05914, 0.0576, 0.0574, 0.05808, 0.05844, 
-//783
-0.12978, 0.15956, 0.21556, 0.29892, 0.409, 0.52776, 0.62306, 0.67562, 0.70126, 0.7098, 0.7164, 0.7216, 0.72668, 0.7306, 0.7344, 0.74156, 0.7494, 0.75328,
-0.75632, 0.7601, 0.75854, 0.75944, 0.75982, 0.76176, 0.76312, 0.76232, 0.76086, 0.75726, 0.75174, 0.74632, 0.73946, 0.73188, 0.72322, 0.71188, 0.70114, 0.68862,
-0.67352, 0.66402, 0.65478, 0.64966, 0.6416, 0.63598, 0.63026, 0.62208, 0.616, 0.6087, 0.60642, 0.60678, 0.60538, 0.60932, 0.61204, 0.60904, 0.61128, 0.61418,
-0.61654, 0.6184, 0.61656, 0.61318, 0.61176, 0.61324, 0.6133, 0.61634, 0.6189, 0.62322, 0.6268, 0.62874, 0.63042, 0.6324, 0.63454, 0.63974, 0.64276, 0.64882,
-0.65956, 0.66802, 0.67998, 0.68968, 0.69456, 0.70066, 0.70576, 0.70598, 0.7082, 
-//784
-0.14328, 0.17372, 0.23004, 0.308, 0.40212, 0.48756, 0.54274, 0.56342, 0.56864, 0.57106, 0.57446, 0.57552, 0.57494, 0.5741, 0.5756, 0.5755, 0.577, 0.57548,
-0.57566, 0.57692, 0.57466, 0.5733, 0.57302, 0.5729, 0.57296, 0.57154, 0.56996, 0.5681, 0.56464, 0.56076, 0.55848, 0.555, 0.54764, 0.5413, 0.53538, 0.52558,
-0.51594, 0.51118, 0.50028, 0.49242, 0.48578, 0.47736, 0.47052, 0.46208, 0.45678, 0.44888, 0.44552, 0.4437, 0.43978, 0.43778, 0.43632, 0.43534, 0.43422, 0.43334,
-0.43624, 0.43944, 0.4411, 0.44072, 0.44076, 0.44164, 0.43964, 0.43754, 0.43374, 0.43188, 0.42954, 0.42794, 0.42788, 0.43006, 0.4331, 0.44106, 0.4494, 0.45826,
-0.4705, 0.48022, 0.48876, 0.49512, 0.49666, 0.50036, 0.5024, 0.50012, 0.50308, 
-//785
-0.13202, 0.1586, 0.20544, 0.26604, 0.32854, 0.37244, 0.3949, 0.40142, 0.40384, 0.4045, 0.40544, 0.40554, 0.40688, 0.40794, 0.40736, 0.40756, 0.40862, 0.4086,
-0.40858, 0.40792, 0.40614, 0.40378, 0.40238, 0.40166, 0.402, 0.4013, 0.40058, 0.3982, 0.39652, 0.39512, 0.39224, 0.38868, 0.38594, 0.38186, 0.37792, 0.37312,
-0.36414, 0.36126, 0.35324, 0.34788, 0.34156, 0.33568, 0.3294, 0.32446, 0.31786, 0.3132, 0.30932, 0.30604, 0.30404, 0.3021, 0.30192, 0.30028, 0.29876, 0.29762,
-0.29824, 0.2982, 0.29908, 0.29912, 0.29912, 0.2986, 0.29694, 0.29456, 0.29608, 0.29586, 0.29262, 0.292, 0.29444, 0.2951, 0.29852, 0.30252, 0.30714, 0.314,
-0.31934, 0.32444, 0.33106, 0.33434, 0.33804, 0.33936, 0.33882, 0.33718, 0.33888, 
-//786
-0.10646, 0.13138, 0.16764, 0.2128, 0.25114, 0.27496, 0.287, 0.291, 0.28968, 0.28924, 0.28986, 0.29158, 0.29276, 0.2912, 0.29022, 0.29126, 0.29236, 0.29256,
-0.29336, 0.2935, 0.29306, 0.29198, 0.29112, 0.28974, 0.29008, 0.28774, 0.28632, 0.2845, 0.28254, 0.2811, 0.2787, 0.27514, 0.27244, 0.26826, 0.2662, 0.25994,
-0.25196, 0.25014, 0.24352, 0.23608, 0.23326, 0.22858, 0.22476, 0.22042, 0.21506, 0.21194, 0.20884, 0.2088, 0.2064, 0.20498, 0.20406, 0.20322, 0.20372, 0.2035,
-0.2046, 0.2057, 0.20648, 0.20548, 0.20658, 0.20656, 0.20542, 0.20346, 0.20094, 0.20038, 0.1983, 0.1977, 0.19716, 0.1986, 0.2027, 0.20474, 0.21124, 0.21766,
-0.22478, 0.23268, 0.23928, 0.24398, 0.24736, 0.2486, 0.25164, 0.24792, 0.2492, 
-//787
-0.115, 0.12776, 0.15338, 0.17756, 0.1967, 0.20518, 0.20974, 0.20988, 0.21068, 0.21038, 0.21158, 0.2117, 0.21128, 0.21158, 0.21094, 0.21054, 0.20986, 0.21074,
-0.2095, 0.20872, 0.2082, 0.207, 0.20576, 0.20546, 0.206, 0.20472, 0.2049, 0.20408, 0.2028, 0.20056, 0.1984, 0.1954, 0.19284, 0.1896, 0.1858, 0.18016, 0.17306,
-0.17278, 0.16842, 0.16428, 0.16158, 0.15846, 0.15464, 0.14992, 0.14764, 0.14486, 0.1417, 0.13964, 0.13802, 0.13732, 0.1358, 0.13482, 0.1346, 0.13458, 0.13546,
-0.13536, 0.1365, 0.13516, 0.13704, 0.13756, 0.1367, 0.13422, 0.13282, 0.13428, 0.13402, 0.13348, 0.13288, 0.1337, 0.13492, 0.13848, 0.14166, 0.14558, 0.15152,
-0.15604, 0.16084, 0.16446, 0.16728, 0.1693, 0.17006, 0.17076, 0.1703, 
-//788
-0.0892, 0.09668, 0.10822, 0.12126, 0.12986, 0.13228, 0.13416, 0.13318, 0.13406, 0.1348, 0.13486, 0.13582, 0.13536, 0.13524, 0.13578, 0.13492, 0.13574, 0.1354,
-0.13434, 0.1341, 0.1318, 0.13044, 0.13032, 0.13026, 0.13028, 0.12956, 0.12936, 0.12844, 0.12762, 0.1257, 0.12446, 0.12186, 0.12034, 0.11718, 0.1142, 0.1115,
-0.10478, 0.10522, 0.10192, 0.099, 0.09614, 0.094, 0.09062, 0.08714, 0.08522, 0.08314, 0.08284, 0.08164, 0.0818, 0.0808, 0.0808, 0.08012, 0.08026, 0.08146,
-0.08094, 0.08214, 0.08224, 0.08124, 0.08238, 0.08254, 0.08248, 0.08108, 0.08034, 0.08146, 0.08268, 0.08284, 0.0835, 0.08484, 0.08588, 0.08776, 0.08998, 0.0937,
-0.09666, 0.09896, 0.10264, 0.10374, 0.10498, 0.10686, 0.10854, 0.10854, 0.1091, 
-//789
-0.05746, 0.0637, 0.06896, 0.07308, 0.07588, 0.07696, 0.07882, 0.07912, 0.07876, 0.07962, 0.0788, 0.07932, 0.07902, 0.08048, 0.08008, 0.07992, 0.0816, 0.083,
-0.0841, 0.08416, 0.08452, 0.0847, 0.08258, 0.08404, 0.08252, 0.08198, 0.08136, 0.07958, 0.078, 0.0749, 0.0734, 0.07064, 0.06812, 0.06598, 0.06392, 0.06092,
-0.05572, 0.05642, 0.05574, 0.05502, 0.0532, 0.05264, 0.05148, 0.05066, 0.05168, 0.04922, 0.04954, 0.04926, 0.04972, 0.04874, 0.04868, 0.0498, 0.05068, 0.05082,
-0.05102, 0.05192, 0.05334, 0.05172, 0.05246, 0.05354, 0.0537, 0.05248, 0.05342, 0.05456, 0.05554, 0.05522, 0.05566, 0.05666, 0.05768, 0.05974, 0.06128, 0.06354,
-0.0673, 0.0691, 0.0727, 0.07534, 0.07714, 0.0786, 0.07812, 0.07766, 0.07882, 
-//790
-0.04882, 0.04854, 0.04954, 0.05, 0.05078, 0.05066, 0.05136, 0.05114, 0.05066, 0.05046, 0.05138, 0.05164, 0.05186, 0.05226, 0.0536, 0.05512, 0.05898, 0.06204,
-0.06518, 0.06792, 0.0704, 0.07172, 0.07162, 0.07046, 0.06936, 0.06756, 0.06386, 0.06022, 0.05592, 0.0528, 0.0493, 0.04642, 0.04448, 0.04236, 0.0417, 0.04038,
-0.03794, 0.03914, 0.0386, 0.03838, 0.03766, 0.03798, 0.03634, 0.03704, 0.03552, 0.03602, 0.03612, 0.03594, 0.03702, 0.03678, 0.03848, 0.03852, 0.03942, 0.03952,
-0.04054, 0.04128, 0.04114, 0.0396, 0.04156, 0.04248, 0.04222, 0.04162, 0.04192, 0.0427, 0.0447, 0.04494, 0.04736, 0.04914, 0.04986, 0.05122, 0.05264, 0.05366,
-0.0548, 0.05578, 0.05758, 0.05854, 0.05788, 0.05878, 0.05864, 0.05706, 0.05918, 
-//791
-0.12316, 0.15438, 0.20682, 0.28622, 0.37942, 0.47222, 0.533, 0.56196, 0.5745, 0.5821, 0.5878, 0.59478, 0.60086, 0.60518, 0.61178, 0.61588, 0.62058, 0.62496,
-0.6279, 0.63218, 0.63052, 0.63126, 0.63018, 0.62888, 0.62652, 0.62298, 0.61956, 0.61288, 0.60402, 0.59494, 0.58372, 0.57112, 0.55568, 0.53886, 0.52058, 0.49876,
-0.47616, 0.46084, 0.44286, 0.4282, 0.41456, 0.40218, 0.3913, 0.38078, 0.3703, 0.36178, 0.35628, 0.35282, 0.3488, 0.34752, 0.34624, 0.34606, 0.34834, 0.3506,
-0.35058, 0.3512, 0.35462, 0.35726, 0.35804, 0.3584, 0.35496, 0.35326, 0.34928, 0.34584, 0.34008, 0.33732, 0.33686, 0.33768, 0.3435, 0.35548, 0.3705, 0.38884,
-0.4136, 0.43862, 0.46284, 0.4841, 0.49924, 0.5089, 0.51576, 0.5162, 0.52046, 
-//792
-0.12692, 0.15718, 0.20668, 0.27186, 0.34498, 0.4023, 0.43472, 0.44592, 0.45378, 0.4569, 0.46112, 0.46364, 0.46698, 0.4698, 0.47064, 0.47184, 0.47636, 0.4775,
-0.4787, 0.47946, 0.47908, 0.47722, 0.47418, 0.47464, 0.4726, 0.47016, 0.46636, 0.46164, 0.45604, 0.44766, 0.4393, 0.42844, 0.41538, 0.40132, 0.38694, 0.3683,
-0.35028, 0.3348, 0.32084, 0.30912, 0.2978, 0.28924, 0.28004, 0.27224, 0.26522, 0.25922, 0.25652, 0.25328, 0.25062, 0.24862, 0.24924, 0.24694, 0.2469, 0.24748,
-0.25008, 0.25348, 0.25626, 0.25624, 0.25654, 0.2568, 0.25544, 0.25204, 0.2491, 0.24802, 0.24424, 0.24172, 0.24122, 0.24452, 0.24764, 0.25588, 0.26704, 0.282,
-0.3018, 0.321, 0.3433, 0.36192, 0.37598, 0.38204, 0.38662, 0.38808, 0.39294, 
-//793
-0.12308, 0.14978, 0.18786, 0.23584, 0.27574, 0.30312, 0.3158, 0.31864, 0.32112, 0.32286, 0.32578, 0.32782, 0.33032, 0.33148, 0.33312, 0.33582, 0.33826, 0.34016,
-0.3413, 0.33858, 0.33826, 0.33578, 0.3347, 0.33176, 0.33092, 0.32834, 0.32604, 0.32166, 0.31736, 0.3122, 0.30662, 0.3003, 0.29256, 0.28348, 0.27394, 0.25902,
-0.24384, 0.23348, 0.22174, 0.21338, 0.20578, 0.19902, 0.19294, 0.18566, 0.17998, 0.17626, 0.17208, 0.17062, 0.16902, 0.16692, 0.16534, 0.16376, 0.16452,
-0.16372, 0.16502, 0.16658, 0.1702, 0.16914, 0.1691, 0.17072, 0.16942, 0.16664, 0.1646, 0.16422, 0.16256, 0.16088, 0.16208, 0.1638, 0.16802, 0.17248, 0.18104,
-0.1914, 0.2024, 0.21514, 0.22818, 0.23932, 0.24736, 0.2524, 0.25572, 0.25696, 0.2606, 
-//794
-0.10556, 0.12288, 0.15012, 0.18066, 0.20176, 0.21454, 0.22192, 0.22368, 0.22626, 0.22802, 0.23072, 0.23232, 0.23432, 0.2364, 0.23666, 0.23792, 0.23898, 0.2413,
-0.24204, 0.24182, 0.24176, 0.23864, 0.23662, 0.23548, 0.23498, 0.23248, 0.23094, 0.22798, 0.22426, 0.21942, 0.21306, 0.20714, 0.20002, 0.19176, 0.18298, 0.1713,
-0.15726, 0.15048, 0.14348, 0.1354, 0.13024, 0.12608, 0.12358, 0.11862, 0.11454, 0.11224, 0.11008, 0.10932, 0.10764, 0.10744, 0.10684, 0.10662, 0.10532, 0.105,
-0.10618, 0.1079, 0.1082, 0.10742, 0.10952, 0.11134, 0.11026, 0.10754, 0.10572, 0.10432, 0.10402, 0.10302, 0.10486, 0.10644, 0.10948, 0.11434, 0.1219, 0.1282,
-0.13962, 0.15038, 0.16288, 0.17412, 0.18196, 0.18508, 0.18836, 0.18832, 0.18838, 
-//795
-0.09082, 0.09796, 0.11276, 0.1244, 0.13358, 0.1404, 0.1432, 0.14312, 0.14474, 0.14554, 0.14702, 0.14864, 0.15034, 0.15054, 0.15086, 0.15338, 0.15594, 0.15816,
-0.158, 0.15874, 0.15886, 0.1584, 0.15694, 0.15596, 0.15474, 0.15276, 0.1503, 0.14582, 0.14266, 0.13764, 0.1326, 0.12574, 0.11928, 0.1129, 0.10668, 0.09954,
-0.0912, 0.0876, 0.08258, 0.07926, 0.07614, 0.07388, 0.07252, 0.06922, 0.06928, 0.06672, 0.06718, 0.066, 0.06626, 0.06568, 0.06446, 0.06562, 0.0656, 0.06522,
-0.06604, 0.06756, 0.0672, 0.06582, 0.06858, 0.0695, 0.06996, 0.06828, 0.0682, 0.07008, 0.07006, 0.07006, 0.07136, 0.07194, 0.07344, 0.07526, 0.07782, 0.08112,
-0.08614, 0.09288, 0.09898, 0.1049, 0.11066, 0.11548, 0.11848, 0.11954, 0.119, 
-//796
-0.06158, 0.06124, 0.06518, 0.06684, 0.07038, 0.07202, 0.07202, 0.07396, 0.07534, 0.07642, 0.07912, 0.08248, 0.08594, 0.09098, 0.09502, 0.1, 0.10862, 0.11778,
-0.12678, 0.13306, 0.13644, 0.13762, 0.1359, 0.13068, 0.12502, 0.1164, 0.10784, 0.09742, 0.0879, 0.07992, 0.07324, 0.06658, 0.06186, 0.05662, 0.05302, 0.0483,
-0.043, 0.0462, 0.04476, 0.04396, 0.04284, 0.0432, 0.04232, 0.04142, 0.04198, 0.042, 0.04282, 0.04272, 0.04396, 0.04468, 0.04544, 0.04584, 0.04708, 0.04738,
-0.04902, 0.0487, 0.0489, 0.04734, 0.04782, 0.04954, 0.0492, 0.0472, 0.04936, 0.05196, 0.0526, 0.05432, 0.05488, 0.05674, 0.05758, 0.0589, 0.0612, 0.0635,
-0.06666, 0.06912, 0.07186, 0.07564, 0.07922, 0.08024, 0.0811, 0.08162, 0.08388, 
-//797
-0.13916, 0.16748, 0.21764, 0.28574, 0.35996, 0.42138, 0.4566, 0.4711, 0.48042, 0.48638, 0.49406, 0.50262, 0.5121, 0.5196, 0.5253, 0.53334, 0.54312, 0.55026,
-0.55602, 0.55886, 0.55952, 0.55806, 0.55574, 0.5513, 0.54672, 0.5402, 0.53192, 0.51942, 0.50788, 0.49088, 0.47376, 0.45468, 0.4336, 0.41072, 0.38716, 0.36078,
-0.33414, 0.31566, 0.29738, 0.28104, 0.2689, 0.25786, 0.25202, 0.23994, 0.233, 0.22472, 0.22016, 0.2173, 0.2156, 0.21516, 0.21294, 0.2124, 0.21242, 0.21446,
-0.21532, 0.21768, 0.22024, 0.21908, 0.21986, 0.2212, 0.22014, 0.2169, 0.21488, 0.2139, 0.21056, 0.20766, 0.20856, 0.21098, 0.21602, 0.22474, 0.2347, 0.25192,
-0.2736, 0.29668, 0.3256, 0.3499, 0.36754, 0.3817, 0.38888, 0.39148, 0.39644, 
-//798
-0.12686, 0.15138, 0.19092, 0.24068, 0.28562, 0.31814, 0.33608, 0.3418, 0.34708, 0.35268, 0.35596, 0.36264, 0.36946, 0.37684, 0.3822, 0.39044, 0.39808, 0.40456,
-0.40912, 0.41192, 0.41212, 0.411, 0.4081, 0.40402, 0.4009, 0.39382, 0.38608, 0.37704, 0.36576, 0.35194, 0.33694, 0.3214, 0.30578, 0.2868, 0.2684, 0.24812,
-0.2268, 0.2137, 0.20068, 0.18794, 0.1797, 0.17262, 0.16664, 0.15928, 0.15308, 0.14906, 0.14646, 0.14438, 0.14202, 0.14102, 0.13874, 0.1378, 0.13772, 0.13736,
-0.13808, 0.13878, 0.14036, 0.1405, 0.14136, 0.14268, 0.1412, 0.13884, 0.13936, 0.13814, 0.13624, 0.13386, 0.13438, 0.13568, 0.1387, 0.14324, 0.15058, 0.16134,
-0.17616, 0.1951, 0.21404, 0.23246, 0.24698, 0.25726, 0.26626, 0.26854, 0.27046, 
-//799
-0.10296, 0.1193, 0.14596, 0.17924, 0.20498, 0.22258, 0.23298, 0.23582, 0.24238, 0.24596, 0.25164, 0.259, 0.26464, 0.27138, 0.27562, 0.28024, 0.28662, 0.29292,
-0.2959, 0.29786, 0.29962, 0.29736, 0.2956, 0.2935, 0.29094, 0.2862, 0.279, 0.2715, 0.2606, 0.24804, 0.23456, 0.21924, 0.19978, 0.18206, 0.16626, 0.15084,
-0.1334, 0.1221, 0.11204, 0.10464, 0.10112, 0.09548, 0.0928, 0.0894, 0.0857, 0.08388, 0.08322, 0.08334, 0.08222, 0.08218, 0.08264, 0.08188, 0.08228, 0.08294,
-0.08412, 0.08382, 0.08498, 0.08432, 0.08438, 0.0856, 0.0863, 0.08426, 0.0841, 0.0859, 0.08624, 0.08602, 0.087, 0.08788, 0.09082, 0.09524, 0.10116, 0.10662,
-0.11814, 0.1315, 0.14772, 0.1626, 0.17602, 0.18862, 0.1942, 0.19772, 0.2002, 
-//800
-0.08932, 0.0924, 0.1069, 0.12016, 0.13002, 0.13824, 0.1435, 0.14566, 0.14826, 0.15276, 0.15794, 0.1653, 0.17336, 0.1789, 0.18684, 0.19476, 0.2052, 0.21548,
-0.22226, 0.22716, 0.2296, 0.22836, 0.22548, 0.22046, 0.2152, 0.20664, 0.19738, 0.18544, 0.17214, 0.1593, 0.14574, 0.1327, 0.11974, 0.1089, 0.09832, 0.08806,
-0.07634, 0.07398, 0.06892, 0.06514, 0.06354, 0.06114, 0.06104, 0.05894, 0.05848, 0.05802, 0.05752, 0.05802, 0.05698, 0.05724, 0.05722, 0.05716, 0.05772, 0.0587,
-0.05908, 0.05918, 0.0612, 0.06022, 0.0605, 0.062, 0.0624, 0.06086, 0.06072, 0.0626, 0.06216, 0.06326, 0.06498, 0.0662, 0.06794, 0.07098, 0.07488, 0.07768,
-0.08146, 0.08732, 0.09614, 0.10458, 0.1118, 0.11782, 0.1229, 0.12368, 0.12576, 
-//801
-0.05346, 0.05118, 0.05488, 0.05842, 0.06104, 0.06342, 0.06528, 0.0659, 0.0679, 0.069, 0.07172, 0.07712, 0.08386, 0.08904, 0.09588, 0.10618, 0.11836, 0.13374,
-0.1476, 0.1595, 0.16786, 0.16872, 0.1657, 0.1549, 0.14356, 0.12934, 0.11522, 0.09982, 0.08714, 0.0767, 0.06658, 0.0593, 0.05196, 0.04666, 0.04274, 0.03892,
-0.0322, 0.03412, 0.03364, 0.0327, 0.03198, 0.03154, 0.03228, 0.03216, 0.0333, 0.03316, 0.03286, 0.03366, 0.0342, 0.03402, 0.0335, 0.03476, 0.03392, 0.03472,
-0.03544, 0.03448, 0.03616, 0.03346, 0.0347, 0.03674, 0.03724, 0.0377, 0.03784, 0.04016, 0.04026, 0.04146, 0.04324, 0.04396, 0.04526, 0.04728, 0.04808, 0.04886,
-0.05202, 0.05392, 0.0589, 0.06256, 0.06764, 0.07024, 0.07278, 0.07386, 0.0752, 
-//802
-0.12314, 0.153, 0.20402, 0.2743, 0.34754, 0.41194, 0.44942, 0.46562, 0.47934, 0.49086, 0.50044, 0.51628, 0.53336, 0.54672, 0.56012, 0.5755, 0.59342, 0.6116,
-0.62454, 0.63356, 0.63886, 0.63828, 0.63396, 0.62482, 0.61404, 0.59772, 0.57914, 0.55556, 0.53118, 0.50422, 0.47684, 0.44856, 0.41958, 0.3884, 0.35904, 0.32752,
-0.2981, 0.27832, 0.25748, 0.24202, 0.22922, 0.21726, 0.2072, 0.19652, 0.18828, 0.1809, 0.17628, 0.1736, 0.17076, 0.16978, 0.1685, 0.1678, 0.16874, 0.17068,
-0.1731, 0.17658, 0.17952, 0.18038, 0.1817, 0.1813, 0.17974, 0.17656, 0.17242, 0.17008, 0.16688, 0.16392, 0.16428, 0.16778, 0.1708, 0.1792, 0.19228, 0.20798,
-0.23246, 0.26086, 0.2942, 0.32882, 0.35646, 0.37732, 0.3935, 0.39986, 0.40354, 
-//803
-0.1291, 0.15216, 0.19222, 0.2413, 0.28854, 0.32284, 0.34464, 0.3519, 0.36188, 0.37226, 0.37962, 0.39578, 0.41022, 0.42458, 0.4384, 0.45294, 0.47264, 0.49096,
-0.50372, 0.51354, 0.5169, 0.515, 0.50916, 0.50082, 0.48934, 0.47388, 0.45308, 0.43048, 0.40558, 0.37856, 0.3533, 0.32518, 0.2997, 0.27212, 0.2465, 0.22266,
-0.19876, 0.18478, 0.1703, 0.15796, 0.14942, 0.14246, 0.13618, 0.12926, 0.12374, 0.11998, 0.11688, 0.11656, 0.115, 0.11446, 0.11382, 0.11304, 0.11404, 0.11508,
-0.1158, 0.11796, 0.11982, 0.1175, 0.1188, 0.11948, 0.1185, 0.1149, 0.1143, 0.11402, 0.11278, 0.11216, 0.11318, 0.11432, 0.11844, 0.12322, 0.1294, 0.14002,
-0.15534, 0.17332, 0.19586, 0.22068, 0.24308, 0.26162, 0.27052, 0.27608, 0.28114, 
-//804
-0.10658, 0.12192, 0.14508, 0.17576, 0.20136, 0.22024, 0.22876, 0.23346, 0.24226, 0.25084, 0.25946, 0.27414, 0.28938, 0.30358, 0.31892, 0.33646, 0.35848,
-0.38108, 0.3981, 0.40816, 0.41394, 0.41198, 0.40542, 0.3937, 0.37886, 0.36142, 0.3397, 0.31554, 0.28972, 0.26416, 0.2406, 0.21714, 0.19536, 0.17356, 0.15534,
-0.13686, 0.11776, 0.10964, 0.10164, 0.09522, 0.09064, 0.08684, 0.084, 0.08028, 0.07786, 0.07632, 0.07516, 0.07434, 0.0733, 0.0731, 0.0723, 0.07274, 0.07338,
-0.07326, 0.07384, 0.07526, 0.07562, 0.0747, 0.07618, 0.07794, 0.07668, 0.07522, 0.07596, 0.07718, 0.07666, 0.07754, 0.07874, 0.07956, 0.08182, 0.08474, 0.08844,
-0.09246, 0.10094, 0.11244, 0.12634, 0.1428, 0.15894, 0.1735, 0.182, 0.18532, 0.19036, 
-//805
-0.079, 0.08214, 0.09368, 0.10722, 0.11738, 0.12566, 0.13392, 0.1357, 0.14148, 0.14868, 0.1536, 0.16574, 0.1786, 0.19332, 0.2059, 0.21832, 0.23954, 0.26116,
-0.2752, 0.2896, 0.29568, 0.29052, 0.27784, 0.26508, 0.24794, 0.2302, 0.211, 0.19086, 0.17174, 0.15194, 0.13488, 0.11948, 0.10556, 0.09294, 0.08296, 0.07298,
-0.0624, 0.06098, 0.05594, 0.05308, 0.05224, 0.05186, 0.04986, 0.04856, 0.04784, 0.04792, 0.0483, 0.04836, 0.04814, 0.05012, 0.04988, 0.04956, 0.0506, 0.0508,
-0.05108, 0.05056, 0.05054, 0.04902, 0.05022, 0.05216, 0.05238, 0.05016, 0.05084, 0.05238, 0.05464, 0.0542, 0.05712, 0.05782, 0.06104, 0.0628, 0.06532, 0.0673,
-0.07084, 0.07684, 0.08596, 0.09432, 0.10466, 0.11238, 0.1189, 0.1236, 0.1258, 
-//806
-0.1394, 0.17024, 0.227, 0.31144, 0.42196, 0.54172, 0.63816, 0.6937, 0.7165, 0.72502, 0.72942, 0.73304, 0.73752, 0.73914, 0.73918, 0.73996, 0.74226, 0.7434,
-0.74482, 0.74346, 0.74156, 0.73802, 0.73702, 0.73516, 0.7345, 0.73118, 0.7291, 0.72588, 0.71976, 0.71576, 0.71008, 0.7048, 0.69938, 0.69232, 0.68342, 0.6736,
-0.66196, 0.65798, 0.64926, 0.64094, 0.63494, 0.62804, 0.62306, 0.61616, 0.61044, 0.60412, 0.60184, 0.5969, 0.59344, 0.59208, 0.59186, 0.5938, 0.59466, 0.59724,
-0.59988, 0.6035, 0.60694, 0.60814, 0.60488, 0.60428, 0.59954, 0.59582, 0.59262, 0.5874, 0.58148, 0.57848, 0.577, 0.57678, 0.58248, 0.59136, 0.60296, 0.61478,
-0.63512, 0.65608, 0.67976, 0.70162, 0.71664, 0.72186, 0.73256, 0.73276, 0.73226, 
-//807
-0.13314, 0.16292, 0.2138, 0.29068, 0.3865, 0.47388, 0.52846, 0.55158, 0.5606, 0.56352, 0.56644, 0.56624, 0.5673, 0.57078, 0.5702, 0.56988, 0.5696, 0.5691,
-0.57026, 0.57078, 0.56576, 0.56134, 0.5604, 0.56276, 0.5627, 0.55852, 0.55602, 0.55272, 0.55006, 0.5462, 0.54538, 0.53904, 0.53504, 0.53086, 0.5256, 0.51742,
-0.50832, 0.50456, 0.49592, 0.48856, 0.48186, 0.47774, 0.47202, 0.46822, 0.4633, 0.45686, 0.45364, 0.45228, 0.45072, 0.45012, 0.45048, 0.4499, 0.45028, 0.45144,
-0.45402, 0.4565, 0.4592, 0.45852, 0.46112, 0.46148, 0.45858, 0.45518, 0.45418, 0.45298, 0.45034, 0.44922, 0.44862, 0.45212, 0.45562, 0.46074, 0.4683, 0.47716,
-0.48958, 0.496, 0.50742, 0.51638, 0.52106, 0.52556, 0.52854, 0.51988, 0.51928, 
-//808
-0.1338, 0.16278, 0.21102, 0.2775, 0.3438, 0.39422, 0.4203, 0.42942, 0.432, 0.4316, 0.43234, 0.43226, 0.43116, 0.42916, 0.42758, 0.4256, 0.42466, 0.42356,
-0.42238, 0.42118, 0.42002, 0.41796, 0.4159, 0.41522, 0.41436, 0.41414, 0.41364, 0.41138, 0.40916, 0.405, 0.40354, 0.39906, 0.39454, 0.3896, 0.38378, 0.37736,
-0.36928, 0.36474, 0.35784, 0.35094, 0.34548, 0.34074, 0.3382, 0.33388, 0.32932, 0.32754, 0.32518, 0.32414, 0.32226, 0.32086, 0.3212, 0.32006, 0.3205, 0.32112,
-0.3226, 0.32426, 0.32548, 0.32286, 0.32186, 0.31958, 0.31634, 0.31248, 0.30838, 0.3041, 0.30264, 0.30018, 0.3023, 0.30626, 0.31094, 0.31848, 0.32776, 0.33548,
-0.34506, 0.3524, 0.35926, 0.36482, 0.36554, 0.36718, 0.36924, 0.36654, 0.36888, 
-//809
-0.1281, 0.15384, 0.19046, 0.23876, 0.2777, 0.29982, 0.30974, 0.31324, 0.3133, 0.31194, 0.31122, 0.30884, 0.30786, 0.30592, 0.30442, 0.30326, 0.3023, 0.30198,
-0.30242, 0.30132, 0.30064, 0.29928, 0.29686, 0.2958, 0.29614, 0.29412, 0.2925, 0.2912, 0.2884, 0.28556, 0.28306, 0.28016, 0.27736, 0.27412, 0.26972, 0.2643,
-0.25648, 0.25188, 0.24714, 0.24252, 0.23914, 0.23648, 0.2339, 0.23092, 0.22796, 0.22716, 0.225, 0.22342, 0.22286, 0.2215, 0.22064, 0.21924, 0.21836, 0.21934,
-0.22034, 0.2214, 0.22134, 0.22014, 0.21936, 0.2193, 0.21696, 0.21278, 0.2104, 0.20956, 0.20596, 0.20514, 0.20656, 0.20824, 0.21264, 0.21818, 0.22348, 0.23022,
-0.23862, 0.24388, 0.2488, 0.25288, 0.2552, 0.25768, 0.25752, 0.25628, 0.25738, 
-//810
-0.10694, 0.12236, 0.14872, 0.17444, 0.19472, 0.20514, 0.20802, 0.20884, 0.20734, 0.20796, 0.20774, 0.20772, 0.20874, 0.20762, 0.2068, 0.20532, 0.20518, 0.20616,
-0.20432, 0.20382, 0.2028, 0.2009, 0.20004, 0.19854, 0.19764, 0.19738, 0.19538, 0.19344, 0.19256, 0.19148, 0.18922, 0.18736, 0.18478, 0.1823, 0.17816, 0.17434,
-0.16644, 0.16446, 0.15982, 0.15814, 0.15376, 0.15092, 0.14818, 0.14508, 0.14278, 0.14, 0.13904, 0.1369, 0.13576, 0.13602, 0.13692, 0.13446, 0.13524, 0.13638,
-0.13766, 0.13756, 0.13902, 0.13756, 0.13736, 0.13784, 0.13552, 0.13202, 0.13048, 0.13014, 0.12916, 0.12938, 0.13006, 0.13054, 0.13232, 0.13608, 0.14, 0.14362,
-0.15036, 0.15628, 0.16254, 0.16684, 0.17062, 0.17222, 0.17384, 0.17372, 0.17402, 
-//811
-0.09402, 0.10282, 0.11598, 0.1268, 0.13558, 0.13772, 0.13914, 0.13874, 0.1378, 0.1385, 0.1388, 0.13914, 0.1396, 0.13904, 0.13676, 0.13628, 0.1371, 0.13724,
-0.13628, 0.13548, 0.13518, 0.13282, 0.13192, 0.13028, 0.12968, 0.12816, 0.12646, 0.1262, 0.12538, 0.1242, 0.1235, 0.12124, 0.11854, 0.11504, 0.11368, 0.10846,
-0.10276, 0.1031, 0.10078, 0.09812, 0.09614, 0.09412, 0.09164, 0.09056, 0.08984, 0.0888, 0.08664, 0.08548, 0.0864, 0.08604, 0.086, 0.08662, 0.08742, 0.0885,
-0.08962, 0.09152, 0.09174, 0.0897, 0.0891, 0.0898, 0.08972, 0.08514, 0.08588, 0.08608, 0.08638, 0.08516, 0.08632, 0.08478, 0.08908, 0.09196, 0.09518, 0.09914,
-0.10364, 0.10844, 0.11238, 0.11582, 0.11984, 0.1209, 0.12224, 0.123, 0.1245, 
-//812
-0.06918, 0.074, 0.07634, 0.07996, 0.0818, 0.08258, 0.08312, 0.08172, 0.08208, 0.08242, 0.08216, 0.08322, 0.08364, 0.08392, 0.08386, 0.0848, 0.08588, 0.08722,
-0.08624, 0.08644, 0.0864, 0.08546, 0.08492, 0.08352, 0.08282, 0.082, 0.08016, 0.0791, 0.07704, 0.07488, 0.0727, 0.07084, 0.06868, 0.06728, 0.06454, 0.06188,
-0.0568, 0.05642, 0.05524, 0.05448, 0.05398, 0.0532, 0.05234, 0.05144, 0.0514, 0.05094, 0.0512, 0.05038, 0.04988, 0.04994, 0.05072, 0.05154, 0.05158, 0.05254,
-0.05278, 0.05326, 0.05422, 0.05308, 0.05444, 0.05494, 0.056, 0.05364, 0.05414, 0.05506, 0.05602, 0.05678, 0.05854, 0.0591, 0.06056, 0.06308, 0.066, 0.06816,
-0.07034, 0.07338, 0.076, 0.07818, 0.08052, 0.08086, 0.08154, 0.0805, 0.08156, 
-//813
-0.05292, 0.05324, 0.05372, 0.05336, 0.05356, 0.0542, 0.055, 0.05658, 0.0536, 0.0531, 0.05294, 0.0534, 0.05456, 0.05436, 0.05732, 0.05982, 0.0637, 0.06882,
-0.07394, 0.0809, 0.08668, 0.08868, 0.08756, 0.08496, 0.07882, 0.0729, 0.06652, 0.06012, 0.05456, 0.04962, 0.04622, 0.04312, 0.04254, 0.04106, 0.0392, 0.0384,
-0.0356, 0.03634, 0.03658, 0.03738, 0.03714, 0.0369, 0.03804, 0.03658, 0.0384, 0.03814, 0.03944, 0.03982, 0.0403, 0.04072, 0.04112, 0.04122, 0.04124, 0.04184,
-0.04202, 0.04306, 0.0433, 0.04296, 0.04364, 0.04536, 0.04598, 0.04474, 0.04558, 0.0469, 0.0474, 0.04962, 0.05128, 0.0521, 0.05414, 0.0558, 0.0579, 0.0588,
-0.06108, 0.06136, 0.06312, 0.06446, 0.06446, 0.06436, 0.06396, 0.06466, 0.06478, 
-//814
-0.1361, 0.16892, 0.22232, 0.3049, 0.40688, 0.51266, 0.58546, 0.61944, 0.63364, 0.64134, 0.64572, 0.65076, 0.65666, 0.65768, 0.65944, 0.65982, 0.66048, 0.66032,
-0.65992, 0.65894, 0.65608, 0.6533, 0.65194, 0.6483, 0.64644, 0.64292, 0.6363, 0.63042, 0.62156, 0.60774, 0.5963, 0.584, 0.56824, 0.55358, 0.53606, 0.516,
-0.49596, 0.48176, 0.46622, 0.45152, 0.43982, 0.42944, 0.42126, 0.41166, 0.40378, 0.39708, 0.39, 0.38726, 0.38482, 0.3816, 0.38062, 0.38042, 0.38128, 0.38394,
-0.38752, 0.3919, 0.39462, 0.39468, 0.39356, 0.39178, 0.38762, 0.3817, 0.37674, 0.37024, 0.36264, 0.35578, 0.354, 0.3558, 0.36162, 0.37256, 0.38648, 0.40618,
-0.43552, 0.4685, 0.50896, 0.54982, 0.57762, 0.59732, 0.6117, 0.61932, 0.62202, 
-//815
-0.1243, 0.15028, 0.19926, 0.26538, 0.34078, 0.40594, 0.4415, 0.4518, 0.4574, 0.45914, 0.45932, 0.46254, 0.46366, 0.46288, 0.4622, 0.46148, 0.46092, 0.46088,
-0.4615, 0.46012, 0.45798, 0.45626, 0.45338, 0.45188, 0.4496, 0.44698, 0.4427, 0.43624, 0.4309, 0.42344, 0.41662, 0.40956, 0.4004, 0.38916, 0.37574, 0.36058,
-0.34324, 0.32948, 0.31798, 0.30654, 0.29714, 0.29038, 0.28292, 0.2751, 0.26842, 0.26228, 0.2583, 0.25488, 0.2541, 0.25306, 0.25298, 0.25368, 0.25464, 0.25782,
-0.2596, 0.26352, 0.26636, 0.26528, 0.26414, 0.2628, 0.25894, 0.25316, 0.24864, 0.24514, 0.24084, 0.23778, 0.23898, 0.24216, 0.24914, 0.25916, 0.27138, 0.28702,
-0.30904, 0.33124, 0.35398, 0.37446, 0.38676, 0.39764, 0.40374, 0.40498, 0.40796, 
-//816
-0.1155, 0.13866, 0.1797, 0.22974, 0.27836, 0.3105, 0.32594, 0.3293, 0.33158, 0.3318, 0.3328, 0.3353, 0.33572, 0.3344, 0.33506, 0.33478, 0.33374, 0.33274,
-0.33186, 0.33012, 0.32706, 0.3267, 0.32296, 0.32122, 0.32016, 0.3176, 0.31466, 0.31034, 0.30508, 0.29974, 0.29418, 0.2869, 0.28028, 0.27188, 0.26264, 0.25092,
-0.23606, 0.2292, 0.2187, 0.21106, 0.20438, 0.1976, 0.19344, 0.18778, 0.18286, 0.17838, 0.17602, 0.17318, 0.17178, 0.17004, 0.17028, 0.17054, 0.17138, 0.17354,
-0.1763, 0.17864, 0.18104, 0.18002, 0.17874, 0.178, 0.17506, 0.17124, 0.16614, 0.16368, 0.16128, 0.15912, 0.15918, 0.16294, 0.16882, 0.17518, 0.1851, 0.19702,
-0.21334, 0.23014, 0.24838, 0.2604, 0.27028, 0.27688, 0.27904, 0.28178, 0.2841, 
-//817
-0.10808, 0.12384, 0.15444, 0.18658, 0.21368, 0.22896, 0.23584, 0.2373, 0.23816, 0.2394, 0.24014, 0.24126, 0.2419, 0.24256, 0.24234, 0.24122, 0.24136, 0.24026,
-0.2388, 0.2376, 0.23598, 0.2335, 0.23122, 0.23054, 0.2289, 0.22654, 0.2242, 0.22092, 0.218, 0.21226, 0.20768, 0.2027, 0.19522, 0.18734, 0.18056, 0.16954,
-0.1582, 0.15388, 0.14602, 0.13802, 0.13376, 0.1298, 0.1253, 0.12144, 0.11968, 0.11748, 0.11524, 0.11384, 0.11412, 0.11338, 0.11226, 0.1137, 0.11388, 0.11466,
-0.11586, 0.11736, 0.11862, 0.11612, 0.1172, 0.11664, 0.11414, 0.10986, 0.10796, 0.10868, 0.10708, 0.10696, 0.10752, 0.10936, 0.11198, 0.11604, 0.12258, 0.13154,
-0.14166, 0.15286, 0.1683, 0.17854, 0.18862, 0.19548, 0.1985, 0.1991, 0.20016, 
-//818
-0.08358, 0.09962, 0.11468, 0.13094, 0.14786, 0.15422, 0.15768, 0.15812, 0.16022, 0.16148, 0.16194, 0.1656, 0.16724, 0.16512, 0.16582, 0.16574, 0.16506, 0.16344,
-0.16284, 0.1612, 0.15882, 0.15796, 0.15552, 0.15294, 0.15126, 0.1494, 0.1478, 0.14428, 0.14096, 0.13652, 0.1316, 0.12836, 0.12114, 0.11522, 0.1082, 0.10152,
-0.09348, 0.08878, 0.08368, 0.08104, 0.07842, 0.07574, 0.07432, 0.07038, 0.07086, 0.06846, 0.0695, 0.0686, 0.06878, 0.06716, 0.06798, 0.06734, 0.06796, 0.06728,
-0.06802, 0.06968, 0.06882, 0.06804, 0.06908, 0.07018, 0.06926, 0.06686, 0.066, 0.06612, 0.0662, 0.0667, 0.06678, 0.06894, 0.07106, 0.0746, 0.0778, 0.08276,
-0.09112, 0.10108, 0.11048, 0.12046, 0.12902, 0.13532, 0.13832, 0.14124, 0.14238, 
-//819
-0.0598, 0.06588, 0.06758, 0.07316, 0.07664, 0.0803, 0.0813, 0.08202, 0.08266, 0.08536, 0.08668, 0.09094, 0.09398, 0.0977, 0.1014, 0.10742, 0.112, 0.11758,
-0.12238, 0.12406, 0.12502, 0.12338, 0.1221, 0.11854, 0.1135, 0.10828, 0.10208, 0.09484, 0.08796, 0.08068, 0.074, 0.06686, 0.0615, 0.05522, 0.0518, 0.04752,
-0.04286, 0.04338, 0.04294, 0.04274, 0.04212, 0.04112, 0.04082, 0.04164, 0.04198, 0.04318, 0.0427, 0.04314, 0.04308, 0.04396, 0.04398, 0.04354, 0.04516, 0.04526,
-0.04516, 0.0453, 0.04556, 0.04518, 0.04592, 0.04716, 0.04734, 0.0464, 0.04698, 0.04942, 0.05038, 0.05198, 0.05358, 0.05462, 0.05628, 0.05868, 0.0595, 0.0611,
-0.06454, 0.06596, 0.06934, 0.07252, 0.07696, 0.08068, 0.08224, 0.0839, 0.08632, 
-//820
-0.11962, 0.14606, 0.19306, 0.26462, 0.3487, 0.42726, 0.47908, 0.49638, 0.50608, 0.51316, 0.51742, 0.52618, 0.53146, 0.53474, 0.53468, 0.53364, 0.53652, 0.53722,
-0.5377, 0.53544, 0.53276, 0.52748, 0.52542, 0.52318, 0.51898, 0.512, 0.50286, 0.49082, 0.47886, 0.46496, 0.44752, 0.43054, 0.40934, 0.38792, 0.3658, 0.34168,
-0.31878, 0.30144, 0.28382, 0.26872, 0.25686, 0.2476, 0.23954, 0.23026, 0.2235, 0.21728, 0.21366, 0.21106, 0.21066, 0.2099, 0.209, 0.20804, 0.2091, 0.21278,
-0.21646, 0.22046, 0.22166, 0.22036, 0.21994, 0.2175, 0.21368, 0.20948, 0.20492, 0.20196, 0.19854, 0.1961, 0.19698, 0.20062, 0.20706, 0.21862, 0.2312, 0.25258,
-0.279, 0.3114, 0.35014, 0.38426, 0.41778, 0.43874, 0.45474, 0.46422, 0.46864, 
-//821
-0.1173, 0.14072, 0.18404, 0.23822, 0.2942, 0.33592, 0.36028, 0.36716, 0.37128, 0.3764, 0.37822, 0.38316, 0.3885, 0.38984, 0.38808, 0.3877, 0.38882, 0.38826,
-0.38738, 0.3853, 0.3839, 0.38034, 0.37652, 0.37372, 0.37054, 0.36384, 0.35724, 0.34968, 0.3391, 0.32752, 0.31682, 0.30272, 0.28808, 0.27152, 0.25524, 0.23638,
-0.21636, 0.20482, 0.19254, 0.18162, 0.1723, 0.16696, 0.16152, 0.15482, 0.14904, 0.14528, 0.14168, 0.1396, 0.13836, 0.13806, 0.1377, 0.13832, 0.13922, 0.14032,
-0.1424, 0.1454, 0.14726, 0.14818, 0.14742, 0.14622, 0.14352, 0.13966, 0.13664, 0.13388, 0.12984, 0.12814, 0.12888, 0.132, 0.1365, 0.1448, 0.15402, 0.17006,
-0.19024, 0.21408, 0.24374, 0.27124, 0.29462, 0.31126, 0.3228, 0.32772, 0.33328, 
-//822
-0.10296, 0.12246, 0.15604, 0.19488, 0.22916, 0.25232, 0.26478, 0.26602, 0.27074, 0.27274, 0.27424, 0.27818, 0.27994, 0.28156, 0.28142, 0.2803, 0.27972, 0.27906,
-0.27838, 0.27598, 0.27376, 0.26972, 0.26544, 0.26434, 0.26178, 0.25768, 0.25018, 0.24196, 0.2335, 0.22578, 0.21648, 0.20554, 0.19328, 0.18154, 0.17038, 0.15712,
-0.13908, 0.13236, 0.12318, 0.11556, 0.10984, 0.10606, 0.10206, 0.09882, 0.0956, 0.09326, 0.09086, 0.09008, 0.09048, 0.09074, 0.09016, 0.09006, 0.09106, 0.09192,
-0.09282, 0.0959, 0.09658, 0.09586, 0.09666, 0.09556, 0.09362, 0.09042, 0.08914, 0.08946, 0.08886, 0.08684, 0.08776, 0.09098, 0.0946, 0.10088, 0.1071, 0.11758,
-0.12966, 0.14484, 0.16256, 0.17766, 0.18876, 0.19658, 0.20392, 0.2037, 0.20644, 
-//823
-0.0775, 0.08686, 0.10228, 0.12064, 0.13886, 0.15084, 0.15932, 0.16254, 0.1674, 0.17184, 0.17524, 0.1847, 0.19166, 0.19738, 0.20012, 0.20202, 0.2061, 0.20818,
-0.21016, 0.20964, 0.20878, 0.20594, 0.20202, 0.19926, 0.19452, 0.18988, 0.18196, 0.17254, 0.16272, 0.15042, 0.13958, 0.12728, 0.11656, 0.10466, 0.0949, 0.0847,
-0.07292, 0.06946, 0.06506, 0.06202, 0.0596, 0.05814, 0.05688, 0.05522, 0.05432, 0.05462, 0.05528, 0.05456, 0.0544, 0.0536, 0.05498, 0.05406, 0.05468, 0.05522,
-0.05546, 0.0568, 0.05528, 0.05398, 0.05656, 0.05688, 0.05756, 0.05554, 0.05476, 0.05678, 0.0582, 0.05862, 0.05998, 0.06128, 0.06394, 0.06704, 0.06998, 0.07394,
-0.07996, 0.08828, 0.09984, 0.1157, 0.13044, 0.14468, 0.15398, 0.1605, 0.16542, 
-//824
-0.0522, 0.05316, 0.05782, 0.06202, 0.06722, 0.06916, 0.07272, 0.07464, 0.07686, 0.08158, 0.08462, 0.09078, 0.09706, 0.10554, 0.11352, 0.12394, 0.13808, 0.15446,
-0.16762, 0.17812, 0.18364, 0.1818, 0.1725, 0.1614, 0.14768, 0.13124, 0.11756, 0.10168, 0.08828, 0.07684, 0.06724, 0.0594, 0.05334, 0.04844, 0.04472, 0.04068,
-0.03688, 0.03774, 0.03566, 0.03574, 0.03476, 0.03428, 0.03434, 0.03322, 0.03436, 0.03378, 0.03402, 0.0352, 0.03608, 0.0363, 0.03796, 0.03842, 0.0378, 0.03936,
-0.03918, 0.03968, 0.03978, 0.03662, 0.03906, 0.0404, 0.04058, 0.0387, 0.03778, 0.04072, 0.04254, 0.04336, 0.04456, 0.04696, 0.0481, 0.04988, 0.05162, 0.05496,
-0.05708, 0.06052, 0.06564, 0.07046, 0.07682, 0.07992, 0.08376, 0.0859, 0.08714, 
-//825
-0.12278, 0.1513, 0.20212, 0.2736, 0.36356, 0.44852, 0.5075, 0.52782, 0.54678, 0.56438, 0.57294, 0.59378, 0.61344, 0.62766, 0.63916, 0.6475, 0.659, 0.66638,
-0.67054, 0.6712, 0.6682, 0.65882, 0.65198, 0.64162, 0.6279, 0.61056, 0.58998, 0.5672, 0.53988, 0.51148, 0.48418, 0.45568, 0.42528, 0.39404, 0.36518, 0.33444,
-0.30348, 0.28114, 0.26228, 0.24492, 0.23372, 0.22268, 0.2141, 0.20614, 0.19854, 0.19096, 0.18758, 0.1848, 0.18434, 0.18396, 0.18306, 0.18214, 0.184, 0.18632,
-0.18966, 0.19334, 0.19574, 0.19548, 0.19514, 0.19406, 0.19126, 0.18622, 0.18332, 0.17942, 0.17438, 0.17136, 0.17228, 0.17518, 0.1816, 0.1933, 0.20668, 0.2273,
-0.25722, 0.29498, 0.34044, 0.38766, 0.43092, 0.46958, 0.49716, 0.51608, 0.52948, 
-//826
-0.11794, 0.14192, 0.18724, 0.24564, 0.31016, 0.35656, 0.38446, 0.39118, 0.40274, 0.4166, 0.42022, 0.4364, 0.45298, 0.46126, 0.46962, 0.47392, 0.48144, 0.48672,
-0.48812, 0.4874, 0.4837, 0.47852, 0.47334, 0.46658, 0.45786, 0.4457, 0.43248, 0.41526, 0.39432, 0.37308, 0.3537, 0.32928, 0.30512, 0.28138, 0.25794, 0.23284,
-0.2103, 0.19664, 0.18146, 0.17046, 0.1623, 0.15464, 0.14872, 0.14142, 0.13736, 0.1318, 0.13012, 0.12938, 0.12842, 0.12738, 0.12732, 0.12778, 0.12668, 0.12886,
-0.1308, 0.1321, 0.13364, 0.13258, 0.13292, 0.13188, 0.13024, 0.12686, 0.12384, 0.1231, 0.12014, 0.11986, 0.12068, 0.12202, 0.12474, 0.132, 0.1396, 0.15124,
-0.17082, 0.19608, 0.23002, 0.26702, 0.30312, 0.33118, 0.35204, 0.36402, 0.3755, 
-//827
-0.10068, 0.12226, 0.15262, 0.18976, 0.2245, 0.25126, 0.26656, 0.27102, 0.27978, 0.2902, 0.2975, 0.3127, 0.32564, 0.33562, 0.3425, 0.35048, 0.35698, 0.36118,
-0.36402, 0.36614, 0.36538, 0.3579, 0.35244, 0.34586, 0.33708, 0.32624, 0.31182, 0.29442, 0.27544, 0.25596, 0.23656, 0.21584, 0.19564, 0.17592, 0.15782, 0.14042,
-0.12156, 0.11438, 0.10348, 0.09712, 0.09252, 0.08828, 0.08598, 0.08374, 0.08184, 0.0794, 0.07816, 0.0788, 0.07812, 0.07812, 0.07822, 0.07846, 0.07932, 0.0792,
-0.0801, 0.0811, 0.08082, 0.07972, 0.08028, 0.0814, 0.08076, 0.07842, 0.07784, 0.07926, 0.08012, 0.07964, 0.0805, 0.08266, 0.08364, 0.08786, 0.09162, 0.09778,
-0.1085, 0.12198, 0.14378, 0.16736, 0.19178, 0.21278, 0.22908, 0.23916, 0.2476, 
-//828
-0.07608, 0.08256, 0.09548, 0.11062, 0.12606, 0.13614, 0.14566, 0.1484, 0.15498, 0.16358, 0.17178, 0.18722, 0.2028, 0.21928, 0.2318, 0.24734, 0.26696, 0.28712,
-0.30154, 0.30828, 0.31114, 0.30606, 0.29496, 0.2794, 0.25994, 0.24058, 0.21696, 0.19368, 0.17036, 0.14936, 0.1315, 0.1147, 0.09972, 0.08782, 0.07706, 0.06836,
-0.05734, 0.05672, 0.05454, 0.0514, 0.04942, 0.04962, 0.04728, 0.04554, 0.04658, 0.04626, 0.04502, 0.04544, 0.04724, 0.04596, 0.04654, 0.04804, 0.0483, 0.04916,
-0.05012, 0.05026, 0.04976, 0.04814, 0.04964, 0.05184, 0.0517, 0.0486, 0.04922, 0.04986, 0.0513, 0.0523, 0.0544, 0.05576, 0.05728, 0.05996, 0.06296, 0.06612,
-0.07128, 0.0781, 0.08674, 0.09988, 0.11324, 0.12594, 0.1343, 0.13962, 0.14534, 
-//829
-0.10494, 0.1287, 0.1703, 0.22658, 0.28588, 0.33882, 0.37202, 0.38236, 0.39876, 0.41204, 0.4214, 0.44524, 0.4695, 0.48522, 0.49826, 0.50888, 0.51966, 0.5322,
-0.53648, 0.53734, 0.53352, 0.5236, 0.51228, 0.50154, 0.48626, 0.46518, 0.44406, 0.4189, 0.39266, 0.36466, 0.33618, 0.3096, 0.28268, 0.25516, 0.23084, 0.20572,
-0.1828, 0.16882, 0.15482, 0.14434, 0.13686, 0.1304, 0.1247, 0.11964, 0.11422, 0.10956, 0.10714, 0.1058, 0.10432, 0.10372, 0.1042, 0.1041, 0.10516, 0.1063,
-0.10986, 0.11224, 0.11398, 0.11308, 0.11308, 0.11304, 0.11104, 0.10784, 0.1053, 0.10546, 0.10224, 0.10112, 0.10238, 0.10332, 0.10566, 0.11236, 0.1201, 0.1335,
-0.15234, 0.17932, 0.21594, 0.2574, 0.2971, 0.3316, 0.36, 0.37684, 0.39184, 
-//830
-0.08152, 0.09904, 0.1251, 0.16016, 0.19294, 0.22148, 0.24088, 0.2482, 0.26134, 0.27662, 0.28838, 0.3096, 0.33244, 0.35146, 0.36762, 0.38378, 0.40174, 0.41886,
-0.43082, 0.4367, 0.43612, 0.42862, 0.4193, 0.4053, 0.38816, 0.3663, 0.34238, 0.31556, 0.2871, 0.25948, 0.23322, 0.20774, 0.18414, 0.1616, 0.14276, 0.12404,
-0.107, 0.09814, 0.08978, 0.08242, 0.07818, 0.07436, 0.0725, 0.068, 0.0668, 0.06634, 0.06532, 0.06618, 0.06488, 0.06494, 0.06492, 0.066, 0.06598, 0.06658,
-0.06784, 0.0689, 0.06912, 0.06784, 0.06936, 0.07024, 0.06912, 0.06722, 0.0663, 0.06706, 0.0667, 0.0666, 0.06712, 0.06978, 0.07216, 0.07616, 0.08242, 0.09018,
-0.1042, 0.12262, 0.14932, 0.18004, 0.21204, 0.24336, 0.26796, 0.28392, 0.2965, 
-//831
-0.14114, 0.17348, 0.23094, 0.31458, 0.4248, 0.54266, 0.64004, 0.69316, 0.71618, 0.72432, 0.72702, 0.72766, 0.73018, 0.7311, 0.73134, 0.73204, 0.73306, 0.7339,
-0.73502, 0.73434, 0.73144, 0.72778, 0.72518, 0.72448, 0.72296, 0.721, 0.71804, 0.7135, 0.70934, 0.70496, 0.7028, 0.70048, 0.69848, 0.69564, 0.69322, 0.68752,
-0.68274, 0.6794, 0.67628, 0.6736, 0.67156, 0.67258, 0.67162, 0.66942, 0.66812, 0.6665, 0.66486, 0.6629, 0.66078, 0.65934, 0.65924, 0.66056, 0.66206, 0.66628,
-0.66644, 0.66878, 0.6726, 0.67094, 0.66862, 0.66964, 0.66566, 0.66648, 0.66556, 0.66396, 0.66144, 0.66108, 0.66048, 0.66236, 0.66464, 0.67356, 0.6795, 0.68786,
-0.69806, 0.70258, 0.71368, 0.7211, 0.72366, 0.73092, 0.73274, 0.72674, 0.73032, 
-//832
-0.14048, 0.16812, 0.22286, 0.2993, 0.39038, 0.4748, 0.5273, 0.5464, 0.55274, 0.55352, 0.55512, 0.5546, 0.5549, 0.55344, 0.55182, 0.55066, 0.54976, 0.54916,
-0.54938, 0.54796, 0.54666, 0.54302, 0.54126, 0.54062, 0.54038, 0.53888, 0.53726, 0.53548, 0.53324, 0.53084, 0.52862, 0.52584, 0.52252, 0.51872, 0.51666,
-0.51226, 0.50694, 0.5051, 0.50088, 0.49868, 0.49612, 0.49484, 0.49308, 0.49152, 0.49022, 0.48882, 0.48684, 0.48654, 0.48562, 0.4852, 0.48676, 0.48712, 0.48578,
-0.48772, 0.48854, 0.48716, 0.4871, 0.48666, 0.48386, 0.48066, 0.47796, 0.47552, 0.47308, 0.47386, 0.47294, 0.4726, 0.4735, 0.47548, 0.47832, 0.48258, 0.4841,
-0.48858, 0.49448, 0.49408, 0.4962, 0.4991, 0.50032, 0.49916, 0.50406, 0.50396, 0.50414, 
-//833
-0.13382, 0.16174, 0.21234, 0.27344, 0.33528, 0.37568, 0.39676, 0.40216, 0.40286, 0.40126, 0.40134, 0.40156, 0.4001, 0.3988, 0.39714, 0.39628, 0.39516, 0.39362,
-0.39342, 0.39208, 0.39056, 0.38786, 0.38558, 0.38328, 0.38308, 0.38264, 0.38196, 0.38084, 0.37892, 0.37684, 0.3762, 0.37512, 0.37298, 0.37172, 0.37042, 0.3684,
-0.36496, 0.36416, 0.3631, 0.36122, 0.35904, 0.35796, 0.35602, 0.3551, 0.35268, 0.3512, 0.35026, 0.3498, 0.34776, 0.34544, 0.34432, 0.34334, 0.3416, 0.34064,
-0.33948, 0.33868, 0.33894, 0.337, 0.33716, 0.33632, 0.33538, 0.33448, 0.33386, 0.33286, 0.3313, 0.33136, 0.33188, 0.33096, 0.33078, 0.33204, 0.33326, 0.33408,
-0.33486, 0.33438, 0.3363, 0.33634, 0.337, 0.33602, 0.33606, 0.3341, 0.33462, 
-//834
-0.12862, 0.15322, 0.18964, 0.23046, 0.2629, 0.28118, 0.28902, 0.29028, 0.29134, 0.28946, 0.2883, 0.28758, 0.28662, 0.28606, 0.28396, 0.28296, 0.2821, 0.2815,
-0.2808, 0.27984, 0.27796, 0.27706, 0.27564, 0.27496, 0.27508, 0.27424, 0.27338, 0.27206, 0.2711, 0.27006, 0.26928, 0.26844, 0.26636, 0.26544, 0.26438, 0.26284,
-0.26066, 0.26086, 0.2599, 0.25906, 0.25668, 0.25484, 0.25448, 0.25226, 0.25066, 0.24838, 0.24732, 0.24576, 0.24448, 0.24366, 0.24274, 0.24124, 0.2397, 0.23826,
-0.23774, 0.23642, 0.23582, 0.23582, 0.23584, 0.23554, 0.23492, 0.23408, 0.2331, 0.23304, 0.23272, 0.23264, 0.23254, 0.2321, 0.23192, 0.23258, 0.23204, 0.23234,
-0.23226, 0.23128, 0.2319, 0.23142, 0.23102, 0.22994, 0.23042, 0.2301, 0.23124, 
-//835
-0.11054, 0.12598, 0.14996, 0.17244, 0.18746, 0.19472, 0.19804, 0.19858, 0.19774, 0.1973, 0.19614, 0.19558, 0.19514, 0.19424, 0.19302, 0.19202, 0.1914, 0.19098,
-0.19016, 0.18968, 0.1889, 0.18814, 0.18734, 0.18654, 0.18608, 0.1858, 0.185, 0.18378, 0.18276, 0.1818, 0.18056, 0.17936, 0.17886, 0.17754, 0.17644, 0.17484,
-0.173, 0.17276, 0.1717, 0.17024, 0.16922, 0.16838, 0.16764, 0.16672, 0.16598, 0.16484, 0.16404, 0.163, 0.16194, 0.16148, 0.16082, 0.1606, 0.16, 0.15994, 0.1591,
-0.15858, 0.15796, 0.15702, 0.15622, 0.15548, 0.15386, 0.1528, 0.151, 0.15142, 0.15188, 0.15234, 0.15292, 0.1547, 0.15554, 0.15764, 0.15918, 0.16078, 0.16218,
-0.16392, 0.16352, 0.16372, 0.16508, 0.16506, 0.16648, 0.16548, 0.1667, 
-//836
-0.08616, 0.09532, 0.10746, 0.11788, 0.12396, 0.12594, 0.12744, 0.12722, 0.12746, 0.1299, 0.12372, 0.1248, 0.12494, 0.12454, 0.12426, 0.12274, 0.12224, 0.12162,
-0.12128, 0.12146, 0.12114, 0.12032, 0.11968, 0.11864, 0.11868, 0.1182, 0.11766, 0.117, 0.11624, 0.11526, 0.11474, 0.11376, 0.11302, 0.1121, 0.11144, 0.11104,
-0.10902, 0.1086, 0.10788, 0.10712, 0.10654, 0.10572, 0.10604, 0.10522, 0.10472, 0.10332, 0.10256, 0.1028, 0.1023, 0.10166, 0.10172, 0.10158, 0.10124, 0.10142,
-0.10154, 0.10162, 0.10142, 0.10098, 0.10084, 0.10032, 0.09992, 0.09908, 0.0988, 0.09958, 0.09938, 0.09918, 0.10044, 0.10168, 0.1033, 0.10496, 0.10668, 0.10734,
-0.11016, 0.1098, 0.11022, 0.11114, 0.11128, 0.11316, 0.11294, 0.11216, 0.11262, 
-//837
-0.07058, 0.07338, 0.0769, 0.07938, 0.0818, 0.08288, 0.08276, 0.08168, 0.08094, 0.08122, 0.07966, 0.07892, 0.0792, 0.07882, 0.07714, 0.0765, 0.07648, 0.07662,
-0.07562, 0.07594, 0.07582, 0.07534, 0.07384, 0.07372, 0.073, 0.07308, 0.0731, 0.07196, 0.07028, 0.06988, 0.06968, 0.06884, 0.06712, 0.06612, 0.06582, 0.06498,
-0.06304, 0.0631, 0.06264, 0.06218, 0.06184, 0.0612, 0.06148, 0.06156, 0.06202, 0.06122, 0.06014, 0.06072, 0.06104, 0.05974, 0.06094, 0.0607, 0.06084, 0.06012,
-0.06102, 0.06044, 0.05966, 0.05812, 0.05866, 0.0602, 0.06014, 0.0595, 0.06006, 0.06156, 0.06238, 0.06242, 0.0634, 0.06406, 0.0661, 0.06722, 0.0683, 0.0697,
-0.07242, 0.0733, 0.0748, 0.07528, 0.07734, 0.07714, 0.07696, 0.07642, 0.07782, 
-//838
-0.0532, 0.0534, 0.05478, 0.05502, 0.05634, 0.05596, 0.05666, 0.05686, 0.05668, 0.05694, 0.05768, 0.05634, 0.05768, 0.05728, 0.05726, 0.05672, 0.05634, 0.05638,
-0.0559, 0.055, 0.05498, 0.05428, 0.05452, 0.0536, 0.05242, 0.05274, 0.05296, 0.05278, 0.05288, 0.0516, 0.05212, 0.05132, 0.05034, 0.05058, 0.0494, 0.04832,
-0.0455, 0.04598, 0.045, 0.04586, 0.0447, 0.04452, 0.0436, 0.04326, 0.04474, 0.04368, 0.04394, 0.04384, 0.04438, 0.04506, 0.04458, 0.0451, 0.04556, 0.0448,
-0.04464, 0.04486, 0.0447, 0.04372, 0.04462, 0.04526, 0.04508, 0.04464, 0.04506, 0.04678, 0.04838, 0.04802, 0.04926, 0.05028, 0.05084, 0.0519, 0.05292, 0.05348,
-0.05508, 0.05558, 0.05648, 0.05574, 0.05722, 0.0579, 0.05822, 0.05794, 0.05806, 
-//839
-0.14002, 0.17092, 0.22632, 0.30968, 0.41988, 0.54144, 0.64516, 0.70794, 0.73562, 0.74546, 0.75296, 0.75482, 0.75838, 0.75876, 0.75948, 0.7614, 0.76168, 0.7633,
-0.76468, 0.76344, 0.76192, 0.75832, 0.75618, 0.75378, 0.7533, 0.7498, 0.74622, 0.74072, 0.73508, 0.72916, 0.72412, 0.71674, 0.70878, 0.69938, 0.68964, 0.67822,
-0.66752, 0.65904, 0.65276, 0.64778, 0.64452, 0.6433, 0.6427, 0.63908, 0.63586, 0.63264, 0.63166, 0.63326, 0.6351, 0.6406, 0.64614, 0.65164, 0.65768, 0.66276,
-0.66786, 0.67056, 0.6721, 0.66942, 0.66496, 0.66232, 0.65822, 0.65382, 0.6501, 0.6521, 0.65328, 0.65592, 0.65978, 0.66298, 0.66702, 0.67354, 0.68212, 0.6892,
-0.69972, 0.70786, 0.71876, 0.72548, 0.73366, 0.74156, 0.74792, 0.74866, 0.75646, 
-//840
-0.14202, 0.17104, 0.22382, 0.3016, 0.39374, 0.4796, 0.53412, 0.55774, 0.5651, 0.56612, 0.56508, 0.56356, 0.5651, 0.56358, 0.56004, 0.5601, 0.561, 0.56036,
-0.55926, 0.558, 0.55484, 0.5532, 0.54988, 0.54976, 0.54814, 0.54806, 0.54682, 0.5433, 0.53858, 0.53398, 0.53108, 0.52644, 0.52092, 0.51476, 0.50788, 0.5007,
-0.4901, 0.48062, 0.47408, 0.46968, 0.46802, 0.46644, 0.46522, 0.46206, 0.46006, 0.4565, 0.456, 0.4571, 0.45794, 0.45994, 0.46492, 0.46794, 0.4712, 0.47396,
-0.47416, 0.47478, 0.47458, 0.47258, 0.46974, 0.46664, 0.46176, 0.45818, 0.4548, 0.4558, 0.45566, 0.45684, 0.45904, 0.46302, 0.46528, 0.4712, 0.47468, 0.48094,
-0.4873, 0.49068, 0.49542, 0.49884, 0.50428, 0.5041, 0.50716, 0.50704, 0.50882, 
-//841
-0.13802, 0.1654, 0.2158, 0.27782, 0.34516, 0.39198, 0.41472, 0.4217, 0.4244, 0.4243, 0.42264, 0.42334, 0.42072, 0.41946, 0.42002, 0.41774, 0.41748, 0.41634,
-0.41502, 0.41478, 0.41282, 0.40942, 0.40786, 0.40734, 0.40634, 0.40378, 0.40198, 0.39952, 0.39704, 0.39344, 0.38978, 0.38572, 0.38064, 0.37668, 0.37254,
-0.36582, 0.35726, 0.35288, 0.34778, 0.3443, 0.3413, 0.33978, 0.33706, 0.33438, 0.33392, 0.329, 0.32724, 0.3284, 0.32882, 0.3307, 0.33334, 0.33534, 0.3364,
-0.33788, 0.33848, 0.3386, 0.33812, 0.333, 0.33048, 0.32822, 0.32244, 0.31894, 0.31796, 0.31694, 0.31766, 0.31934, 0.3221, 0.32472, 0.32802, 0.33, 0.3321, 0.335,
-0.3389, 0.33988, 0.34214, 0.3438, 0.34776, 0.34726, 0.34926, 0.35182, 0.34982, 
-//842
-0.12738, 0.15302, 0.19146, 0.23714, 0.27506, 0.29672, 0.30832, 0.3103, 0.31136, 0.3088, 0.30934, 0.30774, 0.30886, 0.30898, 0.3053, 0.30344, 0.30212, 0.30074,
-0.29998, 0.30002, 0.2982, 0.2952, 0.2927, 0.29296, 0.2928, 0.2907, 0.28986, 0.29018, 0.28854, 0.28558, 0.28302, 0.27874, 0.2757, 0.27156, 0.2712, 0.25974,
-0.25042, 0.24832, 0.24446, 0.2414, 0.23764, 0.23598, 0.2336, 0.23244, 0.2303, 0.22924, 0.22782, 0.2287, 0.22816, 0.22948, 0.23082, 0.23204, 0.23326, 0.23404,
-0.2341, 0.2331, 0.23168, 0.22854, 0.22676, 0.2261, 0.22144, 0.21604, 0.21552, 0.21572, 0.21612, 0.21838, 0.2203, 0.22234, 0.22618, 0.22902, 0.23156, 0.2339,
-0.23796, 0.23964, 0.24218, 0.24396, 0.246, 0.24554, 0.24702, 0.24556, 0.24846, 
-//843
-0.1191, 0.13432, 0.16014, 0.18582, 0.20424, 0.2141, 0.21754, 0.21736, 0.2173, 0.21658, 0.21658, 0.2166, 0.21634, 0.21522, 0.21468, 0.2133, 0.21212, 0.21176,
-0.21016, 0.2098, 0.20828, 0.20596, 0.20366, 0.20312, 0.20134, 0.19972, 0.1996, 0.19538, 0.19292, 0.19094, 0.18836, 0.1861, 0.18404, 0.18038, 0.17818, 0.17322,
-0.16788, 0.16684, 0.16268, 0.16208, 0.15992, 0.15828, 0.15862, 0.15596, 0.15464, 0.15286, 0.15222, 0.15244, 0.15134, 0.15316, 0.15486, 0.15676, 0.1577, 0.15808,
-0.15854, 0.15822, 0.15818, 0.1557, 0.15456, 0.15334, 0.15142, 0.14856, 0.14656, 0.14814, 0.14774, 0.14874, 0.14978, 0.15264, 0.15298, 0.15626, 0.15884, 0.16122,
-0.16412, 0.16628, 0.16834, 0.1685, 0.1706, 0.17098, 0.17112, 0.1717, 0.17238, 
-//844
-0.09394, 0.10918, 0.12062, 0.13246, 0.14006, 0.14268, 0.14472, 0.14354, 0.14444, 0.14304, 0.14336, 0.14332, 0.14328, 0.14196, 0.14178, 0.14128, 0.13934,
-0.13854, 0.13856, 0.13808, 0.13692, 0.13598, 0.13372, 0.13256, 0.13126, 0.12992, 0.12832, 0.1267, 0.1263, 0.12486, 0.1236, 0.12202, 0.1196, 0.11728, 0.11466,
-0.11126, 0.10612, 0.10558, 0.10172, 0.09916, 0.09822, 0.0958, 0.09612, 0.0945, 0.09368, 0.09246, 0.0925, 0.0921, 0.09048, 0.09032, 0.08924, 0.09054, 0.09106,
-0.09064, 0.09198, 0.0913, 0.09162, 0.09018, 0.0906, 0.09062, 0.08932, 0.08654, 0.08718, 0.08864, 0.08746, 0.08648, 0.0876, 0.0905, 0.09246, 0.09466, 0.09708,
-0.10108, 0.1055, 0.10972, 0.11524, 0.11674, 0.11724, 0.11856, 0.11956, 0.11964, 0.1182, 
-//845
-0.06606, 0.0732, 0.07764, 0.08286, 0.08672, 0.08864, 0.0893, 0.08948, 0.08984, 0.08968, 0.08802, 0.08744, 0.08846, 0.08772, 0.08704, 0.08616, 0.08586, 0.08492,
-0.08464, 0.08422, 0.08378, 0.08266, 0.08204, 0.08128, 0.08056, 0.07952, 0.0787, 0.07822, 0.07678, 0.0752, 0.0726, 0.0713, 0.06948, 0.06764, 0.06572, 0.0633,
-0.05968, 0.05856, 0.05784, 0.05634, 0.05596, 0.05584, 0.05496, 0.05484, 0.0545, 0.05356, 0.0531, 0.05326, 0.05374, 0.0522, 0.05316, 0.05272, 0.05224, 0.05364,
-0.05284, 0.05316, 0.05286, 0.05152, 0.0532, 0.05396, 0.0545, 0.05314, 0.05378, 0.05482, 0.05466, 0.05434, 0.05592, 0.05606, 0.05892, 0.06088, 0.06226, 0.06494,
-0.06826, 0.07054, 0.07446, 0.07812, 0.08166, 0.08276, 0.0839, 0.08492, 0.0851, 
-//846
-0.06134, 0.0643, 0.06428, 0.06588, 0.0677, 0.06826, 0.06948, 0.06858, 0.06906, 0.0695, 0.06994, 0.07142, 0.07292, 0.07288, 0.07352, 0.07372, 0.0749, 0.07624,
-0.0768, 0.07726, 0.07694, 0.07604, 0.0739, 0.07414, 0.07196, 0.06894, 0.06818, 0.06656, 0.0643, 0.0617, 0.0596, 0.05752, 0.05518, 0.05306, 0.0508, 0.04796,
-0.04518, 0.04658, 0.04544, 0.04538, 0.04456, 0.04436, 0.04394, 0.04372, 0.04438, 0.04346, 0.04416, 0.04422, 0.04384, 0.04424, 0.04484, 0.0447, 0.04484, 0.04576,
-0.04644, 0.04636, 0.0474, 0.04598, 0.04708, 0.04718, 0.04782, 0.04756, 0.04776, 0.04898, 0.0499, 0.05092, 0.05184, 0.05306, 0.05422, 0.05524, 0.05678, 0.0579,
-0.06004, 0.06232, 0.0658, 0.0673, 0.0697, 0.07198, 0.07244, 0.07204, 0.07342, 
-//847
-0.13182, 0.15896, 0.21508, 0.29458, 0.39874, 0.50492, 0.58244, 0.62144, 0.63472, 0.64114, 0.64522, 0.64954, 0.65242, 0.65526, 0.65764, 0.65896, 0.66042,
-0.65956, 0.65878, 0.65684, 0.6537, 0.65144, 0.6466, 0.64352, 0.63954, 0.6341, 0.62798, 0.61762, 0.60532, 0.59054, 0.57664, 0.56002, 0.54134, 0.5217, 0.50122,
-0.47988, 0.45856, 0.4433, 0.43228, 0.4243, 0.41894, 0.4149, 0.412, 0.40726, 0.4038, 0.40028, 0.39986, 0.40222, 0.4092, 0.41718, 0.42754, 0.43728, 0.44546,
-0.45256, 0.4587, 0.46126, 0.46268, 0.45904, 0.45242, 0.4441, 0.43544, 0.42834, 0.42586, 0.42528, 0.42806, 0.43248, 0.43772, 0.44434, 0.45036, 0.45762, 0.46742,
-0.47998, 0.49598, 0.50724, 0.52346, 0.53674, 0.5508, 0.5607, 0.57638, 0.5771, 0.5885, 
-//848
-0.13468, 0.16152, 0.21108, 0.2804, 0.35812, 0.42388, 0.46274, 0.4759, 0.47866, 0.48134, 0.48302, 0.48552, 0.48716, 0.4872, 0.48608, 0.48446, 0.484, 0.48238,
-0.48196, 0.47892, 0.47498, 0.47118, 0.46862, 0.4672, 0.46414, 0.4593, 0.4536, 0.44658, 0.43932, 0.43076, 0.42108, 0.4105, 0.3983, 0.38372, 0.37044, 0.35624,
-0.34044, 0.33054, 0.32186, 0.31384, 0.30946, 0.30594, 0.3028, 0.29754, 0.29556, 0.29214, 0.29248, 0.29192, 0.29304, 0.29738, 0.30394, 0.30768, 0.31294, 0.31766,
-0.32112, 0.32458, 0.32622, 0.32252, 0.31864, 0.31354, 0.30768, 0.30098, 0.2973, 0.29596, 0.29554, 0.29704, 0.29982, 0.30196, 0.30552, 0.31164, 0.3184, 0.32618,
-0.33756, 0.34696, 0.35902, 0.36736, 0.37632, 0.38318, 0.38832, 0.39448, 0.4006, 
-//849
-0.13014, 0.156, 0.19732, 0.25082, 0.29946, 0.33258, 0.34956, 0.35404, 0.35658, 0.3562, 0.35642, 0.3559, 0.3561, 0.3574, 0.35592, 0.35598, 0.35636, 0.3548,
-0.3531, 0.3519, 0.35034, 0.34716, 0.34376, 0.34126, 0.33974, 0.33608, 0.331, 0.32606, 0.31896, 0.31102, 0.3039, 0.29496, 0.2842, 0.27328, 0.26152, 0.24872,
-0.2356, 0.2268, 0.2198, 0.21514, 0.21154, 0.20926, 0.20726, 0.205, 0.2026, 0.20032, 0.20016, 0.20042, 0.20382, 0.20682, 0.212, 0.21762, 0.2226, 0.22632,
-0.22964, 0.23134, 0.23128, 0.22862, 0.22574, 0.22274, 0.2168, 0.21176, 0.20932, 0.21002, 0.2105, 0.21232, 0.21642, 0.21996, 0.22426, 0.22922, 0.23524, 0.24196,
-0.25114, 0.25912, 0.26744, 0.2749, 0.27926, 0.28336, 0.2884, 0.29342, 0.29452, 
-//850
-0.115, 0.13272, 0.16568, 0.19702, 0.22324, 0.23666, 0.24416, 0.24592, 0.2449, 0.24538, 0.24556, 0.246, 0.24882, 0.2476, 0.24886, 0.24684, 0.2462, 0.24638,
-0.24566, 0.24432, 0.24236, 0.2412, 0.2355, 0.23386, 0.23228, 0.22796, 0.22412, 0.2192, 0.214, 0.20592, 0.19954, 0.19234, 0.18534, 0.1769, 0.16762, 0.15852,
-0.14654, 0.14234, 0.13692, 0.13454, 0.13274, 0.13166, 0.12988, 0.1282, 0.1261, 0.12392, 0.1245, 0.12548, 0.12668, 0.12944, 0.13206, 0.13618, 0.1372, 0.14062,
-0.1439, 0.14422, 0.14528, 0.14096, 0.14038, 0.13788, 0.13404, 0.13064, 0.1292, 0.12964, 0.13162, 0.13332, 0.13642, 0.1385, 0.14308, 0.1462, 0.14882, 0.15486,
-0.1598, 0.16502, 0.17146, 0.17588, 0.18112, 0.1861, 0.19062, 0.19628, 0.19552, 
-//851
-0.09728, 0.10666, 0.1258, 0.1436, 0.15818, 0.16542, 0.16872, 0.16986, 0.17028, 0.17176, 0.1718, 0.17398, 0.17504, 0.17476, 0.17382, 0.17302, 0.1715, 0.1705,
-0.16862, 0.16666, 0.16448, 0.15996, 0.15634, 0.15434, 0.15156, 0.14736, 0.14454, 0.13984, 0.13592, 0.13144, 0.12756, 0.1231, 0.11946, 0.11542, 0.10934, 0.10358,
-0.096, 0.09326, 0.08954, 0.0854, 0.08228, 0.0807, 0.07876, 0.07698, 0.07616, 0.0728, 0.073, 0.07208, 0.07208, 0.07228, 0.07254, 0.07358, 0.0736, 0.07484,
-0.07592, 0.07752, 0.07752, 0.07682, 0.07666, 0.07782, 0.07656, 0.07396, 0.07446, 0.0744, 0.07384, 0.07388, 0.07472, 0.07658, 0.07868, 0.082, 0.08652, 0.09102,
-0.09784, 0.10712, 0.11674, 0.12704, 0.13344, 0.13956, 0.14208, 0.14298, 0.14634, 
-//852
-0.06936, 0.07294, 0.07674, 0.08274, 0.08702, 0.08926, 0.09246, 0.0928, 0.09398, 0.0965, 0.09936, 0.1023, 0.10698, 0.10892, 0.11142, 0.11362, 0.11578, 0.11952,
-0.12028, 0.12018, 0.1192, 0.1172, 0.11498, 0.11126, 0.1088, 0.10414, 0.0991, 0.09386, 0.0868, 0.08172, 0.07542, 0.07024, 0.06508, 0.06044, 0.05688, 0.05256,
-0.04676, 0.0494, 0.04864, 0.04734, 0.04706, 0.04698, 0.04616, 0.04558, 0.0462, 0.04648, 0.04742, 0.04804, 0.04838, 0.04752, 0.04972, 0.05114, 0.05174, 0.0518,
-0.0531, 0.05316, 0.05342, 0.05192, 0.05288, 0.05422, 0.05382, 0.05206, 0.05376, 0.05674, 0.057, 0.05836, 0.0609, 0.06212, 0.06412, 0.06554, 0.06758, 0.06948,
-0.07272, 0.07474, 0.07874, 0.08338, 0.0885, 0.09378, 0.09638, 0.09908, 0.1002, 
-//853
-0.0485, 0.04696, 0.04748, 0.05102, 0.0504, 0.05076, 0.05086, 0.05182, 0.0524, 0.05468, 0.05704, 0.05996, 0.06388, 0.07102, 0.07748, 0.09076, 0.11046, 0.13312,
-0.15184, 0.1632, 0.16592, 0.15636, 0.14024, 0.12164, 0.10376, 0.08748, 0.07492, 0.06458, 0.05844, 0.05214, 0.04862, 0.04638, 0.0448, 0.04242, 0.04062, 0.03852,
-0.03678, 0.03974, 0.04056, 0.04054, 0.04008, 0.04022, 0.0398, 0.04002, 0.04026, 0.04008, 0.03954, 0.03964, 0.03976, 0.04008, 0.03952, 0.0386, 0.03966, 0.04038,
-0.04062, 0.0413, 0.042, 0.04134, 0.04266, 0.04404, 0.04454, 0.04372, 0.04436, 0.04632, 0.04752, 0.0483, 0.04818, 0.0492, 0.04916, 0.04992, 0.05164, 0.0533,
-0.056, 0.05742, 0.0609, 0.06268, 0.06616, 0.06776, 0.06968, 0.06944, 0.07026, 
-//854
-0.14098, 0.16928, 0.22144, 0.29546, 0.37952, 0.45496, 0.50232, 0.52206, 0.5299, 0.5351, 0.54096, 0.54438, 0.54924, 0.55572, 0.55898, 0.56116, 0.5601, 0.56082,
-0.56056, 0.55946, 0.55548, 0.55124, 0.54424, 0.5384, 0.53242, 0.52376, 0.51352, 0.50054, 0.48492, 0.4662, 0.44722, 0.42696, 0.40592, 0.38366, 0.36086, 0.3377,
-0.31846, 0.30216, 0.2908, 0.28312, 0.27768, 0.27256, 0.2688, 0.26362, 0.25926, 0.25552, 0.2551, 0.25562, 0.25992, 0.26506, 0.2723, 0.28004, 0.28528, 0.29078,
-0.29544, 0.2988, 0.2995, 0.29696, 0.2932, 0.28668, 0.2784, 0.27118, 0.26732, 0.26896, 0.27076, 0.27448, 0.2791, 0.28338, 0.28772, 0.29444, 0.30164, 0.31308,
-0.32726, 0.34312, 0.35986, 0.37464, 0.38698, 0.39978, 0.4121, 0.42318, 0.43442, 
-//855
-0.12384, 0.14968, 0.19178, 0.2499, 0.31096, 0.35864, 0.38386, 0.39192, 0.39448, 0.39666, 0.39964, 0.40166, 0.40526, 0.40772, 0.4087, 0.40932, 0.40932, 0.40822,
-0.40656, 0.40396, 0.40018, 0.3947, 0.39, 0.38398, 0.37776, 0.37054, 0.36124, 0.35026, 0.33862, 0.3247, 0.31038, 0.29512, 0.27916, 0.26204, 0.2464, 0.22808,
-0.21034, 0.2012, 0.19208, 0.18456, 0.18166, 0.1782, 0.17442, 0.16972, 0.16748, 0.1636, 0.16222, 0.16338, 0.1651, 0.16824, 0.17306, 0.1779, 0.18276, 0.18556,
-0.18808, 0.1908, 0.19208, 0.18928, 0.1869, 0.18232, 0.17784, 0.17298, 0.16908, 0.17, 0.17094, 0.1738, 0.1773, 0.18032, 0.18448, 0.18864, 0.19482, 0.20296,
-0.21314, 0.22262, 0.2322, 0.24156, 0.24946, 0.2549, 0.26122, 0.26394, 0.26748, 
-//856
-0.10452, 0.132, 0.16398, 0.20332, 0.23816, 0.2633, 0.27558, 0.27872, 0.283, 0.28766, 0.29056, 0.2948, 0.30136, 0.30194, 0.30312, 0.2998, 0.29894, 0.29768,
-0.29658, 0.29302, 0.28832, 0.2826, 0.2766, 0.2713, 0.265, 0.25692, 0.2494, 0.24026, 0.23028, 0.22126, 0.2135, 0.20284, 0.19472, 0.18478, 0.17392, 0.16088,
-0.14788, 0.14118, 0.13376, 0.12662, 0.1219, 0.11818, 0.11492, 0.11142, 0.10988, 0.10614, 0.10504, 0.10398, 0.10258, 0.10272, 0.10262, 0.10372, 0.10526, 0.10754,
-0.10924, 0.11094, 0.11182, 0.11102, 0.11134, 0.1113, 0.1092, 0.1059, 0.1055, 0.10444, 0.1027, 0.10076, 0.10282, 0.10486, 0.10778, 0.11246, 0.12054, 0.1315,
-0.1474, 0.16766, 0.19434, 0.21956, 0.23808, 0.25386, 0.26348, 0.26934, 0.2737, 
-//857
-0.0957, 0.1106, 0.13134, 0.15362, 0.17216, 0.1852, 0.19286, 0.19384, 0.19712, 0.20146, 0.2029, 0.20802, 0.2133, 0.21408, 0.21368, 0.21448, 0.21402, 0.21342,
-0.21228, 0.21072, 0.20742, 0.20314, 0.19788, 0.19206, 0.18722, 0.18062, 0.1744, 0.16608, 0.1574, 0.14822, 0.14066, 0.1313, 0.12312, 0.11378, 0.10588, 0.096,
-0.08598, 0.08414, 0.07958, 0.0763, 0.07362, 0.07156, 0.07004, 0.06886, 0.06594, 0.06478, 0.06474, 0.064, 0.06382, 0.06318, 0.06428, 0.06486, 0.06472, 0.06536,
-0.06746, 0.067, 0.06778, 0.06668, 0.06796, 0.0689, 0.06846, 0.06654, 0.06544, 0.06578, 0.06526, 0.06712, 0.06706, 0.06848, 0.07082, 0.07398, 0.0775, 0.08246,
-0.08988, 0.10034, 0.11318, 0.129, 0.14194, 0.15312, 0.16112, 0.16534, 0.16924, 
-//858
-0.05908, 0.0629, 0.06784, 0.0713, 0.0771, 0.08142, 0.08434, 0.08426, 0.08738, 0.09042, 0.09172, 0.1008, 0.1094, 0.117, 0.1249, 0.13424, 0.14724, 0.15956, 0.169,
-0.17408, 0.1745, 0.17034, 0.16022, 0.1492, 0.13514, 0.12106, 0.1067, 0.09322, 0.0818, 0.07138, 0.0635, 0.05672, 0.05242, 0.04832, 0.0451, 0.04284, 0.03894,
-0.04014, 0.03778, 0.03778, 0.03778, 0.03834, 0.03854, 0.03912, 0.03878, 0.03946, 0.04128, 0.04174, 0.0421, 0.04244, 0.04346, 0.04398, 0.04424, 0.04418, 0.04418,
-0.0437, 0.04334, 0.0416, 0.04308, 0.0449, 0.04516, 0.0435, 0.04554, 0.04704, 0.04836, 0.04896, 0.05126, 0.05312, 0.05484, 0.05784, 0.05924, 0.06224, 0.06444,
-0.06884, 0.0722, 0.07928, 0.08604, 0.09232, 0.09622, 0.09916, 0.10218, 
-//859
-0.13958, 0.16704, 0.2195, 0.29342, 0.38738, 0.47856, 0.54286, 0.57198, 0.58822, 0.60146, 0.61092, 0.62734, 0.64734, 0.65958, 0.66714, 0.67058, 0.67282, 0.6729,
-0.66924, 0.66446, 0.6558, 0.64486, 0.63316, 0.61978, 0.60528, 0.58766, 0.56682, 0.54324, 0.51802, 0.49062, 0.4632, 0.43526, 0.40802, 0.37992, 0.35334, 0.32704,
-0.30324, 0.2858, 0.27218, 0.26166, 0.25424, 0.248, 0.24232, 0.23634, 0.231, 0.22596, 0.22354, 0.22284, 0.22256, 0.22502, 0.2282, 0.23166, 0.23478, 0.23974,
-0.24318, 0.24672, 0.24946, 0.2486, 0.24544, 0.2405, 0.23476, 0.22894, 0.22472, 0.22138, 0.21888, 0.21724, 0.21764, 0.22092, 0.22644, 0.23388, 0.24466, 0.26004,
-0.28338, 0.31198, 0.34916, 0.38728, 0.41966, 0.45108, 0.47762, 0.50006, 0.52264, 
-//860
-0.13176, 0.15796, 0.20328, 0.26232, 0.3245, 0.37224, 0.40102, 0.41438, 0.42102, 0.42832, 0.43586, 0.44308, 0.4555, 0.46546, 0.47342, 0.47896, 0.48436, 0.48856,
-0.48952, 0.48886, 0.48416, 0.47786, 0.46914, 0.45902, 0.44784, 0.43298, 0.41544, 0.39492, 0.37344, 0.34918, 0.3261, 0.30128, 0.2777, 0.25426, 0.23254, 0.21094,
-0.19384, 0.18186, 0.17186, 0.16534, 0.1611, 0.15732, 0.1542, 0.14998, 0.14644, 0.14364, 0.1419, 0.14268, 0.14494, 0.14908, 0.15334, 0.15872, 0.16344, 0.1675,
-0.17148, 0.17394, 0.17602, 0.1745, 0.17144, 0.16694, 0.16114, 0.15726, 0.15456, 0.15502, 0.15662, 0.1589, 0.16274, 0.16698, 0.17048, 0.17718, 0.18554, 0.19646,
-0.21126, 0.2264, 0.24484, 0.26378, 0.28102, 0.29556, 0.30884, 0.32116, 0.33276, 
-//861
-0.1135, 0.13454, 0.16698, 0.20792, 0.24586, 0.27202, 0.28862, 0.2959, 0.30122, 0.30646, 0.3158, 0.3234, 0.33502, 0.3451, 0.3531, 0.36164, 0.36776, 0.37354,
-0.3756, 0.37468, 0.37262, 0.36558, 0.35686, 0.3457, 0.33432, 0.31984, 0.30288, 0.28458, 0.26312, 0.24304, 0.2222, 0.20166, 0.18296, 0.16536, 0.15006, 0.13464,
-0.11958, 0.11316, 0.10802, 0.10368, 0.10142, 0.09898, 0.0975, 0.09602, 0.09394, 0.09222, 0.09154, 0.09166, 0.09246, 0.09512, 0.0978, 0.09994, 0.10258, 0.10448,
-0.10622, 0.1067, 0.10766, 0.10644, 0.10558, 0.10446, 0.10256, 0.09854, 0.09856, 0.09966, 0.10064, 0.10308, 0.10386, 0.10728, 0.10918, 0.11248, 0.11818, 0.12462,
-0.13486, 0.14412, 0.15734, 0.17078, 0.18298, 0.19478, 0.20562, 0.2139, 0.22064, 
-//862
-0.07866, 0.08632, 0.102, 0.11876, 0.13584, 0.1496, 0.15986, 0.1612, 0.16918, 0.17854, 0.18478, 0.203, 0.222, 0.23604, 0.24628, 0.25668, 0.27088, 0.281, 0.2847,
-0.28518, 0.28154, 0.27454, 0.26342, 0.25128, 0.23732, 0.22096, 0.20188, 0.18264, 0.16436, 0.14618, 0.12944, 0.11556, 0.10326, 0.09302, 0.08358, 0.07492,
-0.06616, 0.06456, 0.06084, 0.05896, 0.05598, 0.05486, 0.05308, 0.05204, 0.05092, 0.04998, 0.05046, 0.05048, 0.05084, 0.05058, 0.05148, 0.05166, 0.0526, 0.05284,
-0.05318, 0.05418, 0.05446, 0.05288, 0.054, 0.05468, 0.05474, 0.05322, 0.05412, 0.05426, 0.05542, 0.05576, 0.05732, 0.05922, 0.06124, 0.06482, 0.06738, 0.0709,
-0.07538, 0.08234, 0.09316, 0.10776, 0.12636, 0.14424, 0.16008, 0.16972, 0.17846, 
-//863
-0.1253, 0.15088, 0.19668, 0.258, 0.32666, 0.38816, 0.42972, 0.43792, 0.4561, 0.47536, 0.4853, 0.51846, 0.55214, 0.57082, 0.57996, 0.58374, 0.58544, 0.58596,
-0.58298, 0.57492, 0.5627, 0.54854, 0.53284, 0.51768, 0.5006, 0.47856, 0.45416, 0.426, 0.39776, 0.36936, 0.34036, 0.31296, 0.28644, 0.261, 0.23712, 0.21304,
-0.19212, 0.179, 0.16664, 0.1578, 0.1508, 0.14442, 0.1407, 0.13446, 0.12984, 0.1268, 0.12268, 0.12106, 0.1196, 0.11812, 0.11804, 0.11826, 0.11846, 0.12002,
-0.1219, 0.12362, 0.12478, 0.12564, 0.12498, 0.12386, 0.12178, 0.11944, 0.11678, 0.11496, 0.11166, 0.11002, 0.11106, 0.1108, 0.11422, 0.11892, 0.12604, 0.13496,
-0.14988, 0.17414, 0.20886, 0.25576, 0.3088, 0.36174, 0.40994, 0.44834, 0.48288, 
-//864
-0.1117, 0.13072, 0.1638, 0.2048, 0.24104, 0.27068, 0.28806, 0.29938, 0.3076, 0.31812, 0.33168, 0.34614, 0.36568, 0.38574, 0.4056, 0.42438, 0.44262, 0.46016,
-0.46976, 0.47256, 0.46836, 0.4563, 0.4395, 0.4171, 0.3942, 0.36878, 0.34112, 0.31274, 0.28368, 0.2569, 0.23082, 0.20736, 0.18492, 0.16516, 0.1479, 0.13128,
-0.11486, 0.1087, 0.10298, 0.09852, 0.09652, 0.09404, 0.0922, 0.08874, 0.08778, 0.08612, 0.08506, 0.08532, 0.08574, 0.08898, 0.09142, 0.09526, 0.09806, 0.10102,
-0.10466, 0.10602, 0.10678, 0.10532, 0.10468, 0.10374, 0.10094, 0.0973, 0.09532, 0.09744, 0.099, 0.10142, 0.1037, 0.10632, 0.10878, 0.11456, 0.12122, 0.12792,
-0.13666, 0.14982, 0.16428, 0.17848, 0.19396, 0.20774, 0.21988, 0.23026, 0.24536, 
-//865
-0.12572, 0.1554, 0.20838, 0.29082, 0.40082, 0.52276, 0.62416, 0.68518, 0.71458, 0.72604, 0.73294, 0.7398, 0.74378, 0.74572, 0.74832, 0.75056, 0.75418, 0.75514,
-0.75478, 0.75486, 0.75184, 0.74842, 0.7446, 0.74076, 0.7356, 0.7306, 0.7254, 0.71718, 0.70942, 0.7013, 0.69586, 0.69096, 0.6849, 0.67688, 0.66816, 0.65846,
-0.64892, 0.6406, 0.63594, 0.6343, 0.63452, 0.6333, 0.63466, 0.63196, 0.62968, 0.62844, 0.6265, 0.62724, 0.62876, 0.63524, 0.64398, 0.6514, 0.6572, 0.66392,
-0.66972, 0.67518, 0.67684, 0.67558, 0.6721, 0.66806, 0.65932, 0.65372, 0.64834, 0.64958, 0.65294, 0.6549, 0.6601, 0.66454, 0.66982, 0.67906, 0.68628, 0.69476,
-0.70708, 0.7173, 0.728, 0.7381, 0.75066, 0.75654, 0.76456, 0.76114, 0.75942, 
-//866
-0.13796, 0.16868, 0.22374, 0.304, 0.39696, 0.48234, 0.5366, 0.55722, 0.5645, 0.56494, 0.56624, 0.56498, 0.5657, 0.56386, 0.56286, 0.5627, 0.56016, 0.55952,
-0.55744, 0.55566, 0.55506, 0.55074, 0.54726, 0.54346, 0.54148, 0.53898, 0.53568, 0.53034, 0.52678, 0.5223, 0.5187, 0.51482, 0.51046, 0.50672, 0.5025, 0.49528,
-0.48684, 0.48396, 0.47982, 0.47808, 0.47616, 0.47482, 0.47458, 0.47282, 0.46948, 0.4671, 0.46564, 0.46566, 0.46662, 0.46798, 0.47108, 0.47552, 0.4778, 0.48022,
-0.48254, 0.48298, 0.48262, 0.4796, 0.47706, 0.47412, 0.46968, 0.46728, 0.46342, 0.46316, 0.46386, 0.46598, 0.46696, 0.4688, 0.47224, 0.47682, 0.48016, 0.485,
-0.488, 0.49138, 0.49516, 0.49794, 0.50034, 0.49996, 0.5031, 0.50344, 0.50582, 
-//867
-0.129, 0.15648, 0.20534, 0.26992, 0.33538, 0.3844, 0.40976, 0.41818, 0.41852, 0.41758, 0.41672, 0.41498, 0.41386, 0.4117, 0.41108, 0.40998, 0.409, 0.4077,
-0.4064, 0.40496, 0.40308, 0.39928, 0.39546, 0.39482, 0.39198, 0.3894, 0.387, 0.38362, 0.38196, 0.37734, 0.37518, 0.37222, 0.36938, 0.36632, 0.36294, 0.3585,
-0.35242, 0.34976, 0.34624, 0.34384, 0.34112, 0.34016, 0.33952, 0.33754, 0.3383, 0.33536, 0.33476, 0.3341, 0.33334, 0.33424, 0.33566, 0.3375, 0.33786, 0.33934,
-0.34148, 0.34044, 0.34072, 0.33754, 0.3364, 0.33668, 0.3342, 0.33266, 0.33012, 0.33192, 0.33106, 0.3312, 0.33216, 0.33338, 0.33362, 0.337, 0.33688, 0.33828,
-0.34056, 0.3432, 0.34436, 0.34638, 0.34602, 0.34844, 0.34942, 0.34788, 0.3482, 
-//868
-0.119, 0.14094, 0.17952, 0.22224, 0.2606, 0.28142, 0.29112, 0.29502, 0.29442, 0.29478, 0.29352, 0.29376, 0.29376, 0.29332, 0.29232, 0.2922, 0.2914, 0.29112,
-0.2887, 0.28396, 0.281, 0.2792, 0.27702, 0.27658, 0.27476, 0.27284, 0.27062, 0.2669, 0.264, 0.26182, 0.25948, 0.25566, 0.25268, 0.24946, 0.2465, 0.24234,
-0.23762, 0.23784, 0.23628, 0.23592, 0.2337, 0.23328, 0.23334, 0.23366, 0.23126, 0.22962, 0.2287, 0.22764, 0.2282, 0.2293, 0.2312, 0.23324, 0.23396, 0.23752,
-0.24012, 0.24044, 0.23922, 0.23516, 0.23458, 0.2335, 0.23102, 0.22926, 0.22788, 0.22944, 0.2285, 0.22954, 0.23118, 0.23218, 0.23222, 0.23518, 0.23766, 0.24018,
-0.24166, 0.2437, 0.2466, 0.24768, 0.24754, 0.24798, 0.24882, 0.24928, 0.24616, 
-//869
-0.0993, 0.11882, 0.14456, 0.17044, 0.18996, 0.20058, 0.20654, 0.20704, 0.20722, 0.20494, 0.20586, 0.20516, 0.20476, 0.20456, 0.20282, 0.20344, 0.2038, 0.20304,
-0.20346, 0.20218, 0.20074, 0.1985, 0.19698, 0.19464, 0.19246, 0.1892, 0.18566, 0.18274, 0.17904, 0.17658, 0.1746, 0.1726, 0.17098, 0.16908, 0.16616, 0.16208,
-0.15526, 0.15614, 0.15306, 0.15184, 0.15096, 0.1523, 0.15246, 0.15074, 0.1491, 0.14958, 0.14776, 0.14744, 0.14898, 0.14926, 0.15152, 0.15236, 0.15458, 0.15608,
-0.15664, 0.15634, 0.1557, 0.1548, 0.15462, 0.1542, 0.15296, 0.14988, 0.14904, 0.14984, 0.15198, 0.15274, 0.1555, 0.15694, 0.15878, 0.1619, 0.16476, 0.16776,
-0.17026, 0.17162, 0.17308, 0.17444, 0.17648, 0.17784, 0.17838, 0.17916, 0.18038, 
-//870
-0.09228, 0.10388, 0.11778, 0.12992, 0.13856, 0.14298, 0.14448, 0.14348, 0.14428, 0.1431, 0.14348, 0.14262, 0.14164, 0.14078, 0.13888, 0.13786, 0.13664, 0.13572,
-0.13452, 0.13432, 0.13414, 0.13232, 0.13068, 0.12952, 0.12774, 0.1262, 0.1252, 0.12256, 0.11968, 0.11806, 0.11614, 0.11418, 0.11188, 0.11022, 0.10864, 0.10542,
-0.10106, 0.1009, 0.0993, 0.09852, 0.09706, 0.09658, 0.0968, 0.09598, 0.09586, 0.0955, 0.09552, 0.09504, 0.09502, 0.09554, 0.09508, 0.09582, 0.0965, 0.09702,
-0.09798, 0.09878, 0.09898, 0.0969, 0.09736, 0.09854, 0.09678, 0.0954, 0.09492, 0.09638, 0.09566, 0.09686, 0.0977, 0.09888, 0.0995, 0.10082, 0.10294, 0.10394,
-0.10682, 0.1081, 0.11084, 0.11254, 0.11262, 0.11402, 0.1153, 0.11608, 0.11432, 
-//871
-0.06862, 0.07262, 0.07706, 0.08214, 0.0844, 0.08596, 0.08698, 0.08544, 0.08624, 0.08606, 0.0865, 0.0864, 0.08644, 0.08538, 0.08416, 0.08316, 0.08218, 0.08226,
-0.08118, 0.08076, 0.08094, 0.07854, 0.07756, 0.0769, 0.0762, 0.07544, 0.07456, 0.07346, 0.07114, 0.06918, 0.06754, 0.06502, 0.06334, 0.06088, 0.0602, 0.05758,
-0.05296, 0.05444, 0.05388, 0.0535, 0.05388, 0.05378, 0.05414, 0.05414, 0.05378, 0.05352, 0.0528, 0.0525, 0.05212, 0.05286, 0.0526, 0.05322, 0.0544, 0.05596,
-0.05648, 0.05584, 0.05646, 0.05464, 0.05594, 0.0569, 0.05594, 0.05454, 0.0537, 0.05584, 0.0569, 0.0578, 0.05908, 0.05906, 0.06052, 0.06156, 0.06236, 0.06358,
-0.0652, 0.0678, 0.06988, 0.07114, 0.07304, 0.07398, 0.07632, 0.07616, 0.07808, 
-//872
-0.0577, 0.06024, 0.06232, 0.06366, 0.06536, 0.06586, 0.0669, 0.06554, 0.06504, 0.0662, 0.06602, 0.06698, 0.0673, 0.06852, 0.07038, 0.07128, 0.07538, 0.07596,
-0.07584, 0.0761, 0.07532, 0.07346, 0.07132, 0.07038, 0.06756, 0.06518, 0.06266, 0.05962, 0.05706, 0.05478, 0.05346, 0.0503, 0.04896, 0.04752, 0.04586, 0.04436,
-0.04068, 0.04346, 0.04372, 0.04358, 0.04294, 0.04306, 0.0421, 0.04158, 0.04262, 0.04168, 0.0426, 0.04388, 0.04482, 0.04506, 0.0458, 0.04634, 0.04682, 0.04698,
-0.0469, 0.04572, 0.04628, 0.04458, 0.04616, 0.04664, 0.04692, 0.04604, 0.04626, 0.04744, 0.04898, 0.05082, 0.05108, 0.05282, 0.0534, 0.05516, 0.05618, 0.05816,
-0.05896, 0.05994, 0.06236, 0.06274, 0.06366, 0.06344, 0.06522, 0.06618, 0.06696, 
-//873
-0.13918, 0.1729, 0.22812, 0.31008, 0.41374, 0.5206, 0.59802, 0.63676, 0.65134, 0.65848, 0.66358, 0.66514, 0.66804, 0.66856, 0.66878, 0.6686, 0.66782, 0.66462,
-0.66186, 0.6574, 0.65168, 0.64374, 0.63598, 0.62782, 0.61984, 0.61, 0.59946, 0.58602, 0.57116, 0.56098, 0.55096, 0.54002, 0.529, 0.516, 0.50214, 0.48666,
-0.46912, 0.46072, 0.45278, 0.44784, 0.44358, 0.4417, 0.4408, 0.43714, 0.4346, 0.43084, 0.42842, 0.43064, 0.43214, 0.43816, 0.44512, 0.45358, 0.4603, 0.47056,
-0.47646, 0.48156, 0.4838, 0.48074, 0.47552, 0.46908, 0.46106, 0.45492, 0.4498, 0.45108, 0.45224, 0.45552, 0.45852, 0.4635, 0.46798, 0.47752, 0.48552, 0.49738,
-0.51318, 0.52566, 0.54434, 0.5543, 0.5652, 0.57726, 0.5894, 0.59548, 0.60364, 
-//874
-0.13572, 0.16598, 0.2154, 0.28426, 0.36124, 0.4268, 0.46156, 0.47468, 0.4767, 0.47876, 0.47654, 0.4762, 0.47856, 0.47714, 0.47618, 0.47444, 0.47502, 0.47398,
-0.47116, 0.46822, 0.46342, 0.45672, 0.45078, 0.44694, 0.44178, 0.43514, 0.4282, 0.41974, 0.41048, 0.40198, 0.39602, 0.38698, 0.37714, 0.36684, 0.35648, 0.34196,
-0.32728, 0.31986, 0.31318, 0.30872, 0.30646, 0.30426, 0.30402, 0.30192, 0.29874, 0.29542, 0.2938, 0.29414, 0.29548, 0.30164, 0.30806, 0.31572, 0.3218, 0.32756,
-0.3333, 0.33538, 0.33748, 0.33348, 0.32972, 0.32414, 0.31656, 0.31052, 0.30836, 0.31078, 0.3095, 0.3135, 0.318, 0.32352, 0.32624, 0.33304, 0.33952, 0.34606,
-0.35496, 0.3656, 0.37542, 0.38212, 0.3889, 0.39378, 0.40082, 0.40562, 0.40712, 
-//875
-0.11854, 0.14188, 0.18206, 0.23358, 0.28356, 0.31674, 0.33342, 0.33918, 0.34104, 0.34042, 0.34048, 0.34038, 0.3402, 0.34018, 0.33942, 0.33676, 0.33556, 0.33392,
-0.33296, 0.32902, 0.32454, 0.32084, 0.31404, 0.30914, 0.30396, 0.29858, 0.29112, 0.28318, 0.27446, 0.26642, 0.2612, 0.25444, 0.2486, 0.24256, 0.23518, 0.22578,
-0.21472, 0.20962, 0.20556, 0.2031, 0.2022, 0.20378, 0.20332, 0.20196, 0.20004, 0.19824, 0.1963, 0.19666, 0.19822, 0.2005, 0.20534, 0.21088, 0.21568, 0.22188,
-0.22552, 0.22878, 0.23028, 0.2297, 0.22726, 0.22584, 0.21994, 0.21476, 0.21266, 0.21308, 0.21486, 0.2166, 0.21956, 0.22238, 0.22602, 0.2314, 0.23542, 0.2428,
-0.25142, 0.25872, 0.26514, 0.272, 0.27778, 0.28048, 0.28206, 0.2844, 0.28796, 
-//876
-0.11406, 0.1356, 0.16682, 0.20048, 0.22898, 0.24506, 0.25172, 0.25294, 0.25312, 0.25214, 0.25288, 0.2533, 0.25312, 0.25318, 0.25108, 0.25116, 0.24992, 0.24892,
-0.24754, 0.24606, 0.24154, 0.23764, 0.23334, 0.22754, 0.22426, 0.2189, 0.21414, 0.20838, 0.2018, 0.19698, 0.19122, 0.1861, 0.18064, 0.17554, 0.16894, 0.16056,
-0.15082, 0.14896, 0.1455, 0.1423, 0.14066, 0.13878, 0.13918, 0.13602, 0.13564, 0.13464, 0.13358, 0.1343, 0.13394, 0.13608, 0.13828, 0.14242, 0.1453, 0.14778,
-0.1507, 0.15198, 0.15268, 0.15062, 0.14986, 0.14918, 0.14626, 0.14354, 0.14108, 0.14084, 0.14128, 0.14276, 0.14542, 0.14812, 0.1504, 0.15236, 0.15698, 0.16246,
-0.16734, 0.17284, 0.1787, 0.18292, 0.18664, 0.19078, 0.19404, 0.19724, 0.20122, 
-//877
-0.09268, 0.10696, 0.1254, 0.14264, 0.15628, 0.1639, 0.1666, 0.16838, 0.16964, 0.16988, 0.17174, 0.17038, 0.17174, 0.17268, 0.17018, 0.16994, 0.16874, 0.16764,
-0.16728, 0.1654, 0.164, 0.16042, 0.15652, 0.15412, 0.15094, 0.1469, 0.14218, 0.13778, 0.13196, 0.12614, 0.12088, 0.1165, 0.1115, 0.10598, 0.10178, 0.0965,
-0.08828, 0.08792, 0.08482, 0.08352, 0.08278, 0.08164, 0.0812, 0.07988, 0.07912, 0.07794, 0.07748, 0.078, 0.079, 0.08054, 0.08322, 0.0863, 0.08844, 0.09098,
-0.0934, 0.09402, 0.09302, 0.09152, 0.09074, 0.0895, 0.0866, 0.0839, 0.08336, 0.08562, 0.08676, 0.08914, 0.09124, 0.09276, 0.09534, 0.09758, 0.10174, 0.10572,
-0.1098, 0.11318, 0.11814, 0.1221, 0.12672, 0.13118, 0.13294, 0.1356, 0.14098, 
-//878
-0.07366, 0.07516, 0.07886, 0.0831, 0.08764, 0.09074, 0.09382, 0.09444, 0.0945, 0.0965, 0.10046, 0.10182, 0.10558, 0.1085, 0.11128, 0.11482, 0.11816, 0.11996,
-0.12172, 0.1216, 0.12048, 0.11828, 0.11414, 0.11046, 0.10606, 0.0993, 0.09366, 0.08786, 0.08148, 0.07446, 0.069, 0.0633, 0.05862, 0.05568, 0.05266, 0.05048,
-0.04824, 0.0503, 0.04938, 0.0489, 0.0483, 0.04846, 0.0486, 0.0494, 0.0495, 0.05018, 0.0513, 0.05188, 0.0539, 0.05414, 0.05488, 0.05488, 0.0557, 0.0558, 0.05406,
-0.0545, 0.05302, 0.05214, 0.05306, 0.05446, 0.05526, 0.05318, 0.05508, 0.05738, 0.05746, 0.05792, 0.05934, 0.05972, 0.0616, 0.06296, 0.06478, 0.06734, 0.06964,
-0.0711, 0.07318, 0.07532, 0.07784, 0.08052, 0.08228, 0.08418, 0.0853, 
-//879
-0.05158, 0.05046, 0.05204, 0.0537, 0.057, 0.05854, 0.06002, 0.0596, 0.06012, 0.06288, 0.06562, 0.06814, 0.07104, 0.07512, 0.0794, 0.08436, 0.0908, 0.0967,
-0.1015, 0.10392, 0.10554, 0.10272, 0.0963, 0.09014, 0.0837, 0.0757, 0.06924, 0.06226, 0.05618, 0.04972, 0.04578, 0.04208, 0.03938, 0.03748, 0.03598, 0.03406,
-0.03028, 0.03364, 0.03474, 0.03474, 0.03432, 0.03546, 0.03602, 0.03562, 0.03716, 0.03768, 0.03782, 0.03826, 0.03886, 0.03832, 0.03828, 0.03952, 0.03916,
-0.03954, 0.04008, 0.04, 0.0399, 0.03916, 0.0403, 0.04182, 0.04184, 0.0409, 0.04086, 0.04276, 0.04284, 0.04474, 0.04648, 0.0476, 0.04778, 0.05108, 0.05138,
-0.05336, 0.0543, 0.05614, 0.05742, 0.05836, 0.06064, 0.06232, 0.06366, 0.06546, 0.06666, 
-//880
-0.12132, 0.1486, 0.20136, 0.27978, 0.38262, 0.49532, 0.58542, 0.6368, 0.65962, 0.67174, 0.67794, 0.68376, 0.69316, 0.69974, 0.70538, 0.70688, 0.70774, 0.70784,
-0.70528, 0.69992, 0.69088, 0.67966, 0.66756, 0.6571, 0.6452, 0.63128, 0.61658, 0.59682, 0.57802, 0.5603, 0.54442, 0.52724, 0.51002, 0.4905, 0.46856, 0.44692,
-0.42604, 0.41322, 0.40082, 0.39496, 0.39016, 0.38676, 0.38432, 0.37876, 0.3772, 0.37352, 0.3739, 0.37554, 0.38068, 0.38856, 0.39812, 0.40866, 0.41844, 0.42804,
-0.43602, 0.44046, 0.44394, 0.43984, 0.43496, 0.42706, 0.41764, 0.40836, 0.40296, 0.40514, 0.4084, 0.41338, 0.41792, 0.42532, 0.43148, 0.4442, 0.45506, 0.47106,
-0.49142, 0.5125, 0.53528, 0.56028, 0.57978, 0.60082, 0.62032, 0.63388, 0.64652, 
-//881
-0.1292, 0.1612, 0.21128, 0.2881, 0.37882, 0.46448, 0.5193, 0.54156, 0.55024, 0.55502, 0.55932, 0.5616, 0.56778, 0.56962, 0.57066, 0.5715, 0.56928, 0.5676,
-0.56452, 0.55888, 0.54934, 0.53922, 0.52902, 0.51872, 0.50796, 0.49392, 0.48068, 0.46416, 0.44808, 0.43226, 0.41814, 0.40474, 0.39004, 0.37376, 0.3562, 0.33638,
-0.31642, 0.30484, 0.29654, 0.2912, 0.28804, 0.28516, 0.284, 0.28012, 0.27624, 0.27272, 0.27122, 0.27188, 0.27374, 0.27974, 0.2872, 0.2967, 0.304, 0.31118,
-0.31948, 0.32438, 0.32756, 0.32506, 0.32056, 0.31546, 0.30668, 0.29922, 0.29398, 0.29464, 0.29674, 0.29806, 0.30136, 0.30792, 0.312, 0.3206, 0.33038, 0.3428,
-0.35892, 0.37394, 0.39514, 0.41012, 0.42898, 0.44006, 0.45228, 0.4641, 0.47494, 
-//882
-0.13494, 0.16152, 0.20704, 0.26926, 0.33324, 0.3788, 0.40366, 0.41296, 0.41706, 0.41764, 0.41912, 0.42088, 0.42456, 0.42616, 0.42472, 0.42448, 0.42302, 0.42054,
-0.41802, 0.4118, 0.40444, 0.3937, 0.38368, 0.37324, 0.36362, 0.35198, 0.33908, 0.32656, 0.31274, 0.30052, 0.29058, 0.27986, 0.2683, 0.25682, 0.2445, 0.23082,
-0.21406, 0.20784, 0.2022, 0.19676, 0.19556, 0.19334, 0.19226, 0.18982, 0.18688, 0.18594, 0.1841, 0.18426, 0.18746, 0.1908, 0.19712, 0.20438, 0.21056, 0.21652,
-0.22146, 0.2252, 0.226, 0.22394, 0.22064, 0.21534, 0.21018, 0.20356, 0.20116, 0.20244, 0.20254, 0.20526, 0.20872, 0.21306, 0.21728, 0.22286, 0.23018, 0.24008,
-0.25108, 0.26498, 0.27846, 0.29186, 0.3013, 0.3127, 0.32224, 0.32918, 0.33894, 
-//883
-0.11342, 0.13664, 0.1717, 0.21226, 0.24854, 0.27182, 0.2864, 0.29084, 0.29202, 0.29468, 0.29656, 0.29768, 0.30136, 0.30162, 0.30132, 0.30062, 0.29932, 0.29732,
-0.2949, 0.29048, 0.28588, 0.28048, 0.27236, 0.26486, 0.25728, 0.24968, 0.2407, 0.22968, 0.21978, 0.20874, 0.20012, 0.1897, 0.1812, 0.1713, 0.16182, 0.1512,
-0.13888, 0.13406, 0.1297, 0.12732, 0.12498, 0.12256, 0.12106, 0.11904, 0.1162, 0.11478, 0.11638, 0.116, 0.11758, 0.11926, 0.12232, 0.12608, 0.12948, 0.13344,
-0.13564, 0.13876, 0.13988, 0.138, 0.13564, 0.13522, 0.13064, 0.12662, 0.12552, 0.12432, 0.12562, 0.12648, 0.1285, 0.13156, 0.13532, 0.13892, 0.14322, 0.14946,
-0.15906, 0.1677, 0.17614, 0.18656, 0.19398, 0.20278, 0.21124, 0.21654, 0.22508, 
-//884
-0.10068, 0.11348, 0.13542, 0.15776, 0.17786, 0.18814, 0.1943, 0.19826, 0.1993, 0.20132, 0.2035, 0.20474, 0.20816, 0.21088, 0.21144, 0.21034, 0.21086, 0.20922,
-0.20796, 0.2048, 0.20108, 0.19502, 0.18866, 0.18314, 0.17696, 0.16918, 0.16096, 0.15142, 0.14232, 0.13294, 0.12596, 0.11788, 0.1104, 0.10426, 0.09708, 0.08972,
-0.08112, 0.0805, 0.0781, 0.07712, 0.0756, 0.0751, 0.07488, 0.07326, 0.07324, 0.07096, 0.07216, 0.0712, 0.07302, 0.0734, 0.07608, 0.0779, 0.0807, 0.08336,
-0.08484, 0.08594, 0.08704, 0.08492, 0.08386, 0.0831, 0.08132, 0.0773, 0.0778, 0.0781, 0.0793, 0.08156, 0.08362, 0.08498, 0.08708, 0.09002, 0.09386, 0.09792,
-0.10266, 0.10994, 0.11594, 0.12184, 0.12982, 0.13606, 0.14286, 0.14878, 0.15536, 
-//885
-0.06128, 0.06494, 0.07306, 0.08118, 0.08946, 0.09446, 0.09968, 0.10004, 0.1032, 0.1084, 0.11172, 0.12046, 0.13016, 0.1358, 0.13976, 0.14364, 0.14818, 0.1518,
-0.15368, 0.1532, 0.14986, 0.14536, 0.13968, 0.133, 0.12506, 0.11596, 0.1062, 0.09554, 0.0857, 0.07632, 0.0685, 0.06204, 0.05666, 0.05316, 0.04938, 0.04546,
-0.04138, 0.04296, 0.0413, 0.0412, 0.04148, 0.04176, 0.0416, 0.04, 0.04146, 0.04128, 0.0418, 0.04274, 0.04374, 0.04408, 0.04464, 0.04576, 0.0466, 0.04692,
-0.04728, 0.04738, 0.04764, 0.04484, 0.04638, 0.04628, 0.04596, 0.04514, 0.04646, 0.04898, 0.05062, 0.05208, 0.05408, 0.05746, 0.0589, 0.06048, 0.06218, 0.0633,
-0.06556, 0.0683, 0.074, 0.08148, 0.0904, 0.0997, 0.10754, 0.1126, 0.11846, 
-//886
-0.13632, 0.16618, 0.21944, 0.29638, 0.39544, 0.49168, 0.56044, 0.59774, 0.61256, 0.62346, 0.63554, 0.64558, 0.65836, 0.67078, 0.6779, 0.68158, 0.68058, 0.67766,
-0.67068, 0.66062, 0.6461, 0.62716, 0.6097, 0.59142, 0.57304, 0.55284, 0.53088, 0.50602, 0.48274, 0.45938, 0.43742, 0.41544, 0.39204, 0.36874, 0.34568, 0.3212,
-0.29914, 0.28452, 0.2738, 0.26798, 0.26434, 0.26094, 0.25986, 0.25586, 0.25216, 0.24898, 0.24814, 0.25012, 0.2554, 0.26136, 0.27078, 0.28132, 0.29038, 0.30104,
-0.30842, 0.31324, 0.31584, 0.31168, 0.3065, 0.29838, 0.28906, 0.28116, 0.2769, 0.27784, 0.27974, 0.28588, 0.29064, 0.29874, 0.30422, 0.31308, 0.3228, 0.3371,
-0.35552, 0.37598, 0.39788, 0.42164, 0.4425, 0.4633, 0.48444, 0.50388, 0.5257, 
-//887
-0.13164, 0.15888, 0.20896, 0.27686, 0.35302, 0.41626, 0.4555, 0.47318, 0.47854, 0.48402, 0.49084, 0.49724, 0.5058, 0.51308, 0.51688, 0.51738, 0.51526, 0.51272,
-0.50844, 0.50112, 0.4899, 0.47584, 0.46146, 0.44522, 0.43068, 0.41444, 0.39466, 0.37358, 0.35402, 0.33406, 0.3161, 0.29842, 0.2818, 0.26194, 0.2435, 0.22494,
-0.20492, 0.19516, 0.1877, 0.18198, 0.1792, 0.17828, 0.17616, 0.1733, 0.17036, 0.16872, 0.16746, 0.1682, 0.1715, 0.1779, 0.18464, 0.1936, 0.20198, 0.20968,
-0.21606, 0.2204, 0.22188, 0.21932, 0.21456, 0.20884, 0.20118, 0.19266, 0.19064, 0.192, 0.19452, 0.19794, 0.20274, 0.20764, 0.21214, 0.2194, 0.2278, 0.23946,
-0.25498, 0.27114, 0.2891, 0.30742, 0.325, 0.33932, 0.35512, 0.37004, 0.3876, 
-//888
-0.10836, 0.12934, 0.16304, 0.2071, 0.25158, 0.28274, 0.30218, 0.3114, 0.31606, 0.32238, 0.3293, 0.33678, 0.34508, 0.3526, 0.35786, 0.35972, 0.35784, 0.35638,
-0.35324, 0.34742, 0.33948, 0.32864, 0.3169, 0.3045, 0.29442, 0.2806, 0.26594, 0.24974, 0.23364, 0.21742, 0.20268, 0.1879, 0.17448, 0.16102, 0.1467, 0.1333,
-0.12018, 0.11512, 0.1103, 0.1069, 0.10636, 0.10528, 0.10376, 0.10266, 0.10012, 0.10028, 0.10002, 0.10038, 0.1014, 0.10438, 0.10756, 0.11012, 0.1151, 0.11814,
-0.12118, 0.12442, 0.12626, 0.12396, 0.12308, 0.11976, 0.11622, 0.112, 0.11032, 0.11066, 0.11206, 0.114, 0.118, 0.12084, 0.1251, 0.12888, 0.13446, 0.14338,
-0.15446, 0.16544, 0.18086, 0.19602, 0.21322, 0.22748, 0.2403, 0.25268, 0.26464, 
-//889
-0.09214, 0.10328, 0.12488, 0.14954, 0.17122, 0.191, 0.20464, 0.20612, 0.2155, 0.22564, 0.22982, 0.24518, 0.2594, 0.26572, 0.26684, 0.2668, 0.26678, 0.26504,
-0.26116, 0.25488, 0.2471, 0.23522, 0.22482, 0.21386, 0.20358, 0.19144, 0.17882, 0.16446, 0.1517, 0.13928, 0.12886, 0.11826, 0.10832, 0.09772, 0.08968, 0.08094,
-0.07206, 0.07148, 0.06756, 0.06462, 0.06308, 0.0612, 0.06078, 0.05942, 0.0585, 0.05778, 0.05788, 0.05728, 0.05738, 0.05862, 0.059, 0.05952, 0.06014, 0.06108,
-0.06204, 0.06182, 0.06238, 0.06034, 0.0608, 0.06104, 0.0599, 0.05838, 0.0601, 0.06104, 0.06202, 0.06286, 0.06536, 0.06654, 0.06928, 0.07208, 0.07528, 0.0802,
-0.08602, 0.0946, 0.10822, 0.1271, 0.14922, 0.17184, 0.1914, 0.20478, 0.21964, 
-//890
-0.131, 0.15814, 0.2086, 0.27654, 0.35254, 0.42064, 0.46448, 0.48544, 0.4966, 0.50862, 0.52106, 0.53406, 0.55286, 0.56992, 0.58132, 0.58456, 0.58436, 0.58158,
-0.57518, 0.56412, 0.54992, 0.53, 0.51108, 0.49076, 0.47006, 0.44724, 0.4222, 0.39558, 0.36842, 0.34196, 0.31764, 0.29316, 0.26898, 0.24484, 0.22442, 0.20298,
-0.1839, 0.1738, 0.16412, 0.15834, 0.15524, 0.15114, 0.14946, 0.14714, 0.1441, 0.14262, 0.14134, 0.14188, 0.14418, 0.14852, 0.1548, 0.16052, 0.16688, 0.1724,
-0.1784, 0.18076, 0.18276, 0.18042, 0.1763, 0.17112, 0.16448, 0.1582, 0.15512, 0.15556, 0.15696, 0.15892, 0.16414, 0.16934, 0.17546, 0.18306, 0.19252, 0.20604,
-0.22408, 0.24362, 0.2657, 0.2894, 0.31356, 0.33784, 0.35772, 0.38148, 0.40446, 
-//891
-0.10938, 0.13096, 0.16716, 0.2147, 0.2647, 0.30412, 0.33094, 0.3425, 0.34864, 0.36106, 0.37432, 0.38836, 0.40902, 0.42842, 0.4389, 0.44566, 0.44668, 0.4452,
-0.44216, 0.43278, 0.4208, 0.40614, 0.38946, 0.3711, 0.35234, 0.33088, 0.3078, 0.28234, 0.2584, 0.23478, 0.21154, 0.19006, 0.1712, 0.15354, 0.1388, 0.12318,
-0.11006, 0.10588, 0.0999, 0.09662, 0.09474, 0.09338, 0.09154, 0.0907, 0.08732, 0.08712, 0.08624, 0.08738, 0.08946, 0.09188, 0.09448, 0.09816, 0.10174, 0.1046,
-0.10794, 0.10876, 0.10888, 0.10766, 0.1054, 0.10322, 0.10096, 0.0959, 0.0945, 0.0946, 0.0956, 0.09794, 0.10212, 0.1044, 0.10802, 0.1117, 0.11778, 0.12598,
-0.137, 0.14954, 0.16358, 0.1813, 0.19904, 0.2178, 0.23536, 0.25286, 0.27246, 
-//892
-0.07046, 0.08424, 0.10662, 0.13494, 0.16584, 0.192, 0.21024, 0.21396, 0.22754, 0.24362, 0.25196, 0.27988, 0.3073, 0.32322, 0.33068, 0.33162, 0.33308, 0.33394,
-0.32956, 0.32066, 0.31046, 0.29476, 0.28088, 0.2646, 0.24922, 0.23038, 0.21222, 0.19172, 0.17318, 0.15504, 0.13808, 0.1228, 0.10958, 0.0975, 0.08636, 0.0757,
-0.06348, 0.05996, 0.05526, 0.0528, 0.05066, 0.04886, 0.04792, 0.04668, 0.04698, 0.04418, 0.04386, 0.04366, 0.04242, 0.0437, 0.04262, 0.0434, 0.04348, 0.04432,
-0.04596, 0.04668, 0.04754, 0.0462, 0.0471, 0.0482, 0.04742, 0.0452, 0.04448, 0.04614, 0.04678, 0.0475, 0.04834, 0.05034, 0.05246, 0.05486, 0.05848, 0.06332,
-0.07114, 0.08438, 0.1049, 0.13414, 0.17068, 0.21204, 0.24912, 0.28062, 0.31204, 
-//893
-0.1294, 0.15818, 0.21092, 0.28986, 0.39424, 0.50786, 0.60142, 0.6582, 0.68022, 0.68938, 0.69298, 0.6951, 0.6993, 0.6992, 0.7, 0.70092, 0.70304, 0.70314, 0.7033,
-0.7026, 0.7012, 0.69998, 0.69778, 0.69562, 0.69348, 0.69048, 0.6876, 0.68286, 0.6789, 0.67374, 0.6714, 0.67012, 0.66708, 0.66444, 0.66404, 0.65732, 0.6535,
-0.65102, 0.64966, 0.6513, 0.65058, 0.65306, 0.65592, 0.65596, 0.65544, 0.65332, 0.65518, 0.65614, 0.6559, 0.65444, 0.65914, 0.66082, 0.66426, 0.6689, 0.67248,
-0.67334, 0.6763, 0.67586, 0.67568, 0.676, 0.67436, 0.67302, 0.67284, 0.67458, 0.6745, 0.67412, 0.67672, 0.67828, 0.68184, 0.68666, 0.68616, 0.69144, 0.69534,
-0.69236, 0.69612, 0.69914, 0.702, 0.70416, 0.7037, 0.69958, 0.7018, 
-//894
-0.12446, 0.15232, 0.20304, 0.27806, 0.36822, 0.4503, 0.50222, 0.52134, 0.52952, 0.53062, 0.53152, 0.53076, 0.53228, 0.53044, 0.52842, 0.52888, 0.52706, 0.52752,
-0.52842, 0.52668, 0.52416, 0.52248, 0.5199, 0.51866, 0.5183, 0.51744, 0.51396, 0.50994, 0.50732, 0.50242, 0.50254, 0.50074, 0.49956, 0.49788, 0.4975, 0.49486,
-0.48952, 0.48948, 0.4899, 0.49048, 0.49062, 0.49078, 0.49236, 0.49142, 0.49066, 0.4889, 0.48686, 0.484, 0.482, 0.48172, 0.48392, 0.48484, 0.4848, 0.48742,
-0.48784, 0.48786, 0.48782, 0.48664, 0.4846, 0.48468, 0.483, 0.4796, 0.47714, 0.47954, 0.48056, 0.48006, 0.48364, 0.4836, 0.48702, 0.489, 0.49038, 0.49172,
-0.49306, 0.49116, 0.4909, 0.49186, 0.49184, 0.49384, 0.49346, 0.49078, 0.4929, 
-//895
-0.13148, 0.15914, 0.20608, 0.26824, 0.33514, 0.38408, 0.40652, 0.41442, 0.41478, 0.41382, 0.41326, 0.41352, 0.41306, 0.41076, 0.40966, 0.40746, 0.40692,
-0.40518, 0.40456, 0.40124, 0.39732, 0.39236, 0.38902, 0.38844, 0.38628, 0.38502, 0.38356, 0.37888, 0.37692, 0.37236, 0.37104, 0.3702, 0.3685, 0.36694, 0.3638,
-0.36332, 0.36048, 0.36262, 0.36244, 0.36362, 0.36402, 0.36468, 0.36718, 0.36736, 0.36754, 0.36642, 0.36392, 0.36188, 0.36014, 0.3586, 0.35862, 0.35898, 0.35886,
-0.3609, 0.36216, 0.36122, 0.36214, 0.36182, 0.3626, 0.36258, 0.36164, 0.35958, 0.35942, 0.3592, 0.36006, 0.35842, 0.35972, 0.35938, 0.35854, 0.36006, 0.35858,
-0.35814, 0.3576, 0.3558, 0.3561, 0.3548, 0.35476, 0.35354, 0.35396, 0.34868, 0.3492, 
-//896
-0.12714, 0.1507, 0.18938, 0.23208, 0.2666, 0.28604, 0.29392, 0.29446, 0.29418, 0.29348, 0.2924, 0.29264, 0.29208, 0.28936, 0.28892, 0.28644, 0.2852, 0.28446,
-0.28376, 0.28232, 0.27962, 0.27706, 0.27448, 0.27474, 0.27414, 0.27206, 0.27184, 0.27042, 0.27048, 0.26724, 0.26612, 0.26502, 0.26204, 0.26134, 0.26074,
-0.25876, 0.25692, 0.25798, 0.25712, 0.2553, 0.2526, 0.25392, 0.2533, 0.25288, 0.25454, 0.25328, 0.25494, 0.25408, 0.25284, 0.25226, 0.2506, 0.25034, 0.24796,
-0.24746, 0.2472, 0.24656, 0.24654, 0.2436, 0.24414, 0.24518, 0.24328, 0.24074, 0.24076, 0.24074, 0.24018, 0.23994, 0.23908, 0.23932, 0.2385, 0.23992, 0.23932,
-0.23956, 0.23932, 0.23844, 0.23786, 0.23772, 0.23756, 0.23542, 0.23656, 0.23604, 0.23326, 
-//897
-0.1117, 0.12466, 0.1484, 0.1702, 0.1867, 0.1937, 0.1972, 0.19718, 0.19748, 0.19642, 0.19584, 0.19494, 0.19456, 0.19352, 0.19168, 0.18998, 0.18964, 0.18836,
-0.187, 0.18594, 0.18484, 0.18354, 0.18144, 0.18248, 0.18134, 0.181, 0.17988, 0.17962, 0.17942, 0.17674, 0.17646, 0.17346, 0.1723, 0.17144, 0.17146, 0.17004,
-0.16818, 0.17204, 0.1712, 0.1701, 0.16964, 0.16842, 0.16666, 0.16612, 0.16654, 0.16722, 0.16714, 0.1668, 0.1673, 0.16768, 0.16778, 0.16716, 0.16652, 0.16522,
-0.16578, 0.1657, 0.16386, 0.16154, 0.16214, 0.16358, 0.16244, 0.16046, 0.1627, 0.16264, 0.16242, 0.16162, 0.16128, 0.16132, 0.16052, 0.16158, 0.16164, 0.16254,
-0.16372, 0.16416, 0.16332, 0.16314, 0.1632, 0.16206, 0.16292, 0.16102, 0.16148, 
-//898
-0.08782, 0.09572, 0.10756, 0.11826, 0.12398, 0.12612, 0.12632, 0.1261, 0.12606, 0.1251, 0.12518, 0.12478, 0.12478, 0.12404, 0.12416, 0.1235, 0.12396, 0.1233,
-0.1223, 0.1208, 0.12034, 0.1166, 0.11604, 0.11464, 0.11382, 0.11378, 0.11198, 0.11094, 0.1097, 0.10836, 0.1073, 0.10648, 0.10658, 0.10508, 0.10386, 0.10216,
-0.09894, 0.1013, 0.10102, 0.1015, 0.10094, 0.10086, 0.10026, 0.10148, 0.10066, 0.1012, 0.10132, 0.10114, 0.10006, 0.09882, 0.10004, 0.10008, 0.10028, 0.10016,
-0.10066, 0.09938, 0.10012, 0.09762, 0.09862, 0.09984, 0.1006, 0.0977, 0.0984, 0.09918, 0.0998, 0.10032, 0.10084, 0.1022, 0.10378, 0.10662, 0.1076, 0.10856,
-0.10984, 0.10942, 0.11122, 0.11174, 0.11242, 0.11232, 0.11186, 0.1122, 0.11158, 
-//899
-0.06714, 0.0702, 0.07544, 0.07836, 0.07992, 0.07994, 0.07924, 0.0789, 0.0784, 0.0773, 0.07888, 0.07874, 0.07878, 0.07714, 0.07692, 0.07594, 0.07554, 0.07568,
-0.0738, 0.0732, 0.07216, 0.07034, 0.06928, 0.06846, 0.06842, 0.06782, 0.06806, 0.0673, 0.06714, 0.06654, 0.06598, 0.06488, 0.0638, 0.063, 0.06244, 0.05996,
-0.05678, 0.06138, 0.06068, 0.05978, 0.05894, 0.05808, 0.05858, 0.05822, 0.05984, 0.059, 0.06046, 0.0597, 0.05944, 0.05986, 0.0599, 0.06024, 0.05998, 0.05984,
-0.05968, 0.05934, 0.05932, 0.05798, 0.05958, 0.06056, 0.06104, 0.0597, 0.05738, 0.0603, 0.06078, 0.06216, 0.0635, 0.06388, 0.06452, 0.0657, 0.0661, 0.0676,
-0.0682, 0.06752, 0.0689, 0.0681, 0.0695, 0.07068, 0.0695, 0.06852, 0.07068, 
-//900
-0.05058, 0.05274, 0.0551, 0.05626, 0.0583, 0.05844, 0.05802, 0.05874, 0.05582, 0.05564, 0.0566, 0.05462, 0.05498, 0.05364, 0.05262, 0.05288, 0.05256, 0.05308,
-0.05372, 0.05488, 0.05388, 0.05188, 0.05264, 0.0514, 0.0517, 0.04946, 0.04776, 0.04658, 0.0457, 0.0458, 0.04468, 0.0436, 0.04426, 0.04364, 0.04384, 0.0424,
-0.03796, 0.04302, 0.0415, 0.04166, 0.04194, 0.03946, 0.04012, 0.03982, 0.0398, 0.04102, 0.04236, 0.04336, 0.04338, 0.04378, 0.04332, 0.04538, 0.0454, 0.04438,
-0.04434, 0.04372, 0.04336, 0.04042, 0.04226, 0.04356, 0.04306, 0.04246, 0.04346, 0.04652, 0.04608, 0.04742, 0.04798, 0.04884, 0.04912, 0.05002, 0.05162,
-0.05366, 0.0557, 0.05632, 0.05834, 0.05928, 0.05952, 0.0596, 0.06072, 0.06062, 0.05974, 
-//901
-0.1428, 0.17418, 0.23068, 0.3155, 0.42578, 0.54566, 0.64576, 0.70632, 0.7343, 0.74622, 0.75112, 0.75564, 0.75726, 0.758, 0.75878, 0.75886, 0.76078, 0.76138,
-0.7621, 0.76164, 0.75574, 0.74878, 0.74178, 0.73726, 0.7324, 0.72618, 0.71908, 0.70996, 0.70032, 0.69164, 0.6873, 0.68454, 0.6798, 0.67664, 0.67306, 0.6673,
-0.65808, 0.65336, 0.6503, 0.6519, 0.65372, 0.65616, 0.6581, 0.66066, 0.659, 0.65762, 0.65566, 0.6537, 0.6541, 0.6563, 0.66286, 0.66912, 0.67588, 0.68452,
-0.68876, 0.69476, 0.69832, 0.69452, 0.69052, 0.6926, 0.69222, 0.68858, 0.68814, 0.69168, 0.69448, 0.6964, 0.70082, 0.70326, 0.70772, 0.71276, 0.71688, 0.7227,
-0.73248, 0.7351, 0.74198, 0.74866, 0.75142, 0.7529, 0.75964, 0.75718, 0.76206, 
-//902
-0.13498, 0.16088, 0.21554, 0.29352, 0.38346, 0.46936, 0.52776, 0.55144, 0.56006, 0.56092, 0.56086, 0.56218, 0.56018, 0.55956, 0.55756, 0.5566, 0.55784, 0.55584,
-0.55578, 0.55406, 0.5505, 0.54684, 0.5415, 0.5392, 0.53712, 0.53324, 0.5289, 0.5225, 0.51754, 0.51226, 0.5095, 0.5069, 0.50522, 0.50168, 0.4989, 0.4938,
-0.48578, 0.4833, 0.47984, 0.48076, 0.4822, 0.48402, 0.48394, 0.48404, 0.48292, 0.48234, 0.48044, 0.47922, 0.47742, 0.47824, 0.48036, 0.48316, 0.48548, 0.48874,
-0.49208, 0.49272, 0.49556, 0.49276, 0.49236, 0.49134, 0.48966, 0.4867, 0.48512, 0.48568, 0.4862, 0.48686, 0.48826, 0.48906, 0.49168, 0.49294, 0.4967, 0.4989,
-0.50476, 0.50362, 0.50774, 0.50818, 0.51046, 0.51106, 0.51112, 0.5107, 0.51374, 
-//903
-0.13514, 0.16322, 0.21398, 0.28132, 0.34886, 0.40102, 0.42788, 0.43652, 0.43964, 0.43814, 0.43818, 0.43698, 0.43558, 0.4325, 0.42986, 0.42748, 0.42702, 0.42652,
-0.425, 0.42302, 0.42094, 0.41648, 0.4126, 0.4096, 0.40786, 0.40424, 0.39946, 0.39492, 0.38876, 0.38506, 0.38158, 0.37906, 0.3782, 0.37578, 0.37348, 0.36922,
-0.36248, 0.36326, 0.36154, 0.35978, 0.35968, 0.36022, 0.3619, 0.36228, 0.3613, 0.3599, 0.358, 0.3553, 0.35398, 0.35536, 0.35582, 0.35784, 0.35934, 0.35968,
-0.36082, 0.36028, 0.36108, 0.35928, 0.35724, 0.35658, 0.35442, 0.35128, 0.34758, 0.3497, 0.34974, 0.35216, 0.35338, 0.35638, 0.35862, 0.36062, 0.3639, 0.36426,
-0.36766, 0.36748, 0.3678, 0.36822, 0.37028, 0.36936, 0.36928, 0.36848, 0.36954, 
-//904
-0.12262, 0.14782, 0.1874, 0.2357, 0.2764, 0.30272, 0.31318, 0.31688, 0.31488, 0.3123, 0.31122, 0.31074, 0.31234, 0.30992, 0.30736, 0.3064, 0.30504, 0.3045,
-0.30312, 0.30088, 0.2982, 0.2935, 0.2909, 0.28862, 0.28488, 0.28206, 0.27944, 0.27698, 0.273, 0.26976, 0.26682, 0.26506, 0.26278, 0.26064, 0.25894, 0.25424,
-0.24896, 0.24794, 0.24702, 0.24698, 0.24706, 0.24704, 0.24762, 0.24764, 0.24764, 0.24598, 0.24468, 0.24328, 0.2403, 0.24166, 0.2421, 0.24238, 0.24462, 0.24712,
-0.24904, 0.24922, 0.2495, 0.24842, 0.2488, 0.24868, 0.24588, 0.2428, 0.24066, 0.24382, 0.24392, 0.245, 0.24528, 0.24606, 0.24682, 0.24862, 0.25078, 0.25112,
-0.25398, 0.25334, 0.2563, 0.25696, 0.25872, 0.2593, 0.26048, 0.259, 0.25702, 
-//905
-0.11476, 0.1316, 0.15842, 0.18496, 0.20526, 0.21374, 0.2169, 0.21752, 0.21596, 0.2145, 0.21538, 0.21314, 0.21246, 0.21116, 0.2121, 0.2093, 0.20742, 0.20808,
-0.20766, 0.2066, 0.20384, 0.2005, 0.19768, 0.19476, 0.1928, 0.18844, 0.18508, 0.1816, 0.17888, 0.17612, 0.17398, 0.17276, 0.17086, 0.16912, 0.16918, 0.16654,
-0.15874, 0.16136, 0.16106, 0.1594, 0.15946, 0.15946, 0.16046, 0.16036, 0.16144, 0.16032, 0.15984, 0.15824, 0.1573, 0.15854, 0.15766, 0.16004, 0.16092, 0.16178,
-0.16334, 0.16364, 0.16378, 0.16146, 0.16186, 0.1625, 0.16082, 0.15872, 0.15694, 0.15864, 0.15834, 0.16158, 0.1624, 0.16528, 0.16646, 0.16844, 0.16968, 0.172,
-0.17392, 0.17268, 0.174, 0.1751, 0.17506, 0.17554, 0.17744, 0.17758, 0.1775, 
-//906
-0.08264, 0.09584, 0.11284, 0.12594, 0.13618, 0.14086, 0.14236, 0.1437, 0.14414, 0.14372, 0.14346, 0.143, 0.14422, 0.14332, 0.14152, 0.13996, 0.14008, 0.13836,
-0.13706, 0.13514, 0.1333, 0.13212, 0.12964, 0.12612, 0.12584, 0.12382, 0.1223, 0.11886, 0.11544, 0.11232, 0.11114, 0.1088, 0.10704, 0.10586, 0.10394, 0.10156,
-0.09688, 0.0979, 0.0967, 0.09788, 0.09806, 0.09766, 0.09964, 0.09926, 0.09934, 0.09842, 0.09828, 0.09722, 0.09692, 0.09696, 0.09732, 0.09924, 0.10034, 0.10236,
-0.10374, 0.10508, 0.10616, 0.1042, 0.10516, 0.10568, 0.10444, 0.10138, 0.10052, 0.10184, 0.10296, 0.10368, 0.10534, 0.10754, 0.10878, 0.1105, 0.11308, 0.11474,
-0.11692, 0.118, 0.12076, 0.12178, 0.1226, 0.12356, 0.12338, 0.12282, 0.12322, 
-//907
-0.06822, 0.0722, 0.07882, 0.08378, 0.08762, 0.08934, 0.09004, 0.08976, 0.09032, 0.08904, 0.08896, 0.08916, 0.08846, 0.08746, 0.08554, 0.0852, 0.08456, 0.08478,
-0.08392, 0.08376, 0.08278, 0.08136, 0.07864, 0.07668, 0.07576, 0.07348, 0.07166, 0.06988, 0.06712, 0.06454, 0.06348, 0.06282, 0.0623, 0.06056, 0.05972, 0.058,
-0.054, 0.05632, 0.05632, 0.05582, 0.0549, 0.05506, 0.05494, 0.0554, 0.05574, 0.05542, 0.05572, 0.0546, 0.05652, 0.05686, 0.05904, 0.05864, 0.05906, 0.06,
-0.06096, 0.06174, 0.06076, 0.05902, 0.05972, 0.06148, 0.06108, 0.05986, 0.06068, 0.0615, 0.06196, 0.06198, 0.06312, 0.0648, 0.06662, 0.06716, 0.06944, 0.07194,
-0.07286, 0.07546, 0.07756, 0.07954, 0.0802, 0.08036, 0.0822, 0.08322, 0.083, 
-//908
-0.05728, 0.05608, 0.05832, 0.059, 0.05818, 0.05768, 0.0565, 0.0568, 0.05636, 0.05514, 0.05728, 0.05722, 0.05854, 0.05996, 0.06158, 0.06238, 0.06394, 0.06732,
-0.06936, 0.06942, 0.06988, 0.06798, 0.06368, 0.05962, 0.05758, 0.05362, 0.05238, 0.04986, 0.04672, 0.04474, 0.04352, 0.04308, 0.0424, 0.04076, 0.04068, 0.0386,
-0.0357, 0.03888, 0.03946, 0.0396, 0.04126, 0.04122, 0.04178, 0.04222, 0.04248, 0.0437, 0.0434, 0.04498, 0.04616, 0.04546, 0.04514, 0.04532, 0.04648, 0.04608,
-0.04724, 0.04732, 0.04736, 0.04472, 0.04716, 0.04918, 0.0484, 0.04716, 0.04584, 0.04868, 0.0498, 0.05176, 0.05278, 0.05428, 0.05592, 0.05736, 0.05954, 0.0607,
-0.06122, 0.0609, 0.06328, 0.0629, 0.06468, 0.0641, 0.0641, 0.06474, 0.06464, 
-//909
-0.13624, 0.16842, 0.22588, 0.30776, 0.41112, 0.51012, 0.57684, 0.6081, 0.62058, 0.62632, 0.62956, 0.63106, 0.63568, 0.63526, 0.63854, 0.63798, 0.63892, 0.63744,
-0.63508, 0.63174, 0.62382, 0.61502, 0.60592, 0.59614, 0.58754, 0.57742, 0.56774, 0.55344, 0.5418, 0.53048, 0.52166, 0.51468, 0.5076, 0.49974, 0.48858, 0.47564,
-0.46336, 0.45524, 0.44834, 0.44548, 0.4456, 0.44798, 0.44888, 0.44798, 0.44564, 0.44296, 0.44122, 0.43994, 0.44116, 0.4454, 0.45286, 0.46232, 0.4703, 0.48062,
-0.49012, 0.49576, 0.50148, 0.50278, 0.49934, 0.49514, 0.48622, 0.48098, 0.47656, 0.47656, 0.47988, 0.48252, 0.48708, 0.49334, 0.4989, 0.50832, 0.51646, 0.52816,
-0.544, 0.55772, 0.57662, 0.58968, 0.6032, 0.61546, 0.62594, 0.63268, 0.6409, 
-//910
-0.13596, 0.165, 0.21486, 0.2863, 0.36564, 0.4326, 0.46788, 0.48208, 0.48588, 0.48528, 0.48726, 0.48812, 0.4874, 0.48628, 0.48444, 0.48288, 0.48096, 0.47816,
-0.47514, 0.4715, 0.46456, 0.45672, 0.44922, 0.4431, 0.43736, 0.43018, 0.42058, 0.41084, 0.40014, 0.39188, 0.38664, 0.38102, 0.37572, 0.36772, 0.36058, 0.34988,
-0.33988, 0.33572, 0.33186, 0.32978, 0.32972, 0.33152, 0.33164, 0.3311, 0.32776, 0.32566, 0.3235, 0.32266, 0.3221, 0.32534, 0.33096, 0.33552, 0.34122, 0.34788,
-0.354, 0.35764, 0.36076, 0.36032, 0.35866, 0.3553, 0.35058, 0.34534, 0.3434, 0.34218, 0.34236, 0.34332, 0.3457, 0.34778, 0.35108, 0.3575, 0.36262, 0.3695,
-0.37896, 0.38656, 0.39464, 0.40104, 0.4059, 0.41006, 0.41322, 0.41516, 0.41966, 
-//911
-0.11472, 0.1382, 0.18206, 0.2348, 0.28764, 0.32264, 0.3397, 0.34538, 0.34778, 0.3463, 0.34636, 0.34638, 0.34622, 0.34594, 0.34378, 0.34172, 0.34142, 0.33888,
-0.33738, 0.33402, 0.32994, 0.32318, 0.31506, 0.30854, 0.3025, 0.29516, 0.28846, 0.27902, 0.27074, 0.26376, 0.25956, 0.25502, 0.25132, 0.24698, 0.24182, 0.2339,
-0.22612, 0.22064, 0.2185, 0.21638, 0.21652, 0.21732, 0.21836, 0.21684, 0.21632, 0.2154, 0.21468, 0.21382, 0.21484, 0.2164, 0.2214, 0.2263, 0.22966, 0.2358,
-0.23946, 0.24302, 0.24476, 0.24394, 0.243, 0.24132, 0.2373, 0.2337, 0.23008, 0.23224, 0.2337, 0.23572, 0.23852, 0.2416, 0.24518, 0.24942, 0.2541, 0.25894,
-0.26572, 0.27002, 0.27448, 0.27848, 0.28324, 0.28494, 0.28814, 0.28856, 0.28976, 
-//912
-0.11524, 0.13538, 0.16974, 0.20458, 0.23164, 0.24832, 0.2558, 0.25808, 0.25826, 0.25804, 0.25824, 0.2572, 0.25842, 0.25604, 0.25498, 0.25276, 0.25178, 0.24958,
-0.2484, 0.24584, 0.24162, 0.23552, 0.2298, 0.22398, 0.21876, 0.21112, 0.2044, 0.1977, 0.19134, 0.18488, 0.18138, 0.1782, 0.174, 0.17076, 0.1666, 0.16042,
-0.15212, 0.14978, 0.14562, 0.1449, 0.14548, 0.14606, 0.14698, 0.14704, 0.14748, 0.14584, 0.14428, 0.14426, 0.14426, 0.14576, 0.14874, 0.15152, 0.15542, 0.16036,
-0.1641, 0.16718, 0.16962, 0.16812, 0.16808, 0.16626, 0.16282, 0.15856, 0.1565, 0.15772, 0.1593, 0.16122, 0.16442, 0.16672, 0.16854, 0.17174, 0.17558, 0.17986,
-0.18492, 0.18938, 0.19492, 0.19832, 0.20288, 0.20648, 0.20882, 0.20874, 0.2099, 
-//913
-0.0996, 0.1137, 0.13304, 0.15192, 0.16506, 0.17236, 0.17416, 0.17598, 0.17586, 0.17536, 0.17602, 0.1763, 0.17696, 0.17584, 0.17506, 0.17286, 0.17228, 0.16894,
-0.16766, 0.16352, 0.1591, 0.15522, 0.15018, 0.14578, 0.14086, 0.13652, 0.13272, 0.12672, 0.12098, 0.11638, 0.11404, 0.11046, 0.10792, 0.10488, 0.10214, 0.0989,
-0.09184, 0.09206, 0.09056, 0.09122, 0.0911, 0.09064, 0.09124, 0.091, 0.08936, 0.08836, 0.08754, 0.08596, 0.08718, 0.08862, 0.0893, 0.09322, 0.0944, 0.09648,
-0.09982, 0.10288, 0.10168, 0.10124, 0.10116, 0.10092, 0.09874, 0.09468, 0.09364, 0.0953, 0.09562, 0.0973, 0.099, 0.10116, 0.1022, 0.10528, 0.10838, 0.11226,
-0.11526, 0.11832, 0.12286, 0.12546, 0.12852, 0.13102, 0.13354, 0.1343, 0.13562, 
-//914
-0.06904, 0.07842, 0.0863, 0.09638, 0.10094, 0.10574, 0.10848, 0.1086, 0.11132, 0.11404, 0.11324, 0.1168, 0.11788, 0.11904, 0.11744, 0.1155, 0.1158, 0.11198,
-0.11154, 0.11026, 0.10514, 0.10084, 0.09676, 0.09206, 0.08828, 0.0851, 0.08022, 0.07546, 0.07184, 0.06822, 0.06622, 0.06446, 0.06256, 0.0617, 0.05912, 0.05606,
-0.052, 0.05214, 0.05246, 0.05328, 0.05102, 0.05052, 0.05048, 0.04952, 0.0495, 0.04966, 0.04796, 0.04912, 0.0496, 0.04996, 0.05128, 0.05126, 0.0522, 0.05332,
-0.05284, 0.05276, 0.05146, 0.05098, 0.05118, 0.05126, 0.05232, 0.05156, 0.05192, 0.05352, 0.0543, 0.05458, 0.05654, 0.05796, 0.06136, 0.06366, 0.06574, 0.06898,
-0.07276, 0.07826, 0.08448, 0.09226, 0.10178, 0.10786, 0.11262, 0.11608, 0.1181, 
-//915
-0.05464, 0.05822, 0.06324, 0.06726, 0.07094, 0.07484, 0.07668, 0.0778, 0.07866, 0.07916, 0.08062, 0.08298, 0.08634, 0.0889, 0.09046, 0.09114, 0.09156, 0.09124,
-0.09098, 0.08904, 0.08728, 0.08354, 0.07932, 0.07462, 0.07098, 0.06566, 0.06072, 0.0563, 0.05218, 0.04954, 0.04688, 0.04514, 0.04314, 0.04096, 0.04038, 0.03878,
-0.03566, 0.03706, 0.03742, 0.03618, 0.03728, 0.0366, 0.03618, 0.0362, 0.03598, 0.03602, 0.03556, 0.03594, 0.03768, 0.03816, 0.03936, 0.03938, 0.0399, 0.04036,
-0.04066, 0.04004, 0.039, 0.03658, 0.03776, 0.03792, 0.03794, 0.0373, 0.0385, 0.04, 0.04084, 0.04356, 0.04548, 0.04488, 0.04634, 0.0471, 0.049, 0.05064, 0.05166,
-0.05374, 0.05598, 0.05978, 0.06352, 0.06776, 0.07184, 0.07586, 0.08068, 
-//916
-0.13748, 0.16628, 0.2229, 0.3063, 0.41064, 0.52184, 0.61046, 0.6611, 0.68296, 0.69224, 0.69872, 0.7062, 0.71286, 0.71392, 0.71544, 0.71474, 0.71288, 0.70998,
-0.70408, 0.69608, 0.68194, 0.6632, 0.64666, 0.6324, 0.6193, 0.60376, 0.58772, 0.56856, 0.5521, 0.53662, 0.5262, 0.51602, 0.50418, 0.49104, 0.47606, 0.45934,
-0.44258, 0.43076, 0.42322, 0.41754, 0.41536, 0.41602, 0.4166, 0.41506, 0.41226, 0.40856, 0.40748, 0.40754, 0.40852, 0.41446, 0.42358, 0.43516, 0.44612, 0.45698,
-0.46738, 0.47426, 0.48026, 0.47974, 0.47634, 0.46844, 0.45916, 0.45102, 0.44508, 0.44616, 0.4484, 0.45314, 0.45952, 0.46678, 0.47408, 0.48686, 0.49744, 0.51294,
-0.53536, 0.55626, 0.57814, 0.60278, 0.62318, 0.6429, 0.65888, 0.67318, 0.6899, 
-//917
-0.12648, 0.15676, 0.20826, 0.28568, 0.38038, 0.47108, 0.53172, 0.56244, 0.57234, 0.57688, 0.58222, 0.58262, 0.58896, 0.58998, 0.58914, 0.5873, 0.5848, 0.58088,
-0.57314, 0.56766, 0.55632, 0.54216, 0.52872, 0.51504, 0.50296, 0.48918, 0.47344, 0.45698, 0.44146, 0.42754, 0.41642, 0.40664, 0.39592, 0.3838, 0.37124, 0.35496,
-0.33864, 0.32896, 0.32232, 0.319, 0.31816, 0.3176, 0.31996, 0.31948, 0.31584, 0.31414, 0.31322, 0.3132, 0.31488, 0.32138, 0.32948, 0.3398, 0.34924, 0.35848,
-0.36834, 0.3748, 0.38052, 0.37952, 0.37488, 0.36968, 0.3594, 0.35274, 0.34966, 0.3506, 0.35302, 0.35752, 0.36404, 0.36932, 0.37556, 0.38294, 0.39404, 0.4075,
-0.4247, 0.4397, 0.45582, 0.4723, 0.48702, 0.5002, 0.51346, 0.52362, 0.5307, 
-//918
-0.1248, 0.15222, 0.19746, 0.25818, 0.32256, 0.37294, 0.40126, 0.41154, 0.41558, 0.41782, 0.42066, 0.42046, 0.4228, 0.4243, 0.4237, 0.42016, 0.41704, 0.41306,
-0.40782, 0.40224, 0.3932, 0.38192, 0.36962, 0.35866, 0.3473, 0.33598, 0.32442, 0.3099, 0.2967, 0.28618, 0.27732, 0.2701, 0.26228, 0.25414, 0.24418, 0.2329,
-0.21996, 0.21462, 0.209, 0.20684, 0.20596, 0.2063, 0.20746, 0.20552, 0.20464, 0.20284, 0.203, 0.20192, 0.203, 0.20814, 0.21348, 0.22174, 0.22898, 0.23664,
-0.24364, 0.24888, 0.25258, 0.25188, 0.24848, 0.24542, 0.23916, 0.23448, 0.23076, 0.23108, 0.23378, 0.23782, 0.24196, 0.24678, 0.25158, 0.25884, 0.26772,
-0.27726, 0.28918, 0.30062, 0.3154, 0.32684, 0.33814, 0.34736, 0.35438, 0.35956, 0.367, 
-//919
-0.12392, 0.14612, 0.18282, 0.22446, 0.26208, 0.28478, 0.29664, 0.30116, 0.30292, 0.3038, 0.30592, 0.30836, 0.30872, 0.30838, 0.30798, 0.3062, 0.30368, 0.29988,
-0.29578, 0.29032, 0.28258, 0.27278, 0.26188, 0.25246, 0.2446, 0.2344, 0.22436, 0.21314, 0.20236, 0.19376, 0.18686, 0.17988, 0.1747, 0.16774, 0.16036, 0.15154,
-0.14192, 0.14088, 0.13622, 0.13472, 0.13338, 0.13308, 0.13424, 0.13238, 0.13154, 0.12968, 0.13004, 0.1298, 0.13044, 0.13294, 0.13722, 0.14268, 0.1476, 0.15314,
-0.1574, 0.1612, 0.1637, 0.16078, 0.16096, 0.15842, 0.15226, 0.1488, 0.14502, 0.14762, 0.14872, 0.15066, 0.15498, 0.1592, 0.16238, 0.16786, 0.17302, 0.17978,
-0.18846, 0.19754, 0.20602, 0.21482, 0.22158, 0.22924, 0.23512, 0.24112, 0.24666, 
-//920
-0.10244, 0.11776, 0.13982, 0.16138, 0.18016, 0.1899, 0.19456, 0.19666, 0.19684, 0.19816, 0.2002, 0.20032, 0.20216, 0.20414, 0.20456, 0.2035, 0.20142, 0.19952,
-0.19566, 0.19182, 0.18464, 0.17798, 0.1691, 0.16082, 0.15494, 0.14748, 0.14048, 0.13234, 0.12618, 0.11954, 0.11472, 0.11066, 0.10564, 0.10114, 0.09524, 0.08874,
-0.08228, 0.08178, 0.07946, 0.0791, 0.0792, 0.07896, 0.07964, 0.07906, 0.07864, 0.07724, 0.0777, 0.07692, 0.07778, 0.07764, 0.07986, 0.083, 0.08602, 0.08978,
-0.09206, 0.09398, 0.09508, 0.09348, 0.0931, 0.09224, 0.08908, 0.0853, 0.08374, 0.0875, 0.08808, 0.09044, 0.09266, 0.09428, 0.09626, 0.09852, 0.10248, 0.10732,
-0.11376, 0.11834, 0.12574, 0.13282, 0.1378, 0.14332, 0.1493, 0.1524, 0.1587, 
-//921
-0.07142, 0.07436, 0.08252, 0.09128, 0.09956, 0.10784, 0.11374, 0.11172, 0.11564, 0.12026, 0.1228, 0.13374, 0.14526, 0.15266, 0.1559, 0.15758, 0.1577, 0.1569,
-0.15456, 0.15014, 0.14452, 0.13686, 0.12936, 0.12202, 0.11374, 0.10646, 0.09778, 0.08936, 0.0818, 0.075, 0.06798, 0.06262, 0.05874, 0.05504, 0.05154, 0.04834,
-0.04362, 0.04558, 0.04658, 0.04646, 0.04556, 0.04594, 0.04672, 0.04778, 0.04768, 0.04698, 0.0476, 0.04806, 0.0484, 0.04954, 0.04896, 0.0491, 0.05108, 0.05188,
-0.05166, 0.05164, 0.05142, 0.05002, 0.0508, 0.0523, 0.05212, 0.05088, 0.05194, 0.05388, 0.05602, 0.05762, 0.05872, 0.05992, 0.0624, 0.0654, 0.0673, 0.06996,
-0.0721, 0.07632, 0.0831, 0.08998, 0.09922, 0.10806, 0.11608, 0.12434, 0.13216, 
-//922
-0.12812, 0.15662, 0.21166, 0.29032, 0.38708, 0.48194, 0.54992, 0.58404, 0.59742, 0.60908, 0.61868, 0.62778, 0.63908, 0.64648, 0.64972, 0.6476, 0.64412, 0.63714,
-0.62884, 0.61492, 0.59546, 0.57302, 0.55012, 0.53026, 0.51156, 0.49184, 0.47006, 0.44672, 0.42684, 0.40994, 0.3944, 0.3807, 0.36776, 0.35272, 0.33684, 0.31856,
-0.3002, 0.28944, 0.28146, 0.27686, 0.2747, 0.27426, 0.27418, 0.27244, 0.27026, 0.26482, 0.26372, 0.26388, 0.2659, 0.27094, 0.27812, 0.28692, 0.29682, 0.30752,
-0.3177, 0.32456, 0.32898, 0.3287, 0.324, 0.31782, 0.30934, 0.30108, 0.29556, 0.29698, 0.29706, 0.29974, 0.30468, 0.31056, 0.31628, 0.32584, 0.33668, 0.35156,
-0.37134, 0.39184, 0.41866, 0.44154, 0.46526, 0.48648, 0.5055, 0.52632, 0.55218, 
-//923
-0.12194, 0.14874, 0.1954, 0.26252, 0.33706, 0.40076, 0.43968, 0.4578, 0.4645, 0.46922, 0.47514, 0.4807, 0.48628, 0.48946, 0.49124, 0.49062, 0.48618, 0.48092,
-0.4736, 0.4637, 0.44818, 0.42968, 0.41128, 0.3938, 0.37712, 0.36036, 0.34304, 0.32516, 0.30782, 0.29342, 0.28244, 0.27072, 0.26002, 0.24662, 0.2341, 0.21834,
-0.20256, 0.19632, 0.19028, 0.18676, 0.18616, 0.1849, 0.18472, 0.18288, 0.17996, 0.17768, 0.1766, 0.1766, 0.178, 0.18374, 0.19172, 0.19998, 0.20816, 0.21588,
-0.22348, 0.22914, 0.23224, 0.22962, 0.2264, 0.22072, 0.21404, 0.2077, 0.20562, 0.20622, 0.20852, 0.21238, 0.21834, 0.22284, 0.23008, 0.23666, 0.24692, 0.25916,
-0.27498, 0.29584, 0.31314, 0.33376, 0.35384, 0.37178, 0.38864, 0.40258, 0.4137, 
-//924
-0.11178, 0.1329, 0.17046, 0.218, 0.26486, 0.29846, 0.31696, 0.32728, 0.3298, 0.33474, 0.34088, 0.34562, 0.35186, 0.35592, 0.35646, 0.355, 0.3516, 0.34838,
-0.34276, 0.33432, 0.32348, 0.30822, 0.29258, 0.27608, 0.26364, 0.25146, 0.23808, 0.2225, 0.20774, 0.19624, 0.18852, 0.18052, 0.17116, 0.1616, 0.1519, 0.13984,
-0.12798, 0.12562, 0.1213, 0.11956, 0.11866, 0.11784, 0.11716, 0.1155, 0.11434, 0.11218, 0.11164, 0.1126, 0.11462, 0.11816, 0.12244, 0.12824, 0.13408, 0.13928,
-0.14514, 0.14942, 0.15082, 0.1485, 0.14726, 0.14354, 0.1393, 0.13378, 0.13258, 0.13558, 0.1364, 0.13928, 0.14218, 0.14674, 0.15194, 0.1567, 0.16404, 0.17506,
-0.18802, 0.20276, 0.21878, 0.23608, 0.25224, 0.26674, 0.28104, 0.29162, 0.30066, 
-//925
-0.09202, 0.10554, 0.13056, 0.15886, 0.1848, 0.20224, 0.21542, 0.2199, 0.2221, 0.22876, 0.23482, 0.24148, 0.24898, 0.25278, 0.25586, 0.2559, 0.2541, 0.25044,
-0.24586, 0.2393, 0.2292, 0.21788, 0.20442, 0.19242, 0.18178, 0.17112, 0.16024, 0.14762, 0.13642, 0.12674, 0.11878, 0.11106, 0.1044, 0.09614, 0.08788, 0.08036,
-0.07252, 0.07138, 0.06786, 0.06794, 0.06738, 0.06798, 0.06772, 0.06654, 0.06642, 0.06526, 0.06602, 0.06512, 0.06552, 0.06744, 0.06944, 0.0722, 0.07524, 0.07824,
-0.0816, 0.08366, 0.08514, 0.08344, 0.08314, 0.08096, 0.07878, 0.07486, 0.07232, 0.07608, 0.0767, 0.07924, 0.0815, 0.08406, 0.08648, 0.09118, 0.09524, 0.10264,
-0.11088, 0.12086, 0.13162, 0.1451, 0.15884, 0.16946, 0.18094, 0.1919, 0.20284, 
-//926
-0.06372, 0.06538, 0.07276, 0.079, 0.08754, 0.09506, 0.1012, 0.10112, 0.10876, 0.11558, 0.12034, 0.13952, 0.1606, 0.17534, 0.18262, 0.18738, 0.19074, 0.19112,
-0.18894, 0.18082, 0.17064, 0.15732, 0.14486, 0.1331, 0.1211, 0.10902, 0.09698, 0.08578, 0.07576, 0.06772, 0.06142, 0.05614, 0.0517, 0.04818, 0.04498, 0.04218,
-0.03778, 0.04036, 0.0387, 0.0391, 0.03922, 0.0397, 0.03984, 0.04078, 0.04186, 0.04246, 0.04344, 0.04312, 0.04494, 0.04494, 0.04466, 0.04432, 0.046, 0.04552,
-0.04552, 0.04634, 0.04706, 0.04538, 0.04706, 0.04808, 0.0488, 0.04716, 0.04758, 0.04986, 0.05138, 0.05292, 0.05396, 0.05634, 0.05884, 0.0608, 0.06252, 0.0658,
-0.06808, 0.07166, 0.07744, 0.08418, 0.09676, 0.11136, 0.12776, 0.14412, 0.16336, 
-//927
-0.1151, 0.14058, 0.18796, 0.25398, 0.33358, 0.4054, 0.45486, 0.4781, 0.48746, 0.4984, 0.51292, 0.52528, 0.54404, 0.55736, 0.565, 0.56636, 0.5648, 0.55808,
-0.54868, 0.53318, 0.5118, 0.48766, 0.46294, 0.44172, 0.42068, 0.39738, 0.3746, 0.3512, 0.33034, 0.3107, 0.29312, 0.27524, 0.25802, 0.24052, 0.22312, 0.20564,
-0.18702, 0.17764, 0.1713, 0.16704, 0.16292, 0.16218, 0.1614, 0.15834, 0.1562, 0.15282, 0.15258, 0.1537, 0.15694, 0.16006, 0.16718, 0.17614, 0.1828, 0.19146,
-0.19708, 0.20254, 0.20542, 0.20114, 0.19842, 0.19274, 0.18454, 0.17702, 0.175, 0.1762, 0.17854, 0.18246, 0.1866, 0.19194, 0.19732, 0.20632, 0.21544, 0.22852,
-0.24724, 0.26792, 0.29102, 0.31466, 0.33964, 0.36422, 0.387, 0.41046, 0.42876, 
-//928
-0.11476, 0.13706, 0.17784, 0.22904, 0.2822, 0.32446, 0.3509, 0.3639, 0.36904, 0.37768, 0.38806, 0.39788, 0.41248, 0.42322, 0.4274, 0.42762, 0.42424, 0.41714,
-0.409, 0.39548, 0.3787, 0.35704, 0.33596, 0.31536, 0.29688, 0.27762, 0.25876, 0.23826, 0.22144, 0.20642, 0.19328, 0.18288, 0.1702, 0.1572, 0.14576, 0.13392,
-0.12152, 0.11616, 0.11118, 0.1081, 0.10624, 0.10328, 0.10318, 0.10268, 0.10208, 0.1005, 0.10024, 0.09976, 0.10092, 0.10414, 0.10742, 0.11014, 0.11456, 0.11866,
-0.12198, 0.12444, 0.12682, 0.12524, 0.12394, 0.12256, 0.11896, 0.11408, 0.11116, 0.11254, 0.114, 0.11598, 0.11736, 0.11968, 0.12276, 0.12798, 0.13298, 0.14068,
-0.15278, 0.165, 0.18248, 0.1989, 0.21564, 0.2319, 0.24908, 0.26438, 0.28254, 
-//929
-0.10036, 0.11688, 0.14196, 0.16936, 0.1972, 0.21878, 0.23576, 0.2442, 0.24774, 0.25794, 0.27024, 0.28316, 0.30094, 0.31896, 0.33026, 0.33382, 0.33318, 0.32762,
-0.31952, 0.3082, 0.29168, 0.27204, 0.25048, 0.23346, 0.21622, 0.1994, 0.18118, 0.16366, 0.14848, 0.1333, 0.1213, 0.11, 0.09988, 0.09096, 0.08168, 0.0758,
-0.06912, 0.06768, 0.0659, 0.0642, 0.06322, 0.06304, 0.06234, 0.06224, 0.06294, 0.06234, 0.0642, 0.06366, 0.0648, 0.06622, 0.06764, 0.069, 0.07036, 0.07158,
-0.07216, 0.07188, 0.07366, 0.07138, 0.07224, 0.07162, 0.06982, 0.06778, 0.06698, 0.06946, 0.07088, 0.0721, 0.07408, 0.07612, 0.0769, 0.07932, 0.08182, 0.08502,
-0.09072, 0.09578, 0.1034, 0.11192, 0.12216, 0.13138, 0.14208, 0.15606, 0.1688, 
-//930
-0.11894, 0.1412, 0.18502, 0.23884, 0.298, 0.34768, 0.37858, 0.39466, 0.4023, 0.4151, 0.43064, 0.44384, 0.46434, 0.48208, 0.49298, 0.49392, 0.4906, 0.48254,
-0.4707, 0.45482, 0.43268, 0.40614, 0.37932, 0.35398, 0.33204, 0.3086, 0.2855, 0.26262, 0.24048, 0.22128, 0.20532, 0.19028, 0.17618, 0.16054, 0.14656, 0.13278,
-0.11852, 0.1142, 0.1085, 0.10512, 0.10262, 0.1018, 0.10032, 0.09852, 0.0971, 0.09606, 0.0961, 0.09738, 0.09852, 0.10176, 0.10608, 0.11084, 0.11596, 0.12032,
-0.12422, 0.12784, 0.12884, 0.12642, 0.12402, 0.12112, 0.1167, 0.11172, 0.10992, 0.11178, 0.1131, 0.11504, 0.11838, 0.12238, 0.12554, 0.13188, 0.1387, 0.14758,
-0.15976, 0.17644, 0.19478, 0.21504, 0.23606, 0.25758, 0.28048, 0.30364, 0.32586, 
-//931
-0.08644, 0.09902, 0.1206, 0.14582, 0.16932, 0.19166, 0.2085, 0.21702, 0.22372, 0.2359, 0.2504, 0.26726, 0.29454, 0.32302, 0.34394, 0.35508, 0.35868, 0.35696,
-0.35002, 0.33582, 0.31822, 0.29504, 0.27452, 0.2509, 0.22838, 0.2055, 0.18324, 0.16148, 0.14206, 0.12426, 0.10908, 0.09612, 0.08738, 0.07812, 0.07122, 0.06432,
-0.05808, 0.05748, 0.05546, 0.05384, 0.0529, 0.05354, 0.05342, 0.05328, 0.05372, 0.05432, 0.05398, 0.05622, 0.05524, 0.05608, 0.05724, 0.05732, 0.05878, 0.05952,
-0.06072, 0.06064, 0.0607, 0.0602, 0.06004, 0.06068, 0.0595, 0.058, 0.057, 0.05798, 0.05914, 0.06098, 0.06218, 0.06452, 0.06578, 0.0679, 0.07168, 0.07372,
-0.0789, 0.0826, 0.08922, 0.09802, 0.10546, 0.11374, 0.1244, 0.13652, 0.15128, 
-//932
-0.14112, 0.17098, 0.2266, 0.31044, 0.41996, 0.5413, 0.64608, 0.7098, 0.73952, 0.75152, 0.75686, 0.76128, 0.7656, 0.76536, 0.76576, 0.76638, 0.76796, 0.76714,
-0.767, 0.76368, 0.75874, 0.75144, 0.7441, 0.73874, 0.73376, 0.72728, 0.71938, 0.70888, 0.70002, 0.69256, 0.68838, 0.68432, 0.68174, 0.67996, 0.67538, 0.67142,
-0.6647, 0.66174, 0.66014, 0.6624, 0.66456, 0.669, 0.6731, 0.67494, 0.67558, 0.67384, 0.6708, 0.66754, 0.6678, 0.66896, 0.6738, 0.6807, 0.68896, 0.69748,
-0.70548, 0.71068, 0.71166, 0.70762, 0.7061, 0.70708, 0.71, 0.71198, 0.71494, 0.71534, 0.71722, 0.7201, 0.7227, 0.7262, 0.72906, 0.7367, 0.74174, 0.74738,
-0.75266, 0.75764, 0.7644, 0.771, 0.77542, 0.77758, 0.78308, 0.78136, 0.78434, 
-//933
-0.13564, 0.16346, 0.21704, 0.29564, 0.38916, 0.4762, 0.53054, 0.55476, 0.56082, 0.56162, 0.56236, 0.56054, 0.559, 0.55766, 0.55566, 0.55486, 0.55344, 0.55184,
-0.55072, 0.54848, 0.54404, 0.53938, 0.53432, 0.52982, 0.52672, 0.52288, 0.51706, 0.51082, 0.50372, 0.49992, 0.49774, 0.4959, 0.49464, 0.49362, 0.49298, 0.48898,
-0.48396, 0.48516, 0.48502, 0.48564, 0.48854, 0.49124, 0.49474, 0.4948, 0.4945, 0.49218, 0.48938, 0.48574, 0.4819, 0.48142, 0.48338, 0.48534, 0.48908, 0.49284,
-0.49608, 0.49546, 0.49792, 0.49612, 0.4968, 0.4962, 0.4961, 0.49302, 0.49314, 0.49382, 0.4937, 0.49406, 0.49612, 0.49498, 0.49712, 0.5002, 0.5002, 0.50088,
-0.50308, 0.50162, 0.50058, 0.50222, 0.5, 0.49948, 0.50226, 0.49744, 0.49998, 
-//934
-0.1344, 0.16374, 0.2133, 0.27638, 0.34322, 0.39356, 0.4166, 0.4246, 0.4255, 0.42326, 0.42212, 0.42258, 0.4214, 0.41932, 0.4167, 0.41556, 0.41492, 0.41414,
-0.41182, 0.41074, 0.40608, 0.40072, 0.39656, 0.39206, 0.389, 0.38554, 0.38052, 0.37372, 0.36812, 0.36518, 0.36274, 0.36022, 0.35966, 0.3586, 0.35708, 0.35406,
-0.34934, 0.35054, 0.35066, 0.35176, 0.3538, 0.35788, 0.35986, 0.36136, 0.36172, 0.36128, 0.3588, 0.35472, 0.35078, 0.34994, 0.34974, 0.35058, 0.35278, 0.35444,
-0.3571, 0.35722, 0.35864, 0.35756, 0.35724, 0.35868, 0.35712, 0.35342, 0.35176, 0.35458, 0.35418, 0.3545, 0.3564, 0.35812, 0.35916, 0.36054, 0.36022, 0.35992,
-0.36152, 0.36066, 0.36168, 0.36188, 0.36084, 0.35984, 0.35942, 0.35828, 0.36088, 
-//935
-0.12528, 0.14696, 0.18712, 0.23214, 0.272, 0.29688, 0.30688, 0.30912, 0.30976, 0.30916, 0.3077, 0.3069, 0.30594, 0.30332, 0.30224, 0.29968, 0.29884, 0.29736,
-0.29394, 0.29134, 0.28858, 0.28486, 0.28084, 0.27874, 0.27546, 0.27162, 0.26746, 0.26232, 0.25824, 0.25464, 0.25252, 0.24988, 0.24874, 0.24836, 0.2475, 0.24594,
-0.24166, 0.242, 0.24334, 0.2446, 0.24644, 0.24856, 0.25224, 0.25282, 0.25438, 0.25256, 0.25056, 0.24858, 0.24626, 0.24396, 0.24402, 0.24698, 0.24816, 0.24958,
-0.25274, 0.25454, 0.25582, 0.2549, 0.25582, 0.25724, 0.25636, 0.25452, 0.25254, 0.25472, 0.25582, 0.25558, 0.25616, 0.2576, 0.25706, 0.2587, 0.25824, 0.25768,
-0.25576, 0.25522, 0.25512, 0.2532, 0.25444, 0.25444, 0.25578, 0.2539, 0.25496, 
-//936
-0.10524, 0.12348, 0.14964, 0.1772, 0.19654, 0.20686, 0.21092, 0.20984, 0.20948, 0.20818, 0.20742, 0.20602, 0.2057, 0.20182, 0.1997, 0.19996, 0.19902, 0.19874,
-0.1977, 0.19516, 0.19364, 0.19074, 0.18808, 0.18384, 0.18046, 0.1775, 0.17422, 0.1712, 0.16742, 0.1639, 0.16286, 0.16314, 0.16196, 0.16094, 0.16, 0.15764,
-0.1521, 0.15412, 0.15378, 0.15472, 0.1563, 0.15792, 0.16026, 0.16204, 0.16126, 0.16234, 0.16054, 0.15796, 0.15604, 0.15524, 0.15574, 0.15666, 0.15796, 0.15916,
-0.16014, 0.16212, 0.1623, 0.16224, 0.16316, 0.16516, 0.16366, 0.1609, 0.16098, 0.1628, 0.16278, 0.16384, 0.16494, 0.16478, 0.16578, 0.16586, 0.1664, 0.1663,
-0.16698, 0.1679, 0.16768, 0.16812, 0.1684, 0.1689, 0.16842, 0.16774, 0.16762, 
-//937
-0.09364, 0.10518, 0.1197, 0.13208, 0.14034, 0.1447, 0.14524, 0.1454, 0.1438, 0.14302, 0.14254, 0.14196, 0.141, 0.13844, 0.139, 0.13848, 0.13792, 0.13718,
-0.13608, 0.13412, 0.13258, 0.12958, 0.1263, 0.12306, 0.12054, 0.11656, 0.11484, 0.11204, 0.1095, 0.10638, 0.10522, 0.10496, 0.1045, 0.10504, 0.10322, 0.10062,
-0.09808, 0.09958, 0.09968, 0.10008, 0.1, 0.1015, 0.1037, 0.10398, 0.10584, 0.10458, 0.10432, 0.1028, 0.10202, 0.10192, 0.10118, 0.10282, 0.10548, 0.10642,
-0.10766, 0.10882, 0.11054, 0.10894, 0.11176, 0.11234, 0.1122, 0.11032, 0.1095, 0.11114, 0.11196, 0.11212, 0.11396, 0.11414, 0.11602, 0.1164, 0.11706, 0.11798,
-0.1188, 0.11846, 0.12006, 0.12002, 0.12178, 0.12232, 0.12172, 0.12268, 0.12278, 
-//938
-0.07186, 0.07586, 0.08206, 0.08732, 0.09144, 0.09312, 0.094, 0.09386, 0.0932, 0.09264, 0.09318, 0.0925, 0.09226, 0.09068, 0.09042, 0.08838, 0.08732, 0.08648,
-0.08624, 0.08462, 0.08228, 0.08096, 0.07734, 0.07366, 0.0714, 0.0691, 0.0659, 0.06384, 0.06262, 0.06004, 0.05858, 0.05884, 0.05916, 0.05876, 0.05854, 0.05774,
-0.05534, 0.0564, 0.0553, 0.05558, 0.0567, 0.05784, 0.05972, 0.05958, 0.0602, 0.0594, 0.0595, 0.05882, 0.05914, 0.05828, 0.05912, 0.06038, 0.0606, 0.06302,
-0.06434, 0.06604, 0.06832, 0.06784, 0.06904, 0.06948, 0.06902, 0.06672, 0.06598, 0.06886, 0.06944, 0.07026, 0.07032, 0.0727, 0.07284, 0.07458, 0.07672, 0.0775,
-0.08036, 0.08168, 0.0828, 0.08466, 0.08568, 0.0874, 0.08796, 0.08814, 0.08892, 
-//939
-0.06504, 0.06414, 0.06666, 0.06856, 0.07104, 0.07138, 0.07206, 0.07296, 0.07254, 0.07058, 0.07032, 0.07046, 0.07114, 0.07064, 0.07176, 0.07052, 0.06992,
-0.06912, 0.0692, 0.06844, 0.06652, 0.0649, 0.06106, 0.0598, 0.05838, 0.05656, 0.05336, 0.05214, 0.052, 0.04994, 0.04936, 0.04774, 0.04764, 0.04606, 0.04452,
-0.04474, 0.04198, 0.04352, 0.04346, 0.04372, 0.04484, 0.04568, 0.0457, 0.04596, 0.04638, 0.04678, 0.04688, 0.04736, 0.04822, 0.04866, 0.04834, 0.04948, 0.04948,
-0.05038, 0.0498, 0.05048, 0.051, 0.04848, 0.0487, 0.04916, 0.04898, 0.04754, 0.04934, 0.05224, 0.05348, 0.05392, 0.0552, 0.05578, 0.0557, 0.05712, 0.05866,
-0.05912, 0.06086, 0.0622, 0.0643, 0.06588, 0.06846, 0.07014, 0.07034, 0.0716, 0.07166, 
-//940
-0.12488, 0.15274, 0.2043, 0.28164, 0.38016, 0.48656, 0.56834, 0.6117, 0.63098, 0.63892, 0.64204, 0.64638, 0.64866, 0.6486, 0.64762, 0.64696, 0.64574, 0.64286,
-0.63908, 0.63246, 0.62182, 0.60854, 0.59446, 0.58322, 0.57012, 0.55722, 0.543, 0.52574, 0.5105, 0.49782, 0.49116, 0.48656, 0.4827, 0.47744, 0.47006, 0.46128,
-0.44988, 0.44564, 0.44432, 0.444, 0.44828, 0.45392, 0.45782, 0.4584, 0.45696, 0.45362, 0.45192, 0.44974, 0.44876, 0.45278, 0.46036, 0.47132, 0.4821, 0.4931,
-0.50414, 0.51162, 0.51764, 0.52002, 0.51734, 0.51366, 0.50488, 0.49934, 0.49602, 0.49566, 0.49854, 0.50114, 0.50602, 0.51122, 0.5168, 0.52614, 0.53412, 0.54384,
-0.55684, 0.56818, 0.58148, 0.59142, 0.60228, 0.61098, 0.61942, 0.62476, 0.6281, 
-//941
-0.12286, 0.14648, 0.19432, 0.2617, 0.33996, 0.40644, 0.44638, 0.46238, 0.46936, 0.47164, 0.47246, 0.4743, 0.47416, 0.4733, 0.47332, 0.47, 0.46844, 0.46298,
-0.4572, 0.45256, 0.44514, 0.43454, 0.42386, 0.41538, 0.4065, 0.39718, 0.386, 0.37194, 0.36106, 0.35232, 0.34644, 0.3444, 0.33964, 0.33448, 0.33054, 0.32542,
-0.31596, 0.3139, 0.31268, 0.31624, 0.319, 0.32414, 0.32856, 0.33184, 0.33294, 0.33028, 0.32788, 0.32478, 0.32266, 0.3246, 0.32948, 0.33626, 0.34546, 0.35498,
-0.36286, 0.37012, 0.3742, 0.37464, 0.37578, 0.37462, 0.37062, 0.36818, 0.36644, 0.36762, 0.36824, 0.3718, 0.37604, 0.37732, 0.38128, 0.38546, 0.38832, 0.39368,
-0.39774, 0.40176, 0.40694, 0.41036, 0.41402, 0.41548, 0.42278, 0.42166, 0.42152, 
-//942
-0.12614, 0.15022, 0.19104, 0.24354, 0.29644, 0.33164, 0.34672, 0.3521, 0.35454, 0.3535, 0.3536, 0.35272, 0.35224, 0.35106, 0.34862, 0.34654, 0.34404, 0.33966,
-0.33752, 0.33302, 0.32606, 0.31764, 0.3086, 0.30048, 0.2941, 0.2861, 0.27702, 0.26814, 0.25868, 0.2528, 0.2472, 0.24354, 0.24236, 0.24064, 0.23668, 0.23172,
-0.2262, 0.22552, 0.225, 0.22798, 0.23052, 0.23592, 0.2407, 0.2435, 0.24416, 0.24266, 0.24008, 0.23688, 0.2356, 0.23652, 0.23864, 0.24564, 0.25324, 0.26062,
-0.2696, 0.27614, 0.27986, 0.27784, 0.27668, 0.27444, 0.27262, 0.26836, 0.26886, 0.26782, 0.26836, 0.27052, 0.27438, 0.27638, 0.27984, 0.28236, 0.28522, 0.28816,
-0.29388, 0.29432, 0.29628, 0.2996, 0.30198, 0.2993, 0.30268, 0.30114, 0.30378, 
-//943
-0.11602, 0.13454, 0.16514, 0.19878, 0.22304, 0.23956, 0.24652, 0.24874, 0.24868, 0.24716, 0.24838, 0.24818, 0.24672, 0.24608, 0.24416, 0.2414, 0.23882, 0.23708,
-0.23418, 0.23126, 0.22634, 0.22064, 0.21422, 0.20852, 0.20354, 0.19736, 0.19078, 0.18342, 0.17626, 0.17198, 0.16812, 0.1653, 0.16442, 0.16058, 0.15956, 0.1557,
-0.14786, 0.1509, 0.15056, 0.15076, 0.15212, 0.15496, 0.15744, 0.15946, 0.1596, 0.15856, 0.15658, 0.15502, 0.15354, 0.1536, 0.15674, 0.1599, 0.16416, 0.16862,
-0.17394, 0.1771, 0.17874, 0.17868, 0.1795, 0.1793, 0.17802, 0.17502, 0.1753, 0.17676, 0.17732, 0.17866, 0.17998, 0.1803, 0.18156, 0.18462, 0.18722, 0.18898,
-0.19256, 0.19474, 0.19812, 0.2016, 0.20278, 0.20478, 0.20614, 0.205, 0.20654, 
-//944
-0.10362, 0.1161, 0.13678, 0.15448, 0.16766, 0.17466, 0.17844, 0.17842, 0.179, 0.17916, 0.18078, 0.1812, 0.18002, 0.17954, 0.17808, 0.17718, 0.17498, 0.17198,
-0.1691, 0.16504, 0.1611, 0.1531, 0.1466, 0.14124, 0.13604, 0.1313, 0.1268, 0.12014, 0.11488, 0.11034, 0.1077, 0.10492, 0.1038, 0.10296, 0.09966, 0.0958,
-0.09058, 0.09276, 0.09226, 0.09308, 0.09438, 0.09706, 0.09918, 0.10066, 0.1014, 0.1003, 0.0985, 0.09766, 0.09672, 0.09642, 0.09924, 0.1024, 0.10618, 0.11018,
-0.1149, 0.11866, 0.12196, 0.1215, 0.12322, 0.12364, 0.1221, 0.11836, 0.11938, 0.12104, 0.12166, 0.12246, 0.12438, 0.12724, 0.12938, 0.13136, 0.13398, 0.13788,
-0.14108, 0.14526, 0.14904, 0.15156, 0.1536, 0.15574, 0.15752, 0.15596, 0.15746, 
-//945
-0.07978, 0.08686, 0.09572, 0.1042, 0.11162, 0.11552, 0.11648, 0.11666, 0.1165, 0.1171, 0.11816, 0.11828, 0.11774, 0.1186, 0.11782, 0.11646, 0.11444, 0.11248,
-0.11044, 0.10624, 0.10014, 0.0945, 0.08866, 0.08314, 0.07818, 0.07422, 0.07106, 0.06866, 0.06582, 0.06324, 0.063, 0.06094, 0.06082, 0.0601, 0.0584, 0.05626,
-0.05204, 0.0563, 0.05658, 0.05684, 0.05654, 0.05686, 0.05622, 0.05598, 0.05562, 0.05538, 0.05554, 0.05588, 0.05634, 0.0572, 0.05892, 0.05966, 0.06172, 0.06342,
-0.06434, 0.06428, 0.06506, 0.06346, 0.06516, 0.06528, 0.06556, 0.06264, 0.06292, 0.0636, 0.0651, 0.06512, 0.06736, 0.06936, 0.07136, 0.07248, 0.07528, 0.07764,
-0.08112, 0.08486, 0.08834, 0.09198, 0.09502, 0.09744, 0.1007, 0.10126, 0.10488, 
-//946
-0.06152, 0.06286, 0.06742, 0.073, 0.07782, 0.08186, 0.08322, 0.08534, 0.0859, 0.08744, 0.08954, 0.09118, 0.09358, 0.09492, 0.09398, 0.09286, 0.0913, 0.08884,
-0.08654, 0.08334, 0.0789, 0.07448, 0.06928, 0.06488, 0.06106, 0.05818, 0.05424, 0.05158, 0.04908, 0.04588, 0.04456, 0.04214, 0.04108, 0.04074, 0.04074, 0.03896,
-0.0362, 0.03884, 0.03988, 0.03924, 0.03986, 0.03882, 0.04026, 0.04002, 0.04078, 0.04134, 0.04174, 0.04264, 0.0426, 0.04364, 0.0435, 0.04342, 0.04364, 0.04384,
-0.04424, 0.04458, 0.04384, 0.04242, 0.04356, 0.04542, 0.04468, 0.04384, 0.04332, 0.04528, 0.04564, 0.04714, 0.0493, 0.05066, 0.05234, 0.05324, 0.0547, 0.05632,
-0.05806, 0.06004, 0.0618, 0.06442, 0.06618, 0.07004, 0.07264, 0.07582, 0.08134, 
-//947
-0.1371, 0.1671, 0.22058, 0.29992, 0.4017, 0.51098, 0.59988, 0.65018, 0.66902, 0.67704, 0.68404, 0.6875, 0.69116, 0.69196, 0.69116, 0.69034, 0.68834, 0.68438,
-0.67822, 0.6655, 0.65278, 0.63538, 0.61816, 0.6015, 0.58546, 0.5685, 0.55264, 0.53552, 0.51816, 0.50502, 0.49568, 0.49166, 0.48818, 0.48378, 0.47626, 0.4629,
-0.44892, 0.4449, 0.44218, 0.4439, 0.44744, 0.45184, 0.45936, 0.46222, 0.4628, 0.46012, 0.45502, 0.45244, 0.45268, 0.4577, 0.46442, 0.47576, 0.48844, 0.5028,
-0.51714, 0.5279, 0.5333, 0.53304, 0.53054, 0.52898, 0.52394, 0.51846, 0.5167, 0.52052, 0.52198, 0.52494, 0.52982, 0.53632, 0.54104, 0.5509, 0.55888, 0.56852,
-0.58444, 0.59932, 0.61552, 0.63026, 0.64696, 0.65894, 0.6751, 0.68686, 0.69566, 
-//948
-0.13942, 0.16926, 0.22306, 0.3, 0.39356, 0.4819, 0.5422, 0.5683, 0.57946, 0.58324, 0.58638, 0.58834, 0.58926, 0.58702, 0.58482, 0.58054, 0.57708, 0.57092,
-0.56318, 0.55176, 0.5373, 0.51728, 0.49844, 0.47988, 0.46378, 0.44774, 0.43016, 0.41064, 0.39348, 0.38132, 0.3731, 0.3667, 0.36268, 0.35792, 0.3528, 0.3418,
-0.32994, 0.32496, 0.32476, 0.3258, 0.3301, 0.33596, 0.34252, 0.345, 0.3453, 0.3419, 0.33824, 0.33428, 0.33296, 0.33716, 0.34596, 0.35616, 0.3684, 0.38192,
-0.39542, 0.407, 0.41482, 0.41702, 0.41606, 0.41322, 0.40724, 0.4008, 0.39908, 0.4003, 0.40242, 0.40536, 0.40906, 0.41422, 0.42032, 0.42932, 0.43762, 0.44964,
-0.46396, 0.4764, 0.4929, 0.50408, 0.51622, 0.52634, 0.53524, 0.54368, 0.55056, 
-//949
-0.13042, 0.15372, 0.20154, 0.26266, 0.32952, 0.38208, 0.40986, 0.42048, 0.42362, 0.42578, 0.42806, 0.42978, 0.43048, 0.42856, 0.4261, 0.42288, 0.419, 0.41338,
-0.40746, 0.39788, 0.38502, 0.3706, 0.3543, 0.34008, 0.32788, 0.31416, 0.3006, 0.28614, 0.27266, 0.2637, 0.25774, 0.25274, 0.24908, 0.24514, 0.23938, 0.23216,
-0.22426, 0.22132, 0.21942, 0.221, 0.22198, 0.228, 0.23288, 0.23496, 0.23634, 0.23394, 0.23328, 0.23038, 0.23068, 0.23272, 0.2381, 0.24668, 0.2561, 0.26692,
-0.27682, 0.28592, 0.2922, 0.29376, 0.29476, 0.29362, 0.28916, 0.28476, 0.2825, 0.2849, 0.28698, 0.28916, 0.29468, 0.3004, 0.30506, 0.31256, 0.31864, 0.3275,
-0.33854, 0.34762, 0.35846, 0.36532, 0.37404, 0.38052, 0.38526, 0.38812, 0.39082, 
-//950
-0.11972, 0.14222, 0.1789, 0.22212, 0.25912, 0.28362, 0.2953, 0.29998, 0.30256, 0.30272, 0.30386, 0.30448, 0.30484, 0.302, 0.3014, 0.29734, 0.29258, 0.28782,
-0.28256, 0.27486, 0.26578, 0.25322, 0.24168, 0.23, 0.21986, 0.2102, 0.20016, 0.18926, 0.17882, 0.17114, 0.16652, 0.1633, 0.1603, 0.15782, 0.1547, 0.14956,
-0.14342, 0.14312, 0.14184, 0.14264, 0.1447, 0.14824, 0.15036, 0.15078, 0.15194, 0.15022, 0.14868, 0.14654, 0.14546, 0.1463, 0.14946, 0.15516, 0.16136, 0.16824,
-0.17504, 0.1818, 0.18594, 0.1871, 0.18784, 0.18774, 0.18504, 0.18216, 0.18038, 0.18208, 0.18228, 0.1834, 0.1858, 0.18786, 0.19074, 0.19522, 0.1992, 0.20588,
-0.2134, 0.22044, 0.22776, 0.23488, 0.23978, 0.24434, 0.2479, 0.24924, 0.2544, 
-//951
-0.10316, 0.1188, 0.14474, 0.17156, 0.19032, 0.2014, 0.20848, 0.21078, 0.21174, 0.21272, 0.21434, 0.21424, 0.21508, 0.21226, 0.2109, 0.2075, 0.20442, 0.20046,
-0.19452, 0.18886, 0.18042, 0.16982, 0.16006, 0.15062, 0.14242, 0.1344, 0.12724, 0.11918, 0.11164, 0.10588, 0.10344, 0.1001, 0.0982, 0.09728, 0.09652, 0.09304,
-0.08704, 0.08842, 0.08784, 0.0879, 0.0895, 0.09048, 0.09274, 0.09198, 0.09268, 0.09182, 0.0916, 0.09084, 0.08964, 0.09142, 0.09384, 0.09794, 0.10368, 0.1088,
-0.1132, 0.11778, 0.12132, 0.12176, 0.12278, 0.1229, 0.12032, 0.11738, 0.11678, 0.11922, 0.11986, 0.12278, 0.12552, 0.12792, 0.13092, 0.1346, 0.13824, 0.14326,
-0.15056, 0.15504, 0.16318, 0.16942, 0.17472, 0.17896, 0.18236, 0.18464, 0.18758, 
-//952
-0.08168, 0.08718, 0.09782, 0.1102, 0.122, 0.1281, 0.13472, 0.13664, 0.14008, 0.14302, 0.1468, 0.14944, 0.15316, 0.15466, 0.1541, 0.1512, 0.14774, 0.14204,
-0.1361, 0.12842, 0.1195, 0.10872, 0.09864, 0.09254, 0.08582, 0.08088, 0.0754, 0.0706, 0.06814, 0.06356, 0.06096, 0.05874, 0.05684, 0.05492, 0.05422, 0.05224,
-0.04842, 0.05182, 0.05228, 0.05336, 0.05316, 0.0541, 0.05458, 0.05566, 0.05512, 0.055, 0.05498, 0.05472, 0.05496, 0.05562, 0.05704, 0.05812, 0.05916, 0.06136,
-0.0626, 0.06264, 0.06356, 0.06278, 0.06276, 0.0624, 0.0614, 0.05952, 0.0615, 0.06278, 0.06348, 0.06468, 0.0655, 0.06588, 0.06874, 0.07102, 0.07248, 0.07562,
-0.07956, 0.08382, 0.09034, 0.09614, 0.10288, 0.11062, 0.11692, 0.12324, 0.13186, 
-//953
-0.06128, 0.06276, 0.06762, 0.07342, 0.07926, 0.0831, 0.0892, 0.09026, 0.09222, 0.0961, 0.09992, 0.10432, 0.11144, 0.11766, 0.12156, 0.1207, 0.11886, 0.1144,
-0.10946, 0.10222, 0.09366, 0.08452, 0.07582, 0.06938, 0.06466, 0.05966, 0.05466, 0.05164, 0.0488, 0.04558, 0.0434, 0.04162, 0.04014, 0.03858, 0.03798, 0.03698,
-0.03634, 0.0389, 0.03822, 0.03944, 0.03996, 0.03982, 0.03956, 0.03932, 0.0399, 0.04038, 0.0406, 0.04146, 0.04284, 0.04342, 0.04422, 0.04504, 0.045, 0.04522,
-0.04478, 0.04376, 0.04376, 0.04234, 0.04256, 0.04342, 0.04338, 0.04194, 0.04434, 0.04576, 0.04698, 0.04764, 0.04794, 0.04996, 0.05144, 0.05274, 0.05356,
-0.05646, 0.05862, 0.06036, 0.06236, 0.06528, 0.06776, 0.07036, 0.07462, 0.07892, 0.08362, 
-//954
-0.13112, 0.16324, 0.21792, 0.29926, 0.3973, 0.49718, 0.56878, 0.60558, 0.62042, 0.62866, 0.6354, 0.6393, 0.64226, 0.64188, 0.64056, 0.63596, 0.62818, 0.61826,
-0.60562, 0.5892, 0.56858, 0.54308, 0.51664, 0.4931, 0.47384, 0.45326, 0.43344, 0.41312, 0.39332, 0.37992, 0.37036, 0.36312, 0.35762, 0.35012, 0.3405, 0.3284,
-0.31402, 0.30794, 0.30368, 0.30176, 0.30538, 0.31028, 0.31496, 0.31766, 0.31798, 0.31514, 0.3125, 0.31166, 0.3122, 0.316, 0.32472, 0.33538, 0.35008, 0.36408,
-0.37874, 0.39146, 0.39986, 0.40324, 0.40088, 0.39656, 0.38894, 0.38132, 0.37828, 0.37908, 0.38282, 0.3872, 0.39422, 0.40202, 0.41132, 0.42178, 0.43456, 0.45002,
-0.47124, 0.49088, 0.51462, 0.53768, 0.55946, 0.5815, 0.60222, 0.61582, 0.63854, 
-//955
-0.13014, 0.158, 0.20882, 0.27986, 0.35974, 0.42922, 0.4715, 0.49172, 0.49834, 0.50294, 0.50708, 0.509, 0.51198, 0.5118, 0.50762, 0.50132, 0.49358, 0.4835,
-0.47102, 0.45526, 0.43496, 0.40984, 0.38622, 0.3628, 0.34442, 0.32492, 0.30724, 0.28868, 0.27324, 0.26172, 0.25476, 0.25048, 0.24712, 0.24162, 0.23524, 0.22502,
-0.21532, 0.21184, 0.2092, 0.211, 0.2139, 0.21898, 0.22512, 0.22814, 0.22908, 0.22658, 0.22478, 0.22206, 0.22138, 0.22336, 0.23074, 0.24038, 0.2529, 0.26678,
-0.28092, 0.29522, 0.30372, 0.30802, 0.3088, 0.30442, 0.29916, 0.29236, 0.28962, 0.29106, 0.29422, 0.30004, 0.30632, 0.31238, 0.31996, 0.32876, 0.33986, 0.35262,
-0.37008, 0.38804, 0.40862, 0.42606, 0.44378, 0.45958, 0.47166, 0.4825, 0.49434, 
-//956
-0.13052, 0.15558, 0.19718, 0.24826, 0.29534, 0.3294, 0.3487, 0.35712, 0.36106, 0.36452, 0.36796, 0.36942, 0.37122, 0.37074, 0.36804, 0.362, 0.35558, 0.3471,
-0.33788, 0.32542, 0.30972, 0.28896, 0.26974, 0.2527, 0.2388, 0.22506, 0.21184, 0.19732, 0.18448, 0.17632, 0.17086, 0.16566, 0.16236, 0.15852, 0.15358, 0.14706,
-0.13962, 0.13878, 0.13644, 0.13666, 0.1384, 0.14098, 0.14506, 0.14624, 0.14644, 0.14474, 0.14316, 0.14132, 0.14102, 0.14256, 0.14712, 0.15326, 0.16208, 0.17092,
-0.17954, 0.18734, 0.19382, 0.1952, 0.19532, 0.19494, 0.18948, 0.18464, 0.18294, 0.1843, 0.18616, 0.18958, 0.19512, 0.19936, 0.20452, 0.20982, 0.21744, 0.22618,
-0.23712, 0.24838, 0.26324, 0.27422, 0.2852, 0.29638, 0.30594, 0.31352, 0.31914, 
-//957
-0.10886, 0.1275, 0.15566, 0.18638, 0.21516, 0.2345, 0.24496, 0.2514, 0.25568, 0.25828, 0.26156, 0.26402, 0.2671, 0.26568, 0.26282, 0.25884, 0.25266, 0.24576,
-0.23692, 0.22726, 0.21242, 0.19666, 0.18042, 0.16724, 0.15546, 0.14382, 0.1341, 0.12406, 0.11524, 0.10914, 0.10572, 0.0991, 0.09932, 0.098, 0.09626, 0.09296,
-0.0879, 0.08564, 0.08358, 0.0843, 0.08534, 0.08768, 0.0893, 0.08994, 0.08966, 0.08844, 0.08764, 0.08628, 0.08512, 0.08694, 0.08894, 0.09188, 0.0961, 0.10178,
-0.10702, 0.11134, 0.11584, 0.11626, 0.11732, 0.11654, 0.11302, 0.11036, 0.10936, 0.1103, 0.1115, 0.11262, 0.11396, 0.11614, 0.11786, 0.12242, 0.1289, 0.13346,
-0.14378, 0.15374, 0.16472, 0.17656, 0.18584, 0.19512, 0.2036, 0.21278, 0.22192, 
-//958
-0.08198, 0.09054, 0.10124, 0.11562, 0.12858, 0.13928, 0.14728, 0.15226, 0.15578, 0.1627, 0.16966, 0.17768, 0.19152, 0.20222, 0.2054, 0.20398, 0.19952, 0.18996,
-0.1792, 0.1661, 0.15212, 0.13462, 0.11984, 0.10754, 0.09858, 0.08978, 0.083, 0.07702, 0.0715, 0.06722, 0.0653, 0.06242, 0.0605, 0.05746, 0.05596, 0.05354,
-0.05018, 0.0529, 0.05348, 0.05268, 0.05282, 0.05364, 0.05472, 0.05522, 0.05518, 0.0564, 0.05684, 0.05746, 0.0577, 0.05818, 0.05852, 0.05858, 0.05944, 0.06014,
-0.06078, 0.06066, 0.06108, 0.06016, 0.06062, 0.06166, 0.06114, 0.05948, 0.06004, 0.06128, 0.06314, 0.06444, 0.06526, 0.06632, 0.06808, 0.0699, 0.07184, 0.0746,
-0.07578, 0.08032, 0.08184, 0.08708, 0.0935, 0.09816, 0.10482, 0.1123, 0.12314, 
-//959
-0.1283, 0.15594, 0.20654, 0.2789, 0.36238, 0.438, 0.48816, 0.51156, 0.52422, 0.53176, 0.53958, 0.5447, 0.55148, 0.55222, 0.5492, 0.54132, 0.53112, 0.51774,
-0.50224, 0.48254, 0.45662, 0.42722, 0.39978, 0.37482, 0.35448, 0.33402, 0.31414, 0.29332, 0.27636, 0.26276, 0.25462, 0.2494, 0.24296, 0.23736, 0.2313, 0.21956,
-0.20988, 0.20614, 0.20286, 0.20368, 0.20558, 0.2103, 0.21474, 0.21622, 0.21604, 0.21372, 0.20988, 0.20836, 0.20748, 0.21036, 0.21718, 0.22746, 0.23774, 0.25058,
-0.26382, 0.2754, 0.28328, 0.28656, 0.28578, 0.28122, 0.27606, 0.26918, 0.26612, 0.26644, 0.26848, 0.27082, 0.27458, 0.27962, 0.28622, 0.29596, 0.30686, 0.32194,
-0.34188, 0.36256, 0.38576, 0.40878, 0.42898, 0.44958, 0.4693, 0.48714, 0.51188, 
-//960
-0.13124, 0.15412, 0.20046, 0.25944, 0.32132, 0.3722, 0.40218, 0.41524, 0.42366, 0.43076, 0.43782, 0.44444, 0.44838, 0.44954, 0.44614, 0.43902, 0.42832, 0.41604,
-0.40008, 0.37966, 0.35604, 0.32762, 0.30096, 0.27924, 0.2594, 0.24218, 0.22568, 0.20968, 0.19492, 0.18508, 0.17824, 0.17244, 0.1674, 0.16172, 0.15538, 0.14798,
-0.1393, 0.13736, 0.13416, 0.13366, 0.13434, 0.13598, 0.1392, 0.14026, 0.13964, 0.13938, 0.13776, 0.13722, 0.13718, 0.13994, 0.14656, 0.15206, 0.1606, 0.1707,
-0.1801, 0.18884, 0.19444, 0.197, 0.1964, 0.19308, 0.18834, 0.18236, 0.17994, 0.1811, 0.18368, 0.18664, 0.19248, 0.19892, 0.20454, 0.2128, 0.22294, 0.23646,
-0.25378, 0.27302, 0.29504, 0.31744, 0.34072, 0.36074, 0.38272, 0.4005, 0.41742, 
-//961
-0.11538, 0.13138, 0.16248, 0.19832, 0.23178, 0.25666, 0.272, 0.28084, 0.28706, 0.29492, 0.30198, 0.30916, 0.31632, 0.32028, 0.31898, 0.3125, 0.30402, 0.29292,
-0.27796, 0.26116, 0.24114, 0.21824, 0.19702, 0.1795, 0.16604, 0.15326, 0.14122, 0.1297, 0.12042, 0.1133, 0.10796, 0.10486, 0.1014, 0.09698, 0.09342, 0.088,
-0.08296, 0.08312, 0.08178, 0.08136, 0.07968, 0.08174, 0.08288, 0.0814, 0.07968, 0.0824, 0.08284, 0.08296, 0.08116, 0.0834, 0.0855, 0.08856, 0.09238, 0.09626,
-0.0999, 0.10364, 0.10704, 0.10712, 0.10672, 0.1058, 0.10338, 0.10054, 0.0988, 0.09984, 0.10046, 0.10182, 0.104, 0.10668, 0.10872, 0.11292, 0.1186, 0.1254,
-0.13464, 0.14654, 0.15962, 0.1738, 0.18836, 0.2029, 0.21688, 0.23204, 0.2502, 
-//962
-0.07668, 0.08536, 0.10118, 0.11894, 0.13602, 0.15024, 0.15908, 0.1665, 0.17118, 0.18006, 0.18908, 0.19902, 0.21126, 0.2217, 0.22488, 0.22232, 0.21532, 0.2047,
-0.19222, 0.17764, 0.15948, 0.1414, 0.12446, 0.1107, 0.10134, 0.09234, 0.08396, 0.07628, 0.07046, 0.06528, 0.06262, 0.06022, 0.05568, 0.05388, 0.0512, 0.04826,
-0.04466, 0.0464, 0.04632, 0.04684, 0.04742, 0.04778, 0.04778, 0.04726, 0.0472, 0.04628, 0.04652, 0.0471, 0.04756, 0.0473, 0.04796, 0.0502, 0.05134, 0.05262,
-0.05398, 0.05448, 0.05622, 0.05432, 0.05504, 0.05518, 0.05414, 0.05156, 0.05118, 0.05344, 0.05464, 0.0558, 0.05644, 0.05862, 0.05912, 0.06032, 0.06352, 0.06704,
-0.07178, 0.07714, 0.08476, 0.0909, 0.0995, 0.10776, 0.11766, 0.12834, 0.14024, 
-//963
-0.12064, 0.15054, 0.20092, 0.26844, 0.33718, 0.39144, 0.42378, 0.44216, 0.45406, 0.46388, 0.4709, 0.4761, 0.47916, 0.47848, 0.47498, 0.46846, 0.45822, 0.44444,
-0.42788, 0.40572, 0.3797, 0.34936, 0.3206, 0.2954, 0.27312, 0.25168, 0.23102, 0.21144, 0.19444, 0.18096, 0.17058, 0.1624, 0.1556, 0.14912, 0.14372, 0.13766,
-0.13106, 0.12966, 0.12642, 0.12422, 0.12302, 0.12338, 0.12422, 0.12426, 0.12568, 0.12502, 0.12526, 0.12504, 0.12542, 0.12704, 0.13026, 0.13304, 0.13898,
-0.14634, 0.15336, 0.16254, 0.17216, 0.1822, 0.19634, 0.21162, 0.22654, 0.24314, 0.25984, 0.28014, 0.30018, 0.3216, 0.34454, 0.36684, 0.38926, 0.4097, 0.42966,
-0.44616, 0.46362, 0.47606, 0.48766, 0.49748, 0.50806, 0.51166, 0.51658, 0.51948, 0.52274, 
-//964
-0.0965, 0.11578, 0.14766, 0.18502, 0.22202, 0.24998, 0.26838, 0.2803, 0.28762, 0.29748, 0.30696, 0.3174, 0.32814, 0.33448, 0.33364, 0.32634, 0.31598, 0.30262,
-0.28694, 0.26794, 0.24408, 0.21934, 0.19556, 0.17774, 0.16228, 0.1482, 0.13588, 0.12346, 0.11356, 0.10666, 0.10238, 0.09826, 0.0944, 0.09092, 0.08592, 0.08064,
-0.07576, 0.07408, 0.07246, 0.07182, 0.07212, 0.07306, 0.07388, 0.07474, 0.0745, 0.0734, 0.0734, 0.07268, 0.07326, 0.07366, 0.07608, 0.07918, 0.08322, 0.08794,
-0.09262, 0.0972, 0.10008, 0.10172, 0.10142, 0.0993, 0.0967, 0.09338, 0.09238, 0.09208, 0.09272, 0.09442, 0.09668, 0.09928, 0.1027, 0.10752, 0.11368, 0.1221,
-0.13362, 0.1464, 0.1627, 0.18076, 0.19822, 0.21546, 0.23498, 0.25578, 0.27776, 
-//965
-0.14818, 0.17886, 0.23664, 0.3229, 0.43544, 0.55664, 0.65784, 0.71326, 0.7342, 0.74368, 0.74698, 0.74838, 0.75114, 0.75198, 0.75122, 0.75072, 0.75216, 0.75204,
-0.75142, 0.7508, 0.748, 0.74356, 0.73946, 0.73712, 0.73354, 0.7293, 0.72458, 0.71912, 0.71428, 0.70892, 0.70606, 0.70382, 0.70228, 0.70058, 0.7006, 0.69768,
-0.69354, 0.69438, 0.6936, 0.69552, 0.6968, 0.70136, 0.70466, 0.70678, 0.70692, 0.70558, 0.7041, 0.70274, 0.69938, 0.69976, 0.7025, 0.7064, 0.70784, 0.71344,
-0.71834, 0.72054, 0.72202, 0.72016, 0.71704, 0.71932, 0.7202, 0.72282, 0.72272, 0.72342, 0.72526, 0.7253, 0.72864, 0.73004, 0.73068, 0.73428, 0.73406, 0.73648,
-0.73796, 0.73754, 0.73876, 0.74014, 0.7427, 0.74332, 0.7409, 0.74162, 0.74168, 
-//966
-0.13386, 0.16314, 0.21554, 0.29204, 0.385, 0.4689, 0.51746, 0.53696, 0.54334, 0.54306, 0.54356, 0.542, 0.54208, 0.54036, 0.53728, 0.5371, 0.53508, 0.53426,
-0.5324, 0.53166, 0.5289, 0.5257, 0.52382, 0.52296, 0.52214, 0.51984, 0.5171, 0.51454, 0.50978, 0.50558, 0.50274, 0.50074, 0.49994, 0.50022, 0.4999, 0.49898,
-0.49748, 0.49668, 0.49906, 0.50142, 0.50314, 0.50574, 0.50676, 0.5065, 0.50608, 0.50492, 0.5046, 0.5033, 0.50254, 0.50064, 0.50054, 0.49928, 0.4989, 0.49678,
-0.49738, 0.49656, 0.49626, 0.49578, 0.49544, 0.49558, 0.49452, 0.49366, 0.49206, 0.49268, 0.49188, 0.49058, 0.49094, 0.49126, 0.4896, 0.4901, 0.48828, 0.4884,
-0.48922, 0.48696, 0.48782, 0.48594, 0.48452, 0.48456, 0.4832, 0.48286, 0.48194, 
-//967
-0.13502, 0.16434, 0.21446, 0.2786, 0.344, 0.39048, 0.41066, 0.41602, 0.41744, 0.41552, 0.4137, 0.41304, 0.41144, 0.40898, 0.40636, 0.40422, 0.40354, 0.40262,
-0.40108, 0.39776, 0.39524, 0.39226, 0.3879, 0.3859, 0.38442, 0.38272, 0.38168, 0.3799, 0.3768, 0.37328, 0.36948, 0.36796, 0.36654, 0.3684, 0.3697, 0.37174,
-0.37436, 0.37492, 0.37366, 0.37244, 0.3703, 0.36838, 0.3696, 0.37004, 0.37276, 0.37518, 0.37824, 0.37994, 0.38088, 0.38116, 0.38066, 0.37992, 0.37858, 0.3782,
-0.37726, 0.37542, 0.37522, 0.37404, 0.3735, 0.37288, 0.37174, 0.3706, 0.37062, 0.3699, 0.36918, 0.3688, 0.36762, 0.36704, 0.36646, 0.36782, 0.3671, 0.36626,
-0.3673, 0.36486, 0.36504, 0.36382, 0.36388, 0.36418, 0.36292, 0.35986, 0.36192, 
-//968
-0.126, 0.15152, 0.18982, 0.23256, 0.26952, 0.29038, 0.29826, 0.30062, 0.30018, 0.29806, 0.29844, 0.29688, 0.29568, 0.2937, 0.29208, 0.29038, 0.28928, 0.2878,
-0.28654, 0.28462, 0.28272, 0.27996, 0.27754, 0.27648, 0.2756, 0.2746, 0.2718, 0.26916, 0.2657, 0.26192, 0.25896, 0.25676, 0.2564, 0.25736, 0.2578, 0.2573,
-0.2554, 0.25472, 0.25724, 0.2603, 0.26358, 0.267, 0.26838, 0.26964, 0.2702, 0.26916, 0.26944, 0.2686, 0.2672, 0.26654, 0.26654, 0.26498, 0.26436, 0.26314,
-0.26204, 0.26228, 0.26082, 0.25944, 0.25978, 0.25904, 0.2578, 0.25674, 0.25536, 0.25614, 0.25556, 0.25506, 0.25542, 0.25514, 0.25434, 0.2544, 0.2535, 0.25308,
-0.25414, 0.25262, 0.25286, 0.25306, 0.25348, 0.25238, 0.25282, 0.25108, 0.25044, 
-//969
-0.1061, 0.12436, 0.15054, 0.17422, 0.1912, 0.20002, 0.20316, 0.20238, 0.20136, 0.20062, 0.2001, 0.2001, 0.19982, 0.198, 0.19744, 0.19522, 0.19476, 0.19376,
-0.19306, 0.19212, 0.19146, 0.1897, 0.18722, 0.18662, 0.18608, 0.18478, 0.18388, 0.1813, 0.17938, 0.17574, 0.17366, 0.17272, 0.17314, 0.1735, 0.17426, 0.17304,
-0.17374, 0.17498, 0.17524, 0.17716, 0.17952, 0.1806, 0.18126, 0.18158, 0.18064, 0.18002, 0.17964, 0.1789, 0.1791, 0.17844, 0.178, 0.17716, 0.17654, 0.17578,
-0.17616, 0.17418, 0.17454, 0.17374, 0.17362, 0.17362, 0.17312, 0.17278, 0.17208, 0.17224, 0.1718, 0.17166, 0.17186, 0.17164, 0.17078, 0.17174, 0.17078, 0.17224,
-0.17172, 0.17144, 0.17098, 0.17132, 0.1725, 0.17038, 0.16992, 0.16994, 0.17042, 
-//970
-0.08572, 0.09646, 0.10916, 0.11938, 0.12698, 0.12916, 0.13154, 0.1314, 0.13126, 0.131, 0.13026, 0.12946, 0.12956, 0.1281, 0.12702, 0.12646, 0.12608, 0.1255,
-0.12508, 0.1239, 0.12296, 0.1214, 0.11992, 0.11898, 0.11798, 0.11648, 0.11582, 0.11448, 0.1136, 0.11198, 0.11176, 0.11074, 0.10954, 0.10934, 0.10988, 0.1093,
-0.10954, 0.11024, 0.10982, 0.10906, 0.10772, 0.10736, 0.1074, 0.10906, 0.1101, 0.1117, 0.11378, 0.11476, 0.11552, 0.1158, 0.11702, 0.11762, 0.11768, 0.11742,
-0.11744, 0.11764, 0.11742, 0.1174, 0.11886, 0.11842, 0.1183, 0.11812, 0.1185, 0.11968, 0.11934, 0.11944, 0.1198, 0.1206, 0.12046, 0.1211, 0.12178, 0.12272,
-0.12266, 0.12244, 0.12242, 0.1228, 0.12324, 0.12344, 0.12314, 0.12446, 0.12412, 
-//971
-0.06856, 0.07158, 0.0775, 0.08174, 0.08306, 0.08404, 0.08426, 0.08352, 0.08324, 0.08308, 0.08296, 0.08202, 0.08302, 0.08218, 0.0812, 0.08066, 0.08012, 0.07914,
-0.07894, 0.07834, 0.078, 0.07588, 0.07414, 0.07306, 0.07288, 0.07156, 0.07022, 0.06944, 0.06778, 0.06724, 0.06684, 0.06608, 0.06596, 0.06548, 0.06566, 0.06502,
-0.06488, 0.06484, 0.06406, 0.06542, 0.06498, 0.0657, 0.06572, 0.06604, 0.06668, 0.06658, 0.0665, 0.0664, 0.06618, 0.06572, 0.06606, 0.0668, 0.06658, 0.0673,
-0.06828, 0.06858, 0.0693, 0.06928, 0.06928, 0.07076, 0.07108, 0.07048, 0.07058, 0.0713, 0.07038, 0.07082, 0.07192, 0.07268, 0.07328, 0.07394, 0.07416, 0.07452,
-0.07512, 0.0754, 0.07634, 0.07668, 0.07746, 0.07706, 0.07704, 0.07746, 0.07876, 
-//972
-0.05726, 0.0586, 0.06042, 0.06024, 0.06192, 0.0618, 0.06226, 0.06178, 0.06136, 0.05994, 0.06004, 0.05962, 0.05894, 0.05866, 0.05874, 0.05792, 0.0581, 0.05728,
-0.05764, 0.0566, 0.05608, 0.05484, 0.054, 0.05292, 0.0518, 0.05154, 0.0511, 0.04998, 0.04976, 0.04878, 0.0479, 0.0485, 0.0481, 0.0478, 0.04694, 0.04694,
-0.04702, 0.04778, 0.0476, 0.04814, 0.04828, 0.04822, 0.0487, 0.0488, 0.04878, 0.04868, 0.04816, 0.04776, 0.0482, 0.04792, 0.04854, 0.04944, 0.0499, 0.05032,
-0.05078, 0.0518, 0.05168, 0.05048, 0.05194, 0.05192, 0.05218, 0.0522, 0.0525, 0.05278, 0.0542, 0.05472, 0.05596, 0.05662, 0.0569, 0.05836, 0.05936, 0.05968,
-0.06054, 0.06148, 0.06262, 0.06264, 0.06386, 0.06358, 0.06394, 0.0651, 0.0648, 
-//973
-0.13336, 0.16562, 0.22266, 0.3102, 0.4235, 0.55144, 0.6584, 0.72364, 0.75402, 0.7663, 0.77114, 0.77654, 0.77912, 0.78052, 0.78054, 0.78066, 0.7808, 0.77878,
-0.77692, 0.77448, 0.76852, 0.76128, 0.7516, 0.74386, 0.73634, 0.72888, 0.72102, 0.7099, 0.69962, 0.69308, 0.68906, 0.68744, 0.68662, 0.6847, 0.68302, 0.67762,
-0.67564, 0.67392, 0.67354, 0.67706, 0.68208, 0.68944, 0.69642, 0.69988, 0.70186, 0.7, 0.69888, 0.69496, 0.69204, 0.69248, 0.69636, 0.70334, 0.71226, 0.72396,
-0.73268, 0.73734, 0.73632, 0.72896, 0.7286, 0.73208, 0.7365, 0.7482, 0.75792, 0.7649, 0.77104, 0.77308, 0.77594, 0.77796, 0.77998, 0.78596, 0.79028, 0.79316,
-0.79748, 0.79776, 0.80128, 0.80338, 0.80726, 0.8092, 0.8109, 0.8045, 0.80972, 
-//974
-0.12968, 0.16144, 0.21514, 0.29224, 0.38728, 0.47764, 0.53594, 0.55974, 0.56666, 0.56776, 0.56774, 0.56818, 0.56982, 0.5664, 0.56332, 0.56108, 0.56048, 0.55874,
-0.55648, 0.55442, 0.55008, 0.5433, 0.53848, 0.53324, 0.52886, 0.52402, 0.51886, 0.513, 0.50624, 0.49996, 0.49672, 0.49534, 0.4931, 0.49144, 0.49236, 0.49094,
-0.48828, 0.48656, 0.48826, 0.4891, 0.49232, 0.49546, 0.49972, 0.50402, 0.50742, 0.50912, 0.5095, 0.50874, 0.50596, 0.50598, 0.50788, 0.51018, 0.51336, 0.51726,
-0.51998, 0.52304, 0.52584, 0.52766, 0.52884, 0.53034, 0.52926, 0.52928, 0.5294, 0.53088, 0.5308, 0.52958, 0.53004, 0.52876, 0.52956, 0.53006, 0.53068, 0.52924,
-0.53076, 0.52908, 0.52934, 0.52804, 0.52756, 0.52698, 0.52884, 0.52376, 0.52684, 
-//975
-0.1416, 0.17102, 0.22184, 0.289, 0.35418, 0.4061, 0.43002, 0.43798, 0.43864, 0.43618, 0.43612, 0.43392, 0.43216, 0.42976, 0.42726, 0.42438, 0.42198, 0.42004,
-0.41678, 0.41464, 0.41154, 0.40604, 0.40294, 0.39888, 0.39554, 0.39164, 0.38798, 0.38462, 0.3809, 0.37646, 0.3734, 0.36924, 0.3664, 0.36534, 0.36532, 0.36542,
-0.36536, 0.36522, 0.3632, 0.361, 0.35858, 0.35736, 0.35924, 0.3622, 0.365, 0.37004, 0.37426, 0.37656, 0.37714, 0.3783, 0.37874, 0.37964, 0.37896, 0.38056,
-0.3794, 0.37868, 0.37982, 0.37846, 0.37848, 0.37778, 0.37762, 0.376, 0.3749, 0.37632, 0.37256, 0.37274, 0.37182, 0.37138, 0.37048, 0.37, 0.3696, 0.3684,
-0.36986, 0.3679, 0.36684, 0.3666, 0.3673, 0.36638, 0.36774, 0.36558, 0.36466, 
-//976
-0.12526, 0.15166, 0.19314, 0.24218, 0.28554, 0.30972, 0.32282, 0.32724, 0.32798, 0.32428, 0.32204, 0.3199, 0.31852, 0.31672, 0.31494, 0.31372, 0.31122, 0.30832,
-0.30614, 0.30282, 0.29854, 0.29388, 0.28918, 0.28528, 0.28184, 0.2778, 0.27434, 0.27094, 0.26708, 0.2634, 0.25976, 0.25692, 0.25588, 0.25558, 0.25538, 0.25528,
-0.25596, 0.25472, 0.25394, 0.25206, 0.25124, 0.2519, 0.25378, 0.25762, 0.26188, 0.26622, 0.26852, 0.27092, 0.2723, 0.27248, 0.2732, 0.27376, 0.27396, 0.27582,
-0.27436, 0.27482, 0.27748, 0.27718, 0.2789, 0.27836, 0.27804, 0.2777, 0.27782, 0.2761, 0.27626, 0.27648, 0.27638, 0.27622, 0.27518, 0.27636, 0.27556, 0.2752,
-0.27652, 0.27508, 0.27458, 0.27464, 0.27534, 0.27466, 0.27548, 0.27148, 0.27234, 
-//977
-0.10858, 0.12536, 0.1553, 0.18486, 0.2051, 0.2133, 0.21634, 0.2163, 0.21584, 0.21622, 0.2158, 0.217, 0.21748, 0.21584, 0.21326, 0.21034, 0.20798, 0.20658,
-0.20448, 0.20144, 0.19884, 0.19434, 0.1896, 0.18702, 0.18358, 0.18086, 0.17938, 0.17698, 0.17438, 0.17152, 0.16936, 0.16698, 0.16576, 0.16548, 0.16658, 0.16608,
-0.16612, 0.16544, 0.16362, 0.1618, 0.15952, 0.158, 0.1596, 0.16278, 0.16764, 0.17216, 0.17694, 0.17894, 0.1816, 0.18242, 0.18436, 0.1849, 0.1843, 0.18498,
-0.18526, 0.18424, 0.1854, 0.1834, 0.18318, 0.18234, 0.18006, 0.17984, 0.17686, 0.17734, 0.17814, 0.18028, 0.18242, 0.18424, 0.1855, 0.18796, 0.19002, 0.19014,
-0.19182, 0.19144, 0.19216, 0.19362, 0.19422, 0.19424, 0.19468, 0.19348, 0.19424, 
-//978
-0.09232, 0.10352, 0.12008, 0.13496, 0.14512, 0.14844, 0.15072, 0.1503, 0.15042, 0.14956, 0.14932, 0.14902, 0.14764, 0.146, 0.14442, 0.14254, 0.14186, 0.13978,
-0.13794, 0.13534, 0.13288, 0.13044, 0.12664, 0.12408, 0.12186, 0.11914, 0.11746, 0.11406, 0.1114, 0.10832, 0.10708, 0.10618, 0.10578, 0.10514, 0.1051, 0.10408,
-0.10276, 0.1029, 0.1029, 0.10326, 0.10366, 0.1062, 0.10726, 0.10884, 0.1111, 0.11246, 0.1136, 0.11328, 0.11338, 0.11366, 0.11474, 0.11604, 0.11668, 0.11938,
-0.12174, 0.12308, 0.12442, 0.12576, 0.12674, 0.12766, 0.12762, 0.12718, 0.1282, 0.12804, 0.12958, 0.12974, 0.12966, 0.12974, 0.13006, 0.13052, 0.1309, 0.13132,
-0.13186, 0.13188, 0.13158, 0.13212, 0.13178, 0.13088, 0.13168, 0.12996, 0.13014, 
-//979
-0.0714, 0.07738, 0.08552, 0.09194, 0.09542, 0.09664, 0.09672, 0.09704, 0.09708, 0.09546, 0.09548, 0.09424, 0.09406, 0.09184, 0.0902, 0.0897, 0.08878, 0.08728,
-0.0862, 0.08456, 0.0827, 0.07998, 0.07688, 0.07428, 0.07278, 0.07012, 0.06864, 0.06638, 0.06378, 0.06358, 0.06184, 0.06148, 0.06172, 0.06104, 0.06112, 0.05986,
-0.05958, 0.06006, 0.06018, 0.06088, 0.06208, 0.06326, 0.0643, 0.06484, 0.06566, 0.066, 0.06642, 0.06614, 0.0658, 0.06606, 0.06692, 0.06782, 0.06914, 0.07026,
-0.071, 0.07218, 0.07298, 0.07316, 0.07316, 0.07482, 0.07488, 0.07478, 0.07576, 0.07586, 0.07664, 0.07666, 0.07696, 0.0776, 0.07888, 0.07912, 0.08014, 0.07832,
-0.07984, 0.07966, 0.0801, 0.08014, 0.08066, 0.08104, 0.08134, 0.08232, 0.08148, 
-//980
-0.06142, 0.0641, 0.06842, 0.0705, 0.07222, 0.07336, 0.07376, 0.07362, 0.0746, 0.07404, 0.07362, 0.07364, 0.07324, 0.07246, 0.07146, 0.0699, 0.0685, 0.0678,
-0.0667, 0.06478, 0.06268, 0.06058, 0.0579, 0.05584, 0.05462, 0.05292, 0.0514, 0.05016, 0.0495, 0.04902, 0.04828, 0.04762, 0.04804, 0.04774, 0.0472, 0.04686,
-0.04722, 0.04776, 0.04804, 0.04896, 0.04968, 0.04982, 0.05076, 0.0511, 0.05034, 0.0502, 0.05016, 0.04964, 0.0497, 0.05016, 0.05142, 0.05246, 0.05448, 0.05614,
-0.05778, 0.05954, 0.06062, 0.06078, 0.06212, 0.06316, 0.06364, 0.06364, 0.0642, 0.0647, 0.06504, 0.0657, 0.06772, 0.06774, 0.0689, 0.07022, 0.07182, 0.07294,
-0.07426, 0.07586, 0.07664, 0.07728, 0.07826, 0.0799, 0.0798, 0.0797, 0.08112, 
-//981
-0.1276, 0.15738, 0.20772, 0.288, 0.39066, 0.49794, 0.57966, 0.62232, 0.64002, 0.6461, 0.65334, 0.65532, 0.65944, 0.65982, 0.65992, 0.65772, 0.65418, 0.65232,
-0.6468, 0.6388, 0.62898, 0.61282, 0.59624, 0.5817, 0.5695, 0.55498, 0.53994, 0.52338, 0.50862, 0.49722, 0.49034, 0.48708, 0.48486, 0.4825, 0.47908, 0.47284,
-0.4659, 0.4637, 0.46506, 0.46966, 0.47862, 0.4892, 0.4993, 0.50558, 0.50916, 0.50728, 0.5027, 0.49854, 0.49382, 0.49388, 0.50136, 0.51082, 0.52464, 0.53992,
-0.55528, 0.5701, 0.58112, 0.58878, 0.59216, 0.59558, 0.59448, 0.59324, 0.59144, 0.59476, 0.59758, 0.6005, 0.60668, 0.61152, 0.61504, 0.62192, 0.62992, 0.63946,
-0.64832, 0.65548, 0.66638, 0.67482, 0.67996, 0.68478, 0.6907, 0.69538, 0.69716, 
-//982
-0.1318, 0.16322, 0.21574, 0.28636, 0.36694, 0.43338, 0.4706, 0.48526, 0.49172, 0.492, 0.49326, 0.49342, 0.4932, 0.49092, 0.4893, 0.48514, 0.48198, 0.47932,
-0.47418, 0.46734, 0.4585, 0.448, 0.43462, 0.42198, 0.41276, 0.4018, 0.38982, 0.37738, 0.36538, 0.35788, 0.35258, 0.35062, 0.34992, 0.34656, 0.34592, 0.3414,
-0.33608, 0.33444, 0.33468, 0.33878, 0.3455, 0.35482, 0.36356, 0.37006, 0.37306, 0.37122, 0.3675, 0.3603, 0.35526, 0.35562, 0.35984, 0.36538, 0.37324, 0.3852,
-0.39424, 0.40488, 0.41274, 0.41648, 0.41948, 0.42258, 0.42312, 0.4229, 0.42286, 0.42356, 0.42526, 0.42778, 0.42974, 0.43144, 0.4343, 0.4362, 0.4395, 0.44074,
-0.44208, 0.44258, 0.4438, 0.44476, 0.44602, 0.44524, 0.44902, 0.44698, 0.44734, 
-//983
-0.13086, 0.15808, 0.20316, 0.257, 0.3073, 0.34152, 0.35568, 0.35992, 0.362, 0.36108, 0.36024, 0.36054, 0.35974, 0.35588, 0.35314, 0.352, 0.34834, 0.34526,
-0.34146, 0.33586, 0.329, 0.32068, 0.3092, 0.29908, 0.29182, 0.28248, 0.27298, 0.26192, 0.25374, 0.24604, 0.24192, 0.2416, 0.23954, 0.23844, 0.23852, 0.23492,
-0.2317, 0.23242, 0.2326, 0.2365, 0.24122, 0.24804, 0.25426, 0.259, 0.26164, 0.26036, 0.25752, 0.2527, 0.24616, 0.24604, 0.2494, 0.25254, 0.2603, 0.26842,
-0.27424, 0.281, 0.2867, 0.28858, 0.29004, 0.29454, 0.2932, 0.29202, 0.29086, 0.29228, 0.29258, 0.29464, 0.29594, 0.29746, 0.29896, 0.30188, 0.30332, 0.30252,
-0.30516, 0.30278, 0.30432, 0.30372, 0.30514, 0.303, 0.3034, 0.30238, 0.30244, 
-//984
-0.11588, 0.13754, 0.16878, 0.20428, 0.23026, 0.24512, 0.25212, 0.25432, 0.25468, 0.25412, 0.25566, 0.2542, 0.25402, 0.25142, 0.24952, 0.246, 0.2441, 0.24196,
-0.2381, 0.2334, 0.22766, 0.21962, 0.21076, 0.20306, 0.19688, 0.18862, 0.1815, 0.17296, 0.16584, 0.16064, 0.15784, 0.15682, 0.15638, 0.15666, 0.1556, 0.15314,
-0.15126, 0.15002, 0.1513, 0.15478, 0.15814, 0.16398, 0.16928, 0.17382, 0.1765, 0.17462, 0.17186, 0.16912, 0.16472, 0.16254, 0.1646, 0.16894, 0.17342, 0.17978,
-0.18606, 0.19182, 0.19736, 0.1987, 0.20238, 0.2052, 0.2053, 0.20454, 0.20438, 0.20566, 0.20622, 0.2069, 0.2087, 0.21054, 0.2109, 0.21344, 0.21448, 0.21376,
-0.21548, 0.21436, 0.21482, 0.21428, 0.21596, 0.21472, 0.21552, 0.21248, 0.21584, 
-//985
-0.10036, 0.11486, 0.1379, 0.15678, 0.17146, 0.1792, 0.18338, 0.18464, 0.18472, 0.18516, 0.18546, 0.18476, 0.18452, 0.1819, 0.18058, 0.17886, 0.17584, 0.17468,
-0.17072, 0.16628, 0.16002, 0.15264, 0.14484, 0.1372, 0.13064, 0.12464, 0.11862, 0.1124, 0.1073, 0.10476, 0.10124, 0.10116, 0.10012, 0.10032, 0.10116, 0.09842,
-0.09656, 0.09638, 0.0973, 0.09856, 0.10246, 0.105, 0.10988, 0.11288, 0.11426, 0.11404, 0.11318, 0.10954, 0.10788, 0.10734, 0.10864, 0.11218, 0.11638, 0.12204,
-0.12764, 0.1333, 0.13748, 0.13932, 0.14332, 0.14724, 0.14858, 0.14984, 0.15092, 0.15224, 0.15378, 0.15494, 0.15524, 0.15506, 0.1559, 0.15612, 0.15592, 0.15628,
-0.15858, 0.15636, 0.15832, 0.15816, 0.15864, 0.15754, 0.1584, 0.15856, 0.15812, 
-//986
-0.07852, 0.08756, 0.09826, 0.10752, 0.11388, 0.11576, 0.1174, 0.11814, 0.11922, 0.11994, 0.12002, 0.12162, 0.12028, 0.11934, 0.11758, 0.11462, 0.11186, 0.109,
-0.10576, 0.10118, 0.09542, 0.08844, 0.08212, 0.0777, 0.07314, 0.0698, 0.06632, 0.06294, 0.06056, 0.05898, 0.05912, 0.05692, 0.05758, 0.05784, 0.0581, 0.0579,
-0.05718, 0.05886, 0.05956, 0.06004, 0.06204, 0.06348, 0.06486, 0.06582, 0.0662, 0.06562, 0.06488, 0.06282, 0.06266, 0.06212, 0.0641, 0.06576, 0.06858, 0.07138,
-0.0753, 0.07914, 0.08288, 0.08554, 0.0893, 0.09192, 0.09358, 0.09332, 0.09418, 0.09564, 0.09576, 0.09658, 0.09848, 0.0995, 0.10024, 0.1027, 0.10434, 0.1067,
-0.10916, 0.11154, 0.11334, 0.11588, 0.11812, 0.11908, 0.12064, 0.12036, 0.122, 
-//987
-0.06252, 0.06768, 0.07482, 0.08186, 0.08554, 0.08714, 0.08842, 0.08938, 0.09206, 0.09028, 0.09188, 0.09222, 0.09162, 0.09096, 0.08804, 0.0867, 0.08554, 0.08192,
-0.07948, 0.07462, 0.07052, 0.06448, 0.06024, 0.0566, 0.0541, 0.05218, 0.0483, 0.04738, 0.04458, 0.04762, 0.04374, 0.0431, 0.04332, 0.0429, 0.04242, 0.04152,
-0.04276, 0.04252, 0.04394, 0.04426, 0.04548, 0.04766, 0.04852, 0.04794, 0.04824, 0.04872, 0.04696, 0.04602, 0.0473, 0.04706, 0.04798, 0.04908, 0.05174, 0.05378,
-0.05604, 0.05888, 0.06224, 0.06294, 0.06646, 0.06784, 0.0692, 0.06848, 0.06872, 0.06904, 0.07116, 0.07252, 0.07602, 0.07744, 0.07954, 0.08156, 0.08494, 0.0892,
-0.09336, 0.09524, 0.09824, 0.10258, 0.10758, 0.11094, 0.11424, 0.11052, 0.11168, 
-//988
-0.1201, 0.15178, 0.20198, 0.27842, 0.37216, 0.46438, 0.52798, 0.55512, 0.56752, 0.57234, 0.5765, 0.57768, 0.57956, 0.57906, 0.57522, 0.5711, 0.56632, 0.55996,
-0.5525, 0.54032, 0.52394, 0.50228, 0.4812, 0.46148, 0.44502, 0.42664, 0.4082, 0.38852, 0.37022, 0.35766, 0.35136, 0.34722, 0.34582, 0.34436, 0.34176, 0.3354,
-0.32888, 0.32632, 0.32806, 0.33472, 0.3438, 0.35396, 0.3642, 0.37048, 0.3732, 0.37116, 0.36572, 0.36084, 0.35696, 0.35986, 0.36816, 0.38072, 0.39692, 0.41558,
-0.43292, 0.44796, 0.46002, 0.468, 0.47286, 0.47392, 0.47124, 0.4682, 0.46742, 0.46936, 0.47322, 0.47722, 0.48332, 0.4889, 0.49352, 0.50132, 0.51072, 0.52432,
-0.53842, 0.54868, 0.56392, 0.57808, 0.58864, 0.5964, 0.60738, 0.61012, 0.61776, 
-//989
-0.12482, 0.14956, 0.19654, 0.25806, 0.32486, 0.37828, 0.40668, 0.41754, 0.42158, 0.42068, 0.42332, 0.42134, 0.42142, 0.41946, 0.41524, 0.41084, 0.40736, 0.4006,
-0.39198, 0.3819, 0.36914, 0.35242, 0.33494, 0.32006, 0.3062, 0.29316, 0.28008, 0.26588, 0.25308, 0.24396, 0.23902, 0.23636, 0.23436, 0.23416, 0.23244, 0.22732,
-0.22262, 0.22114, 0.22142, 0.22568, 0.23194, 0.2404, 0.25018, 0.25702, 0.25906, 0.2589, 0.25522, 0.24912, 0.24482, 0.24416, 0.24942, 0.25744, 0.26922, 0.28304,
-0.2983, 0.31082, 0.32258, 0.33096, 0.33582, 0.33964, 0.34026, 0.34052, 0.34206, 0.34308, 0.34618, 0.3491, 0.3531, 0.35614, 0.35832, 0.36282, 0.36758, 0.37208,
-0.37688, 0.37978, 0.3858, 0.3891, 0.39098, 0.39312, 0.39524, 0.39538, 0.39574, 
-//990
-0.1291, 0.15326, 0.1904, 0.2366, 0.27384, 0.29866, 0.3099, 0.31326, 0.31616, 0.31574, 0.3167, 0.31604, 0.31544, 0.31298, 0.30932, 0.30502, 0.30072, 0.29488,
-0.28832, 0.28028, 0.26796, 0.2536, 0.23928, 0.22594, 0.21368, 0.20274, 0.1918, 0.18004, 0.1698, 0.16316, 0.15944, 0.15812, 0.15696, 0.15598, 0.15456, 0.15112,
-0.14808, 0.14838, 0.14962, 0.15138, 0.15808, 0.16508, 0.17156, 0.17748, 0.17996, 0.17998, 0.17606, 0.17186, 0.16728, 0.1666, 0.1707, 0.17796, 0.18654, 0.19762,
-0.20814, 0.21926, 0.22914, 0.23516, 0.24068, 0.24412, 0.24492, 0.24496, 0.24488, 0.2461, 0.2483, 0.25062, 0.25388, 0.25728, 0.25962, 0.26212, 0.26596, 0.2693,
-0.27172, 0.27436, 0.27768, 0.27904, 0.28054, 0.27988, 0.28294, 0.28194, 0.2814, 
-//991
-0.11104, 0.13078, 0.15768, 0.18368, 0.20388, 0.21476, 0.22114, 0.2222, 0.22476, 0.2248, 0.22628, 0.2252, 0.22474, 0.22248, 0.2187, 0.21572, 0.21046, 0.20488,
-0.19878, 0.19076, 0.18062, 0.16772, 0.1548, 0.14476, 0.1357, 0.12764, 0.11988, 0.11292, 0.10656, 0.10212, 0.09996, 0.09794, 0.09848, 0.09782, 0.0973, 0.09592,
-0.0937, 0.09306, 0.09388, 0.09584, 0.09894, 0.10416, 0.11034, 0.1144, 0.11704, 0.11768, 0.11498, 0.11136, 0.108, 0.10758, 0.1106, 0.11488, 0.12174, 0.13076,
-0.14022, 0.15002, 0.15966, 0.16568, 0.1722, 0.17838, 0.18106, 0.18262, 0.18458, 0.1849, 0.18796, 0.18984, 0.1927, 0.19594, 0.19774, 0.2012, 0.2023, 0.20554,
-0.20854, 0.20974, 0.21174, 0.21266, 0.21594, 0.2154, 0.21708, 0.2165, 0.21776, 
-//992
-0.08264, 0.09626, 0.11066, 0.12574, 0.1374, 0.14242, 0.14698, 0.14976, 0.15206, 0.15402, 0.15542, 0.15562, 0.1543, 0.15182, 0.1489, 0.14352, 0.13878, 0.13236,
-0.12678, 0.1176, 0.10938, 0.09802, 0.08818, 0.08204, 0.07734, 0.07266, 0.06886, 0.0651, 0.06222, 0.05978, 0.05852, 0.05846, 0.05896, 0.05802, 0.05768, 0.0573,
-0.05566, 0.0561, 0.05652, 0.0584, 0.06122, 0.06342, 0.065, 0.06766, 0.06784, 0.067, 0.06636, 0.06422, 0.06402, 0.06408, 0.06582, 0.0677, 0.07126, 0.07724,
-0.0834, 0.09104, 0.09786, 0.10498, 0.11036, 0.11604, 0.11878, 0.1203, 0.12206, 0.12322, 0.12668, 0.12956, 0.13272, 0.1372, 0.1403, 0.14524, 0.14942, 0.15322,
-0.15852, 0.16346, 0.16876, 0.17276, 0.177, 0.18088, 0.1834, 0.18426, 0.18564, 
-//993
-0.06572, 0.06846, 0.07638, 0.08492, 0.09006, 0.09514, 0.09724, 0.10116, 0.10422, 0.10598, 0.10964, 0.11048, 0.11108, 0.10922, 0.1048, 0.09964, 0.09374, 0.08798,
-0.08232, 0.0755, 0.06854, 0.06232, 0.05608, 0.05094, 0.04916, 0.04628, 0.0447, 0.04302, 0.04176, 0.04186, 0.0419, 0.0419, 0.04128, 0.04192, 0.0422, 0.04216,
-0.04092, 0.04272, 0.04404, 0.04478, 0.04602, 0.047, 0.04786, 0.04796, 0.04832, 0.04648, 0.04648, 0.04676, 0.04612, 0.047, 0.0477, 0.04864, 0.04976, 0.0519,
-0.05496, 0.05774, 0.06184, 0.0633, 0.06674, 0.069, 0.06944, 0.06952, 0.07054, 0.0716, 0.0736, 0.07608, 0.07828, 0.08002, 0.08264, 0.08624, 0.09142, 0.09696,
-0.1069, 0.11796, 0.13128, 0.14736, 0.16314, 0.1804, 0.1988, 0.2191, 0.23866, 
-//994
-0.12818, 0.16004, 0.21338, 0.29358, 0.39292, 0.48946, 0.5569, 0.5893, 0.60636, 0.61286, 0.61788, 0.6218, 0.62424, 0.62344, 0.6185, 0.61372, 0.60674, 0.59668,
-0.5843, 0.5683, 0.5455, 0.52002, 0.49364, 0.46968, 0.44918, 0.42966, 0.40802, 0.38462, 0.36654, 0.35202, 0.34558, 0.34348, 0.34102, 0.33986, 0.33566, 0.3284,
-0.32106, 0.31818, 0.31976, 0.3264, 0.33594, 0.34716, 0.35814, 0.3646, 0.36672, 0.36492, 0.3605, 0.3545, 0.35168, 0.35378, 0.36178, 0.3764, 0.39368, 0.414,
-0.43682, 0.45516, 0.47296, 0.48262, 0.4872, 0.48782, 0.48418, 0.4809, 0.48028, 0.4825, 0.48656, 0.49284, 0.50092, 0.50824, 0.51678, 0.52502, 0.53844, 0.5561,
-0.57788, 0.59616, 0.6197, 0.64092, 0.65812, 0.67414, 0.691, 0.7, 0.7112, 
-//995
-0.1369, 0.1749, 0.2236, 0.29396, 0.37404, 0.4411, 0.4796, 0.49612, 0.50268, 0.50678, 0.50952, 0.50998, 0.50952, 0.50532, 0.49964, 0.49298, 0.4841, 0.47258,
-0.45868, 0.44194, 0.42008, 0.39614, 0.37004, 0.34778, 0.32854, 0.3104, 0.29288, 0.2738, 0.25882, 0.24726, 0.24236, 0.24062, 0.23856, 0.23776, 0.2348, 0.2302,
-0.2254, 0.22322, 0.22526, 0.22982, 0.23662, 0.24716, 0.25956, 0.26774, 0.27032, 0.26984, 0.265, 0.25692, 0.25314, 0.251, 0.25658, 0.26754, 0.28176, 0.29944,
-0.3201, 0.3389, 0.35702, 0.36812, 0.37664, 0.38258, 0.38422, 0.38412, 0.3812, 0.38286, 0.38624, 0.3889, 0.39488, 0.4003, 0.40348, 0.41092, 0.41814, 0.4268,
-0.43894, 0.44972, 0.4626, 0.47108, 0.48118, 0.48512, 0.4914, 0.49682, 0.50218, 
-//996
-0.11968, 0.14718, 0.19386, 0.24772, 0.29826, 0.33152, 0.34908, 0.35698, 0.36212, 0.36272, 0.36548, 0.36512, 0.36382, 0.36068, 0.354, 0.3474, 0.34088, 0.33096,
-0.31998, 0.30656, 0.28988, 0.26822, 0.2484, 0.23018, 0.21394, 0.20168, 0.18832, 0.17472, 0.16442, 0.15668, 0.15264, 0.15064, 0.14954, 0.14914, 0.14706, 0.14272,
-0.13924, 0.13778, 0.13844, 0.14062, 0.14642, 0.15394, 0.16358, 0.17058, 0.17436, 0.17446, 0.17034, 0.16516, 0.16024, 0.15956, 0.1637, 0.1717, 0.18464, 0.19846,
-0.21486, 0.23188, 0.24786, 0.25902, 0.26874, 0.27518, 0.2793, 0.28118, 0.28398, 0.28658, 0.28852, 0.29252, 0.2993, 0.30476, 0.3092, 0.3139, 0.32118, 0.3277,
-0.33572, 0.34162, 0.34732, 0.35302, 0.35816, 0.36136, 0.36326, 0.36172, 0.36612, 
-//997
-0.10776, 0.12318, 0.15954, 0.19632, 0.22764, 0.24724, 0.25828, 0.26396, 0.26724, 0.27006, 0.27272, 0.27302, 0.27184, 0.26898, 0.26384, 0.2563, 0.24852, 0.23964,
-0.22806, 0.2163, 0.20072, 0.18132, 0.16338, 0.15024, 0.13972, 0.12894, 0.12068, 0.11062, 0.10376, 0.09938, 0.09668, 0.09672, 0.0965, 0.09628, 0.09524, 0.09326,
-0.09114, 0.09168, 0.0928, 0.09396, 0.09788, 0.1028, 0.10902, 0.11276, 0.11636, 0.11528, 0.11336, 0.1091, 0.10534, 0.10376, 0.10762, 0.11258, 0.12044, 0.13172,
-0.14544, 0.15944, 0.17424, 0.1855, 0.19674, 0.2048, 0.21004, 0.21328, 0.21524, 0.21914, 0.22354, 0.22754, 0.23216, 0.23728, 0.2412, 0.24732, 0.25372, 0.2587,
-0.2665, 0.27314, 0.2799, 0.2856, 0.28774, 0.29198, 0.29548, 0.29578, 0.29958, 
-//998
-0.07938, 0.09234, 0.11128, 0.1291, 0.14238, 0.14992, 0.15842, 0.1631, 0.16782, 0.17294, 0.17502, 0.17728, 0.17738, 0.17264, 0.16668, 0.1583, 0.1495, 0.14068,
-0.1302, 0.1176, 0.1056, 0.09286, 0.08244, 0.07494, 0.06912, 0.06506, 0.06144, 0.058, 0.05582, 0.05482, 0.05502, 0.0543, 0.05418, 0.054, 0.05406, 0.05364,
-0.0537, 0.05454, 0.05482, 0.05662, 0.05808, 0.05894, 0.0601, 0.06104, 0.06098, 0.06032, 0.05934, 0.05844, 0.05786, 0.05816, 0.05996, 0.06206, 0.06574, 0.07088,
-0.07662, 0.08336, 0.09142, 0.09812, 0.10478, 0.1093, 0.11124, 0.11246, 0.11374, 0.11644, 0.12006, 0.12268, 0.12784, 0.13208, 0.1367, 0.14244, 0.14928, 0.15858,
-0.17108, 0.18684, 0.2036, 0.2203, 0.2391, 0.25518, 0.27016, 0.28844, 0.30684, 
-//999
-0.13532, 0.1649, 0.2186, 0.29294, 0.3799, 0.45702, 0.50572, 0.5274, 0.5403, 0.5452, 0.55168, 0.55192, 0.55138, 0.5478, 0.5384, 0.52808, 0.51626, 0.50128,
-0.48486, 0.46472, 0.43802, 0.40666, 0.37816, 0.35192, 0.3296, 0.3088, 0.28806, 0.26706, 0.24946, 0.237, 0.23022, 0.22702, 0.2266, 0.2256, 0.2232, 0.21662,
-0.2097, 0.21136, 0.21404, 0.2205, 0.2297, 0.23998, 0.25062, 0.2574, 0.25862, 0.2576, 0.2522, 0.24488, 0.2417, 0.24242, 0.25036, 0.26292, 0.27834, 0.29808,
-0.3188, 0.3376, 0.35342, 0.36474, 0.37184, 0.3736, 0.3721, 0.37072, 0.3676, 0.3673, 0.37174, 0.37752, 0.38662, 0.39428, 0.4013, 0.41296, 0.427, 0.4438, 0.46424,
-0.484, 0.50558, 0.5284, 0.54772, 0.56622, 0.5855, 0.59988, 0.61322, 
-//1000
-0.13096, 0.15776, 0.20422, 0.26744, 0.33274, 0.38338, 0.41286, 0.42706, 0.43532, 0.44028, 0.4453, 0.44764, 0.4469, 0.44128, 0.43348, 0.42042, 0.40688, 0.39176,
-0.37312, 0.35212, 0.32624, 0.29652, 0.26984, 0.24742, 0.22784, 0.21158, 0.19656, 0.18112, 0.16898, 0.1606, 0.15588, 0.15456, 0.15402, 0.1535, 0.15196, 0.14816,
-0.1436, 0.14022, 0.14122, 0.14502, 0.15094, 0.1589, 0.16842, 0.17526, 0.18034, 0.1797, 0.17616, 0.17134, 0.16692, 0.16676, 0.1714, 0.18212, 0.19376, 0.21278,
-0.2326, 0.25374, 0.27426, 0.29056, 0.303, 0.31182, 0.31514, 0.31708, 0.31954, 0.32554, 0.33104, 0.33762, 0.34506, 0.3532, 0.36198, 0.37306, 0.38446, 0.39986,
-0.41836, 0.43716, 0.45798, 0.47638, 0.49612, 0.51136, 0.52462, 0.53598, 0.54798, 
-//1001
-0.10736, 0.13122, 0.1676, 0.21086, 0.25226, 0.27938, 0.29576, 0.30482, 0.31106, 0.31676, 0.3206, 0.32446, 0.32464, 0.31836, 0.30852, 0.29728, 0.28748, 0.27386,
-0.258, 0.24058, 0.21878, 0.19466, 0.1726, 0.15652, 0.1432, 0.1297, 0.11828, 0.10756, 0.09874, 0.09342, 0.0909, 0.0895, 0.08928, 0.08958, 0.08844, 0.08666,
-0.0839, 0.0835, 0.08518, 0.08742, 0.09152, 0.09728, 0.10422, 0.10712, 0.10986, 0.10926, 0.10786, 0.10278, 0.09984, 0.10062, 0.1037, 0.11064, 0.11864, 0.12928,
-0.14296, 0.15812, 0.17144, 0.18326, 0.1914, 0.1977, 0.19746, 0.19782, 0.1997, 0.20348, 0.20756, 0.21266, 0.2199, 0.22642, 0.23372, 0.24338, 0.25494, 0.26784,
-0.28638, 0.30658, 0.32692, 0.34856, 0.36638, 0.38142, 0.39878, 0.40826, 0.41974, 
-//1002
-0.1307, 0.1607, 0.21514, 0.3005, 0.41144, 0.53654, 0.6385, 0.70124, 0.73096, 0.74196, 0.74828, 0.75286, 0.7566, 0.75778, 0.75902, 0.76044, 0.7626, 0.76346,
-0.76326, 0.76154, 0.7557, 0.74944, 0.74236, 0.73474, 0.72912, 0.72118, 0.71194, 0.69902, 0.68802, 0.68108, 0.67758, 0.67648, 0.67596, 0.6762, 0.67574, 0.67114,
-0.66854, 0.6683, 0.67098, 0.67786, 0.68574, 0.69382, 0.70302, 0.70738, 0.71052, 0.70862, 0.70614, 0.70178, 0.69774, 0.6989, 0.70436, 0.71138, 0.7206, 0.73068,
-0.74022, 0.74634, 0.75238, 0.75708, 0.75768, 0.76204, 0.7602, 0.76074, 0.76038, 0.76212, 0.76368, 0.76574, 0.76654, 0.76922, 0.76974, 0.77412, 0.7754, 0.77884,
-0.78582, 0.78608, 0.78676, 0.78908, 0.78962, 0.79006, 0.7961, 0.79244, 0.79586, 
-//1003
-0.13892, 0.17066, 0.22882, 0.313, 0.41306, 0.50714, 0.56406, 0.5862, 0.59388, 0.59292, 0.59616, 0.59744, 0.59772, 0.5962, 0.5924, 0.59012, 0.58718, 0.58442,
-0.58196, 0.57886, 0.5733, 0.5681, 0.5604, 0.555, 0.55082, 0.54552, 0.54146, 0.53468, 0.5295, 0.5233, 0.51878, 0.5155, 0.5155, 0.51544, 0.5161, 0.5173, 0.51694,
-0.51648, 0.51666, 0.51648, 0.51726, 0.51996, 0.52468, 0.52894, 0.5359, 0.54074, 0.54412, 0.54598, 0.54674, 0.54812, 0.54952, 0.55328, 0.55674, 0.5598, 0.5629,
-0.564, 0.56626, 0.56804, 0.56822, 0.5691, 0.56936, 0.56814, 0.5684, 0.5684, 0.56826, 0.56748, 0.56802, 0.56796, 0.56698, 0.56848, 0.56776, 0.5692, 0.5717,
-0.56546, 0.56752, 0.56618, 0.56656, 0.5654, 0.56514, 0.56604, 0.56516, 
-//1004
-0.14124, 0.1726, 0.22362, 0.29158, 0.36292, 0.41724, 0.44426, 0.4525, 0.45268, 0.4498, 0.44932, 0.44844, 0.44498, 0.44242, 0.43908, 0.4347, 0.43304, 0.43056,
-0.42738, 0.42382, 0.41956, 0.4146, 0.40842, 0.40528, 0.40328, 0.39994, 0.3976, 0.39334, 0.38928, 0.3837, 0.37952, 0.37674, 0.37434, 0.37484, 0.3773, 0.38134,
-0.38224, 0.38348, 0.37996, 0.3772, 0.37456, 0.3732, 0.3757, 0.38046, 0.3876, 0.39416, 0.40182, 0.40684, 0.41004, 0.41214, 0.4137, 0.41354, 0.41204, 0.41374,
-0.41316, 0.41194, 0.41216, 0.4111, 0.4107, 0.41114, 0.40856, 0.40864, 0.40674, 0.40738, 0.4056, 0.40526, 0.40534, 0.40488, 0.40416, 0.40434, 0.40516, 0.40398,
-0.40422, 0.40244, 0.40244, 0.40124, 0.3999, 0.39888, 0.39966, 0.39826, 0.39768, 
-//1005
-0.1298, 0.15406, 0.19436, 0.23894, 0.28502, 0.31066, 0.32146, 0.32432, 0.32446, 0.32312, 0.32208, 0.32046, 0.32268, 0.31404, 0.31274, 0.31046, 0.30796, 0.30408,
-0.30104, 0.29796, 0.29458, 0.2888, 0.28504, 0.2812, 0.27764, 0.27478, 0.27176, 0.26844, 0.26572, 0.26254, 0.25902, 0.2555, 0.25412, 0.25366, 0.25516, 0.25672,
-0.25728, 0.25848, 0.25584, 0.25658, 0.25428, 0.25384, 0.25584, 0.26054, 0.26846, 0.27404, 0.27904, 0.28438, 0.28764, 0.28958, 0.2918, 0.29024, 0.2908, 0.29026,
-0.29022, 0.288, 0.28786, 0.28922, 0.28896, 0.28878, 0.2879, 0.28636, 0.28564, 0.28508, 0.28524, 0.28392, 0.28404, 0.28316, 0.28234, 0.28368, 0.28392, 0.28296,
-0.28208, 0.28064, 0.27998, 0.27992, 0.27932, 0.27994, 0.27876, 0.27738, 0.27824, 
-//1006
-0.11004, 0.12932, 0.1572, 0.18718, 0.20874, 0.21948, 0.22508, 0.22504, 0.22546, 0.22322, 0.2226, 0.22132, 0.21906, 0.21766, 0.21602, 0.21292, 0.21214, 0.20912,
-0.20734, 0.20468, 0.20212, 0.19842, 0.19464, 0.1927, 0.19102, 0.18836, 0.18626, 0.1831, 0.18006, 0.17622, 0.17274, 0.1707, 0.17034, 0.17094, 0.17136, 0.17114,
-0.17068, 0.17208, 0.17374, 0.17456, 0.17582, 0.17548, 0.17752, 0.17938, 0.18232, 0.1862, 0.18928, 0.19072, 0.19236, 0.19294, 0.19388, 0.19442, 0.1943, 0.19372,
-0.1937, 0.19332, 0.19312, 0.19182, 0.19244, 0.19262, 0.19266, 0.19248, 0.1904, 0.18878, 0.18956, 0.18912, 0.19042, 0.19058, 0.19028, 0.19096, 0.19132, 0.19092,
-0.1907, 0.19026, 0.19004, 0.1901, 0.19032, 0.19062, 0.19002, 0.18834, 0.1898, 
-//1007
-0.0969, 0.10776, 0.1229, 0.13548, 0.14364, 0.14728, 0.1478, 0.1477, 0.14684, 0.14576, 0.14476, 0.14318, 0.14282, 0.14112, 0.13982, 0.13724, 0.13606, 0.13482,
-0.13346, 0.13046, 0.12834, 0.126, 0.12334, 0.12066, 0.1189, 0.11622, 0.11466, 0.11278, 0.11066, 0.10756, 0.1053, 0.1037, 0.10176, 0.1009, 0.10208, 0.10258,
-0.10294, 0.10564, 0.1041, 0.1016, 0.1017, 0.09948, 0.10108, 0.1042, 0.10804, 0.11248, 0.11744, 0.12076, 0.12308, 0.12552, 0.12724, 0.12786, 0.12814, 0.1292,
-0.1287, 0.12846, 0.12866, 0.12698, 0.12862, 0.12918, 0.12792, 0.12732, 0.1273, 0.1275, 0.12844, 0.1285, 0.12836, 0.12944, 0.1291, 0.1297, 0.13042, 0.1304,
-0.1314, 0.13124, 0.1313, 0.13086, 0.13142, 0.13256, 0.1322, 0.13188, 0.13154, 
-//1008
-0.07478, 0.07836, 0.08508, 0.08916, 0.0921, 0.09366, 0.09458, 0.09422, 0.09378, 0.09326, 0.0932, 0.09222, 0.09124, 0.09026, 0.08932, 0.0868, 0.08648, 0.08394,
-0.0832, 0.0803, 0.07828, 0.07658, 0.07304, 0.07088, 0.0694, 0.06784, 0.0668, 0.0655, 0.06442, 0.06334, 0.06212, 0.06226, 0.06178, 0.06188, 0.06232, 0.06246,
-0.06196, 0.06142, 0.0613, 0.06192, 0.06166, 0.06172, 0.0625, 0.0649, 0.06578, 0.06838, 0.071, 0.0717, 0.07358, 0.0742, 0.07554, 0.07644, 0.0769, 0.07868,
-0.07974, 0.0799, 0.08128, 0.08094, 0.082, 0.08254, 0.08326, 0.08284, 0.08406, 0.08544, 0.08536, 0.08568, 0.08706, 0.08786, 0.08812, 0.08726, 0.08828, 0.08902,
-0.0891, 0.08936, 0.08984, 0.08916, 0.08996, 0.08998, 0.0901, 0.08986, 0.09106, 
-//1009
-0.05906, 0.06362, 0.06764, 0.0693, 0.07014, 0.0724, 0.07244, 0.07318, 0.07224, 0.07166, 0.07154, 0.07086, 0.07054, 0.06852, 0.06686, 0.06492, 0.06282, 0.06104,
-0.05944, 0.05806, 0.0555, 0.05404, 0.05138, 0.05008, 0.05008, 0.0485, 0.0483, 0.04764, 0.04654, 0.0467, 0.04702, 0.04628, 0.04704, 0.04782, 0.04698, 0.0468,
-0.04644, 0.04694, 0.04696, 0.04748, 0.04806, 0.04856, 0.04946, 0.04914, 0.05108, 0.0516, 0.0524, 0.05236, 0.0529, 0.05338, 0.05376, 0.05492, 0.0568, 0.05894,
-0.06076, 0.06286, 0.06524, 0.066, 0.069, 0.07194, 0.07308, 0.07436, 0.07552, 0.0771, 0.07842, 0.07888, 0.07996, 0.08184, 0.0822, 0.08314, 0.08412, 0.08324,
-0.08538, 0.08538, 0.08682, 0.08578, 0.08696, 0.08706, 0.08844, 0.08902, 0.0908, 
-//1010
-0.1389, 0.16932, 0.22818, 0.31412, 0.41852, 0.52716, 0.602, 0.63828, 0.65164, 0.65806, 0.661, 0.66236, 0.66234, 0.66098, 0.66012, 0.65584, 0.6525, 0.64836,
-0.64208, 0.63314, 0.62062, 0.6053, 0.5897, 0.57516, 0.56248, 0.5489, 0.53344, 0.51768, 0.5042, 0.4932, 0.48848, 0.4877, 0.48708, 0.48674, 0.48658, 0.48372,
-0.48052, 0.47924, 0.48164, 0.48884, 0.50088, 0.51316, 0.52846, 0.53976, 0.54738, 0.54812, 0.54462, 0.53554, 0.52728, 0.52698, 0.53224, 0.54276, 0.55592,
-0.57302, 0.58944, 0.60602, 0.62004, 0.63014, 0.63878, 0.64762, 0.6529, 0.65878, 0.6612, 0.66504, 0.66676, 0.66716, 0.6693, 0.671, 0.671, 0.67392, 0.67674,
-0.67376, 0.67596, 0.67378, 0.6768, 0.67524, 0.67322, 0.67516, 0.67518, 0.67336, 0.66964, 
-//1011
-0.13672, 0.16992, 0.22434, 0.29854, 0.38284, 0.45086, 0.48892, 0.50374, 0.50522, 0.50434, 0.5031, 0.50176, 0.5, 0.49748, 0.4935, 0.48884, 0.48506, 0.47868,
-0.4731, 0.46556, 0.45606, 0.44412, 0.43142, 0.42122, 0.41096, 0.40042, 0.39096, 0.37856, 0.36898, 0.36108, 0.35486, 0.34978, 0.34946, 0.34958, 0.34936, 0.3487,
-0.34614, 0.34514, 0.34618, 0.34786, 0.3507, 0.3571, 0.36608, 0.37558, 0.38682, 0.3933, 0.3972, 0.39754, 0.3967, 0.39702, 0.40184, 0.4108, 0.4202, 0.43096,
-0.4437, 0.45424, 0.4641, 0.4716, 0.4759, 0.48098, 0.48528, 0.4844, 0.48686, 0.48598, 0.48964, 0.4901, 0.4907, 0.4923, 0.49406, 0.49608, 0.49598, 0.49616,
-0.49664, 0.496, 0.49638, 0.49598, 0.4979, 0.49754, 0.49736, 0.49456, 0.495, 
-//1012
-0.134, 0.16108, 0.20848, 0.2648, 0.31772, 0.3519, 0.368, 0.37176, 0.37178, 0.36944, 0.3671, 0.36684, 0.36202, 0.35912, 0.35272, 0.34844, 0.34506, 0.33816,
-0.3322, 0.32664, 0.31904, 0.31034, 0.30122, 0.29198, 0.2852, 0.27768, 0.27042, 0.26424, 0.2574, 0.25018, 0.24496, 0.23984, 0.23682, 0.23708, 0.23786, 0.23974,
-0.24028, 0.24068, 0.23928, 0.23778, 0.2363, 0.23654, 0.24088, 0.24982, 0.26116, 0.27366, 0.28536, 0.29302, 0.29858, 0.30316, 0.30814, 0.31256, 0.31766, 0.32246,
-0.32652, 0.32916, 0.33178, 0.33252, 0.33348, 0.3353, 0.33462, 0.33418, 0.33508, 0.3373, 0.3365, 0.3349, 0.33586, 0.33564, 0.3346, 0.33456, 0.33612, 0.33372,
-0.33624, 0.33432, 0.33382, 0.334, 0.33288, 0.33402, 0.33368, 0.33246, 0.33198, 
-//1013
-0.10878, 0.1309, 0.16518, 0.20152, 0.23264, 0.2486, 0.25638, 0.25856, 0.25742, 0.25826, 0.25816, 0.25654, 0.2545, 0.25386, 0.25094, 0.24902, 0.24598, 0.24222,
-0.23952, 0.23528, 0.22928, 0.22314, 0.21328, 0.20532, 0.19854, 0.19202, 0.18444, 0.17652, 0.17044, 0.16504, 0.16078, 0.15928, 0.15872, 0.15968, 0.15948,
-0.15806, 0.15708, 0.15746, 0.15786, 0.16048, 0.16418, 0.1683, 0.1741, 0.17912, 0.1847, 0.18764, 0.18984, 0.18988, 0.18838, 0.1895, 0.19262, 0.19842, 0.20394,
-0.20982, 0.2147, 0.21976, 0.22424, 0.22442, 0.22646, 0.22982, 0.2308, 0.2303, 0.23186, 0.23346, 0.23404, 0.23306, 0.23402, 0.23412, 0.23456, 0.23348, 0.23304,
-0.23384, 0.23482, 0.23386, 0.23462, 0.23484, 0.23414, 0.23378, 0.23426, 0.23268, 0.23404, 
-//1014
-0.09654, 0.11264, 0.13474, 0.15686, 0.17216, 0.1793, 0.1829, 0.18338, 0.18506, 0.18328, 0.18298, 0.18142, 0.1793, 0.17556, 0.17204, 0.16872, 0.16436, 0.16074,
-0.15716, 0.15186, 0.14824, 0.14144, 0.13456, 0.12844, 0.12426, 0.11982, 0.11504, 0.11164, 0.10722, 0.10406, 0.10116, 0.0991, 0.09846, 0.0982, 0.09772, 0.09884,
-0.09744, 0.0964, 0.0963, 0.09652, 0.09862, 0.09904, 0.10276, 0.10798, 0.11328, 0.11912, 0.12428, 0.12612, 0.12722, 0.12936, 0.13242, 0.13694, 0.14224, 0.14722,
-0.15284, 0.15704, 0.16098, 0.1626, 0.16432, 0.16682, 0.16658, 0.16696, 0.16838, 0.16932, 0.16916, 0.17018, 0.17214, 0.17338, 0.17536, 0.17806, 0.17904, 0.18028,
-0.18246, 0.18316, 0.1841, 0.18408, 0.18408, 0.18424, 0.18424, 0.18504, 0.18614, 
-//1015
-0.08272, 0.09224, 0.10424, 0.11456, 0.12164, 0.12522, 0.12666, 0.12638, 0.12698, 0.12612, 0.12432, 0.12288, 0.1214, 0.1174, 0.11432, 0.10986, 0.1063, 0.10222,
-0.09686, 0.09322, 0.08822, 0.08252, 0.07746, 0.07396, 0.07128, 0.06812, 0.06704, 0.0638, 0.06262, 0.06112, 0.06006, 0.05958, 0.05992, 0.05966, 0.05994, 0.06034,
-0.05936, 0.05902, 0.05948, 0.05976, 0.06084, 0.06178, 0.06346, 0.06526, 0.0686, 0.07194, 0.07418, 0.07602, 0.07708, 0.07898, 0.08068, 0.08406, 0.08962, 0.09402,
-0.0994, 0.10454, 0.10966, 0.11508, 0.11996, 0.1236, 0.12638, 0.12926, 0.13068, 0.1327, 0.13376, 0.13516, 0.13638, 0.13698, 0.138, 0.13776, 0.139, 0.13816,
-0.13938, 0.13914, 0.13982, 0.1401, 0.14114, 0.14172, 0.14218, 0.14042, 0.14326, 
-//1016
-0.06504, 0.07074, 0.07958, 0.08832, 0.09292, 0.09556, 0.09902, 0.09854, 0.09864, 0.09902, 0.09926, 0.09806, 0.09614, 0.09182, 0.08702, 0.08288, 0.07948,
-0.07528, 0.07108, 0.0672, 0.0633, 0.05902, 0.05454, 0.05078, 0.04944, 0.0476, 0.04616, 0.04482, 0.0442, 0.0435, 0.04346, 0.04268, 0.04246, 0.04298, 0.04266,
-0.04306, 0.0431, 0.0451, 0.04592, 0.04684, 0.04738, 0.0486, 0.04922, 0.05008, 0.05112, 0.05142, 0.0507, 0.05098, 0.05086, 0.05094, 0.05266, 0.05494, 0.05796,
-0.06236, 0.069, 0.07648, 0.08428, 0.09274, 0.10308, 0.11228, 0.12108, 0.1275, 0.13458, 0.14088, 0.1463, 0.15042, 0.1543, 0.15742, 0.15998, 0.16406, 0.16448,
-0.16598, 0.16686, 0.1669, 0.16886, 0.1699, 0.16958, 0.17022, 0.17062, 0.17104, 0.17188, 
-//1017
-0.13936, 0.17078, 0.2272, 0.30868, 0.40516, 0.49514, 0.55016, 0.5753, 0.58342, 0.58508, 0.58534, 0.5809, 0.57884, 0.57166, 0.5646, 0.55616, 0.5461, 0.53506,
-0.52336, 0.51056, 0.49386, 0.47568, 0.45666, 0.43918, 0.42446, 0.40856, 0.39452, 0.38076, 0.3667, 0.35714, 0.34962, 0.34582, 0.3437, 0.34256, 0.34358, 0.34128,
-0.33854, 0.33868, 0.3397, 0.34196, 0.34606, 0.35244, 0.36272, 0.37458, 0.38858, 0.4001, 0.40576, 0.40612, 0.40472, 0.40786, 0.41556, 0.42808, 0.4436, 0.46168,
-0.48078, 0.4975, 0.51314, 0.52448, 0.53242, 0.54008, 0.5443, 0.5487, 0.5495, 0.55252, 0.55488, 0.5602, 0.56384, 0.566, 0.56946, 0.57322, 0.57858, 0.58192,
-0.58694, 0.5908, 0.5938, 0.59684, 0.59766, 0.5989, 0.60132, 0.60088, 0.60252, 
-//1018
-0.13812, 0.16744, 0.2184, 0.28384, 0.35296, 0.4021, 0.42732, 0.43474, 0.4369, 0.4356, 0.43466, 0.4321, 0.42712, 0.42214, 0.41596, 0.40794, 0.40008, 0.3913,
-0.38172, 0.37144, 0.35884, 0.34246, 0.32658, 0.31332, 0.30138, 0.28988, 0.27894, 0.26678, 0.2564, 0.24714, 0.24224, 0.23812, 0.23646, 0.23484, 0.23616, 0.23566,
-0.23376, 0.2328, 0.2335, 0.23514, 0.23896, 0.24362, 0.25208, 0.26232, 0.2739, 0.28478, 0.29074, 0.29352, 0.2924, 0.29422, 0.30066, 0.30944, 0.32108, 0.335,
-0.3486, 0.36136, 0.37272, 0.3812, 0.38804, 0.39476, 0.3991, 0.40096, 0.4026, 0.4026, 0.40494, 0.40566, 0.40696, 0.40712, 0.40626, 0.40942, 0.40858, 0.40624,
-0.4076, 0.40632, 0.40708, 0.40706, 0.40644, 0.40452, 0.4037, 0.40082, 0.40442, 
-//1019
-0.11408, 0.137, 0.17578, 0.2209, 0.26136, 0.28614, 0.2972, 0.3015, 0.30304, 0.30286, 0.30266, 0.30098, 0.29926, 0.29636, 0.29174, 0.2863, 0.2816, 0.275,
-0.26858, 0.25926, 0.24936, 0.23656, 0.22274, 0.2107, 0.19958, 0.18922, 0.17874, 0.1685, 0.15828, 0.15092, 0.1479, 0.14638, 0.14548, 0.14668, 0.1473, 0.14634,
-0.14454, 0.14576, 0.14772, 0.15288, 0.15752, 0.16378, 0.17172, 0.18044, 0.18648, 0.19166, 0.19286, 0.18952, 0.18706, 0.18738, 0.19344, 0.20126, 0.21178,
-0.22286, 0.23488, 0.24464, 0.25494, 0.26348, 0.2702, 0.27574, 0.2793, 0.2824, 0.2845, 0.28716, 0.28796, 0.28874, 0.28968, 0.29016, 0.28924, 0.2914, 0.29194,
-0.29116, 0.29212, 0.29132, 0.29214, 0.2914, 0.29086, 0.29118, 0.29242, 0.29034, 0.28956, 
-//1020
-0.10794, 0.12778, 0.1551, 0.18352, 0.20376, 0.21444, 0.21926, 0.22126, 0.22204, 0.22006, 0.21926, 0.21588, 0.21308, 0.20764, 0.20226, 0.19666, 0.19092, 0.18436,
-0.17748, 0.16994, 0.16218, 0.1523, 0.14238, 0.13406, 0.12676, 0.1211, 0.11512, 0.10932, 0.10366, 0.10002, 0.0977, 0.09534, 0.09436, 0.09392, 0.09344, 0.09326,
-0.0928, 0.09516, 0.09456, 0.09492, 0.0964, 0.0981, 0.10112, 0.10634, 0.11316, 0.11928, 0.12494, 0.1265, 0.12856, 0.13044, 0.13582, 0.14072, 0.14876, 0.15862,
-0.1697, 0.17916, 0.1887, 0.1946, 0.20118, 0.20736, 0.21106, 0.2128, 0.21502, 0.21662, 0.2179, 0.21778, 0.2194, 0.22034, 0.22106, 0.22154, 0.22248, 0.22282,
-0.22272, 0.22294, 0.22354, 0.22368, 0.22294, 0.2227, 0.22296, 0.22284, 0.22146, 
-//1021
-0.08534, 0.09778, 0.11584, 0.13296, 0.1443, 0.1516, 0.15468, 0.15704, 0.15752, 0.15532, 0.15358, 0.14984, 0.14364, 0.13802, 0.13176, 0.12516, 0.1172, 0.10984,
-0.10344, 0.09674, 0.08972, 0.08348, 0.077, 0.07324, 0.06914, 0.06636, 0.0639, 0.06114, 0.05922, 0.05736, 0.05654, 0.0557, 0.05478, 0.05468, 0.05552, 0.05506,
-0.05506, 0.0543, 0.0539, 0.0555, 0.05636, 0.05666, 0.058, 0.06124, 0.06478, 0.06964, 0.07368, 0.0759, 0.07808, 0.08004, 0.08364, 0.0903, 0.09784, 0.10782,
-0.11992, 0.1333, 0.14718, 0.1599, 0.17508, 0.18768, 0.19792, 0.20718, 0.21618, 0.22442, 0.23042, 0.23654, 0.24126, 0.24642, 0.24912, 0.25304, 0.25704, 0.25956,
-0.26314, 0.26402, 0.2677, 0.26784, 0.27138, 0.27162, 0.2741, 0.27522, 0.27666, 
-//1022
-0.13458, 0.1654, 0.2225, 0.3037, 0.40504, 0.50662, 0.57868, 0.61762, 0.63142, 0.6378, 0.6413, 0.64452, 0.64346, 0.63912, 0.63202, 0.6233, 0.61196, 0.60006,
-0.58656, 0.56632, 0.54224, 0.51316, 0.48354, 0.45768, 0.43674, 0.41358, 0.39208, 0.3689, 0.34818, 0.33466, 0.32848, 0.32758, 0.32912, 0.33036, 0.32908, 0.32502,
-0.32014, 0.322, 0.32904, 0.34176, 0.35902, 0.3789, 0.39966, 0.41416, 0.42342, 0.42304, 0.41334, 0.39998, 0.39016, 0.388, 0.39666, 0.41398, 0.43524, 0.46374,
-0.49212, 0.5217, 0.5531, 0.57988, 0.60634, 0.63144, 0.65058, 0.67012, 0.68376, 0.70044, 0.71388, 0.7237, 0.73282, 0.7379, 0.74416, 0.75124, 0.75446, 0.7584,
-0.76256, 0.76388, 0.76628, 0.76426, 0.76554, 0.76878, 0.7703, 0.76634, 0.77022, 
-//1023
-0.13232, 0.16232, 0.21344, 0.28678, 0.36976, 0.44012, 0.48032, 0.49698, 0.50284, 0.50334, 0.50298, 0.49838, 0.492, 0.48262, 0.47234, 0.46172, 0.44944, 0.43422,
-0.42, 0.40274, 0.3838, 0.3615, 0.3385, 0.31958, 0.30346, 0.28822, 0.27502, 0.25986, 0.24678, 0.23824, 0.23212, 0.22732, 0.22658, 0.22586, 0.22558, 0.22386,
-0.22266, 0.22246, 0.22334, 0.22644, 0.23232, 0.24062, 0.2525, 0.26538, 0.2784, 0.2882, 0.29276, 0.29234, 0.28972, 0.29016, 0.2982, 0.31172, 0.3306, 0.35372,
-0.38006, 0.40578, 0.42966, 0.45166, 0.47132, 0.49056, 0.50356, 0.51448, 0.52358, 0.5322, 0.53918, 0.54464, 0.54736, 0.54976, 0.55348, 0.5568, 0.55912, 0.55892,
-0.56054, 0.558, 0.55812, 0.55804, 0.56184, 0.55906, 0.55778, 0.55718, 0.55796, 
-//1024
-0.11466, 0.13934, 0.18576, 0.24166, 0.29604, 0.3334, 0.35146, 0.36004, 0.36274, 0.36294, 0.363, 0.36194, 0.35856, 0.35318, 0.34808, 0.3389, 0.33078, 0.32056,
-0.30896, 0.29488, 0.27812, 0.2589, 0.2405, 0.22356, 0.20826, 0.19444, 0.18264, 0.16882, 0.15776, 0.15042, 0.14656, 0.14442, 0.14486, 0.14586, 0.14616, 0.1438,
-0.14308, 0.14396, 0.14748, 0.1532, 0.15968, 0.1687, 0.17902, 0.18904, 0.19654, 0.2012, 0.20174, 0.19696, 0.19268, 0.19312, 0.20046, 0.21264, 0.22748, 0.24614,
-0.26666, 0.28656, 0.30456, 0.32098, 0.33602, 0.34892, 0.3574, 0.36486, 0.37148, 0.37678, 0.38124, 0.3845, 0.38608, 0.38838, 0.38912, 0.39222, 0.39358, 0.39298,
-0.39496, 0.39358, 0.3938, 0.394, 0.3924, 0.39342, 0.39278, 0.39084, 0.3904, 
-//1025
-0.10812, 0.13314, 0.16806, 0.2051, 0.23616, 0.2562, 0.26426, 0.26784, 0.26898, 0.26694, 0.26388, 0.25806, 0.25196, 0.2434, 0.23374, 0.22328, 0.21234, 0.20146,
-0.19092, 0.17904, 0.16802, 0.15474, 0.14232, 0.13176, 0.12472, 0.11714, 0.11136, 0.10556, 0.101, 0.0965, 0.09326, 0.09166, 0.09096, 0.09124, 0.09174, 0.09208,
-0.09144, 0.09158, 0.09196, 0.0916, 0.09306, 0.09474, 0.09882, 0.10474, 0.1135, 0.12372, 0.1324, 0.13802, 0.14104, 0.14454, 0.15038, 0.1611, 0.17164, 0.18716,
-0.20514, 0.22236, 0.2394, 0.25444, 0.26966, 0.28114, 0.28974, 0.29692, 0.30232, 0.30896, 0.31298, 0.31676, 0.31942, 0.32238, 0.32386, 0.3257, 0.32808, 0.32812,
-0.32904, 0.33048, 0.33162, 0.33266, 0.33272, 0.33294, 0.3337, 0.33256, 0.33386, 
-//1026
-0.08218, 0.09554, 0.11512, 0.13738, 0.15588, 0.16896, 0.1747, 0.1787, 0.18046, 0.1796, 0.17676, 0.17194, 0.16628, 0.1581, 0.14772, 0.13718, 0.12722, 0.11818,
-0.1096, 0.10128, 0.09286, 0.08444, 0.07752, 0.07056, 0.06642, 0.06334, 0.06024, 0.0574, 0.0548, 0.05284, 0.05292, 0.0514, 0.05184, 0.05218, 0.05224, 0.05264,
-0.05316, 0.05334, 0.05356, 0.05474, 0.05426, 0.05582, 0.05786, 0.06072, 0.06368, 0.06744, 0.06986, 0.07076, 0.07108, 0.07292, 0.07706, 0.08258, 0.09074, 0.1024,
-0.11708, 0.13654, 0.15774, 0.17964, 0.20508, 0.23268, 0.25872, 0.28498, 0.31124, 0.33664, 0.3584, 0.37912, 0.39824, 0.41486, 0.42828, 0.44286, 0.45674, 0.46662,
-0.47824, 0.48762, 0.49584, 0.50326, 0.51068, 0.5165, 0.52004, 0.52052, 0.5214, 
-//1027
-0.13308, 0.16192, 0.21494, 0.2892, 0.37494, 0.45156, 0.49786, 0.51866, 0.52738, 0.52684, 0.52878, 0.52524, 0.51996, 0.50918, 0.49872, 0.48562, 0.47032, 0.45508,
-0.43722, 0.41672, 0.39404, 0.36774, 0.3424, 0.31896, 0.30024, 0.28308, 0.26488, 0.24746, 0.2324, 0.22182, 0.21674, 0.21316, 0.21362, 0.21306, 0.21376, 0.21064,
-0.20776, 0.2096, 0.2133, 0.2211, 0.23146, 0.24246, 0.2561, 0.27038, 0.28296, 0.2888, 0.2898, 0.2838, 0.27868, 0.28044, 0.29004, 0.30618, 0.32766, 0.35448,
-0.38376, 0.41466, 0.44592, 0.4774, 0.50558, 0.5342, 0.55622, 0.58072, 0.59876, 0.61756, 0.63424, 0.64758, 0.65932, 0.66772, 0.67596, 0.6851, 0.69296, 0.69614,
-0.70234, 0.70224, 0.7075, 0.71026, 0.71238, 0.7155, 0.7208, 0.7183, 0.71928, 
-//1028
-0.11514, 0.14126, 0.18932, 0.25114, 0.31766, 0.37024, 0.39936, 0.41288, 0.42108, 0.42034, 0.4215, 0.41824, 0.41224, 0.40268, 0.38844, 0.37586, 0.36116, 0.34384,
-0.32602, 0.30722, 0.28578, 0.26054, 0.23742, 0.21742, 0.2019, 0.18772, 0.17448, 0.16134, 0.1508, 0.14196, 0.13836, 0.1364, 0.13654, 0.1373, 0.13728, 0.13524,
-0.13368, 0.13528, 0.13828, 0.14368, 0.1507, 0.15954, 0.17038, 0.18106, 0.19104, 0.19762, 0.19744, 0.19218, 0.18838, 0.18908, 0.19698, 0.20906, 0.22942, 0.25242,
-0.2812, 0.31104, 0.34274, 0.3738, 0.40412, 0.43514, 0.46132, 0.48714, 0.5081, 0.52736, 0.547, 0.56064, 0.57422, 0.58508, 0.59404, 0.60362, 0.6093, 0.61542,
-0.61818, 0.62032, 0.62452, 0.62388, 0.62684, 0.62704, 0.6285, 0.6235, 0.6276, 
-//1029
-0.1201, 0.14168, 0.18, 0.22738, 0.26822, 0.29714, 0.313, 0.3172, 0.319, 0.3166, 0.31302, 0.3053, 0.2942, 0.28178, 0.26602, 0.2519, 0.2364, 0.22078, 0.20752,
-0.19152, 0.17704, 0.16086, 0.14624, 0.13428, 0.12416, 0.11706, 0.11094, 0.10426, 0.09738, 0.0934, 0.0901, 0.08764, 0.0867, 0.08574, 0.08576, 0.0856, 0.08522,
-0.08536, 0.08562, 0.0858, 0.0881, 0.09006, 0.09414, 0.10052, 0.1096, 0.11988, 0.12696, 0.1305, 0.1335, 0.13706, 0.14526, 0.1565, 0.17058, 0.19064, 0.21566,
-0.24358, 0.27374, 0.30316, 0.33492, 0.36496, 0.39342, 0.42102, 0.44916, 0.47242, 0.49576, 0.51344, 0.53282, 0.54682, 0.5598, 0.5733, 0.5838, 0.59098, 0.60246,
-0.60384, 0.60854, 0.615, 0.61954, 0.6204, 0.62362, 0.62496, 0.63204, 
-//1030
-0.13038, 0.15974, 0.21168, 0.2943, 0.39888, 0.51776, 0.61386, 0.67064, 0.69316, 0.7028, 0.7078, 0.70956, 0.71062, 0.71124, 0.71024, 0.71018, 0.71024, 0.70912,
-0.70778, 0.70532, 0.70036, 0.6961, 0.69448, 0.69224, 0.68908, 0.68622, 0.68342, 0.68058, 0.67742, 0.67366, 0.67014, 0.66702, 0.6649, 0.6628, 0.66512, 0.66674,
-0.66744, 0.66888, 0.66646, 0.6633, 0.6618, 0.6606, 0.66402, 0.66754, 0.67608, 0.68494, 0.69214, 0.70008, 0.7032, 0.70576, 0.71006, 0.71026, 0.70818, 0.71162,
-0.711, 0.7111, 0.71236, 0.71102, 0.70898, 0.7107, 0.7095, 0.71048, 0.70946, 0.71148, 0.71226, 0.71184, 0.713, 0.71382, 0.71264, 0.71426, 0.71536, 0.71502,
-0.71692, 0.71312, 0.7154, 0.71604, 0.71614, 0.71278, 0.71434, 0.7156, 0.71568, 
-//1031
-0.13188, 0.16048, 0.21312, 0.2886, 0.38198, 0.4656, 0.51668, 0.53456, 0.54024, 0.54158, 0.54178, 0.54018, 0.53874, 0.53614, 0.53428, 0.53306, 0.53298, 0.53238,
-0.5322, 0.53026, 0.52634, 0.52272, 0.52, 0.51954, 0.51872, 0.51866, 0.51738, 0.51408, 0.50984, 0.50352, 0.4996, 0.49706, 0.49862, 0.50072, 0.50532, 0.5066,
-0.50478, 0.50826, 0.50818, 0.51282, 0.5157, 0.51814, 0.52096, 0.52056, 0.52004, 0.52004, 0.52068, 0.51774, 0.51698, 0.5165, 0.51506, 0.51394, 0.51282, 0.51362,
-0.5126, 0.51076, 0.5111, 0.50908, 0.50838, 0.50978, 0.50816, 0.50672, 0.50478, 0.5059, 0.5058, 0.5052, 0.50614, 0.5042, 0.50292, 0.50258, 0.50168, 0.5011,
-0.50266, 0.50064, 0.50158, 0.5012, 0.49896, 0.49886, 0.50126, 0.49936, 0.4973, 
-//1032
-0.12998, 0.15916, 0.20748, 0.27074, 0.33162, 0.37432, 0.39334, 0.39986, 0.39864, 0.39632, 0.39604, 0.39456, 0.39428, 0.39324, 0.39224, 0.3885, 0.38636, 0.38518,
-0.383, 0.38106, 0.37668, 0.3728, 0.36966, 0.36604, 0.36568, 0.36426, 0.3633, 0.36016, 0.35832, 0.35602, 0.35558, 0.35404, 0.35446, 0.35648, 0.36032, 0.36176,
-0.36152, 0.36344, 0.3648, 0.36814, 0.37202, 0.373, 0.3745, 0.37362, 0.37452, 0.37332, 0.37284, 0.37082, 0.36924, 0.36932, 0.3687, 0.36726, 0.365, 0.36434,
-0.36296, 0.36144, 0.36168, 0.35894, 0.35802, 0.35862, 0.35586, 0.35478, 0.3531, 0.35418, 0.35428, 0.3527, 0.35342, 0.35278, 0.3508, 0.3521, 0.35094, 0.35038,
-0.35014, 0.3487, 0.34864, 0.34922, 0.34792, 0.34578, 0.34518, 0.34476, 0.34524, 
-//1033
-0.11582, 0.1388, 0.17536, 0.2176, 0.25364, 0.27466, 0.28396, 0.28594, 0.28438, 0.28276, 0.28034, 0.28018, 0.28016, 0.27868, 0.27712, 0.27616, 0.27498, 0.27544,
-0.27356, 0.27166, 0.26898, 0.26586, 0.2624, 0.26096, 0.26042, 0.258, 0.25632, 0.25282, 0.24954, 0.24472, 0.24294, 0.24228, 0.2421, 0.24656, 0.25084, 0.25258,
-0.25358, 0.2552, 0.25712, 0.2594, 0.26044, 0.26142, 0.26358, 0.26274, 0.2636, 0.26334, 0.26176, 0.26006, 0.2581, 0.25808, 0.2577, 0.25762, 0.25604, 0.25582,
-0.25508, 0.25288, 0.25462, 0.25246, 0.2514, 0.25222, 0.25176, 0.2514, 0.24918, 0.2496, 0.24928, 0.24852, 0.24794, 0.24786, 0.24838, 0.24948, 0.24894, 0.24778,
-0.24728, 0.24706, 0.2466, 0.24718, 0.24664, 0.24636, 0.24678, 0.24456, 0.24456, 
-//1034
-0.10048, 0.11542, 0.1402, 0.163, 0.1802, 0.18888, 0.19042, 0.19132, 0.19004, 0.18902, 0.18854, 0.18784, 0.18844, 0.1877, 0.18648, 0.18468, 0.18432, 0.1838,
-0.18122, 0.1795, 0.17824, 0.17554, 0.17348, 0.17218, 0.17142, 0.17014, 0.16916, 0.16764, 0.16472, 0.16408, 0.1638, 0.16344, 0.16266, 0.16192, 0.16326, 0.16264,
-0.16064, 0.1628, 0.1651, 0.16844, 0.17272, 0.1749, 0.17632, 0.17604, 0.17672, 0.17492, 0.17484, 0.17444, 0.17352, 0.17314, 0.17158, 0.17232, 0.16942, 0.16992,
-0.16958, 0.1695, 0.1684, 0.16614, 0.16602, 0.16936, 0.16848, 0.16796, 0.16566, 0.16754, 0.16636, 0.16694, 0.1671, 0.16712, 0.16602, 0.16684, 0.16762, 0.16688,
-0.16736, 0.16766, 0.16752, 0.16856, 0.16786, 0.16786, 0.1695, 0.16646, 0.16704, 
-//1035
-0.088, 0.09364, 0.1052, 0.11582, 0.12138, 0.12346, 0.12496, 0.12402, 0.1242, 0.12268, 0.12248, 0.12114, 0.11992, 0.1175, 0.11656, 0.11534, 0.11564, 0.1149,
-0.11434, 0.11394, 0.11322, 0.11076, 0.10964, 0.10962, 0.10806, 0.10718, 0.10662, 0.1057, 0.10252, 0.1, 0.09884, 0.09732, 0.09836, 0.0978, 0.09942, 0.09946,
-0.09804, 0.09962, 0.10094, 0.10416, 0.10648, 0.10802, 0.10912, 0.10916, 0.10972, 0.1097, 0.10908, 0.10826, 0.10856, 0.10746, 0.10824, 0.10786, 0.10736, 0.10684,
-0.10682, 0.10692, 0.10678, 0.10508, 0.1055, 0.10734, 0.10818, 0.10736, 0.10754, 0.10672, 0.10714, 0.10714, 0.10878, 0.10884, 0.1088, 0.1102, 0.10992, 0.11006,
-0.11042, 0.11006, 0.11126, 0.11046, 0.11016, 0.11032, 0.11024, 0.10974, 0.10858, 
-//1036
-0.06652, 0.06882, 0.07104, 0.07452, 0.07592, 0.07564, 0.07704, 0.07692, 0.07612, 0.07554, 0.07574, 0.0749, 0.07474, 0.07326, 0.07268, 0.07172, 0.07118, 0.07098,
-0.07018, 0.0693, 0.06862, 0.06656, 0.06524, 0.06428, 0.06256, 0.06226, 0.06134, 0.0594, 0.05828, 0.05744, 0.05684, 0.05626, 0.05608, 0.05726, 0.05782, 0.0563,
-0.05642, 0.05736, 0.05864, 0.05972, 0.06118, 0.06314, 0.0639, 0.06512, 0.06444, 0.06436, 0.06506, 0.06458, 0.06622, 0.0659, 0.06608, 0.0661, 0.06618, 0.06612,
-0.06618, 0.0671, 0.06708, 0.06536, 0.06684, 0.06716, 0.06766, 0.0672, 0.06724, 0.06716, 0.06676, 0.06754, 0.06866, 0.0692, 0.06958, 0.07068, 0.07016, 0.07044,
-0.07176, 0.07274, 0.07276, 0.07296, 0.07386, 0.07498, 0.07454, 0.0744, 0.07594, 
-//1037
-0.0539, 0.05608, 0.05802, 0.0596, 0.06014, 0.06002, 0.05914, 0.05876, 0.05894, 0.05814, 0.05774, 0.05738, 0.05622, 0.05548, 0.0554, 0.05476, 0.05468, 0.05398,
-0.05376, 0.05372, 0.05254, 0.05148, 0.05164, 0.05072, 0.05074, 0.05008, 0.04922, 0.04868, 0.04778, 0.04658, 0.04658, 0.04564, 0.04572, 0.04552, 0.0457, 0.04498,
-0.0443, 0.04518, 0.04556, 0.047, 0.04658, 0.04784, 0.04874, 0.0495, 0.05062, 0.05184, 0.05312, 0.05336, 0.05336, 0.05412, 0.0545, 0.0541, 0.05404, 0.05402,
-0.05392, 0.05296, 0.0539, 0.05124, 0.05256, 0.05434, 0.05396, 0.05452, 0.05616, 0.05624, 0.05652, 0.05732, 0.05802, 0.0574, 0.05858, 0.0597, 0.0593, 0.06028,
-0.0601, 0.06056, 0.0607, 0.06224, 0.06286, 0.06184, 0.06234, 0.06306, 0.0636, 
-//1038
-0.1465, 0.17748, 0.233, 0.31794, 0.42638, 0.54524, 0.64174, 0.70122, 0.72692, 0.73704, 0.7398, 0.74242, 0.74294, 0.7425, 0.74322, 0.74206, 0.74078, 0.73806,
-0.7363, 0.73358, 0.72906, 0.72222, 0.71616, 0.71112, 0.7083, 0.70152, 0.69452, 0.68768, 0.68278, 0.67784, 0.6734, 0.66948, 0.66712, 0.6667, 0.66966, 0.66932,
-0.6702, 0.6705, 0.66986, 0.66936, 0.67016, 0.67368, 0.67902, 0.6849, 0.6931, 0.70042, 0.70662, 0.71018, 0.7117, 0.71358, 0.71806, 0.72268, 0.72876, 0.73726,
-0.74186, 0.74468, 0.74898, 0.74986, 0.75232, 0.75796, 0.75732, 0.75932, 0.75848, 0.76046, 0.76128, 0.76152, 0.76226, 0.76244, 0.76242, 0.76456, 0.76346, 0.7645,
-0.76688, 0.76508, 0.76632, 0.76526, 0.76468, 0.7636, 0.768, 0.7643, 0.76596, 
-//1039
-0.14418, 0.17026, 0.22438, 0.30408, 0.3999, 0.49068, 0.55146, 0.5761, 0.5843, 0.58466, 0.584, 0.58346, 0.58294, 0.5796, 0.57768, 0.5762, 0.57392, 0.5701,
-0.56734, 0.56282, 0.55724, 0.55084, 0.54588, 0.54214, 0.54018, 0.53474, 0.5293, 0.52256, 0.5145, 0.50756, 0.50186, 0.49862, 0.49776, 0.49882, 0.50026, 0.4988,
-0.4989, 0.50072, 0.50352, 0.50712, 0.51146, 0.51552, 0.52014, 0.525, 0.5289, 0.53482, 0.53852, 0.54102, 0.54196, 0.54242, 0.54446, 0.5449, 0.54334, 0.544,
-0.54344, 0.54162, 0.54152, 0.54182, 0.53992, 0.53826, 0.53726, 0.53716, 0.53636, 0.5356, 0.53572, 0.53444, 0.53564, 0.53416, 0.53316, 0.53384, 0.53278, 0.5319,
-0.53308, 0.52912, 0.53064, 0.52846, 0.52812, 0.52772, 0.52776, 0.52628, 0.52414, 
-//1040
-0.14038, 0.17022, 0.21856, 0.28472, 0.35198, 0.4004, 0.42494, 0.43228, 0.43232, 0.43192, 0.43148, 0.42842, 0.42716, 0.42532, 0.4211, 0.41816, 0.41702, 0.41478,
-0.41172, 0.40738, 0.40392, 0.3998, 0.3957, 0.39156, 0.38932, 0.3865, 0.38246, 0.37696, 0.37218, 0.36616, 0.36222, 0.3609, 0.36058, 0.36096, 0.36044, 0.35672,
-0.35414, 0.35534, 0.35956, 0.36724, 0.37648, 0.38456, 0.38958, 0.39268, 0.39338, 0.39422, 0.39396, 0.39332, 0.39208, 0.3911, 0.39008, 0.38876, 0.38742, 0.38516,
-0.38476, 0.38322, 0.38182, 0.37942, 0.37848, 0.37814, 0.37586, 0.37384, 0.3745, 0.3756, 0.37416, 0.3729, 0.37202, 0.37026, 0.36976, 0.3711, 0.37014, 0.37048,
-0.36924, 0.36832, 0.36728, 0.36612, 0.36624, 0.36782, 0.36714, 0.36444, 0.36458, 
-//1041
-0.12566, 0.15262, 0.19276, 0.2395, 0.28132, 0.30642, 0.3158, 0.31856, 0.31814, 0.31656, 0.31596, 0.31382, 0.3115, 0.30902, 0.30696, 0.30424, 0.30298, 0.30118,
-0.29862, 0.2963, 0.29306, 0.2879, 0.28448, 0.28182, 0.28056, 0.27676, 0.27302, 0.26644, 0.26022, 0.25286, 0.24882, 0.24726, 0.24836, 0.24978, 0.25138, 0.24972,
-0.24718, 0.25016, 0.2539, 0.26026, 0.26804, 0.27312, 0.27808, 0.28022, 0.28186, 0.28242, 0.28222, 0.28152, 0.2812, 0.28058, 0.28074, 0.28096, 0.2796, 0.27914,
-0.27878, 0.2765, 0.27718, 0.27568, 0.27536, 0.27494, 0.27398, 0.27322, 0.27184, 0.27342, 0.27132, 0.27156, 0.27168, 0.2716, 0.26936, 0.26946, 0.2699, 0.26966,
-0.2704, 0.2669, 0.26822, 0.26808, 0.26634, 0.2664, 0.26462, 0.26518, 0.26652, 
-//1042
-0.10464, 0.12056, 0.1465, 0.1774, 0.19724, 0.20706, 0.21104, 0.21106, 0.21178, 0.21134, 0.21156, 0.21068, 0.20948, 0.20742, 0.20674, 0.20442, 0.20336, 0.20222,
-0.19962, 0.19618, 0.19354, 0.19106, 0.1884, 0.18332, 0.18194, 0.17834, 0.17568, 0.1737, 0.1714, 0.16956, 0.16716, 0.16512, 0.16386, 0.1628, 0.16304, 0.16244,
-0.16256, 0.16514, 0.16568, 0.16718, 0.16954, 0.17042, 0.17356, 0.17722, 0.18252, 0.18814, 0.194, 0.19656, 0.19824, 0.20028, 0.20094, 0.2023, 0.2031, 0.2027,
-0.20344, 0.20228, 0.20232, 0.2001, 0.20126, 0.20258, 0.2015, 0.20186, 0.20162, 0.20216, 0.2017, 0.20264, 0.20382, 0.20258, 0.20336, 0.20302, 0.2036, 0.2033,
-0.2043, 0.20278, 0.20164, 0.20358, 0.20196, 0.20228, 0.20196, 0.20182, 0.2024, 
-//1043
-0.08274, 0.09322, 0.10832, 0.12246, 0.13214, 0.13782, 0.1384, 0.13998, 0.13944, 0.13932, 0.139, 0.13872, 0.13732, 0.1368, 0.13466, 0.1326, 0.13136, 0.12902,
-0.12732, 0.12496, 0.1231, 0.11978, 0.11692, 0.11314, 0.11182, 0.10998, 0.10802, 0.10546, 0.10454, 0.10168, 0.09974, 0.0984, 0.09822, 0.09844, 0.0991, 0.1,
-0.10064, 0.10142, 0.10196, 0.1011, 0.09956, 0.09998, 0.10178, 0.104, 0.1094, 0.11438, 0.1192, 0.12344, 0.12578, 0.1286, 0.13036, 0.13098, 0.13148, 0.13218,
-0.13306, 0.1333, 0.13436, 0.13282, 0.13392, 0.13528, 0.13608, 0.1356, 0.1345, 0.13508, 0.13538, 0.13528, 0.13606, 0.13678, 0.1364, 0.13604, 0.13604, 0.13564,
-0.13736, 0.13668, 0.13842, 0.13802, 0.14016, 0.13816, 0.13966, 0.139, 0.13988, 
-//1044
-0.07266, 0.0777, 0.08228, 0.0882, 0.09172, 0.0921, 0.09244, 0.09218, 0.09156, 0.0906, 0.09, 0.08942, 0.0887, 0.08712, 0.08502, 0.08306, 0.08088, 0.07924,
-0.07754, 0.07522, 0.0733, 0.0706, 0.0675, 0.06646, 0.06506, 0.06338, 0.06268, 0.06106, 0.0606, 0.05934, 0.0586, 0.0578, 0.0577, 0.05768, 0.05856, 0.05894,
-0.05792, 0.0579, 0.05834, 0.05778, 0.05674, 0.05654, 0.05804, 0.06004, 0.0626, 0.06536, 0.06978, 0.07346, 0.07602, 0.0779, 0.08018, 0.08026, 0.0806, 0.08092,
-0.08072, 0.08082, 0.07994, 0.07918, 0.08018, 0.08174, 0.08136, 0.08104, 0.08322, 0.08384, 0.08412, 0.08416, 0.0849, 0.08492, 0.08454, 0.08536, 0.08594, 0.0863,
-0.08764, 0.08814, 0.08846, 0.08908, 0.08906, 0.08944, 0.09078, 0.08856, 0.0898, 
-//1045
-0.05872, 0.06112, 0.06504, 0.06788, 0.0718, 0.07194, 0.07312, 0.07278, 0.07222, 0.07172, 0.07084, 0.06972, 0.06808, 0.06578, 0.06344, 0.06136, 0.05878, 0.05774,
-0.05594, 0.05398, 0.0512, 0.04978, 0.04646, 0.04562, 0.044, 0.0439, 0.04274, 0.0426, 0.04238, 0.0405, 0.04086, 0.04048, 0.04164, 0.04136, 0.04122, 0.04158,
-0.04092, 0.04228, 0.04248, 0.04216, 0.04162, 0.04232, 0.04324, 0.04386, 0.04442, 0.04624, 0.04854, 0.04992, 0.05204, 0.0536, 0.05526, 0.05802, 0.06052, 0.06312,
-0.06518, 0.06722, 0.0696, 0.0696, 0.07222, 0.0739, 0.0757, 0.07506, 0.07686, 0.07718, 0.07782, 0.07938, 0.08062, 0.08056, 0.0818, 0.08254, 0.08232, 0.0838,
-0.08422, 0.0844, 0.08552, 0.0868, 0.08752, 0.08648, 0.08818, 0.0886, 0.08912, 
-//1046
-0.1447, 0.17494, 0.23062, 0.31388, 0.41914, 0.52782, 0.60644, 0.64166, 0.6552, 0.6546, 0.6538, 0.65066, 0.64516, 0.63858, 0.6332, 0.62376, 0.61794, 0.60834,
-0.60024, 0.59068, 0.58138, 0.56974, 0.55824, 0.54852, 0.53868, 0.53176, 0.52594, 0.51884, 0.51368, 0.50448, 0.49728, 0.48926, 0.48412, 0.48402, 0.4867, 0.49126,
-0.49368, 0.4936, 0.4903, 0.48386, 0.47876, 0.47634, 0.4811, 0.49302, 0.51248, 0.53548, 0.5594, 0.58254, 0.59952, 0.61374, 0.62426, 0.63116, 0.63558, 0.63908,
-0.6421, 0.64348, 0.64592, 0.64698, 0.64586, 0.64696, 0.64538, 0.64632, 0.64574, 0.64626, 0.64668, 0.64592, 0.64582, 0.64462, 0.64522, 0.64678, 0.64658, 0.64356,
-0.64344, 0.64354, 0.64474, 0.6421, 0.64298, 0.64324, 0.64462, 0.641, 0.6425, 
-//1047
-0.1292, 0.15666, 0.21058, 0.28032, 0.3639, 0.43804, 0.47876, 0.49544, 0.49888, 0.49564, 0.49388, 0.49026, 0.4864, 0.4807, 0.473, 0.4671, 0.46098, 0.45338,
-0.44668, 0.43704, 0.42798, 0.41848, 0.40868, 0.39972, 0.39252, 0.38518, 0.3789, 0.37384, 0.368, 0.36094, 0.3549, 0.34898, 0.34566, 0.3474, 0.3479, 0.35024,
-0.35334, 0.35436, 0.35054, 0.34624, 0.34306, 0.34212, 0.34756, 0.35664, 0.3719, 0.39062, 0.41012, 0.4269, 0.43864, 0.44872, 0.45492, 0.45956, 0.46116, 0.46532,
-0.46504, 0.46618, 0.46698, 0.4661, 0.46654, 0.46766, 0.46434, 0.46452, 0.46264, 0.46394, 0.46332, 0.46404, 0.46344, 0.46502, 0.4631, 0.46396, 0.4631, 0.46146,
-0.46342, 0.45846, 0.45962, 0.46112, 0.46046, 0.45878, 0.45936, 0.45774, 0.45772, 
-//1048
-0.12992, 0.15452, 0.19906, 0.25366, 0.30436, 0.3395, 0.35434, 0.35842, 0.35844, 0.35718, 0.35496, 0.35324, 0.34832, 0.34346, 0.34022, 0.33362, 0.33008, 0.3245,
-0.31808, 0.31238, 0.30644, 0.29992, 0.29152, 0.2854, 0.2805, 0.2746, 0.27012, 0.26272, 0.25492, 0.24418, 0.23772, 0.23302, 0.23182, 0.23134, 0.2324, 0.23324,
-0.23358, 0.23484, 0.23474, 0.2363, 0.2372, 0.23962, 0.24558, 0.25216, 0.26412, 0.27622, 0.28878, 0.2987, 0.30502, 0.3095, 0.31284, 0.3144, 0.31576, 0.31598,
-0.31578, 0.3156, 0.31624, 0.31408, 0.31462, 0.3156, 0.3134, 0.31238, 0.31106, 0.31136, 0.31084, 0.31072, 0.31108, 0.31178, 0.31104, 0.31108, 0.3111, 0.31124,
-0.31276, 0.30976, 0.30966, 0.30974, 0.31042, 0.3105, 0.31038, 0.30822, 0.30652, 
-//1049
-0.11828, 0.1401, 0.1727, 0.20854, 0.23642, 0.25188, 0.25896, 0.25852, 0.25762, 0.25502, 0.255, 0.25268, 0.2505, 0.24536, 0.2417, 0.23776, 0.23306, 0.22878,
-0.22372, 0.21708, 0.21168, 0.20588, 0.19914, 0.19386, 0.18788, 0.1843, 0.1801, 0.17714, 0.17294, 0.16734, 0.16412, 0.1596, 0.15744, 0.15742, 0.15742, 0.15818,
-0.15864, 0.161, 0.1606, 0.15968, 0.15954, 0.15952, 0.16212, 0.1693, 0.17982, 0.19222, 0.20476, 0.21564, 0.2257, 0.23174, 0.23578, 0.23866, 0.24054, 0.24174,
-0.24252, 0.24298, 0.24322, 0.2423, 0.2428, 0.24386, 0.24348, 0.24224, 0.24186, 0.24144, 0.242, 0.24172, 0.24214, 0.24214, 0.24152, 0.24272, 0.24158, 0.2411,
-0.24242, 0.24218, 0.2416, 0.24134, 0.24096, 0.23856, 0.23862, 0.23742, 0.23696, 
-//1050
-0.09942, 0.11522, 0.13584, 0.1554, 0.16952, 0.17634, 0.17876, 0.17892, 0.17772, 0.1762, 0.17546, 0.17146, 0.169, 0.16514, 0.1612, 0.15616, 0.15168, 0.14786,
-0.14438, 0.139, 0.13426, 0.13032, 0.12434, 0.11916, 0.11564, 0.11298, 0.10996, 0.10756, 0.10388, 0.10074, 0.0976, 0.09556, 0.09318, 0.09318, 0.09402, 0.09484,
-0.09488, 0.09594, 0.0956, 0.09434, 0.09394, 0.09288, 0.09496, 0.1002, 0.108, 0.118, 0.1287, 0.1394, 0.14684, 0.15272, 0.15666, 0.15924, 0.15954, 0.16108,
-0.16276, 0.16156, 0.1633, 0.16266, 0.16324, 0.165, 0.16448, 0.16418, 0.16452, 0.16516, 0.1651, 0.16534, 0.16536, 0.16614, 0.16624, 0.1653, 0.16566, 0.1658,
-0.16626, 0.16558, 0.16486, 0.1649, 0.16518, 0.16482, 0.16668, 0.16436, 0.16522, 
-//1051
-0.08316, 0.09402, 0.10592, 0.11698, 0.1228, 0.12552, 0.12602, 0.1253, 0.1238, 0.12328, 0.1206, 0.1168, 0.115, 0.1106, 0.10618, 0.1026, 0.09828, 0.09342,
-0.08898, 0.086, 0.08212, 0.07864, 0.07396, 0.07038, 0.06888, 0.06592, 0.0643, 0.06328, 0.06146, 0.0597, 0.0577, 0.05752, 0.05704, 0.05572, 0.05618, 0.05638,
-0.05618, 0.05708, 0.0578, 0.05598, 0.05522, 0.05438, 0.05624, 0.058, 0.06244, 0.06816, 0.07368, 0.08028, 0.08554, 0.09154, 0.09612, 0.10088, 0.1045, 0.11006,
-0.11476, 0.11812, 0.12094, 0.12156, 0.12488, 0.12814, 0.12776, 0.12858, 0.12898, 0.13006, 0.13174, 0.13242, 0.1338, 0.134, 0.1359, 0.13616, 0.1372, 0.13774,
-0.13838, 0.13814, 0.13932, 0.13928, 0.13938, 0.13996, 0.14058, 0.13872, 0.14072, 
-//1052
-0.0654, 0.07176, 0.0785, 0.08644, 0.0901, 0.09262, 0.09442, 0.09494, 0.09414, 0.09234, 0.09028, 0.08802, 0.08546, 0.08066, 0.07676, 0.07192, 0.06796, 0.06498,
-0.06228, 0.05952, 0.05708, 0.054, 0.05196, 0.04964, 0.04816, 0.04768, 0.0464, 0.0449, 0.04408, 0.04394, 0.04268, 0.04212, 0.04302, 0.04294, 0.04288, 0.0434,
-0.04384, 0.04488, 0.04582, 0.04568, 0.0459, 0.04742, 0.04736, 0.04742, 0.04824, 0.04988, 0.05018, 0.05124, 0.05226, 0.05316, 0.05558, 0.05796, 0.06156, 0.0675,
-0.0759, 0.08642, 0.1006, 0.11624, 0.13492, 0.15566, 0.17522, 0.19408, 0.21174, 0.22816, 0.24332, 0.2564, 0.26918, 0.2791, 0.2891, 0.2972, 0.30448, 0.31158,
-0.31678, 0.31886, 0.32326, 0.32692, 0.32998, 0.33376, 0.33568, 0.335, 0.33838, 
-//1053
-0.1371, 0.16688, 0.22396, 0.30348, 0.39728, 0.48432, 0.53776, 0.559, 0.56488, 0.56308, 0.5597, 0.55308, 0.54572, 0.53488, 0.52414, 0.51212, 0.50108, 0.48816,
-0.4763, 0.46262, 0.4503, 0.43524, 0.42, 0.4068, 0.39536, 0.38582, 0.37806, 0.37008, 0.36174, 0.35268, 0.3445, 0.33662, 0.33114, 0.33042, 0.33392, 0.33784,
-0.34052, 0.34166, 0.33884, 0.33284, 0.32718, 0.32582, 0.33184, 0.34474, 0.36668, 0.3947, 0.42426, 0.45178, 0.47526, 0.49328, 0.50932, 0.52092, 0.53006, 0.53998,
-0.54798, 0.55318, 0.55968, 0.56214, 0.56688, 0.5695, 0.57116, 0.57248, 0.5743, 0.57526, 0.57668, 0.57654, 0.57786, 0.57944, 0.57946, 0.58062, 0.5803, 0.58192,
-0.58254, 0.58166, 0.58116, 0.58066, 0.57864, 0.58018, 0.58182, 0.57672, 0.5795, 
-//1054
-0.12202, 0.15016, 0.19872, 0.26566, 0.33312, 0.38866, 0.41426, 0.42328, 0.4232, 0.42086, 0.41628, 0.4107, 0.40446, 0.39512, 0.38736, 0.377, 0.36696, 0.35684,
-0.3461, 0.33494, 0.32356, 0.31144, 0.29888, 0.28726, 0.27874, 0.2703, 0.26204, 0.25518, 0.24792, 0.24022, 0.2331, 0.22792, 0.22414, 0.22352, 0.22566, 0.22886,
-0.23136, 0.23216, 0.2293, 0.22566, 0.22366, 0.22394, 0.22898, 0.23886, 0.25526, 0.2772, 0.29926, 0.32048, 0.33566, 0.34958, 0.3609, 0.37124, 0.382, 0.39042,
-0.39674, 0.40254, 0.40642, 0.40924, 0.41272, 0.41612, 0.41634, 0.41696, 0.4181, 0.41922, 0.41916, 0.4207, 0.42, 0.41972, 0.41998, 0.42098, 0.4218, 0.42192,
-0.42052, 0.41912, 0.4193, 0.41774, 0.416, 0.41672, 0.41664, 0.4148, 0.41416, 
-//1055
-0.1207, 0.1464, 0.183, 0.22596, 0.263, 0.28616, 0.29426, 0.2967, 0.29574, 0.29298, 0.29048, 0.2848, 0.28016, 0.27324, 0.26752, 0.25966, 0.2514, 0.24468,
-0.23652, 0.22836, 0.21966, 0.21118, 0.20086, 0.19244, 0.18534, 0.17852, 0.1736, 0.16906, 0.16476, 0.15814, 0.15268, 0.14802, 0.14482, 0.14368, 0.14666, 0.14938,
-0.1515, 0.15272, 0.1512, 0.14722, 0.14366, 0.1412, 0.14444, 0.1515, 0.16476, 0.18238, 0.20192, 0.2193, 0.23526, 0.24852, 0.2567, 0.26218, 0.26652, 0.26998,
-0.27242, 0.27348, 0.27488, 0.2745, 0.276, 0.27816, 0.27816, 0.279, 0.27812, 0.27888, 0.27852, 0.27744, 0.27904, 0.27792, 0.27956, 0.27818, 0.27874, 0.2784,
-0.27856, 0.2766, 0.27752, 0.27664, 0.27676, 0.27668, 0.27656, 0.27444, 0.27448, 
-//1056
-0.1063, 0.12484, 0.15108, 0.17862, 0.19982, 0.21044, 0.21446, 0.21458, 0.21404, 0.20988, 0.20682, 0.20298, 0.19762, 0.19132, 0.18636, 0.17852, 0.17068, 0.16332,
-0.1552, 0.1482, 0.1413, 0.13422, 0.12656, 0.1192, 0.11474, 0.10936, 0.10614, 0.10196, 0.09876, 0.09414, 0.09112, 0.08844, 0.08686, 0.08816, 0.08954, 0.09052,
-0.09128, 0.09148, 0.091, 0.08892, 0.08766, 0.0877, 0.0901, 0.09528, 0.10488, 0.1182, 0.13316, 0.14828, 0.16128, 0.17294, 0.18244, 0.18908, 0.19454, 0.20046,
-0.20492, 0.20722, 0.21088, 0.21188, 0.2147, 0.21686, 0.2176, 0.21808, 0.21948, 0.2198, 0.22036, 0.22072, 0.22168, 0.22122, 0.2204, 0.22168, 0.22226, 0.22242,
-0.22296, 0.22296, 0.22352, 0.22348, 0.22248, 0.2227, 0.22508, 0.22382, 0.22252, 
-//1057
-0.08552, 0.09812, 0.11762, 0.13756, 0.14994, 0.15826, 0.16198, 0.16178, 0.1607, 0.15614, 0.1511, 0.14442, 0.13758, 0.12888, 0.12052, 0.11198, 0.10406, 0.09696,
-0.09116, 0.08484, 0.07934, 0.07304, 0.06782, 0.06394, 0.0608, 0.05948, 0.05868, 0.05692, 0.05522, 0.05374, 0.05332, 0.0522, 0.0522, 0.05134, 0.0521, 0.05316,
-0.05206, 0.05248, 0.05312, 0.0528, 0.05318, 0.05362, 0.0545, 0.05634, 0.06038, 0.06518, 0.0715, 0.08, 0.08742, 0.0937, 0.10214, 0.11158, 0.12256, 0.13722,
-0.1511, 0.16722, 0.1829, 0.1978, 0.21124, 0.22518, 0.23626, 0.2448, 0.25356, 0.2594, 0.2659, 0.27044, 0.27522, 0.27908, 0.28218, 0.28818, 0.29196, 0.29522,
-0.29884, 0.30224, 0.30796, 0.30942, 0.3125, 0.31374, 0.3171, 0.31876, 0.32072, 
-//1058
-0.06756, 0.07432, 0.085, 0.09486, 0.10424, 0.10906, 0.11188, 0.11344, 0.11232, 0.10902, 0.10474, 0.09908, 0.093, 0.0861, 0.07978, 0.07276, 0.06732, 0.06236,
-0.05858, 0.05596, 0.05276, 0.05014, 0.04772, 0.04584, 0.0442, 0.04348, 0.0428, 0.04142, 0.0408, 0.03996, 0.04002, 0.03968, 0.0397, 0.03974, 0.04006, 0.04074,
-0.041, 0.04176, 0.04202, 0.04158, 0.04188, 0.0416, 0.04256, 0.04372, 0.04516, 0.04684, 0.04978, 0.05176, 0.05426, 0.05676, 0.0597, 0.066, 0.07466, 0.08852,
-0.1092, 0.13572, 0.16984, 0.20862, 0.24982, 0.29408, 0.3364, 0.3779, 0.41428, 0.44986, 0.48036, 0.50748, 0.5316, 0.55114, 0.56684, 0.58382, 0.5957, 0.60712,
-0.6191, 0.62516, 0.63416, 0.63806, 0.64266, 0.6472, 0.64958, 0.6529, 0.65342, 
-//1059
-0.13834, 0.16824, 0.22386, 0.30602, 0.4088, 0.51232, 0.58594, 0.6222, 0.6317, 0.62914, 0.62388, 0.61622, 0.60586, 0.59124, 0.57782, 0.5615, 0.54598, 0.52888,
-0.51204, 0.49446, 0.4753, 0.45516, 0.43388, 0.41656, 0.40104, 0.38676, 0.37496, 0.36294, 0.35164, 0.34098, 0.33238, 0.32612, 0.32286, 0.32142, 0.32438, 0.32668,
-0.32776, 0.32824, 0.32678, 0.32456, 0.32498, 0.32752, 0.33542, 0.35072, 0.37136, 0.39616, 0.42154, 0.4405, 0.45556, 0.46966, 0.4871, 0.50524, 0.52664, 0.55228,
-0.57706, 0.60322, 0.62612, 0.64656, 0.66438, 0.68234, 0.69616, 0.70972, 0.72068, 0.73068, 0.7374, 0.74546, 0.75128, 0.75628, 0.75622, 0.76268, 0.765, 0.76624,
-0.77056, 0.76948, 0.7707, 0.77394, 0.77644, 0.77658, 0.7779, 0.77598, 0.77632, 
-//1060
-0.12288, 0.15112, 0.2027, 0.27596, 0.35902, 0.42826, 0.46722, 0.48082, 0.48338, 0.4808, 0.47382, 0.46598, 0.45648, 0.44446, 0.43272, 0.41752, 0.4036, 0.3876,
-0.37202, 0.3566, 0.34102, 0.32368, 0.30704, 0.29162, 0.27796, 0.26724, 0.2569, 0.24668, 0.23874, 0.2291, 0.22182, 0.21704, 0.21486, 0.21524, 0.21678, 0.21952,
-0.22166, 0.22212, 0.2208, 0.21958, 0.21818, 0.22014, 0.22736, 0.23968, 0.26018, 0.28418, 0.30828, 0.32916, 0.34564, 0.35962, 0.37662, 0.39432, 0.4126, 0.43198,
-0.45114, 0.46898, 0.48384, 0.49704, 0.50852, 0.5179, 0.52386, 0.53154, 0.53678, 0.54088, 0.54358, 0.5456, 0.5486, 0.54962, 0.55028, 0.55536, 0.555, 0.55432,
-0.55764, 0.55686, 0.55676, 0.55598, 0.55818, 0.55612, 0.55736, 0.55986, 0.5564, 
-//1061
-0.12178, 0.14798, 0.19088, 0.24426, 0.29686, 0.32952, 0.34712, 0.35268, 0.35374, 0.35078, 0.34896, 0.34318, 0.33724, 0.32956, 0.31936, 0.3086, 0.2987, 0.28694,
-0.27526, 0.26258, 0.2486, 0.2327, 0.2176, 0.20524, 0.19456, 0.18514, 0.17472, 0.16374, 0.15472, 0.1465, 0.14148, 0.13934, 0.13928, 0.14006, 0.14052, 0.14054,
-0.13904, 0.14074, 0.1443, 0.14998, 0.15676, 0.16436, 0.17428, 0.18578, 0.19964, 0.2135, 0.22328, 0.22788, 0.23058, 0.23538, 0.2442, 0.25686, 0.2722, 0.29168,
-0.31078, 0.32854, 0.34546, 0.35922, 0.37288, 0.38298, 0.39008, 0.3971, 0.4018, 0.40742, 0.41088, 0.41402, 0.41682, 0.41706, 0.4194, 0.4216, 0.42236, 0.42426,
-0.42552, 0.4248, 0.4257, 0.42552, 0.4246, 0.42556, 0.42706, 0.4244, 0.4263, 
-//1062
-0.0977, 0.12356, 0.14996, 0.18824, 0.2291, 0.25232, 0.26534, 0.27006, 0.26648, 0.2607, 0.25388, 0.24734, 0.23778, 0.22646, 0.21666, 0.20554, 0.19244, 0.18194,
-0.17116, 0.16088, 0.1504, 0.13926, 0.12782, 0.11956, 0.11308, 0.10748, 0.1031, 0.0983, 0.09516, 0.0909, 0.08798, 0.08556, 0.08506, 0.0847, 0.08578, 0.0863,
-0.08736, 0.08796, 0.08736, 0.08628, 0.08626, 0.08752, 0.09054, 0.09674, 0.10648, 0.11942, 0.1334, 0.14534, 0.15612, 0.16582, 0.17678, 0.1881, 0.20304, 0.22172,
-0.23946, 0.25662, 0.2726, 0.2858, 0.2982, 0.31056, 0.3175, 0.32402, 0.32818, 0.33316, 0.33786, 0.33954, 0.34364, 0.34584, 0.3467, 0.34906, 0.35182, 0.3522,
-0.35448, 0.35352, 0.35262, 0.35254, 0.35416, 0.35562, 0.35644, 0.35422, 0.35348, 
-//1063
-0.08994, 0.10362, 0.12572, 0.14666, 0.16292, 0.17282, 0.1782, 0.1789, 0.17758, 0.17388, 0.1671, 0.15924, 0.14998, 0.13924, 0.12902, 0.11928, 0.10942, 0.10092,
-0.09338, 0.08652, 0.08134, 0.07468, 0.0683, 0.06448, 0.06216, 0.05912, 0.05746, 0.05532, 0.05362, 0.05214, 0.0506, 0.0493, 0.0495, 0.04878, 0.04924, 0.05036,
-0.05022, 0.05018, 0.04914, 0.04884, 0.04858, 0.04936, 0.05048, 0.05284, 0.05722, 0.06292, 0.0696, 0.07608, 0.08288, 0.08878, 0.09586, 0.10618, 0.11964, 0.1367,
-0.15866, 0.18412, 0.21398, 0.24448, 0.27432, 0.30526, 0.33218, 0.35864, 0.37896, 0.39944, 0.41666, 0.43148, 0.44576, 0.45672, 0.46614, 0.47596, 0.48414,
-0.49042, 0.49782, 0.50058, 0.5068, 0.51, 0.51686, 0.51876, 0.51952, 0.52044, 0.52358, 
-//1064
-0.13072, 0.16118, 0.21312, 0.28228, 0.35642, 0.41302, 0.44186, 0.44928, 0.44624, 0.43838, 0.42612, 0.41086, 0.39456, 0.37504, 0.35718, 0.33832, 0.31732,
-0.29844, 0.28156, 0.26504, 0.2479, 0.23148, 0.21626, 0.20222, 0.19132, 0.18228, 0.17544, 0.16888, 0.16136, 0.1542, 0.14804, 0.14372, 0.14088, 0.14084, 0.1429,
-0.14462, 0.14618, 0.14708, 0.14514, 0.1432, 0.14162, 0.1407, 0.1442, 0.1541, 0.17188, 0.19778, 0.2287, 0.2597, 0.28966, 0.317, 0.34314, 0.36944, 0.39556,
-0.42486, 0.45592, 0.48354, 0.51292, 0.53828, 0.5626, 0.58416, 0.60488, 0.62378, 0.64108, 0.65932, 0.67268, 0.68436, 0.69154, 0.6973, 0.7041, 0.71366, 0.72072,
-0.72352, 0.73168, 0.732, 0.73818, 0.7411, 0.74508, 0.74964, 0.74812, 0.7505, 0.75318, 
-//1065
-0.1035, 0.12352, 0.1619, 0.21144, 0.2561, 0.2857, 0.30184, 0.3065, 0.30472, 0.29928, 0.2908, 0.2805, 0.26744, 0.25332, 0.23906, 0.22216, 0.2084, 0.19484,
-0.18146, 0.16856, 0.15724, 0.14458, 0.13296, 0.12374, 0.11598, 0.10936, 0.10444, 0.09982, 0.09466, 0.08988, 0.08738, 0.0861, 0.08472, 0.08462, 0.08664, 0.08718,
-0.08738, 0.08748, 0.08718, 0.08646, 0.08628, 0.08764, 0.0903, 0.09652, 0.10658, 0.1199, 0.13516, 0.14848, 0.15982, 0.17054, 0.18404, 0.19948, 0.22048, 0.24468,
-0.27166, 0.3019, 0.3322, 0.36132, 0.38984, 0.41884, 0.44146, 0.46336, 0.48366, 0.49986, 0.51604, 0.52732, 0.54002, 0.55078, 0.55916, 0.56762, 0.57612, 0.5792,
-0.5861, 0.58736, 0.58996, 0.59562, 0.59948, 0.59976, 0.6013, 0.60136, 0.6019, 
-//1066
-0.12392, 0.15536, 0.2079, 0.28812, 0.39406, 0.51002, 0.60632, 0.66334, 0.68804, 0.69768, 0.7031, 0.70574, 0.70906, 0.70782, 0.70924, 0.70788, 0.70874, 0.70818,
-0.7053, 0.70334, 0.69844, 0.69328, 0.68972, 0.68628, 0.68374, 0.68048, 0.6745, 0.66642, 0.65784, 0.6494, 0.6437, 0.64126, 0.6404, 0.64276, 0.64366, 0.64252,
-0.64128, 0.64422, 0.65046, 0.6596, 0.6677, 0.67566, 0.68342, 0.69108, 0.69734, 0.70514, 0.71062, 0.71536, 0.71846, 0.72216, 0.72354, 0.72548, 0.7247, 0.72396,
-0.7235, 0.72452, 0.72642, 0.7266, 0.72678, 0.72718, 0.726, 0.7274, 0.72732, 0.72886, 0.72868, 0.73, 0.72902, 0.72892, 0.72922, 0.73056, 0.73042, 0.73052,
-0.73126, 0.73046, 0.72952, 0.73068, 0.73066, 0.72804, 0.7311, 0.72768, 0.73008, 
-//1067
-0.12734, 0.15556, 0.20716, 0.28156, 0.37228, 0.45824, 0.51382, 0.53426, 0.54, 0.53904, 0.53988, 0.5393, 0.53682, 0.53414, 0.53236, 0.52984, 0.52926, 0.52876,
-0.52768, 0.52482, 0.52094, 0.51386, 0.50792, 0.50736, 0.5059, 0.5035, 0.50026, 0.49188, 0.4826, 0.47248, 0.4663, 0.46526, 0.46692, 0.47226, 0.47648, 0.4752,
-0.47514, 0.48072, 0.48718, 0.49776, 0.50932, 0.51696, 0.52336, 0.52456, 0.52628, 0.52588, 0.52664, 0.52544, 0.5248, 0.5241, 0.52362, 0.52304, 0.52124, 0.51986,
-0.5204, 0.51924, 0.51864, 0.51788, 0.51716, 0.51804, 0.51716, 0.51524, 0.51292, 0.5125, 0.5118, 0.51158, 0.5122, 0.51072, 0.50932, 0.51122, 0.51202, 0.51118,
-0.51178, 0.51006, 0.50932, 0.50952, 0.5088, 0.50884, 0.507, 0.5083, 0.50622, 
-//1068
-0.12752, 0.15516, 0.20358, 0.26618, 0.33378, 0.3807, 0.4042, 0.41084, 0.41112, 0.4077, 0.4071, 0.40506, 0.40314, 0.39934, 0.39664, 0.39536, 0.39436, 0.39362,
-0.39196, 0.38926, 0.38536, 0.37982, 0.3749, 0.37176, 0.37052, 0.36922, 0.36602, 0.35976, 0.35144, 0.34298, 0.33772, 0.3359, 0.3377, 0.34334, 0.34872, 0.35036,
-0.35346, 0.3555, 0.3606, 0.369, 0.37728, 0.3833, 0.38686, 0.38954, 0.39118, 0.39206, 0.39326, 0.39168, 0.39186, 0.39062, 0.39102, 0.39026, 0.38912, 0.38746,
-0.38676, 0.38638, 0.38528, 0.38462, 0.38378, 0.38388, 0.3826, 0.38182, 0.38112, 0.38062, 0.37946, 0.37828, 0.37904, 0.37646, 0.37618, 0.3774, 0.37592, 0.37672,
-0.37614, 0.37408, 0.3753, 0.37258, 0.37306, 0.37392, 0.37396, 0.3713, 0.37004, 
-//1069
-0.11718, 0.14366, 0.18258, 0.2283, 0.2684, 0.29142, 0.29914, 0.3003, 0.30008, 0.2974, 0.29526, 0.29372, 0.29262, 0.29018, 0.2878, 0.28472, 0.2849, 0.28336,
-0.28152, 0.27998, 0.27664, 0.27066, 0.26666, 0.26414, 0.26324, 0.26254, 0.25974, 0.25316, 0.24656, 0.23972, 0.23308, 0.23162, 0.2349, 0.23836, 0.24318, 0.24452,
-0.2446, 0.24712, 0.25236, 0.25922, 0.26756, 0.2737, 0.27858, 0.28076, 0.28284, 0.28366, 0.28282, 0.28248, 0.28152, 0.28098, 0.28046, 0.28026, 0.2789, 0.2778,
-0.2775, 0.27608, 0.27448, 0.2727, 0.27256, 0.27372, 0.27152, 0.27134, 0.2696, 0.27028, 0.27026, 0.26962, 0.26988, 0.26762, 0.2692, 0.26948, 0.26902, 0.26966,
-0.27056, 0.26868, 0.26986, 0.26824, 0.268, 0.26712, 0.26564, 0.2667, 0.2655, 
-//1070
-0.10766, 0.1275, 0.15392, 0.1799, 0.19776, 0.20526, 0.20828, 0.20676, 0.20598, 0.20354, 0.20238, 0.20132, 0.20006, 0.19866, 0.19622, 0.19522, 0.19452, 0.19386,
-0.19204, 0.18992, 0.18868, 0.18506, 0.1806, 0.1782, 0.17796, 0.17584, 0.17272, 0.16928, 0.1635, 0.15738, 0.15382, 0.15244, 0.15404, 0.15698, 0.16094, 0.1625,
-0.16206, 0.1643, 0.16862, 0.17464, 0.18094, 0.18582, 0.1898, 0.19078, 0.19238, 0.19268, 0.1926, 0.19314, 0.19188, 0.19228, 0.19226, 0.19094, 0.1903, 0.18956,
-0.18912, 0.1879, 0.18646, 0.18506, 0.18586, 0.18594, 0.18524, 0.18416, 0.18364, 0.18412, 0.18426, 0.18426, 0.18468, 0.18408, 0.18404, 0.18518, 0.1853, 0.18506,
-0.18584, 0.18466, 0.18568, 0.1855, 0.18546, 0.18424, 0.18436, 0.1836, 0.18476, 
-//1071
-0.08834, 0.0986, 0.1132, 0.12502, 0.1324, 0.1352, 0.13512, 0.13666, 0.13414, 0.13414, 0.13296, 0.13198, 0.1319, 0.13072, 0.12894, 0.12834, 0.1272, 0.1248,
-0.12272, 0.1209, 0.11782, 0.116, 0.11244, 0.11026, 0.10894, 0.10608, 0.10498, 0.1031, 0.10138, 0.099, 0.09882, 0.0983, 0.09656, 0.09538, 0.09378, 0.09232,
-0.0917, 0.09278, 0.09704, 0.10186, 0.108, 0.11408, 0.11806, 0.11972, 0.1231, 0.12502, 0.1267, 0.1271, 0.12806, 0.1282, 0.1278, 0.12782, 0.1275, 0.12716,
-0.12666, 0.12562, 0.12632, 0.12476, 0.1257, 0.12714, 0.12706, 0.12734, 0.12616, 0.1268, 0.12638, 0.1265, 0.1261, 0.1267, 0.12718, 0.12716, 0.12798, 0.12738,
-0.1292, 0.12888, 0.1292, 0.12838, 0.12964, 0.13, 0.12964, 0.1304, 0.13018, 
-//1072
-0.0684, 0.07448, 0.08068, 0.08572, 0.08998, 0.08982, 0.09084, 0.09002, 0.0888, 0.08874, 0.08906, 0.08742, 0.086, 0.08444, 0.08328, 0.08118, 0.07974, 0.07834,
-0.07712, 0.07472, 0.07264, 0.07028, 0.06752, 0.06522, 0.06508, 0.06418, 0.06164, 0.06062, 0.05872, 0.05768, 0.05658, 0.05502, 0.05552, 0.05608, 0.05664, 0.0561,
-0.05576, 0.0568, 0.05836, 0.0604, 0.06302, 0.06498, 0.0673, 0.06946, 0.07216, 0.07466, 0.0759, 0.079, 0.08034, 0.08056, 0.08208, 0.08162, 0.0823, 0.08286,
-0.08334, 0.0828, 0.08344, 0.08218, 0.0826, 0.08462, 0.08416, 0.08424, 0.08444, 0.08362, 0.0838, 0.08402, 0.08458, 0.08422, 0.08458, 0.08546, 0.08538, 0.08636,
-0.08634, 0.08778, 0.08734, 0.08854, 0.08816, 0.08882, 0.08914, 0.08902, 0.08906, 
-//1073
-0.05832, 0.05888, 0.0613, 0.064, 0.06394, 0.06338, 0.06298, 0.06284, 0.06234, 0.06154, 0.06086, 0.06082, 0.06024, 0.05844, 0.05732, 0.05546, 0.05486, 0.0541,
-0.05276, 0.05132, 0.05032, 0.04912, 0.04712, 0.04626, 0.04596, 0.0446, 0.0448, 0.04254, 0.04286, 0.04166, 0.04044, 0.0408, 0.04118, 0.04148, 0.04106, 0.0411,
-0.04094, 0.04324, 0.04382, 0.04508, 0.04654, 0.04688, 0.0499, 0.05052, 0.05212, 0.05304, 0.05262, 0.05246, 0.0522, 0.05288, 0.05446, 0.05602, 0.05688, 0.0594,
-0.0614, 0.0642, 0.06668, 0.06772, 0.07032, 0.0722, 0.07378, 0.0752, 0.07618, 0.07718, 0.0782, 0.07922, 0.08024, 0.08156, 0.0815, 0.08228, 0.08378, 0.0832,
-0.08694, 0.08722, 0.08728, 0.08706, 0.0889, 0.08906, 0.09038, 0.09122, 0.09124, 
-//1074
-0.12194, 0.15006, 0.19932, 0.27816, 0.37554, 0.47796, 0.55098, 0.58538, 0.59618, 0.5982, 0.59854, 0.59808, 0.59404, 0.59024, 0.58836, 0.58196, 0.5792, 0.5741,
-0.56744, 0.56086, 0.55016, 0.53974, 0.53178, 0.52302, 0.51698, 0.50922, 0.49826, 0.48232, 0.46628, 0.45062, 0.4407, 0.43872, 0.44098, 0.44574, 0.44568, 0.44136,
-0.44062, 0.4466, 0.4603, 0.48174, 0.50348, 0.52244, 0.53954, 0.554, 0.56708, 0.57868, 0.58858, 0.59556, 0.6011, 0.60648, 0.6081, 0.611, 0.61176, 0.61204,
-0.6125, 0.61152, 0.61118, 0.6103, 0.61328, 0.615, 0.6133, 0.6132, 0.61474, 0.61726, 0.61806, 0.6189, 0.61756, 0.6162, 0.61456, 0.6151, 0.61842, 0.6175, 0.62036,
-0.61744, 0.61866, 0.61932, 0.6184, 0.61754, 0.61744, 0.61604, 0.61654, 
-//1075
-0.13066, 0.16122, 0.2086, 0.27838, 0.3544, 0.41862, 0.45184, 0.46504, 0.46904, 0.46514, 0.46604, 0.46338, 0.46014, 0.4561, 0.4506, 0.44572, 0.4429, 0.43902,
-0.43356, 0.42748, 0.42132, 0.41056, 0.405, 0.399, 0.39374, 0.38794, 0.3779, 0.36538, 0.351, 0.33758, 0.32944, 0.3256, 0.32696, 0.329, 0.32978, 0.32684, 0.32302,
-0.32556, 0.33534, 0.3521, 0.37618, 0.39668, 0.4165, 0.43184, 0.44138, 0.44668, 0.4504, 0.45348, 0.4542, 0.45594, 0.45608, 0.45626, 0.45556, 0.45524, 0.45566,
-0.45456, 0.45302, 0.45014, 0.4512, 0.4526, 0.45184, 0.44988, 0.45042, 0.45198, 0.4501, 0.44938, 0.44914, 0.4477, 0.44756, 0.44832, 0.44758, 0.44574, 0.44844,
-0.44504, 0.44432, 0.44454, 0.4435, 0.4456, 0.44396, 0.4426, 0.44206, 
-//1076
-0.11884, 0.14614, 0.18698, 0.23942, 0.28548, 0.3149, 0.32946, 0.33202, 0.33292, 0.3295, 0.32856, 0.32684, 0.32522, 0.3212, 0.31736, 0.31444, 0.31076, 0.30854,
-0.30482, 0.29886, 0.29314, 0.28616, 0.2796, 0.27384, 0.26932, 0.26532, 0.25736, 0.2473, 0.23476, 0.22426, 0.21756, 0.21438, 0.21762, 0.22134, 0.22376, 0.22244,
-0.21958, 0.22506, 0.23398, 0.24778, 0.2655, 0.28034, 0.29384, 0.30034, 0.30658, 0.30842, 0.30964, 0.31244, 0.31174, 0.31202, 0.31226, 0.31146, 0.31128, 0.31036,
-0.30932, 0.30936, 0.3086, 0.30704, 0.30574, 0.30606, 0.30398, 0.30402, 0.30388, 0.30468, 0.30368, 0.3025, 0.30252, 0.3028, 0.3017, 0.30168, 0.30088, 0.3011,
-0.30112, 0.29904, 0.29984, 0.29862, 0.29774, 0.2973, 0.29958, 0.29746, 0.29786, 
-//1077
-0.1068, 0.12358, 0.1524, 0.18368, 0.20776, 0.21966, 0.2229, 0.22332, 0.22508, 0.22298, 0.22114, 0.2197, 0.21828, 0.21456, 0.21242, 0.2102, 0.2083, 0.20516,
-0.20226, 0.19834, 0.1936, 0.18788, 0.18182, 0.17872, 0.17628, 0.17154, 0.16624, 0.15856, 0.15026, 0.14108, 0.13646, 0.13452, 0.13614, 0.13916, 0.1413, 0.1407,
-0.1403, 0.14088, 0.14826, 0.15882, 0.17174, 0.18332, 0.19252, 0.19946, 0.20378, 0.20548, 0.2071, 0.20696, 0.2092, 0.20978, 0.20892, 0.20788, 0.20836, 0.20852,
-0.20818, 0.20876, 0.20718, 0.2045, 0.2059, 0.20744, 0.20648, 0.20516, 0.2055, 0.20574, 0.2053, 0.20408, 0.20402, 0.205, 0.20432, 0.20372, 0.20388, 0.20356,
-0.20372, 0.20306, 0.20596, 0.20522, 0.20304, 0.20474, 0.20484, 0.20366, 0.20426, 
-//1078
-0.09298, 0.10686, 0.12506, 0.14262, 0.15418, 0.15956, 0.16194, 0.16238, 0.16362, 0.1616, 0.16084, 0.1579, 0.15532, 0.15436, 0.15012, 0.14638, 0.14368, 0.14008,
-0.1367, 0.13214, 0.12748, 0.12194, 0.1176, 0.11284, 0.10982, 0.10616, 0.10214, 0.09778, 0.09218, 0.0873, 0.08438, 0.0834, 0.08376, 0.0863, 0.08634, 0.08558,
-0.08338, 0.08466, 0.089, 0.09392, 0.09984, 0.10756, 0.11438, 0.12054, 0.12764, 0.13456, 0.14054, 0.14544, 0.14854, 0.15148, 0.15328, 0.15304, 0.15552, 0.15486,
-0.15478, 0.15416, 0.15404, 0.15232, 0.15322, 0.15446, 0.15312, 0.15212, 0.15438, 0.15406, 0.155, 0.15486, 0.15664, 0.15598, 0.1563, 0.15654, 0.1576, 0.1579,
-0.15778, 0.15798, 0.15778, 0.15788, 0.15772, 0.15766, 0.15582, 0.15722, 0.15588, 
-//1079
-0.07482, 0.08312, 0.0921, 0.10162, 0.1075, 0.11006, 0.11102, 0.11024, 0.10982, 0.10846, 0.1058, 0.10424, 0.1019, 0.09844, 0.09564, 0.09248, 0.08958, 0.08638,
-0.08304, 0.08024, 0.07592, 0.0726, 0.06934, 0.06598, 0.0648, 0.0624, 0.06096, 0.05816, 0.05612, 0.05418, 0.053, 0.0523, 0.05262, 0.05342, 0.05336, 0.05294,
-0.05284, 0.05398, 0.05456, 0.05626, 0.05902, 0.06088, 0.0637, 0.0677, 0.07258, 0.07904, 0.08736, 0.09354, 0.09928, 0.10506, 0.10786, 0.11076, 0.11098, 0.11282,
-0.11436, 0.11454, 0.11508, 0.11316, 0.11406, 0.11418, 0.11492, 0.11422, 0.11446, 0.11532, 0.1158, 0.11588, 0.11632, 0.1159, 0.11558, 0.11622, 0.11746, 0.1181,
-0.11988, 0.1188, 0.11924, 0.11992, 0.12098, 0.1201, 0.12166, 0.12166, 0.12092, 
-//1080
-0.06402, 0.06844, 0.0741, 0.08058, 0.08556, 0.08598, 0.08822, 0.08758, 0.08816, 0.08666, 0.08476, 0.08266, 0.0802, 0.07694, 0.0726, 0.06966, 0.0673, 0.06324,
-0.05998, 0.05664, 0.05386, 0.05092, 0.04788, 0.0459, 0.04538, 0.04372, 0.04234, 0.04058, 0.03906, 0.0387, 0.0387, 0.03782, 0.03824, 0.03754, 0.03844, 0.03838,
-0.03702, 0.03928, 0.04102, 0.04168, 0.04482, 0.04748, 0.0517, 0.05732, 0.06034, 0.06254, 0.06334, 0.06108, 0.06086, 0.06092, 0.06438, 0.068, 0.07314, 0.0792,
-0.08912, 0.0997, 0.10988, 0.11856, 0.12972, 0.13934, 0.1462, 0.1517, 0.15646, 0.16064, 0.16558, 0.16922, 0.17466, 0.17888, 0.18146, 0.18444, 0.18628, 0.18872,
-0.1907, 0.1938, 0.1964, 0.1973, 0.19742, 0.20024, 0.20026, 0.20166, 0.20352, 
-//1081
-0.12584, 0.15314, 0.20646, 0.28766, 0.39376, 0.50946, 0.60312, 0.65212, 0.67194, 0.6736, 0.67344, 0.66886, 0.66308, 0.65576, 0.64446, 0.63632, 0.62634, 0.61488,
-0.60596, 0.59184, 0.57816, 0.56152, 0.54814, 0.53612, 0.52726, 0.5164, 0.50378, 0.49, 0.47396, 0.45848, 0.44788, 0.44362, 0.44506, 0.44842, 0.4506, 0.45014,
-0.4487, 0.4542, 0.46444, 0.4783, 0.4919, 0.50516, 0.52062, 0.54004, 0.56504, 0.59334, 0.62376, 0.65102, 0.67474, 0.69536, 0.71218, 0.72352, 0.73354, 0.74274,
-0.748, 0.7536, 0.75766, 0.76006, 0.7632, 0.76786, 0.76868, 0.7719, 0.77306, 0.77486, 0.77668, 0.77718, 0.77954, 0.78276, 0.7806, 0.7832, 0.78538, 0.78636,
-0.78852, 0.78866, 0.78732, 0.78914, 0.79106, 0.791, 0.7928, 0.79252, 0.79394, 
-//1082
-0.11692, 0.14236, 0.18742, 0.25652, 0.34012, 0.42144, 0.47218, 0.49212, 0.498, 0.49802, 0.49712, 0.49276, 0.48718, 0.47948, 0.47408, 0.46632, 0.46036, 0.45272,
-0.44276, 0.43358, 0.42218, 0.41038, 0.3966, 0.3867, 0.38028, 0.37088, 0.36024, 0.3449, 0.3262, 0.31052, 0.29976, 0.29564, 0.29712, 0.30032, 0.30436, 0.30236,
-0.29972, 0.3014, 0.31308, 0.3304, 0.3527, 0.37418, 0.39514, 0.4128, 0.43304, 0.45198, 0.4702, 0.48234, 0.4915, 0.49922, 0.50512, 0.50874, 0.51114, 0.51124,
-0.51294, 0.51258, 0.5132, 0.51232, 0.51164, 0.51274, 0.51208, 0.51266, 0.51068, 0.5123, 0.51196, 0.51204, 0.51116, 0.51174, 0.50966, 0.51216, 0.51134, 0.51192,
-0.51258, 0.51024, 0.51098, 0.5111, 0.50984, 0.50906, 0.51438, 0.50966, 0.5107, 
-//1083
-0.12064, 0.14944, 0.19364, 0.2535, 0.31522, 0.35812, 0.3762, 0.38442, 0.38468, 0.38194, 0.37838, 0.37346, 0.36946, 0.36292, 0.3562, 0.34928, 0.344, 0.33722,
-0.3296, 0.32158, 0.31146, 0.29836, 0.2891, 0.27892, 0.27342, 0.26556, 0.25446, 0.24228, 0.22654, 0.21286, 0.20422, 0.2013, 0.20228, 0.2057, 0.208, 0.20532,
-0.20492, 0.20912, 0.21844, 0.23506, 0.25266, 0.27, 0.28904, 0.30318, 0.31746, 0.33304, 0.34398, 0.35334, 0.35944, 0.36466, 0.3692, 0.37028, 0.37104, 0.37358,
-0.37274, 0.37182, 0.37194, 0.3697, 0.37026, 0.36906, 0.36964, 0.36874, 0.36622, 0.36698, 0.3662, 0.36652, 0.36622, 0.3668, 0.36576, 0.36858, 0.36978, 0.3666,
-0.36702, 0.36502, 0.36448, 0.36672, 0.36654, 0.36468, 0.365, 0.3622, 0.36156, 
-//1084
-0.11442, 0.13464, 0.17022, 0.21146, 0.24576, 0.26546, 0.2738, 0.27564, 0.27454, 0.27134, 0.26934, 0.26594, 0.2619, 0.25628, 0.25108, 0.24562, 0.23968, 0.23436,
-0.22794, 0.22062, 0.2125, 0.20228, 0.1934, 0.18846, 0.18182, 0.17584, 0.1688, 0.15794, 0.14702, 0.1386, 0.13172, 0.12946, 0.13024, 0.13182, 0.13312, 0.13244,
-0.1308, 0.13508, 0.14058, 0.14944, 0.16292, 0.17562, 0.18698, 0.19954, 0.21246, 0.22414, 0.23686, 0.2456, 0.25284, 0.25816, 0.26108, 0.26318, 0.26502, 0.26602,
-0.26606, 0.26558, 0.2662, 0.26382, 0.26394, 0.26518, 0.26452, 0.26352, 0.26236, 0.26134, 0.26248, 0.26152, 0.26294, 0.26264, 0.26288, 0.26356, 0.2648, 0.2635,
-0.2651, 0.26424, 0.26422, 0.26474, 0.26454, 0.26432, 0.26464, 0.26042, 0.262, 
-//1085
-0.09796, 0.1175, 0.14066, 0.16496, 0.1849, 0.1933, 0.19632, 0.1975, 0.19734, 0.19404, 0.19084, 0.18722, 0.1824, 0.17718, 0.17222, 0.16542, 0.1606, 0.15538,
-0.1491, 0.14308, 0.13662, 0.12874, 0.12158, 0.11626, 0.11206, 0.1088, 0.10376, 0.09856, 0.09278, 0.08668, 0.08286, 0.08144, 0.0816, 0.082, 0.08378, 0.08302,
-0.08272, 0.08486, 0.08708, 0.0908, 0.09666, 0.10152, 0.10712, 0.11372, 0.1251, 0.13872, 0.1522, 0.16564, 0.17628, 0.18504, 0.19188, 0.19668, 0.19974, 0.20264,
-0.20494, 0.20308, 0.20434, 0.20304, 0.20382, 0.20478, 0.20484, 0.2044, 0.20626, 0.2054, 0.2054, 0.2062, 0.20678, 0.2073, 0.20952, 0.21026, 0.21124, 0.21266,
-0.21278, 0.21174, 0.21174, 0.21184, 0.21112, 0.21196, 0.21244, 0.21112, 0.21158, 
-//1086
-0.10332, 0.11342, 0.12654, 0.13874, 0.14938, 0.15532, 0.1574, 0.15528, 0.15174, 0.1454, 0.13832, 0.13042, 0.1221, 0.11292, 0.10494, 0.09606, 0.08968, 0.0826,
-0.07778, 0.07354, 0.07074, 0.06682, 0.06362, 0.06038, 0.05818, 0.05722, 0.0558, 0.05508, 0.05346, 0.0526, 0.05228, 0.05044, 0.04964, 0.0497, 0.05126, 0.05036,
-0.04884, 0.0509, 0.04996, 0.04968, 0.05, 0.0486, 0.05066, 0.05246, 0.05422, 0.05934, 0.0678, 0.0804, 0.09474, 0.11106, 0.12976, 0.15186, 0.17044, 0.1905,
-0.21174, 0.2314, 0.24892, 0.26404, 0.27844, 0.29254, 0.30332, 0.31372, 0.3248, 0.33308, 0.34202, 0.35006, 0.35694, 0.3614, 0.36756, 0.3744, 0.38026, 0.38472,
-0.38798, 0.39118, 0.39444, 0.39844, 0.4011, 0.40338, 0.40826, 0.4095, 0.41334, 
-//1087
-0.136, 0.16784, 0.22356, 0.30474, 0.40322, 0.49382, 0.5527, 0.57734, 0.58408, 0.5809, 0.57478, 0.56678, 0.55742, 0.54704, 0.53358, 0.5202, 0.50896, 0.49594,
-0.48144, 0.46648, 0.4494, 0.43272, 0.41606, 0.40244, 0.39232, 0.38014, 0.36486, 0.3465, 0.32656, 0.30834, 0.29752, 0.29236, 0.29416, 0.29624, 0.29728, 0.29504,
-0.29428, 0.30014, 0.31256, 0.3319, 0.35246, 0.36958, 0.38906, 0.41308, 0.44178, 0.47496, 0.508, 0.53906, 0.56596, 0.58984, 0.60802, 0.62212, 0.63246, 0.64238,
-0.64962, 0.65356, 0.6594, 0.66228, 0.6662, 0.66866, 0.67132, 0.67246, 0.67202, 0.67438, 0.67774, 0.67792, 0.67972, 0.68224, 0.68272, 0.6854, 0.68752, 0.68792,
-0.68978, 0.6874, 0.68932, 0.69006, 0.6927, 0.69042, 0.69372, 0.69232, 0.69222, 
-//1088
-0.1246, 0.1521, 0.20166, 0.27168, 0.34782, 0.40816, 0.44012, 0.44912, 0.45, 0.44528, 0.44068, 0.43184, 0.42426, 0.41294, 0.4022, 0.38968, 0.37884, 0.3677,
-0.35596, 0.34248, 0.32842, 0.31224, 0.30004, 0.2877, 0.27904, 0.2688, 0.25782, 0.2448, 0.2292, 0.21484, 0.2043, 0.19918, 0.19952, 0.2023, 0.2045, 0.20484,
-0.2042, 0.20788, 0.2136, 0.22696, 0.24302, 0.25962, 0.2778, 0.29838, 0.32252, 0.3506, 0.37848, 0.40422, 0.42562, 0.44478, 0.45776, 0.46586, 0.47154, 0.47618,
-0.4804, 0.48232, 0.48484, 0.48512, 0.48584, 0.48764, 0.48656, 0.487, 0.4849, 0.48714, 0.48706, 0.48834, 0.48738, 0.48686, 0.48664, 0.48744, 0.48788, 0.48668,
-0.48658, 0.4847, 0.48412, 0.48562, 0.48564, 0.4847, 0.48638, 0.48422, 0.4839, 
-//1089
-0.11544, 0.13928, 0.18184, 0.23534, 0.2857, 0.31886, 0.33304, 0.33644, 0.33546, 0.33162, 0.32446, 0.31632, 0.30866, 0.29948, 0.28984, 0.27914, 0.26854, 0.25834,
-0.24692, 0.23578, 0.2234, 0.21146, 0.20022, 0.19088, 0.18438, 0.17546, 0.16906, 0.15912, 0.15044, 0.13958, 0.13296, 0.13012, 0.12968, 0.13152, 0.13198, 0.13228,
-0.13244, 0.13366, 0.13684, 0.1445, 0.15312, 0.16216, 0.17268, 0.18656, 0.20594, 0.22894, 0.25504, 0.2822, 0.30318, 0.3221, 0.337, 0.34734, 0.35308, 0.3582,
-0.36188, 0.3642, 0.36734, 0.36734, 0.36884, 0.36976, 0.37064, 0.3695, 0.37014, 0.37046, 0.37014, 0.3713, 0.37152, 0.3715, 0.36996, 0.37194, 0.37222, 0.37012,
-0.37268, 0.36988, 0.37164, 0.37238, 0.37178, 0.3707, 0.3712, 0.36866, 0.3704, 
-//1090
-0.10224, 0.12342, 0.15396, 0.18964, 0.2177, 0.23348, 0.23932, 0.2401, 0.2377, 0.2332, 0.2275, 0.21858, 0.21074, 0.20122, 0.19302, 0.18164, 0.17294, 0.16408,
-0.15476, 0.14564, 0.1375, 0.12912, 0.12084, 0.1144, 0.10904, 0.10482, 0.1004, 0.0962, 0.09148, 0.08642, 0.08228, 0.08082, 0.07976, 0.08026, 0.08092, 0.0819,
-0.08246, 0.08524, 0.08656, 0.087, 0.08702, 0.08814, 0.09286, 0.09922, 0.10998, 0.12492, 0.14542, 0.1689, 0.1906, 0.21246, 0.22954, 0.2442, 0.25576, 0.26416,
-0.27146, 0.27612, 0.28114, 0.28284, 0.2858, 0.28892, 0.28952, 0.29132, 0.29068, 0.2931, 0.29458, 0.29622, 0.29746, 0.29988, 0.29878, 0.30144, 0.30184, 0.3034,
-0.30496, 0.30402, 0.30676, 0.30792, 0.30818, 0.30942, 0.30974, 0.30744, 0.30898, 
-//1091
-0.08688, 0.1032, 0.13072, 0.16018, 0.18362, 0.19504, 0.20106, 0.20052, 0.1962, 0.18814, 0.17854, 0.16714, 0.156, 0.14298, 0.13102, 0.1191, 0.1091, 0.10034,
-0.09256, 0.08558, 0.07854, 0.07228, 0.06708, 0.06256, 0.05944, 0.05758, 0.05514, 0.05364, 0.0522, 0.05044, 0.0498, 0.04812, 0.04752, 0.04804, 0.04866, 0.04884,
-0.04972, 0.04958, 0.04892, 0.04922, 0.04826, 0.04816, 0.05016, 0.05306, 0.05734, 0.06446, 0.07676, 0.09144, 0.10814, 0.12594, 0.14512, 0.16698, 0.191, 0.21826,
-0.24872, 0.28236, 0.3174, 0.34994, 0.38264, 0.4137, 0.43972, 0.46448, 0.48478, 0.50288, 0.52028, 0.53464, 0.54848, 0.5594, 0.56884, 0.5792, 0.58616, 0.59168,
-0.60096, 0.60566, 0.60826, 0.61154, 0.61512, 0.61644, 0.62124, 0.62356, 0.62786, 
-//1092
-0.122, 0.15006, 0.2027, 0.27728, 0.36532, 0.44382, 0.48832, 0.5037, 0.50628, 0.49828, 0.48778, 0.47526, 0.46214, 0.44438, 0.42662, 0.4083, 0.39076, 0.3732,
-0.35614, 0.33934, 0.3232, 0.30594, 0.2896, 0.27458, 0.26404, 0.2532, 0.24416, 0.23444, 0.22254, 0.21206, 0.20328, 0.19802, 0.19706, 0.1982, 0.20154, 0.20428,
-0.20552, 0.20694, 0.20926, 0.21158, 0.21422, 0.21842, 0.22764, 0.24294, 0.267, 0.30028, 0.34036, 0.38282, 0.42404, 0.4643, 0.49998, 0.53018, 0.55502, 0.57794,
-0.59732, 0.61384, 0.6273, 0.63902, 0.64844, 0.66, 0.66602, 0.67428, 0.68188, 0.68834, 0.69454, 0.69826, 0.70148, 0.70564, 0.70978, 0.71678, 0.72014, 0.72258,
-0.73068, 0.7327, 0.73714, 0.74242, 0.7451, 0.75022, 0.75716, 0.75406, 0.75338, 
-//1093
-0.11848, 0.14602, 0.19486, 0.26156, 0.32588, 0.3741, 0.39498, 0.40044, 0.39834, 0.39068, 0.37912, 0.36598, 0.34966, 0.33414, 0.31682, 0.3002, 0.28352, 0.26776,
-0.25122, 0.23706, 0.2237, 0.20982, 0.19832, 0.18612, 0.1769, 0.1694, 0.16242, 0.15494, 0.14748, 0.13996, 0.13368, 0.12854, 0.12756, 0.12808, 0.12978, 0.13212,
-0.1323, 0.13306, 0.1345, 0.135, 0.1363, 0.13778, 0.14466, 0.1559, 0.17538, 0.20202, 0.23578, 0.27702, 0.31792, 0.35962, 0.39648, 0.42964, 0.45734, 0.48134,
-0.50304, 0.51828, 0.53312, 0.54274, 0.55244, 0.56096, 0.56784, 0.57438, 0.5811, 0.58566, 0.59, 0.59352, 0.59688, 0.60142, 0.60354, 0.60592, 0.6102, 0.61244,
-0.6157, 0.61314, 0.61528, 0.61634, 0.61926, 0.61838, 0.62116, 0.62136, 0.62276, 
-//1094
-0.11142, 0.13666, 0.1743, 0.21642, 0.25476, 0.27788, 0.28708, 0.28876, 0.28428, 0.2768, 0.26716, 0.25624, 0.24174, 0.22878, 0.21456, 0.19896, 0.18626, 0.173,
-0.16078, 0.1506, 0.13962, 0.12926, 0.11928, 0.11134, 0.10586, 0.10154, 0.09722, 0.09368, 0.08944, 0.08394, 0.0807, 0.07856, 0.07756, 0.07818, 0.07918, 0.0794,
-0.07988, 0.08096, 0.08052, 0.07998, 0.07986, 0.08134, 0.08444, 0.0912, 0.10316, 0.12054, 0.14724, 0.17704, 0.21178, 0.24632, 0.27838, 0.30714, 0.3323, 0.35528,
-0.37444, 0.39172, 0.40584, 0.41694, 0.42942, 0.4387, 0.44466, 0.44984, 0.45532, 0.46034, 0.46696, 0.47146, 0.47754, 0.47976, 0.48338, 0.48812, 0.4917, 0.49474,
-0.49828, 0.49868, 0.50234, 0.50368, 0.50616, 0.5077, 0.51176, 0.51316, 0.51148, 
-//1095
-0.12834, 0.15708, 0.21198, 0.2927, 0.40098, 0.51722, 0.6094, 0.6584, 0.67582, 0.68108, 0.68238, 0.68376, 0.68506, 0.68524, 0.68452, 0.685, 0.68512, 0.68586,
-0.68462, 0.68396, 0.68126, 0.67712, 0.6744, 0.67348, 0.6723, 0.67124, 0.668, 0.66312, 0.65684, 0.64776, 0.64422, 0.64372, 0.64648, 0.65088, 0.65428, 0.65454,
-0.65574, 0.6575, 0.66306, 0.67144, 0.67754, 0.68326, 0.68816, 0.6893, 0.69002, 0.69102, 0.68966, 0.68908, 0.6863, 0.68764, 0.68766, 0.6864, 0.68524, 0.68534,
-0.6854, 0.68484, 0.6855, 0.6856, 0.68466, 0.68408, 0.68328, 0.6829, 0.6797, 0.68084, 0.68254, 0.68212, 0.68396, 0.68456, 0.6838, 0.68568, 0.68588, 0.68494,
-0.68604, 0.6839, 0.68578, 0.68496, 0.6847, 0.68446, 0.68594, 0.68346, 0.68528, 
-//1096
-0.128, 0.156, 0.20846, 0.28442, 0.37292, 0.45312, 0.50124, 0.51962, 0.52256, 0.5216, 0.52128, 0.52022, 0.51966, 0.51802, 0.51634, 0.51334, 0.51322, 0.51324,
-0.51316, 0.51106, 0.50768, 0.5019, 0.4976, 0.496, 0.49582, 0.49532, 0.49442, 0.48958, 0.48322, 0.47712, 0.47242, 0.47202, 0.47644, 0.48368, 0.49244, 0.4971,
-0.49952, 0.5018, 0.5041, 0.50778, 0.5103, 0.51202, 0.51346, 0.51356, 0.51442, 0.5124, 0.51236, 0.51088, 0.50984, 0.50848, 0.50786, 0.50684, 0.50598, 0.50476,
-0.50432, 0.50222, 0.50238, 0.50216, 0.50066, 0.4996, 0.49856, 0.49876, 0.49758, 0.49708, 0.497, 0.4965, 0.49618, 0.49534, 0.49454, 0.49576, 0.4938, 0.49378,
-0.49414, 0.4931, 0.49096, 0.4913, 0.49194, 0.48892, 0.48886, 0.4878, 0.4899, 
-//1097
-0.12474, 0.15278, 0.20132, 0.2643, 0.32774, 0.3716, 0.39384, 0.39798, 0.39792, 0.39472, 0.39336, 0.39156, 0.39104, 0.3882, 0.38532, 0.38382, 0.38338, 0.38338,
-0.38354, 0.38156, 0.37774, 0.37168, 0.36852, 0.36584, 0.36558, 0.3658, 0.36536, 0.3612, 0.35494, 0.34872, 0.34506, 0.34498, 0.34804, 0.3565, 0.36462, 0.36932,
-0.37212, 0.37472, 0.3766, 0.37978, 0.38132, 0.3832, 0.38414, 0.38366, 0.38462, 0.38388, 0.38248, 0.38208, 0.37978, 0.38036, 0.37866, 0.3784, 0.3756, 0.37548,
-0.3747, 0.37278, 0.37256, 0.37094, 0.36988, 0.37004, 0.3679, 0.36824, 0.36654, 0.3668, 0.3667, 0.36512, 0.36512, 0.36434, 0.36482, 0.36396, 0.36382, 0.36188,
-0.3626, 0.36056, 0.36038, 0.35926, 0.36, 0.3596, 0.36014, 0.35678, 0.3584, 
-//1098
-0.11366, 0.13872, 0.176, 0.2182, 0.25334, 0.27352, 0.28102, 0.28376, 0.28272, 0.28092, 0.28004, 0.27892, 0.2775, 0.27538, 0.27276, 0.27324, 0.27276, 0.27262,
-0.27314, 0.27222, 0.2692, 0.2658, 0.26352, 0.26192, 0.26148, 0.26102, 0.25932, 0.25598, 0.25158, 0.24782, 0.24396, 0.24364, 0.2465, 0.25142, 0.25636, 0.25858,
-0.2593, 0.26152, 0.26396, 0.26654, 0.26964, 0.27088, 0.27112, 0.27196, 0.2718, 0.27096, 0.27002, 0.2697, 0.26928, 0.26896, 0.26884, 0.2674, 0.26728, 0.26734,
-0.2666, 0.26522, 0.2653, 0.2649, 0.264, 0.26548, 0.26458, 0.26364, 0.2639, 0.26334, 0.264, 0.26302, 0.2626, 0.2623, 0.26312, 0.26382, 0.2633, 0.26114, 0.2615,
-0.26066, 0.2602, 0.26058, 0.25984, 0.25924, 0.25956, 0.25924, 0.25958, 
-//1099
-0.10168, 0.1167, 0.139, 0.16124, 0.17562, 0.18254, 0.18438, 0.1844, 0.18402, 0.18238, 0.18166, 0.18164, 0.18066, 0.17958, 0.17906, 0.17668, 0.17744, 0.17714,
-0.17596, 0.17552, 0.17364, 0.17046, 0.16814, 0.16752, 0.1663, 0.1669, 0.16616, 0.16326, 0.15968, 0.15652, 0.1543, 0.15346, 0.15554, 0.15864, 0.16414, 0.16758,
-0.16842, 0.16872, 0.1704, 0.17302, 0.17576, 0.1765, 0.1778, 0.1782, 0.1781, 0.17808, 0.17786, 0.17774, 0.1768, 0.17652, 0.17666, 0.17578, 0.17514, 0.1747,
-0.17396, 0.17296, 0.17232, 0.1714, 0.17218, 0.17204, 0.17212, 0.17086, 0.171, 0.17138, 0.17194, 0.17148, 0.17206, 0.17126, 0.17178, 0.17314, 0.1732, 0.1737,
-0.17324, 0.1729, 0.17312, 0.17208, 0.1718, 0.17246, 0.17192, 0.17094, 0.16992, 
-//1100
-0.08244, 0.09104, 0.10358, 0.1118, 0.11664, 0.11764, 0.11938, 0.11848, 0.11816, 0.11714, 0.11788, 0.11624, 0.11592, 0.11352, 0.11344, 0.11334, 0.11288, 0.11258,
-0.11262, 0.11164, 0.11116, 0.10872, 0.10634, 0.1056, 0.10614, 0.10506, 0.1034, 0.10202, 0.0991, 0.09648, 0.0946, 0.0941, 0.09604, 0.099, 0.10298, 0.10308,
-0.10248, 0.10492, 0.10652, 0.10894, 0.11164, 0.11188, 0.11482, 0.11622, 0.11554, 0.11434, 0.11492, 0.11422, 0.11362, 0.1133, 0.11408, 0.11304, 0.1137, 0.11284,
-0.11262, 0.11262, 0.11248, 0.11154, 0.112, 0.11286, 0.11308, 0.11282, 0.11276, 0.11308, 0.11254, 0.1127, 0.11452, 0.11354, 0.11438, 0.11468, 0.1155, 0.1156,
-0.11648, 0.1152, 0.11432, 0.11482, 0.11604, 0.11514, 0.1155, 0.1135, 0.1159, 
-//1101
-0.0672, 0.0698, 0.0727, 0.0746, 0.07552, 0.07484, 0.07536, 0.07454, 0.07482, 0.07276, 0.07262, 0.07074, 0.07144, 0.0705, 0.06952, 0.0695, 0.06994, 0.06954,
-0.06934, 0.06858, 0.06768, 0.06674, 0.0653, 0.06498, 0.06534, 0.06398, 0.06378, 0.06256, 0.06018, 0.05958, 0.05958, 0.0597, 0.06012, 0.06116, 0.06122, 0.06116,
-0.06126, 0.06364, 0.06416, 0.06584, 0.06714, 0.06976, 0.0697, 0.07002, 0.07058, 0.07054, 0.07058, 0.0694, 0.0699, 0.06948, 0.07002, 0.06846, 0.06906, 0.06886,
-0.06826, 0.0682, 0.06838, 0.0671, 0.06846, 0.06918, 0.0697, 0.06916, 0.06968, 0.06972, 0.06952, 0.06978, 0.07116, 0.07076, 0.072, 0.07318, 0.07348, 0.07404,
-0.0741, 0.07494, 0.07536, 0.07506, 0.0751, 0.07582, 0.0761, 0.0764, 0.07614, 
-//1102
-0.05678, 0.05788, 0.0586, 0.06046, 0.061, 0.06058, 0.05958, 0.05878, 0.05862, 0.05846, 0.05776, 0.05784, 0.05756, 0.05744, 0.05642, 0.05532, 0.0542, 0.0534,
-0.05336, 0.05224, 0.0511, 0.05018, 0.04898, 0.04896, 0.04786, 0.0469, 0.04666, 0.04554, 0.04458, 0.0436, 0.04348, 0.0431, 0.04334, 0.04392, 0.04444, 0.04468,
-0.04454, 0.04686, 0.04798, 0.04964, 0.05062, 0.05232, 0.05436, 0.05488, 0.05562, 0.05614, 0.05654, 0.05594, 0.0557, 0.05662, 0.0572, 0.05676, 0.0573, 0.05698,
-0.05746, 0.05704, 0.05662, 0.05586, 0.05654, 0.05782, 0.05792, 0.05742, 0.05684, 0.05736, 0.05774, 0.05774, 0.05866, 0.0593, 0.05996, 0.06106, 0.0615, 0.06234,
-0.06242, 0.0635, 0.06336, 0.06364, 0.06426, 0.06508, 0.06526, 0.06472, 0.06458, 
-//1103
-0.15026, 0.18142, 0.24144, 0.32684, 0.44096, 0.56062, 0.65264, 0.70374, 0.7226, 0.7268, 0.7286, 0.72776, 0.72844, 0.7284, 0.72602, 0.72488, 0.72514, 0.72342,
-0.72208, 0.72086, 0.7173, 0.71146, 0.70626, 0.70418, 0.70188, 0.69898, 0.6962, 0.6879, 0.6774, 0.66784, 0.66134, 0.65894, 0.66138, 0.667, 0.67292, 0.67582,
-0.6751, 0.67754, 0.685, 0.69664, 0.7097, 0.72346, 0.7348, 0.74076, 0.74482, 0.74772, 0.75034, 0.75244, 0.75242, 0.75334, 0.75414, 0.75162, 0.74954, 0.74852,
-0.75126, 0.74958, 0.7508, 0.74746, 0.74646, 0.74854, 0.74612, 0.74636, 0.74546, 0.74524, 0.74656, 0.74722, 0.74782, 0.74672, 0.74658, 0.74828, 0.748, 0.7479,
-0.74954, 0.74628, 0.74756, 0.747, 0.7477, 0.74624, 0.74826, 0.74444, 0.74364, 
-//1104
-0.14146, 0.17106, 0.22516, 0.3029, 0.39518, 0.47948, 0.53138, 0.54916, 0.555, 0.55268, 0.54924, 0.54838, 0.54662, 0.5426, 0.5381, 0.53778, 0.53622, 0.53582,
-0.5353, 0.53384, 0.52946, 0.52148, 0.51636, 0.5125, 0.511, 0.50982, 0.50618, 0.4993, 0.49052, 0.48008, 0.47426, 0.47294, 0.47552, 0.48362, 0.4939, 0.50028,
-0.50418, 0.51066, 0.51902, 0.5285, 0.5376, 0.54556, 0.55116, 0.55472, 0.55504, 0.55646, 0.55696, 0.55472, 0.55284, 0.55296, 0.5527, 0.55124, 0.5491, 0.54868,
-0.54838, 0.5472, 0.5452, 0.54466, 0.54394, 0.54638, 0.54462, 0.54262, 0.54198, 0.54242, 0.54088, 0.5408, 0.54176, 0.54046, 0.53902, 0.53974, 0.53928, 0.53766,
-0.53888, 0.53714, 0.5379, 0.53596, 0.533, 0.533, 0.53212, 0.5323, 0.53154, 
-//1105
-0.12368, 0.15072, 0.19678, 0.25898, 0.32498, 0.37324, 0.39606, 0.40194, 0.4009, 0.40026, 0.39852, 0.39794, 0.39598, 0.39388, 0.391, 0.38808, 0.38754, 0.3873,
-0.38718, 0.38442, 0.37988, 0.37382, 0.36692, 0.36468, 0.36272, 0.36274, 0.35938, 0.35182, 0.34344, 0.33486, 0.3288, 0.32804, 0.332, 0.33988, 0.34732, 0.35136,
-0.35264, 0.3554, 0.36194, 0.3719, 0.37874, 0.38534, 0.3905, 0.39232, 0.39318, 0.39312, 0.392, 0.39194, 0.39122, 0.39082, 0.3914, 0.38962, 0.3879, 0.38678,
-0.38556, 0.38342, 0.3829, 0.38114, 0.3809, 0.38132, 0.38064, 0.38016, 0.37906, 0.37732, 0.37642, 0.37496, 0.37658, 0.37574, 0.37612, 0.37648, 0.3767, 0.37636,
-0.37632, 0.37408, 0.37538, 0.37482, 0.37362, 0.37256, 0.374, 0.37224, 0.37286, 
-//1106
-0.12894, 0.15404, 0.19356, 0.23762, 0.27506, 0.2933, 0.30186, 0.3027, 0.2992, 0.29714, 0.2949, 0.293, 0.29074, 0.28866, 0.28408, 0.28298, 0.28288, 0.28066,
-0.27902, 0.27742, 0.27208, 0.26662, 0.2622, 0.25976, 0.25836, 0.25748, 0.25562, 0.25058, 0.2437, 0.23764, 0.23352, 0.23218, 0.23516, 0.2409, 0.24782, 0.25122,
-0.25414, 0.2567, 0.26096, 0.26814, 0.27522, 0.28152, 0.28592, 0.28904, 0.28978, 0.28938, 0.28882, 0.28704, 0.28656, 0.28584, 0.28608, 0.28312, 0.28152, 0.28124,
-0.28128, 0.2796, 0.27942, 0.27698, 0.27776, 0.27704, 0.27636, 0.27578, 0.27448, 0.27344, 0.27318, 0.27174, 0.27266, 0.27118, 0.27202, 0.27196, 0.27192, 0.27114,
-0.27166, 0.27084, 0.27006, 0.27034, 0.26796, 0.26838, 0.26842, 0.26722, 0.26782, 
-//1107
-0.11844, 0.13618, 0.16018, 0.18658, 0.2049, 0.21288, 0.2155, 0.21554, 0.21354, 0.21132, 0.21006, 0.209, 0.207, 0.20428, 0.20332, 0.20172, 0.2006, 0.1993,
-0.1987, 0.19674, 0.19306, 0.18766, 0.18464, 0.18156, 0.17864, 0.17932, 0.17818, 0.17326, 0.16716, 0.1616, 0.15722, 0.15668, 0.15944, 0.16464, 0.1707, 0.17376,
-0.17458, 0.17734, 0.18092, 0.18646, 0.19214, 0.19876, 0.2024, 0.20412, 0.20546, 0.20616, 0.20664, 0.20526, 0.20522, 0.20482, 0.20504, 0.20432, 0.2029, 0.2031,
-0.20362, 0.2035, 0.2031, 0.2008, 0.20058, 0.20174, 0.20134, 0.20108, 0.2001, 0.20054, 0.19992, 0.199, 0.20018, 0.19988, 0.20012, 0.1999, 0.2009, 0.19932,
-0.19982, 0.1992, 0.19942, 0.20038, 0.19914, 0.19796, 0.19972, 0.19784, 0.19786, 
-//1108
-0.09242, 0.10178, 0.11552, 0.12784, 0.13446, 0.13722, 0.1374, 0.1372, 0.13524, 0.13368, 0.13226, 0.1325, 0.13116, 0.12878, 0.12768, 0.12638, 0.1264, 0.12564,
-0.1243, 0.12236, 0.12068, 0.1168, 0.11452, 0.11232, 0.11154, 0.10948, 0.10698, 0.10362, 0.09786, 0.09448, 0.0921, 0.09178, 0.09374, 0.09526, 0.09788, 0.09762,
-0.0971, 0.09944, 0.10252, 0.10784, 0.1135, 0.11908, 0.12446, 0.12702, 0.12846, 0.13054, 0.13062, 0.13088, 0.13028, 0.13136, 0.13158, 0.13144, 0.13104, 0.1309,
-0.13126, 0.13014, 0.12986, 0.12838, 0.12874, 0.12936, 0.12894, 0.12718, 0.12716, 0.12718, 0.128, 0.12786, 0.12872, 0.12878, 0.1294, 0.12946, 0.13038, 0.13126,
-0.13108, 0.13142, 0.13102, 0.1315, 0.13096, 0.13178, 0.13144, 0.13026, 0.1309, 
-//1109
-0.07228, 0.07636, 0.08138, 0.08586, 0.08754, 0.08836, 0.08814, 0.08756, 0.08648, 0.08476, 0.08306, 0.08358, 0.08198, 0.08016, 0.07872, 0.07732, 0.07596,
-0.07592, 0.07418, 0.07272, 0.07092, 0.0702, 0.0673, 0.06714, 0.06702, 0.06562, 0.06436, 0.06166, 0.05998, 0.05822, 0.0575, 0.05704, 0.05658, 0.05756, 0.05816,
-0.05676, 0.0555, 0.0567, 0.05866, 0.06122, 0.06522, 0.06966, 0.07366, 0.07738, 0.07972, 0.08024, 0.08196, 0.08266, 0.08396, 0.0836, 0.08364, 0.08396, 0.0833,
-0.08204, 0.08238, 0.08144, 0.08092, 0.07804, 0.07936, 0.08128, 0.0803, 0.08004, 0.08142, 0.08198, 0.08244, 0.083, 0.0832, 0.08366, 0.08364, 0.08382, 0.08432,
-0.08392, 0.08502, 0.08422, 0.0849, 0.08506, 0.08584, 0.0864, 0.08734, 0.0868, 0.08728, 
-//1110
-0.0646, 0.06508, 0.06718, 0.06886, 0.06924, 0.06954, 0.0697, 0.06792, 0.0681, 0.06674, 0.0691, 0.06418, 0.06078, 0.0592, 0.05724, 0.05608, 0.0554, 0.05466,
-0.05356, 0.05326, 0.05036, 0.05052, 0.04898, 0.04766, 0.04666, 0.04546, 0.04656, 0.04546, 0.04556, 0.04478, 0.04478, 0.04302, 0.04374, 0.04338, 0.0425, 0.04286,
-0.04266, 0.04238, 0.04316, 0.04284, 0.0442, 0.0447, 0.04588, 0.0465, 0.0473, 0.05002, 0.05262, 0.0573, 0.06282, 0.06714, 0.071, 0.07446, 0.07636, 0.0789,
-0.08032, 0.08156, 0.08294, 0.08206, 0.08402, 0.08646, 0.08678, 0.08684, 0.08844, 0.08958, 0.09128, 0.09284, 0.09416, 0.09462, 0.0964, 0.09808, 0.09884, 0.10178,
-0.10234, 0.10314, 0.10468, 0.10594, 0.10648, 0.10796, 0.1093, 0.10902, 0.10952, 
-//1111
-0.13692, 0.16682, 0.22104, 0.30096, 0.40062, 0.49578, 0.56016, 0.5874, 0.59398, 0.59228, 0.59122, 0.58734, 0.58474, 0.58068, 0.57616, 0.57178, 0.569, 0.56612,
-0.56182, 0.55418, 0.54638, 0.53516, 0.5254, 0.51862, 0.51362, 0.50866, 0.49904, 0.48458, 0.46728, 0.45096, 0.441, 0.43908, 0.44374, 0.45226, 0.45926, 0.46106,
-0.46242, 0.46916, 0.48388, 0.50542, 0.53068, 0.55482, 0.57468, 0.58782, 0.59696, 0.6027, 0.60638, 0.6088, 0.6108, 0.61172, 0.61116, 0.61158, 0.61064, 0.61116,
-0.611, 0.60844, 0.60898, 0.6081, 0.60676, 0.6064, 0.60696, 0.60604, 0.6044, 0.60506, 0.60392, 0.60368, 0.6043, 0.6035, 0.6033, 0.60562, 0.60586, 0.60282,
-0.6044, 0.60234, 0.60394, 0.60422, 0.60264, 0.60178, 0.6058, 0.59856, 0.59912, 
-//1112
-0.12286, 0.15234, 0.20238, 0.27012, 0.34406, 0.4001, 0.43012, 0.43848, 0.43856, 0.4363, 0.43344, 0.43124, 0.42802, 0.42534, 0.42078, 0.41732, 0.41584, 0.41226,
-0.4098, 0.40472, 0.39704, 0.38674, 0.37698, 0.37276, 0.3693, 0.36378, 0.3575, 0.3449, 0.32948, 0.31416, 0.30508, 0.30164, 0.30408, 0.31188, 0.31902, 0.32062,
-0.3202, 0.32536, 0.33534, 0.35424, 0.3779, 0.40034, 0.41782, 0.42978, 0.43904, 0.4424, 0.44558, 0.44764, 0.44798, 0.44822, 0.4481, 0.44774, 0.44624, 0.44624,
-0.44518, 0.44518, 0.44556, 0.44408, 0.4442, 0.44436, 0.44184, 0.4418, 0.44008, 0.4395, 0.4391, 0.43918, 0.43878, 0.43854, 0.43744, 0.43902, 0.43742, 0.43636,
-0.4373, 0.43494, 0.4361, 0.4359, 0.4364, 0.4349, 0.43354, 0.43058, 0.43244, 
-//1113
-0.11344, 0.13934, 0.18068, 0.23208, 0.2751, 0.29982, 0.30928, 0.30916, 0.3081, 0.30452, 0.30228, 0.30078, 0.29792, 0.29526, 0.29214, 0.2893, 0.28812, 0.2877,
-0.28534, 0.28148, 0.27518, 0.266, 0.25762, 0.2534, 0.25162, 0.2496, 0.24374, 0.23264, 0.22012, 0.20838, 0.20004, 0.19994, 0.20436, 0.2129, 0.21842, 0.21958,
-0.21978, 0.2263, 0.23846, 0.25592, 0.2739, 0.28872, 0.29916, 0.30582, 0.30876, 0.3107, 0.31076, 0.30902, 0.30784, 0.30662, 0.30748, 0.30552, 0.30438, 0.3038,
-0.3021, 0.29992, 0.2996, 0.29782, 0.29676, 0.29806, 0.29624, 0.29562, 0.2942, 0.29346, 0.29226, 0.29194, 0.29054, 0.29056, 0.2887, 0.29004, 0.29006, 0.2869,
-0.28692, 0.28566, 0.28546, 0.28468, 0.28394, 0.2823, 0.28228, 0.2799, 0.27808, 
-//1114
-0.11754, 0.13862, 0.16826, 0.19948, 0.22186, 0.23276, 0.2334, 0.23462, 0.23112, 0.2277, 0.22546, 0.2235, 0.22222, 0.21766, 0.214, 0.21018, 0.20734, 0.20564,
-0.2031, 0.20022, 0.195, 0.18898, 0.18408, 0.17934, 0.1774, 0.17468, 0.17022, 0.16292, 0.15458, 0.14658, 0.14176, 0.13984, 0.1408, 0.14408, 0.14694, 0.14838,
-0.14722, 0.15114, 0.15662, 0.1657, 0.17954, 0.19296, 0.20698, 0.21512, 0.22184, 0.22724, 0.22964, 0.23088, 0.22986, 0.23086, 0.23042, 0.22958, 0.2282, 0.22898,
-0.22854, 0.22684, 0.22668, 0.2254, 0.22634, 0.22658, 0.2258, 0.22444, 0.2233, 0.22368, 0.22366, 0.22364, 0.22296, 0.2233, 0.2226, 0.221, 0.22216, 0.22342,
-0.22386, 0.22302, 0.22344, 0.22274, 0.22192, 0.22082, 0.22138, 0.22096, 0.22106, 
-//1115
-0.09312, 0.10524, 0.12142, 0.13686, 0.14716, 0.15026, 0.15102, 0.15008, 0.14966, 0.14718, 0.14654, 0.14436, 0.14216, 0.14012, 0.13814, 0.13578, 0.1342, 0.13164,
-0.1296, 0.12582, 0.12276, 0.11708, 0.11286, 0.10934, 0.10722, 0.10514, 0.10112, 0.09496, 0.08866, 0.08404, 0.08164, 0.07952, 0.08064, 0.08278, 0.08378, 0.08284,
-0.08122, 0.0828, 0.0876, 0.09476, 0.10574, 0.1174, 0.1266, 0.13502, 0.14142, 0.14502, 0.1474, 0.14964, 0.1507, 0.15132, 0.15188, 0.15084, 0.15044, 0.1504,
-0.14982, 0.14998, 0.14894, 0.14782, 0.14746, 0.14912, 0.14808, 0.14702, 0.14816, 0.14924, 0.1491, 0.14982, 0.1503, 0.14996, 0.15014, 0.14988, 0.15172, 0.15102,
-0.15238, 0.15118, 0.152, 0.15162, 0.15138, 0.15162, 0.15174, 0.15166, 0.15042, 
-//1116
-0.07366, 0.08088, 0.09008, 0.09722, 0.10202, 0.10344, 0.10334, 0.10416, 0.1042, 0.10204, 0.10116, 0.09898, 0.09772, 0.0941, 0.09008, 0.08642, 0.08296, 0.0792,
-0.07508, 0.07228, 0.06872, 0.06606, 0.06374, 0.0594, 0.05834, 0.05632, 0.05494, 0.05352, 0.05134, 0.05076, 0.05042, 0.04946, 0.04922, 0.04876, 0.04872, 0.04798,
-0.0484, 0.04988, 0.0517, 0.05448, 0.05796, 0.06146, 0.06518, 0.06932, 0.0745, 0.08088, 0.089, 0.09638, 0.10136, 0.10536, 0.10856, 0.10976, 0.11084, 0.1114,
-0.11232, 0.11246, 0.11204, 0.11026, 0.11074, 0.1125, 0.11122, 0.111, 0.1117, 0.1122, 0.11278, 0.1126, 0.1134, 0.11344, 0.11316, 0.114, 0.1143, 0.11314, 0.11408,
-0.11298, 0.1124, 0.11174, 0.1129, 0.1128, 0.11192, 0.11154, 0.11228, 
-//1117
-0.0664, 0.07382, 0.08072, 0.08876, 0.09368, 0.0956, 0.09604, 0.0965, 0.09466, 0.0918, 0.08932, 0.08548, 0.08186, 0.07854, 0.07348, 0.06972, 0.06606, 0.06268,
-0.06008, 0.05776, 0.05482, 0.05276, 0.0503, 0.04832, 0.04782, 0.0463, 0.04576, 0.0443, 0.04334, 0.04178, 0.04168, 0.04166, 0.04146, 0.04124, 0.042, 0.04206,
-0.0417, 0.04254, 0.04248, 0.04252, 0.04348, 0.04266, 0.04402, 0.04522, 0.04834, 0.05148, 0.0573, 0.06474, 0.0755, 0.0869, 0.09902, 0.1115, 0.12258, 0.13228,
-0.13936, 0.14618, 0.1531, 0.15752, 0.16222, 0.16686, 0.1688, 0.17166, 0.17576, 0.1789, 0.18066, 0.18454, 0.18812, 0.19156, 0.19526, 0.1973, 0.20154, 0.20388,
-0.20766, 0.21024, 0.21288, 0.21358, 0.21592, 0.2181, 0.2198, 0.2203, 0.2246, 
-//1118
-0.12968, 0.16122, 0.21714, 0.29876, 0.4017, 0.50974, 0.58576, 0.62298, 0.63336, 0.63338, 0.6326, 0.628, 0.6247, 0.61692, 0.60844, 0.60094, 0.59462, 0.58782,
-0.57864, 0.56906, 0.55396, 0.53974, 0.52634, 0.51616, 0.50882, 0.49872, 0.48634, 0.46552, 0.4419, 0.42122, 0.4065, 0.40102, 0.40422, 0.41202, 0.41602, 0.4136,
-0.4108, 0.41646, 0.43244, 0.46188, 0.50178, 0.54624, 0.5883, 0.62098, 0.6495, 0.6687, 0.68416, 0.6939, 0.70074, 0.70584, 0.70928, 0.71198, 0.71162, 0.71422,
-0.7152, 0.71412, 0.71576, 0.71488, 0.7131, 0.71606, 0.7157, 0.71646, 0.7148, 0.71692, 0.71666, 0.71868, 0.7203, 0.7212, 0.72206, 0.72816, 0.72556, 0.71882,
-0.7187, 0.72008, 0.72036, 0.72148, 0.72206, 0.72144, 0.72328, 0.71918, 0.72308, 
-//1119
-0.12508, 0.15214, 0.20442, 0.27528, 0.35664, 0.42608, 0.46404, 0.47562, 0.47716, 0.47398, 0.47166, 0.4681, 0.46324, 0.456, 0.4495, 0.4438, 0.43928, 0.43542,
-0.43058, 0.42284, 0.40976, 0.39638, 0.38544, 0.37564, 0.37008, 0.36292, 0.34966, 0.33126, 0.31116, 0.29404, 0.28348, 0.28038, 0.28434, 0.29286, 0.29552, 0.2936,
-0.28984, 0.29668, 0.31586, 0.34592, 0.3843, 0.41956, 0.45136, 0.47376, 0.4889, 0.4994, 0.50616, 0.51002, 0.5122, 0.51464, 0.51594, 0.51544, 0.51512, 0.5147,
-0.51428, 0.51096, 0.51292, 0.51184, 0.51108, 0.5119, 0.51128, 0.51058, 0.50958, 0.5111, 0.50962, 0.50932, 0.50944, 0.5101, 0.51096, 0.5094, 0.50954, 0.50866,
-0.50896, 0.50826, 0.50778, 0.50726, 0.50614, 0.50512, 0.50656, 0.50396, 0.50796, 
-//1120
-0.12504, 0.15256, 0.19732, 0.25634, 0.31312, 0.35034, 0.36614, 0.36886, 0.3687, 0.36368, 0.36064, 0.35528, 0.35132, 0.3454, 0.33916, 0.33286, 0.32674, 0.32136,
-0.31574, 0.3063, 0.29424, 0.2835, 0.27208, 0.26576, 0.25924, 0.25286, 0.24224, 0.22788, 0.21248, 0.19958, 0.19178, 0.19022, 0.19344, 0.20034, 0.20374, 0.20394,
-0.20272, 0.20688, 0.21934, 0.24056, 0.26596, 0.29528, 0.32014, 0.34098, 0.35542, 0.36618, 0.3731, 0.37678, 0.38034, 0.38142, 0.38304, 0.38318, 0.3822, 0.38122,
-0.38296, 0.38096, 0.38084, 0.3794, 0.37924, 0.37978, 0.37906, 0.37728, 0.3774, 0.37604, 0.37662, 0.3755, 0.37492, 0.37514, 0.37342, 0.37468, 0.37324, 0.37316,
-0.37326, 0.37138, 0.3715, 0.37062, 0.37014, 0.3689, 0.36982, 0.36694, 0.36688, 
-//1121
-0.12284, 0.14116, 0.1749, 0.21344, 0.24472, 0.25964, 0.2672, 0.26718, 0.26766, 0.26396, 0.26016, 0.25632, 0.25178, 0.24826, 0.2422, 0.23608, 0.23148, 0.22706,
-0.2212, 0.21258, 0.2047, 0.19708, 0.18762, 0.1824, 0.17732, 0.17156, 0.1651, 0.15436, 0.14408, 0.13428, 0.12894, 0.12652, 0.12816, 0.1325, 0.13578, 0.1345,
-0.13238, 0.1347, 0.1431, 0.15822, 0.17718, 0.199, 0.22014, 0.23744, 0.24934, 0.25882, 0.26484, 0.26736, 0.27088, 0.27244, 0.27308, 0.27334, 0.27334, 0.27294,
-0.27238, 0.27224, 0.27114, 0.26922, 0.2698, 0.27056, 0.27062, 0.26848, 0.26978, 0.26854, 0.26812, 0.26784, 0.26896, 0.26696, 0.26616, 0.26678, 0.26742, 0.26706,
-0.26894, 0.2664, 0.26586, 0.26604, 0.26652, 0.26578, 0.2682, 0.2676, 0.26456, 
-//1122
-0.10602, 0.12018, 0.14072, 0.1611, 0.17562, 0.18114, 0.18268, 0.1835, 0.18254, 0.1801, 0.17636, 0.17368, 0.16912, 0.16456, 0.15954, 0.15396, 0.14904, 0.14374,
-0.13824, 0.133, 0.12638, 0.11962, 0.1142, 0.11014, 0.1065, 0.10196, 0.09642, 0.08936, 0.08354, 0.07804, 0.07598, 0.0731, 0.07396, 0.07498, 0.07566, 0.07382,
-0.07432, 0.0745, 0.07812, 0.08546, 0.0951, 0.10786, 0.12258, 0.13696, 0.15218, 0.16564, 0.17854, 0.188, 0.19472, 0.19898, 0.20176, 0.20466, 0.20474, 0.20504,
-0.20526, 0.20552, 0.20466, 0.20306, 0.20346, 0.20472, 0.20428, 0.20312, 0.203, 0.20398, 0.20446, 0.20412, 0.20344, 0.20348, 0.20344, 0.20458, 0.20594, 0.20522,
-0.20668, 0.20592, 0.2064, 0.20708, 0.2063, 0.20556, 0.20458, 0.2044, 0.20506, 
-//1123
-0.08696, 0.09372, 0.10632, 0.12032, 0.1289, 0.13418, 0.13634, 0.13514, 0.13268, 0.12962, 0.12386, 0.11862, 0.11156, 0.1055, 0.10026, 0.09332, 0.08872, 0.08336,
-0.07784, 0.07416, 0.07028, 0.06562, 0.06154, 0.05896, 0.05712, 0.05544, 0.05364, 0.05132, 0.05094, 0.04944, 0.04896, 0.0489, 0.0486, 0.04936, 0.04944, 0.04884,
-0.04924, 0.04994, 0.05062, 0.05054, 0.05054, 0.05122, 0.05312, 0.05458, 0.05954, 0.06676, 0.07776, 0.09124, 0.10708, 0.12554, 0.14428, 0.16268, 0.17678,
-0.19004, 0.20244, 0.21082, 0.2192, 0.22548, 0.2323, 0.2382, 0.24112, 0.24584, 0.24992, 0.2552, 0.2592, 0.2612, 0.26476, 0.26828, 0.26906, 0.27298, 0.27456,
-0.27724, 0.27946, 0.28014, 0.28248, 0.2859, 0.28786, 0.28994, 0.29266, 0.29122, 0.2918, 
-//1124
-0.1294, 0.16036, 0.2128, 0.29126, 0.3857, 0.46994, 0.52132, 0.54018, 0.54498, 0.54106, 0.53556, 0.53058, 0.5203, 0.51, 0.50056, 0.4904, 0.48072, 0.4711,
-0.46046, 0.44582, 0.43084, 0.41286, 0.39802, 0.38798, 0.3777, 0.36676, 0.35276, 0.33132, 0.30802, 0.2881, 0.27482, 0.2684, 0.2704, 0.27578, 0.27962, 0.27752,
-0.27408, 0.2782, 0.29416, 0.32378, 0.36428, 0.413, 0.45978, 0.50234, 0.53714, 0.56418, 0.58282, 0.5982, 0.60808, 0.61544, 0.6212, 0.62426, 0.62506, 0.62766,
-0.6276, 0.62694, 0.62774, 0.62838, 0.62756, 0.6281, 0.62724, 0.62764, 0.6256, 0.62614, 0.62676, 0.62638, 0.6284, 0.62826, 0.62916, 0.63118, 0.63128, 0.62888,
-0.63252, 0.62862, 0.62828, 0.6305, 0.62986, 0.62948, 0.62878, 0.62426, 0.63104, 
-//1125
-0.12264, 0.15118, 0.1996, 0.26168, 0.3235, 0.36648, 0.38678, 0.39308, 0.39488, 0.39182, 0.38714, 0.3826, 0.37782, 0.36884, 0.35958, 0.35046, 0.34156, 0.33146,
-0.32166, 0.30938, 0.29684, 0.28326, 0.27156, 0.26316, 0.2556, 0.24662, 0.23478, 0.22072, 0.20698, 0.1941, 0.18654, 0.18308, 0.18472, 0.18642, 0.18572, 0.18124,
-0.17754, 0.1801, 0.19046, 0.21328, 0.24522, 0.28426, 0.32354, 0.35754, 0.38566, 0.4053, 0.42216, 0.4337, 0.43936, 0.44386, 0.44748, 0.44926, 0.45026, 0.45036,
-0.45064, 0.4509, 0.4502, 0.44896, 0.44898, 0.45022, 0.44854, 0.44846, 0.44648, 0.44602, 0.44594, 0.4465, 0.4472, 0.44756, 0.44782, 0.45018, 0.44914, 0.44776,
-0.44802, 0.4456, 0.44688, 0.44704, 0.44508, 0.44424, 0.44582, 0.44378, 0.44354, 
-//1126
-0.11712, 0.14066, 0.17632, 0.21752, 0.25282, 0.27072, 0.27962, 0.28264, 0.28208, 0.27822, 0.27506, 0.27038, 0.26564, 0.25964, 0.25302, 0.24536, 0.23812,
-0.22982, 0.22316, 0.21386, 0.20266, 0.19002, 0.18062, 0.17356, 0.16732, 0.16024, 0.1502, 0.13684, 0.124, 0.11358, 0.10778, 0.107, 0.10912, 0.11188, 0.11136,
-0.1094, 0.1086, 0.11118, 0.12104, 0.13892, 0.16356, 0.19398, 0.22244, 0.24682, 0.26586, 0.27838, 0.28786, 0.29486, 0.30008, 0.3018, 0.3039, 0.30558, 0.30402,
-0.30472, 0.30488, 0.30628, 0.30632, 0.30358, 0.30502, 0.30528, 0.3048, 0.30512, 0.30208, 0.302, 0.3031, 0.30372, 0.30308, 0.30392, 0.30296, 0.30462, 0.30418,
-0.30476, 0.30586, 0.306, 0.30566, 0.30232, 0.30294, 0.30366, 0.3041, 0.3026, 0.30144, 
-//1127
-0.10086, 0.1187, 0.14578, 0.17376, 0.1954, 0.20632, 0.2114, 0.21048, 0.20816, 0.20502, 0.20066, 0.19482, 0.18832, 0.18198, 0.17404, 0.16584, 0.16046, 0.1529,
-0.14604, 0.1371, 0.12828, 0.12022, 0.11276, 0.10682, 0.1029, 0.09912, 0.09264, 0.08604, 0.07942, 0.0738, 0.0708, 0.07042, 0.0704, 0.07188, 0.072, 0.07092,
-0.0691, 0.0721, 0.07642, 0.08324, 0.09282, 0.1036, 0.11444, 0.12776, 0.14382, 0.16204, 0.18036, 0.1982, 0.2156, 0.22866, 0.23896, 0.24608, 0.25176, 0.25544,
-0.25704, 0.26074, 0.2632, 0.2617, 0.26442, 0.2678, 0.2678, 0.2676, 0.26722, 0.26674, 0.26764, 0.26748, 0.26908, 0.27044, 0.27246, 0.27444, 0.2757, 0.27674,
-0.277, 0.2774, 0.27672, 0.27736, 0.2785, 0.27838, 0.27994, 0.27818, 0.27708, 
-//1128
-0.10292, 0.10658, 0.123, 0.14236, 0.1588, 0.16656, 0.17144, 0.16902, 0.1663, 0.15878, 0.1514, 0.14158, 0.13156, 0.12246, 0.1113, 0.1024, 0.09402, 0.08582,
-0.08108, 0.0748, 0.06954, 0.0641, 0.06036, 0.0561, 0.05366, 0.05208, 0.0506, 0.04972, 0.0487, 0.0475, 0.0468, 0.04624, 0.04596, 0.04668, 0.04588, 0.04636,
-0.04648, 0.04698, 0.04602, 0.04564, 0.045, 0.04546, 0.04606, 0.04756, 0.05196, 0.05792, 0.07074, 0.08744, 0.11126, 0.13842, 0.1711, 0.20426, 0.23762, 0.26816,
-0.2945, 0.31856, 0.34194, 0.36008, 0.37688, 0.39246, 0.4036, 0.41432, 0.42542, 0.43584, 0.44222, 0.44938, 0.45728, 0.46252, 0.46946, 0.47662, 0.48086, 0.48708,
-0.49288, 0.49476, 0.49878, 0.50284, 0.50952, 0.51216, 0.51478, 0.51552, 0.51866, 
-//1129
-0.13344, 0.1645, 0.21464, 0.28552, 0.36282, 0.4194, 0.44822, 0.4602, 0.4584, 0.45296, 0.44586, 0.4369, 0.42604, 0.41154, 0.39808, 0.38486, 0.3721, 0.35886,
-0.34432, 0.32902, 0.3122, 0.29704, 0.28324, 0.27236, 0.26304, 0.252, 0.23902, 0.22242, 0.20602, 0.1907, 0.18182, 0.17782, 0.1784, 0.18126, 0.182, 0.17872,
-0.17616, 0.17872, 0.18648, 0.20514, 0.23298, 0.26546, 0.30256, 0.34038, 0.38026, 0.41946, 0.45698, 0.49198, 0.519, 0.54258, 0.56026, 0.57264, 0.58098, 0.5892,
-0.5949, 0.59816, 0.60182, 0.60338, 0.60424, 0.6068, 0.606, 0.60692, 0.60604, 0.60954, 0.61164, 0.61162, 0.61246, 0.61346, 0.6146, 0.61586, 0.61682, 0.61686,
-0.61838, 0.61968, 0.61918, 0.62142, 0.62364, 0.62252, 0.62294, 0.62096, 0.6189, 
-//1130
-0.11292, 0.13742, 0.18132, 0.23376, 0.28564, 0.31674, 0.32998, 0.33324, 0.33148, 0.32636, 0.31912, 0.3091, 0.29896, 0.28818, 0.27616, 0.26342, 0.25374, 0.24276,
-0.23136, 0.22018, 0.20662, 0.19378, 0.18222, 0.17308, 0.16744, 0.15886, 0.14998, 0.1375, 0.12614, 0.11556, 0.10928, 0.10686, 0.10572, 0.1078, 0.10922, 0.1083,
-0.10678, 0.10852, 0.11466, 0.12708, 0.1456, 0.16816, 0.19238, 0.21794, 0.24626, 0.27676, 0.30806, 0.3369, 0.3618, 0.38392, 0.39862, 0.40938, 0.41692, 0.42372,
-0.42814, 0.43052, 0.43242, 0.43344, 0.43534, 0.43756, 0.43714, 0.43778, 0.43694, 0.43888, 0.44174, 0.44352, 0.44364, 0.44224, 0.4423, 0.4441, 0.4444, 0.44474,
-0.44724, 0.44526, 0.4449, 0.44684, 0.44466, 0.44444, 0.44516, 0.44432, 0.44398, 
-//1131
-0.1154, 0.13608, 0.17086, 0.20888, 0.23922, 0.25476, 0.26104, 0.2621, 0.25962, 0.2526, 0.24534, 0.23516, 0.22492, 0.21314, 0.20072, 0.18858, 0.17746, 0.1665,
-0.15578, 0.1456, 0.13582, 0.12616, 0.11756, 0.111, 0.10576, 0.10016, 0.09498, 0.08888, 0.08316, 0.07766, 0.07502, 0.07344, 0.07346, 0.07456, 0.07508, 0.07476,
-0.07464, 0.07598, 0.07944, 0.08442, 0.0898, 0.09596, 0.10338, 0.11352, 0.12986, 0.15156, 0.17962, 0.21254, 0.24592, 0.27942, 0.31002, 0.33742, 0.36002, 0.38012,
-0.3997, 0.41516, 0.4311, 0.44484, 0.4564, 0.46834, 0.47736, 0.48688, 0.49432, 0.50282, 0.5132, 0.52034, 0.52738, 0.53462, 0.53998, 0.54852, 0.555, 0.5604,
-0.56628, 0.57046, 0.57552, 0.57856, 0.58424, 0.58766, 0.59334, 0.59478, 0.60018, 
-//1132
-0.12014, 0.14744, 0.1916, 0.24818, 0.30436, 0.34184, 0.3571, 0.36164, 0.35916, 0.35362, 0.3446, 0.33094, 0.31908, 0.30296, 0.28822, 0.27254, 0.2586, 0.24446,
-0.2317, 0.21784, 0.20354, 0.19012, 0.17768, 0.16862, 0.1611, 0.15274, 0.14478, 0.13488, 0.12552, 0.11646, 0.11028, 0.10804, 0.10814, 0.10962, 0.11046, 0.10956,
-0.10906, 0.10968, 0.11294, 0.12162, 0.13292, 0.14404, 0.16032, 0.1804, 0.20748, 0.24272, 0.28436, 0.3312, 0.37766, 0.4215, 0.46032, 0.49558, 0.52544, 0.5521,
-0.5735, 0.59388, 0.6115, 0.62432, 0.63596, 0.64408, 0.65066, 0.65638, 0.66388, 0.67084, 0.68062, 0.68824, 0.69548, 0.70164, 0.7069, 0.7141, 0.71936, 0.72218,
-0.72944, 0.72896, 0.7338, 0.7351, 0.7394, 0.74182, 0.74384, 0.7439, 0.74782, 
-//1133
-0.12656, 0.15608, 0.21008, 0.29298, 0.40304, 0.52284, 0.62036, 0.67596, 0.69766, 0.7047, 0.70616, 0.70932, 0.70884, 0.70826, 0.70736, 0.70682, 0.70566, 0.70708,
-0.70694, 0.70546, 0.69998, 0.69242, 0.68662, 0.68492, 0.6842, 0.68166, 0.67856, 0.66858, 0.6569, 0.64566, 0.63852, 0.63846, 0.64308, 0.65086, 0.65712, 0.65892,
-0.65958, 0.6651, 0.6765, 0.69204, 0.7077, 0.72038, 0.7312, 0.73748, 0.7416, 0.74248, 0.74468, 0.74628, 0.74532, 0.7456, 0.74628, 0.74642, 0.74488, 0.74534,
-0.74458, 0.74426, 0.74506, 0.74466, 0.7429, 0.74366, 0.7424, 0.74428, 0.7434, 0.74528, 0.74574, 0.74574, 0.74666, 0.74742, 0.74704, 0.74748, 0.74864, 0.7489,
-0.75246, 0.74756, 0.74978, 0.74986, 0.74868, 0.75008, 0.74818, 0.74734, 0.74884, 
-//1134
-0.12586, 0.1537, 0.20676, 0.28228, 0.37554, 0.46104, 0.51472, 0.53354, 0.5384, 0.53738, 0.53644, 0.53542, 0.5344, 0.53252, 0.52858, 0.52728, 0.5277, 0.52692,
-0.52618, 0.52386, 0.51872, 0.51138, 0.50408, 0.50136, 0.50122, 0.5016, 0.49842, 0.49156, 0.4808, 0.47092, 0.46396, 0.46458, 0.46996, 0.47994, 0.4903, 0.49558,
-0.4979, 0.50244, 0.5108, 0.52112, 0.53218, 0.5396, 0.54418, 0.54676, 0.5481, 0.54942, 0.54816, 0.54968, 0.5488, 0.5482, 0.54732, 0.54656, 0.5448, 0.54444,
-0.54518, 0.5423, 0.5413, 0.54212, 0.54106, 0.54032, 0.54042, 0.53904, 0.53704, 0.538, 0.53802, 0.53652, 0.5371, 0.5364, 0.53554, 0.53734, 0.53664, 0.53568,
-0.53668, 0.53284, 0.53374, 0.53202, 0.53218, 0.53124, 0.5312, 0.5278, 0.5299, 
-//1135
-0.1345, 0.1651, 0.2135, 0.27786, 0.34346, 0.38856, 0.40842, 0.41308, 0.4122, 0.40894, 0.4067, 0.40494, 0.40356, 0.40084, 0.39824, 0.39532, 0.39474, 0.3957,
-0.39488, 0.39216, 0.38794, 0.38018, 0.3741, 0.37152, 0.37138, 0.37194, 0.37148, 0.3649, 0.35698, 0.34726, 0.34204, 0.34082, 0.34668, 0.3575, 0.36984, 0.37848,
-0.38264, 0.38918, 0.39504, 0.40184, 0.40784, 0.41232, 0.41512, 0.41532, 0.416, 0.41498, 0.41436, 0.4136, 0.41158, 0.41104, 0.41032, 0.40854, 0.40666, 0.40574,
-0.4062, 0.40446, 0.4037, 0.40234, 0.40184, 0.40156, 0.39972, 0.40012, 0.39812, 0.39688, 0.39652, 0.3963, 0.3949, 0.39416, 0.39426, 0.39588, 0.3946, 0.3933,
-0.39566, 0.39374, 0.39252, 0.392, 0.39174, 0.39084, 0.38992, 0.38742, 0.38896, 
-//1136
-0.11896, 0.14244, 0.17846, 0.22084, 0.25778, 0.2784, 0.2875, 0.28924, 0.28794, 0.28848, 0.2881, 0.28744, 0.28622, 0.2848, 0.28278, 0.28006, 0.27856, 0.2765,
-0.2739, 0.27164, 0.26822, 0.26542, 0.26204, 0.26028, 0.25786, 0.25434, 0.2517, 0.24868, 0.24652, 0.24402, 0.2447, 0.24466, 0.2446, 0.24338, 0.24248, 0.24198,
-0.24138, 0.2439, 0.24976, 0.26242, 0.27642, 0.2898, 0.29748, 0.30124, 0.30418, 0.30492, 0.30456, 0.30446, 0.30376, 0.30304, 0.30294, 0.30082, 0.29984, 0.29968,
-0.29866, 0.29732, 0.29768, 0.29686, 0.29648, 0.29558, 0.29428, 0.29456, 0.29476, 0.2948, 0.29428, 0.29354, 0.2938, 0.29302, 0.2927, 0.29324, 0.29292, 0.29264,
-0.29204, 0.29068, 0.28964, 0.29006, 0.29038, 0.2907, 0.29072, 0.28806, 0.2875, 
-//1137
-0.09448, 0.11064, 0.1329, 0.15616, 0.17376, 0.1829, 0.18666, 0.18748, 0.1876, 0.1871, 0.18648, 0.1867, 0.18714, 0.18698, 0.1875, 0.18638, 0.18626, 0.1855,
-0.18454, 0.18314, 0.18198, 0.17972, 0.17776, 0.1757, 0.1737, 0.17184, 0.17014, 0.16734, 0.16496, 0.16374, 0.16296, 0.16344, 0.1617, 0.1602, 0.15912, 0.15762,
-0.15812, 0.16006, 0.16546, 0.17502, 0.18568, 0.19434, 0.20002, 0.20174, 0.20336, 0.2037, 0.20428, 0.20486, 0.20436, 0.2041, 0.20352, 0.203, 0.20296, 0.20284,
-0.20292, 0.20268, 0.20194, 0.20094, 0.2012, 0.20114, 0.20058, 0.20012, 0.2004, 0.20138, 0.202, 0.20184, 0.20172, 0.20188, 0.20198, 0.20262, 0.20276, 0.20276,
-0.20322, 0.20288, 0.2027, 0.2021, 0.20384, 0.20166, 0.20198, 0.20094, 0.2021, 
-//1138
-0.08818, 0.09806, 0.11186, 0.12292, 0.12946, 0.13116, 0.13132, 0.1297, 0.12768, 0.127, 0.12624, 0.12478, 0.12372, 0.12178, 0.12026, 0.11888, 0.1175, 0.11656,
-0.11608, 0.11526, 0.11192, 0.1096, 0.10734, 0.1058, 0.10552, 0.10442, 0.10214, 0.09864, 0.095, 0.0915, 0.09048, 0.08972, 0.09162, 0.09488, 0.09754, 0.0983,
-0.0982, 0.09956, 0.10346, 0.10856, 0.11522, 0.12132, 0.12606, 0.12872, 0.1302, 0.1311, 0.13198, 0.13168, 0.1318, 0.13138, 0.13114, 0.13146, 0.13126, 0.13008,
-0.12966, 0.12926, 0.12868, 0.12766, 0.12834, 0.12904, 0.1281, 0.12892, 0.12834, 0.12982, 0.129, 0.12916, 0.1294, 0.13046, 0.13032, 0.12976, 0.13012, 0.1298,
-0.12992, 0.12986, 0.13094, 0.13076, 0.12988, 0.13034, 0.1296, 0.1302, 0.13168, 
-//1139
-0.07224, 0.07254, 0.0769, 0.0794, 0.08118, 0.0807, 0.08092, 0.08, 0.07856, 0.07836, 0.07744, 0.07668, 0.07614, 0.07626, 0.07568, 0.0745, 0.07364, 0.07198,
-0.0703, 0.0691, 0.06718, 0.06606, 0.06406, 0.06288, 0.06152, 0.06058, 0.05922, 0.0569, 0.05578, 0.05376, 0.05312, 0.0531, 0.05318, 0.05352, 0.0537, 0.0545,
-0.0548, 0.05506, 0.05766, 0.0612, 0.06616, 0.07132, 0.07698, 0.07962, 0.0826, 0.08332, 0.08446, 0.08446, 0.08422, 0.08404, 0.08436, 0.0839, 0.08324, 0.0833,
-0.08208, 0.08188, 0.0824, 0.08108, 0.0817, 0.08232, 0.08194, 0.0819, 0.0824, 0.08352, 0.08412, 0.08426, 0.08464, 0.08488, 0.08498, 0.08568, 0.08512, 0.08516,
-0.0858, 0.08618, 0.08822, 0.0882, 0.08852, 0.0889, 0.08878, 0.08922, 0.08956, 
-//1140
-0.06308, 0.0639, 0.0662, 0.06822, 0.06854, 0.06858, 0.06816, 0.06766, 0.0665, 0.06574, 0.06408, 0.0633, 0.06224, 0.0608, 0.05856, 0.0568, 0.05536, 0.05408,
-0.05306, 0.05226, 0.05114, 0.04976, 0.04858, 0.0473, 0.04694, 0.04598, 0.04588, 0.04494, 0.04398, 0.0434, 0.0436, 0.0436, 0.04488, 0.04488, 0.0454, 0.04516,
-0.04506, 0.04618, 0.04698, 0.0468, 0.04706, 0.04762, 0.04886, 0.04886, 0.05028, 0.0521, 0.05506, 0.06088, 0.06626, 0.0737, 0.07928, 0.08476, 0.08842, 0.09168,
-0.09456, 0.09688, 0.09916, 0.09956, 0.10174, 0.10446, 0.10436, 0.10512, 0.10524, 0.10702, 0.10942, 0.11126, 0.11376, 0.11638, 0.11778, 0.1205, 0.12218, 0.12438,
-0.12642, 0.12714, 0.12974, 0.13002, 0.13156, 0.13362, 0.13274, 0.13496, 0.1356, 
-//1141
-0.14206, 0.17076, 0.22768, 0.30896, 0.40884, 0.50492, 0.56504, 0.58928, 0.59444, 0.59102, 0.587, 0.58454, 0.58126, 0.57728, 0.57306, 0.56794, 0.56674, 0.56484,
-0.56242, 0.55722, 0.54648, 0.53454, 0.52356, 0.51666, 0.5134, 0.50914, 0.50348, 0.49032, 0.47378, 0.45652, 0.44678, 0.44418, 0.45086, 0.46504, 0.47986, 0.48912,
-0.49296, 0.50334, 0.5196, 0.54506, 0.57352, 0.60102, 0.62416, 0.64036, 0.65194, 0.65882, 0.66346, 0.66696, 0.66772, 0.66896, 0.6698, 0.67014, 0.6675, 0.6689,
-0.66762, 0.6676, 0.66818, 0.66672, 0.6669, 0.66658, 0.66552, 0.66538, 0.66448, 0.6634, 0.66402, 0.66344, 0.66282, 0.66454, 0.6611, 0.66528, 0.66406, 0.6634,
-0.66358, 0.66132, 0.6613, 0.66204, 0.66168, 0.66076, 0.66324, 0.65924, 0.65802, 
-//1142
-0.12518, 0.15122, 0.20012, 0.26682, 0.33884, 0.39346, 0.41768, 0.42508, 0.42384, 0.42074, 0.41862, 0.41598, 0.4138, 0.41028, 0.4061, 0.40338, 0.40214, 0.40156,
-0.40046, 0.396, 0.3874, 0.37716, 0.36648, 0.3613, 0.36078, 0.35796, 0.3532, 0.34212, 0.32718, 0.31256, 0.30478, 0.30308, 0.30972, 0.32184, 0.33206, 0.33586,
-0.33856, 0.34484, 0.35994, 0.3816, 0.40576, 0.42614, 0.44262, 0.45148, 0.45918, 0.46194, 0.4632, 0.46466, 0.46514, 0.46386, 0.46526, 0.46536, 0.46368, 0.4633,
-0.46218, 0.46236, 0.4623, 0.46054, 0.46048, 0.45994, 0.4576, 0.45704, 0.45852, 0.45726, 0.45734, 0.45602, 0.45646, 0.45638, 0.45412, 0.45596, 0.45618, 0.45376,
-0.4555, 0.45264, 0.45236, 0.45228, 0.45376, 0.45442, 0.45326, 0.45054, 0.45092, 
-//1143
-0.13706, 0.16208, 0.20236, 0.25052, 0.29278, 0.31578, 0.3265, 0.32728, 0.32122, 0.31572, 0.3123, 0.31038, 0.30758, 0.30396, 0.2995, 0.29604, 0.29402, 0.29288,
-0.29184, 0.28758, 0.28056, 0.27138, 0.2634, 0.25778, 0.2551, 0.25346, 0.24922, 0.23998, 0.22912, 0.21872, 0.21254, 0.21176, 0.21594, 0.22528, 0.23616, 0.2429,
-0.24672, 0.25368, 0.26432, 0.28048, 0.29748, 0.3141, 0.32674, 0.33508, 0.34272, 0.34564, 0.34746, 0.34836, 0.34826, 0.34828, 0.34724, 0.34646, 0.34508, 0.34518,
-0.34388, 0.34276, 0.3427, 0.34158, 0.3409, 0.34088, 0.33984, 0.33938, 0.3365, 0.33666, 0.33606, 0.33602, 0.33562, 0.33516, 0.33512, 0.3356, 0.3345, 0.33404,
-0.33554, 0.33294, 0.3343, 0.33258, 0.33406, 0.33126, 0.33094, 0.3294, 0.32924, 
-//1144
-0.12256, 0.14162, 0.16938, 0.19594, 0.21464, 0.2228, 0.22376, 0.22146, 0.22, 0.21586, 0.2143, 0.2121, 0.2099, 0.20612, 0.20268, 0.19904, 0.19814, 0.19664,
-0.1943, 0.19084, 0.18578, 0.17922, 0.1747, 0.17138, 0.16964, 0.1682, 0.16502, 0.15824, 0.1503, 0.14336, 0.13822, 0.13758, 0.13998, 0.14474, 0.1499, 0.1525,
-0.1534, 0.15674, 0.16326, 0.17386, 0.18872, 0.2032, 0.21662, 0.22554, 0.23158, 0.23668, 0.23854, 0.24092, 0.24108, 0.24176, 0.24064, 0.24048, 0.23918, 0.2383,
-0.23902, 0.23878, 0.23776, 0.23672, 0.23562, 0.23632, 0.23592, 0.23542, 0.2332, 0.23346, 0.2325, 0.23272, 0.23278, 0.23252, 0.2325, 0.2331, 0.23266, 0.2329,
-0.23314, 0.23182, 0.23092, 0.23048, 0.22826, 0.22892, 0.23002, 0.22778, 0.22746, 
-//1145
-0.10074, 0.11198, 0.1279, 0.14004, 0.14848, 0.1497, 0.1501, 0.14752, 0.1459, 0.14422, 0.14174, 0.13982, 0.13738, 0.13562, 0.1321, 0.13, 0.12874, 0.1278,
-0.12592, 0.1232, 0.11926, 0.11454, 0.1103, 0.10846, 0.10654, 0.10418, 0.10128, 0.09638, 0.0912, 0.08632, 0.08376, 0.08356, 0.08566, 0.08778, 0.09012, 0.09122,
-0.09138, 0.09264, 0.09672, 0.10398, 0.11436, 0.12684, 0.13932, 0.14958, 0.15802, 0.16326, 0.1676, 0.17056, 0.17224, 0.1732, 0.17328, 0.17282, 0.17316, 0.17248,
-0.17214, 0.17166, 0.17162, 0.17024, 0.17044, 0.17078, 0.16996, 0.16926, 0.1691, 0.1697, 0.1692, 0.1691, 0.16916, 0.16884, 0.16886, 0.17014, 0.16972, 0.1702,
-0.1713, 0.17046, 0.17056, 0.17028, 0.1699, 0.17082, 0.17164, 0.17006, 0.17012, 
-//1146
-0.07808, 0.08134, 0.08786, 0.094, 0.0995, 0.0993, 0.09952, 0.0992, 0.09794, 0.09588, 0.09368, 0.09106, 0.08796, 0.08472, 0.08156, 0.07762, 0.07508, 0.0721,
-0.06994, 0.06684, 0.06442, 0.0614, 0.06006, 0.05896, 0.0574, 0.05672, 0.05494, 0.05272, 0.05166, 0.05128, 0.05008, 0.05038, 0.05052, 0.05002, 0.05146, 0.05088,
-0.05036, 0.052, 0.0534, 0.05444, 0.05696, 0.06014, 0.06426, 0.06958, 0.07564, 0.08428, 0.09382, 0.10384, 0.11368, 0.12224, 0.12958, 0.13576, 0.14042, 0.14402,
-0.1467, 0.14954, 0.15134, 0.1517, 0.15448, 0.15492, 0.1555, 0.1549, 0.15658, 0.15782, 0.15898, 0.161, 0.1637, 0.16556, 0.16844, 0.17094, 0.17294, 0.17392,
-0.17654, 0.17774, 0.17948, 0.18046, 0.18248, 0.1837, 0.18494, 0.18426, 0.18812, 
-//1147
-0.06328, 0.06668, 0.0733, 0.07632, 0.07978, 0.0804, 0.0806, 0.08064, 0.07962, 0.07758, 0.07512, 0.0724, 0.07032, 0.06636, 0.06382, 0.06016, 0.05784, 0.05568,
-0.0534, 0.0515, 0.04994, 0.048, 0.04646, 0.0447, 0.04428, 0.04394, 0.04338, 0.04282, 0.04232, 0.04138, 0.04124, 0.041, 0.0406, 0.04132, 0.04186, 0.04114,
-0.0417, 0.04256, 0.04258, 0.04254, 0.04356, 0.04396, 0.04464, 0.0463, 0.04792, 0.05182, 0.0571, 0.06478, 0.07494, 0.0874, 0.09902, 0.11008, 0.12002, 0.12792,
-0.1349, 0.14032, 0.1458, 0.149, 0.153, 0.156, 0.15868, 0.16048, 0.16276, 0.16484, 0.16866, 0.17092, 0.17442, 0.17814, 0.18118, 0.1852, 0.18808, 0.19076,
-0.19322, 0.19618, 0.19746, 0.20002, 0.2013, 0.20422, 0.20642, 0.20656, 0.20668, 
-//1148
-0.1434, 0.17248, 0.22848, 0.30918, 0.40902, 0.50636, 0.5687, 0.59228, 0.59668, 0.59286, 0.58922, 0.58606, 0.58202, 0.57566, 0.56866, 0.56304, 0.56006, 0.55768,
-0.55308, 0.54506, 0.53274, 0.51756, 0.50376, 0.49506, 0.49042, 0.48486, 0.47358, 0.4539, 0.43064, 0.40924, 0.39778, 0.39626, 0.40282, 0.41416, 0.42128, 0.42176,
-0.42266, 0.43234, 0.4569, 0.49406, 0.5381, 0.5842, 0.62816, 0.66216, 0.69092, 0.71158, 0.72844, 0.73996, 0.74634, 0.75338, 0.75778, 0.75934, 0.76098, 0.76248,
-0.7625, 0.76316, 0.7656, 0.7643, 0.76372, 0.7654, 0.76396, 0.76384, 0.7635, 0.76522, 0.76786, 0.76916, 0.76964, 0.76916, 0.76974, 0.77278, 0.7731, 0.77224,
-0.77948, 0.77468, 0.77586, 0.7759, 0.7778, 0.77744, 0.77826, 0.77678, 0.77742, 
-//1149
-0.12364, 0.15132, 0.2015, 0.271, 0.34682, 0.40886, 0.44036, 0.44706, 0.4458, 0.44098, 0.43918, 0.43376, 0.43034, 0.4239, 0.41912, 0.415, 0.41188, 0.40974,
-0.40656, 0.4007, 0.38902, 0.37412, 0.3623, 0.35466, 0.35146, 0.3474, 0.3374, 0.3216, 0.3004, 0.28392, 0.27334, 0.27084, 0.27828, 0.28874, 0.29616, 0.29674,
-0.2966, 0.30484, 0.32564, 0.35864, 0.39696, 0.43586, 0.46924, 0.49466, 0.51192, 0.52356, 0.53194, 0.53694, 0.5397, 0.54166, 0.54328, 0.54318, 0.5424, 0.54448,
-0.5439, 0.54282, 0.54274, 0.54298, 0.54122, 0.5429, 0.5393, 0.5398, 0.5385, 0.53978, 0.53944, 0.53934, 0.53836, 0.5387, 0.54092, 0.53992, 0.53872, 0.53956,
-0.5402, 0.53816, 0.5375, 0.53552, 0.53812, 0.53698, 0.53616, 0.53474, 0.53746, 
-//1150
-0.13054, 0.1547, 0.1978, 0.25176, 0.30066, 0.32962, 0.33988, 0.3405, 0.33714, 0.33196, 0.32806, 0.3248, 0.32084, 0.31562, 0.30962, 0.30368, 0.3009, 0.29832,
-0.29366, 0.2886, 0.27732, 0.26572, 0.25578, 0.24866, 0.24454, 0.24156, 0.23348, 0.22178, 0.20758, 0.19562, 0.18794, 0.1869, 0.1906, 0.19896, 0.20642, 0.2107,
-0.21292, 0.21854, 0.23218, 0.25374, 0.28224, 0.31122, 0.33896, 0.36022, 0.37484, 0.38542, 0.39158, 0.39602, 0.39914, 0.4002, 0.40206, 0.40162, 0.40156, 0.4019,
-0.40162, 0.40014, 0.40026, 0.39888, 0.39844, 0.39876, 0.398, 0.39554, 0.39556, 0.39498, 0.3953, 0.39482, 0.39514, 0.395, 0.39384, 0.3947, 0.39398, 0.39324,
-0.39278, 0.39334, 0.39176, 0.39096, 0.391, 0.38898, 0.3878, 0.38736, 0.38672, 
-//1151
-0.11748, 0.13968, 0.17032, 0.20412, 0.22976, 0.24136, 0.24496, 0.24422, 0.24064, 0.2365, 0.23374, 0.23126, 0.22714, 0.22308, 0.2188, 0.2138, 0.21128, 0.2091,
-0.20528, 0.19982, 0.19238, 0.18428, 0.17668, 0.17054, 0.16782, 0.16382, 0.15764, 0.14814, 0.13898, 0.1298, 0.1242, 0.12312, 0.12448, 0.1281, 0.1321, 0.13238,
-0.13124, 0.13456, 0.1417, 0.15604, 0.17696, 0.2004, 0.22476, 0.2463, 0.26386, 0.2774, 0.28766, 0.29418, 0.29758, 0.30044, 0.30132, 0.30172, 0.30222, 0.30298,
-0.30264, 0.3015, 0.3015, 0.30104, 0.30098, 0.3009, 0.3005, 0.30102, 0.2987, 0.30094, 0.30028, 0.30034, 0.30026, 0.2997, 0.29816, 0.29984, 0.29942, 0.29862,
-0.29948, 0.29756, 0.299, 0.29912, 0.29796, 0.29952, 0.29788, 0.29828, 0.29854, 
-//1152
-0.10598, 0.1187, 0.1374, 0.15552, 0.16588, 0.17058, 0.16976, 0.16842, 0.1665, 0.16342, 0.16144, 0.1582, 0.15446, 0.14984, 0.14534, 0.14162, 0.13866, 0.1349,
-0.13106, 0.1268, 0.12058, 0.11422, 0.11024, 0.10644, 0.10414, 0.10184, 0.09788, 0.09156, 0.08566, 0.08202, 0.07862, 0.07828, 0.07908, 0.0817, 0.08302, 0.08168,
-0.08066, 0.08318, 0.08648, 0.09346, 0.10652, 0.12134, 0.1407, 0.15858, 0.17376, 0.1883, 0.19906, 0.20522, 0.20922, 0.21366, 0.21644, 0.2169, 0.21802, 0.21806,
-0.21856, 0.21736, 0.21802, 0.21636, 0.21672, 0.21754, 0.21674, 0.2158, 0.21474, 0.2147, 0.21454, 0.21548, 0.2157, 0.2156, 0.21616, 0.2157, 0.21706, 0.2168,
-0.2167, 0.21764, 0.217, 0.2162, 0.21742, 0.21634, 0.21658, 0.21644, 0.21496, 
-//1153
-0.08336, 0.08956, 0.09862, 0.11004, 0.1173, 0.11832, 0.11954, 0.1179, 0.11542, 0.11288, 0.10896, 0.10448, 0.1008, 0.0952, 0.0898, 0.0851, 0.0812, 0.07742,
-0.07356, 0.0702, 0.06726, 0.06384, 0.0606, 0.05874, 0.05792, 0.0563, 0.0546, 0.0532, 0.05038, 0.04946, 0.04822, 0.04768, 0.0487, 0.04874, 0.04864, 0.04808,
-0.04776, 0.04906, 0.04998, 0.05296, 0.05734, 0.06376, 0.07398, 0.08586, 0.09574, 0.10674, 0.1169, 0.1258, 0.13352, 0.1404, 0.14742, 0.15338, 0.159, 0.16368,
-0.16718, 0.16944, 0.17112, 0.17038, 0.16918, 0.1668, 0.16336, 0.16116, 0.16102, 0.16252, 0.16474, 0.16786, 0.17238, 0.17636, 0.18138, 0.18602, 0.19142, 0.1978,
-0.20558, 0.21158, 0.21782, 0.22366, 0.22788, 0.23184, 0.2363, 0.23932, 0.24492, 
-//1154
-0.12808, 0.15874, 0.20908, 0.28524, 0.37086, 0.44252, 0.4812, 0.49194, 0.49102, 0.48518, 0.4813, 0.4762, 0.46938, 0.46166, 0.4539, 0.44776, 0.4426, 0.43848,
-0.43372, 0.42202, 0.40742, 0.39012, 0.37452, 0.3646, 0.3585, 0.3513, 0.33802, 0.317, 0.2944, 0.27436, 0.263, 0.26038, 0.26682, 0.2761, 0.28026, 0.27882,
-0.27794, 0.2872, 0.30838, 0.34678, 0.3942, 0.44698, 0.49826, 0.5408, 0.57726, 0.60326, 0.62398, 0.63862, 0.64756, 0.65448, 0.66102, 0.66378, 0.6661, 0.66958,
-0.67064, 0.67106, 0.67246, 0.67464, 0.67338, 0.67362, 0.67312, 0.67478, 0.67354, 0.67252, 0.67324, 0.67538, 0.67556, 0.67572, 0.67784, 0.67864, 0.6807, 0.6812,
-0.68086, 0.6803, 0.68318, 0.68178, 0.68244, 0.6833, 0.6858, 0.68282, 0.68352, 
-//1155
-0.12114, 0.14692, 0.1926, 0.25036, 0.30572, 0.34168, 0.35524, 0.35786, 0.35358, 0.3472, 0.34264, 0.33914, 0.33586, 0.3276, 0.32036, 0.31414, 0.30952, 0.30612,
-0.30154, 0.2923, 0.28122, 0.26772, 0.25588, 0.24788, 0.24296, 0.23704, 0.22654, 0.21074, 0.19332, 0.1779, 0.16972, 0.16902, 0.17322, 0.17904, 0.18376, 0.18218,
-0.18106, 0.18776, 0.20424, 0.2341, 0.27158, 0.31466, 0.35558, 0.38756, 0.4142, 0.43318, 0.44638, 0.45652, 0.45938, 0.46508, 0.46596, 0.46712, 0.46678, 0.468,
-0.46682, 0.46728, 0.46778, 0.46722, 0.46772, 0.4693, 0.4675, 0.4678, 0.467, 0.4674, 0.46784, 0.46682, 0.4669, 0.4687, 0.46786, 0.4681, 0.46798, 0.46738,
-0.46918, 0.46716, 0.46756, 0.46724, 0.46674, 0.4666, 0.4675, 0.46562, 0.4659, 
-//1156
-0.12442, 0.14664, 0.18258, 0.22118, 0.25056, 0.26528, 0.26948, 0.2685, 0.26474, 0.2586, 0.2538, 0.24922, 0.24414, 0.2371, 0.23158, 0.2252, 0.2201, 0.21612,
-0.21066, 0.20294, 0.19306, 0.18222, 0.174, 0.16754, 0.163, 0.159, 0.15234, 0.14174, 0.1304, 0.12066, 0.11528, 0.11386, 0.1156, 0.11838, 0.12128, 0.12134,
-0.12026, 0.12218, 0.1308, 0.14504, 0.16748, 0.20046, 0.23512, 0.26768, 0.29912, 0.32654, 0.34944, 0.36542, 0.3765, 0.38566, 0.3911, 0.39282, 0.3957, 0.39648,
-0.39748, 0.3979, 0.399, 0.3981, 0.39712, 0.39802, 0.39756, 0.39764, 0.39684, 0.39416, 0.39326, 0.39384, 0.39488, 0.39428, 0.39356, 0.39542, 0.39542, 0.39476,
-0.39606, 0.3931, 0.39454, 0.39362, 0.39542, 0.39368, 0.39346, 0.39168, 0.39242, 
-//1157
-0.10092, 0.11456, 0.13598, 0.15676, 0.17068, 0.17628, 0.17706, 0.17692, 0.17602, 0.1713, 0.16798, 0.16312, 0.15806, 0.15106, 0.14508, 0.13954, 0.13474, 0.12974,
-0.1252, 0.11918, 0.1123, 0.10402, 0.099, 0.09532, 0.09208, 0.08854, 0.08386, 0.07788, 0.07222, 0.06754, 0.06498, 0.06376, 0.0639, 0.06514, 0.06562, 0.06538,
-0.0638, 0.06518, 0.06918, 0.07542, 0.087, 0.10312, 0.12438, 0.14812, 0.17154, 0.19382, 0.21246, 0.22712, 0.23768, 0.2453, 0.25036, 0.25384, 0.2562, 0.25726,
-0.259, 0.25956, 0.25848, 0.25852, 0.25896, 0.25868, 0.25764, 0.25738, 0.25794, 0.25776, 0.25772, 0.2581, 0.25922, 0.25932, 0.26024, 0.26102, 0.26118, 0.26126,
-0.26188, 0.2593, 0.26092, 0.26084, 0.2616, 0.2611, 0.2617, 0.25894, 0.25894, 
-//1158
-0.12486, 0.15352, 0.20712, 0.28274, 0.37388, 0.44984, 0.4933, 0.50588, 0.50578, 0.50096, 0.49554, 0.48912, 0.4829, 0.47404, 0.46426, 0.45506, 0.44822, 0.44076,
-0.4317, 0.41874, 0.40138, 0.3831, 0.36726, 0.3561, 0.34828, 0.33928, 0.32436, 0.3027, 0.27994, 0.26004, 0.24846, 0.24572, 0.25162, 0.25916, 0.26296, 0.2599,
-0.2569, 0.2635, 0.28622, 0.32468, 0.37348, 0.4314, 0.4873, 0.53866, 0.58334, 0.6199, 0.64984, 0.67272, 0.69068, 0.70584, 0.71752, 0.72358, 0.72896, 0.73452,
-0.73772, 0.73906, 0.7429, 0.74246, 0.7435, 0.74504, 0.74662, 0.7489, 0.75018, 0.75204, 0.75428, 0.7558, 0.75974, 0.75954, 0.76238, 0.7655, 0.76706, 0.7712,
-0.7727, 0.77164, 0.77426, 0.77478, 0.7765, 0.7798, 0.78202, 0.78132, 0.78264, 
-//1159
-0.12184, 0.149, 0.19642, 0.26044, 0.32532, 0.36648, 0.38516, 0.3885, 0.3863, 0.37976, 0.37492, 0.36868, 0.36184, 0.35194, 0.34446, 0.33508, 0.3273, 0.32062,
-0.31378, 0.2994, 0.28488, 0.26896, 0.25552, 0.24766, 0.23992, 0.23192, 0.2202, 0.20238, 0.1843, 0.16848, 0.15986, 0.15768, 0.1624, 0.16762, 0.17002, 0.16776,
-0.1647, 0.17084, 0.18692, 0.21696, 0.25952, 0.30982, 0.3614, 0.4091, 0.44948, 0.48344, 0.50856, 0.52754, 0.54182, 0.55126, 0.5599, 0.56584, 0.5686, 0.57222,
-0.57456, 0.57534, 0.57624, 0.57744, 0.57938, 0.57986, 0.57912, 0.57962, 0.57998, 0.5808, 0.58134, 0.58236, 0.58324, 0.58458, 0.58438, 0.5868, 0.5871, 0.58686,
-0.58924, 0.58724, 0.58698, 0.58704, 0.58984, 0.59044, 0.5906, 0.58796, 0.58862, 
-//1160
-0.12662, 0.14968, 0.18896, 0.23346, 0.27032, 0.29136, 0.29602, 0.29614, 0.29298, 0.28758, 0.28316, 0.27706, 0.26896, 0.2589, 0.25138, 0.24192, 0.23542, 0.2286,
-0.21942, 0.20938, 0.19708, 0.18458, 0.17418, 0.16606, 0.16024, 0.15264, 0.14296, 0.12976, 0.11776, 0.10678, 0.101, 0.09974, 0.102, 0.10544, 0.10628, 0.10328,
-0.10206, 0.10462, 0.1143, 0.13352, 0.1643, 0.20256, 0.2461, 0.28736, 0.32448, 0.3552, 0.37942, 0.39714, 0.40982, 0.41978, 0.4261, 0.43102, 0.43442, 0.43492,
-0.4364, 0.43796, 0.43928, 0.43848, 0.43878, 0.44078, 0.4403, 0.43984, 0.43864, 0.44002, 0.44174, 0.4417, 0.44206, 0.44348, 0.44238, 0.4449, 0.44434, 0.44422,
-0.44752, 0.4454, 0.44894, 0.44858, 0.44914, 0.44912, 0.45016, 0.4485, 0.44696, 
-//1161
-0.10348, 0.12078, 0.14766, 0.17816, 0.20048, 0.21014, 0.21318, 0.21312, 0.21014, 0.20608, 0.19962, 0.19302, 0.18538, 0.17656, 0.16776, 0.15812, 0.15012,
-0.14232, 0.13392, 0.12516, 0.11486, 0.10696, 0.1001, 0.09364, 0.09016, 0.08506, 0.08012, 0.0732, 0.06836, 0.06242, 0.06132, 0.0613, 0.06198, 0.06306, 0.06364,
-0.06254, 0.06144, 0.06248, 0.06586, 0.074, 0.08366, 0.09696, 0.11322, 0.13096, 0.15302, 0.18006, 0.20974, 0.24326, 0.2744, 0.3039, 0.32934, 0.35034, 0.366,
-0.38178, 0.3943, 0.40276, 0.41144, 0.4168, 0.4219, 0.42538, 0.42626, 0.43068, 0.4339, 0.43964, 0.44548, 0.45062, 0.45664, 0.46242, 0.46642, 0.47208, 0.47894,
-0.48578, 0.49274, 0.49578, 0.50282, 0.51018, 0.51692, 0.52226, 0.52694, 0.52668, 0.53098, 
-//1162
-0.1179, 0.14564, 0.19324, 0.25816, 0.32506, 0.37258, 0.39624, 0.40152, 0.39756, 0.38912, 0.38214, 0.37354, 0.36598, 0.35572, 0.34556, 0.33634, 0.32638, 0.31804,
-0.30678, 0.29274, 0.27728, 0.26048, 0.24726, 0.23602, 0.2288, 0.21904, 0.20372, 0.1853, 0.16624, 0.1519, 0.14322, 0.1415, 0.14542, 0.14936, 0.15016, 0.14654,
-0.14322, 0.15094, 0.16758, 0.1954, 0.23748, 0.28846, 0.3433, 0.3995, 0.44888, 0.49444, 0.53126, 0.5621, 0.58596, 0.60618, 0.62156, 0.63326, 0.64226, 0.64868,
-0.6555, 0.66022, 0.66572, 0.66696, 0.66942, 0.67098, 0.67224, 0.67414, 0.67744, 0.68882, 0.69322, 0.69742, 0.7016, 0.70574, 0.70872, 0.71516, 0.71958, 0.72158,
-0.7261, 0.72458, 0.72868, 0.7339, 0.73772, 0.73726, 0.73878, 0.73774, 0.74288, 
-//1163
-0.12276, 0.14898, 0.191, 0.24224, 0.28842, 0.31646, 0.32616, 0.32938, 0.32902, 0.32278, 0.31352, 0.30192, 0.29104, 0.27682, 0.26328, 0.25046, 0.23848, 0.22792,
-0.21632, 0.2038, 0.19088, 0.1782, 0.16742, 0.1595, 0.15392, 0.14544, 0.1373, 0.12568, 0.1144, 0.10478, 0.09936, 0.09926, 0.10012, 0.0984, 0.0993, 0.09608,
-0.09424, 0.0982, 0.10388, 0.11616, 0.13558, 0.1625, 0.19622, 0.23508, 0.27942, 0.3263, 0.37512, 0.42088, 0.46112, 0.499, 0.53084, 0.55506, 0.57556, 0.59226,
-0.6075, 0.61548, 0.62348, 0.62912, 0.63314, 0.63692, 0.63584, 0.63906, 0.64284, 0.64458, 0.65116, 0.65544, 0.66158, 0.66498, 0.6713, 0.67458, 0.67988, 0.68318,
-0.6872, 0.68732, 0.69078, 0.69238, 0.69374, 0.6949, 0.7003, 0.70078, 0.70234, 
-//1164
-0.14682, 0.1778, 0.23478, 0.31856, 0.43118, 0.54706, 0.63702, 0.68262, 0.69756, 0.70176, 0.70388, 0.7056, 0.70566, 0.7035, 0.70246, 0.70162, 0.70338, 0.70434,
-0.70556, 0.70436, 0.7012, 0.6967, 0.69146, 0.68906, 0.69012, 0.69038, 0.68902, 0.68442, 0.67778, 0.67074, 0.66656, 0.66608, 0.66876, 0.67624, 0.6874, 0.6951,
-0.69944, 0.70402, 0.7089, 0.71564, 0.72062, 0.72568, 0.72966, 0.72988, 0.73266, 0.73224, 0.73212, 0.73228, 0.7311, 0.73118, 0.73186, 0.7306, 0.7292, 0.73018,
-0.72984, 0.729, 0.72822, 0.72882, 0.7262, 0.72728, 0.7275, 0.72654, 0.72516, 0.72554, 0.72634, 0.72414, 0.72574, 0.72468, 0.72472, 0.72712, 0.72778, 0.72604,
-0.7285, 0.72514, 0.7263, 0.72522, 0.72432, 0.72464, 0.72382, 0.72514, 0.72682, 
-//1165
-0.1307, 0.16196, 0.21834, 0.29808, 0.38622, 0.45958, 0.49932, 0.51286, 0.51586, 0.51698, 0.51606, 0.51804, 0.51886, 0.51634, 0.51584, 0.51486, 0.51538, 0.51636,
-0.5184, 0.5184, 0.5156, 0.51042, 0.50736, 0.50686, 0.5065, 0.50382, 0.50212, 0.4988, 0.49248, 0.4864, 0.48236, 0.4811, 0.48458, 0.49254, 0.50218, 0.509,
-0.51354, 0.51706, 0.52042, 0.52606, 0.52974, 0.53254, 0.53368, 0.53394, 0.53616, 0.53568, 0.53496, 0.53468, 0.53344, 0.53312, 0.53294, 0.53208, 0.53092,
-0.53154, 0.53142, 0.53032, 0.53124, 0.53018, 0.52944, 0.52858, 0.52754, 0.52664, 0.52654, 0.52676, 0.52678, 0.52576, 0.52742, 0.52594, 0.52606, 0.52816, 0.5277,
-0.52706, 0.52874, 0.52862, 0.52942, 0.5312, 0.52726, 0.5271, 0.52804, 0.52706, 0.52566, 
-//1166
-0.1299, 0.159, 0.20992, 0.27444, 0.33738, 0.38192, 0.40062, 0.40466, 0.40554, 0.4003, 0.399, 0.39796, 0.39784, 0.39192, 0.38726, 0.38682, 0.38622, 0.38514,
-0.38524, 0.38466, 0.38146, 0.37748, 0.37202, 0.3694, 0.36954, 0.3705, 0.37178, 0.36946, 0.36352, 0.3569, 0.3528, 0.3523, 0.3549, 0.3638, 0.37728, 0.38788,
-0.39488, 0.4, 0.40196, 0.40204, 0.40244, 0.40214, 0.40384, 0.4017, 0.39988, 0.39974, 0.3994, 0.39804, 0.39638, 0.39588, 0.39426, 0.39392, 0.39168, 0.39298,
-0.39148, 0.38946, 0.38974, 0.38846, 0.38804, 0.38732, 0.38584, 0.38576, 0.37986, 0.37784, 0.37746, 0.37842, 0.37834, 0.37808, 0.37614, 0.37696, 0.37872,
-0.37618, 0.37784, 0.37536, 0.374, 0.37552, 0.37566, 0.37336, 0.37528, 0.37382, 0.37386, 
-//1167
-0.1191, 0.14264, 0.17804, 0.2191, 0.25392, 0.26968, 0.2759, 0.27654, 0.27646, 0.27466, 0.27516, 0.27374, 0.27252, 0.27078, 0.2694, 0.26722, 0.26598, 0.26498,
-0.26326, 0.26206, 0.26018, 0.2565, 0.25612, 0.25432, 0.25296, 0.25212, 0.25246, 0.25064, 0.24922, 0.24876, 0.24738, 0.24692, 0.24826, 0.24972, 0.25364, 0.2588,
-0.26498, 0.27108, 0.27542, 0.27908, 0.2803, 0.28088, 0.28178, 0.28224, 0.28052, 0.28046, 0.27886, 0.2775, 0.27662, 0.27582, 0.27552, 0.27552, 0.27408, 0.27352,
-0.2739, 0.27138, 0.27138, 0.26976, 0.2707, 0.27044, 0.269, 0.26982, 0.2683, 0.26718, 0.26638, 0.26498, 0.26492, 0.26562, 0.26596, 0.2671, 0.26554, 0.2657,
-0.26532, 0.26452, 0.26446, 0.265, 0.26362, 0.26258, 0.26228, 0.26382, 0.2623, 
-//1168
-0.11212, 0.1297, 0.15298, 0.17424, 0.18762, 0.19324, 0.19414, 0.19388, 0.19084, 0.18922, 0.18712, 0.18658, 0.18588, 0.18372, 0.18208, 0.18066, 0.18068, 0.18022,
-0.18026, 0.17986, 0.17814, 0.1766, 0.1729, 0.17158, 0.17154, 0.17166, 0.17216, 0.1703, 0.16736, 0.16388, 0.16168, 0.16118, 0.16328, 0.1684, 0.1764, 0.18214,
-0.18692, 0.1901, 0.19152, 0.19294, 0.19324, 0.19396, 0.19478, 0.1941, 0.19482, 0.19382, 0.1931, 0.1919, 0.19028, 0.1901, 0.18904, 0.18798, 0.18678, 0.1863,
-0.18624, 0.18564, 0.18544, 0.18388, 0.18382, 0.18408, 0.18344, 0.18348, 0.18232, 0.18284, 0.18266, 0.18262, 0.18256, 0.18154, 0.18154, 0.18164, 0.18132, 0.1812,
-0.18194, 0.17948, 0.18176, 0.18164, 0.18244, 0.1818, 0.18178, 0.1799, 0.18082, 
-//1169
-0.08458, 0.09282, 0.10344, 0.11214, 0.11528, 0.11754, 0.1173, 0.11652, 0.11476, 0.11306, 0.11274, 0.11128, 0.1101, 0.10894, 0.10714, 0.10646, 0.10606, 0.1063,
-0.10604, 0.10566, 0.10428, 0.10252, 0.10124, 0.10024, 0.10066, 0.0994, 0.09972, 0.09746, 0.09538, 0.0929, 0.09178, 0.09164, 0.09478, 0.09788, 0.10178, 0.10462,
-0.10704, 0.10808, 0.10948, 0.11174, 0.11306, 0.11392, 0.1161, 0.11594, 0.11674, 0.11602, 0.11652, 0.1163, 0.11652, 0.11678, 0.11654, 0.11658, 0.11622, 0.11622,
-0.11526, 0.11494, 0.1138, 0.1125, 0.1135, 0.11344, 0.1131, 0.11256, 0.11276, 0.1132, 0.11278, 0.1126, 0.11358, 0.1148, 0.11506, 0.11506, 0.1166, 0.1168,
-0.11644, 0.1165, 0.11784, 0.1177, 0.11698, 0.11768, 0.11746, 0.11764, 0.11822, 
-//1170
-0.06688, 0.07026, 0.07296, 0.07332, 0.0738, 0.0733, 0.07182, 0.07142, 0.0718, 0.07016, 0.07, 0.06938, 0.06974, 0.06912, 0.06804, 0.06828, 0.06804, 0.06776,
-0.06644, 0.06666, 0.06536, 0.06414, 0.06318, 0.06304, 0.06232, 0.06204, 0.06106, 0.05982, 0.05882, 0.0581, 0.05854, 0.05968, 0.06142, 0.06236, 0.06424, 0.06532,
-0.06488, 0.06562, 0.06576, 0.06666, 0.06858, 0.07086, 0.07222, 0.07264, 0.0736, 0.07302, 0.07374, 0.07382, 0.07414, 0.07454, 0.07242, 0.0739, 0.07414, 0.07348,
-0.07314, 0.07262, 0.07306, 0.0716, 0.07196, 0.073, 0.0726, 0.07252, 0.0716, 0.07336, 0.07214, 0.07264, 0.07368, 0.0745, 0.07486, 0.07528, 0.07648, 0.07586,
-0.07696, 0.0774, 0.07766, 0.07762, 0.07762, 0.07766, 0.0778, 0.07972, 0.0777, 
-//1171
-0.05306, 0.05336, 0.05494, 0.05728, 0.0568, 0.0566, 0.05642, 0.05568, 0.05544, 0.05494, 0.05546, 0.05376, 0.0547, 0.05402, 0.0522, 0.05206, 0.05162, 0.05126,
-0.05164, 0.05062, 0.04998, 0.05014, 0.04984, 0.04878, 0.0482, 0.04796, 0.04758, 0.04648, 0.046, 0.04558, 0.04538, 0.0456, 0.0447, 0.04466, 0.0448, 0.04494,
-0.0446, 0.04652, 0.04844, 0.05056, 0.0532, 0.05502, 0.05682, 0.0567, 0.05722, 0.05762, 0.05656, 0.0567, 0.05674, 0.0569, 0.05674, 0.0559, 0.05602, 0.05594,
-0.05564, 0.05508, 0.05462, 0.05328, 0.05428, 0.05512, 0.05518, 0.05494, 0.05536, 0.05624, 0.05658, 0.05722, 0.05806, 0.05792, 0.05884, 0.05974, 0.06008, 0.0602,
-0.06094, 0.05974, 0.06082, 0.0617, 0.0619, 0.06094, 0.0617, 0.06178, 0.0622, 
-//1172
-0.14028, 0.17236, 0.22746, 0.31104, 0.41854, 0.53296, 0.6251, 0.67422, 0.69176, 0.69326, 0.69568, 0.69652, 0.69488, 0.69254, 0.69142, 0.68832, 0.69054, 0.68976,
-0.68916, 0.68804, 0.68382, 0.67604, 0.67076, 0.66904, 0.6708, 0.6727, 0.67268, 0.66874, 0.66278, 0.6538, 0.64602, 0.64176, 0.64608, 0.65592, 0.66764, 0.67732,
-0.68384, 0.68978, 0.69674, 0.70706, 0.7183, 0.72744, 0.73764, 0.74282, 0.74666, 0.7495, 0.75124, 0.752, 0.75146, 0.75032, 0.75168, 0.75218, 0.75034, 0.7504,
-0.7498, 0.75014, 0.75172, 0.74956, 0.75052, 0.75034, 0.75284, 0.75362, 0.75144, 0.75232, 0.75336, 0.7519, 0.7543, 0.75702, 0.75566, 0.75782, 0.75578, 0.75662,
-0.75964, 0.75652, 0.7603, 0.7602, 0.76168, 0.76202, 0.76346, 0.7598, 0.76264, 
-//1173
-0.14344, 0.17374, 0.22792, 0.30706, 0.39406, 0.4728, 0.5172, 0.53156, 0.53354, 0.53202, 0.5296, 0.52692, 0.52628, 0.5219, 0.51938, 0.51746, 0.51722, 0.51762,
-0.51744, 0.51652, 0.5096, 0.50274, 0.4959, 0.4907, 0.49174, 0.49334, 0.49258, 0.48764, 0.47876, 0.4704, 0.46398, 0.46132, 0.46642, 0.47868, 0.49722, 0.51342,
-0.52196, 0.5326, 0.53656, 0.54146, 0.54798, 0.5527, 0.55618, 0.55694, 0.55872, 0.55762, 0.55734, 0.55802, 0.55624, 0.55522, 0.55446, 0.55208, 0.55314, 0.55202,
-0.55012, 0.55016, 0.54934, 0.54734, 0.54498, 0.54532, 0.5441, 0.5431, 0.54098, 0.54182, 0.5424, 0.54212, 0.54072, 0.5403, 0.53834, 0.53908, 0.54026, 0.53782,
-0.54082, 0.53576, 0.53558, 0.53676, 0.53522, 0.53362, 0.53594, 0.5338, 0.53244, 
-//1174
-0.11992, 0.15066, 0.19688, 0.25888, 0.3195, 0.36082, 0.38048, 0.38428, 0.3817, 0.37956, 0.37548, 0.37302, 0.37218, 0.36738, 0.36494, 0.36308, 0.3637, 0.3641,
-0.36374, 0.36234, 0.35814, 0.35118, 0.34416, 0.34174, 0.3427, 0.34354, 0.34348, 0.33818, 0.3305, 0.32124, 0.31528, 0.3154, 0.32182, 0.33274, 0.3463, 0.3556,
-0.3629, 0.37082, 0.37628, 0.38352, 0.39092, 0.39414, 0.39872, 0.39992, 0.40116, 0.39912, 0.3992, 0.39826, 0.39666, 0.39582, 0.3962, 0.39592, 0.3933, 0.39214,
-0.39182, 0.3909, 0.39116, 0.39054, 0.38962, 0.3903, 0.38854, 0.38816, 0.38678, 0.3867, 0.38604, 0.38536, 0.38478, 0.38376, 0.38342, 0.3832, 0.38396, 0.38284,
-0.38448, 0.38234, 0.38332, 0.38666, 0.38588, 0.38238, 0.38602, 0.38238, 0.38172, 
-//1175
-0.11036, 0.1357, 0.17472, 0.21812, 0.25268, 0.26998, 0.2752, 0.27454, 0.27042, 0.26716, 0.2654, 0.2631, 0.261, 0.26002, 0.25626, 0.25594, 0.25602, 0.25724,
-0.2583, 0.25546, 0.25064, 0.24288, 0.23624, 0.23262, 0.23234, 0.23406, 0.23502, 0.22926, 0.2224, 0.21584, 0.21062, 0.21122, 0.2163, 0.22642, 0.23918, 0.24758,
-0.25142, 0.25618, 0.26236, 0.27078, 0.28038, 0.28762, 0.29122, 0.2925, 0.29284, 0.29156, 0.29056, 0.2897, 0.2873, 0.28678, 0.28574, 0.28442, 0.28284, 0.28224,
-0.2806, 0.27854, 0.27788, 0.27514, 0.27396, 0.2733, 0.27142, 0.27038, 0.26926, 0.26802, 0.26754, 0.26664, 0.26626, 0.26458, 0.26376, 0.26312, 0.2636, 0.26172,
-0.2634, 0.26168, 0.2596, 0.26072, 0.2592, 0.25756, 0.25736, 0.25638, 0.2552, 
-//1176
-0.11596, 0.13086, 0.1558, 0.17744, 0.19358, 0.19998, 0.2009, 0.19782, 0.19616, 0.19194, 0.18972, 0.18762, 0.18634, 0.18348, 0.18168, 0.17852, 0.17816, 0.17846,
-0.17758, 0.17688, 0.17336, 0.1687, 0.16632, 0.16412, 0.16402, 0.16534, 0.16428, 0.16036, 0.156, 0.14974, 0.14682, 0.14662, 0.14998, 0.1559, 0.1634, 0.16926,
-0.17374, 0.17676, 0.18082, 0.18654, 0.19156, 0.19608, 0.19862, 0.20046, 0.20186, 0.2024, 0.20324, 0.20168, 0.20218, 0.20162, 0.20126, 0.20122, 0.20014, 0.19808,
-0.19784, 0.19612, 0.19462, 0.19382, 0.19316, 0.19352, 0.19262, 0.19106, 0.19284, 0.19296, 0.1943, 0.19338, 0.1939, 0.1929, 0.192, 0.1927, 0.19202, 0.19206,
-0.19272, 0.19176, 0.19168, 0.1906, 0.19028, 0.1907, 0.19, 0.18976, 0.19086, 
-//1177
-0.09104, 0.10164, 0.11224, 0.12226, 0.12556, 0.1269, 0.12624, 0.12482, 0.12392, 0.12206, 0.12202, 0.12054, 0.11928, 0.11824, 0.11624, 0.1146, 0.1133, 0.11238,
-0.1134, 0.11072, 0.108, 0.10442, 0.10168, 0.09968, 0.10022, 0.10048, 0.09892, 0.09602, 0.09324, 0.08952, 0.0892, 0.09092, 0.0933, 0.09744, 0.10174, 0.10458,
-0.1069, 0.10842, 0.11186, 0.1164, 0.12222, 0.12708, 0.132, 0.13364, 0.13542, 0.13586, 0.13476, 0.1357, 0.13428, 0.13462, 0.13434, 0.13288, 0.1329, 0.13236,
-0.13212, 0.13122, 0.13082, 0.12962, 0.12954, 0.13226, 0.13216, 0.1307, 0.13148, 0.13078, 0.131, 0.13026, 0.13154, 0.1316, 0.13176, 0.13224, 0.13338, 0.13352,
-0.13406, 0.1335, 0.13362, 0.13482, 0.13464, 0.13404, 0.13424, 0.13252, 0.1332, 
-//1178
-0.06528, 0.067, 0.07076, 0.07184, 0.07586, 0.07524, 0.07504, 0.07566, 0.07418, 0.07242, 0.07284, 0.0713, 0.07016, 0.06918, 0.06774, 0.0658, 0.06576, 0.06396,
-0.06418, 0.06276, 0.06106, 0.05972, 0.0579, 0.05802, 0.05806, 0.05734, 0.05636, 0.05472, 0.0535, 0.0521, 0.05192, 0.0522, 0.05358, 0.05376, 0.05434, 0.05364,
-0.05262, 0.05386, 0.05682, 0.05964, 0.06544, 0.07174, 0.07606, 0.08002, 0.0825, 0.08466, 0.08566, 0.0866, 0.0872, 0.0859, 0.0872, 0.08692, 0.0857, 0.08524,
-0.08482, 0.08418, 0.0835, 0.08274, 0.0831, 0.08534, 0.08588, 0.0853, 0.08572, 0.08522, 0.08622, 0.08584, 0.0857, 0.0873, 0.08838, 0.08852, 0.0897, 0.09006,
-0.0909, 0.0918, 0.09266, 0.09172, 0.09206, 0.09232, 0.09298, 0.092, 0.09322, 
-//1179
-0.0598, 0.05782, 0.05946, 0.05948, 0.05906, 0.05968, 0.0602, 0.05838, 0.0581, 0.05698, 0.05584, 0.05508, 0.0545, 0.05332, 0.05328, 0.05096, 0.05002, 0.0484,
-0.04858, 0.04772, 0.04654, 0.04618, 0.04584, 0.04526, 0.0453, 0.0446, 0.04442, 0.04378, 0.04336, 0.04212, 0.04212, 0.04214, 0.04168, 0.0431, 0.04282, 0.04336,
-0.04418, 0.04524, 0.04548, 0.04598, 0.04552, 0.04614, 0.04642, 0.04698, 0.04844, 0.04928, 0.05234, 0.05624, 0.06336, 0.0706, 0.0786, 0.08568, 0.08998, 0.09408,
-0.09904, 0.1018, 0.10392, 0.10386, 0.10818, 0.11138, 0.11254, 0.11386, 0.1179, 0.1204, 0.12218, 0.12304, 0.12528, 0.1275, 0.1278, 0.13214, 0.13172, 0.13288,
-0.13556, 0.13638, 0.13846, 0.14, 0.14206, 0.14394, 0.1447, 0.14536, 0.1482, 
-//1180
-0.1372, 0.16906, 0.2219, 0.30048, 0.39254, 0.4767, 0.52776, 0.54416, 0.54654, 0.54088, 0.5388, 0.53496, 0.53252, 0.5283, 0.52356, 0.5203, 0.51956, 0.51876,
-0.51812, 0.51542, 0.50608, 0.49422, 0.48406, 0.47696, 0.47582, 0.47388, 0.469, 0.45766, 0.44288, 0.42924, 0.4195, 0.41704, 0.4249, 0.44054, 0.4604, 0.47648,
-0.4874, 0.49994, 0.51634, 0.5389, 0.56354, 0.58772, 0.60794, 0.6213, 0.63106, 0.63674, 0.64046, 0.64322, 0.64328, 0.64314, 0.6456, 0.64516, 0.6422, 0.64238,
-0.64194, 0.64012, 0.64182, 0.6408, 0.6396, 0.64134, 0.63916, 0.63894, 0.63932, 0.6406, 0.63826, 0.63876, 0.6372, 0.63662, 0.63706, 0.63828, 0.63932, 0.63782,
-0.6372, 0.6369, 0.63822, 0.63418, 0.63486, 0.63436, 0.63446, 0.63434, 0.6345, 
-//1181
-0.13328, 0.1614, 0.21168, 0.27436, 0.33806, 0.3824, 0.4012, 0.40322, 0.39888, 0.39186, 0.3898, 0.38572, 0.3834, 0.37892, 0.3743, 0.3727, 0.37112, 0.37266,
-0.37284, 0.36908, 0.36092, 0.35018, 0.34092, 0.33448, 0.33466, 0.3348, 0.33064, 0.32004, 0.30708, 0.29262, 0.28412, 0.28282, 0.29118, 0.30554, 0.32308, 0.33472,
-0.34108, 0.35208, 0.36808, 0.38984, 0.41418, 0.43434, 0.44998, 0.45816, 0.4643, 0.46686, 0.46852, 0.47022, 0.46926, 0.46938, 0.47042, 0.46814, 0.46754, 0.4671,
-0.46648, 0.4646, 0.46432, 0.46204, 0.4626, 0.46362, 0.46084, 0.46004, 0.45946, 0.4607, 0.45914, 0.45802, 0.45804, 0.4566, 0.4564, 0.45732, 0.45696, 0.45742,
-0.45716, 0.45588, 0.45616, 0.45596, 0.45698, 0.45518, 0.45474, 0.45514, 0.45728, 
-//1182
-0.11232, 0.136, 0.16884, 0.20974, 0.24322, 0.2636, 0.27326, 0.2757, 0.27706, 0.27484, 0.27558, 0.27372, 0.27154, 0.2701, 0.2657, 0.26292, 0.26068, 0.25574,
-0.25158, 0.24634, 0.24352, 0.23726, 0.23276, 0.22978, 0.2279, 0.22408, 0.22056, 0.21782, 0.2152, 0.215, 0.21482, 0.21398, 0.2148, 0.2149, 0.21436, 0.21488,
-0.21448, 0.21896, 0.23106, 0.25002, 0.27608, 0.30352, 0.32184, 0.33456, 0.34118, 0.34518, 0.34538, 0.34492, 0.3438, 0.3432, 0.34348, 0.34128, 0.33896, 0.33794,
-0.33794, 0.33582, 0.33536, 0.33194, 0.33078, 0.33104, 0.32994, 0.32756, 0.32774, 0.32626, 0.32564, 0.32436, 0.32326, 0.32062, 0.32082, 0.31996, 0.31906,
-0.31706, 0.3172, 0.31612, 0.3148, 0.31348, 0.31358, 0.31106, 0.31084, 0.31006, 0.30952, 
-//1183
-0.10378, 0.12176, 0.14714, 0.1737, 0.19356, 0.20054, 0.20248, 0.20006, 0.19642, 0.1933, 0.19064, 0.18946, 0.1884, 0.18414, 0.18186, 0.18106, 0.17976, 0.18018,
-0.17962, 0.17714, 0.17054, 0.16438, 0.15836, 0.15514, 0.15298, 0.15308, 0.1489, 0.1421, 0.13552, 0.12776, 0.125, 0.12538, 0.12852, 0.1363, 0.1438, 0.14906,
-0.15186, 0.1579, 0.16516, 0.17922, 0.19474, 0.21142, 0.22276, 0.23188, 0.23806, 0.24278, 0.24586, 0.24722, 0.24652, 0.24614, 0.24762, 0.24718, 0.24608, 0.24532,
-0.24472, 0.24418, 0.24378, 0.24176, 0.24258, 0.24436, 0.24146, 0.23928, 0.2428, 0.24214, 0.24216, 0.2418, 0.24074, 0.2405, 0.23956, 0.23948, 0.23962, 0.2394,
-0.23914, 0.23894, 0.23936, 0.23814, 0.24022, 0.24018, 0.23954, 0.23614, 0.23812, 
-//1184
-0.09388, 0.10302, 0.11626, 0.12676, 0.13176, 0.13262, 0.1318, 0.13012, 0.12782, 0.125, 0.12444, 0.12336, 0.12134, 0.11872, 0.11666, 0.11524, 0.11452, 0.11452,
-0.11322, 0.1112, 0.10724, 0.10142, 0.09796, 0.0966, 0.09484, 0.09322, 0.09084, 0.0865, 0.08298, 0.07814, 0.07814, 0.07798, 0.07986, 0.08422, 0.08744, 0.08914,
-0.08952, 0.09238, 0.09672, 0.10362, 0.1154, 0.12846, 0.14114, 0.15288, 0.15932, 0.16578, 0.1691, 0.17064, 0.17274, 0.1731, 0.17472, 0.17472, 0.17402, 0.1749,
-0.17592, 0.17476, 0.17486, 0.17274, 0.17338, 0.17558, 0.17476, 0.17216, 0.17268, 0.17272, 0.17282, 0.17286, 0.173, 0.17234, 0.1728, 0.1717, 0.17216, 0.17292,
-0.17234, 0.17222, 0.1736, 0.17266, 0.17488, 0.17412, 0.17402, 0.17394, 0.17296, 
-//1185
-0.06994, 0.07294, 0.07854, 0.08206, 0.08494, 0.08436, 0.08546, 0.08506, 0.08372, 0.08208, 0.08124, 0.0796, 0.07616, 0.07432, 0.07158, 0.0692, 0.06778, 0.06496,
-0.06328, 0.06018, 0.05684, 0.05502, 0.05182, 0.05192, 0.05174, 0.04966, 0.04904, 0.04726, 0.0453, 0.045, 0.045, 0.04538, 0.04556, 0.04564, 0.04648, 0.04536,
-0.04508, 0.04656, 0.04752, 0.05006, 0.05458, 0.0604, 0.06854, 0.078, 0.0876, 0.09702, 0.10656, 0.11134, 0.11774, 0.12136, 0.12466, 0.12708, 0.12844, 0.13084,
-0.13192, 0.132, 0.13282, 0.1301, 0.13128, 0.13342, 0.1334, 0.13322, 0.13572, 0.13736, 0.13798, 0.13852, 0.1387, 0.14078, 0.14038, 0.14124, 0.14376, 0.1447,
-0.14738, 0.14644, 0.14914, 0.15004, 0.15386, 0.15346, 0.15474, 0.15572, 0.154, 
-//1186
-0.0553, 0.05732, 0.06018, 0.06234, 0.06352, 0.06342, 0.06242, 0.06288, 0.0621, 0.06112, 0.0598, 0.05912, 0.05808, 0.058, 0.05494, 0.05342, 0.05314, 0.05114,
-0.05056, 0.04858, 0.04692, 0.04526, 0.04356, 0.04264, 0.04256, 0.04212, 0.04108, 0.04052, 0.03988, 0.03796, 0.03842, 0.03706, 0.03656, 0.03704, 0.03636,
-0.03558, 0.03816, 0.03906, 0.04006, 0.04232, 0.04406, 0.04438, 0.04698, 0.04926, 0.05536, 0.06018, 0.06918, 0.07846, 0.08696, 0.09494, 0.1016, 0.1071, 0.11042,
-0.11254, 0.11576, 0.11646, 0.11854, 0.11688, 0.11888, 0.12054, 0.12184, 0.12258, 0.12408, 0.1265, 0.12734, 0.12876, 0.12954, 0.13062, 0.13076, 0.13092, 0.1323,
-0.13208, 0.1354, 0.13404, 0.13718, 0.13738, 0.13834, 0.14042, 0.14118, 0.14092, 0.1421, 
-//1187
-0.13668, 0.1629, 0.21566, 0.29074, 0.38212, 0.46628, 0.51696, 0.53398, 0.53486, 0.52974, 0.5281, 0.52338, 0.52126, 0.51546, 0.51086, 0.5057, 0.50522, 0.50296,
-0.49862, 0.49338, 0.48284, 0.46908, 0.45702, 0.4488, 0.44576, 0.44388, 0.43682, 0.41678, 0.39944, 0.38308, 0.37544, 0.37612, 0.38398, 0.39894, 0.41552, 0.42754,
-0.43682, 0.4501, 0.46952, 0.50152, 0.53936, 0.58134, 0.62238, 0.65752, 0.68436, 0.70406, 0.7203, 0.73256, 0.7398, 0.74856, 0.7552, 0.75902, 0.75716, 0.75822,
-0.76138, 0.76102, 0.76126, 0.75876, 0.75606, 0.75738, 0.75948, 0.7627, 0.76202, 0.76486, 0.76986, 0.76816, 0.76678, 0.76822, 0.76712, 0.76996, 0.77126, 0.77156,
-0.7757, 0.77404, 0.7752, 0.7755, 0.775, 0.7776, 0.78272, 0.77312, 0.7725, 
-//1188
-0.1225, 0.14826, 0.19356, 0.2582, 0.32798, 0.38202, 0.40792, 0.41118, 0.40764, 0.40076, 0.39578, 0.39368, 0.3894, 0.3827, 0.37928, 0.375, 0.37458, 0.37522,
-0.37244, 0.36718, 0.35708, 0.34304, 0.3304, 0.32384, 0.32106, 0.32176, 0.3163, 0.30354, 0.28932, 0.2727, 0.26362, 0.26028, 0.26732, 0.2814, 0.29936, 0.31084,
-0.31618, 0.32302, 0.34014, 0.3682, 0.40452, 0.44526, 0.47986, 0.50878, 0.52864, 0.54302, 0.5543, 0.55964, 0.56314, 0.565, 0.56796, 0.56824, 0.56734, 0.56746,
-0.5666, 0.56678, 0.56684, 0.5663, 0.5658, 0.56638, 0.56538, 0.56476, 0.56492, 0.56376, 0.56548, 0.56286, 0.56398, 0.56238, 0.5621, 0.56402, 0.56342, 0.56194,
-0.5632, 0.5611, 0.56176, 0.55944, 0.5613, 0.5602, 0.56134, 0.55854, 0.55664, 
-//1189
-0.12838, 0.1503, 0.18994, 0.23764, 0.27756, 0.29888, 0.30698, 0.30658, 0.30126, 0.29622, 0.29316, 0.29008, 0.28928, 0.28332, 0.2778, 0.27234, 0.2683, 0.26664,
-0.2622, 0.25652, 0.24874, 0.24008, 0.23232, 0.22762, 0.22586, 0.22196, 0.21804, 0.21206, 0.2055, 0.20006, 0.1971, 0.1964, 0.1979, 0.20016, 0.2042, 0.20418,
-0.20078, 0.2054, 0.21706, 0.24388, 0.28698, 0.33198, 0.37, 0.39554, 0.41176, 0.42066, 0.42542, 0.42876, 0.4307, 0.43026, 0.4308, 0.43108, 0.42976, 0.42834,
-0.42804, 0.42592, 0.42438, 0.42232, 0.4197, 0.42168, 0.41958, 0.41906, 0.41914, 0.4182, 0.41752, 0.41834, 0.41752, 0.41624, 0.41814, 0.41856, 0.41892, 0.4183,
-0.42066, 0.41698, 0.4151, 0.4131, 0.4134, 0.41406, 0.41528, 0.41198, 0.41108, 
-//1190
-0.10616, 0.1274, 0.15384, 0.18056, 0.19812, 0.20588, 0.20696, 0.20382, 0.20132, 0.19774, 0.19528, 0.19278, 0.18966, 0.18676, 0.1834, 0.18122, 0.17862, 0.17806,
-0.17698, 0.17248, 0.1658, 0.15688, 0.14892, 0.14508, 0.1428, 0.1417, 0.13758, 0.1308, 0.12296, 0.11358, 0.10938, 0.10938, 0.1138, 0.12022, 0.12624, 0.1298,
-0.13044, 0.13496, 0.1449, 0.16098, 0.18382, 0.20874, 0.23122, 0.25056, 0.26518, 0.27566, 0.28274, 0.2877, 0.2916, 0.29302, 0.29576, 0.29642, 0.29734, 0.29816,
-0.29732, 0.2961, 0.2966, 0.2952, 0.29458, 0.29472, 0.29354, 0.29216, 0.29392, 0.29214, 0.29134, 0.29076, 0.29116, 0.29048, 0.2916, 0.29182, 0.2916, 0.2932,
-0.29256, 0.29158, 0.29212, 0.29202, 0.29284, 0.29124, 0.29216, 0.289, 0.2908, 
-//1191
-0.09984, 0.11128, 0.12664, 0.14032, 0.14968, 0.1506, 0.1494, 0.14682, 0.14272, 0.14024, 0.13762, 0.13488, 0.13362, 0.12952, 0.12628, 0.12272, 0.12158, 0.12086,
-0.11776, 0.11392, 0.10942, 0.10298, 0.0975, 0.09506, 0.09238, 0.09036, 0.08666, 0.0816, 0.07704, 0.07296, 0.07212, 0.072, 0.07324, 0.0766, 0.07774, 0.0791,
-0.07942, 0.07912, 0.08388, 0.09248, 0.1042, 0.12382, 0.14442, 0.16474, 0.18472, 0.20034, 0.21424, 0.2231, 0.22816, 0.23032, 0.23314, 0.23556, 0.23478, 0.23658,
-0.23636, 0.23486, 0.23678, 0.23464, 0.23488, 0.23688, 0.23618, 0.2344, 0.23526, 0.23512, 0.23614, 0.2362, 0.23576, 0.23574, 0.23606, 0.2381, 0.23674, 0.2369,
-0.23794, 0.23778, 0.2397, 0.23988, 0.24148, 0.23974, 0.24004, 0.23996, 0.23776, 
-//1192
-0.07256, 0.07692, 0.08364, 0.0917, 0.0934, 0.09474, 0.09334, 0.09274, 0.09156, 0.0898, 0.08728, 0.0839, 0.08166, 0.07804, 0.07342, 0.0703, 0.06812, 0.06574,
-0.0633, 0.06092, 0.05806, 0.05494, 0.05298, 0.051, 0.05032, 0.04822, 0.04724, 0.04528, 0.04536, 0.04502, 0.044, 0.04396, 0.0445, 0.04468, 0.04574, 0.04576,
-0.04406, 0.0448, 0.04698, 0.04908, 0.0516, 0.05534, 0.06032, 0.06628, 0.07536, 0.08754, 0.10222, 0.12066, 0.13676, 0.1526, 0.16548, 0.1757, 0.18346, 0.18982,
-0.1951, 0.19928, 0.20408, 0.20452, 0.20818, 0.21284, 0.2148, 0.21708, 0.21998, 0.22384, 0.22508, 0.2292, 0.23124, 0.23402, 0.23484, 0.23818, 0.24074, 0.24324,
-0.24674, 0.24606, 0.24874, 0.25088, 0.25228, 0.25434, 0.2568, 0.25584, 0.25652, 
-//1193
-0.13342, 0.1626, 0.213, 0.28386, 0.35876, 0.41326, 0.43908, 0.44378, 0.43934, 0.4321, 0.42642, 0.42058, 0.41682, 0.4089, 0.40184, 0.39598, 0.39304, 0.38912,
-0.3849, 0.3779, 0.36428, 0.34888, 0.33384, 0.3254, 0.32124, 0.3156, 0.30682, 0.29064, 0.27302, 0.25694, 0.24802, 0.24646, 0.2515, 0.26414, 0.27628, 0.28456,
-0.2894, 0.29844, 0.31764, 0.3514, 0.39364, 0.44234, 0.4932, 0.53954, 0.57914, 0.61192, 0.63888, 0.65926, 0.67316, 0.68458, 0.6923, 0.6973, 0.70262, 0.7066,
-0.70744, 0.70896, 0.71056, 0.70932, 0.70996, 0.71186, 0.71238, 0.71528, 0.71468, 0.71636, 0.7172, 0.71812, 0.7205, 0.71954, 0.72084, 0.72334, 0.72514, 0.72422,
-0.72704, 0.72612, 0.7234, 0.72732, 0.7262, 0.72464, 0.72612, 0.72526, 0.72848, 
-//1194
-0.11806, 0.14078, 0.18086, 0.2307, 0.27772, 0.3051, 0.31298, 0.31294, 0.30606, 0.30078, 0.29696, 0.29292, 0.28872, 0.2839, 0.2776, 0.27458, 0.2724, 0.27048,
-0.2678, 0.26048, 0.24926, 0.2368, 0.22658, 0.21936, 0.21582, 0.2119, 0.20558, 0.19322, 0.18042, 0.16822, 0.16234, 0.16018, 0.1629, 0.17294, 0.18232, 0.18754,
-0.18892, 0.19576, 0.2098, 0.23458, 0.27054, 0.31172, 0.3537, 0.38974, 0.4216, 0.44432, 0.46192, 0.47622, 0.48372, 0.4899, 0.49382, 0.49666, 0.49716, 0.49818,
-0.49976, 0.49932, 0.50154, 0.49836, 0.4981, 0.49844, 0.49644, 0.49764, 0.49404, 0.49624, 0.49666, 0.49732, 0.49952, 0.49878, 0.49906, 0.50166, 0.50108, 0.49952,
-0.50252, 0.49954, 0.50084, 0.49996, 0.50022, 0.49898, 0.49956, 0.49858, 0.4977, 
-//1195
-0.11544, 0.13774, 0.1671, 0.19844, 0.2207, 0.22964, 0.2282, 0.22516, 0.2205, 0.21446, 0.2099, 0.20566, 0.20292, 0.19844, 0.19306, 0.18856, 0.18546, 0.18456,
-0.18114, 0.17596, 0.16944, 0.1596, 0.15182, 0.14676, 0.14384, 0.13986, 0.13674, 0.1271, 0.11874, 0.11068, 0.10602, 0.10454, 0.10674, 0.11216, 0.11784, 0.119,
-0.11858, 0.1239, 0.13158, 0.14702, 0.1701, 0.19984, 0.23556, 0.2711, 0.30486, 0.3345, 0.3584, 0.37608, 0.38916, 0.39872, 0.40548, 0.40874, 0.41074, 0.4143,
-0.4146, 0.41432, 0.41562, 0.41548, 0.41514, 0.41648, 0.41562, 0.41456, 0.4137, 0.4135, 0.41412, 0.41398, 0.41464, 0.41456, 0.41412, 0.41458, 0.41648, 0.41494,
-0.41472, 0.41456, 0.41242, 0.41338, 0.41258, 0.41426, 0.4142, 0.41406, 0.4153, 
-//1196
-0.09976, 0.1109, 0.12766, 0.14478, 0.15398, 0.15656, 0.15506, 0.15208, 0.14934, 0.14572, 0.14204, 0.13792, 0.1346, 0.13076, 0.12578, 0.1214, 0.11772, 0.11576,
-0.11248, 0.10874, 0.10286, 0.09598, 0.09172, 0.0884, 0.08608, 0.08324, 0.07944, 0.07352, 0.06918, 0.06542, 0.06414, 0.06282, 0.06504, 0.06638, 0.0676, 0.0667,
-0.0663, 0.06926, 0.07152, 0.07898, 0.09056, 0.10862, 0.1316, 0.1577, 0.18582, 0.21432, 0.23932, 0.26272, 0.2781, 0.29236, 0.30316, 0.31002, 0.31554, 0.31992,
-0.32178, 0.32302, 0.32588, 0.3249, 0.32596, 0.3288, 0.32958, 0.32866, 0.3291, 0.33038, 0.33236, 0.33176, 0.33428, 0.3358, 0.33712, 0.33842, 0.3386, 0.3404,
-0.34056, 0.3407, 0.34232, 0.34346, 0.34456, 0.34666, 0.34804, 0.34758, 0.3499, 
-//1197
-0.12496, 0.14598, 0.19032, 0.24364, 0.29366, 0.32192, 0.33018, 0.32908, 0.32262, 0.3154, 0.30964, 0.306, 0.30032, 0.29294, 0.28594, 0.27914, 0.27478, 0.27178,
-0.26782, 0.25964, 0.24798, 0.23226, 0.21936, 0.2126, 0.20868, 0.20472, 0.19802, 0.18442, 0.16992, 0.1577, 0.15052, 0.14856, 0.15288, 0.16028, 0.1675, 0.17074,
-0.17184, 0.17556, 0.19008, 0.21606, 0.25326, 0.29994, 0.35044, 0.40012, 0.44684, 0.4887, 0.52206, 0.54966, 0.5714, 0.58734, 0.60062, 0.61038, 0.61586, 0.62124,
-0.62586, 0.62854, 0.62898, 0.62942, 0.62998, 0.62966, 0.62764, 0.62986, 0.63046, 0.63264, 0.63622, 0.63952, 0.64326, 0.64556, 0.64816, 0.65056, 0.6503, 0.65356,
-0.65578, 0.655, 0.65792, 0.65924, 0.66272, 0.66324, 0.667, 0.6619, 0.66322, 
-//1198
-0.12288, 0.14032, 0.17396, 0.20706, 0.23302, 0.24294, 0.24454, 0.24266, 0.23806, 0.23234, 0.22786, 0.2225, 0.21906, 0.21166, 0.20446, 0.19822, 0.1944, 0.18964,
-0.18482, 0.17664, 0.1674, 0.1569, 0.1487, 0.14142, 0.13786, 0.13436, 0.1274, 0.11936, 0.11022, 0.10426, 0.10096, 0.1002, 0.10334, 0.10602, 0.10956, 0.11046,
-0.10912, 0.11146, 0.12008, 0.13504, 0.16, 0.19296, 0.23164, 0.2774, 0.32376, 0.36522, 0.40366, 0.43586, 0.4602, 0.48296, 0.49954, 0.51184, 0.52096, 0.52914,
-0.53454, 0.53932, 0.5443, 0.54582, 0.54728, 0.55178, 0.5511, 0.5532, 0.5555, 0.55734, 0.56, 0.56208, 0.56416, 0.56582, 0.5682, 0.57088, 0.5739, 0.57298,
-0.57564, 0.57332, 0.5764, 0.57678, 0.57776, 0.58166, 0.58472, 0.58368, 0.58498, 
-//1199
-0.10428, 0.1157, 0.13362, 0.15266, 0.16828, 0.17116, 0.1721, 0.1695, 0.16716, 0.16294, 0.1583, 0.15356, 0.14828, 0.14158, 0.13528, 0.12936, 0.12546, 0.1203,
-0.11474, 0.1092, 0.1022, 0.09474, 0.08916, 0.08586, 0.08276, 0.0795, 0.07592, 0.0704, 0.06582, 0.06124, 0.06048, 0.06042, 0.06122, 0.06286, 0.06336, 0.06236,
-0.06268, 0.0637, 0.06672, 0.0723, 0.08338, 0.10094, 0.12402, 0.15292, 0.18714, 0.22488, 0.26334, 0.30162, 0.33522, 0.36306, 0.38778, 0.4071, 0.42136, 0.43434,
-0.4428, 0.45142, 0.4568, 0.4607, 0.46268, 0.46628, 0.46824, 0.47026, 0.47146, 0.4754, 0.47834, 0.48278, 0.4881, 0.49156, 0.49394, 0.49648, 0.49872, 0.50288,
-0.50632, 0.50678, 0.51064, 0.51246, 0.51342, 0.51508, 0.51796, 0.52062, 0.51858, 
-//1200
-0.13154, 0.15178, 0.17832, 0.206, 0.22308, 0.2307, 0.23132, 0.22958, 0.22848, 0.22666, 0.2241, 0.22166, 0.22006, 0.21656, 0.2126, 0.2073, 0.20112, 0.19446,
-0.1853, 0.17678, 0.16858, 0.1573, 0.14956, 0.14376, 0.13714, 0.13048, 0.12422, 0.11686, 0.11162, 0.10802, 0.10434, 0.10294, 0.1015, 0.09954, 0.09838, 0.09624,
-0.09456, 0.09652, 0.10224, 0.114, 0.13764, 0.17336, 0.21914, 0.27336, 0.32906, 0.38502, 0.43586, 0.48108, 0.52034, 0.55294, 0.582, 0.60232, 0.61926, 0.63262,
-0.64286, 0.64942, 0.65594, 0.66028, 0.66258, 0.6675, 0.66738, 0.66766, 0.6714, 0.67472, 0.68194, 0.68558, 0.68944, 0.69106, 0.69248, 0.69964, 0.7014, 0.7033,
-0.70794, 0.7096, 0.7096, 0.71488, 0.71768, 0.71978, 0.72192, 0.72394, 0.72586, 
-//1201
-0.1067, 0.11866, 0.1386, 0.1606, 0.17694, 0.18276, 0.18432, 0.18374, 0.18236, 0.17674, 0.17036, 0.16358, 0.15532, 0.1473, 0.13826, 0.13062, 0.12442, 0.11774,
-0.11148, 0.10428, 0.09818, 0.0905, 0.0854, 0.08268, 0.07954, 0.0755, 0.07256, 0.06724, 0.06418, 0.0605, 0.05814, 0.05856, 0.05964, 0.06064, 0.06072, 0.06052,
-0.05986, 0.06024, 0.06262, 0.06738, 0.07672, 0.09062, 0.10968, 0.13566, 0.16888, 0.20816, 0.25276, 0.30096, 0.3473, 0.39238, 0.43568, 0.47234, 0.5011, 0.52718,
-0.54554, 0.56224, 0.5749, 0.5839, 0.59114, 0.59656, 0.59846, 0.60082, 0.60714, 0.61612, 0.62426, 0.6315, 0.64128, 0.64994, 0.65788, 0.66652, 0.6707, 0.67904,
-0.6853, 0.68698, 0.69274, 0.69752, 0.69894, 0.70452, 0.7078, 0.70806, 0.70932, 
-//1202
-0.1391, 0.17196, 0.22974, 0.31424, 0.42904, 0.55, 0.64346, 0.68962, 0.70534, 0.70984, 0.71036, 0.71022, 0.7114, 0.7075, 0.70666, 0.7044, 0.70626, 0.7073,
-0.70816, 0.70518, 0.6994, 0.6903, 0.6815, 0.6775, 0.67732, 0.67764, 0.67828, 0.67326, 0.66632, 0.6575, 0.65182, 0.64778, 0.65112, 0.66392, 0.6832, 0.70258,
-0.71764, 0.72656, 0.73012, 0.72856, 0.72608, 0.72364, 0.72774, 0.73406, 0.74544, 0.757, 0.76952, 0.78048, 0.78822, 0.79612, 0.80232, 0.80682, 0.80816, 0.8105,
-0.81208, 0.812, 0.81408, 0.811, 0.80806, 0.81, 0.81148, 0.81304, 0.8133, 0.81352, 0.81438, 0.81406, 0.81492, 0.81412, 0.81528, 0.81534, 0.81672, 0.8175,
-0.82046, 0.81914, 0.81698, 0.81546, 0.81674, 0.82066, 0.81896, 0.81432, 0.82112, 
-//1203
-0.14094, 0.17114, 0.22564, 0.30442, 0.3952, 0.47668, 0.52294, 0.53828, 0.53936, 0.53738, 0.53478, 0.5353, 0.53464, 0.5297, 0.52706, 0.52298, 0.5231, 0.5229,
-0.52174, 0.5191, 0.51322, 0.50558, 0.4972, 0.49398, 0.49428, 0.494, 0.49436, 0.49042, 0.48476, 0.47882, 0.47552, 0.4763, 0.47866, 0.4907, 0.5063, 0.51992,
-0.52998, 0.53874, 0.54568, 0.55324, 0.56298, 0.56834, 0.57266, 0.57532, 0.57474, 0.57348, 0.5731, 0.5721, 0.57058, 0.57128, 0.57084, 0.5699, 0.56742, 0.56766,
-0.56626, 0.56604, 0.56688, 0.56456, 0.5632, 0.56444, 0.56154, 0.56098, 0.55964, 0.56002, 0.56036, 0.55906, 0.56004, 0.55808, 0.55822, 0.55842, 0.55764, 0.55564,
-0.55824, 0.55666, 0.55662, 0.55664, 0.5561, 0.55638, 0.55746, 0.55256, 0.55348, 
-//1204
-0.1468, 0.17168, 0.21974, 0.28102, 0.34026, 0.38104, 0.39724, 0.39916, 0.3974, 0.39368, 0.39078, 0.39034, 0.38784, 0.38478, 0.38016, 0.37954, 0.37836, 0.37776,
-0.3798, 0.37738, 0.37156, 0.3625, 0.35386, 0.34654, 0.3459, 0.34772, 0.34826, 0.34564, 0.34136, 0.33444, 0.33236, 0.33116, 0.33626, 0.34882, 0.3669, 0.38334,
-0.39532, 0.40346, 0.40814, 0.41386, 0.41752, 0.42022, 0.4214, 0.42096, 0.42134, 0.41968, 0.41888, 0.41794, 0.41696, 0.41594, 0.41616, 0.41414, 0.41208, 0.41044,
-0.40954, 0.40906, 0.40844, 0.40614, 0.40592, 0.40476, 0.40308, 0.40052, 0.40152, 0.40054, 0.39956, 0.39872, 0.3975, 0.39624, 0.3961, 0.3959, 0.39684, 0.39416,
-0.39486, 0.39282, 0.39208, 0.39212, 0.3937, 0.39256, 0.39142, 0.3896, 0.38928, 
-//1205
-0.1133, 0.13494, 0.17088, 0.21038, 0.24606, 0.26332, 0.27026, 0.27048, 0.26764, 0.26542, 0.26436, 0.26384, 0.26216, 0.26018, 0.26008, 0.25768, 0.2581, 0.25858,
-0.25812, 0.25612, 0.25218, 0.2464, 0.24048, 0.2382, 0.23846, 0.23992, 0.24072, 0.23658, 0.23048, 0.22518, 0.22208, 0.22288, 0.22794, 0.23704, 0.24824, 0.25636,
-0.26106, 0.26338, 0.26948, 0.27656, 0.28486, 0.29102, 0.293, 0.29428, 0.29514, 0.29504, 0.29586, 0.2947, 0.29458, 0.29458, 0.2932, 0.29072, 0.29264, 0.29262,
-0.29052, 0.29134, 0.29198, 0.28762, 0.28736, 0.28742, 0.28648, 0.2862, 0.28684, 0.28636, 0.28664, 0.28526, 0.2855, 0.2845, 0.28446, 0.28476, 0.28428, 0.285,
-0.28562, 0.2842, 0.28644, 0.28496, 0.28576, 0.28494, 0.28438, 0.2844, 0.28574, 
-//1206
-0.09742, 0.11214, 0.1346, 0.15476, 0.17092, 0.17532, 0.1766, 0.17556, 0.17168, 0.17112, 0.17002, 0.17048, 0.1701, 0.16896, 0.16878, 0.16728, 0.16684, 0.16818,
-0.1684, 0.16838, 0.16514, 0.16056, 0.15634, 0.15392, 0.15386, 0.15306, 0.15336, 0.14934, 0.14566, 0.14306, 0.14084, 0.14182, 0.14456, 0.15112, 0.15736, 0.1619,
-0.16366, 0.1668, 0.17174, 0.17916, 0.18964, 0.1953, 0.19808, 0.1996, 0.2007, 0.20102, 0.20066, 0.20086, 0.19942, 0.19998, 0.19962, 0.19814, 0.19776, 0.19612,
-0.19564, 0.19528, 0.1961, 0.19374, 0.19396, 0.1949, 0.19434, 0.19472, 0.19372, 0.19484, 0.1942, 0.19486, 0.19632, 0.19642, 0.19652, 0.1963, 0.1972, 0.19606,
-0.19666, 0.19638, 0.19822, 0.19586, 0.19624, 0.19602, 0.1962, 0.19608, 0.19714, 
-//1207
-0.08546, 0.0938, 0.1039, 0.1115, 0.1138, 0.11466, 0.11482, 0.1127, 0.11136, 0.11016, 0.10946, 0.10852, 0.1084, 0.10716, 0.1067, 0.10562, 0.10576, 0.1055,
-0.10522, 0.10386, 0.10228, 0.09852, 0.09562, 0.09364, 0.09326, 0.09274, 0.09126, 0.08958, 0.0881, 0.08632, 0.08698, 0.08698, 0.08806, 0.09238, 0.09666, 0.09814,
-0.099, 0.10042, 0.10638, 0.11472, 0.12076, 0.12732, 0.1304, 0.1325, 0.13344, 0.13174, 0.1317, 0.13168, 0.13152, 0.13106, 0.13202, 0.131, 0.131, 0.12992, 0.1292,
-0.12956, 0.1295, 0.12774, 0.12888, 0.1299, 0.12932, 0.12812, 0.1286, 0.12754, 0.1276, 0.12808, 0.12898, 0.12826, 0.12818, 0.12924, 0.129, 0.12952, 0.12986,
-0.13048, 0.13116, 0.1305, 0.13134, 0.13154, 0.13126, 0.13006, 0.12986, 
-//1208
-0.0655, 0.06642, 0.06874, 0.07074, 0.07102, 0.06974, 0.06958, 0.06898, 0.06844, 0.06838, 0.06748, 0.06826, 0.06736, 0.06694, 0.06602, 0.0653, 0.06448, 0.0628,
-0.06124, 0.05918, 0.05866, 0.05574, 0.05516, 0.05474, 0.05382, 0.05308, 0.0535, 0.05152, 0.05132, 0.05134, 0.05122, 0.05198, 0.05206, 0.05144, 0.05204, 0.0518,
-0.05176, 0.05422, 0.05782, 0.06102, 0.06738, 0.07324, 0.07958, 0.08402, 0.08714, 0.08864, 0.0889, 0.08856, 0.08796, 0.08778, 0.08784, 0.08662, 0.0874, 0.08622,
-0.08528, 0.08516, 0.0842, 0.08272, 0.08326, 0.0839, 0.08258, 0.08232, 0.08364, 0.08448, 0.08422, 0.08438, 0.08476, 0.08392, 0.08426, 0.08442, 0.08476, 0.08544,
-0.086, 0.0856, 0.0864, 0.08656, 0.08728, 0.08762, 0.08778, 0.08784, 0.08868, 
-//1209
-0.06072, 0.05742, 0.05852, 0.05938, 0.05886, 0.05832, 0.05858, 0.0575, 0.05742, 0.05618, 0.0564, 0.0552, 0.05456, 0.05322, 0.05262, 0.05182, 0.051, 0.05054,
-0.0502, 0.04906, 0.04832, 0.0469, 0.04556, 0.04496, 0.04408, 0.04402, 0.04364, 0.04292, 0.04248, 0.04226, 0.0427, 0.04294, 0.04242, 0.04302, 0.0441, 0.04376,
-0.04382, 0.04482, 0.04562, 0.04652, 0.04874, 0.05108, 0.05376, 0.0566, 0.05946, 0.06182, 0.06454, 0.06644, 0.06896, 0.06968, 0.07048, 0.07136, 0.07182, 0.07292,
-0.07358, 0.07358, 0.07338, 0.07324, 0.07484, 0.07492, 0.07522, 0.07574, 0.0756, 0.07662, 0.07758, 0.07866, 0.07972, 0.08022, 0.08192, 0.08372, 0.08384, 0.08554,
-0.08736, 0.0874, 0.08792, 0.08878, 0.08886, 0.09002, 0.09162, 0.09122, 0.09178, 
-//1210
-0.1319, 0.16032, 0.21348, 0.29142, 0.3824, 0.46438, 0.51154, 0.52664, 0.52644, 0.5219, 0.52004, 0.5188, 0.5172, 0.5129, 0.50826, 0.50498, 0.50484, 0.50496,
-0.50426, 0.49996, 0.49082, 0.47982, 0.4695, 0.4629, 0.46108, 0.4614, 0.4593, 0.45214, 0.44312, 0.43384, 0.42774, 0.42762, 0.43388, 0.4471, 0.4646, 0.47994,
-0.489, 0.49814, 0.51748, 0.54976, 0.58838, 0.61824, 0.64032, 0.65346, 0.66152, 0.66444, 0.66632, 0.66664, 0.6654, 0.66642, 0.66574, 0.66548, 0.66438, 0.66606,
-0.66454, 0.66418, 0.66424, 0.66156, 0.66224, 0.6636, 0.662, 0.66044, 0.66032, 0.6616, 0.66076, 0.659, 0.66056, 0.6598, 0.66098, 0.66362, 0.66222, 0.66042,
-0.66182, 0.6591, 0.6617, 0.65856, 0.65984, 0.65992, 0.66012, 0.65902, 0.6616, 
-//1211
-0.11828, 0.15056, 0.19776, 0.2596, 0.32398, 0.3691, 0.38718, 0.39252, 0.38894, 0.38312, 0.38112, 0.37922, 0.37662, 0.37296, 0.37016, 0.3665, 0.36646, 0.36758,
-0.36748, 0.3633, 0.35528, 0.34528, 0.33682, 0.3308, 0.3308, 0.33078, 0.32856, 0.32254, 0.31454, 0.30752, 0.30294, 0.30238, 0.30686, 0.31866, 0.33518, 0.34712,
-0.35494, 0.36304, 0.3788, 0.40392, 0.4331, 0.45578, 0.47376, 0.4806, 0.4858, 0.4873, 0.48606, 0.48346, 0.48338, 0.4817, 0.4809, 0.47878, 0.47758, 0.47684,
-0.47644, 0.47662, 0.4763, 0.4745, 0.47362, 0.4731, 0.47108, 0.47244, 0.46972, 0.47102, 0.46978, 0.47028, 0.47002, 0.46782, 0.46754, 0.4683, 0.46976, 0.4686,
-0.46946, 0.46528, 0.46564, 0.46586, 0.46142, 0.4612, 0.46028, 0.45992, 0.45924, 
-//1212
-0.11378, 0.13522, 0.17082, 0.21224, 0.24178, 0.25892, 0.2627, 0.2612, 0.25794, 0.25354, 0.25188, 0.2506, 0.24968, 0.24674, 0.24476, 0.24458, 0.24432, 0.24378,
-0.24342, 0.24126, 0.2361, 0.22834, 0.22114, 0.21598, 0.21546, 0.2154, 0.21324, 0.20826, 0.20142, 0.19586, 0.19242, 0.19156, 0.19468, 0.20326, 0.21436, 0.2215,
-0.22462, 0.22968, 0.2414, 0.26378, 0.28838, 0.30948, 0.32288, 0.32942, 0.33338, 0.33474, 0.33522, 0.33508, 0.33364, 0.3329, 0.33266, 0.3317, 0.33152, 0.33128,
-0.3304, 0.33008, 0.32952, 0.3291, 0.32936, 0.33046, 0.3295, 0.32904, 0.32734, 0.32826, 0.32754, 0.32608, 0.32714, 0.3286, 0.32908, 0.331, 0.33026, 0.3288,
-0.32908, 0.3282, 0.32816, 0.32624, 0.32686, 0.32718, 0.3275, 0.32456, 0.3235, 
-//1213
-0.11208, 0.12956, 0.15416, 0.17534, 0.18732, 0.1923, 0.1918, 0.18866, 0.1851, 0.18128, 0.1801, 0.17822, 0.17754, 0.17522, 0.17308, 0.17104, 0.16958, 0.1691,
-0.16784, 0.16598, 0.16144, 0.1552, 0.1503, 0.14668, 0.14582, 0.14486, 0.14334, 0.14022, 0.13508, 0.13166, 0.13006, 0.13048, 0.1326, 0.13716, 0.144, 0.14878,
-0.15124, 0.15518, 0.16142, 0.17748, 0.201, 0.22198, 0.23692, 0.2459, 0.25042, 0.25224, 0.25358, 0.25406, 0.25238, 0.25282, 0.25288, 0.25188, 0.25, 0.24962,
-0.2483, 0.24722, 0.24708, 0.24472, 0.24378, 0.24582, 0.24494, 0.24454, 0.24316, 0.24352, 0.243, 0.2435, 0.2423, 0.24062, 0.24168, 0.2413, 0.24028, 0.23888,
-0.23966, 0.23816, 0.23768, 0.2362, 0.23674, 0.23672, 0.23856, 0.23666, 0.23626, 
-//1214
-0.08864, 0.09622, 0.10564, 0.11416, 0.11888, 0.1188, 0.11966, 0.11712, 0.11442, 0.11334, 0.1128, 0.11266, 0.11238, 0.11146, 0.11026, 0.1083, 0.10762, 0.10702,
-0.1054, 0.10284, 0.10024, 0.09544, 0.09148, 0.09056, 0.08852, 0.0881, 0.08708, 0.08506, 0.08356, 0.08106, 0.08076, 0.08106, 0.08244, 0.08434, 0.08556, 0.0852,
-0.08526, 0.08498, 0.08842, 0.0976, 0.11518, 0.13508, 0.15204, 0.16494, 0.17172, 0.175, 0.17684, 0.17698, 0.17766, 0.17754, 0.17708, 0.17656, 0.17538, 0.17468,
-0.17366, 0.17322, 0.17286, 0.1714, 0.17256, 0.17316, 0.17294, 0.17318, 0.17228, 0.1726, 0.1731, 0.17346, 0.17342, 0.17388, 0.17338, 0.17384, 0.17428, 0.17294,
-0.1733, 0.17278, 0.1717, 0.17188, 0.17414, 0.1735, 0.17384, 0.17114, 0.17408, 
-//1215
-0.066, 0.06914, 0.06916, 0.07288, 0.07426, 0.0731, 0.07296, 0.0724, 0.07166, 0.0706, 0.07002, 0.0686, 0.06782, 0.0675, 0.06836, 0.06186, 0.06074, 0.05872,
-0.058, 0.05616, 0.05376, 0.0515, 0.05024, 0.04978, 0.04928, 0.04754, 0.0464, 0.04458, 0.0442, 0.04464, 0.04532, 0.04466, 0.04524, 0.04618, 0.04648, 0.04582,
-0.04726, 0.04856, 0.0488, 0.0508, 0.05456, 0.0596, 0.06624, 0.07382, 0.08374, 0.09318, 0.10438, 0.11402, 0.12202, 0.12674, 0.13044, 0.1342, 0.13604, 0.13756,
-0.13874, 0.1395, 0.13994, 0.13918, 0.14084, 0.14186, 0.14178, 0.14322, 0.14356, 0.14478, 0.1476, 0.14788, 0.15032, 0.15252, 0.15392, 0.1566, 0.15746, 0.16056,
-0.16264, 0.1633, 0.16652, 0.16868, 0.16866, 0.16988, 0.1717, 0.17206, 0.17318, 
-//1216
-0.05702, 0.05714, 0.05902, 0.0609, 0.06312, 0.06116, 0.06214, 0.06198, 0.06054, 0.05894, 0.05646, 0.05474, 0.05282, 0.05038, 0.04844, 0.04668, 0.04656, 0.04636,
-0.04542, 0.04412, 0.04378, 0.04324, 0.04138, 0.0414, 0.04138, 0.04042, 0.04068, 0.03942, 0.03972, 0.0385, 0.03818, 0.0384, 0.03926, 0.03984, 0.03958, 0.03968,
-0.03948, 0.03998, 0.04126, 0.0418, 0.04204, 0.04258, 0.04374, 0.04274, 0.04514, 0.04778, 0.05148, 0.06008, 0.074, 0.08906, 0.1051, 0.11922, 0.13216, 0.1438,
-0.15268, 0.15978, 0.16744, 0.17262, 0.17888, 0.18494, 0.18926, 0.1919, 0.19546, 0.20042, 0.20488, 0.20808, 0.21092, 0.21436, 0.21672, 0.22018, 0.2236, 0.22594,
-0.22838, 0.23078, 0.2333, 0.23536, 0.23686, 0.24018, 0.24358, 0.24526, 0.2467, 
-//1217
-0.12148, 0.14908, 0.20062, 0.2746, 0.36232, 0.44228, 0.48684, 0.50362, 0.50332, 0.49908, 0.49718, 0.4971, 0.49626, 0.4931, 0.48976, 0.4868, 0.48602, 0.4849,
-0.4825, 0.4761, 0.46544, 0.4526, 0.43992, 0.43084, 0.42862, 0.42584, 0.4216, 0.41258, 0.4034, 0.3941, 0.38926, 0.39102, 0.3947, 0.40476, 0.41636, 0.42176,
-0.42458, 0.43344, 0.45694, 0.50092, 0.55974, 0.61754, 0.66814, 0.70546, 0.73116, 0.74876, 0.75892, 0.76638, 0.77144, 0.77344, 0.77602, 0.77688, 0.7776, 0.77948,
-0.78066, 0.78198, 0.7814, 0.77986, 0.77838, 0.78346, 0.78258, 0.78206, 0.78028, 0.78246, 0.78386, 0.78444, 0.78348, 0.78228, 0.78326, 0.78652, 0.78574, 0.78516,
-0.7882, 0.7864, 0.78848, 0.78954, 0.79092, 0.78984, 0.79352, 0.78944, 0.7896, 
-//1218
-0.12344, 0.14792, 0.19688, 0.25862, 0.32256, 0.36706, 0.38504, 0.38682, 0.38216, 0.37712, 0.37448, 0.37244, 0.3715, 0.36684, 0.3653, 0.3625, 0.361, 0.36034,
-0.3583, 0.3537, 0.3431, 0.33098, 0.319, 0.31204, 0.31072, 0.3093, 0.30552, 0.29756, 0.28812, 0.28022, 0.27566, 0.27608, 0.28068, 0.291, 0.30196, 0.30884,
-0.31132, 0.31714, 0.33442, 0.37174, 0.42304, 0.47516, 0.51672, 0.54246, 0.55694, 0.56528, 0.57152, 0.57474, 0.57542, 0.57514, 0.57472, 0.5747, 0.57388, 0.57424,
-0.5749, 0.5729, 0.57292, 0.5714, 0.57146, 0.57248, 0.57054, 0.56854, 0.56872, 0.56892, 0.5703, 0.56862, 0.5695, 0.56792, 0.56918, 0.56824, 0.56886, 0.56672,
-0.56838, 0.56654, 0.5681, 0.56714, 0.5649, 0.5664, 0.5673, 0.56568, 0.5616, 
-//1219
-0.11434, 0.13852, 0.17512, 0.21618, 0.24904, 0.26588, 0.27024, 0.26718, 0.2646, 0.25876, 0.25836, 0.25778, 0.25636, 0.25338, 0.24968, 0.24742, 0.24746, 0.24616,
-0.24452, 0.2402, 0.23274, 0.22186, 0.21346, 0.20696, 0.20516, 0.20432, 0.20066, 0.19428, 0.18726, 0.18208, 0.1795, 0.17962, 0.18282, 0.18804, 0.19638, 0.2,
-0.20042, 0.20466, 0.21868, 0.2469, 0.2898, 0.33394, 0.36716, 0.3879, 0.40256, 0.40968, 0.4123, 0.41518, 0.41504, 0.41542, 0.41534, 0.41558, 0.41442, 0.4154,
-0.41448, 0.41408, 0.41398, 0.4124, 0.41118, 0.41318, 0.41154, 0.41068, 0.4111, 0.40842, 0.4096, 0.4093, 0.4101, 0.40894, 0.40866, 0.4077, 0.4086, 0.40952,
-0.4104, 0.4082, 0.4069, 0.4068, 0.40736, 0.40726, 0.40776, 0.40312, 0.40442, 
-//1220
-0.10794, 0.12672, 0.1496, 0.17212, 0.18658, 0.19102, 0.19018, 0.18588, 0.18142, 0.17866, 0.17786, 0.17548, 0.17546, 0.1717, 0.17082, 0.16844, 0.16664, 0.16608,
-0.16348, 0.16064, 0.15594, 0.14958, 0.14322, 0.13782, 0.1368, 0.13426, 0.13064, 0.12648, 0.12114, 0.11794, 0.11628, 0.11592, 0.11718, 0.1196, 0.12314, 0.12564,
-0.12458, 0.12822, 0.13622, 0.1571, 0.19068, 0.22822, 0.26168, 0.28358, 0.29776, 0.30438, 0.30908, 0.31096, 0.31188, 0.3138, 0.313, 0.31188, 0.31164, 0.31106,
-0.30976, 0.308, 0.3091, 0.30708, 0.30724, 0.30702, 0.30472, 0.3039, 0.30284, 0.3037, 0.30336, 0.30374, 0.3028, 0.30294, 0.30212, 0.30258, 0.3027, 0.30256,
-0.30304, 0.2999, 0.30106, 0.29798, 0.30102, 0.29894, 0.2996, 0.29856, 0.29906, 
-//1221
-0.09974, 0.09696, 0.10848, 0.11554, 0.1206, 0.11902, 0.11944, 0.11636, 0.11482, 0.1131, 0.11278, 0.1125, 0.1133, 0.11134, 0.10916, 0.10872, 0.10624, 0.10328,
-0.10056, 0.09606, 0.09264, 0.08766, 0.0845, 0.08218, 0.0802, 0.07862, 0.07706, 0.07424, 0.07228, 0.07166, 0.07154, 0.07162, 0.07162, 0.07104, 0.0703, 0.07036,
-0.0703, 0.07142, 0.07562, 0.08502, 0.103, 0.12724, 0.15254, 0.1776, 0.1953, 0.2058, 0.21596, 0.21974, 0.22178, 0.22236, 0.22324, 0.22362, 0.2232, 0.22288,
-0.22176, 0.221, 0.22112, 0.21866, 0.2195, 0.22028, 0.21942, 0.21834, 0.21914, 0.2187, 0.21956, 0.21886, 0.2181, 0.21868, 0.2184, 0.21922, 0.21804, 0.2175,
-0.2177, 0.21804, 0.21824, 0.21836, 0.21918, 0.2176, 0.21808, 0.21692, 0.21734, 
-//1222
-0.06644, 0.06808, 0.07362, 0.07886, 0.08238, 0.0808, 0.08008, 0.07862, 0.07738, 0.07538, 0.0738, 0.07116, 0.06932, 0.066, 0.06386, 0.06216, 0.06064, 0.05914,
-0.05782, 0.05586, 0.05316, 0.05024, 0.0487, 0.0464, 0.04722, 0.0458, 0.04388, 0.04234, 0.0404, 0.03948, 0.03916, 0.03934, 0.0399, 0.04018, 0.04178, 0.04168,
-0.04164, 0.04284, 0.04376, 0.04468, 0.05036, 0.05668, 0.0651, 0.07858, 0.0916, 0.10834, 0.12392, 0.13792, 0.14824, 0.15844, 0.16602, 0.17248, 0.17644, 0.1808,
-0.18404, 0.18568, 0.18872, 0.18866, 0.19094, 0.19336, 0.19252, 0.19334, 0.19378, 0.19666, 0.19924, 0.20196, 0.20536, 0.20676, 0.21044, 0.21332, 0.21688,
-0.21726, 0.2189, 0.22102, 0.22296, 0.22278, 0.22586, 0.22642, 0.2278, 0.2305, 0.2339, 
-//1223
-0.13008, 0.15918, 0.20414, 0.26348, 0.32254, 0.36172, 0.37616, 0.37724, 0.37132, 0.36546, 0.36082, 0.3601, 0.35872, 0.35334, 0.35, 0.34602, 0.34404, 0.34298,
-0.34164, 0.33626, 0.32724, 0.31634, 0.30398, 0.29722, 0.2934, 0.28978, 0.28604, 0.27714, 0.26822, 0.25972, 0.25634, 0.25478, 0.25624, 0.26184, 0.26996, 0.27502,
-0.27786, 0.28178, 0.29896, 0.3381, 0.39848, 0.4689, 0.5355, 0.58816, 0.63046, 0.66072, 0.68146, 0.69182, 0.6992, 0.7044, 0.70868, 0.71008, 0.71024, 0.7131,
-0.71396, 0.7127, 0.71412, 0.7114, 0.71222, 0.71512, 0.71554, 0.71612, 0.71756, 0.71896, 0.71926, 0.72158, 0.72008, 0.72142, 0.71968, 0.7234, 0.72208, 0.72174,
-0.7236, 0.7215, 0.72344, 0.72338, 0.72458, 0.72376, 0.72784, 0.72318, 0.72096, 
-//1224
-0.12752, 0.15298, 0.18892, 0.2297, 0.26056, 0.27496, 0.2811, 0.27582, 0.27094, 0.26464, 0.26206, 0.25966, 0.25758, 0.2552, 0.25308, 0.25032, 0.24844, 0.24808,
-0.24608, 0.24162, 0.23332, 0.22204, 0.21508, 0.20928, 0.20508, 0.20122, 0.19846, 0.19124, 0.18424, 0.1797, 0.17556, 0.17386, 0.1755, 0.17818, 0.1841, 0.18848,
-0.18674, 0.18872, 0.20048, 0.23004, 0.27892, 0.33832, 0.39476, 0.44134, 0.47346, 0.49464, 0.5064, 0.51554, 0.51834, 0.52128, 0.524, 0.5243, 0.52328, 0.52324,
-0.52162, 0.5201, 0.52044, 0.5194, 0.51984, 0.52156, 0.52012, 0.51886, 0.5186, 0.51698, 0.51518, 0.5158, 0.51702, 0.51688, 0.51348, 0.51328, 0.51294, 0.51308,
-0.515, 0.51264, 0.51322, 0.51102, 0.51468, 0.51262, 0.51202, 0.5119, 0.5128, 
-//1225
-0.0961, 0.11706, 0.13924, 0.1623, 0.17516, 0.17876, 0.17858, 0.17632, 0.17286, 0.17026, 0.16956, 0.16946, 0.1695, 0.16738, 0.16682, 0.1637, 0.16112, 0.15922,
-0.1551, 0.14984, 0.14416, 0.13576, 0.12994, 0.12554, 0.12176, 0.11854, 0.1155, 0.11036, 0.10664, 0.10458, 0.10344, 0.10294, 0.10274, 0.10398, 0.10362, 0.10232,
-0.1011, 0.1017, 0.10918, 0.1303, 0.16484, 0.21088, 0.25784, 0.29786, 0.32558, 0.34276, 0.35486, 0.36144, 0.36564, 0.36738, 0.36904, 0.36928, 0.36804, 0.36764,
-0.3682, 0.36656, 0.36544, 0.36268, 0.3641, 0.3642, 0.36346, 0.36376, 0.36376, 0.363, 0.36358, 0.3633, 0.36478, 0.36344, 0.36472, 0.36648, 0.36604, 0.3653,
-0.36652, 0.36388, 0.36404, 0.36486, 0.36372, 0.36298, 0.36574, 0.36244, 0.36442, 
-//1226
-0.08446, 0.09366, 0.10878, 0.12028, 0.12664, 0.12884, 0.12834, 0.12658, 0.12262, 0.11934, 0.1171, 0.11544, 0.11258, 0.11058, 0.10684, 0.10302, 0.10234, 0.10004,
-0.09646, 0.09316, 0.08776, 0.08212, 0.07736, 0.07598, 0.07402, 0.07304, 0.07026, 0.0645, 0.0609, 0.0575, 0.0574, 0.0572, 0.0587, 0.06138, 0.06222, 0.06192,
-0.06028, 0.06192, 0.0669, 0.0763, 0.0928, 0.11782, 0.14474, 0.17348, 0.20154, 0.22636, 0.24562, 0.25994, 0.27216, 0.27914, 0.2849, 0.28884, 0.29234, 0.29366,
-0.29436, 0.295, 0.29674, 0.29604, 0.29734, 0.29932, 0.30036, 0.3002, 0.30142, 0.30346, 0.30434, 0.30486, 0.30678, 0.30698, 0.30824, 0.30926, 0.30872, 0.3099,
-0.3116, 0.31194, 0.31282, 0.31416, 0.31454, 0.31594, 0.31564, 0.31588, 0.31802, 
-//1227
-0.13578, 0.15648, 0.19326, 0.2352, 0.26658, 0.27936, 0.2808, 0.27766, 0.27398, 0.26862, 0.26628, 0.2654, 0.26284, 0.26074, 0.25882, 0.2548, 0.2527, 0.24886,
-0.24476, 0.23818, 0.2279, 0.21852, 0.20876, 0.20152, 0.19546, 0.19142, 0.18642, 0.17838, 0.1724, 0.16826, 0.1655, 0.16372, 0.16456, 0.16628, 0.16658, 0.16604,
-0.16286, 0.16218, 0.17266, 0.20032, 0.2493, 0.31454, 0.38532, 0.45296, 0.50984, 0.5557, 0.5886, 0.61204, 0.62642, 0.63592, 0.64236, 0.6449, 0.64478, 0.64788,
-0.64842, 0.64814, 0.64974, 0.64874, 0.64854, 0.64832, 0.64786, 0.64736, 0.64826, 0.64748, 0.64866, 0.64834, 0.6492, 0.64776, 0.64702, 0.64872, 0.64624, 0.6471,
-0.64978, 0.64794, 0.64906, 0.64772, 0.64762, 0.6491, 0.6497, 0.64706, 0.6463, 
-//1228
-0.1103, 0.12716, 0.15058, 0.17366, 0.18444, 0.1884, 0.1878, 0.18464, 0.18378, 0.18116, 0.18128, 0.1803, 0.18078, 0.18086, 0.17898, 0.17506, 0.17256, 0.1676,
-0.16094, 0.15386, 0.14726, 0.13844, 0.13196, 0.12586, 0.12058, 0.11566, 0.1096, 0.10462, 0.10026, 0.0982, 0.09788, 0.09706, 0.09456, 0.09218, 0.09192, 0.08918,
-0.08768, 0.0909, 0.098, 0.1165, 0.15184, 0.20206, 0.26236, 0.32248, 0.37648, 0.41954, 0.45046, 0.46882, 0.4809, 0.4904, 0.49522, 0.49814, 0.49936, 0.49998,
-0.50002, 0.50042, 0.50142, 0.50042, 0.49958, 0.50044, 0.50088, 0.5014, 0.50036, 0.50142, 0.50068, 0.50018, 0.50058, 0.5011, 0.50026, 0.50014, 0.50066, 0.50088,
-0.50232, 0.49996, 0.5026, 0.50194, 0.50436, 0.50214, 0.50064, 0.50252, 0.50252, 
-//1229
-0.09274, 0.1022, 0.11422, 0.12306, 0.12776, 0.12696, 0.12602, 0.12544, 0.12284, 0.12164, 0.12042, 0.11978, 0.11758, 0.11454, 0.11262, 0.1097, 0.10558, 0.10198,
-0.0977, 0.09186, 0.08564, 0.08124, 0.07772, 0.07248, 0.07156, 0.06854, 0.06498, 0.06168, 0.05948, 0.0561, 0.05458, 0.05492, 0.05464, 0.05422, 0.05364, 0.05292,
-0.05468, 0.05732, 0.06004, 0.0671, 0.0804, 0.1008, 0.129, 0.16498, 0.20514, 0.24592, 0.2864, 0.32296, 0.35304, 0.3769, 0.39494, 0.40844, 0.41792, 0.42558,
-0.43166, 0.43636, 0.43962, 0.44238, 0.44448, 0.44604, 0.448, 0.44962, 0.451, 0.45318, 0.4567, 0.45912, 0.46068, 0.46284, 0.46456, 0.46662, 0.46842, 0.47074,
-0.4735, 0.4729, 0.47536, 0.47722, 0.47922, 0.48036, 0.48268, 0.48062, 0.48574, 
-//1230
-0.1091, 0.12504, 0.14478, 0.16326, 0.17278, 0.17654, 0.17676, 0.17492, 0.1769, 0.17482, 0.17548, 0.17562, 0.17744, 0.17566, 0.17292, 0.16912, 0.16364, 0.15728,
-0.15044, 0.14344, 0.13598, 0.1294, 0.12416, 0.11782, 0.1133, 0.10758, 0.10202, 0.09748, 0.09294, 0.09148, 0.09, 0.08824, 0.08648, 0.08362, 0.08232, 0.08012,
-0.0795, 0.08154, 0.08622, 0.09682, 0.11824, 0.15482, 0.2034, 0.26168, 0.3245, 0.38644, 0.44154, 0.49264, 0.53404, 0.56976, 0.59572, 0.6167, 0.63, 0.64278,
-0.65166, 0.65982, 0.66594, 0.6703, 0.6733, 0.67918, 0.67912, 0.68108, 0.68338, 0.69154, 0.69678, 0.70138, 0.70718, 0.71016, 0.71358, 0.71824, 0.72332, 0.72434,
-0.7303, 0.7305, 0.73384, 0.73336, 0.7374, 0.73666, 0.74258, 0.73882, 0.74008, 
-//1231
-0.09222, 0.10212, 0.11364, 0.1282, 0.13578, 0.13726, 0.13752, 0.13522, 0.13496, 0.13216, 0.12882, 0.1252, 0.12018, 0.11464, 0.10948, 0.10462, 0.10076, 0.09564,
-0.09124, 0.08636, 0.08148, 0.07558, 0.07194, 0.06944, 0.0671, 0.06474, 0.06154, 0.0587, 0.05636, 0.05278, 0.05156, 0.0521, 0.05204, 0.05212, 0.05234, 0.05178,
-0.05152, 0.05458, 0.05686, 0.06118, 0.06868, 0.08144, 0.10088, 0.12764, 0.15976, 0.20014, 0.24478, 0.2948, 0.34338, 0.39104, 0.43508, 0.47108, 0.50068, 0.52702,
-0.54436, 0.55938, 0.57366, 0.58336, 0.58914, 0.59636, 0.59746, 0.60094, 0.60516, 0.61268, 0.62024, 0.63008, 0.63684, 0.6443, 0.65088, 0.65946, 0.66594, 0.67142,
-0.67772, 0.67914, 0.68176, 0.6855, 0.68994, 0.69674, 0.69964, 0.69752, 0.70112, 
-//1232
-0.14566, 0.17756, 0.2342, 0.32036, 0.43036, 0.54672, 0.6367, 0.68164, 0.69816, 0.70216, 0.7027, 0.7043, 0.70514, 0.70284, 0.70108, 0.69966, 0.70018, 0.7001,
-0.69922, 0.69806, 0.6941, 0.68994, 0.68646, 0.68518, 0.68608, 0.68716, 0.68782, 0.68486, 0.68268, 0.67898, 0.6773, 0.67866, 0.6824, 0.69016, 0.70136, 0.71342,
-0.72062, 0.7247, 0.72774, 0.72966, 0.7293, 0.72974, 0.73098, 0.73006, 0.73106, 0.72822, 0.72984, 0.72896, 0.72732, 0.72616, 0.7254, 0.72492, 0.72334, 0.724,
-0.7241, 0.72336, 0.72248, 0.72178, 0.72132, 0.72036, 0.71982, 0.72046, 0.71524, 0.71634, 0.71526, 0.71536, 0.71578, 0.7145, 0.71366, 0.7145, 0.71562, 0.71398,
-0.71576, 0.7146, 0.71224, 0.7124, 0.71322, 0.71216, 0.716, 0.71338, 0.70962, 
-//1233
-0.1378, 0.16712, 0.21858, 0.29414, 0.3838, 0.46166, 0.50746, 0.52256, 0.52616, 0.52328, 0.52236, 0.52016, 0.52004, 0.51728, 0.51432, 0.51288, 0.51352, 0.51408,
-0.5165, 0.51524, 0.51048, 0.50402, 0.4987, 0.4959, 0.49674, 0.49858, 0.4996, 0.49634, 0.49084, 0.4837, 0.47894, 0.47816, 0.47996, 0.49362, 0.5097, 0.52462,
-0.53332, 0.53816, 0.53796, 0.53866, 0.53794, 0.53718, 0.53666, 0.53536, 0.5346, 0.53424, 0.5333, 0.5321, 0.53108, 0.53, 0.53028, 0.52976, 0.52732, 0.52642,
-0.5247, 0.524, 0.52234, 0.52228, 0.522, 0.5208, 0.52068, 0.51826, 0.51808, 0.51844, 0.51836, 0.51846, 0.5184, 0.51626, 0.51456, 0.51488, 0.5154, 0.51428,
-0.51662, 0.51242, 0.51286, 0.51152, 0.50988, 0.50842, 0.51114, 0.50736, 0.50918, 
-//1234
-0.12896, 0.15848, 0.20684, 0.26536, 0.32202, 0.36208, 0.37812, 0.38174, 0.37992, 0.37656, 0.37458, 0.37526, 0.3746, 0.37274, 0.37068, 0.36894, 0.3693, 0.37008,
-0.36998, 0.37048, 0.36668, 0.36172, 0.35672, 0.35506, 0.3558, 0.35732, 0.35814, 0.3556, 0.35066, 0.34494, 0.34092, 0.34032, 0.34458, 0.35414, 0.36726, 0.38054,
-0.3892, 0.39358, 0.39434, 0.395, 0.39522, 0.39474, 0.39444, 0.39268, 0.39316, 0.39216, 0.3913, 0.39016, 0.38902, 0.38882, 0.38832, 0.38798, 0.38654, 0.38584,
-0.38426, 0.38312, 0.3839, 0.3822, 0.38112, 0.38054, 0.37998, 0.3789, 0.37826, 0.38036, 0.3791, 0.37772, 0.37872, 0.37804, 0.37568, 0.3781, 0.37688, 0.37726,
-0.37738, 0.37522, 0.37562, 0.37424, 0.37478, 0.37616, 0.37488, 0.37296, 0.37372, 
-//1235
-0.11768, 0.14036, 0.17614, 0.21786, 0.25082, 0.2692, 0.27658, 0.27702, 0.27528, 0.27338, 0.27058, 0.27002, 0.26996, 0.26674, 0.26576, 0.26372, 0.26434, 0.26502,
-0.26522, 0.26398, 0.26126, 0.2575, 0.2525, 0.25178, 0.252, 0.25326, 0.25408, 0.25146, 0.2476, 0.24288, 0.23978, 0.2403, 0.2446, 0.2527, 0.26264, 0.27164,
-0.27686, 0.2796, 0.2816, 0.28384, 0.2852, 0.28492, 0.2846, 0.28446, 0.28412, 0.2821, 0.2828, 0.28114, 0.28014, 0.27924, 0.27918, 0.27938, 0.27734, 0.27718,
-0.27616, 0.2762, 0.27566, 0.27426, 0.27484, 0.27568, 0.2742, 0.2739, 0.27348, 0.2718, 0.2727, 0.27082, 0.27166, 0.27166, 0.27166, 0.27218, 0.27206, 0.27172,
-0.2718, 0.27166, 0.27126, 0.26998, 0.27252, 0.27068, 0.27162, 0.26968, 0.27022, 
-//1236
-0.11218, 0.12792, 0.15074, 0.17244, 0.18646, 0.1921, 0.19308, 0.19224, 0.19006, 0.18674, 0.18644, 0.1848, 0.18368, 0.18184, 0.17986, 0.17834, 0.17696, 0.17772,
-0.17734, 0.17598, 0.17354, 0.17, 0.16714, 0.16566, 0.16558, 0.1671, 0.16734, 0.16568, 0.16318, 0.16004, 0.15834, 0.15842, 0.16194, 0.16738, 0.17662, 0.18396,
-0.18918, 0.19116, 0.19322, 0.19352, 0.19462, 0.19454, 0.19474, 0.19388, 0.1935, 0.19296, 0.19164, 0.1912, 0.1906, 0.18848, 0.18934, 0.18834, 0.18772, 0.18762,
-0.18658, 0.1867, 0.18568, 0.1839, 0.18448, 0.18532, 0.18426, 0.18368, 0.18382, 0.18332, 0.18322, 0.18242, 0.18246, 0.18276, 0.1826, 0.18296, 0.18312, 0.1834,
-0.18194, 0.18088, 0.18308, 0.1811, 0.18112, 0.18142, 0.18184, 0.18146, 0.18056, 
-//1237
-0.08696, 0.09484, 0.1043, 0.11102, 0.11592, 0.11612, 0.1162, 0.11544, 0.11384, 0.112, 0.11106, 0.11008, 0.10916, 0.1082, 0.1074, 0.10598, 0.1063, 0.1061,
-0.1058, 0.10434, 0.10336, 0.10174, 0.09966, 0.09876, 0.09874, 0.09908, 0.09902, 0.09796, 0.09632, 0.09428, 0.09408, 0.09498, 0.0968, 0.1007, 0.1055, 0.10938,
-0.11068, 0.11266, 0.1144, 0.11682, 0.11852, 0.11996, 0.12008, 0.11998, 0.1197, 0.1195, 0.11904, 0.1186, 0.11926, 0.1185, 0.11834, 0.11808, 0.11746, 0.1178,
-0.1176, 0.11598, 0.1161, 0.11532, 0.11546, 0.11646, 0.11504, 0.11508, 0.11532, 0.11504, 0.11492, 0.11494, 0.11658, 0.1162, 0.11672, 0.11688, 0.1177, 0.11784,
-0.1182, 0.11782, 0.1188, 0.11798, 0.11828, 0.11704, 0.1189, 0.11826, 0.11758, 
-//1238
-0.06894, 0.06998, 0.07256, 0.07362, 0.07488, 0.07402, 0.07446, 0.07296, 0.07268, 0.0721, 0.07216, 0.07162, 0.07058, 0.07048, 0.06968, 0.06902, 0.06898, 0.06776,
-0.06726, 0.06772, 0.06956, 0.0648, 0.06322, 0.06268, 0.06286, 0.06294, 0.06224, 0.06132, 0.0608, 0.06096, 0.06106, 0.062, 0.06258, 0.06356, 0.06388, 0.064,
-0.06398, 0.06482, 0.0667, 0.06946, 0.07362, 0.0771, 0.0793, 0.07914, 0.07912, 0.07896, 0.0781, 0.0775, 0.07668, 0.07632, 0.07674, 0.0764, 0.07614, 0.07534,
-0.07534, 0.07528, 0.0757, 0.0736, 0.07462, 0.07532, 0.07458, 0.07448, 0.07542, 0.07598, 0.07564, 0.07598, 0.07676, 0.07604, 0.07594, 0.07596, 0.0767, 0.07684,
-0.07814, 0.07948, 0.07902, 0.07892, 0.07954, 0.0798, 0.08104, 0.08012, 0.08136, 
-//1239
-0.05222, 0.05142, 0.05094, 0.05092, 0.05156, 0.05158, 0.05166, 0.05112, 0.05078, 0.05, 0.05064, 0.0502, 0.0504, 0.04888, 0.04878, 0.04752, 0.04708, 0.04708,
-0.04638, 0.04506, 0.04586, 0.04464, 0.04334, 0.04424, 0.04374, 0.04422, 0.04378, 0.04386, 0.04346, 0.04324, 0.04334, 0.0439, 0.04336, 0.04388, 0.04404, 0.0443,
-0.04444, 0.04642, 0.04852, 0.05028, 0.05264, 0.05458, 0.05556, 0.05636, 0.05568, 0.05584, 0.05566, 0.05534, 0.05594, 0.0555, 0.05518, 0.0543, 0.05476, 0.05362,
-0.05384, 0.05332, 0.05326, 0.05196, 0.05224, 0.05284, 0.05318, 0.0524, 0.0536, 0.05446, 0.05424, 0.0547, 0.05532, 0.05512, 0.0565, 0.05698, 0.05792, 0.0577,
-0.05874, 0.05872, 0.05886, 0.059, 0.06032, 0.06112, 0.06202, 0.0618, 0.062, 
-//1240
-0.14784, 0.1807, 0.23832, 0.32516, 0.43468, 0.54954, 0.63896, 0.68534, 0.70268, 0.7072, 0.70858, 0.70968, 0.70938, 0.7083, 0.70698, 0.70478, 0.70388, 0.7034,
-0.70108, 0.69804, 0.69294, 0.68676, 0.68342, 0.68014, 0.67916, 0.67902, 0.67744, 0.67508, 0.6716, 0.6708, 0.67008, 0.67298, 0.67696, 0.68412, 0.694, 0.7038,
-0.71066, 0.7164, 0.72392, 0.73464, 0.7438, 0.75072, 0.75666, 0.75844, 0.75986, 0.7594, 0.7598, 0.76116, 0.76112, 0.762, 0.7645, 0.76612, 0.7655, 0.76722,
-0.77018, 0.77036, 0.7725, 0.76956, 0.76874, 0.76982, 0.76922, 0.77098, 0.77008, 0.77148, 0.7741, 0.77408, 0.77558, 0.7774, 0.77956, 0.7819, 0.78366, 0.78552,
-0.78682, 0.7856, 0.79038, 0.79112, 0.79302, 0.7896, 0.79024, 0.78728, 0.7901, 
-//1241
-0.13694, 0.16696, 0.22338, 0.30024, 0.38896, 0.46516, 0.50574, 0.51988, 0.52048, 0.51974, 0.51898, 0.51596, 0.51612, 0.51222, 0.5097, 0.50668, 0.50558, 0.50578,
-0.50494, 0.5022, 0.49804, 0.49134, 0.48528, 0.48164, 0.48248, 0.483, 0.48404, 0.48256, 0.47898, 0.47286, 0.4712, 0.47162, 0.4777, 0.49038, 0.50888, 0.52674,
-0.53916, 0.54668, 0.54984, 0.55226, 0.5532, 0.55312, 0.5545, 0.55412, 0.55506, 0.55316, 0.55346, 0.5526, 0.5512, 0.55176, 0.5514, 0.551, 0.54964, 0.5485,
-0.54804, 0.5471, 0.548, 0.54544, 0.54626, 0.54612, 0.54348, 0.54388, 0.54244, 0.543, 0.54202, 0.54232, 0.54208, 0.54182, 0.54086, 0.54232, 0.54292, 0.54216,
-0.54144, 0.53876, 0.54132, 0.53906, 0.53728, 0.53832, 0.54062, 0.53642, 0.53532, 
-//1242
-0.12786, 0.15436, 0.20102, 0.25974, 0.31738, 0.35306, 0.3671, 0.36606, 0.36148, 0.35644, 0.35238, 0.35064, 0.34846, 0.34512, 0.34094, 0.3391, 0.33872, 0.33892,
-0.33952, 0.33614, 0.32834, 0.31824, 0.30904, 0.30444, 0.30302, 0.3048, 0.30464, 0.2994, 0.29094, 0.28222, 0.27816, 0.27712, 0.28428, 0.3006, 0.32318, 0.34262,
-0.3557, 0.36698, 0.38174, 0.40656, 0.43072, 0.44854, 0.46104, 0.46626, 0.46912, 0.4686, 0.46894, 0.46944, 0.46832, 0.46848, 0.46746, 0.46526, 0.46486, 0.46542,
-0.46358, 0.46372, 0.46398, 0.46412, 0.46236, 0.46308, 0.4607, 0.46094, 0.45882, 0.4588, 0.4579, 0.4563, 0.4568, 0.45642, 0.45536, 0.4566, 0.45676, 0.4561,
-0.4555, 0.45396, 0.45302, 0.45346, 0.45134, 0.45058, 0.4512, 0.45032, 0.449, 
-//1243
-0.11852, 0.14132, 0.17568, 0.21678, 0.24952, 0.26668, 0.2703, 0.26932, 0.26616, 0.26184, 0.25936, 0.25702, 0.25612, 0.25354, 0.25028, 0.2476, 0.24856, 0.2501,
-0.25106, 0.24928, 0.24574, 0.24028, 0.23526, 0.2312, 0.23254, 0.23446, 0.23464, 0.23168, 0.22514, 0.2179, 0.21346, 0.21286, 0.219, 0.23154, 0.2494, 0.26534,
-0.27552, 0.28224, 0.28758, 0.29074, 0.29384, 0.29596, 0.29708, 0.29852, 0.2984, 0.2975, 0.29724, 0.29786, 0.29598, 0.29406, 0.2948, 0.29336, 0.29258, 0.29252,
-0.29232, 0.29036, 0.28968, 0.28794, 0.28782, 0.2878, 0.2859, 0.28518, 0.2857, 0.28438, 0.28332, 0.28412, 0.28418, 0.2833, 0.28366, 0.28292, 0.28228, 0.28184,
-0.28372, 0.2812, 0.27978, 0.28052, 0.28068, 0.27922, 0.27846, 0.27752, 0.27582, 
-//1244
-0.09694, 0.1158, 0.1379, 0.15956, 0.17294, 0.17924, 0.18078, 0.17776, 0.17644, 0.17398, 0.17304, 0.17284, 0.17312, 0.17084, 0.16906, 0.16802, 0.16802, 0.16918,
-0.17026, 0.16932, 0.166, 0.16244, 0.15764, 0.15606, 0.15664, 0.1569, 0.15632, 0.15278, 0.14972, 0.14528, 0.1427, 0.1443, 0.1477, 0.15432, 0.1629, 0.16854,
-0.17268, 0.17588, 0.18054, 0.1874, 0.19492, 0.1993, 0.20278, 0.20304, 0.20324, 0.2024, 0.203, 0.20222, 0.20138, 0.20168, 0.20112, 0.20118, 0.2011, 0.20106,
-0.20006, 0.20032, 0.20056, 0.19744, 0.19888, 0.19974, 0.19868, 0.19842, 0.19666, 0.19666, 0.19746, 0.1981, 0.1979, 0.19818, 0.1971, 0.19818, 0.19922, 0.19842,
-0.20062, 0.19954, 0.20036, 0.19896, 0.19866, 0.19922, 0.19868, 0.19742, 0.19956, 
-//1245
-0.0905, 0.09872, 0.10726, 0.11478, 0.11864, 0.11822, 0.11804, 0.1153, 0.11358, 0.1113, 0.11052, 0.11084, 0.10952, 0.1084, 0.10778, 0.10608, 0.10624, 0.1062,
-0.10508, 0.10324, 0.1021, 0.09982, 0.09638, 0.09512, 0.09464, 0.09478, 0.09372, 0.09162, 0.0898, 0.08922, 0.08916, 0.08998, 0.09236, 0.09608, 0.10162, 0.1058,
-0.10544, 0.10546, 0.1082, 0.115, 0.12476, 0.13324, 0.13786, 0.1411, 0.1418, 0.14234, 0.14212, 0.14144, 0.14014, 0.13954, 0.14086, 0.13934, 0.13874, 0.13738,
-0.137, 0.1355, 0.1349, 0.13378, 0.13414, 0.1352, 0.13472, 0.1343, 0.13404, 0.13344, 0.134, 0.13322, 0.13522, 0.13486, 0.1347, 0.13532, 0.13584, 0.13628,
-0.13704, 0.13618, 0.13576, 0.13544, 0.13564, 0.13638, 0.13644, 0.13674, 0.13438, 
-//1246
-0.0597, 0.06412, 0.06534, 0.06604, 0.06658, 0.06496, 0.06526, 0.0646, 0.06462, 0.06432, 0.06458, 0.06422, 0.0645, 0.0641, 0.0635, 0.06188, 0.06222, 0.0606,
-0.05894, 0.05808, 0.05672, 0.05536, 0.05424, 0.05368, 0.05264, 0.05174, 0.05174, 0.05184, 0.05206, 0.0522, 0.05246, 0.0528, 0.05284, 0.05412, 0.05372, 0.05386,
-0.05546, 0.05732, 0.06006, 0.06468, 0.06998, 0.07608, 0.08184, 0.0864, 0.088, 0.08934, 0.08834, 0.08672, 0.08706, 0.0866, 0.08602, 0.08464, 0.08448, 0.08384,
-0.08364, 0.08266, 0.08242, 0.08216, 0.08214, 0.08334, 0.08384, 0.08332, 0.0834, 0.08292, 0.08376, 0.0843, 0.08482, 0.08378, 0.0852, 0.08566, 0.08572, 0.08624,
-0.08668, 0.08664, 0.0862, 0.08592, 0.08642, 0.08732, 0.08642, 0.0867, 0.0882, 
-//1247
-0.0559, 0.05502, 0.0545, 0.0543, 0.05484, 0.0541, 0.05302, 0.0536, 0.05286, 0.05332, 0.05314, 0.05256, 0.05114, 0.05018, 0.04922, 0.04726, 0.04742, 0.04682,
-0.04658, 0.04588, 0.04494, 0.04462, 0.04316, 0.04308, 0.04332, 0.04188, 0.04122, 0.0414, 0.04136, 0.0411, 0.04228, 0.04148, 0.04196, 0.04304, 0.04336, 0.04362,
-0.04336, 0.04368, 0.04632, 0.04866, 0.05052, 0.05332, 0.05616, 0.06048, 0.064, 0.06836, 0.07206, 0.07514, 0.07842, 0.0795, 0.08198, 0.08322, 0.08354, 0.0852,
-0.08508, 0.086, 0.08652, 0.0865, 0.08904, 0.09038, 0.09252, 0.09248, 0.0938, 0.09412, 0.09448, 0.09644, 0.0988, 0.10046, 0.10248, 0.1037, 0.1049, 0.10696,
-0.1085, 0.10916, 0.11046, 0.11242, 0.11446, 0.11468, 0.11698, 0.11606, 0.11684, 
-//1248
-0.14034, 0.16956, 0.22306, 0.29842, 0.38364, 0.45658, 0.4936, 0.50128, 0.5007, 0.49434, 0.49068, 0.48816, 0.4862, 0.48046, 0.47728, 0.4737, 0.47218, 0.47242,
-0.47262, 0.46874, 0.4606, 0.45048, 0.44048, 0.43426, 0.43374, 0.435, 0.43532, 0.42994, 0.42158, 0.41212, 0.40718, 0.4071, 0.4153, 0.43502, 0.46372, 0.49402,
-0.51412, 0.52828, 0.54554, 0.57254, 0.60248, 0.62604, 0.64042, 0.64806, 0.65326, 0.65358, 0.6546, 0.65508, 0.65604, 0.65406, 0.655, 0.6538, 0.65248, 0.6536,
-0.65228, 0.6508, 0.65098, 0.6512, 0.65034, 0.65008, 0.64764, 0.64816, 0.64684, 0.64886, 0.64814, 0.64902, 0.6492, 0.64928, 0.64756, 0.65024, 0.64932, 0.648,
-0.64944, 0.64728, 0.6471, 0.6475, 0.64686, 0.64724, 0.648, 0.64364, 0.64424, 
-//1249
-0.12786, 0.15436, 0.20102, 0.25974, 0.31738, 0.35306, 0.3671, 0.36606, 0.36148, 0.35644, 0.35238, 0.35064, 0.34846, 0.34512, 0.34094, 0.3391, 0.33872, 0.33892,
-0.33952, 0.33614, 0.32834, 0.31824, 0.30904, 0.30444, 0.30302, 0.3048, 0.30464, 0.2994, 0.29094, 0.28222, 0.27816, 0.27712, 0.28428, 0.3006, 0.32318, 0.34262,
-0.3557, 0.36698, 0.38174, 0.40656, 0.43072, 0.44854, 0.46104, 0.46626, 0.46912, 0.4686, 0.46894, 0.46944, 0.46832, 0.46848, 0.46746, 0.46526, 0.46486, 0.46542,
-0.46358, 0.46372, 0.46398, 0.46412, 0.46236, 0.46308, 0.4607, 0.46094, 0.45882, 0.4588, 0.4579, 0.4563, 0.4568, 0.45642, 0.45536, 0.4566, 0.45676, 0.4561,
-0.4555, 0.45396, 0.45302, 0.45346, 0.45134, 0.45058, 0.4512, 0.45032, 0.449, 
-//1250
-0.1194, 0.1429, 0.17934, 0.21902, 0.25078, 0.2657, 0.26792, 0.26446, 0.26008, 0.25386, 0.25152, 0.2493, 0.24736, 0.24404, 0.2416, 0.23846, 0.23814, 0.23954,
-0.24064, 0.23858, 0.23224, 0.22346, 0.21584, 0.2109, 0.21116, 0.21332, 0.21298, 0.20688, 0.19774, 0.18834, 0.18224, 0.1831, 0.19066, 0.20528, 0.22632, 0.2435,
-0.25492, 0.2653, 0.27578, 0.29098, 0.30758, 0.32186, 0.33432, 0.34136, 0.34534, 0.3474, 0.34854, 0.34922, 0.349, 0.3492, 0.34778, 0.34702, 0.34602, 0.34622,
-0.34538, 0.34526, 0.34588, 0.3437, 0.34418, 0.34416, 0.34304, 0.34228, 0.33962, 0.33866, 0.33844, 0.33888, 0.33694, 0.33724, 0.3365, 0.33694, 0.33606, 0.3347,
-0.33518, 0.33514, 0.3344, 0.3333, 0.3339, 0.33334, 0.33198, 0.33376, 0.33226, 
-//1251
-0.10554, 0.1225, 0.14426, 0.1633, 0.17426, 0.1789, 0.17796, 0.17438, 0.17074, 0.1679, 0.1656, 0.16452, 0.16346, 0.16116, 0.15926, 0.15792, 0.15858, 0.15864,
-0.15796, 0.15654, 0.15178, 0.14546, 0.14088, 0.13844, 0.1376, 0.1374, 0.1366, 0.13266, 0.12922, 0.12546, 0.12378, 0.12416, 0.12748, 0.13418, 0.1425, 0.14898,
-0.1537, 0.15812, 0.16776, 0.18498, 0.20616, 0.22406, 0.23702, 0.244, 0.24734, 0.249, 0.24938, 0.24998, 0.24848, 0.2476, 0.2477, 0.24586, 0.2458, 0.24454,
-0.24396, 0.24378, 0.2437, 0.24316, 0.24256, 0.24242, 0.24276, 0.24216, 0.24204, 0.2419, 0.24152, 0.24112, 0.24136, 0.24086, 0.24158, 0.24032, 0.24108, 0.23976,
-0.23988, 0.23876, 0.23952, 0.23924, 0.23848, 0.23876, 0.23882, 0.23826, 0.23826, 
-//1252
-0.08702, 0.09528, 0.10444, 0.11024, 0.11186, 0.1127, 0.11166, 0.10936, 0.10594, 0.10424, 0.10306, 0.10234, 0.1027, 0.10048, 0.09934, 0.09842, 0.0986, 0.098,
-0.09678, 0.09502, 0.09184, 0.08906, 0.08712, 0.08508, 0.08436, 0.0829, 0.08202, 0.07998, 0.0785, 0.07734, 0.07688, 0.077, 0.07898, 0.08144, 0.08384, 0.08448,
-0.0847, 0.08702, 0.09228, 0.10344, 0.1203, 0.1391, 0.15378, 0.164, 0.169, 0.17148, 0.17318, 0.17342, 0.1732, 0.17278, 0.17176, 0.171, 0.17062, 0.17066, 0.17088,
-0.16952, 0.1692, 0.16834, 0.16888, 0.16946, 0.1689, 0.16896, 0.16782, 0.16874, 0.16942, 0.1686, 0.16874, 0.16898, 0.16872, 0.16862, 0.16828, 0.16878, 0.16962,
-0.1687, 0.16932, 0.16828, 0.16726, 0.1677, 0.1692, 0.16668, 0.16834, 
-//1253
-0.06322, 0.06394, 0.06622, 0.0664, 0.06546, 0.0652, 0.06512, 0.06434, 0.06326, 0.0632, 0.06264, 0.06252, 0.0622, 0.06128, 0.06048, 0.05822, 0.05736, 0.05536,
-0.05418, 0.05328, 0.0521, 0.05084, 0.0495, 0.04924, 0.04952, 0.04868, 0.0481, 0.0484, 0.04816, 0.04816, 0.0484, 0.04762, 0.04792, 0.04806, 0.04866, 0.04936,
-0.05088, 0.05358, 0.05582, 0.059, 0.06162, 0.06442, 0.0669, 0.06944, 0.0737, 0.08108, 0.09004, 0.10212, 0.11498, 0.12868, 0.14168, 0.15238, 0.1618, 0.16986,
-0.17584, 0.1804, 0.18468, 0.18818, 0.19134, 0.19484, 0.19686, 0.19766, 0.19932, 0.20184, 0.20348, 0.2059, 0.2086, 0.20978, 0.21162, 0.21428, 0.2174, 0.21844,
-0.2211, 0.22184, 0.223, 0.2263, 0.22604, 0.22838, 0.23056, 0.22898, 0.23218, 
-//1254
-0.06378, 0.06218, 0.06378, 0.0647, 0.0656, 0.06528, 0.06522, 0.06448, 0.06414, 0.06358, 0.06218, 0.06166, 0.06108, 0.05944, 0.0574, 0.05592, 0.05388, 0.05342,
-0.05196, 0.05068, 0.04878, 0.0466, 0.04592, 0.04494, 0.0444, 0.04336, 0.04286, 0.04142, 0.04082, 0.04056, 0.04092, 0.0408, 0.04146, 0.042, 0.04184, 0.04168,
-0.04248, 0.04284, 0.04384, 0.04588, 0.04932, 0.05378, 0.06082, 0.0694, 0.0794, 0.08844, 0.09718, 0.1046, 0.10918, 0.11298, 0.1165, 0.1192, 0.12048, 0.12276,
-0.12316, 0.12334, 0.12538, 0.1253, 0.12686, 0.12848, 0.12828, 0.12972, 0.13136, 0.1325, 0.13416, 0.13532, 0.13684, 0.13922, 0.14018, 0.1414, 0.14302, 0.14362,
-0.14422, 0.14626, 0.14642, 0.14902, 0.15068, 0.15074, 0.15278, 0.15472, 0.1566, 
-//1255
-0.12268, 0.15154, 0.20156, 0.27646, 0.36188, 0.43316, 0.47024, 0.47946, 0.47726, 0.4713, 0.46784, 0.4653, 0.46384, 0.46112, 0.45566, 0.45294, 0.4532, 0.45316,
-0.4531, 0.44948, 0.43972, 0.42518, 0.41362, 0.40692, 0.40594, 0.40714, 0.40476, 0.39784, 0.38632, 0.37514, 0.36894, 0.369, 0.3775, 0.39518, 0.41704, 0.4323,
-0.44134, 0.4507, 0.4733, 0.51776, 0.57566, 0.63154, 0.67826, 0.71146, 0.73416, 0.74834, 0.75678, 0.76274, 0.7638, 0.76602, 0.76906, 0.76894, 0.76836, 0.7705,
-0.77038, 0.76898, 0.77098, 0.76948, 0.76954, 0.7713, 0.76946, 0.76994, 0.77098, 0.7729, 0.7734, 0.7727, 0.77304, 0.77548, 0.7739, 0.77624, 0.77804, 0.77838,
-0.77872, 0.77746, 0.78154, 0.78016, 0.78292, 0.78204, 0.783, 0.78146, 0.7794, 
-//1256
-0.12372, 0.15152, 0.19716, 0.25546, 0.3094, 0.34386, 0.35424, 0.35176, 0.34538, 0.33904, 0.337, 0.33444, 0.33222, 0.32722, 0.32436, 0.32222, 0.32178, 0.32336,
-0.32216, 0.31854, 0.3104, 0.29932, 0.28966, 0.28346, 0.28178, 0.28176, 0.28058, 0.27472, 0.26526, 0.25654, 0.25118, 0.25088, 0.25696, 0.27176, 0.29178, 0.30896,
-0.32126, 0.33526, 0.35538, 0.39244, 0.44056, 0.48464, 0.5159, 0.53426, 0.54588, 0.55226, 0.55698, 0.55744, 0.55772, 0.55846, 0.55878, 0.55952, 0.5585, 0.55898,
-0.55912, 0.5591, 0.56032, 0.5586, 0.55894, 0.55978, 0.55476, 0.55256, 0.5478, 0.54986, 0.54882, 0.54816, 0.54844, 0.54894, 0.54822, 0.5496, 0.55014, 0.5483,
-0.54914, 0.5483, 0.54866, 0.54694, 0.54542, 0.54482, 0.546, 0.54254, 0.54176, 
-//1257
-0.11642, 0.1394, 0.17384, 0.21438, 0.2435, 0.258, 0.25972, 0.25568, 0.24934, 0.2434, 0.24054, 0.23758, 0.23362, 0.2295, 0.22512, 0.22232, 0.22254, 0.22386,
-0.22376, 0.2217, 0.21458, 0.2052, 0.19638, 0.19114, 0.19246, 0.19496, 0.19268, 0.1872, 0.17722, 0.16708, 0.1615, 0.16144, 0.16796, 0.18222, 0.20162, 0.2195,
-0.2303, 0.24116, 0.25682, 0.28018, 0.3102, 0.34206, 0.36874, 0.3895, 0.40286, 0.41334, 0.4202, 0.42472, 0.42658, 0.42858, 0.43068, 0.43138, 0.43142, 0.43078,
-0.43044, 0.42922, 0.43034, 0.4282, 0.42748, 0.4282, 0.42656, 0.427, 0.42636, 0.42544, 0.42326, 0.4228, 0.4229, 0.42326, 0.42062, 0.42004, 0.42158, 0.42016,
-0.41884, 0.4178, 0.41674, 0.41782, 0.41502, 0.41602, 0.41752, 0.41324, 0.41662, 
-//1258
-0.10496, 0.11282, 0.13122, 0.14518, 0.15346, 0.15446, 0.15266, 0.15022, 0.14642, 0.14408, 0.14246, 0.1423, 0.14338, 0.14146, 0.14104, 0.13836, 0.13772, 0.1403,
-0.1403, 0.1376, 0.13434, 0.1321, 0.13008, 0.1274, 0.12344, 0.12122, 0.11934, 0.11532, 0.11132, 0.10816, 0.10676, 0.10784, 0.112, 0.11708, 0.1229, 0.12736,
-0.12862, 0.1338, 0.14244, 0.16458, 0.19726, 0.23246, 0.26184, 0.28054, 0.2922, 0.29846, 0.30054, 0.3019, 0.30236, 0.30348, 0.30354, 0.30476, 0.30398, 0.30276,
-0.30052, 0.29986, 0.29942, 0.2978, 0.29738, 0.29914, 0.29752, 0.29688, 0.29692, 0.29706, 0.29692, 0.2975, 0.2967, 0.29616, 0.2963, 0.29714, 0.29666, 0.29692,
-0.29724, 0.29464, 0.29468, 0.2941, 0.29496, 0.29334, 0.29426, 0.2901, 0.29282, 
-//1259
-0.08962, 0.09486, 0.10468, 0.1094, 0.1118, 0.11018, 0.10918, 0.10672, 0.10374, 0.10286, 0.10132, 0.10096, 0.10058, 0.09938, 0.09888, 0.09772, 0.09688, 0.0956,
-0.09368, 0.09168, 0.08826, 0.08432, 0.08226, 0.0809, 0.07886, 0.07776, 0.07584, 0.07384, 0.07212, 0.0706, 0.0715, 0.07148, 0.07188, 0.0737, 0.07504, 0.07494,
-0.07432, 0.07556, 0.0793, 0.0908, 0.10928, 0.13598, 0.16368, 0.18758, 0.20452, 0.21446, 0.22144, 0.2243, 0.22684, 0.22722, 0.22778, 0.22718, 0.22628, 0.22634,
-0.2251, 0.2251, 0.22528, 0.22254, 0.22286, 0.22344, 0.22266, 0.22174, 0.22256, 0.22198, 0.22324, 0.22244, 0.22298, 0.2216, 0.22124, 0.22256, 0.2216, 0.2217,
-0.22198, 0.22184, 0.2235, 0.22118, 0.22374, 0.22406, 0.22304, 0.22302, 0.22332, 
-//1260
-0.0576, 0.05958, 0.06134, 0.06278, 0.06268, 0.06216, 0.06122, 0.06122, 0.06052, 0.05894, 0.05978, 0.0586, 0.05744, 0.05616, 0.05542, 0.05266, 0.05126, 0.05026,
-0.04778, 0.04584, 0.0449, 0.04458, 0.04326, 0.0428, 0.04268, 0.04122, 0.04186, 0.0414, 0.04068, 0.0413, 0.04092, 0.04074, 0.0416, 0.0405, 0.04038, 0.04208,
-0.04268, 0.0458, 0.04722, 0.04928, 0.051, 0.05182, 0.05258, 0.05464, 0.05734, 0.06172, 0.07158, 0.08544, 0.10472, 0.1296, 0.15808, 0.191, 0.22278, 0.25714,
-0.28968, 0.32148, 0.3519, 0.3775, 0.40214, 0.42676, 0.4454, 0.46208, 0.47734, 0.49088, 0.50256, 0.51562, 0.52674, 0.53608, 0.5478, 0.5592, 0.56826, 0.57808,
-0.58984, 0.60018, 0.6146, 0.6245, 0.63288, 0.64206, 0.64688, 0.649, 0.65594, 
-//1261
-0.12506, 0.15274, 0.1997, 0.25676, 0.30948, 0.34268, 0.3531, 0.34984, 0.343, 0.3375, 0.33358, 0.33132, 0.32808, 0.32406, 0.31866, 0.31422, 0.31102, 0.31264,
-0.31172, 0.30736, 0.29814, 0.28576, 0.27522, 0.26966, 0.26778, 0.26686, 0.2644, 0.25828, 0.24912, 0.24036, 0.23554, 0.23532, 0.2406, 0.25258, 0.2698, 0.28142,
-0.29028, 0.30176, 0.3234, 0.36566, 0.42746, 0.4918, 0.5494, 0.5946, 0.62326, 0.64306, 0.65742, 0.66452, 0.66996, 0.67296, 0.67476, 0.67598, 0.67628, 0.67828,
-0.6798, 0.67674, 0.67802, 0.67624, 0.67548, 0.67878, 0.67772, 0.67864, 0.67732, 0.67606, 0.67592, 0.6762, 0.67874, 0.68112, 0.67974, 0.68264, 0.68214, 0.682,
-0.68324, 0.68284, 0.68244, 0.68402, 0.68434, 0.68572, 0.6906, 0.68484, 0.68958, 
-//1262
-0.11812, 0.13994, 0.17682, 0.21454, 0.2434, 0.25678, 0.25796, 0.25272, 0.24672, 0.23942, 0.23474, 0.23268, 0.22964, 0.22526, 0.2199, 0.21816, 0.2168, 0.21822,
-0.21914, 0.21626, 0.20798, 0.19708, 0.18912, 0.18468, 0.18404, 0.1854, 0.1835, 0.17638, 0.16492, 0.15566, 0.14904, 0.14784, 0.15324, 0.16666, 0.18442, 0.19872,
-0.20694, 0.21818, 0.23416, 0.26064, 0.29872, 0.34298, 0.38654, 0.42522, 0.45534, 0.47712, 0.49172, 0.50488, 0.51126, 0.51678, 0.51926, 0.52008, 0.52098,
-0.52246, 0.52326, 0.52318, 0.52314, 0.52226, 0.52326, 0.5248, 0.5247, 0.5241, 0.52454, 0.52568, 0.52556, 0.5257, 0.52636, 0.52616, 0.52598, 0.52652, 0.52636,
-0.52466, 0.52626, 0.52584, 0.52298, 0.52492, 0.52264, 0.52244, 0.52448, 0.52236, 0.52498, 
-//1263
-0.1015, 0.1168, 0.13768, 0.15496, 0.1644, 0.16656, 0.16476, 0.16254, 0.15888, 0.15598, 0.15382, 0.15266, 0.1524, 0.15058, 0.14786, 0.1457, 0.14482, 0.1435,
-0.14096, 0.13786, 0.13304, 0.1257, 0.12166, 0.11808, 0.11632, 0.11422, 0.11172, 0.10798, 0.10452, 0.10256, 0.1015, 0.10218, 0.10296, 0.10596, 0.10816, 0.10852,
-0.10946, 0.11138, 0.12012, 0.13884, 0.17346, 0.21842, 0.2659, 0.30648, 0.33832, 0.3591, 0.37224, 0.38184, 0.38468, 0.38778, 0.38962, 0.38988, 0.38914, 0.38978,
-0.3894, 0.38834, 0.38786, 0.38636, 0.38602, 0.3871, 0.3858, 0.38528, 0.38466, 0.38494, 0.38438, 0.38482, 0.38372, 0.38466, 0.38294, 0.3848, 0.38568, 0.38588,
-0.38504, 0.38326, 0.38426, 0.38394, 0.38374, 0.38376, 0.3831, 0.38202, 0.3837, 
-//1264
-0.08478, 0.09298, 0.10174, 0.105, 0.10602, 0.10472, 0.1045, 0.10176, 0.09996, 0.0988, 0.09802, 0.09748, 0.09738, 0.0975, 0.09568, 0.09358, 0.09092, 0.08918,
-0.08718, 0.0833, 0.07976, 0.07566, 0.07214, 0.0709, 0.06814, 0.0662, 0.06384, 0.0622, 0.0609, 0.06004, 0.05952, 0.0595, 0.05902, 0.0592, 0.0579, 0.05738,
-0.05802, 0.06064, 0.06582, 0.07462, 0.09204, 0.12004, 0.15458, 0.19294, 0.22798, 0.25308, 0.26984, 0.28092, 0.28786, 0.29044, 0.29374, 0.29316, 0.29354, 0.2923,
-0.29262, 0.29272, 0.29138, 0.29126, 0.29206, 0.29138, 0.29212, 0.29144, 0.29148, 0.29208, 0.29312, 0.29198, 0.29232, 0.2939, 0.29346, 0.29434, 0.29356, 0.29422,
-0.2942, 0.2913, 0.29292, 0.29214, 0.2937, 0.29264, 0.29244, 0.2915, 0.29164, 
-//1265
-0.10734, 0.1282, 0.16358, 0.20072, 0.22946, 0.24148, 0.2446, 0.24026, 0.23406, 0.22748, 0.22476, 0.22158, 0.21966, 0.21518, 0.2109, 0.20862, 0.20934, 0.20994,
-0.21126, 0.20764, 0.19974, 0.18862, 0.1799, 0.1734, 0.17374, 0.17348, 0.16936, 0.16152, 0.14802, 0.13808, 0.13318, 0.1324, 0.13788, 0.1503, 0.16134, 0.16932,
-0.17306, 0.18212, 0.2022, 0.23498, 0.28004, 0.33228, 0.38698, 0.43914, 0.4837, 0.52274, 0.5526, 0.57442, 0.59114, 0.60378, 0.61496, 0.62046, 0.62552, 0.63194,
-0.63474, 0.63658, 0.64068, 0.64306, 0.64206, 0.64532, 0.64474, 0.64532, 0.64782, 0.65328, 0.65462, 0.65728, 0.66184, 0.66402, 0.6648, 0.66776, 0.668, 0.66886,
-0.67402, 0.67614, 0.68136, 0.67952, 0.68074, 0.68272, 0.6843, 0.68724, 0.6904, 
-//1266
-0.10936, 0.12346, 0.14206, 0.15556, 0.163, 0.16366, 0.16212, 0.15872, 0.15566, 0.15242, 0.1504, 0.1492, 0.14666, 0.14652, 0.1446, 0.14154, 0.14044, 0.13812,
-0.13624, 0.13198, 0.12738, 0.12166, 0.11662, 0.11328, 0.11112, 0.1095, 0.10588, 0.10148, 0.09962, 0.09698, 0.09678, 0.0959, 0.09606, 0.0972, 0.09832, 0.09778,
-0.09636, 0.09664, 0.10166, 0.11486, 0.14036, 0.18294, 0.23582, 0.29438, 0.34976, 0.39828, 0.4352, 0.46354, 0.4834, 0.49714, 0.50472, 0.51024, 0.51302, 0.5146,
-0.5151, 0.5163, 0.51576, 0.51558, 0.51634, 0.51774, 0.51588, 0.51454, 0.51638, 0.51578, 0.51514, 0.51554, 0.5153, 0.51566, 0.51538, 0.51676, 0.51556, 0.5158,
-0.51902, 0.51542, 0.51588, 0.51724, 0.51592, 0.51686, 0.517, 0.5167, 0.5166, 
-//1267
-0.08528, 0.09554, 0.10348, 0.11174, 0.11486, 0.11334, 0.11184, 0.10858, 0.10546, 0.10332, 0.10012, 0.09874, 0.09542, 0.09184, 0.08964, 0.08764, 0.08554,
-0.08408, 0.08206, 0.07878, 0.0754, 0.07204, 0.07002, 0.06734, 0.06786, 0.0656, 0.06294, 0.06044, 0.0568, 0.0555, 0.05496, 0.05654, 0.05768, 0.05942, 0.06064,
-0.05912, 0.05864, 0.06066, 0.06268, 0.06682, 0.07478, 0.0881, 0.10804, 0.13366, 0.16858, 0.2098, 0.256, 0.3032, 0.34896, 0.39144, 0.42724, 0.45644, 0.47982,
-0.50058, 0.51542, 0.52822, 0.53838, 0.54338, 0.54632, 0.54602, 0.54042, 0.54164, 0.5457, 0.5541, 0.56736, 0.5783, 0.58956, 0.59956, 0.60776, 0.61816, 0.62708,
-0.6339, 0.64054, 0.64522, 0.6489, 0.65508, 0.6587, 0.6629, 0.6674, 0.66618, 0.67318, 
-//1268
-0.11378, 0.12842, 0.14764, 0.1625, 0.16906, 0.16886, 0.16696, 0.16232, 0.16, 0.15596, 0.15434, 0.15396, 0.1538, 0.1516, 0.14906, 0.14614, 0.14412, 0.14124,
-0.1387, 0.13276, 0.12832, 0.12158, 0.11658, 0.11198, 0.10864, 0.10612, 0.1022, 0.09824, 0.09416, 0.0934, 0.0931, 0.09184, 0.09288, 0.09168, 0.09104, 0.08982,
-0.08742, 0.08934, 0.09524, 0.10922, 0.13868, 0.1843, 0.24466, 0.31296, 0.38348, 0.45046, 0.50892, 0.55876, 0.59772, 0.6297, 0.65302, 0.66896, 0.67976, 0.68828,
-0.69558, 0.69994, 0.70428, 0.70706, 0.70908, 0.71202, 0.71288, 0.71748, 0.7185, 0.7209, 0.7244, 0.72612, 0.72884, 0.73086, 0.73112, 0.73376, 0.73486, 0.73462,
-0.74048, 0.73872, 0.74326, 0.74488, 0.74464, 0.74548, 0.74916, 0.74746, 0.7485, 
-//1269
-0.08006, 0.08656, 0.09516, 0.1005, 0.10428, 0.10226, 0.10212, 0.10088, 0.09944, 0.09834, 0.09746, 0.09642, 0.09528, 0.09384, 0.09096, 0.087, 0.08386, 0.07994,
-0.07608, 0.07168, 0.06798, 0.06382, 0.06154, 0.05922, 0.05718, 0.0555, 0.05346, 0.05102, 0.04924, 0.0481, 0.04766, 0.04642, 0.04688, 0.04656, 0.04644, 0.04588,
-0.04596, 0.04872, 0.05182, 0.0578, 0.06974, 0.08874, 0.11672, 0.15478, 0.19688, 0.24414, 0.29084, 0.33728, 0.37748, 0.41558, 0.4472, 0.47218, 0.49278, 0.50778,
-0.51938, 0.5298, 0.53874, 0.54568, 0.5516, 0.55926, 0.5623, 0.56782, 0.57296, 0.57904, 0.58792, 0.59516, 0.60332, 0.61074, 0.61878, 0.6243, 0.63262, 0.63692,
-0.6462, 0.64872, 0.6574, 0.66354, 0.66828, 0.67408, 0.68218, 0.68046, 0.68514, 
-];
\ No newline at end of file
+#[cfg(feature = "compress")]
+#[test]
+fn test_matt_blob() {
+    use scot::blob::Blob;
+    // the compressed blob decompresses to the same data as the blob
+    let blob = Blob::parse(include_bytes!("matt.bin")).unwrap();
+    assert_eq!(Blob::parse(include_bytes!("matt.bin.z")).unwrap(), blob);
+}
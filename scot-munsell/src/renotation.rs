@@ -50,16 +50,19 @@ Table I if found here as `MUNSELL_RENOTATION_DATA`.
 
 use std::collections::HashMap;
 
-use scot::{models::CieLab, observers::CieObs1931, illuminants::CieIllC};
+use scot::{models::CieLab, observers::{CieObs1931, StandardObserver}, illuminants::{CieIllC, Illuminant}};
+use scot::{swatches::Swatch, Domain, Meter, SpectralDistribution, Step, WavelengthStep};
 use nalgebra::{Matrix3x1, Matrix3xX};
 
+use crate::MunsellMatt;
+
 fn to_lab(x: f64, y: f64, yy: f64) -> CieLab<CieIllC, CieObs1931> {
     let xx = x * yy / y;
     let zz = (1.0 - x - y) * yy / y;
     let c = Matrix3x1::new(98.07171, 100.0, 118.22489);
     let m = Matrix3xX::from_vec(vec![xx, yy, zz]);
     let lab = scot::models::xyz_to_lab(&c, m);
-    CieLab::<CieIllC, CieObs1931>::new(lab)
+    CieLab::<CieIllC, CieObs1931>::new(lab.as_slice().to_vec())
 }
 
 
@@ -74,6 +77,104 @@ fn munsell_renotation(){
     println!("{:?}", mrd["7.5YR1/2"]);
 }
 
+/**
+Munsell chips as a swatch collection, with keys of the form "5R 5/10", and with their reference chromaticity, and
+luminance factor, values from the renotation table.
+
+The spectral reflectance data are the measured spectra of the 1269 chips of the Munsell Book of Color, matt edition,
+as used by `MunsellMatt`. Reference values are not available for chips with a value of 8.5, which is not included in the
+renotation table.
+
+# Examples
+```
+use scot_munsell::MunsellRenotation;
+use scot::SpectralDistribution;
+
+let keys = MunsellRenotation.keys().unwrap();
+assert_eq!(keys.len(), 1269);
+let [x, y, yy] = MunsellRenotation::xyy("5R 5/10").unwrap();
+println!("x: {:.3} y: {:.3} Y: {:.2}", x, y, yy);
+```
+*/
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MunsellRenotation;
+
+impl MunsellRenotation {
+    /// Reference chromaticity coordinates, and luminance factor, for illuminant C, and the CIE 1931 observer, of a
+    /// Munsell notation, with, or without, a space between its hue, and its value.
+    pub fn xyy(key: &str) -> Option<[f64; 3]> {
+        let key: String = key.chars().filter(|c| !c.is_whitespace()).collect();
+        MUNSELL_RENOTATION_DATA
+            .iter()
+            .find(|(k, ..)| *k == key)
+            .map(|&(_, x, y, yy)| [x, y, yy])
+    }
+
+    /// Reference CIELAB values, for illuminant C, and the CIE 1931 observer, of a Munsell notation.
+    pub fn reference_lab(key: &str) -> Option<[f64; 3]> {
+        let [x, y, yy] = Self::xyy(key)?;
+        let lab = to_lab(x, y, yy).data;
+        Some([lab[(0, 0)], lab[(1, 0)], lab[(2, 0)]])
+    }
+}
+
+/// Munsell notation with a space between its hue, and its value, such as "5R 5/10".
+fn spaced_key(key: &str) -> String {
+    match key.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(i) => {
+            let j = i + key[i..].find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(key.len() - i);
+            format!("{} {}", &key[..j], &key[j..])
+        }
+        None => key.to_string(),
+    }
+}
+
+impl SpectralDistribution for MunsellRenotation {
+    type MatrixType = <MunsellMatt as SpectralDistribution>::MatrixType;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        MunsellMatt.spd()
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        MunsellMatt.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        MunsellMatt.keys().map(|keys| keys.iter().map(|k| spaced_key(k)).collect())
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("Munsell Renotation, matt chips".to_string())
+    }
+}
+
+impl Swatch for MunsellRenotation {}
+
+impl<I, C> From<MunsellRenotation> for CieLab<I, C>
+where
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+    I: Illuminant,
+    C: StandardObserver,
+{
+    fn from(mr: MunsellRenotation) -> Self {
+        mr.lab()
+    }
+}
+
+#[test]
+fn test_munsell_renotation_swatch() {
+    let keys = MunsellRenotation.keys().unwrap();
+    assert_eq!(keys[0], "2.5R 9/2");
+    assert_eq!(spaced_key("10YR2.5/1"), "10YR 2.5/1");
+    assert_eq!(MunsellRenotation::xyy("5R 5/10"), MunsellRenotation::xyy("5R5/10"));
+    assert!(MunsellRenotation::xyy("5Y 8.5/2").is_none());
+
+    let [l, a, _] = MunsellRenotation::reference_lab("5R 5/10").unwrap();
+    assert!((l - 51.6).abs() < 0.1 && a > 30.0);
+}
+
 // "HV/C" => [x, y, Y], 
 pub const M: usize = 4995;
 //pub fn munsell_renotation_data() -> [(&'static str, f64, f64, f64); M] {[
@@ -9,6 +9,12 @@ pub use ohta::*;
 pub mod babel;
 pub use babel::*;
 
+pub mod tlci;
+pub use tlci::*;
+
+pub mod xrite2014;
+pub use xrite2014::*;
+
 pub(crate) const M:usize = 24;
 
 pub(crate) static CHECKER_KEYS: [&str; M] = [
@@ -78,6 +84,6 @@ pub static CHECKERLAB: [[f64;3];24] = [
 #[test]
 fn checker_ref() {
     use scot::models::CieLab;
-    let lab: CieLab = (&CHECKERLAB).into();
+    let lab: CieLab = CieLab::new(CHECKERLAB.iter().flatten().cloned().collect());
     println!("{}", lab.data.transpose());
 }
\ No newline at end of file
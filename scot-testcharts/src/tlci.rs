@@ -0,0 +1,188 @@
+/*!
+EBU TLCI-2012, the television lighting consistency index.
+
+The television lighting consistency index rates how well a light source renders colors on a television camera: the
+patches of a 24-patch Color Checker chart are recorded by a camera model, white-balanced to the source, and shown on
+a display model, and compared with the rendering of the same patches under a reference illuminant with the same
+correlated color temperature: a blackbody radiator up to 3400K, CIE daylight from 5000K, and a linear mix in between.
+
+The camera model, as described in EBU Tech 3355, consists of the red, green, and blue spectral sensitivities of a
+camera, and a 3×3 matrix to convert its white-balanced signals into ITU-R BT.709 linear RGB values.
+The camera data of the EBU standard camera is not included in this library: use a `DataObserver` with the tabulated
+sensitivities, and `TlciCamera::set_matrix`, to set up this camera, or any other camera.
+
+The display model applies the ITU-R BT.709 camera transfer function, and a display gamma of 2.4, and the color
+differences of the 18 colored patches are calculated in CIE L\*a\*b\*, for a D65 white point, with the CIEDE2000
+formula.
+These are combined into a single color difference value &Delta;E<sub>a</sub>, as their fourth-power mean, and the
+index is calculated as Q<sub>a</sub> = 100 / (1 + (&Delta;E<sub>a</sub>/3.16)<sup>2.4</sup>).
+All calculations use the domain of the Color Checker data, from 380 to 730nm, in steps of 10nm.
+*/
+
+use nalgebra::{matrix, DMatrix, DVector, Matrix3, Matrix3x1, Matrix3xX};
+use scot::differences::de2000;
+use scot::illuminants::{CctDuvCalc, Ohno2014Cascade, ReferenceIlluminant, ReferenceSelection};
+use scot::models::{cielab, CieYuv1960};
+use scot::observers::{CieObs1931, DataObserver, StandardObserver};
+use scot::{Domain, SpectralDistribution, WavelengthStep};
+
+use crate::CheckerBabel;
+
+/// Number of colored patches in the Color Checker chart, used for the consistency index.
+pub const TLCI_PATCHES: usize = 18;
+
+/// ITU-R BT.709 linear RGB to CIE XYZ matrix.
+const BT709: Matrix3<f64> = matrix![
+    0.4124564, 0.3575761, 0.1804375;
+    0.2126729, 0.7151522, 0.0721750;
+    0.0193339, 0.1191920, 0.9503041;
+];
+
+/**
+Camera model for the television lighting consistency index.
+
+Its spectral sensitivities are given as the three response functions of a `DataObserver`, and its matrix, which
+converts white-balanced camera signals into BT.709 linear RGB values, is the identity matrix, unless set with
+`set_matrix`.
+*/
+#[derive(Debug, Clone)]
+pub struct TlciCamera {
+    pub sensitivities: DataObserver,
+    pub matrix: Matrix3<f64>,
+}
+
+impl TlciCamera {
+    pub fn new(sensitivities: DataObserver) -> Self {
+        Self {
+            sensitivities,
+            matrix: Matrix3::identity(),
+        }
+    }
+
+    pub fn set_matrix(mut self, matrix: Matrix3<f64>) -> Self {
+        self.matrix = matrix;
+        self
+    }
+
+    /// CIELAB values of the Color Checker patches, as shown on the display, for a source on the chart domain.
+    fn render(&self, s: &DVector<f64>) -> Matrix3xX<f64> {
+        let (d, r) = CheckerBabel.spd();
+        let l = DMatrix::from_column_slice(s.len(), 1, s.as_slice());
+        let m = DMatrix::from_column_slice(r.nrows(), r.ncols(), r.as_slice());
+        let (w, rgb) = self.sensitivities.xyz_from_dom_ill_mat(d, l, m);
+        let mut rgb = Matrix3xX::from_fn(rgb.ncols(), |i, j| rgb[(i, j)] / w[i]);
+        rgb = self.matrix * rgb;
+        rgb.apply(|v| bt709_oetf(v.max(0.0)).powf(2.4));
+        let xyz_n = BT709 * Matrix3x1::repeat(100.0);
+        cielab(xyz_n, BT709 * rgb * 100.0)
+    }
+}
+
+/**
+Television lighting consistency index Q<sub>a</sub> of a light source, calculated according to EBU TLCI-2012.
+
+Only the first spectral distribution of a source is used.
+
+# Examples
+A camera with Gaussian shaped sensitivities, and a fluorescent lamp.
+```
+use nalgebra::Matrix3xX;
+use scot::illuminants::FL;
+use scot::observers::DataObserver;
+use scot::{Domain, NM};
+use scot_testcharts::{Tlci, TlciCamera};
+
+let gauss = |l: f64, c: f64| (-((l - c) / 40.0).powi(2)).exp();
+let rgb = Matrix3xX::from_fn(401, |i, j| gauss(380.0 + j as f64, [600.0, 540.0, 450.0][i]));
+let camera = TlciCamera::new(DataObserver::new(Domain::new(380, 780, NM), rgb));
+
+let tlci = Tlci::new(&camera, &FL::<2>);
+println!("Qa {:.0}", tlci.qa);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tlci {
+    /// Television lighting consistency index.
+    pub qa: f64,
+    /// CIEDE2000 color differences of the 18 colored patches.
+    pub de: Vec<f64>,
+    /// Correlated color temperature of the source, in Kelvin, which is also the temperature of the reference.
+    pub cct: f64,
+    /// Distance of the source to the Planckian locus, in the CIE 1960 UCS diagram.
+    pub duv: f64,
+}
+
+impl Tlci {
+    pub fn new<S>(camera: &TlciCamera, source: &S) -> Self
+    where
+        S: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let d_cct = Domain::default();
+        let xyz = CieObs1931::values(&d_cct) * source.map_domain(d_cct.clone()).column(0);
+        let den = xyz.x + 15.0 * xyz.y + 3.0 * xyz.z;
+        let yuv = CieYuv1960::<CieObs1931>::new(Matrix3xX::from_column_slice(&[
+            1.0,
+            4.0 * xyz.x / den,
+            6.0 * xyz.y / den,
+        ]));
+        let td = Ohno2014Cascade::<CieObs1931>::new()
+            .cct_duv(yuv)
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let d = CheckerBabel.spd().0;
+        let s = source.map_domain(d.clone()).column(0).into_owned();
+        let reference = ReferenceIlluminant::new(td.t, ReferenceSelection::Tlci).set_domain(d);
+        let s_r = reference.spd().1.column(0).into_owned();
+
+        let test = camera.render(&s);
+        let refs = camera.render(&s_r);
+        let de: Vec<f64> = test
+            .column_iter()
+            .zip(refs.column_iter())
+            .take(TLCI_PATCHES)
+            .map(|(t, r)| de2000(t.x, t.y, t.z, r.x, r.y, r.z))
+            .collect();
+        let de_a = (de.iter().map(|e| e.powi(4)).sum::<f64>() / de.len() as f64).powf(0.25);
+        Self {
+            qa: 100.0 / (1.0 + (de_a / 3.16).powf(2.4)),
+            de,
+            cct: td.t,
+            duv: td.d,
+        }
+    }
+}
+
+/// ITU-R BT.709 camera opto-electronic transfer function.
+fn bt709_oetf(l: f64) -> f64 {
+    if l < 0.018 {
+        4.5 * l
+    } else {
+        1.099 * l.powf(0.45) - 0.099
+    }
+}
+
+#[test]
+fn test_tlci() {
+    use approx::assert_abs_diff_eq;
+    use scot::illuminants::{CieIllA, FL};
+    use scot::NM;
+
+    let gauss = |l: f64, c: f64| (-((l - c) / 40.0).powi(2)).exp();
+    let rgb = Matrix3xX::from_fn(401, |i, j| gauss(380.0 + j as f64, [600.0, 540.0, 450.0][i]));
+    let camera = TlciCamera::new(DataObserver::new(Domain::new(380, 780, NM), rgb));
+
+    // illuminant A, and a reference illuminant in the blending range, are their own references
+    let a = Tlci::new(&camera, &CieIllA);
+    assert_abs_diff_eq!(a.qa, 100.0, epsilon = 1E-3);
+    assert_eq!(a.de.len(), TLCI_PATCHES);
+    let r = Tlci::new(&camera, &ReferenceIlluminant::new(4200.0, ReferenceSelection::Tlci));
+    assert_abs_diff_eq!(r.cct, 4200.0, epsilon = 1.0);
+    assert_abs_diff_eq!(r.qa, 100.0, epsilon = 0.1);
+
+    // a broadband fluorescent lamp renders better than a halophosphate lamp
+    let f2 = Tlci::new(&camera, &FL::<2>);
+    let f7 = Tlci::new(&camera, &FL::<7>);
+    assert!(f2.qa < f7.qa && f7.qa < 100.0, "{} {}", f2.qa, f7.qa);
+}
@@ -0,0 +1,154 @@
+/*!
+Color Checker charts, in the X-Rite formulation produced since November 2014.
+
+In November 2014, X-Rite changed the pigment formulation of its Color Checker charts, and published new reference
+CIELAB values, for illuminant D50, and the CIE 1931 standard observer. Comparing measurements of a new chart with
+legacy reference data, such as `CHECKERLAB`, `CheckerBabel`, or `CheckerOhta`, results in systematic errors, of more
+than 2 &Delta;E<sub>76</sub> for the white patch.
+
+X-Rite did not publish reflectance spectra for the new formulation, and only its reference CIELAB values,
+`CHECKERLAB_2014`, are published data. The spectra of `SyntheticCheckerXRite2014` are synthetic, estimated and not
+measured: they are the average BabelColor spectra of the legacy chart, `CheckerBabel`, each with the smallest
+correction, in the least squares sense, which results in the post-2014 reference CIELAB values for D50 and the CIE
+1931 observer. They are intended for the comparison with measurements of new charts, and for other illuminants and
+observers their colorimetric values are approximations.
+
+# Examples
+```
+use scot::{illuminants::D50, models::CieLab, observers::CieObs1931};
+use scot_testcharts::{SyntheticCheckerXRite2014, CHECKERLAB_2014};
+
+let lab: CieLab<D50, CieObs1931> = SyntheticCheckerXRite2014.into();
+assert!((lab.data[(0, 18)] - CHECKERLAB_2014[18][0]).abs() < 1E-6);
+```
+*/
+
+use std::sync::OnceLock;
+
+use nalgebra::{DMatrix, Matrix3xX};
+use scot::illuminants::{CieIllD50, Illuminant};
+use scot::models::{lab_to_xyz, CieLab};
+use scot::observers::{CieObs1931, StandardObserver};
+use scot::swatches::Swatch;
+use scot::{Domain, Meter, SpectralDistribution, Step, Unit, WavelengthStep, NM10};
+
+use crate::{CheckerBabel, CHECKER_KEYS, M};
+
+/**
+CIE L\*a\*b\* values for D50, and the CIE 1931 observer, of the Color Checker charts produced since November 2014.
+
+As supplied by X-Rite, for personal and educational use only. Not for commercial use.
+*/
+pub static CHECKERLAB_2014: [[f64; 3]; M] = [
+    [37.54, 14.37, 14.92],
+    [64.66, 19.27, 17.5],
+    [49.32, -3.82, -22.54],
+    [43.46, -12.74, 22.72],
+    [54.94, 9.61, -24.79],
+    [70.48, -32.26, -0.37],
+    [62.73, 35.83, 56.5],
+    [39.43, 10.75, -45.17],
+    [50.57, 48.64, 16.67],
+    [30.1, 22.54, -20.87],
+    [71.77, -24.13, 58.19],
+    [71.51, 18.24, 67.37],
+    [28.37, 15.42, -49.8],
+    [54.38, -39.72, 32.27],
+    [42.43, 51.05, 28.62],
+    [81.8, 2.67, 80.41],
+    [50.63, 51.28, -14.12],
+    [49.57, -29.71, -28.32],
+    [95.19, -1.03, 2.93],
+    [81.29, -0.57, 0.44],
+    [66.89, -0.75, -0.06],
+    [50.76, -0.13, 0.14],
+    [35.63, -0.46, -0.48],
+    [20.64, 0.07, -0.46],
+];
+
+const N: usize = 36;
+
+/// Synthetic reflectance spectra of the Color Checker charts produced since November 2014, estimated from the legacy
+/// BabelColor spectra, and the post-2014 reference CIELAB values.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SyntheticCheckerXRite2014;
+
+impl SpectralDistribution for SyntheticCheckerXRite2014 {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        static DATA: OnceLock<DMatrix<f64>> = OnceLock::new();
+        (
+            Domain::new(380 / 10, 730 / 10, NM10),
+            DATA.get_or_init(estimate).clone(),
+        )
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (N, M)
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        Some(CHECKER_KEYS.iter().map(|s| s.to_string()).collect())
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("Synthetic Color Checker, estimated for the X-Rite formulation since November 2014".to_string())
+    }
+}
+
+impl Swatch for SyntheticCheckerXRite2014 {}
+
+impl<I, C> From<SyntheticCheckerXRite2014> for CieLab<I, C>
+where
+    I: Illuminant,
+    C: StandardObserver,
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+{
+    fn from(sw: SyntheticCheckerXRite2014) -> Self {
+        sw.lab()
+    }
+}
+
+/// Legacy spectra, with a minimum norm correction to the post-2014 reference tristimulus values.
+fn estimate() -> DMatrix<f64> {
+    let (d, s) = CheckerBabel.spd();
+    let cmf = CieObs1931::values_cached(&d);
+    let l = CieIllD50.map_domain(d.clone());
+    let dl = d.step.unitvalue(1).value();
+    let a = Matrix3xX::from_fn(N, |r, i| cmf[(r, i)] * l[(i, 0)] * CieObs1931::K * dl);
+    let xyzn = a.column_sum();
+    let correction = a.transpose() * (&a * a.transpose()).try_inverse().unwrap();
+    let mut data = DMatrix::from_fn(N, M, |i, j| s[(i, j)]);
+    for (j, lab) in CHECKERLAB_2014.iter().enumerate() {
+        // tristimulus values, scaled to the white point
+        let xyz = lab_to_xyz(xyzn, Matrix3xX::from_column_slice(lab)).column(0) * (xyzn.y / 100.0);
+        let dr = &correction * (xyz - &a * data.column(j));
+        let mut c = data.column_mut(j);
+        c += dr;
+    }
+    data
+}
+
+#[test]
+fn test_synthetic_checker_xrite_2014() {
+    use approx::assert_abs_diff_eq;
+    use scot::illuminants::CieIllD50;
+
+    let lab: CieLab<CieIllD50, CieObs1931> = SyntheticCheckerXRite2014.into();
+    for (j, ref_lab) in CHECKERLAB_2014.iter().enumerate() {
+        for (k, v) in ref_lab.iter().enumerate() {
+            assert_abs_diff_eq!(lab.data[(k, j)], v, epsilon = 1E-6);
+        }
+    }
+
+    // small corrections of the legacy spectra, which remain within the range of reflectance values
+    let (_, s) = SyntheticCheckerXRite2014.spd();
+    let (_, legacy) = CheckerBabel.spd();
+    assert!(s.iter().all(|&v| v > 0.0 && v < 1.0));
+    assert!(s
+        .iter()
+        .zip(legacy.iter())
+        .all(|(a, b)| (a - b).abs() < 0.06));
+}
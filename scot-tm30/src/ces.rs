@@ -16,12 +16,14 @@ Lighting Laboratory of the Helsinki University of Technology, through Wikipedia
 */
 
 pub use scot::swatch;
+use scot::blob::LazyBlob;
+use scot::include_blob;
 
 const N: usize = 401; // number of points in a spectral distributions, and the number of rows in the column major spectral matrix
 const M: usize = 99; // number of spectra in the set, or the number of columns in the spectral matrix
 
-swatch!(CesSwatch, N, M, "CES{}", scot::Domain::new(380, 780, scot::NM), CES_DATA);
-swatch!(Ces, N, M, "CES", scot::Domain::new(380, 780, scot::NM), CES_DATA, CES_KEYS);
+swatch!(CesSwatch, N, M, "CES{}", scot::Domain::new(380, 780, scot::NM), CES.data());
+swatch!(Ces, N, M, "CES", scot::Domain::new(380, 780, scot::NM), CES.data(), CES.keys());
 
 
 #[test]
@@ -41,21 +43,20 @@ fn test_ciede76(){
 	use scot::observers::{CieObs1931};
 	use scot::illuminants::{CieIllD65};
 	use crate::ces::Ces;
-	use scot::swatches::{ColorCheckerSwatch};
+	use scot_testcharts::CheckerBabel;
 	use scot::differences::{CieDE1994, GraphicArts, DeltaEValues};
-	let de = CieDE1994::<CieIllD65, CieObs1931, GraphicArts>::new(ColorCheckerSwatch::<13>, Ces);
-	println!("{:.0}", de.0);
+	let de = CieDE1994::<CieIllD65, CieObs1931, GraphicArts>::new(CheckerBabel, Ces);
 	let m = de.matches();
-	let mut prev = 0f64;
-	println!("{}", m);
+	assert_eq!(m.shape(), (99, 24));
 	// check if error differences are in increasing order
-	
-	for i in 0..m.nrows() {
-		let ind = m[(i,0)];
-		let v = de.0[(ind,0)];
-		assert!(v>prev);
-		prev = v;
-		println!("{} {} {:.1}", i, ind, v);
+	for j in 0..m.ncols() {
+		let mut prev = 0f64;
+		for i in 0..m.nrows() {
+			let ind = m[(i,j)];
+			let v = de.0[(ind,j)];
+			assert!(v>prev);
+			prev = v;
+		}
 	}
 	
 }
@@ -65,3039 +66,27 @@ fn test_ciede76_2(){
 	use scot::observers::{CieObs1931};
 	use scot::illuminants::{CieIllD65};
 	use crate::ces::Ces;
-	use scot::swatches::{ColorCheckerSwatch};
+	use scot_testcharts::CheckerBabel;
 	use scot::differences::{CieDE1976, DeltaEValues};
-	let de = CieDE1976::<CieIllD65, CieObs1931>::from((ColorCheckerSwatch::<13>, Ces));
-//	println!("{:.1}", de);
+	let de = CieDE1976::<CieIllD65, CieObs1931>::from((CheckerBabel, Ces));
 	let m = de.matches();
 	let mut prev = 0f64;
-	// check if error differences are in increasing order
+	// check if error differences are in increasing order, for the "blue" swatch
 	for i in 0..m.nrows() {
-		let ind = m[(i,0)];
-		let v = de.0[(ind,0)];
+		let ind = m[(i,12)];
+		let v = de.0[(ind,12)];
 		assert!(v>prev);
 		prev = v;
-	//	println!("{} {} {:.1}", i, ind, v);
 	}
 }
 
+#[cfg(feature = "compress")]
+#[test]
+fn test_ces_blob() {
+	use scot::blob::Blob;
+	// the compressed blob decompresses to the same data as the blob
+	let blob = Blob::parse(include_bytes!("ces.bin")).unwrap();
+	assert_eq!(Blob::parse(include_bytes!("ces.bin.z")).unwrap(), blob);
+}
 
-static CES_KEYS: [&str;M] = [
-	"CES01", "CES02", "CES03", "CES04", "CES05", "CES06", "CES07", "CES08", "CES09", "CES10", "CES11", "CES12", "CES13", "CES14", "CES15", "CES16", "CES17", "CES18", "CES19", "CES20", "CES21", "CES22",
-	"CES23", "CES24", "CES25", "CES26", "CES27", "CES28", "CES29", "CES30", "CES31", "CES32", "CES33", "CES34", "CES35", "CES36", "CES37", "CES38", "CES39", "CES40", "CES41", "CES42", "CES43", "CES44",
-	"CES45", "CES46", "CES47", "CES48", "CES49", "CES50", "CES51", "CES52", "CES53", "CES54", "CES55", "CES56", "CES57", "CES58", "CES59", "CES60", "CES61", "CES62", "CES63", "CES64", "CES65", "CES66",
-	"CES67", "CES68", "CES69", "CES70", "CES71", "CES72", "CES73", "CES74", "CES75", "CES76", "CES77", "CES78", "CES79", "CES80", "CES81", "CES82", "CES83", "CES84", "CES85", "CES86", "CES87", "CES88",
-	"CES89", "CES90", "CES91", "CES92", "CES93", "CES94", "CES95", "CES96", "CES97", "CES98", "CES99"
-];
-
-
-static CES_DATA: [f64;N*M] = [
-/* CES01 */ 0.6359, 0.6359, 0.6359, 0.6359, 0.6359, 0.6359, 0.6359, 0.6359, 0.6359, 0.6359, 0.6359, 0.6359068, 0.635934, 0.6359612, 0.6359544, 0.6359,
-0.6358064, 0.6356632, 0.635554, 0.6356068, 0.6359, 0.636418768, 0.63716784, 0.638094912, 0.639110144, 0.64015, 0.641219392, 0.642315072, 0.643402592,
-0.644440592, 0.6454, 0.646271744, 0.647043712, 0.6477236, 0.648331328, 0.64888, 0.64936208, 0.64977224, 0.65012176, 0.65042752, 0.6507, 0.650937568, 0.65114088,
-0.651303952, 0.651416224, 0.65147, 0.651466144, 0.651405152, 0.65127976, 0.651080128, 0.6508, 0.65043912, 0.64999584, 0.6494748, 0.64888432, 0.64823,
-0.647509408, 0.64671976, 0.645866752, 0.644958864, 0.644, 0.642987152, 0.641919232, 0.640784592, 0.639565792, 0.63825, 0.636838144, 0.635332432, 0.63371888,
-0.631978128, 0.6301, 0.628088816, 0.62594592, 0.623690224, 0.621350848, 0.61895, 0.616489344, 0.613970544, 0.611415424, 0.608852464, 0.6063, 0.603759712,
-0.601236784, 0.598718496, 0.596181568, 0.59361, 0.591009856, 0.588387856, 0.585726896, 0.583001856, 0.5802, 0.577328752, 0.574391216, 0.57141296, 0.568432704,
-0.56548, 0.562556704, 0.55966376, 0.556832016, 0.554102352, 0.5515, 0.549024288, 0.546679456, 0.544443744, 0.542279712, 0.54016, 0.538087296, 0.536063424,
-0.534062272, 0.53204848, 0.53, 0.52791784, 0.525798992, 0.523659184, 0.521526016, 0.51942, 0.517340448, 0.515288224, 0.51328216, 0.511347056, 0.5095,
-0.507743936, 0.50608408, 0.504517264, 0.503034528, 0.50163, 0.500307232, 0.499068544, 0.497908976, 0.496821088, 0.4958, 0.49484576, 0.493957296, 0.493136352,
-0.492386528, 0.49171, 0.491104704, 0.490568272, 0.49010312, 0.489713248, 0.4894, 0.489160848, 0.48899448, 0.488892832, 0.488844224, 0.48884, 0.488880576,
-0.488967472, 0.489090928, 0.489237424, 0.4894, 0.489582384, 0.489786608, 0.490025392, 0.490318016, 0.49068, 0.491115488, 0.491629632, 0.492237136, 0.49295592,
-0.4938, 0.494775984, 0.495891616, 0.497151168, 0.49855632, 0.50011, 0.50181872, 0.503687968, 0.505722016, 0.507924784, 0.5103, 0.512851088, 0.515580976,
-0.518493344, 0.521592032, 0.52488, 0.528357568, 0.532023664, 0.53588264, 0.539940656, 0.5442, 0.548656624, 0.55330696, 0.558142256, 0.563150272, 0.56832,
-0.573644128, 0.579113376, 0.584718704, 0.590451152, 0.5963, 0.602252832, 0.608297744, 0.614416336, 0.620586848, 0.62679, 0.633014144, 0.639248256, 0.645472048,
-0.65166208, 0.6578, 0.663876192, 0.669879888, 0.675806944, 0.6816576, 0.68743, 0.69311616, 0.698709744, 0.704206928, 0.709604672, 0.7149, 0.72008952,
-0.725170288, 0.730140096, 0.734997184, 0.73974, 0.744367488, 0.748879376, 0.753273584, 0.757547472, 0.7617, 0.76573232, 0.769645184, 0.773443168, 0.777132992,
-0.78072, 0.784205504, 0.787591008, 0.790881472, 0.794082976, 0.7972, 0.800234224, 0.803187792, 0.80606072, 0.808851648, 0.81156, 0.814187488, 0.81673552,
-0.819203952, 0.821592144, 0.8239, 0.826128608, 0.828278992, 0.830352016, 0.83234864, 0.83427, 0.83611792, 0.837894816, 0.839600912, 0.841235648, 0.8428,
-0.84429712, 0.845729552, 0.847103744, 0.848428256, 0.84971, 0.850951072, 0.852153264, 0.853323536, 0.854470528, 0.8556, 0.856713056, 0.857811616, 0.858892656,
-0.859950176, 0.86098, 0.86198352, 0.862961936, 0.863911072, 0.864824928, 0.8657, 0.8665372, 0.867336672, 0.868101824, 0.868838176, 0.86955, 0.870237696,
-0.870901824, 0.871546752, 0.87217816, 0.8728, 0.873413136, 0.874018992, 0.874616768, 0.875204224, 0.87578, 0.876345408, 0.876901648, 0.877447408, 0.877980528,
-0.8785, 0.879006832, 0.879501648, 0.879986704, 0.88046528, 0.88094, 0.881411456, 0.881880224, 0.882349072, 0.88282152, 0.8833, 0.883784768, 0.884276112,
-0.884774256, 0.88527904, 0.88579, 0.886305952, 0.886824896, 0.8873468, 0.887872224, 0.8884, 0.8889264, 0.88944792, 0.88995792, 0.89044784, 0.89091, 0.891343616,
-0.89175144, 0.892113744, 0.892401888, 0.8926, 0.892711184, 0.892731504, 0.892690784, 0.892636544, 0.8926, 0.892578368, 0.892575664, 0.89258648, 0.892597296,
-0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926,
-0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926,
-0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926,
-0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926,
-/* CES02 */ 0.2615, 0.2615, 0.2615, 0.2615, 0.2615, 0.2615, 0.2615, 0.2615, 0.2615, 0.2615, 0.2615, 0.261504144, 0.26152072, 0.261537296, 0.261533152, 0.2615,
-0.261442496, 0.261353376, 0.261284976, 0.261317616, 0.2615, 0.26182184, 0.262285376, 0.262855072, 0.263470848, 0.26409, 0.264715232, 0.265344352, 0.265952992,
-0.266511632, 0.267, 0.267412736, 0.267742112, 0.267995888, 0.268191264, 0.26834, 0.268437664, 0.268481488, 0.268481312, 0.268451136, 0.2684, 0.268327456,
-0.268234512, 0.268117968, 0.267971424, 0.26779, 0.267573664, 0.267321568, 0.267029072, 0.266690176, 0.2663, 0.265856048, 0.265355232, 0.264795216, 0.26417424,
-0.26349, 0.262738752, 0.261916656, 0.26102112, 0.260049904, 0.259, 0.257867936, 0.25665112, 0.255341984, 0.253930688, 0.25241, 0.2507808, 0.249046224,
-0.247196208, 0.245216192, 0.2431, 0.240854144, 0.238482368, 0.236006192, 0.233457216, 0.23086, 0.228218176, 0.225534992, 0.222835008, 0.220149824, 0.2175,
-0.214888096, 0.212320368, 0.20978344, 0.207252352, 0.20471, 0.202162784, 0.19961728, 0.197056336, 0.194454912, 0.1918, 0.189098368, 0.186352656, 0.183586624,
-0.180836512, 0.17813, 0.175468768, 0.172853744, 0.170314, 0.167888096, 0.1656, 0.163449136, 0.16143928, 0.159551584, 0.157753248, 0.15602, 0.154352896,
-0.152751344, 0.151193232, 0.14964936, 0.1481, 0.146542656, 0.144972192, 0.143393888, 0.141820224, 0.14026, 0.138711136, 0.137173728, 0.13565408, 0.134160512,
-0.1327, 0.131275824, 0.1298916, 0.128553296, 0.127267552, 0.12604, 0.12487344, 0.123769856, 0.122735312, 0.121777168, 0.1209, 0.120104096, 0.119390352,
-0.118754608, 0.118189664, 0.11769, 0.117254944, 0.116883008, 0.116569872, 0.116310336, 0.1161, 0.115936352, 0.115816752, 0.115737952, 0.115696672, 0.11569,
-0.115715808, 0.115772272, 0.115856816, 0.11596672, 0.1161, 0.11625592, 0.116433936, 0.116633792, 0.116855648, 0.1171, 0.117368128, 0.117662192, 0.117982256,
-0.11832776, 0.1187, 0.119103264, 0.119541856, 0.120022208, 0.12055232, 0.12114, 0.121792448, 0.122518368, 0.123324128, 0.124215328, 0.1252, 0.12628928,
-0.127493568, 0.128830016, 0.130319424, 0.13198, 0.133821952, 0.135854976, 0.13809824, 0.140574144, 0.1433, 0.146283792, 0.14953416, 0.153055888, 0.156850496,
-0.16092, 0.16526784, 0.169894368, 0.174804816, 0.180006064, 0.1855, 0.191281216, 0.197344976, 0.203677136, 0.210257856, 0.21707, 0.224104544, 0.231350496,
-0.238792688, 0.24641456, 0.2542, 0.262134864, 0.270204768, 0.278392592, 0.286680096, 0.29505, 0.30348784, 0.311979472, 0.320507424, 0.329053136, 0.3376,
-0.346135072, 0.354645424, 0.363118816, 0.371543968, 0.37991, 0.388207136, 0.396427376, 0.404558256, 0.412585856, 0.4205, 0.428295904, 0.435967936, 0.443518448,
-0.4509544, 0.45828, 0.465490912, 0.472583088, 0.479561024, 0.48643216, 0.4932, 0.499861456, 0.506415104, 0.512852352, 0.51916048, 0.52533, 0.531360864,
-0.537253632, 0.54299872, 0.548583248, 0.554, 0.559250848, 0.564336, 0.569265712, 0.574056224, 0.57872, 0.583258304, 0.587672912, 0.59197552, 0.596181088,
-0.6003, 0.604334816, 0.60828968, 0.612161584, 0.615943168, 0.61963, 0.623226592, 0.626737664, 0.630158256, 0.633480128, 0.6367, 0.639822816, 0.642851856,
-0.645798816, 0.648680416, 0.65151, 0.654290688, 0.657025456, 0.659727984, 0.662415552, 0.6651, 0.667784016, 0.670471984, 0.673159712, 0.67583776, 0.6785,
-0.681150336, 0.683792432, 0.686420208, 0.689024304, 0.6916, 0.694149728, 0.696673568, 0.699182688, 0.701694208, 0.70422, 0.706756448, 0.709297888, 0.711857728,
-0.714455648, 0.7171, 0.719782464, 0.722499168, 0.725219392, 0.727897696, 0.7305, 0.73302656, 0.735480672, 0.737824704, 0.740007296, 0.742, 0.743810208,
-0.745437184, 0.7469264, 0.748349696, 0.74976, 0.75115344, 0.75252368, 0.75392624, 0.75543728, 0.7571, 0.75890176, 0.7608384, 0.76285856, 0.7648808, 0.76684,
-0.768739104, 0.77059344, 0.772287296, 0.773654912, 0.7746, 0.775133376, 0.775229536, 0.775031136, 0.774770656, 0.7746, 0.774500672, 0.774488256, 0.77453792,
-0.774587584, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746,
-0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746,
-0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746,
-0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746,
-/* CES03 */ 0.00001, 0.000759472, 0.00180792, 0.003150688, 0.004770176, 0.00663, 0.008713344, 0.011029968, 0.013365072, 0.015403696, 0.01694, 0.017983312,
-0.018506992, 0.018565552, 0.018277952, 0.01773, 0.016908928, 0.015820512, 0.014530256, 0.01312328, 0.01167, 0.010198976, 0.008738096, 0.007353936, 0.006125216,
-0.00511, 0.004330176, 0.003810816, 0.003555216, 0.003550816, 0.00379, 0.004280496, 0.005020416, 0.006010736, 0.007253216, 0.00874, 0.010461184, 0.012417296,
-0.014536448, 0.01671368, 0.01888, 0.02102352, 0.023106608, 0.025187616, 0.027373824, 0.02972, 0.032196544, 0.034811216, 0.037435088, 0.03986928, 0.04199,
-0.043808864, 0.045298848, 0.046554512, 0.047740896, 0.04896, 0.05018144, 0.051402672, 0.052584384, 0.053656096, 0.05457, 0.055347472, 0.056014464, 0.056516256,
-0.056770848, 0.05674, 0.056443408, 0.055873456, 0.055165904, 0.054528752, 0.0541, 0.053865792, 0.053831104, 0.053998816, 0.054351728, 0.05487, 0.055565136,
-0.056459056, 0.057442976, 0.058351456, 0.05908, 0.05963488, 0.059992016, 0.060221392, 0.060448368, 0.06075, 0.061099904, 0.061492672, 0.06190144, 0.062278368,
-0.06259, 0.062847104, 0.06306224, 0.063198016, 0.063200032, 0.06304, 0.06272528, 0.062247136, 0.061678752, 0.061134848, 0.06069, 0.060331584, 0.060057792,
-0.05986592, 0.059741888, 0.05967, 0.059657968, 0.0597252, 0.059787792, 0.059717984, 0.05944, 0.0589648, 0.058275072, 0.057461744, 0.056674896, 0.05601,
-0.055448432, 0.054993984, 0.054620256, 0.054274208, 0.05392, 0.053572464, 0.053244496, 0.052904048, 0.0525044, 0.05202, 0.051453984, 0.050795168, 0.050101952,
-0.049467536, 0.04895, 0.048537568, 0.048232512, 0.048007856, 0.04781504, 0.04762, 0.047428576, 0.047243216, 0.047040416, 0.046789216, 0.04647, 0.046085152,
-0.045636496, 0.04512904, 0.044572864, 0.04398, 0.04335408, 0.04269408, 0.0420452, 0.0414728, 0.04102, 0.04068296, 0.04046176, 0.04035608, 0.04035888, 0.04046,
-0.04065888, 0.04096024, 0.04131072, 0.04163128, 0.04187, 0.042030528, 0.04210872, 0.042112032, 0.042060384, 0.04197, 0.041836176, 0.041652672, 0.041456928,
-0.041304224, 0.04123, 0.04123328, 0.041321888, 0.041476256, 0.041659984, 0.04185, 0.042048688, 0.042247696, 0.042463744, 0.042726752, 0.04305, 0.043429488,
-0.043879744, 0.04433024, 0.044671216, 0.04484, 0.044849504, 0.04468464, 0.044442096, 0.044277712, 0.04429, 0.044461872, 0.044800416, 0.0452672, 0.045789824,
-0.04632, 0.046868896, 0.04743744, 0.048013344, 0.048584208, 0.04914, 0.049675168, 0.050185664, 0.0506652, 0.051107616, 0.05151, 0.051874976, 0.05220696,
-0.052509664, 0.052787968, 0.05305, 0.053303744, 0.053554544, 0.053832944, 0.054181184, 0.05463, 0.0551812, 0.055835504, 0.056613568, 0.057540832, 0.05863,
-0.059883344, 0.061313728, 0.062867712, 0.064460496, 0.06604, 0.067613984, 0.069173152, 0.07075912, 0.072443248, 0.07427, 0.07622336, 0.0782948, 0.08048088,
-0.08277312, 0.08516, 0.08763928, 0.0902156, 0.09283376, 0.09541248, 0.0979, 0.100290832, 0.10255904, 0.104757888, 0.106981136, 0.10928, 0.111635264,
-0.114056928, 0.116452592, 0.118676656, 0.12064, 0.122362736, 0.123842592, 0.125133248, 0.126327104, 0.12749, 0.128616528, 0.129708368, 0.130807408, 0.131966128,
-0.13322, 0.1345716, 0.136023728, 0.137575376, 0.139218224, 0.14094, 0.142740544, 0.144627776, 0.146533408, 0.14835576, 0.15003, 0.151566096, 0.152964528,
-0.15424144, 0.155430112, 0.15656, 0.15762752, 0.1586212, 0.15962152, 0.1607468, 0.16207, 0.163581616, 0.16528952, 0.167142064, 0.169049888, 0.17095,
-0.172840384, 0.174703104, 0.176527344, 0.178310704, 0.18004, 0.181703152, 0.183312224, 0.184762976, 0.185901248, 0.18664, 0.18700832, 0.187010976, 0.186755712,
-0.186413008, 0.18611, 0.185839168, 0.185585632, 0.185493136, 0.18575984, 0.1865, 0.18770136, 0.189391136, 0.191393712, 0.193424128, 0.19529, 0.197025664,
-0.198649872, 0.20008784, 0.201250608, 0.2021, 0.202626016, 0.20276888, 0.202788544, 0.203092928, 0.20393, 0.205261552, 0.207132704, 0.209320736, 0.211449088,
-0.21328, 0.214863392, 0.216185696, 0.21733008, 0.218454864, 0.21966, 0.220911936, 0.22220384, 0.223525744, 0.224852768, 0.22617, 0.227487392, 0.228809904,
-0.230137696, 0.231471008, 0.23281, 0.234154656, 0.235504976, 0.236860976, 0.238222656, 0.23959, 0.240962992, 0.242341616, 0.24372576, 0.245115264, 0.24651,
-0.247909968, 0.2493152, 0.250725472, 0.252140464, 0.25356, 0.254984096, 0.256412672, 0.257846128, 0.259285104, 0.26073, 0.262180752, 0.263637408, 0.265099744,
-0.26656736, 0.26804, 0.269517712, 0.271000464, 0.272488336, 0.273981488, 0.27548, 0.276983808, 0.278492896, 0.280007104, 0.281526192, 0.28305, 0.284578528,
-0.286111744, 0.28764968, 0.289192416, 0.29074, 0.292292384, 0.29384952, 0.295411456, 0.296978272, 0.29855, 0.300126624, 0.301708176, 0.303294448, 0.30488512,
-0.30648, 0.308065872, 0.309616208, 0.311170944, 0.31279664, 0.31452, 0.316327552, 0.318245424, 0.320101936, 0.321633008, 0.32268,
-/* CES04 */ 0.47376, 0.474683184, 0.476040128, 0.477419552, 0.478184096, 0.47793, 0.476719408, 0.474528752, 0.471509776, 0.46795096, 0.46406, 0.459806464,
-0.455182816, 0.450365408, 0.44559048, 0.44102, 0.43667624, 0.432602768, 0.428774016, 0.425113664, 0.42157, 0.418176192, 0.414959856, 0.41185272, 0.408750864,
-0.40559, 0.402378784, 0.39910848, 0.395824096, 0.392603872, 0.3895, 0.38650168, 0.383606256, 0.380827472, 0.378179488, 0.37567, 0.373302704, 0.371085552,
-0.3689912, 0.366975408, 0.36501, 0.36309472, 0.36121768, 0.35940288, 0.35769304, 0.35611, 0.35464008, 0.3532816, 0.35199016, 0.35069808, 0.34936, 0.34797536,
-0.34653368, 0.34504384, 0.34352856, 0.342, 0.340446976, 0.33886424, 0.337241344, 0.335563328, 0.33382, 0.332007664, 0.330117984, 0.328161024, 0.326155584,
-0.32411, 0.322015776, 0.319870624, 0.317647552, 0.31530648, 0.31282, 0.310191152, 0.307422512, 0.304502272, 0.301416352, 0.29816, 0.294737184, 0.291146672,
-0.28742744, 0.283638288, 0.27982, 0.275976064, 0.27211808, 0.268245216, 0.264346912, 0.26042, 0.256477152, 0.252526336, 0.24858176, 0.244661824, 0.24078,
-0.236940672, 0.23315008, 0.229408448, 0.225712096, 0.22206, 0.218457344, 0.214907648, 0.211415072, 0.207984576, 0.20462, 0.201324016, 0.198100432, 0.194947568,
-0.191861184, 0.18884, 0.185889312, 0.183014368, 0.180218544, 0.17750536, 0.17488, 0.172347632, 0.169911584, 0.167588896, 0.165402848, 0.16337, 0.16149208,
-0.159771536, 0.158213552, 0.156822448, 0.1556, 0.154547536, 0.153667952, 0.152947088, 0.152362704, 0.1519, 0.151560432, 0.151343088, 0.151247664, 0.15127608,
-0.15143, 0.151708944, 0.152113264, 0.152646544, 0.153313904, 0.15412, 0.15506744, 0.156159184, 0.157400848, 0.158799152, 0.16036, 0.162086288, 0.163980208,
-0.166049488, 0.168303888, 0.17075, 0.173387808, 0.176216928, 0.179238528, 0.182453328, 0.18586, 0.189455424, 0.193236608, 0.197193392, 0.201311776, 0.20558,
-0.209993504, 0.214547632, 0.21922976, 0.224024448, 0.22892, 0.233910736, 0.23898912, 0.244155744, 0.249415648, 0.25477, 0.260211776, 0.265735744, 0.271334432,
-0.27699848, 0.28272, 0.288493136, 0.294310032, 0.300169568, 0.306073664, 0.31202, 0.317998688, 0.324001008, 0.330013168, 0.336017968, 0.342, 0.347950544,
-0.353861328, 0.359713872, 0.365485856, 0.37116, 0.376729056, 0.382185152, 0.387521808, 0.392734704, 0.39782, 0.402771808, 0.407584208, 0.412260368, 0.416807648,
-0.42123, 0.425524272, 0.429689328, 0.433720864, 0.43761248, 0.44136, 0.444964144, 0.448425344, 0.451743264, 0.454917904, 0.45795, 0.460840688, 0.463591024,
-0.46620544, 0.468690096, 0.47105, 0.47328784, 0.47540744, 0.4774104, 0.4792972, 0.48107, 0.482733904, 0.4842936, 0.485755856, 0.487128832, 0.48842, 0.48963456,
-0.490777936, 0.491857792, 0.492882608, 0.49386, 0.494794192, 0.495688032, 0.496552112, 0.497399872, 0.49824, 0.499073824, 0.499904432, 0.50072496, 0.501522928,
-0.50229, 0.503028288, 0.50373864, 0.504417952, 0.505062464, 0.50567, 0.50624032, 0.506772432, 0.507270544, 0.507741536, 0.50819, 0.508616192, 0.509021984,
-0.509405296, 0.509761888, 0.51009, 0.510392336, 0.510670496, 0.510929776, 0.511177696, 0.51142, 0.511657264, 0.511889696, 0.512123088, 0.51236504, 0.51262,
-0.5128872, 0.513166608, 0.513453456, 0.513739984, 0.51402, 0.514292656, 0.514556976, 0.514805616, 0.515028896, 0.51522, 0.515377504, 0.515499136, 0.515585328,
-0.51563848, 0.51566, 0.515649968, 0.515610688, 0.515538048, 0.515425248, 0.51527, 0.515076784, 0.514848208, 0.514597392, 0.514343216, 0.5141, 0.513868288,
-0.513647232, 0.513452736, 0.51330632, 0.51322, 0.51319296, 0.513229696, 0.513305552, 0.513380928, 0.51343, 0.513455488, 0.513454192, 0.513431776, 0.51340112,
-0.51337, 0.513334272, 0.513292656, 0.51324328, 0.513182944, 0.51311, 0.513025968, 0.51293208, 0.512829952, 0.512721824, 0.51261, 0.512496896, 0.512385712,
-0.512278128, 0.512175344, 0.51208, 0.511994992, 0.511921168, 0.511871344, 0.51186368, 0.51191, 0.51200912, 0.512160864, 0.512364048, 0.512615152, 0.51291,
-0.513247984, 0.513630048, 0.514039392, 0.514451456, 0.51485, 0.515234832, 0.515602512, 0.515957872, 0.516311472, 0.51667, 0.517030144, 0.517390112, 0.51775184,
-0.518117968, 0.51849, 0.51886792, 0.5192524, 0.51964048, 0.52002736, 0.52041, 0.52078888, 0.52116368, 0.52153568, 0.52190728, 0.52228, 0.52265336, 0.52302728,
-0.5234016, 0.52377592, 0.52415, 0.524524, 0.524898, 0.525272, 0.525646, 0.52602, 0.526394, 0.526768, 0.527142, 0.527516, 0.52789, 0.528264, 0.528638, 0.529012,
-0.529386, 0.52976, 0.530134, 0.530508, 0.530882, 0.531256, 0.53163, 0.532003984, 0.53237792, 0.532751856, 0.533125872, 0.5335, 0.53387424, 0.534248656,
-0.534622992, 0.534996848, 0.53537, 0.53574248, 0.536114192, 0.536485584, 0.536857376, 0.53723, 0.5376004, 0.537962704, 0.538325408, 0.538702672, 0.5391,
-0.539514512, 0.539952128, 0.540374384, 0.54072216, 0.54096,
-/* CES05 */ 0.13351, 0.133787856, 0.134196192, 0.134667008, 0.135091344, 0.1354, 0.135604496, 0.135700208, 0.1357112, 0.135683952, 0.13565, 0.135602896,
-0.13554304, 0.135481424, 0.135430288, 0.1354, 0.13539544, 0.135419744, 0.135484768, 0.135605232, 0.13579, 0.136038528, 0.136351328, 0.136719888, 0.137129968,
-0.13757, 0.138036352, 0.138523248, 0.139021424, 0.13952048, 0.14001, 0.140481232, 0.140925744, 0.141329376, 0.141675488, 0.14195, 0.142146496, 0.142260176,
-0.142275376, 0.142172816, 0.14194, 0.14157528, 0.141075376, 0.140448432, 0.139709568, 0.13887, 0.13792952, 0.136889552, 0.135759104, 0.134549616, 0.13327,
-0.131924064, 0.130516464, 0.129050304, 0.127527344, 0.12595, 0.124322976, 0.122650864, 0.120935552, 0.11917792, 0.11738, 0.115545968, 0.113679552, 0.111785856,
-0.10987088, 0.10794, 0.105996784, 0.104044816, 0.102089328, 0.10013608, 0.09819, 0.096254592, 0.094333568, 0.092429504, 0.09054424, 0.08868, 0.086840048,
-0.085027344, 0.08324464, 0.081494656, 0.07978, 0.078103072, 0.07646616, 0.074871728, 0.073322256, 0.07182, 0.070366656, 0.068963648, 0.06761328, 0.066318112,
-0.06508, 0.0638996, 0.06277752, 0.06171384, 0.06070816, 0.05976, 0.058868768, 0.05803328, 0.057253392, 0.056529264, 0.05586, 0.05524336, 0.054677392,
-0.054157264, 0.053676576, 0.05323, 0.052815472, 0.052431024, 0.052071136, 0.051729088, 0.0514, 0.051082288, 0.050774016, 0.050475104, 0.050186832, 0.04991,
-0.049643872, 0.049388384, 0.049143856, 0.048910768, 0.04869, 0.048482672, 0.048289856, 0.0481144, 0.047960064, 0.04783, 0.04772568, 0.04764872, 0.04760184,
-0.04758808, 0.04761, 0.047669088, 0.04776672, 0.047904832, 0.048085424, 0.04831, 0.048578688, 0.048890912, 0.049248656, 0.04965472, 0.05011, 0.050612528,
-0.051160736, 0.051749424, 0.052371232, 0.05302, 0.053693408, 0.054388944, 0.05510016, 0.055819216, 0.05654, 0.057259872, 0.05797592, 0.058685728, 0.059387616,
-0.06008, 0.060761712, 0.061432768, 0.062090144, 0.06272976, 0.06335, 0.063953216, 0.064541504, 0.065120352, 0.06569752, 0.06628, 0.066872608, 0.067481632,
-0.068112336, 0.0687696, 0.06946, 0.070191856, 0.070972736, 0.071816656, 0.072740896, 0.07376, 0.074880512, 0.076108176, 0.07745792, 0.078947664, 0.08059,
-0.082387936, 0.08434488, 0.086460224, 0.088729888, 0.09115, 0.093717504, 0.096425744, 0.099274064, 0.102263744, 0.10539, 0.108640176, 0.112003344, 0.115452352,
-0.1189512, 0.12247, 0.12599736, 0.129522432, 0.133014464, 0.136436016, 0.13976, 0.142978608, 0.146082864, 0.1490704, 0.151944976, 0.15471, 0.157367936,
-0.15992808, 0.162386784, 0.164736048, 0.16698, 0.169140016, 0.171234944, 0.173309712, 0.17542432, 0.17763, 0.179951888, 0.182417632, 0.185075536, 0.18798072,
-0.19118, 0.194702864, 0.198579616, 0.202841088, 0.20751576, 0.21263, 0.218203808, 0.224250528, 0.230803248, 0.237901648, 0.24557, 0.253807936, 0.262616848,
-0.27197608, 0.281850432, 0.29221, 0.30304088, 0.31432136, 0.32602912, 0.3381416, 0.35063, 0.363459728, 0.3765976, 0.389987552, 0.403561584, 0.41726,
-0.431047888, 0.444890832, 0.458728896, 0.47249368, 0.48613, 0.499606544, 0.512889856, 0.525958448, 0.53880008, 0.5514, 0.563735872, 0.575791888, 0.587546864,
-0.59897784, 0.61007, 0.620819136, 0.631219744, 0.641285952, 0.65104304, 0.66051, 0.66968688, 0.678575712, 0.687193664, 0.695562976, 0.7037, 0.711609872,
-0.719299664, 0.726770896, 0.734021088, 0.74105, 0.747864208, 0.754469616, 0.760866464, 0.767053072, 0.77303, 0.778802032, 0.784373184, 0.789749776, 0.794939728,
-0.79995, 0.804784848, 0.809448896, 0.813948384, 0.818290032, 0.82248, 0.826523456, 0.830425824, 0.834191632, 0.83782488, 0.84133, 0.844712544, 0.847978032,
-0.85113128, 0.854176928, 0.85712, 0.859965904, 0.86271976, 0.865388416, 0.867979552, 0.8705, 0.872953824, 0.875344336, 0.877679568, 0.8799692, 0.88222,
-0.884433872, 0.886613328, 0.888756784, 0.89086, 0.89292, 0.89493776, 0.896913504, 0.898844688, 0.900727792, 0.90256, 0.9043404, 0.906067968, 0.907740416,
-0.909355104, 0.91091, 0.912405136, 0.913841456, 0.915215536, 0.916522416, 0.91776, 0.918930896, 0.920037136, 0.921084976, 0.922083376, 0.92304, 0.923958464,
-0.924843136, 0.925701248, 0.92654104, 0.92737, 0.928189392, 0.928996368, 0.929810464, 0.93066, 0.93156, 0.932509168, 0.933516384, 0.93453936, 0.935510896,
-0.93639, 0.937183568, 0.93788504, 0.938528352, 0.939171184, 0.93985, 0.940557008, 0.941294112, 0.942049296, 0.94279944, 0.94353, 0.944246112, 0.944948976,
-0.94564008, 0.946323024, 0.947, 0.94766968, 0.94833144, 0.94898536, 0.94963152, 0.95027, 0.950900864, 0.95152416, 0.952140016, 0.952748592, 0.95335,
-0.953944304, 0.954531616, 0.955111808, 0.95568464, 0.95625, 0.956808, 0.957358688, 0.957902336, 0.958439344, 0.95897, 0.959494336, 0.960012416, 0.960524336,
-0.961030176, 0.96153, 0.962020496, 0.962494976, 0.962963536, 0.963442976, 0.96394, 0.964451504, 0.964984736, 0.965495088, 0.96591384, 0.9662,
-/* CES06 */ 0.04525, 0.046261376, 0.047743488, 0.04956128, 0.051486032, 0.05337, 0.055233424, 0.05705496, 0.058878336, 0.060793952, 0.06285, 0.065036368,
-0.067391296, 0.069777984, 0.071978512, 0.07388, 0.075502352, 0.076774864, 0.078039936, 0.079838448, 0.08249, 0.085924464, 0.090185696, 0.094931088, 0.09959784,
-0.10382, 0.107648624, 0.111051328, 0.114092672, 0.116927376, 0.11965, 0.122205088, 0.124567552, 0.126740656, 0.12872696, 0.13053, 0.132155984, 0.133611776,
-0.134900768, 0.13602528, 0.13699, 0.137802944, 0.138471328, 0.139008192, 0.139429776, 0.13975, 0.139975552, 0.140112512, 0.140175952, 0.140184032, 0.14015,
-0.140078784, 0.139976592, 0.13984256, 0.139671328, 0.13946, 0.139212832, 0.13893288, 0.138618928, 0.138268416, 0.13788, 0.137455008, 0.136994368, 0.136499088,
-0.135970528, 0.13541, 0.13481864, 0.134197888, 0.133548816, 0.132872384, 0.13217, 0.131443504, 0.130694496, 0.129926448, 0.12914376, 0.12835, 0.127546352,
-0.126733728, 0.125915744, 0.12509688, 0.12428, 0.123465584, 0.122654704, 0.121844704, 0.121030864, 0.12021, 0.119383472, 0.118552864, 0.117714896, 0.116864688,
-0.116, 0.115122896, 0.114234656, 0.113341056, 0.112450576, 0.11157, 0.110700896, 0.109844896, 0.109008976, 0.108201936, 0.10743, 0.10669464, 0.105997856,
-0.105339392, 0.104717248, 0.10413, 0.103578176, 0.103061472, 0.102578848, 0.102128944, 0.10171, 0.101320352, 0.100958528, 0.100619984, 0.10029872, 0.09999,
-0.099692816, 0.099406624, 0.099126352, 0.09884544, 0.09856, 0.098270464, 0.097976592, 0.09768256, 0.097395248, 0.09712, 0.09685752, 0.09660896, 0.09637928,
-0.09617472, 0.096, 0.095856896, 0.0957476, 0.095673184, 0.095633808, 0.09563, 0.09566336, 0.095735024, 0.095846048, 0.095997472, 0.09619, 0.096424096,
-0.096700368, 0.09701784, 0.097374752, 0.09777, 0.098204208, 0.098678, 0.099190752, 0.099741504, 0.10033, 0.10095704, 0.101623232, 0.102330624, 0.103082096,
-0.10388, 0.104725008, 0.105617744, 0.1065608, 0.107557456, 0.10861, 0.109718976, 0.1108852, 0.112108224, 0.113386768, 0.11472, 0.116108464, 0.117552544,
-0.119051504, 0.120604224, 0.12221, 0.123868848, 0.125580544, 0.12734568, 0.129165296, 0.13104, 0.132968896, 0.13495064, 0.136986704, 0.139079568, 0.14123,
-0.143435824, 0.145695184, 0.148004144, 0.150357344, 0.15275, 0.155178976, 0.157640384, 0.160130352, 0.16264504, 0.16518, 0.167730336, 0.170291472, 0.172855888,
-0.175414624, 0.17796, 0.180487888, 0.182994448, 0.185472288, 0.187912928, 0.19031, 0.1926604, 0.194960448, 0.197210496, 0.199413264, 0.20157, 0.203677328,
-0.205731856, 0.207735264, 0.209690992, 0.2116, 0.213459072, 0.215265904, 0.217014016, 0.218694448, 0.2203, 0.221828672, 0.223278576, 0.22464256, 0.225911744,
-0.22708, 0.228146288, 0.22910928, 0.229969472, 0.230729024, 0.23139, 0.231953952, 0.232424032, 0.232801152, 0.233085552, 0.23328, 0.233390944, 0.233424672,
-0.23339192, 0.233306208, 0.23318, 0.233022448, 0.232844, 0.232656112, 0.232470624, 0.2323, 0.232156816, 0.232053472, 0.232006128, 0.232032784, 0.23215,
-0.232370304, 0.232706128, 0.233173552, 0.233789776, 0.23457, 0.235526144, 0.236670592, 0.23801264, 0.239559648, 0.24132, 0.24330544, 0.24552752, 0.24799424,
-0.25071232, 0.25369, 0.256937568, 0.260464, 0.264283872, 0.268414464, 0.27287, 0.277655296, 0.282772672, 0.288239328, 0.294077504, 0.3003, 0.306903248,
-0.313884688, 0.321232208, 0.328926528, 0.33695, 0.345289376, 0.353925248, 0.36284712, 0.372047632, 0.38151, 0.3912056, 0.40110904, 0.41116744, 0.42131328,
-0.43149, 0.441673904, 0.45184344, 0.461939936, 0.471892352, 0.48165, 0.49119624, 0.500509216, 0.509597472, 0.518488688, 0.5272, 0.535717072, 0.544029712,
-0.552150592, 0.560099952, 0.56789, 0.575516848, 0.582979232, 0.590270736, 0.59738056, 0.6043, 0.611026864, 0.617557696, 0.623884608, 0.62999816, 0.63589,
-0.64157768, 0.647101488, 0.652385056, 0.657305584, 0.66181, 0.665921008, 0.669597696, 0.673117424, 0.676908112, 0.68123, 0.686048688, 0.691417024, 0.6970912,
-0.702659056, 0.70786, 0.712748144, 0.7173084, 0.721629376, 0.725880912, 0.73017, 0.734459552, 0.738741296, 0.74300448, 0.747222704, 0.75138, 0.75548656,
-0.75954744, 0.76356208, 0.76752976, 0.77145, 0.77532272, 0.77914776, 0.78292536, 0.786656, 0.79034, 0.79397728, 0.79756784, 0.80111176, 0.80460912, 0.80806,
-0.811464528, 0.81482288, 0.818134992, 0.821400704, 0.82462, 0.827793072, 0.830920032, 0.834001392, 0.837037872, 0.84003, 0.842977984, 0.845882192, 0.84874248,
-0.851558448, 0.85433, 0.857057552, 0.85974136, 0.862382288, 0.864981536, 0.86754, 0.870057952, 0.872535808, 0.874973744, 0.87737176, 0.87973, 0.882048944,
-0.884329024, 0.886570624, 0.888774144, 0.89094, 0.893068608, 0.895160384, 0.89721584, 0.899235536, 0.90122, 0.903169648, 0.90508488, 0.906966272, 0.908814464,
-0.91063, 0.912401904, 0.914107872, 0.91578224, 0.917481968, 0.91923, 0.921016464, 0.92286664, 0.924629856, 0.926073712, 0.92706,
-/* CES07 */ 0.0583, 0.0583, 0.0583, 0.0583, 0.0583, 0.0583, 0.0583, 0.0583, 0.0583, 0.0583, 0.0583, 0.058299296, 0.05829648, 0.058293664, 0.058294368, 0.0583,
-0.058309664, 0.058324384, 0.058335584, 0.058330144, 0.0583, 0.058246656, 0.058169664, 0.058074112, 0.0579688, 0.05786, 0.057747392, 0.057631552, 0.057515872,
-0.057404352, 0.0573, 0.057204096, 0.057118112, 0.057042368, 0.056976384, 0.05692, 0.056873952, 0.056838528, 0.056814144, 0.05680128, 0.0568, 0.056809888,
-0.056830624, 0.05686064, 0.056897696, 0.05694, 0.056987008, 0.05703808, 0.057091712, 0.057146144, 0.0572, 0.05725256, 0.057302992, 0.057351024, 0.057396736,
-0.05744, 0.05747984, 0.057515104, 0.057546288, 0.057574512, 0.0576, 0.057621584, 0.057638528, 0.057648032, 0.057646176, 0.05763, 0.057599168, 0.057553472,
-0.057490336, 0.05740648, 0.0573, 0.057171152, 0.057019856, 0.05684848, 0.056660864, 0.05646, 0.056246432, 0.05602104, 0.055786288, 0.055545136, 0.0553,
-0.05505216, 0.054803008, 0.054553856, 0.054305824, 0.05406, 0.05381744, 0.053578976, 0.053345792, 0.053119168, 0.0529, 0.052688656, 0.052485552, 0.052290288,
-0.052101984, 0.05192, 0.051744512, 0.051575648, 0.051412624, 0.05125432, 0.0511, 0.05094968, 0.050803264, 0.050660928, 0.050523072, 0.05039, 0.050261536,
-0.050137408, 0.0500184, 0.049905712, 0.0498, 0.049700976, 0.04960856, 0.049521824, 0.049439328, 0.04936, 0.04928384, 0.049210864, 0.049140048, 0.049070032,
-0.049, 0.048930016, 0.048859968, 0.04879072, 0.048723712, 0.04866, 0.048599424, 0.04854176, 0.048488256, 0.048440672, 0.0484, 0.048365968, 0.048338576,
-0.048316464, 0.048297472, 0.04828, 0.048263968, 0.048249184, 0.04823448, 0.048218416, 0.0482, 0.04817904, 0.04815528, 0.04812888, 0.04810032, 0.04807,
-0.048037952, 0.04800432, 0.047969648, 0.047934656, 0.0479, 0.047866048, 0.047833168, 0.047802208, 0.047774208, 0.04775, 0.047729856, 0.047713968, 0.04770312,
-0.047698272, 0.0477, 0.047708384, 0.04772368, 0.047744976, 0.047770752, 0.0478, 0.047833056, 0.047870256, 0.047910976, 0.047954336, 0.048, 0.048048544,
-0.048100496, 0.048157008, 0.04821968, 0.04829, 0.048368928, 0.048457568, 0.048557648, 0.048671168, 0.0488, 0.048945888, 0.049110928, 0.049296128, 0.049502048,
-0.04973, 0.049983424, 0.050266448, 0.050579952, 0.050923776, 0.0513, 0.051714448, 0.052172752, 0.052683136, 0.05325568, 0.0539, 0.054624128, 0.055437296,
-0.056348304, 0.057365872, 0.0585, 0.059762368, 0.061164704, 0.06272128, 0.064447936, 0.06636, 0.06847104, 0.07079536, 0.073348, 0.07614432, 0.0792, 0.082529488,
-0.086146, 0.090070832, 0.094328704, 0.09894, 0.103911936, 0.109247872, 0.114972368, 0.121117104, 0.1277, 0.134716, 0.142162128, 0.150017616, 0.158249664,
-0.16683, 0.175743872, 0.184970176, 0.19448712, 0.204272704, 0.2143, 0.22453744, 0.23495448, 0.24550288, 0.25612512, 0.26677, 0.277405152, 0.28799832,
-0.298498448, 0.308848256, 0.319, 0.32892376, 0.338588528, 0.347969216, 0.357046304, 0.3658, 0.374209824, 0.382261616, 0.389929008, 0.39718152, 0.404,
-0.410382016, 0.416323328, 0.42184112, 0.426965472, 0.43172, 0.43610896, 0.44014048, 0.44383424, 0.44721376, 0.4503, 0.45310704, 0.45564976, 0.45794672,
-0.46001744, 0.46188, 0.463548128, 0.46503408, 0.466357472, 0.467540384, 0.4686, 0.469545888, 0.470388832, 0.471130656, 0.47176784, 0.4723, 0.472736288,
-0.473085216, 0.473346784, 0.473518112, 0.4736, 0.473599744, 0.473523264, 0.473381824, 0.473189984, 0.47296, 0.472697024, 0.472405824, 0.472099424, 0.471793664,
-0.4715, 0.47122216, 0.470964784, 0.470727568, 0.470506752, 0.4703, 0.470109152, 0.469934368, 0.469775184, 0.46963096, 0.4695, 0.469380096, 0.469269584,
-0.469160912, 0.46904368, 0.46891, 0.468759712, 0.468593952, 0.468403152, 0.468174112, 0.4679, 0.467584352, 0.467229072, 0.466846432, 0.466455072, 0.46607,
-0.465691616, 0.465317712, 0.464973328, 0.464693824, 0.4645, 0.464390288, 0.464370608, 0.464411648, 0.464465248, 0.4645, 0.464520096, 0.464522608, 0.46451256,
-0.464502512, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645,
-0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645,
-0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645,
-0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645,
-/* CES08 */ 0.05818, 0.058388864, 0.058694576, 0.059047888, 0.05936992, 0.05961, 0.059775296, 0.059861408, 0.0598828, 0.059869152, 0.05984, 0.05978928,
-0.05971576, 0.05962448, 0.05952096, 0.05941, 0.05929424, 0.0591756, 0.0590596, 0.05895296, 0.05886, 0.058781408, 0.05871856, 0.058667952, 0.058623264, 0.05858,
-0.05853912, 0.058501472, 0.058462704, 0.058416736, 0.05836, 0.05829312, 0.058215824, 0.058132128, 0.058048592, 0.05797, 0.057896, 0.057826288, 0.057765216,
-0.057718624, 0.05769, 0.05767888, 0.057685456, 0.057706352, 0.057735968, 0.05777, 0.057808224, 0.057850272, 0.0578916, 0.057926208, 0.05795, 0.0579624,
-0.05796232, 0.05795056, 0.05792928, 0.0579, 0.0578624, 0.05781672, 0.05776376, 0.05770448, 0.05764, 0.05757136, 0.05749952, 0.0574268, 0.05735616, 0.05729,
-0.0572288, 0.05717256, 0.05712464, 0.0570896, 0.05707, 0.057065232, 0.05707536, 0.057096608, 0.057122976, 0.05715, 0.057177584, 0.057205488, 0.057229872,
-0.057245776, 0.05725, 0.057242288, 0.057221872, 0.057190736, 0.05715256, 0.05711, 0.057062768, 0.057010896, 0.056956464, 0.056902192, 0.05685, 0.056800272,
-0.056753584, 0.056709936, 0.056668848, 0.05663, 0.056593584, 0.056559536, 0.056527728, 0.056498, 0.05647, 0.056443312, 0.056417648, 0.056391504, 0.0563628,
-0.05633, 0.05629304, 0.056251984, 0.056205488, 0.056151792, 0.05609, 0.056020496, 0.055943408, 0.05586072, 0.055775472, 0.05569, 0.055604608, 0.05551968,
-0.055437312, 0.055360144, 0.05529, 0.055227248, 0.055172432, 0.055125216, 0.05508464, 0.05505, 0.055021408, 0.054998736, 0.054981424, 0.054968752, 0.05496,
-0.054954672, 0.054952304, 0.054951696, 0.054951328, 0.05495, 0.05494736, 0.054943136, 0.054936272, 0.054925488, 0.05491, 0.054889792, 0.054864752, 0.054835632,
-0.054803712, 0.05477, 0.054734608, 0.054697712, 0.054660416, 0.05462416, 0.05459, 0.054558192, 0.054529056, 0.05450288, 0.054479824, 0.05446, 0.054443552,
-0.05443056, 0.054420848, 0.054414096, 0.05441, 0.054408448, 0.054409328, 0.054412048, 0.054415808, 0.05442, 0.05442472, 0.054430288, 0.054435616, 0.054439104,
-0.05444, 0.054438896, 0.054436096, 0.054433536, 0.054434096, 0.05444, 0.0544516, 0.054469296, 0.054495072, 0.054531408, 0.05458, 0.05464152, 0.054717152,
-0.054805424, 0.054903536, 0.05501, 0.05512704, 0.055257424, 0.055399568, 0.055550512, 0.05571, 0.055882304, 0.056071328, 0.056284352, 0.056530976, 0.05682,
-0.057157632, 0.057551232, 0.058008832, 0.058538752, 0.05915, 0.059852128, 0.060654752, 0.061569856, 0.06261072, 0.06379, 0.065118352, 0.066606736, 0.06826776,
-0.070114624, 0.07216, 0.074414832, 0.07688968, 0.079597888, 0.082553776, 0.08577, 0.089254176, 0.093012048, 0.09705824, 0.101410352, 0.10608, 0.111068704,
-0.11637864, 0.122005056, 0.127938272, 0.13417, 0.140696288, 0.147509936, 0.154605584, 0.161978432, 0.16962, 0.177517232, 0.185657584, 0.194019136, 0.202574688,
-0.2113, 0.220179728, 0.229197536, 0.238330144, 0.247551952, 0.25684, 0.266176608, 0.275543424, 0.28492344, 0.294300976, 0.30366, 0.31298336, 0.3222548,
-0.33145752, 0.34057456, 0.34959, 0.358489808, 0.3672604, 0.375887872, 0.384358624, 0.39266, 0.400782176, 0.408717392, 0.416450928, 0.423965744, 0.43125,
-0.43829992, 0.445110768, 0.451686736, 0.458037504, 0.46417, 0.47008312, 0.475777616, 0.481258112, 0.486530448, 0.4916, 0.496470608, 0.501146592, 0.505633136,
-0.50993576, 0.51406, 0.518011424, 0.521796016, 0.525418928, 0.52888504, 0.5322, 0.53537048, 0.538402928, 0.541305776, 0.544088544, 0.54676, 0.549326432,
-0.551793856, 0.55417176, 0.556470864, 0.5587, 0.560864752, 0.5629712, 0.565022848, 0.567021536, 0.56897, 0.57087336, 0.572736048, 0.574562096, 0.576355504,
-0.57812, 0.579858944, 0.581575616, 0.583272928, 0.58495352, 0.58662, 0.58827456, 0.589918688, 0.591556336, 0.593192384, 0.59483, 0.596469552, 0.598111776,
-0.5997548, 0.601395104, 0.60303, 0.604659072, 0.6062812, 0.607894848, 0.609498416, 0.61109, 0.612667648, 0.614229648, 0.615772128, 0.617290208, 0.61878,
-0.620240544, 0.621671408, 0.623068272, 0.624425536, 0.62574, 0.627012336, 0.628242672, 0.629435008, 0.630595744, 0.63173, 0.632839264, 0.633925808, 0.634993552,
-0.636046896, 0.63709, 0.638124288, 0.639149312, 0.640175136, 0.641216, 0.64228, 0.643367072, 0.644481376, 0.64560624, 0.646714624, 0.64779, 0.64883552,
-0.64984896, 0.65084288, 0.65183872, 0.65285, 0.653873712, 0.65491048, 0.655955728, 0.657000736, 0.65804, 0.659075376, 0.660107248, 0.66113616, 0.662163472,
-0.66319, 0.6642152, 0.6652388, 0.6662608, 0.6672812, 0.6683, 0.6693172, 0.6703328, 0.6713468, 0.6723592, 0.67337, 0.6743792, 0.6753868, 0.6763928, 0.6773972,
-0.6784, 0.6794012, 0.6804008, 0.6813988, 0.6823952, 0.68339, 0.684383184, 0.68537472, 0.686364656, 0.687353072, 0.68834, 0.689317712, 0.690270816, 0.69122224,
-0.692210224, 0.69325, 0.694333952, 0.6954776, 0.696580688, 0.697488976, 0.69811,
-/* CES09 */ 0.04157, 0.0415892, 0.04161728, 0.04165136, 0.04168664, 0.04172, 0.041752048, 0.0417828, 0.041812352, 0.041841264, 0.04187, 0.041897904,
-0.041923872, 0.04195048, 0.041981808, 0.04202, 0.042064704, 0.0421172, 0.042171376, 0.042217552, 0.04225, 0.042269984, 0.042276736, 0.042276288, 0.04227856,
-0.04229, 0.042309744, 0.042338448, 0.042374832, 0.042415936, 0.04246, 0.042508096, 0.042560672, 0.042618128, 0.042681104, 0.04275, 0.04282472, 0.042905248,
-0.042991456, 0.043083104, 0.04318, 0.043282096, 0.043389296, 0.043501456, 0.043618416, 0.04374, 0.043866032, 0.043996336, 0.04413064, 0.044268624, 0.04441,
-0.044554608, 0.04470232, 0.044852752, 0.045005424, 0.04516, 0.045316384, 0.045474432, 0.04563424, 0.045796048, 0.04596, 0.046125936, 0.04629368, 0.046463424,
-0.046635488, 0.04681, 0.046986544, 0.047164544, 0.047344224, 0.047526064, 0.04771, 0.047895264, 0.048081344, 0.048266064, 0.048446384, 0.04862, 0.048786576,
-0.048945824, 0.049095872, 0.0492344, 0.04936, 0.04947288, 0.049573232, 0.049661904, 0.049740336, 0.04981, 0.049872224, 0.049928864, 0.049981344, 0.050031024,
-0.05008, 0.050130928, 0.050186064, 0.0502512, 0.050333856, 0.05044, 0.050570864, 0.05072688, 0.050914976, 0.051144272, 0.05142, 0.051741008, 0.052107056,
-0.052511904, 0.052945552, 0.0534, 0.05387296, 0.054360944, 0.054857728, 0.055356272, 0.05585, 0.05633408, 0.056803888, 0.057251936, 0.057669664, 0.05805,
-0.058389872, 0.058687136, 0.05893472, 0.059124064, 0.05925, 0.059312512, 0.05931088, 0.059250688, 0.059141296, 0.05899, 0.058797728, 0.058566048, 0.058301968,
-0.058014368, 0.05771, 0.057391408, 0.057061728, 0.056722768, 0.056375168, 0.05602, 0.055659664, 0.055295968, 0.054930512, 0.054564816, 0.0542, 0.053836768,
-0.053475792, 0.053116656, 0.05275832, 0.0524, 0.052041792, 0.051683616, 0.05132464, 0.050963744, 0.0506, 0.050233056, 0.04986256, 0.049487584, 0.049107008,
-0.04872, 0.048326544, 0.047926864, 0.047519984, 0.047104544, 0.04668, 0.046247232, 0.045807104, 0.045361136, 0.044911408, 0.04446, 0.04400872, 0.043559776,
-0.043115392, 0.042677888, 0.04225, 0.041834624, 0.041434432, 0.04105456, 0.040701328, 0.04038, 0.04009248, 0.03984, 0.03962888, 0.0394672, 0.03936, 0.039307328,
-0.03931, 0.039364032, 0.039462464, 0.0396, 0.039776512, 0.039991472, 0.040239632, 0.040514112, 0.04081, 0.041126016, 0.041460192, 0.041812848, 0.042185824,
-0.04258, 0.042993552, 0.043424928, 0.043874384, 0.04434272, 0.04483, 0.045334896, 0.045856224, 0.046392272, 0.04694088, 0.0475, 0.048067808, 0.048642032,
-0.049221296, 0.04980456, 0.05039, 0.050974864, 0.051556816, 0.052130768, 0.05269024, 0.05323, 0.053748384, 0.054244128, 0.054711952, 0.055145296, 0.05554,
-0.055895776, 0.056211792, 0.056491088, 0.056739104, 0.05696, 0.057154048, 0.057322048, 0.057467728, 0.057595808, 0.05771, 0.057811824, 0.057903008, 0.057985392,
-0.058060576, 0.05813, 0.05819504, 0.058256912, 0.058316384, 0.058373936, 0.05843, 0.05848528, 0.058540464, 0.058595168, 0.058648512, 0.0587, 0.058750416,
-0.058800768, 0.05885008, 0.058896672, 0.05894, 0.058981312, 0.05902144, 0.059063488, 0.059111856, 0.05917, 0.059238416, 0.059317328, 0.05941072, 0.059523872,
-0.05966, 0.059818816, 0.06000056, 0.060202544, 0.060420208, 0.06065, 0.060891312, 0.061143024, 0.061402256, 0.061665568, 0.06193, 0.062193744, 0.062454896,
-0.062711168, 0.06296024, 0.0632, 0.06342904, 0.063646368, 0.063849296, 0.064034544, 0.0642, 0.064345392, 0.064470176, 0.06457584, 0.064665104, 0.06474,
-0.064800496, 0.0648468, 0.064880704, 0.064904528, 0.06492, 0.06492768, 0.064928304, 0.064922208, 0.064909392, 0.06489, 0.064864608, 0.064833648, 0.064797408,
-0.064756128, 0.06471, 0.0646592, 0.064603888, 0.064544016, 0.064479424, 0.06441, 0.064335808, 0.064256896, 0.064173104, 0.064084192, 0.06399, 0.063891056,
-0.063788384, 0.063680432, 0.06356464, 0.06344, 0.063307104, 0.063165072, 0.06302048, 0.062883408, 0.06276, 0.062649504, 0.0625532, 0.062465376, 0.062376352,
-0.06228, 0.062177616, 0.062068896, 0.061955776, 0.061842016, 0.06173, 0.06161888, 0.061508416, 0.061398592, 0.061289168, 0.06118, 0.061071296, 0.060963232,
-0.060855568, 0.060747904, 0.06064, 0.060531904, 0.060423568, 0.060315232, 0.060207296, 0.0601, 0.059993296, 0.059887232, 0.059781568, 0.059675904, 0.05957,
-0.059463904, 0.059357568, 0.059251232, 0.059145296, 0.05904, 0.05893528, 0.058831152, 0.058727424, 0.058623776, 0.05852, 0.058416144, 0.058312224, 0.058208224,
-0.058104144, 0.058, 0.057895776, 0.057791424, 0.057687152, 0.05758328, 0.05748, 0.057377296, 0.057275232, 0.057173568, 0.057071904, 0.05697, 0.056867904,
-0.056765568, 0.056663232, 0.056561296, 0.05646, 0.05635928, 0.056259152, 0.056159424, 0.056059776, 0.05596, 0.055860144, 0.055760224, 0.055660224, 0.055560144,
-0.05546, 0.05536056, 0.055263344, 0.055166208, 0.055065552, 0.05496, 0.054850304, 0.054734928, 0.054623872, 0.054532496, 0.05447,
-/* CES10 */ 0.19983, 0.19983, 0.19983, 0.19983, 0.19983, 0.19983, 0.19983, 0.19983, 0.19983, 0.19983, 0.19983, 0.19984032, 0.1998816, 0.19992288, 0.19991256,
-0.19983, 0.199683584, 0.1994488, 0.199265616, 0.199350112, 0.19983, 0.200670992, 0.201874176, 0.20332568, 0.204838944, 0.20628, 0.207655504, 0.2089624,
-0.210110736, 0.210987312, 0.21152, 0.211703008, 0.211519696, 0.211022544, 0.210306032, 0.20944, 0.208420624, 0.207250384, 0.205992304, 0.204729504, 0.20352,
-0.202374896, 0.201312624, 0.200327072, 0.19939448, 0.1985, 0.19765592, 0.196869792, 0.196130704, 0.195421456, 0.19473, 0.194055936, 0.193396784, 0.192750192,
-0.19211512, 0.19149, 0.190872128, 0.190259872, 0.189649696, 0.18903728, 0.18842, 0.187797856, 0.187170496, 0.186540576, 0.185912576, 0.18529, 0.184673616,
-0.184064816, 0.183466096, 0.182880256, 0.18231, 0.181757232, 0.181223536, 0.18071296, 0.180230544, 0.17978, 0.179361248, 0.1789732, 0.178620192, 0.178308304,
-0.17804, 0.177812208, 0.177622752, 0.177462576, 0.17731856, 0.17718, 0.17704392, 0.176907296, 0.176758512, 0.176582928, 0.17637, 0.176116912, 0.175820032,
-0.175478752, 0.175094992, 0.17467, 0.174202992, 0.173694992, 0.173145472, 0.172553392, 0.17192, 0.17124936, 0.170544992, 0.169816784, 0.169078096, 0.16834,
-0.167606512, 0.166881504, 0.166176576, 0.165505968, 0.16488, 0.164301824, 0.163775296, 0.163300848, 0.16287624, 0.1625, 0.16217296, 0.161894128, 0.161663856,
-0.161482944, 0.16135, 0.16126152, 0.161215056, 0.161199232, 0.161198208, 0.1612, 0.161204272, 0.161212352, 0.161210432, 0.161179632, 0.16111, 0.161005824,
-0.160868832, 0.16071672, 0.160576688, 0.16047, 0.160400064, 0.16037096, 0.160403376, 0.160523552, 0.16075, 0.161087344, 0.161542256, 0.162112608, 0.16279024,
-0.16357, 0.164455584, 0.165448688, 0.166546512, 0.167744816, 0.16904, 0.170431072, 0.171917152, 0.173493152, 0.175152352, 0.17689, 0.1787072, 0.180606592,
-0.182581984, 0.184624256, 0.18673, 0.1889048, 0.191152864, 0.193488448, 0.195931872, 0.1985, 0.201199552, 0.204038528, 0.207032064, 0.2101976, 0.21355,
-0.217100672, 0.220863424, 0.224842176, 0.229036128, 0.23345, 0.238105088, 0.243026976, 0.248216064, 0.253664352, 0.25938, 0.265393184, 0.271726624, 0.278448864,
-0.285652544, 0.29341, 0.301732288, 0.310622464, 0.3201616, 0.330456896, 0.34157, 0.35348688, 0.36620384, 0.37965024, 0.3937128, 0.4083, 0.423385152, 0.43892912,
-0.454853728, 0.471067936, 0.48749, 0.504062192, 0.520724208, 0.537404624, 0.5540296, 0.57053, 0.586850704, 0.602942064, 0.618729664, 0.634130864, 0.64908,
-0.663539568, 0.677469984, 0.6908532, 0.703685936, 0.71596, 0.727652656, 0.73875024, 0.749235104, 0.759088208, 0.7683, 0.776873616, 0.784811824, 0.792136992,
-0.79888328, 0.80508, 0.81074192, 0.815889232, 0.820545824, 0.824736816, 0.82849, 0.831833696, 0.834795024, 0.837417952, 0.83975456, 0.84185, 0.843728816,
-0.845412992, 0.846949888, 0.848395264, 0.84979, 0.851150384, 0.852496048, 0.853824432, 0.855118896, 0.85637, 0.857589856, 0.858786592, 0.859954848, 0.861084784,
-0.86217, 0.863212336, 0.864212368, 0.86516912, 0.866081792, 0.86695, 0.867774368, 0.86855624, 0.869294992, 0.869989424, 0.87064, 0.871249152, 0.871818592,
-0.872355552, 0.872870112, 0.87337, 0.873855968, 0.874328032, 0.874794496, 0.8752664, 0.87575, 0.876244016, 0.876748656, 0.877255056, 0.877749056, 0.87822,
-0.878668144, 0.879094096, 0.879486048, 0.87982792, 0.88011, 0.880333344, 0.880497008, 0.880609952, 0.880687216, 0.88074, 0.880768048, 0.880771552, 0.880761136,
-0.88075088, 0.88075, 0.880759424, 0.880781776, 0.880811968, 0.88084024, 0.88086, 0.880874144, 0.880885648, 0.880887872, 0.880870976, 0.88083, 0.880767824,
-0.880685472, 0.88059344, 0.880507648, 0.88044, 0.88039024, 0.88035728, 0.88035408, 0.88039832, 0.8805, 0.880656288, 0.8808664, 0.881117872, 0.881390944,
-0.88167, 0.881954944, 0.882247472, 0.882524, 0.882751488, 0.88291, 0.883000176, 0.88301616, 0.882981984, 0.882937888, 0.88291, 0.882894128, 0.882892144,
-0.88290008, 0.882908016, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291,
-0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291,
-0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291,
-0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291,
-0.88291, 0.88291, 0.88291, 0.88291, 0.88291,
-/* CES11 */ 0.03951, 0.04013528, 0.041050992, 0.042170624, 0.043348416, 0.04449, 0.045607744, 0.046687984, 0.047757264, 0.048871344, 0.05006, 0.051315536,
-0.052659024, 0.054010512, 0.05524288, 0.05629, 0.05716312, 0.057821072, 0.058461344, 0.059396176, 0.06081, 0.06266224, 0.064978384, 0.067559968, 0.070081072,
-0.07233, 0.074336656, 0.076082528, 0.07760648, 0.079000192, 0.08032, 0.081534352, 0.0826296, 0.083607888, 0.084470576, 0.08522, 0.085861024, 0.086398928,
-0.086836432, 0.087175536, 0.08742, 0.08757584, 0.087648352, 0.087648064, 0.087588256, 0.08748, 0.08732768, 0.087134944, 0.086913728, 0.086678752, 0.08644,
-0.086199632, 0.085961008, 0.085720144, 0.08546848, 0.0852, 0.084916352, 0.084618304, 0.084300656, 0.083956208, 0.08358, 0.08317152, 0.082729616, 0.082254512,
-0.081747648, 0.08121, 0.08064112, 0.080041152, 0.079410544, 0.078749856, 0.07806, 0.07734208, 0.076597104, 0.075827808, 0.075037792, 0.07423, 0.073405488,
-0.072565248, 0.071712128, 0.070849568, 0.06998, 0.069104304, 0.068223728, 0.067337392, 0.066443216, 0.06554, 0.064629488, 0.063713872, 0.062790896, 0.06185672,
-0.06091, 0.059953456, 0.058988976, 0.058022896, 0.057064176, 0.05612, 0.05519232, 0.054283136, 0.053399392, 0.052549648, 0.05174, 0.050972192, 0.050248512,
-0.049568432, 0.048929472, 0.04833, 0.047770752, 0.047251472, 0.046771872, 0.046331712, 0.04593, 0.045564976, 0.045235072, 0.044936128, 0.044662624, 0.04441,
-0.044176784, 0.043961808, 0.043759792, 0.043564016, 0.04337, 0.04317704, 0.042983792, 0.042792304, 0.042606736, 0.04243, 0.042261552, 0.042101424, 0.041951376,
-0.041813648, 0.04169, 0.041581296, 0.041488656, 0.041412496, 0.041352816, 0.04131, 0.041285504, 0.041280896, 0.041296608, 0.04133272, 0.04139, 0.041470768,
-0.041577648, 0.041712128, 0.041875488, 0.04207, 0.042300624, 0.042573568, 0.042890432, 0.043251376, 0.04366, 0.044125232, 0.044655472, 0.045264832, 0.045970992,
-0.04679, 0.0477328, 0.048811472, 0.050041344, 0.051438896, 0.05302, 0.054798224, 0.056786944, 0.059003184, 0.061465344, 0.06419, 0.067188, 0.070468304,
-0.074049888, 0.077955152, 0.0822, 0.086788768, 0.091726128, 0.097011808, 0.102641248, 0.10861, 0.11491384, 0.121543488, 0.128499776, 0.135786944, 0.1434,
-0.151320352, 0.159530976, 0.16799712, 0.176673424, 0.18552, 0.1945132, 0.20362632, 0.21282288, 0.2220632, 0.23131, 0.240532928, 0.24970184, 0.258779792,
-0.267727664, 0.27651, 0.285102368, 0.293484352, 0.301616416, 0.30945264, 0.31696, 0.324126416, 0.330937616, 0.337398256, 0.343525216, 0.34933, 0.354807776,
-0.359958496, 0.364789296, 0.369309776, 0.37353, 0.377458336, 0.381103856, 0.384482096, 0.387611776, 0.39051, 0.393188624, 0.395660016, 0.397941568, 0.40005248,
-0.40201, 0.403827472, 0.405518848, 0.407096864, 0.4085732, 0.40996, 0.411270416, 0.412516784, 0.413712832, 0.41487288, 0.41601, 0.41713504, 0.418258832,
-0.419391424, 0.420542096, 0.42172, 0.422933808, 0.424190944, 0.425502, 0.426878656, 0.42833, 0.429860944, 0.43147664, 0.433178736, 0.434966512, 0.43684,
-0.438801424, 0.440851456, 0.442991808, 0.44522456, 0.44755, 0.449966576, 0.452473488, 0.4550632, 0.457724752, 0.46045, 0.463239216, 0.4660936, 0.469004144,
-0.471958448, 0.47495, 0.477981616, 0.481054304, 0.484180032, 0.4873772, 0.49066, 0.494030096, 0.497489392, 0.501051648, 0.504734544, 0.50855, 0.51249904,
-0.516583408, 0.520801536, 0.525148624, 0.52962, 0.534211264, 0.538914256, 0.543728288, 0.5486552, 0.55369, 0.558819104, 0.564031568, 0.569295952, 0.574570016,
-0.57982, 0.585037392, 0.590215792, 0.595319712, 0.600304032, 0.60514, 0.609825408, 0.614353392, 0.618744976, 0.623038, 0.62726, 0.631407504, 0.635478576,
-0.639499408, 0.6435056, 0.64752, 0.651542304, 0.655575648, 0.659609232, 0.663622576, 0.6676, 0.671540352, 0.675439632, 0.679284752, 0.683059552, 0.68675,
-0.690361376, 0.693912032, 0.697349008, 0.700591264, 0.7036, 0.706384976, 0.708920128, 0.7113588, 0.713940192, 0.71681, 0.71994712, 0.72337888, 0.72697512,
-0.73051528, 0.73386, 0.73703872, 0.74004376, 0.74292248, 0.74576552, 0.74863, 0.751496272, 0.75436008, 0.757215568, 0.760048416, 0.76285, 0.765625856,
-0.768378688, 0.77110856, 0.773815632, 0.7765, 0.7791616, 0.7818004, 0.7844164, 0.7870096, 0.78958, 0.792127616, 0.79465248, 0.797154544, 0.799633728, 0.80209,
-0.804523392, 0.806933904, 0.809321696, 0.811687008, 0.81403, 0.816350704, 0.818649216, 0.820925408, 0.82317904, 0.82541, 0.827618384, 0.829804208, 0.831967792,
-0.834109616, 0.83623, 0.838328976, 0.840406672, 0.842462928, 0.844497424, 0.84651, 0.848500816, 0.850469968, 0.85241768, 0.854344272, 0.85625, 0.858134944,
-0.8599992, 0.861842976, 0.863666512, 0.86547, 0.867253568, 0.869017376, 0.870761424, 0.872485632, 0.87419, 0.875874672, 0.877539744, 0.879185456, 0.880812128,
-0.88242, 0.883998192, 0.885524896, 0.88703304, 0.888577424, 0.89018, 0.891830624, 0.89355272, 0.895201776, 0.896555152, 0.89748,
-/* CES12 */ 0.24167, 0.238506992, 0.23390896, 0.228261728, 0.222218736, 0.21622, 0.21021648, 0.204247328, 0.198361936, 0.192562704, 0.18687, 0.18135352,
-0.176054336, 0.171030592, 0.166347648, 0.16204, 0.158113136, 0.154589232, 0.151358928, 0.148247824, 0.14514, 0.14205064, 0.138977808, 0.135911376, 0.132853264,
-0.12981, 0.126779072, 0.123757856, 0.12079872, 0.117979984, 0.11536, 0.112927072, 0.11065456, 0.108636048, 0.107008576, 0.10584, 0.105114, 0.104865248,
-0.104863856, 0.104750624, 0.1043, 0.103548192, 0.102477616, 0.10119488, 0.099894384, 0.09871, 0.09760496, 0.09654984, 0.09564928, 0.09504896, 0.09483,
-0.094979104, 0.09551528, 0.096288816, 0.097060672, 0.09767, 0.09813768, 0.098465296, 0.098616992, 0.098562048, 0.09829, 0.097788, 0.097022672, 0.096130384,
-0.095325136, 0.09474, 0.09435176, 0.094171184, 0.094124368, 0.094080352, 0.09395, 0.093755232, 0.093510368, 0.093162704, 0.092642, 0.09191, 0.090966096,
-0.089787904, 0.088475552, 0.08718944, 0.08603, 0.08498128, 0.084055232, 0.083197344, 0.082309776, 0.08133, 0.080276752, 0.079155664, 0.077966656, 0.076715968,
-0.07541, 0.074049312, 0.072636016, 0.0711876, 0.069728784, 0.06828, 0.066845728, 0.06542832, 0.064056512, 0.062768864, 0.06159, 0.060519648, 0.059561152,
-0.058701696, 0.05791744, 0.05719, 0.05652224, 0.055918496, 0.055349152, 0.054771648, 0.05416, 0.053519408, 0.052850832, 0.052169616, 0.05150208, 0.05087,
-0.050272032, 0.049702336, 0.04920616, 0.048848944, 0.04867, 0.048665744, 0.04884528, 0.049164256, 0.049548672, 0.04995, 0.050375104, 0.050820096, 0.051289248,
-0.05179656, 0.05235, 0.052939584, 0.053556288, 0.054206832, 0.054903136, 0.05565, 0.056449856, 0.057317552, 0.058207488, 0.059047584, 0.0598, 0.060478016,
-0.061073168, 0.06167256, 0.062412352, 0.06338, 0.064568208, 0.06599224, 0.067614272, 0.069361664, 0.07119, 0.073115056, 0.075137152, 0.077276288, 0.079567184,
-0.08203, 0.08465576, 0.087441488, 0.090372256, 0.093424304, 0.09658, 0.099841968, 0.103215136, 0.106675824, 0.110190192, 0.11374, 0.117331632, 0.120965184,
-0.124673056, 0.128505808, 0.1325, 0.136656896, 0.140981056, 0.145492336, 0.150213456, 0.15516, 0.160335536, 0.165743616, 0.171381056, 0.177239296, 0.18331,
-0.189589456, 0.196071856, 0.202743056, 0.209584816, 0.21658, 0.223725552, 0.231026256, 0.2384356, 0.245886384, 0.25334, 0.2607996, 0.26825096, 0.27576088,
-0.28343784, 0.29135, 0.299483088, 0.3078388, 0.316392272, 0.325096944, 0.33392, 0.34285984, 0.351903552, 0.361049664, 0.370303856, 0.37966, 0.389099184,
-0.398612464, 0.408138464, 0.417590784, 0.42691, 0.436086416, 0.445097664, 0.453959392, 0.46271032, 0.47137, 0.479918416, 0.488346672, 0.496627328, 0.504720784,
-0.5126, 0.520259504, 0.527685728, 0.534891952, 0.541905776, 0.54874, 0.55538704, 0.561853872, 0.568090704, 0.574020816, 0.5796, 0.584838608, 0.589730224,
-0.59433336, 0.598742496, 0.60302, 0.6071572, 0.61115608, 0.61501776, 0.61873464, 0.6223, 0.62572736, 0.62903896, 0.63218416, 0.63508216, 0.63769, 0.640024624,
-0.64208128, 0.643945936, 0.645752672, 0.64759, 0.6494492, 0.651336736, 0.653246992, 0.655157168, 0.65705, 0.658938976, 0.660841072, 0.662707248, 0.664461744,
-0.66606, 0.667495296, 0.668729248, 0.66986048, 0.671051872, 0.67239, 0.67385696, 0.67549904, 0.67708976, 0.67827024, 0.67883, 0.678819264, 0.678212,
-0.677242976, 0.676295392, 0.67562, 0.675174608, 0.674961136, 0.674977424, 0.675185152, 0.67555, 0.676097184, 0.676863264, 0.677707504, 0.678414224, 0.67885,
-0.679034288, 0.678950784, 0.6786896, 0.678407616, 0.67821, 0.678074272, 0.67799248, 0.678000288, 0.678138736, 0.67843, 0.678872656, 0.679470768, 0.68017504,
-0.680907392, 0.68161, 0.682288544, 0.68295336, 0.683536496, 0.683942992, 0.68412, 0.684076224, 0.683798976, 0.683386368, 0.68299504, 0.68273, 0.682573376,
-0.682516608, 0.68259136, 0.682832032, 0.68325, 0.683850368, 0.68466288, 0.685548672, 0.686291744, 0.68676, 0.6869716, 0.686901712, 0.686664144, 0.686452736,
-0.68639, 0.686449376, 0.686636864, 0.686912592, 0.68719976, 0.68745, 0.687680304, 0.687894592, 0.68809744, 0.688300288, 0.68851, 0.688722, 0.688934, 0.689146,
-0.689358, 0.68957, 0.689782, 0.689994, 0.690206, 0.690418, 0.69063, 0.690841984, 0.69105392, 0.691265856, 0.691477872, 0.69169, 0.691902224, 0.692114576,
-0.692326848, 0.69253872, 0.69275, 0.69296072, 0.693170848, 0.693380576, 0.693590224, 0.6938, 0.694009872, 0.694219856, 0.69442992, 0.694639984, 0.69485,
-0.695059984, 0.69526992, 0.695479856, 0.695689872, 0.6959, 0.696110224, 0.696320576, 0.696530848, 0.69674072, 0.69695, 0.69715872, 0.697366848, 0.697574576,
-0.697782224, 0.69799, 0.698197872, 0.698405856, 0.69861392, 0.698821984, 0.69903, 0.699238, 0.699446, 0.699654, 0.699862, 0.70007, 0.700276336, 0.70047768,
-0.700679024, 0.700888688, 0.70111, 0.701341296, 0.701585904, 0.701822192, 0.70201688, 0.70215,
-/* CES13 */ 0.04337, 0.04336512, 0.043358048, 0.043349296, 0.043339744, 0.04333, 0.043320096, 0.043310256, 0.043300416, 0.043290336, 0.04328, 0.04326928,
-0.043257456, 0.043246672, 0.043240288, 0.04324, 0.043245536, 0.043258112, 0.043271888, 0.043277664, 0.04327, 0.04325016, 0.043217568, 0.043177776, 0.043139904,
-0.04311, 0.043087504, 0.043073216, 0.043066608, 0.04306584, 0.04307, 0.04308032, 0.043097488, 0.043122416, 0.043156304, 0.0432, 0.043253536, 0.043316896,
-0.043390496, 0.043474816, 0.04357, 0.043675696, 0.043791536, 0.043916656, 0.044049856, 0.04419, 0.044336256, 0.044487616, 0.044642896, 0.044800816, 0.04496,
-0.0451192, 0.045277296, 0.045431952, 0.045580288, 0.04572, 0.045850512, 0.045971632, 0.046080672, 0.046174112, 0.04625, 0.046308736, 0.046350192, 0.046377648,
-0.046396304, 0.04641, 0.046418336, 0.046420608, 0.04642128, 0.046426592, 0.04644, 0.046460592, 0.04648856, 0.046518288, 0.046540896, 0.04655, 0.046546608,
-0.046532208, 0.046500128, 0.046441008, 0.04635, 0.04622992, 0.046082608, 0.045915936, 0.045741744, 0.04557, 0.045405184, 0.045253296, 0.045122528, 0.04502176,
-0.04496, 0.04494496, 0.044983728, 0.045089296, 0.045277184, 0.04556, 0.045941616, 0.046423696, 0.047021056, 0.047752896, 0.04863, 0.049649536, 0.050810416,
-0.052098416, 0.053491056, 0.05497, 0.056529504, 0.058161536, 0.059849728, 0.06157488, 0.06332, 0.065073968, 0.066825488, 0.068559248, 0.070258848, 0.07191,
-0.07350456, 0.075036688, 0.076490176, 0.077845424, 0.07909, 0.08022232, 0.081238976, 0.082150672, 0.082976368, 0.08373, 0.084410448, 0.085017472, 0.085564096,
-0.08606776, 0.08654, 0.086981888, 0.087396416, 0.087778704, 0.088118832, 0.08841, 0.08865472, 0.088855024, 0.089004688, 0.089094752, 0.08912, 0.089081808,
-0.088980368, 0.088820928, 0.088611968, 0.08836, 0.0880656, 0.087729648, 0.087358416, 0.086959984, 0.08654, 0.086099856, 0.085641776, 0.085164496, 0.084664576,
-0.08414, 0.083593328, 0.083027376, 0.082439264, 0.081824112, 0.08118, 0.080509984, 0.079816144, 0.079105344, 0.078387344, 0.07767, 0.076955392, 0.076245584,
-0.075548576, 0.074874368, 0.07423, 0.073617552, 0.073040096, 0.07249584, 0.071980064, 0.07149, 0.071028576, 0.07059856, 0.070197664, 0.069821968, 0.06947,
-0.06914552, 0.068852624, 0.068593728, 0.068371392, 0.06819, 0.068059648, 0.067994128, 0.067993648, 0.068053808, 0.06818, 0.06839072, 0.068700288, 0.069150816,
-0.069799104, 0.07069, 0.071832688, 0.073232656, 0.074938064, 0.077011232, 0.07949, 0.082371424, 0.085658144, 0.089317104, 0.093292464, 0.09754, 0.102050096,
-0.106806464, 0.111772032, 0.11690288, 0.12216, 0.12751768, 0.132949392, 0.138419824, 0.143891216, 0.14933, 0.154715136, 0.160030144, 0.165236992, 0.17029048,
-0.17516, 0.179837248, 0.184310112, 0.188594736, 0.19272384, 0.19672, 0.200575712, 0.204285456, 0.20786912, 0.211354944, 0.21476, 0.218081904, 0.22132224,
-0.224466016, 0.227488032, 0.23037, 0.233114224, 0.235723056, 0.238178448, 0.2404556, 0.24254, 0.244434848, 0.246140608, 0.247671808, 0.249052048, 0.2503,
-0.251419392, 0.252415808, 0.253306384, 0.25411216, 0.25485, 0.255528016, 0.256155264, 0.256740112, 0.25728944, 0.25781, 0.258308464, 0.258789792, 0.25926184,
-0.259733328, 0.26021, 0.260693536, 0.26118616, 0.261683344, 0.262176688, 0.26266, 0.263133968, 0.263598864, 0.2640484, 0.264474016, 0.26487, 0.265235904,
-0.2655704, 0.265875216, 0.266154192, 0.26641, 0.26664184, 0.266849376, 0.267034752, 0.267200928, 0.26735, 0.2674824, 0.267598912, 0.267699424, 0.267783216,
-0.26785, 0.267900848, 0.267936944, 0.26795776, 0.267962176, 0.26795, 0.267922528, 0.26788072, 0.267827312, 0.267766064, 0.2677, 0.267629888, 0.267556352,
-0.267482656, 0.26741296, 0.26735, 0.267294064, 0.267245776, 0.267203888, 0.26716592, 0.26713, 0.267096144, 0.267064048, 0.267032032, 0.266997936, 0.26696,
-0.26691848, 0.266874592, 0.266823984, 0.266760176, 0.26668, 0.266584032, 0.266470064, 0.266350096, 0.266242928, 0.26616, 0.2660996, 0.266063856, 0.266042512,
-0.266018208, 0.26598, 0.265930192, 0.265868112, 0.265797792, 0.265726832, 0.26566, 0.265595712, 0.265533712, 0.265473152, 0.265412272, 0.26535, 0.265286864,
-0.265223072, 0.2651588, 0.265094368, 0.26503, 0.264965632, 0.2649012, 0.264836928, 0.264773136, 0.26471, 0.264647504, 0.264585728, 0.264524272, 0.264462496,
-0.2644, 0.264336864, 0.264273072, 0.2642088, 0.264144368, 0.26408, 0.264015632, 0.2639512, 0.263886928, 0.263823136, 0.26376, 0.26369752, 0.263635808,
-0.263574416, 0.263512624, 0.26345, 0.263386608, 0.263322336, 0.263257664, 0.263193392, 0.26313, 0.263067392, 0.263005664, 0.262944336, 0.262882608, 0.26282,
-0.262756608, 0.262692336, 0.262627664, 0.262563392, 0.2625, 0.262437392, 0.262375664, 0.262314336, 0.262252608, 0.26219, 0.262126608, 0.262062336, 0.261997664,
-0.261933392, 0.26187, 0.261807888, 0.261748144, 0.2616888, 0.261626576, 0.26156, 0.26148968, 0.26141456, 0.26134152, 0.2612812, 0.26124,
-/* CES14 */ 0.3414, 0.3414, 0.3414, 0.3414, 0.3414, 0.3414, 0.3414, 0.3414, 0.3414, 0.3414, 0.3414, 0.341402576, 0.34141288, 0.341423184, 0.341420608, 0.3414,
-0.341364704, 0.341311104, 0.341270384, 0.341290224, 0.3414, 0.34159464, 0.341876384, 0.342226368, 0.342611872, 0.34301, 0.343423328, 0.343851488, 0.344282528,
-0.344701968, 0.3451, 0.345474224, 0.345821008, 0.346144992, 0.346455776, 0.34676, 0.347055616, 0.347341392, 0.347622768, 0.347907344, 0.3482, 0.348500624,
-0.348809968, 0.349125712, 0.349443456, 0.34976, 0.350075936, 0.350391712, 0.350703888, 0.351007664, 0.3513, 0.351580992, 0.351850128, 0.352109264, 0.35236176,
-0.35261, 0.352853728, 0.353093024, 0.35332936, 0.353564576, 0.3538, 0.354035968, 0.35427288, 0.354510912, 0.354749984, 0.35499, 0.355231136, 0.355473552,
-0.355716528, 0.355958944, 0.3562, 0.356439648, 0.356677728, 0.356913968, 0.357148128, 0.35738, 0.357609664, 0.357837488, 0.358062592, 0.358283616, 0.3585,
-0.358712352, 0.358920912, 0.359127952, 0.359336832, 0.35955, 0.359767424, 0.359988992, 0.36021672, 0.360453248, 0.3607, 0.360956544, 0.3612228, 0.361496256,
-0.361772992, 0.36205, 0.362327136, 0.362604336, 0.362878256, 0.363144416, 0.3634, 0.363645072, 0.363879216, 0.36410432, 0.364323744, 0.36454, 0.36475328,
-0.36496424, 0.36517448, 0.36538584, 0.3656, 0.365818224, 0.36604184, 0.366272336, 0.366511232, 0.36676, 0.367020192, 0.367293536, 0.36758088, 0.367882704,
-0.3682, 0.36853456, 0.36888792, 0.3692628, 0.36966256, 0.37009, 0.370546464, 0.37103328, 0.371552816, 0.372107712, 0.3727, 0.3733304, 0.373999376, 0.374708192,
-0.375458208, 0.37625, 0.377081728, 0.377950192, 0.378857456, 0.37980736, 0.3808, 0.381830048, 0.382893376, 0.383977264, 0.385064192, 0.38614, 0.387200544,
-0.388241904, 0.389249824, 0.390206624, 0.3911, 0.391926912, 0.392683024, 0.393371056, 0.393998368, 0.39457, 0.395083872, 0.395539136, 0.39593992, 0.396292064,
-0.3966, 0.396865408, 0.39709096, 0.397277152, 0.397423264, 0.39753, 0.39760208, 0.397645072, 0.397659344, 0.397643616, 0.3976, 0.397536032, 0.397458624,
-0.397380016, 0.397315808, 0.39728, 0.397281728, 0.397331216, 0.397441504, 0.397626592, 0.3979, 0.398272592, 0.398754304, 0.399362016, 0.400115328, 0.40103,
-0.402110048, 0.403355616, 0.404786784, 0.406430352, 0.4083, 0.410388608, 0.412692064, 0.41518392, 0.417824096, 0.42058, 0.423441472, 0.42639544, 0.429412288,
-0.432456896, 0.4355, 0.438524896, 0.441513808, 0.44444856, 0.447312512, 0.45009, 0.452768352, 0.45533856, 0.457781168, 0.460073216, 0.4622, 0.464159248,
-0.465947008, 0.467574928, 0.469063968, 0.47043, 0.471673664, 0.472797648, 0.473815712, 0.474745296, 0.4756, 0.476385568, 0.477108912, 0.477775136, 0.47838752,
-0.47895, 0.479468448, 0.479947696, 0.480392784, 0.480808832, 0.4812, 0.481569232, 0.481919504, 0.482251616, 0.482565088, 0.48286, 0.483138336, 0.483401776,
-0.483650256, 0.483883056, 0.4841, 0.484302128, 0.484490256, 0.484665264, 0.484828192, 0.48498, 0.485121728, 0.485254784, 0.48537912, 0.485494096, 0.4856,
-0.485698448, 0.48579056, 0.485880032, 0.485971904, 0.48607, 0.486174784, 0.486286432, 0.48640848, 0.486545488, 0.4867, 0.486871456, 0.48705984, 0.487261264,
-0.487469568, 0.48768, 0.487892032, 0.488104944, 0.488314176, 0.488513888, 0.4887, 0.48887168, 0.489027696, 0.489168432, 0.489295488, 0.48941, 0.489511744,
-0.489601152, 0.48967864, 0.489744608, 0.4898, 0.489846208, 0.4898844, 0.489918112, 0.489952064, 0.48999, 0.490032896, 0.490081552, 0.490139568, 0.490211424,
-0.4903, 0.490405536, 0.490528608, 0.49066744, 0.490818752, 0.49098, 0.491151136, 0.49133192, 0.491519424, 0.491709728, 0.4919, 0.492089488, 0.492277024,
-0.49246256, 0.492646816, 0.49283, 0.493010912, 0.49318848, 0.493362528, 0.493533136, 0.4937, 0.493862128, 0.494018608, 0.494168128, 0.494309088, 0.49444,
-0.4945608, 0.494672368, 0.494770096, 0.494847184, 0.4949, 0.494929536, 0.494934976, 0.494924256, 0.494909856, 0.4949, 0.494894112, 0.494893376, 0.49489632,
-0.494899264, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949,
-0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949,
-0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949,
-0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949,
-/* CES15 */ 0.16992, 0.170307008, 0.170873072, 0.171560656, 0.17227376, 0.17295, 0.17359768, 0.174209296, 0.174798592, 0.175396448, 0.17602, 0.176661808,
-0.177326912, 0.177986656, 0.1785956, 0.17913, 0.179593504, 0.179970976, 0.180332848, 0.18079064, 0.18141, 0.182177808, 0.183106528, 0.184118528, 0.185086528,
-0.18593, 0.186664784, 0.187287408, 0.187824432, 0.188327056, 0.18883, 0.1893268, 0.189819152, 0.190317104, 0.190830736, 0.19137, 0.191944416, 0.192563264,
-0.193236832, 0.19397576, 0.19479, 0.195687472, 0.196675312, 0.197764192, 0.198965952, 0.20029, 0.201738672, 0.203311552, 0.205020352, 0.206880752, 0.2089,
-0.211072464, 0.213394512, 0.21584656, 0.218399568, 0.22103, 0.223731024, 0.22649456, 0.229297936, 0.232113552, 0.23492, 0.237707696, 0.240465536, 0.243187136,
-0.245869936, 0.24851, 0.25109936, 0.253632256, 0.256102352, 0.258503088, 0.26083, 0.263081264, 0.265254912, 0.26735392, 0.269384128, 0.27135, 0.273251584,
-0.2750896, 0.276868416, 0.278593712, 0.28027, 0.281899216, 0.283483696, 0.285025696, 0.286527136, 0.28799, 0.289416656, 0.290809376, 0.292169856, 0.293499536,
-0.2948, 0.296073312, 0.297321536, 0.29854616, 0.299748464, 0.30093, 0.302093376, 0.30324168, 0.304375504, 0.305494528, 0.3066, 0.30769528, 0.308782864,
-0.309870048, 0.310966592, 0.31208, 0.313211328, 0.314360848, 0.315536208, 0.316747408, 0.318, 0.31929208, 0.320622448, 0.321984176, 0.323366464, 0.32476,
-0.326160112, 0.327560336, 0.3289528, 0.330329104, 0.33168, 0.332996368, 0.3342696, 0.335484592, 0.336623184, 0.33767, 0.338617648, 0.339459712, 0.340180576,
-0.34076168, 0.34119, 0.341462144, 0.341574176, 0.341527568, 0.34132792, 0.34098, 0.340485968, 0.339851328, 0.339077728, 0.338165568, 0.33712, 0.33595224,
-0.334672368, 0.333303056, 0.331873904, 0.33041, 0.328922944, 0.327424576, 0.325939648, 0.3244972, 0.32312, 0.321819264, 0.320607328, 0.319491312, 0.318474176,
-0.31756, 0.316756576, 0.316069072, 0.315503488, 0.315065984, 0.31476, 0.314584992, 0.314539888, 0.314620304, 0.31481936, 0.31513, 0.315544768, 0.316053024,
-0.31665064, 0.317335616, 0.3181, 0.318929136, 0.31981136, 0.320713424, 0.321591168, 0.32241, 0.323163904, 0.323850864, 0.324432544, 0.324858544, 0.3251,
-0.325164528, 0.325055904, 0.32480536, 0.324462656, 0.32407, 0.323647184, 0.32322288, 0.322829936, 0.322502512, 0.32228, 0.322202048, 0.322303856, 0.322660144,
-0.323363952, 0.32449, 0.326058432, 0.328078784, 0.330635616, 0.333838368, 0.33775, 0.34235888, 0.347664416, 0.353588832, 0.360007008, 0.36682, 0.374007232,
-0.381538272, 0.389324352, 0.397257312, 0.40525, 0.413258896, 0.421235632, 0.429136688, 0.436926624, 0.44457, 0.452031008, 0.459283168, 0.466282448, 0.472979088,
-0.47934, 0.48535584, 0.491014128, 0.496337456, 0.501368864, 0.50614, 0.510648448, 0.514895936, 0.518909744, 0.522725632, 0.52637, 0.529850944, 0.533179664,
-0.536359904, 0.539389664, 0.54227, 0.545011392, 0.547623184, 0.550107056, 0.552462048, 0.55469, 0.556798144, 0.558792576, 0.560682288, 0.56247824, 0.56419,
-0.565822816, 0.567381648, 0.56887664, 0.570319712, 0.57172, 0.573081792, 0.57441, 0.575706128, 0.576969376, 0.5782, 0.579401376, 0.580575968, 0.58172504,
-0.582849472, 0.58395, 0.585027648, 0.58608344, 0.587116672, 0.588125824, 0.58911, 0.590070432, 0.591008752, 0.591923152, 0.592810592, 0.59367, 0.59450344,
-0.595312272, 0.596101664, 0.596878976, 0.59765, 0.598416, 0.599178224, 0.599942128, 0.600714512, 0.6015, 0.602299472, 0.603114288, 0.603943024, 0.6047824,
-0.60563, 0.606486112, 0.607350464, 0.608220976, 0.609094928, 0.60997, 0.61084496, 0.611718416, 0.612588432, 0.613452928, 0.61431, 0.615158192, 0.615996112,
-0.616821952, 0.617633792, 0.61843, 0.61920944, 0.619970992, 0.620713744, 0.621436976, 0.62214, 0.622821936, 0.623481904, 0.624119792, 0.62473584, 0.62533,
-0.625904208, 0.626463152, 0.626998336, 0.62749592, 0.62795, 0.628363328, 0.628731376, 0.629085824, 0.629475472, 0.62993, 0.630445584, 0.631028384, 0.631650384,
-0.632264224, 0.63284, 0.633383968, 0.633894384, 0.63438176, 0.634866096, 0.63536, 0.635859216, 0.63636288, 0.636869584, 0.637375968, 0.63788, 0.638382928,
-0.638885344, 0.63938728, 0.639888816, 0.64039, 0.640890816, 0.64139128, 0.641891344, 0.642390928, 0.64289, 0.643388576, 0.643886624, 0.644384352, 0.64488208,
-0.64538, 0.64587808, 0.646376352, 0.646874624, 0.647372576, 0.64787, 0.648366928, 0.648863344, 0.64935928, 0.649854816, 0.65035, 0.6508448, 0.6513392,
-0.6518332, 0.6523268, 0.65282, 0.653312816, 0.65380528, 0.654297344, 0.654788928, 0.65528, 0.655770576, 0.656260624, 0.656750352, 0.65724008, 0.65773,
-0.658220064, 0.658710272, 0.65920048, 0.659690448, 0.66018, 0.660669168, 0.661158, 0.661646272, 0.662133664, 0.66262, 0.663105296, 0.663589472, 0.664072928,
-0.664556304, 0.66504, 0.665520112, 0.665989008, 0.666457984, 0.66694584, 0.66746, 0.667996704, 0.668563664, 0.669110944, 0.669561744, 0.66987,
-/* CES16 */ 0.03466, 0.034750672, 0.034883296, 0.0350444, 0.035211504, 0.03537, 0.035522336, 0.03566776, 0.035807984, 0.035947728, 0.03609, 0.036232352,
-0.036372544, 0.036514016, 0.036662208, 0.03682, 0.036986944, 0.037164736, 0.037345408, 0.03751632, 0.03767, 0.037808032, 0.037929248, 0.038041824, 0.0381592,
-0.03829, 0.038432816, 0.038588304, 0.038754192, 0.03892576, 0.0391, 0.039278112, 0.039460512, 0.039646832, 0.039836752, 0.04003, 0.040226672, 0.040427232,
-0.040630592, 0.040835072, 0.04104, 0.041246208, 0.041454112, 0.041666496, 0.04188744, 0.04212, 0.04236432, 0.042620416, 0.042891152, 0.043180288, 0.04349,
-0.043819952, 0.044170272, 0.044538192, 0.044919232, 0.04531, 0.045710496, 0.046120752, 0.046537088, 0.046954544, 0.04737, 0.047783488, 0.048194448, 0.048605088,
-0.049019328, 0.04944, 0.049866768, 0.050299488, 0.050740608, 0.051193408, 0.05166, 0.05214048, 0.052635328, 0.053143296, 0.053662064, 0.05419, 0.054727376,
-0.055274256, 0.055829536, 0.056391776, 0.05696, 0.057534048, 0.058113616, 0.058699024, 0.059290992, 0.05989, 0.060495792, 0.061108144, 0.061727536, 0.062354688,
-0.06299, 0.063633248, 0.064284256, 0.064942704, 0.065608112, 0.06628, 0.066957952, 0.067641424, 0.068329936, 0.069023008, 0.06972, 0.070420208, 0.071123056,
-0.071826944, 0.072529792, 0.07323, 0.073927216, 0.074621184, 0.075310592, 0.07599384, 0.07667, 0.077339072, 0.078000832, 0.078656752, 0.079309232, 0.07996,
-0.080608992, 0.081256272, 0.081902992, 0.082550592, 0.0832, 0.083851264, 0.084504512, 0.08515928, 0.085814688, 0.08647, 0.08712488, 0.08777872, 0.08843112,
-0.08908176, 0.08973, 0.090374944, 0.09101592, 0.091650656, 0.092276112, 0.09289, 0.09349208, 0.094082336, 0.094658672, 0.095218368, 0.09576, 0.096284032,
-0.096790512, 0.097282432, 0.097764432, 0.09824, 0.098709488, 0.099173472, 0.099634576, 0.100096, 0.10056, 0.101027232, 0.101498576, 0.1019736, 0.102451024,
-0.10293, 0.103410976, 0.10389416, 0.104378944, 0.104864448, 0.10535, 0.105835488, 0.106320784, 0.10680536, 0.107288576, 0.10777, 0.108249552, 0.10872712,
-0.109202848, 0.109677056, 0.11015, 0.110621808, 0.111092768, 0.111562848, 0.112031888, 0.1125, 0.112968192, 0.113437888, 0.113908624, 0.11437928, 0.11485,
-0.115322864, 0.115799584, 0.116284384, 0.116782944, 0.1173, 0.117837808, 0.118399024, 0.11898728, 0.119606416, 0.12026, 0.120951152, 0.12168312, 0.122458608,
-0.123280016, 0.12415, 0.12507192, 0.126049168, 0.127084496, 0.128180464, 0.12934, 0.130566576, 0.131863536, 0.133235056, 0.134685776, 0.13622, 0.137840656,
-0.139550256, 0.141354256, 0.143259216, 0.14527, 0.147388384, 0.149616416, 0.151954688, 0.15440264, 0.15696, 0.15962696, 0.162402448, 0.165288256, 0.168287264,
-0.1714, 0.174623584, 0.177955856, 0.181389328, 0.18491352, 0.18852, 0.192206112, 0.195968928, 0.199801024, 0.2036936, 0.20764, 0.211637072, 0.215680944,
-0.219771536, 0.223911008, 0.2281, 0.232334352, 0.236609616, 0.2409272, 0.245290544, 0.2497, 0.25415088, 0.2586396, 0.26315696, 0.26769048, 0.27223, 0.27677224,
-0.28131408, 0.28584536, 0.2903536, 0.29483, 0.299272176, 0.30367696, 0.308045584, 0.312382288, 0.31669, 0.320967776, 0.325215984, 0.329436112, 0.33363, 0.3378,
-0.341948272, 0.346076592, 0.350191152, 0.354300192, 0.35841, 0.362520832, 0.366631712, 0.370750512, 0.374888112, 0.37905, 0.383233264, 0.387436672, 0.39164848,
-0.395850848, 0.40003, 0.404184432, 0.40831272, 0.412400848, 0.416430576, 0.42039, 0.424278224, 0.428092688, 0.431839152, 0.435528816, 0.43917, 0.442762176,
-0.446305952, 0.449808928, 0.453281104, 0.45673, 0.460158032, 0.463568368, 0.466963104, 0.4703432, 0.47371, 0.477065536, 0.480410704, 0.483748912, 0.48708456,
-0.49042, 0.4937544, 0.497087392, 0.500414944, 0.503730816, 0.50703, 0.510310912, 0.513571264, 0.516807296, 0.520014848, 0.52319, 0.526335088, 0.529457936,
-0.532538064, 0.535543712, 0.53846, 0.541291184, 0.544026864, 0.546729824, 0.549497744, 0.55239, 0.555398048, 0.558533264, 0.56174112, 0.564929456, 0.56804,
-0.5710848, 0.5740604, 0.57698672, 0.57990192, 0.58283, 0.58576256, 0.58869768, 0.59163272, 0.59456144, 0.59748, 0.600390576, 0.60329416, 0.606190464,
-0.609079168, 0.61196, 0.614832784, 0.617697344, 0.620553504, 0.623401104, 0.62624, 0.629070016, 0.631890944, 0.634702832, 0.63750584, 0.6403, 0.643085136,
-0.645861152, 0.648627648, 0.651384064, 0.65413, 0.65686536, 0.659589968, 0.662303856, 0.665007184, 0.6677, 0.670382128, 0.673053456, 0.675713744, 0.678362672,
-0.681, 0.683625648, 0.686239504, 0.68884152, 0.691431696, 0.69401, 0.696576304, 0.69913048, 0.701672496, 0.704202352, 0.70672, 0.709225328, 0.711718256,
-0.714198544, 0.716665872, 0.71912, 0.72156088, 0.723988464, 0.726402608, 0.728803152, 0.73119, 0.733563088, 0.735922288, 0.738267888, 0.740600368, 0.74292,
-0.745209568, 0.747434848, 0.749646848, 0.751930608, 0.75432, 0.756798496, 0.759401408, 0.76190456, 0.763962912, 0.76537,
-/* CES17 */ 0.0308, 0.031091104, 0.031517184, 0.032036544, 0.032579264, 0.0331, 0.03360504, 0.034088944, 0.034562288, 0.035048352, 0.03556, 0.036092176,
-0.036649648, 0.0372088, 0.037732112, 0.0382, 0.038615776, 0.03896744, 0.039312544, 0.039742128, 0.04031, 0.04100432, 0.041832464, 0.042736928, 0.043623232,
-0.04443, 0.045165968, 0.045825888, 0.046420768, 0.046976768, 0.04751, 0.048011488, 0.048477408, 0.048908608, 0.049305808, 0.04967, 0.050002928, 0.050306608,
-0.050581888, 0.050829248, 0.05105, 0.051246608, 0.051421248, 0.051578208, 0.051722928, 0.05186, 0.051991456, 0.052119168, 0.05224792, 0.052383472, 0.05253,
-0.052689008, 0.0528624, 0.053049792, 0.053249344, 0.05346, 0.053682976, 0.053919072, 0.054167808, 0.054428304, 0.0547, 0.054983056, 0.055277488, 0.05558336,
-0.055900832, 0.05623, 0.056570688, 0.05692264, 0.057286192, 0.057661904, 0.05805, 0.058450064, 0.058861712, 0.0592844, 0.059717408, 0.06016, 0.060611584,
-0.06107152, 0.061538736, 0.062011952, 0.06249, 0.062971968, 0.063456816, 0.063943664, 0.064431712, 0.06492, 0.065407488, 0.065893344, 0.0663756, 0.066851776,
-0.06732, 0.067779536, 0.06822944, 0.068669424, 0.069099648, 0.06952, 0.069929888, 0.070329104, 0.07071648, 0.071090416, 0.07145, 0.071795392, 0.07212648,
-0.072444608, 0.072751936, 0.07305, 0.073338624, 0.073617648, 0.073888272, 0.074152096, 0.07441, 0.074661808, 0.074907632, 0.075145856, 0.07537392, 0.07559,
-0.075794528, 0.075988176, 0.076168784, 0.076333232, 0.07648, 0.07661016, 0.076724304, 0.076825808, 0.076919712, 0.07701, 0.077097648, 0.077183888, 0.077272128,
-0.077366448, 0.07747, 0.077584416, 0.077711728, 0.07785216, 0.078004912, 0.07817, 0.078349872, 0.0785472, 0.078761808, 0.078992576, 0.07924, 0.079507392,
-0.079797808, 0.080116064, 0.08046824, 0.08086, 0.081295568, 0.081779744, 0.082318, 0.082916096, 0.08358, 0.084315216, 0.08512688, 0.086023504, 0.087015088,
-0.08811, 0.089311824, 0.090623104, 0.092053024, 0.093612944, 0.09531, 0.097144176, 0.099115984, 0.101220992, 0.10345128, 0.1058, 0.108264016, 0.110838432,
-0.113517168, 0.116293584, 0.11916, 0.122108208, 0.125130048, 0.128212608, 0.131340528, 0.1345, 0.137682032, 0.140877648, 0.144072704, 0.14725128, 0.1504,
-0.153510256, 0.156573024, 0.159581072, 0.16252872, 0.16541, 0.168218368, 0.170948912, 0.173593456, 0.17614264, 0.17859, 0.180933552, 0.183170896, 0.1853044,
-0.187339424, 0.18928, 0.191126528, 0.19288096, 0.194545072, 0.196120464, 0.19761, 0.199017744, 0.200347232, 0.20160704, 0.202808288, 0.20396, 0.205065088,
-0.20612592, 0.207151712, 0.208153904, 0.20914, 0.210111072, 0.211069152, 0.212010032, 0.212925632, 0.21381, 0.214663472, 0.215485472, 0.216271152, 0.217014272,
-0.21771, 0.218356704, 0.218952592, 0.21949504, 0.219981488, 0.22041, 0.220780736, 0.22109528, 0.221349824, 0.221538688, 0.22166, 0.221717984, 0.221716144,
-0.221663984, 0.221574784, 0.22146, 0.221325856, 0.221180064, 0.221032112, 0.220892, 0.22077, 0.220676032, 0.220619952, 0.220614432, 0.220673472, 0.22081,
-0.221033696, 0.221353872, 0.221783888, 0.222338464, 0.22303, 0.223867312, 0.224860048, 0.226013024, 0.22732832, 0.22881, 0.230468112, 0.232313024, 0.234348416,
-0.236575728, 0.239, 0.241631648, 0.244479456, 0.247560944, 0.250898352, 0.25451, 0.25840224, 0.262579824, 0.267063088, 0.271877632, 0.27704, 0.282550576,
-0.288410768, 0.29461408, 0.301147712, 0.308, 0.315162144, 0.32261928, 0.330366896, 0.338404112, 0.34672, 0.355290528, 0.364094976, 0.373085024, 0.382197472,
-0.39138, 0.400612688, 0.409877104, 0.41911992, 0.428276256, 0.4373, 0.446176384, 0.4548856, 0.463436496, 0.471855792, 0.48016, 0.4883352, 0.496370736,
-0.504279472, 0.512082048, 0.51979, 0.527397392, 0.534901072, 0.542290112, 0.549547712, 0.55666, 0.56362192, 0.570426672, 0.577062064, 0.583514096, 0.58977,
-0.595844608, 0.601776704, 0.60748176, 0.612826176, 0.61775, 0.622274736, 0.62635608, 0.630278864, 0.634484128, 0.63924, 0.644509568, 0.650345824, 0.6564996,
-0.662549776, 0.66823, 0.67359528, 0.67862992, 0.68342416, 0.6881512, 0.69292, 0.697692448, 0.70246, 0.707210752, 0.711916464, 0.71656, 0.721151584, 0.725695872,
-0.7301932, 0.734644448, 0.73905, 0.743409328, 0.74772224, 0.751987632, 0.756203904, 0.76037, 0.764485872, 0.768551232, 0.772566512, 0.776532592, 0.78045,
-0.784318512, 0.788138112, 0.791908512, 0.795629232, 0.7993, 0.802920944, 0.806492112, 0.81001376, 0.813486288, 0.81691, 0.820284992, 0.82361144, 0.826889408,
-0.830118896, 0.8333, 0.836433008, 0.839518208, 0.842555888, 0.845546368, 0.84849, 0.851387104, 0.854237968, 0.857043232, 0.859803696, 0.86252, 0.865192496,
-0.867821632, 0.870407568, 0.872950304, 0.87545, 0.877907136, 0.880322128, 0.88269552, 0.885027952, 0.88732, 0.88957208, 0.89178464, 0.89395816, 0.89609312,
-0.89819, 0.900236112, 0.9022056, 0.904138448, 0.906100976, 0.90812, 0.910184048, 0.912322368, 0.914360608, 0.916029808, 0.91717,
-/* CES18 */ 0.0731, 0.0731, 0.0731, 0.0731, 0.0731, 0.0731, 0.0731, 0.0731, 0.0731, 0.0731, 0.0731, 0.073103648, 0.07311824, 0.073132832, 0.073129184, 0.0731,
-0.073050272, 0.072975392, 0.072918752, 0.072946592, 0.0731, 0.073372512, 0.073767712, 0.074260832, 0.074808352, 0.07538, 0.075979872, 0.076608032, 0.077248992,
-0.077883872, 0.0785, 0.079094784, 0.079664192, 0.08021408, 0.080756448, 0.0813, 0.081843392, 0.08238656, 0.082937248, 0.083505856, 0.0841, 0.084721504,
-0.085372928, 0.086056032, 0.086771456, 0.08752, 0.088302912, 0.089120352, 0.089974272, 0.090867232, 0.0918, 0.092770896, 0.093778672, 0.094817808, 0.095880384,
-0.09696, 0.098054656, 0.099162048, 0.1002764, 0.101390832, 0.1025, 0.103601472, 0.10469256, 0.105771568, 0.106837696, 0.10789, 0.108926912, 0.109947488,
-0.110950544, 0.11193488, 0.1129, 0.11384616, 0.114773504, 0.115684368, 0.116582272, 0.11747, 0.118348064, 0.119217008, 0.120079712, 0.120939856, 0.1218,
-0.122660768, 0.123523152, 0.124386496, 0.12524912, 0.12611, 0.126969984, 0.127829776, 0.128688688, 0.1295456, 0.1304, 0.131252448, 0.132103328, 0.132953808,
-0.133805568, 0.13466, 0.135517632, 0.136379088, 0.137245664, 0.13811888, 0.139, 0.139889728, 0.140788784, 0.141698, 0.142618176, 0.14355, 0.144493824,
-0.14544976, 0.146418736, 0.147401952, 0.1484, 0.149412304, 0.150438176, 0.151477008, 0.152528, 0.15359, 0.154660928, 0.155737968, 0.156820448, 0.157908368,
-0.159, 0.160091264, 0.161178608, 0.162254112, 0.163307456, 0.16433, 0.165318208, 0.166268752, 0.167172656, 0.16801904, 0.1688, 0.169513072, 0.170155376,
-0.1707264, 0.171227584, 0.17166, 0.172023552, 0.17231976, 0.172548208, 0.172707856, 0.1728, 0.17282912, 0.172799328, 0.172719936, 0.172603264, 0.17246,
-0.17229584, 0.172117056, 0.171933952, 0.171758208, 0.1716, 0.171465936, 0.171362512, 0.171298128, 0.171281504, 0.17132, 0.171416992, 0.171573888, 0.171799824,
-0.17210648, 0.1725, 0.172978048, 0.173539504, 0.17417304, 0.174861216, 0.17559, 0.176355488, 0.17715248, 0.177968432, 0.178788384, 0.1796, 0.180396912,
-0.181172672, 0.181918832, 0.182626912, 0.18329, 0.183905888, 0.184475152, 0.184988096, 0.1854316, 0.1858, 0.186099296, 0.186334096, 0.186521536, 0.186686496,
-0.18685, 0.187021632, 0.187213456, 0.18744376, 0.187732864, 0.1881, 0.188559904, 0.18912656, 0.189822416, 0.190673152, 0.1917, 0.192910976, 0.194310096,
-0.195922336, 0.197779616, 0.1999, 0.202279536, 0.204917216, 0.207789056, 0.210856896, 0.21409, 0.217480096, 0.221015456, 0.224668416, 0.228405936, 0.2322,
-0.236033936, 0.239889936, 0.243748576, 0.247591136, 0.2514, 0.255160992, 0.258862896, 0.26248488, 0.266002864, 0.2694, 0.27267056, 0.27580736, 0.27881616,
-0.28171056, 0.2845, 0.28718144, 0.28975376, 0.29222448, 0.294604, 0.2969, 0.299114592, 0.30125104, 0.303310688, 0.305293376, 0.3072, 0.309034528, 0.310800928,
-0.312500288, 0.314132768, 0.3157, 0.317205984, 0.318654208, 0.320050912, 0.321403936, 0.32272, 0.324002432, 0.325254432, 0.326482912, 0.327696032, 0.3289,
-0.330097536, 0.331291712, 0.332483648, 0.333673024, 0.33486, 0.336046176, 0.337232288, 0.33841936, 0.339608512, 0.3408, 0.341993248, 0.343188, 0.344380672,
-0.345565984, 0.34674, 0.347902656, 0.349054112, 0.350190528, 0.351306784, 0.3524, 0.353470896, 0.354519568, 0.35555, 0.356568512, 0.35758, 0.358584896,
-0.35958368, 0.360581584, 0.361585488, 0.3626, 0.363625632, 0.364663504, 0.365712176, 0.366768448, 0.36783, 0.368897088, 0.369969376, 0.371045264, 0.372122752,
-0.3732, 0.374275888, 0.375349344, 0.376418, 0.377478976, 0.37853, 0.379570752, 0.38060152, 0.381619168, 0.382619376, 0.3836, 0.384561776, 0.385504288,
-0.38643384, 0.387360192, 0.38829, 0.389220672, 0.39014824, 0.391080608, 0.392029616, 0.393, 0.393984928, 0.394979248, 0.395966528, 0.396923328, 0.39783,
-0.398686144, 0.399497648, 0.400222832, 0.400801536, 0.4012, 0.401423952, 0.401464752, 0.401382432, 0.401273152, 0.4012, 0.401156864, 0.401151472, 0.40117304,
-0.401194608, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012,
-0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012,
-0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012,
-0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012,
-/* CES19 */ 0.1202, 0.1202, 0.1202, 0.1202, 0.1202, 0.1202, 0.1202, 0.1202, 0.1202, 0.1202, 0.1202, 0.120204736, 0.12022368, 0.120242624, 0.120237888, 0.1202,
-0.120133344, 0.120027744, 0.119945824, 0.119984064, 0.1202, 0.120579408, 0.121123664, 0.12178456, 0.122482336, 0.12316, 0.12382112, 0.12446464, 0.12505368,
-0.125542, 0.1259, 0.126124864, 0.12620936, 0.126174576, 0.126058752, 0.12589, 0.12566624, 0.125387936, 0.125080272, 0.124776608, 0.1245, 0.1242544, 0.124046832,
-0.123873664, 0.123723776, 0.12359, 0.12347728, 0.123388944, 0.123318928, 0.123257952, 0.1232, 0.123145552, 0.123093808, 0.123046624, 0.12300784, 0.12298,
-0.12296192, 0.122952624, 0.122954128, 0.122969392, 0.123, 0.1230452, 0.123104688, 0.123176016, 0.123255424, 0.12334, 0.123429408, 0.123523296, 0.123618704,
-0.123711792, 0.1238, 0.123882928, 0.123959744, 0.12403168, 0.124101216, 0.12417, 0.124237376, 0.12430288, 0.124367584, 0.124433008, 0.1245, 0.124568272,
-0.124637744, 0.124707296, 0.124775168, 0.12484, 0.124901664, 0.124960016, 0.125013648, 0.12506072, 0.1251, 0.125131504, 0.125155248, 0.125171152, 0.125179296,
-0.12518, 0.125174528, 0.125164992, 0.125150416, 0.12512872, 0.1251, 0.125068352, 0.125037936, 0.12501384, 0.125002304, 0.12501, 0.125045152, 0.1251184,
-0.125233488, 0.125391776, 0.1256, 0.125871888, 0.126218848, 0.126668208, 0.127255648, 0.12801, 0.128939392, 0.130049488, 0.131371904, 0.13294696, 0.1348,
-0.13693048, 0.139340384, 0.142014448, 0.144925312, 0.14805, 0.151378432, 0.154893648, 0.158580064, 0.16242264, 0.1664, 0.170485376, 0.174654864, 0.178856992,
-0.18302656, 0.18711, 0.191088672, 0.194948432, 0.198631152, 0.202064032, 0.2052, 0.208035392, 0.210561472, 0.212803792, 0.214810592, 0.21662, 0.218239936,
-0.219687472, 0.220991328, 0.222184064, 0.2233, 0.224371184, 0.225429888, 0.226525312, 0.227714816, 0.22905, 0.23056528, 0.232294592, 0.234289824, 0.236608816,
-0.2393, 0.242392112, 0.245912624, 0.249894176, 0.254368288, 0.25936, 0.264874816, 0.270905136, 0.277489296, 0.284680976, 0.2925, 0.300914896, 0.309901936,
-0.319372656, 0.329200256, 0.33928, 0.34957344, 0.360035936, 0.370568752, 0.381055088, 0.3914, 0.401552544, 0.411457872, 0.42106808, 0.430344768, 0.43925,
-0.447747232, 0.45581216, 0.463394608, 0.470435776, 0.4769, 0.482785248, 0.488085008, 0.492839648, 0.497117088, 0.50097, 0.504405408, 0.507436048, 0.510108688,
-0.512481408, 0.5146, 0.516485488, 0.518162448, 0.519648928, 0.520956768, 0.5221, 0.523099136, 0.523971088, 0.52473368, 0.525405072, 0.526, 0.526528992,
-0.52700288, 0.527423968, 0.527789696, 0.5281, 0.528362368, 0.528583008, 0.528763008, 0.528901728, 0.529, 0.529061728, 0.529090048, 0.529089248, 0.529064448,
-0.52902, 0.528958048, 0.528880448, 0.528791648, 0.528696928, 0.5286, 0.528502144, 0.528405088, 0.528307552, 0.528206496, 0.5281, 0.52798928, 0.527875232,
-0.527756704, 0.527631936, 0.5275, 0.527361408, 0.527216384, 0.5270664, 0.526913696, 0.52676, 0.52660528, 0.52644928, 0.5262944, 0.526144, 0.526, 0.525861936,
-0.52572984, 0.525601264, 0.525472288, 0.52534, 0.525204608, 0.525066464, 0.52492232, 0.524767696, 0.5246, 0.524419776, 0.52422696, 0.524024624, 0.523817728,
-0.52361, 0.52340144, 0.523192064, 0.522985648, 0.522787232, 0.5226, 0.522423952, 0.522259648, 0.522104944, 0.521956, 0.52181, 0.521667328, 0.521528304,
-0.52138992, 0.521248016, 0.5211, 0.520946032, 0.52078576, 0.520621008, 0.520454976, 0.52029, 0.520125632, 0.519961328, 0.519799904, 0.51964536, 0.5195,
-0.519363248, 0.519235104, 0.51911288, 0.518992256, 0.51887, 0.518746016, 0.51862008, 0.518489344, 0.518350128, 0.5182, 0.518038816, 0.517866224, 0.517682912,
-0.5174904, 0.51729, 0.51708224, 0.516868112, 0.516648784, 0.516425536, 0.5162, 0.515974064, 0.515749584, 0.515529824, 0.515318784, 0.51512, 0.51493408,
-0.514760144, 0.514606288, 0.514484112, 0.5144, 0.514352832, 0.514344448, 0.514361984, 0.51438496, 0.5144, 0.514408192, 0.514407424, 0.514401216, 0.514397248,
-0.5144, 0.514406976, 0.514417536, 0.514429056, 0.514437376, 0.51444, 0.514437376, 0.514429056, 0.514417536, 0.514406976, 0.5144, 0.514396096, 0.514395456,
-0.514397056, 0.514398976, 0.5144, 0.514400512, 0.514400576, 0.51440032, 0.514400064, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144,
-0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144,
-0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144,
-0.5144, 0.5144,
-/* CES20 */ 0.02509, 0.025332624, 0.025687664, 0.026120704, 0.026573984, 0.02701, 0.027432192, 0.027832624, 0.028224816, 0.028636048, 0.02908, 0.029555296,
-0.030078336, 0.030593536, 0.031012736, 0.03129, 0.031432912, 0.031412816, 0.03136632, 0.031509504, 0.03197, 0.032719248, 0.033774, 0.034998272, 0.036168784,
-0.03714, 0.037931472, 0.038529792, 0.038958672, 0.039276912, 0.03952, 0.039665664, 0.039703952, 0.03963368, 0.039452528, 0.03916, 0.038761248, 0.03826352,
-0.037663872, 0.036955904, 0.03614, 0.035226432, 0.034223072, 0.033153632, 0.032050592, 0.03094, 0.029828496, 0.028721552, 0.027646608, 0.026638464, 0.02572,
-0.024894448, 0.024168528, 0.023529408, 0.022952368, 0.02242, 0.021936736, 0.021505808, 0.0211112, 0.020730512, 0.02035, 0.019971776, 0.01959576, 0.019230624,
-0.018891328, 0.01859, 0.018329984, 0.018116784, 0.017960944, 0.017874784, 0.01787, 0.017956816, 0.018146864, 0.018449632, 0.01887376, 0.01943, 0.020135088,
-0.021008192, 0.022058336, 0.02329128, 0.02472, 0.02636656, 0.028249536, 0.030410512, 0.032902928, 0.03577, 0.039024448, 0.042674992, 0.046768496, 0.05136448,
-0.0565, 0.062171632, 0.068377616, 0.07509952, 0.082304384, 0.08996, 0.098036608, 0.10648856, 0.115299152, 0.124461264, 0.13394, 0.143666112, 0.153580832,
-0.163540832, 0.173358272, 0.18288, 0.1920568, 0.200848352, 0.209092864, 0.216588096, 0.2232, 0.22890864, 0.233677824, 0.237570688, 0.240713632, 0.2432,
-0.24502408, 0.246196688, 0.246794416, 0.246915744, 0.24664, 0.246003328, 0.245047936, 0.243818544, 0.242355952, 0.2407, 0.238887952, 0.236950944, 0.234934336,
-0.232888128, 0.23085, 0.228839472, 0.226878256, 0.22496424, 0.223081104, 0.22122, 0.219394304, 0.2176136, 0.215873216, 0.214163792, 0.21248, 0.21082552,
-0.209202576, 0.207613472, 0.206061568, 0.20455, 0.20308112, 0.201658032, 0.200283344, 0.198959536, 0.19769, 0.196479104, 0.195330944, 0.194252704, 0.193253184,
-0.19234, 0.191517696, 0.190791104, 0.190166592, 0.18965088, 0.18925, 0.188968592, 0.188811152, 0.188782512, 0.188887392, 0.18913, 0.189513312, 0.190039552,
-0.190713072, 0.191538832, 0.19252, 0.193657072, 0.194950752, 0.196398592, 0.197996192, 0.19974, 0.201628896, 0.203660832, 0.205833408, 0.208144144, 0.21059,
-0.213167968, 0.215875648, 0.218705968, 0.221649728, 0.2247, 0.227856336, 0.231119488, 0.234482, 0.237933792, 0.24147, 0.245094576, 0.24881088, 0.252628304,
-0.256560528, 0.26062, 0.264814992, 0.269156224, 0.273654656, 0.278321488, 0.28317, 0.288214624, 0.293468736, 0.298956848, 0.30470888, 0.31075, 0.317091168,
-0.323741328, 0.330727568, 0.338083008, 0.34583, 0.353971264, 0.362510048, 0.371442832, 0.380759616, 0.39045, 0.400502448, 0.410896992, 0.421631536, 0.43270992,
-0.44412, 0.45582728, 0.467801216, 0.479974512, 0.492259088, 0.50458, 0.516901584, 0.529186752, 0.54136224, 0.553342768, 0.56506, 0.576477824, 0.5875576,
-0.598270736, 0.608598192, 0.61852, 0.628013168, 0.637064256, 0.645644224, 0.653718912, 0.66127, 0.668301376, 0.674813984, 0.680843072, 0.68644376, 0.69166,
-0.696502016, 0.700983552, 0.705145488, 0.709037824, 0.7127, 0.716150896, 0.719412448, 0.7224988, 0.725417792, 0.72818, 0.730804416, 0.7333076, 0.735702144,
-0.737999248, 0.74021, 0.742346368, 0.744419664, 0.7464392, 0.748413216, 0.75035, 0.752258304, 0.754146, 0.756021616, 0.757893792, 0.75977, 0.761655968,
-0.763557216, 0.765477904, 0.767421152, 0.76939, 0.77138712, 0.773413664, 0.775474448, 0.777575552, 0.77972, 0.7819064, 0.784134128, 0.786395936, 0.788680784,
-0.79098, 0.793291712, 0.795613456, 0.79793776, 0.800255584, 0.80256, 0.804847936, 0.80711576, 0.809362064, 0.811587008, 0.81379, 0.815967888, 0.818117744,
-0.82023936, 0.822333536, 0.8244, 0.826436384, 0.82844072, 0.830410096, 0.832340912, 0.83423, 0.83607528, 0.837874416, 0.839624912, 0.841324288, 0.84297,
-0.844565728, 0.846121472, 0.847615536, 0.84901352, 0.8503, 0.851480752, 0.852544736, 0.8535648, 0.854653664, 0.85588, 0.85723464, 0.85873128, 0.86030712,
-0.86185576, 0.86331, 0.864684096, 0.8659744, 0.867203904, 0.868416528, 0.86964, 0.870864896, 0.872089184, 0.873310352, 0.87452192, 0.87572, 0.876907328,
-0.878085392, 0.879253856, 0.88041216, 0.88156, 0.882697472, 0.883824496, 0.88494168, 0.886049984, 0.88715, 0.88824168, 0.8893252, 0.8904, 0.89146512, 0.89252,
-0.893564832, 0.8945996, 0.895624848, 0.896641456, 0.89765, 0.898650416, 0.899642768, 0.90062696, 0.901602752, 0.90257, 0.903528832, 0.90447936, 0.905421488,
-0.906355056, 0.90728, 0.908196368, 0.909104128, 0.910003648, 0.910895488, 0.91178, 0.912657184, 0.913527168, 0.914389632, 0.915244016, 0.91609, 0.916927712,
-0.917757152, 0.918578672, 0.919392832, 0.9202, 0.92100016, 0.921793392, 0.922579584, 0.923358496, 0.92413, 0.924894192, 0.925651104, 0.926400896, 0.927143808,
-0.92788, 0.928604288, 0.929306336, 0.930001664, 0.930716112, 0.93146, 0.932228448, 0.933032384, 0.9338036, 0.934437056, 0.93487,
-/* CES21 */ 0.12356, 0.124467696, 0.125795744, 0.127412672, 0.1290984, 0.13071, 0.13226144, 0.133724672, 0.135147904, 0.136629616, 0.13822, 0.139910896,
-0.141754064, 0.143566752, 0.1450592, 0.14608, 0.146653312, 0.146684432, 0.146619792, 0.147166032, 0.14874, 0.151252704, 0.154769312, 0.15882544, 0.162659008,
-0.16578, 0.168268128, 0.17008912, 0.171359872, 0.172329824, 0.17316, 0.173789408, 0.174200192, 0.174423776, 0.1744936, 0.17444, 0.17428376, 0.174042256,
-0.173749712, 0.173446048, 0.17316, 0.17290256, 0.172687232, 0.172508624, 0.172349776, 0.1722, 0.172067248, 0.171956704, 0.1718608, 0.171767776, 0.17167,
-0.171568336, 0.17146216, 0.171353104, 0.171244848, 0.17114, 0.171038032, 0.170939024, 0.170844816, 0.170757808, 0.17068, 0.170612464, 0.170556496, 0.170513328,
-0.17048408, 0.17047, 0.17047264, 0.170493568, 0.170534176, 0.170595824, 0.17068, 0.170788304, 0.170922256, 0.171083968, 0.17127584, 0.1715, 0.171757728,
-0.172050128, 0.172379568, 0.172748848, 0.17316, 0.1736136, 0.174110208, 0.174650176, 0.175233504, 0.17586, 0.176528976, 0.177239056, 0.177990576, 0.178784416,
-0.17962, 0.1804948, 0.181406736, 0.182349952, 0.183316528, 0.1843, 0.185298688, 0.186311472, 0.187330496, 0.18834552, 0.18935, 0.190343968, 0.191326736,
-0.192301504, 0.193274272, 0.19425, 0.195230432, 0.196218704, 0.197218576, 0.198234048, 0.19927, 0.200332544, 0.201428256, 0.202564368, 0.2037488, 0.20499,
-0.206297792, 0.207683568, 0.209154624, 0.21071696, 0.21238, 0.214157776, 0.216063264, 0.218118272, 0.22034944, 0.22278, 0.225422768, 0.228290032, 0.231405936,
-0.23479864, 0.23849, 0.242490256, 0.246810416, 0.251456976, 0.256432496, 0.26174, 0.267383856, 0.273364496, 0.279688976, 0.286366576, 0.2934, 0.300782288,
-0.308507376, 0.316556944, 0.324905232, 0.33353, 0.342419744, 0.351560544, 0.360932224, 0.370512464, 0.38028, 0.390216736, 0.400303904, 0.410520912, 0.42084656,
-0.43126, 0.441741536, 0.452271472, 0.462828768, 0.473391904, 0.48394, 0.494454496, 0.504917808, 0.51530728, 0.525598432, 0.53577, 0.545810192, 0.55571064,
-0.565447008, 0.574989696, 0.58432, 0.59343568, 0.602331568, 0.611024336, 0.619543344, 0.62791, 0.636121776, 0.644176976, 0.652096336, 0.659908096, 0.66763,
-0.675261456, 0.682805136, 0.690249216, 0.697572656, 0.70476, 0.711812928, 0.718732496, 0.725503104, 0.732103472, 0.73852, 0.744752464, 0.750797984, 0.756664944,
-0.762368704, 0.76792, 0.773315792, 0.778553184, 0.783642576, 0.788598768, 0.79343, 0.798134032, 0.802711296, 0.80714896, 0.811426704, 0.81553, 0.819461776,
-0.82322648, 0.826807904, 0.830182928, 0.83334, 0.836286016, 0.839024144, 0.841579072, 0.84398808, 0.84628, 0.848458544, 0.850527552, 0.85251528, 0.854458048,
-0.85638, 0.858284688, 0.86017936, 0.862052592, 0.863881424, 0.86565, 0.867363632, 0.869026432, 0.870624032, 0.872135952, 0.87355, 0.874868608, 0.876091552,
-0.877229776, 0.87830136, 0.87932, 0.880286128, 0.881200736, 0.882077184, 0.882932992, 0.88378, 0.884620016, 0.885456624, 0.886285872, 0.88709896, 0.88789,
-0.888661744, 0.889416272, 0.89014856, 0.890851248, 0.89152, 0.89215592, 0.89275912, 0.89333352, 0.89388552, 0.89442, 0.894937056, 0.8954368, 0.895924544,
-0.896407408, 0.89689, 0.897372496, 0.897855824, 0.898337632, 0.89881344, 0.89928, 0.899737648, 0.900186272, 0.900623296, 0.90104536, 0.90145, 0.901836608,
-0.902204416, 0.902552784, 0.902881392, 0.90319, 0.903478368, 0.903746544, 0.90399448, 0.904222176, 0.90443, 0.904618672, 0.90478888, 0.904942528, 0.905082176,
-0.90521, 0.905327056, 0.905434528, 0.9055344, 0.905628912, 0.90572, 0.905808912, 0.90589688, 0.905985408, 0.906076016, 0.90617, 0.906268192, 0.906371248,
-0.906480224, 0.90659624, 0.90672, 0.906851648, 0.906991344, 0.9071384, 0.907291616, 0.90745, 0.907613232, 0.9077808, 0.907951808, 0.908125216, 0.9083,
-0.908475952, 0.908653488, 0.908829424, 0.90899912, 0.90916, 0.909312256, 0.909454624, 0.909592832, 0.90973608, 0.90989, 0.910053584, 0.910227632, 0.91040784,
-0.910586848, 0.91076, 0.91092832, 0.9110916, 0.91125128, 0.91141016, 0.91157, 0.91173016, 0.91189048, 0.9120508, 0.91221072, 0.91237, 0.912528816, 0.91268728,
-0.912845344, 0.913002928, 0.91316, 0.913316592, 0.913472704, 0.913628496, 0.913784208, 0.91394, 0.91409584, 0.914251696, 0.914407632, 0.914563728, 0.91472,
-0.914876464, 0.915033232, 0.91518984, 0.915345568, 0.9155, 0.915653184, 0.91580496, 0.915956016, 0.916107472, 0.91626, 0.916413472, 0.916568016, 0.91672296,
-0.916877184, 0.91703, 0.917181552, 0.91733176, 0.917481088, 0.917630336, 0.91778, 0.917929968, 0.918080288, 0.918230688, 0.918380688, 0.91853, 0.918678704,
-0.918826768, 0.918974432, 0.919122096, 0.91927, 0.91941808, 0.919566352, 0.919714624, 0.919862576, 0.92001, 0.920156944, 0.920303424, 0.920449424, 0.920594944,
-0.92074, 0.92088344, 0.921022944, 0.921162128, 0.921306992, 0.92146, 0.92162, 0.921789328, 0.921952976, 0.922087824, 0.92218,
-/* CES22 */ 0.00012, 0.001470288, 0.003445808, 0.005839088, 0.008305088, 0.01062, 0.012818768, 0.014884528, 0.016855168, 0.018821808, 0.02084, 0.022876048,
-0.024913568, 0.026955888, 0.029007088, 0.03107, 0.03314384, 0.035226608, 0.037322096, 0.039435984, 0.04157, 0.04371984, 0.045882176, 0.048046112, 0.050196288,
-0.05232, 0.054412912, 0.056470512, 0.058479472, 0.060423392, 0.06229, 0.064074976, 0.065773312, 0.067382288, 0.068901424, 0.07033, 0.071666864, 0.072913408,
-0.074065792, 0.075118336, 0.07607, 0.076926272, 0.077691392, 0.078380352, 0.079014192, 0.07961, 0.08017296, 0.080708352, 0.081233264, 0.081768736, 0.08233,
-0.082921968, 0.083550944, 0.08421656, 0.084913856, 0.08564, 0.0863988, 0.08719264, 0.0880196, 0.08887624, 0.08976, 0.090670816, 0.09160824, 0.092570384,
-0.093554928, 0.09456, 0.095585072, 0.096629904, 0.097692336, 0.098769568, 0.09986, 0.100963952, 0.102081456, 0.10321456, 0.104366544, 0.10554, 0.106735376,
-0.10795328, 0.109196304, 0.110467728, 0.11177, 0.113103872, 0.114470224, 0.115869776, 0.117302928, 0.11877, 0.120270992, 0.121805296, 0.123374, 0.124978784,
-0.12662, 0.128296208, 0.13000648, 0.131746272, 0.133509104, 0.13529, 0.137088432, 0.138904192, 0.140732112, 0.142565392, 0.1444, 0.146236432, 0.148074432,
-0.149918272, 0.151774992, 0.15365, 0.155544032, 0.157458432, 0.159397552, 0.161366672, 0.16337, 0.165409728, 0.167488432, 0.169607536, 0.17176768, 0.17397,
-0.176217152, 0.178511776, 0.18085496, 0.183247264, 0.18569, 0.188185632, 0.19073624, 0.193345728, 0.196018976, 0.19876, 0.201570192, 0.204450528, 0.207405504,
-0.2104408, 0.21356, 0.21676336, 0.220051744, 0.223422208, 0.226869552, 0.23039, 0.233984256, 0.237653088, 0.24139224, 0.245195712, 0.24906, 0.252985888,
-0.25697336, 0.261026112, 0.265150144, 0.26935, 0.273626016, 0.277978832, 0.282412368, 0.286931584, 0.29154, 0.296238368, 0.301027648, 0.305908048, 0.310879008,
-0.31594, 0.3210904, 0.326328528, 0.331655296, 0.337072544, 0.34258, 0.348174592, 0.353854096, 0.35961032, 0.365431904, 0.37131, 0.377242976, 0.3832296,
-0.389261264, 0.395327008, 0.40142, 0.407539584, 0.413683504, 0.419858944, 0.426078304, 0.43235, 0.438670224, 0.445033984, 0.451451424, 0.457937824, 0.4645,
-0.471131168, 0.477827264, 0.4845688, 0.491327216, 0.49808, 0.504822576, 0.51155016, 0.518240864, 0.524867168, 0.53141, 0.537864688, 0.544224064, 0.55049344,
-0.556685936, 0.56281, 0.568860272, 0.57483256, 0.580733728, 0.586574256, 0.59236, 0.5980884, 0.603758368, 0.609363376, 0.614893264, 0.62034, 0.62570216,
-0.630978016, 0.636159392, 0.641235888, 0.6462, 0.651049728, 0.655782432, 0.660397616, 0.66489656, 0.66928, 0.673547056, 0.677698016, 0.681732416, 0.685649536,
-0.68945, 0.693135952, 0.696709136, 0.7001756, 0.703543664, 0.70682, 0.710006592, 0.71310544, 0.716122688, 0.719065776, 0.72194, 0.724747232, 0.727490048,
-0.730167024, 0.7327744, 0.73531, 0.737776512, 0.740176784, 0.742508256, 0.744766528, 0.74695, 0.749061584, 0.751103136, 0.753081888, 0.75500816, 0.75689,
-0.758728464, 0.760524048, 0.762285712, 0.764025296, 0.76575, 0.767459552, 0.769155232, 0.770829792, 0.772470992, 0.77407, 0.775628144, 0.777146832, 0.77861728,
-0.780027328, 0.78137, 0.782647168, 0.78385928, 0.785014192, 0.786124464, 0.7872, 0.788242592, 0.789254752, 0.790245952, 0.791228032, 0.79221, 0.793195152,
-0.794187312, 0.795189392, 0.796203072, 0.79723, 0.798271552, 0.799327392, 0.800401232, 0.801498192, 0.80262, 0.803763776, 0.804927792, 0.806101488, 0.807269504,
-0.80842, 0.809551648, 0.810663648, 0.811743728, 0.812775808, 0.81375, 0.814666656, 0.815524368, 0.81633168, 0.817103232, 0.81785, 0.81857184, 0.8192692,
-0.81995272, 0.82063648, 0.82133, 0.822034688, 0.82275344, 0.823483152, 0.824216864, 0.82495, 0.825684864, 0.826423152, 0.82716144, 0.827894688, 0.82862,
-0.829338016, 0.8300484, 0.830755024, 0.831464128, 0.83218, 0.832901024, 0.833624864, 0.834356624, 0.835103904, 0.83587, 0.836651568, 0.837446784, 0.83824488,
-0.839030016, 0.83979, 0.84052368, 0.84123048, 0.84189744, 0.84250744, 0.84305, 0.843526, 0.84393472, 0.8442844, 0.84458912, 0.84486, 0.845099376, 0.84531136,
-0.845505104, 0.845691408, 0.84588, 0.846071632, 0.846261344, 0.846476096, 0.846755408, 0.84712, 0.84756752, 0.848110176, 0.848687472, 0.849203568, 0.8496,
-0.849886304, 0.850052752, 0.8501484, 0.850256608, 0.85043, 0.850657248, 0.85094112, 0.851263632, 0.851590464, 0.8519, 0.852199616, 0.852490832, 0.852776128,
-0.853061344, 0.85335, 0.853639936, 0.853930128, 0.8542204, 0.854510432, 0.8548, 0.855089152, 0.85537792, 0.855666128, 0.855953536, 0.85624, 0.85652552,
-0.856810048, 0.857093776, 0.857377024, 0.85766, 0.857942672, 0.858225056, 0.85850712, 0.858788784, 0.85907, 0.859350784, 0.85963112, 0.859911056, 0.860190672,
-0.86047, 0.860746848, 0.861016896, 0.861286464, 0.861566112, 0.86186, 0.862166, 0.862488464, 0.862799248, 0.863055072, 0.86323,
-/* CES23 */ 0.37853, 0.381475488, 0.385785024, 0.39093352, 0.396060576, 0.40061, 0.404662736, 0.40816944, 0.411255184, 0.414195008, 0.41716, 0.42006664,
-0.422881504, 0.425611088, 0.428256112, 0.43082, 0.43331408, 0.435747408, 0.438126096, 0.440455344, 0.44274, 0.444985216, 0.447195776, 0.449374176, 0.451521696,
-0.45364, 0.455731968, 0.457799776, 0.459845664, 0.461871872, 0.46388, 0.465871056, 0.467846224, 0.469804272, 0.47174272, 0.47366, 0.475557424, 0.477436592,
-0.47929536, 0.481130288, 0.48294, 0.484726304, 0.48649016, 0.488237056, 0.489974992, 0.49171, 0.493442384, 0.495172096, 0.496905168, 0.4986496, 0.50041,
-0.502185664, 0.503977008, 0.505777792, 0.507577936, 0.50937, 0.511154368, 0.512931472, 0.514694016, 0.51643216, 0.51814, 0.51981832, 0.521466816, 0.523090912,
-0.524699648, 0.5263, 0.527892736, 0.529479232, 0.531065888, 0.532660784, 0.53427, 0.535895568, 0.537539888, 0.539204768, 0.540891168, 0.5426, 0.544331984,
-0.546086688, 0.547866272, 0.549673776, 0.55151, 0.55337264, 0.555260032, 0.557165104, 0.559077776, 0.56099, 0.562899936, 0.564805904, 0.566699472, 0.56856992,
-0.57041, 0.572218592, 0.573993792, 0.575737712, 0.577455232, 0.57915, 0.58082208, 0.582472752, 0.584104224, 0.585718976, 0.58732, 0.588910416, 0.590493184,
-0.592074272, 0.59366112, 0.59526, 0.59687392, 0.598505792, 0.600161504, 0.601847856, 0.60357, 0.605330192, 0.607130864, 0.608972896, 0.610855968, 0.61278,
-0.614745744, 0.616753056, 0.618802528, 0.62089496, 0.62303, 0.62520608, 0.627422048, 0.629672656, 0.631950544, 0.63425, 0.636570304, 0.638911216, 0.641266448,
-0.64362768, 0.64599, 0.648354336, 0.650720928, 0.65309432, 0.655481952, 0.65789, 0.660320992, 0.6627788, 0.665267968, 0.667793216, 0.67036, 0.67297352,
-0.675638048, 0.678364896, 0.681168544, 0.68406, 0.687039696, 0.690105456, 0.693271216, 0.696556336, 0.69997, 0.703505248, 0.707157696, 0.710905184, 0.714714832,
-0.71856, 0.722432672, 0.726323104, 0.730206576, 0.734053408, 0.73784, 0.741554864, 0.745185376, 0.748721328, 0.75215536, 0.75548, 0.758687248, 0.761772128,
-0.764724528, 0.767532688, 0.77019, 0.772697088, 0.775053648, 0.777270448, 0.779364688, 0.78135, 0.7832292, 0.785006288, 0.786693936, 0.788307744, 0.78986,
-0.791356176, 0.792802416, 0.794204096, 0.795565296, 0.79689, 0.798182032, 0.799443616, 0.80068008, 0.801897744, 0.8031, 0.804286304, 0.80545688, 0.806604656,
-0.807718512, 0.80879, 0.809818688, 0.810804096, 0.811737824, 0.812608832, 0.81341, 0.8141412, 0.814801424, 0.815393968, 0.815925152, 0.8164, 0.816819488,
-0.817185728, 0.817502928, 0.817776048, 0.81801, 0.818208688, 0.818375968, 0.818518528, 0.818644288, 0.81876, 0.818869056, 0.818974368, 0.81908288, 0.819202832,
-0.81934, 0.81949616, 0.8196736, 0.8198708, 0.82008384, 0.82031, 0.820550336, 0.82080536, 0.821072544, 0.821348288, 0.82163, 0.821917376, 0.822209904,
-0.822506672, 0.82280688, 0.82311, 0.823416176, 0.823726032, 0.824038848, 0.824353504, 0.82467, 0.824989712, 0.825313568, 0.825645984, 0.8259932, 0.82636,
-0.826746704, 0.827153104, 0.827584704, 0.828048944, 0.82855, 0.829086816, 0.829659344, 0.830261632, 0.83088424, 0.83152, 0.83216864, 0.832829872, 0.833496384,
-0.834158496, 0.83481, 0.83545064, 0.836079104, 0.836699168, 0.837317792, 0.83794, 0.83856488, 0.839191728, 0.839825616, 0.840473584, 0.84114, 0.841824544,
-0.842527776, 0.843246528, 0.84397528, 0.84471, 0.845450976, 0.846198208, 0.84694824, 0.847696512, 0.84844, 0.849178384, 0.84991088, 0.850638736, 0.851364512,
-0.85209, 0.852814864, 0.853539136, 0.854263888, 0.85499048, 0.85572, 0.856452752, 0.857188928, 0.857929664, 0.8586764, 0.85943, 0.860189872, 0.860955024,
-0.861726176, 0.862504528, 0.86329, 0.864080912, 0.864876096, 0.86567048, 0.866456944, 0.86723, 0.867989344, 0.86873544, 0.869461456, 0.870158032, 0.87082,
-0.871448736, 0.872044096, 0.872614496, 0.873173216, 0.87373, 0.874284064, 0.874834176, 0.875390368, 0.8759664, 0.87657, 0.877199232, 0.877854208, 0.878523744,
-0.87919008, 0.87984, 0.880474, 0.881092944, 0.881683088, 0.882225792, 0.88271, 0.883137136, 0.883506128, 0.88382896, 0.884125392, 0.88441, 0.884681952,
-0.88494056, 0.885199968, 0.885479216, 0.88579, 0.886131632, 0.886505728, 0.886902384, 0.88730464, 0.8877, 0.888089552, 0.888474144, 0.888841136, 0.889173328,
-0.88946, 0.889701648, 0.889896736, 0.890053024, 0.890184112, 0.8903, 0.890399744, 0.890482784, 0.890559024, 0.890641904, 0.89074, 0.89085336, 0.890983584,
-0.891125568, 0.891269952, 0.89141, 0.891546848, 0.891681248, 0.891806848, 0.891914928, 0.892, 0.89206232, 0.892101168, 0.892119936, 0.892124704, 0.89212,
-0.8921056, 0.892081616, 0.892052432, 0.892023888, 0.892, 0.891981376, 0.891969152, 0.891962688, 0.891960064, 0.89196, 0.891963136, 0.891969728, 0.89197888,
-0.891989312, 0.892, 0.89201088, 0.89202208, 0.892032, 0.8920384, 0.89204, 0.892036864, 0.89202848, 0.892017216, 0.892006912, 0.892,
-/* CES24 */ 0.2515, 0.2515, 0.2515, 0.2515, 0.2515, 0.2515, 0.2515, 0.2515, 0.2515, 0.2515, 0.2515, 0.25152328, 0.2516164, 0.25170952, 0.25168624, 0.2515,
-0.25117504, 0.25066672, 0.2502748, 0.25046008, 0.2515, 0.253331808, 0.25596584, 0.259186672, 0.262633664, 0.26605, 0.269453152, 0.272836032, 0.276040752,
-0.278871552, 0.2812, 0.283006576, 0.284254432, 0.285018608, 0.285442464, 0.28563, 0.285567168, 0.285251088, 0.284774528, 0.284262128, 0.2838, 0.283401808,
-0.283093248, 0.282862128, 0.282669248, 0.28249, 0.28234368, 0.282243568, 0.282170816, 0.282095824, 0.282, 0.281886832, 0.281755776, 0.28161416, 0.281475584,
-0.28135, 0.281236416, 0.2811348, 0.281054144, 0.281006448, 0.281, 0.281036176, 0.281117504, 0.281242672, 0.28140768, 0.28161, 0.281851392, 0.282132912,
-0.282452992, 0.282809232, 0.2832, 0.283625472, 0.284085712, 0.284580192, 0.285108352, 0.28567, 0.286266016, 0.286897872, 0.287564848, 0.288265504, 0.289,
-0.289770848, 0.290580128, 0.291433328, 0.292337888, 0.2933, 0.294322016, 0.295406288, 0.2965592, 0.297788512, 0.2991, 0.300496064, 0.30197976, 0.303551056,
-0.305208032, 0.30695, 0.308780096, 0.310701536, 0.312713296, 0.314812896, 0.317, 0.319277744, 0.321648416, 0.324118448, 0.32669664, 0.32939, 0.332200288,
-0.335129008, 0.338183168, 0.341371568, 0.3447, 0.348169392, 0.351781568, 0.355533104, 0.35941744, 0.36343, 0.367572, 0.371844304, 0.376243008, 0.380762672,
-0.3854, 0.3901556, 0.395029568, 0.400024176, 0.405143264, 0.41039, 0.415765568, 0.421271856, 0.426911264, 0.432686352, 0.4386, 0.44465472, 0.450852464,
-0.457199328, 0.463703232, 0.47037, 0.477199424, 0.484189968, 0.491348272, 0.498683616, 0.5062, 0.513892912, 0.521758832, 0.529786112, 0.537957952, 0.54626,
-0.55468544, 0.563225872, 0.571868144, 0.580597456, 0.5894, 0.59826432, 0.607178144, 0.616129728, 0.625107712, 0.6341, 0.643092352, 0.652069728, 0.661020704,
-0.66993504, 0.6788, 0.687599488, 0.696318464, 0.70493488, 0.713422976, 0.72176, 0.72993232, 0.73792736, 0.74572112, 0.75328576, 0.7606, 0.767654032, 0.77443704,
-0.780945168, 0.787179616, 0.79314, 0.798821152, 0.804221088, 0.809336304, 0.8141628, 0.8187, 0.822951664, 0.826921184, 0.830620224, 0.834065184, 0.83727,
-0.84024112, 0.842986144, 0.845518048, 0.847851632, 0.85, 0.85197248, 0.853778848, 0.855429136, 0.856933024, 0.8583, 0.859538944, 0.860657936, 0.861667328,
-0.86257824, 0.8634, 0.86413912, 0.864802368, 0.865393696, 0.865915264, 0.86637, 0.866763296, 0.867099936, 0.867383136, 0.867615536, 0.8678, 0.867940144,
-0.868039376, 0.868100608, 0.86812656, 0.86812, 0.868083776, 0.868020528, 0.86793336, 0.867825552, 0.8677, 0.867558944, 0.86740464, 0.867238976, 0.867063552,
-0.86688, 0.866689824, 0.866494016, 0.866295168, 0.86609648, 0.8659, 0.865705936, 0.865514768, 0.86532504, 0.865134112, 0.86494, 0.864742592, 0.86454144,
-0.864335088, 0.864121776, 0.8639, 0.863668976, 0.863427968, 0.86317552, 0.862909952, 0.86263, 0.862335776, 0.862028, 0.861704544, 0.861362288, 0.861,
-0.860619232, 0.860220864, 0.859810096, 0.859394528, 0.85898, 0.858567328, 0.858157296, 0.857755264, 0.857368032, 0.857, 0.856651408, 0.856322944, 0.85601248,
-0.855715936, 0.85543, 0.855153632, 0.8548848, 0.854621248, 0.854360656, 0.8541, 0.853836176, 0.853566768, 0.853284, 0.852977632, 0.85264, 0.852270912,
-0.8518716, 0.851433488, 0.850944816, 0.8504, 0.84980288, 0.849155488, 0.848472256, 0.847774784, 0.84708, 0.846390592, 0.845709216, 0.84505328, 0.844445184,
-0.8439, 0.843419744, 0.84300816, 0.842660736, 0.842367232, 0.84212, 0.841919328, 0.841763456, 0.841646464, 0.841560992, 0.8415, 0.841458768, 0.841432784,
-0.84141208, 0.841384416, 0.84134, 0.841276608, 0.84119392, 0.841079792, 0.840918064, 0.8407, 0.840428752, 0.840105632, 0.839744512, 0.839366912, 0.83899,
-0.838614048, 0.838236832, 0.837885696, 0.83759912, 0.8374, 0.837287056, 0.837266896, 0.837309376, 0.837364576, 0.8374, 0.837420352, 0.837422896, 0.83741272,
-0.837402544, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374,
-0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374,
-0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374,
-0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374,
-/* CES25 */ 0.10281, 0.101523136, 0.099642896, 0.097375136, 0.095060256, 0.09292, 0.090920784, 0.089077536, 0.087362208, 0.08569888, 0.08404, 0.082420432,
-0.080865968, 0.079343504, 0.0778012, 0.07621, 0.0745824, 0.072922624, 0.071263488, 0.069656272, 0.06814, 0.066707408, 0.065341808, 0.064110688, 0.063112768,
-0.0624, 0.061959936, 0.061809328, 0.06181912, 0.061786352, 0.06158, 0.061220928, 0.06070688, 0.060057792, 0.059322304, 0.05854, 0.057702304, 0.056797792,
-0.05591088, 0.055164928, 0.05464, 0.054326272, 0.05422272, 0.054320608, 0.054595296, 0.05502, 0.055590496, 0.056308608, 0.05708352, 0.057783872, 0.05832,
-0.058694976, 0.05890592, 0.058933824, 0.058765088, 0.0584, 0.057837536, 0.057065184, 0.056180672, 0.0553328, 0.05462, 0.05403488, 0.053585952, 0.053265184,
-0.053043936, 0.0529, 0.052843984, 0.052886864, 0.052979024, 0.053046144, 0.05304, 0.05296576, 0.052821024, 0.052614928, 0.052368752, 0.0521, 0.051805488,
-0.051480448, 0.051162688, 0.050907328, 0.05075, 0.05068624, 0.050715648, 0.050833056, 0.051025584, 0.05128, 0.051595024, 0.051972496, 0.052370048, 0.05272624,
-0.053, 0.053194688, 0.053310768, 0.053343408, 0.053291568, 0.05316, 0.05295088, 0.052661408, 0.052341216, 0.052064064, 0.05188, 0.051788272, 0.051794736,
-0.05190216, 0.052105504, 0.0524, 0.052792592, 0.05329224, 0.053870368, 0.054482256, 0.0551, 0.055728592, 0.056366368, 0.057027184, 0.05773592, 0.05851,
-0.059350512, 0.060264384, 0.061251136, 0.062305808, 0.06343, 0.06464544, 0.065980736, 0.067429712, 0.068974448, 0.07062, 0.072397104, 0.074320512, 0.07649776,
-0.079081728, 0.08218, 0.085804368, 0.0899796, 0.094720112, 0.100023504, 0.10589, 0.112331232, 0.119346912, 0.126942112, 0.135122512, 0.14388, 0.153183984,
-0.162998672, 0.17328336, 0.183989408, 0.19506, 0.206448112, 0.2181168, 0.229930528, 0.241709456, 0.25332, 0.26472616, 0.275880848, 0.286743936, 0.297291344,
-0.3075, 0.317335584, 0.326770576, 0.335803648, 0.34444696, 0.35271, 0.360583744, 0.368060048, 0.375168512, 0.381954816, 0.38845, 0.394655008, 0.400577552,
-0.406214256, 0.41155344, 0.41659, 0.4213372, 0.425808816, 0.430001952, 0.433908608, 0.43753, 0.44087872, 0.443961552, 0.446818064, 0.449504176, 0.45206,
-0.45449264, 0.456814784, 0.459032128, 0.461143232, 0.46315, 0.465067184, 0.466909808, 0.468673872, 0.470348096, 0.47193, 0.473427568, 0.474839952, 0.476205376,
-0.4775804, 0.479, 0.48045856, 0.481957776, 0.483476992, 0.484980048, 0.48644, 0.487862944, 0.489258352, 0.49058512, 0.491783488, 0.49282, 0.493700816,
-0.49441776, 0.495030304, 0.495633568, 0.49629, 0.496988736, 0.497727584, 0.498510912, 0.49933704, 0.5002, 0.501104544, 0.502064032, 0.50301672, 0.503867968,
-0.50456, 0.505102848, 0.50549064, 0.505767072, 0.506006624, 0.50626, 0.506515552, 0.506764752, 0.507037232, 0.507372352, 0.50779, 0.50828928, 0.508883552,
-0.509503184, 0.510038336, 0.51042, 0.510659536, 0.510751424, 0.510727792, 0.51064736, 0.51055, 0.510427728, 0.510279232, 0.510120336, 0.50996968, 0.50984,
-0.509741184, 0.509690736, 0.509664048, 0.50961752, 0.50953, 0.509408944, 0.509240688, 0.509102672, 0.509115616, 0.50935, 0.509783472, 0.510413072, 0.511174112,
-0.511962032, 0.5127, 0.513397168, 0.514067552, 0.514623856, 0.51494408, 0.51496, 0.514678768, 0.514076736, 0.513288384, 0.512529552, 0.51194, 0.511499504,
-0.511216784, 0.511066144, 0.510987664, 0.51094, 0.510939056, 0.510994624, 0.511068432, 0.51110568, 0.51107, 0.510955904, 0.510746832, 0.51046632, 0.510159408,
-0.50985, 0.509532928, 0.50922512, 0.508859712, 0.508330464, 0.50758, 0.50663184, 0.505484512, 0.504237024, 0.503043776, 0.50201, 0.501128368, 0.500404464,
-0.49986072, 0.499513536, 0.49937, 0.499447472, 0.49977752, 0.500276608, 0.500810096, 0.5013, 0.501749568, 0.502113968, 0.502540768, 0.503271248, 0.50444,
-0.506015536, 0.508040768, 0.51026792, 0.512300992, 0.5139, 0.5151024, 0.515858, 0.51639568, 0.51710088, 0.51821, 0.519667776, 0.521494, 0.523539104,
-0.525545248, 0.52735, 0.528991472, 0.530463904, 0.531820096, 0.533160928, 0.53455, 0.535965568, 0.537402976, 0.538855744, 0.540307952, 0.54175, 0.543188096,
-0.544625344, 0.546061552, 0.54749648, 0.54893, 0.550362064, 0.551792512, 0.55322184, 0.554650848, 0.55608, 0.5575092, 0.55893856, 0.56036744, 0.5617948,
-0.56322, 0.564643152, 0.56606416, 0.567483488, 0.568901936, 0.57032, 0.571737536, 0.573154528, 0.5745708, 0.575986032, 0.5774, 0.578812752, 0.58022432,
-0.581634528, 0.583043136, 0.58445, 0.585855088, 0.587258288, 0.588659888, 0.590060368, 0.59146, 0.59285872, 0.594256608, 0.595653216, 0.597047824, 0.59844,
-0.599829792, 0.601217056, 0.60260232, 0.603986464, 0.60537, 0.606752816, 0.60813504, 0.609515984, 0.610894528, 0.61227, 0.613642512, 0.615011904, 0.616378896,
-0.617744688, 0.61911, 0.620463936, 0.621785136, 0.623105136, 0.624476496, 0.62592, 0.627425136, 0.629013456, 0.630545616, 0.631807296, 0.63267,
-/* CES26 */ 0.1688, 0.1688, 0.1688, 0.1688, 0.1688, 0.1688, 0.1688, 0.1688, 0.1688, 0.1688, 0.1688, 0.168794848, 0.16877424, 0.168753632, 0.168758784, 0.1688,
-0.168870912, 0.168979392, 0.169062112, 0.169022112, 0.1688, 0.168407072, 0.167839872, 0.167137792, 0.166368512, 0.16558, 0.164769312, 0.163940192, 0.163117472,
-0.162330272, 0.1616, 0.160935408, 0.160346992, 0.159833456, 0.15938608, 0.159, 0.158682048, 0.158437216, 0.158262224, 0.158150992, 0.1581, 0.15811104,
-0.158184784, 0.158323568, 0.158531072, 0.15881, 0.159159968, 0.159580288, 0.160074288, 0.160646688, 0.1613, 0.162033168, 0.162845888, 0.163733808, 0.164690208,
-0.16571, 0.166792832, 0.167938288, 0.169141664, 0.17039688, 0.1717, 0.173050576, 0.174447424, 0.175893232, 0.17739296, 0.17895, 0.180562976, 0.182230352,
-0.183955808, 0.185744784, 0.1876, 0.18952016, 0.191505328, 0.193548576, 0.195639264, 0.19777, 0.199942976, 0.202161616, 0.204417616, 0.206698816, 0.209,
-0.211326288, 0.213680176, 0.216080064, 0.218553152, 0.22112, 0.223780992, 0.226534864, 0.229404336, 0.232420208, 0.2356, 0.23894008, 0.242441296, 0.246079312,
-0.249815168, 0.25362, 0.25749504, 0.261441952, 0.265431824, 0.269425616, 0.2734, 0.277357872, 0.281297344, 0.285241696, 0.289229568, 0.29329, 0.297423104,
-0.301630016, 0.305939008, 0.31038744, 0.315, 0.31977888, 0.324729888, 0.329843056, 0.335097984, 0.34048, 0.345991392, 0.351632016, 0.35739008, 0.363249984,
-0.3692, 0.375236512, 0.3813548, 0.387551248, 0.393823776, 0.40017, 0.4065864, 0.413070528, 0.419619376, 0.426229824, 0.4329, 0.439628864, 0.446414576,
-0.453261888, 0.46017872, 0.46717, 0.474231776, 0.481358608, 0.48855672, 0.495836192, 0.5032, 0.51064024, 0.51815128, 0.52571296, 0.5332968, 0.54088,
-0.548456608, 0.55602072, 0.563549872, 0.571016144, 0.5784, 0.585696176, 0.592897072, 0.600007248, 0.607038944, 0.614, 0.6208848, 0.627688768, 0.634419216,
-0.641087504, 0.6477, 0.654253888, 0.660748016, 0.667175504, 0.673525632, 0.67979, 0.685966944, 0.692054144, 0.698044304, 0.703928544, 0.7097, 0.71535576,
-0.720892544, 0.726308048, 0.731600992, 0.73677, 0.741813504, 0.746731088, 0.751520032, 0.756176816, 0.7607, 0.76509112, 0.769351232, 0.773485904, 0.777503296,
-0.78141, 0.785208032, 0.788899824, 0.792491296, 0.795989488, 0.7994, 0.802725776, 0.805970176, 0.809135056, 0.812221216, 0.81523, 0.81816416, 0.821025936,
-0.823817632, 0.826541648, 0.8292, 0.831794192, 0.834325792, 0.836795472, 0.839203392, 0.84155, 0.843836384, 0.846063312, 0.848232, 0.850343888, 0.8524,
-0.854400784, 0.85634688, 0.858237776, 0.860072352, 0.86185, 0.863571776, 0.865239056, 0.866850656, 0.868404496, 0.8699, 0.871338896, 0.872722336, 0.874054736,
-0.875342336, 0.87659, 0.877798656, 0.878969136, 0.880106016, 0.881215056, 0.8823, 0.883361328, 0.884400096, 0.885413904, 0.886398272, 0.88735, 0.88826992,
-0.889158864, 0.890013248, 0.890827952, 0.8916, 0.892330144, 0.893018448, 0.893668032, 0.894283936, 0.89487, 0.895426592, 0.895954352, 0.896456592, 0.896937472,
-0.8974, 0.897845104, 0.898274032, 0.89868672, 0.899082208, 0.89946, 0.899821248, 0.90016712, 0.900496592, 0.900807824, 0.9011, 0.901374096, 0.901630672,
-0.901871888, 0.902100864, 0.90232, 0.902529792, 0.902730848, 0.902924944, 0.90311416, 0.9033, 0.903483024, 0.903663904, 0.903842464, 0.904017984, 0.90419,
-0.904358912, 0.904525024, 0.904687776, 0.904846288, 0.905, 0.905148976, 0.905293136, 0.905432736, 0.905568256, 0.9057, 0.905827872, 0.905951856, 0.90607192,
-0.906187984, 0.9063, 0.906408032, 0.90651216, 0.906612288, 0.906708256, 0.9068, 0.906887808, 0.906972128, 0.907052608, 0.907128608, 0.9072, 0.907267328,
-0.907330848, 0.907392448, 0.907454848, 0.90752, 0.907587808, 0.907658048, 0.907732288, 0.907812608, 0.9079, 0.907993856, 0.908093888, 0.90819744, 0.908300512,
-0.9084, 0.908495648, 0.90858736, 0.908671552, 0.908743424, 0.9088, 0.908841472, 0.908867552, 0.908880352, 0.908883552, 0.90888, 0.908869632, 0.908852352,
-0.908831872, 0.908813312, 0.9088, 0.908792192, 0.908790912, 0.908794112, 0.908797952, 0.9088, 0.908801024, 0.908801152, 0.90880064, 0.908800128, 0.9088, 0.9088,
-0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088,
-0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088,
-0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088,
-/* CES27 */ 0.06462, 0.06465056, 0.064693984, 0.064759168, 0.064858752, 0.065, 0.06518088, 0.065401008, 0.065652336, 0.065921984, 0.0662, 0.066490544,
-0.066802576, 0.067110688, 0.06737624, 0.06758, 0.06773536, 0.067849168, 0.067959936, 0.068125264, 0.06839, 0.06875808, 0.069230736, 0.069860832, 0.070719328,
-0.07185, 0.073262208, 0.074985232, 0.076948656, 0.07903312, 0.08117, 0.08338816, 0.085694336, 0.088151472, 0.090859488, 0.09389, 0.097232592, 0.100873152,
-0.104881232, 0.109353312, 0.11434, 0.119815568, 0.125765312, 0.132106256, 0.13871224, 0.14548, 0.152383184, 0.159393056, 0.166392288, 0.17323128, 0.1798,
-0.186074656, 0.192031488, 0.19762536, 0.202814832, 0.20758, 0.211913488, 0.21579904, 0.219329792, 0.222652704, 0.22587, 0.228972064, 0.231955712, 0.234872,
-0.237782608, 0.24072, 0.243691712, 0.24672816, 0.249713488, 0.252462176, 0.25486, 0.256939328, 0.258709968, 0.260214848, 0.261530368, 0.26272, 0.263779392,
-0.264692048, 0.265591664, 0.26667168, 0.26805, 0.269713216, 0.271678944, 0.273843472, 0.27603088, 0.27812, 0.28012704, 0.282053232, 0.283846144, 0.285444416,
-0.28681, 0.28793856, 0.288820784, 0.289496048, 0.290032032, 0.29048, 0.290845104, 0.291140048, 0.291402272, 0.291676416, 0.292, 0.292390784, 0.292863952,
-0.2934564, 0.294211728, 0.29516, 0.296309424, 0.29767064, 0.299233776, 0.300975872, 0.30288, 0.304948464, 0.307179856, 0.309558688, 0.3120644, 0.31468,
-0.317392288, 0.320181488, 0.323051008, 0.326014288, 0.32907, 0.332205264, 0.335425408, 0.338645392, 0.341739136, 0.34463, 0.347327456, 0.349818512, 0.352173408,
-0.354510864, 0.35691, 0.359354512, 0.361838368, 0.364383664, 0.36701408, 0.36974, 0.372559728, 0.375477344, 0.37844184, 0.381374576, 0.38422, 0.386974544,
-0.38962576, 0.392161536, 0.394575632, 0.39686, 0.399003504, 0.401001136, 0.402834048, 0.404478, 0.40592, 0.407168272, 0.408232288, 0.409122864, 0.40985544,
-0.41045, 0.41091776, 0.411261264, 0.411553968, 0.411900432, 0.41237, 0.412962528, 0.413687968, 0.414533248, 0.415465568, 0.41646, 0.417522544, 0.418657008,
-0.419826032, 0.420976096, 0.42207, 0.4231044, 0.424070832, 0.424967424, 0.425799536, 0.42657, 0.427272704, 0.427904304, 0.428467584, 0.428967824, 0.42941,
-0.429797984, 0.430137904, 0.430432864, 0.430685024, 0.4309, 0.431085056, 0.431243824, 0.431402992, 0.43159984, 0.43186, 0.43218344, 0.432571152, 0.433029184,
-0.433562176, 0.43417, 0.43485, 0.435601024, 0.436397888, 0.437203472, 0.43799, 0.438756272, 0.439502128, 0.440201664, 0.44082088, 0.44134, 0.441759216,
-0.442071904, 0.442310512, 0.44252808, 0.44276, 0.443001024, 0.443251152, 0.44351592, 0.443798608, 0.4441, 0.444421808, 0.44476696, 0.445118192, 0.445448704,
-0.44574, 0.44599744, 0.446228832, 0.446415344, 0.446529616, 0.44656, 0.446509248, 0.446365424, 0.44619704, 0.446110336, 0.44617, 0.4463652, 0.44670472,
-0.44713536, 0.44756688, 0.44794, 0.448269424, 0.44856224, 0.448804576, 0.448981872, 0.44909, 0.44912808, 0.449085136, 0.449023232, 0.449038128, 0.44919,
-0.449470064, 0.449883712, 0.45040136, 0.450969168, 0.45155, 0.452145104, 0.45274496, 0.453346656, 0.453953392, 0.45456, 0.45515176, 0.455721776, 0.456225952,
-0.456602608, 0.45681, 0.456852768, 0.456736912, 0.456446176, 0.45596136, 0.45528, 0.454411344, 0.453352736, 0.452190608, 0.45105392, 0.45003, 0.449116272,
-0.448321248, 0.447651184, 0.44709992, 0.44666, 0.446342496, 0.446163664, 0.446066032, 0.44596008, 0.44579, 0.445558896, 0.445249872, 0.444911568, 0.444628544,
-0.44445, 0.444362384, 0.444374928, 0.444427392, 0.444421936, 0.4443, 0.444081392, 0.443772032, 0.443406192, 0.443040752, 0.44272, 0.4424424, 0.442202832,
-0.442065264, 0.442119376, 0.44242, 0.44296488, 0.443767344, 0.444771728, 0.445883552, 0.44704, 0.448239888, 0.449459088, 0.450724448, 0.452090928, 0.45358,
-0.455179936, 0.456919728, 0.4586456, 0.460120432, 0.46121, 0.461934512, 0.46224512, 0.462385168, 0.462746816, 0.46357, 0.464811024, 0.466503808, 0.468464592,
-0.470379376, 0.47205, 0.473520528, 0.474781952, 0.475899776, 0.47699944, 0.47816, 0.479354224, 0.480576176, 0.481817888, 0.48305976, 0.48429, 0.485516256,
-0.486742288, 0.48796816, 0.489194032, 0.49042, 0.491646, 0.492872, 0.494098, 0.495324, 0.49655, 0.497776, 0.499002, 0.500228, 0.501454, 0.50268, 0.503905984,
-0.50513192, 0.506357856, 0.507583872, 0.50881, 0.510036224, 0.511262576, 0.512488848, 0.51371472, 0.51494, 0.516164704, 0.517388768, 0.518612432, 0.519836096,
-0.52106, 0.52228408, 0.523508352, 0.524732624, 0.525956576, 0.52718, 0.528402928, 0.529625344, 0.53084728, 0.532068816, 0.53329, 0.534510784, 0.53573112,
-0.536951056, 0.538170672, 0.53939, 0.540609024, 0.541827776, 0.543046048, 0.54426352, 0.54548, 0.546695504, 0.547909968, 0.549123632, 0.550336896, 0.55155,
-0.552753248, 0.553927392, 0.555101136, 0.55632232, 0.55761, 0.558954608, 0.560375456, 0.561747264, 0.562877312, 0.56365,
-/* CES28 */ 0.0458, 0.045684704, 0.045516912, 0.0453124, 0.045097568, 0.04489, 0.044690832, 0.04450808, 0.044332448, 0.044145536, 0.04394, 0.043722208,
-0.043488048, 0.043279568, 0.043160688, 0.04317, 0.043300832, 0.043557888, 0.043906944, 0.04429056, 0.04467, 0.045046464, 0.045410784, 0.045766944, 0.046128704,
-0.0465, 0.046872368, 0.047248464, 0.04758696, 0.047826176, 0.04793, 0.047901648, 0.04772856, 0.047465152, 0.047200624, 0.04699, 0.046828608, 0.046734832,
-0.046654976, 0.04649704, 0.04621, 0.045813216, 0.045301296, 0.044753056, 0.044292896, 0.044, 0.043860448, 0.043876176, 0.044025424, 0.044264352, 0.04456,
-0.044919472, 0.045350384, 0.045804416, 0.046212128, 0.04653, 0.046757312, 0.046879696, 0.0469372, 0.046999664, 0.04711, 0.047252896, 0.04742288, 0.047607904,
-0.047786208, 0.04794, 0.048077152, 0.048214784, 0.048298336, 0.048244768, 0.04801, 0.047603984, 0.047009776, 0.046335888, 0.0457536, 0.04537, 0.045171568,
-0.045176528, 0.045316288, 0.045469328, 0.04556, 0.045608736, 0.045613488, 0.04560416, 0.045635152, 0.04574, 0.045905712, 0.0461272, 0.046397968, 0.046705696,
-0.04704, 0.0474016, 0.047793008, 0.048191456, 0.048563984, 0.04889, 0.049169456, 0.049395776, 0.049588016, 0.049779296, 0.04999, 0.050215328, 0.050457456,
-0.050703744, 0.050932272, 0.05113, 0.05130792, 0.051479264, 0.051633968, 0.051755072, 0.05184, 0.051898432, 0.051926768, 0.051997504, 0.0522188, 0.05266,
-0.053322224, 0.054227744, 0.055328304, 0.056536064, 0.0578, 0.059133776, 0.060524064, 0.062038352, 0.06378632, 0.06583, 0.068144224, 0.070724432, 0.07350056,
-0.076362128, 0.07923, 0.082100832, 0.08496096, 0.087778048, 0.090519616, 0.09316, 0.095681872, 0.098067888, 0.100316624, 0.102436, 0.10443, 0.10628768,
-0.107999104, 0.109573488, 0.111027472, 0.11237, 0.113602, 0.114734528, 0.115736256, 0.116556704, 0.11717, 0.117588592, 0.117810736, 0.11789576, 0.117935184,
-0.11799, 0.11805816, 0.11814984, 0.11825672, 0.1183548, 0.11843, 0.118491808, 0.11854256, 0.118587712, 0.118635824, 0.11869, 0.118747536, 0.118810192,
-0.118855488, 0.118849344, 0.11877, 0.118632736, 0.118461168, 0.11822504, 0.117876112, 0.1174, 0.116799568, 0.11603272, 0.115298672, 0.114905184, 0.11503,
-0.115642592, 0.116790432, 0.118209712, 0.119472272, 0.12031, 0.120766416, 0.120807392, 0.120579488, 0.120345664, 0.12027, 0.120311264, 0.120481248, 0.120708352,
-0.120862736, 0.12087, 0.120755248, 0.120506672, 0.120220416, 0.12005088, 0.12009, 0.12031704, 0.120743456, 0.121279552, 0.121777008, 0.12214, 0.122384816,
-0.122506512, 0.122521248, 0.122467344, 0.12237, 0.12222, 0.122016048, 0.121765536, 0.121477104, 0.12116, 0.120818976, 0.120455056, 0.120093856, 0.119771296,
-0.11951, 0.11930992, 0.119176656, 0.119092752, 0.119027328, 0.11896, 0.118895264, 0.118833792, 0.11877224, 0.118708128, 0.11864, 0.118567856, 0.11849296,
-0.118415824, 0.118337248, 0.11826, 0.118181664, 0.118092624, 0.118025024, 0.118028624, 0.11813, 0.118319824, 0.118601824, 0.118922224, 0.119196864, 0.11937,
-0.119451664, 0.119447104, 0.119344304, 0.119131984, 0.11881, 0.118378528, 0.117825584, 0.11722936, 0.116709136, 0.11634, 0.116114624, 0.1160456, 0.116087776,
-0.116160032, 0.11621, 0.116248736, 0.116273056, 0.116290496, 0.116319776, 0.11637, 0.116433664, 0.116511616, 0.116580128, 0.1166032, 0.11656, 0.116450064,
-0.116260368, 0.116032912, 0.115836416, 0.11571, 0.1156516, 0.115685552, 0.115729344, 0.115648976, 0.11537, 0.114912512, 0.114257904, 0.113538656, 0.112965648,
-0.11267, 0.112626736, 0.112844576, 0.113254576, 0.113734176, 0.1142, 0.114663184, 0.115118016, 0.115544048, 0.1159236, 0.11624, 0.116489472, 0.116683248,
-0.116769904, 0.11667384, 0.11636, 0.11584136, 0.115101264, 0.114281488, 0.113601152, 0.1132, 0.113054576, 0.113166288, 0.11350016, 0.113984112, 0.11456,
-0.115238592, 0.11603832, 0.116839168, 0.117465936, 0.11781, 0.117882592, 0.117668688, 0.117245664, 0.11674832, 0.11627, 0.11579544, 0.115319424, 0.114893728,
-0.114583872, 0.11443, 0.114435248, 0.114612688, 0.114914288, 0.115258608, 0.11559, 0.115918672, 0.116249648, 0.116555504, 0.11680184, 0.11697, 0.11705656,
-0.117046064, 0.116995888, 0.116998352, 0.11711, 0.117317408, 0.117622048, 0.117990848, 0.118366768, 0.11871, 0.119027456, 0.119323328, 0.11957048, 0.119732992,
-0.11979, 0.119744032, 0.1195924, 0.119363008, 0.119101216, 0.11884, 0.118577232, 0.118311488, 0.118070944, 0.11789296, 0.1178, 0.117792816, 0.117879344,
-0.118028912, 0.11819032, 0.11833, 0.118454688, 0.118565632, 0.118659936, 0.11873752, 0.1188, 0.118843632, 0.118860656, 0.1188776, 0.118935904, 0.11906,
-0.119244176, 0.11949088, 0.119774624, 0.120053648, 0.1203, 0.120518144, 0.120708864, 0.120865904, 0.120983664, 0.12106, 0.121093056, 0.121077904, 0.121035632,
-0.12099936, 0.12099, 0.1210052, 0.121048432, 0.121108064, 0.121162976, 0.1212, 0.121223216, 0.121233024, 0.121233152, 0.12123064, 0.12123,
-/* CES29 */ 0.03981, 0.04003128, 0.040355056, 0.040748992, 0.041159008, 0.04155, 0.041928784, 0.042295232, 0.0426512, 0.043004208, 0.04336, 0.043711376,
-0.04404712, 0.044392224, 0.044786288, 0.04525, 0.045779984, 0.046389024, 0.047016304, 0.047565584, 0.04798, 0.048272256, 0.048434864, 0.048528272, 0.04865176,
-0.04887, 0.049174224, 0.049570352, 0.0500476, 0.050579488, 0.05115, 0.051769568, 0.05244368, 0.053171152, 0.053950224, 0.05478, 0.055662208, 0.056599472,
-0.057589936, 0.05863024, 0.05972, 0.060863424, 0.062063936, 0.063330608, 0.06467584, 0.06611, 0.06763752, 0.069263328, 0.070996976, 0.072849504, 0.07483,
-0.07694376, 0.079196416, 0.081592592, 0.084135888, 0.08683, 0.089679072, 0.092686352, 0.095856112, 0.099192912, 0.1027, 0.106378512, 0.110229392, 0.114252432,
-0.118446432, 0.12281, 0.12734144, 0.132037792, 0.136898384, 0.141923216, 0.14711, 0.152453056, 0.157946864, 0.163582032, 0.16934672, 0.17523, 0.181223664,
-0.187318192, 0.193504, 0.199771488, 0.20611, 0.21250824, 0.21895552, 0.22543552, 0.2319292, 0.23842, 0.244898624, 0.2513568, 0.257777296, 0.264139952, 0.27043,
-0.276641488, 0.282767296, 0.288808544, 0.294771552, 0.30066, 0.306469664, 0.312197824, 0.317846624, 0.323419824, 0.32892, 0.334347504, 0.339703824, 0.344987744,
-0.350196704, 0.35533, 0.360392576, 0.365391184, 0.370323152, 0.37518264, 0.37997, 0.384694784, 0.389364512, 0.39400032, 0.398631008, 0.40328, 0.407954864,
-0.41266296, 0.417427376, 0.422276352, 0.42723, 0.432293872, 0.437474896, 0.44277216, 0.448178864, 0.45369, 0.45930616, 0.46502432, 0.47084264, 0.4767596,
-0.48277, 0.488864704, 0.49503544, 0.501261936, 0.507517552, 0.51378, 0.520039968, 0.526288256, 0.532502624, 0.538656512, 0.54473, 0.550714592, 0.556600064,
-0.562385216, 0.568074688, 0.57367, 0.579162848, 0.584545856, 0.589820544, 0.594991552, 0.60006, 0.605020832, 0.609870784, 0.614600416, 0.619196768, 0.62365,
-0.627959088, 0.632123856, 0.636134704, 0.639979072, 0.64365, 0.647149488, 0.650478224, 0.65364624, 0.656669296, 0.65956, 0.662321264, 0.66495696, 0.667479216,
-0.669902992, 0.67224, 0.674495536, 0.676675776, 0.678784896, 0.680825296, 0.6828, 0.684714144, 0.686572096, 0.688377168, 0.69013224, 0.69184, 0.693503424,
-0.695125488, 0.696707152, 0.698248416, 0.69975, 0.701214592, 0.702644832, 0.704041872, 0.705406432, 0.70674, 0.708045104, 0.709323872, 0.71058072, 0.711821248,
-0.71305, 0.714268384, 0.71547736, 0.716681856, 0.717888112, 0.7191, 0.72031768, 0.721542016, 0.722769392, 0.723993568, 0.72521, 0.726419328, 0.727622192,
-0.728813856, 0.72998776, 0.73114, 0.732271312, 0.733381696, 0.73447464, 0.735555904, 0.73663, 0.737697664, 0.73876008, 0.739821376, 0.740886672, 0.74196,
-0.743043056, 0.744137696, 0.745245936, 0.746369536, 0.74751, 0.74866824, 0.749844576, 0.751040752, 0.752258928, 0.7535, 0.754762944, 0.756046832, 0.75734872,
-0.758664368, 0.75999, 0.761323696, 0.76266328, 0.764004304, 0.765341408, 0.76667, 0.7679872, 0.769289824, 0.770574768, 0.771839152, 0.77308, 0.774294528,
-0.775480528, 0.776633488, 0.777747968, 0.77882, 0.779848208, 0.780830928, 0.781768128, 0.782660928, 0.78351, 0.784314928, 0.785076128, 0.785792928, 0.786464208,
-0.78709, 0.787672192, 0.788212608, 0.788714544, 0.78918232, 0.78962, 0.79003136, 0.790421424, 0.790792048, 0.791143872, 0.79148, 0.791806704, 0.792129008,
-0.792459712, 0.792815616, 0.79321, 0.793646128, 0.794126272, 0.794663616, 0.7952748, 0.79597, 0.796749104, 0.797613616, 0.798553888, 0.79955352, 0.8006,
-0.801692624, 0.802829728, 0.803999472, 0.805186576, 0.80638, 0.807576624, 0.808772032, 0.80996608, 0.811161648, 0.81236, 0.813557536, 0.81475136, 0.815942064,
-0.817131408, 0.81832, 0.819506128, 0.820688544, 0.82186512, 0.823033056, 0.82419, 0.825334704, 0.82646568, 0.827581296, 0.828679952, 0.82976, 0.830822432,
-0.831870976, 0.83289568, 0.833881104, 0.83482, 0.835714496, 0.83655968, 0.837385744, 0.838239648, 0.83915, 0.840112768, 0.841133424, 0.84218624, 0.843227616,
-0.84423, 0.845199248, 0.84613392, 0.847043232, 0.847944864, 0.84885, 0.8497548, 0.850658352, 0.851559984, 0.852457616, 0.85335, 0.854238192, 0.855122864,
-0.856003536, 0.856879408, 0.85775, 0.858615424, 0.859475616, 0.860331008, 0.86118232, 0.86203, 0.862873952, 0.863714208, 0.864550544, 0.86538256, 0.86621,
-0.867032944, 0.867851424, 0.868665424, 0.869474944, 0.87028, 0.871080576, 0.871876624, 0.872668352, 0.87345608, 0.87424, 0.875020112, 0.875796512, 0.876568912,
-0.877336832, 0.8781, 0.87885848, 0.879612192, 0.880361584, 0.881107376, 0.88185, 0.882589392, 0.883325664, 0.884058336, 0.884786608, 0.88551, 0.88622864,
-0.886942496, 0.887651952, 0.888357648, 0.88906, 0.889758928, 0.890454432, 0.891146496, 0.89183504, 0.89252, 0.893201408, 0.893879296, 0.894553504, 0.895223792,
-0.89589, 0.896547216, 0.897185584, 0.897819872, 0.89847472, 0.89916, 0.899870928, 0.900617632, 0.901335776, 0.90192632, 0.90233,
-/* CES30 */ 0.03043, 0.030675376, 0.031034848, 0.03143888, 0.031777392, 0.03198, 0.032057136, 0.03200376, 0.031841504, 0.031613648, 0.03135, 0.031047504,
-0.030712384, 0.030350064, 0.029963184, 0.02956, 0.02914904, 0.028729024, 0.028351168, 0.028089712, 0.02799, 0.028042832, 0.028246768, 0.028575104, 0.028982,
-0.02943, 0.029922496, 0.030470704, 0.030997952, 0.03139304, 0.03159, 0.031600528, 0.031418272, 0.031111376, 0.03079216, 0.03054, 0.030356016, 0.030255616,
-0.030262736, 0.030397296, 0.03068, 0.031124, 0.031729136, 0.032549232, 0.033659408, 0.0351, 0.036866176, 0.038975232, 0.041327008, 0.043762464, 0.04618,
-0.048600496, 0.051027728, 0.05347688, 0.055983552, 0.05858, 0.061265968, 0.0640372, 0.066974912, 0.070196304, 0.07378, 0.07771904, 0.082010352, 0.086668384,
-0.091703376, 0.09711, 0.102875616, 0.108993904, 0.115374432, 0.1218856, 0.12843, 0.13499016, 0.141538112, 0.148032224, 0.154433856, 0.16071, 0.166831088,
-0.172767824, 0.17851384, 0.184074896, 0.18945, 0.194616992, 0.1995552, 0.204263968, 0.208749616, 0.21301, 0.21703976, 0.220848848, 0.224375456, 0.227526464,
-0.23025, 0.232568016, 0.234489216, 0.236074816, 0.237420176, 0.2386, 0.23962296, 0.240497456, 0.241306592, 0.242159728, 0.24313, 0.244228064, 0.245477072,
-0.24684528, 0.248267248, 0.2497, 0.251156816, 0.25263984, 0.254139504, 0.255646048, 0.25715, 0.258639376, 0.260099104, 0.261529552, 0.26293736, 0.26432,
-0.265669632, 0.266991632, 0.268225872, 0.269282992, 0.27011, 0.27071744, 0.271100672, 0.271313024, 0.271442336, 0.27155, 0.271633392, 0.271699024, 0.271768496,
-0.271863328, 0.272, 0.272186384, 0.272427696, 0.272730048, 0.27309768, 0.27353, 0.27402592, 0.274587408, 0.275182816, 0.275764624, 0.2763, 0.27678968,
-0.277231296, 0.277616112, 0.277936768, 0.27819, 0.278374064, 0.278485472, 0.27854424, 0.278581808, 0.27862, 0.278660528, 0.27871024, 0.278768352, 0.278828624,
-0.27889, 0.278959952, 0.279041712, 0.279152512, 0.279316592, 0.27955, 0.279851088, 0.280218752, 0.280654736, 0.28115944, 0.28173, 0.282365664, 0.283070096,
-0.283812208, 0.28454536, 0.28524, 0.285899712, 0.286522048, 0.287119744, 0.28771664, 0.28833, 0.28895544, 0.289588144, 0.290249648, 0.290970912, 0.29177,
-0.292642656, 0.293589088, 0.29459016, 0.295614832, 0.29664, 0.297667008, 0.29869784, 0.299707472, 0.300661664, 0.30154, 0.30234424, 0.303070672, 0.303742944,
-0.304400256, 0.30507, 0.305750528, 0.306443984, 0.3071612, 0.307913776, 0.30871, 0.30955344, 0.31044784, 0.31139088, 0.31237648, 0.3134, 0.314459872,
-0.31555136, 0.316675488, 0.317835616, 0.31903, 0.32025384, 0.321508208, 0.322760976, 0.323964864, 0.32509, 0.32613992, 0.327112496, 0.328021152, 0.328890768,
-0.32974, 0.330563184, 0.331351552, 0.33213816, 0.332971888, 0.33388, 0.334858032, 0.33591472, 0.336997888, 0.338024016, 0.33894, 0.33975432, 0.340462928,
-0.341084576, 0.341655504, 0.3422, 0.342710592, 0.343183376, 0.34363088, 0.344069744, 0.34451, 0.344955856, 0.34541856, 0.345873104, 0.346277728, 0.34661,
-0.346879712, 0.347085584, 0.347267776, 0.347488848, 0.34779, 0.34816096, 0.348592496, 0.349099552, 0.349700928, 0.3504, 0.351191184, 0.352083072, 0.35299024,
-0.353783488, 0.35438, 0.354791104, 0.35501408, 0.355070176, 0.355003632, 0.35485, 0.354604752, 0.354261296, 0.35388528, 0.353571104, 0.35338, 0.353313824,
-0.35338936, 0.353572656, 0.353799472, 0.35403, 0.354273632, 0.354523296, 0.35480384, 0.355158784, 0.35561, 0.356141056, 0.3567456, 0.357382144, 0.357988608,
-0.35852, 0.35897656, 0.359358544, 0.359633008, 0.359757952, 0.35971, 0.35949312, 0.359105408, 0.358586416, 0.357999024, 0.35739, 0.356762784, 0.356124816,
-0.355508848, 0.3549544, 0.35449, 0.354127952, 0.353885648, 0.353753024, 0.35370528, 0.35373, 0.353832464, 0.354003104, 0.354283264, 0.354737744, 0.3554,
-0.356257744, 0.357318704, 0.358499744, 0.359671344, 0.36075, 0.36174312, 0.362638144, 0.363465808, 0.364286992, 0.36514, 0.36601008, 0.366892688, 0.367786576,
-0.368686704, 0.36959, 0.370499344, 0.371416656, 0.372339728, 0.37326496, 0.37419, 0.375115104, 0.376039968, 0.376965072, 0.377891536, 0.37882, 0.379750032,
-0.380681472, 0.381613952, 0.382546912, 0.38348, 0.384413264, 0.385346672, 0.3862804, 0.387214768, 0.38815, 0.389086032, 0.3900228, 0.390960528, 0.391899536,
-0.39284, 0.393781904, 0.394725328, 0.395669872, 0.396614896, 0.39756, 0.398505248, 0.399450592, 0.400396256, 0.40134264, 0.40229, 0.403238272, 0.404187456,
-0.40513752, 0.406088384, 0.40704, 0.407992384, 0.40894552, 0.409899456, 0.410854272, 0.41181, 0.412766624, 0.413724176, 0.414682448, 0.41564112, 0.4166,
-0.417559104, 0.418518368, 0.419478032, 0.420438496, 0.4214, 0.42236248, 0.423325952, 0.424290224, 0.425254976, 0.42622, 0.427185328, 0.428150944, 0.42911688,
-0.430083216, 0.43105, 0.432009424, 0.43294592, 0.433882816, 0.434858992, 0.43589, 0.436968048, 0.438108656, 0.439210784, 0.440118992, 0.44074,
-/* CES31 */ 0.04401, 0.04394184, 0.04384392, 0.04374712, 0.0436988, 0.04373, 0.043838976, 0.04403056, 0.044296864, 0.044620368, 0.04499, 0.045410752,
-0.045884384, 0.046409616, 0.046985248, 0.04761, 0.04828232, 0.049000576, 0.049763632, 0.050570528, 0.05142, 0.052310592, 0.053241232, 0.054208832, 0.055209392,
-0.05624, 0.05730088, 0.058392992, 0.059512944, 0.060655936, 0.06182, 0.063007504, 0.064220064, 0.065464784, 0.066751984, 0.06809, 0.069481072, 0.070927584,
-0.072438016, 0.074022928, 0.07569, 0.077441712, 0.079281296, 0.0812088, 0.083222064, 0.08532, 0.087504656, 0.08977728, 0.092137744, 0.094585488, 0.09712,
-0.099741328, 0.102449504, 0.1052432, 0.108120496, 0.11108, 0.114122064, 0.11724744, 0.120454016, 0.123738672, 0.1271, 0.130539184, 0.134056736, 0.137657008,
-0.14134648, 0.14513, 0.149007504, 0.152978528, 0.157048352, 0.161224176, 0.16551, 0.169905248, 0.174410912, 0.179019376, 0.18371848, 0.1885, 0.193367392,
-0.198325616, 0.2033652, 0.208471904, 0.21364, 0.218876032, 0.22418288, 0.229585008, 0.235118496, 0.24081, 0.246657824, 0.252657168, 0.258837952, 0.265241696,
-0.27189, 0.278773232, 0.285888352, 0.293193312, 0.300623552, 0.30813, 0.315709936, 0.323361392, 0.331034768, 0.338664624, 0.34621, 0.353670256, 0.361036688,
-0.36834264, 0.375646512, 0.38299, 0.390364528, 0.39776136, 0.405222672, 0.412807664, 0.42055, 0.428440128, 0.436476352, 0.444617696, 0.45279896, 0.46097,
-0.46912592, 0.477259936, 0.485323632, 0.493254688, 0.50101, 0.508580528, 0.515951552, 0.523132816, 0.53015008, 0.53702, 0.543731824, 0.550277536, 0.556671248,
-0.56293448, 0.56908, 0.575104576, 0.581008368, 0.58678024, 0.592401872, 0.59786, 0.603156624, 0.60829432, 0.613259056, 0.618031232, 0.6226, 0.626969136,
-0.631139856, 0.635127136, 0.638954416, 0.64264, 0.646186912, 0.649599216, 0.65289392, 0.656092304, 0.65921, 0.6622524, 0.66522656, 0.66813304, 0.670968,
-0.67373, 0.67642464, 0.6790564, 0.68162528, 0.68412984, 0.68657, 0.688948528, 0.69126768, 0.693530352, 0.695740064, 0.6979, 0.700012384, 0.702079632,
-0.70410464, 0.706090448, 0.70804, 0.70995576, 0.71184, 0.71369624, 0.71552848, 0.71734, 0.719131776, 0.72090392, 0.722660864, 0.724408528, 0.72615, 0.72788416,
-0.729610864, 0.731323648, 0.733012512, 0.73467, 0.736296288, 0.737891888, 0.739449008, 0.740957088, 0.74241, 0.743808928, 0.745153728, 0.746451568, 0.747714048,
-0.74895, 0.750159984, 0.751344928, 0.752513232, 0.753675776, 0.75484, 0.756007056, 0.757178912, 0.758354128, 0.759528784, 0.7607, 0.761868208, 0.763032928,
-0.764191808, 0.765341808, 0.76648, 0.767604496, 0.768713488, 0.76980256, 0.770866192, 0.7719, 0.772903056, 0.7738752, 0.774811184, 0.775704048, 0.77655,
-0.777350272, 0.778105264, 0.778820976, 0.779506768, 0.78017, 0.780811984, 0.781434416, 0.782044368, 0.78265072, 0.78326, 0.783874336, 0.784496448, 0.78512696,
-0.785764912, 0.78641, 0.787063712, 0.78772664, 0.788399728, 0.789084096, 0.78978, 0.790486544, 0.791202928, 0.791926432, 0.792653216, 0.79338, 0.79410528,
-0.794827312, 0.795542864, 0.796248176, 0.79694, 0.797616496, 0.798275984, 0.798915312, 0.79953088, 0.80012, 0.800682784, 0.801220512, 0.8017292, 0.802203088,
-0.80264, 0.803042832, 0.80341312, 0.803761408, 0.804103136, 0.80445, 0.804802336, 0.805159488, 0.8055344, 0.805944592, 0.8064, 0.806898448, 0.80744024,
-0.808011552, 0.808590304, 0.80916, 0.8097208, 0.810272912, 0.810799984, 0.811280176, 0.8117, 0.812060128, 0.812358784, 0.81260672, 0.812822496, 0.81302,
-0.813199296, 0.8133616, 0.813519264, 0.813688288, 0.81388, 0.81409696, 0.814342944, 0.814617568, 0.814917312, 0.81524, 0.815587536, 0.815960528, 0.816358,
-0.816778432, 0.81722, 0.817681136, 0.81816024, 0.818653184, 0.819154608, 0.81966, 0.820167616, 0.820675984, 0.821179872, 0.82167272, 0.82215, 0.822610624,
-0.823052912, 0.82347824, 0.823889888, 0.82429, 0.824677488, 0.82505168, 0.825413952, 0.825766304, 0.82611, 0.82644488, 0.826770992, 0.827087824, 0.827394416,
-0.82769, 0.827974528, 0.828247904, 0.82850912, 0.828756816, 0.82899, 0.829208384, 0.82941152, 0.829599376, 0.829772192, 0.82993, 0.830072368, 0.830198976,
-0.830309424, 0.830403232, 0.83048, 0.83054136, 0.830590784, 0.830622448, 0.830626832, 0.8306, 0.830543952, 0.830455488, 0.830357504, 0.8302852, 0.83026,
-0.8302792, 0.830347264, 0.830444048, 0.830535472, 0.8306, 0.830642144, 0.830660688, 0.830662992, 0.830663136, 0.83067, 0.830680544, 0.830694112, 0.83070984,
-0.830725568, 0.83074, 0.830754, 0.830768, 0.830782, 0.830796, 0.83081, 0.830824, 0.830838, 0.830852, 0.830866, 0.83088, 0.830894, 0.830908, 0.830922, 0.830936,
-0.83095, 0.830964, 0.830978, 0.830992, 0.831006, 0.83102, 0.831034, 0.831048, 0.831062, 0.831076, 0.83109, 0.831103888, 0.83111744, 0.831130992, 0.831145104,
-0.83116, 0.831175568, 0.831192032, 0.831207936, 0.83122104, 0.83123,
-/* CES32 */ 0.0429, 0.0429, 0.0429, 0.0429, 0.0429, 0.0429, 0.0429, 0.0429, 0.0429, 0.0429, 0.0429, 0.042898656, 0.04289328, 0.042887904, 0.042889248, 0.0429,
-0.042918624, 0.042947424, 0.042969504, 0.042958944, 0.0429, 0.042796048, 0.042646544, 0.04246248, 0.042262496, 0.04206, 0.04185472, 0.04164816, 0.04144792,
-0.04126296, 0.0411, 0.040961984, 0.0408524, 0.040771536, 0.040717632, 0.04069, 0.04069104, 0.040722496, 0.040784592, 0.040877088, 0.041, 0.041154096,
-0.041340032, 0.041557968, 0.041807904, 0.04209, 0.042404704, 0.042752368, 0.043133632, 0.043549296, 0.044, 0.044486144, 0.045008272, 0.0455664, 0.046160288,
-0.04679, 0.047456576, 0.04816136, 0.048903984, 0.049683488, 0.0505, 0.051355392, 0.052251264, 0.053191056, 0.054179328, 0.05522, 0.056314912, 0.057465936,
-0.05867728, 0.059953984, 0.0613, 0.062717312, 0.06420824, 0.065773808, 0.067414176, 0.06913, 0.070923456, 0.072796608, 0.07475016, 0.076784352, 0.0789,
-0.08109888, 0.08338248, 0.08575344, 0.08821504, 0.09077, 0.093419552, 0.09616488, 0.099008368, 0.101952736, 0.105, 0.10815088, 0.111406048, 0.114765936,
-0.118230624, 0.1218, 0.125473504, 0.129249936, 0.133129568, 0.13711312, 0.1412, 0.145387856, 0.149674688, 0.1540552, 0.158522272, 0.16307, 0.167696, 0.17239776,
-0.17716976, 0.18200552, 0.1869, 0.191850736, 0.19685496, 0.201911424, 0.207019968, 0.21218, 0.217389632, 0.222647504, 0.227952496, 0.233303568, 0.2387,
-0.244141168, 0.249626256, 0.255156464, 0.260733952, 0.26636, 0.272033376, 0.277752544, 0.283518832, 0.28933448, 0.2952, 0.301112976, 0.307071232, 0.313070528,
-0.319105184, 0.32517, 0.331261312, 0.337374688, 0.343505504, 0.34964904, 0.3558, 0.361952336, 0.368099824, 0.374235392, 0.38035136, 0.38644, 0.39249328,
-0.398502192, 0.404460304, 0.410362096, 0.4162, 0.42196368, 0.427643584, 0.433224608, 0.438688672, 0.44402, 0.449209664, 0.454249248, 0.459122592, 0.463810976,
-0.4683, 0.472582768, 0.476651632, 0.480503856, 0.48414016, 0.48756, 0.490759104, 0.493735136, 0.496485488, 0.49900744, 0.5013, 0.503364576, 0.505202768,
-0.50681896, 0.508219392, 0.50941, 0.510395744, 0.5111832, 0.511776816, 0.512180352, 0.5124, 0.512445296, 0.512325376, 0.512054736, 0.511650976, 0.51113,
-0.5105024, 0.509779216, 0.508976352, 0.508111408, 0.5072, 0.506253344, 0.505282672, 0.50430048, 0.503319168, 0.50235, 0.501400672, 0.50047656, 0.499591568,
-0.498762496, 0.498, 0.497305056, 0.496679808, 0.49611624, 0.495600032, 0.49512, 0.4946744, 0.4942596, 0.49386624, 0.49348304, 0.4931, 0.492710928, 0.4923096,
-0.491886112, 0.491429344, 0.49093, 0.490384, 0.489788832, 0.489134144, 0.488407056, 0.4876, 0.486714064, 0.485749584, 0.484714304, 0.483620864, 0.48248,
-0.481296576, 0.480077744, 0.478832272, 0.47756952, 0.4763, 0.47503496, 0.473785712, 0.472568144, 0.471400576, 0.4703, 0.469279616, 0.468353344, 0.467537072,
-0.46684728, 0.4663, 0.46590968, 0.465690432, 0.465659104, 0.465833536, 0.46623, 0.466860224, 0.467734464, 0.468870144, 0.470287024, 0.472, 0.474015776,
-0.476341504, 0.478978832, 0.48192544, 0.48518, 0.488744224, 0.492616912, 0.49679952, 0.501294368, 0.5061, 0.511209344, 0.51661528, 0.522304976, 0.528261792,
-0.53447, 0.540916608, 0.547585696, 0.554464464, 0.561541152, 0.5688, 0.576220432, 0.583783104, 0.591456656, 0.599203328, 0.60699, 0.614797664, 0.622608176,
-0.630387168, 0.6380948, 0.6457, 0.653187344, 0.660539968, 0.667749552, 0.674814176, 0.68173, 0.688487648, 0.695082192, 0.701504896, 0.70774568, 0.7138,
-0.719670752, 0.725360016, 0.7308824, 0.736259744, 0.74151, 0.746639712, 0.75165736, 0.756578448, 0.761420736, 0.7662, 0.77092488, 0.775601968, 0.780251456,
-0.784898464, 0.78956, 0.79422896, 0.798889776, 0.803567472, 0.808300528, 0.8131, 0.817933632, 0.822774912, 0.827551232, 0.832161632, 0.83652, 0.840623136,
-0.844498912, 0.847953408, 0.850705984, 0.8526, 0.853663808, 0.853857888, 0.853467488, 0.852948448, 0.8526, 0.852394176, 0.852368448, 0.85247136, 0.852574272,
-0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526,
-0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526,
-0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526,
-0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526,
-/* CES33 */ 0.17315, 0.173979056, 0.175192624, 0.176658672, 0.17815832, 0.17955, 0.180856304, 0.182066992, 0.18320888, 0.184343728, 0.18551, 0.1866872,
-0.18786576, 0.18905136, 0.19025112, 0.19147, 0.192706608, 0.19395752, 0.195229472, 0.196532544, 0.19787, 0.199235712, 0.200626352, 0.202020272, 0.203386032,
-0.2047, 0.20596184, 0.207173392, 0.208309104, 0.209334336, 0.21023, 0.211002224, 0.211652784, 0.212209824, 0.212717424, 0.21321, 0.21369288, 0.214173024,
-0.214683408, 0.215265712, 0.21595, 0.216745888, 0.217666928, 0.218710448, 0.219863568, 0.22112, 0.222493216, 0.223996288, 0.22562336, 0.227362272, 0.22921,
-0.231179488, 0.23328112, 0.235537312, 0.237978304, 0.24063, 0.243505536, 0.246619872, 0.249996608, 0.253662144, 0.25764, 0.261945264, 0.266591408, 0.271603152,
-0.277008896, 0.28283, 0.28906688, 0.295711872, 0.302794064, 0.310354736, 0.31841, 0.326937792, 0.335922704, 0.345299536, 0.354974048, 0.36487, 0.374965248,
-0.385235296, 0.395606064, 0.405986912, 0.41631, 0.426547824, 0.436665984, 0.446659664, 0.456543264, 0.46632, 0.475959296, 0.485433264, 0.494742912, 0.50389896,
-0.5129, 0.521724064, 0.530354832, 0.5387536, 0.546868928, 0.55466, 0.562117552, 0.56923584, 0.575971168, 0.582267296, 0.58809, 0.593441728, 0.598320128,
-0.602754528, 0.606794608, 0.61048, 0.613819936, 0.616829728, 0.61954344, 0.622001472, 0.62424, 0.626283264, 0.62815624, 0.629892896, 0.631529952, 0.6331,
-0.634623216, 0.636115616, 0.637614016, 0.639162176, 0.64079, 0.642505488, 0.644319696, 0.646220144, 0.648179952, 0.65018, 0.652224272, 0.654313264, 0.656431696,
-0.658559088, 0.66068, 0.662789888, 0.664882816, 0.666953184, 0.668996912, 0.67101, 0.672988608, 0.674930784, 0.6768308, 0.678681696, 0.68048, 0.682226896,
-0.68392256, 0.685576144, 0.687201728, 0.68881, 0.69040152, 0.691976704, 0.693546288, 0.695124432, 0.69672, 0.698332816, 0.699964128, 0.70160768, 0.703252432,
-0.70489, 0.706519792, 0.70814008, 0.709743968, 0.711322896, 0.71287, 0.714382464, 0.715857488, 0.717290272, 0.718675696, 0.72001, 0.72129336, 0.722527872,
-0.723708144, 0.724826336, 0.72588, 0.72687408, 0.727811504, 0.728709408, 0.729592592, 0.73048, 0.731372816, 0.732270688, 0.73319448, 0.734172192, 0.73522,
-0.736335008, 0.737518, 0.738748672, 0.739994224, 0.74123, 0.742455872, 0.743671472, 0.744852192, 0.745965312, 0.74699, 0.747925952, 0.748769392, 0.749533232,
-0.750240992, 0.75091, 0.75153896, 0.752128112, 0.752693344, 0.753255776, 0.75383, 0.754418656, 0.755026544, 0.755650832, 0.7562836, 0.75692, 0.757564304,
-0.758220112, 0.75888344, 0.759547808, 0.76021, 0.760872352, 0.76153584, 0.762207088, 0.762896016, 0.76361, 0.764348848, 0.765111808, 0.765906688, 0.766744128,
-0.76763, 0.768562288, 0.769540208, 0.770555568, 0.771595568, 0.77265, 0.773716304, 0.774790928, 0.775864912, 0.776927536, 0.77797, 0.778987808, 0.779976352,
-0.780929856, 0.78184264, 0.78271, 0.783530144, 0.784303136, 0.785022448, 0.78567936, 0.78627, 0.786797424, 0.787263168, 0.787680112, 0.788067456, 0.78844,
-0.788799184, 0.789145952, 0.78949592, 0.789869648, 0.79028, 0.790727104, 0.79121352, 0.791728976, 0.792255472, 0.79278, 0.793304464, 0.793830416, 0.794345328,
-0.794832, 0.79528, 0.795690528, 0.796062688, 0.796405488, 0.796734128, 0.79706, 0.797382992, 0.797703488, 0.798032544, 0.7983848, 0.79877, 0.799189024,
-0.799644064, 0.800131904, 0.800645584, 0.80118, 0.801735648, 0.802311904, 0.80290472, 0.803508896, 0.80412, 0.804735728, 0.8053536, 0.805969712, 0.806579744,
-0.80718, 0.807768672, 0.808344592, 0.808903392, 0.809439632, 0.80995, 0.810434528, 0.810892752, 0.811327936, 0.8117456, 0.81215, 0.81254168, 0.812921696,
-0.813293632, 0.813661888, 0.81403, 0.814399632, 0.814772592, 0.815150832, 0.815536112, 0.81593, 0.816333728, 0.816748192, 0.817174816, 0.81761512, 0.81807,
-0.818539376, 0.819023056, 0.819520256, 0.820029696, 0.82055, 0.821079792, 0.821617216, 0.8221608, 0.822709104, 0.82326, 0.823810912, 0.82435968, 0.824900528,
-0.825425936, 0.82593, 0.82641192, 0.826871968, 0.827302656, 0.827693824, 0.82804, 0.828343488, 0.828605536, 0.828834304, 0.829042352, 0.82924, 0.829430912,
-0.829620064, 0.829816336, 0.830029888, 0.83027, 0.8305352, 0.830815856, 0.831145632, 0.831575328, 0.83213, 0.832804512, 0.833614992, 0.834471152, 0.835231552,
-0.83581, 0.836219424, 0.836444192, 0.83655992, 0.836694848, 0.83693, 0.837248128, 0.83765352, 0.838118912, 0.838592064, 0.83904, 0.839474016, 0.839896592,
-0.840310608, 0.840723584, 0.84114, 0.841556688, 0.841972048, 0.842386048, 0.842798688, 0.84321, 0.843619968, 0.844028528, 0.844436048, 0.844843088, 0.84525,
-0.845656736, 0.846063328, 0.8464696, 0.846875232, 0.84728, 0.847683952, 0.84808712, 0.848489328, 0.848890336, 0.84929, 0.84968832, 0.850085248, 0.850480976,
-0.850875824, 0.85127, 0.8516604, 0.852040896, 0.852420672, 0.852815008, 0.85323, 0.85366264, 0.854119152, 0.854559504, 0.854922096, 0.85517,
-/* CES34 */ 0.04389, 0.04389, 0.04389, 0.04389, 0.04389, 0.04389, 0.04389, 0.04389, 0.04389, 0.04389, 0.04389, 0.043890128, 0.04389064, 0.043891152,
-0.043891024, 0.04389, 0.043888192, 0.043885312, 0.043883072, 0.043884112, 0.04389, 0.043900304, 0.043914992, 0.0439328, 0.043951648, 0.04397, 0.043988016,
-0.04400592, 0.044021984, 0.044033808, 0.04404, 0.044040816, 0.044036144, 0.044027712, 0.04401832, 0.04401, 0.044002768, 0.043996832, 0.043993456, 0.04399416,
-0.044, 0.044011296, 0.044028496, 0.044051216, 0.044078576, 0.04411, 0.044145856, 0.044186496, 0.044231296, 0.044279296, 0.04433, 0.04438384, 0.044441216,
-0.044502592, 0.044568608, 0.04464, 0.044717552, 0.044802192, 0.044894992, 0.044997152, 0.04511, 0.045234752, 0.045372512, 0.045525712, 0.045697392, 0.04589,
-0.046104032, 0.046339472, 0.046599632, 0.046888992, 0.04721, 0.047562128, 0.047945712, 0.048356096, 0.04878592, 0.04923, 0.049689648, 0.050166816, 0.050655744,
-0.051148112, 0.05164, 0.052134544, 0.052633584, 0.053146784, 0.053688464, 0.05427, 0.054893712, 0.055561984, 0.056286576, 0.057082448, 0.05796, 0.05892168,
-0.059971296, 0.061106432, 0.062320688, 0.06361, 0.064977152, 0.066424192, 0.067948112, 0.069544272, 0.07121, 0.072946224, 0.074753152, 0.07663344, 0.078591328,
-0.08063, 0.082749312, 0.08494896, 0.087232608, 0.089605296, 0.09207, 0.094626688, 0.097276448, 0.100014768, 0.102834208, 0.10573, 0.108705648, 0.111766208,
-0.114904848, 0.118110688, 0.12138, 0.124720128, 0.128136048, 0.131647168, 0.135281168, 0.13906, 0.142988832, 0.147072848, 0.151333184, 0.15579616, 0.16048,
-0.1653868, 0.170518464, 0.175877088, 0.181462112, 0.18727, 0.193288544, 0.199497248, 0.205900352, 0.212510256, 0.21932, 0.226295984, 0.233410992, 0.2405888,
-0.247726368, 0.25474, 0.261604048, 0.26829552, 0.274728032, 0.280794064, 0.28642, 0.291590192, 0.296280912, 0.300514112, 0.304341552, 0.3078, 0.310881504,
-0.313587152, 0.31594416, 0.317988368, 0.31975, 0.321244352, 0.32248888, 0.323507808, 0.324326976, 0.32497, 0.325454144, 0.325794928, 0.326017072, 0.326148256,
-0.32621, 0.326214, 0.326173072, 0.326090304, 0.325962656, 0.32579, 0.325581072, 0.325342624, 0.325076256, 0.324781808, 0.32446, 0.324114112, 0.323746816,
-0.3233596, 0.322953584, 0.32253, 0.322090432, 0.32163624, 0.321168848, 0.320689696, 0.3202, 0.319700576, 0.319192128, 0.31867544, 0.318151232, 0.31762,
-0.317081776, 0.31653632, 0.315983984, 0.315425248, 0.31486, 0.314287392, 0.313706704, 0.313115616, 0.312510928, 0.31189, 0.311252, 0.310596176, 0.309919552,
-0.309218368, 0.30849, 0.307733904, 0.306949312, 0.30613648, 0.305296448, 0.30443, 0.303537648, 0.30262088, 0.301678512, 0.300708304, 0.29971, 0.298686304,
-0.297639232, 0.2965752, 0.295503088, 0.29443, 0.29335816, 0.29229, 0.29123152, 0.29018976, 0.28917, 0.288174496, 0.28720584, 0.286264464, 0.285349328, 0.28446,
-0.28359776, 0.282763104, 0.281956608, 0.281178912, 0.28043, 0.279709152, 0.279015808, 0.278347264, 0.27769968, 0.27707, 0.276457408, 0.275861024, 0.27527808,
-0.274705216, 0.27414, 0.273581616, 0.27302904, 0.272482384, 0.271942528, 0.27141, 0.270884416, 0.270365824, 0.269854032, 0.26934872, 0.26885, 0.268358576,
-0.267875072, 0.267401088, 0.266938784, 0.26649, 0.26605544, 0.265635728, 0.265233136, 0.264850544, 0.26449, 0.264152192, 0.263838176, 0.26354736, 0.263278224,
-0.26303, 0.262803904, 0.26260112, 0.262421456, 0.262264352, 0.26213, 0.262019472, 0.261933376, 0.26187512, 0.261849504, 0.26186, 0.261906176, 0.26198696,
-0.262106304, 0.262269808, 0.26248, 0.262734752, 0.263032944, 0.263366976, 0.263725568, 0.2641, 0.264489376, 0.264893136, 0.265301936, 0.265703456, 0.26609,
-0.266461376, 0.266815776, 0.267159856, 0.267505216, 0.26786, 0.268221648, 0.268586976, 0.268965424, 0.269370752, 0.26981, 0.270278832, 0.270774784, 0.271285456,
-0.271792208, 0.27228, 0.272748752, 0.273201536, 0.27361184, 0.273941904, 0.27417, 0.274298656, 0.27432192, 0.274274224, 0.274211408, 0.27417, 0.274145808,
-0.274142784, 0.27415488, 0.274166976, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417,
-0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417,
-0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417,
-0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417,
-0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417,
-/* CES35 */ 0.05245, 0.05245, 0.05245, 0.05245, 0.05245, 0.05245, 0.05245, 0.05245, 0.05245, 0.05245, 0.05245, 0.052449904, 0.05244952, 0.052449136,
-0.052449232, 0.05245, 0.052451088, 0.052452176, 0.052452784, 0.052452272, 0.05245, 0.052445504, 0.052438304, 0.052427424, 0.052411664, 0.05239, 0.052362112,
-0.052327904, 0.052285776, 0.052233568, 0.05217, 0.052095248, 0.052009216, 0.051913264, 0.051809632, 0.0517, 0.05158456, 0.051463744, 0.051338608, 0.051210352,
-0.05108, 0.050948288, 0.050816048, 0.050683728, 0.050551568, 0.05042, 0.050289712, 0.050161168, 0.050035664, 0.04991488, 0.0498, 0.049691344, 0.049589424,
-0.049493984, 0.049404304, 0.04932, 0.049241472, 0.049168944, 0.049102656, 0.049042928, 0.04899, 0.048943904, 0.048904656, 0.048872448, 0.04884752, 0.04883,
-0.04881976, 0.048816608, 0.048820576, 0.048831744, 0.04885, 0.048875072, 0.048906816, 0.04894416, 0.048985584, 0.04903, 0.049077776, 0.04912968, 0.049183904,
-0.049237728, 0.04929, 0.049342192, 0.049395424, 0.049453056, 0.049519888, 0.0496, 0.049695168, 0.049807456, 0.049940944, 0.050100432, 0.05029, 0.050512176,
-0.050769664, 0.051065152, 0.05140112, 0.05178, 0.052203808, 0.052673952, 0.053194016, 0.0537684, 0.0544, 0.055089056, 0.055835936, 0.056639696, 0.057498336,
-0.05841, 0.059373296, 0.060385856, 0.061446736, 0.062555456, 0.06371, 0.0649064, 0.066141136, 0.067406432, 0.068692208, 0.06999, 0.071296064, 0.072606672,
-0.0739136, 0.075207168, 0.07648, 0.0777292, 0.07895184, 0.08014544, 0.08130848, 0.08244, 0.083540736, 0.0846136, 0.085655424, 0.086661008, 0.08763, 0.088568576,
-0.089481184, 0.090385232, 0.09130512, 0.09226, 0.093253168, 0.094286752, 0.095381296, 0.09656352, 0.09785, 0.09924, 0.100735056, 0.102323232, 0.103983728,
-0.1057, 0.107468176, 0.109281312, 0.111126128, 0.112987264, 0.11485, 0.116703136, 0.118535728, 0.1203296, 0.122063632, 0.12372, 0.125290688, 0.12677,
-0.128137952, 0.129369824, 0.13045, 0.131377392, 0.132148832, 0.132776352, 0.133281312, 0.13368, 0.133973296, 0.134163952, 0.134266768, 0.134300624, 0.13428,
-0.134210512, 0.134099088, 0.133950224, 0.13376616, 0.13355, 0.133307552, 0.133043584, 0.132762016, 0.132466448, 0.13216, 0.131845488, 0.131525616, 0.131201344,
-0.130872672, 0.13054, 0.130204928, 0.129868624, 0.12953192, 0.129195536, 0.12886, 0.12852568, 0.12819288, 0.12786136, 0.12753056, 0.1272, 0.126869632,
-0.12653936, 0.126208368, 0.125875536, 0.12554, 0.125201424, 0.124859328, 0.124513472, 0.124163776, 0.12381, 0.123451664, 0.123088432, 0.12271944, 0.122343568,
-0.12196, 0.121568528, 0.12116888, 0.120760912, 0.120344624, 0.11992, 0.119487264, 0.119047072, 0.1185984, 0.118139568, 0.11767, 0.117190848, 0.11670288,
-0.116209072, 0.115713664, 0.11522, 0.114728864, 0.114241072, 0.11375968, 0.113288448, 0.11283, 0.112385056, 0.11195464, 0.111538064, 0.111133568, 0.11074,
-0.11035784, 0.109987184, 0.109627648, 0.109278752, 0.10894, 0.108611008, 0.108291408, 0.107980368, 0.107676848, 0.10738, 0.107089488, 0.106805008, 0.106525808,
-0.106251008, 0.10598, 0.105712576, 0.105448448, 0.10518792, 0.104931632, 0.10468, 0.104432976, 0.10419072, 0.103953024, 0.103719488, 0.10349, 0.103264864,
-0.103044224, 0.102829024, 0.102620624, 0.10242, 0.102227232, 0.102042464, 0.101866096, 0.101698528, 0.10154, 0.101390624, 0.101250576, 0.101119328, 0.100996,
-0.10088, 0.10077168, 0.100671568, 0.100578736, 0.100491744, 0.10041, 0.100334128, 0.100264336, 0.100203104, 0.100154112, 0.10012, 0.100100432, 0.100094784,
-0.100105696, 0.100136848, 0.10019, 0.100264048, 0.100358576, 0.100468864, 0.100587792, 0.10071, 0.10083528, 0.100963584, 0.101089648, 0.101206512, 0.10131,
-0.101400352, 0.101476848, 0.101544144, 0.10161, 0.10168, 0.101753312, 0.101829024, 0.101912576, 0.102011488, 0.10213, 0.102266704, 0.102420976, 0.102587888,
-0.10275968, 0.10293, 0.103098864, 0.103267568, 0.103424192, 0.103551696, 0.10364, 0.103689968, 0.103698912, 0.103680176, 0.10365576, 0.10364, 0.103630912,
-0.103629776, 0.10363432, 0.103638864, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364,
-0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364,
-0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364,
-0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364,
-0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364,
-/* CES36 */ 0.03431, 0.034604064, 0.035047072, 0.03562448, 0.036304048, 0.03707, 0.03794672, 0.03895776, 0.04010664, 0.04139328, 0.04283, 0.04442072,
-0.04614176, 0.04812424, 0.05056608, 0.05358, 0.057150912, 0.06131832, 0.065884768, 0.070531216, 0.07506, 0.079507536, 0.083852768, 0.0882156, 0.092806272,
-0.09776, 0.103046944, 0.10867064, 0.114612896, 0.120828272, 0.12729, 0.134013968, 0.141003616, 0.148271344, 0.155836992, 0.16371, 0.17187952, 0.180335824,
-0.189065408, 0.198049872, 0.20727, 0.216711648, 0.226360848, 0.236183168, 0.246134848, 0.25618, 0.26629968, 0.276471248, 0.286675856, 0.296898144, 0.30712,
-0.317316256, 0.327462176, 0.337536816, 0.347520096, 0.35739, 0.367124832, 0.376706576, 0.38609728, 0.395250304, 0.40413, 0.41272344, 0.4210168, 0.42899584,
-0.4366496, 0.44397, 0.45094808, 0.45757448, 0.46386056, 0.46982776, 0.47549, 0.480844032, 0.48589056, 0.490628768, 0.495055536, 0.49917, 0.502978672,
-0.506489968, 0.509699904, 0.51260016, 0.51519, 0.5174804, 0.519479744, 0.521211968, 0.522709872, 0.524, 0.525093088, 0.526002208, 0.526745648, 0.527342448,
-0.52781, 0.528163632, 0.528419488, 0.528588384, 0.5286784, 0.5287, 0.528666208, 0.528586464, 0.528484, 0.528388176, 0.52832, 0.528284064, 0.52828592,
-0.528328336, 0.528410272, 0.52853, 0.528689648, 0.528891456, 0.529121744, 0.529359552, 0.52959, 0.52981432, 0.530033824, 0.530237808, 0.530412272, 0.53055,
-0.530651712, 0.530713968, 0.530758544, 0.53081976, 0.53092, 0.531054912, 0.531222784, 0.531425216, 0.531661888, 0.53193, 0.532227424, 0.532553376, 0.532887488,
-0.53320016, 0.53347, 0.53369504, 0.533871808, 0.533990816, 0.534042304, 0.53402, 0.533923648, 0.533754496, 0.533513664, 0.533203712, 0.53283, 0.53239784,
-0.531910864, 0.531393008, 0.530877792, 0.53039, 0.529932656, 0.529509008, 0.52913472, 0.528828112, 0.5286, 0.528450464, 0.52838104, 0.528377536, 0.528416592,
-0.52848, 0.528566432, 0.528674256, 0.52878272, 0.528864224, 0.5289, 0.528889504, 0.5288316, 0.528724656, 0.528569632, 0.52837, 0.528128976, 0.527849616,
-0.527551136, 0.527260736, 0.527, 0.526773216, 0.526583696, 0.526454896, 0.526417136, 0.52649, 0.52667288, 0.526966096, 0.527364272, 0.527856128, 0.52843,
-0.529082032, 0.529809552, 0.530581792, 0.531355632, 0.5321, 0.532811696, 0.533486112, 0.534110128, 0.534670544, 0.53516, 0.535576752, 0.535917568, 0.536200384,
-0.53645416, 0.5367, 0.536938624, 0.537173024, 0.537415664, 0.537681104, 0.53798, 0.538315472, 0.538690304, 0.539107136, 0.539567168, 0.54007, 0.540614288,
-0.541197936, 0.541813584, 0.542450832, 0.5431, 0.54375744, 0.544421024, 0.545071808, 0.545683952, 0.54624, 0.546740256, 0.547184448, 0.54757048, 0.547898112,
-0.54817, 0.548387856, 0.5485512, 0.548684144, 0.548822208, 0.54899, 0.549187968, 0.549419184, 0.54968848, 0.549998416, 0.55035, 0.550745424, 0.5511872,
-0.551664336, 0.552159392, 0.55266, 0.553163648, 0.553663856, 0.554162944, 0.554667952, 0.55518, 0.555692256, 0.556202704, 0.556688032, 0.55711456, 0.55746,
-0.557727808, 0.557921072, 0.558034736, 0.55806384, 0.55801, 0.557877392, 0.557664976, 0.55741392, 0.557185184, 0.55702, 0.556916656, 0.55687792, 0.556908064,
-0.557006448, 0.55717, 0.557400832, 0.557703264, 0.558047056, 0.558386288, 0.55869, 0.558960576, 0.559196976, 0.559394736, 0.559552096, 0.55967, 0.559746176,
-0.559775136, 0.559783696, 0.559813216, 0.55989, 0.560009152, 0.560170096, 0.56036688, 0.560587104, 0.56082, 0.561065984, 0.56132696, 0.561578096, 0.561783552,
-0.56192, 0.561988464, 0.561986096, 0.561919808, 0.56180416, 0.56165, 0.561450672, 0.561195648, 0.560912384, 0.56064312, 0.56041, 0.560207808, 0.560045824,
-0.55986584, 0.559576176, 0.55912, 0.5585068, 0.5577304, 0.55682608, 0.55585568, 0.55486, 0.553829952, 0.55276368, 0.551668288, 0.550549456, 0.54941,
-0.548252576, 0.547081408, 0.54588088, 0.544625952, 0.5433, 0.54190848, 0.54045752, 0.53893616, 0.5373288, 0.53563, 0.5338464, 0.53197896, 0.53006472,
-0.52815888, 0.5263, 0.524486208, 0.52271696, 0.521010512, 0.519388224, 0.51786, 0.516426704, 0.515097632, 0.5138236, 0.512527488, 0.51116, 0.509726944,
-0.50822232, 0.506665376, 0.505093232, 0.50353, 0.501968, 0.500404816, 0.498844832, 0.497292288, 0.49575, 0.494219792, 0.492704032, 0.491198432, 0.489695392,
-0.48819, 0.486683392, 0.485175552, 0.483666752, 0.482158032, 0.48065, 0.479142048, 0.477633792, 0.476125456, 0.47461744, 0.47311, 0.471603104, 0.470096816,
-0.468591008, 0.46708544, 0.46558, 0.464074768, 0.462569728, 0.461065248, 0.459561888, 0.45806, 0.456559504, 0.455060368, 0.453562512, 0.452065776, 0.45057,
-0.449075216, 0.447581552, 0.446088368, 0.444594704, 0.4431, 0.441604368, 0.440107728, 0.438610768, 0.437114608, 0.43562, 0.43412688, 0.432635328, 0.431145376,
-0.429656944, 0.42817, 0.4266964, 0.425259696, 0.423824672, 0.422332608, 0.42076, 0.419118544, 0.417384672, 0.41571104, 0.414332528, 0.41339,
-/* CES37 */ 0.05028, 0.05027336, 0.050263584, 0.050251888, 0.050240272, 0.05023, 0.05022072, 0.050212288, 0.050204576, 0.050197264, 0.05019, 0.050183232,
-0.050178016, 0.0501716, 0.050159664, 0.05014, 0.050112912, 0.0500768, 0.050039008, 0.050011136, 0.05, 0.05000432, 0.050025568, 0.050055296, 0.050079664,
-0.05009, 0.050088192, 0.050074016, 0.0500508, 0.050024704, 0.05, 0.049976144, 0.04995344, 0.049933616, 0.049918592, 0.04991, 0.049908672, 0.049915216,
-0.04993136, 0.049959184, 0.05, 0.050054032, 0.05012176, 0.050201808, 0.050291776, 0.05039, 0.050497152, 0.050614128, 0.050738784, 0.05086792, 0.051,
-0.051136064, 0.051276464, 0.051425184, 0.051588224, 0.05177, 0.05197024, 0.052188304, 0.052428848, 0.052698272, 0.053, 0.053332608, 0.053695488, 0.054083088,
-0.054486848, 0.0549, 0.055321216, 0.055748848, 0.05617632, 0.056595392, 0.057, 0.057388128, 0.0577572, 0.058106672, 0.058437504, 0.05875, 0.059042432,
-0.059313632, 0.059563472, 0.059792192, 0.06, 0.060187056, 0.060353952, 0.060500368, 0.060625664, 0.06073, 0.060815808, 0.060886448, 0.060941408, 0.060978928,
-0.061, 0.061009296, 0.061010368, 0.06101448, 0.061036992, 0.06109, 0.061175872, 0.06129608, 0.061463488, 0.061694736, 0.062, 0.062378288, 0.062829728,
-0.063346928, 0.063917248, 0.06453, 0.065180544, 0.065861648, 0.066564832, 0.067281136, 0.068, 0.06871264, 0.069413392, 0.070075424, 0.070662656, 0.07115,
-0.071545216, 0.071864624, 0.072074832, 0.07212592, 0.072, 0.071726736, 0.071325072, 0.070884928, 0.070536064, 0.07038, 0.070433728, 0.070711408, 0.071317008,
-0.072384368, 0.074, 0.076168592, 0.078905648, 0.082166544, 0.08586656, 0.08994, 0.094378496, 0.099160064, 0.104232112, 0.10953176, 0.115, 0.12059544,
-0.126277312, 0.131973984, 0.137601536, 0.14309, 0.14841152, 0.153548864, 0.158420928, 0.162925232, 0.167, 0.170645696, 0.173850528, 0.17668472, 0.179265792,
-0.18168, 0.183919936, 0.1859788, 0.187941984, 0.189925568, 0.192, 0.1941584, 0.196408144, 0.198686288, 0.200886592, 0.20293, 0.20481888, 0.206552448,
-0.208055056, 0.209226144, 0.21, 0.210373456, 0.210334176, 0.209909616, 0.209154496, 0.20811, 0.206776448, 0.205162256, 0.203301024, 0.201234832, 0.199,
-0.196617696, 0.194111024, 0.191510112, 0.18884672, 0.18615, 0.183441216, 0.180738832, 0.178074928, 0.175486144, 0.173, 0.170629904, 0.168391568, 0.166283712,
-0.164294976, 0.16242, 0.160671232, 0.159059632, 0.157581712, 0.156229232, 0.155, 0.153900208, 0.152931472, 0.152115696, 0.1514848, 0.15106, 0.150830464,
-0.150778176, 0.150931408, 0.15133384, 0.152, 0.152901312, 0.154019568, 0.155270224, 0.15653184, 0.15771, 0.158791488, 0.159767584, 0.16053912, 0.160976736,
-0.161, 0.160609472, 0.15979176, 0.158611968, 0.157183296, 0.15559, 0.153829632, 0.151905488, 0.149895824, 0.14790384, 0.146, 0.14419544, 0.142511184,
-0.140930048, 0.139409152, 0.13792, 0.136477184, 0.135090288, 0.133735952, 0.132379856, 0.131, 0.129597808, 0.128170192, 0.126726736, 0.12528576, 0.12386,
-0.122445504, 0.121038816, 0.119652048, 0.11830248, 0.117, 0.115742576, 0.114531328, 0.11335248, 0.112183872, 0.11101, 0.109835264, 0.10866592, 0.107484736,
-0.106266672, 0.105, 0.103695072, 0.102358976, 0.10101896, 0.099714944, 0.09848, 0.097326624, 0.09627016, 0.095340896, 0.094574112, 0.094, 0.093637296,
-0.093505376, 0.093626176, 0.094021216, 0.09471, 0.095705888, 0.097018016, 0.098670304, 0.100691632, 0.1031, 0.105896736, 0.109085504, 0.112650032, 0.11656208,
-0.1208, 0.125361856, 0.130243152, 0.135424928, 0.140883424, 0.1466, 0.152566176, 0.158771728, 0.16521016, 0.171877952, 0.17877, 0.18587632, 0.19318752,
-0.20069816, 0.20840416, 0.2163, 0.224377312, 0.23262824, 0.241043808, 0.249614176, 0.25833, 0.267183296, 0.276165808, 0.28526872, 0.294483072, 0.3038,
-0.313218704, 0.32274616, 0.332350176, 0.341982912, 0.35162, 0.361261824, 0.370886576, 0.380583408, 0.39049368, 0.4007, 0.41118216, 0.421950288, 0.432909776,
-0.443906624, 0.45484, 0.465719824, 0.476531696, 0.487296608, 0.4980636, 0.50886, 0.519663552, 0.530462128, 0.541241824, 0.55198296, 0.56267, 0.57329768,
-0.583859104, 0.594344688, 0.604744672, 0.61505, 0.625252944, 0.635346048, 0.645321552, 0.655171856, 0.66489, 0.674469904, 0.683905792, 0.69319248, 0.702325328,
-0.7113, 0.720112352, 0.72875872, 0.737235728, 0.745540336, 0.75367, 0.76162264, 0.769396448, 0.776990656, 0.784405184, 0.79164, 0.798694688, 0.805569216,
-0.812264224, 0.818780752, 0.82512, 0.831283072, 0.837271264, 0.843086736, 0.848732128, 0.85421, 0.859522464, 0.864671856, 0.869660928, 0.87449264, 0.87917,
-0.883695824, 0.888072928, 0.892304992, 0.896396096, 0.90035, 0.904169792, 0.907858752, 0.911419872, 0.914855952, 0.91817, 0.921365328, 0.924445072, 0.927413056,
-0.93027344, 0.93303, 0.935672384, 0.938176976, 0.940586768, 0.94297128, 0.94536, 0.947744944, 0.950160528, 0.952428592, 0.954272576, 0.95553,
-/* CES38 */ 0.11323, 0.11364184, 0.114226192, 0.114831504, 0.115225136, 0.11525, 0.1149316, 0.114290064, 0.113261808, 0.111770032, 0.10979, 0.107335424,
-0.104380128, 0.101174752, 0.098101456, 0.09541, 0.093089344, 0.091184352, 0.08961608, 0.088218848, 0.08689, 0.085680512, 0.08461528, 0.083688608, 0.082896416,
-0.08224, 0.081725568, 0.081359728, 0.081165808, 0.081176448, 0.08142, 0.08190928, 0.082657888, 0.083698736, 0.085072064, 0.08681, 0.088925888, 0.091432816,
-0.094352064, 0.097705792, 0.10151, 0.105770656, 0.110492144, 0.115675152, 0.12131632, 0.12741, 0.133952016, 0.140937552, 0.148344928, 0.156144064, 0.16431,
-0.172830176, 0.181688208, 0.19086888, 0.200358352, 0.21014, 0.220193536, 0.2305, 0.241032064, 0.251758048, 0.26265, 0.273685808, 0.284839504, 0.29609768,
-0.307452896, 0.31889, 0.33038088, 0.34190088, 0.35341104, 0.36486424, 0.37622, 0.387456064, 0.39855064, 0.409466176, 0.420160432, 0.4306, 0.440767536,
-0.450645536, 0.460221616, 0.469488576, 0.47844, 0.487067328, 0.495365136, 0.503332864, 0.510973072, 0.51829, 0.525284336, 0.531954944, 0.538325072, 0.54442896,
-0.55029, 0.555911472, 0.561302752, 0.566456272, 0.571354672, 0.57599, 0.58037168, 0.584503472, 0.588404784, 0.592103856, 0.59562, 0.598955328, 0.602116064,
-0.60509824, 0.607891296, 0.61049, 0.612905936, 0.6151532, 0.617222384, 0.619095968, 0.62077, 0.622259968, 0.623574784, 0.6247596, 0.625878736, 0.62698,
-0.6280688, 0.62915096, 0.6302596, 0.63143456, 0.6327, 0.634057344, 0.63551216, 0.637035856, 0.638580352, 0.64011, 0.641624736, 0.643121776, 0.644571616,
-0.645936416, 0.64719, 0.648328048, 0.649344096, 0.650240384, 0.651026432, 0.65171, 0.652290176, 0.652768224, 0.653156512, 0.65347224, 0.65373, 0.653936112,
-0.654097552, 0.654229152, 0.654348512, 0.65447, 0.65459896, 0.654738912, 0.654904704, 0.655114736, 0.65538, 0.655699568, 0.656073744, 0.65649168, 0.656935376,
-0.65739, 0.657852976, 0.6583216, 0.658775664, 0.659188208, 0.65954, 0.65982904, 0.660053184, 0.660205248, 0.660278352, 0.66027, 0.660182848, 0.660019808,
-0.659794128, 0.659525168, 0.65923, 0.658913344, 0.658577648, 0.658253232, 0.657981536, 0.65779, 0.657679184, 0.657652912, 0.65770512, 0.657821408, 0.65799,
-0.658212016, 0.65848832, 0.658797344, 0.659108368, 0.6594, 0.659670592, 0.659916064, 0.660133936, 0.660325008, 0.66049, 0.660626432, 0.660732656, 0.66081448,
-0.660881184, 0.66094, 0.660992, 0.66103872, 0.66108696, 0.66114528, 0.66122, 0.661312544, 0.66142424, 0.661557856, 0.661716032, 0.6619, 0.662109136,
-0.662342496, 0.662596896, 0.662867616, 0.66315, 0.663442384, 0.663744176, 0.664044048, 0.66432616, 0.66458, 0.664806352, 0.665005488, 0.665176784, 0.66532088,
-0.66544, 0.66553568, 0.665608384, 0.665672288, 0.665747952, 0.66585, 0.66597824, 0.666132448, 0.666321296, 0.666555424, 0.66684, 0.66717328, 0.667555536,
-0.667970912, 0.668395008, 0.66881, 0.669214576, 0.669605792, 0.669973888, 0.670307904, 0.6706, 0.670847328, 0.671046848, 0.671199648, 0.671309648, 0.67138,
-0.671411328, 0.671406048, 0.671365968, 0.671292768, 0.67119, 0.671061616, 0.670909568, 0.67074984, 0.670604832, 0.67049, 0.670404928, 0.67034936, 0.670328672,
-0.670348464, 0.67041, 0.670512112, 0.670656672, 0.670820752, 0.670969632, 0.67108, 0.671153232, 0.671187552, 0.671181872, 0.671138512, 0.67106, 0.670944032,
-0.670787312, 0.670604272, 0.670417072, 0.67024, 0.670074, 0.669925392, 0.669782544, 0.669624416, 0.66944, 0.669236896, 0.669017984, 0.668799952, 0.66860792,
-0.66846, 0.668354112, 0.668286512, 0.668275312, 0.668345632, 0.66851, 0.668765696, 0.669118272, 0.669526928, 0.669927904, 0.67028, 0.670588, 0.670848048,
-0.671070976, 0.671279744, 0.67149, 0.671696448, 0.671896176, 0.672100464, 0.672324992, 0.67258, 0.672865728, 0.673184224, 0.67353128, 0.673898496, 0.67428,
-0.674678816, 0.6750988, 0.675530624, 0.675960128, 0.67638, 0.676792208, 0.677193984, 0.67760528, 0.678057376, 0.67857, 0.679138944, 0.67976352, 0.680438496,
-0.681153552, 0.6819, 0.682678576, 0.683491376, 0.684319296, 0.685134656, 0.68592, 0.6866756, 0.687396896, 0.688097152, 0.688799888, 0.68952, 0.69025336,
-0.690999472, 0.691755904, 0.692517296, 0.69328, 0.694045424, 0.694814464, 0.695584144, 0.696350224, 0.69711, 0.697863456, 0.698609904, 0.699351152, 0.70009048,
-0.70083, 0.701569152, 0.702307872, 0.703046112, 0.703783552, 0.70452, 0.70525568, 0.705990752, 0.706725024, 0.707458176, 0.70819, 0.708920544, 0.709649824,
-0.710377824, 0.711104544, 0.71183, 0.71255416, 0.713276944, 0.713998608, 0.714719552, 0.71544, 0.716159984, 0.716879728, 0.717598592, 0.718315536, 0.71903,
-0.719742096, 0.720451552, 0.721159728, 0.721868784, 0.72258, 0.72329312, 0.724008368, 0.724724336, 0.725438704, 0.72615, 0.726858464, 0.727563936, 0.728267008,
-0.7289688, 0.72967, 0.730364896, 0.731042528, 0.73171904, 0.732421312, 0.73316, 0.733929776, 0.73474168, 0.735524624, 0.736169248, 0.73661,
-/* CES39 */ 0.04566, 0.045668976, 0.045682144, 0.045698112, 0.04571456, 0.04573, 0.045744832, 0.045759232, 0.045773152, 0.045786672, 0.0458, 0.045812608,
-0.045823232, 0.045835056, 0.04585312, 0.04588, 0.045915264, 0.045960656, 0.046007808, 0.04604352, 0.04606, 0.046058992, 0.046039488, 0.046009824, 0.04598368,
-0.04597, 0.045967584, 0.045977184, 0.045997664, 0.046025904, 0.04606, 0.046101344, 0.046150784, 0.046207664, 0.046270944, 0.04634, 0.04641496, 0.046495744,
-0.046582928, 0.046677472, 0.04678, 0.046890432, 0.047008848, 0.047135104, 0.04726888, 0.04741, 0.047558464, 0.047714064, 0.047877344, 0.048049184, 0.04823,
-0.048419456, 0.048617424, 0.048822832, 0.04903408, 0.04925, 0.049470608, 0.049695952, 0.049925056, 0.05015664, 0.05039, 0.050625216, 0.050862096, 0.051102096,
-0.051347536, 0.0516, 0.051858896, 0.052123376, 0.052395216, 0.052677056, 0.05297, 0.05327288, 0.053585136, 0.053902592, 0.054219088, 0.05453, 0.054835088,
-0.055134272, 0.055423216, 0.05569624, 0.05595, 0.056185296, 0.056402656, 0.056604736, 0.056795856, 0.05698, 0.057159872, 0.057339296, 0.05752168, 0.057710384,
-0.05791, 0.058125952, 0.05836296, 0.058632048, 0.058947296, 0.05932, 0.059752864, 0.060247008, 0.060815792, 0.061476736, 0.06224, 0.063103632, 0.064067392,
-0.065119792, 0.066242272, 0.06742, 0.068649408, 0.069925232, 0.071234176, 0.07256024, 0.07389, 0.075215888, 0.076529856, 0.077822704, 0.079085472, 0.08031,
-0.081490432, 0.082622544, 0.083696736, 0.084701648, 0.08563, 0.086480528, 0.087250976, 0.087947504, 0.088581152, 0.08916, 0.089682832, 0.090148544, 0.090565936,
-0.090947248, 0.0913, 0.091623584, 0.091919056, 0.092180208, 0.0923964, 0.09256, 0.092672192, 0.092734048, 0.092738464, 0.09267568, 0.09254, 0.09233272,
-0.092054064, 0.091709808, 0.091309312, 0.09086, 0.090363392, 0.089821728, 0.089241904, 0.0886324, 0.088, 0.087348032, 0.086680304, 0.085999856, 0.085308928,
-0.08461, 0.083906096, 0.083199536, 0.082493856, 0.081793056, 0.0811, 0.080416016, 0.079742816, 0.079079136, 0.078422096, 0.07777, 0.07712464, 0.076487936,
-0.075858192, 0.075232528, 0.07461, 0.073992688, 0.073381952, 0.072783136, 0.07220384, 0.07165, 0.071122624, 0.070622496, 0.070155648, 0.06972984, 0.06935,
-0.069016416, 0.068730208, 0.06848776, 0.068282592, 0.06811, 0.067970528, 0.0678644, 0.067787392, 0.067733824, 0.0677, 0.06768568, 0.067689952, 0.067714784,
-0.067763936, 0.06784, 0.067942032, 0.068069104, 0.068223856, 0.068410128, 0.06863, 0.0688824, 0.069166656, 0.069480192, 0.069819088, 0.07018, 0.070561584,
-0.070962192, 0.07137824, 0.071805408, 0.07224, 0.072679792, 0.0731224, 0.073565088, 0.074005136, 0.07444, 0.074867744, 0.075286848, 0.075694192, 0.076086096,
-0.07646, 0.0768152, 0.077150832, 0.077467504, 0.077766816, 0.07805, 0.078316784, 0.078567024, 0.078802624, 0.079026304, 0.07924, 0.07944408, 0.079639264,
-0.079825568, 0.080002512, 0.08017, 0.08032872, 0.080479088, 0.080622016, 0.080758704, 0.08089, 0.08101624, 0.081138016, 0.081254832, 0.081365648, 0.08147,
-0.0815688, 0.081662992, 0.081752784, 0.081838256, 0.08192, 0.081999216, 0.082076944, 0.082155792, 0.0822392, 0.08233, 0.082428912, 0.082536272, 0.082655872,
-0.082792752, 0.08295, 0.083127376, 0.083325152, 0.083540848, 0.083770224, 0.08401, 0.084259584, 0.084517808, 0.084782352, 0.085050576, 0.08532, 0.085588784,
-0.085855152, 0.08611624, 0.086368768, 0.08661, 0.08683872, 0.08705408, 0.0872532, 0.08743256, 0.08759, 0.087725456, 0.08783856, 0.087931424, 0.088007648,
-0.08807, 0.088118704, 0.088154224, 0.088179184, 0.088196944, 0.08821, 0.088219168, 0.088225504, 0.08822944, 0.088230896, 0.08823, 0.088227504, 0.08822392,
-0.088219616, 0.088214912, 0.08821, 0.088204976, 0.088199936, 0.088194496, 0.088188016, 0.08818, 0.088170352, 0.088158896, 0.08814528, 0.088129104, 0.08811,
-0.088088224, 0.08806456, 0.088037056, 0.088002672, 0.08796, 0.087909456, 0.087850016, 0.087787856, 0.087732576, 0.08769, 0.087659312, 0.087641696, 0.0876316,
-0.087619664, 0.0876, 0.08757384, 0.0875408, 0.0875032, 0.08746536, 0.08743, 0.08739624, 0.08736392, 0.08733264, 0.08730152, 0.08727, 0.087238368, 0.0872068,
-0.087175072, 0.087142864, 0.08711, 0.087076496, 0.087042272, 0.087007728, 0.086973504, 0.08694, 0.086907136, 0.086874928, 0.0868432, 0.086811632, 0.08678,
-0.086748368, 0.0867168, 0.086685072, 0.086652864, 0.08662, 0.086586496, 0.086552272, 0.086517728, 0.086483504, 0.08645, 0.086417152, 0.086385008, 0.086353344,
-0.08632176, 0.08629, 0.086258128, 0.086226144, 0.08619408, 0.086162016, 0.08613, 0.086098, 0.086066, 0.086034, 0.086002, 0.08597, 0.085938, 0.085906, 0.085874,
-0.085842, 0.08581, 0.085778, 0.085746, 0.085714, 0.085682, 0.08565, 0.085618, 0.085586, 0.085554, 0.085522, 0.08549, 0.085458256, 0.08542728, 0.085396304,
-0.085364048, 0.08533, 0.085294416, 0.085256784, 0.085220432, 0.08519048, 0.08517,
-/* CES40 */ 0.075, 0.075002784, 0.07500688, 0.075011696, 0.075016272, 0.07502, 0.075023248, 0.075026496, 0.075029184, 0.075030432, 0.07503, 0.075027392,
-0.075020704, 0.075015456, 0.075020368, 0.07504, 0.075073616, 0.075124016, 0.075177856, 0.075214016, 0.07522, 0.075198592, 0.075148176, 0.07508184, 0.075021104,
-0.07498, 0.074956656, 0.0749524, 0.074964944, 0.074988608, 0.07502, 0.07506136, 0.075113744, 0.075177408, 0.075252752, 0.07534, 0.075439136, 0.075550288,
-0.07567296, 0.075806352, 0.07595, 0.076104048, 0.07626848, 0.076443792, 0.076630784, 0.07683, 0.077041408, 0.077265072, 0.077501056, 0.07774936, 0.07801,
-0.078283168, 0.078569136, 0.078867584, 0.079177952, 0.0795, 0.079834336, 0.080181744, 0.080542032, 0.08091472, 0.0813, 0.08169864, 0.082110832, 0.082540224,
-0.082992096, 0.08347, 0.083972192, 0.084495664, 0.085045456, 0.085629328, 0.08625, 0.086902912, 0.087585536, 0.08828288, 0.088973184, 0.08964, 0.090282528,
-0.09090112, 0.091478752, 0.091992704, 0.09243, 0.092794576, 0.093088752, 0.093325808, 0.093526784, 0.09371, 0.093885184, 0.094066048, 0.094267792, 0.094506256,
-0.0948, 0.095168016, 0.095627232, 0.096213728, 0.096972544, 0.09794, 0.099125984, 0.100535168, 0.102209632, 0.104204016, 0.10655, 0.109242064, 0.112280112,
-0.11562704, 0.119222848, 0.12302, 0.127008576, 0.13117384, 0.135472624, 0.139852208, 0.14427, 0.148704592, 0.153132144, 0.157530896, 0.161882848, 0.16617,
-0.170374304, 0.174482176, 0.178471248, 0.18231624, 0.186, 0.189516096, 0.192855568, 0.19603112, 0.199067232, 0.20198, 0.204760288, 0.20739792, 0.209910192,
-0.212323584, 0.21465, 0.21687824, 0.219002512, 0.220988064, 0.222783616, 0.22435, 0.225684624, 0.226787104, 0.227615584, 0.228114304, 0.22825, 0.228027072,
-0.227443904, 0.226532896, 0.225347728, 0.22393, 0.222285344, 0.220423456, 0.218381328, 0.21620496, 0.21393, 0.211571664, 0.209147968, 0.206670512, 0.204145216,
-0.20158, 0.198989008, 0.196383392, 0.193773616, 0.19116984, 0.18858, 0.186010272, 0.183467376, 0.18094888, 0.178447664, 0.17596, 0.17349208, 0.17105032,
-0.16863128, 0.16622832, 0.16384, 0.161473088, 0.15913248, 0.156833392, 0.154596944, 0.15244, 0.150366352, 0.148378912, 0.146496032, 0.144741312, 0.14313,
-0.141663584, 0.140346112, 0.13916768, 0.138109968, 0.13716, 0.136320784, 0.1355948, 0.134969696, 0.134428192, 0.13396, 0.133566656, 0.133247216, 0.133012176,
-0.132878896, 0.13286, 0.132953696, 0.133157696, 0.133485536, 0.133956096, 0.13458, 0.135353584, 0.136275216, 0.137332528, 0.13850608, 0.13978, 0.141149216,
-0.142606688, 0.14413832, 0.145727632, 0.14736, 0.149025552, 0.15071408, 0.152412928, 0.154108896, 0.15579, 0.157448256, 0.159077568, 0.16066336, 0.162188112,
-0.16364, 0.16501624, 0.16631272, 0.16753504, 0.16869472, 0.1698, 0.170849168, 0.1718416, 0.172784672, 0.173688464, 0.17456, 0.175400464, 0.176212112,
-0.17699424, 0.177744048, 0.17846, 0.179144224, 0.17979848, 0.180421856, 0.181012592, 0.18157, 0.18209552, 0.182590416, 0.183056352, 0.183495488, 0.18391,
-0.184302176, 0.184674992, 0.185029808, 0.185367424, 0.18569, 0.186001344, 0.186304608, 0.186607472, 0.186919936, 0.18725, 0.187599856, 0.187971072, 0.188371888,
-0.188812704, 0.1893, 0.18983368, 0.190414368, 0.191037456, 0.191694864, 0.19238, 0.19309112, 0.193825376, 0.194576512, 0.195337008, 0.1961, 0.196860832,
-0.197614752, 0.198354512, 0.199071952, 0.19976, 0.200415088, 0.201034752, 0.201610336, 0.20213104, 0.20259, 0.202986704, 0.203319696, 0.203593968, 0.20381848,
-0.204, 0.204138544, 0.204234768, 0.204295152, 0.204328176, 0.20434, 0.204332272, 0.204307312, 0.204265712, 0.204206672, 0.20413, 0.204037392, 0.203930032,
-0.203808112, 0.203671472, 0.20352, 0.20335392, 0.203173312, 0.202977824, 0.202766896, 0.20254, 0.202296848, 0.202037104, 0.20176, 0.201464576, 0.20115,
-0.200818192, 0.20047344, 0.200107808, 0.199708496, 0.19927, 0.198794752, 0.198278528, 0.197750384, 0.19725512, 0.19682, 0.196441488, 0.196125184, 0.1958456,
-0.195559616, 0.19524, 0.194892464, 0.19451544, 0.194118416, 0.193719472, 0.19333, 0.192946128, 0.192567056, 0.192191504, 0.191816432, 0.19144, 0.191063344,
-0.190687024, 0.190311024, 0.189935344, 0.18956, 0.189184976, 0.188810224, 0.188435952, 0.18806248, 0.18769, 0.187318496, 0.186948032, 0.186578368, 0.186209104,
-0.18584, 0.185471104, 0.185102368, 0.184734032, 0.184366496, 0.184, 0.18363448, 0.183269952, 0.182906224, 0.182542976, 0.18218, 0.181817344, 0.181455024,
-0.181093024, 0.180731344, 0.18037, 0.180008976, 0.179648224, 0.179287952, 0.17892848, 0.17857, 0.178212496, 0.177856032, 0.177500368, 0.177145104, 0.17679,
-0.176435104, 0.176080368, 0.175726032, 0.175372496, 0.17502, 0.174668496, 0.174318032, 0.173968368, 0.173619104, 0.17327, 0.172921088, 0.172572288, 0.172223888,
-0.171876368, 0.17153, 0.17118744, 0.170854208, 0.170521696, 0.170175584, 0.16981, 0.169427728, 0.169023216, 0.168632304, 0.168310192, 0.16809,
-/* CES41 */ 0.4098, 0.4098, 0.4098, 0.4098, 0.4098, 0.4098, 0.4098, 0.4098, 0.4098, 0.4098, 0.4098, 0.40981272, 0.4098636, 0.40991448, 0.40990176, 0.4098,
-0.40962384, 0.40935168, 0.40914312, 0.40924296, 0.4098, 0.410784384, 0.41220504, 0.413953296, 0.415844832, 0.41775, 0.419683296, 0.421646976, 0.423563376,
-0.425334816, 0.4269, 0.428255552, 0.429388336, 0.43034928, 0.431227744, 0.43209, 0.43293312, 0.4337588, 0.43462856, 0.43562384, 0.4368, 0.438165056, 0.43973488,
-0.441495184, 0.443411328, 0.44546, 0.44765184, 0.449993424, 0.452465568, 0.455040512, 0.4577, 0.46044432, 0.463270608, 0.466183696, 0.469194304, 0.47231,
-0.4755288, 0.478850016, 0.482280112, 0.485827968, 0.4895, 0.493297248, 0.497221232, 0.501273856, 0.50545648, 0.50977, 0.51421408, 0.518787056, 0.523490832,
-0.528328448, 0.5333, 0.538400976, 0.543627152, 0.548969888, 0.554417664, 0.55996, 0.565589696, 0.571298128, 0.57707592, 0.582913392, 0.5888, 0.594724576,
-0.600676, 0.606639744, 0.612599488, 0.61854, 0.624449408, 0.630315744, 0.63612368, 0.641856736, 0.6475, 0.653041728, 0.65847008, 0.663773312, 0.668940224,
-0.67396, 0.678823168, 0.683521792, 0.688043136, 0.6923728, 0.6965, 0.700419872, 0.704127136, 0.70762128, 0.710905024, 0.71398, 0.716844704, 0.71949968,
-0.721944256, 0.724177312, 0.7262, 0.728016384, 0.729630176, 0.731050688, 0.7322904, 0.73336, 0.73426496, 0.735011488, 0.735609216, 0.736068864, 0.7364,
-0.736609712, 0.736705296, 0.73669416, 0.736583424, 0.73638, 0.736090464, 0.73572096, 0.735278256, 0.734769232, 0.7342, 0.733575568, 0.732900896, 0.732179744,
-0.731415072, 0.73061, 0.729768256, 0.728893024, 0.727987712, 0.72705576, 0.7261, 0.725122624, 0.724125952, 0.72311032, 0.722075008, 0.72102, 0.719947424,
-0.71885944, 0.717755936, 0.716636032, 0.7155, 0.714350096, 0.713188256, 0.712018016, 0.710843936, 0.70967, 0.708498464, 0.707331776, 0.706173728, 0.70502856,
-0.7039, 0.70279064, 0.701703088, 0.700640256, 0.699605024, 0.6986, 0.697627104, 0.696687856, 0.695785008, 0.69492168, 0.6941, 0.693320448, 0.692583488,
-0.691888608, 0.691234528, 0.69062, 0.690043968, 0.689504608, 0.689001248, 0.688533568, 0.6881, 0.687697456, 0.687323248, 0.68697096, 0.686632192, 0.6863,
-0.685971968, 0.68564608, 0.685314672, 0.684968144, 0.6846, 0.684209056, 0.683793392, 0.683355248, 0.682899584, 0.68243, 0.681946048, 0.681448128, 0.680938928,
-0.680421888, 0.6799, 0.679375008, 0.678848848, 0.678324128, 0.677803648, 0.67729, 0.67678528, 0.676291568, 0.675811216, 0.675346624, 0.6749, 0.674473088,
-0.674067456, 0.673685264, 0.673328832, 0.673, 0.67269936, 0.672427024, 0.672184928, 0.671975552, 0.6718, 0.671657552, 0.671547968, 0.671467264, 0.67140944,
-0.67137, 0.671349024, 0.671346944, 0.671358784, 0.671377744, 0.6714, 0.671426928, 0.671459344, 0.67150144, 0.671559776, 0.67164, 0.671744768, 0.67187768,
-0.672043952, 0.672249424, 0.6725, 0.672801088, 0.673158272, 0.673578656, 0.67407008, 0.67464, 0.675294432, 0.676039296, 0.67688272, 0.677833664, 0.6789,
-0.680087328, 0.68140128, 0.682847712, 0.684432224, 0.68616, 0.688034688, 0.690058592, 0.692238816, 0.69458416, 0.6971, 0.69978608, 0.702642656, 0.705665312,
-0.708846528, 0.71218, 0.715662816, 0.719290272, 0.723058368, 0.726963424, 0.731, 0.735160368, 0.739437008, 0.743818288, 0.748290208, 0.75284, 0.75745856,
-0.762136128, 0.766860656, 0.771619344, 0.7764, 0.781192064, 0.785984976, 0.790766928, 0.79552576, 0.80025, 0.804930144, 0.809557408, 0.814119792, 0.818604256,
-0.823, 0.82730016, 0.831497872, 0.835587904, 0.839566496, 0.84343, 0.847175264, 0.850801264, 0.854301904, 0.857669344, 0.8609, 0.863995728, 0.866956624,
-0.86979552, 0.872531936, 0.87518, 0.877737472, 0.88020024, 0.882584688, 0.884914096, 0.8872, 0.889434208, 0.891611168, 0.893708768, 0.895694208, 0.89754,
-0.8992496, 0.900838208, 0.902236576, 0.903342304, 0.9041, 0.904523936, 0.904601856, 0.904447616, 0.904240896, 0.9041, 0.904016032, 0.904005536, 0.90404752,
-0.904089504, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041,
-0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041,
-0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041,
-0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041,
-/* CES42 */ 0.06598, 0.065901712, 0.065787232, 0.065648432, 0.065505072, 0.06537, 0.065241488, 0.065120992, 0.065005856, 0.06489, 0.06477, 0.06464744,
-0.064521616, 0.064397312, 0.064282128, 0.06418, 0.06409024, 0.064015312, 0.063943264, 0.063855216, 0.06374, 0.06360024, 0.063434784, 0.063254928, 0.063079232,
-0.06292, 0.062776, 0.062648688, 0.062537216, 0.062438224, 0.06235, 0.062274944, 0.062214576, 0.062169728, 0.06214128, 0.06213, 0.06213616, 0.062159728,
-0.062202096, 0.062265184, 0.06235, 0.062456096, 0.062583296, 0.062729776, 0.062892736, 0.06307, 0.0632612, 0.063465856, 0.063682032, 0.063907328, 0.06414,
-0.064379552, 0.064625392, 0.064877312, 0.065135472, 0.0654, 0.06567088, 0.065948432, 0.066232544, 0.066522976, 0.06682, 0.067124688, 0.067438144, 0.06776208,
-0.068098656, 0.06845, 0.068818, 0.06920488, 0.06961272, 0.0700436, 0.0705, 0.070984608, 0.07149984, 0.072050432, 0.072642224, 0.07328, 0.073965648, 0.074700752,
-0.075489856, 0.0763384, 0.07725, 0.078225552, 0.079266496, 0.0803704, 0.081532624, 0.08275, 0.08402416, 0.08535712, 0.08674456, 0.08817992, 0.08966,
-0.091188064, 0.09276712, 0.094401456, 0.096096992, 0.09786, 0.099698096, 0.101621776, 0.103633856, 0.105734496, 0.10793, 0.110236256, 0.112668176, 0.115250736,
-0.118015216, 0.12099, 0.124195136, 0.127653376, 0.131389696, 0.135429696, 0.1398, 0.144525392, 0.149628336, 0.1551476, 0.161129024, 0.16761, 0.174602784,
-0.18211312, 0.190184496, 0.198872912, 0.20821, 0.218185568, 0.228791856, 0.239995504, 0.251744192, 0.26399, 0.276698144, 0.289818944, 0.303319504, 0.317172544,
-0.33133, 0.345719312, 0.360275104, 0.374866416, 0.389327808, 0.40352, 0.41738216, 0.430859136, 0.443806032, 0.456048048, 0.46746, 0.47800168, 0.487622432,
-0.496335264, 0.504195696, 0.51124, 0.517446608, 0.522810224, 0.52735104, 0.531097776, 0.53408, 0.536319568, 0.53783984, 0.538689392, 0.538928624, 0.53861,
-0.537762272, 0.536414192, 0.534618032, 0.532433872, 0.52991, 0.52707432, 0.523957872, 0.520575824, 0.516933056, 0.51304, 0.5089236, 0.504608304, 0.500107328,
-0.495430192, 0.49059, 0.485606608, 0.480497888, 0.475286928, 0.469999888, 0.46466, 0.459281392, 0.453876288, 0.448470464, 0.44309432, 0.43777, 0.432506432,
-0.427314784, 0.422191856, 0.417125808, 0.41211, 0.407153328, 0.402263616, 0.397435424, 0.392658912, 0.38793, 0.383254544, 0.378636144, 0.374087664, 0.369627504,
-0.36527, 0.361017472, 0.356871744, 0.352847296, 0.348962768, 0.34523, 0.341649312, 0.338222656, 0.33494248, 0.331795104, 0.32877, 0.325866128, 0.3230808,
-0.320405072, 0.317827904, 0.31534, 0.312935984, 0.310609792, 0.30835544, 0.306167408, 0.30404, 0.301967216, 0.29994352, 0.297962304, 0.296016528, 0.2941,
-0.29220808, 0.290336144, 0.288479968, 0.286635792, 0.2848, 0.28296968, 0.281143008, 0.279314736, 0.277478384, 0.27563, 0.27376992, 0.271898256, 0.270017872,
-0.268133648, 0.26625, 0.264369824, 0.262497472, 0.26063616, 0.258788768, 0.25696, 0.255156832, 0.25338592, 0.251658688, 0.249989216, 0.24839, 0.246868512,
-0.245432288, 0.244094304, 0.24286944, 0.24177, 0.240803344, 0.239977264, 0.239298064, 0.238770624, 0.2384, 0.238191648, 0.238149504, 0.2382804, 0.238592176,
-0.23909, 0.239774352, 0.24064544, 0.241702048, 0.242941376, 0.24436, 0.245952352, 0.247709568, 0.249632064, 0.25172344, 0.25398, 0.256387712, 0.258934784,
-0.261592176, 0.264321248, 0.26709, 0.269886224, 0.272698016, 0.275493328, 0.27823344, 0.28089, 0.283453584, 0.285913248, 0.288266512, 0.290517776, 0.29267,
-0.294721536, 0.296676112, 0.298531888, 0.300285264, 0.30194, 0.303509232, 0.305004528, 0.306456624, 0.30790688, 0.30939, 0.310920592, 0.312513904, 0.314204256,
-0.316032368, 0.31803, 0.320212816, 0.322598816, 0.325195856, 0.328004816, 0.33103, 0.33428616, 0.337786016, 0.341535552, 0.345538448, 0.3498, 0.35429992,
-0.358987632, 0.363959584, 0.369371376, 0.37529, 0.381685632, 0.388608944, 0.395708416, 0.402442128, 0.40848, 0.413864192, 0.418526416, 0.4227736, 0.427125504,
-0.43191, 0.43705696, 0.44258392, 0.44837576, 0.45421392, 0.45996, 0.465659168, 0.47131984, 0.476954192, 0.482594624, 0.48826, 0.493935456, 0.499612912,
-0.505290688, 0.510966944, 0.51664, 0.522308464, 0.527970848, 0.533625952, 0.539272736, 0.54491, 0.550536304, 0.556150352, 0.56175032, 0.567334128, 0.5729,
-0.578446688, 0.5839728, 0.589477472, 0.594960144, 0.60042, 0.605855728, 0.611266192, 0.616649856, 0.62200496, 0.62733, 0.632623952, 0.637885696, 0.6431144,
-0.648309424, 0.65347, 0.658595104, 0.66368384, 0.668735056, 0.673747472, 0.67872, 0.683651888, 0.688542336, 0.693390784, 0.698196832, 0.70296, 0.707679616,
-0.712355104, 0.716985792, 0.72157096, 0.72611, 0.73060248, 0.735047952, 0.739446144, 0.743796896, 0.7481, 0.75235512, 0.756561984, 0.760720288, 0.764829712,
-0.76889, 0.772873664, 0.776725888, 0.780528592, 0.784418416, 0.78845, 0.792597904, 0.796920672, 0.80105712, 0.804450848, 0.80677,
-/* CES43 */ 0.0671, 0.0671, 0.0671, 0.0671, 0.0671, 0.0671, 0.0671, 0.0671, 0.0671, 0.0671, 0.0671, 0.067092288, 0.06706144, 0.067030592, 0.067038304, 0.0671,
-0.067205952, 0.067367552, 0.067490592, 0.067430912, 0.0671, 0.066514208, 0.065668032, 0.064618976, 0.06346624, 0.06228, 0.061054976, 0.059795296, 0.058538496,
-0.057329376, 0.0562, 0.055161296, 0.054227056, 0.053392336, 0.052640256, 0.05196, 0.051358848, 0.050840576, 0.050398064, 0.050020592, 0.0497, 0.049434544,
-0.049221504, 0.049056224, 0.048933824, 0.04885, 0.048802208, 0.048788704, 0.04880432, 0.048842736, 0.0489, 0.048976016, 0.04907032, 0.049185824, 0.049327648,
-0.0495, 0.049704384, 0.049943344, 0.050219984, 0.050537584, 0.0509, 0.051311328, 0.051775424, 0.05229936, 0.052891776, 0.05356, 0.0543072, 0.05513584,
-0.0560544, 0.05707344, 0.0582, 0.059436496, 0.06078744, 0.062245904, 0.063799168, 0.06544, 0.067177568, 0.069023904, 0.07096904, 0.072995696, 0.0751,
-0.07729792, 0.0796004, 0.08204936, 0.08470384, 0.08761, 0.090775456, 0.09420528, 0.097947984, 0.102066528, 0.1066, 0.111549392, 0.116923584, 0.122686736,
-0.128776928, 0.13515, 0.14181488, 0.148780976, 0.156004032, 0.163421968, 0.171, 0.178746912, 0.186660912, 0.194794432, 0.203230752, 0.21203, 0.221182592,
-0.230674032, 0.240569872, 0.250962432, 0.2619, 0.273356976, 0.285318832, 0.297706368, 0.310399424, 0.3233, 0.33637648, 0.349587888, 0.362845776, 0.376045904,
-0.3891, 0.401955024, 0.414555056, 0.426840048, 0.4387528, 0.45024, 0.461259808, 0.471780608, 0.481739248, 0.491062128, 0.4997, 0.50764104, 0.514869088,
-0.521410496, 0.527317664, 0.53263, 0.537347872, 0.541479456, 0.54505648, 0.548118544, 0.5507, 0.552821584, 0.55450616, 0.555781696, 0.556677152, 0.55722,
-0.557433088, 0.557337616, 0.556963024, 0.556341552, 0.5555, 0.55445616, 0.553228704, 0.551828928, 0.550263232, 0.54854, 0.546673376, 0.544675008, 0.54255616,
-0.540328032, 0.538, 0.535579552, 0.5330744, 0.530486528, 0.527814816, 0.52506, 0.522228544, 0.519326688, 0.516355072, 0.513312416, 0.5102, 0.507023584,
-0.503788032, 0.50050192, 0.497176128, 0.49382, 0.490438304, 0.48703584, 0.483621856, 0.480207072, 0.4768, 0.473405072, 0.470027056, 0.46666904, 0.463332704,
-0.46002, 0.456733888, 0.45347632, 0.450249872, 0.447057264, 0.4439, 0.440777472, 0.437688592, 0.434632272, 0.431606912, 0.42861, 0.42563648, 0.422679232,
-0.419737424, 0.416812256, 0.4139, 0.410991472, 0.408080384, 0.405141456, 0.402139968, 0.39905, 0.395872, 0.392610896, 0.389236352, 0.385706288, 0.382,
-0.378130544, 0.374104992, 0.36996936, 0.365792768, 0.36163, 0.35749296, 0.3533956, 0.3493904, 0.34554264, 0.3419, 0.338477472, 0.33529384, 0.332352768,
-0.329646176, 0.32717, 0.324932, 0.322932848, 0.321176016, 0.319665984, 0.3184, 0.317367152, 0.316558256, 0.31594176, 0.315474144, 0.31512, 0.314868128,
-0.31470744, 0.314602672, 0.314510544, 0.3144, 0.31426112, 0.314080832, 0.313862304, 0.313619296, 0.31336, 0.313076032, 0.312761824, 0.312422496, 0.312066688,
-0.3117, 0.31131976, 0.310922896, 0.310513712, 0.310098688, 0.30968, 0.309246816, 0.308783072, 0.308296368, 0.307802224, 0.3073, 0.306766736, 0.306186048,
-0.3054984, 0.304621152, 0.30349, 0.302095552, 0.3004336, 0.298434848, 0.296008336, 0.2931, 0.28971776, 0.285861248, 0.281582096, 0.276965504, 0.27208,
-0.266944032, 0.261586256, 0.25606512, 0.250449024, 0.2448, 0.239160624, 0.2335736, 0.228101536, 0.222813792, 0.21777, 0.213000608, 0.208526656, 0.204417184,
-0.200757232, 0.1976, 0.194953056, 0.192835744, 0.191189712, 0.18991408, 0.18894, 0.18829472, 0.188012112, 0.188016704, 0.188196576, 0.1885, 0.188962976,
-0.189593264, 0.190553072, 0.19208704, 0.19437, 0.197367328, 0.201017632, 0.205522576, 0.21117152, 0.2181, 0.226192576, 0.235372656, 0.245315936, 0.255545376,
-0.26567, 0.275661344, 0.285566896, 0.294711088, 0.30214256, 0.3073, 0.310221328, 0.310744688, 0.309650368, 0.308223008, 0.3073, 0.306767136, 0.306700528,
-0.30696696, 0.307233392, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073,
-0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073,
-0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073,
-0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073,
-/* CES44 */ 0.0439, 0.0439, 0.0439, 0.0439, 0.0439, 0.0439, 0.0439, 0.0439, 0.0439, 0.0439, 0.0439, 0.043900976, 0.04390488, 0.043908784, 0.043907808, 0.0439,
-0.043886464, 0.043865504, 0.043849424, 0.043857104, 0.0439, 0.043975744, 0.044084944, 0.044219184, 0.044364224, 0.04451, 0.044657344, 0.044805984, 0.044950224,
-0.045083104, 0.0452, 0.045300048, 0.045381824, 0.04544776, 0.045502656, 0.04555, 0.045589184, 0.04561992, 0.045645696, 0.045671312, 0.0457, 0.04573208,
-0.045768416, 0.045808112, 0.045849088, 0.04589, 0.045931552, 0.045974192, 0.046017152, 0.046059312, 0.0461, 0.046139328, 0.046177232, 0.046214336, 0.04625168,
-0.04629, 0.046329152, 0.046368976, 0.04641016, 0.046453664, 0.0465, 0.046548944, 0.0466004, 0.046653536, 0.046707072, 0.04676, 0.046812192, 0.046863536,
-0.04691288, 0.046958704, 0.047, 0.047036704, 0.04706864, 0.047096096, 0.047119712, 0.04714, 0.04715712, 0.047171456, 0.047183232, 0.047192608, 0.0472,
-0.047206096, 0.047211472, 0.047217648, 0.047226624, 0.04724, 0.047258144, 0.047281248, 0.047311152, 0.047350256, 0.0474, 0.047460256, 0.047531152, 0.047611248,
-0.047698144, 0.04779, 0.047886784, 0.047988448, 0.048092912, 0.048197376, 0.0483, 0.048400608, 0.048498672, 0.048595136, 0.04869184, 0.04879, 0.048889152,
-0.048988976, 0.04909016, 0.049193664, 0.0493, 0.049408912, 0.04952024, 0.049633248, 0.049746816, 0.04986, 0.049972544, 0.050084208, 0.050193712, 0.050299376,
-0.0504, 0.050495264, 0.050584672, 0.05066832, 0.050746688, 0.05082, 0.050887904, 0.05095024, 0.051006656, 0.051056672, 0.0511, 0.051136688, 0.051166736,
-0.051190384, 0.051208032, 0.05122, 0.051226432, 0.051227584, 0.051223536, 0.051214288, 0.0512, 0.051181072, 0.051157856, 0.05113104, 0.051101504, 0.05107,
-0.051036768, 0.05100192, 0.050966592, 0.050932304, 0.0509, 0.050869648, 0.050841392, 0.050814496, 0.05078768, 0.05076, 0.050731584, 0.050702576, 0.050671888,
-0.050638, 0.0506, 0.050558, 0.050511888, 0.050462576, 0.050411584, 0.05036, 0.05030768, 0.050254496, 0.050201392, 0.050149648, 0.0501, 0.050052304, 0.050006592,
-0.04996192, 0.049916768, 0.04987, 0.049821824, 0.04977264, 0.049720736, 0.049663632, 0.0496, 0.0495304, 0.049454976, 0.049375952, 0.049296768, 0.04922,
-0.04914576, 0.049074192, 0.049007584, 0.048948896, 0.0489, 0.048860944, 0.048832064, 0.048812064, 0.048798624, 0.04879, 0.048786272, 0.048787424, 0.048791616,
-0.048796368, 0.0488, 0.048802288, 0.048802736, 0.048801824, 0.048800672, 0.0488, 0.04879936, 0.048798384, 0.048797648, 0.048798032, 0.0488, 0.048803296,
-0.048807808, 0.0488128, 0.048817152, 0.04882, 0.04882144, 0.04882176, 0.04881952, 0.04881264, 0.0488, 0.048782048, 0.04875888, 0.048732352, 0.048705344,
-0.04868, 0.04865648, 0.048635072, 0.048617344, 0.048605216, 0.0486, 0.048601952, 0.048611424, 0.048628096, 0.048651168, 0.04868, 0.048714688, 0.048755296,
-0.048800704, 0.048849312, 0.0489, 0.048952608, 0.049006784, 0.04906256, 0.049120256, 0.04918, 0.049241472, 0.04930448, 0.049368768, 0.049434016, 0.0495,
-0.04956664, 0.049633808, 0.049701616, 0.049770304, 0.04984, 0.049910688, 0.049982496, 0.050055024, 0.050127632, 0.0502, 0.050272304, 0.050344544, 0.050417504,
-0.050492384, 0.05057, 0.05065024, 0.050733024, 0.050818688, 0.050907632, 0.051, 0.0510956, 0.051194288, 0.051295296, 0.051397504, 0.0515, 0.051602464,
-0.051704496, 0.051805328, 0.05190408, 0.052, 0.052092576, 0.052181248, 0.0522656, 0.052345312, 0.05242, 0.05248928, 0.05255296, 0.05261008, 0.05265936, 0.0527,
-0.052732032, 0.05275536, 0.052770528, 0.052778496, 0.05278, 0.052775136, 0.052764128, 0.05274752, 0.052725952, 0.0527, 0.052670096, 0.05263672, 0.052600304,
-0.052561248, 0.05252, 0.052477056, 0.052432864, 0.052388112, 0.0523436, 0.0523, 0.052257712, 0.052217152, 0.052178752, 0.052142912, 0.05211, 0.05208016,
-0.052053312, 0.052030304, 0.052012336, 0.052, 0.051993104, 0.051991824, 0.051994304, 0.051997664, 0.052, 0.052001408, 0.052001584, 0.05200088, 0.052000176,
-0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052,
-0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052,
-0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052,
-0.052, 0.052,
-/* CES45 */ 0.0554, 0.05534864, 0.055271792, 0.055195424, 0.055159856, 0.05519, 0.055280784, 0.055432864, 0.055632944, 0.055857664, 0.05609, 0.056331792,
-0.056584064, 0.056830896, 0.057050768, 0.05723, 0.057368768, 0.057464976, 0.057526544, 0.057567952, 0.0576, 0.05762096, 0.057628864, 0.057637728, 0.057667392,
-0.05773, 0.057827216, 0.057966448, 0.05812888, 0.058282752, 0.05841, 0.058514176, 0.05858848, 0.058664144, 0.058791568, 0.059, 0.05928376, 0.059650624,
-0.060059328, 0.060442752, 0.06076, 0.061018448, 0.061211248, 0.061372528, 0.061560208, 0.06181, 0.062115344, 0.062482608, 0.062885872, 0.063280336, 0.06364,
-0.063970784, 0.064265312, 0.06455984, 0.064913328, 0.06536, 0.065892192, 0.06651728, 0.067189808, 0.067835936, 0.06841, 0.06892368, 0.069378128, 0.069792576,
-0.070201344, 0.07063, 0.071076, 0.071537296, 0.072042672, 0.072631808, 0.07333, 0.074139632, 0.075070512, 0.076100032, 0.077188032, 0.07831, 0.079472992,
-0.080675792, 0.081932912, 0.083269232, 0.0847, 0.086221872, 0.087836912, 0.089534352, 0.091295712, 0.09311, 0.09498264, 0.096917232, 0.098916704, 0.100985776,
-0.10313, 0.1053524, 0.107654624, 0.110053648, 0.112573232, 0.11523, 0.118028576, 0.120978288, 0.12407224, 0.127294992, 0.13064, 0.134117616, 0.13773416,
-0.141506704, 0.145459168, 0.14961, 0.153963632, 0.158525264, 0.163307776, 0.168325808, 0.17359, 0.179091936, 0.184811056, 0.190784256, 0.197069936, 0.20369,
-0.210618688, 0.217852176, 0.225266784, 0.232678272, 0.23996, 0.247097632, 0.254047344, 0.260832976, 0.267521088, 0.27414, 0.280646768, 0.287024336, 0.293188224,
-0.299016992, 0.30443, 0.309425856, 0.313989104, 0.318139472, 0.3219228, 0.32537, 0.328471872, 0.331228352, 0.333651872, 0.335757472, 0.33756, 0.339069456,
-0.340294352, 0.341259568, 0.341996864, 0.34253, 0.3428684, 0.343025008, 0.343000736, 0.342789264, 0.34239, 0.341816112, 0.341078976, 0.34018664, 0.339147184,
-0.33797, 0.336665056, 0.33524136, 0.333715984, 0.332109808, 0.33044, 0.328708224, 0.326909664, 0.325076704, 0.323255584, 0.32147, 0.319717696, 0.318014144,
-0.316281952, 0.31439888, 0.31229, 0.309979552, 0.307480352, 0.304814432, 0.302021152, 0.29914, 0.296164432, 0.293062512, 0.289991952, 0.287189152, 0.28479,
-0.282774624, 0.281182432, 0.27979848, 0.278274448, 0.27639, 0.274182112, 0.27163144, 0.268827248, 0.265937216, 0.26307, 0.260193872, 0.257301648, 0.254404064,
-0.2515056, 0.24861, 0.245730096, 0.242881184, 0.240041952, 0.23717512, 0.23426, 0.231302464, 0.228295872, 0.22527848, 0.222311248, 0.21943, 0.216633024,
-0.21393848, 0.211286576, 0.208578912, 0.20576, 0.202848224, 0.199838016, 0.196807568, 0.19388128, 0.19114, 0.188574736, 0.186194368, 0.18398024, 0.181890912,
-0.1799, 0.178016896, 0.17624136, 0.174575424, 0.173025008, 0.17159, 0.170265664, 0.169054544, 0.167922304, 0.166817824, 0.16571, 0.164599008, 0.163472144,
-0.16236872, 0.161354976, 0.16047, 0.15970464, 0.1590664, 0.15851128, 0.15796704, 0.15739, 0.156786288, 0.15614368, 0.155508592, 0.154958944, 0.15454,
-0.154237904, 0.154056672, 0.153946, 0.153824528, 0.15364, 0.15340296, 0.15311456, 0.15278096, 0.15241816, 0.15204, 0.151641488, 0.1512132, 0.150798112,
-0.150460784, 0.15024, 0.150130944, 0.150142032, 0.15023136, 0.150329008, 0.15039, 0.15042488, 0.15043616, 0.15042552, 0.1504008, 0.15037, 0.150328208,
-0.1502648, 0.150220272, 0.150256624, 0.15041, 0.150680912, 0.151091072, 0.151571552, 0.152009232, 0.15234, 0.152577024, 0.152698912, 0.1528156, 0.153104048,
-0.15367, 0.154488496, 0.15556976, 0.156818464, 0.158076448, 0.15924, 0.160322704, 0.161314704, 0.162227824, 0.163096464, 0.16394, 0.164747312, 0.165521904,
-0.166239376, 0.166861168, 0.16737, 0.16777416, 0.168064976, 0.168318672, 0.168652528, 0.16914, 0.16976896, 0.170543552, 0.171429184, 0.172363056, 0.1733,
-0.174245552, 0.175200944, 0.176129696, 0.176983488, 0.17773, 0.178366032, 0.178884096, 0.17929392, 0.179617264, 0.17987, 0.180045568, 0.18013648, 0.180169072,
-0.180182544, 0.1802, 0.180222, 0.180261312, 0.180277344, 0.180203136, 0.18, 0.179683216, 0.179256464, 0.178754352, 0.17823208, 0.17773, 0.177248144,
-0.176788752, 0.17638152, 0.176063888, 0.17586, 0.175773296, 0.17581176, 0.175956624, 0.176173808, 0.17644, 0.176763968, 0.177155264, 0.17759024, 0.178034256,
-0.17847, 0.17890032, 0.17931424, 0.17977192, 0.18036784, 0.18116, 0.18213688, 0.1833024, 0.18461928, 0.1860232, 0.18747, 0.188961728, 0.19049, 0.192049632,
-0.193641264, 0.19526, 0.196893488, 0.198535552, 0.200156816, 0.20171712, 0.20319, 0.204574528, 0.205865936, 0.207072704, 0.208212912, 0.2093, 0.21032648,
-0.211281024, 0.212194928, 0.213116032, 0.21407, 0.215048288, 0.216054608, 0.217037728, 0.217917328, 0.21864, 0.219219904, 0.219671568, 0.219967552, 0.220070816,
-0.21997, 0.219669856, 0.219148912, 0.218543888, 0.218064544, 0.21784, 0.21785592, 0.218138928, 0.218580256, 0.218993184, 0.21926,
-/* CES46 */ 0.1126, 0.1126, 0.1126, 0.1126, 0.1126, 0.1126, 0.1126, 0.1126, 0.1126, 0.1126, 0.1126, 0.11260568, 0.1126284, 0.11265112, 0.11264544, 0.1126,
-0.11252128, 0.11239952, 0.11230616, 0.1123508, 0.1126, 0.113040096, 0.11367464, 0.114455184, 0.115299648, 0.11615, 0.117011424, 0.117883104, 0.118730864,
-0.119512384, 0.1202, 0.120789696, 0.121273744, 0.121668832, 0.12200656, 0.12231, 0.122576672, 0.122806672, 0.123020432, 0.123245152, 0.1235, 0.123788944,
-0.124118512, 0.12448752, 0.124889248, 0.12532, 0.125785184, 0.12628904, 0.126828976, 0.127400112, 0.128, 0.128631056, 0.129294896, 0.129994736, 0.130735136,
-0.13152, 0.132351264, 0.133231296, 0.134163568, 0.13515192, 0.1362, 0.137310944, 0.138488048, 0.139734672, 0.141054176, 0.14245, 0.14392592, 0.145485952,
-0.147133104, 0.148870016, 0.1507, 0.15262728, 0.154655744, 0.156791248, 0.159040672, 0.16141, 0.163902528, 0.166521168, 0.169272288, 0.172163408, 0.1752,
-0.17838384, 0.181716848, 0.185199456, 0.188830784, 0.19261, 0.19653632, 0.200607376, 0.204824112, 0.209188608, 0.2137, 0.218352736, 0.223141472, 0.228056848,
-0.233086784, 0.23822, 0.243447712, 0.248759408, 0.254145344, 0.259596, 0.2651, 0.270644096, 0.276215664, 0.281795312, 0.28736016, 0.29289, 0.29837248,
-0.303795712, 0.309139344, 0.314380256, 0.3195, 0.324488304, 0.329334144, 0.334031504, 0.338577984, 0.34297, 0.34720016, 0.351263024, 0.355153568, 0.358866992,
-0.3624, 0.36575096, 0.368918368, 0.371904496, 0.374713824, 0.37735, 0.379813984, 0.382107696, 0.384234208, 0.38619704, 0.388, 0.389646864, 0.391141568,
-0.392489552, 0.393697056, 0.39477, 0.395713312, 0.396532272, 0.397232512, 0.397819792, 0.3983, 0.398678784, 0.398961552, 0.39915568, 0.399269408, 0.39931,
-0.399281888, 0.39918888, 0.399038672, 0.398840224, 0.3986, 0.39832048, 0.398004672, 0.397651504, 0.397257376, 0.39682, 0.396341088, 0.395821744, 0.39526016,
-0.394653536, 0.394, 0.39329976, 0.3925528, 0.39175888, 0.39091792, 0.39003, 0.389095808, 0.38811664, 0.387091712, 0.386019504, 0.3849, 0.383735312, 0.382526992,
-0.381280352, 0.380002752, 0.3787, 0.377373344, 0.376023792, 0.3746572, 0.373281008, 0.3719, 0.370514528, 0.3691256, 0.367730272, 0.366323104, 0.3649,
-0.363460832, 0.362004832, 0.360528672, 0.359028192, 0.3575, 0.355942272, 0.354352832, 0.352730112, 0.351072992, 0.34938, 0.347648672, 0.345876512, 0.344061952,
-0.342203712, 0.3403, 0.338348624, 0.336347952, 0.33429336, 0.332178848, 0.33, 0.327757312, 0.32545264, 0.323079888, 0.320630256, 0.3181, 0.315493424,
-0.312813568, 0.310072192, 0.307286336, 0.30447, 0.301628192, 0.298766912, 0.295898912, 0.293039152, 0.2902, 0.28738864, 0.284612832, 0.281879184, 0.279193056,
-0.27656, 0.273985952, 0.271475504, 0.269035936, 0.266675488, 0.2644, 0.2622116, 0.260112736, 0.258101952, 0.256175328, 0.25433, 0.252566016, 0.250882112,
-0.249277248, 0.247750544, 0.2463, 0.244922368, 0.243614688, 0.242370448, 0.241181248, 0.24004, 0.23894368, 0.237889488, 0.236869856, 0.235875584, 0.2349,
-0.233940784, 0.232994976, 0.232063008, 0.23114752, 0.23025, 0.229368704, 0.228502688, 0.227652192, 0.226817776, 0.226, 0.2251992, 0.224415872, 0.223650864,
-0.222905216, 0.22218, 0.221476224, 0.220795024, 0.220137664, 0.219505504, 0.2189, 0.218322576, 0.217774624, 0.217258272, 0.216776, 0.21633, 0.215921344,
-0.215550752, 0.21522128, 0.214936848, 0.2147, 0.214510928, 0.21437016, 0.214276352, 0.214226944, 0.21422, 0.214255264, 0.214331952, 0.21444904, 0.214605488,
-0.2148, 0.215031168, 0.21529776, 0.215596992, 0.215925344, 0.21628, 0.21666016, 0.217065312, 0.217492544, 0.217938176, 0.2184, 0.218877648, 0.219369904,
-0.21988056, 0.220415936, 0.22098, 0.221569472, 0.22217968, 0.222816368, 0.223488816, 0.2242, 0.224942608, 0.225710848, 0.226488928, 0.227254848, 0.22799,
-0.2286928, 0.229367168, 0.229975296, 0.230463184, 0.2308, 0.230989776, 0.231024176, 0.230954016, 0.230861376, 0.2308, 0.230764032, 0.230759536, 0.23077752,
-0.230795504, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308,
-0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308,
-0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308,
-0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308,
-/* CES47 */ 0.1446, 0.1446, 0.1446, 0.1446, 0.1446, 0.1446, 0.1446, 0.1446, 0.1446, 0.1446, 0.1446, 0.144600864, 0.14460432, 0.144607776, 0.144606912, 0.1446,
-0.144588096, 0.144569856, 0.144555936, 0.144562656, 0.1446, 0.144666432, 0.144763296, 0.14488272, 0.145011264, 0.14514, 0.14527232, 0.14541184, 0.14555184,
-0.14568256, 0.1458, 0.145909024, 0.1460128, 0.146125856, 0.146269312, 0.14646, 0.146701792, 0.146998656, 0.14736752, 0.147829664, 0.1484, 0.1490824, 0.14988256,
-0.15079744, 0.1518184, 0.15294, 0.154166304, 0.15550032, 0.156937856, 0.158472352, 0.1601, 0.161822288, 0.163639856, 0.165555664, 0.167574432, 0.1697,
-0.1719336, 0.174277024, 0.176733488, 0.179306672, 0.182, 0.184816144, 0.187757888, 0.190828512, 0.194031456, 0.19737, 0.200846912, 0.204464832, 0.208227232,
-0.212137872, 0.2162, 0.220415792, 0.224787312, 0.229316992, 0.234007232, 0.23886, 0.24387584, 0.249054352, 0.254398704, 0.259913296, 0.2656, 0.271456064,
-0.277478624, 0.283663744, 0.290006144, 0.2965, 0.303137728, 0.309909024, 0.31681104, 0.323843456, 0.331, 0.338264976, 0.34562344, 0.353051504, 0.360519488,
-0.368, 0.375472704, 0.382914624, 0.390300624, 0.397604784, 0.4048, 0.411860032, 0.418759984, 0.425462576, 0.431924928, 0.43811, 0.443998336, 0.449574336,
-0.454796976, 0.459616736, 0.464, 0.46793944, 0.471424736, 0.474468112, 0.477096448, 0.47933, 0.481169056, 0.482619472, 0.483696128, 0.484416944, 0.4848,
-0.48486032, 0.484613328, 0.484083776, 0.483300704, 0.48229, 0.481068128, 0.479651056, 0.478065104, 0.476340032, 0.4745, 0.472559424, 0.470533824, 0.468432144,
-0.466258784, 0.46402, 0.46172768, 0.459391664, 0.457020128, 0.454620672, 0.4522, 0.44976432, 0.447319728, 0.444868656, 0.442411584, 0.43995, 0.437488384,
-0.435030656, 0.432578928, 0.43013472, 0.4277, 0.4252776, 0.422869968, 0.420480416, 0.418112704, 0.41577, 0.413453856, 0.411165456, 0.408908016, 0.406685376,
-0.4045, 0.402352224, 0.400242656, 0.398169488, 0.39612944, 0.39412, 0.392141216, 0.390193008, 0.38827248, 0.386375712, 0.3845, 0.38264496, 0.38080984,
-0.37899496, 0.37720144, 0.37543, 0.373680448, 0.37195312, 0.370247632, 0.368563264, 0.3669, 0.365258992, 0.363641312, 0.362048912, 0.360484352, 0.35895,
-0.357447744, 0.355979952, 0.35454832, 0.353154288, 0.3518, 0.35048816, 0.34922088, 0.348004, 0.34684512, 0.34575, 0.34471888, 0.3437508, 0.34285272, 0.34203424,
-0.3413, 0.340646736, 0.3400724, 0.339566464, 0.339113248, 0.3387, 0.338322784, 0.337976784, 0.337650064, 0.337328304, 0.337, 0.336659392, 0.336300224,
-0.335916896, 0.335504928, 0.33506, 0.334578176, 0.334057056, 0.333490976, 0.332873216, 0.3322, 0.331472224, 0.330690656, 0.329859968, 0.32898752, 0.32808,
-0.327141952, 0.326179808, 0.325198624, 0.32420304, 0.3232, 0.32219936, 0.321210624, 0.320249088, 0.319333312, 0.31848, 0.3177, 0.317004448, 0.316410336,
-0.315936704, 0.3156, 0.315411696, 0.315383856, 0.315526736, 0.315849056, 0.31636, 0.317069888, 0.317987776, 0.319124624, 0.320492112, 0.3221, 0.323955008,
-0.326063984, 0.32843112, 0.331058816, 0.33395, 0.337108864, 0.34053808, 0.344242736, 0.348228832, 0.3525, 0.35705616, 0.361897456, 0.367020512, 0.372419648,
-0.37809, 0.384028832, 0.390231792, 0.396696272, 0.403420352, 0.4104, 0.417627904, 0.425096832, 0.43279656, 0.440714848, 0.44884, 0.457161696, 0.46566792,
-0.474349184, 0.483196928, 0.4922, 0.501343744, 0.510614224, 0.519991024, 0.529450144, 0.53897, 0.548536, 0.558133344, 0.567741168, 0.577336672, 0.5869,
-0.596417152, 0.605874128, 0.615257024, 0.62455296, 0.63375, 0.642838912, 0.651813264, 0.660658576, 0.669357728, 0.6779, 0.686281424, 0.694493936, 0.702555808,
-0.71049824, 0.71834, 0.726062528, 0.733640368, 0.741099248, 0.748481968, 0.7558, 0.763013408, 0.770088928, 0.776946208, 0.783475008, 0.78958, 0.795261824,
-0.800563328, 0.805244512, 0.808954016, 0.8115, 0.812926432, 0.813188032, 0.812667712, 0.811972032, 0.8115, 0.811219424, 0.811184352, 0.81132464, 0.811464928,
-0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115,
-0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115,
-0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115,
-0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115,
-/* CES48 */ 0.08776, 0.087840592, 0.087957856, 0.08810376, 0.088264304, 0.08843, 0.088601888, 0.08877896, 0.088959792, 0.089143824, 0.08933, 0.08951624,
-0.089701072, 0.089881904, 0.090055616, 0.09022, 0.090375696, 0.090524464, 0.090662752, 0.0907852, 0.09089, 0.090981792, 0.091065232, 0.091146912, 0.091235472,
-0.09134, 0.091470736, 0.091640992, 0.091857168, 0.092123424, 0.09245, 0.092854368, 0.093351248, 0.093975648, 0.094773008, 0.09578, 0.097007008, 0.098461168,
-0.100182368, 0.102221408, 0.10461, 0.107348912, 0.110443968, 0.113869184, 0.1175792, 0.12154, 0.125751488, 0.130210624, 0.13488656, 0.139739216, 0.14474,
-0.14988168, 0.15515352, 0.16055664, 0.16610048, 0.17179, 0.177616528, 0.18357312, 0.189663392, 0.195894544, 0.20227, 0.208785056, 0.215435712, 0.222218288,
-0.229128144, 0.23616, 0.243306384, 0.250557248, 0.257910432, 0.265366496, 0.27292, 0.280557008, 0.288265552, 0.296019216, 0.3037836, 0.31153, 0.319246656,
-0.326922336, 0.334528096, 0.342029136, 0.3494, 0.356631376, 0.363711856, 0.370641856, 0.377429696, 0.38408, 0.390586256, 0.396944736, 0.403157376, 0.409228016,
-0.41516, 0.420954192, 0.426611856, 0.43213792, 0.437538864, 0.44282, 0.44798296, 0.45302896, 0.45796408, 0.46279608, 0.46753, 0.472166592, 0.4767076,
-0.481149328, 0.485484976, 0.48971, 0.493826608, 0.497837328, 0.501737328, 0.505519328, 0.50918, 0.512722608, 0.516149328, 0.519468448, 0.522692048, 0.52583,
-0.528885408, 0.531861808, 0.534768928, 0.537618608, 0.54042, 0.543176928, 0.545893648, 0.548573408, 0.551218208, 0.55383, 0.556410336, 0.558958928, 0.56148008,
-0.563979712, 0.56646, 0.568917936, 0.57135176, 0.573750304, 0.576097248, 0.57838, 0.580596992, 0.582747024, 0.584817456, 0.586791888, 0.58866, 0.590421328,
-0.592073456, 0.593624784, 0.595090112, 0.59648, 0.597792432, 0.599025584, 0.600189536, 0.601298288, 0.60236, 0.603372672, 0.604335856, 0.60524144, 0.606076304,
-0.60683, 0.607500224, 0.6080836, 0.608570496, 0.608949152, 0.60921, 0.609348432, 0.609359616, 0.60923744, 0.608975904, 0.60857, 0.608017472, 0.60731776,
-0.606464608, 0.605449936, 0.60427, 0.602927056, 0.601421968, 0.5997664, 0.597977952, 0.59607, 0.594042944, 0.5918964, 0.589645136, 0.587309072, 0.5849,
-0.582416624, 0.579860496, 0.577217968, 0.57446656, 0.57159, 0.568590432, 0.565470368, 0.562213584, 0.55879768, 0.55521, 0.55145416, 0.547530944, 0.543456448,
-0.539256192, 0.53495, 0.530540608, 0.526031808, 0.521442368, 0.516796048, 0.51211, 0.507389536, 0.502642048, 0.49786664, 0.493056992, 0.48821, 0.483332048,
-0.47842864, 0.473497872, 0.468535424, 0.46354, 0.458515792, 0.453465952, 0.448397392, 0.443319472, 0.43824, 0.433161952, 0.428088272, 0.423027152, 0.417988592,
-0.41298, 0.408004304, 0.403065232, 0.3981628, 0.393294688, 0.38846, 0.383661968, 0.37890344, 0.374184192, 0.369503024, 0.36486, 0.360257376, 0.355696912,
-0.351182368, 0.346718704, 0.34231, 0.337957872, 0.333663968, 0.329431984, 0.32526624, 0.32117, 0.317144672, 0.313191824, 0.309311936, 0.305504688, 0.30177,
-0.298108544, 0.294520496, 0.291006208, 0.28756608, 0.2842, 0.280907344, 0.277687648, 0.274538592, 0.271456896, 0.26844, 0.26548744, 0.262598832, 0.259771664,
-0.257002736, 0.25429, 0.251633184, 0.249031584, 0.246486944, 0.244002384, 0.24158, 0.239218928, 0.236918224, 0.23467984, 0.232506656, 0.2304, 0.228358672,
-0.22638192, 0.224466368, 0.222607056, 0.2208, 0.219044192, 0.217338528, 0.215679024, 0.21406072, 0.21248, 0.210935664, 0.209426144, 0.207951424, 0.206512544,
-0.20511, 0.203742736, 0.202410064, 0.201111952, 0.19984856, 0.19862, 0.197426128, 0.196266832, 0.195142416, 0.19405336, 0.193, 0.191982112, 0.190999296,
-0.19005232, 0.189142384, 0.18827, 0.187434576, 0.18663576, 0.185871904, 0.185140608, 0.18444, 0.183769712, 0.183129344, 0.182517216, 0.181931248, 0.18137,
-0.180833088, 0.180319936, 0.179831024, 0.179367472, 0.17893, 0.17851824, 0.178131984, 0.177771568, 0.177437472, 0.17713, 0.17684912, 0.176594848, 0.176367056,
-0.176165504, 0.17599, 0.17584072, 0.175718016, 0.175621072, 0.175548608, 0.1755, 0.175475824, 0.175476512, 0.17550312, 0.175557168, 0.17564, 0.175752272,
-0.17589472, 0.176068928, 0.176276816, 0.17652, 0.176795216, 0.177095088, 0.17743328, 0.177831792, 0.1783, 0.178833728, 0.1794404, 0.180069152, 0.180641584,
-0.18111, 0.181480608, 0.181743632, 0.181943776, 0.18215664, 0.18243, 0.182753872, 0.183130976, 0.18354496, 0.183964544, 0.18437, 0.184768064, 0.18516016,
-0.185548256, 0.185937232, 0.18633, 0.186724608, 0.187120096, 0.187516304, 0.187912992, 0.18831, 0.18870736, 0.189105104, 0.189503168, 0.189901472, 0.1903,
-0.190698752, 0.191097648, 0.191497104, 0.19189776, 0.1923, 0.19270376, 0.193109104, 0.193515648, 0.193922752, 0.19433, 0.194737472, 0.195145168, 0.195553104,
-0.19596136, 0.19637, 0.196775648, 0.197171584, 0.197568, 0.197981856, 0.19842, 0.198879056, 0.19936568, 0.199836464, 0.200224608, 0.20049,
-/* CES49 */ 0.00057, 0.001450112, 0.002738016, 0.00429616, 0.005896144, 0.00739, 0.008801088, 0.01011856, 0.011368592, 0.012613024, 0.01389, 0.015177744,
-0.016465792, 0.01775864, 0.019062048, 0.02038, 0.021711392, 0.02305368, 0.024411808, 0.025793136, 0.0272, 0.02862736, 0.030071728, 0.031518496, 0.032946784,
-0.03434, 0.035694336, 0.037006096, 0.038258736, 0.039431456, 0.04051, 0.041491696, 0.042372496, 0.043155616, 0.043849376, 0.04446, 0.044987248, 0.045433056,
-0.045801824, 0.046098832, 0.04633, 0.046500816, 0.046616624, 0.046688192, 0.04672888, 0.04675, 0.04675688, 0.046754432, 0.046754064, 0.046769296, 0.04681,
-0.046880064, 0.046984304, 0.047121744, 0.047287824, 0.04748, 0.047701792, 0.047956064, 0.048240896, 0.048552768, 0.04889, 0.049254624, 0.049648256, 0.050073248,
-0.0505328, 0.05103, 0.051567424, 0.052148288, 0.052775392, 0.053451456, 0.05418, 0.054965312, 0.055811392, 0.056725312, 0.057715712, 0.05879, 0.059952064,
-0.061205632, 0.06255792, 0.064017248, 0.06559, 0.06727904, 0.06908736, 0.07101664, 0.07306736, 0.07524, 0.077535344, 0.07995304, 0.082494096, 0.085159872,
-0.08795, 0.090861344, 0.093890496, 0.097032528, 0.1002812, 0.10363, 0.10707176, 0.110597408, 0.114202016, 0.117881984, 0.12163, 0.125434, 0.129283216,
-0.133156272, 0.137026128, 0.14087, 0.14467768, 0.148439632, 0.152132784, 0.155729616, 0.15921, 0.162566288, 0.165789104, 0.16887944, 0.171844976, 0.17469,
-0.177408464, 0.17999608, 0.182455696, 0.184792592, 0.18701, 0.189106752, 0.191082656, 0.19293648, 0.194666064, 0.19627, 0.197748944, 0.1991036, 0.200332896,
-0.201435232, 0.20241, 0.203258368, 0.203981456, 0.204581104, 0.205059792, 0.20542, 0.205664112, 0.205795184, 0.205815216, 0.205725888, 0.20553, 0.205231744,
-0.204834976, 0.204346768, 0.20377592, 0.20313, 0.2024128, 0.201627888, 0.200783216, 0.199888224, 0.19895, 0.197971584, 0.196956576, 0.195905488, 0.1948168,
-0.19369, 0.192527584, 0.191331408, 0.190101632, 0.188837856, 0.18754, 0.186209008, 0.184845792, 0.183450176, 0.1820216, 0.18056, 0.179066736, 0.177543536,
-0.175990096, 0.174405456, 0.17279, 0.171146128, 0.169475856, 0.167783984, 0.166076912, 0.16436, 0.16263536, 0.160905024, 0.159174688, 0.157451312, 0.15574,
-0.154042656, 0.152361728, 0.15069704, 0.149046752, 0.14741, 0.145788992, 0.14418584, 0.142599488, 0.141027776, 0.13947, 0.13792792, 0.136402688, 0.134898016,
-0.133419104, 0.13197, 0.130551184, 0.129162736, 0.127809328, 0.1264972, 0.12523, 0.124007088, 0.122828528, 0.121690288, 0.120585808, 0.11951, 0.118462368,
-0.117442208, 0.116444288, 0.115461808, 0.11449, 0.11352784, 0.112573728, 0.111628176, 0.110693184, 0.10977, 0.10885768, 0.107955776, 0.107064912, 0.106186048,
-0.10532, 0.104467184, 0.103628192, 0.1028036, 0.101993968, 0.1012, 0.100422528, 0.09966232, 0.098920752, 0.098199504, 0.0975, 0.096823168, 0.096170112,
-0.095541536, 0.09493792, 0.09436, 0.093808736, 0.093284736, 0.092790176, 0.092327936, 0.0919, 0.091506672, 0.091148496, 0.0908252, 0.090535904, 0.09028,
-0.090057408, 0.08986752, 0.089710832, 0.089588304, 0.0895, 0.089444656, 0.089421312, 0.089427008, 0.089457664, 0.08951, 0.089583008, 0.089675648, 0.089784928,
-0.089907248, 0.09004, 0.090182272, 0.090332928, 0.090492144, 0.09066096, 0.09084, 0.091028368, 0.091225184, 0.0914316, 0.091649536, 0.09188, 0.09212232,
-0.09237616, 0.09264008, 0.09291192, 0.09319, 0.093473904, 0.09376304, 0.094056256, 0.094352272, 0.09465, 0.09494872, 0.095247696, 0.095546272, 0.095843888,
-0.09614, 0.096434176, 0.096726192, 0.097015168, 0.097299984, 0.09758, 0.097855392, 0.098126288, 0.098393344, 0.0986576, 0.09892, 0.099181136, 0.099441824,
-0.099702912, 0.09996528, 0.10023, 0.100498192, 0.100770832, 0.101050192, 0.101339152, 0.10164, 0.101953184, 0.102278752, 0.10261952, 0.102979248, 0.10336,
-0.103761216, 0.10418288, 0.104621584, 0.105071968, 0.10553, 0.105995376, 0.106467584, 0.106943312, 0.1074184, 0.10789, 0.1083576, 0.108820432, 0.109279104,
-0.109735216, 0.11019, 0.110643488, 0.111096144, 0.11154896, 0.112003136, 0.11246, 0.112920816, 0.11338688, 0.113860384, 0.114343968, 0.11484, 0.115350144,
-0.115876224, 0.116420224, 0.116984144, 0.11757, 0.118176368, 0.118798384, 0.11944848, 0.120146016, 0.1209, 0.121707696, 0.12257536, 0.123464384, 0.124314768,
-0.12509, 0.125795216, 0.126423344, 0.127007712, 0.12760472, 0.12825, 0.128936064, 0.129664912, 0.13042432, 0.131190928, 0.13195, 0.13270656, 0.13346168,
-0.1342168, 0.13497552, 0.13574, 0.136508816, 0.13728128, 0.138057344, 0.138836928, 0.13962, 0.140406576, 0.141196624, 0.141990352, 0.14278808, 0.14359,
-0.144396096, 0.145206432, 0.146020768, 0.146838704, 0.14766, 0.14848472, 0.149312848, 0.150144576, 0.150980224, 0.15182, 0.152663856, 0.153511776, 0.154363776,
-0.155219856, 0.15608, 0.156936944, 0.157776176, 0.158619328, 0.15950248, 0.16044, 0.16142448, 0.162470128, 0.163482976, 0.164318544, 0.16489,
-/* CES50 */ 0.07883, 0.079265072, 0.079901616, 0.080676, 0.081481824, 0.08225, 0.082991616, 0.08370184, 0.084391024, 0.085085168, 0.0858, 0.086524848,
-0.087253744, 0.08798888, 0.088733696, 0.08949, 0.090257552, 0.09103752, 0.091824608, 0.092610416, 0.09339, 0.094164368, 0.094932608, 0.095700528, 0.096477648,
-0.09727, 0.0980764, 0.098897088, 0.099730176, 0.100571984, 0.10142, 0.10227472, 0.103136096, 0.104003152, 0.104874768, 0.10575, 0.106628624, 0.107510752,
-0.10839472, 0.109278208, 0.11016, 0.111040576, 0.11192, 0.112800704, 0.113686448, 0.11458, 0.11548144, 0.116390944, 0.117310608, 0.118243072, 0.11919,
-0.120151584, 0.121128368, 0.122118752, 0.123119936, 0.12413, 0.125149696, 0.126179952, 0.127218608, 0.128262464, 0.12931, 0.130362368, 0.131420128, 0.132487088,
-0.133568928, 0.13467, 0.135790352, 0.136929568, 0.138093104, 0.13928848, 0.14052, 0.141786896, 0.143089664, 0.144422192, 0.14577464, 0.14714, 0.14851984,
-0.149916432, 0.151321904, 0.152724976, 0.15412, 0.155511232, 0.156901984, 0.158301536, 0.159723488, 0.16118, 0.162679072, 0.164231456, 0.16584624, 0.167531904,
-0.1693, 0.17116432, 0.17313648, 0.17524416, 0.17752272, 0.18, 0.1826812, 0.18556712, 0.18869016, 0.19209328, 0.1958, 0.199801904, 0.20409464, 0.208644896,
-0.213400912, 0.21832, 0.223387008, 0.228581296, 0.233865024, 0.239194512, 0.24453, 0.249843376, 0.255106464, 0.260279792, 0.26532016, 0.27019, 0.274868992,
-0.279342832, 0.283567232, 0.287487792, 0.29107, 0.294310176, 0.297199472, 0.299766208, 0.302060704, 0.30412, 0.305941568, 0.307525648, 0.308905968, 0.310127328,
-0.31122, 0.31218816, 0.313040608, 0.313768016, 0.314349184, 0.31477, 0.315037568, 0.315157456, 0.315117184, 0.314898272, 0.31449, 0.313895504, 0.313115264,
-0.312160064, 0.311047264, 0.30979, 0.30838864, 0.306843744, 0.305168448, 0.303380112, 0.30149, 0.2994992, 0.297411008, 0.295218256, 0.292907584, 0.29047,
-0.287909344, 0.285229616, 0.282422208, 0.2794744, 0.27638, 0.273144352, 0.269771568, 0.266272784, 0.26266416, 0.25896, 0.255169008, 0.251302544, 0.24737232,
-0.243390176, 0.23937, 0.235326704, 0.23127392, 0.227237056, 0.223247152, 0.21933, 0.215496, 0.211753216, 0.208129072, 0.204657328, 0.20136, 0.1982404,
-0.195304832, 0.192539264, 0.189917376, 0.18742, 0.185049728, 0.182807584, 0.18067536, 0.178628336, 0.17665, 0.17473872, 0.17289008, 0.17110984, 0.1694104,
-0.1678, 0.16627488, 0.16483224, 0.1634784, 0.16222256, 0.16107, 0.160018688, 0.15906744, 0.158211792, 0.157444704, 0.15676, 0.1561544, 0.155623392, 0.155161424,
-0.154762496, 0.15442, 0.154127552, 0.153879184, 0.153663776, 0.153467808, 0.15328, 0.153095856, 0.152912096, 0.152716096, 0.152492096, 0.15223, 0.151929056,
-0.151587296, 0.151211136, 0.150812336, 0.1504, 0.149975056, 0.149539856, 0.149102496, 0.148673056, 0.14826, 0.147867888, 0.147501696, 0.147167584, 0.146871792,
-0.14662, 0.146415824, 0.146261584, 0.146165024, 0.146135824, 0.14618, 0.146297936, 0.146491104, 0.146753712, 0.14707576, 0.14745, 0.147877184, 0.148357712,
-0.1488852, 0.149451088, 0.15005, 0.150681808, 0.1513452, 0.152044592, 0.152787744, 0.15358, 0.154419536, 0.155304272, 0.156239648, 0.157233584, 0.15829,
-0.159405776, 0.160578528, 0.16180152, 0.163064912, 0.16436, 0.165681808, 0.167024, 0.168378592, 0.169736944, 0.17109, 0.172429504, 0.173747712, 0.17503096,
-0.176262928, 0.17743, 0.178526768, 0.1795496, 0.180482912, 0.181307104, 0.18201, 0.182591328, 0.183048992, 0.183393376, 0.18364248, 0.18381, 0.18389648,
-0.183903616, 0.183844432, 0.183735808, 0.18359, 0.183410464, 0.183202032, 0.18296544, 0.182698368, 0.1824, 0.18207384, 0.18172224, 0.18134592, 0.18094512,
-0.18052, 0.180071104, 0.17959888, 0.179102336, 0.178579792, 0.17803, 0.177452784, 0.176847696, 0.176214208, 0.17555184, 0.17486, 0.17414184, 0.173404448,
-0.172635136, 0.171813264, 0.17093, 0.169989424, 0.168984816, 0.167963168, 0.16699688, 0.16613, 0.165356848, 0.164686528, 0.164077648, 0.163460208, 0.16279,
-0.162076304, 0.161316608, 0.160526272, 0.159734576, 0.15896, 0.158196272, 0.157442032, 0.156695552, 0.155952432, 0.15521, 0.154470048, 0.153733552, 0.153000096,
-0.15226904, 0.15154, 0.150813008, 0.150087936, 0.149365264, 0.148645792, 0.14793, 0.147217792, 0.146509264, 0.145803936, 0.145101008, 0.1444, 0.143701008,
-0.143003936, 0.142309264, 0.141617792, 0.14093, 0.140245792, 0.139565264, 0.138887936, 0.138213008, 0.13754, 0.136869008, 0.136199936, 0.135533264, 0.134869792,
-0.13421, 0.13355376, 0.132901104, 0.132251648, 0.131604752, 0.13096, 0.130317488, 0.129677248, 0.129039248, 0.128403488, 0.12777, 0.127138736, 0.126509568,
-0.12588296, 0.125259632, 0.12464, 0.124024, 0.12341176, 0.12280264, 0.1221956, 0.12159, 0.120985968, 0.12038344, 0.119782832, 0.119184864, 0.11859, 0.118002608,
-0.117431632, 0.116863616, 0.11627608, 0.11566, 0.115019792, 0.114346336, 0.113698, 0.113164624, 0.1128,
-/* CES51 */ 0.0995, 0.100079952, 0.100928464, 0.101960816, 0.103035328, 0.10406, 0.10504952, 0.105997296, 0.106917312, 0.107844528, 0.1088, 0.109769648,
-0.110746112, 0.111730576, 0.11272488, 0.11373, 0.114745856, 0.115773136, 0.116808976, 0.117848816, 0.11889, 0.119933024, 0.120977216, 0.122026208, 0.12308592,
-0.12416, 0.125247552, 0.126348608, 0.127461344, 0.12858256, 0.12971, 0.130843808, 0.131983744, 0.1331288, 0.134277856, 0.13543, 0.136584992, 0.13774288,
-0.138902368, 0.140061696, 0.14122, 0.142377744, 0.143535088, 0.144694192, 0.145858336, 0.14703, 0.148209152, 0.149395552, 0.150592112, 0.151802832, 0.15303,
-0.154273216, 0.155532752, 0.156805328, 0.158085664, 0.15937, 0.160659088, 0.161953968, 0.163250688, 0.164543648, 0.16583, 0.16711112, 0.168387328, 0.169664896,
-0.170953424, 0.17226, 0.1735844, 0.174926096, 0.176292032, 0.177691568, 0.17913, 0.180606368, 0.182121232, 0.183666496, 0.1852292, 0.1868, 0.188380608,
-0.189973616, 0.191568384, 0.193149792, 0.19471, 0.196254064, 0.197785824, 0.199316544, 0.200862944, 0.20244, 0.20405752, 0.205728784, 0.207465168, 0.209277392,
-0.21118, 0.213190192, 0.215322448, 0.217611664, 0.22010248, 0.22283, 0.225800848, 0.229016304, 0.23251656, 0.236354736, 0.24056, 0.245122144, 0.25003584,
-0.255260416, 0.260732592, 0.2664, 0.272243296, 0.278235936, 0.284332176, 0.290479776, 0.29663, 0.302746624, 0.308793936, 0.314718688, 0.32046112, 0.32597,
-0.331219808, 0.336192688, 0.340829488, 0.345057248, 0.34883, 0.352143936, 0.354988448, 0.357402, 0.359452352, 0.36119, 0.362613856, 0.36372712, 0.364575584,
-0.365219168, 0.3657, 0.366027392, 0.366216144, 0.366261776, 0.366146528, 0.36586, 0.36541384, 0.364816976, 0.364061792, 0.363135168, 0.36203, 0.360750512,
-0.359298432, 0.357682592, 0.355916432, 0.35401, 0.351963264, 0.349775712, 0.34745768, 0.345023088, 0.34248, 0.339827344, 0.33706624, 0.334185696, 0.331167872,
-0.328, 0.324684864, 0.321226496, 0.317610208, 0.31381496, 0.30983, 0.305662512, 0.301317648, 0.296812544, 0.29217264, 0.28742, 0.282566848, 0.277629584,
-0.27262552, 0.267572176, 0.26249, 0.257400864, 0.2523248, 0.247298816, 0.242368032, 0.23757, 0.232919712, 0.228428416, 0.22413712, 0.220096704, 0.21634,
-0.212871056, 0.20969864, 0.206800144, 0.204134048, 0.20167, 0.199411312, 0.197358864, 0.195484416, 0.193749968, 0.19213, 0.19062152, 0.189217376, 0.187926032,
-0.186766128, 0.18575, 0.184871376, 0.184125152, 0.183521888, 0.183077264, 0.1828, 0.182685664, 0.182731168, 0.182928432, 0.183265136, 0.18373, 0.184314896,
-0.185008512, 0.185802688, 0.186690304, 0.18766, 0.188696128, 0.189785088, 0.190896608, 0.191992048, 0.19304, 0.194030496, 0.194956288, 0.19578376, 0.196470592,
-0.19699, 0.197340656, 0.1975176, 0.197539984, 0.197442048, 0.19725, 0.196966128, 0.196596544, 0.19616328, 0.195693616, 0.19521, 0.194723824, 0.19424752,
-0.193795616, 0.193382192, 0.19302, 0.192718144, 0.192483136, 0.192329648, 0.19227496, 0.19233, 0.192496576, 0.192778128, 0.19316408, 0.193635872, 0.19418,
-0.194797968, 0.19549064, 0.196245472, 0.197045504, 0.19788, 0.198748848, 0.199649792, 0.200590336, 0.20158392, 0.20264, 0.20375576, 0.204928016, 0.206167232,
-0.207488528, 0.2089, 0.210397344, 0.211977872, 0.21363072, 0.215339408, 0.21709, 0.218876112, 0.22068912, 0.222517488, 0.224348736, 0.22617, 0.227969744,
-0.229737088, 0.231453552, 0.233097296, 0.23465, 0.236104272, 0.237455392, 0.238681632, 0.239755472, 0.24066, 0.241395264, 0.241958592, 0.24236552, 0.242642608,
-0.24281, 0.242868608, 0.24282104, 0.242685712, 0.242486304, 0.24224, 0.241951728, 0.241628272, 0.241270496, 0.24087488, 0.24044, 0.239970624, 0.239469936,
-0.238938288, 0.23837512, 0.23778, 0.237153504, 0.236495968, 0.235805952, 0.235081216, 0.23432, 0.233521776, 0.232685712, 0.231810768, 0.230895904, 0.22994,
-0.22894736, 0.227927808, 0.226863376, 0.225724944, 0.2245, 0.223194208, 0.221798096, 0.220377584, 0.219034272, 0.21783, 0.216756768, 0.215827264, 0.21498376,
-0.214128576, 0.2132, 0.212210992, 0.21115808, 0.210062448, 0.208964576, 0.20789, 0.206830016, 0.205782768, 0.20474576, 0.203712752, 0.20268, 0.201649984,
-0.20062392, 0.199601856, 0.198583872, 0.19757, 0.196560224, 0.195554576, 0.194552848, 0.19355472, 0.19256, 0.19156872, 0.190580848, 0.189596576, 0.188616224,
-0.18764, 0.186667856, 0.185699776, 0.184735776, 0.183775856, 0.18282, 0.181868224, 0.180920576, 0.179976848, 0.17903672, 0.1781, 0.177166704, 0.176236768,
-0.175310432, 0.174388096, 0.17347, 0.172556096, 0.171646432, 0.170740768, 0.169838704, 0.16894, 0.168044704, 0.167152768, 0.166264432, 0.165380096, 0.1645,
-0.16362408, 0.162752352, 0.161884624, 0.161020576, 0.16016, 0.159302944, 0.158449424, 0.157599424, 0.156752944, 0.15591, 0.15507056, 0.154234544, 0.153402208,
-0.152573952, 0.15175, 0.150936528, 0.150146048, 0.149359488, 0.148545088, 0.14769, 0.146800368, 0.145863408, 0.144960688, 0.144217808, 0.14371,
-/* CES52 */ 0.0586, 0.05857696, 0.058543264, 0.058502368, 0.058460032, 0.05842, 0.05838192, 0.058346528, 0.058312576, 0.058277504, 0.05824, 0.058200336,
-0.058157296, 0.058115376, 0.058081696, 0.05806, 0.058049664, 0.058052896, 0.058059088, 0.05805144, 0.05802, 0.057966912, 0.057890768, 0.057802064, 0.05771808,
-0.05765, 0.057596144, 0.057557424, 0.057531584, 0.057513504, 0.0575, 0.057492688, 0.057492224, 0.0574984, 0.057511056, 0.05753, 0.057555168, 0.05758672,
-0.057623792, 0.057665104, 0.05771, 0.057758864, 0.057811872, 0.05787024, 0.057935808, 0.05801, 0.058093136, 0.05818568, 0.058288624, 0.058403088, 0.05853,
-0.058670032, 0.058823984, 0.058992016, 0.059173968, 0.05937, 0.059581136, 0.059808496, 0.060052336, 0.060312656, 0.06059, 0.060885312, 0.061198976, 0.06153456,
-0.061897104, 0.06229, 0.062711616, 0.06315904, 0.063637104, 0.064153248, 0.06471, 0.06530288, 0.065929424, 0.066574688, 0.067216992, 0.06784, 0.06844312,
-0.069027008, 0.069574656, 0.070063264, 0.07048, 0.070829344, 0.071114176, 0.071348448, 0.071554, 0.07175, 0.071947536, 0.072162288, 0.07240896, 0.072701952,
-0.07306, 0.073504512, 0.074054, 0.074750768, 0.075648496, 0.07679, 0.078185744, 0.079840528, 0.081802352, 0.084133536, 0.08687, 0.090004992, 0.093537952,
-0.097425552, 0.101598032, 0.106, 0.11061936, 0.115438352, 0.120406864, 0.125463936, 0.13056, 0.135669472, 0.140764064, 0.145817296, 0.150806688, 0.15571,
-0.160505744, 0.165177696, 0.169698288, 0.17403616, 0.17817, 0.18209192, 0.185790848, 0.189282096, 0.192595344, 0.19575, 0.198734512, 0.201535936, 0.20417536,
-0.206685264, 0.20908, 0.211345312, 0.21347376, 0.215421328, 0.217123216, 0.21853, 0.219638368, 0.220447728, 0.220905968, 0.220943808, 0.22052, 0.2196404,
-0.218303728, 0.216549856, 0.214444704, 0.21204, 0.209344608, 0.206372816, 0.203171504, 0.199798512, 0.1963, 0.192697952, 0.189017344, 0.185279616, 0.181501408,
-0.1777, 0.17389448, 0.170099216, 0.166336432, 0.162631008, 0.159, 0.155449872, 0.151989072, 0.148606912, 0.145282032, 0.142, 0.138766992, 0.135588672,
-0.132451472, 0.129335472, 0.12623, 0.123139968, 0.120067152, 0.117028336, 0.11404936, 0.11115, 0.108331872, 0.105596576, 0.10296384, 0.100459424, 0.0981,
-0.095886592, 0.09382272, 0.091899328, 0.090099296, 0.08841, 0.086832144, 0.085364688, 0.083997072, 0.082715616, 0.08151, 0.08037664, 0.079310912, 0.078310224,
-0.077373776, 0.0765, 0.075684928, 0.074925264, 0.07421896, 0.073564416, 0.07296, 0.07240352, 0.0718928, 0.07142712, 0.0710064, 0.07063, 0.070295648, 0.07000088,
-0.069745152, 0.069528544, 0.06935, 0.069206848, 0.069096912, 0.069015056, 0.06895456, 0.06891, 0.068879536, 0.068861456, 0.068850816, 0.068841696, 0.06883,
-0.068814608, 0.068794016, 0.068769504, 0.068744112, 0.06872, 0.068696352, 0.068672544, 0.068651216, 0.068636208, 0.06863, 0.068632496, 0.068644096, 0.068663776,
-0.068689536, 0.06872, 0.0687556, 0.068796656, 0.068842032, 0.068890128, 0.06894, 0.068991984, 0.069046352, 0.0691036, 0.069164528, 0.06923, 0.06930096,
-0.06937872, 0.069464, 0.06955736, 0.06966, 0.069773744, 0.06990008, 0.070043136, 0.070208352, 0.0704, 0.070618816, 0.070864976, 0.071143376, 0.071460496,
-0.07182, 0.072220912, 0.072662896, 0.07314136, 0.073649024, 0.07418, 0.074732368, 0.07530328, 0.075888192, 0.076482064, 0.07708, 0.077678032, 0.078272352,
-0.078856912, 0.079424752, 0.07997, 0.080489872, 0.080982272, 0.081440992, 0.081858512, 0.08223, 0.082554768, 0.082831472, 0.083063776, 0.08325832, 0.08342,
-0.08354856, 0.083644256, 0.083711312, 0.083755248, 0.08378, 0.083786464, 0.083775952, 0.08374856, 0.083703408, 0.08364, 0.083559296, 0.08346208, 0.083347424,
-0.083213728, 0.08306, 0.08288648, 0.082693104, 0.082480368, 0.082249152, 0.082, 0.081732784, 0.081447568, 0.081144032, 0.080821616, 0.08048, 0.080121312,
-0.079749552, 0.079359072, 0.078940432, 0.07849, 0.078010048, 0.077497232, 0.076976576, 0.0764864, 0.07605, 0.075664528, 0.075334976, 0.075038944, 0.074738592,
-0.07441, 0.074058144, 0.073681664, 0.073288704, 0.072894864, 0.07251, 0.072130736, 0.071756304, 0.071385952, 0.0710176, 0.07065, 0.070284096, 0.069920432,
-0.069558768, 0.069198704, 0.06884, 0.068482688, 0.068126688, 0.067772288, 0.067419968, 0.06707, 0.06672232, 0.066377008, 0.066033616, 0.065691424, 0.06535,
-0.065009408, 0.064669536, 0.064330864, 0.063994192, 0.06366, 0.063328192, 0.062998864, 0.062671536, 0.062345408, 0.06202, 0.061695408, 0.061371536, 0.061048864,
-0.060728192, 0.06041, 0.060094176, 0.059780784, 0.059469392, 0.05915928, 0.05885, 0.058541648, 0.058234192, 0.057927856, 0.05762304, 0.05732, 0.057018656,
-0.056718976, 0.056420976, 0.056124656, 0.05583, 0.055537024, 0.055245776, 0.054956048, 0.05466752, 0.05438, 0.054093504, 0.053807968, 0.053523632, 0.053240896,
-0.05296, 0.052682992, 0.052414112, 0.052146832, 0.051870272, 0.05158, 0.051278112, 0.050960272, 0.050654112, 0.050402192, 0.05023,
-/* CES53 */ 0.0779, 0.0779, 0.0779, 0.0779, 0.0779, 0.0779, 0.0779, 0.0779, 0.0779, 0.0779, 0.0779, 0.07790224, 0.0779112, 0.07792016, 0.07791792, 0.0779,
-0.07786944, 0.07782336, 0.07778848, 0.0778056, 0.0779, 0.078067696, 0.07831096, 0.078614224, 0.078950208, 0.0793, 0.079666464, 0.080050144, 0.080441104,
-0.080827024, 0.0812, 0.08155912, 0.081902304, 0.082235328, 0.082568672, 0.08291, 0.083258528, 0.083614048, 0.083983648, 0.084376848, 0.0848, 0.085253696,
-0.085739488, 0.08625528, 0.086796512, 0.08736, 0.087946784, 0.08855752, 0.089189216, 0.089837632, 0.0905, 0.091176544, 0.091867216, 0.092572368, 0.09329296,
-0.09403, 0.094784672, 0.095559008, 0.096353104, 0.0971664, 0.098, 0.098857312, 0.099741264, 0.100658816, 0.101619168, 0.10263, 0.103694368, 0.104815216,
-0.106000464, 0.107259712, 0.1086, 0.110023984, 0.111535024, 0.113132864, 0.114814944, 0.11658, 0.118430816, 0.120369904, 0.122395952, 0.12450632, 0.1267,
-0.128979104, 0.131345312, 0.1338016, 0.136351968, 0.139, 0.141748192, 0.14459968, 0.147557088, 0.150622816, 0.1538, 0.15709264, 0.160504448, 0.164041536,
-0.167711264, 0.17152, 0.175471136, 0.179567776, 0.183816576, 0.188225376, 0.1928, 0.197541616, 0.202450576, 0.207530736, 0.212786656, 0.21822, 0.223823744,
-0.229586496, 0.235514288, 0.24161896, 0.2479, 0.254337504, 0.260913968, 0.267591152, 0.274318016, 0.28105, 0.28776176, 0.294424032, 0.300996624, 0.307435776,
-0.3137, 0.31975592, 0.325571264, 0.331100848, 0.336294752, 0.34111, 0.345524288, 0.349521968, 0.353053728, 0.356059088, 0.3585, 0.360373856, 0.361673168,
-0.36242848, 0.362693632, 0.36251, 0.361881344, 0.36081672, 0.359352656, 0.357534912, 0.3554, 0.352965392, 0.350251696, 0.34727488, 0.344046464, 0.34058,
-0.336894944, 0.33300888, 0.328937616, 0.324696432, 0.3203, 0.315762688, 0.311098656, 0.306319744, 0.301436512, 0.29646, 0.291402656, 0.286276224, 0.281092512,
-0.27586336, 0.2706, 0.265312784, 0.260011952, 0.25470696, 0.249406688, 0.24412, 0.238855584, 0.23362128, 0.228427056, 0.223283632, 0.2182, 0.21318232,
-0.208236896, 0.203367872, 0.198577888, 0.19387, 0.18924832, 0.184715712, 0.180276864, 0.175937136, 0.1717, 0.167566224, 0.163536944, 0.159609344, 0.155778304,
-0.15204, 0.148394272, 0.144840304, 0.141375376, 0.137996208, 0.1347, 0.131485104, 0.128349776, 0.125291888, 0.12230928, 0.1194, 0.116562784, 0.113796768,
-0.111099472, 0.108467856, 0.1059, 0.103395744, 0.100954672, 0.09857832, 0.096269408, 0.09403, 0.09186016, 0.08976016, 0.08773192, 0.08577792, 0.0839,
-0.082098624, 0.08037432, 0.078727776, 0.077159552, 0.07567, 0.074258848, 0.072925296, 0.071670224, 0.070495072, 0.0694, 0.068383248, 0.067443344, 0.06657632,
-0.065776736, 0.06504, 0.064363968, 0.06374616, 0.063182512, 0.062668464, 0.0622, 0.06177424, 0.061388192, 0.061038944, 0.060723776, 0.06044, 0.06018496,
-0.059956224, 0.059751008, 0.059566432, 0.0594, 0.05924976, 0.059113728, 0.058990656, 0.058879744, 0.05878, 0.058689952, 0.058608416, 0.05853392, 0.058464864,
-0.0584, 0.05833864, 0.05828008, 0.05822408, 0.05817072, 0.05812, 0.058071872, 0.05802672, 0.057983728, 0.057941616, 0.0579, 0.057859808, 0.057821648,
-0.057788208, 0.057763328, 0.05775, 0.057748736, 0.057759968, 0.05778664, 0.057832512, 0.0579, 0.057989328, 0.05810112, 0.058233712, 0.058384064, 0.05855,
-0.058731712, 0.058929072, 0.059140672, 0.059364752, 0.0596, 0.05984592, 0.060101872, 0.060367744, 0.060643776, 0.06093, 0.061225664, 0.061529904, 0.061843184,
-0.062166464, 0.0625, 0.062842864, 0.063194384, 0.063552704, 0.063915264, 0.06428, 0.064646496, 0.065014544, 0.065381552, 0.06574408, 0.0661, 0.066449168,
-0.066790832, 0.067127376, 0.06746288, 0.0678, 0.068137408, 0.068473536, 0.068810704, 0.069152432, 0.0695, 0.069851024, 0.070203584, 0.070552384, 0.070889984,
-0.07121, 0.071512224, 0.071798784, 0.072054944, 0.072259344, 0.0724, 0.072479024, 0.072493424, 0.072464384, 0.072425824, 0.0724, 0.072384768, 0.072382864,
-0.07239048, 0.072398096, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724,
-0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724,
-0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724,
-0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724,
-/* CES54 */ 0.4719, 0.4719, 0.4719, 0.4719, 0.4719, 0.4719, 0.4719, 0.4719, 0.4719, 0.4719, 0.4719, 0.471927088, 0.47203544, 0.472143792, 0.472116704, 0.4719,
-0.471530112, 0.470971552, 0.470548432, 0.470755792, 0.4719, 0.473931056, 0.476874112, 0.480541088, 0.484602944, 0.48883, 0.493249568, 0.497857568, 0.502537888,
-0.507150768, 0.5116, 0.515859152, 0.519890928, 0.523731744, 0.52746288, 0.53114, 0.534741696, 0.538254064, 0.541725712, 0.54522616, 0.5488, 0.55244552,
-0.556169712, 0.559951504, 0.563750976, 0.56754, 0.57132576, 0.575113984, 0.578878128, 0.582580912, 0.5862, 0.58973904, 0.593196848, 0.596593616, 0.599962944,
-0.60333, 0.606694752, 0.610058176, 0.61344424, 0.616884544, 0.6204, 0.623992336, 0.62766608, 0.631413584, 0.635218848, 0.63907, 0.642967456, 0.646908944,
-0.650885472, 0.65488584, 0.6589, 0.66292152, 0.666943552, 0.670956144, 0.674948256, 0.67891, 0.682835072, 0.686718064, 0.690547936, 0.694311728, 0.698,
-0.70160888, 0.705133536, 0.708575472, 0.711939968, 0.71523, 0.718441856, 0.721572432, 0.724623648, 0.727599024, 0.7305, 0.733324368, 0.736070688, 0.738734448,
-0.741309248, 0.74379, 0.74617568, 0.748465488, 0.750653856, 0.752733584, 0.7547, 0.756552832, 0.758291216, 0.75991744, 0.761435904, 0.76285, 0.764160096,
-0.76536728, 0.766474224, 0.767484128, 0.7684, 0.769224288, 0.769959744, 0.77060904, 0.771174816, 0.77166, 0.77206848, 0.77240472, 0.77267088, 0.77286832, 0.773,
-0.773071024, 0.77308576, 0.773053216, 0.772984832, 0.77289, 0.772772, 0.772633376, 0.772484192, 0.772337008, 0.7722, 0.772074192, 0.771961712, 0.771857632,
-0.771752672, 0.77164, 0.771520128, 0.771392752, 0.771251856, 0.77108952, 0.7709, 0.770681712, 0.770432336, 0.7701516, 0.769840704, 0.7695, 0.769127296,
-0.76872064, 0.768280144, 0.767806608, 0.7673, 0.766758656, 0.766181104, 0.765565392, 0.76490912, 0.76421, 0.763465952, 0.762674432, 0.761833872, 0.760943072,
-0.76, 0.759001648, 0.757945472, 0.756825936, 0.755636, 0.75437, 0.75302656, 0.751605136, 0.750098912, 0.748498928, 0.7468, 0.745003008, 0.743107792,
-0.741120736, 0.73905216, 0.73691, 0.734695104, 0.732408816, 0.730058448, 0.72765328, 0.7252, 0.722700208, 0.720155888, 0.717567968, 0.714936128, 0.71226,
-0.709538912, 0.706770448, 0.703956304, 0.7010996, 0.6982, 0.69525232, 0.692252384, 0.689187808, 0.686041632, 0.6828, 0.679458272, 0.676011808, 0.672446944,
-0.66874704, 0.6649, 0.660902768, 0.656752304, 0.65244568, 0.647981536, 0.64336, 0.6385856, 0.63366736, 0.62860104, 0.623378, 0.618, 0.612483792, 0.60684336,
-0.601115648, 0.595350656, 0.58959, 0.583850112, 0.578147968, 0.572524704, 0.56702952, 0.5617, 0.556552624, 0.551606384, 0.546868864, 0.542339264, 0.53802,
-0.53392304, 0.530055984, 0.526425808, 0.523039312, 0.5199, 0.517007616, 0.514361888, 0.51195408, 0.509770592, 0.5078, 0.506037568, 0.5044768, 0.503107712,
-0.501919104, 0.5009, 0.500040976, 0.499332432, 0.498761808, 0.498315264, 0.49798, 0.497746368, 0.497605088, 0.497542928, 0.497545328, 0.4976, 0.497699168,
-0.497835248, 0.498000368, 0.498187328, 0.49839, 0.49860528, 0.498832608, 0.499063696, 0.499287744, 0.4995, 0.49970464, 0.499904496, 0.500114592, 0.500357088,
-0.50065, 0.500998688, 0.501409392, 0.501899696, 0.50249104, 0.5032, 0.50403336, 0.504998576, 0.506101952, 0.507347808, 0.50874, 0.510280992, 0.511970032,
-0.513814192, 0.515823152, 0.518, 0.520338304, 0.522833152, 0.5254656, 0.528208608, 0.53104, 0.533952256, 0.53693696, 0.539972704, 0.543033728, 0.5461,
-0.549162496, 0.552211104, 0.555239232, 0.55824352, 0.56122, 0.564162848, 0.567068992, 0.569931776, 0.57274336, 0.5755, 0.578202096, 0.580847856, 0.583452016,
-0.586037536, 0.58862, 0.591192704, 0.593745376, 0.596297488, 0.59887848, 0.6015, 0.604144528, 0.606798688, 0.609421568, 0.611955008, 0.61435, 0.616605856,
-0.618738528, 0.62064064, 0.622156752, 0.6232, 0.623786, 0.62389288, 0.62367776, 0.62339184, 0.6232, 0.62308672, 0.62307256, 0.6231292, 0.62318584, 0.6232,
-0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232,
-0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232,
-0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232,
-0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232,
-/* CES55 */ 0.30073, 0.30409872, 0.309027968, 0.314971856, 0.321027424, 0.32661, 0.331808304, 0.336574016, 0.341022448, 0.34541728, 0.34992, 0.354440928,
-0.358939888, 0.363418048, 0.367871888, 0.3723, 0.376707584, 0.381098288, 0.385471712, 0.389826016, 0.39416, 0.398474672, 0.402770752, 0.407046752, 0.411300352,
-0.41553, 0.41973664, 0.423921792, 0.428082944, 0.432216176, 0.43632, 0.440396608, 0.444447264, 0.44847864, 0.452500416, 0.45652, 0.460538032, 0.46455472,
-0.468577808, 0.472617536, 0.47668, 0.48076472, 0.484872608, 0.488996656, 0.493125264, 0.49725, 0.501371968, 0.505492496, 0.509602864, 0.513690992, 0.51775,
-0.521781744, 0.525786544, 0.529772784, 0.533753824, 0.53774, 0.541732784, 0.545734384, 0.549753984, 0.553803024, 0.55789, 0.562017632, 0.566189104, 0.570406336,
-0.574669808, 0.57898, 0.583337536, 0.587741456, 0.592193536, 0.596696416, 0.60125, 0.605850256, 0.610493536, 0.615170816, 0.619869856, 0.62458, 0.629295184,
-0.634008176, 0.638709808, 0.64339032, 0.64804, 0.652650192, 0.657212528, 0.661715184, 0.66614488, 0.67049, 0.674743968, 0.678901504, 0.6829496, 0.686872656,
-0.69066, 0.694308912, 0.6978156, 0.701184128, 0.704423456, 0.70754, 0.710532304, 0.713399968, 0.716148832, 0.718786896, 0.72132, 0.723749568, 0.726077792,
-0.728305616, 0.73043288, 0.73246, 0.734388768, 0.736220336, 0.737956464, 0.739599232, 0.74115, 0.742609184, 0.743977504, 0.745253584, 0.746434944, 0.74752,
-0.748509552, 0.749404384, 0.750203536, 0.750905568, 0.75151, 0.752017696, 0.752429136, 0.752747296, 0.752976496, 0.75312, 0.753177936, 0.753150176, 0.753040176,
-0.752852576, 0.75259, 0.75225184, 0.751838096, 0.751345152, 0.750767248, 0.7501, 0.749343408, 0.748497632, 0.747557616, 0.74651648, 0.74537, 0.744118416,
-0.742761056, 0.741301616, 0.739746416, 0.7381, 0.7363616, 0.734530416, 0.732610992, 0.730609648, 0.72853, 0.726371248, 0.724133552, 0.721812176, 0.71939952,
-0.71689, 0.71428392, 0.711581616, 0.708777792, 0.705865328, 0.70284, 0.699702816, 0.696454352, 0.693097728, 0.689638064, 0.68608, 0.682426784, 0.678683248,
-0.674852352, 0.670936416, 0.66694, 0.662870512, 0.658734752, 0.654545632, 0.650319392, 0.64607, 0.641804736, 0.637530912, 0.633262208, 0.629014384, 0.6248,
-0.62062568, 0.616498448, 0.612423136, 0.608402624, 0.60444, 0.600538816, 0.596700496, 0.592930576, 0.589236016, 0.58562, 0.582080112, 0.578614576, 0.57521448,
-0.571866624, 0.56856, 0.565290256, 0.56205192, 0.558834944, 0.555627728, 0.55242, 0.549205472, 0.545978384, 0.542727936, 0.539441648, 0.53611, 0.532732656,
-0.529312896, 0.525836976, 0.522285376, 0.51865, 0.51494008, 0.511161536, 0.507343472, 0.503528368, 0.49975, 0.496016304, 0.492335872, 0.48874128, 0.485273008,
-0.48196, 0.478810656, 0.47583616, 0.473034544, 0.470394768, 0.46791, 0.465586176, 0.463426064, 0.461425712, 0.45957888, 0.45788, 0.45632632, 0.454914352,
-0.453637664, 0.452488576, 0.45146, 0.450546864, 0.449743904, 0.449043744, 0.448438224, 0.44792, 0.4474836, 0.447123504, 0.446833568, 0.446607632, 0.44644,
-0.446326544, 0.446264128, 0.446245712, 0.446262896, 0.44631, 0.44638584, 0.446488832, 0.446621024, 0.446786896, 0.44699, 0.447230896, 0.447511184, 0.447833792,
-0.44820216, 0.44862, 0.449090512, 0.449616672, 0.450205072, 0.450863952, 0.4516, 0.452414992, 0.453309632, 0.454292192, 0.455373552, 0.45656, 0.457850256,
-0.459244112, 0.460734368, 0.462309424, 0.46396, 0.465683504, 0.467475968, 0.469330032, 0.471237296, 0.47319, 0.475182208, 0.477207792, 0.479259376, 0.4813292,
-0.48341, 0.485495952, 0.487581616, 0.48965944, 0.491721184, 0.49376, 0.495771152, 0.49774952, 0.499692768, 0.501600176, 0.50347, 0.505297632, 0.507078768,
-0.508810944, 0.51049224, 0.51212, 0.513690608, 0.515201024, 0.51664552, 0.518017056, 0.51931, 0.520523072, 0.52165608, 0.522702048, 0.523651696, 0.5245,
-0.52524888, 0.525899328, 0.526458896, 0.526939264, 0.52735, 0.527694304, 0.527976496, 0.528205088, 0.52839, 0.52854, 0.528661392, 0.528761088, 0.528845264,
-0.52891952, 0.52899, 0.529064352, 0.529150384, 0.529254336, 0.529381968, 0.52954, 0.52973632, 0.529978256, 0.530276512, 0.530643488, 0.53109, 0.531622336,
-0.532246192, 0.532972528, 0.533813984, 0.53478, 0.535875472, 0.537106608, 0.538471424, 0.53996352, 0.54158, 0.543328704, 0.545218704, 0.547245424, 0.549399664,
-0.55168, 0.554093616, 0.556641424, 0.559357712, 0.5622928, 0.56548, 0.56890152, 0.572528112, 0.576404704, 0.580600976, 0.58514, 0.58997616, 0.595080784,
-0.600317728, 0.605491312, 0.61045, 0.615174656, 0.619654128, 0.62372896, 0.627190272, 0.62991, 0.631893456, 0.63312304, 0.633711744, 0.633852448, 0.63369,
-0.6332252, 0.632468304, 0.631554608, 0.630661072, 0.62991, 0.629323344, 0.628938288, 0.628734672, 0.628652016, 0.62865, 0.628748784, 0.628956432, 0.62924472,
-0.629573328, 0.62991, 0.63025272, 0.63060552, 0.630918, 0.6311196, 0.63117, 0.631071216, 0.63080712, 0.630452304, 0.630127728, 0.62991,
-/* CES56 */ 0.2315, 0.2315, 0.2315, 0.2315, 0.2315, 0.2315, 0.2315, 0.2315, 0.2315, 0.2315, 0.2315, 0.231537648, 0.23168824, 0.231838832, 0.231801184, 0.2315,
-0.230978448, 0.230172272, 0.229554336, 0.22985, 0.2315, 0.234414032, 0.238615296, 0.24378536, 0.249384304, 0.25503, 0.260754432, 0.26654856, 0.272189808,
-0.277404176, 0.28201, 0.285974768, 0.289242608, 0.291910928, 0.294170848, 0.29616, 0.297855328, 0.299249728, 0.300465328, 0.301667408, 0.30297, 0.30439,
-0.305960448, 0.307666016, 0.309456384, 0.3113, 0.313221472, 0.315237056, 0.31732384, 0.319445904, 0.32158, 0.323729776, 0.32589496, 0.328078464, 0.330287968,
-0.33253, 0.334807536, 0.337127104, 0.339491872, 0.34190392, 0.34437, 0.346901616, 0.349508112, 0.352216928, 0.355064624, 0.35808, 0.361268336, 0.364631648,
-0.36820248, 0.372023632, 0.37612, 0.380488688, 0.3851328, 0.390019392, 0.395094864, 0.40032, 0.405698656, 0.411235712, 0.416891088, 0.422609744, 0.42836,
-0.43414736, 0.439968528, 0.445866416, 0.451910304, 0.45815, 0.464576832, 0.471178656, 0.47800888, 0.485142784, 0.49262, 0.500420992, 0.50853608, 0.516899488,
-0.525410816, 0.53399, 0.542618112, 0.551271408, 0.559874384, 0.56833424, 0.57658, 0.584581984, 0.592304864, 0.599731664, 0.606859584, 0.61368, 0.620166928,
-0.626301024, 0.6320668, 0.637450016, 0.64244, 0.647029488, 0.65121184, 0.654990352, 0.658374064, 0.66137, 0.6639792, 0.666206032, 0.668054064, 0.669526576,
-0.67063, 0.671374352, 0.671769104, 0.671832976, 0.671589728, 0.67106, 0.67025488, 0.669185696, 0.667873232, 0.666341488, 0.66461, 0.662690016, 0.660593712,
-0.658329488, 0.655902784, 0.65332, 0.650590416, 0.647721488, 0.64472144, 0.641598752, 0.63836, 0.635009408, 0.63155144, 0.627985712, 0.624309104, 0.62052,
-0.616621136, 0.612614512, 0.608499568, 0.604274944, 0.59994, 0.595495888, 0.590943728, 0.586283648, 0.581515568, 0.57664, 0.5716592, 0.566576208, 0.561390816,
-0.556101744, 0.55071, 0.54522008, 0.539635936, 0.533965872, 0.528220768, 0.52241, 0.516538544, 0.510611872, 0.50463864, 0.498628528, 0.49259, 0.48652896,
-0.48045144, 0.4743636, 0.46827128, 0.46218, 0.456094272, 0.4500176, 0.443956608, 0.437918976, 0.43191, 0.425931424, 0.419985568, 0.414069952, 0.408179296,
-0.40231, 0.39646336, 0.390640192, 0.384837664, 0.379051776, 0.37328, 0.367522592, 0.361779424, 0.356051616, 0.350341248, 0.34465, 0.338978528, 0.333328096,
-0.327699424, 0.322092992, 0.31651, 0.310952928, 0.305424384, 0.2999272, 0.294464576, 0.28904, 0.283658064, 0.27832456, 0.273041296, 0.267808672, 0.26263,
-0.257512816, 0.252463696, 0.247495936, 0.242626576, 0.23787, 0.233232464, 0.228719776, 0.224347088, 0.22013272, 0.21609, 0.212223328, 0.208537968, 0.205034288,
-0.201709008, 0.19856, 0.19558848, 0.192793248, 0.190174816, 0.187734304, 0.18547, 0.183376608, 0.181449376, 0.179676224, 0.178040992, 0.17653, 0.175137024,
-0.173855424, 0.172671904, 0.171570944, 0.17054, 0.169572048, 0.168659344, 0.16779688, 0.166981696, 0.16621, 0.165475696, 0.16477384, 0.164099104, 0.163445968,
-0.16281, 0.1621884, 0.161578464, 0.160978928, 0.160389552, 0.15981, 0.159239712, 0.158679248, 0.158127024, 0.15758072, 0.15704, 0.156507264, 0.155984384,
-0.155477984, 0.154997344, 0.15455, 0.154138336, 0.153764864, 0.153436832, 0.15316304, 0.15295, 0.15280024, 0.152716912, 0.152700144, 0.152748096, 0.15286,
-0.153038384, 0.153285424, 0.153600544, 0.153982224, 0.15443, 0.154944768, 0.155526384, 0.15617904, 0.156908976, 0.15772, 0.158608816, 0.1595704, 0.160609904,
-0.161735808, 0.16295, 0.164244832, 0.165615024, 0.167039616, 0.168489008, 0.16994, 0.171388848, 0.172833216, 0.174247984, 0.175600352, 0.17687, 0.178056592,
-0.179155584, 0.180185776, 0.181179568, 0.18216, 0.183122224, 0.184061056, 0.184999488, 0.18596984, 0.18699, 0.188053088, 0.189155968, 0.190275728, 0.191376688,
-0.19243, 0.193437104, 0.194406208, 0.195282112, 0.195985216, 0.19647, 0.19674296, 0.196792432, 0.196691504, 0.196558256, 0.19647, 0.196418288, 0.196411824,
-0.19643768, 0.196463536, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647,
-0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647,
-0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647,
-0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647,
-0.19647, 0.19647, 0.19647, 0.19647, 0.19647,
-/* CES57 */ 0.1347, 0.1347, 0.1347, 0.1347, 0.1347, 0.1347, 0.1347, 0.1347, 0.1347, 0.1347, 0.1347, 0.134708224, 0.13474112, 0.134774016, 0.134765792, 0.1347,
-0.134586496, 0.134412096, 0.134278816, 0.134342976, 0.1347, 0.1353316, 0.136244016, 0.137370192, 0.138595648, 0.13984, 0.141112352, 0.142413472, 0.143696912,
-0.144904752, 0.146, 0.146979216, 0.147833632, 0.148590048, 0.149296864, 0.14999, 0.150668064, 0.151333248, 0.152017632, 0.152763216, 0.1536, 0.154535104,
-0.155579072, 0.15673224, 0.157987168, 0.15934, 0.160797856, 0.1623652, 0.164040384, 0.165819648, 0.1677, 0.169681488, 0.171763744, 0.17394272, 0.176212896,
-0.17857, 0.181013536, 0.18354352, 0.186155104, 0.188841808, 0.1916, 0.194430768, 0.197334544, 0.200316, 0.203382496, 0.20654, 0.209790208, 0.21313544,
-0.216581232, 0.220134224, 0.2238, 0.227581424, 0.231481232, 0.23550424, 0.239655808, 0.24394, 0.248357056, 0.2529056, 0.257591184, 0.262421648, 0.2674,
-0.27252168, 0.277783104, 0.283172448, 0.288672832, 0.29427, 0.299957344, 0.305726688, 0.311565792, 0.317461136, 0.3234, 0.32937152, 0.335364192, 0.341367024,
-0.347369376, 0.35336, 0.359325632, 0.365252144, 0.371129536, 0.376949248, 0.3827, 0.388366736, 0.393935616, 0.399385376, 0.404690816, 0.40983, 0.41479072,
-0.419561856, 0.424120512, 0.428439888, 0.4325, 0.436292512, 0.439807872, 0.443044912, 0.446008192, 0.4487, 0.451115584, 0.453252752, 0.45511168, 0.456693408,
-0.458, 0.459034016, 0.45979832, 0.460299824, 0.460547648, 0.46055, 0.460312224, 0.459840544, 0.459142544, 0.458226304, 0.4571, 0.455771232, 0.454247504,
-0.452538816, 0.450656288, 0.44861, 0.446406816, 0.444052976, 0.441559376, 0.438938496, 0.4362, 0.43334872, 0.430389936, 0.427325632, 0.424155488, 0.42088,
-0.417502016, 0.414022992, 0.410445008, 0.406770384, 0.403, 0.399133456, 0.395171088, 0.39110696, 0.386932032, 0.38264, 0.378232672, 0.37371296, 0.369073648,
-0.364304176, 0.3594, 0.354366512, 0.349207808, 0.343936864, 0.33857232, 0.33313, 0.32761728, 0.322043264, 0.316422048, 0.310769392, 0.3051, 0.299425344,
-0.293756928, 0.288109552, 0.282499136, 0.27694, 0.271441504, 0.266011312, 0.26066576, 0.255424128, 0.2503, 0.245297584, 0.24042208, 0.235670656, 0.231035232,
-0.22651, 0.222096192, 0.217793056, 0.21359632, 0.209500464, 0.2055, 0.201590432, 0.19776672, 0.194022128, 0.190349056, 0.18674, 0.183188032, 0.179685648,
-0.176225344, 0.17279952, 0.1694, 0.166019536, 0.162652384, 0.159283552, 0.15589408, 0.15247, 0.149012448, 0.145526912, 0.141994816, 0.13838952, 0.1347,
-0.130938736, 0.127113296, 0.123264256, 0.119450976, 0.11572, 0.112079584, 0.108537616, 0.105140688, 0.10194824, 0.099, 0.09630152, 0.093863248, 0.091670096,
-0.089690144, 0.0879, 0.086301984, 0.084893696, 0.083656208, 0.08256504, 0.0816, 0.080751376, 0.080008128, 0.07935616, 0.078781152, 0.07827, 0.07781312,
-0.07740272, 0.07702416, 0.07666056, 0.0763, 0.075937888, 0.07556832, 0.075195792, 0.074830784, 0.07448, 0.07413872, 0.073802512, 0.073477984, 0.073175456,
-0.0729, 0.072647376, 0.072414784, 0.072192992, 0.07196848, 0.07173, 0.071473184, 0.071192672, 0.07087808, 0.070517328, 0.0701, 0.069620144, 0.06907224,
-0.068446496, 0.067731872, 0.06692, 0.066010368, 0.065005616, 0.063894544, 0.062661392, 0.0613, 0.05981864, 0.058223024, 0.056538288, 0.054800832, 0.05304,
-0.051263936, 0.049481568, 0.04772152, 0.046018992, 0.0444, 0.042873728, 0.04145144, 0.040135632, 0.038922304, 0.03781, 0.036805088, 0.035910912, 0.035128656,
-0.03445872, 0.0339, 0.033452464, 0.033118016, 0.032882448, 0.03272432, 0.03263, 0.032609472, 0.032678288, 0.032816864, 0.03299384, 0.0332, 0.03345568,
-0.033770064, 0.034218848, 0.034912192, 0.03593, 0.03726128, 0.038881808, 0.040884976, 0.043403424, 0.0465, 0.050124176, 0.054242896, 0.058709536, 0.063307616,
-0.06786, 0.072354528, 0.076812976, 0.080930384, 0.084277232, 0.0866, 0.087915744, 0.088151424, 0.087658464, 0.087015584, 0.0866, 0.086360128, 0.086330144,
-0.08645008, 0.086570016, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866,
-0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866,
-0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866,
-0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866,
-/* CES58 */ 0.10606, 0.106598352, 0.107385568, 0.108348384, 0.10936336, 0.11035, 0.111320944, 0.112270224, 0.113207344, 0.114158864, 0.11514, 0.116137696,
-0.117144704, 0.118158912, 0.119178, 0.1202, 0.121224112, 0.122250032, 0.123275072, 0.124295712, 0.12531, 0.126318512, 0.127321472, 0.128321632, 0.129323312,
-0.13033, 0.131342624, 0.132362432, 0.13339272, 0.134437488, 0.1355, 0.136581968, 0.13768528, 0.138811712, 0.139962784, 0.14114, 0.142344768, 0.143578112,
-0.144842016, 0.1461388, 0.14747, 0.148836064, 0.150237696, 0.151673648, 0.1531416, 0.15464, 0.156169712, 0.157731808, 0.159324304, 0.16094416, 0.16259,
-0.164263584, 0.165966544, 0.167701344, 0.169471344, 0.17128, 0.173131088, 0.175029664, 0.17697784, 0.178976736, 0.18103, 0.183144704, 0.18532712, 0.187590016,
-0.189949712, 0.19242, 0.195007072, 0.197716816, 0.20056336, 0.203563584, 0.20673, 0.210066848, 0.21357864, 0.217267952, 0.221135104, 0.22518, 0.229401504,
-0.233795232, 0.23836392, 0.243112608, 0.24804, 0.253135808, 0.25839112, 0.263783232, 0.269281504, 0.27486, 0.2805072, 0.286210832, 0.291945104, 0.297679616,
-0.30339, 0.309062576, 0.314681584, 0.320239552, 0.32573424, 0.33116, 0.336501168, 0.341742432, 0.346877376, 0.35190248, 0.35681, 0.36158568, 0.366217296,
-0.370682752, 0.374954608, 0.37901, 0.382839776, 0.386436592, 0.389775808, 0.392827024, 0.39557, 0.398001408, 0.400115728, 0.401922048, 0.403438848, 0.40468,
-0.405645744, 0.406339488, 0.406771552, 0.406954496, 0.4069, 0.406616448, 0.406112672, 0.405401216, 0.40449608, 0.40341, 0.402152112, 0.400731216, 0.39916,
-0.397452384, 0.39562, 0.393670672, 0.39161264, 0.389451008, 0.387188816, 0.38483, 0.382380976, 0.379847088, 0.37723376, 0.374546512, 0.37179, 0.36896776,
-0.36608336, 0.36313848, 0.36013368, 0.35707, 0.353949712, 0.3507744, 0.347546208, 0.344267536, 0.34094, 0.337564192, 0.334140928, 0.330668944, 0.32714584,
-0.32357, 0.319942272, 0.316263664, 0.312532176, 0.308744768, 0.3049, 0.300999392, 0.297044256, 0.29303704, 0.288981184, 0.28488, 0.28073624, 0.27655344,
-0.27233448, 0.26808208, 0.2638, 0.259492928, 0.25516504, 0.250824992, 0.246483664, 0.24215, 0.23782704, 0.233516912, 0.229229664, 0.224978016, 0.22077,
-0.21660616, 0.212488384, 0.208410208, 0.204360192, 0.20033, 0.196320624, 0.192332688, 0.188358192, 0.184386256, 0.18041, 0.176429744, 0.172444752, 0.1684588,
-0.164478768, 0.16051, 0.15655336, 0.1526108, 0.14868632, 0.14478456, 0.14091, 0.137066368, 0.1332576, 0.129488512, 0.125764304, 0.12209, 0.11847032,
-0.114910352, 0.111414304, 0.107986016, 0.10463, 0.101351504, 0.098155264, 0.095048864, 0.092041264, 0.08914, 0.086348288, 0.083668384, 0.08110888, 0.078680496,
-0.07639, 0.074237664, 0.07222456, 0.070345936, 0.068593312, 0.06696, 0.065444832, 0.064045536, 0.06275624, 0.061569824, 0.06048, 0.059482592, 0.05857304,
-0.057746208, 0.056996896, 0.05632, 0.055710832, 0.055164928, 0.054676704, 0.05424016, 0.05385, 0.053502208, 0.053192704, 0.05291792, 0.052674736, 0.05246,
-0.052270512, 0.0521036, 0.051955488, 0.051822016, 0.0517, 0.051587792, 0.051483648, 0.051387264, 0.05129928, 0.05122, 0.051148912, 0.051086224, 0.051031136,
-0.050982448, 0.05094, 0.050905072, 0.050878656, 0.05086424, 0.050866704, 0.05089, 0.050935232, 0.05100344, 0.051098768, 0.051226416, 0.05139, 0.051590416,
-0.051829008, 0.05210488, 0.052415712, 0.05276, 0.053138352, 0.0535508, 0.053997088, 0.054476976, 0.05499, 0.055535408, 0.056112448, 0.056719888, 0.057356208,
-0.05802, 0.058709952, 0.059424368, 0.060162704, 0.06092488, 0.06171, 0.062515904, 0.063340704, 0.064180864, 0.065031904, 0.06589, 0.066753328, 0.067620144,
-0.06848648, 0.069347616, 0.0702, 0.071042, 0.0718716, 0.0726888, 0.0734948, 0.07429, 0.075072352, 0.07583976, 0.076592768, 0.077332816, 0.07806, 0.078772192,
-0.079467728, 0.080142624, 0.08079152, 0.08141, 0.081996544, 0.082549744, 0.083064944, 0.083536384, 0.08396, 0.084334864, 0.084659824, 0.084934944, 0.085161344,
-0.08534, 0.08547136, 0.085556784, 0.085596528, 0.085590512, 0.08554, 0.085448112, 0.085317808, 0.085154864, 0.08496672, 0.08476, 0.084538832, 0.084307824,
-0.084073216, 0.083841808, 0.08362, 0.083407984, 0.083200896, 0.083020208, 0.08289784, 0.08285, 0.082874144, 0.082980368, 0.083115472, 0.083195696, 0.08317,
-0.083046352, 0.082815792, 0.082522512, 0.082241472, 0.08202, 0.081847984, 0.081727952, 0.08164368, 0.081564208, 0.08147, 0.081367696, 0.08125872, 0.081144944,
-0.081031088, 0.08092, 0.080809776, 0.080699424, 0.080589152, 0.08047928, 0.08037, 0.080261296, 0.080153232, 0.080045568, 0.079937904, 0.07983, 0.079721888,
-0.079613488, 0.079505088, 0.079397168, 0.07929, 0.07918352, 0.079077808, 0.078972416, 0.078866624, 0.07876, 0.07865264, 0.078544496, 0.078435952, 0.078327648,
-0.07822, 0.07811376, 0.078010592, 0.077907984, 0.077801696, 0.07769, 0.07757376, 0.077451344, 0.077333408, 0.077236352, 0.07717,
-/* CES59 */ 0.4345, 0.4345, 0.4345, 0.4345, 0.4345, 0.4345, 0.4345, 0.4345, 0.4345, 0.4345, 0.4345, 0.434626512, 0.43513256, 0.435638608, 0.435512096, 0.4345,
-0.432747328, 0.430038048, 0.427961328, 0.428954928, 0.4345, 0.444290896, 0.458401408, 0.4757672, 0.494583552, 0.51357, 0.53282016, 0.55228416, 0.5712224,
-0.5887316, 0.6042, 0.617490592, 0.62839488, 0.637172208, 0.644371136, 0.65038, 0.655106656, 0.658517808, 0.66094256, 0.662830752, 0.6645, 0.66600744,
-0.66745328, 0.66883968, 0.67009072, 0.67117, 0.672159872, 0.67312032, 0.674036928, 0.674870416, 0.6756, 0.67624808, 0.676828208, 0.677353056, 0.677839104,
-0.6783, 0.678741184, 0.679167856, 0.679593328, 0.68003352, 0.6805, 0.680997072, 0.681530128, 0.682098064, 0.68269568, 0.68332, 0.683974944, 0.684663744,
-0.685383664, 0.686129904, 0.6869, 0.687696176, 0.688520064, 0.689374432, 0.6902632, 0.69119, 0.69215776, 0.693170432, 0.694230304, 0.695339056, 0.6965,
-0.697718432, 0.698998944, 0.700351376, 0.701788288, 0.70332, 0.704949952, 0.706680656, 0.70852368, 0.710493664, 0.7126, 0.714843216, 0.71722496, 0.719739744,
-0.722377408, 0.72513, 0.727996032, 0.730972224, 0.734052736, 0.737230928, 0.7405, 0.743853184, 0.747282976, 0.750782288, 0.754344, 0.75796, 0.7616192,
-0.765308688, 0.769022176, 0.772755584, 0.7765, 0.780239856, 0.783961376, 0.787638176, 0.791237056, 0.79473, 0.7981048, 0.801350656, 0.804437632, 0.807329328,
-0.81, 0.812441984, 0.814645152, 0.816614, 0.818362528, 0.8199, 0.821221632, 0.82232568, 0.823218048, 0.823907136, 0.8244, 0.824699728, 0.824810048, 0.824737248,
-0.824488448, 0.82407, 0.823486048, 0.822740448, 0.821839648, 0.820790928, 0.8196, 0.818270144, 0.816805088, 0.815205552, 0.813470496, 0.8116, 0.80959744,
-0.807466032, 0.805206144, 0.802817216, 0.8003, 0.797657312, 0.794891344, 0.792007216, 0.789011648, 0.78591, 0.782703776, 0.779394176, 0.775986416, 0.772486976,
-0.7689, 0.765226496, 0.761468656, 0.757621696, 0.753677216, 0.74963, 0.74548464, 0.741247376, 0.736910832, 0.732463008, 0.7279, 0.723230944, 0.718462752,
-0.71361624, 0.708720608, 0.7038, 0.698863232, 0.69391992, 0.688993728, 0.684113056, 0.6793, 0.674564848, 0.669919328, 0.665369888, 0.660918848, 0.65657,
-0.652331328, 0.648208288, 0.644207648, 0.640336688, 0.6366, 0.632998464, 0.629533088, 0.626199632, 0.622990656, 0.6199, 0.61692544, 0.614063312, 0.611308704,
-0.608656256, 0.6061, 0.603633968, 0.601252384, 0.59894544, 0.596701376, 0.59451, 0.59236608, 0.59026512, 0.58819568, 0.58614392, 0.5841, 0.582061056,
-0.58002352, 0.577988304, 0.575959488, 0.57394, 0.571929568, 0.569929904, 0.56794144, 0.565964096, 0.564, 0.56205368, 0.5601288, 0.5582368, 0.55639312, 0.55461,
-0.552890016, 0.55123472, 0.549657504, 0.548175728, 0.5468, 0.545529792, 0.544366304, 0.543300176, 0.542315488, 0.5414, 0.54055232, 0.539769616, 0.539041472,
-0.538355008, 0.5377, 0.53707208, 0.536466592, 0.535877824, 0.535300416, 0.53473, 0.534165024, 0.533605984, 0.533046784, 0.532479184, 0.5319, 0.531313264,
-0.530721584, 0.530139104, 0.529586464, 0.52908, 0.528622976, 0.528218544, 0.527883472, 0.52763912, 0.5275, 0.527467696, 0.527544592, 0.527729168, 0.528016064,
-0.5284, 0.528876, 0.529434688, 0.530075056, 0.530798864, 0.5316, 0.532463264, 0.533377136, 0.534302768, 0.53518736, 0.53599, 0.536705408, 0.537332928,
-0.537827088, 0.538127488, 0.5382, 0.538052288, 0.537684368, 0.537143808, 0.536506368, 0.53583, 0.535119264, 0.534380528, 0.533669872, 0.533059936, 0.5326,
-0.532296576, 0.532161312, 0.532183728, 0.532336864, 0.5326, 0.53297136, 0.533440848, 0.533995616, 0.534621824, 0.5353, 0.536009008, 0.536731616, 0.537413984,
-0.537984992, 0.53839, 0.538623744, 0.538689824, 0.538524864, 0.538043184, 0.5372, 0.536016304, 0.534503024, 0.532739264, 0.530845824, 0.52892, 0.52696672,
-0.524976784, 0.523105328, 0.521569552, 0.5205, 0.519891968, 0.519783968, 0.520013888, 0.520311168, 0.5205, 0.520607776, 0.520621248, 0.52056736, 0.520513472,
-0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205,
-0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205,
-0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205,
-0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205,
-/* CES60 */ 0.4371, 0.44177272, 0.448610256, 0.456830832, 0.465145408, 0.47272, 0.479678352, 0.485947152, 0.491704352, 0.497347472, 0.50312, 0.50889088,
-0.514602832, 0.520250464, 0.525816496, 0.53129, 0.5366792, 0.541989584, 0.547212768, 0.552335712, 0.55735, 0.562256784, 0.567055648, 0.571748352, 0.576338816,
-0.58083, 0.58522216, 0.589516592, 0.593714864, 0.597818576, 0.60183, 0.60575208, 0.609587664, 0.613341808, 0.617020752, 0.62063, 0.624172768, 0.627652288,
-0.631074208, 0.634445008, 0.63777, 0.64105216, 0.644294528, 0.647500016, 0.650671104, 0.65381, 0.656918144, 0.659996016, 0.663046688, 0.66607408, 0.66908,
-0.672063344, 0.675023648, 0.677955232, 0.680849536, 0.6837, 0.686505824, 0.689266112, 0.69197488, 0.694624528, 0.69721, 0.699730624, 0.70218512, 0.704575536,
-0.706906112, 0.70918, 0.711396912, 0.713557056, 0.71566344, 0.717720064, 0.71973, 0.721694304, 0.72361432, 0.725491296, 0.727326192, 0.72912, 0.730873872,
-0.732588736, 0.73426568, 0.735905824, 0.73751, 0.739078736, 0.74061264, 0.742111344, 0.743573968, 0.745, 0.746389952, 0.747744304, 0.749062736, 0.750344688,
-0.75159, 0.752799056, 0.753972016, 0.755110256, 0.756215776, 0.75729, 0.758332704, 0.759343456, 0.760323728, 0.7612756, 0.7622, 0.763096064, 0.763963248,
-0.764798832, 0.765598816, 0.76636, 0.767081376, 0.767761632, 0.768398288, 0.768988544, 0.76953, 0.77002104, 0.770459968, 0.770845296, 0.771175744, 0.77145,
-0.771666496, 0.771823696, 0.771920576, 0.771956336, 0.77193, 0.771840272, 0.771686016, 0.77146568, 0.771177504, 0.77082, 0.77039256, 0.76989488, 0.76932504,
-0.76868056, 0.76796, 0.767163792, 0.76629232, 0.765346688, 0.764328656, 0.76324, 0.762082432, 0.760858368, 0.759569104, 0.7582156, 0.7568, 0.755325792,
-0.753795984, 0.752217616, 0.750599808, 0.74895, 0.747270544, 0.745563216, 0.743836128, 0.74209952, 0.74036, 0.738618384, 0.736876608, 0.735129952, 0.733369776,
-0.73159, 0.729792048, 0.727977072, 0.726139616, 0.72427208, 0.72237, 0.720434544, 0.718466176, 0.716468688, 0.71444824, 0.71241, 0.710356112, 0.708289728,
-0.706215104, 0.70413688, 0.70206, 0.699989232, 0.697929264, 0.695887296, 0.693871728, 0.69189, 0.689946656, 0.688045936, 0.686195616, 0.684404656, 0.68268,
-0.681024944, 0.679442976, 0.677936048, 0.6765048, 0.67515, 0.67387264, 0.672672208, 0.671551296, 0.670513584, 0.66956, 0.66868744, 0.667893376, 0.667169712,
-0.666505088, 0.66589, 0.665320736, 0.664793232, 0.664297888, 0.663823184, 0.66336, 0.66290392, 0.662450048, 0.661994736, 0.661535584, 0.66107, 0.660595056,
-0.660109216, 0.659608016, 0.659085936, 0.65854, 0.657971072, 0.657379536, 0.65677048, 0.656151824, 0.65553, 0.654907152, 0.65428632, 0.653672688, 0.653072096,
-0.65249, 0.651930432, 0.651397248, 0.650896624, 0.6504356, 0.65002, 0.649652048, 0.649332864, 0.64906928, 0.648870016, 0.64874, 0.6486784, 0.64868544,
-0.64875376, 0.6488716, 0.64903, 0.649228608, 0.64946704, 0.649735952, 0.650022944, 0.65032, 0.650626752, 0.650941552, 0.651268672, 0.651616192, 0.65199,
-0.652389376, 0.652814192, 0.653269808, 0.653763424, 0.6543, 0.654880304, 0.655505808, 0.656175712, 0.656887616, 0.65764, 0.65843392, 0.659270032, 0.660147344,
-0.661064336, 0.66202, 0.663014144, 0.664046144, 0.665116864, 0.666227904, 0.66738, 0.668571088, 0.669798224, 0.67106344, 0.672370496, 0.67372, 0.675107632,
-0.67653, 0.677977728, 0.679437936, 0.6809, 0.682360096, 0.683814288, 0.685252, 0.686660432, 0.68803, 0.689357008, 0.69063712, 0.691868912, 0.693053184, 0.69419,
-0.695277296, 0.696314432, 0.697299888, 0.698231824, 0.69911, 0.699935792, 0.700709888, 0.701439104, 0.70213336, 0.7028, 0.703438896, 0.704049264, 0.704638592,
-0.70521704, 0.70579, 0.706355616, 0.706913712, 0.707453888, 0.707959984, 0.70842, 0.708834576, 0.709205088, 0.70951848, 0.709756832, 0.70991, 0.709981008,
-0.70997088, 0.709891792, 0.709763024, 0.7096, 0.709406528, 0.709187952, 0.708958256, 0.70873432, 0.70853, 0.708352896, 0.708211456, 0.708113056, 0.708063776,
-0.70807, 0.70813888, 0.708276416, 0.708490432, 0.708789408, 0.70918, 0.709665984, 0.710251232, 0.71093712, 0.711723328, 0.71261, 0.713598704, 0.71468984,
-0.715884656, 0.717184672, 0.71859, 0.720100096, 0.721715776, 0.723428656, 0.725226096, 0.7271, 0.72905576, 0.731101696, 0.733227072, 0.735414848, 0.73766,
-0.739972128, 0.742353472, 0.744849216, 0.747526, 0.75043, 0.753546064, 0.756848016, 0.760392928, 0.7642656, 0.7685, 0.773053808, 0.777902688, 0.782910208,
-0.787877168, 0.79265, 0.797211968, 0.801553088, 0.805513168, 0.808881968, 0.81153, 0.813461728, 0.814658608, 0.815230768, 0.815367328, 0.81521, 0.814758416,
-0.814021808, 0.813132, 0.812261632, 0.81153, 0.810958288, 0.81058256, 0.810383392, 0.810302224, 0.8103, 0.810396304, 0.810598992, 0.81088048, 0.811201328,
-0.81153, 0.81186456, 0.81220896, 0.812514, 0.8127108, 0.81276, 0.812663568, 0.81240576, 0.812059392, 0.811742544, 0.81153,
-/* CES61 */ 0.28873, 0.291221392, 0.294866, 0.299299488, 0.303912736, 0.30831, 0.312551552, 0.316603888, 0.320533264, 0.32450056, 0.3286, 0.332774064,
-0.337007024, 0.341254864, 0.345448064, 0.34955, 0.353566144, 0.357473984, 0.361378064, 0.365444144, 0.36977, 0.374333088, 0.379145584, 0.38410328, 0.389035296,
-0.39383, 0.398501632, 0.4030392, 0.407460048, 0.411808256, 0.41611, 0.420346912, 0.424509648, 0.428597024, 0.43260752, 0.43654, 0.440394384, 0.444170784,
-0.447868064, 0.451484704, 0.45502, 0.458474368, 0.461847904, 0.46514288, 0.468362736, 0.47151, 0.47458488, 0.47758776, 0.48052016, 0.48338384, 0.48618,
-0.48890912, 0.49157192, 0.49416744, 0.49669384, 0.49915, 0.50153688, 0.5038556, 0.50610504, 0.50828336, 0.51039, 0.512426144, 0.51439232, 0.516292816,
-0.518133872, 0.51992, 0.521650528, 0.523324016, 0.524945744, 0.526523232, 0.52806, 0.52955368, 0.531003504, 0.532399168, 0.533725152, 0.53497, 0.5361348,
-0.537222048, 0.538218176, 0.539104144, 0.53987, 0.540521248, 0.541061376, 0.541506064, 0.541878992, 0.5422, 0.54247784, 0.542724384, 0.542955488, 0.543188192,
-0.54344, 0.543726256, 0.544061008, 0.54447016, 0.544984752, 0.54563, 0.546413904, 0.5473404, 0.548438256, 0.549744432, 0.55128, 0.553040976, 0.555027216,
-0.557210736, 0.559546736, 0.562, 0.564564208, 0.567230256, 0.569965424, 0.572729072, 0.57549, 0.578235312, 0.580949584, 0.583624176, 0.586256048, 0.58884,
-0.591364128, 0.593819136, 0.596197664, 0.598493072, 0.6007, 0.602812784, 0.604824784, 0.606739904, 0.608566944, 0.61031, 0.61195904, 0.613501424, 0.614943888,
-0.616299632, 0.61757, 0.618739152, 0.619796048, 0.620700464, 0.62139632, 0.62184, 0.622025056, 0.621948224, 0.621563152, 0.6208092, 0.61965, 0.61808904,
-0.616123392, 0.613789024, 0.611145856, 0.60824, 0.605076096, 0.601662624, 0.598041952, 0.59426776, 0.59038, 0.586392576, 0.582323392, 0.578178128, 0.573953344,
-0.56965, 0.565282496, 0.560862448, 0.55639312, 0.551874912, 0.54731, 0.542705696, 0.53806888, 0.533402544, 0.528708448, 0.52399, 0.519255744, 0.514515904,
-0.509771824, 0.505021824, 0.50027, 0.495528656, 0.490807744, 0.486131952, 0.48153392, 0.47704, 0.47265768, 0.468392672, 0.464273104, 0.460334576, 0.4566,
-0.453071392, 0.449754064, 0.446633296, 0.443681968, 0.44088, 0.438228592, 0.435726896, 0.43335656, 0.431093344, 0.42892, 0.426832496, 0.42482456, 0.422896704,
-0.421054288, 0.4193, 0.417628688, 0.416036384, 0.41452424, 0.413095056, 0.41175, 0.410486736, 0.40930336, 0.408197824, 0.407167568, 0.40621, 0.405322464,
-0.404501664, 0.403745584, 0.403052624, 0.40242, 0.401843568, 0.401319744, 0.4008408, 0.400396896, 0.39998, 0.399587312, 0.39921656, 0.398859568, 0.398506256,
-0.39815, 0.397789808, 0.397423888, 0.397055888, 0.396692848, 0.39634, 0.395997168, 0.395665008, 0.395347888, 0.395051408, 0.39478, 0.394535488, 0.394319968,
-0.394135808, 0.393985168, 0.39387, 0.39379168, 0.393751008, 0.393750416, 0.393792864, 0.39388, 0.394011696, 0.394188256, 0.394406576, 0.394661696, 0.39495,
-0.395271616, 0.395626576, 0.396011936, 0.396423856, 0.39686, 0.397320288, 0.397804016, 0.398313984, 0.398854912, 0.39943, 0.400038064, 0.400677664, 0.401351904,
-0.402065344, 0.40282, 0.403613552, 0.404444144, 0.405306736, 0.406194128, 0.4071, 0.408020832, 0.408952256, 0.40988848, 0.410823184, 0.41175, 0.412663456,
-0.4135584, 0.414426064, 0.415256128, 0.41604, 0.416774112, 0.417455984, 0.418076256, 0.418623328, 0.41909, 0.419476048, 0.419780256, 0.420008864, 0.420172672,
-0.42028, 0.42033144, 0.420328304, 0.420278768, 0.420193392, 0.42008, 0.419941056, 0.419779808, 0.4195976, 0.419394192, 0.41917, 0.41892744, 0.41866824,
-0.41839344, 0.41810384, 0.4178, 0.417482384, 0.41715136, 0.416806336, 0.416446192, 0.41607, 0.415677552, 0.415268496, 0.41484176, 0.414395984, 0.41393,
-0.413445792, 0.41294792, 0.412427488, 0.411870256, 0.41127, 0.41062928, 0.409943408, 0.409243936, 0.408579584, 0.40798, 0.40744128, 0.406969456, 0.406536912,
-0.406096928, 0.40562, 0.405112336, 0.404572272, 0.404010048, 0.403445184, 0.40289, 0.40234032, 0.401795248, 0.401253616, 0.400712464, 0.40017, 0.399627424,
-0.399085376, 0.398543648, 0.39800192, 0.39746, 0.39691792, 0.396375648, 0.395833376, 0.395291424, 0.39475, 0.394209056, 0.393668576, 0.393128576, 0.392589056,
-0.39205, 0.391511424, 0.390973376, 0.390435648, 0.38989792, 0.38936, 0.38882192, 0.388283648, 0.387745376, 0.387207424, 0.38667, 0.386133056, 0.385596576,
-0.385060576, 0.384525056, 0.38399, 0.383455424, 0.382921376, 0.382387648, 0.38185392, 0.38132, 0.38078592, 0.380251648, 0.379717376, 0.379183424, 0.37865,
-0.378117072, 0.377584656, 0.37705272, 0.376521184, 0.37599, 0.375459184, 0.37492872, 0.374398656, 0.373869072, 0.37334, 0.37281144, 0.372283456, 0.371755792,
-0.371228048, 0.3707, 0.370175872, 0.369663952, 0.369152112, 0.368620112, 0.36806, 0.367475872, 0.366859392, 0.366264672, 0.365774912, 0.36544,
-/* CES62 */ 0.17009, 0.171260752, 0.172973568, 0.175057904, 0.17722848, 0.1793, 0.181300704, 0.183214704, 0.185073664, 0.186953904, 0.1889, 0.190885648,
-0.192905344, 0.19493416, 0.196932576, 0.19888, 0.200779648, 0.20261832, 0.204457792, 0.206395824, 0.20849, 0.210728416, 0.213121072, 0.215601568, 0.218061024,
-0.22043, 0.222720912, 0.224929728, 0.227076384, 0.22920096, 0.23133, 0.233456176, 0.235578704, 0.237704112, 0.23983912, 0.24199, 0.244161328, 0.246356752,
-0.248584176, 0.25085304, 0.25317, 0.25553736, 0.257958256, 0.260430512, 0.262948848, 0.26551, 0.268116704, 0.270771552, 0.27347152, 0.276211728, 0.27899,
-0.281808544, 0.28466848, 0.287576176, 0.290540992, 0.29357, 0.296663264, 0.299820176, 0.303048768, 0.30636, 0.30976, 0.313246592, 0.316818608, 0.320468784,
-0.32418576, 0.32796, 0.331787488, 0.335662624, 0.33957736, 0.343522816, 0.34749, 0.35147056, 0.35545592, 0.35943536, 0.36339712, 0.36733, 0.371224704,
-0.37507192, 0.378860016, 0.382576512, 0.38621, 0.38975144, 0.393191856, 0.396521472, 0.399730528, 0.40281, 0.405753424, 0.408556032, 0.4112064, 0.413690768,
-0.416, 0.418132032, 0.42008352, 0.421860208, 0.423473136, 0.42493, 0.426228992, 0.427369168, 0.428356304, 0.42919824, 0.4299, 0.430461776, 0.430884784,
-0.431165792, 0.43129888, 0.43128, 0.431110816, 0.430793232, 0.430322768, 0.429692784, 0.4289, 0.427947184, 0.426836528, 0.425573392, 0.424165536, 0.42262,
-0.420941824, 0.419137792, 0.41721288, 0.415171408, 0.41302, 0.410768288, 0.40842536, 0.406006192, 0.403529024, 0.40101, 0.398459296, 0.395887552, 0.393309808,
-0.390742464, 0.3882, 0.385692384, 0.383229008, 0.380822432, 0.378485856, 0.37623, 0.374058592, 0.371972112, 0.369983632, 0.368110272, 0.36636, 0.364727488,
-0.363209952, 0.361784496, 0.3604168, 0.35908, 0.357769856, 0.356482096, 0.355189376, 0.353856576, 0.35246, 0.35099608, 0.349458336, 0.347854272, 0.346200768,
-0.34451, 0.342780384, 0.341013472, 0.3392176, 0.337403248, 0.33558, 0.333754432, 0.33193376, 0.330127568, 0.328346336, 0.3266, 0.324897088, 0.323246448,
-0.321657248, 0.320138688, 0.3187, 0.317349328, 0.316093728, 0.314945888, 0.313920768, 0.31303, 0.31227536, 0.311655488, 0.311184896, 0.310883344, 0.31076,
-0.310807616, 0.311021536, 0.311377616, 0.311840096, 0.31238, 0.312988768, 0.313656176, 0.314354784, 0.315051392, 0.31572, 0.316348704, 0.316924144, 0.317436784,
-0.317880864, 0.31825, 0.318536, 0.318734144, 0.318834448, 0.318825152, 0.3187, 0.318460592, 0.318107648, 0.317653104, 0.31711552, 0.31651, 0.315841008,
-0.315114464, 0.31434456, 0.313548336, 0.31274, 0.311927664, 0.31112016, 0.310326176, 0.309553552, 0.30881, 0.30810248, 0.307436336, 0.306821632, 0.306270128,
-0.30579, 0.3053836, 0.305053792, 0.304798384, 0.304611856, 0.30449, 0.304432176, 0.304435744, 0.304499232, 0.30462168, 0.3048, 0.305028816, 0.305303632,
-0.305612288, 0.305938704, 0.30627, 0.306602736, 0.306934368, 0.30725144, 0.307536912, 0.30778, 0.307980224, 0.3081356, 0.308253776, 0.308348432, 0.30843,
-0.308499424, 0.308559296, 0.308617888, 0.30868528, 0.30877, 0.308877184, 0.309012608, 0.309181632, 0.309389056, 0.30964, 0.309940816, 0.310298032, 0.310716048,
-0.311198464, 0.31175, 0.312377296, 0.313086608, 0.31388592, 0.314784272, 0.31579, 0.316909504, 0.31814936, 0.319517376, 0.321021712, 0.32267, 0.324468864,
-0.326424976, 0.328544768, 0.3308344, 0.3333, 0.335947584, 0.338782768, 0.341812112, 0.345042496, 0.34848, 0.352129488, 0.355995952, 0.360082976, 0.36439328,
-0.36893, 0.373697504, 0.378699856, 0.383940528, 0.3894228, 0.39515, 0.401124816, 0.407349008, 0.4138284, 0.420570432, 0.42758, 0.434854496, 0.4423888,
-0.450190064, 0.458269488, 0.46663, 0.47526136, 0.484154944, 0.493288768, 0.502632512, 0.51216, 0.521857536, 0.531708928, 0.5416904, 0.551775632, 0.56194,
-0.572161136, 0.58241384, 0.592681984, 0.602953408, 0.61321, 0.62343376, 0.633618704, 0.643698768, 0.653581872, 0.66321, 0.672579712, 0.681670048, 0.690535744,
-0.69927264, 0.70794, 0.716521088, 0.725016384, 0.73340048, 0.741628096, 0.74967, 0.7575332, 0.76521784, 0.77272536, 0.7800616, 0.78723, 0.794227424, 0.80105232,
-0.807705296, 0.814187472, 0.8205, 0.826643728, 0.832619776, 0.838429824, 0.844075872, 0.84956, 0.854884096, 0.860050144, 0.865060992, 0.86991992, 0.87463,
-0.879193728, 0.883613792, 0.887892976, 0.89203408, 0.89604, 0.899913664, 0.903657936, 0.907276288, 0.91077248, 0.91415, 0.917411776, 0.920560848, 0.92360008,
-0.926532192, 0.92936, 0.932086496, 0.93471456, 0.937247344, 0.939688128, 0.94204, 0.944305664, 0.946487824, 0.948589184, 0.950612384, 0.95256, 0.95443456,
-0.956238544, 0.957974288, 0.959644032, 0.96125, 0.962794416, 0.964279408, 0.9657072, 0.967080032, 0.9684, 0.969669008, 0.97088896, 0.972061472, 0.973187984,
-0.97427, 0.975304096, 0.976281632, 0.977219568, 0.978145104, 0.97907, 0.979991472, 0.980923008, 0.981796544, 0.98250624, 0.98299,
-/* CES63 */ 0.02353, 0.0239812, 0.024642304, 0.025452048, 0.026306912, 0.02714, 0.027960176, 0.028757408, 0.02955192, 0.030385392, 0.03128, 0.032231072,
-0.03325624, 0.034292048, 0.035237456, 0.03604, 0.036708832, 0.037211328, 0.037705024, 0.03843888, 0.03956, 0.041036304, 0.042888464, 0.044958064, 0.046984864,
-0.0488, 0.050427824, 0.051854064, 0.053110304, 0.054270384, 0.05538, 0.056414544, 0.057363584, 0.058230384, 0.059018064, 0.05973, 0.060370368, 0.060943664,
-0.06145288, 0.061900496, 0.06229, 0.062626336, 0.06291408, 0.063160224, 0.063373088, 0.06356, 0.063725216, 0.063872704, 0.064010272, 0.06414704, 0.06429,
-0.064442528, 0.064608512, 0.064788896, 0.06498272, 0.06519, 0.06541352, 0.065655376, 0.065916592, 0.066197888, 0.0665, 0.066824016, 0.067170992, 0.067541088,
-0.067934064, 0.06835, 0.06879, 0.069255328, 0.069745776, 0.070260624, 0.0708, 0.071365184, 0.071956976, 0.072578768, 0.07323528, 0.07393, 0.074662656,
-0.075432288, 0.0762432, 0.077101472, 0.07801, 0.078966592, 0.07996992, 0.081013088, 0.082085856, 0.08318, 0.084293184, 0.085422528, 0.086560672, 0.087698816,
-0.08883, 0.089950512, 0.091055952, 0.092144592, 0.093216352, 0.09427, 0.095300864, 0.096304192, 0.0972788, 0.098224688, 0.09914, 0.10002008, 0.10086112,
-0.10165488, 0.10239072, 0.10306, 0.103659968, 0.10418848, 0.104636512, 0.104992784, 0.10525, 0.105407456, 0.105463552, 0.105422608, 0.105292704, 0.10508,
-0.104784736, 0.104408048, 0.10395552, 0.103434272, 0.10285, 0.102205408, 0.10150376, 0.100747632, 0.099938944, 0.09908, 0.098174416, 0.097225872, 0.096236288,
-0.095206944, 0.09414, 0.093039072, 0.091907408, 0.090749904, 0.0895724, 0.08838, 0.087175616, 0.085961984, 0.084744352, 0.0835288, 0.08232, 0.081120384,
-0.079932832, 0.0787576, 0.077593408, 0.07644, 0.075300016, 0.07417592, 0.073067984, 0.071975808, 0.0709, 0.06984288, 0.068806464, 0.067794288, 0.066810832,
-0.06586, 0.064943792, 0.064064128, 0.063225344, 0.06243264, 0.06169, 0.060999296, 0.060362784, 0.059780992, 0.05925336, 0.05878, 0.05836272, 0.058002912,
-0.057701744, 0.057460416, 0.05728, 0.057161232, 0.057104704, 0.057111536, 0.057183008, 0.05732, 0.057522128, 0.057788416, 0.058120464, 0.058520752, 0.05899,
-0.059526064, 0.060127104, 0.060788704, 0.061504784, 0.06227, 0.063081248, 0.063934784, 0.06482584, 0.065749296, 0.0667, 0.06767312, 0.06866376, 0.069666,
-0.07067344, 0.07168, 0.07268056, 0.07367, 0.07464224, 0.07559088, 0.07651, 0.077394736, 0.07824032, 0.079041504, 0.079793008, 0.08049, 0.081129168, 0.081708144,
-0.0822208, 0.082659696, 0.08302, 0.083301072, 0.08350176, 0.083625008, 0.083676336, 0.08366, 0.08357672, 0.083428288, 0.083217376, 0.082946864, 0.08262,
-0.082240176, 0.081810736, 0.081336896, 0.080824816, 0.08028, 0.07970584, 0.079105536, 0.078485072, 0.077851408, 0.07721, 0.076563728, 0.075915872, 0.075267616,
-0.0746188, 0.07397, 0.073323872, 0.072682656, 0.0720476, 0.071419664, 0.0708, 0.07019016, 0.06959152, 0.06900576, 0.06843472, 0.06788, 0.067342272, 0.06682176,
-0.066320928, 0.065843056, 0.06539, 0.064961488, 0.064557808, 0.064175968, 0.063811168, 0.06346, 0.063123024, 0.062800928, 0.062490592, 0.062187696, 0.06189,
-0.061598528, 0.061313632, 0.061039696, 0.06078344, 0.06055, 0.060340144, 0.060154976, 0.059998528, 0.05987568, 0.05979, 0.059742656, 0.059735008, 0.05976736,
-0.059839152, 0.05995, 0.060100464, 0.0602908, 0.060520096, 0.060786912, 0.06109, 0.061429008, 0.061803616, 0.062211904, 0.062651312, 0.06312, 0.063618,
-0.064145584, 0.064701248, 0.065282992, 0.06589, 0.06652304, 0.067182448, 0.067871776, 0.068596304, 0.06936, 0.070162816, 0.071004336, 0.071889056, 0.072823136,
-0.07381, 0.074848416, 0.075937776, 0.077073776, 0.078249696, 0.07946, 0.080702704, 0.081975056, 0.083272288, 0.08458896, 0.08592, 0.087265216, 0.088628048,
-0.08999224, 0.091333712, 0.09264, 0.093912896, 0.09514512, 0.096374464, 0.097660608, 0.09904, 0.100506928, 0.102067664, 0.1036916, 0.105326656, 0.10694,
-0.10853888, 0.11012176, 0.11169992, 0.1132948, 0.11492, 0.116571088, 0.1182472, 0.119947392, 0.121668864, 0.12341, 0.125172224, 0.126956352, 0.1287624,
-0.130590288, 0.13244, 0.134311728, 0.13620568, 0.138121792, 0.140059904, 0.14202, 0.14400224, 0.146006672, 0.148033904, 0.150084816, 0.15216, 0.154259504,
-0.156383504, 0.158531824, 0.160704064, 0.1629, 0.165119808, 0.167363584, 0.16963144, 0.171923536, 0.17424, 0.176580896, 0.17894632, 0.181336304, 0.183750848,
-0.18619, 0.188653824, 0.191142304, 0.193655824, 0.196194944, 0.19876, 0.201350976, 0.203967984, 0.206610592, 0.20927808, 0.21197, 0.214686432, 0.217427312,
-0.220192912, 0.222983712, 0.2258, 0.228641648, 0.231508592, 0.234400736, 0.23731792, 0.24026, 0.243226944, 0.246218736, 0.249235088, 0.2522756, 0.25534,
-0.258401248, 0.261405248, 0.264433088, 0.267619968, 0.27102, 0.274605152, 0.278427488, 0.282138784, 0.28520368, 0.2873,
-/* CES64 */ 0.22326, 0.22326, 0.22326, 0.22326, 0.22326, 0.22326, 0.22326, 0.22326, 0.22326, 0.22326, 0.22326, 0.223285072, 0.22338536, 0.223485648,
-0.223460576, 0.22326, 0.22291352, 0.222380048, 0.221971936, 0.222167984, 0.22326, 0.225190352, 0.227976096, 0.23141128, 0.235145104, 0.23893, 0.242788752,
-0.2467168, 0.250572608, 0.254182096, 0.25743, 0.260295744, 0.262743808, 0.264837392, 0.266699856, 0.26842, 0.269982992, 0.271384032, 0.272701792, 0.274042752,
-0.27548, 0.277023744, 0.278694752, 0.28048096, 0.282346928, 0.28427, 0.286265824, 0.28834496, 0.290489936, 0.292673952, 0.29488, 0.2971108, 0.299365856,
-0.301650672, 0.303975968, 0.30635, 0.30877376, 0.311250192, 0.313785344, 0.316386256, 0.31906, 0.321812416, 0.324649264, 0.327580352, 0.33061728, 0.33377,
-0.337043424, 0.340441552, 0.34397544, 0.347658528, 0.3515, 0.355501392, 0.35966456, 0.363988128, 0.368467936, 0.3731, 0.377880976, 0.382804608, 0.3878696,
-0.393076272, 0.39842, 0.403888976, 0.40947208, 0.415148704, 0.420892528, 0.42668, 0.432495888, 0.438323104, 0.44413992, 0.449923056, 0.45565, 0.461301232,
-0.46685744, 0.472294528, 0.477586576, 0.48271, 0.487648048, 0.492385808, 0.496898208, 0.501156848, 0.50514, 0.508836928, 0.512235888, 0.515334208, 0.518135248,
-0.52064, 0.522842272, 0.524738768, 0.526327744, 0.527608, 0.52858, 0.529245616, 0.529606784, 0.529671072, 0.52944912, 0.52895, 0.528178288, 0.527139632,
-0.525841616, 0.5242924, 0.5225, 0.520471648, 0.518214656, 0.515737664, 0.513049872, 0.51016, 0.50707536, 0.503803104, 0.500352048, 0.496731552, 0.49295,
-0.489014208, 0.484931248, 0.480706528, 0.476344448, 0.47185, 0.467230128, 0.462491808, 0.457639648, 0.452677408, 0.44761, 0.442444176, 0.437186128, 0.43184456,
-0.426429552, 0.42095, 0.415411312, 0.40981848, 0.404180688, 0.398508496, 0.39281, 0.387089408, 0.381351648, 0.375597088, 0.369823408, 0.36403, 0.3582212,
-0.352401008, 0.346569536, 0.340725664, 0.33487, 0.329006368, 0.323138256, 0.317270544, 0.311409232, 0.30556, 0.29972768, 0.293917984, 0.288135488, 0.282384352,
-0.27667, 0.270999296, 0.265378528, 0.2598184, 0.254331872, 0.24893, 0.2436184, 0.23840224, 0.23329248, 0.22830192, 0.22344, 0.218710416, 0.21411736,
-0.209661104, 0.205339168, 0.20115, 0.197094896, 0.193173584, 0.189385952, 0.18573192, 0.18221, 0.178817168, 0.175550592, 0.172403216, 0.16936568, 0.16643,
-0.16359232, 0.160848496, 0.158190832, 0.155610448, 0.1531, 0.150655248, 0.148271792, 0.145945456, 0.14367264, 0.14145, 0.139274768, 0.137145136, 0.135057184,
-0.133006352, 0.13099, 0.129008176, 0.127060544, 0.125150992, 0.12328584, 0.12147, 0.119704128, 0.117989232, 0.116329936, 0.11473208, 0.1132, 0.111735056,
-0.110338896, 0.109012496, 0.107756096, 0.10657, 0.105454624, 0.104409536, 0.103436048, 0.10253608, 0.10171, 0.10095592, 0.100272288, 0.099654336, 0.099095424,
-0.09859, 0.098135744, 0.097730016, 0.097367648, 0.09704264, 0.09675, 0.096486816, 0.096250048, 0.09603672, 0.095844192, 0.09567, 0.095512016, 0.09536864,
-0.095237264, 0.095115008, 0.095, 0.094891744, 0.094789584, 0.094695104, 0.094611184, 0.09454, 0.094481744, 0.094437024, 0.094407344, 0.094394464, 0.0944,
-0.094425008, 0.094470464, 0.09453832, 0.094630896, 0.09475, 0.094896032, 0.09506904, 0.095270928, 0.095504176, 0.09577, 0.096067744, 0.096397088, 0.096755072,
-0.097137216, 0.09754, 0.097962496, 0.098403392, 0.098860128, 0.099329824, 0.09981, 0.100298976, 0.100795008, 0.10129648, 0.101801952, 0.10231, 0.102818928,
-0.10332696, 0.103833392, 0.104337984, 0.10484, 0.10533776, 0.105829792, 0.106314064, 0.106788176, 0.10725, 0.107698032, 0.108130624, 0.108546176, 0.108943168,
-0.10932, 0.109675168, 0.110007456, 0.110314304, 0.110592592, 0.11084, 0.111056544, 0.111242944, 0.111396304, 0.111512544, 0.11159, 0.111630752, 0.111636304,
-0.111612576, 0.111568128, 0.11151, 0.111439472, 0.111357056, 0.11127296, 0.111201024, 0.11115, 0.111120496, 0.11111544, 0.111127024, 0.111141488, 0.11115,
-0.111154608, 0.111155184, 0.11115288, 0.111150576, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115,
-0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115,
-0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115,
-0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115,
-0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115,
-/* CES65 */ 0.00602, 0.006377376, 0.006905424, 0.007566832, 0.00829536, 0.00905, 0.009842864, 0.010674192, 0.01156, 0.012529088, 0.0136, 0.014776432,
-0.01607488, 0.017469248, 0.018913936, 0.02039, 0.021913024, 0.023477728, 0.025176912, 0.027151216, 0.02949, 0.032178336, 0.035215952, 0.038578448, 0.042216864,
-0.04609, 0.050188912, 0.054498128, 0.058965024, 0.0635216, 0.06811, 0.072700928, 0.077263024, 0.08174424, 0.086086256, 0.09024, 0.094183696, 0.09790424,
-0.101344784, 0.104434048, 0.10713, 0.10943552, 0.111345984, 0.112912528, 0.114219152, 0.11533, 0.116249344, 0.116984848, 0.117597872, 0.118167776, 0.11875,
-0.119356864, 0.120007872, 0.120694, 0.121387168, 0.12207, 0.122757328, 0.12346048, 0.124165392, 0.124849504, 0.1255, 0.12612256, 0.126719472, 0.127302784,
-0.127891376, 0.1285, 0.129131184, 0.129788384, 0.130485104, 0.131238144, 0.13206, 0.132955136, 0.133929104, 0.134983552, 0.1361172, 0.13733, 0.138625888,
-0.140007792, 0.141476096, 0.14303024, 0.14467, 0.146395552, 0.148205936, 0.15010328, 0.152090944, 0.15417, 0.15633448, 0.1585756, 0.1608976, 0.16330912,
-0.16581, 0.168386448, 0.17102688, 0.173703392, 0.176378384, 0.17902, 0.181613392, 0.184142192, 0.186576512, 0.188882032, 0.19103, 0.193003344, 0.194784672,
-0.1963556, 0.197699088, 0.1988, 0.199648752, 0.20024064, 0.200555968, 0.200570096, 0.20027, 0.199660192, 0.198742848, 0.197541904, 0.19609432, 0.19443,
-0.192557648, 0.190488864, 0.18825072, 0.185875456, 0.18339, 0.180810256, 0.17815368, 0.175435824, 0.172669968, 0.16987, 0.167051856, 0.164230064, 0.161419472,
-0.15863496, 0.15589, 0.15319576, 0.150562832, 0.148001424, 0.145520976, 0.14313, 0.140834336, 0.138637344, 0.136549792, 0.13458488, 0.13275, 0.13104384,
-0.129466272, 0.128006064, 0.126645376, 0.12537, 0.124176672, 0.123060784, 0.122009376, 0.121006688, 0.12004, 0.11910304, 0.118188976, 0.117290992, 0.116403168,
-0.11552, 0.114637472, 0.113753632, 0.112860432, 0.111947712, 0.11101, 0.11004912, 0.109066032, 0.108069664, 0.107073856, 0.10609, 0.105121952, 0.104174864,
-0.103259216, 0.102387328, 0.10157, 0.100813888, 0.100125536, 0.099515024, 0.098993472, 0.09857, 0.09824736, 0.098025584, 0.097917248, 0.097939232, 0.0981,
-0.098394528, 0.098819568, 0.099358288, 0.099985408, 0.10068, 0.101433968, 0.102236688, 0.103070208, 0.103914208, 0.10475, 0.10556376, 0.106341728, 0.107065216,
-0.107713904, 0.10827, 0.108723072, 0.109065056, 0.10927624, 0.109333104, 0.10922, 0.108933952, 0.10847064, 0.107837648, 0.107050176, 0.10612, 0.105048688,
-0.103840848, 0.102505088, 0.101051328, 0.09949, 0.0978304, 0.096081648, 0.094259696, 0.092383664, 0.09047, 0.088527264, 0.086563136, 0.084594688, 0.08264208,
-0.08072, 0.07883408, 0.076991168, 0.075190016, 0.073424224, 0.07169, 0.069991264, 0.068330336, 0.066705248, 0.06511264, 0.06355, 0.062017056, 0.060513088,
-0.05903664, 0.057586112, 0.05616, 0.05475728, 0.05337712, 0.05201744, 0.05067568, 0.04935, 0.048039808, 0.04674432, 0.045463712, 0.044198784, 0.04295,
-0.041717152, 0.040500512, 0.039299392, 0.038112672, 0.03694, 0.035782224, 0.034639952, 0.03351528, 0.032411168, 0.03133, 0.030272512, 0.02923952, 0.028233168,
-0.027256016, 0.02631, 0.025395952, 0.024514848, 0.023666944, 0.022852, 0.02207, 0.021321376, 0.020606144, 0.019925152, 0.0192796, 0.01867, 0.018095792,
-0.017556592, 0.017050752, 0.016575872, 0.01613, 0.015712416, 0.015322192, 0.014957808, 0.014617584, 0.0143, 0.014003936, 0.013728288, 0.01347168, 0.013232672,
-0.01301, 0.012802704, 0.01260984, 0.012430576, 0.012264192, 0.01211, 0.01196728, 0.011835376, 0.011713792, 0.011602128, 0.0115, 0.011406928, 0.011322432,
-0.011246416, 0.01117896, 0.01112, 0.011069216, 0.011026416, 0.010991056, 0.010962416, 0.01094, 0.010923648, 0.010913056, 0.010908544, 0.010910752, 0.01092,
-0.010935536, 0.010956304, 0.010983152, 0.0110176, 0.01106, 0.011109792, 0.011167712, 0.011227632, 0.011280272, 0.01132, 0.011347424, 0.011361232, 0.01136712,
-0.011374688, 0.01139, 0.011411808, 0.01144048, 0.011473872, 0.011507904, 0.01154, 0.01157104, 0.011601232, 0.011630784, 0.011660256, 0.01169, 0.011719776,
-0.011749424, 0.011779152, 0.01180928, 0.01184, 0.01187128, 0.011903152, 0.011935424, 0.011967776, 0.012, 0.012032128, 0.012064144, 0.01209608, 0.012128016,
-0.01216, 0.012192016, 0.01222408, 0.012256144, 0.012288128, 0.01232, 0.01235176, 0.012383344, 0.012415008, 0.012447152, 0.01248, 0.01251352, 0.012547808,
-0.012582416, 0.012616624, 0.01265, 0.012682624, 0.012714416, 0.012745808, 0.01277752, 0.01281, 0.012843152, 0.012877008, 0.012911344, 0.01294576, 0.01298,
-0.013014144, 0.013048224, 0.013082224, 0.013116144, 0.01315, 0.013183776, 0.013217424, 0.013251152, 0.01328528, 0.01332, 0.01335528, 0.013391152, 0.013427424,
-0.013463776, 0.0135, 0.01353584, 0.013570704, 0.013605488, 0.013641712, 0.01368, 0.013720032, 0.013762368, 0.013803264, 0.01383696, 0.01386,
-/* CES66 */ 0.1249, 0.1249, 0.1249, 0.1249, 0.1249, 0.1249, 0.1249, 0.1249, 0.1249, 0.1249, 0.1249, 0.124911856, 0.12495928, 0.125006704, 0.124994848, 0.1249,
-0.124736544, 0.124485824, 0.124294384, 0.124386704, 0.1249, 0.12580816, 0.127119984, 0.128740848, 0.130508832, 0.13231, 0.134155968, 0.136045568, 0.137914448,
-0.139683328, 0.1413, 0.142755808, 0.144035248, 0.145168288, 0.146212608, 0.14721, 0.148154752, 0.149045968, 0.149920304, 0.15082688, 0.1518, 0.152845856,
-0.153975264, 0.155185232, 0.15646272, 0.1578, 0.159205216, 0.160683952, 0.162231168, 0.163838304, 0.1655, 0.167217936, 0.168992768, 0.17082528, 0.172717152,
-0.17467, 0.17668496, 0.17876384, 0.18090816, 0.18311944, 0.1854, 0.187752992, 0.19018144, 0.192690928, 0.195288416, 0.19798, 0.200768896, 0.203658128,
-0.20665456, 0.209766432, 0.213, 0.2163572, 0.21983968, 0.22345072, 0.2271936, 0.23107, 0.235076512, 0.23920656, 0.243465568, 0.247863056, 0.2524, 0.257064128,
-0.261845088, 0.266717168, 0.271645408, 0.2766, 0.281566336, 0.286527728, 0.29145696, 0.296323392, 0.3011, 0.305767648, 0.31030664, 0.314696432, 0.318917184,
-0.32295, 0.326778912, 0.330390592, 0.333763312, 0.336872512, 0.3397, 0.342237584, 0.344476032, 0.3464164, 0.348066208, 0.34943, 0.35050352, 0.35128504,
-0.35177632, 0.35198024, 0.3519, 0.351538336, 0.35089848, 0.349985984, 0.348807648, 0.34737, 0.345678976, 0.343741744, 0.341563152, 0.3391472, 0.3365,
-0.333630704, 0.330547952, 0.32726496, 0.323797568, 0.32016, 0.316361728, 0.31241256, 0.308326192, 0.304117584, 0.2998, 0.295383552, 0.290878752, 0.286294592,
-0.281638912, 0.27692, 0.272147456, 0.267330272, 0.262477408, 0.257597824, 0.2527, 0.247791712, 0.242880608, 0.237973824, 0.23307808, 0.2282, 0.223345952,
-0.218521664, 0.213734336, 0.208991648, 0.2043, 0.199663808, 0.195087616, 0.190573984, 0.186124192, 0.18174, 0.177424704, 0.173181024, 0.169011104, 0.164916864,
-0.1609, 0.156962208, 0.153105184, 0.14932928, 0.145634176, 0.14202, 0.138488256, 0.13504048, 0.131676704, 0.128396448, 0.1252, 0.1220888, 0.119063904,
-0.116128288, 0.113285952, 0.11054, 0.107890816, 0.105338368, 0.10288624, 0.100539232, 0.0983, 0.0961676, 0.09414152, 0.09221832, 0.09039264, 0.08866,
-0.087018656, 0.08546624, 0.083998704, 0.082611408, 0.0813, 0.08006112, 0.078891344, 0.077786128, 0.076740512, 0.07575, 0.074811232, 0.073920928, 0.073075024,
-0.07226928, 0.0715, 0.070764384, 0.070059664, 0.069383584, 0.068734304, 0.06811, 0.067508704, 0.066928784, 0.066368464, 0.065825984, 0.0653, 0.064789632,
-0.064293984, 0.063813296, 0.063348448, 0.0629, 0.06246752, 0.062050736, 0.061650112, 0.061266368, 0.0609, 0.060551008, 0.060219472, 0.059905456, 0.05960896,
-0.05933, 0.059068416, 0.058823776, 0.058596816, 0.058388736, 0.0582, 0.058029856, 0.057877776, 0.057742016, 0.057620096, 0.05751, 0.057411168, 0.057323056,
-0.057243504, 0.057169792, 0.0571, 0.057033584, 0.056969744, 0.056908864, 0.056852064, 0.0568, 0.056752128, 0.056708144, 0.05666808, 0.056632016, 0.0566,
-0.056571968, 0.05654784, 0.056527712, 0.056511744, 0.0565, 0.056492512, 0.056489472, 0.056490272, 0.056493952, 0.0565, 0.056508672, 0.056520032, 0.056534912,
-0.056554592, 0.05658, 0.056611072, 0.056647712, 0.056690752, 0.056741312, 0.0568, 0.056866624, 0.056941152, 0.05702256, 0.057109248, 0.0572, 0.057294592,
-0.05739264, 0.057493408, 0.057596096, 0.0577, 0.057804608, 0.057909408, 0.058013888, 0.058117568, 0.05822, 0.058320768, 0.058419488, 0.058515808, 0.058609408,
-0.0587, 0.058787344, 0.058871248, 0.058951472, 0.059027776, 0.0591, 0.059168, 0.059231552, 0.059291024, 0.059347056, 0.0594, 0.059449696, 0.059496144,
-0.059538832, 0.05957696, 0.05961, 0.059638112, 0.059661472, 0.059679792, 0.059692672, 0.0597, 0.059702112, 0.059699312, 0.059692352, 0.059682272, 0.05967,
-0.059655776, 0.059639632, 0.059623408, 0.059609664, 0.0596, 0.059594448, 0.059593488, 0.059595648, 0.059598368, 0.0596, 0.059600896, 0.059601008, 0.05960056,
-0.059600112, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596,
-0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596,
-0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596,
-0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596,
-/* CES67 */ 0.147, 0.147, 0.147, 0.147, 0.147, 0.147, 0.147, 0.147, 0.147, 0.147, 0.147, 0.147018272, 0.14709136, 0.147164448, 0.147146176, 0.147, 0.146747968,
-0.146361088, 0.146065568, 0.146207968, 0.147, 0.14840096, 0.150423968, 0.152922656, 0.155646784, 0.15842, 0.161259136, 0.164160896, 0.167026016, 0.169732736,
-0.1722, 0.174412528, 0.176344976, 0.178039024, 0.179577472, 0.18102, 0.182356992, 0.183586624, 0.184760656, 0.185948688, 0.1872, 0.188524384, 0.189937936,
-0.191440688, 0.19302016, 0.19467, 0.196402336, 0.198225408, 0.20013656, 0.202129472, 0.2042, 0.206350096, 0.2085808, 0.210889744, 0.213273568, 0.21573,
-0.218259872, 0.220864624, 0.223541696, 0.226287248, 0.2291, 0.231982512, 0.234936576, 0.23796936, 0.241091104, 0.24431, 0.247628192, 0.25104792, 0.254577088,
-0.258225456, 0.262, 0.265901888, 0.269932048, 0.274092608, 0.278385248, 0.28281, 0.287362112, 0.292033488, 0.296827184, 0.30174992, 0.3068, 0.311963216,
-0.317227344, 0.322563232, 0.32793184, 0.3333, 0.338652256, 0.343971952, 0.349226928, 0.354379824, 0.3594, 0.364269376, 0.368968128, 0.37348256, 0.377803552,
-0.38192, 0.38581472, 0.38947232, 0.39288016, 0.39602656, 0.3989, 0.401489152, 0.40378384, 0.405771968, 0.407440896, 0.40878, 0.409784736, 0.410453568,
-0.4107728, 0.410724672, 0.4103, 0.409502944, 0.40833584, 0.406816096, 0.404970272, 0.40282, 0.400372128, 0.397635616, 0.394629984, 0.391378272, 0.3879,
-0.38420744, 0.380314064, 0.376231568, 0.371969952, 0.36754, 0.362954976, 0.358227648, 0.35336904, 0.348389552, 0.3433, 0.338112, 0.33283672, 0.32748632,
-0.32207344, 0.31661, 0.311106016, 0.30557104, 0.300016944, 0.294456288, 0.2889, 0.283356272, 0.277833344, 0.272337776, 0.266874848, 0.26145, 0.25606944,
-0.250738416, 0.245462752, 0.240248368, 0.2351, 0.230020928, 0.225014512, 0.220081216, 0.21521984, 0.21043, 0.205713856, 0.201073136, 0.196507696, 0.192016736,
-0.1876, 0.183258576, 0.178993456, 0.174805056, 0.170693696, 0.16666, 0.162705408, 0.158831696, 0.155039344, 0.151328432, 0.1477, 0.144156304, 0.140699184,
-0.137333424, 0.134065344, 0.1309, 0.127838528, 0.124881504, 0.1220348, 0.119306096, 0.1167, 0.114215936, 0.111854, 0.109610064, 0.107477248, 0.10545,
-0.103526784, 0.101705184, 0.099980464, 0.098347104, 0.0968, 0.095335344, 0.093949184, 0.092636464, 0.091391744, 0.09021, 0.089087264, 0.088019664, 0.087002464,
-0.086030704, 0.0851, 0.08420696, 0.083348224, 0.082520848, 0.081722272, 0.08095, 0.080201632, 0.079475248, 0.078768064, 0.07807704, 0.0774, 0.076736016,
-0.076084064, 0.075444752, 0.07481968, 0.07421, 0.073615552, 0.073036592, 0.072473792, 0.071927952, 0.0714, 0.070890752, 0.070400912, 0.069932512, 0.069488192,
-0.06907, 0.068678176, 0.068312592, 0.067975728, 0.067670944, 0.0674, 0.067162112, 0.066956928, 0.066781264, 0.06663024, 0.0665, 0.066389632, 0.066297904,
-0.066221536, 0.066156608, 0.0661, 0.066050208, 0.066005616, 0.065964784, 0.065926592, 0.06589, 0.065854144, 0.065818624, 0.065782064, 0.065742784, 0.0657,
-0.065654112, 0.065605264, 0.065555936, 0.065509888, 0.06547, 0.065436672, 0.065410416, 0.06539368, 0.065389504, 0.0654, 0.065425584, 0.0654668, 0.065523456,
-0.065594752, 0.06568, 0.065779104, 0.065891696, 0.066016848, 0.06615336, 0.0663, 0.066455792, 0.066619728, 0.066789904, 0.066964, 0.06714, 0.067316736,
-0.067493024, 0.067666992, 0.06783656, 0.068, 0.068156272, 0.068304352, 0.068443232, 0.068572032, 0.06869, 0.068796608, 0.068891552, 0.068974176, 0.06904376,
-0.0691, 0.069143072, 0.069173056, 0.06919128, 0.069199744, 0.0692, 0.069192256, 0.06917672, 0.069154944, 0.069128928, 0.0691, 0.069068384, 0.069034544,
-0.068997744, 0.068956544, 0.06891, 0.068858784, 0.068803744, 0.068743504, 0.068675904, 0.0686, 0.068516864, 0.068427184, 0.068333664, 0.068240224, 0.06815,
-0.068063168, 0.067979344, 0.06790344, 0.067842336, 0.0678, 0.06777608, 0.06777176, 0.06778064, 0.06779232, 0.0678, 0.06780448, 0.06780504, 0.0678028,
-0.06780056, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678,
-0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678,
-0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678,
-0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678,
-/* CES68 */ 0.22149, 0.223326032, 0.22601216, 0.229281488, 0.232687456, 0.23594, 0.239084224, 0.242097008, 0.245025152, 0.247983536, 0.25104, 0.254151904,
-0.257304272, 0.260476, 0.263633728, 0.26676, 0.26985744, 0.27291496, 0.27598408, 0.2791464, 0.28245, 0.285884208, 0.28945592, 0.293112032, 0.296765504, 0.30036,
-0.30390376, 0.307392032, 0.310836544, 0.314263696, 0.31769, 0.32110728, 0.324512144, 0.327906368, 0.331291792, 0.33467, 0.338041424, 0.341405728, 0.344766032,
-0.348126736, 0.35149, 0.3548544, 0.358219152, 0.361579504, 0.364928336, 0.36826, 0.371572912, 0.374864944, 0.378131696, 0.381368128, 0.38457, 0.387734672,
-0.390859376, 0.3939416, 0.396979184, 0.39997, 0.40291216, 0.4058044, 0.40864368, 0.41142632, 0.41415, 0.41681392, 0.4194164, 0.42196112, 0.4244544, 0.4269,
-0.429294224, 0.43163216, 0.433918336, 0.436160352, 0.43836, 0.440510736, 0.442608336, 0.444634096, 0.446561456, 0.44837, 0.4500576, 0.451623616, 0.453046432,
-0.454297488, 0.45536, 0.456237216, 0.456929952, 0.457454448, 0.457837184, 0.4581, 0.458250448, 0.458299648, 0.458266608, 0.458173088, 0.45804, 0.457883232,
-0.457717968, 0.457570784, 0.45747288, 0.45745, 0.457511568, 0.457662784, 0.45793352, 0.458361856, 0.45897, 0.459754848, 0.46071688, 0.461830112, 0.463052304,
-0.46435, 0.465716256, 0.467140592, 0.468592768, 0.470035904, 0.47144, 0.472789712, 0.474068128, 0.475258784, 0.47634736, 0.47732, 0.478164416, 0.478871904,
-0.479424672, 0.47980184, 0.47999, 0.479986016, 0.479784352, 0.479398288, 0.478851424, 0.47816, 0.477318336, 0.476320048, 0.47518176, 0.473927712, 0.47257,
-0.471101472, 0.46951944, 0.467797168, 0.465894176, 0.46378, 0.461454528, 0.458919488, 0.456141968, 0.453077408, 0.4497, 0.446015456, 0.442024528, 0.43775584,
-0.433255552, 0.42856, 0.423676576, 0.41861656, 0.413411344, 0.408098848, 0.40271, 0.397261168, 0.391770384, 0.38625408, 0.380726016, 0.3752, 0.369690048,
-0.36420712, 0.358767952, 0.353391184, 0.34809, 0.34287032, 0.337739632, 0.332691344, 0.327711136, 0.32279, 0.317934432, 0.313150784, 0.308431056, 0.303762608,
-0.29914, 0.29456944, 0.290054576, 0.285612432, 0.281267808, 0.27704, 0.272931824, 0.268945552, 0.26510096, 0.261423648, 0.25793, 0.25462096, 0.25150008,
-0.24855672, 0.24577144, 0.24313, 0.24063384, 0.23828328, 0.23606504, 0.23396128, 0.23196, 0.230059696, 0.22825688, 0.226555584, 0.224964688, 0.22349,
-0.222128016, 0.220875424, 0.219738032, 0.21872472, 0.21784, 0.217080624, 0.216444512, 0.21592432, 0.215509168, 0.21519, 0.214963264, 0.21482448, 0.214765456,
-0.214776752, 0.21485, 0.214979456, 0.215159216, 0.215382256, 0.215641376, 0.21593, 0.216243296, 0.216577216, 0.216924656, 0.217277536, 0.21763, 0.217979552,
-0.218323216, 0.21866224, 0.219000384, 0.21934, 0.219679056, 0.22001584, 0.220352704, 0.220693408, 0.22104, 0.221391392, 0.221747104, 0.222104576, 0.222459888,
-0.22281, 0.223154768, 0.223494256, 0.223824704, 0.224140992, 0.22444, 0.224722192, 0.224987584, 0.225238336, 0.225478128, 0.22571, 0.225935456, 0.226157056,
-0.226376016, 0.226592976, 0.22681, 0.2270308, 0.227258336, 0.227500672, 0.227768448, 0.22807, 0.22840704, 0.228780512, 0.229198944, 0.229673296, 0.23021,
-0.230808032, 0.231467264, 0.232180976, 0.232938208, 0.23373, 0.234553504, 0.235404496, 0.236275168, 0.23715648, 0.23804, 0.238919616, 0.239789328, 0.2406396,
-0.241459552, 0.24224, 0.242976576, 0.24366608, 0.244298704, 0.244862528, 0.24535, 0.245760384, 0.246091984, 0.246350784, 0.246547424, 0.24669, 0.246778784,
-0.246814864, 0.246805904, 0.246761824, 0.24669, 0.246592832, 0.246473504, 0.246333296, 0.246171968, 0.24599, 0.245789776, 0.245572896, 0.245341136, 0.245096416,
-0.24484, 0.244572288, 0.244293776, 0.244003424, 0.243699312, 0.24338, 0.243045424, 0.242695264, 0.242328464, 0.241943744, 0.24154, 0.241119088, 0.240685344,
-0.24023032, 0.239740496, 0.23921, 0.238641264, 0.23802976, 0.237405856, 0.236816432, 0.23629, 0.2358228, 0.235420656, 0.235056832, 0.234686128, 0.23428,
-0.233844432, 0.233377792, 0.232890032, 0.232400112, 0.23192, 0.231445648, 0.230976192, 0.230510496, 0.23004568, 0.22958, 0.22911464, 0.228650256, 0.228186592,
-0.227723248, 0.22726, 0.226796864, 0.226333712, 0.22587104, 0.225409648, 0.22495, 0.224492016, 0.22403584, 0.223580784, 0.223125728, 0.22267, 0.222213728,
-0.221756784, 0.22129984, 0.220844016, 0.22039, 0.219937648, 0.21948704, 0.219037712, 0.218588864, 0.21814, 0.217691232, 0.217242512, 0.216794112, 0.216346512,
-0.2159, 0.215454496, 0.215010032, 0.214566368, 0.214123104, 0.21368, 0.21323712, 0.212794448, 0.212352176, 0.211910624, 0.21147, 0.211030256, 0.210591376,
-0.210153376, 0.209716256, 0.20928, 0.20884464, 0.208410256, 0.207976592, 0.207543248, 0.20711, 0.206676864, 0.206243712, 0.20581104, 0.205379648, 0.20495,
-0.204525376, 0.20411264, 0.203701024, 0.203272608, 0.20282, 0.202346672, 0.201845744, 0.201361616, 0.200962688, 0.20069,
-/* CES69 */ 0.13225, 0.133149808, 0.13446632, 0.136068512, 0.137737184, 0.13933, 0.140869232, 0.142343712, 0.143776512, 0.145224112, 0.14672, 0.1482432,
-0.149785952, 0.151339504, 0.152889936, 0.15443, 0.155960912, 0.157478144, 0.159003296, 0.160570528, 0.1622, 0.16388752, 0.165636416, 0.167424032, 0.169213168,
-0.17098, 0.172728672, 0.174457792, 0.176173392, 0.177888032, 0.17961, 0.181336576, 0.183066992, 0.184803968, 0.186550784, 0.18831, 0.19008208, 0.191866928,
-0.193667536, 0.195487904, 0.19733, 0.19919264, 0.201075136, 0.202973072, 0.204879808, 0.20679, 0.208702112, 0.210614192, 0.212521472, 0.214418272, 0.2163,
-0.21816424, 0.220008352, 0.221830144, 0.223627936, 0.2254, 0.227144464, 0.228859984, 0.230544304, 0.232194864, 0.23381, 0.235388848, 0.236929904, 0.23843592,
-0.239911696, 0.24136, 0.242777376, 0.24415904, 0.245509344, 0.246835728, 0.24814, 0.249415792, 0.250659024, 0.251851776, 0.252968608, 0.25399, 0.254914064,
-0.255740416, 0.256448288, 0.25701016, 0.25741, 0.257651136, 0.257734368, 0.2576764, 0.257504272, 0.25724, 0.256890528, 0.25646616, 0.255985552, 0.255470144,
-0.25494, 0.25440976, 0.253893632, 0.253414704, 0.252999456, 0.25267, 0.252435408, 0.252301024, 0.25229256, 0.252442496, 0.25277, 0.253273248, 0.25395352,
-0.254788992, 0.255743824, 0.25679, 0.257922944, 0.259135312, 0.26040192, 0.261691648, 0.26298, 0.26425584, 0.26550632, 0.26672168, 0.26789536, 0.26902,
-0.270085872, 0.27108568, 0.272009568, 0.272846816, 0.27359, 0.274235488, 0.274778448, 0.275224848, 0.275586288, 0.27587, 0.276069968, 0.276178928, 0.276205648,
-0.276164288, 0.27606, 0.275884208, 0.275631888, 0.275278048, 0.274786608, 0.27413, 0.273306032, 0.272314368, 0.271125904, 0.269702, 0.26802, 0.26608376,
-0.263893024, 0.261472128, 0.258860752, 0.25609, 0.2531652, 0.250094608, 0.246906496, 0.243635824, 0.24031, 0.236941408, 0.233544496, 0.230129344, 0.226702032,
-0.22327, 0.21984456, 0.216434464, 0.213050128, 0.209702592, 0.2064, 0.203147152, 0.199949728, 0.196804304, 0.19370264, 0.19064, 0.18762176, 0.184653424,
-0.181730928, 0.178847152, 0.176, 0.173195296, 0.170437088, 0.16773936, 0.165121792, 0.1626, 0.16017696, 0.15785472, 0.15565072, 0.15358768, 0.15168,
-0.149928512, 0.1483368, 0.146893728, 0.145579456, 0.14438, 0.14329856, 0.142338048, 0.141485536, 0.140722944, 0.14004, 0.139439184, 0.138920176, 0.138496848,
-0.13819136, 0.13802, 0.137981328, 0.138073168, 0.138312768, 0.138723888, 0.13932, 0.140096976, 0.141053248, 0.1421732, 0.143432112, 0.14481, 0.146301264,
-0.14789808, 0.149582336, 0.151332432, 0.15313, 0.154963984, 0.156822576, 0.158692928, 0.16056264, 0.16242, 0.16425568, 0.166062448, 0.167825856, 0.169528944,
-0.17116, 0.172715424, 0.174190416, 0.175589728, 0.17692384, 0.1782, 0.179415952, 0.180570688, 0.181669824, 0.18272112, 0.18373, 0.184697344, 0.185624864,
-0.186512224, 0.187357584, 0.18816, 0.188921232, 0.189642864, 0.190324096, 0.190963328, 0.19156, 0.19211568, 0.192631856, 0.193110032, 0.193552048, 0.19396,
-0.194336528, 0.194684912, 0.195007056, 0.19530448, 0.19558, 0.195837872, 0.196081776, 0.19632, 0.196563184, 0.19682, 0.197093008, 0.19738384, 0.197702512,
-0.198061904, 0.19847, 0.19892664, 0.199432832, 0.199982304, 0.200564176, 0.20117, 0.201798352, 0.202446384, 0.203107616, 0.203774448, 0.20444, 0.20509968,
-0.205748976, 0.206380672, 0.206986608, 0.20756, 0.208097648, 0.208597152, 0.209052016, 0.20945456, 0.2098, 0.21008784, 0.210316976, 0.210492032, 0.210621008,
-0.21071, 0.210759344, 0.210769952, 0.21074744, 0.210699008, 0.21063, 0.210542144, 0.21043768, 0.210317536, 0.210181552, 0.21003, 0.20986456, 0.209686496,
-0.209496272, 0.209294048, 0.20908, 0.208854576, 0.208618112, 0.208370448, 0.208111184, 0.20784, 0.207556928, 0.207261968, 0.206954608, 0.206634128, 0.2063,
-0.205953616, 0.205597808, 0.20522792, 0.204836352, 0.20442, 0.203980464, 0.20351512, 0.203042656, 0.202591792, 0.20218, 0.201805072, 0.201470656, 0.20116024,
-0.200845904, 0.20051, 0.200156224, 0.1997836, 0.199398096, 0.199011152, 0.19863, 0.19825216, 0.197877056, 0.197504192, 0.197132128, 0.19676, 0.19638848,
-0.196017952, 0.195648224, 0.195278976, 0.19491, 0.194541328, 0.194172944, 0.19380488, 0.193437216, 0.19307, 0.192703216, 0.19233688, 0.191970944, 0.191605328,
-0.19124, 0.190874992, 0.190510304, 0.190146096, 0.189782608, 0.18942, 0.18905824, 0.188697296, 0.188337232, 0.187978128, 0.18762, 0.187262848, 0.186906752,
-0.186551296, 0.18619584, 0.18584, 0.185483824, 0.185127216, 0.184770608, 0.18441472, 0.18406, 0.183706368, 0.183353888, 0.183002288, 0.182651088, 0.1823,
-0.181949088, 0.181598288, 0.181247888, 0.180898368, 0.18055, 0.18020272, 0.179856608, 0.179511216, 0.179165824, 0.17882, 0.17847384, 0.178127296, 0.177780752,
-0.177434848, 0.17709, 0.176748816, 0.176416672, 0.176085488, 0.175741744, 0.17538, 0.17500296, 0.174605248, 0.174221696, 0.173905904, 0.17369,
-/* CES70 */ 0.31794, 0.321518336, 0.326752736, 0.333121696, 0.339753056, 0.34608, 0.352189904, 0.358037136, 0.363711568, 0.36943552, 0.37534, 0.381340144,
-0.387403728, 0.393492992, 0.399548976, 0.40554, 0.411469232, 0.417316512, 0.423167472, 0.429158672, 0.43537, 0.441781968, 0.448404832, 0.455147456, 0.46186136,
-0.46845, 0.474926064, 0.481280336, 0.487531328, 0.49372232, 0.49988, 0.50598952, 0.512044128, 0.518045936, 0.523997264, 0.5299, 0.535754512, 0.541560416,
-0.5473208, 0.553039984, 0.55872, 0.564359264, 0.5699568, 0.575507536, 0.581003952, 0.58644, 0.591814048, 0.597124176, 0.602364624, 0.607528352, 0.61261,
-0.617607312, 0.622517584, 0.627339776, 0.632074048, 0.63672, 0.641275312, 0.645737856, 0.65010752, 0.654384944, 0.65857, 0.662660784, 0.66665544, 0.670552736,
-0.674351472, 0.67805, 0.68164528, 0.685133456, 0.688513792, 0.691786608, 0.69495, 0.697999088, 0.700929952, 0.703732336, 0.70639264, 0.7089, 0.711251792,
-0.713446336, 0.71547176, 0.717312784, 0.71896, 0.720413632, 0.7216724, 0.722744368, 0.723643376, 0.72438, 0.724954944, 0.725369888, 0.725634592, 0.725761536,
-0.72576, 0.725633072, 0.725384752, 0.725016592, 0.724528192, 0.72392, 0.723194992, 0.722355312, 0.721402272, 0.720336912, 0.71916, 0.717872336, 0.716474752,
-0.714966288, 0.713345104, 0.71161, 0.709761248, 0.707798768, 0.705722608, 0.703533008, 0.70123, 0.69881336, 0.696283088, 0.693638256, 0.690877504, 0.688,
-0.685006512, 0.681898256, 0.67867392, 0.675331344, 0.67187, 0.668291648, 0.66459736, 0.660792512, 0.656884784, 0.65288, 0.648778768, 0.644581472, 0.640293456,
-0.6359216, 0.63147, 0.626938672, 0.622328736, 0.61763464, 0.612847184, 0.60796, 0.602975184, 0.59789552, 0.592714016, 0.587420432, 0.58201, 0.576487072,
-0.570854656, 0.56512392, 0.559311184, 0.55343, 0.547485664, 0.54148472, 0.535439376, 0.529363712, 0.52327, 0.517166448, 0.511061616, 0.504964704, 0.498884672,
-0.49283, 0.48680768, 0.480823504, 0.474887248, 0.469010032, 0.4632, 0.457461024, 0.451797888, 0.446208512, 0.440686976, 0.43523, 0.429842272, 0.424528672,
-0.419285632, 0.414106752, 0.40899, 0.4039404, 0.398961392, 0.394064624, 0.389266496, 0.38458, 0.380007584, 0.375550944, 0.371224464, 0.367046864, 0.36303,
-0.35917424, 0.355481904, 0.351944688, 0.348547712, 0.34528, 0.342142064, 0.339133328, 0.336244352, 0.333462816, 0.33078, 0.328193792, 0.325700912, 0.323302192,
-0.321001232, 0.3188, 0.316695552, 0.314685312, 0.312770912, 0.310955392, 0.30924, 0.307622704, 0.306102032, 0.30467448, 0.303335168, 0.30208, 0.300907072,
-0.29981408, 0.298797648, 0.297854096, 0.29698, 0.296172576, 0.295429008, 0.29474616, 0.294120832, 0.29355, 0.293031184, 0.29256216, 0.292139616, 0.291759872,
-0.29142, 0.291118224, 0.290852576, 0.290622528, 0.2904284, 0.29027, 0.290145344, 0.290052288, 0.289991312, 0.289963856, 0.28997, 0.290007616, 0.290075152,
-0.290168368, 0.290281504, 0.29041, 0.290552688, 0.290708528, 0.290873248, 0.291041568, 0.29121, 0.291378096, 0.291545088, 0.29171248, 0.291883312, 0.29206,
-0.292243232, 0.29243456, 0.292635248, 0.292846416, 0.29307, 0.293308624, 0.293564448, 0.293843392, 0.294153216, 0.2945, 0.294884848, 0.295308112, 0.295776496,
-0.2962988, 0.29688, 0.29751872, 0.298214336, 0.298960832, 0.299748688, 0.30057, 0.301421536, 0.302298592, 0.303195088, 0.304104544, 0.30502, 0.305934912,
-0.306843248, 0.307733824, 0.30859312, 0.30941, 0.31018016, 0.310900464, 0.311559248, 0.312142112, 0.31264, 0.31305232, 0.313377248, 0.313622096, 0.313799744,
-0.31392, 0.313983232, 0.313990736, 0.31395184, 0.313878624, 0.31378, 0.31365864, 0.31351808, 0.31335984, 0.31318368, 0.31299, 0.31278144, 0.31256, 0.31232608,
-0.31207944, 0.31182, 0.311548416, 0.31126512, 0.310969744, 0.310661568, 0.31034, 0.310004928, 0.309656144, 0.30929296, 0.308914496, 0.30852, 0.308111088,
-0.30769136, 0.307254112, 0.306788544, 0.30629, 0.305760496, 0.305196432, 0.304622288, 0.304075824, 0.30358, 0.303131824, 0.302736048, 0.302370992, 0.302000016,
-0.3016, 0.301175792, 0.300726032, 0.300258992, 0.299790352, 0.29933, 0.298874624, 0.298423552, 0.29797568, 0.297528368, 0.29708, 0.296631568, 0.2961836,
-0.295735872, 0.295288064, 0.29484, 0.294391696, 0.293943072, 0.293494528, 0.293046704, 0.2926, 0.292154352, 0.291709808, 0.291266144, 0.29082296, 0.29038,
-0.289937312, 0.289494864, 0.289052736, 0.288611088, 0.28817, 0.28772944, 0.287289456, 0.286849792, 0.286410048, 0.28597, 0.285529696, 0.285089072, 0.284648528,
-0.284208704, 0.28377, 0.283332352, 0.282895808, 0.282460144, 0.28202496, 0.28159, 0.281155328, 0.280720944, 0.28028688, 0.279853216, 0.27942, 0.278987184,
-0.27855472, 0.278122656, 0.277691072, 0.27726, 0.27682944, 0.276399456, 0.275969792, 0.275540048, 0.27511, 0.274679696, 0.274249072, 0.273818528, 0.273388704,
-0.27296, 0.272535728, 0.272122688, 0.271710528, 0.271281968, 0.27083, 0.270358048, 0.269859328, 0.269377808, 0.268981168, 0.26871,
-/* CES71 */ 0.0504, 0.0504, 0.0504, 0.0504, 0.0504, 0.0504, 0.0504, 0.0504, 0.0504, 0.0504, 0.0504, 0.05040712, 0.0504356, 0.05046408, 0.05045696, 0.0504,
-0.05030416, 0.05016288, 0.0500572, 0.05011032, 0.0504, 0.050917248, 0.05167144, 0.052622832, 0.053698784, 0.05485, 0.056086752, 0.057411392, 0.058803472,
-0.060238432, 0.0617, 0.063184704, 0.064686992, 0.0662168, 0.067793248, 0.06943, 0.07112432, 0.07287464, 0.07469352, 0.07659824, 0.0786, 0.080698704, 0.08289592,
-0.085187296, 0.087564192, 0.09002, 0.092554368, 0.095165616, 0.097848144, 0.100594992, 0.1034, 0.106259344, 0.109168944, 0.112123024, 0.115115264, 0.11814,
-0.121193792, 0.124273984, 0.127373616, 0.130484208, 0.1336, 0.136719232, 0.139839056, 0.14296312, 0.146098624, 0.14925, 0.152413536, 0.15558496, 0.158768944,
-0.161973088, 0.1652, 0.168443792, 0.171699584, 0.174956016, 0.178197408, 0.18141, 0.184586016, 0.187716176, 0.190787776, 0.193786896, 0.1967, 0.199515776,
-0.202223216, 0.204806336, 0.207247136, 0.20953, 0.2116464, 0.213589616, 0.215342352, 0.216883808, 0.2182, 0.219288, 0.220143552, 0.220772784, 0.221188416,
-0.2214, 0.221407616, 0.221213584, 0.220825152, 0.2202512, 0.2195, 0.218577328, 0.217489152, 0.216244896, 0.21485536, 0.21333, 0.211674496, 0.209894016,
-0.207998016, 0.205997296, 0.2039, 0.201710368, 0.199433536, 0.197068624, 0.194611392, 0.19206, 0.189419936, 0.186697104, 0.183888992, 0.18099024, 0.178,
-0.174925168, 0.171771552, 0.168550976, 0.1652792, 0.16197, 0.1586312, 0.155271776, 0.151904352, 0.148542768, 0.1452, 0.141886304, 0.138611712, 0.13538968,
-0.132234848, 0.12916, 0.126172608, 0.12327848, 0.120491552, 0.117828384, 0.1153, 0.112908272, 0.110655712, 0.108537792, 0.106545152, 0.10467, 0.102909376,
-0.101257472, 0.099709088, 0.098259184, 0.0969, 0.095621296, 0.094413808, 0.09325744, 0.092126592, 0.091, 0.089869248, 0.08872696, 0.087551152, 0.086315024,
-0.085, 0.083601536, 0.082113472, 0.080540128, 0.078892864, 0.07718, 0.075400448, 0.073555808, 0.071652288, 0.069697728, 0.0677, 0.065665744, 0.063602288,
-0.061519472, 0.059428416, 0.05734, 0.055264224, 0.053212032, 0.05119368, 0.049219248, 0.0473, 0.045448208, 0.04367552, 0.041998912, 0.040437984, 0.03901,
-0.037725248, 0.036592992, 0.035631456, 0.03486184, 0.0343, 0.03395128, 0.033820496, 0.033912992, 0.034232448, 0.03478, 0.035549216, 0.036527152, 0.037721328,
-0.039146064, 0.0408, 0.042659648, 0.044706368, 0.046884128, 0.049116128, 0.05134, 0.0535368, 0.055688768, 0.057732096, 0.059587584, 0.0612, 0.062555952,
-0.063636656, 0.0644524, 0.065033184, 0.0654, 0.065546976, 0.06547584, 0.065199664, 0.064735248, 0.0641, 0.063307648, 0.062371984, 0.06132136, 0.060190816,
-0.05901, 0.05779264, 0.05655104, 0.05531576, 0.05412336, 0.053, 0.051954448, 0.05099776, 0.050126192, 0.049326464, 0.04859, 0.047922496, 0.047326832,
-0.046796928, 0.046323664, 0.0459, 0.045524144, 0.045193408, 0.044903632, 0.044650496, 0.04443, 0.044239008, 0.044074672, 0.043932896, 0.0438092, 0.0437,
-0.04360336, 0.043517456, 0.043440672, 0.043371808, 0.04331, 0.043255264, 0.043208592, 0.0431684, 0.043132288, 0.0431, 0.043074496, 0.0430584, 0.043058224,
-0.043082848, 0.04314, 0.043233728, 0.043368624, 0.04355232, 0.043793536, 0.0441, 0.044476976, 0.04492976, 0.045465024, 0.046089728, 0.04681, 0.04762928,
-0.048549664, 0.049579008, 0.050727152, 0.052, 0.053397104, 0.054918768, 0.056559232, 0.058308896, 0.06016, 0.062110176, 0.064155632, 0.066290288, 0.068507344,
-0.0708, 0.073162736, 0.075590448, 0.07807336, 0.080599712, 0.08316, 0.085751584, 0.0883736, 0.091014576, 0.093659472, 0.0963, 0.09893656, 0.101565856,
-0.104206432, 0.106887968, 0.10963, 0.112421536, 0.115246592, 0.118129888, 0.121109744, 0.1242, 0.127374608, 0.130613328, 0.133857088, 0.137022688, 0.14004,
-0.14290528, 0.145636528, 0.148087536, 0.150048304, 0.1514, 0.152160576, 0.152298816, 0.152018496, 0.151647296, 0.1514, 0.151254592, 0.151236416, 0.15130912,
-0.151381824, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514,
-0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514,
-0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514,
-0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514,
-/* CES72 */ 0.37756, 0.379555776, 0.382474768, 0.3860208, 0.389699632, 0.39319, 0.396540016, 0.39972192, 0.402789424, 0.405873248, 0.40905, 0.412272848,
-0.415528864, 0.41878296, 0.421979696, 0.42509, 0.428118464, 0.43104728, 0.433960656, 0.436991952, 0.44022, 0.443626608, 0.447220976, 0.450921424, 0.454593792,
-0.45815, 0.461600576, 0.464936384, 0.468168272, 0.47132696, 0.47443, 0.477462128, 0.480414912, 0.483285696, 0.48607152, 0.48877, 0.49138064, 0.493903776,
-0.496335872, 0.498672048, 0.50091, 0.50305152, 0.505097792, 0.507054384, 0.508929536, 0.51073, 0.512458064, 0.514116544, 0.515711664, 0.517250784, 0.51874,
-0.520182704, 0.521582464, 0.522943024, 0.524267824, 0.52556, 0.526822064, 0.528055904, 0.529264704, 0.530451984, 0.53162, 0.532769248, 0.533900384, 0.53501144,
-0.536098896, 0.53716, 0.538194752, 0.53920312, 0.540181488, 0.541124896, 0.54203, 0.542896448, 0.543723328, 0.544511568, 0.545263328, 0.54598, 0.546660896,
-0.547305648, 0.54791488, 0.548489472, 0.54903, 0.549536512, 0.5500092, 0.550447728, 0.550851456, 0.55122, 0.551553584, 0.551852368, 0.552116352, 0.552345536,
-0.55254, 0.552699888, 0.552825312, 0.552916736, 0.5529748, 0.553, 0.552992464, 0.552952336, 0.552879968, 0.55277576, 0.55264, 0.5524728, 0.552274288,
-0.552044416, 0.551783024, 0.55149, 0.551165392, 0.550809216, 0.55042136, 0.550001664, 0.54955, 0.5490664, 0.54855096, 0.54800336, 0.54742312, 0.54681,
-0.54616416, 0.54548568, 0.54477504, 0.54403296, 0.54326, 0.542456224, 0.54162168, 0.540756976, 0.539862912, 0.53894, 0.537987776, 0.537005376, 0.535993936,
-0.534955296, 0.53389, 0.532796688, 0.531674496, 0.530519504, 0.529326112, 0.52809, 0.52681104, 0.525489744, 0.524120448, 0.522695312, 0.52121, 0.51966608,
-0.518064208, 0.516410176, 0.514713024, 0.51298, 0.511213168, 0.509415456, 0.507592864, 0.505752352, 0.5039, 0.50203976, 0.500175664, 0.498312688, 0.496455952,
-0.49461, 0.492777904, 0.490962128, 0.489167312, 0.487398736, 0.48566, 0.48395232, 0.482277312, 0.480632864, 0.479014736, 0.47742, 0.475849632, 0.474304384,
-0.472781616, 0.471277568, 0.46979, 0.468319344, 0.466865536, 0.465430608, 0.46401792, 0.46263, 0.461266928, 0.459928928, 0.458618288, 0.457337968, 0.45609,
-0.454874784, 0.453693008, 0.452544112, 0.451426736, 0.45034, 0.449284784, 0.448262192, 0.44727048, 0.446306848, 0.44537, 0.444461104, 0.44358072, 0.442732256,
-0.441920752, 0.44115, 0.440420128, 0.439731056, 0.439086624, 0.438491952, 0.43795, 0.437460272, 0.437022944, 0.436634256, 0.436288128, 0.43598, 0.435709712,
-0.435476896, 0.43527752, 0.435106384, 0.43496, 0.43483776, 0.43473848, 0.43466376, 0.43461688, 0.4346, 0.434611936, 0.43465152, 0.434720864, 0.434823168,
-0.43496, 0.435130128, 0.435332784, 0.43556488, 0.435821856, 0.4361, 0.436397824, 0.43671328, 0.437043856, 0.437386992, 0.43774, 0.438100352, 0.438465776,
-0.43883224, 0.439194944, 0.43955, 0.43989584, 0.4402312, 0.44055272, 0.44085648, 0.44114, 0.441403088, 0.44164544, 0.441868752, 0.442076064, 0.44227,
-0.442451664, 0.442622752, 0.44278584, 0.442943888, 0.4431, 0.443256848, 0.44341696, 0.443585312, 0.443767984, 0.44397, 0.444193072, 0.444438192, 0.444711392,
-0.445020432, 0.44537, 0.445759584, 0.446189232, 0.44665504, 0.447150448, 0.44767, 0.448211664, 0.44877232, 0.449347376, 0.449931712, 0.45052, 0.451107616,
-0.451690256, 0.452259616, 0.452805616, 0.45332, 0.45379984, 0.454243216, 0.454641152, 0.454982368, 0.45526, 0.45547424, 0.455624272, 0.455716624, 0.455762336,
-0.45577, 0.45574072, 0.455676464, 0.455585248, 0.455477152, 0.45536, 0.455237088, 0.455112368, 0.454988528, 0.454867088, 0.45475, 0.454640256, 0.454539968,
-0.45445248, 0.454381632, 0.45433, 0.454298288, 0.45428744, 0.454295952, 0.454320864, 0.45436, 0.45441344, 0.454480832, 0.454560624, 0.454650896, 0.45475,
-0.454856928, 0.454970544, 0.45508968, 0.455213216, 0.45534, 0.455469248, 0.45560064, 0.455731632, 0.455858784, 0.45598, 0.456095184, 0.456203552, 0.45630776,
-0.456412288, 0.45652, 0.456630336, 0.45674344, 0.456858624, 0.456974448, 0.45709, 0.457205664, 0.457321584, 0.457437664, 0.457553824, 0.45767, 0.457786128,
-0.457902144, 0.45801808, 0.458134016, 0.45825, 0.458365984, 0.45848192, 0.458597856, 0.458713872, 0.45883, 0.45894624, 0.459062656, 0.459178992, 0.459294848,
-0.45941, 0.459524496, 0.459638272, 0.459751728, 0.459865504, 0.45998, 0.460095152, 0.460211008, 0.460327344, 0.46044376, 0.46056, 0.460676128, 0.460792144,
-0.46090808, 0.461024016, 0.46114, 0.461256, 0.461372, 0.461488, 0.461604, 0.46172, 0.461836, 0.461952, 0.462068, 0.462184, 0.4623, 0.462416, 0.462532, 0.462648,
-0.462764, 0.46288, 0.462996, 0.463112, 0.463228, 0.463344, 0.46346, 0.463575984, 0.46369192, 0.463807856, 0.463923872, 0.46404, 0.464155312, 0.464268016,
-0.46438064, 0.464497424, 0.46462, 0.464747488, 0.46488168, 0.465010912, 0.465117264, 0.46519,
-/* CES73 */ 0.1616, 0.163942032, 0.16737, 0.171553328, 0.175936736, 0.18016, 0.184273536, 0.188234688, 0.19212536, 0.196127152, 0.20034, 0.20472344, 0.20931424,
-0.21392592, 0.21826272, 0.22217, 0.225671216, 0.22866744, 0.231616944, 0.235245488, 0.23998, 0.245733712, 0.252585744, 0.260031536, 0.267244368, 0.2737,
-0.279498864, 0.284616496, 0.289219088, 0.29363064, 0.29807, 0.302489552, 0.306890768, 0.311345424, 0.3159324, 0.32072, 0.325744896, 0.331033344, 0.336663872,
-0.3427324, 0.3493, 0.356370864, 0.363954992, 0.37201232, 0.380470208, 0.38927, 0.398395104, 0.40781512, 0.417484976, 0.427354672, 0.43737, 0.44747976,
-0.457634896, 0.467747792, 0.477712768, 0.48744, 0.496887504, 0.506020432, 0.51474128, 0.522931568, 0.53051, 0.537456704, 0.54374344, 0.549397536, 0.554481552,
-0.55904, 0.56306336, 0.566552016, 0.569540352, 0.572074048, 0.57419, 0.575904368, 0.577236672, 0.578209216, 0.57884336, 0.57916, 0.579178528, 0.578916256,
-0.578396384, 0.577644112, 0.57668, 0.575517792, 0.574172384, 0.572648976, 0.570947168, 0.56907, 0.567030848, 0.564842576, 0.562508704, 0.560029632, 0.55741,
-0.554661616, 0.551794624, 0.548826432, 0.54577872, 0.54267, 0.53950888, 0.536302992, 0.533072624, 0.529842416, 0.52663, 0.523440752, 0.520281424, 0.517150736,
-0.514041808, 0.51095, 0.507877152, 0.504822176, 0.50178384, 0.498760944, 0.49575, 0.492745536, 0.48974288, 0.486727984, 0.483682048, 0.48059, 0.47744776,
-0.474252064, 0.470987408, 0.467634272, 0.46418, 0.460623296, 0.456961168, 0.45320112, 0.449357232, 0.44544, 0.441448864, 0.4373844, 0.433256576, 0.429078592,
-0.42486, 0.420603392, 0.416312656, 0.41198808, 0.407627424, 0.40323, 0.398799792, 0.39434056, 0.389851808, 0.385331616, 0.38078, 0.376199984, 0.371593568,
-0.366967392, 0.362330576, 0.35769, 0.353045808, 0.348397072, 0.343751776, 0.33912088, 0.33451, 0.329916624, 0.325340096, 0.320768368, 0.31618296, 0.31157,
-0.30692928, 0.302261008, 0.297551216, 0.292781264, 0.28794, 0.283029232, 0.278049136, 0.2730092, 0.267925184, 0.26281, 0.257667968, 0.25250576, 0.247334032,
-0.242164784, 0.23701, 0.231879872, 0.226784432, 0.221739312, 0.216762592, 0.21187, 0.20707032, 0.202371152, 0.197789184, 0.193344096, 0.18905, 0.184912032,
-0.180936624, 0.177121136, 0.173457328, 0.16994, 0.166573088, 0.163359296, 0.160294624, 0.157372832, 0.15459, 0.151946576, 0.149441904, 0.147078352, 0.14486016,
-0.14279, 0.140866096, 0.139086352, 0.137453248, 0.135970704, 0.13464, 0.133457968, 0.132421888, 0.131525648, 0.130760848, 0.13012, 0.12959872, 0.129191888,
-0.128892016, 0.128690704, 0.12858, 0.128553232, 0.128603376, 0.12872296, 0.128904384, 0.12914, 0.129421952, 0.12974208, 0.130093168, 0.130468336, 0.13086,
-0.131260096, 0.131661328, 0.13205208, 0.132418752, 0.13275, 0.133040848, 0.1332876, 0.133478432, 0.133598944, 0.13364, 0.133600848, 0.133479712, 0.133283296,
-0.1330236, 0.13271, 0.132344016, 0.131928816, 0.131472096, 0.130983056, 0.13047, 0.129938704, 0.129395296, 0.128847408, 0.12830312, 0.12777, 0.127254192,
-0.126761568, 0.126299744, 0.12587688, 0.1255, 0.125174224, 0.124904784, 0.124695584, 0.124549584, 0.12447, 0.124460432, 0.124523424, 0.124664016, 0.124888208,
-0.1252, 0.12560008, 0.126089376, 0.126666272, 0.127327408, 0.12807, 0.12889296, 0.129794112, 0.130771824, 0.131824656, 0.13295, 0.134143936, 0.135402864,
-0.136719472, 0.13808448, 0.13949, 0.140932384, 0.142408192, 0.1439092, 0.145425568, 0.14695, 0.148480864, 0.1500172, 0.151554976, 0.153089792, 0.15462,
-0.15615224, 0.157697696, 0.159250112, 0.160797408, 0.16234, 0.163896064, 0.165479552, 0.16713456, 0.168921808, 0.17089, 0.173051824, 0.17541832, 0.178039776,
-0.180979552, 0.18428, 0.187947632, 0.191994016, 0.19640208, 0.201136144, 0.20617, 0.21150544, 0.21713856, 0.22304968, 0.229214, 0.23561, 0.242225152,
-0.24904584, 0.256053328, 0.263227456, 0.27055, 0.278010592, 0.285602688, 0.293300144, 0.3010696, 0.30889, 0.316756448, 0.324656784, 0.33261872, 0.340690176,
-0.3489, 0.357238304, 0.3657036, 0.374279376, 0.382937952, 0.39166, 0.400445616, 0.409290896, 0.418191776, 0.427146016, 0.43615, 0.445196864, 0.454280336,
-0.463394448, 0.47253304, 0.48169, 0.490859504, 0.500035728, 0.509212272, 0.518382496, 0.52754, 0.536679008, 0.545793792, 0.554878096, 0.56392552, 0.57293,
-0.581886112, 0.590788496, 0.59963176, 0.608410624, 0.61712, 0.625755088, 0.63431128, 0.642783872, 0.651168224, 0.65946, 0.667655376, 0.675750752, 0.683742368,
-0.691626544, 0.6994, 0.707059936, 0.714603648, 0.7220292, 0.729335152, 0.73652, 0.743581904, 0.75051936, 0.757330816, 0.764014752, 0.77057, 0.776995904,
-0.783291936, 0.789457888, 0.79549384, 0.8014, 0.807176448, 0.812823328, 0.818341808, 0.823733568, 0.829, 0.834141872, 0.839160288, 0.844055824, 0.8488288,
-0.85348, 0.85798496, 0.862293104, 0.866484608, 0.870692112, 0.87497, 0.879297504, 0.883735568, 0.887937632, 0.891368096, 0.89371,
-/* CES74 */ 0.03388, 0.034000976, 0.034177952, 0.034393168, 0.034616944, 0.03483, 0.035035328, 0.035231408, 0.035421328, 0.035612688, 0.03581, 0.036010368,
-0.036212608, 0.036415808, 0.036618528, 0.03682, 0.037020336, 0.037219088, 0.03741832, 0.037621312, 0.03783, 0.038044016, 0.03826376, 0.038486944, 0.038709808,
-0.03893, 0.039148032, 0.039363824, 0.039578336, 0.039793328, 0.04001, 0.040228208, 0.040448096, 0.040669904, 0.040893792, 0.04112, 0.041348944, 0.041581024,
-0.041816624, 0.042056144, 0.0423, 0.042548576, 0.042802224, 0.043061552, 0.04332728, 0.0436, 0.04388024, 0.044168752, 0.044465264, 0.044769056, 0.04508,
-0.045398832, 0.045725904, 0.046063296, 0.046413968, 0.04678, 0.047161152, 0.047556976, 0.04796912, 0.048399824, 0.04885, 0.04931856, 0.04980472, 0.05030512,
-0.05081488, 0.05133, 0.05184896, 0.05236984, 0.05288936, 0.05340384, 0.05391, 0.054405568, 0.05488832, 0.055355312, 0.055803424, 0.05623, 0.056633536,
-0.057012912, 0.057365568, 0.057688544, 0.05798, 0.058239744, 0.058467408, 0.058664752, 0.058834816, 0.05898, 0.059100896, 0.059198592, 0.059274768, 0.059331264,
-0.05937, 0.059392608, 0.059400528, 0.059396928, 0.059385728, 0.05937, 0.059350368, 0.059326928, 0.059303088, 0.059283328, 0.05927, 0.059262256, 0.059259888,
-0.05925824, 0.059250112, 0.05923, 0.059197472, 0.05915208, 0.059088368, 0.058999216, 0.05888, 0.058730464, 0.058549728, 0.058340032, 0.058105696, 0.05785,
-0.057573296, 0.057276752, 0.056962448, 0.056632704, 0.05629, 0.055936624, 0.055574768, 0.055208352, 0.054842096, 0.05448, 0.05412384, 0.053774992, 0.053437984,
-0.053118416, 0.05282, 0.052543248, 0.052289024, 0.05205576, 0.051840256, 0.05164, 0.051454592, 0.05128296, 0.051123168, 0.050972976, 0.05083, 0.05069224,
-0.050557888, 0.050422736, 0.050281504, 0.05013, 0.049967184, 0.049792576, 0.049601648, 0.04938856, 0.04915, 0.048886688, 0.048598768, 0.048290608, 0.047969168,
-0.04764, 0.047304448, 0.046964448, 0.046624928, 0.046291808, 0.04597, 0.045662272, 0.045371648, 0.045101024, 0.04485296, 0.04463, 0.044434432, 0.044267904,
-0.044134016, 0.044037088, 0.04398, 0.043963168, 0.043987136, 0.044050944, 0.044152512, 0.04429, 0.044462176, 0.044666784, 0.044903232, 0.04517152, 0.04547,
-0.045794832, 0.046142672, 0.046505872, 0.046874432, 0.04724, 0.047599232, 0.047948992, 0.048280592, 0.048583472, 0.04885, 0.049077744, 0.049263712, 0.049408,
-0.049512928, 0.04958, 0.049608112, 0.0495972, 0.049548048, 0.049461776, 0.04934, 0.049184544, 0.048997248, 0.048782272, 0.048544976, 0.04829, 0.048019712,
-0.047736512, 0.047445232, 0.047151552, 0.04686, 0.046572976, 0.046293152, 0.046022128, 0.045760704, 0.04551, 0.045271872, 0.045047568, 0.044839264, 0.04464952,
-0.04448, 0.044331008, 0.044203024, 0.04409488, 0.044004416, 0.04393, 0.043871392, 0.04382792, 0.043798768, 0.043783136, 0.04378, 0.04378824, 0.043806928,
-0.043833696, 0.043865504, 0.0439, 0.043936416, 0.043973936, 0.044011216, 0.044046896, 0.04408, 0.04411008, 0.044136736, 0.044160192, 0.044181088, 0.0442,
-0.04421712, 0.044232832, 0.044247904, 0.044263296, 0.04428, 0.044298896, 0.044320944, 0.044347472, 0.04438, 0.04442, 0.044468928, 0.044528512, 0.044599696,
-0.04468312, 0.04478, 0.044892448, 0.045022416, 0.045172944, 0.045347712, 0.04555, 0.045781808, 0.046045104, 0.04634336, 0.046680576, 0.04706, 0.047483712,
-0.04795416, 0.048472048, 0.049037136, 0.04965, 0.05031352, 0.051030768, 0.051802416, 0.052628384, 0.05351, 0.054450896, 0.055454416, 0.056526016, 0.057672496,
-0.0589, 0.060212256, 0.061612976, 0.063109216, 0.064709296, 0.06642, 0.068245312, 0.070189776, 0.072256, 0.074445344, 0.07676, 0.079204352, 0.08178248,
-0.084497488, 0.087352256, 0.09035, 0.09349456, 0.096789728, 0.100239536, 0.103848224, 0.10762, 0.1115588, 0.115668576, 0.119953872, 0.124419488, 0.12907,
-0.13390472, 0.138918272, 0.144129104, 0.149565136, 0.15524, 0.16115128, 0.167309024, 0.173660768, 0.180124272, 0.18665, 0.193245792, 0.199901888, 0.206667824,
-0.21362648, 0.22083, 0.228267056, 0.235939744, 0.243829312, 0.25190048, 0.26013, 0.268522832, 0.277077872, 0.285793712, 0.294671472, 0.30371, 0.312902896,
-0.322244272, 0.331729328, 0.341353104, 0.35111, 0.360993648, 0.370997488, 0.381113808, 0.391334128, 0.40165, 0.412053648, 0.422537008, 0.433090768, 0.443705008,
-0.45437, 0.4650768, 0.475816288, 0.486578496, 0.497353104, 0.50813, 0.518899888, 0.529653616, 0.540380544, 0.551069472, 0.56171, 0.57229336, 0.582810784,
-0.593253408, 0.603612592, 0.61388, 0.62404784, 0.634108768, 0.644054736, 0.653877584, 0.66357, 0.673126016, 0.682539856, 0.691806416, 0.700921216, 0.70988,
-0.718678576, 0.727313296, 0.735780576, 0.744077056, 0.7522, 0.760147328, 0.767917136, 0.775508944, 0.782923152, 0.79016, 0.79721896, 0.804099984, 0.810803408,
-0.817329792, 0.82368, 0.829821072, 0.835686128, 0.841379424, 0.84707384, 0.85284, 0.858651232, 0.864588624, 0.870196096, 0.874768688, 0.87789,
-/* CES75 */ 0.0509, 0.0509, 0.0509, 0.0509, 0.0509, 0.0509, 0.0509, 0.0509, 0.0509, 0.0509, 0.0509, 0.050912416, 0.05096208, 0.051011744, 0.050999328, 0.0509,
-0.050730464, 0.050474464, 0.050280544, 0.050375584, 0.0509, 0.051831024, 0.053180464, 0.054861744, 0.056723424, 0.05866, 0.060684704, 0.062796544, 0.064942384,
-0.067058064, 0.0691, 0.071057584, 0.072915264, 0.074691904, 0.076427424, 0.07815, 0.079852288, 0.081530784, 0.0832104, 0.084925616, 0.0867, 0.088535952,
-0.09043928, 0.092407008, 0.094429376, 0.0965, 0.098622688, 0.100799248, 0.103025168, 0.105293808, 0.1076, 0.1099424, 0.112319168, 0.114726816, 0.117161504,
-0.11962, 0.122100896, 0.124603456, 0.127123776, 0.129656896, 0.1322, 0.134753168, 0.137315536, 0.139892304, 0.142491872, 0.14512, 0.147774272, 0.150451104,
-0.153157936, 0.155905968, 0.1587, 0.161533984, 0.164403216, 0.167294448, 0.1701888, 0.17307, 0.175929376, 0.178755968, 0.18153624, 0.184255872, 0.1869,
-0.189454656, 0.19190648, 0.194234944, 0.196416288, 0.19843, 0.200265152, 0.201912624, 0.203350416, 0.204552288, 0.2055, 0.20618872, 0.206612336, 0.206775072,
-0.206688128, 0.20636, 0.205790944, 0.204984592, 0.20394632, 0.202682128, 0.2012, 0.199509184, 0.19761856, 0.195545856, 0.193313312, 0.19094, 0.18843568,
-0.185810016, 0.183081792, 0.180272768, 0.1774, 0.174472512, 0.171500512, 0.168487712, 0.165433632, 0.16234, 0.159215328, 0.156067232, 0.152898016, 0.14970816,
-0.1465, 0.14327968, 0.140052736, 0.136825632, 0.133605728, 0.1304, 0.127214368, 0.124055232, 0.120928896, 0.1178416, 0.1148, 0.111810864, 0.108880496,
-0.106018288, 0.10323504, 0.10054, 0.097937728, 0.095431648, 0.093032208, 0.090752208, 0.0886, 0.08657648, 0.084683248, 0.082916176, 0.081267264, 0.07973,
-0.078302272, 0.076979936, 0.07575864, 0.074633984, 0.0736, 0.072649392, 0.07177536, 0.070964688, 0.070200896, 0.06947, 0.06876544, 0.068081168, 0.067402816,
-0.066713264, 0.066, 0.065258464, 0.064483216, 0.063674368, 0.06283584, 0.06197, 0.061074656, 0.060149488, 0.0591948, 0.058211072, 0.0572, 0.05616464,
-0.05510816, 0.05403672, 0.05295824, 0.05188, 0.050807168, 0.04974568, 0.048702432, 0.047684704, 0.0467, 0.045755392, 0.044857712, 0.044016912, 0.043244352,
-0.04255, 0.041939584, 0.041417952, 0.04099608, 0.040687008, 0.0405, 0.040437216, 0.04050072, 0.040691424, 0.041008448, 0.04145, 0.042011424, 0.042684144,
-0.043470544, 0.044376704, 0.0454, 0.046525792, 0.047742304, 0.049015856, 0.050300768, 0.05156, 0.052782336, 0.053957616, 0.055047936, 0.056006176, 0.0568,
-0.057422544, 0.057863616, 0.058131968, 0.05824896, 0.05823, 0.058072672, 0.057779008, 0.057359904, 0.0568292, 0.0562, 0.055481408, 0.054682944, 0.05382088,
-0.052914336, 0.05198, 0.051027072, 0.0500636, 0.049107808, 0.048181376, 0.0473, 0.04646944, 0.045696848, 0.044980496, 0.044313024, 0.04369, 0.043115488,
-0.042591776, 0.042116784, 0.041687072, 0.0413, 0.040955104, 0.040651504, 0.040387424, 0.040160864, 0.03997, 0.03981328, 0.039688944, 0.039595888, 0.039533312,
-0.0395, 0.039494192, 0.039514448, 0.039557824, 0.03962064, 0.0397, 0.039795392, 0.039906864, 0.040030896, 0.040162768, 0.0403, 0.040443744, 0.040594656,
-0.040756928, 0.04093696, 0.04114, 0.041367808, 0.041622688, 0.041909408, 0.042233568, 0.0426, 0.043011728, 0.043471888, 0.043984208, 0.044552448, 0.04518,
-0.045868864, 0.046620128, 0.047438672, 0.048330736, 0.0493, 0.050345968, 0.051468672, 0.052664256, 0.0539264, 0.05525, 0.056633408, 0.058074016, 0.059568064,
-0.061111472, 0.0627, 0.064329984, 0.065998144, 0.067697744, 0.069420544, 0.07116, 0.072914464, 0.074683344, 0.076459424, 0.078233344, 0.08, 0.081759616,
-0.083510064, 0.085263472, 0.0870392, 0.08885, 0.090688896, 0.092545792, 0.094436368, 0.096384864, 0.0984, 0.100465248, 0.102567728, 0.104669888, 0.106718848,
-0.10867, 0.110521088, 0.112283888, 0.113864688, 0.115128768, 0.116, 0.116490128, 0.116579248, 0.116398688, 0.116159488, 0.116, 0.115906176, 0.115894448,
-0.11594136, 0.115988272, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116,
-0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116,
-0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116,
-0.116, 0.116, 0.116, 0.116, 0.116, 0.116,
-/* CES76 */ 0.2886, 0.2886, 0.2886, 0.2886, 0.2886, 0.2886, 0.2886, 0.2886, 0.2886, 0.2886, 0.2886, 0.288636016, 0.28878008, 0.288924144, 0.288888128, 0.2886,
-0.288105344, 0.287351264, 0.286777264, 0.287055824, 0.2886, 0.29133544, 0.295291264, 0.300195328, 0.305578112, 0.31111, 0.316825568, 0.322718848, 0.328611808,
-0.334287568, 0.3396, 0.344516768, 0.348987808, 0.353081648, 0.356939168, 0.36066, 0.364220032, 0.367607728, 0.370910144, 0.3742472, 0.3777, 0.381275472,
-0.384992384, 0.388835456, 0.392763968, 0.39675, 0.40080416, 0.404929696, 0.409105792, 0.413303568, 0.4175, 0.421685904, 0.425849232, 0.42997728, 0.434058368,
-0.43808, 0.44202704, 0.44588392, 0.44963896, 0.45328184, 0.4568, 0.460178128, 0.46340248, 0.466450992, 0.469297504, 0.47192, 0.474308704, 0.476456192,
-0.47833848, 0.479926128, 0.4812, 0.482157936, 0.48279584, 0.483124384, 0.483163808, 0.48293, 0.482425792, 0.481657664, 0.480637856, 0.479380528, 0.4779,
-0.47620832, 0.474317376, 0.472247152, 0.470021248, 0.46766, 0.465174144, 0.462573072, 0.45987808, 0.457114368, 0.4543, 0.451441408, 0.44854664, 0.445612592,
-0.442629184, 0.43959, 0.436499776, 0.433361632, 0.430170448, 0.426918304, 0.4236, 0.420215824, 0.416764928, 0.413248592, 0.409669696, 0.40603, 0.402327808,
-0.398561232, 0.394732416, 0.39084488, 0.3869, 0.382895904, 0.378831696, 0.374701408, 0.37049632, 0.36621, 0.36184272, 0.357395408, 0.352861296, 0.348231104,
-0.3435, 0.338670944, 0.333746336, 0.328733008, 0.32364112, 0.31848, 0.313256512, 0.307980048, 0.302657344, 0.29729424, 0.2919, 0.286487488, 0.281067984,
-0.27566568, 0.270311296, 0.26503, 0.259830592, 0.25472, 0.249724688, 0.244877536, 0.2402, 0.235694384, 0.231365248, 0.227201552, 0.223182016, 0.21929,
-0.215523104, 0.211875312, 0.20833296, 0.204879728, 0.2015, 0.198182, 0.19491376, 0.1916764, 0.18844816, 0.18521, 0.181951136, 0.17866232, 0.175322784,
-0.171907888, 0.1684, 0.16479376, 0.161083024, 0.157268448, 0.153355872, 0.14935, 0.145252032, 0.141067248, 0.136796464, 0.13243904, 0.128, 0.123492032,
-0.118926944, 0.114329296, 0.109731008, 0.10516, 0.100631936, 0.096163856, 0.091782816, 0.087519296, 0.0834, 0.07944376, 0.075670176, 0.072098272, 0.068745728,
-0.06563, 0.062767616, 0.060172832, 0.057865888, 0.055869104, 0.0542, 0.052866896, 0.051877168, 0.05123888, 0.050958592, 0.05104, 0.051477952, 0.05226008,
-0.053397808, 0.054910576, 0.0568, 0.05904136, 0.061614608, 0.064462416, 0.067505984, 0.07068, 0.073959712, 0.077318976, 0.08069368, 0.084007024, 0.0872,
-0.09024688, 0.09311864, 0.09579976, 0.09828592, 0.10057, 0.102636512, 0.1044784, 0.106080048, 0.107422976, 0.1085, 0.10931848, 0.109883328, 0.110228496,
-0.110404064, 0.11045, 0.110376064, 0.110193296, 0.109940128, 0.10966408, 0.1094, 0.109159904, 0.108959088, 0.108798752, 0.108670336, 0.10857, 0.108508032,
-0.108491472, 0.108519792, 0.108589952, 0.1087, 0.108851808, 0.109046752, 0.109282256, 0.10955424, 0.10986, 0.110201024, 0.110579536, 0.110991968, 0.1114328,
-0.1119, 0.112396944, 0.112925888, 0.113496032, 0.114120576, 0.11481, 0.115566496, 0.116392032, 0.117297568, 0.118297104, 0.1194, 0.12060712, 0.121920048,
-0.123336576, 0.124851424, 0.12646, 0.12815968, 0.129945296, 0.131814992, 0.133768048, 0.1358, 0.137901136, 0.140062352, 0.142266608, 0.144492224, 0.14672,
-0.148938432, 0.151135168, 0.153291344, 0.15538584, 0.1574, 0.159321008, 0.161136224, 0.16282992, 0.164385856, 0.16579, 0.167035168, 0.16811776, 0.169019712,
-0.169718064, 0.1702, 0.170467696, 0.170520272, 0.170378848, 0.170077024, 0.16964, 0.169067744, 0.168360048, 0.167542352, 0.166648656, 0.1657, 0.164697264,
-0.163646992, 0.16252712, 0.161299808, 0.15994, 0.158461056, 0.15688048, 0.155168944, 0.153281488, 0.1512, 0.148950544, 0.146552384, 0.144066944, 0.141581824,
-0.13917, 0.13683744, 0.134572064, 0.132511168, 0.130849552, 0.1297, 0.129050928, 0.128933808, 0.129175008, 0.129491968, 0.1297, 0.129821216, 0.129836368,
-0.12977576, 0.129715152, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297,
-0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297,
-0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297,
-0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297,
-/* CES77 */ 0.02805, 0.028737056, 0.029734976, 0.031117936, 0.032978816, 0.03537, 0.038292064, 0.041764816, 0.045682048, 0.04987232, 0.05422, 0.058722544,
-0.063345248, 0.068113312, 0.073090016, 0.0783, 0.083708256, 0.089312192, 0.094991488, 0.100567824, 0.10593, 0.111084096, 0.116003328, 0.12077752, 0.125563392,
-0.13046, 0.135441536, 0.1405068, 0.145633984, 0.150778368, 0.15591, 0.161035216, 0.166151424, 0.171253632, 0.17633872, 0.1814, 0.18642944, 0.191424192,
-0.196353664, 0.201174896, 0.20586, 0.210406032, 0.214802064, 0.219068736, 0.223243968, 0.22735, 0.231376576, 0.235320496, 0.239167856, 0.242896336, 0.24649,
-0.249950208, 0.253277856, 0.256461184, 0.259484832, 0.26234, 0.265029216, 0.267554144, 0.269923552, 0.27215136, 0.27425, 0.276219568, 0.278055792, 0.279791216,
-0.28147328, 0.28313, 0.284757344, 0.286360416, 0.287905648, 0.28933784, 0.29062, 0.29175824, 0.292753968, 0.293597936, 0.294281184, 0.2948, 0.295152608,
-0.295333216, 0.295369744, 0.295305952, 0.29517, 0.294959408, 0.294678784, 0.29431688, 0.293851776, 0.29327, 0.292578624, 0.29178232, 0.290879616, 0.289868592,
-0.28875, 0.287526, 0.286196416, 0.284777552, 0.283293408, 0.28176, 0.280176576, 0.278544432, 0.276863168, 0.275129424, 0.27334, 0.271497392, 0.269606048,
-0.267650384, 0.2656064, 0.26346, 0.261216928, 0.258880784, 0.25645936, 0.253965136, 0.25141, 0.248797248, 0.24612792, 0.243429792, 0.240741824, 0.23809,
-0.235476544, 0.232908912, 0.23037752, 0.227862048, 0.22535, 0.222847328, 0.22035616, 0.217877872, 0.215415264, 0.21297, 0.210540368, 0.208124352, 0.205723856,
-0.20334208, 0.20098, 0.198636784, 0.196314016, 0.194000528, 0.19167928, 0.18934, 0.186984224, 0.184610128, 0.182227792, 0.179854096, 0.1775, 0.175164928,
-0.172852032, 0.170556096, 0.16826704, 0.16598, 0.163698656, 0.161423136, 0.159166336, 0.156947776, 0.15478, 0.152661408, 0.150592576, 0.148570304, 0.146587872,
-0.14464, 0.14272688, 0.140848224, 0.138996928, 0.137163232, 0.13534, 0.13352624, 0.131720768, 0.129920096, 0.128120544, 0.12632, 0.1245188, 0.122717616,
-0.120918512, 0.119124768, 0.11734, 0.115566224, 0.113804912, 0.11206544, 0.110360688, 0.1087, 0.10708464, 0.10551624, 0.10399872, 0.10253592, 0.10113,
-0.099781872, 0.09849312, 0.097257648, 0.096065536, 0.09491, 0.093791472, 0.092709248, 0.091662064, 0.0906492, 0.08967, 0.0887236, 0.087809504, 0.086927488,
-0.086077552, 0.08526, 0.084474976, 0.083722368, 0.08300488, 0.082326512, 0.08169, 0.08109528, 0.08054256, 0.08003232, 0.0795648, 0.07914, 0.078757904,
-0.07841848, 0.078120336, 0.077861392, 0.07764, 0.077455712, 0.077307936, 0.07719536, 0.077116464, 0.07707, 0.077055824, 0.07707432, 0.077123136, 0.077198912,
-0.0773, 0.077427504, 0.077582016, 0.077767168, 0.0779884, 0.07825, 0.078553712, 0.078902288, 0.079297184, 0.07973912, 0.08023, 0.08077384, 0.081374464,
-0.082036848, 0.082766832, 0.08357, 0.084450352, 0.085411648, 0.086461344, 0.0876084, 0.08886, 0.090219712, 0.091691424, 0.093278576, 0.094983888, 0.09681,
-0.098760096, 0.100837136, 0.103042416, 0.105376416, 0.10784, 0.110435328, 0.113164416, 0.116027984, 0.119026272, 0.12216, 0.125429472, 0.128833424, 0.132377856,
-0.136071808, 0.13992, 0.14391888, 0.148065456, 0.152354512, 0.156778528, 0.16133, 0.166003888, 0.170794832, 0.175690816, 0.1806768, 0.18574, 0.19087264,
-0.196065616, 0.201310992, 0.206601808, 0.21193, 0.217284336, 0.222652912, 0.228028048, 0.233403424, 0.23877, 0.244114496, 0.249424288, 0.25468184, 0.259866672,
-0.26496, 0.269952576, 0.27483912, 0.279587984, 0.284157248, 0.28852, 0.292670784, 0.296595264, 0.300322544, 0.303904064, 0.30737, 0.31071, 0.313925504,
-0.316988368, 0.319852832, 0.32249, 0.324905328, 0.327097248, 0.329076368, 0.330862768, 0.33247, 0.333896128, 0.335142928, 0.336212048, 0.337103728, 0.33782,
-0.338365552, 0.338743888, 0.338963664, 0.33903616, 0.33897, 0.338769952, 0.338443264, 0.337987856, 0.337397168, 0.33667, 0.335816832, 0.334848016, 0.33377128,
-0.332594944, 0.33133, 0.329984688, 0.328562, 0.327099712, 0.325650784, 0.32425, 0.322898704, 0.321604832, 0.3203544, 0.319119088, 0.31788, 0.316644256,
-0.31541648, 0.314184784, 0.312932528, 0.31165, 0.310337536, 0.308991504, 0.307627632, 0.30627168, 0.30494, 0.303629856, 0.302342752, 0.301072528, 0.299807424,
-0.29854, 0.297272784, 0.296006368, 0.294741232, 0.293478816, 0.29222, 0.290964096, 0.289710672, 0.288460128, 0.287213104, 0.28597, 0.284730768, 0.283495488,
-0.282263888, 0.281035488, 0.27981, 0.278587488, 0.277367888, 0.276151488, 0.274938768, 0.27373, 0.27252496, 0.271323408, 0.270125376, 0.268930944, 0.26774,
-0.266552512, 0.265368816, 0.26418712, 0.263004704, 0.26182, 0.260633344, 0.25944448, 0.258255536, 0.257069952, 0.25589, 0.25471544, 0.253546496, 0.252382992,
-0.251224288, 0.25007, 0.248929248, 0.247819792, 0.246715136, 0.24557112, 0.24437, 0.2431204, 0.241804416, 0.240536592, 0.239493248, 0.23878,
-/* CES78 */ 0.12947, 0.131012112, 0.133269136, 0.13602168, 0.138901584, 0.14167, 0.144361568, 0.14695072, 0.149487712, 0.152086224, 0.15481, 0.157630112,
-0.160560992, 0.163508752, 0.166324352, 0.16893, 0.171337424, 0.173496192, 0.17563912, 0.178134848, 0.1812, 0.184792752, 0.18895824, 0.193430928, 0.197775856,
-0.20172, 0.205321696, 0.208573008, 0.21157608, 0.214521232, 0.21754, 0.2206144, 0.22375328, 0.22700768, 0.23043376, 0.23408, 0.237971872, 0.2421272,
-0.246602048, 0.251465216, 0.25676, 0.26248784, 0.268654208, 0.275229056, 0.282158944, 0.2894, 0.29693704, 0.304744016, 0.312787952, 0.321033568, 0.32944,
-0.337964048, 0.346564112, 0.355168896, 0.3636924, 0.37206, 0.380231216, 0.388168416, 0.395794096, 0.403017456, 0.40977, 0.416021936, 0.421739456, 0.426914496,
-0.431556976, 0.43567, 0.439236224, 0.442247616, 0.444697968, 0.44658168, 0.4479, 0.448661168, 0.448872928, 0.448564128, 0.447775168, 0.44654, 0.444873312,
-0.442792128, 0.440327904, 0.43751688, 0.43439, 0.430967984, 0.427273424, 0.423324064, 0.419134144, 0.41472, 0.41010432, 0.405309424, 0.400351888, 0.395246352,
-0.39001, 0.384663808, 0.379227168, 0.373726528, 0.368191968, 0.36265, 0.357116544, 0.351605568, 0.346145312, 0.340768736, 0.3355, 0.330348368, 0.325324432,
-0.320428016, 0.31565152, 0.31099, 0.306446576, 0.302020336, 0.297710176, 0.293514896, 0.28943, 0.28544824, 0.281563456, 0.277755792, 0.273998448, 0.27027,
-0.266565136, 0.262879872, 0.259191488, 0.255471024, 0.2517, 0.247877456, 0.243999888, 0.2400792, 0.236137072, 0.23219, 0.228238896, 0.22428704, 0.220348624,
-0.216441488, 0.21258, 0.208770752, 0.205021104, 0.201339376, 0.197733328, 0.19421, 0.190774512, 0.187430256, 0.18418552, 0.181050144, 0.17803, 0.1751248,
-0.17233472, 0.16965368, 0.1670716, 0.16458, 0.162175104, 0.15985112, 0.157601776, 0.155420672, 0.1533, 0.151231216, 0.149206576, 0.147210496, 0.145223616,
-0.14323, 0.141223776, 0.139200496, 0.137143216, 0.135030896, 0.13285, 0.130599376, 0.128276336, 0.125889136, 0.123453056, 0.12098, 0.118471792, 0.115932656,
-0.113372, 0.110800944, 0.10823, 0.105667088, 0.10312032, 0.100601552, 0.098124144, 0.0957, 0.093336368, 0.091039392, 0.088822656, 0.08670232, 0.08469,
-0.082789904, 0.081007376, 0.079340448, 0.07778264, 0.07633, 0.07498576, 0.073751888, 0.072626496, 0.071606544, 0.07069, 0.069876944, 0.069166976, 0.068560368,
-0.06805792, 0.06766, 0.067365328, 0.067172128, 0.067081968, 0.067097648, 0.06722, 0.067446304, 0.067774128, 0.068199312, 0.068716336, 0.06932, 0.07000616,
-0.070769712, 0.071606224, 0.072511456, 0.07348, 0.074504928, 0.075579424, 0.076694, 0.077837616, 0.079, 0.080173392, 0.08134976, 0.082518688, 0.083668896,
-0.08479, 0.085873984, 0.086913088, 0.087897312, 0.088816016, 0.08966, 0.090424352, 0.091105952, 0.091693872, 0.092174592, 0.09254, 0.092790528, 0.092925552,
-0.092953376, 0.09288784, 0.09274, 0.092512704, 0.092210576, 0.091842608, 0.0914192, 0.09095, 0.090442384, 0.089904048, 0.089344352, 0.088773216, 0.0882,
-0.087632288, 0.087077232, 0.086544816, 0.086046, 0.08559, 0.085182912, 0.084830976, 0.08453904, 0.084310784, 0.08415, 0.08406096, 0.08404688, 0.08411232,
-0.08426224, 0.0845, 0.08482656, 0.08524288, 0.08574752, 0.08633744, 0.08701, 0.08776416, 0.08859808, 0.08950912, 0.09049424, 0.09155, 0.092672976, 0.09385976,
-0.095104464, 0.096399968, 0.09774, 0.099120832, 0.100538784, 0.101987536, 0.103459888, 0.10495, 0.106455616, 0.107975376, 0.109503536, 0.111033056, 0.11256,
-0.114089536, 0.115630656, 0.117176016, 0.118712896, 0.12024, 0.12177256, 0.123322176, 0.124926672, 0.126638528, 0.1285, 0.130522352, 0.132715712, 0.135124112,
-0.137802992, 0.14079, 0.14409208, 0.147720512, 0.151663264, 0.155892976, 0.16039, 0.165157712, 0.170194864, 0.175486656, 0.181013808, 0.18676, 0.192717232,
-0.198876896, 0.2052244, 0.211743344, 0.21842, 0.225247696, 0.23222144, 0.239327744, 0.246550608, 0.25388, 0.261312096, 0.268839104, 0.276478352, 0.28425944,
-0.2922, 0.300293632, 0.308538992, 0.316924912, 0.325432992, 0.33405, 0.342774848, 0.351603712, 0.360532816, 0.36955952, 0.37868, 0.387887936, 0.397177296,
-0.406541856, 0.415974976, 0.42547, 0.43502064, 0.444620496, 0.454262192, 0.463937888, 0.47364, 0.483361696, 0.493096032, 0.502835568, 0.512572704, 0.5223,
-0.532010528, 0.541697488, 0.551353248, 0.560969888, 0.57054, 0.580057136, 0.589514848, 0.59890688, 0.608227232, 0.61747, 0.626629456, 0.63570024, 0.644676304,
-0.653551408, 0.66232, 0.670977664, 0.679520064, 0.687943424, 0.696244464, 0.70442, 0.71246672, 0.720381664, 0.728162128, 0.735805632, 0.74331, 0.750673408,
-0.757894288, 0.764971328, 0.771903488, 0.77869, 0.785330224, 0.791823648, 0.798170752, 0.804372576, 0.81043, 0.816343376, 0.822113392, 0.827740688, 0.833225904,
-0.83857, 0.843744784, 0.848692528, 0.853503952, 0.858329296, 0.86323, 0.868182544, 0.873256432, 0.8780572, 0.881975248, 0.88465,
-/* CES79 */ 0.56749, 0.568910112, 0.57098688, 0.573506128, 0.576111136, 0.57857, 0.580910736, 0.583100448, 0.5851972, 0.587327552, 0.58956, 0.591869808,
-0.59429088, 0.596671152, 0.598769584, 0.60046, 0.601762608, 0.602599392, 0.603338816, 0.60456424, 0.60662, 0.60943368, 0.613060816, 0.617117152, 0.620971728,
-0.62422, 0.626930528, 0.629077232, 0.630762496, 0.63220024, 0.63353, 0.63470392, 0.635709856, 0.636580112, 0.637349888, 0.63805, 0.638697936, 0.639306592,
-0.639911728, 0.640556784, 0.64127, 0.64205552, 0.642920848, 0.643847856, 0.644802704, 0.64576, 0.6467196, 0.647677616, 0.648612592, 0.649497248, 0.65031,
-0.651042432, 0.651685472, 0.652226032, 0.652650992, 0.65295, 0.653121136, 0.653167552, 0.653073568, 0.652817184, 0.65239, 0.651803504, 0.651065168, 0.650212432,
-0.649299696, 0.64837, 0.647432272, 0.646495312, 0.645602352, 0.644808112, 0.64415, 0.643634432, 0.643271392, 0.643052832, 0.642957712, 0.64297, 0.643089008,
-0.643307792, 0.643615936, 0.64400176, 0.64445, 0.64494384, 0.645467696, 0.645986672, 0.646456368, 0.64684, 0.647123904, 0.647296992, 0.64731936, 0.647141408,
-0.64673, 0.646079248, 0.64517912, 0.644044912, 0.642707744, 0.64119, 0.639487664, 0.637599792, 0.63554608, 0.633353168, 0.63104, 0.628610832, 0.6260728,
-0.623425168, 0.620661776, 0.61778, 0.614787792, 0.611692928, 0.608493024, 0.60518232, 0.60176, 0.598233648, 0.594609264, 0.59090048, 0.587125616, 0.5833,
-0.579430032, 0.5755224, 0.571592048, 0.567656656, 0.56373, 0.55981856, 0.555929488, 0.552067056, 0.548233104, 0.54443, 0.540661376, 0.536928576, 0.533236896,
-0.529593056, 0.526, 0.522455648, 0.518959136, 0.515499584, 0.512060672, 0.50863, 0.505206752, 0.501790464, 0.498368736, 0.494925248, 0.49145, 0.487943664,
-0.484405296, 0.480843088, 0.47727104, 0.4737, 0.470131264, 0.466567328, 0.463018032, 0.459495696, 0.45601, 0.452565248, 0.449166512, 0.445817616, 0.44252112,
-0.43928, 0.436098448, 0.432979856, 0.429928064, 0.426947072, 0.42404, 0.421208624, 0.418454624, 0.415778624, 0.413180464, 0.41066, 0.408217456, 0.405852464,
-0.403564832, 0.40135432, 0.39922, 0.397160048, 0.395172512, 0.393254256, 0.39140136, 0.38961, 0.38787696, 0.386198576, 0.384570832, 0.382989488, 0.38145,
-0.37994832, 0.378480992, 0.377040064, 0.375615616, 0.3742, 0.372792512, 0.371394304, 0.369995616, 0.368583008, 0.36715, 0.365700976, 0.364238416, 0.362777776,
-0.361342176, 0.35995, 0.358605248, 0.357312416, 0.356089744, 0.354960112, 0.35394, 0.353034176, 0.352248944, 0.351584272, 0.35103552, 0.3506, 0.350280624,
-0.350078032, 0.34999232, 0.350023488, 0.35017, 0.350428768, 0.35079688, 0.351266512, 0.351827184, 0.35247, 0.353190736, 0.353984752, 0.354843728, 0.355758144,
-0.35672, 0.357724544, 0.358767008, 0.359842032, 0.360944496, 0.36207, 0.36321608, 0.364381632, 0.365561344, 0.366748576, 0.36794, 0.369137792, 0.370344064,
-0.371564416, 0.372807088, 0.37408, 0.375389872, 0.376745776, 0.37815376, 0.379618944, 0.38115, 0.382759776, 0.38445992, 0.386273264, 0.388228528, 0.39035,
-0.39264872, 0.395134864, 0.397833488, 0.400774592, 0.40398, 0.407457408, 0.411216128, 0.415255568, 0.419568368, 0.42415, 0.42900432, 0.434132048, 0.439532016,
-0.445202464, 0.45114, 0.457339136, 0.463793536, 0.470495056, 0.477434016, 0.4846, 0.491980272, 0.499558816, 0.50732872, 0.515286144, 0.52342, 0.531708816,
-0.54013312, 0.548657104, 0.557235728, 0.56583, 0.574419184, 0.582982464, 0.591480704, 0.599868624, 0.60811, 0.616185328, 0.624073584, 0.63176096, 0.639239536,
-0.6465, 0.653529008, 0.66031792, 0.666852432, 0.673116304, 0.6791, 0.68480352, 0.690226032, 0.695379984, 0.700285776, 0.70496, 0.709407664, 0.713636304,
-0.717660304, 0.721496384, 0.72516, 0.728663072, 0.732018304, 0.735239856, 0.738342288, 0.74134, 0.744246864, 0.747076896, 0.749844448, 0.752564, 0.75525,
-0.757906992, 0.760529808, 0.763162224, 0.7658676, 0.76868, 0.7715968, 0.774637344, 0.777711328, 0.780674832, 0.78344, 0.786021776, 0.788406288, 0.79066528,
-0.792920832, 0.79525, 0.797636048, 0.80008288, 0.802564352, 0.805030544, 0.80745, 0.809833472, 0.812183232, 0.814502672, 0.816799952, 0.81908, 0.821339776,
-0.823577872, 0.825794128, 0.827988224, 0.83016, 0.832309584, 0.834437008, 0.836542592, 0.838626816, 0.84069, 0.842732192, 0.844753552, 0.846753872, 0.848732752,
-0.85069, 0.852625776, 0.854540112, 0.856433488, 0.858306624, 0.86016, 0.861993648, 0.863807728, 0.865602048, 0.867376208, 0.86913, 0.870863632, 0.872577248,
-0.874271024, 0.8759452, 0.8776, 0.879235536, 0.880851904, 0.882449472, 0.88402872, 0.88559, 0.887133424, 0.888659152, 0.8901672, 0.891657488, 0.89313,
-0.894584912, 0.8960224, 0.897442448, 0.898844976, 0.90023, 0.90159768, 0.902948128, 0.904281696, 0.905598864, 0.9069, 0.908185216, 0.909454656, 0.910708496,
-0.911946896, 0.91317, 0.914369696, 0.915529616, 0.916674576, 0.917845856, 0.91906, 0.920309424, 0.921611856, 0.922858368, 0.92388112, 0.92458,
-/* CES80 */ 0.31684, 0.320407168, 0.325632848, 0.331941328, 0.338378688, 0.34433, 0.349892768, 0.355016288, 0.359842928, 0.364680928, 0.36972, 0.374863984,
-0.380072128, 0.385339312, 0.390649056, 0.39599, 0.401366224, 0.406777472, 0.41221656, 0.417673968, 0.42314, 0.428607376, 0.43406864, 0.439508384, 0.444907568,
-0.45025, 0.455527936, 0.460734304, 0.465852432, 0.47086248, 0.47575, 0.48050968, 0.485135072, 0.489626944, 0.493990816, 0.49823, 0.502341072, 0.506322224,
-0.510175376, 0.513903728, 0.51751, 0.520995088, 0.524360176, 0.527608464, 0.530743952, 0.53377, 0.536688096, 0.539499584, 0.542207872, 0.54481704, 0.54733,
-0.549747616, 0.552070912, 0.554299728, 0.556433024, 0.55847, 0.560410784, 0.562255088, 0.564002112, 0.565650816, 0.5672, 0.568648512, 0.569995152, 0.571237712,
-0.572373472, 0.5734, 0.574315776, 0.575119232, 0.575807888, 0.576378944, 0.57683, 0.577159328, 0.577365008, 0.577445888, 0.577401248, 0.57723, 0.576929904,
-0.576498528, 0.575935072, 0.575239296, 0.57441, 0.573444544, 0.572340672, 0.57109392, 0.569698608, 0.56815, 0.566446224, 0.56458568, 0.562563296, 0.560372832,
-0.55801, 0.555474256, 0.552765056, 0.549882336, 0.546826896, 0.5436, 0.540204096, 0.536643296, 0.532917616, 0.529025776, 0.52497, 0.520757584, 0.516395136,
-0.511896448, 0.50727952, 0.50256, 0.497746288, 0.492847408, 0.487878768, 0.482857968, 0.4778, 0.47271464, 0.467612208, 0.462501936, 0.457391824, 0.45229,
-0.44720512, 0.442144736, 0.437117792, 0.432133648, 0.4272, 0.422322144, 0.417505472, 0.4127524, 0.408063488, 0.40344, 0.3988852, 0.39440128, 0.3899908,
-0.38565648, 0.3814, 0.377221872, 0.37312288, 0.369100528, 0.365150576, 0.36127, 0.357459632, 0.353720608, 0.350049184, 0.34643992, 0.34289, 0.339400832,
-0.335972864, 0.332611536, 0.329325168, 0.32612, 0.322996256, 0.319954096, 0.316999376, 0.314139776, 0.31138, 0.308719808, 0.306159776, 0.303695824, 0.301320992,
-0.29903, 0.29682272, 0.294698704, 0.292652768, 0.290678112, 0.28877, 0.28692752, 0.285149168, 0.283435536, 0.281788704, 0.28021, 0.278698352, 0.277252976,
-0.2758752, 0.274567104, 0.27333, 0.27216368, 0.27106824, 0.27004312, 0.26908728, 0.2682, 0.267381408, 0.26663152, 0.265949952, 0.265336224, 0.26479,
-0.264311056, 0.263898992, 0.263554288, 0.263277824, 0.26307, 0.262929616, 0.262854928, 0.2628472, 0.262908752, 0.26304, 0.263238448, 0.26350224, 0.263825552,
-0.264200304, 0.26462, 0.265082832, 0.265587072, 0.266126272, 0.266692432, 0.26728, 0.267887488, 0.268512672, 0.269157296, 0.26982552, 0.27052, 0.271239024,
-0.271981216, 0.272748608, 0.27354432, 0.27437, 0.275224816, 0.276108528, 0.27701832, 0.277949872, 0.2789, 0.279868816, 0.28085656, 0.281860144, 0.282875408,
-0.2839, 0.28493448, 0.285978864, 0.287036368, 0.288112112, 0.28921, 0.290330496, 0.291474368, 0.2926448, 0.293845712, 0.29508, 0.296348816, 0.29765368,
-0.298994464, 0.300370048, 0.30178, 0.30322624, 0.304711024, 0.306233008, 0.307789552, 0.30938, 0.311006944, 0.312672288, 0.314381472, 0.316142016, 0.31796,
-0.31983744, 0.321776512, 0.323782624, 0.325862176, 0.32802, 0.330258304, 0.332579744, 0.334984544, 0.337471424, 0.34004, 0.342692816, 0.345432464, 0.348258032,
-0.35116736, 0.35416, 0.357238288, 0.360403792, 0.363661536, 0.36701848, 0.37048, 0.374046928, 0.377719776, 0.381503824, 0.385405872, 0.38943, 0.393575888,
-0.397843904, 0.40222992, 0.406727056, 0.41133, 0.416038096, 0.42085016, 0.425761504, 0.430766288, 0.43586, 0.441040752, 0.446306304, 0.451655216, 0.457086768,
-0.4626, 0.468193008, 0.473864096, 0.479612544, 0.485438032, 0.49134, 0.497316848, 0.503366704, 0.50948992, 0.515687696, 0.52196, 0.528302976, 0.53471144,
-0.541186944, 0.547733328, 0.55435, 0.561029664, 0.567766384, 0.574545024, 0.581345184, 0.58815, 0.594953136, 0.601748224, 0.608518592, 0.61524416, 0.62191,
-0.628509936, 0.635036272, 0.641489008, 0.647872944, 0.65419, 0.660433456, 0.666597168, 0.67268248, 0.678693232, 0.68463, 0.690487888, 0.69626344, 0.701947152,
-0.707526064, 0.71299, 0.7183372, 0.723566832, 0.728667664, 0.733624976, 0.73843, 0.743083536, 0.747584624, 0.751942832, 0.756174, 0.76029, 0.764290704,
-0.768176272, 0.77195848, 0.775653008, 0.77927, 0.782810288, 0.78627672, 0.789662832, 0.792956624, 0.79615, 0.79924648, 0.802249872, 0.805151984, 0.807940656,
-0.81061, 0.813163728, 0.815602864, 0.81794264, 0.820206096, 0.82241, 0.824552144, 0.82662824, 0.828657456, 0.830666832, 0.83267, 0.834659952, 0.836634576,
-0.83856344, 0.840400144, 0.84211, 0.843693488, 0.84515304, 0.846451952, 0.847540464, 0.84839, 0.84900688, 0.849391232, 0.849577984, 0.849623216, 0.84957,
-0.849422192, 0.849185424, 0.848901376, 0.848623808, 0.84839, 0.84820744, 0.848088096, 0.848025472, 0.848000368, 0.848, 0.848030704, 0.848094992, 0.84818416,
-0.848285808, 0.84839, 0.84849608, 0.84860528, 0.848702, 0.8487644, 0.84878, 0.848749424, 0.84866768, 0.848557856, 0.848457392, 0.84839,
-/* CES81 */ 0.12067, 0.127693984, 0.137899824, 0.150332544, 0.163396224, 0.17602, 0.188332624, 0.200263264, 0.211691744, 0.222606624, 0.23298, 0.242653664,
-0.251489344, 0.259601024, 0.267213744, 0.27447, 0.281325344, 0.287780704, 0.293811824, 0.299365824, 0.30442, 0.309003952, 0.313129024, 0.316867216, 0.320323488,
-0.32357, 0.326610992, 0.329461696, 0.33212232, 0.334577904, 0.33682, 0.338872272, 0.34076448, 0.342456928, 0.343882576, 0.34501, 0.345862416, 0.346442128,
-0.34684304, 0.347206992, 0.34763, 0.348100464, 0.34861168, 0.349195056, 0.349884752, 0.35069, 0.351607104, 0.352652656, 0.353691888, 0.35451896, 0.355,
-0.355151104, 0.354966688, 0.354466432, 0.353700416, 0.35271, 0.351491168, 0.350041472, 0.348439136, 0.34679568, 0.34519, 0.343624256, 0.342102976, 0.340660416,
-0.339333696, 0.33814, 0.337078128, 0.336155856, 0.335298544, 0.334390272, 0.33335, 0.332187376, 0.330911344, 0.329469472, 0.32779352, 0.32585, 0.323643376,
-0.321155392, 0.318507888, 0.315891504, 0.31343, 0.31110384, 0.308913008, 0.306847056, 0.304872944, 0.30296, 0.3011148, 0.299351216, 0.297570992, 0.295628448,
-0.29343, 0.290986288, 0.288292752, 0.285375136, 0.28228664, 0.27907, 0.275717552, 0.272216736, 0.2686532, 0.265151664, 0.26179, 0.258571024, 0.2555256,
-0.252572576, 0.249571632, 0.24644, 0.24320192, 0.239848576, 0.236461552, 0.233174448, 0.23007, 0.227129632, 0.224356912, 0.221709872, 0.219114272, 0.21652,
-0.213933984, 0.211352512, 0.2087668, 0.206171488, 0.20356, 0.200922816, 0.19825304, 0.195540064, 0.192771808, 0.18994, 0.187041008, 0.184069504, 0.18103248,
-0.177943296, 0.17481, 0.171632624, 0.1684172, 0.165149936, 0.161807792, 0.15838, 0.154880736, 0.151323296, 0.147721536, 0.144093456, 0.14046, 0.136839088,
-0.133247056, 0.129730144, 0.126348512, 0.12315, 0.120140816, 0.117317024, 0.114745952, 0.11252024, 0.11069, 0.109245568, 0.108198912, 0.107442896, 0.10680808,
-0.10618, 0.10556496, 0.104949296, 0.104326832, 0.103708608, 0.1031, 0.102475264, 0.101804992, 0.10112648, 0.100503968, 0.09997, 0.099507168, 0.09911848,
-0.098725792, 0.098208864, 0.09749, 0.096581312, 0.095483152, 0.094205232, 0.092772992, 0.09121, 0.089515344, 0.087684752, 0.08578288, 0.083903648, 0.08211,
-0.080411984, 0.07883904, 0.077354656, 0.075886992, 0.0744, 0.072920176, 0.071451216, 0.070069536, 0.068890736, 0.06799, 0.067364704, 0.067029936, 0.066956288,
-0.06708504, 0.06738, 0.067847232, 0.068472848, 0.069291264, 0.07036144, 0.07171, 0.073312, 0.075159184, 0.077170608, 0.079225952, 0.08124, 0.083210528,
-0.085128928, 0.086961008, 0.088671488, 0.09024, 0.091652464, 0.092881888, 0.093997712, 0.095114336, 0.0963, 0.097529328, 0.098792672, 0.100052816, 0.1012484,
-0.10233, 0.103305008, 0.104194336, 0.104893024, 0.105247152, 0.10517, 0.104691952, 0.103821104, 0.102662016, 0.101379568, 0.1001, 0.098836976, 0.097610336,
-0.096533456, 0.095749776, 0.09536, 0.095384976, 0.095853056, 0.096760736, 0.098077776, 0.09978, 0.101889008, 0.104432816, 0.107325664, 0.110434672, 0.11368,
-0.117069744, 0.120579744, 0.124304784, 0.128403024, 0.13297, 0.137985104, 0.143462784, 0.149320064, 0.155417584, 0.16167, 0.168100864, 0.174710144, 0.181542384,
-0.188674704, 0.19616, 0.203974272, 0.212084784, 0.220561616, 0.229510528, 0.23898, 0.248917824, 0.259282656, 0.269969808, 0.28082976, 0.29173, 0.30263728,
-0.313543408, 0.324194336, 0.334237984, 0.34345, 0.3518264, 0.359312656, 0.366060592, 0.372345088, 0.37835, 0.384031264, 0.389373792, 0.39444216, 0.399312448,
-0.40403, 0.408619184, 0.41313408, 0.417447856, 0.421349632, 0.42472, 0.427599232, 0.429976656, 0.43201328, 0.433965584, 0.436, 0.438094688, 0.44026216,
-0.442470752, 0.444646784, 0.44674, 0.44877056, 0.450750512, 0.452637424, 0.454370656, 0.45591, 0.45726408, 0.458443984, 0.459431968, 0.460204832, 0.46076,
-0.461109744, 0.461249888, 0.461288352, 0.461386096, 0.46165, 0.462072384, 0.462659552, 0.46340904, 0.464299568, 0.46531, 0.46645, 0.46773728, 0.46908192,
-0.470348, 0.47145, 0.47239488, 0.4731664, 0.47381968, 0.4744532, 0.47513, 0.475834272, 0.47656592, 0.477317328, 0.478069216, 0.47881, 0.47954752, 0.480285808,
-0.481024416, 0.481762624, 0.4825, 0.483236624, 0.483972416, 0.484707808, 0.48544352, 0.48618, 0.486917152, 0.487655008, 0.488393344, 0.48913176, 0.48987,
-0.490608128, 0.491346144, 0.49208408, 0.492822016, 0.49356, 0.494298, 0.495036, 0.495774, 0.496512, 0.49725, 0.497988, 0.498726, 0.499464, 0.500202, 0.50094,
-0.501678, 0.502416, 0.503154, 0.503892, 0.50463, 0.505368, 0.506106, 0.506844, 0.507582, 0.50832, 0.509057984, 0.50979592, 0.510533856, 0.511271872, 0.51201,
-0.51274824, 0.513486656, 0.514224992, 0.514962848, 0.5157, 0.51643648, 0.517172192, 0.517907584, 0.518643376, 0.51938, 0.520111488, 0.520826144, 0.5215412,
-0.522285376, 0.52307, 0.52388928, 0.52475496, 0.52559072, 0.5262792, 0.52675,
-/* CES82 */ 0.7365, 0.7365, 0.7365, 0.7365, 0.7365, 0.7365, 0.7365, 0.7365, 0.7365, 0.7365, 0.7365, 0.736511632, 0.73655816, 0.736604688, 0.736593056, 0.7365,
-0.736340608, 0.736098528, 0.735914608, 0.736004208, 0.7365, 0.737378912, 0.738650768, 0.740230704, 0.74197152, 0.74377, 0.745637024, 0.747569984, 0.749514864,
-0.751406464, 0.7532, 0.754883408, 0.756439424, 0.75788552, 0.759260256, 0.76059, 0.761864608, 0.76307736, 0.764251072, 0.765418384, 0.7666, 0.767794608,
-0.769004992, 0.770220176, 0.77142, 0.77259, 0.773732672, 0.774849616, 0.77592752, 0.776948144, 0.7779, 0.778783392, 0.7795964, 0.780346688, 0.781047936,
-0.78171, 0.782331264, 0.782910448, 0.783457072, 0.783984256, 0.7845, 0.785003376, 0.785494992, 0.785968448, 0.786412864, 0.78682, 0.787189472, 0.787520208,
-0.787804784, 0.78803368, 0.7882, 0.78830168, 0.788335824, 0.788302448, 0.788203552, 0.78804, 0.787809376, 0.787509728, 0.7871412, 0.786704592, 0.7862,
-0.785626384, 0.7849832, 0.784268096, 0.783477792, 0.78261, 0.781665632, 0.780646496, 0.77954896, 0.778367504, 0.7771, 0.775749456, 0.77431768, 0.772813824,
-0.771251168, 0.76964, 0.767981728, 0.766277424, 0.76453752, 0.762775536, 0.761, 0.759211424, 0.757412, 0.755594656, 0.753746912, 0.75186, 0.749936512,
-0.747979456, 0.7459792, 0.743921824, 0.7418, 0.739617376, 0.73737552, 0.735086784, 0.732770048, 0.73044, 0.728098848, 0.725749184, 0.72340512, 0.721084576,
-0.7188, 0.716554144, 0.7143512, 0.712188736, 0.710059872, 0.70796, 0.705891232, 0.703854336, 0.70184624, 0.699862624, 0.6979, 0.69595728, 0.69403328, 0.6921248,
-0.690228, 0.68834, 0.686460864, 0.68459168, 0.682728416, 0.680865312, 0.679, 0.6771352, 0.675272576, 0.673420992, 0.671593408, 0.6698, 0.668042688, 0.666323392,
-0.664652096, 0.66304144, 0.6615, 0.660029488, 0.658632816, 0.657306704, 0.656044032, 0.65484, 0.653697024, 0.652617344, 0.651595824, 0.650624784, 0.6497,
-0.64882304, 0.647994064, 0.647219408, 0.646508992, 0.64587, 0.645301504, 0.644802048, 0.644379152, 0.644043296, 0.6438, 0.643646656, 0.643582032, 0.643596768,
-0.643676544, 0.64381, 0.643994496, 0.644226608, 0.64449608, 0.644790432, 0.6451, 0.645420496, 0.64574696, 0.646076544, 0.646408128, 0.64674, 0.647068768,
-0.647392144, 0.64770712, 0.648010416, 0.6483, 0.64857552, 0.64883648, 0.64908512, 0.64932528, 0.64956, 0.649790016, 0.65001664, 0.650242144, 0.650469088,
-0.6507, 0.650936848, 0.651181424, 0.65143736, 0.651709056, 0.652, 0.652311104, 0.65264272, 0.652998576, 0.653383472, 0.6538, 0.654247328, 0.654725136,
-0.655229104, 0.655752512, 0.65629, 0.656840064, 0.657400544, 0.657967184, 0.658535104, 0.6591, 0.659659072, 0.660209584, 0.660747616, 0.661268928, 0.66177,
-0.662249312, 0.662706096, 0.6631364, 0.663535264, 0.6639, 0.664231232, 0.66452896, 0.664798128, 0.665046496, 0.66528, 0.665499136, 0.665704528, 0.66590192,
-0.666098752, 0.6663, 0.666507056, 0.66672272, 0.666942944, 0.667160128, 0.66737, 0.667578496, 0.667793504, 0.668008352, 0.66821144, 0.6684, 0.668584608,
-0.668772672, 0.668990896, 0.6692768, 0.66966, 0.670146464, 0.670740976, 0.671474208, 0.67238544, 0.6735, 0.674820064, 0.676351728, 0.678078992, 0.679972256,
-0.68201, 0.684194976, 0.686528192, 0.688989808, 0.691553024, 0.6942, 0.696929056, 0.699735568, 0.70262608, 0.705614432, 0.70871, 0.711908864, 0.71520792,
-0.718615536, 0.722143872, 0.7258, 0.729581024, 0.733484736, 0.737506928, 0.74164096, 0.74588, 0.750216832, 0.754641168, 0.759148864, 0.76373776, 0.7684,
-0.773121312, 0.777890224, 0.782674736, 0.787432448, 0.79213, 0.796761248, 0.801323936, 0.805780624, 0.810082112, 0.8142, 0.81813712, 0.821888464, 0.825495808,
-0.829026912, 0.83253, 0.83599472, 0.839406768, 0.842818256, 0.846303424, 0.8499, 0.853584336, 0.857341776, 0.861104736, 0.864771936, 0.86826, 0.871570848,
-0.874729776, 0.877566864, 0.879836592, 0.8814, 0.882279264, 0.882439104, 0.882115104, 0.881685984, 0.8814, 0.881231808, 0.881210784, 0.88129488, 0.881378976,
-0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814,
-0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814,
-0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814,
-0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814,
-/* CES83 */ 0.5957, 0.5957, 0.5957, 0.5957, 0.5957, 0.5957, 0.5957, 0.5957, 0.5957, 0.5957, 0.5957, 0.595713216, 0.59576608, 0.595818944, 0.595805728, 0.5957,
-0.595516544, 0.595232064, 0.595013664, 0.595117824, 0.5957, 0.596727248, 0.598206704, 0.6000244, 0.601987776, 0.60396, 0.60595056, 0.60795408, 0.60989032,
-0.61166104, 0.6132, 0.614492752, 0.61551664, 0.616302048, 0.616911776, 0.61739, 0.617725152, 0.617911168, 0.617986464, 0.61800424, 0.618, 0.617976192,
-0.617941184, 0.617884496, 0.617782688, 0.61762, 0.617403712, 0.617139376, 0.61681296, 0.616403904, 0.6159, 0.615303792, 0.61461472, 0.613844288, 0.613011456,
-0.61213, 0.611198336, 0.610214688, 0.60919264, 0.608150832, 0.6071, 0.606037968, 0.6049652, 0.603868192, 0.602725184, 0.60152, 0.600253344, 0.598926192,
-0.59752184, 0.596017648, 0.5944, 0.592670128, 0.59082616, 0.588882032, 0.586861024, 0.58478, 0.58263664, 0.580428352, 0.578172144, 0.575891536, 0.5736,
-0.57129488, 0.568977264, 0.566628848, 0.564220192, 0.56173, 0.55916144, 0.556519008, 0.553780016, 0.550912704, 0.5479, 0.544748672, 0.541460816, 0.53806448,
-0.534603104, 0.53111, 0.527588352, 0.52404184, 0.520502768, 0.517012896, 0.5136, 0.510267744, 0.507023808, 0.503856272, 0.500740736, 0.49766, 0.494618656,
-0.491619472, 0.488646688, 0.485678304, 0.4827, 0.479711824, 0.476711488, 0.473704352, 0.470701216, 0.46771, 0.464730176, 0.461762752, 0.458813728, 0.455890704,
-0.453, 0.45014472, 0.447328208, 0.444555296, 0.441831104, 0.43916, 0.43654416, 0.433984816, 0.431487152, 0.429057648, 0.4267, 0.424413776, 0.422199312,
-0.420051088, 0.417960224, 0.41592, 0.413930272, 0.411990848, 0.410094864, 0.4082332, 0.4064, 0.404595312, 0.402818464, 0.401072096, 0.399361248, 0.39769,
-0.396059424, 0.394471616, 0.392929888, 0.391438, 0.39, 0.38861936, 0.387299168, 0.386046736, 0.384871264, 0.38378, 0.382774272, 0.381854096, 0.38102816,
-0.380308064, 0.3797, 0.379201968, 0.37881376, 0.378523712, 0.378313664, 0.37817, 0.378092544, 0.378081312, 0.37812288, 0.378199408, 0.3783, 0.378424928,
-0.378572, 0.378752112, 0.378983744, 0.37928, 0.37963792, 0.380054032, 0.380541824, 0.381120416, 0.3818, 0.382575968, 0.383446384, 0.3843948, 0.385395936,
-0.38643, 0.387493792, 0.38858352, 0.389679728, 0.390757696, 0.3918, 0.392802192, 0.393757728, 0.394669104, 0.3955448, 0.39639, 0.397200096, 0.397971824,
-0.398708832, 0.39941704, 0.4001, 0.400756672, 0.401386992, 0.401988512, 0.402557152, 0.40309, 0.403587648, 0.404050832, 0.404476496, 0.40486032, 0.4052,
-0.405496528, 0.405750256, 0.405965024, 0.406146752, 0.4063, 0.40642512, 0.406522384, 0.406596688, 0.406654512, 0.4067, 0.406733392, 0.406755728, 0.406764144,
-0.40675344, 0.40672, 0.406664704, 0.406588224, 0.406487664, 0.406359024, 0.4062, 0.406011776, 0.405795664, 0.405552432, 0.4052832, 0.40499, 0.4046776,
-0.404352832, 0.404015664, 0.403663776, 0.4033, 0.402934352, 0.402575104, 0.402243376, 0.401967328, 0.40177, 0.401659232, 0.401642256, 0.4017424, 0.401988304,
-0.4024, 0.402983184, 0.40374592, 0.404682896, 0.405780512, 0.40703, 0.408437184, 0.410006736, 0.411730848, 0.41359752, 0.4156, 0.417740704, 0.420019888,
-0.422446112, 0.425032896, 0.42779, 0.430715904, 0.433808272, 0.43707768, 0.440539008, 0.4442, 0.448056944, 0.4521084, 0.456339936, 0.460729472, 0.46526,
-0.469929632, 0.474736336, 0.47966344, 0.484689424, 0.4898, 0.494991856, 0.50025816, 0.505609584, 0.511066208, 0.51664, 0.522318368, 0.528085184, 0.5339564,
-0.539958736, 0.5461, 0.552360448, 0.5587284, 0.565145232, 0.571526944, 0.57781, 0.583991968, 0.590076512, 0.595994576, 0.60165296, 0.607, 0.612047168,
-0.616790256, 0.621316224, 0.625762912, 0.63023, 0.634702688, 0.639158544, 0.64370488, 0.648492256, 0.6536, 0.65898792, 0.66463368, 0.6704104, 0.67612496,
-0.68162, 0.686897472, 0.69199624, 0.696617968, 0.700334576, 0.7029, 0.704345888, 0.704607488, 0.704071808, 0.703365888, 0.7029, 0.702627616, 0.702593568,
-0.70272976, 0.702865952, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029,
-0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029,
-0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029,
-0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029,
-/* CES84 */ 0.03375, 0.033978288, 0.03431224, 0.034718992, 0.035143504, 0.03555, 0.035944, 0.036322592, 0.036691424, 0.037064496, 0.03745, 0.037842928,
-0.038242064, 0.03864336, 0.039040416, 0.03943, 0.03981264, 0.040186, 0.0405612, 0.04095576, 0.04138, 0.04183184, 0.04231312, 0.04281184, 0.04330832, 0.04379,
-0.044259168, 0.0447152, 0.045161472, 0.045604864, 0.04605, 0.046495568, 0.046941232, 0.047388976, 0.04784128, 0.0483, 0.048765424, 0.049237616, 0.049718048,
-0.05020856, 0.05071, 0.051221712, 0.051743168, 0.052272144, 0.05280536, 0.05334, 0.053874848, 0.054408464, 0.05493784, 0.055459376, 0.05597, 0.056467904,
-0.0569512, 0.057417536, 0.057864512, 0.05829, 0.0586928, 0.059072256, 0.059425472, 0.059748768, 0.06004, 0.060299376, 0.060526672, 0.060724688, 0.060897984,
-0.06105, 0.061180672, 0.061289888, 0.061381424, 0.0614604, 0.06153, 0.061590144, 0.061641424, 0.061681264, 0.061705184, 0.06171, 0.061696464, 0.061665424,
-0.061613504, 0.061535904, 0.06143, 0.061297024, 0.061137744, 0.060955344, 0.060754704, 0.06054, 0.060313344, 0.060077664, 0.059836224, 0.059592384, 0.05935,
-0.059112912, 0.058884464, 0.058672016, 0.058484768, 0.05833, 0.0582096, 0.058124576, 0.058082352, 0.058092368, 0.05816, 0.058284224, 0.058464912, 0.05869496,
-0.058963008, 0.05926, 0.059583648, 0.05993056, 0.060293072, 0.060662144, 0.06103, 0.061391936, 0.061743152, 0.062077408, 0.062388224, 0.06267, 0.06291944,
-0.063134208, 0.063308416, 0.063435104, 0.06351, 0.06353272, 0.063502256, 0.063423312, 0.063303888, 0.06315, 0.062961536, 0.062738592, 0.062487328, 0.062215984,
-0.06193, 0.061629824, 0.061316768, 0.060988592, 0.060640736, 0.06027, 0.059877552, 0.059464512, 0.059027152, 0.058560032, 0.05806, 0.057528048, 0.056964512,
-0.056372336, 0.05575624, 0.05512, 0.05446432, 0.053790096, 0.053101392, 0.052403408, 0.0517, 0.050992528, 0.050282832, 0.049571216, 0.04885696, 0.04814,
-0.047422, 0.046704336, 0.045987712, 0.045272688, 0.04456, 0.043850768, 0.043146112, 0.042447056, 0.04175464, 0.04107, 0.040394592, 0.039730096, 0.03907736,
-0.038436944, 0.03781, 0.037198496, 0.03660416, 0.036030304, 0.035481088, 0.03496, 0.034468288, 0.034006784, 0.03357984, 0.033193056, 0.03285, 0.032551056,
-0.03229736, 0.032085904, 0.031911328, 0.03177, 0.031663312, 0.031592784, 0.031554976, 0.031544848, 0.03156, 0.031602128, 0.031671936, 0.031775664, 0.031922672,
-0.03212, 0.03236784, 0.032666064, 0.033021808, 0.033444592, 0.03394, 0.034506848, 0.035144768, 0.035848448, 0.036609248, 0.03742, 0.038278016, 0.039179328,
-0.04011792, 0.041087072, 0.04208, 0.043090912, 0.04411424, 0.045140608, 0.046158976, 0.04716, 0.048139392, 0.049093888, 0.050013184, 0.05088464, 0.0517,
-0.052457968, 0.053156144, 0.05379976, 0.054398656, 0.05496, 0.055483008, 0.05596768, 0.056420272, 0.056849104, 0.05726, 0.057654064, 0.058033152, 0.0583968,
-0.058742848, 0.05907, 0.059379792, 0.05967352, 0.059949648, 0.060205616, 0.06044, 0.060653616, 0.060846928, 0.0610212, 0.061178352, 0.06132, 0.06144696,
-0.0615604, 0.06166136, 0.0617508, 0.06183, 0.061900512, 0.06196368, 0.062022368, 0.062080176, 0.06214, 0.062202752, 0.062269088, 0.062341904, 0.0624248,
-0.06252, 0.062627568, 0.062747904, 0.06287896, 0.063017216, 0.06316, 0.063307056, 0.06345776, 0.063610064, 0.063761488, 0.06391, 0.064054544, 0.064193984,
-0.064327104, 0.064452784, 0.06457, 0.064677968, 0.064776144, 0.06486336, 0.064938256, 0.065, 0.065048592, 0.065084, 0.065106928, 0.065118576, 0.06512,
-0.065111472, 0.065093248, 0.065067104, 0.06503544, 0.065, 0.064961232, 0.064919824, 0.064875936, 0.064829328, 0.06478, 0.06472848, 0.064675056, 0.064620352,
-0.064565168, 0.06451, 0.064454816, 0.064399632, 0.064344128, 0.064287744, 0.06423, 0.064170752, 0.064109808, 0.064046544, 0.06398016, 0.06391, 0.0638364,
-0.063760304, 0.063679328, 0.063589792, 0.06349, 0.063380464, 0.063259968, 0.063136032, 0.063020336, 0.06292, 0.062834032, 0.062763792, 0.062702912, 0.062640592,
-0.06257, 0.062492576, 0.062407952, 0.062318368, 0.062228144, 0.06214, 0.062052976, 0.061966848, 0.06188136, 0.061795872, 0.06171, 0.061624016, 0.06153808,
-0.061452144, 0.061366128, 0.06128, 0.061193776, 0.061107424, 0.061021152, 0.06093528, 0.06085, 0.06076528, 0.060681152, 0.060597424, 0.060513776, 0.06043,
-0.060346144, 0.060262224, 0.060178224, 0.060094144, 0.06001, 0.059925776, 0.059841424, 0.059757152, 0.05967328, 0.05959, 0.05950728, 0.059425152, 0.059343424,
-0.059261776, 0.05918, 0.059098128, 0.059016144, 0.05893408, 0.058852016, 0.05877, 0.058688016, 0.05860608, 0.058524144, 0.058442128, 0.05836, 0.058277776,
-0.058195424, 0.058113152, 0.05803128, 0.05795, 0.05786928, 0.057789152, 0.057709424, 0.057629776, 0.05755, 0.057470128, 0.057390144, 0.05731008, 0.057230016,
-0.05715, 0.05707064, 0.0569932, 0.05691576, 0.05683512, 0.05675, 0.05666104, 0.05656696, 0.05647608, 0.0564012, 0.05635,
-/* CES85 */ 0.01425, 0.013956016, 0.013523232, 0.013083008, 0.012839024, 0.01292, 0.013318544, 0.014067888, 0.015078592, 0.016190736, 0.01731, 0.018456512,
-0.019601552, 0.020864352, 0.022440912, 0.02444, 0.026830464, 0.029638032, 0.03267104, 0.035624768, 0.03831, 0.040756784, 0.04294016, 0.044980416, 0.047088672,
-0.0494, 0.05187808, 0.054515456, 0.057304832, 0.060220048, 0.06324, 0.066375744, 0.069641792, 0.0729796, 0.076300608, 0.07955, 0.082734736, 0.08584704,
-0.088925744, 0.092038368, 0.09523, 0.09848736, 0.101800144, 0.105192608, 0.108697552, 0.11233, 0.116072368, 0.119906048, 0.123805648, 0.127736608, 0.13166,
-0.13556192, 0.139447728, 0.143182976, 0.146573904, 0.1495, 0.151978, 0.154002816, 0.155640752, 0.157009408, 0.1582, 0.159200272, 0.159987712, 0.160712432,
-0.161590192, 0.16275, 0.164180832, 0.165914512, 0.167796752, 0.169571152, 0.17107, 0.172326192, 0.173344272, 0.174115792, 0.174649792, 0.17496, 0.175035008,
-0.174853632, 0.174517776, 0.17418344, 0.17395, 0.173806096, 0.173762496, 0.173778176, 0.173775136, 0.1737, 0.173565952, 0.173378816, 0.1731072, 0.172709744,
-0.17216, 0.171463056, 0.17062568, 0.169646144, 0.168524288, 0.16727, 0.165885216, 0.164353744, 0.162779792, 0.1613204, 0.16007, 0.159019872, 0.158196672,
-0.157490112, 0.156716032, 0.15576, 0.154641856, 0.153343392, 0.151938048, 0.150554784, 0.14927, 0.14806312, 0.146946288, 0.145841776, 0.144621664, 0.14321,
-0.141629888, 0.139880896, 0.138024304, 0.136160912, 0.13436, 0.132612576, 0.130916624, 0.129301872, 0.1278032, 0.12644, 0.12521432, 0.124135632, 0.123157664,
-0.122205856, 0.12123, 0.120234608, 0.119215744, 0.11816832, 0.117092976, 0.11599, 0.114851472, 0.11366856, 0.112456768, 0.111242096, 0.11004, 0.108852544,
-0.107693648, 0.106530432, 0.105308336, 0.104, 0.102618576, 0.101159072, 0.099694448, 0.098337344, 0.09716, 0.09615616, 0.095337168, 0.094672256, 0.094102384,
-0.09359, 0.093140592, 0.092742976, 0.09241912, 0.092209184, 0.09213, 0.092168256, 0.09233032, 0.092533664, 0.092652848, 0.09261, 0.092415552, 0.092059664,
-0.091593536, 0.091106848, 0.09066, 0.0902388, 0.089835776, 0.089478112, 0.089200128, 0.08902, 0.08894104, 0.088979632, 0.089073744, 0.089124016, 0.08907,
-0.088918496, 0.088652064, 0.088333472, 0.08806872, 0.08792, 0.087872288, 0.087937792, 0.088043056, 0.088068, 0.08794, 0.087680784, 0.087293536, 0.086820208,
-0.08633168, 0.08588, 0.085459984, 0.085066528, 0.084753072, 0.084592816, 0.08463, 0.084868016, 0.085327872, 0.085944128, 0.086607184, 0.08725, 0.087881584,
-0.088482928, 0.089118272, 0.089896816, 0.09088, 0.092047728, 0.093409632, 0.094873936, 0.09629448, 0.09758, 0.09873512, 0.099729216, 0.100643872, 0.101621008,
-0.10274, 0.1039772, 0.105353392, 0.106734304, 0.107905776, 0.10874, 0.109266624, 0.109473264, 0.109477344, 0.109473424, 0.10959, 0.109811216, 0.110148704,
-0.110595792, 0.11112176, 0.11171, 0.112377344, 0.113128272, 0.11397632, 0.114941008, 0.11603, 0.117242352, 0.11858992, 0.120013648, 0.121421856, 0.12276,
-0.124029776, 0.125205408, 0.12637016, 0.127662032, 0.12916, 0.130841456, 0.13271872, 0.134684864, 0.136568048, 0.13826, 0.139796688, 0.141204144, 0.14247696,
-0.143614736, 0.14464, 0.145567424, 0.14638232, 0.147289856, 0.148594032, 0.15049, 0.152944464, 0.155944896, 0.159469488, 0.16346064, 0.16785, 0.172644768,
-0.177897648, 0.183279168, 0.188296528, 0.19265, 0.196372384, 0.199401328, 0.202025632, 0.204733376, 0.20784, 0.211270576, 0.215006112, 0.219051008, 0.223374944,
-0.22794, 0.23276704, 0.237903168, 0.243121056, 0.248074704, 0.25255, 0.256569808, 0.260093296, 0.263287424, 0.266441312, 0.26974, 0.27313408, 0.276611184,
-0.280163728, 0.283759792, 0.28737, 0.2910048, 0.294682208, 0.298300896, 0.301709024, 0.30481, 0.30762584, 0.310170256, 0.312449232, 0.314481888, 0.3163,
-0.317900608, 0.319246592, 0.320549296, 0.32212712, 0.32417, 0.326658784, 0.329647856, 0.332901008, 0.3360264, 0.33878, 0.341210848, 0.343296928, 0.345165968,
-0.347045728, 0.34908, 0.351230208, 0.353496208, 0.355844288, 0.358206368, 0.36054, 0.362862672, 0.365179808, 0.367494944, 0.36981664, 0.37215, 0.374491696,
-0.376840144, 0.379194832, 0.38155496, 0.38392, 0.386289952, 0.388664672, 0.391044352, 0.393429392, 0.39582, 0.398216, 0.400617312, 0.403023664, 0.405434656,
-0.40785, 0.410269632, 0.412693424, 0.415121376, 0.417553568, 0.41999, 0.422430528, 0.424875056, 0.427323344, 0.429775072, 0.43223, 0.434688032, 0.437149024,
-0.439612976, 0.442079968, 0.44455, 0.447022928, 0.449498656, 0.451976944, 0.454457472, 0.45694, 0.459424432, 0.461910624, 0.464398576, 0.466888368, 0.46938,
-0.471873328, 0.474368256, 0.476864544, 0.479361872, 0.48186, 0.484358848, 0.486858304, 0.48935832, 0.491858896, 0.49436, 0.496861472, 0.49936312, 0.501865008,
-0.504367296, 0.50687, 0.509353072, 0.511776688, 0.514199904, 0.51672128, 0.51938, 0.522156208, 0.525089664, 0.52792176, 0.530254736, 0.53185,
-/* CES86 */ 0.50252, 0.50398984, 0.506139408, 0.508748016, 0.511447904, 0.514, 0.51643792, 0.518737056, 0.520943312, 0.523163888, 0.52546, 0.527799376,
-0.530184992, 0.532553888, 0.534806304, 0.53689, 0.538813376, 0.540544288, 0.54223488, 0.544126032, 0.54636, 0.54890624, 0.55178624, 0.55484408, 0.55782368,
-0.56056, 0.563079232, 0.56536936, 0.567467328, 0.569453696, 0.57138, 0.573224016, 0.574976928, 0.57665024, 0.578257792, 0.57981, 0.581306944, 0.58274528,
-0.584138976, 0.585507792, 0.58686, 0.588187184, 0.589484976, 0.590722048, 0.59185216, 0.59284, 0.59368272, 0.594378928, 0.594892496, 0.595175824, 0.5952,
-0.59496696, 0.594473296, 0.593745872, 0.592830048, 0.59176, 0.59053616, 0.589160832, 0.587666704, 0.586096656, 0.58448, 0.582821536, 0.581130064, 0.579398512,
-0.57760912, 0.57575, 0.573826992, 0.571843792, 0.569790432, 0.567652352, 0.56542, 0.563093728, 0.560672272, 0.558157936, 0.55555592, 0.55287, 0.550099472,
-0.547244336, 0.54430808, 0.541295424, 0.53821, 0.53505344, 0.53182816, 0.52853448, 0.5251716, 0.52174, 0.518243472, 0.51468624, 0.511069408, 0.507393216,
-0.50366, 0.499874944, 0.496042528, 0.492172672, 0.488278256, 0.48437, 0.480451824, 0.476526992, 0.47260752, 0.468708448, 0.46484, 0.461004128, 0.45720392,
-0.453436112, 0.449693264, 0.44597, 0.442267088, 0.438583952, 0.434916656, 0.43126016, 0.42761, 0.423963872, 0.420319616, 0.41667176, 0.413013584, 0.40934,
-0.405650688, 0.40194664, 0.398221712, 0.394467424, 0.39068, 0.38686304, 0.383019072, 0.379158864, 0.375298176, 0.37145, 0.367618928, 0.363810464, 0.36003688,
-0.356312736, 0.35265, 0.349055072, 0.34553464, 0.342095968, 0.338745776, 0.33549, 0.332332112, 0.329273408, 0.326322224, 0.32348928, 0.32078, 0.318191616,
-0.315722384, 0.313361312, 0.31109176, 0.3089, 0.306780752, 0.304726912, 0.302727152, 0.300768832, 0.29884, 0.296931728, 0.295035712, 0.293137456, 0.29122008,
-0.28927, 0.287283312, 0.285258976, 0.28318096, 0.281028304, 0.27879, 0.276470784, 0.27407328, 0.271616816, 0.269130992, 0.26664, 0.264151936, 0.261677616,
-0.259238256, 0.256858496, 0.25456, 0.252356736, 0.250262656, 0.248296976, 0.246480256, 0.24483, 0.243354416, 0.242057696, 0.240961616, 0.240093856, 0.23947,
-0.23908648, 0.238942176, 0.239015952, 0.239274288, 0.23969, 0.240254832, 0.240956992, 0.241772992, 0.242675472, 0.24364, 0.244649936, 0.245688272, 0.246732928,
-0.247760464, 0.24875, 0.249688208, 0.250564608, 0.251355728, 0.252033808, 0.25258, 0.252990352, 0.253259968, 0.253394624, 0.2534076, 0.25331, 0.25310656,
-0.252807264, 0.252417648, 0.251941712, 0.25139, 0.250781584, 0.250134368, 0.249482272, 0.248868256, 0.24833, 0.247889472, 0.247570112, 0.247407872, 0.247442832,
-0.24771, 0.248234064, 0.249040432, 0.2501536, 0.251596128, 0.25339, 0.255555456, 0.25810936, 0.261077104, 0.264486928, 0.26836, 0.272706128, 0.277535664,
-0.28284952, 0.288642176, 0.29491, 0.301654912, 0.3088744, 0.316569488, 0.324742416, 0.33339, 0.342500352, 0.352060848, 0.362053904, 0.37245776, 0.38325,
-0.394406192, 0.405895664, 0.417702336, 0.429815008, 0.44221, 0.45484664, 0.467688096, 0.480665952, 0.493694448, 0.5067, 0.519645584, 0.532495312, 0.5451744,
-0.557595088, 0.56969, 0.581427696, 0.59277264, 0.603711984, 0.614248848, 0.62438, 0.634083232, 0.643344144, 0.652151536, 0.660495248, 0.66837, 0.67577552,
-0.682711936, 0.689192832, 0.695239488, 0.70087, 0.70609264, 0.710918912, 0.715364224, 0.719445376, 0.72318, 0.726585072, 0.729677584, 0.732481456, 0.735023968,
-0.73733, 0.739417008, 0.741301936, 0.743010624, 0.744571952, 0.74601, 0.747340448, 0.748579984, 0.74973968, 0.750826736, 0.75185, 0.752823104, 0.75375784,
-0.754665216, 0.755556032, 0.75644, 0.757325728, 0.758221776, 0.759133584, 0.760064832, 0.76102, 0.762006144, 0.763029824, 0.764095664, 0.765207584, 0.76637,
-0.767578912, 0.768820464, 0.770128736, 0.771557088, 0.77313, 0.774839408, 0.776702496, 0.778610304, 0.780393792, 0.78195, 0.783293088, 0.784402944, 0.78537312,
-0.786362096, 0.78747, 0.788675664, 0.78998456, 0.791362656, 0.792744832, 0.79409, 0.795412224, 0.796714576, 0.798000848, 0.79928072, 0.80056, 0.80183472,
-0.803102848, 0.804364576, 0.805620224, 0.80687, 0.808113872, 0.809351856, 0.81058392, 0.811809984, 0.81303, 0.814243984, 0.81545192, 0.816653856, 0.817849872,
-0.81904, 0.82022424, 0.821402656, 0.822574992, 0.823740848, 0.8249, 0.826052496, 0.827198272, 0.828337728, 0.829471504, 0.8306, 0.831723152, 0.832841008,
-0.833953344, 0.83505976, 0.83616, 0.837254144, 0.838342224, 0.839424224, 0.840500144, 0.84157, 0.842633792, 0.843691504, 0.844743296, 0.845789408, 0.84683,
-0.847865072, 0.848894656, 0.84991872, 0.850937184, 0.85195, 0.8529572, 0.8539588, 0.8549548, 0.8559452, 0.85693, 0.8579092, 0.8588828, 0.8598508, 0.8608132,
-0.86177, 0.862714128, 0.86363144, 0.864543152, 0.865484624, 0.86647, 0.867492432, 0.868566512, 0.869599632, 0.870449232, 0.87103,
-/* CES87 */ 0.10613, 0.107359696, 0.10915952, 0.111354624, 0.113651568, 0.11586, 0.118005152, 0.120061824, 0.122077456, 0.124154928, 0.12635, 0.128643424,
-0.131064016, 0.133486768, 0.13571328, 0.13764, 0.13928368, 0.140579488, 0.141833936, 0.143532064, 0.14596, 0.149056464, 0.152865696, 0.157069648, 0.1611464,
-0.16476, 0.16796432, 0.170735248, 0.173150976, 0.175379824, 0.17753, 0.179559008, 0.181453936, 0.183234144, 0.184919872, 0.18653, 0.188079152, 0.189580384,
-0.191054496, 0.192524928, 0.19401, 0.195519072, 0.197061856, 0.19864352, 0.200265584, 0.20193, 0.203639952, 0.20539496, 0.207200768, 0.209065216, 0.21099,
-0.212967952, 0.214992928, 0.217046464, 0.2191028, 0.22114, 0.2231476, 0.225113264, 0.227016928, 0.228835952, 0.23055, 0.232144928, 0.233606928, 0.234916768,
-0.236053648, 0.237, 0.237747568, 0.238291648, 0.238611888, 0.238682848, 0.23849, 0.23803568, 0.237319888, 0.236362176, 0.235193904, 0.23384, 0.232305792,
-0.230598896, 0.22874256, 0.226765344, 0.22469, 0.2225276, 0.22029088, 0.21798944, 0.21562992, 0.21322, 0.21077032, 0.20828968, 0.20578792, 0.20327528, 0.20076,
-0.198247664, 0.19574408, 0.193250576, 0.190765872, 0.18829, 0.185826784, 0.183379136, 0.180948288, 0.17853496, 0.17614, 0.173764752, 0.171410288, 0.169077504,
-0.1667672, 0.16448, 0.16221576, 0.159973664, 0.157755488, 0.155563952, 0.1534, 0.151261936, 0.149148688, 0.1470552, 0.144974192, 0.1429, 0.140831472,
-0.13876736, 0.136702768, 0.134631696, 0.13255, 0.130456912, 0.128351488, 0.126234144, 0.12410648, 0.12197, 0.119825824, 0.117676224, 0.115521744, 0.113362384,
-0.1112, 0.1090388, 0.106882464, 0.104739808, 0.102622432, 0.10054, 0.098496416, 0.096495488, 0.09454688, 0.092662192, 0.09085, 0.089113248, 0.0874552,
-0.085877232, 0.084378944, 0.08296, 0.081620304, 0.080357792, 0.079174, 0.078071648, 0.07705, 0.076103712, 0.0752284, 0.074410848, 0.073632976, 0.07288,
-0.072147024, 0.071429248, 0.070711792, 0.069976416, 0.06921, 0.068408592, 0.067567232, 0.066686192, 0.065769552, 0.06482, 0.063836, 0.062818032, 0.061766864,
-0.060683376, 0.05957, 0.058430656, 0.057269024, 0.056094112, 0.05491776, 0.05375, 0.05259528, 0.051458032, 0.050348624, 0.049279456, 0.04826, 0.047295408,
-0.046392464, 0.04555008, 0.044763056, 0.04403, 0.043361072, 0.04276848, 0.042248528, 0.041792176, 0.0414, 0.041087024, 0.040864768, 0.040766112, 0.040835856,
-0.04111, 0.041597792, 0.042306592, 0.043274992, 0.044552112, 0.04617, 0.048130304, 0.050437392, 0.05307896, 0.056030208, 0.05927, 0.062787744, 0.066564,
-0.070587376, 0.074849312, 0.07933, 0.083997008, 0.088822176, 0.093739184, 0.098661952, 0.10352, 0.108289296, 0.112949824, 0.117427792, 0.1216316, 0.1255,
-0.12902216, 0.132179872, 0.135000544, 0.137540336, 0.13984, 0.14189408, 0.143703904, 0.145303408, 0.146737392, 0.14804, 0.14922304, 0.150301808, 0.151287696,
-0.152187584, 0.15301, 0.153768416, 0.154473856, 0.155137296, 0.155769696, 0.15638, 0.156974944, 0.157561456, 0.158140608, 0.15871024, 0.15927, 0.159824672,
-0.160378128, 0.160931504, 0.16148512, 0.16204, 0.162598656, 0.163163264, 0.163736432, 0.16432112, 0.16492, 0.165534784, 0.166166992, 0.16681968, 0.167496448,
-0.1682, 0.168931056, 0.16969008, 0.170478624, 0.171298368, 0.17215, 0.173031344, 0.173938704, 0.174874064, 0.175841264, 0.17684, 0.177863984, 0.178908144,
-0.179958064, 0.180994064, 0.182, 0.18297048, 0.183900304, 0.184772848, 0.185567632, 0.18627, 0.18687592, 0.187380368, 0.187783376, 0.188088944, 0.1883,
-0.18841632, 0.188440576, 0.188372672, 0.188211568, 0.18796, 0.18762512, 0.187212832, 0.186740944, 0.186233536, 0.18571, 0.185175664, 0.184634944, 0.184108144,
-0.183620864, 0.18319, 0.182817664, 0.182507984, 0.182253184, 0.182037744, 0.18185, 0.181689856, 0.181554864, 0.181435712, 0.1813208, 0.1812, 0.181073344,
-0.180946512, 0.18079224, 0.180570368, 0.18026, 0.179863792, 0.17936936, 0.178839328, 0.178372736, 0.17803, 0.177801376, 0.177697008, 0.177666, 0.177621872,
-0.17751, 0.177342144, 0.17711552, 0.176848416, 0.176575872, 0.17632, 0.176073216, 0.175833856, 0.175599936, 0.175366336, 0.17513, 0.174893056, 0.174656576,
-0.174420576, 0.174185056, 0.17395, 0.173715424, 0.173481376, 0.173247648, 0.17301392, 0.17278, 0.172545904, 0.172311568, 0.172077232, 0.171843296, 0.17161,
-0.171377296, 0.171145232, 0.170913568, 0.170681904, 0.17045, 0.17021792, 0.169985648, 0.169753376, 0.169521424, 0.16929, 0.169059056, 0.168828576, 0.168598576,
-0.168369056, 0.16814, 0.167911424, 0.167683376, 0.167455648, 0.16722792, 0.167, 0.16677192, 0.166543648, 0.166315376, 0.166087424, 0.16586, 0.165633056,
-0.165406576, 0.165180576, 0.164955056, 0.16473, 0.164505424, 0.164281376, 0.164057648, 0.16383392, 0.16361, 0.163385904, 0.163161568, 0.162937232, 0.162713296,
-0.16249, 0.162269056, 0.162054032, 0.161839408, 0.161615984, 0.16138, 0.161133264, 0.160872208, 0.160619952, 0.160412096, 0.16027,
-/* CES88 */ 0.4264, 0.428181152, 0.430786192, 0.433949152, 0.437226512, 0.44033, 0.443291792, 0.446065152, 0.448731312, 0.451462592, 0.45435, 0.457367456,
-0.460578672, 0.463731168, 0.466424784, 0.46845, 0.469840608, 0.470467728, 0.470941728, 0.472228928, 0.4749, 0.478832592, 0.484113248, 0.490115904, 0.49581232,
-0.50054, 0.504404352, 0.507357184, 0.509548256, 0.511303088, 0.51283, 0.514042944, 0.514913056, 0.515477008, 0.51577424, 0.51584, 0.515697024, 0.515363888,
-0.514879872, 0.514291136, 0.51363, 0.512907312, 0.512137312, 0.511308832, 0.510395552, 0.50938, 0.508271184, 0.507075792, 0.5057788, 0.504357968, 0.5028,
-0.501108336, 0.49928376, 0.497335824, 0.495280368, 0.49313, 0.490887376, 0.488556624, 0.486149392, 0.48367992, 0.48116, 0.478596016, 0.475994992, 0.473364208,
-0.470710384, 0.46804, 0.465358848, 0.462671648, 0.459985888, 0.457309968, 0.45465, 0.452008544, 0.449388448, 0.446788912, 0.444206816, 0.44164, 0.439089264,
-0.436554432, 0.43403416, 0.431526688, 0.42903, 0.426542048, 0.42406056, 0.421582272, 0.419103344, 0.41662, 0.414128784, 0.411625792, 0.40910744, 0.406570208,
-0.40401, 0.401422304, 0.39880296, 0.396144896, 0.393439632, 0.39068, 0.38786288, 0.384985936, 0.382040992, 0.379017888, 0.37591, 0.372717104, 0.369438752,
-0.36607696, 0.362635968, 0.35912, 0.355532976, 0.3518812, 0.348167024, 0.344391568, 0.34056, 0.336682, 0.332765424, 0.328832528, 0.324912912, 0.32103,
-0.317188832, 0.313392288, 0.309665664, 0.30604168, 0.30254, 0.299159056, 0.295900624, 0.292745152, 0.28966, 0.28662, 0.283622768, 0.280664032, 0.277719776,
-0.27475928, 0.27176, 0.268716064, 0.265620416, 0.262467408, 0.25925448, 0.25598, 0.252645344, 0.249257488, 0.245808752, 0.242286496, 0.23869, 0.235035136,
-0.231334112, 0.227628208, 0.223974784, 0.22042, 0.216976192, 0.213654608, 0.210502944, 0.20758112, 0.20493, 0.20255688, 0.200473024, 0.198668128, 0.197116832,
-0.1958, 0.194716992, 0.193860128, 0.193217664, 0.19277656, 0.19252, 0.192429456, 0.192487664, 0.192657312, 0.1928912, 0.19315, 0.193417648, 0.193680032,
-0.193896576, 0.194018, 0.19401, 0.1938648, 0.193572736, 0.193138192, 0.192576368, 0.1919, 0.191114048, 0.190231632, 0.189258096, 0.18819632, 0.18706,
-0.185875648, 0.184666656, 0.183489104, 0.182416512, 0.18151, 0.180793424, 0.180289184, 0.180059984, 0.180181984, 0.18071, 0.181660848, 0.183055104, 0.18489176,
-0.187153936, 0.18983, 0.19292424, 0.19643152, 0.20034928, 0.20467576, 0.2094, 0.214500896, 0.2199592, 0.225727184, 0.231741808, 0.23795, 0.244328448,
-0.250853264, 0.25747224, 0.264123776, 0.27076, 0.277357728, 0.28389072, 0.290347152, 0.296725504, 0.30302, 0.309212144, 0.315288192, 0.32123752, 0.327050528,
-0.33272, 0.33824008, 0.34360464, 0.34881832, 0.35389128, 0.35883, 0.363629856, 0.36828616, 0.372805584, 0.377198608, 0.38147, 0.38561512, 0.389631344,
-0.393505968, 0.397220352, 0.40076, 0.404122768, 0.407306768, 0.410297408, 0.413075888, 0.41563, 0.417959136, 0.420061328, 0.42194208, 0.423612112, 0.42508,
-0.426347968, 0.42742072, 0.428304192, 0.429004704, 0.42953, 0.429889456, 0.430092832, 0.430152768, 0.430083744, 0.4299, 0.429614912, 0.429243488, 0.428798624,
-0.42829256, 0.42774, 0.427158784, 0.426566144, 0.425985904, 0.425445504, 0.42497, 0.42457752, 0.424286464, 0.424121168, 0.424107872, 0.42427, 0.424625616,
-0.425193088, 0.42598936, 0.427029872, 0.42833, 0.429905024, 0.4317684, 0.433937136, 0.436429392, 0.43926, 0.442437856, 0.445971296, 0.449867616, 0.454132416,
-0.45877, 0.46378072, 0.469160496, 0.474918192, 0.481067008, 0.48761, 0.494534656, 0.501830272, 0.509468368, 0.517409584, 0.52562, 0.534081552, 0.542772448,
-0.551663424, 0.56072288, 0.56992, 0.579227008, 0.588615424, 0.59805376, 0.607509296, 0.61695, 0.626345792, 0.63566624, 0.644879888, 0.653954976, 0.66286,
-0.671592496, 0.680178368, 0.6885012, 0.696387712, 0.70375, 0.710601824, 0.71688792, 0.722898336, 0.729089712, 0.73574, 0.742805296, 0.750334176, 0.758083696,
-0.765642336, 0.77275, 0.779461632, 0.785763216, 0.79174208, 0.797565744, 0.80334, 0.809028816, 0.81462464, 0.820118064, 0.825484608, 0.83071, 0.83580536,
-0.840776864, 0.845625648, 0.850352912, 0.85496, 0.85944832, 0.863819248, 0.868074896, 0.872217744, 0.87625, 0.880173312, 0.883989536, 0.88770016, 0.891306464,
-0.89481, 0.898212768, 0.90151664, 0.904724032, 0.907837664, 0.91086, 0.913792928, 0.916638432, 0.919398496, 0.92207504, 0.92467, 0.927185376, 0.929623136,
-0.931985216, 0.934273536, 0.93649, 0.93863648, 0.940714816, 0.942726912, 0.944674688, 0.94656, 0.948384576, 0.950150112, 0.951858368, 0.953511104, 0.95511,
-0.956656624, 0.958152528, 0.959599152, 0.960997856, 0.96235, 0.963657024, 0.964920352, 0.9661412, 0.967320688, 0.96846, 0.9695604, 0.97062304, 0.97164944,
-0.97264128, 0.9736, 0.974521776, 0.975398, 0.976243984, 0.977084528, 0.97793, 0.978777344, 0.979638784, 0.980449664, 0.981109744, 0.98156,
-/* CES89 */ 0.07275, 0.073921936, 0.075638048, 0.077736, 0.079942352, 0.08208, 0.084176896, 0.08621712, 0.088235344, 0.090311088, 0.09249, 0.09474968,
-0.097094144, 0.099475488, 0.101817312, 0.10408, 0.106271392, 0.108368448, 0.110488224, 0.11281544, 0.11546, 0.118394976, 0.121627584, 0.125058352, 0.12852304,
-0.13191, 0.135225008, 0.138450432, 0.141578736, 0.1446192, 0.14757, 0.150403968, 0.153102096, 0.155638464, 0.157982192, 0.16011, 0.16202192, 0.163726224,
-0.165190368, 0.166368032, 0.16724, 0.167826528, 0.168138288, 0.168249328, 0.168268768, 0.16828, 0.168289392, 0.168299248, 0.168396704, 0.16869712, 0.16927,
-0.17010968, 0.171224224, 0.172544608, 0.173955312, 0.17537, 0.176789616, 0.178211568, 0.1795536, 0.180706992, 0.1816, 0.182226096, 0.18256784, 0.182655824,
-0.182551888, 0.1823, 0.181890208, 0.181317584, 0.18062168, 0.179856976, 0.17906, 0.178233552, 0.17738616, 0.176508368, 0.175578016, 0.17458, 0.173520288,
-0.172402208, 0.171213488, 0.169936688, 0.16856, 0.167083296, 0.165505168, 0.16382552, 0.162046432, 0.16017, 0.15819832, 0.15613608, 0.15398288, 0.15173664,
-0.1494, 0.1469816, 0.1444884, 0.14194104, 0.13936744, 0.13679, 0.134214512, 0.13164552, 0.129106768, 0.126628416, 0.12423, 0.121913776, 0.119685168, 0.11753136,
-0.115428592, 0.11336, 0.11132928, 0.10933928, 0.10737424, 0.10541224, 0.10344, 0.101459248, 0.09946896, 0.097480432, 0.095512704, 0.09358, 0.091682336,
-0.089820592, 0.088007648, 0.086260304, 0.08459, 0.08299832, 0.081488128, 0.080057136, 0.078699264, 0.07741, 0.076189888, 0.075038096, 0.073950784, 0.072922992,
-0.07195, 0.071028688, 0.070155904, 0.0693252, 0.068528736, 0.06776, 0.067016272, 0.06629536, 0.065590608, 0.064893936, 0.0642, 0.063507904, 0.062816208,
-0.062127632, 0.061447536, 0.06078, 0.060125072, 0.059483472, 0.058858992, 0.058256512, 0.05768, 0.05713136, 0.056612832, 0.056126704, 0.055675056, 0.05526,
-0.054883904, 0.054549104, 0.054257424, 0.054010464, 0.05381, 0.053657904, 0.053555664, 0.053506304, 0.053513504, 0.05358, 0.053705968, 0.053890944, 0.05413776,
-0.054450256, 0.05483, 0.055275088, 0.05578408, 0.056351392, 0.056968944, 0.05763, 0.05833176, 0.059070752, 0.059841104, 0.060636176, 0.06145, 0.06227848,
-0.063117664, 0.063961728, 0.064804272, 0.06564, 0.066466944, 0.067284448, 0.068085872, 0.068862576, 0.06961, 0.070329792, 0.071022512, 0.071696752, 0.072365792,
-0.07304, 0.073721216, 0.074411872, 0.075121328, 0.075861184, 0.07664, 0.077460768, 0.078327248, 0.079240128, 0.080197888, 0.0812, 0.0822488, 0.083345648,
-0.084491216, 0.085685984, 0.08693, 0.088222832, 0.089563856, 0.09095168, 0.092384384, 0.09386, 0.095376528, 0.09693136, 0.098522912, 0.100149904, 0.10181,
-0.103499408, 0.105214512, 0.106949376, 0.10869672, 0.11045, 0.112204976, 0.113957216, 0.115700176, 0.117426576, 0.11913, 0.120806192, 0.122451136, 0.12405904,
-0.125623664, 0.12714, 0.1286064, 0.13002256, 0.1313828, 0.13267976, 0.13391, 0.135076176, 0.13618032, 0.137230944, 0.138240608, 0.13922, 0.140174048,
-0.141109024, 0.14203456, 0.142961456, 0.1439, 0.144858592, 0.14584576, 0.146872368, 0.147950176, 0.14909, 0.150299952, 0.151587648, 0.152964144, 0.1544416,
-0.15603, 0.157735696, 0.159565344, 0.161522832, 0.16361016, 0.16583, 0.1681872, 0.170685712, 0.173328784, 0.176119376, 0.17906, 0.182152736, 0.185399424,
-0.188800992, 0.19235776, 0.19607, 0.199938, 0.203961392, 0.208140784, 0.212477056, 0.21697, 0.22161808, 0.226420064, 0.231371568, 0.236466512, 0.2417,
-0.24707088, 0.252578288, 0.258216656, 0.263978784, 0.26986, 0.275859488, 0.281975296, 0.288211344, 0.294574752, 0.30107, 0.307693632, 0.314441264, 0.321318336,
-0.328333568, 0.33549, 0.342781264, 0.350202336, 0.357739648, 0.36537424, 0.37309, 0.38087896, 0.388731408, 0.396633776, 0.404571344, 0.41253, 0.420507152,
-0.428509616, 0.43649448, 0.444399024, 0.45219, 0.459869168, 0.46741416, 0.474922672, 0.482550224, 0.49039, 0.498423248, 0.506663472, 0.515021456, 0.52334872,
-0.53155, 0.5396408, 0.547612416, 0.555491552, 0.563333008, 0.57117, 0.578986112, 0.586774912, 0.594529392, 0.602237312, 0.60989, 0.61748776, 0.625029312,
-0.632511024, 0.639929056, 0.64728, 0.654561184, 0.661769904, 0.668903904, 0.675961264, 0.68294, 0.689837856, 0.696652784, 0.703382752, 0.71002576, 0.71658,
-0.723043984, 0.729416352, 0.73569568, 0.741880608, 0.74797, 0.753962928, 0.75985848, 0.765656432, 0.771356944, 0.77696, 0.782465184, 0.787872352, 0.79318096,
-0.798390288, 0.8035, 0.808510352, 0.81342152, 0.818234368, 0.822950176, 0.82757, 0.832094304, 0.836523728, 0.840858992, 0.845100816, 0.84925, 0.853307472,
-0.857274192, 0.861151152, 0.864939392, 0.86864, 0.872254064, 0.875782672, 0.8792272, 0.882589168, 0.88587, 0.889070896, 0.89219312, 0.895237904, 0.898206448,
-0.9011, 0.903903536, 0.906585664, 0.909196832, 0.91182024, 0.91449, 0.917193216, 0.919968192, 0.922597328, 0.924744304, 0.92621,
-/* CES90 */ 0.2617, 0.2617, 0.2617, 0.2617, 0.2617, 0.2617, 0.2617, 0.2617, 0.2617, 0.2617, 0.2617, 0.261717616, 0.26178808, 0.261858544, 0.261840928, 0.2617,
-0.261453504, 0.261066464, 0.260767504, 0.260908304, 0.2617, 0.263093232, 0.265094464, 0.267536736, 0.270141568, 0.27271, 0.275253216, 0.277763296, 0.280117216,
-0.282163056, 0.2838, 0.285009952, 0.285761536, 0.2861084, 0.286156064, 0.28598, 0.285565664, 0.2849068, 0.284070336, 0.283147552, 0.2822, 0.281233984,
-0.280265456, 0.279278448, 0.27823504, 0.27711, 0.275916, 0.274662048, 0.273326896, 0.271879264, 0.2703, 0.268592624, 0.266756736, 0.264804528, 0.26275696,
-0.26063, 0.258424512, 0.256142768, 0.253799584, 0.251414, 0.249, 0.246562096, 0.244106464, 0.241634032, 0.23914208, 0.23663, 0.234103424, 0.231567312,
-0.22902192, 0.226465968, 0.2239, 0.221327648, 0.2187516, 0.216178112, 0.213615424, 0.21107, 0.208542944, 0.206034512, 0.20355208, 0.201105408, 0.1987,
-0.196334592, 0.19400912, 0.191715888, 0.189442656, 0.18718, 0.184926976, 0.182682288, 0.1804368, 0.178178752, 0.1759, 0.173599264, 0.17127464, 0.168926576,
-0.166557632, 0.16417, 0.161764608, 0.159344096, 0.156909264, 0.154460352, 0.152, 0.149533616, 0.147065824, 0.144608912, 0.1421792, 0.13979, 0.13744528,
-0.135148112, 0.132913344, 0.130759856, 0.1287, 0.126734976, 0.124867504, 0.123091552, 0.12139536, 0.11977, 0.118214688, 0.116726512, 0.115299376, 0.11392624,
-0.1126, 0.111314912, 0.110065536, 0.10884136, 0.107629664, 0.10642, 0.105208448, 0.10399224, 0.102760352, 0.101499104, 0.1002, 0.098862912, 0.097486672,
-0.096078192, 0.094649472, 0.09321, 0.09176144, 0.090306752, 0.088854704, 0.087416096, 0.086, 0.084611488, 0.083256144, 0.08194016, 0.080669536, 0.07945,
-0.078286144, 0.07718152, 0.076143536, 0.075180992, 0.0743, 0.07350208, 0.072789056, 0.072159792, 0.071611008, 0.07114, 0.070745632, 0.070425072, 0.070177632,
-0.070003392, 0.0699, 0.069862176, 0.069885552, 0.069958128, 0.070063904, 0.07019, 0.070332704, 0.070488928, 0.070645552, 0.070786176, 0.0709, 0.070985872,
-0.071041632, 0.071071792, 0.071085472, 0.07109, 0.071087328, 0.071081712, 0.071078176, 0.071082, 0.0711, 0.07114032, 0.071210896, 0.071325312, 0.071500128,
-0.07175, 0.072084128, 0.072512112, 0.073047696, 0.07370592, 0.0745, 0.075438912, 0.076531136, 0.07778792, 0.079221024, 0.08084, 0.082648, 0.08464504,
-0.08684336, 0.0892592, 0.0919, 0.094759456, 0.09783288, 0.101100784, 0.104534528, 0.10811, 0.11181664, 0.115641024, 0.119561968, 0.123555712, 0.1276,
-0.131677408, 0.135770448, 0.139856288, 0.143910208, 0.14791, 0.151841152, 0.155691568, 0.159436304, 0.16304608, 0.1665, 0.16979088, 0.172909984, 0.175860848,
-0.178654912, 0.1813, 0.183792992, 0.186133648, 0.188327104, 0.19038032, 0.1923, 0.194090688, 0.195757264, 0.19730888, 0.198756576, 0.20011, 0.20137472,
-0.20255616, 0.20366392, 0.20470896, 0.2057, 0.206642016, 0.20754056, 0.208397824, 0.209213888, 0.20999, 0.210730688, 0.211439824, 0.21211976, 0.212772416,
-0.2134, 0.214005424, 0.21459144, 0.215160736, 0.215716032, 0.21626, 0.216794912, 0.217322736, 0.21784696, 0.218371664, 0.2189, 0.219433248, 0.21997264,
-0.220519552, 0.221075104, 0.22164, 0.222213696, 0.222794592, 0.223384448, 0.223986144, 0.2246, 0.22522312, 0.225853808, 0.226482256, 0.227094464, 0.22768,
-0.228239232, 0.228774016, 0.22927224, 0.229717104, 0.2301, 0.230425136, 0.23069408, 0.230924384, 0.231142848, 0.23137, 0.231607456, 0.231856544, 0.232138272,
-0.23248024, 0.2329, 0.23339792, 0.233977152, 0.234626544, 0.235325856, 0.23606, 0.236829312, 0.237632464, 0.238456096, 0.239282848, 0.2401, 0.240903744,
-0.241688816, 0.242453968, 0.24320096, 0.24393, 0.244636512, 0.245316608, 0.245970064, 0.24659792, 0.2472, 0.247773504, 0.248315984, 0.248824864, 0.249297184,
-0.24973, 0.250123776, 0.250481904, 0.250791792, 0.2510344, 0.2512, 0.251292272, 0.251309392, 0.251276192, 0.251231232, 0.2512, 0.251181184, 0.251178832,
-0.25118824, 0.251197648, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512,
-0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512,
-0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512,
-0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512,
-/* CES91 */ 0.02966, 0.029995872, 0.030533776, 0.03131568, 0.032385264, 0.03379, 0.035571744, 0.03776424, 0.040457056, 0.043764032, 0.04777, 0.052461136,
-0.057788896, 0.063896096, 0.070993216, 0.07917, 0.088386992, 0.098701616, 0.10964072, 0.120476624, 0.13075, 0.140501824, 0.14964592, 0.158459616, 0.167442992,
-0.17691, 0.186769488, 0.197015856, 0.207570944, 0.218281312, 0.22905, 0.239907664, 0.250854464, 0.261907584, 0.273103824, 0.28446, 0.295950672, 0.307555664,
-0.319251136, 0.331006128, 0.34279, 0.354583136, 0.366368016, 0.378091776, 0.389686416, 0.4011, 0.412313008, 0.423300896, 0.434048304, 0.444548192, 0.45479,
-0.464751392, 0.474412464, 0.483762656, 0.492794928, 0.5015, 0.509867904, 0.517894896, 0.525553408, 0.53280592, 0.53963, 0.54602984, 0.552006208, 0.557577376,
-0.562773664, 0.56762, 0.572120176, 0.576277776, 0.580127856, 0.583717616, 0.58708, 0.590220912, 0.593150896, 0.5958668, 0.598355664, 0.60061, 0.602640592,
-0.60445816, 0.606051568, 0.607401776, 0.6085, 0.609354576, 0.609969168, 0.61036696, 0.610582192, 0.61064, 0.610545008, 0.61030552, 0.609929392, 0.609422064,
-0.60879, 0.608044016, 0.607195152, 0.606247088, 0.605200624, 0.60406, 0.602834144, 0.601529088, 0.600163232, 0.598760976, 0.59734, 0.595904224, 0.594459072,
-0.59300792, 0.591551328, 0.59009, 0.588627536, 0.58716688, 0.585704464, 0.584233728, 0.58275, 0.581255984, 0.579755904, 0.578240144, 0.576693904, 0.57511,
-0.573492192, 0.571839824, 0.570173936, 0.568526768, 0.56692, 0.565351088, 0.563819376, 0.562329744, 0.560886432, 0.55949, 0.558139296, 0.556835104, 0.555557392,
-0.55427608, 0.55297, 0.551638768, 0.550280032, 0.548886496, 0.5474512, 0.54597, 0.544442864, 0.542870896, 0.541257488, 0.53960824, 0.53793, 0.53622744,
-0.534504208, 0.532780016, 0.531081904, 0.52943, 0.527827488, 0.526277696, 0.524793024, 0.523387632, 0.52207, 0.520840528, 0.519700464, 0.51863952, 0.517640576,
-0.51669, 0.515786688, 0.51492968, 0.514101072, 0.513276624, 0.51244, 0.511591472, 0.510730832, 0.509856432, 0.508968512, 0.50807, 0.507164032, 0.506252992,
-0.505356272, 0.504501552, 0.50371, 0.50298496, 0.502329712, 0.501762064, 0.501304096, 0.50097, 0.500759728, 0.500673584, 0.50070656, 0.500848736, 0.50109,
-0.501426848, 0.50185672, 0.502354192, 0.502883824, 0.50342, 0.503959872, 0.504499552, 0.505027872, 0.505533472, 0.50601, 0.506456576, 0.506871552, 0.507268448,
-0.507669024, 0.50809, 0.5085332, 0.509001488, 0.509510096, 0.510078304, 0.51072, 0.51143816, 0.512236176, 0.513116992, 0.514081648, 0.51513, 0.516261808,
-0.517475872, 0.518766096, 0.5201236, 0.52154, 0.5230124, 0.524539456, 0.526103232, 0.527678928, 0.52925, 0.530816592, 0.532377392, 0.533933472, 0.535489392,
-0.53705, 0.53861496, 0.540182592, 0.541771184, 0.543407696, 0.54511, 0.54687696, 0.548709744, 0.550607408, 0.552565552, 0.55458, 0.556650352, 0.558775568,
-0.560945904, 0.56314768, 0.56537, 0.567606544, 0.569847504, 0.572093024, 0.574347824, 0.57661, 0.578869472, 0.581121424, 0.583335296, 0.585468048, 0.58749,
-0.589401968, 0.591204256, 0.592886864, 0.594439552, 0.59586, 0.597151312, 0.598312224, 0.599381456, 0.600417168, 0.60146, 0.602509728, 0.603569616, 0.604653424,
-0.605774512, 0.60694, 0.608152784, 0.609418544, 0.610712624, 0.611995424, 0.61324, 0.614449888, 0.615625984, 0.61675896, 0.617838576, 0.61886, 0.619820816,
-0.62071344, 0.621565824, 0.622422368, 0.62331, 0.624221088, 0.625154144, 0.62609424, 0.627016016, 0.6279, 0.628747408, 0.62956056, 0.630312832, 0.630966944,
-0.6315, 0.631914736, 0.632209472, 0.632400768, 0.632517104, 0.63258, 0.632583552, 0.632517088, 0.632419984, 0.63235048, 0.63234, 0.63238256, 0.632489584,
-0.632589008, 0.632566592, 0.63235, 0.631950128, 0.631360208, 0.630610528, 0.629757888, 0.62884, 0.627846448, 0.626773408, 0.625633728, 0.624442768, 0.62321,
-0.621938368, 0.620634368, 0.619281648, 0.617852528, 0.61633, 0.614723104, 0.613040528, 0.611277552, 0.609426976, 0.60749, 0.605474752, 0.603383232, 0.601259312,
-0.599166912, 0.59715, 0.595206768, 0.593337632, 0.591556176, 0.58987568, 0.5883, 0.58683016, 0.585475296, 0.584180272, 0.582860048, 0.58146, 0.579985232,
-0.578428192, 0.576809952, 0.575171632, 0.57354, 0.571906256, 0.570267312, 0.568629248, 0.566998704, 0.56538, 0.563775008, 0.562186608, 0.560609088, 0.559032368,
-0.55745, 0.555863504, 0.554272768, 0.552678832, 0.551084096, 0.54949, 0.547895712, 0.546300912, 0.544705312, 0.543108432, 0.54151, 0.539910096, 0.538308672,
-0.536706128, 0.535103104, 0.5335, 0.531896784, 0.530293568, 0.528690032, 0.527085616, 0.52548, 0.523873296, 0.522265472, 0.520656928, 0.519048304, 0.51744,
-0.515831952, 0.514224208, 0.512616544, 0.51100856, 0.5094, 0.507790944, 0.506181424, 0.504571424, 0.502960944, 0.50135, 0.499738608, 0.498126784, 0.49651464,
-0.494902336, 0.49329, 0.491690528, 0.49012968, 0.488568992, 0.486944304, 0.48523, 0.48343896, 0.481545472, 0.479716784, 0.478210176, 0.47718,
-/* CES92 */ 0.00526, 0.007533936, 0.010819504, 0.014937472, 0.01956792, 0.02448, 0.029703056, 0.035250912, 0.040922288, 0.046446544, 0.05165, 0.0565156,
-0.060983328, 0.065190976, 0.069387024, 0.07373, 0.078181088, 0.082744896, 0.087391504, 0.092055312, 0.0967, 0.101346464, 0.105994864, 0.110690864, 0.115505504,
-0.12048, 0.125603424, 0.130878944, 0.136259024, 0.141666784, 0.14705, 0.152411776, 0.157745744, 0.163046432, 0.16831488, 0.17355, 0.178739696, 0.183871232,
-0.188953408, 0.194003744, 0.19903, 0.204023024, 0.208978928, 0.213875472, 0.218680176, 0.22337, 0.227944544, 0.232402432, 0.23673296, 0.240924208, 0.24497,
-0.24886712, 0.25260696, 0.25621728, 0.25974272, 0.26321, 0.266610768, 0.26994336, 0.273190432, 0.276323184, 0.27932, 0.28217984, 0.284899872, 0.287462224,
-0.289844336, 0.29203, 0.294016192, 0.295799824, 0.297376496, 0.298743328, 0.2999, 0.3008484, 0.301591376, 0.302138592, 0.302503808, 0.3027, 0.30273392,
-0.302611952, 0.302355184, 0.301990896, 0.30154, 0.301010176, 0.300410944, 0.299749632, 0.29903088, 0.29826, 0.297443184, 0.296583472, 0.2956916, 0.294781248,
-0.29386, 0.292927856, 0.29198808, 0.291023584, 0.290006928, 0.28892, 0.287764912, 0.286540384, 0.285247216, 0.283889488, 0.28247, 0.280987088, 0.279440096,
-0.277832064, 0.276167472, 0.27445, 0.272683184, 0.270873104, 0.269016384, 0.267105744, 0.26514, 0.263125856, 0.261065904, 0.258983872, 0.256914, 0.25488,
-0.252881248, 0.250918032, 0.248998656, 0.24713168, 0.24532, 0.243562288, 0.241860096, 0.240187904, 0.238506912, 0.23679, 0.23503824, 0.233250944, 0.231416448,
-0.229521712, 0.22756, 0.225531792, 0.223435568, 0.221289984, 0.21912432, 0.21696, 0.214798176, 0.212641904, 0.210504112, 0.20839992, 0.20634, 0.204328336,
-0.202370192, 0.200461648, 0.198593504, 0.19676, 0.194963872, 0.193205888, 0.191485904, 0.18980408, 0.18816, 0.18655312, 0.184983824, 0.183447568, 0.181937632,
-0.18045, 0.17898616, 0.177547248, 0.176134816, 0.174751424, 0.1734, 0.17208256, 0.170800976, 0.169562352, 0.168376208, 0.16725, 0.16618528, 0.165183072,
-0.164250784, 0.163397856, 0.16263, 0.161946672, 0.161347984, 0.160829136, 0.160382048, 0.16, 0.159681984, 0.159427136, 0.159225328, 0.1590628, 0.15893,
-0.158825408, 0.158746128, 0.158693168, 0.158670368, 0.15868, 0.158720256, 0.158790528, 0.15889064, 0.159020352, 0.15918, 0.159370768, 0.15959392, 0.159851472,
-0.160145984, 0.16048, 0.160855536, 0.161274752, 0.161741008, 0.162258224, 0.16283, 0.163459616, 0.164151088, 0.164906, 0.165724912, 0.16661, 0.16756672,
-0.16860064, 0.16971696, 0.17092128, 0.17222, 0.17362056, 0.17513096, 0.17675976, 0.17851616, 0.18041, 0.182451024, 0.18464888, 0.187017536, 0.189573072,
-0.19233, 0.195296128, 0.198476976, 0.201893344, 0.205571792, 0.20953, 0.213768368, 0.218287584, 0.22308632, 0.228159456, 0.2335, 0.23910248, 0.24496064,
-0.251054, 0.25735496, 0.26384, 0.27050024, 0.277328, 0.28429376, 0.29135992, 0.2985, 0.305706704, 0.31296888, 0.320292256, 0.327692912, 0.33518, 0.342743408,
-0.350374496, 0.358077904, 0.365862192, 0.37373, 0.38167144, 0.389675904, 0.397736288, 0.405844912, 0.41399, 0.422156384, 0.430329728, 0.438479632, 0.446567376,
-0.45456, 0.4624424, 0.470200432, 0.477799024, 0.485195936, 0.49236, 0.499281344, 0.505949504, 0.512357744, 0.518504544, 0.52439, 0.530012032, 0.535370704,
-0.540478016, 0.545352288, 0.55001, 0.55446112, 0.558718896, 0.562798512, 0.566716128, 0.57049, 0.5741332, 0.577652272, 0.581094944, 0.584526496, 0.58799,
-0.591483136, 0.595005904, 0.598556592, 0.60212584, 0.6057, 0.60928264, 0.612890512, 0.616436624, 0.619789376, 0.62287, 0.625691136, 0.628239904, 0.630597232,
-0.63289768, 0.63523, 0.637583264, 0.639966192, 0.6423724, 0.644777888, 0.64717, 0.649564528, 0.65197096, 0.654394112, 0.656839104, 0.65931, 0.661809904,
-0.664342592, 0.66690704, 0.669499888, 0.67212, 0.674764336, 0.67742248, 0.680117824, 0.682887888, 0.68575, 0.688692528, 0.691716064, 0.69476248, 0.697743536,
-0.7006, 0.703338528, 0.70596072, 0.708454672, 0.710811024, 0.71303, 0.715110176, 0.717040032, 0.718890688, 0.720770944, 0.72275, 0.724821728, 0.726997408,
-0.729241888, 0.731490048, 0.7337, 0.735883872, 0.738043968, 0.740179584, 0.74229424, 0.74439, 0.746462912, 0.748509824, 0.750535296, 0.752547008, 0.75455,
-0.756543456, 0.758527616, 0.760502336, 0.762466656, 0.76442, 0.766363056, 0.768296336, 0.770219216, 0.772130656, 0.77403, 0.775917376, 0.777792736, 0.779656336,
-0.781508656, 0.78335, 0.785180272, 0.786999456, 0.78880752, 0.790604384, 0.79239, 0.794164928, 0.79593024, 0.797684352, 0.799424624, 0.80115, 0.802860864,
-0.804555872, 0.80624232, 0.807931488, 0.80963, 0.811336512, 0.81305208, 0.814768928, 0.816474336, 0.81816, 0.819826992, 0.821474528, 0.823103904, 0.8247184,
-0.82632, 0.82789608, 0.829423344, 0.830935888, 0.832490512, 0.83411, 0.835783616, 0.837535168, 0.83921584, 0.840596432, 0.84154,
-/* CES93 */ 0.23077, 0.232399984, 0.234783616, 0.237677968, 0.24067792, 0.24352, 0.246244576, 0.248831248, 0.25131952, 0.253809232, 0.25636, 0.258931008,
-0.26150168, 0.264070912, 0.266637344, 0.2692, 0.271759184, 0.274315712, 0.27686784, 0.279412928, 0.28195, 0.284480864, 0.28700704, 0.289532256, 0.292061712,
-0.2946, 0.297149968, 0.299715216, 0.302299424, 0.304906272, 0.30754, 0.310204336, 0.312901824, 0.315642272, 0.31843864, 0.3213, 0.324223632, 0.327203472,
-0.330252192, 0.333388752, 0.33662, 0.339934848, 0.343326352, 0.346760416, 0.35018776, 0.35357, 0.35690152, 0.360178176, 0.363359952, 0.366394848, 0.36925,
-0.3719244, 0.374411552, 0.376735184, 0.378937616, 0.38105, 0.383070016, 0.384997584, 0.386861552, 0.3887004, 0.39054, 0.392382736, 0.394234192, 0.396088368,
-0.397930624, 0.39975, 0.4015464, 0.403315808, 0.405051536, 0.406746064, 0.40839, 0.409973936, 0.411489696, 0.412915456, 0.414222976, 0.41539, 0.416412304,
-0.417288816, 0.417993968, 0.418494, 0.41877, 0.41882584, 0.418660528, 0.418303536, 0.417801744, 0.41719, 0.416467088, 0.415631056, 0.414717424, 0.413774192,
-0.41283, 0.411881824, 0.410933184, 0.409953344, 0.408891344, 0.40771, 0.406413392, 0.405006064, 0.403450496, 0.401695168, 0.39971, 0.397501168, 0.395068576,
-0.392444544, 0.389681552, 0.38682, 0.383863904, 0.380819584, 0.377725424, 0.374630544, 0.37157, 0.368553056, 0.365592944, 0.362687952, 0.35982592, 0.357,
-0.354219312, 0.351490032, 0.348808192, 0.346166432, 0.34356, 0.340990352, 0.338457952, 0.335962272, 0.333502992, 0.33108, 0.328694, 0.326346512, 0.324036224,
-0.321760816, 0.31952, 0.317316304, 0.315151424, 0.313032464, 0.310969424, 0.30897, 0.307035184, 0.305165504, 0.303368704, 0.301654864, 0.30003, 0.298493632,
-0.297046464, 0.295681936, 0.294389168, 0.29316, 0.29199448, 0.290892416, 0.289845792, 0.288843968, 0.28788, 0.286953328, 0.286062192, 0.285210256, 0.28440456,
-0.28365, 0.28294512, 0.282288736, 0.281685312, 0.281141168, 0.28066, 0.280241024, 0.279884352, 0.279586, 0.279339488, 0.27914, 0.278987792, 0.2788832,
-0.278821168, 0.278794816, 0.2788, 0.278837056, 0.278905328, 0.27900912, 0.279155632, 0.27935, 0.279591328, 0.27987864, 0.280216832, 0.280612704, 0.28107,
-0.281587632, 0.282165632, 0.282797952, 0.283475072, 0.28419, 0.284943376, 0.285736272, 0.286561168, 0.287407664, 0.28827, 0.289150064, 0.290048368, 0.290973072,
-0.291936976, 0.29295, 0.294013248, 0.295128032, 0.296304416, 0.29755544, 0.29889, 0.300309696, 0.301817376, 0.303410416, 0.305082656, 0.30683, 0.30865456,
-0.310557936, 0.312536512, 0.314584928, 0.3167, 0.318882512, 0.321132512, 0.323452672, 0.325847392, 0.32832, 0.330870672, 0.333499792, 0.336210352, 0.339006192,
-0.34189, 0.344862384, 0.347924272, 0.35107528, 0.354314128, 0.35764, 0.361053376, 0.3645544, 0.368142784, 0.371818128, 0.37558, 0.379428144, 0.383362384,
-0.387381664, 0.391484544, 0.39567, 0.399938, 0.404288544, 0.408721088, 0.413234992, 0.41783, 0.422505984, 0.427262368, 0.432101392, 0.437026576, 0.44204,
-0.447139376, 0.452321312, 0.457589088, 0.462948224, 0.4684, 0.473939152, 0.479561648, 0.485255184, 0.49100264, 0.49679, 0.502612592, 0.508465584, 0.514335056,
-0.520204048, 0.52606, 0.531897904, 0.537711376, 0.543500528, 0.54926952, 0.55502, 0.560745952, 0.566441568, 0.572108544, 0.57775112, 0.58337, 0.588959776,
-0.594516224, 0.600029872, 0.605488, 0.61088, 0.616201824, 0.621449552, 0.626612, 0.631675488, 0.63663, 0.641471952, 0.6461968, 0.650804608, 0.655298496,
-0.65968, 0.663946128, 0.668094928, 0.672126448, 0.676041328, 0.67984, 0.683522336, 0.687088608, 0.69053872, 0.693872432, 0.69709, 0.700193296, 0.70318504,
-0.706064384, 0.708829248, 0.71148, 0.714020624, 0.716454304, 0.718789664, 0.721038384, 0.72321, 0.725307552, 0.727333824, 0.729298736, 0.731214608, 0.73309,
-0.734926864, 0.736727936, 0.738491648, 0.74021344, 0.74189, 0.743522, 0.745109008, 0.746648336, 0.748136464, 0.74957, 0.750946848, 0.752265056, 0.753519264,
-0.754702672, 0.75581, 0.756840736, 0.757795584, 0.758667632, 0.75944736, 0.76013, 0.760718624, 0.761215312, 0.76162912, 0.761973568, 0.76226, 0.762493104,
-0.76267888, 0.762827616, 0.762951072, 0.76306, 0.763157328, 0.763241136, 0.763336624, 0.763479632, 0.76369, 0.763967136, 0.764321984, 0.764707712, 0.76504904,
-0.7653, 0.765468912, 0.765549072, 0.765576832, 0.765614112, 0.7657, 0.765825936, 0.765993792, 0.766190528, 0.766391184, 0.76658, 0.766762368, 0.766939488,
-0.767112688, 0.767285488, 0.76746, 0.76763472, 0.767808848, 0.767982576, 0.768156224, 0.76833, 0.768503872, 0.768677856, 0.76885192, 0.769025984, 0.7692,
-0.769374, 0.769548, 0.769722, 0.769896, 0.77007, 0.770243984, 0.77041792, 0.770591856, 0.770765872, 0.77094, 0.771114224, 0.771288576, 0.771462848, 0.77163672,
-0.77181, 0.771981344, 0.772147968, 0.772314192, 0.772487216, 0.77267, 0.772861136, 0.773063392, 0.773258848, 0.773419904, 0.77353,
-/* CES94 */ 0.06102, 0.061775552, 0.062881584, 0.064231536, 0.065646368, 0.06701, 0.068341344, 0.069631776, 0.070900528, 0.07219408, 0.07354, 0.07492176,
-0.076334128, 0.077768096, 0.079209184, 0.08065, 0.082092272, 0.083531776, 0.08499136, 0.086506944, 0.0881, 0.089765392, 0.0915044, 0.093298608, 0.095117456,
-0.09694, 0.09876712, 0.100595408, 0.102422256, 0.104247664, 0.10607, 0.107883552, 0.109683616, 0.11146592, 0.113226064, 0.11496, 0.11666512, 0.11833968,
-0.119978, 0.12157296, 0.12312, 0.1246184, 0.12606648, 0.12746832, 0.1288312, 0.13016, 0.131452736, 0.13270728, 0.133927984, 0.135121248, 0.13629, 0.13743168,
-0.138544864, 0.139621488, 0.140649632, 0.14162, 0.142531232, 0.143382128, 0.144163104, 0.14486176, 0.14547, 0.145987008, 0.146410704, 0.14674552, 0.146999936,
-0.14718, 0.147284368, 0.14731208, 0.147268992, 0.147163264, 0.147, 0.146778992, 0.146501152, 0.146162752, 0.145757232, 0.14528, 0.144732464, 0.144116272,
-0.1434264, 0.142655568, 0.1418, 0.140861744, 0.13984176, 0.138746736, 0.137586832, 0.13637, 0.13509768, 0.133771616, 0.132399232, 0.130989808, 0.12955,
-0.128082128, 0.126589552, 0.125070816, 0.12352168, 0.12194, 0.120329856, 0.118695696, 0.117035136, 0.115343536, 0.11362, 0.111869488, 0.110095616, 0.108309584,
-0.106526912, 0.10476, 0.103011776, 0.101284624, 0.099591712, 0.09794984, 0.09637, 0.09485344, 0.093402992, 0.092012704, 0.090671136, 0.08937, 0.088110512,
-0.086893024, 0.085710016, 0.084551328, 0.08341, 0.082285104, 0.081174736, 0.080080048, 0.0790044, 0.07795, 0.076915504, 0.075900048, 0.074905552, 0.073934896,
-0.07299, 0.072070896, 0.071178032, 0.070310928, 0.069468464, 0.06865, 0.06785616, 0.067087488, 0.066343776, 0.065624624, 0.06493, 0.064260352, 0.063615936,
-0.06299816, 0.062409024, 0.06185, 0.061321312, 0.06082328, 0.060356608, 0.059922016, 0.05952, 0.059150752, 0.058814448, 0.058510864, 0.05823952, 0.058,
-0.057792112, 0.057615504, 0.057469856, 0.057354848, 0.05727, 0.05721464, 0.057188096, 0.057189312, 0.057217008, 0.05727, 0.05734736, 0.057448032, 0.057571024,
-0.057715376, 0.05788, 0.058063744, 0.058265584, 0.058483664, 0.058715744, 0.05896, 0.059215792, 0.059482704, 0.059758816, 0.060041728, 0.06033, 0.060623712,
-0.060922736, 0.06122856, 0.061543664, 0.06187, 0.062208, 0.0625584, 0.06292272, 0.06330272, 0.0637, 0.064115776, 0.06455136, 0.065008064, 0.065487168, 0.06599,
-0.06651816, 0.067073424, 0.067656688, 0.068268512, 0.06891, 0.069583264, 0.070290368, 0.071033712, 0.071816016, 0.07264, 0.073508336, 0.074424032, 0.075389568,
-0.076407264, 0.07748, 0.078611328, 0.079804608, 0.081064928, 0.082398288, 0.08381, 0.085303248, 0.086881008, 0.088548928, 0.090313568, 0.09218, 0.094150896,
-0.096229408, 0.098416, 0.100709552, 0.10311, 0.105620688, 0.1082452, 0.110982752, 0.113831024, 0.11679, 0.11986312, 0.123052672, 0.126366704, 0.129816336,
-0.13341, 0.137148208, 0.141030464, 0.14506592, 0.149266896, 0.15364, 0.158182768, 0.16289432, 0.167763552, 0.172773264, 0.17791, 0.18317168, 0.188555792,
-0.194049024, 0.199634416, 0.2053, 0.21104264, 0.216857664, 0.222746928, 0.228716512, 0.23477, 0.240903456, 0.247113488, 0.2534032, 0.259777872, 0.26624,
-0.272786976, 0.27941704, 0.286125344, 0.292904928, 0.29975, 0.30665832, 0.313627264, 0.320651328, 0.327724032, 0.33484, 0.341995904, 0.349187968, 0.356413792,
-0.363671856, 0.37096, 0.378274096, 0.385609872, 0.392965408, 0.400339584, 0.40773, 0.41513224, 0.422542368, 0.429953856, 0.437358704, 0.44475, 0.452124352,
-0.459478816, 0.46680552, 0.474094864, 0.48134, 0.488538592, 0.49568744, 0.502788048, 0.509844736, 0.51686, 0.523831088, 0.530755568, 0.537635168, 0.544472928,
-0.55127, 0.558024144, 0.564733568, 0.571394432, 0.578001456, 0.58455, 0.591037552, 0.597461232, 0.603816352, 0.610097552, 0.6163, 0.622426336, 0.628485232,
-0.634454048, 0.640297744, 0.646, 0.6515652, 0.656981088, 0.662318896, 0.667689504, 0.67316, 0.678720144, 0.684382496, 0.690083248, 0.69571512, 0.70121,
-0.706581152, 0.711824048, 0.716960624, 0.72203368, 0.72707, 0.732059504, 0.736999504, 0.741887024, 0.746715264, 0.75148, 0.756183456, 0.760826624, 0.765409072,
-0.76993032, 0.77439, 0.778787984, 0.783124192, 0.7873984, 0.791610368, 0.79576, 0.799847328, 0.80387232, 0.807835552, 0.811737904, 0.81558, 0.819361968,
-0.823084112, 0.826746336, 0.83034832, 0.83389, 0.837371792, 0.840794016, 0.84415728, 0.847462384, 0.85071, 0.853900528, 0.85703448, 0.860112192, 0.863133904,
-0.8661, 0.869011088, 0.871867712, 0.874670736, 0.8774212, 0.88012, 0.882767696, 0.885364896, 0.887912256, 0.890410416, 0.89286, 0.895261664, 0.897616096,
-0.899923728, 0.90218488, 0.9044, 0.90656976, 0.908694768, 0.910775856, 0.912813984, 0.91481, 0.916764544, 0.918678336, 0.920551888, 0.9223856, 0.92418,
-0.925924992, 0.927599568, 0.929237184, 0.93089312, 0.93259, 0.934318752, 0.936103984, 0.937802096, 0.939191328, 0.94014,
-/* CES95 */ 0.07372, 0.075816672, 0.078890544, 0.082668976, 0.086688448, 0.09065, 0.094610752, 0.098557376, 0.10253656, 0.106660864, 0.111, 0.115511456,
-0.12017096, 0.124997344, 0.130018368, 0.13525, 0.14069384, 0.146361984, 0.152217888, 0.158201792, 0.16428, 0.170458688, 0.176724768, 0.183140448, 0.189805808,
-0.19678, 0.204043344, 0.211591888, 0.219381152, 0.227338496, 0.23541, 0.243589632, 0.251862992, 0.260201072, 0.268572752, 0.27695, 0.285313648, 0.293647152,
-0.301920656, 0.3101, 0.31816, 0.326088432, 0.333870096, 0.34151072, 0.349027664, 0.35643, 0.363701552, 0.3708248, 0.377810928, 0.384680816, 0.39144,
-0.398070032, 0.404558528, 0.410860864, 0.41691432, 0.42267, 0.428120656, 0.433262384, 0.438042592, 0.4423924, 0.44627, 0.449678432, 0.452613552, 0.455113472,
-0.457242512, 0.45905, 0.460540528, 0.461723392, 0.462642736, 0.46335424, 0.4639, 0.46429544, 0.464559456, 0.464702512, 0.464727568, 0.46464, 0.464452496,
-0.464173152, 0.463811808, 0.463378864, 0.46288, 0.462315952, 0.461688848, 0.460984544, 0.4601804, 0.45926, 0.458225632, 0.457080624, 0.455807456, 0.454381648,
-0.45279, 0.45103784, 0.449127136, 0.447078672, 0.444924608, 0.44269, 0.440378528, 0.437994672, 0.435562816, 0.433114, 0.43067, 0.428236064, 0.425820016,
-0.423418528, 0.4210208, 0.41862, 0.41622088, 0.413826048, 0.411431456, 0.409030944, 0.40662, 0.404197936, 0.401763376, 0.399314736, 0.396850736, 0.39437,
-0.391871184, 0.389353456, 0.386814288, 0.38425048, 0.38166, 0.379042928, 0.376399248, 0.373729808, 0.371036208, 0.36832, 0.365583264, 0.362829568, 0.360057632,
-0.357264336, 0.35445, 0.351620128, 0.348779072, 0.345938576, 0.34311456, 0.34032, 0.337559328, 0.334836976, 0.332165344, 0.329559392, 0.32703, 0.324581024,
-0.322217264, 0.319938224, 0.317739904, 0.31562, 0.313581056, 0.311624224, 0.309749072, 0.30795472, 0.30624, 0.304603872, 0.303045232, 0.301560992, 0.300147072,
-0.2988, 0.297517904, 0.296298592, 0.29513936, 0.294037408, 0.29299, 0.291994928, 0.29105032, 0.290152432, 0.289296784, 0.28848, 0.287701824, 0.286962912,
-0.28625936, 0.285585808, 0.28494, 0.284324304, 0.28374024, 0.283195776, 0.282702592, 0.28227, 0.281900144, 0.281595296, 0.281364768, 0.28122024, 0.28117,
-0.28121672, 0.281364368, 0.281610816, 0.281950384, 0.28238, 0.2829044, 0.283528736, 0.284249552, 0.285060528, 0.28596, 0.286953952, 0.288046992, 0.289251472,
-0.290584432, 0.29206, 0.293683616, 0.295461152, 0.297406208, 0.299534944, 0.30186, 0.30438728, 0.307123248, 0.310072336, 0.313237024, 0.31662, 0.320224784,
-0.324053136, 0.328109088, 0.33239736, 0.33692, 0.341675232, 0.346661488, 0.351872224, 0.35729784, 0.36293, 0.368764688, 0.374797024, 0.38101848, 0.387419136,
-0.39399, 0.400723648, 0.40761144, 0.414648032, 0.421829584, 0.42915, 0.436596688, 0.444155072, 0.451820496, 0.45959152, 0.46746, 0.47540856, 0.483422496,
-0.491469232, 0.499506608, 0.5075, 0.515437424, 0.523308992, 0.53107792, 0.538698448, 0.54614, 0.553397328, 0.56046152, 0.567346112, 0.574078864, 0.58068,
-0.587147232, 0.593481872, 0.599700352, 0.605824112, 0.61187, 0.617844784, 0.623756672, 0.62961368, 0.635422528, 0.64119, 0.646923088, 0.65262736, 0.658310992,
-0.663983024, 0.66965, 0.675314432, 0.680978832, 0.686642832, 0.692303952, 0.69796, 0.703609248, 0.709247552, 0.714875936, 0.72049728, 0.72611, 0.731706224,
-0.737279376, 0.742812368, 0.74828224, 0.75367, 0.758967984, 0.764168048, 0.769252832, 0.774202096, 0.779, 0.783639184, 0.788112032, 0.7924124, 0.796536208,
-0.80048, 0.804241792, 0.8078228, 0.811217168, 0.814416816, 0.81742, 0.820233424, 0.822861968, 0.825326832, 0.827658176, 0.82988, 0.8319976, 0.834015712,
-0.835959344, 0.837860336, 0.83974, 0.841601472, 0.843450544, 0.845279056, 0.847069728, 0.84881, 0.85050112, 0.852141536, 0.853722192, 0.855231648, 0.85666,
-0.858014224, 0.859313472, 0.86051112, 0.861534528, 0.86235, 0.862968016, 0.86336528, 0.863687184, 0.864159568, 0.86492, 0.865949392, 0.867274464, 0.868770656,
-0.870226928, 0.87151, 0.872648096, 0.873633856, 0.874513136, 0.875373456, 0.87627, 0.877184, 0.878111536, 0.879046832, 0.879975808, 0.88089, 0.88179488,
-0.882693152, 0.883584944, 0.884470496, 0.88535, 0.886223424, 0.887090704, 0.887952224, 0.888808544, 0.88966, 0.890506592, 0.891348464, 0.892185136, 0.893015808,
-0.89384, 0.894657824, 0.895469216, 0.896274608, 0.89707472, 0.89787, 0.898660384, 0.899445968, 0.900226432, 0.901001216, 0.90177, 0.90253288, 0.903289792,
-0.904041184, 0.904787776, 0.90553, 0.906267808, 0.907001344, 0.90773008, 0.908453136, 0.90917, 0.9098808, 0.91058544, 0.91128456, 0.9119792, 0.91267,
-0.913356864, 0.91403992, 0.914718656, 0.915392192, 0.91606, 0.91672224, 0.917378896, 0.918030352, 0.918677248, 0.91932, 0.919958544, 0.920592912, 0.92122304,
-0.921848768, 0.92247, 0.923082288, 0.92367664, 0.924266592, 0.924874704, 0.92551, 0.926168144, 0.926858512, 0.92752192, 0.928067248, 0.92844,
-/* CES96 */ 0.33851, 0.34143264, 0.345707648, 0.350905456, 0.356308384, 0.36145, 0.366403504, 0.371134496, 0.375712368, 0.38031248, 0.38504, 0.389822656,
-0.394622128, 0.39944336, 0.404294672, 0.40918, 0.414098208, 0.4190524, 0.424026112, 0.428993664, 0.43394, 0.438864656, 0.443757952, 0.448648608, 0.453583904,
-0.45859, 0.46365344, 0.468768768, 0.473906656, 0.479021904, 0.48408, 0.489074512, 0.493995216, 0.49881936, 0.503521344, 0.50808, 0.512487648, 0.51674136,
-0.520814832, 0.524673904, 0.5283, 0.531697504, 0.534865632, 0.53783856, 0.540670048, 0.5434, 0.546026624, 0.54854656, 0.551001536, 0.553448352, 0.55592,
-0.558410336, 0.560920736, 0.563412096, 0.565821056, 0.5681, 0.570248608, 0.572265856, 0.574104704, 0.575702752, 0.57702, 0.578055472, 0.578801904, 0.579281936,
-0.579537568, 0.5796, 0.579467792, 0.579143056, 0.57865392, 0.578037264, 0.57732, 0.576509504, 0.57561608, 0.574642976, 0.573587632, 0.57245, 0.571237536,
-0.569955216, 0.568605536, 0.567190176, 0.56571, 0.564166032, 0.562559456, 0.56088576, 0.559137664, 0.55731, 0.555403936, 0.55342112, 0.551355904, 0.549200208,
-0.54695, 0.544608016, 0.542175984, 0.539661632, 0.53707648, 0.53443, 0.531725312, 0.528966192, 0.526161872, 0.523323472, 0.52046, 0.517576176, 0.514677232,
-0.511767568, 0.508850624, 0.50593, 0.503009824, 0.500093408, 0.497185072, 0.494289456, 0.49141, 0.488548496, 0.485706912, 0.482884608, 0.480079424, 0.47729,
-0.47451728, 0.471761728, 0.469022656, 0.466299024, 0.46359, 0.460895664, 0.458216336, 0.455550368, 0.45289536, 0.45025, 0.447616384, 0.444997808, 0.442391472,
-0.439792496, 0.4372, 0.43461912, 0.432053712, 0.429516144, 0.427023616, 0.42459, 0.422219328, 0.419915584, 0.4176924, 0.415566576, 0.41355, 0.411646,
-0.40985904, 0.40818752, 0.40662576, 0.40517, 0.403822352, 0.4025836, 0.401451088, 0.400420976, 0.39949, 0.398656688, 0.397919248, 0.397274608, 0.396719248,
-0.39625, 0.395864992, 0.395562688, 0.395339104, 0.39518936, 0.39511, 0.395100064, 0.395158304, 0.395285104, 0.395481984, 0.39575, 0.396089056, 0.396499824,
-0.396982112, 0.39753536, 0.39816, 0.398857616, 0.399629312, 0.400479648, 0.401414944, 0.40244, 0.403555328, 0.404761088, 0.406061888, 0.407463728, 0.40897,
-0.410580128, 0.412294368, 0.414107568, 0.416011488, 0.418, 0.420073776, 0.422234048, 0.4244732, 0.426780512, 0.42915, 0.431583552, 0.43408152, 0.436652128,
-0.439308336, 0.44206, 0.44490768, 0.447852048, 0.450902816, 0.454072704, 0.45737, 0.460795344, 0.464350656, 0.468031648, 0.47183008, 0.47574, 0.479762272,
-0.483897088, 0.488139264, 0.49248184, 0.49692, 0.501452912, 0.506079024, 0.510799136, 0.515615648, 0.52053, 0.525541168, 0.530648736, 0.535852704, 0.541153072,
-0.54655, 0.552043136, 0.557631424, 0.563317712, 0.56910648, 0.575, 0.58099408, 0.587082672, 0.593269584, 0.599561856, 0.60596, 0.612454656, 0.619038304,
-0.625690192, 0.63238192, 0.63909, 0.645805936, 0.652521152, 0.659211728, 0.665848544, 0.67241, 0.67888776, 0.685271488, 0.691560176, 0.697759024, 0.70387,
-0.70988568, 0.715800576, 0.721614112, 0.727327248, 0.73294, 0.738449936, 0.743855152, 0.749154368, 0.754346384, 0.75943, 0.764404112, 0.769267808, 0.774019504,
-0.77865736, 0.78318, 0.787586784, 0.791876944, 0.796050544, 0.800108144, 0.80405, 0.807875248, 0.811582864, 0.81517368, 0.818649216, 0.82201, 0.825255008,
-0.82838368, 0.831393232, 0.834279664, 0.83704, 0.839674656, 0.842184752, 0.844565968, 0.846812064, 0.84892, 0.850892416, 0.852731488, 0.85444248, 0.856032992,
-0.85751, 0.85887912, 0.86014816, 0.86132088, 0.86239952, 0.86339, 0.864302704, 0.86514624, 0.865941456, 0.866715472, 0.86749, 0.868270752, 0.869061456,
-0.86988536, 0.870772144, 0.87174, 0.872788096, 0.87391808, 0.875114784, 0.876352288, 0.87761, 0.878883408, 0.880164784, 0.88143656, 0.882677856, 0.88387,
-0.88501544, 0.88613112, 0.88715488, 0.88799336, 0.8886, 0.888983632, 0.88911488, 0.889155408, 0.889358896, 0.88988, 0.890695296, 0.891832528, 0.8931572,
-0.894441072, 0.89554, 0.8964848, 0.89726784, 0.89793808, 0.89858912, 0.89928, 0.899990576, 0.90071656, 0.901452144, 0.902182848, 0.9029, 0.903609392,
-0.904313904, 0.905013696, 0.905709008, 0.9064, 0.907086672, 0.907769056, 0.90844712, 0.909120784, 0.90979, 0.9104548, 0.9111152, 0.9117712, 0.9124228, 0.91307,
-0.913712816, 0.91435128, 0.914985344, 0.915614928, 0.91624, 0.916860608, 0.917476784, 0.91808864, 0.918696336, 0.9193, 0.919899632, 0.9204952, 0.921086928,
-0.921675136, 0.92226, 0.92284152, 0.923419808, 0.923994416, 0.924564624, 0.92513, 0.92569064, 0.926246496, 0.926797952, 0.927345648, 0.92789, 0.928430944,
-0.928968512, 0.92950264, 0.930033168, 0.93056, 0.9310832, 0.9316028, 0.9321188, 0.9326312, 0.93314, 0.933645216, 0.93414688, 0.934644944, 0.935139328, 0.93563,
-0.936113408, 0.936582384, 0.93704784, 0.937527936, 0.93803, 0.938550576, 0.93909712, 0.939622624, 0.940054688, 0.94035,
-/* CES97 */ 0.1766, 0.1766, 0.1766, 0.1766, 0.1766, 0.1766, 0.1766, 0.1766, 0.1766, 0.1766, 0.1766, 0.176628656, 0.17674328, 0.176857904, 0.176829248, 0.1766,
-0.176202464, 0.175586624, 0.175114064, 0.175339664, 0.1766, 0.178824592, 0.182029824, 0.185969696, 0.190228128, 0.19451, 0.198838016, 0.203203456, 0.207432736,
-0.211312336, 0.2147, 0.217568864, 0.219874016, 0.221688048, 0.22315552, 0.22438, 0.225342272, 0.226035888, 0.226551264, 0.22701104, 0.2275, 0.228030336,
-0.228625744, 0.229277552, 0.22995264, 0.23063, 0.231325184, 0.232046432, 0.2327796, 0.233503008, 0.2342, 0.23486664, 0.23549664, 0.2360804, 0.236608, 0.23707,
-0.237458432, 0.23776584, 0.237982208, 0.238096656, 0.2381, 0.23798624, 0.237749488, 0.237384256, 0.236885824, 0.23625, 0.235473984, 0.234556656, 0.233492528,
-0.23227472, 0.2309, 0.229370688, 0.227688608, 0.225861808, 0.223902208, 0.22182, 0.219620288, 0.217309808, 0.214896928, 0.212390528, 0.2098, 0.207134192,
-0.204401408, 0.201615744, 0.19879392, 0.19595, 0.193090272, 0.190219584, 0.187353056, 0.184509168, 0.1817, 0.178927424, 0.176194816, 0.173494768, 0.17081328,
-0.16814, 0.165475808, 0.162820528, 0.160164288, 0.157493888, 0.1548, 0.152081376, 0.149336208, 0.1465628, 0.143760672, 0.14093, 0.138073216, 0.13519568,
-0.132294064, 0.129362048, 0.1264, 0.123417712, 0.120422784, 0.117439056, 0.114499328, 0.11163, 0.108838528, 0.106131856, 0.103536384, 0.101084912, 0.0988,
-0.096686208, 0.094750064, 0.09298672, 0.091383296, 0.08993, 0.088626272, 0.08746744, 0.086447888, 0.085561696, 0.0848, 0.084151552, 0.083605888, 0.083140464,
-0.08272656, 0.08234, 0.081970336, 0.081607984, 0.081227712, 0.08079904, 0.0803, 0.079723696, 0.079061792, 0.078314688, 0.077489184, 0.07659, 0.075615904,
-0.074569888, 0.073451872, 0.072260656, 0.071, 0.06968112, 0.068315392, 0.066921264, 0.065522016, 0.06414, 0.06279504, 0.061511344, 0.060306368, 0.059195232,
-0.0582, 0.057351184, 0.056676608, 0.056225792, 0.056059616, 0.05623, 0.056762048, 0.057678432, 0.059033376, 0.0608916, 0.0633, 0.066272304, 0.069823056,
-0.073957728, 0.078672, 0.08396, 0.08981104, 0.096199728, 0.103135216, 0.110638224, 0.1187, 0.127270608, 0.136308176, 0.145701424, 0.155300192, 0.16498,
-0.174693408, 0.184392544, 0.19395336, 0.203226416, 0.2121, 0.220530096, 0.22846464, 0.235890944, 0.242824608, 0.24927, 0.255197536, 0.260591104, 0.265442112,
-0.26974456, 0.2735, 0.276715968, 0.279400032, 0.281587056, 0.28332656, 0.28466, 0.285604384, 0.286179536, 0.286424608, 0.28638528, 0.2861, 0.285592704,
-0.284889328, 0.284011952, 0.282978976, 0.28181, 0.280528096, 0.279153952, 0.277709808, 0.276218464, 0.2747, 0.273170448, 0.271645728, 0.270137808, 0.268655968,
-0.26721, 0.265811072, 0.264467888, 0.263193024, 0.2620004, 0.2609, 0.259896, 0.258993104, 0.258188768, 0.257476032, 0.25685, 0.256312032, 0.255861968,
-0.255496304, 0.2552104, 0.255, 0.254862624, 0.254795744, 0.254793904, 0.254850464, 0.25496, 0.255121024, 0.255333104, 0.255589184, 0.255879904, 0.2562,
-0.256550672, 0.256931904, 0.257351296, 0.257820928, 0.25835, 0.25893936, 0.259590336, 0.260311072, 0.261111888, 0.262, 0.262976512, 0.264042752, 0.265199312,
-0.266445472, 0.26778, 0.269200192, 0.270700912, 0.272283232, 0.273950272, 0.2757, 0.277523024, 0.279411232, 0.28134512, 0.283298688, 0.28525, 0.287189152,
-0.2891056, 0.290978368, 0.292783056, 0.2945, 0.296118768, 0.297628368, 0.299019648, 0.300285728, 0.30142, 0.302416448, 0.303272048, 0.303977648, 0.304522128,
-0.3049, 0.305113296, 0.305162448, 0.30506232, 0.304835712, 0.3045, 0.304056128, 0.30350464, 0.302863632, 0.302157104, 0.3014, 0.300593408, 0.299742512,
-0.298831536, 0.29783328, 0.29673, 0.295532256, 0.294253856, 0.292873936, 0.291360096, 0.2897, 0.287914176, 0.286018096, 0.284059136, 0.282104416, 0.28021,
-0.278380768, 0.276607376, 0.274996144, 0.273697952, 0.2728, 0.272293104, 0.272201584, 0.272389824, 0.272637344, 0.2728, 0.272894848, 0.272906704, 0.27285928,
-0.272811856, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728,
-0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728,
-0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728,
-0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728,
-/* CES98 */ 0.3104, 0.3104, 0.3104, 0.3104, 0.3104, 0.3104, 0.3104, 0.3104, 0.3104, 0.3104, 0.3104, 0.310415808, 0.31047904, 0.310542272, 0.310526464, 0.3104,
-0.310182864, 0.309851792, 0.30959976, 0.309722048, 0.3104, 0.311600704, 0.31333648, 0.315488336, 0.317850992, 0.32028, 0.322789072, 0.325373536, 0.327956,
-0.330442944, 0.33277, 0.334919744, 0.33686736, 0.338636896, 0.340282192, 0.34184, 0.343295648, 0.344639296, 0.345903184, 0.347133632, 0.34836, 0.349579632,
-0.350795104, 0.351992816, 0.353147488, 0.35424, 0.355269392, 0.356231456, 0.35711096, 0.357889104, 0.35855, 0.359084928, 0.35948456, 0.359736752, 0.359828944,
-0.35975, 0.359492848, 0.359052272, 0.358415696, 0.35756816, 0.3565, 0.35521, 0.353696176, 0.351964512, 0.350026128, 0.34789, 0.34555888, 0.343038032,
-0.340333504, 0.337451536, 0.3344, 0.331188176, 0.327825024, 0.324325712, 0.32070864, 0.31699, 0.313179536, 0.309287072, 0.305328368, 0.301321104, 0.29728,
-0.293214656, 0.289135408, 0.28504872, 0.280958512, 0.27687, 0.27279216, 0.26873312, 0.2646992, 0.26069616, 0.25673, 0.252807008, 0.2489328, 0.245114912,
-0.241361664, 0.23768, 0.2340728, 0.230541472, 0.227094304, 0.223741856, 0.22049, 0.217337456, 0.214284144, 0.211320912, 0.20843328, 0.20561, 0.202849504,
-0.200149632, 0.19749976, 0.194886448, 0.1923, 0.189737968, 0.18719728, 0.184676832, 0.182177504, 0.1797, 0.177244448, 0.174812992, 0.172404416, 0.1700164,
-0.16765, 0.165310592, 0.163002496, 0.16073944, 0.158540224, 0.15642, 0.154383024, 0.15243312, 0.150585776, 0.148860352, 0.14727, 0.145816864, 0.144504176,
-0.143328768, 0.1422828, 0.14136, 0.140558832, 0.139875008, 0.139304944, 0.13884528, 0.13849, 0.138230576, 0.138059424, 0.137958672, 0.1379052, 0.13788,
-0.137876384, 0.137888752, 0.13789704, 0.137876368, 0.13781, 0.137695296, 0.13752816, 0.137313984, 0.137064768, 0.13679, 0.136491584, 0.136174784, 0.135845824,
-0.135511264, 0.13518, 0.134863376, 0.134572784, 0.134326192, 0.13414528, 0.13405, 0.134055232, 0.134177312, 0.134434512, 0.134845712, 0.13543, 0.136205584,
-0.137190272, 0.13840656, 0.139879008, 0.14163, 0.14367568, 0.14603104, 0.14871904, 0.1517652, 0.15519, 0.159004496, 0.16321944, 0.167844224, 0.172885888,
-0.17835, 0.184238016, 0.190546224, 0.197284752, 0.20446824, 0.2121, 0.220166128, 0.228654512, 0.237533136, 0.24675776, 0.25629, 0.266109296, 0.276191216,
-0.286500736, 0.296999216, 0.30765, 0.318421952, 0.329282736, 0.34019776, 0.351131824, 0.36205, 0.372918368, 0.38370296, 0.394368512, 0.404879264, 0.4152,
-0.425298688, 0.435145232, 0.444699376, 0.45391696, 0.46276, 0.471209232, 0.479252016, 0.48684336, 0.493927424, 0.50047, 0.506471056, 0.51192576, 0.516866144,
-0.521346768, 0.52541, 0.529061712, 0.532312944, 0.535200736, 0.537770768, 0.54006, 0.542087712, 0.543876336, 0.54544272, 0.546799024, 0.54796, 0.548947968,
-0.54978408, 0.550484672, 0.551064544, 0.55154, 0.551929968, 0.552252352, 0.552528256, 0.55278048, 0.55303, 0.553292272, 0.553581456, 0.55391992, 0.554332784,
-0.55484, 0.555453104, 0.55618472, 0.557039536, 0.558017232, 0.55912, 0.560357552, 0.561738096, 0.56326536, 0.564941584, 0.56677, 0.568755744, 0.57090288,
-0.573217856, 0.575708272, 0.57838, 0.58123368, 0.584268176, 0.587491232, 0.590913568, 0.59454, 0.59836616, 0.602389152, 0.606595264, 0.610964336, 0.61548,
-0.62013704, 0.624929104, 0.629841408, 0.634856432, 0.63996, 0.645143904, 0.650398608, 0.655719392, 0.661104496, 0.66655, 0.672045504, 0.677580272, 0.68315072,
-0.688755648, 0.69439, 0.70004304, 0.70570584, 0.71135976, 0.71698096, 0.72255, 0.728060784, 0.73350904, 0.738873216, 0.744125952, 0.74925, 0.754242896,
-0.759097856, 0.763833056, 0.768480176, 0.77306, 0.77756048, 0.781966016, 0.786301232, 0.790604208, 0.79489, 0.799135136, 0.803321392, 0.807397488, 0.811290944,
-0.81494, 0.818346096, 0.821535568, 0.82435968, 0.826600992, 0.82814, 0.82900272, 0.82916072, 0.82884552, 0.82842472, 0.82814, 0.82797104, 0.82794992, 0.8280344,
-0.82811888, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814,
-0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814,
-0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814,
-0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814,
-0.82814, 0.82814, 0.82814, 0.82814,
-/* CES99 */ 0.2092, 0.2092, 0.2092, 0.2092, 0.2092, 0.2092, 0.2092, 0.2092, 0.2092, 0.2092, 0.2092, 0.209217984, 0.20928992, 0.209361856, 0.209343872, 0.2092,
-0.208948576, 0.208554336, 0.208250016, 0.208393536, 0.2092, 0.210619728, 0.212659856, 0.215151344, 0.217811872, 0.22044, 0.223047904, 0.225628384, 0.228057424,
-0.230181744, 0.2319, 0.233194928, 0.234035904, 0.2344784, 0.234630496, 0.23457, 0.234283616, 0.2337664, 0.233088384, 0.232344688, 0.2316, 0.230862144,
-0.230148704, 0.229445744, 0.228717344, 0.22794, 0.227127232, 0.226288304, 0.225404416, 0.224447488, 0.2234, 0.222264656, 0.221040576, 0.219736736, 0.218369216,
-0.21695, 0.215478912, 0.213957056, 0.21239488, 0.210805904, 0.2092, 0.207580144, 0.20595048, 0.204311616, 0.202661632, 0.201, 0.199330176, 0.197654896,
-0.195974736, 0.194289616, 0.1926, 0.190907504, 0.189213456, 0.187519568, 0.18582784, 0.18414, 0.182456768, 0.180778528, 0.179107728, 0.177447568, 0.1758,
-0.174164896, 0.172542448, 0.17093096, 0.169327552, 0.16773, 0.166138176, 0.16455184, 0.162968624, 0.161385408, 0.1598, 0.15821208, 0.156621184, 0.155027088,
-0.153429952, 0.15183, 0.15022784, 0.148624848, 0.147020096, 0.145411824, 0.1438, 0.14218704, 0.140574736, 0.138969152, 0.137378688, 0.13581, 0.134264544,
-0.132743472, 0.13125368, 0.129803968, 0.1284, 0.127042112, 0.12573136, 0.124464528, 0.123235616, 0.12204, 0.120876992, 0.119744848, 0.118640384, 0.11756,
-0.1165, 0.115457296, 0.114429024, 0.113409472, 0.11239168, 0.11137, 0.110342528, 0.109308032, 0.108260576, 0.10719272, 0.1061, 0.1049828, 0.103840976,
-0.102678752, 0.101503168, 0.10032, 0.0991312, 0.097939792, 0.096750544, 0.095568816, 0.0944, 0.093248656, 0.092119184, 0.091018992, 0.0899568, 0.08894,
-0.08797216, 0.087056192, 0.086199824, 0.085412336, 0.0847, 0.084064032, 0.083506064, 0.083024016, 0.082613248, 0.08227, 0.081993312, 0.081780896, 0.08163,
-0.081537664, 0.0815, 0.081512624, 0.08157152, 0.081667856, 0.081790432, 0.08193, 0.082083264, 0.082247696, 0.082413088, 0.08256664, 0.0827, 0.082812624,
-0.082903328, 0.082975472, 0.083035776, 0.08309, 0.083140864, 0.083193232, 0.08325104, 0.083317968, 0.0834, 0.083506048, 0.08364488, 0.083830592, 0.084080384,
-0.08441, 0.084830528, 0.085353872, 0.085995536, 0.08677232, 0.0877, 0.08879208, 0.090062576, 0.091525152, 0.093193088, 0.09508, 0.097200416, 0.099568912,
-0.102199248, 0.105104944, 0.1083, 0.111797408, 0.115608288, 0.119753408, 0.124257568, 0.12914, 0.13440304, 0.140043648, 0.146086816, 0.152566944, 0.1595,
-0.16687336, 0.174679216, 0.182875312, 0.191399008, 0.2002, 0.209264928, 0.218578112, 0.228091696, 0.23774712, 0.2475, 0.257331408, 0.267218016, 0.277153984,
-0.287144992, 0.29719, 0.307267744, 0.317358624, 0.327460064, 0.337575184, 0.3477, 0.357817776, 0.367914384, 0.377966112, 0.3879432, 0.39782, 0.407583168,
-0.417219472, 0.426703216, 0.43600456, 0.4451, 0.45397736, 0.462623216, 0.471030672, 0.479197568, 0.48712, 0.4947888, 0.502197312, 0.509339184, 0.516208176,
-0.5228, 0.529112864, 0.535145264, 0.540899344, 0.546379584, 0.55159, 0.556533312, 0.561214304, 0.565634736, 0.569795328, 0.5737, 0.577356928, 0.580773456,
-0.583965024, 0.586951552, 0.58975, 0.59236832, 0.594814384, 0.597105488, 0.599262112, 0.6013, 0.60322672, 0.605051168, 0.606775696, 0.608398464, 0.60992,
-0.611347936, 0.612689136, 0.613944896, 0.615114656, 0.6162, 0.617206352, 0.618138176, 0.6190032, 0.619811104, 0.62057, 0.621283328, 0.62195408, 0.622590752,
-0.623203664, 0.6238, 0.624381888, 0.624952352, 0.625508976, 0.62604608, 0.62656, 0.627053024, 0.627527056, 0.627979008, 0.62840416, 0.6288, 0.629167792,
-0.629507968, 0.629824544, 0.63012368, 0.63041, 0.630683488, 0.630943904, 0.63119616, 0.631446896, 0.6317, 0.63195464, 0.63221064, 0.63246416, 0.63270896,
-0.63294, 0.633157984, 0.63336504, 0.633550416, 0.633698352, 0.6338, 0.633857056, 0.633867456, 0.633846496, 0.633818656, 0.6338, 0.633788992, 0.633787616,
-0.63379312, 0.633798624, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338,
-0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338,
-0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338,
-0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338
-];
+static CES: LazyBlob = LazyBlob::new(include_blob!("ces.bin"));
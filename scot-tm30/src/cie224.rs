@@ -0,0 +1,94 @@
+/*!
+The CIE 224:2017 color fidelity index Rf.
+
+CIE 224:2017, "Colour fidelity index for accurate scientific use", defines the same general fidelity index Rf as
+ANSI/IES TM-30-18, and later: it uses the same 99 color evaluation samples, the same reference illuminants, with a
+blend of a Planckian radiator, and CIE daylight, between 4000 and 5000K, and the same CAM02-UCS color differences,
+and scaling.
+The earlier TM-30-15 blended its reference illuminants between 4500 and 5500K, and gives slightly different values
+for sources in that range.
+
+CIE 224 does not define a gamut index, or any of the hue bin metrics of TM-30; it reports the general fidelity index
+Rf, and the special color fidelity indices Rf,i of the individual samples, only.
+*/
+
+use scot::{SpectralDistribution, WavelengthStep};
+
+use crate::tm30::Tm30Result;
+
+/**
+General, and special, color fidelity indices of a light source, calculated according to CIE 224:2017.
+
+# Examples
+```
+use scot_tm30::samples::CieF2;
+use scot_tm30::Cie224;
+
+let cie = Cie224::new(&CieF2::default());
+assert_eq!(cie.rf.round(), 70.0);
+println!("Rf {:.0}, Rf,1 {:.0}", cie.rf, cie.rf_i[0]);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cie224 {
+	/// General color fidelity index.
+	pub rf: f64,
+	/// Special color fidelity indices Rf,1 to Rf,99, of the color evaluation samples.
+	pub rf_i: Vec<f64>,
+	/// Correlated color temperature of the source, in Kelvin, which is also the temperature of the reference.
+	pub cct: f64,
+	/// Distance of the source to the Planckian locus, in the CIE 1960 UCS diagram.
+	pub duv: f64,
+}
+
+impl Cie224 {
+	pub fn new<S>(source: &S) -> Self
+	where
+		S: SpectralDistribution<StepType = WavelengthStep>,
+	{
+		Tm30Result::new(source).into()
+	}
+}
+
+impl From<Tm30Result> for Cie224 {
+	fn from(tm30: Tm30Result) -> Self {
+		Self {
+			rf: tm30.rf,
+			rf_i: tm30.rf_ces,
+			cct: tm30.cct,
+			duv: tm30.duv,
+		}
+	}
+}
+
+#[test]
+fn test_cie224() {
+	use approx::assert_abs_diff_eq;
+	use crate::samples::*;
+	use scot::illuminants::fl_ies_tm30_tool_values;
+
+	// The IES TM-30 calculation tool values, for a selection of the CIE F illuminants: TM-30-18 and later use the CIE
+	// 224 fidelity index, and their Rf values are the CIE 224 values.
+	let tool = fl_ies_tm30_tool_values();
+	for (key, cie) in [
+		("F1", Cie224::new(&CieF1::default())),
+		("F4", Cie224::new(&CieF4::default())),
+		("F7", Cie224::new(&CieF7::default())),
+		("F8", Cie224::new(&CieF8::default())),
+		("F11", Cie224::new(&CieF11::default())),
+	] {
+		let [_x, _y, cct, _duv, rf, _rg] = tool[key];
+		assert_abs_diff_eq!(cie.rf, rf, epsilon = 0.1);
+		assert_abs_diff_eq!(cie.cct, cct, epsilon = 2.5);
+		let mean = cie.rf_i.iter().sum::<f64>() / 99.0;
+		assert!(cie.rf_i.iter().all(|&rf_i| rf_i <= 100.0));
+		assert!(cie.rf_i.iter().any(|&rf_i| rf_i < cie.rf));
+		assert_abs_diff_eq!(mean, cie.rf, epsilon = 3.0);
+	}
+
+	let tm30 = Tm30Result::new(&CieF11::default());
+	let cie = Cie224::new(&CieF11::default());
+	assert_eq!(cie.rf, tm30.rf);
+	assert_eq!(cie.rf_i.len(), 99);
+	assert_eq!(cie.rf_i, tm30.rf_ces);
+}
@@ -0,0 +1,162 @@
+/*!
+The TM-30 color vector graphic, as an SVG image.
+
+The color vector graphic shows the average color shifts of the color evaluation samples in each of the 16 hue angle
+bins, in the CAM02-UCS a'b' plane, normalized to the average chroma of the bin under the reference illuminant: the
+reference illuminant is the black unit circle, and the test source the red polygon, with arrows showing the shift
+of each bin from the reference to the test source.
+The bins are drawn as colored sectors in the background, using the IES bin colors, with the fidelity index Rf, the
+gamut index Rg, and the correlated color temperature of the source, listed in its corners.
+
+This module requires the `svg` feature.
+*/
+
+use std::f64::consts::PI;
+
+use svg::node::element::path::Data;
+use svg::node::element::{Circle, Element, Path, Rectangle};
+use svg::node::{Node, Text};
+use svg::Document;
+
+use crate::tm30::{bin_means, Tm30Result, TM30_HUE_BINS};
+
+/// Colors of the hue angle bins, as used by the IES in the color vector graphic.
+pub static TM30_BIN_COLORS: [&str; TM30_HUE_BINS] = [
+	"#e62828", "#e74b4b", "#fb812e", "#ffb529", "#cbca46", "#7eb94c", "#41c06d", "#009c7c", "#16bcb0", "#00a4bf",
+	"#0085c3", "#3b62aa", "#4568ae", "#6a4e85", "#9d69a1", "#a74f81",
+];
+
+/// Width, and height, of the color vector graphic, in pixels.
+pub const CVG_SIZE: f64 = 600.0;
+
+/// Radius of the reference circle in the color vector graphic, in pixels.
+const R: f64 = 200.0;
+
+/// Position in the graphic of a point in the normalized a'b' plane, with positive b' values upwards.
+fn xy(a: f64, b: f64) -> (f64, f64) {
+	(CVG_SIZE / 2.0 + R * a, CVG_SIZE / 2.0 - R * b)
+}
+
+fn text(x: f64, y: f64, anchor: &str, content: String) -> Element {
+	let mut e = Element::new("text");
+	e.assign("x", x);
+	e.assign("y", y);
+	e.assign("text-anchor", anchor);
+	e.assign("font-family", "Arial, Helvetica, sans-serif");
+	e.assign("font-size", 20);
+	e.append(Text::new(content));
+	e
+}
+
+impl Tm30Result {
+	/// Color vector graphic of the result, as an SVG document.
+	///
+	/// # Examples
+	/// ```
+	/// use scot_tm30::samples::CieF2;
+	/// use scot_tm30::Tm30Result;
+	///
+	/// let cvg = Tm30Result::new(&CieF2::default()).cvg();
+	/// assert!(cvg.to_string().starts_with("<svg"));
+	/// // svg::save("cvg.svg", &cvg).unwrap();
+	/// ```
+	pub fn cvg(&self) -> Document {
+		let ab_t = bin_means(&self.test, &self.hue_bins);
+		let ab_r = bin_means(&self.reference, &self.hue_bins);
+
+		// reference, and test, points, normalized to the reference chroma of each bin
+		let mut reference = Vec::with_capacity(TM30_HUE_BINS);
+		let mut test = Vec::with_capacity(TM30_HUE_BINS);
+		for ([a_t, b_t], [a_r, b_r]) in ab_t.iter().zip(ab_r.iter()) {
+			let c_r = a_r.hypot(*b_r);
+			let (sin, cos) = b_r.atan2(*a_r).sin_cos();
+			reference.push((cos, sin));
+			test.push((cos + (a_t - a_r) / c_r, sin + (b_t - b_r) / c_r));
+		}
+
+		let mut doc = Document::new()
+			.set("viewBox", (0, 0, CVG_SIZE, CVG_SIZE))
+			.set("width", CVG_SIZE)
+			.set("height", CVG_SIZE)
+			.add(Rectangle::new()
+				.set("width", CVG_SIZE)
+				.set("height", CVG_SIZE)
+				.set("fill", "white"));
+
+		// colored hue angle bin sectors
+		let r_bg = 1.5;
+		for (j, color) in TM30_BIN_COLORS.iter().enumerate() {
+			let h0 = 2.0 * PI * j as f64 / TM30_HUE_BINS as f64;
+			let h1 = 2.0 * PI * (j + 1) as f64 / TM30_HUE_BINS as f64;
+			let data = Data::new()
+				.move_to(xy(0.0, 0.0))
+				.line_to(xy(r_bg * h0.cos(), r_bg * h0.sin()))
+				.line_to(xy(r_bg * h1.cos(), r_bg * h1.sin()))
+				.close();
+			doc = doc.add(Path::new()
+				.set("d", data)
+				.set("fill", *color)
+				.set("fill-opacity", 0.35)
+				.set("stroke", "none"));
+		}
+
+		// reference circle
+		let (cx, cy) = xy(0.0, 0.0);
+		doc = doc.add(Circle::new()
+			.set("cx", cx)
+			.set("cy", cy)
+			.set("r", R)
+			.set("fill", "none")
+			.set("stroke", "black")
+			.set("stroke-width", 3));
+
+		// test source polygon
+		let mut data = Data::new().move_to(xy(test[0].0, test[0].1));
+		for &(a, b) in test.iter().skip(1) {
+			data = data.line_to(xy(a, b));
+		}
+		doc = doc.add(Path::new()
+			.set("d", data.close())
+			.set("fill", "none")
+			.set("stroke", "#e00000")
+			.set("stroke-width", 3));
+
+		// arrows, from the reference to the test source, for each bin
+		for ((&(a_r, b_r), &(a_t, b_t)), color) in reference.iter().zip(test.iter()).zip(TM30_BIN_COLORS.iter()) {
+			let (x0, y0) = xy(a_r, b_r);
+			let (x1, y1) = xy(a_t, b_t);
+			let len = (x1 - x0).hypot(y1 - y0);
+			let mut arrow = Data::new().move_to((x0, y0)).line_to((x1, y1));
+			if len > 1.0 {
+				// arrow head, with a length of 12 pixels, or the length of the arrow, if shorter
+				let (ux, uy) = ((x1 - x0) / len, (y1 - y0) / len);
+				let l = len.min(12.0);
+				arrow = arrow
+					.move_to((x1 - l * ux - l / 2.0 * uy, y1 - l * uy + l / 2.0 * ux))
+					.line_to((x1, y1))
+					.line_to((x1 - l * ux + l / 2.0 * uy, y1 - l * uy - l / 2.0 * ux));
+			}
+			doc = doc.add(Path::new()
+				.set("d", arrow)
+				.set("fill", "none")
+				.set("stroke", *color)
+				.set("stroke-width", 3));
+		}
+
+		doc.add(text(20.0, 35.0, "start", format!("Rf {:.0}", self.rf)))
+			.add(text(CVG_SIZE - 20.0, 35.0, "end", format!("Rg {:.0}", self.rg)))
+			.add(text(20.0, CVG_SIZE - 20.0, "start", format!("CCT {:.0}K", self.cct)))
+			.add(text(CVG_SIZE - 20.0, CVG_SIZE - 20.0, "end", format!("Duv {:.4}", self.duv)))
+	}
+}
+
+#[test]
+fn test_cvg() {
+	use scot::illuminants::CieIllA;
+
+	let svg = Tm30Result::new(&CieIllA).cvg().to_string();
+	assert_eq!(svg.matches("<path").count(), 2 * TM30_HUE_BINS + 1);
+	assert_eq!(svg.matches("<circle").count(), 1);
+	assert!(svg.contains("Rf 100"));
+	assert!(svg.contains("Rg 100"));
+}
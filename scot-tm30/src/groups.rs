@@ -0,0 +1,137 @@
+/*!
+Color evaluation samples, grouped by their colors under TM-30 reference illuminants.
+
+TM-30 assigns each of the 99 color evaluation samples to one of 16 hue angle bins, by its hue angle in CAM02-UCS
+under the reference illuminant, which depends on the correlated color temperature of the source tested.
+`CesReference` holds the CAM02-UCS J', a', and b' values, and the hue angle bins, of the samples under a reference
+illuminant, to select subsets of samples without a full TM-30 calculation.
+For the reference illuminants at the correlated color temperatures in `CES_REFERENCE_CCTS` these are calculated once,
+when first used, and shared, with `ces_references`.
+
+# Examples
+```
+use scot_tm30::{ces_references, CesReference};
+
+// samples in the first, red, hue angle bin, under a 3000K Planckian reference
+let r3000 = CesReference::nearest(3000.0);
+let reds = r3000.samples_in_bin(0);
+assert!(reds.iter().all(|&i| r3000.hue_bin(i) == 0));
+assert_eq!(ces_references().len(), 10);
+```
+*/
+
+use std::sync::OnceLock;
+
+use nalgebra::Matrix3xX;
+use scot::illuminants::{ReferenceIlluminant, ReferenceSelection};
+use scot::observers::{CieObs1964, StandardObserver};
+use scot::{Domain, SpectralDistribution, NM};
+
+use crate::tm30::{ces_jab, hue_bins, TM30_HUE_BINS};
+
+/// Correlated color temperatures, in Kelvin, of the reference illuminants of `ces_references`.
+pub const CES_REFERENCE_CCTS: [f64; 10] = [
+	2700.0, 3000.0, 3500.0, 4000.0, 4500.0, 5000.0, 5700.0, 6500.0, 8000.0, 10000.0,
+];
+
+/// CAM02-UCS values, and hue angle bins, of the color evaluation samples under a TM-30 reference illuminant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CesReference {
+	/// Correlated color temperature of the reference illuminant, in Kelvin.
+	pub cct: f64,
+	/// CAM02-UCS J', a', and b' values of the samples, one in each column.
+	pub jab: Matrix3xX<f64>,
+	/// Hue angle bin of each sample, from 0 to 15.
+	pub hue_bins: Vec<usize>,
+}
+
+impl CesReference {
+	/// Sample values for a TM-30 reference illuminant with a correlated color temperature, in Kelvin.
+	pub fn new(cct: f64) -> Self {
+		let d = Domain::new(380, 780, NM);
+		let s = ReferenceIlluminant::new(cct, ReferenceSelection::Tm30)
+			.set_domain(d.clone())
+			.spd()
+			.1
+			.column(0)
+			.into_owned();
+		let jab = ces_jab(&CieObs1964::values(&d), &s);
+		Self {
+			cct,
+			hue_bins: hue_bins(&jab),
+			jab,
+		}
+	}
+
+	/// The shared sample values for the reference illuminant in `ces_references` with the correlated color
+	/// temperature closest to `cct`.
+	pub fn nearest(cct: f64) -> &'static Self {
+		ces_references()
+			.iter()
+			.min_by(|a, b| (a.cct - cct).abs().partial_cmp(&(b.cct - cct).abs()).unwrap())
+			.unwrap()
+	}
+
+	/// CAM02-UCS J', a', and b' values of a sample, with index 0 for CES1.
+	pub fn jab(&self, sample: usize) -> [f64; 3] {
+		let c = self.jab.column(sample);
+		[c.x, c.y, c.z]
+	}
+
+	/// Hue angle of a sample, in degrees, from 0 to 360.
+	pub fn hue_angle(&self, sample: usize) -> f64 {
+		let [_, a, b] = self.jab(sample);
+		b.atan2(a).to_degrees().rem_euclid(360.0)
+	}
+
+	/// CAM02-UCS chroma of a sample.
+	pub fn chroma(&self, sample: usize) -> f64 {
+		let [_, a, b] = self.jab(sample);
+		a.hypot(b)
+	}
+
+	/// Hue angle bin of a sample, from 0 to 15.
+	pub fn hue_bin(&self, sample: usize) -> usize {
+		self.hue_bins[sample]
+	}
+
+	/// Indices of the samples in a hue angle bin, with index 0 for CES1.
+	pub fn samples_in_bin(&self, bin: usize) -> Vec<usize> {
+		assert!(bin < TM30_HUE_BINS, "hue angle bin should be less than {}", TM30_HUE_BINS);
+		self.samples_where(|i| self.hue_bins[i] == bin)
+	}
+
+	/// Indices of the samples, with index 0 for CES1, for which a predicate on the sample index is true.
+	pub fn samples_where<P: Fn(usize) -> bool>(&self, predicate: P) -> Vec<usize> {
+		(0..self.hue_bins.len()).filter(|&i| predicate(i)).collect()
+	}
+}
+
+/// Sample values for the reference illuminants with the correlated color temperatures in `CES_REFERENCE_CCTS`,
+/// calculated when first used.
+pub fn ces_references() -> &'static [CesReference] {
+	static REFERENCES: OnceLock<Vec<CesReference>> = OnceLock::new();
+	REFERENCES.get_or_init(|| CES_REFERENCE_CCTS.iter().map(|&t| CesReference::new(t)).collect())
+}
+
+#[test]
+fn test_ces_reference() {
+	use crate::Tm30Result;
+	use approx::assert_abs_diff_eq;
+
+	// same values as in a full TM-30 calculation, for a reference illuminant as source
+	let r = CesReference::nearest(4400.0);
+	assert_eq!(r.cct, 4500.0);
+	let tm30 = Tm30Result::new(&ReferenceIlluminant::new(4500.0, ReferenceSelection::Tm30));
+	assert_eq!(r.hue_bins, tm30.hue_bins);
+	assert_abs_diff_eq!(r.jab, tm30.reference, epsilon = 1E-3);
+
+	// all samples are in one of the bins
+	let n: usize = (0..TM30_HUE_BINS).map(|j| r.samples_in_bin(j).len()).sum();
+	assert_eq!(n, 99);
+	for i in r.samples_in_bin(4) {
+		assert!(r.hue_angle(i) >= 90.0 && r.hue_angle(i) < 112.5);
+	}
+	assert!(!r.samples_where(|i| r.chroma(i) > 30.0).is_empty());
+	assert!(std::ptr::eq(CesReference::nearest(1000.0), &ces_references()[0]));
+}
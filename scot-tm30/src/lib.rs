@@ -11,4 +11,22 @@ pub mod samples;
 pub use self::samples::*;
 
 pub mod ces;
-pub use self::ces::*;
\ No newline at end of file
+pub use self::ces::*;
+
+pub mod tm30;
+pub use self::tm30::*;
+
+pub mod groups;
+pub use self::groups::*;
+
+pub mod cie224;
+pub use self::cie224::*;
+
+pub mod report;
+pub use self::report::*;
+
+#[cfg(feature="svg")]
+pub mod cvg;
+
+#[cfg(feature="svg")]
+pub use self::cvg::*;
@@ -0,0 +1,146 @@
+/*!
+A report with the color quality, efficacy, and &alpha;-opic, metrics of a light source.
+
+A `LightSourceReport` collects, for a single spectral distribution, its correlated color temperature and distance to
+the Planckian locus, its CIE 13.3 color rendering indices, and gamut area index, its ANSI/IES TM-30 fidelity and gamut
+indices, its luminous efficacy of radiation, and its CIE S 026 &alpha;-opic efficacies, all calculated with the
+engines of this, and the `scot-cri`, library, using the CIE 1931 observer as photopic observer.
+The report holds plain values only, and can be printed as a table, or, with the `serde` feature enabled, serialized
+in any format supported by serde.
+*/
+
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use scot::observers::{ler, CieObs1931, ALPHA_OPIC};
+use scot::{SpectralDistribution, WavelengthStep};
+use scot_cri::Cri;
+
+use crate::tm30::Tm30Result;
+
+/**
+Color quality, efficacy, and &alpha;-opic, metrics of a light source.
+
+Only the first spectral distribution of a source is used.
+
+# Examples
+```
+use scot_tm30::samples::CieF2;
+use scot_tm30::LightSourceReport;
+
+let report = LightSourceReport::new(&CieF2::default());
+assert_eq!(report.rf.round(), 70.0);
+println!("{}", report);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LightSourceReport {
+	/// Correlated color temperature, in Kelvin.
+	pub cct: f64,
+	/// Distance to the Planckian locus, in the CIE 1960 UCS diagram.
+	pub duv: f64,
+	/// CIE 13.3 general color rendering index.
+	pub ra: f64,
+	/// CIE 13.3 special color rendering indices R1 to R15.
+	pub ri: [f64; 15],
+	/// Gamut area index.
+	pub gai: f64,
+	/// TM-30 fidelity index.
+	pub rf: f64,
+	/// TM-30 gamut index.
+	pub rg: f64,
+	/// TM-30 hue bin fidelity indices.
+	pub rf_h: Vec<f64>,
+	/// TM-30 hue bin relative chroma shifts.
+	pub rcs_h: Vec<f64>,
+	/// Luminous efficacy of radiation, in lm/W.
+	pub ler: f64,
+	/// &alpha;-opic efficacies of luminous radiation, in W/lm, in the order of `ALPHA_OPIC`.
+	pub elr: [f64; 4],
+	/// &alpha;-opic daylight (D65) efficacy ratios, in the order of `ALPHA_OPIC`.
+	pub der: [f64; 4],
+}
+
+impl LightSourceReport {
+	pub fn new<S>(source: &S) -> Self
+	where
+		S: SpectralDistribution<StepType = WavelengthStep>,
+	{
+		let cri = Cri::new(source);
+		let tm30 = Tm30Result::new(source);
+		let mut elr = [0.0; 4];
+		let mut der = [0.0; 4];
+		for (i, a) in ALPHA_OPIC.iter().enumerate() {
+			elr[i] = a.elr::<CieObs1931, S>(source)[0];
+			der[i] = a.der::<CieObs1931, S>(source)[0];
+		}
+		Self {
+			cct: tm30.cct,
+			duv: tm30.duv,
+			ra: cri.ra,
+			ri: cri.ri,
+			gai: cri.gai,
+			rf: tm30.rf,
+			rg: tm30.rg,
+			rf_h: tm30.rf_h,
+			rcs_h: tm30.rcs_h,
+			ler: ler::<CieObs1931, S>(source)[0],
+			elr,
+			der,
+		}
+	}
+}
+
+impl Display for LightSourceReport {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		writeln!(f, "CCT {:.0}K, Duv {:.4}", self.cct, self.duv)?;
+		writeln!(f, "CRI Ra {:.1}, R9 {:.1}, GAI {:.1}", self.ra, self.ri[8], self.gai)?;
+		writeln!(f, "TM-30 Rf {:.1}, Rg {:.1}", self.rf, self.rg)?;
+		write!(f, "Rf,h")?;
+		for v in self.rf_h.iter() {
+			write!(f, " {:.0}", v)?;
+		}
+		writeln!(f)?;
+		write!(f, "Rcs,h")?;
+		for v in self.rcs_h.iter() {
+			write!(f, " {:.0}%", v * 100.0)?;
+		}
+		writeln!(f)?;
+		writeln!(f, "LER {:.1} lm/W", self.ler)?;
+		for (i, a) in ALPHA_OPIC.iter().enumerate() {
+			writeln!(f, "{} ELR {:.3} mW/lm, DER {:.3}", a.name(), self.elr[i] * 1000.0, self.der[i])?;
+		}
+		Ok(())
+	}
+}
+
+#[test]
+fn test_report() {
+	use crate::samples::CieF11;
+
+	let report = LightSourceReport::new(&CieF11::default());
+	let tm30 = Tm30Result::new(&CieF11::default());
+	assert_eq!(report.rf, tm30.rf);
+	assert_eq!(report.rcs_h, tm30.rcs_h);
+	assert_eq!(report.ra, Cri::new(&CieF11::default()).ra);
+	assert!(report.ler > 250.0 && report.ler < 400.0, "{}", report.ler);
+	assert!(report.to_string().contains("Rf"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_report_serde() {
+	use approx::assert_abs_diff_eq;
+	use crate::samples::CieF11;
+
+	let report = LightSourceReport::new(&CieF11::default());
+	let json = serde_json::to_string(&report).unwrap();
+	let back: LightSourceReport = serde_json::from_str(&json).unwrap();
+	assert_abs_diff_eq!(back.ri.as_ref(), report.ri.as_ref(), epsilon = 1E-12);
+	assert_abs_diff_eq!(back.rcs_h.as_slice(), report.rcs_h.as_slice(), epsilon = 1E-12);
+	assert_abs_diff_eq!(back.elr.as_ref(), report.elr.as_ref(), epsilon = 1E-12);
+	assert_eq!(back.rf, report.rf);
+}
@@ -46,7 +46,7 @@ impl<const K: usize> SpectralDistribution for TM30Illuminant<K> {
     fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
         (
             Domain::new(380, 780, NM),
-            Self::MatrixType::from_slice(&TM30_ILLUMINANTS_DATA[(K - 1) * N..K * N]),
+            Self::MatrixType::from_slice(&TM30_ILLUMINANTS_DATA.data()[(K - 1) * N..K * N]),
         )
     }
 
@@ -86,7 +86,7 @@ impl Default for TM30SampleSpectra {
     fn default() -> Self {
         Self(
             Domain::new(380, 780, NM),
-            <Self as SpectralDistribution>::MatrixType::from_slice(&TM30_ILLUMINANTS_DATA),
+            <Self as SpectralDistribution>::MatrixType::from_slice(TM30_ILLUMINANTS_DATA.data()),
         )
     }
 }
@@ -166,7 +166,7 @@ impl<C: StandardObserver> From<EmissionType> for CieXYZ<C> {
             if e == *j {
                 let sd = DataSpectrumFromSlice::new(
                     Domain::new(380, 780, NM),
-                    &TM30_ILLUMINANTS_DATA[i * N..(i + 1) * N],
+                    &TM30_ILLUMINANTS_DATA.data()[i * N..(i + 1) * N],
                 );
                 let XYZValues { x, y, z } = sd.xyz::<C>().into_iter().next().unwrap();
                 v.push(x);
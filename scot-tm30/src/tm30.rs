@@ -0,0 +1,273 @@
+/*!
+The ANSI/IES TM-30-20 color fidelity index Rf, and gamut index Rg.
+
+TM-30 compares the colors of the 99 color evaluation samples, the CES set, illuminated by a test source, with their
+colors under a reference illuminant with the same correlated color temperature: a Planckian radiator up to 4000K,
+CIE daylight from 5000K, and a mix of the two in between.
+Both sets of colors are calculated in the CAM02-UCS color space, using the CIE 1964 standard observer, an adapting
+luminance of 100 cd/m<sup>2</sup>, a relative background luminance of 20, an average surround, and full chromatic
+adaptation, with each source as its own white point.
+
+The fidelity index Rf is calculated from the mean color difference &Delta;E of the samples, as
+Rf = 10 ln(exp((100 - 6.73 &Delta;E)/10) + 1).
+For the gamut index Rg the samples are grouped into 16 hue angle bins, by their hue angle under the reference
+illuminant; Rg is the ratio of the areas of the polygons spanned by the average a' and b' coordinates of the bins for
+the test source, and for the reference illuminant, multiplied by 100.
+
+The same hue angle bins are used for the local metrics: the local fidelity indices Rf,hj, calculated from the mean
+color difference of the samples in each bin, and the local chroma and hue shifts Rcs,hj and Rhs,hj, the differences
+of the mean a' and b' coordinates of a bin, for the test source and the reference, in the radial and tangential
+directions of the mean reference hue angle of the bin, relative to the mean reference chroma.
+
+The correlated color temperature of the source is calculated with the CIE 1931 standard observer.
+*/
+
+use nalgebra::{DMatrix, DVector, Matrix3xX};
+use scot::illuminants::{CctDuvCalc, Ohno2014Cascade, ReferenceIlluminant, ReferenceSelection};
+use scot::models::{CieCamEnv, CieYuv1960, VcTm30};
+use scot::observers::{CieObs1931, CieObs1964, StandardObserver};
+use scot::{Domain, SpectralDistribution, WavelengthStep, NM};
+
+use crate::ces::Ces;
+
+/// Scaling factor, used to convert the mean color difference of the samples into a fidelity index.
+pub const TM30_CF: f64 = 6.73;
+
+/// Number of hue angle bins, used for the gamut index, and the local metrics.
+pub const TM30_HUE_BINS: usize = 16;
+
+/**
+Color fidelity and gamut indices of a light source, calculated according to ANSI/IES TM-30-20.
+
+Only the first spectral distribution of a source is used.
+
+# Examples
+```
+use scot_tm30::samples::CieF2;
+use scot_tm30::Tm30Result;
+
+let tm30 = Tm30Result::new(&CieF2::default());
+assert_eq!(tm30.rf.round(), 70.0);
+assert_eq!(tm30.rg.round(), 86.0);
+println!("Rf {:.0}, Rg {:.0}, CCT {:.0}K", tm30.rf, tm30.rg, tm30.cct);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tm30Result {
+	/// Color fidelity index.
+	pub rf: f64,
+	/// Color gamut index.
+	pub rg: f64,
+	/// Correlated color temperature of the source, in Kelvin, which is also the temperature of the reference.
+	pub cct: f64,
+	/// Distance of the source to the Planckian locus, in the CIE 1960 UCS diagram.
+	pub duv: f64,
+	/// Fidelity indices of the individual color evaluation samples, Rf,CES1 to Rf,CES99.
+	pub rf_ces: Vec<f64>,
+	/// Hue angle bin of each color evaluation sample, from 0 to 15, by its hue angle under the reference illuminant.
+	pub hue_bins: Vec<usize>,
+	/// Local fidelity indices Rf,h1 to Rf,h16.
+	pub rf_h: Vec<f64>,
+	/// Local chroma shifts Rcs,h1 to Rcs,h16, as fractions of the reference chroma.
+	pub rcs_h: Vec<f64>,
+	/// Local hue shifts Rhs,h1 to Rhs,h16, as fractions of the reference chroma.
+	pub rhs_h: Vec<f64>,
+	/// CAM02-UCS J', a', and b' values of the color evaluation samples, illuminated by the test source.
+	pub test: Matrix3xX<f64>,
+	/// CAM02-UCS J', a', and b' values of the color evaluation samples, illuminated by the reference illuminant.
+	pub reference: Matrix3xX<f64>,
+}
+
+impl Tm30Result {
+	pub fn new<S>(source: &S) -> Self
+	where
+		S: SpectralDistribution<StepType = WavelengthStep>,
+	{
+		let d = Domain::new(380, 780, NM);
+		let s = source.map_domain(d.clone()).column(0).into_owned();
+
+		let xyz = CieObs1931::values(&d) * &s;
+		let den = xyz.x + 15.0 * xyz.y + 3.0 * xyz.z;
+		let yuv = CieYuv1960::<CieObs1931>::new(Matrix3xX::from_column_slice(&[
+			1.0,
+			4.0 * xyz.x / den,
+			6.0 * xyz.y / den,
+		]));
+		let td = Ohno2014Cascade::<CieObs1931>::new()
+			.cct_duv(yuv)
+			.into_iter()
+			.next()
+			.unwrap();
+
+		let reference = ReferenceIlluminant::new(td.t, ReferenceSelection::Tm30).set_domain(d.clone());
+		let s_r = reference.spd().1.column(0).into_owned();
+
+		let cmf = CieObs1964::values(&d);
+		let test = ces_jab(&cmf, &s);
+		let reference = ces_jab(&cmf, &s_r);
+
+		let de: Vec<f64> = (&test - &reference).column_iter().map(|c| c.norm()).collect();
+		let hue_bins = hue_bins(&reference);
+		let ab_t = bin_means(&test, &hue_bins);
+		let ab_r = bin_means(&reference, &hue_bins);
+
+		let mut de_h = vec![(0.0, 0usize); TM30_HUE_BINS];
+		for (&e, &bin) in de.iter().zip(hue_bins.iter()) {
+			de_h[bin].0 += e;
+			de_h[bin].1 += 1;
+		}
+		let mut rcs_h = Vec::with_capacity(TM30_HUE_BINS);
+		let mut rhs_h = Vec::with_capacity(TM30_HUE_BINS);
+		for ([a_t, b_t], [a_r, b_r]) in ab_t.iter().zip(ab_r.iter()) {
+			let c_r = a_r.hypot(*b_r);
+			let (sin, cos) = b_r.atan2(*a_r).sin_cos();
+			let (da, db) = (a_t - a_r, b_t - b_r);
+			rcs_h.push((da * cos + db * sin) / c_r);
+			rhs_h.push((db * cos - da * sin) / c_r);
+		}
+
+		Self {
+			rf: fidelity(de.iter().sum::<f64>() / de.len() as f64),
+			rg: polygon_area(&ab_t) / polygon_area(&ab_r) * 100.0,
+			cct: td.t,
+			duv: td.d,
+			rf_ces: de.iter().map(|&e| fidelity(e)).collect(),
+			rf_h: de_h.iter().map(|&(e, n)| fidelity(e / n as f64)).collect(),
+			hue_bins,
+			rcs_h,
+			rhs_h,
+			test,
+			reference,
+		}
+	}
+}
+
+/// Fidelity index for a color difference in CAM02-UCS.
+fn fidelity(de: f64) -> f64 {
+	10.0 * (((100.0 - TM30_CF * de) / 10.0).exp() + 1.0).ln()
+}
+
+/// CAM02-UCS J', a', and b' values of the color evaluation samples, for a source with its luminance normalized to 100.
+pub(crate) fn ces_jab(cmf: &Matrix3xX<f64>, s: &DVector<f64>) -> Matrix3xX<f64> {
+	let w = cmf * s;
+	let k = 100.0 / w.y;
+	let cam = CieCamEnv::<(), CieObs1964>::with_white(VcTm30::default(), [k * w.x, 100.0, k * w.z]);
+	let r = Ces.spd().1;
+	let sr = DMatrix::from_fn(r.nrows(), r.ncols(), |i, j| s[i] * r[(i, j)]);
+	let mut jab = cmf * sr * k;
+	for mut c in jab.column_iter_mut() {
+		let [j, a, b] = cam.xyz_into_ucs_jab(c.x, c.y, c.z);
+		c.x = j;
+		c.y = a;
+		c.z = b;
+	}
+	jab
+}
+
+/// Hue angle bin of each sample, by its hue angle under the reference illuminant.
+pub(crate) fn hue_bins(reference: &Matrix3xX<f64>) -> Vec<usize> {
+	reference
+		.column_iter()
+		.map(|c| {
+			let h = c.z.atan2(c.y).rem_euclid(2.0 * std::f64::consts::PI);
+			((h / (2.0 * std::f64::consts::PI) * TM30_HUE_BINS as f64) as usize).min(TM30_HUE_BINS - 1)
+		})
+		.collect()
+}
+
+/// Mean a' and b' values of the samples in each of the hue angle bins.
+pub(crate) fn bin_means(jab: &Matrix3xX<f64>, bins: &[usize]) -> Vec<[f64; 2]> {
+	let mut ab = vec![[0.0; 3]; TM30_HUE_BINS];
+	for (c, &bin) in jab.column_iter().zip(bins.iter()) {
+		ab[bin][0] += c.y;
+		ab[bin][1] += c.z;
+		ab[bin][2] += 1.0;
+	}
+	ab.iter().map(|[a, b, n]| [a / n, b / n]).collect()
+}
+
+/// Area of a polygon, with its vertices in counter clockwise order.
+fn polygon_area(ab: &[[f64; 2]]) -> f64 {
+	let mut area = 0.0;
+	for (i, [a, b]) in ab.iter().enumerate() {
+		let [a_n, b_n] = ab[(i + 1) % ab.len()];
+		area += a * b_n - a_n * b;
+	}
+	area / 2.0
+}
+
+#[test]
+fn test_tm30() {
+	use approx::assert_abs_diff_eq;
+	use scot::illuminants::CieIllA;
+
+	// CAM02-UCS values of the first samples under CIE F1, from the ANSI-IES-TM30-18 Advanced Calculation Tool V2.0
+	let f1 = Tm30Result::new(&crate::samples::CieF1::default());
+	let want = [
+		[84.47803641, 14.07393212, -1.358663773],
+		[58.31960581, 26.00405014, -0.911529935],
+		[29.81095962, 7.894779592, 0.402798249],
+	];
+	for (c, w) in f1.test.column_iter().zip(want.iter()) {
+		assert_abs_diff_eq!(c.x, w[0], epsilon = 1E-4);
+		assert_abs_diff_eq!(c.y, w[1], epsilon = 1E-4);
+		assert_abs_diff_eq!(c.z, w[2], epsilon = 1E-4);
+	}
+
+	// illuminant A is a Planckian radiator, and its own reference
+	let a = Tm30Result::new(&CieIllA);
+	assert_abs_diff_eq!(a.rf, 100.0, epsilon = 0.05);
+	assert_abs_diff_eq!(a.rg, 100.0, epsilon = 0.05);
+	for j in 0..TM30_HUE_BINS {
+		assert!(a.hue_bins.contains(&j));
+		assert_abs_diff_eq!(a.rf_h[j], 100.0, epsilon = 0.05);
+		assert_abs_diff_eq!(a.rcs_h[j], 0.0, epsilon = 1E-3);
+		assert_abs_diff_eq!(a.rhs_h[j], 0.0, epsilon = 1E-3);
+	}
+
+	// a halophosphate lamp desaturates reds, and the mean fidelity of the samples is close to Rf
+	let f2 = Tm30Result::new(&crate::samples::CieF2::default());
+	assert!(f2.rcs_h[0] < -0.1, "{}", f2.rcs_h[0]);
+	assert_eq!(f2.rf_ces.len(), 99);
+	assert!(f2.rf_h.iter().all(|&r| r < 100.0));
+	let mean = f2.rf_ces.iter().sum::<f64>() / 99.0;
+	assert_abs_diff_eq!(mean, f2.rf, epsilon = 2.0);
+
+	// a reference illuminant in the blending range
+	let r = Tm30Result::new(&ReferenceIlluminant::new(4500.0, ReferenceSelection::Tm30));
+	assert_abs_diff_eq!(r.cct, 4500.0, epsilon = 1.0);
+	assert_abs_diff_eq!(r.rf, 100.0, epsilon = 0.05);
+}
+
+#[test]
+fn test_tm30_cie_f() {
+	use approx::assert_abs_diff_eq;
+	use crate::samples::*;
+	use scot::illuminants::fl_ies_tm30_tool_values;
+
+	// CIE F1 to F12, with the CCT, Duv, Rf and Rg values from the IES TM-30 calculation tool; Rf and Rg are given
+	// with one decimal, and the tool uses a different CCT method
+	let tool = fl_ies_tm30_tool_values();
+	let results = [
+		("F1", Tm30Result::new(&CieF1::default())),
+		("F2", Tm30Result::new(&CieF2::default())),
+		("F3", Tm30Result::new(&CieF3::default())),
+		("F4", Tm30Result::new(&CieF4::default())),
+		("F5", Tm30Result::new(&CieF5::default())),
+		("F6", Tm30Result::new(&CieF6::default())),
+		("F7", Tm30Result::new(&CieF7::default())),
+		("F8", Tm30Result::new(&CieF8::default())),
+		("F9", Tm30Result::new(&CieF9::default())),
+		("F10", Tm30Result::new(&CieF10::default())),
+		("F11", Tm30Result::new(&CieF11::default())),
+		("F12", Tm30Result::new(&CieF12::default())),
+	];
+	for (key, r) in results.iter() {
+		let [_x, _y, cct, duv, rf, rg] = tool[key];
+		assert_abs_diff_eq!(r.cct, cct, epsilon = 2.5);
+		assert_abs_diff_eq!(r.duv, duv, epsilon = 3E-5);
+		assert_abs_diff_eq!(r.rf, rf, epsilon = 0.1);
+		assert_abs_diff_eq!(r.rg, rg, epsilon = 0.1);
+		assert!(r.rf_h.iter().all(|&rf_h| rf_h < 100.0), "{}", key);
+	}
+}
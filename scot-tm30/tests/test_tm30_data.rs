@@ -17,7 +17,7 @@ fn test_f1(){
 #[test]
 fn ces_ucs(){
     use scot::observers::CieObs1964;
-    use scot::models::cam02::{CieCamUcs, VcTm30};
+    use scot::models::{CieCamUcs, VcTm30};
     //use scot::illuminants::D50;
     use scot_tm30::ces::Ces;
     use scot_tm30::samples::{TM30Illuminant, CieF1};
@@ -0,0 +1,430 @@
+/*!
+Compact binary format for large embedded datasets.
+
+Large spectral datasets, such as sample libraries, and lamp collections, are embedded with `include_bytes!` as binary
+blobs, instead of as Rust arrays, which are slow to compile. A `LazyBlob` holds the bytes of a blob, and parses them
+at first use, into a `Blob`, which is kept for the lifetime of the program.
+
+A blob holds a matrix of values, in column major order, with an optional key for each column. All numbers are little
+endian:
+
+| Offset | Size | Content |
+|--------|------|---------|
+| 0 | 4 | Signature `SCOT` |
+| 4 | 1 | Format version, 1 |
+| 5 | 1 | Value encoding: 0 for `f64`, 1 for `i16`, and 2 for `i32`, decimal scaled, values, and 3 for decimal values |
+| 6 | 1 | Decimal scale *k* of scaled values, which are the encoded integers divided by 10<sup>k</sup> |
+| 7 | 1 | Reserved, 0 |
+| 8 | 4 | Number of rows, `u32` |
+| 12 | 4 | Number of columns, `u32` |
+| 16 | 4 | Number of keys, `u32`, 0, or the number of columns |
+| 20 | | Keys, each with its length in bytes, as `u16`, followed by its UTF-8 bytes |
+| | | Values |
+
+Most datasets are tables of decimal numbers with a few digits, which are encoded without loss as scaled 16, or 32,
+bit integers, taking a quarter, or half, of the size of `f64` values. Decimal values with a large range, such as
+measured spectra with values from 10<sup>-7</sup> to 1, are encoded with a decimal scale for each value, as an `i32`
+followed by its scale as a `u8`. `Blob::to_bytes` uses the smallest encoding which reproduces all values exactly.
+
+# Compression
+
+With the `compress` feature, datasets are embedded as compressed blobs, to keep megabytes of constant data out of
+programs, such as WebAssembly modules, or embedded applications. These are decompressed, and parsed, at their first
+use, into a cache on the heap, which is shared by all threads. A compressed blob starts with the signature `SCOZ`,
+followed by the format version, 1, three reserved bytes, and the length of the blob, as `u32`, and then the blob,
+compressed as a raw deflate stream. Datasets are embedded with the `include_blob!` macro, which includes a blob file,
+such as `cie_fl3.bin`, or, with the `compress` feature, its compressed version, `cie_fl3.bin.z`.
+
+# Source tables
+
+Each blob is generated from a source table next to it, with the same name, and a `.tsv` extension, by the `blobs`
+example, which writes both the blob, and its compressed version:
+`cargo run --example blobs --features compress -- src/illuminants/cie_fl3.tsv`.
+
+All crates in this repository commit the same files for a dataset: its source table, and the two blobs which
+`include_blob!` embeds, the blob, for builds without the `compress` feature, and its compressed version, for builds
+with it. Each crate has a test, with the `compress` feature, which checks that the compressed blob decompresses to the
+blob.
+*/
+
+use std::convert::TryInto;
+use std::fmt;
+use std::sync::OnceLock;
+
+const SIGNATURE: &[u8; 4] = b"SCOT";
+const COMPRESSED: &[u8; 4] = b"SCOZ";
+const VERSION: u8 = 1;
+
+/// Error for invalid blob data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlobError {
+    pub message: String,
+}
+
+impl fmt::Display for BlobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "blob data: {}", self.message)
+    }
+}
+
+impl std::error::Error for BlobError {}
+
+fn err(message: impl Into<String>) -> BlobError {
+    BlobError {
+        message: message.into(),
+    }
+}
+
+/// A matrix of values, in column major order, with an optional key for each column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blob {
+    pub nrows: usize,
+    pub ncols: usize,
+    /// Column keys, empty if the columns have no keys.
+    pub keys: Vec<String>,
+    pub data: Vec<f64>,
+}
+
+impl Blob {
+    pub fn new(nrows: usize, ncols: usize, data: Vec<f64>) -> Self {
+        assert!(
+            nrows * ncols == data.len(),
+            "data length does not match the number of rows and columns"
+        );
+        Self {
+            nrows,
+            ncols,
+            keys: Vec::new(),
+            data,
+        }
+    }
+
+    /// Sets the keys, one for each column.
+    pub fn set_keys(mut self, keys: &[&str]) -> Self {
+        assert!(
+            keys.len() == self.ncols,
+            "number of keys does not match the number of columns"
+        );
+        self.keys = keys.iter().map(|k| k.to_string()).collect();
+        self
+    }
+
+    /// Reads a blob, or a compressed blob, which requires the `compress` feature.
+    pub fn parse(bytes: &[u8]) -> Result<Self, BlobError> {
+        let inflated;
+        let bytes = if bytes.starts_with(COMPRESSED) {
+            inflated = decompress(bytes)?;
+            inflated.as_slice()
+        } else {
+            bytes
+        };
+        let mut r = Reader { bytes, pos: 0 };
+        if r.take(4)? != SIGNATURE {
+            return Err(err("no SCOT signature"));
+        }
+        let [version, encoding, scale, _] = r.array::<4>()?;
+        if version != VERSION {
+            return Err(err(format!("unsupported version {}", version)));
+        }
+        let nrows = u32::from_le_bytes(r.array()?) as usize;
+        let ncols = u32::from_le_bytes(r.array()?) as usize;
+        let nkeys = u32::from_le_bytes(r.array()?) as usize;
+        if nkeys != 0 && nkeys != ncols {
+            return Err(err(format!("expected {} keys, found {}", ncols, nkeys)));
+        }
+        let mut keys = Vec::with_capacity(nkeys);
+        for _ in 0..nkeys {
+            let len = u16::from_le_bytes(r.array()?) as usize;
+            let key = std::str::from_utf8(r.take(len)?).map_err(|_| err("key is not UTF-8"))?;
+            keys.push(key.to_string());
+        }
+
+        let n = nrows * ncols;
+        let divisor = 10f64.powi(scale as i32);
+        let data: Vec<f64> = match encoding {
+            0 => r
+                .take(8 * n)?
+                .chunks_exact(8)
+                .map(|c| f64::from_le_bytes(c.try_into().unwrap()))
+                .collect(),
+            1 => r
+                .take(2 * n)?
+                .chunks_exact(2)
+                .map(|c| i16::from_le_bytes(c.try_into().unwrap()) as f64 / divisor)
+                .collect(),
+            2 => r
+                .take(4 * n)?
+                .chunks_exact(4)
+                .map(|c| i32::from_le_bytes(c.try_into().unwrap()) as f64 / divisor)
+                .collect(),
+            3 => r
+                .take(5 * n)?
+                .chunks_exact(5)
+                .map(|c| {
+                    i32::from_le_bytes(c[..4].try_into().unwrap()) as f64 / 10f64.powi(c[4] as i32)
+                })
+                .collect(),
+            _ => return Err(err(format!("unknown value encoding {}", encoding))),
+        };
+        if r.pos != bytes.len() {
+            return Err(err("unexpected data after values"));
+        }
+        Ok(Self {
+            nrows,
+            ncols,
+            keys,
+            data,
+        })
+    }
+
+    /// The blob as bytes, with its values in the smallest encoding which reproduces them exactly.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (encoding, scale) = self.encoding();
+        let mut b = SIGNATURE.to_vec();
+        b.extend([VERSION, encoding, scale, 0]);
+        b.extend((self.nrows as u32).to_le_bytes());
+        b.extend((self.ncols as u32).to_le_bytes());
+        b.extend((self.keys.len() as u32).to_le_bytes());
+        for k in &self.keys {
+            b.extend((k.len() as u16).to_le_bytes());
+            b.extend(k.as_bytes());
+        }
+        let multiplier = 10f64.powi(scale as i32);
+        for v in &self.data {
+            match encoding {
+                1 => b.extend(((v * multiplier).round() as i16).to_le_bytes()),
+                2 => b.extend(((v * multiplier).round() as i32).to_le_bytes()),
+                3 => {
+                    let (n, k) = decimal(*v).unwrap();
+                    b.extend(n.to_le_bytes());
+                    b.push(k);
+                }
+                _ => b.extend(v.to_le_bytes()),
+            }
+        }
+        b
+    }
+
+    /// The blob as compressed bytes.
+    #[cfg(feature = "compress")]
+    pub fn to_compressed_bytes(&self) -> Vec<u8> {
+        let blob = self.to_bytes();
+        let mut b = COMPRESSED.to_vec();
+        b.extend([VERSION, 0, 0, 0]);
+        b.extend((blob.len() as u32).to_le_bytes());
+        b.extend(miniz_oxide::deflate::compress_to_vec(&blob, 10));
+        b
+    }
+
+    /// Encoding, and decimal scale, of the values: scaled integers, if they are decimal numbers with at most 9
+    /// decimals, and in range, decimal values, if they all have a decimal representation, or `f64` otherwise.
+    fn encoding(&self) -> (u8, u8) {
+        for scale in 0..=9u8 {
+            let s = 10f64.powi(scale as i32);
+            let mut max = 0.0f64;
+            let exact = self.data.iter().all(|v| {
+                let n = (v * s).round();
+                max = max.max(n.abs());
+                n.abs() <= i32::MAX as f64 && n / s == *v
+            });
+            if exact {
+                return if max <= i16::MAX as f64 {
+                    (1, scale)
+                } else {
+                    (2, scale)
+                };
+            }
+        }
+        if self.data.iter().all(|v| decimal(*v).is_some()) {
+            (3, 0)
+        } else {
+            (0, 0)
+        }
+    }
+}
+
+/// Decimal representation of a value, as an integer, and the power of ten it is divided by, with the smallest power,
+/// up to 22, for which the value is reproduced exactly.
+fn decimal(v: f64) -> Option<(i32, u8)> {
+    (0..=22u8).find_map(|k| {
+        let s = 10f64.powi(k as i32);
+        let n = (v * s).round();
+        (n.abs() <= i32::MAX as f64 && n / s == v).then_some((n as i32, k))
+    })
+}
+
+/// The blob bytes of a compressed blob.
+#[cfg(feature = "compress")]
+fn decompress(bytes: &[u8]) -> Result<Vec<u8>, BlobError> {
+    let mut r = Reader { bytes, pos: 4 };
+    let [version, ..] = r.array::<4>()?;
+    if version != VERSION {
+        return Err(err(format!("unsupported version {}", version)));
+    }
+    let len = u32::from_le_bytes(r.array()?) as usize;
+    let blob = miniz_oxide::inflate::decompress_to_vec_with_limit(&bytes[r.pos..], len)
+        .map_err(|e| err(format!("decompression failed, {}", e)))?;
+    if blob.len() != len {
+        return Err(err(format!(
+            "expected {} decompressed bytes, found {}",
+            len,
+            blob.len()
+        )));
+    }
+    Ok(blob)
+}
+
+#[cfg(not(feature = "compress"))]
+fn decompress(_bytes: &[u8]) -> Result<Vec<u8>, BlobError> {
+    Err(err("compressed data requires the compress feature"))
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], BlobError> {
+        let b = self
+            .bytes
+            .get(self.pos..self.pos + n)
+            .ok_or_else(|| err("truncated data"))?;
+        self.pos += n;
+        Ok(b)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], BlobError> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+}
+
+/**
+An embedded blob, parsed at its first use.
+
+Embedded datasets are included with `include_blob!`, as in
+`static CIE_FL3_DATA: LazyBlob = LazyBlob::new(include_blob!("cie_fl3.bin"));`.
+
+# Examples
+```
+use scot::blob::LazyBlob;
+
+// a blob with two rows, and a column, of 16 bit integers with a decimal scale of 1
+static DATA: LazyBlob = LazyBlob::new(
+    b"SCOT\x01\x01\x01\x00\x02\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x05\x00\xff\xff",
+);
+let blob = DATA.get();
+assert_eq!((blob.nrows, blob.ncols), (2, 1));
+assert_eq!(DATA.data(), &[0.5, -0.1]);
+```
+*/
+#[derive(Debug)]
+pub struct LazyBlob {
+    bytes: &'static [u8],
+    blob: OnceLock<Blob>,
+}
+
+impl LazyBlob {
+    pub const fn new(bytes: &'static [u8]) -> Self {
+        Self {
+            bytes,
+            blob: OnceLock::new(),
+        }
+    }
+
+    /**
+    The blob, which is decompressed, and parsed, at the first call, and kept for later calls; panics for invalid data,
+    as embedded data is part of the program.
+    */
+    pub fn get(&self) -> &Blob {
+        self.blob.get_or_init(|| {
+            Blob::parse(self.bytes).unwrap_or_else(|e| panic!("invalid embedded dataset: {}", e))
+        })
+    }
+
+    /// The values, in column major order.
+    pub fn data(&self) -> &[f64] {
+        &self.get().data
+    }
+
+    /// The column keys, empty if the columns have no keys.
+    pub fn keys(&self) -> &[String] {
+        &self.get().keys
+    }
+}
+
+/**
+Includes a blob file, or, with the `compress` feature, its compressed version, with the same name, and a `.z`
+extension, as a byte slice, for a `LazyBlob`.
+
+Crates using this macro for their own datasets should forward the feature, with `compress = ["scot/compress"]`.
+*/
+#[macro_export]
+macro_rules! include_blob {
+    ($FILE:literal) => {{
+        #[cfg(feature = "compress")]
+        let bytes: &'static [u8] = include_bytes!(concat!($FILE, ".z"));
+        #[cfg(not(feature = "compress"))]
+        let bytes: &'static [u8] = include_bytes!($FILE);
+        bytes
+    }};
+}
+
+#[test]
+fn test_blob() {
+    // decimal data, with 4 digits, in 16 bit integers, and keys
+    let blob = Blob::new(3, 2, vec![0.0573, 0.1, 1.0, -3.2767, 0.0, 0.9999]).set_keys(&["a", "bé"]);
+    let bytes = blob.to_bytes();
+    assert_eq!(&bytes[..8], b"SCOT\x01\x01\x04\x00");
+    assert_eq!(bytes.len(), 20 + 3 + 5 + 6 * 2);
+    assert_eq!(Blob::parse(&bytes).unwrap(), blob);
+
+    // larger decimal numbers, in 32 bit integers
+    let blob = Blob::new(2, 1, vec![123.456, 98765.4]);
+    let bytes = blob.to_bytes();
+    assert_eq!(&bytes[4..8], [1, 2, 3, 0]);
+    assert_eq!(Blob::parse(&bytes).unwrap(), blob);
+
+    // decimal values with a large range, with a scale for each value
+    let blob = Blob::new(3, 1, vec![3.39988E-07, 0.5, 12.75]);
+    let bytes = blob.to_bytes();
+    assert_eq!(&bytes[4..8], [1, 3, 0, 0]);
+    assert_eq!(bytes.len(), 20 + 3 * 5);
+    assert_eq!(Blob::parse(&bytes).unwrap(), blob);
+
+    // values which are not short decimal numbers, as f64
+    let blob = Blob::new(1, 3, vec![1.0 / 3.0, std::f64::consts::PI, f64::NAN]);
+    let parsed = Blob::parse(&blob.to_bytes()).unwrap();
+    assert_eq!(parsed.data[..2], blob.data[..2]);
+    assert!(parsed.data[2].is_nan());
+
+    assert_eq!(
+        Blob::parse(b"SCOB").unwrap_err().to_string(),
+        "blob data: no SCOT signature"
+    );
+    let bytes = Blob::new(2, 2, vec![1.0; 4]).to_bytes();
+    assert_eq!(
+        Blob::parse(&bytes[..bytes.len() - 1]).unwrap_err(),
+        err("truncated data")
+    );
+
+    static LAZY: LazyBlob = LazyBlob::new(
+        b"SCOT\x01\x01\x01\x00\x02\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x05\x00\xff\xff",
+    );
+    assert_eq!(LAZY.data(), &[0.5, -0.1]);
+    assert!(LAZY.keys().is_empty());
+}
+
+#[cfg(feature = "compress")]
+#[test]
+fn test_compressed_blob() {
+    let blob = Blob::new(100, 2, (0..200).map(|i| (i % 10) as f64 / 8.0).collect());
+    let bytes = blob.to_compressed_bytes();
+    assert_eq!(&bytes[..4], b"SCOZ");
+    assert!(bytes.len() < blob.to_bytes().len() / 4);
+    assert_eq!(Blob::parse(&bytes).unwrap(), blob);
+    assert!(Blob::parse(&bytes[..bytes.len() - 4]).is_err());
+
+    // embedded datasets
+    assert_eq!(crate::illuminants::fluorescent_cie::CIE_FL3_DATA.data().len(), 81 * 15);
+}
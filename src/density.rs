@@ -0,0 +1,138 @@
+/*!
+Spectral densities on frequency, wavenumber, and photon energy domains.
+
+Spectral distributions in this library are defined on wavelength domains, but spectroscopic data is often given per
+unit of frequency, of wavenumber, or of photon energy. The values of a spectral density depend on the spectral variable
+used: the power in a 1nm wavelength interval at 400nm is contained in a frequency interval of 1.87THz, but the same
+1nm interval at 800nm covers only 0.47THz. A `SpectralDensity` is converted from one spectral variable to another
+with `convert`, which interpolates the values to the new domain, and multiplies them with the Jacobian of the mapping
+of the spectral variables, so that the integral of the density over a band is retained.
+
+# Examples
+The spectrum of a blackbody radiator, per unit of frequency, with its peak at 2.821kT/h, instead of at the
+frequency corresponding to the peak wavelength of its spectrum per unit of wavelength.
+```
+use nalgebra::DMatrix;
+use scot::{planck, Domain, DynamicSpectralDistribution, SpectralDensity, Unit, NM, THZ};
+
+let d = Domain::new(200, 20000, NM);
+let bb = DMatrix::from_iterator(d.len(), 1, d.iter().map(|l| planck(l.value(), 5000.0, 1.0)));
+let sd = DynamicSpectralDistribution::new(d, bb);
+let f = SpectralDensity::from_spectrum(&sd, Domain::new(15, 1400, THZ));
+let (i, _) = f.data().column(0).argmax();
+assert_eq!(15 + i, 294);
+```
+*/
+
+use nalgebra::DMatrix;
+
+use crate::{
+    Domain, DynamicSpectralDistribution, SpectralDistribution, SpectralStep, Unit, WavelengthStep,
+};
+
+/// A collection of spectral densities, as columns of a matrix, on a domain of a spectral variable, such as frequency.
+#[derive(Debug, Clone)]
+pub struct SpectralDensity<S: SpectralStep> {
+    domain: Domain<S>,
+    data: DMatrix<f64>,
+}
+
+impl<S: SpectralStep> SpectralDensity<S> {
+    pub fn new(domain: Domain<S>, data: DMatrix<f64>) -> Self {
+        assert_eq!(
+            domain.len(),
+            data.nrows(),
+            "domain and data lengths should be equal"
+        );
+        Self { domain, data }
+    }
+
+    /// Spectral densities of a spectral distribution, per unit of wavelength, converted to a domain of another
+    /// spectral variable.
+    pub fn from_spectrum<Sd>(sd: &Sd, domain: Domain<S>) -> Self
+    where
+        Sd: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let (d, s) = sd.spd();
+        let (n, m) = sd.shape();
+        let data = DMatrix::from_fn(n, m, |i, j| s[(i, j)]);
+        SpectralDensity::new(d, data).convert(domain)
+    }
+
+    pub fn domain(&self) -> &Domain<S> {
+        &self.domain
+    }
+
+    pub fn data(&self) -> &DMatrix<f64> {
+        &self.data
+    }
+
+    /**
+    Converts the spectral densities to a domain of another spectral variable, or to another domain of the same
+    variable.
+
+    The values are linearly interpolated in the original domain, and are zero outside of it.
+    */
+    pub fn convert<S2: SpectralStep>(&self, domain: Domain<S2>) -> SpectralDensity<S2> {
+        let m = self.data.ncols();
+        let x: Vec<(f64, f64)> = domain
+            .iter()
+            .map(|v| {
+                let wl = S2::wavelength(v.value());
+                let x = S::from_wavelength(wl);
+                (x, S2::jacobian(v.value()) / S::jacobian(x))
+            })
+            .collect();
+        let data = DMatrix::from_fn(x.len(), m, |i, j| {
+            let (x, f) = x[i];
+            self.value_at(x, j) * f
+        });
+        SpectralDensity { domain, data }
+    }
+
+    /// Converts the spectral densities to spectral distributions, per unit of wavelength, on a wavelength domain.
+    pub fn to_wavelength(&self, domain: Domain<WavelengthStep>) -> DynamicSpectralDistribution {
+        let SpectralDensity { domain, data } = self.convert(domain);
+        DynamicSpectralDistribution::new(domain, data)
+    }
+
+    fn value_at(&self, x: f64, j: usize) -> f64 {
+        let n = self.domain.len();
+        let t = x / self.domain.step.unitvalue(1).value() - self.domain.range.start as f64;
+        if !(t > -1E-9 && t < (n - 1) as f64 + 1E-9) {
+            return 0.0;
+        }
+        if n == 1 {
+            return self.data[(0, j)];
+        }
+        let i = (t.max(0.0).floor() as usize).min(n - 2);
+        let f = t - i as f64;
+        (1.0 - f) * self.data[(i, j)] + f * self.data[(i + 1, j)]
+    }
+}
+
+#[test]
+fn test_spectral_density() {
+    use crate::{planck, CEV, CM10, NM, THZ};
+    use approx::assert_abs_diff_eq;
+
+    // a blackbody spectrum retains its radiant exitance, and its peak moves to 2.821kT/h
+    let d = Domain::new(200, 20000, NM);
+    let bb = DMatrix::from_iterator(d.len(), 1, d.iter().map(|l| planck(l.value(), 5000.0, 1.0)));
+    let sd = DynamicSpectralDistribution::new(d, bb);
+    let total = sd.data().sum() * 1E-9;
+    let f = SpectralDensity::from_spectrum(&sd, Domain::new(15, 1400, THZ));
+    assert_abs_diff_eq!(f.data().sum() * 1E12, total, epsilon = 1E-3 * total);
+    let (i, _) = f.data().column(0).argmax();
+    assert_abs_diff_eq!((15 + i) as f64, 293.9, epsilon = 0.5);
+
+    let e = f.convert(Domain::new(10, 600, CEV));
+    assert_abs_diff_eq!(e.data().sum() * 1E-2, total, epsilon = 1E-3 * total);
+    let k = f.convert(Domain::new(5, 4900, CM10));
+    assert_abs_diff_eq!(k.data().sum() * 1E3, total, epsilon = 1E-3 * total);
+
+    // and is recovered in the visible part of the spectrum
+    let back = k.to_wavelength(Domain::new(380, 780, NM));
+    let orig = sd.to_dynamic().map_domain(Domain::new(380, 780, NM));
+    assert_abs_diff_eq!(back.data(), &orig, epsilon = 1E-3 * orig.max());
+}
@@ -77,6 +77,7 @@ use super::WavelengthStep;
 
 */
 #[derive(Clone, Debug, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Domain<S: Step> {
     pub range: Range<i32>,
     pub step: S,
@@ -89,6 +90,8 @@ where
 {
     /**
        Creates a new domain ranging from start to end, including end, and with a scale.
+
+       An end value less than the start value results in an empty domain; use `try_new` to get an error instead.
     */
     pub fn new(start: i32, end: i32, step: S) -> Self {
         Self {
@@ -100,6 +103,31 @@ where
         }
     }
 
+    /**
+       Creates a new domain ranging from start to end, including end, and with a scale, as `new`, but fails with a
+       `DomainError::EmptyRange` if end is less than start.
+
+       # Examples
+       ```
+       use scot::{Domain, DomainError, NM};
+
+       assert_eq!(Domain::try_new(380, 780, NM).unwrap().len(), 401);
+       assert!(matches!(Domain::try_new(780, 380, NM), Err(DomainError::EmptyRange)));
+       ```
+    */
+    pub fn try_new(start: i32, end: i32, step: S) -> Result<Self, DomainError> {
+        if end < start {
+            return Err(DomainError::EmptyRange);
+        }
+        let end = end
+            .checked_add(1)
+            .ok_or(DomainError::OutOfRange(end as f64))?;
+        Ok(Self {
+            range: Range { start, end },
+            step,
+        })
+    }
+
     pub fn len(&self) -> usize {
         self.range.len()
     }
@@ -134,6 +162,68 @@ where
             _phd: PhantomData,
         }
     }
+
+    /// Physical values of the first and last points, and of the step, in base units.
+    fn bounds(&self) -> (f64, f64, f64) {
+        (
+            self.step.unitvalue(self.range.start).value(),
+            self.step.unitvalue(self.range.end - 1).value(),
+            self.step.unitvalue(1).value(),
+        )
+    }
+
+    /**
+       Checks if two domains have the same physical start, end, and step values, in the same unit, even if they are
+       expressed with different step sizes and indices. Empty domains are equivalent only to other empty domains.
+
+       # Examples
+       ```
+       use scot::{Domain, WavelengthStep, NM, NM10};
+
+       let a10 = WavelengthStep { size: 100, exp: -10 }; // 100 Angstrom
+       assert!(Domain::new(38, 78, NM10).is_equivalent(&Domain::new(38, 78, a10)));
+       assert!(!Domain::new(38, 78, NM10).is_equivalent(&Domain::new(380, 780, NM)));
+       ```
+    */
+    pub fn is_equivalent<S2: Step>(&self, other: &Domain<S2>) -> bool {
+        if self.is_empty() || other.is_empty() {
+            return self.is_empty() && other.is_empty();
+        }
+        let (a0, a1, da) = self.bounds();
+        let (b0, b1, db) = other.bounds();
+        let tol = 1E-9 * da.abs().min(db.abs());
+        S::UnitValueType::NAME == S2::UnitValueType::NAME
+            && (a0 - b0).abs() < tol
+            && (a1 - b1).abs() < tol
+            && (da - db).abs() < tol
+    }
+
+    /**
+       Checks if the physical range of this domain includes the range of another domain, in the same unit, so that
+       data on this domain can be interpolated onto the other domain without extrapolation, irrespective of their step
+       sizes.
+
+       # Examples
+       ```
+       use scot::{Domain, NM, NM5};
+
+       let cie1931 = Domain::new(360 / 5, 830 / 5, NM5);
+       assert!(cie1931.covers(&Domain::new(380, 780, NM)));
+       assert!(!Domain::new(380, 780, NM).covers(&cie1931));
+       ```
+    */
+    pub fn covers<S2: Step>(&self, other: &Domain<S2>) -> bool {
+        if other.is_empty() {
+            return true;
+        }
+        if self.is_empty() || S::UnitValueType::NAME != S2::UnitValueType::NAME {
+            return false;
+        }
+        let (a0, a1, da) = self.bounds();
+        let (b0, b1, db) = other.bounds();
+        let tol = 1E-9 * da.abs().min(db.abs());
+        a0 <= b0 + tol && a1 >= b1 - tol
+    }
 }
 
 pub struct IterInterpolate<S: Step, S2: Step> {
@@ -196,6 +286,228 @@ impl Default for Domain<WavelengthStep> {
 
 pub const DOMAIN_DEFAULT_LEN: usize = 401;
 
+impl Domain<WavelengthStep> {
+    /**
+       250 to 400 nm ultraviolet wavelength scale, with 1 nm steps, covering the UV-A and UV-B bands, and the
+       range of the erythemal action spectrum.
+    */
+    pub fn ultraviolet() -> Self {
+        Domain::new(250, 400, WavelengthStep { size: 1, exp: -9 })
+    }
+
+    /**
+       Wavelength domain from a start, end, and step value, in nanometer.
+
+       The step value has to be positive, and representable as an integer multiple of a power of ten, such as 1, 2.5,
+       5, or 10nm, and the start and end values have to be multiples of the step value, with the end value not less
+       than the start value.
+
+       # Examples
+       ```
+       use scot::{Domain, NM5};
+
+       let d = Domain::from_nm(380.0, 780.0, 5.0).unwrap();
+       assert_eq!(d, Domain::new(380 / 5, 780 / 5, NM5));
+       assert!(Domain::from_nm(382.0, 780.0, 5.0).is_err());
+       ```
+    */
+    pub fn from_nm(start: f64, end: f64, step: f64) -> Result<Self, DomainError> {
+        Self::from_scaled(start, end, step, -9)
+    }
+
+    /// Wavelength domain from a start, end, and step value, in Angstrom, validated as in `from_nm`.
+    pub fn from_angstrom(start: f64, end: f64, step: f64) -> Result<Self, DomainError> {
+        Self::from_scaled(start, end, step, -10)
+    }
+
+    /// Wavelength domain from a start, end, and step value, in micrometer, validated as in `from_nm`.
+    pub fn from_um(start: f64, end: f64, step: f64) -> Result<Self, DomainError> {
+        Self::from_scaled(start, end, step, -6)
+    }
+
+    /// Domain for values in a unit of 10<sup>unit_exp</sup> meter.
+    fn from_scaled(start: f64, end: f64, step: f64, unit_exp: i32) -> Result<Self, DomainError> {
+        let on_grid = |v: f64| v.is_finite() && (v - v.round()).abs() < 1E-6;
+        if !(step.is_finite() && step > 0.0) {
+            return Err(DomainError::InvalidStep(step));
+        }
+        let (size, exp) = (-6..=6)
+            .rev()
+            .map(|exp| (step / 10f64.powi(exp), exp))
+            .find(|&(size, _)| size >= 0.5 && on_grid(size) && size < u32::MAX as f64)
+            .ok_or(DomainError::InvalidStep(step))?;
+        let div = size.round() * 10f64.powi(exp);
+        let (i, j) = (start / div, end / div);
+        if !on_grid(i) {
+            return Err(DomainError::OffGrid(start));
+        }
+        if !on_grid(j) {
+            return Err(DomainError::OffGrid(end));
+        }
+        if j < i {
+            return Err(DomainError::EmptyRange);
+        }
+        let step = WavelengthStep {
+            size: size.round() as u32,
+            exp: exp + unit_exp,
+        };
+        Ok(Domain::new(i.round() as i32, j.round() as i32, step))
+    }
+}
+
+/**
+   A domain with an explicit, irregular, set of wavelength values, as reported by some instruments.
+
+   Spectral data on an irregular domain can be brought onto a regular `Domain`, using `lin_interp_irregular_mat_col`,
+   or `DynamicSpectralDistribution::from_irregular`, to use it with the rest of this library.
+
+   # Examples
+   ```
+   use scot::IrregularDomain;
+
+   let d = IrregularDomain::from_nm(vec![380.3, 383.7, 387.1, 390.4]).unwrap();
+   let regular = d.to_domain(1.0).unwrap();
+   assert_eq!(regular.len(), 10); // 381 to 390nm
+   ```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct IrregularDomain {
+    wavelengths: Vec<f64>,
+}
+
+impl IrregularDomain {
+    /// Irregular domain from strictly increasing wavelength values, in meter.
+    pub fn new(wavelengths: Vec<f64>) -> Result<Self, DomainError> {
+        if wavelengths.is_empty() {
+            return Err(DomainError::EmptyRange);
+        }
+        if let Some(&v) = wavelengths.iter().find(|v| !v.is_finite()) {
+            return Err(DomainError::OffGrid(v));
+        }
+        if let Some(w) = wavelengths.windows(2).find(|w| w[1] <= w[0]) {
+            return Err(DomainError::NotIncreasing(w[1]));
+        }
+        Ok(Self { wavelengths })
+    }
+
+    /// Irregular domain from strictly increasing wavelength values, in nanometer.
+    pub fn from_nm(wavelengths: Vec<f64>) -> Result<Self, DomainError> {
+        Self::new(wavelengths.into_iter().map(|v| v * 1E-9).collect())
+    }
+
+    pub fn len(&self) -> usize {
+        self.wavelengths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.wavelengths.is_empty()
+    }
+
+    /// Wavelength values, in meter.
+    pub fn wavelengths(&self) -> &[f64] {
+        &self.wavelengths
+    }
+
+    /**
+       The largest regular wavelength domain, with a step size in nanometer, within the range of this domain, which
+       can be used as target domain for interpolation, without extrapolation.
+    */
+    pub fn to_domain(&self, step: f64) -> Result<Domain<WavelengthStep>, DomainError> {
+        let first = self.wavelengths[0] * 1E9;
+        let last = self.wavelengths[self.len() - 1] * 1E9;
+        let start = ((first - 1E-6) / step).ceil() * step;
+        let end = ((last + 1E-6) / step).floor() * step;
+        Domain::from_nm(start, end, step)
+    }
+}
+
+/// Error returned when a domain can not be constructed from its parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DomainError {
+    /// The step value is not positive, or can not be represented as an integer multiple of a power of ten.
+    InvalidStep(f64),
+    /// A start, or end, value is not a multiple of the step value.
+    OffGrid(f64),
+    /// The end value is less than the start value, or a domain has no values.
+    EmptyRange,
+    /// A value in an irregular domain is not larger than its predecessor.
+    NotIncreasing(f64),
+    /// A value is outside of the range of values supported by a domain.
+    OutOfRange(f64),
+}
+
+impl std::fmt::Display for DomainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainError::InvalidStep(v) => write!(f, "invalid domain step value {}", v),
+            DomainError::OffGrid(v) => {
+                write!(f, "domain value {} is not a multiple of its step", v)
+            }
+            DomainError::EmptyRange => write!(f, "domain end value is less than its start value"),
+            DomainError::NotIncreasing(v) => {
+                write!(f, "domain value {} is not larger than its predecessor", v)
+            }
+            DomainError::OutOfRange(v) => write!(f, "domain value {} is out of range", v),
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
+
+/**
+   Formats a domain with its start and end values, its step, and its number of points, such as "380–780 nm, 1 nm, 401
+   pts". Wavelengths are shown in nanometer, and other quantities in their unit, with an SI prefix matching the step
+   size.
+
+   # Examples
+   ```
+   use scot::{Domain, NM5, THZ};
+
+   assert_eq!(Domain::new(360 / 5, 830 / 5, NM5).to_string(), "360–830 nm, 5 nm, 95 pts");
+   assert_eq!(Domain::new(15, 1400, THZ).to_string(), "15–1400 THz, 1 THz, 1386 pts");
+   ```
+*/
+impl<S: Step> std::fmt::Display for Domain<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "empty {} domain", S::NAME.to_lowercase());
+        }
+        let (start, end, step) = self.bounds();
+        let (scale, unit) = display_unit(S::UnitValueType::SYMBOL, step);
+        let v = |x: f64| (x * scale * 1E6).round() / 1E6;
+        let pts = if self.len() == 1 { "pt" } else { "pts" };
+        write!(
+            f,
+            "{}–{}{}, {}{}, {} {}",
+            v(start),
+            v(end),
+            unit,
+            v(step),
+            unit,
+            self.len(),
+            pts
+        )
+    }
+}
+
+/// Scale factor, and unit symbol with a leading space, used to display domain values.
+fn display_unit(symbol: &str, step: f64) -> (f64, String) {
+    const PREFIXES: [&str; 9] = ["p", "n", "µ", "m", "", "k", "M", "G", "T"];
+    match symbol {
+        "m" => (1E9, " nm".to_string()),
+        "-" => (1.0, String::new()),
+        _ => {
+            let exp = ((step.log10() + 1E-9).floor() as i32)
+                .div_euclid(3)
+                .clamp(-4, 4);
+            (
+                10f64.powi(-3 * exp),
+                format!(" {}{}", PREFIXES[(exp + 4) as usize], symbol),
+            )
+        }
+    }
+}
+
 impl<S1: Step, S2: Step> PartialEq<Domain<S2>> for Domain<S1> {
     fn eq(&self, other: &Domain<S2>) -> bool {
         /*
@@ -345,3 +657,126 @@ fn test_iter_interpolate() {
         println!("{:?}", val)
     }
 }
+
+#[test]
+fn test_from_nm() {
+    use crate::{A, NM, NM10, NM5, UM};
+
+    assert_eq!(
+        Domain::from_nm(380.0, 780.0, 1.0).unwrap(),
+        Domain::new(380, 780, NM)
+    );
+    assert_eq!(
+        Domain::from_nm(360.0, 830.0, 5.0).unwrap(),
+        Domain::new(72, 166, NM5)
+    );
+    assert_eq!(
+        Domain::from_nm(380.0, 730.0, 10.0).unwrap(),
+        Domain::new(38, 73, NM10)
+    );
+    let d = Domain::from_nm(380.0, 385.0, 2.5).unwrap();
+    assert_eq!(d.len(), 3);
+    assert_eq!(d.step, WavelengthStep { size: 25, exp: -10 });
+    assert_eq!(
+        Domain::from_angstrom(4530.0, 4550.0, 1.0).unwrap(),
+        Domain::new(4530, 4550, A)
+    );
+    assert_eq!(
+        Domain::from_um(1.0, 2.0, 1.0).unwrap(),
+        Domain::new(1, 2, UM)
+    );
+
+    assert_eq!(
+        Domain::from_nm(380.0, 780.0, 0.0),
+        Err(DomainError::InvalidStep(0.0))
+    );
+    assert_eq!(
+        Domain::from_nm(380.0, 780.0, 1.0 / 3.0),
+        Err(DomainError::InvalidStep(1.0 / 3.0))
+    );
+    assert_eq!(
+        Domain::from_nm(382.0, 780.0, 5.0),
+        Err(DomainError::OffGrid(382.0))
+    );
+    assert_eq!(
+        Domain::from_nm(780.0, 380.0, 5.0),
+        Err(DomainError::EmptyRange)
+    );
+}
+
+#[test]
+fn test_irregular_domain() {
+    use crate::NM;
+
+    let d = IrregularDomain::from_nm(vec![379.6, 381.2, 383.1, 384.9]).unwrap();
+    assert_eq!(d.len(), 4);
+    assert_eq!(d.to_domain(1.0).unwrap(), Domain::new(380, 384, NM));
+    assert_eq!(d.to_domain(5.0).unwrap().len(), 1);
+    let d2 = IrregularDomain::from_nm(vec![381.2, 384.9]).unwrap();
+    assert_eq!(d2.to_domain(5.0), Err(DomainError::EmptyRange));
+    assert!(matches!(
+        IrregularDomain::from_nm(vec![380.0, 382.0, 381.0]),
+        Err(DomainError::NotIncreasing(_))
+    ));
+}
+
+#[test]
+fn test_equivalent_covers() {
+    use crate::{KK, NM, NM10, NM5};
+
+    let nm5 = Domain::new(360 / 5, 830 / 5, NM5);
+    let nm = Domain::new(360, 830, NM);
+    assert!(nm5.is_equivalent(&Domain::from_nm(360.0, 830.0, 5.0).unwrap()));
+    assert!(!nm5.is_equivalent(&nm));
+    assert!(nm5.covers(&nm) && nm.covers(&nm5));
+    assert!(nm.covers(&Domain::new(38, 78, NM10)));
+    assert!(!Domain::new(38, 78, NM10).covers(&nm));
+
+    // different units are not equivalent, and do not cover each other
+    let t = Domain::new(0, 1, KK);
+    assert!(!nm.is_equivalent(&t));
+    assert!(!t.covers(&Domain::new(0, 1, NM)));
+
+    let empty = Domain::new(1, 0, NM);
+    assert!(empty.is_equivalent(&Domain::new(5, 3, NM10)));
+    assert!(nm.covers(&empty) && !empty.covers(&nm));
+}
+
+#[test]
+fn test_try_new() {
+    use crate::{KK, NM};
+
+    let d = Domain::try_new(38, 78, crate::NM10).unwrap();
+    assert_eq!(d, Domain::new(38, 78, crate::NM10));
+    assert_eq!(Domain::try_new(3, 3, KK).unwrap().len(), 1);
+    assert!(matches!(
+        Domain::try_new(1, 0, NM),
+        Err(DomainError::EmptyRange)
+    ));
+    assert!(matches!(
+        Domain::try_new(0, i32::MAX, NM),
+        Err(DomainError::OutOfRange(_))
+    ));
+}
+
+#[test]
+fn test_display() {
+    use crate::{A, KK, NM, NM10, PCT};
+
+    assert_eq!(
+        Domain::new(380, 780, NM).to_string(),
+        "380–780 nm, 1 nm, 401 pts"
+    );
+    assert_eq!(
+        Domain::new(38, 78, NM10).to_string(),
+        "380–780 nm, 10 nm, 41 pts"
+    );
+    assert_eq!(
+        Domain::new(4530, 4550, A).to_string(),
+        "453–455 nm, 0.1 nm, 21 pts"
+    );
+    assert_eq!(Domain::new(3, 7, KK).to_string(), "3–7 kK, 1 kK, 5 pts");
+    assert_eq!(Domain::new(0, 100, PCT).to_string(), "0–1, 0.01, 101 pts");
+    assert_eq!(Domain::new(5, 5, NM10).to_string(), "50–50 nm, 10 nm, 1 pt");
+    assert_eq!(Domain::new(1, 0, NM).to_string(), "empty wavelength domain");
+}
@@ -0,0 +1,434 @@
+/*!
+An owned spectral distribution type, for runtime data, and for arithmetic on spectral distributions.
+
+A `DynamicSpectralDistribution` holds a collection of spectral distributions on a wavelength domain, with optional
+keys, and a description, and its `SpectralQuantity`. It is the standard carrier for spectral data loaded, or measured,
+at runtime, and can be used as a spectral distribution, an illuminant, and a collection of swatches, wherever the
+library expects one of these. As a type parameter for an illuminant, such as in `CieLab<DynamicSpectralDistribution,
+CieObs1931>`, its default value, the equal energy illuminant E, is used as reference white.
+
+# Arithmetic
+
+Spectral distributions from different sources can be combined with the `+`, `-`, and `*` operators, after converting
+the left operand into a `DynamicSpectralDistribution`, using `SpectralDistribution::to_dynamic`:
+
+- the sum, or difference, of two spectral distributions, such as a mix of two lamps;
+- the component-wise product of two spectral distributions, such as a lamp spectrum, and the transmission spectrum
+  of a filter;
+- the product of a spectral distribution, and a scale factor.
+
+The results are defined on the domain of the left operand: the right operand is linearly interpolated to this domain,
+with values of zero outside its own domain. A right operand with a single spectral distribution is combined with each
+of the spectral distributions of the left operand; otherwise the number of spectral distributions of both operands
+has to be the same.
+
+# Examples
+A 3:1 mix of daylight, and incandescent light, both normalized to the same luminance.
+```
+use scot::illuminants::{CieIllA, CieIllD65};
+use scot::models::CieYxy;
+use scot::observers::CieObs1931;
+use scot::SpectralDistribution;
+
+let mix = CieIllD65.normalize_luminance(100.0).to_dynamic() * 3.0 + CieIllA.normalize_luminance(100.0);
+let yxy: CieYxy<CieObs1931> = mix.xyz().into();
+println!("{}", yxy);
+```
+*/
+
+use std::ops::{Add, Mul, Sub};
+
+use nalgebra::DMatrix;
+
+use crate::illuminants::{Illuminant, IlluminantData};
+use crate::models::{CieLab, CieXYZ};
+use crate::observers::StandardObserver;
+use crate::swatches::Swatch;
+use crate::{
+    lin_interp_irregular_mat_col, Domain, DomainError, IrregularDomain, Meter,
+    SpectralDistribution, SpectralQuantity, Step, WavelengthStep,
+};
+
+/**
+An owned collection of spectral distributions, on a wavelength domain, with optional keys, and description.
+
+Each column in its data matrix holds a spectral distribution. Its spectral values are relative, unless set otherwise
+with `set_quantity`.
+
+# Examples
+Measured reflectance spectra, and their CIELAB values, for illuminant D65.
+```
+use nalgebra::DMatrix;
+use scot::illuminants::CieIllD65;
+use scot::models::CieLab;
+use scot::observers::CieObs1931;
+use scot::{Domain, DynamicSpectralDistribution, SpectralDistribution, NM10};
+
+let d = Domain::new(38, 73, NM10);
+let m = DMatrix::from_fn(d.len(), 2, |i, j| if j == 0 { 0.5 } else { 0.2 + 0.02 * i as f64 });
+let samples = DynamicSpectralDistribution::new(d, m).set_keys(&["gray", "orange"]);
+let lab: CieLab<CieIllD65, CieObs1931> = samples.into();
+assert_eq!(lab.data.ncols(), 2);
+```
+*/
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynamicSpectralDistribution {
+    domain: Domain<WavelengthStep>,
+    data: DMatrix<f64>,
+    keys: Option<Vec<String>>,
+    description: Option<String>,
+    quantity: SpectralQuantity,
+}
+
+impl DynamicSpectralDistribution {
+    pub fn new(domain: Domain<WavelengthStep>, data: DMatrix<f64>) -> Self {
+        assert!(
+            domain.len() == data.nrows(),
+            "data length does not match domain"
+        );
+        Self {
+            domain,
+            data,
+            keys: None,
+            description: None,
+            quantity: SpectralQuantity::Relative,
+        }
+    }
+
+    /// Copies the spectral distributions, keys, description, and quantity, of a source.
+    pub fn from_spd<S>(sd: &S) -> Self
+    where
+        S: SpectralDistribution<StepType = WavelengthStep> + ?Sized,
+    {
+        let (d, s) = sd.spd();
+        let (n, m) = sd.shape();
+        Self::with_attributes_of(d, DMatrix::from_fn(n, m, |i, j| s[(i, j)]), sd)
+    }
+
+    /// New spectral distributions, derived from a source, with its keys, description, and quantity.
+    pub(crate) fn with_attributes_of<S>(
+        domain: Domain<WavelengthStep>,
+        data: DMatrix<f64>,
+        sd: &S,
+    ) -> Self
+    where
+        S: SpectralDistribution + ?Sized,
+    {
+        assert!(
+            domain.len() == data.nrows(),
+            "data length does not match domain"
+        );
+        Self {
+            domain,
+            data,
+            keys: sd.keys(),
+            description: sd.description(),
+            quantity: sd.quantity(),
+        }
+    }
+
+    /**
+    Spectral distributions measured on an irregular wavelength domain, linearly interpolated to a regular domain,
+    covering the range of the irregular domain, with a step size in nanometer.
+
+    # Examples
+    ```
+    use nalgebra::DMatrix;
+    use scot::{DynamicSpectralDistribution, IrregularDomain, SpectralDistribution};
+
+    let d = IrregularDomain::from_nm(vec![379.6, 385.3, 391.1, 396.8]).unwrap();
+    let sd = DynamicSpectralDistribution::from_irregular(&d, DMatrix::from_element(4, 1, 0.5), 5.0).unwrap();
+    assert_eq!(sd.shape(), (4, 1)); // 380 to 395nm
+    ```
+    */
+    pub fn from_irregular(
+        domain: &IrregularDomain,
+        data: DMatrix<f64>,
+        step: f64,
+    ) -> Result<Self, DomainError> {
+        assert!(
+            domain.len() == data.nrows(),
+            "data length does not match domain"
+        );
+        let to = domain.to_domain(step)?;
+        let nc = data.ncols();
+        let data = lin_interp_irregular_mat_col(domain, &to, nc, data);
+        Ok(Self::new(to, data))
+    }
+
+    /// Sets the keys, one for each of the spectral distributions in the collection.
+    pub fn set_keys(mut self, keys: &[&str]) -> Self {
+        assert!(
+            keys.len() == self.data.ncols(),
+            "number of keys does not match the number of spectra"
+        );
+        self.keys = Some(keys.iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    pub fn set_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn set_quantity(mut self, quantity: SpectralQuantity) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    pub fn domain(&self) -> &Domain<WavelengthStep> {
+        &self.domain
+    }
+
+    pub fn data(&self) -> &DMatrix<f64> {
+        &self.data
+    }
+
+    pub fn data_mut(&mut self) -> &mut DMatrix<f64> {
+        &mut self.data
+    }
+
+    /// Data of a right operand, interpolated to the domain, and broadcast to the shape, of this distribution.
+    fn operand<S>(&self, rhs: &S) -> DMatrix<f64>
+    where
+        S: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let r = rhs.map_domain(self.domain.clone());
+        let m = self.data.ncols();
+        match r.ncols() {
+            k if k == m => r,
+            1 => DMatrix::from_fn(r.nrows(), m, |i, _| r[(i, 0)]),
+            k => panic!(
+                "number of spectral distributions does not match: {} and {}",
+                m, k
+            ),
+        }
+    }
+}
+
+impl SpectralDistribution for DynamicSpectralDistribution {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        (self.domain.clone(), self.data.clone())
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.data.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        self.keys.clone()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    fn quantity(&self) -> SpectralQuantity {
+        self.quantity
+    }
+}
+
+impl std::fmt::Display for DynamicSpectralDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.summary())
+    }
+}
+
+impl Default for DynamicSpectralDistribution {
+    fn default() -> Self {
+        let domain = Domain::default();
+        let data = DMatrix::from_element(domain.len(), 1, 1.0);
+        Self::new(domain, data).set_description("Equal Energy Illuminant")
+    }
+}
+
+impl Illuminant for DynamicSpectralDistribution {}
+
+impl Swatch for DynamicSpectralDistribution {}
+
+impl From<IlluminantData> for DynamicSpectralDistribution {
+    fn from(ill: IlluminantData) -> Self {
+        Self::from_spd(&ill)
+    }
+}
+
+/// Tristimulus values, normalized to a luminance of 100 for relative data, and in the photometric unit of the
+/// spectral quantity for absolute data.
+impl<C: StandardObserver> From<DynamicSpectralDistribution> for CieXYZ<C> {
+    fn from(sd: DynamicSpectralDistribution) -> Self {
+        if sd.quantity.is_absolute() {
+            sd.xyz()
+        } else {
+            sd.xyz().normalize(100.0)
+        }
+    }
+}
+
+impl<I, C> From<DynamicSpectralDistribution> for CieLab<I, C>
+where
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+    I: Illuminant,
+    C: StandardObserver,
+{
+    fn from(sd: DynamicSpectralDistribution) -> Self {
+        sd.lab()
+    }
+}
+
+impl<S> Add<S> for DynamicSpectralDistribution
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: S) -> Self {
+        self.data += self.operand(&rhs);
+        self
+    }
+}
+
+impl<S> Sub<S> for DynamicSpectralDistribution
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: S) -> Self {
+        self.data -= self.operand(&rhs);
+        self
+    }
+}
+
+/// Component-wise product.
+impl<S> Mul<S> for DynamicSpectralDistribution
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    type Output = Self;
+
+    fn mul(mut self, rhs: S) -> Self {
+        let r = self.operand(&rhs);
+        self.data.component_mul_assign(&r);
+        self
+    }
+}
+
+impl Mul<f64> for DynamicSpectralDistribution {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f64) -> Self {
+        self.data *= rhs;
+        self
+    }
+}
+
+impl Mul<DynamicSpectralDistribution> for f64 {
+    type Output = DynamicSpectralDistribution;
+
+    fn mul(self, rhs: DynamicSpectralDistribution) -> DynamicSpectralDistribution {
+        rhs * self
+    }
+}
+
+#[test]
+fn test_arithmetic() {
+    use crate::illuminants::{CieIllA, CieIllD65, FL};
+    use crate::NM5;
+    use approx::assert_abs_diff_eq;
+
+    // the domain of the left operand is used, with D65 from 300 to 830nm, in 5nm steps
+    let a = CieIllA.to_dynamic();
+    let d65 = CieIllD65.to_dynamic();
+    let sum = a.clone() + CieIllD65;
+    assert_eq!(sum.domain(), a.domain());
+    let (_, d65_a) = CieIllA.spd();
+    let i = 260; // 560nm
+    assert_abs_diff_eq!(sum.data()[(i, 0)], d65_a[(i, 0)] + 100.0, epsilon = 1E-3);
+    let diff = sum - CieIllD65;
+    assert_abs_diff_eq!(diff.data(), a.data(), epsilon = 1E-10);
+
+    let scaled = 2.0 * d65.clone() * 0.5;
+    assert_abs_diff_eq!(scaled.data(), d65.data(), epsilon = 1E-12);
+
+    // a single spectrum operand is broadcast, and the component-wise product of D65 with itself is its square
+    let f = FL::<1>.to_dynamic() + FL::<2>;
+    assert_eq!(f.shape().1, 1);
+    let sq = d65.clone() * CieIllD65;
+    assert_abs_diff_eq!(sq.data()[(52, 0)], 100.0 * 100.0, epsilon = 1E-6);
+    assert_eq!(sq.domain().step, NM5);
+}
+
+#[test]
+fn test_dynamic() {
+    use crate::illuminants::{CieIllD65, IlluminantData};
+    use crate::models::CieYxy;
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let d65 = CieIllD65.to_dynamic().set_keys(&["D65"]);
+    assert_eq!(d65.keys().unwrap(), vec!["D65".to_string()]);
+    assert_eq!(d65.quantity(), SpectralQuantity::Relative);
+    let yxy: CieYxy<CieObs1931> = d65.clone().into();
+    let yxy_ref: CieYxy<CieObs1931> = CieIllD65.into();
+    assert_abs_diff_eq!(yxy.data, yxy_ref.data, epsilon = 1E-10);
+
+    let e: CieYxy<CieObs1931> = DynamicSpectralDistribution::default().into();
+    assert_abs_diff_eq!(e.data[(1, 0)], 1.0 / 3.0, epsilon = 1E-4);
+
+    // absolute data is not normalized: 1 mW/m2/nm of equal energy light is 106.86 * 0.683 lux
+    let ee =
+        DynamicSpectralDistribution::default().set_quantity(SpectralQuantity::Irradiance) * 0.001;
+    let xyz: CieXYZ<CieObs1931> = ee.into();
+    assert_abs_diff_eq!(xyz.data[(1, 0)], 72.98, epsilon = 0.05);
+
+    // a perfect white reflector, under its own reference illuminant
+    let white = IlluminantData::default();
+    let lab: CieLab<CieIllD65, CieObs1931> = DynamicSpectralDistribution::from(white)
+        .set_description("perfect white")
+        .into();
+    assert_abs_diff_eq!(lab.data[(0, 0)], 100.0, epsilon = 1E-6);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use crate::illuminants::{CctDuv, CieIllA, CieIllD65};
+    use crate::models::{CieCamUcs, VcAvg};
+    use crate::observers::CieObs1931;
+
+    let d65 = CieIllD65
+        .to_dynamic()
+        .set_keys(&["D65"])
+        .set_quantity(SpectralQuantity::Irradiance);
+    let json = serde_json::to_string(&d65).unwrap();
+    let sd: DynamicSpectralDistribution = serde_json::from_str(&json).unwrap();
+    assert_eq!(sd.domain(), d65.domain());
+    assert_eq!(sd.data(), d65.data());
+    assert_eq!(sd.keys(), d65.keys());
+    assert_eq!(sd.quantity(), SpectralQuantity::Irradiance);
+
+    let xyz: CieXYZ<CieObs1931> = d65.clone().into();
+    let json = serde_json::to_string(&xyz).unwrap();
+    let xyz2: CieXYZ<CieObs1931> = serde_json::from_str(&json).unwrap();
+    assert_eq!(xyz2.data, xyz.data);
+    assert_eq!(xyz2.keys(), xyz.keys());
+
+    let lab: CieLab<CieIllD65, CieObs1931> = CieIllA.to_dynamic().into();
+    let lab2: CieLab<CieIllD65, CieObs1931> =
+        serde_json::from_str(&serde_json::to_string(&lab).unwrap()).unwrap();
+    assert_eq!(lab2.data, lab.data);
+
+    let ucs: CieCamUcs<VcAvg, CieIllD65, CieObs1931> = CieCamUcs::new(lab.data.clone());
+    let ucs2: CieCamUcs<VcAvg, CieIllD65, CieObs1931> =
+        serde_json::from_str(&serde_json::to_string(&ucs).unwrap()).unwrap();
+    assert_eq!(ucs2.data, ucs.data);
+
+    let cct = CctDuv::<CieObs1931>::new(vec![[2856.0, 0.0], [6504.0, 0.003]]);
+    let cct2: CctDuv<CieObs1931> =
+        serde_json::from_str(&serde_json::to_string(&cct).unwrap()).unwrap();
+    assert_eq!(cct2, cct);
+}
@@ -0,0 +1,150 @@
+use nalgebra::DMatrix;
+
+use crate::illuminants::Illuminant;
+use crate::models::CieXYZ;
+use crate::observers::StandardObserver;
+use crate::{lin_interp_mat_col, Domain, SpectralDistribution, SpectralQuantity, WavelengthStep};
+
+/**
+Spectral distributions, multiplied by the transmission spectra of a filter.
+
+The transmission spectra are linearly interpolated to the domain of the source, and are taken to be zero outside of
+their own domain. If the filter has more than one transmission spectrum, these are treated as a stack of filters, and
+are all applied. Filters can also be stacked by filtering a `Filtered` distribution again.
+
+The tristimulus values of a filtered distribution are not normalized, so that the attenuation by the filters is kept.
+
+# Examples
+A neutral density filter, with a transmission of 50%, and a window, transmitting 90% in the visible part of the
+spectrum, but blocking ultraviolet.
+```
+use scot::illuminants::CieIllD65;
+use scot::swatches::DataSwatch;
+use scot::{Domain, SpectralDistribution, NM};
+
+let nd = DataSwatch::new(Domain::new(300, 830, NM), vec![0.5; 531]);
+let window = DataSwatch::new(Domain::new(380, 830, NM), vec![0.9; 451]);
+let filtered = CieIllD65.filtered(nd).filtered(window);
+let (_, v) = filtered.spd();
+assert_eq!(v[(0, 0)], 0.0); // 300nm
+```
+*/
+#[derive(Debug, Clone)]
+pub struct Filtered<S, F> {
+    source: S,
+    filter: F,
+}
+
+impl<S, F> Filtered<S, F> {
+    pub fn new(source: S, filter: F) -> Self {
+        Self { source, filter }
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    pub fn filter(&self) -> &F {
+        &self.filter
+    }
+}
+
+impl<S: Default, F: Default> Default for Filtered<S, F> {
+    fn default() -> Self {
+        Self::new(S::default(), F::default())
+    }
+}
+
+impl<S, F> SpectralDistribution for Filtered<S, F>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+    F: SpectralDistribution<StepType = WavelengthStep>,
+{
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let (d, s) = self.source.spd();
+        let (n, m) = self.source.shape();
+        let (df, f) = self.filter.spd();
+        let t = lin_interp_mat_col(&df, &d, self.filter.shape().1, f);
+        let t: Vec<f64> = t.row_iter().map(|r| r.iter().product()).collect();
+        (d, DMatrix::from_fn(n, m, |i, j| s[(i, j)] * t[i]))
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.source.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        self.source.keys()
+    }
+
+    fn description(&self) -> Option<String> {
+        match (self.source.description(), self.filter.description()) {
+            (Some(s), Some(f)) => Some(format!("{}, filtered by {}", s, f)),
+            (s, _) => s,
+        }
+    }
+
+    fn quantity(&self) -> SpectralQuantity {
+        self.source.quantity()
+    }
+}
+
+impl<S, F> Illuminant for Filtered<S, F>
+where
+    S: Illuminant<StepType = WavelengthStep>,
+    F: SpectralDistribution<StepType = WavelengthStep> + Default,
+{
+}
+
+impl<S, F, C> From<Filtered<S, F>> for CieXYZ<C>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+    F: SpectralDistribution<StepType = WavelengthStep>,
+    C: StandardObserver,
+{
+    fn from(f: Filtered<S, F>) -> Self {
+        f.xyz()
+    }
+}
+
+#[test]
+fn test_filtered() {
+    use crate::illuminants::CieIllD65;
+    use crate::models::CieYxy;
+    use crate::observers::CieObs1931;
+    use crate::swatches::DataSwatch;
+    use crate::NM5;
+    use approx::assert_abs_diff_eq;
+
+    let d = Domain::new(300 / 5, 830 / 5, NM5);
+    let nd = DataSwatch::new(d.clone(), vec![0.5; 107]);
+    let (_, v) = CieIllD65.spd();
+    let (_, vf) = CieIllD65.filtered(nd).spd();
+    assert_abs_diff_eq!(vf[(52, 0)], v[(52, 0)] * 0.5, epsilon = 1E-12);
+
+    // a stack, as a filter with two transmission spectra
+    let stack =
+        crate::illuminants::IlluminantData::new(d, DMatrix::from_fn(107, 2, |_, j| [0.5, 0.4][j]));
+    let filtered = CieIllD65.filtered(stack);
+    let (_, vf) = filtered.spd();
+    assert_abs_diff_eq!(vf[(52, 0)], v[(52, 0)] * 0.2, epsilon = 1E-12);
+
+    // neutral filters do not change chromaticity, but do attenuate
+    let xyz: CieXYZ<CieObs1931> = filtered.into();
+    let xyz_ref: CieXYZ<CieObs1931> = CieIllD65.xyz();
+    assert_abs_diff_eq!(xyz.data, xyz_ref.data * 0.2, epsilon = 1E-9);
+    let yxy: CieYxy<CieObs1931> = CieXYZ::<CieObs1931>::from(CieIllD65.filtered(DataSwatch::new(
+        Domain::new(380 / 5, 780 / 5, NM5),
+        vec![0.3; 81],
+    )))
+    .into();
+    let yxy_ref: CieYxy<CieObs1931> = CieIllD65.into();
+    assert_abs_diff_eq!(
+        yxy.data.column(0).y,
+        yxy_ref.data.column(0).y,
+        epsilon = 1E-6
+    );
+}
@@ -0,0 +1,262 @@
+/*!
+A library of common photographic and lighting filters, as transmission spectra.
+
+The color conversion gels in this library are models, not measurements of a particular manufacturer's product:
+
+- the color temperature orange (CTO) filters convert a 6500K Planckian radiator to a lower color temperature,
+  with shifts of +159, +80, and +40 mired for the full, half, and quarter strength filters;
+- the color temperature blue (CTB) filters convert a 3200K Planckian radiator to a higher color temperature, with
+  shifts of -137, -68, and -34 mired;
+- the minus-green filters have a Gaussian absorption band, centered at 535nm, with a full width at half maximum of
+  90nm, and a peak optical density of 0.3, 0.15, and 0.075.
+
+Their transmission spectra are tabulated from 380 to 780nm, in steps of 5nm, and scaled to a maximum transmission of
+1.0. Neutral density filters, and ideal long-pass, short-pass, and band-pass filters, are defined by their
+parameters.
+The filters implement the `Swatch` trait too, to get the color of a filter, as seen in transmission.
+
+# Examples
+```
+use scot::filters::{CtoFull, NeutralDensity};
+use scot::illuminants::CieIllD65;
+use scot::SpectralDistribution;
+
+let warm = CieIllD65.filtered(CtoFull::default()).filtered(NeutralDensity(0.6));
+println!("{:?}", warm.description());
+```
+*/
+
+use nalgebra::DVector;
+
+use crate::swatches::Swatch;
+use crate::{Domain, SpectralDistribution, Unit, WavelengthStep, NM, NM5};
+
+const N: usize = 81;
+const M: usize = 3;
+
+swatch!(
+    Cto,
+    N,
+    M,
+    "CTO Filter {}",
+    Domain::new(380 / 5, 780 / 5, NM5),
+    CTO_DATA
+);
+/// Full color temperature orange filter, converting 6500K to 3200K.
+pub type CtoFull = Cto<1>;
+/// Half color temperature orange filter.
+pub type CtoHalf = Cto<2>;
+/// Quarter color temperature orange filter.
+pub type CtoQuarter = Cto<3>;
+
+swatch!(
+    Ctb,
+    N,
+    M,
+    "CTB Filter {}",
+    Domain::new(380 / 5, 780 / 5, NM5),
+    CTB_DATA
+);
+/// Full color temperature blue filter, converting 3200K to about 5700K.
+pub type CtbFull = Ctb<1>;
+/// Half color temperature blue filter.
+pub type CtbHalf = Ctb<2>;
+/// Quarter color temperature blue filter.
+pub type CtbQuarter = Ctb<3>;
+
+swatch!(
+    MinusGreen,
+    N,
+    M,
+    "Minus Green Filter {}",
+    Domain::new(380 / 5, 780 / 5, NM5),
+    MINUS_GREEN_DATA
+);
+/// Full minus-green, or magenta, filter, to correct the green tint of fluorescent lamps.
+pub type MinusGreenFull = MinusGreen<1>;
+/// Half minus-green filter.
+pub type MinusGreenHalf = MinusGreen<2>;
+/// Quarter minus-green filter.
+pub type MinusGreenQuarter = MinusGreen<3>;
+
+/// Transmission spectra of the parametric filters, from 300 to 830nm, in steps of 1nm.
+fn transmission(f: impl Fn(f64) -> f64) -> (Domain<WavelengthStep>, DVector<f64>) {
+    let d = Domain::new(300, 830, NM);
+    let v = DVector::from_iterator(d.len(), d.iter().map(|l| f(l.value() * 1E9)));
+    (d, v)
+}
+
+macro_rules! parametric_filter {
+    ($FILTER:ident, $DESC:literal, |$s:ident, $l:ident| $T:expr) => {
+        impl SpectralDistribution for $FILTER {
+            type MatrixType = DVector<f64>;
+            type StepType = WavelengthStep;
+
+            fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+                let $s = self;
+                transmission(|$l| $T)
+            }
+
+            fn shape(&self) -> (usize, usize) {
+                (531, 1)
+            }
+
+            fn description(&self) -> Option<String> {
+                let $s = self;
+                Some(format!($DESC, $s))
+            }
+        }
+
+        impl Swatch for $FILTER {}
+    };
+}
+
+/// Neutral density filter, with its optical density as parameter: an optical density of 0.3 transmits about 50%,
+/// and 1.0 transmits 10%. Its default value, with an optical density of 0.0, is a clear filter.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NeutralDensity(pub f64);
+
+parametric_filter!(NeutralDensity, "{:?}", |s, _l| 10f64.powf(-s.0));
+
+/// Ideal long-pass filter, transmitting all wavelengths above its cut-off wavelength, in nanometer.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LongPass(pub f64);
+
+parametric_filter!(LongPass, "{:?}", |s, l| if l >= s.0 { 1.0 } else { 0.0 });
+
+/// Ideal short-pass filter, transmitting all wavelengths below its cut-off wavelength, in nanometer.
+#[derive(Debug, Clone, Copy)]
+pub struct ShortPass(pub f64);
+
+impl Default for ShortPass {
+    fn default() -> Self {
+        Self(f64::INFINITY)
+    }
+}
+
+parametric_filter!(ShortPass, "{:?}", |s, l| if l <= s.0 { 1.0 } else { 0.0 });
+
+/// Ideal band-pass filter, transmitting the wavelengths from its lower to its upper cut-off wavelength, in
+/// nanometer.
+#[derive(Debug, Clone, Copy)]
+pub struct BandPass(pub f64, pub f64);
+
+impl Default for BandPass {
+    fn default() -> Self {
+        Self(0.0, f64::INFINITY)
+    }
+}
+
+parametric_filter!(BandPass, "{:?}", |s, l| if l >= s.0 && l <= s.1 {
+    1.0
+} else {
+    0.0
+});
+
+static CTO_DATA: [f64; N * 3] = [
+    0.0482, 0.0521, 0.0562, 0.0605, 0.0650, 0.0698, 0.0747, 0.0799, 0.0853, 0.0909, 0.0967, 0.1028,
+    0.1091, 0.1156, 0.1223, 0.1293, 0.1365, 0.1440, 0.1516, 0.1595, 0.1677, 0.1760, 0.1846, 0.1934,
+    0.2025, 0.2117, 0.2212, 0.2310, 0.2409, 0.2511, 0.2614, 0.2720, 0.2829, 0.2939, 0.3051, 0.3166,
+    0.3282, 0.3401, 0.3521, 0.3644, 0.3768, 0.3895, 0.4023, 0.4153, 0.4285, 0.4419, 0.4555, 0.4692,
+    0.4831, 0.4972, 0.5114, 0.5258, 0.5404, 0.5551, 0.5700, 0.5850, 0.6002, 0.6155, 0.6309, 0.6465,
+    0.6623, 0.6781, 0.6941, 0.7102, 0.7264, 0.7428, 0.7592, 0.7758, 0.7925, 0.8093, 0.8261, 0.8431,
+    0.8602, 0.8774, 0.8947, 0.9120, 0.9295, 0.9470, 0.9646, 0.9823, 1.0000, 0.2211, 0.2299, 0.2388,
+    0.2478, 0.2570, 0.2662, 0.2755, 0.2849, 0.2944, 0.3039, 0.3136, 0.3233, 0.3330, 0.3428, 0.3527,
+    0.3626, 0.3726, 0.3826, 0.3927, 0.4028, 0.4129, 0.4231, 0.4332, 0.4435, 0.4537, 0.4639, 0.4742,
+    0.4845, 0.4947, 0.5050, 0.5153, 0.5256, 0.5359, 0.5462, 0.5565, 0.5668, 0.5771, 0.5873, 0.5976,
+    0.6078, 0.6180, 0.6282, 0.6384, 0.6486, 0.6587, 0.6688, 0.6789, 0.6890, 0.6990, 0.7090, 0.7190,
+    0.7289, 0.7388, 0.7487, 0.7585, 0.7683, 0.7781, 0.7878, 0.7975, 0.8072, 0.8168, 0.8264, 0.8359,
+    0.8454, 0.8548, 0.8643, 0.8736, 0.8829, 0.8922, 0.9014, 0.9106, 0.9198, 0.9289, 0.9379, 0.9469,
+    0.9559, 0.9648, 0.9737, 0.9825, 0.9913, 1.0000, 0.4738, 0.4831, 0.4924, 0.5015, 0.5107, 0.5197,
+    0.5287, 0.5376, 0.5464, 0.5552, 0.5639, 0.5725, 0.5810, 0.5895, 0.5978, 0.6061, 0.6144, 0.6225,
+    0.6306, 0.6386, 0.6465, 0.6543, 0.6621, 0.6698, 0.6774, 0.6849, 0.6924, 0.6998, 0.7071, 0.7144,
+    0.7215, 0.7286, 0.7357, 0.7426, 0.7495, 0.7563, 0.7631, 0.7697, 0.7763, 0.7829, 0.7893, 0.7958,
+    0.8021, 0.8084, 0.8146, 0.8207, 0.8268, 0.8328, 0.8388, 0.8446, 0.8505, 0.8562, 0.8620, 0.8676,
+    0.8732, 0.8787, 0.8842, 0.8896, 0.8950, 0.9003, 0.9055, 0.9107, 0.9159, 0.9210, 0.9260, 0.9310,
+    0.9359, 0.9408, 0.9457, 0.9504, 0.9552, 0.9599, 0.9645, 0.9691, 0.9737, 0.9782, 0.9826, 0.9870,
+    0.9914, 0.9957, 1.0000,
+];
+
+static CTB_DATA: [f64; N * 3] = [
+    1.0000, 0.9350, 0.8757, 0.8215, 0.7719, 0.7265, 0.6847, 0.6463, 0.6109, 0.5781, 0.5479, 0.5199,
+    0.4939, 0.4697, 0.4473, 0.4264, 0.4068, 0.3886, 0.3716, 0.3556, 0.3407, 0.3266, 0.3135, 0.3011,
+    0.2894, 0.2784, 0.2680, 0.2583, 0.2490, 0.2403, 0.2320, 0.2242, 0.2167, 0.2097, 0.2030, 0.1966,
+    0.1905, 0.1848, 0.1793, 0.1741, 0.1691, 0.1643, 0.1597, 0.1554, 0.1512, 0.1473, 0.1435, 0.1398,
+    0.1363, 0.1330, 0.1297, 0.1267, 0.1237, 0.1208, 0.1181, 0.1155, 0.1129, 0.1105, 0.1081, 0.1059,
+    0.1037, 0.1016, 0.0995, 0.0976, 0.0957, 0.0938, 0.0921, 0.0904, 0.0887, 0.0871, 0.0856, 0.0841,
+    0.0826, 0.0812, 0.0798, 0.0785, 0.0772, 0.0760, 0.0748, 0.0736, 0.0725, 1.0000, 0.9671, 0.9361,
+    0.9069, 0.8793, 0.8531, 0.8284, 0.8049, 0.7826, 0.7615, 0.7414, 0.7223, 0.7041, 0.6867, 0.6702,
+    0.6544, 0.6393, 0.6248, 0.6110, 0.5978, 0.5851, 0.5730, 0.5613, 0.5502, 0.5394, 0.5291, 0.5192,
+    0.5096, 0.5004, 0.4916, 0.4830, 0.4748, 0.4669, 0.4592, 0.4518, 0.4447, 0.4377, 0.4311, 0.4246,
+    0.4183, 0.4123, 0.4064, 0.4007, 0.3952, 0.3899, 0.3847, 0.3797, 0.3748, 0.3701, 0.3655, 0.3610,
+    0.3567, 0.3525, 0.3483, 0.3444, 0.3405, 0.3367, 0.3330, 0.3294, 0.3259, 0.3225, 0.3192, 0.3159,
+    0.3128, 0.3097, 0.3067, 0.3038, 0.3009, 0.2981, 0.2954, 0.2927, 0.2901, 0.2876, 0.2851, 0.2827,
+    0.2803, 0.2779, 0.2757, 0.2734, 0.2713, 0.2691, 1.0000, 0.9834, 0.9675, 0.9523, 0.9377, 0.9236,
+    0.9101, 0.8971, 0.8846, 0.8726, 0.8610, 0.8498, 0.8390, 0.8286, 0.8186, 0.8089, 0.7995, 0.7904,
+    0.7816, 0.7731, 0.7649, 0.7569, 0.7491, 0.7416, 0.7343, 0.7273, 0.7204, 0.7137, 0.7073, 0.7010,
+    0.6949, 0.6889, 0.6831, 0.6775, 0.6720, 0.6666, 0.6614, 0.6564, 0.6514, 0.6466, 0.6419, 0.6373,
+    0.6328, 0.6284, 0.6242, 0.6200, 0.6159, 0.6119, 0.6081, 0.6043, 0.6005, 0.5969, 0.5933, 0.5899,
+    0.5865, 0.5831, 0.5799, 0.5767, 0.5735, 0.5705, 0.5675, 0.5645, 0.5617, 0.5588, 0.5561, 0.5533,
+    0.5507, 0.5481, 0.5455, 0.5430, 0.5405, 0.5381, 0.5357, 0.5334, 0.5311, 0.5288, 0.5266, 0.5245,
+    0.5223, 0.5202, 0.5182,
+];
+
+static MINUS_GREEN_DATA: [f64; N * 3] = [
+    0.9998, 0.9997, 0.9995, 0.9992, 0.9987, 0.9979, 0.9967, 0.9950, 0.9926, 0.9891, 0.9843, 0.9777,
+    0.9690, 0.9577, 0.9434, 0.9257, 0.9042, 0.8789, 0.8499, 0.8175, 0.7825, 0.7456, 0.7079, 0.6707,
+    0.6350, 0.6019, 0.5725, 0.5475, 0.5275, 0.5130, 0.5041, 0.5012, 0.5041, 0.5130, 0.5275, 0.5475,
+    0.5725, 0.6019, 0.6350, 0.6707, 0.7079, 0.7456, 0.7825, 0.8175, 0.8499, 0.8789, 0.9042, 0.9257,
+    0.9434, 0.9577, 0.9690, 0.9777, 0.9843, 0.9891, 0.9926, 0.9950, 0.9967, 0.9979, 0.9987, 0.9992,
+    0.9995, 0.9997, 0.9998, 0.9999, 0.9999, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000,
+    1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 0.9999, 0.9998, 0.9997,
+    0.9996, 0.9993, 0.9989, 0.9984, 0.9975, 0.9963, 0.9945, 0.9921, 0.9888, 0.9844, 0.9786, 0.9713,
+    0.9621, 0.9509, 0.9375, 0.9219, 0.9042, 0.8846, 0.8635, 0.8414, 0.8189, 0.7968, 0.7758, 0.7566,
+    0.7399, 0.7263, 0.7162, 0.7100, 0.7079, 0.7100, 0.7162, 0.7263, 0.7399, 0.7566, 0.7758, 0.7968,
+    0.8189, 0.8414, 0.8635, 0.8846, 0.9042, 0.9219, 0.9375, 0.9509, 0.9621, 0.9713, 0.9786, 0.9844,
+    0.9888, 0.9921, 0.9945, 0.9963, 0.9975, 0.9984, 0.9989, 0.9993, 0.9996, 0.9997, 0.9998, 0.9999,
+    0.9999, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000,
+    1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 0.9999, 0.9999, 0.9998, 0.9997, 0.9995,
+    0.9992, 0.9988, 0.9981, 0.9973, 0.9960, 0.9944, 0.9922, 0.9893, 0.9855, 0.9809, 0.9751, 0.9682,
+    0.9602, 0.9509, 0.9405, 0.9292, 0.9173, 0.9050, 0.8927, 0.8808, 0.8699, 0.8602, 0.8522, 0.8463,
+    0.8426, 0.8414, 0.8426, 0.8463, 0.8522, 0.8602, 0.8699, 0.8808, 0.8927, 0.9050, 0.9173, 0.9292,
+    0.9405, 0.9509, 0.9602, 0.9682, 0.9751, 0.9809, 0.9855, 0.9893, 0.9922, 0.9944, 0.9960, 0.9973,
+    0.9981, 0.9988, 0.9992, 0.9995, 0.9997, 0.9998, 0.9999, 0.9999, 1.0000, 1.0000, 1.0000, 1.0000,
+    1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000,
+    1.0000, 1.0000, 1.0000,
+];
+
+#[test]
+fn test_filter_library() {
+    use crate::illuminants::{CctDuvCalc, CieIllD65, Robertson};
+    use crate::models::CieXYZ;
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let r: Robertson<CieObs1931> = Robertson::new();
+    let cct = |xyz: CieXYZ<CieObs1931>| r.cct_duv(xyz).into_iter().next().unwrap().t;
+
+    // CTO filters lower, and CTB filters raise, the color temperature of daylight
+    let d65 = cct(CieIllD65.filtered(NeutralDensity::default()).into());
+    let cto_full = cct(CieIllD65.filtered(CtoFull::default()).into());
+    let cto_quarter = cct(CieIllD65.filtered(CtoQuarter::default()).into());
+    let ctb = cct(CieIllD65.filtered(CtbHalf::default()).into());
+    assert!(cto_full < cto_quarter && cto_quarter < d65 && d65 < ctb);
+    assert_abs_diff_eq!(cto_full, 3200.0, epsilon = 200.0);
+
+    // neutral density scales luminance by 10^-D
+    let xyz0: CieXYZ<CieObs1931> = CieIllD65.filtered(NeutralDensity(0.0)).into();
+    let xyz1: CieXYZ<CieObs1931> = CieIllD65.filtered(NeutralDensity(1.0)).into();
+    assert_abs_diff_eq!(xyz1.data[(1, 0)], xyz0.data[(1, 0)] / 10.0, epsilon = 1E-6);
+
+    // an ideal long pass blocks all light below its cut-off
+    let (d, v) = CieIllD65.filtered(LongPass(500.0)).spd();
+    for (l, x) in d.iter().zip(v.iter()) {
+        if l.value() < 499E-9 {
+            assert_eq!(*x, 0.0);
+        }
+    }
+    let (_, v) = CieIllD65.filtered(BandPass(500.0, 600.0)).spd();
+    assert!(v.iter().any(|&x| x > 0.0));
+    let (_, v) = MinusGreenFull::default().spd();
+    assert_abs_diff_eq!(v.min(), 10f64.powf(-0.3), epsilon = 0.01);
+}
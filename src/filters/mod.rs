@@ -0,0 +1,12 @@
+/*!
+Transmission filters, such as color correction gels, neutral density filters, and windows, and their effect on
+spectral distributions.
+*/
+
+pub mod filtered;
+pub use self::filtered::*;
+
+#[cfg(feature = "filter_library")]
+pub mod library;
+#[cfg(feature = "filter_library")]
+pub use self::library::*;
@@ -0,0 +1,279 @@
+/*!
+Photobiological safety weighting functions of IEC 62471, and the erythemal action spectrum.
+
+IEC 62471, "Photobiological safety of lamps and lamp systems", evaluates the retinal hazards of visible, and near
+infrared, radiation with two weighting functions:
+
+- the blue-light hazard function B(&lambda;), for photochemical damage of the retina, defined from 300 to 700nm, with
+  a peak at 435 to 440nm, and
+- the burn hazard function R(&lambda;), for thermal damage of the retina, defined from 380 to 1400nm, equal to ten
+  times the blue-light hazard function from 380 to 500nm, and to 1.0 from 500 to 700nm.
+
+The weighted radiance, L<sub>B</sub>, or irradiance, E<sub>B</sub>, of a source is obtained by integrating the product of
+its spectral radiance, or irradiance, and the hazard function, and is compared with the exposure limits of the
+standard. For the blue-light hazard the weighted radiance determines the risk group of a source, as given by
+`BlueLightRiskGroup`; the exposure limits for the thermal hazard depend on the angular subtense of the source, and
+are not included here.
+
+For screening of spectra, irrespective of their absolute level, IEC TR 62778 uses the blue-light hazard efficacy of
+luminous radiation K<sub>B,v</sub>, the ratio of the blue-light weighted radiance, and the luminance, of a source, in
+W/lm, which is about 0.3 mW/lm for an incandescent lamp, and 0.5 to 1.0 mW/lm for white LEDs.
+
+The erythemal action spectrum of ISO 17166/CIE S 007, for the reddening of the skin by ultraviolet radiation, is
+defined from 250 to 400nm, and decreases by more than three orders of magnitude from the UV-B to the UV-A range.
+The erythemal weighted irradiance of sunlight, in W/m<sup>2</sup>, multiplied by 40 m<sup>2</sup>/W, is its UV index,
+and an erythemal dose is expressed in standard erythemal doses, or SED, of 100 J/m<sup>2</sup>.
+Its use requires spectral data in the ultraviolet, such as `Domain::ultraviolet`, or measured solar spectra:
+the weighted quantities are calculated over the domain of the spectral data only.
+*/
+
+use nalgebra::RowDVector;
+
+use crate::observers::{photopic_luminous, StandardObserver};
+use crate::photometry::{AbsoluteSpd, Radiometric, SpectralIrradiance, SpectralRadiance};
+use crate::{Domain, Meter, SpectralDistribution, Step, Unit, WavelengthStep};
+
+/// Photobiological hazards, with a spectral weighting function in IEC 62471.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhotobiologicalHazard {
+    /// Retinal photochemical, or blue-light, hazard, weighted with B(&lambda;).
+    BlueLight,
+    /// Retinal thermal hazard, weighted with R(&lambda;).
+    RetinalThermal,
+    /// Ultraviolet erythema, or sunburn, weighted with the erythemal action spectrum.
+    Erythemal,
+}
+
+impl PhotobiologicalHazard {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PhotobiologicalHazard::BlueLight => "blue-light hazard",
+            PhotobiologicalHazard::RetinalThermal => "retinal thermal hazard",
+            PhotobiologicalHazard::Erythemal => "erythemal",
+        }
+    }
+
+    /// Weighting function values, on a target domain, as a row vector, with values of zero outside the range of the
+    /// function.
+    pub fn values<L>(&self, target: &Domain<L>) -> RowDVector<f64>
+    where
+        L: Step,
+        Meter: From<<L>::UnitValueType>,
+    {
+        RowDVector::from_iterator(
+            target.len(),
+            target.iter().map(|l| {
+                // rounded, to include the end points of the functions
+                let l = (Meter::from(l).value() * 1E15).round() / 1E6;
+                match self {
+                    PhotobiologicalHazard::BlueLight => blue_light(l),
+                    PhotobiologicalHazard::RetinalThermal => retinal_thermal(l),
+                    PhotobiologicalHazard::Erythemal => erythemal(l),
+                }
+            }),
+        )
+    }
+
+    /// Hazard weighted quantities of a collection of absolutely scaled spectral distributions, in the unit
+    /// `R::INTEGRATED_SYMBOL`: the weighted radiance, in W·sr<sup>-1</sup>·m<sup>-2</sup>, for spectral radiance
+    /// data, and the weighted irradiance, in W·m<sup>-2</sup>, for spectral irradiance data.
+    pub fn weighted<S, R>(&self, spd: &AbsoluteSpd<S, R>) -> RowDVector<f64>
+    where
+        S: SpectralDistribution<StepType = WavelengthStep>,
+        R: Radiometric,
+    {
+        self.integrate(&spd.to_radiant())
+    }
+
+    /// Hazard efficacy of luminous radiation, in W/lm, for a collection of spectral distributions, using the
+    /// y&#772;(&lambda;) function of observer `C` as photopic luminous efficiency function. As a ratio it does not
+    /// depend on the scale of the spectral data.
+    pub fn efficacy<C, S>(&self, sd: &S) -> RowDVector<f64>
+    where
+        C: StandardObserver,
+        S: SpectralDistribution,
+        Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+    {
+        self.integrate(sd)
+            .component_div(&photopic_luminous::<C, S>(sd))
+    }
+
+    fn integrate<S>(&self, sd: &S) -> RowDVector<f64>
+    where
+        S: SpectralDistribution,
+        Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+    {
+        let (d, s) = sd.spd();
+        let (n, m) = sd.shape();
+        let v = self.values(&d);
+        let dl = Meter::from(d.step.unitvalue(1)).value();
+        RowDVector::from_fn(m, |_, j| (0..n).map(|i| v[i] * s[(i, j)]).sum::<f64>() * dl)
+    }
+}
+
+/// UV indices of a collection of spectral irradiance distributions.
+pub fn uv_index<S>(spd: &AbsoluteSpd<S, SpectralIrradiance>) -> RowDVector<f64>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    PhotobiologicalHazard::Erythemal.weighted(spd) * 40.0
+}
+
+/// Erythemal doses, in standard erythemal doses (SED) of 100 J/m<sup>2</sup>, for an exposure time in seconds, of a
+/// collection of spectral irradiance distributions.
+pub fn erythemal_dose<S>(spd: &AbsoluteSpd<S, SpectralIrradiance>, seconds: f64) -> RowDVector<f64>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    PhotobiologicalHazard::Erythemal.weighted(spd) * seconds / 100.0
+}
+
+/// Blue-light hazard risk groups of IEC 62471, for continuous exposure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BlueLightRiskGroup {
+    /// No photobiological hazard, for exposures up to 10 000s.
+    Exempt,
+    /// Low risk, for exposures up to 100s.
+    RiskGroup1,
+    /// Moderate risk, for exposures up to 0.25s, the aversion response time.
+    RiskGroup2,
+    /// High risk, even for momentary exposure.
+    RiskGroup3,
+}
+
+impl BlueLightRiskGroup {
+    /// Risk group for a blue-light weighted radiance, in W·sr<sup>-1</sup>·m<sup>-2</sup>.
+    pub fn from_radiance(l_b: f64) -> Self {
+        if l_b <= 100.0 {
+            BlueLightRiskGroup::Exempt
+        } else if l_b <= 10_000.0 {
+            BlueLightRiskGroup::RiskGroup1
+        } else if l_b <= 4_000_000.0 {
+            BlueLightRiskGroup::RiskGroup2
+        } else {
+            BlueLightRiskGroup::RiskGroup3
+        }
+    }
+
+    /// Risk groups for a collection of spectral radiance distributions.
+    pub fn new<S>(spd: &AbsoluteSpd<S, SpectralRadiance>) -> Vec<Self>
+    where
+        S: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        PhotobiologicalHazard::BlueLight
+            .weighted(spd)
+            .iter()
+            .map(|&l| Self::from_radiance(l))
+            .collect()
+    }
+}
+
+/// Blue-light hazard function B(&lambda;) values, from 380 to 500nm, in steps of 5nm.
+static B_380_500: [f64; 25] = [
+    0.01, 0.013, 0.025, 0.05, 0.1, 0.2, 0.4, 0.8, 0.9, 0.95, 0.98, 1.0, 1.0, 0.97, 0.94, 0.9, 0.8,
+    0.7, 0.62, 0.55, 0.45, 0.4, 0.22, 0.16, 0.1,
+];
+
+fn tabulated(l: f64) -> f64 {
+    let x = (l - 380.0) / 5.0;
+    let i = (x.floor() as usize).min(B_380_500.len() - 2);
+    let f = x - i as f64;
+    B_380_500[i] * (1.0 - f) + B_380_500[i + 1] * f
+}
+
+/// Blue-light hazard function B(&lambda;), for a wavelength in nanometer.
+fn blue_light(l: f64) -> f64 {
+    match l {
+        l if !(300.0..=700.0).contains(&l) => 0.0,
+        l if l < 380.0 => 0.01,
+        l if l < 500.0 => tabulated(l),
+        l if l <= 600.0 => 10f64.powf((450.0 - l) / 50.0),
+        _ => 0.001,
+    }
+}
+
+/// Burn hazard function R(&lambda;), for a wavelength in nanometer.
+fn retinal_thermal(l: f64) -> f64 {
+    match l {
+        l if !(380.0..=1400.0).contains(&l) => 0.0,
+        l if l < 500.0 => 10.0 * tabulated(l),
+        l if l <= 700.0 => 1.0,
+        l if l <= 1050.0 => 10f64.powf((700.0 - l) / 500.0),
+        l if l <= 1150.0 => 0.2,
+        l if l <= 1200.0 => 0.2 * 10f64.powf(0.02 * (1150.0 - l)),
+        _ => 0.02,
+    }
+}
+
+/// Erythemal action spectrum, for a wavelength in nanometer.
+fn erythemal(l: f64) -> f64 {
+    match l {
+        l if !(250.0..=400.0).contains(&l) => 0.0,
+        l if l <= 298.0 => 1.0,
+        l if l <= 328.0 => 10f64.powf(0.094 * (298.0 - l)),
+        l => 10f64.powf(0.015 * (140.0 - l)),
+    }
+}
+
+#[test]
+fn test_hazard() {
+    use crate::illuminants::{CieIllA, CieIllD65};
+    use crate::observers::CieObs1931;
+    use crate::{DataSpectrumFromSlice, NM};
+    use approx::assert_abs_diff_eq;
+
+    let b = PhotobiologicalHazard::BlueLight.values(&Domain::new(300, 700, NM));
+    assert_abs_diff_eq!(b[140], 1.0); // 440nm
+    assert_abs_diff_eq!(b[200], 0.1, epsilon = 1E-12); // 500nm, continuous with the exponential part
+    assert_abs_diff_eq!(b[300], 0.001, epsilon = 1E-12); // 600nm
+    let r = PhotobiologicalHazard::RetinalThermal.values(&Domain::new(380, 1400, NM));
+    assert_abs_diff_eq!(r[60], 10.0); // 440nm
+    assert_abs_diff_eq!(r[670], 0.2, epsilon = 1E-3); // 1050nm
+
+    // blue-light hazard efficacy, in mW/lm, is higher for daylight than for incandescent light
+    let a = PhotobiologicalHazard::BlueLight.efficacy::<CieObs1931, _>(&CieIllA)[0] * 1000.0;
+    let d65 = PhotobiologicalHazard::BlueLight.efficacy::<CieObs1931, _>(&CieIllD65)[0] * 1000.0;
+    assert!(a > 0.25 && a < 0.35, "{}", a);
+    assert!(d65 > 2.0 * a, "{} {}", a, d65);
+
+    // 1 W/sr/m2/nm at 440nm only
+    let mut l = [0.0; 401];
+    l[140] = 1.0;
+    let spd = AbsoluteSpd::<_, SpectralRadiance>::new(DataSpectrumFromSlice::new(
+        Domain::new(300, 700, NM),
+        &l,
+    ));
+    assert_abs_diff_eq!(
+        PhotobiologicalHazard::BlueLight.weighted(&spd)[0],
+        1.0,
+        epsilon = 1E-9
+    );
+    assert_eq!(
+        BlueLightRiskGroup::new(&spd),
+        vec![BlueLightRiskGroup::Exempt]
+    );
+    assert_eq!(
+        BlueLightRiskGroup::from_radiance(2E4),
+        BlueLightRiskGroup::RiskGroup2
+    );
+}
+
+#[test]
+fn test_erythemal() {
+    use crate::DataSpectrumFromSlice;
+    use approx::assert_abs_diff_eq;
+
+    let d = Domain::ultraviolet();
+    let s = PhotobiologicalHazard::Erythemal.values(&d);
+    assert_eq!(s.len(), 151);
+    assert_abs_diff_eq!(s[48], 1.0); // 298nm
+    assert_abs_diff_eq!(s[78], 10f64.powf(-2.82), epsilon = 1E-12); // 328nm
+    assert_abs_diff_eq!(s[150], 10f64.powf(-3.9), epsilon = 1E-12); // 400nm
+
+    // 0.025 W/m2/nm from 280 to 289nm, and nothing else, gives an erythemal irradiance of 0.25 W/m2, or UV index 10
+    let mut e = [0.0; 151];
+    e[30..40].iter_mut().for_each(|v| *v = 0.025);
+    let spd = AbsoluteSpd::<_, SpectralIrradiance>::new(DataSpectrumFromSlice::new(d, &e));
+    assert_abs_diff_eq!(uv_index(&spd)[0], 10.0, epsilon = 1E-9);
+    assert_abs_diff_eq!(erythemal_dose(&spd, 3600.0)[0], 9.0, epsilon = 1E-9);
+}
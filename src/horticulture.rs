@@ -0,0 +1,137 @@
+/*!
+Photon flux metrics for horticultural lighting.
+
+Plants use light for photosynthesis, which is driven by the number of absorbed photons, rather than by their energy,
+or their luminous effect on the human eye. Horticultural light sources are therefore characterized by their photon
+flux, in µmol·s<sup>-1</sup>, or photon flux density, in µmol·m<sup>-2</sup>·s<sup>-1</sup>, in the spectral range
+of photosynthetically active radiation (PAR), from 400 to 700nm, and by its split into blue, green, red, and far-red,
+bands, as defined in ANSI/ASABE S640:
+
+| Metric | Band       |
+|--------|------------|
+| PPFD   | 400-700nm  |
+| PFD-B  | 400-500nm  |
+| PFD-G  | 500-600nm  |
+| PFD-R  | 600-700nm  |
+| PFD-FR | 700-800nm  |
+
+The photon quantities are calculated from absolutely scaled spectral distributions, as an `AbsoluteSpd`, and have
+the area, or solid angle, dimensions of its radiometric quantity: for spectral irradiance data, in
+W·m<sup>-2</sup>·nm<sup>-1</sup>, they are photon flux densities, in µmol·m<sup>-2</sup>·s<sup>-1</sup>, and for
+spectral flux data, in W·nm<sup>-1</sup>, photon fluxes, in µmol·s<sup>-1</sup>.
+Each wavelength band includes its start, and excludes its end, wavelength; the far-red band requires spectral data
+extending to 800nm.
+
+# Examples
+```
+use scot::horticulture::PhotonFlux;
+use scot::illuminants::CieIllA;
+use scot::photometry::{AbsoluteSpd, SpectralIrradiance};
+
+// illuminant A, taken as spectral irradiance, in W/m2/nm
+let pfd = PhotonFlux::new(&AbsoluteSpd::<_, SpectralIrradiance>::new(CieIllA));
+println!("PPFD {:.0} µmol/m2/s, R:FR {:.2}", pfd.ppfd, pfd.red / pfd.far_red);
+```
+*/
+
+use crate::photometry::{AbsoluteSpd, Radiometric};
+use crate::{photon_energy, SpectralDistribution, Step, Unit, WavelengthStep, AVOGADRO};
+use nalgebra::RowDVector;
+
+/// Photosynthetically active radiation band, from 400 to 700nm.
+pub const PAR: [f64; 2] = [400.0, 700.0];
+/// Blue band, from 400 to 500nm.
+pub const PFD_B: [f64; 2] = [400.0, 500.0];
+/// Green band, from 500 to 600nm.
+pub const PFD_G: [f64; 2] = [500.0, 600.0];
+/// Red band, from 600 to 700nm.
+pub const PFD_R: [f64; 2] = [600.0, 700.0];
+/// Far-red band, from 700 to 800nm.
+pub const PFD_FR: [f64; 2] = [700.0, 800.0];
+
+/**
+Photon quantities, in µmol·s<sup>-1</sup> per area, or solid angle, unit of the radiometric quantity, in a wavelength
+band, given by its start, and end, wavelengths in nanometer, for a collection of absolutely scaled spectral
+distributions.
+*/
+pub fn photon_flux<S, R>(spd: &AbsoluteSpd<S, R>, band: [f64; 2]) -> RowDVector<f64>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+    R: Radiometric,
+{
+    let r = spd.to_radiant();
+    let (d, s) = r.spd();
+    let m = r.shape().1;
+    let dl = d.step.unitvalue(1).value();
+    let w: Vec<(usize, f64)> = d
+        .iter()
+        .enumerate()
+        .map(|(i, l)| (i, l.value()))
+        .filter(|&(_, l)| l * 1E9 >= band[0] - 1E-9 && l * 1E9 < band[1] - 1E-9)
+        .map(|(i, l)| (i, 1E6 / (photon_energy(l) * AVOGADRO)))
+        .collect();
+    RowDVector::from_fn(m, |_, j| {
+        w.iter().map(|&(i, f)| s[(i, j)] * f).sum::<f64>() * dl
+    })
+}
+
+/// Photon flux, or photon flux density, of the first spectral distribution of a source, in the PAR range, and in the
+/// blue, green, red, and far-red bands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhotonFlux {
+    /// Photosynthetic photon flux (density), from 400 to 700nm.
+    pub ppfd: f64,
+    /// Blue photon flux (density), from 400 to 500nm.
+    pub blue: f64,
+    /// Green photon flux (density), from 500 to 600nm.
+    pub green: f64,
+    /// Red photon flux (density), from 600 to 700nm.
+    pub red: f64,
+    /// Far-red photon flux (density), from 700 to 800nm.
+    pub far_red: f64,
+}
+
+impl PhotonFlux {
+    pub fn new<S, R>(spd: &AbsoluteSpd<S, R>) -> Self
+    where
+        S: SpectralDistribution<StepType = WavelengthStep>,
+        R: Radiometric,
+    {
+        Self {
+            ppfd: photon_flux(spd, PAR)[0],
+            blue: photon_flux(spd, PFD_B)[0],
+            green: photon_flux(spd, PFD_G)[0],
+            red: photon_flux(spd, PFD_R)[0],
+            far_red: photon_flux(spd, PFD_FR)[0],
+        }
+    }
+}
+
+#[test]
+fn test_photon_flux() {
+    use crate::photometry::SpectralIrradiance;
+    use crate::{DataSpectrumFromSlice, Domain, NM};
+    use approx::assert_abs_diff_eq;
+
+    // 1 W/m2 of 550nm radiation is 4.598 µmol/m2/s
+    let mut e = [0.0; 401];
+    e[150] = 1.0;
+    let spd = AbsoluteSpd::<_, SpectralIrradiance>::new(DataSpectrumFromSlice::new(
+        Domain::new(400, 800, NM),
+        &e,
+    ));
+    let pfd = PhotonFlux::new(&spd);
+    assert_abs_diff_eq!(pfd.ppfd, 4.598, epsilon = 1E-3);
+    assert_abs_diff_eq!(pfd.green, pfd.ppfd);
+    assert_eq!(pfd.blue + pfd.red + pfd.far_red, 0.0);
+
+    // the bands split the PAR range
+    let flat = [1.0; 401];
+    let spd = AbsoluteSpd::<_, SpectralIrradiance>::new(DataSpectrumFromSlice::new(
+        Domain::new(400, 800, NM),
+        &flat,
+    ));
+    let pfd = PhotonFlux::new(&spd);
+    assert_abs_diff_eq!(pfd.blue + pfd.green + pfd.red, pfd.ppfd, epsilon = 1E-9);
+    assert!(pfd.blue < pfd.green && pfd.green < pfd.red && pfd.red < pfd.far_red);
+}
@@ -5,8 +5,8 @@ use crate::illuminants::Illuminant;
 use crate::models::{uv60, CieXYZ};
 use crate::observers::StandardObserver;
 use crate::{
-    planck_c2, planck_prime_c2, stefan_boltzmann, SpectralDistribution, C2, C2_IPTS_1948,
-    C2_IPTS_1990, C2_NBS_1931, DOMAIN_DEFAULT_LEN,
+    planck_c2, planck_prime_c2, stefan_boltzmann, wien_c2, DefaultObserver, SpectralDistribution,
+    C2, C2_IPTS_1948, C2_IPTS_1990, C2_NBS_1931, DOMAIN_DEFAULT_LEN,
 };
 use crate::{Domain, Step, Unit, WavelengthStep};
 
@@ -63,11 +63,29 @@ impl Default for RadiantConstant {
     }
 }
 
+/// Scaling of the spectral distributions of blackbody radiators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlanckianNormalization {
+    /// Spectral radiant exitance, in W/m<sup>2</sup>/m, as given by Planck's law.
+    #[default]
+    Exitance,
+    /// Spectral radiant exitance, in W/m<sup>2</sup>/nm.
+    ExitanceNm,
+    /// A value of 1.0 at a wavelength of 560nm.
+    At560,
+    /// A value of 1.0 at the peak of the distribution, also if the peak is outside of its domain.
+    Peak,
+    /// A luminance, or Y tristimulus value, of 100.0, for the default observer.
+    Y100,
+}
+
 #[derive(Debug, Default)]
 pub struct Planckian {
     pub domain: Domain<WavelengthStep>,
     pub ccts: CctParameters,
     pub c2: RadiantConstant,
+    pub normalization: PlanckianNormalization,
+    pub wien: bool,
 }
 
 impl Planckian {
@@ -88,6 +106,55 @@ impl Planckian {
         self
     }
 
+    pub fn set_normalization(mut self, normalization: PlanckianNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Uses Wien's approximation, instead of Planck's law, if `wien` is true.
+    pub fn set_wien(mut self, wien: bool) -> Self {
+        self.wien = wien;
+        self
+    }
+
+    fn exitance(&self, l: f64, t: f64) -> f64 {
+        if self.wien {
+            wien_c2(l, t, self.c2.value())
+        } else {
+            planck_c2(l, t, self.c2.value())
+        }
+    }
+
+    /// Scale factor for a blackbody with temperature `t`, and its spectral distribution `v` on the domain.
+    fn scale(&self, t: f64, v: &[f64]) -> f64 {
+        match self.normalization {
+            PlanckianNormalization::Exitance => 1.0,
+            PlanckianNormalization::ExitanceNm => 1E-9,
+            PlanckianNormalization::At560 => 1.0 / self.exitance(560E-9, t),
+            PlanckianNormalization::Peak => {
+                // Wien's displacement law: the peak is at c2 / (x T), with x = 5 for Wien's approximation
+                let x = if self.wien {
+                    5.0
+                } else {
+                    4.965_114_231_744_276
+                };
+                1.0 / self.exitance(self.c2.value() / (x * t), t)
+            }
+            PlanckianNormalization::Y100 => {
+                let d = &self.domain;
+                let ybar = DefaultObserver::values_cached(d);
+                let y: f64 = v
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| ybar[(1, i)] * s)
+                    .sum::<f64>()
+                    * DefaultObserver::K
+                    * d.step.unitvalue(1).value();
+                100.0 / y
+            }
+        }
+    }
+
     pub fn radiant_emittance(&self) -> DVector<f64> {
         DVector::from_iterator(
             self.ccts.len(),
@@ -102,14 +169,17 @@ impl SpectralDistribution for Planckian {
 
     fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
         let d = self.domain.clone();
-        let m = Self::MatrixType::from_iterator(
+        let mut m = Self::MatrixType::from_iterator(
             d.len(),
             self.ccts.len(),
-            self.ccts.iter().flat_map(|t| {
-                d.iter()
-                    .map(move |l| planck_c2(l.value(), *t, self.c2.value()))
-            }),
+            self.ccts
+                .iter()
+                .flat_map(|t| d.iter().map(move |l| self.exitance(l.value(), *t))),
         );
+        for (j, t) in self.ccts.iter().enumerate() {
+            let s = self.scale(*t, m.column(j).as_slice());
+            m.column_mut(j).scale_mut(s);
+        }
         (d, m)
     }
 
@@ -185,13 +255,52 @@ fn test_bb() {
     println!("{} {}", x, y);
 }
 
+#[test]
+fn test_planckian_normalization() {
+    use crate::NM;
+    use approx::assert_abs_diff_eq;
+
+    let pl = |n| {
+        Planckian::new(5000)
+            .set_normalization(n)
+            .set_domain(Domain::new(380, 780, NM))
+            .spd()
+            .1
+    };
+    let e = pl(PlanckianNormalization::Exitance);
+    assert_abs_diff_eq!(
+        pl(PlanckianNormalization::ExitanceNm),
+        &e * 1E-9,
+        epsilon = 1E-6
+    );
+    assert_abs_diff_eq!(
+        pl(PlanckianNormalization::At560)[(560 - 380, 0)],
+        1.0,
+        epsilon = 1E-12
+    );
+    let peak = pl(PlanckianNormalization::Peak);
+    assert!(peak.max() <= 1.0 && peak.max() > 0.99999); // peak at 579.6nm
+
+    let y100 = pl(PlanckianNormalization::Y100);
+    let ybar = DefaultObserver::values(&Domain::new(380, 780, NM));
+    let y = (ybar.row(1) * y100)[0] * DefaultObserver::K * 1E-9;
+    assert_abs_diff_eq!(y, 100.0, epsilon = 1E-9);
+
+    // Wien's approximation at low temperatures
+    let wien = Planckian::new(1000).set_wien(true).spd().1;
+    let planck = Planckian::new(1000).spd().1;
+    for (w, p) in wien.iter().zip(planck.iter()) {
+        assert_abs_diff_eq!(w / p, 1.0, epsilon = 1E-8);
+    }
+}
+
 pub fn planck_xyz<C: StandardObserver>(t: f64, c2: f64) -> [f64; 3] {
     let d = C::domain();
     let n = d.len();
-    let cmf = C::values(&d);
+    let cmf = C::values_cached(&d);
     let pl =
         DVector::<f64>::from_iterator(n, (&d).into_iter().map(|p| planck_c2(p.value(), t, c2)));
-    let xyz = cmf * pl;
+    let xyz = cmf.as_ref() * pl;
     [xyz.x, xyz.y, xyz.z]
 }
 
@@ -200,13 +309,13 @@ pub fn planck_xyz_dxyz<C: StandardObserver>(t: f64, c2: f64) -> [[f64; 3]; 2] {
     //	let d = Domain::new(360/5, 830/5, NM5);
     let d = C::domain();
     let n = d.len();
-    let cmf = C::values(&d);
+    let cmf = C::values_cached(&d);
     let pl =
         DVector::<f64>::from_iterator(n, (&d).into_iter().map(|p| planck_c2(p.value(), t, c2)));
     let pl_prime =
         DVector::<f64>::from_iterator(n, d.into_iter().map(|p| planck_prime_c2(p.value(), t, c2)));
-    let xyz = &cmf * pl;
-    let dxyz = cmf * pl_prime;
+    let xyz = cmf.as_ref() * pl;
+    let dxyz = cmf.as_ref() * pl_prime;
     [[xyz.x, xyz.y, xyz.z], [dxyz.x, dxyz.y, dxyz.z]]
 }
 
@@ -41,6 +41,8 @@ of temperature values distributed on a multiplicative scale.
 The implementation here starts with a temperature of 1000K, and uses a multiplication factor of 1%, so the next values in this scale
 are 1000K * 1.01 = 1010K, and 1010K * 1.01 = 1020.1K.
 In total 303 values are generated, with a maximum of 20186.21K.
+These tables are calculated only once for each observer, and temperature range, and are shared by all the instances
+of the methods in a program, also between threads.
 
 The algorithm is a basic brute force minimum search, with either triangular (|Duv|<0.002) or parabolic interpolation (otherwise) within the
 interval containing the minimum values.
@@ -68,6 +70,8 @@ Another minimum search algorithm, proposed by Ohno, is to use Planckian locus ta
 starting with a large 15% step table – a multiplication factor of 1.15 –
 and continuing with finer step table (with 10 times smaller step size, 1.5%) centered around the found minimum.
 This can be repeated a couple of times, getting to very small step size, and small errors.
+The default start table covers a range from 500K, for flames, to 100_000K, for the blue sky, and both Ohno methods can
+be used with a custom range, using their `with_range` constructors.
 
 To use Ohno's Cascade method to calculate the CCT and Duv values for the CIE FL1 illuminant:
 
@@ -83,24 +87,56 @@ To use Ohno's Cascade method to calculate the CCT and Duv values for the CIE FL1
 ```
 
 
+# McCamy, and Hernández-Andrés et al.
+
+Closed form approximations, calculating correlated color temperature directly from CIE 1931 x and y chromaticity
+coordinates, using the inverse slope of the line connecting the chromaticity point with an epicenter, at which the
+isotemperature lines converge.
+McCamy\[1992\] uses a cubic polynomial, and is accurate within about 2K for temperatures from 2856 to 6504K, and
+Hernández-Andrés et al.\[1999\] use a sum of exponential terms, with two sets of coefficients, covering a range from
+3000K to 800_000K, with errors up to about 0.5%.
+These methods are used when fast estimates are good enough, but they are only accurate for chromaticities close to the
+Planckian locus; outside their temperature ranges `f64::NAN` values are reported.
+
+# Newton refinement
+
+For calibration work, where table interpolation errors of a fraction of a Kelvin are too large, `CctNewton` refines
+the results of any of these methods by root finding, using the temperature derivatives of the Planckian locus, to a
+precision better than a millikelvin, and reports the convergence of the iterations for each of the points.
+
+# Failures
+
+All methods report failures as `f64::NAN` values in their `CctDuv` output. Use `CctDuvCalc::try_cct_duv` to get a
+result for each sample instead, with a `CctError` describing the cause of a failure.
+
 # References
 
+- McCamy\[1992\]: C. S. McCamy, Correlated color temperature as an explicit function of chromaticity coordinates,
+  Color Research & Application, 17:2, 142-144, DOI: 10.1002/col.5080170211
+- Hernández-Andrés\[1999\]: J. Hernández-Andrés, R. L. Lee, J. Romero, Calculating correlated color temperatures
+  across the entire gamut of daylight and skylight chromaticities, Applied Optics, 38:27, 5703-5709,
+  DOI: 10.1364/AO.38.005703
 - Ohno\[2014\]: Yoshi Ohno, Practical Use and Calculation of CCT and Duv, LEUKOS: The Journal of the Illuminating Engineering Society
 of North America, 10:1, 47-55, DOI: 10.1080/15502724.2014.839020
 
 
 */
 use core::panic;
+use std::any::type_name;
+use std::collections::HashMap;
 use std::fmt::Display;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::{error::Error, marker::PhantomData};
 
-use crate::models::yuv1960::{CieYuv1960, CieYuv1960Values};
+use crate::models::yuv1960::{uv_from_cct_duv, CieYuv1960, CieYuv1960Values};
+use crate::models::{CieYuv, CieYxy};
 use crate::observers::StandardObserver;
 use crate::DefaultObserver;
 use approx::AbsDiffEq;
-use nalgebra::{DVector, Matrix2xX, Matrix3xX};
+use nalgebra::{DVector, Matrix2xX, Matrix3xX, RowDVector};
 
-use super::Planckian;
+use super::{planck_du_dv, Planckian, ReferenceIlluminant, ReferenceSelection};
+use crate::{DomainError, C2};
 
 /**
     Correlated color temperatures, CCT, and distances to a Planckian locus, Duv, for a collection of spectral sources.
@@ -111,6 +147,7 @@ use super::Planckian;
     For Duv's larger than 0.05, or CCTs below or above the covered range, `f64::NAN` values are reported.
 */
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CctDuv<C: StandardObserver>(Matrix2xX<f64>, PhantomData<*const C>);
 
 impl<C: StandardObserver> CctDuv<C> {
@@ -130,6 +167,83 @@ impl<C: StandardObserver> CctDuv<C> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// CIE 1960 u and v chromaticity coordinates, with a luminance value of 1.0.
+    pub fn uv(&self) -> CieYuv1960<C> {
+        let mut mv: Vec<f64> = Vec::with_capacity(3 * self.len());
+        for td in self.0.column_iter() {
+            let (u, v) = uv_from_cct_duv::<C>(td.x, td.y);
+            mv.push(1.0); // y
+            mv.push(u);
+            mv.push(v);
+        }
+        CieYuv1960::new(Matrix3xX::from_vec(mv))
+    }
+
+    /// CIE 1976 u' and v' chromaticity coordinates, with a luminance value of 1.0.
+    pub fn uv_prime(&self) -> CieYuv<C> {
+        let mut m = self.uv().data;
+        m.row_mut(2).scale_mut(1.5);
+        CieYuv::new(m)
+    }
+
+    /// CIE 1931 x and y chromaticity coordinates, with a luminance value of 1.0.
+    pub fn xy(&self) -> CieYxy<C> {
+        let mut m = self.uv().data;
+        for mut c in m.column_iter_mut() {
+            let den = 2.0 * c.y - 8.0 * c.z + 4.0;
+            let (x, y) = (3.0 * c.y / den, 2.0 * c.z / den);
+            c.y = x;
+            c.z = y;
+        }
+        CieYxy::new(m)
+    }
+
+    /// Correlated color temperatures, in Kelvin.
+    pub fn cct(&self) -> RowDVector<f64> {
+        self.0.row(0).into_owned()
+    }
+
+    /// Distances to the Planckian locus, as specified by `metric`.
+    pub fn duv(&self, metric: DuvMetric) -> RowDVector<f64> {
+        match metric {
+            DuvMetric::Uv1960 => self.0.row(1).into_owned(),
+            DuvMetric::Tint => self.0.row(1) * 1000.0,
+            DuvMetric::UvPrime1976 => RowDVector::from_iterator(
+                self.len(),
+                self.0.column_iter().map(|td| {
+                    let (u0, v0) = uv_from_cct_duv::<C>(td.x, 0.0);
+                    let (u, v) = uv_from_cct_duv::<C>(td.x, td.y);
+                    (u - u0).hypot(1.5 * (v - v0)).copysign(td.y)
+                }),
+            ),
+        }
+    }
+
+    /// Reference illuminants, with a luminance of 100.0, for the correlated color temperatures, as used by a color
+    /// rendering metric; the Duv values are not used.
+    pub fn reference(&self, selection: ReferenceSelection) -> ReferenceIlluminant {
+        ReferenceIlluminant::new(
+            self.0.row(0).iter().copied().collect::<Vec<f64>>(),
+            selection,
+        )
+    }
+}
+
+/**
+    Measures of the distance of a chromaticity point to the Planckian locus, as reported by `CctDuv::duv`, all positive
+    above, and negative below the locus.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuvMetric {
+    /// Distance in the CIE 1960 uv diagram, as defined by the CIE, and used in ANSI C78.377.
+    #[default]
+    Uv1960,
+    /// Distance in the CIE 1976 u'v' diagram, between the chromaticity point, and the point on the Planckian locus
+    /// with the same correlated color temperature.
+    UvPrime1976,
+    /// Tint, as used on lighting product specifications: the CIE 1960 Duv value, in thousandths.
+    Tint,
 }
 
 impl<C> AbsDiffEq for CctDuv<C>
@@ -152,6 +266,8 @@ where
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CctDuvValue {
     pub t: f64,
     pub d: f64,
@@ -204,6 +320,134 @@ pub trait CctDuvCalc {
         U: Into<CieYuv1960<Self::Observer>> //		S: SpectralData,
                                             //		Meter: From<<<S as SpectralData>::StepType as Step>::UnitValueType>
     ;
+
+    /**
+        Correlated color temperatures and Duv values as results for each of the samples, with the cause of a failure,
+        instead of `f64::NAN` values.
+        The default implementation reports a missing temperature as `CctError::OutOfRange`.
+    */
+    fn try_cct_duv<U>(&self, uv: U) -> Vec<Result<CctDuvValue, CctError>>
+    where
+        U: Into<CieYuv1960<Self::Observer>>,
+    {
+        let yuvs: CieYuv1960<Self::Observer> = uv.into();
+        let tds = self.cct_duv(CieYuv1960::new(yuvs.data.clone()));
+        yuvs.into_iter()
+            .zip(tds)
+            .map(|(CieYuv1960Values { y: _, u, v }, CctDuvValue { t, d })| {
+                if t.is_nan() {
+                    Err(CctError::OutOfRange)
+                } else if d.is_nan() {
+                    Err(CctError::DuvTooLarge {
+                        t,
+                        d: planckian_distance::<Self::Observer>(u, v, t),
+                    })
+                } else {
+                    Ok(CctDuvValue { t, d })
+                }
+            })
+            .collect()
+    }
+
+    /**
+        Calculates the correlated color temperatures and Duv values of large collections of chromaticity points, in
+        parallel, in chunks of `CCT_BATCH_CHUNK` points, using rayon's global thread pool.
+        The tables of the method are shared by all the threads.
+        Requires the `rayon` feature.
+    */
+    #[cfg(feature = "rayon")]
+    fn cct_duv_batch<U>(&self, uv: U) -> CctDuv<Self::Observer>
+    where
+        Self: Sync,
+        U: Into<CieYuv1960<Self::Observer>>,
+    {
+        use rayon::prelude::*;
+
+        let yuvs: CieYuv1960<Self::Observer> = uv.into();
+        let parts: Vec<Matrix2xX<f64>> = yuvs
+            .data
+            .as_slice()
+            .par_chunks(3 * CCT_BATCH_CHUNK)
+            .map(|c| {
+                self.cct_duv(CieYuv1960::new(Matrix3xX::from_column_slice(c)))
+                    .0
+            })
+            .collect();
+        CctDuv(
+            Matrix2xX::from_iterator(
+                yuvs.data.ncols(),
+                parts.iter().flat_map(|m| m.iter().copied()),
+            ),
+            PhantomData,
+        )
+    }
+}
+
+/// Number of chromaticity points calculated by a single task in `CctDuvCalc::cct_duv_batch`.
+#[cfg(feature = "rayon")]
+pub const CCT_BATCH_CHUNK: usize = 256;
+
+/// Causes of failure of a correlated color temperature calculation, as reported by `CctDuvCalc::try_cct_duv`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CctError {
+    /// The correlated color temperature is outside of the range covered by the method.
+    OutOfRange,
+    /// The distance to the Planckian locus is larger than 0.05, with the calculated temperature, and distance.
+    DuvTooLarge { t: f64, d: f64 },
+    /// No crossing of the isotemperature lines, or of the normal of the Planckian locus, was found.
+    NoCrossing,
+    /// The iterations did not converge, with the last temperature, and the number of iterations.
+    NoConvergence { t: f64, iterations: usize },
+}
+
+impl Display for CctError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CctError::OutOfRange => write!(f, "correlated color temperature out of range"),
+            CctError::DuvTooLarge { t, d } => write!(
+                f,
+                "distance to the Planckian locus too large: {:.5} at {:.1}K",
+                d, t
+            ),
+            CctError::NoCrossing => write!(f, "no isotemperature line crossing found"),
+            CctError::NoConvergence { t, iterations } => write!(
+                f,
+                "no convergence after {} iterations, at {:.4}K",
+                iterations, t
+            ),
+        }
+    }
+}
+
+impl Error for CctError {}
+
+/// Collects per sample results into a `CctDuv` matrix, with `f64::NAN` values for failures, but keeping the
+/// temperatures for Duv values out of range.
+fn cct_duv_from_results<C: StandardObserver>(
+    results: Vec<Result<CctDuvValue, CctError>>,
+) -> CctDuv<C> {
+    let mut tdv: Vec<f64> = Vec::with_capacity(2 * results.len());
+    for r in results {
+        let [t, d] = match r {
+            Ok(CctDuvValue { t, d }) => [t, d],
+            Err(CctError::DuvTooLarge { t, .. }) => [t, f64::NAN],
+            Err(_) => [f64::NAN, f64::NAN],
+        };
+        tdv.push(t);
+        tdv.push(d);
+    }
+    CctDuv(Matrix2xX::from_vec(tdv), PhantomData)
+}
+
+/// Signed distance of a chromaticity point to the Planckian locus at temperature `t`, negative below the locus.
+fn planckian_distance<C: StandardObserver>(u: f64, v: f64, t: f64) -> f64 {
+    let (up, vp) = uv_from_cct_duv::<C>(t, 0.0);
+    let d = (u - up).hypot(v - vp);
+    if v < vp {
+        -d
+    } else {
+        d
+    }
 }
 
 impl<C: StandardObserver> Display for CctDuv<C> {
@@ -226,12 +470,55 @@ which is done using:
     println!("Robertson {}", cct_duv_fl1);
 ```
  */
-pub struct Robertson<C: StandardObserver = DefaultObserver>(Matrix3xX<f64>, PhantomData<*const C>);
+pub struct Robertson<C: StandardObserver = DefaultObserver>(
+    Matrix3xX<f64>,
+    Vec<f64>,
+    PhantomData<fn() -> C>,
+);
 
 impl<C: StandardObserver> Robertson<C> {
     pub fn new() -> Self {
         Robertson::<C>::default()
     }
+
+    /**
+        Robertson table for a custom set of isotemperature lines, given as a ladder of reciprocal color temperatures,
+        in mireds, in increasing order. The default table uses Robertson's 31 values, from 1 to 600 mireds, with
+        smaller steps giving more accurate results.
+        Panics for an invalid ladder.
+    */
+    #[deprecated(note = "use `Robertson::try_with_mireds`, which returns an error instead of panicking")]
+    pub fn with_mireds(mireds: Vec<f64>) -> Self {
+        match Self::try_with_mireds(mireds) {
+            Ok(robertson) => robertson,
+            Err(e) => panic!("Robertson Error: {}", e),
+        }
+    }
+
+    /**
+        Robertson table for a custom set of isotemperature lines, as `with_mireds`, but returns a `DomainError` for a
+        ladder with less than two values, with values which are not positive and finite, or with values which are not
+        in increasing order.
+    */
+    pub fn try_with_mireds(mireds: Vec<f64>) -> Result<Self, DomainError> {
+        if mireds.len() < 2 {
+            return Err(DomainError::EmptyRange);
+        }
+        if let Some(&t) = mireds.iter().find(|t| !(**t > 0.0 && t.is_finite())) {
+            return Err(DomainError::OutOfRange(t));
+        }
+        if let Some(w) = mireds.windows(2).find(|w| w[0] >= w[1]) {
+            return Err(DomainError::NotIncreasing(w[1]));
+        }
+        let mut rv: Vec<f64> = Vec::with_capacity(3 * mireds.len());
+        for t in &mireds {
+            let (u, v, m) = robertson_normal::<C>(1E6 / t);
+            rv.push(u);
+            rv.push(v);
+            rv.push(m);
+        }
+        Ok(Self(Matrix3xX::from_vec(rv), mireds, PhantomData))
+    }
 }
 
 impl<C> Default for Robertson<C>
@@ -239,14 +526,7 @@ where
     C: StandardObserver,
 {
     fn default() -> Self {
-        let mut rv: Vec<f64> = Vec::with_capacity(3 * ROBERTSON_MRD.len());
-        for t in ROBERTSON_MRD {
-            let (u, v, m) = robertson_normal::<crate::observers::CieObs1931>(1E6 / t);
-            rv.push(u);
-            rv.push(v);
-            rv.push(m);
-        }
-        Self(Matrix3xX::from_vec(rv), PhantomData)
+        Self::try_with_mireds(ROBERTSON_MRD.to_vec()).unwrap()
     }
 }
 
@@ -254,11 +534,18 @@ impl<C: StandardObserver> CctDuvCalc for Robertson<C> {
     type Observer = C;
 
     fn cct_duv<U>(&self, uv: U) -> CctDuv<Self::Observer>
+    where
+        U: Into<CieYuv1960<Self::Observer>>,
+    {
+        cct_duv_from_results(self.try_cct_duv(uv))
+    }
+
+    fn try_cct_duv<U>(&self, uv: U) -> Vec<Result<CctDuvValue, CctError>>
     where
         U: Into<CieYuv1960<Self::Observer>>,
     {
         let yuvs: CieYuv1960<C> = uv.into();
-        let mut tdv: Vec<f64> = Vec::with_capacity(2 * yuvs.data.len());
+        let mut tdv: Vec<Result<CctDuvValue, CctError>> = Vec::with_capacity(yuvs.data.ncols());
         for CieYuv1960Values { y: _, u, v } in yuvs {
             let mut dm = 0f64;
             let mut di = 0f64;
@@ -273,35 +560,24 @@ impl<C: StandardObserver> CctDuvCalc for Robertson<C> {
                 dm = di;
             }
             if ir == 0usize {
-                tdv.push(f64::NAN);
-                tdv.push(f64::NAN);
+                tdv.push(Err(CctError::NoCrossing));
             } else {
                 di /= (1.0 + self.0[(2, ir)].powi(2)).sqrt();
                 dm /= (1.0 + self.0[(2, ir - 1)].powi(2)).sqrt();
                 let p = dm / (dm - di); // p interpolation parameter
-                let t = (ROBERTSON_MRD[ir - 1] * (1.0 - p) + ROBERTSON_MRD[ir] * p).recip() * 1E6;
-                tdv.push(t);
-                let CieYuv1960Values { y: _, u: up, v: vp } =
-                    CieYuv1960::<C>::from(Planckian::new(t))
-                        .into_iter()
-                        .next()
-                        .unwrap();
-                let d = (u - up).hypot(v - vp);
-                if d > 0.05 {
-                    tdv.push(f64::NAN)
-                } else if v < vp {
-                        tdv.push(-d)
-                    } else {
-                        tdv.push(d)
-                    }
-                
+                let t = (self.1[ir - 1] * (1.0 - p) + self.1[ir] * p).recip() * 1E6;
+                let d = planckian_distance::<C>(u, v, t);
+                if d.abs() > 0.05 {
+                    tdv.push(Err(CctError::DuvTooLarge { t, d }))
+                } else {
+                    tdv.push(Ok(CctDuvValue { t, d }))
+                }
             }
         }
-        CctDuv(Matrix2xX::from_vec(tdv), PhantomData)
+        tdv
     }
 }
 
-#[allow(dead_code)]
 fn robertson_normal<C: StandardObserver>(cct: f64) -> (f64, f64, f64) {
     let CieYuv1960Values { y: _, u, v } = CieYuv1960::<C>::from(Planckian::new(cct))
         .into_iter()
@@ -317,7 +593,6 @@ fn robertson_normal<C: StandardObserver>(cct: f64) -> (f64, f64, f64) {
     (u, v, -du / dv)
 }
 
-#[allow(dead_code)]
 const ROBERTSON_MRD: [f64; 31] = [
     1.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0, 125.0, 150.0, 175.0, 200.0,
     225.0, 250.0, 275.0, 300.0, 325.0, 350.0, 375.0, 400.0, 425.0, 450.0, 475.0, 500.0, 525.0,
@@ -369,6 +644,483 @@ fn test_robertson_from_cctduv() {
     let td_calc = r.cct_duv(yuv);
     assert_abs_diff_eq!(tds, td_calc, epsilon = (5.0, 0.000_01));
 }
+
+#[test]
+fn test_robertson_observer_and_mireds() {
+    use crate::illuminants::{CctDuvCalc, Robertson};
+    use crate::observers::CieObsF10;
+    use approx::assert_abs_diff_eq;
+
+    let tds: CctDuv<CieObsF10> = CctDuv::new(vec![[6500.0, 0.0], [3000.0, 0.01], [2000.0, -0.01]]);
+    let yuv: CieYuv1960<_> = tds.clone().into();
+
+    // table calculated with the 10º observer's own Planckian locus
+    let r: Robertson<CieObsF10> = Robertson::new();
+    for (a, b) in tds.clone().into_iter().zip(r.cct_duv(yuv.clone())) {
+        assert_abs_diff_eq!(a.t, b.t, epsilon = 5.0);
+        assert_abs_diff_eq!(a.d, b.d, epsilon = 0.000_01);
+    }
+
+    // a finer mired ladder improves accuracy
+    let r: Robertson<CieObsF10> =
+        Robertson::try_with_mireds((1..=120).map(|i| i as f64 * 5.0).collect()).unwrap();
+    for (a, b) in tds.into_iter().zip(r.cct_duv(yuv)) {
+        assert_abs_diff_eq!(a.t, b.t, epsilon = 1.0);
+        assert_abs_diff_eq!(a.d, b.d, epsilon = 0.000_01);
+    }
+
+    // invalid mired ladders are errors
+    assert_eq!(
+        Robertson::<CieObsF10>::try_with_mireds(vec![100.0]).err(),
+        Some(DomainError::EmptyRange)
+    );
+    assert_eq!(
+        Robertson::<CieObsF10>::try_with_mireds(vec![0.0, 100.0]).err(),
+        Some(DomainError::OutOfRange(0.0))
+    );
+    assert_eq!(
+        Robertson::<CieObsF10>::try_with_mireds(vec![100.0, 200.0, 150.0]).err(),
+        Some(DomainError::NotIncreasing(150.0))
+    );
+}
+/**
+Correlated color temperatures calculated from chromaticity coordinates with the closed form expression of
+McCamy\[1992\], for temperatures from 2000 to 12500K.
+
+The distances to the Planckian locus are calculated for the estimated temperatures.
+The coefficients of the expression apply to CIE 1931 chromaticity coordinates only, and this method is implemented for
+the `CieObs1931` observer only.
+```
+use scot::illuminants::{McCamy, FL, CctDuvCalc};
+
+let cct_duv_fl1 = McCamy::new().cct_duv(FL::<1>);
+println!("McCamy {}", cct_duv_fl1);
+```
+*/
+#[derive(Default)]
+pub struct McCamy;
+
+impl McCamy {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CctDuvCalc for McCamy {
+    type Observer = crate::observers::CieObs1931;
+
+    fn cct_duv<U>(&self, uv: U) -> CctDuv<Self::Observer>
+    where
+        U: Into<CieYuv1960<Self::Observer>>,
+    {
+        closed_form_cct_duv(uv.into(), |x, y| {
+            let n = (x - 0.3320) / (0.1858 - y);
+            let t = 449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33;
+            if (2000.0..=12500.0).contains(&t) {
+                t
+            } else {
+                f64::NAN
+            }
+        })
+    }
+}
+
+/**
+Correlated color temperatures calculated from chromaticity coordinates with the exponential expression of
+Hernández-Andrés et al.\[1999\], for temperatures from 3000 to 800_000K.
+
+As for `McCamy`, the coefficients apply to CIE 1931 chromaticity coordinates, and this method is implemented for the
+`CieObs1931` observer only.
+*/
+#[derive(Default)]
+pub struct HernandezAndres;
+
+impl HernandezAndres {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CctDuvCalc for HernandezAndres {
+    type Observer = crate::observers::CieObs1931;
+
+    fn cct_duv<U>(&self, uv: U) -> CctDuv<Self::Observer>
+    where
+        U: Into<CieYuv1960<Self::Observer>>,
+    {
+        closed_form_cct_duv(uv.into(), |x, y| {
+            let n = (x - 0.3366) / (y - 0.1735);
+            let t = -949.86315
+                + 6253.80338 * (-n / 0.92159).exp()
+                + 28.70599 * (-n / 0.20039).exp()
+                + 0.00004 * (-n / 0.07125).exp();
+            let t = if t > 50000.0 {
+                let n = (x - 0.3356) / (y - 0.1691);
+                36284.48953 + 0.00228 * (-n / 0.07861).exp() + 5.4535E-36 * (-n / 0.01543).exp()
+            } else {
+                t
+            };
+            if (3000.0..=800000.0).contains(&t) {
+                t
+            } else {
+                f64::NAN
+            }
+        })
+    }
+}
+
+/// Applies a closed form CCT expression, as function of CIE 1931 x and y, and calculates the Duv values for the
+/// resulting temperatures.
+fn closed_form_cct_duv<C: StandardObserver>(
+    yuvs: CieYuv1960<C>,
+    cct: impl Fn(f64, f64) -> f64,
+) -> CctDuv<C> {
+    let mut tdv: Vec<f64> = Vec::with_capacity(2 * yuvs.data.ncols());
+    for CieYuv1960Values { y: _, u, v } in yuvs {
+        let den = 2.0 * u - 8.0 * v + 4.0;
+        let t = cct(3.0 * u / den, 2.0 * v / den);
+        let d = if t.is_nan() {
+            f64::NAN
+        } else {
+            let d = planckian_distance::<C>(u, v, t);
+            if d.abs() > 0.05 {
+                f64::NAN
+            } else {
+                d
+            }
+        };
+        tdv.push(t);
+        tdv.push(d);
+    }
+    CctDuv(Matrix2xX::from_vec(tdv), PhantomData)
+}
+
+#[test]
+fn test_closed_form_cct() {
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let tds: CctDuv<CieObs1931> =
+        CctDuv::new(vec![[3000.0, 0.0], [4000.0, 0.001], [6500.0, -0.001]]);
+    let yuv: CieYuv1960<_> = tds.clone().into();
+    assert_abs_diff_eq!(
+        McCamy::new().cct_duv(yuv),
+        tds,
+        epsilon = (10.0, 5E-4)
+    );
+
+    let tds: CctDuv<CieObs1931> = CctDuv::new(vec![
+        [4000.0, 0.001],
+        [6500.0, -0.001],
+        [10000.0, 0.0],
+        [25000.0, 0.0],
+    ]);
+    let yuv: CieYuv1960<_> = tds.clone().into();
+    assert_abs_diff_eq!(
+        HernandezAndres::new().cct_duv(yuv),
+        tds,
+        epsilon = (100.0, 1E-4)
+    );
+
+    // out of range
+    let yuv: CieYuv1960<CieObs1931> = CctDuv::new(vec![[1500.0, 0.0]]).into();
+    assert!(McCamy::new().cct_duv(yuv.clone()).0[(0, 0)].is_nan());
+    assert!(HernandezAndres::new().cct_duv(yuv).0[(0, 0)].is_nan());
+}
+
+/**
+Correlated color temperatures, refined to sub-millikelvin precision by root finding.
+
+An initial estimate, obtained by another method – `Robertson` by default, or for example `Ohno2014` – is refined by
+solving for the temperature at which the line connecting the chromaticity point and the Planckian locus is normal to
+the locus. This uses the Planckian chromaticity coordinates, and their temperature derivatives, as calculated by
+`planck_du_dv`, with the exact value of the second radiation constant.
+Newton steps are used, with bisection of a bracketing interval when a step leaves that interval, similar to Brent's
+method, iterating until the temperature correction is less than the tolerance, with a default of 0.1mK.
+
+Points which do not converge within the maximum number of iterations are reported as `f64::NAN` values by `cct_duv`;
+use `convergence` to get the details of the iterations, for each of the points.
+```
+use scot::illuminants::{CctDuvCalc, CctNewton, Ohno2014, FL};
+use scot::observers::CieObs1931;
+
+let newton = CctNewton::new(Ohno2014::<CieObs1931>::new()).set_tolerance(1E-5);
+for c in newton.convergence(FL::<3>) {
+    assert!(c.converged);
+    println!("FL3 {:.4}K {:.6} ({} iterations)", c.t, c.d, c.iterations);
+}
+```
+*/
+pub struct CctNewton<M: CctDuvCalc = Robertson> {
+    method: M,
+    tolerance: f64,
+    max_iterations: usize,
+}
+
+/// Refinement result, and convergence diagnostics, of `CctNewton` for a single chromaticity point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CctConvergence {
+    /// Correlated color temperature, in Kelvin.
+    pub t: f64,
+    /// Distance to the Planckian locus, or `f64::NAN` if larger than 0.05.
+    pub d: f64,
+    /// Initial estimate of the correlated color temperature.
+    pub t0: f64,
+    pub iterations: usize,
+    /// Size of the last temperature correction, in Kelvin.
+    pub step: f64,
+    /// Distance along the Planckian locus, in CIE 1960 uv units, of the chromaticity point to the normal of the locus
+    /// at the final temperature; zero for an exact solution.
+    pub residual: f64,
+    pub converged: bool,
+}
+
+impl<M: CctDuvCalc> CctNewton<M> {
+    pub fn new(method: M) -> Self {
+        Self {
+            method,
+            tolerance: 1E-4,
+            max_iterations: 50,
+        }
+    }
+
+    /// Sets the tolerance of the temperature, in Kelvin, with a default of 1E-4K.
+    pub fn set_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn set_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Refines the correlated color temperatures of all the chromaticity points, with convergence diagnostics.
+    pub fn convergence<U>(&self, uv: U) -> Vec<CctConvergence>
+    where
+        U: Into<CieYuv1960<M::Observer>>,
+    {
+        let yuvs: CieYuv1960<M::Observer> = uv.into();
+        let initial = self.method.cct_duv(CieYuv1960::new(yuvs.data.clone()));
+        yuvs.into_iter()
+            .zip(initial)
+            .map(|(CieYuv1960Values { y: _, u, v }, CctDuvValue { t, d: _ })| self.refine(u, v, t))
+            .collect()
+    }
+
+    /// Refines a correlated color temperature estimate `t0` for a chromaticity point with CIE 1960 coordinates `u`
+    /// and `v`.
+    pub fn refine(&self, u: f64, v: f64, t0: f64) -> CctConvergence {
+        // component of the vector from the locus to the test point along the tangent of the locus
+        let f = |t: f64| {
+            let [up, vp, du, dv] = planck_du_dv::<M::Observer>(t, C2);
+            ((u - up) * du + (v - vp) * dv, [up, vp, du, dv])
+        };
+        let mut c = CctConvergence {
+            t: f64::NAN,
+            d: f64::NAN,
+            t0,
+            iterations: 0,
+            step: f64::NAN,
+            residual: f64::NAN,
+            converged: false,
+        };
+        if !(t0.is_finite() && t0 > NEWTON_T_MIN) {
+            return c;
+        }
+
+        // bracket the root, expanding geometrically around the initial estimate
+        let (mut lo, mut hi) = (t0 / 1.01, t0 * 1.01);
+        let (mut flo, mut fhi) = (f(lo).0, f(hi).0);
+        while flo.signum() == fhi.signum() {
+            if lo <= NEWTON_T_MIN && hi >= NEWTON_T_MAX {
+                return c;
+            }
+            lo = (lo / 1.5).max(NEWTON_T_MIN);
+            hi = (hi * 1.5).min(NEWTON_T_MAX);
+            flo = f(lo).0;
+            fhi = f(hi).0;
+        }
+
+        let mut t = t0.clamp(lo, hi);
+        while c.iterations < self.max_iterations {
+            c.iterations += 1;
+            let ft = f(t).0;
+            if ft.signum() == flo.signum() {
+                lo = t;
+            } else {
+                hi = t;
+            }
+            let h = t * 1E-4;
+            let dft = (f(t + h).0 - f(t - h).0) / (2.0 * h);
+            let mut tn = t - ft / dft;
+            if !(tn > lo && tn < hi) {
+                tn = (lo * hi).sqrt();
+            }
+            c.step = tn - t;
+            t = tn;
+            if c.step.abs() < self.tolerance {
+                c.converged = true;
+                break;
+            }
+        }
+
+        let (ft, [up, vp, du, dv]) = f(t);
+        let d = (u - up).hypot(v - vp);
+        c.t = t;
+        c.d = if d > 0.05 {
+            f64::NAN
+        } else if v < vp {
+            -d
+        } else {
+            d
+        };
+        c.residual = ft / du.hypot(dv);
+        c
+    }
+}
+
+impl<C: StandardObserver> Default for CctNewton<Robertson<C>> {
+    fn default() -> Self {
+        Self::new(Robertson::default())
+    }
+}
+
+impl<M: CctDuvCalc> CctDuvCalc for CctNewton<M> {
+    type Observer = M::Observer;
+
+    fn cct_duv<U>(&self, uv: U) -> CctDuv<Self::Observer>
+    where
+        U: Into<CieYuv1960<Self::Observer>>,
+    {
+        cct_duv_from_results(self.try_cct_duv(uv))
+    }
+
+    /// Reports failures of the initial method, except for too large Duv values, for which the temperature is refined;
+    /// `CctError::NoCrossing` if the normal of the locus through the test point can not be bracketed; and
+    /// `CctError::NoConvergence` if the maximum number of iterations is reached.
+    fn try_cct_duv<U>(&self, uv: U) -> Vec<Result<CctDuvValue, CctError>>
+    where
+        U: Into<CieYuv1960<Self::Observer>>,
+    {
+        let yuvs: CieYuv1960<M::Observer> = uv.into();
+        let initial = self.method.try_cct_duv(CieYuv1960::new(yuvs.data.clone()));
+        yuvs.into_iter()
+            .zip(initial)
+            .map(|(CieYuv1960Values { y: _, u, v }, r)| {
+                let t0 = match r {
+                    Ok(CctDuvValue { t, .. }) | Err(CctError::DuvTooLarge { t, .. }) => t,
+                    Err(e) => return Err(e),
+                };
+                let c = self.refine(u, v, t0);
+                if c.t.is_nan() {
+                    Err(CctError::NoCrossing)
+                } else if !c.converged {
+                    Err(CctError::NoConvergence {
+                        t: c.t,
+                        iterations: c.iterations,
+                    })
+                } else if c.d.is_nan() {
+                    Err(CctError::DuvTooLarge {
+                        t: c.t,
+                        d: planckian_distance::<M::Observer>(u, v, c.t),
+                    })
+                } else {
+                    Ok(CctDuvValue { t: c.t, d: c.d })
+                }
+            })
+            .collect()
+    }
+}
+
+const NEWTON_T_MIN: f64 = 100.0;
+const NEWTON_T_MAX: f64 = 1E7;
+
+#[test]
+fn test_cct_newton() {
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let tds: CctDuv<CieObs1931> = CctDuv::new(vec![
+        [2000.0, 0.0],
+        [2856.0, -0.01],
+        [4000.0, 0.02],
+        [6500.0, 0.003],
+        [15000.0, -0.02],
+    ]);
+    let yuv: CieYuv1960<_> = tds.clone().into();
+    let newton = CctNewton::new(Robertson::<CieObs1931>::new());
+    // the test points off the locus are within a millikelvin, as their normals are approximated by a 0.01K difference
+    for (c, td) in newton.convergence(yuv.clone()).iter().zip(tds.clone()) {
+        assert!(c.converged && c.iterations < 10, "{:?}", c);
+        assert_abs_diff_eq!(c.t, td.t, epsilon = 1E-3);
+        assert_abs_diff_eq!(c.d, td.d, epsilon = 1E-8);
+        assert!(c.residual.abs() < 1E-9 && c.step.abs() < 1E-4, "{:?}", c);
+    }
+
+    // the same result with a different initial estimate
+    let ohno = CctNewton::new(Ohno2014::<CieObs1931>::new());
+    assert_abs_diff_eq!(
+        ohno.cct_duv(yuv.clone()),
+        newton.cct_duv(yuv.clone()),
+        epsilon = (1E-4, 1E-10)
+    );
+
+    // no convergence within a single step, and no initial estimate below Robertson's range
+    let c = newton.set_max_iterations(1).convergence(yuv);
+    assert!(!c[4].converged && c[4].iterations == 1);
+    let yuv: CieYuv1960<CieObs1931> = CctDuv::new(vec![[1500.0, 0.0]]).into();
+    let cd = CctNewton::<Robertson<CieObs1931>>::default().cct_duv(yuv);
+    assert!(cd.0[(0, 0)].is_nan());
+}
+
+/**
+Isotemperature lines, for plotting correlated color temperature charts, as line segments in the CIE 1960 uv diagram
+of observer `C`.
+
+For each of the temperatures in `ccts`, in Kelvin, a segment is returned, with its end points, as `[u, v]` pairs, at
+distances `-duv`, and `duv` from the Planckian locus, on the normal of the locus.
+```
+use scot::illuminants::isotherm_lines;
+use scot::observers::CieObs1931;
+
+for [[u0, v0], [u1, v1]] in isotherm_lines::<CieObs1931>(&[2700.0, 4000.0, 6500.0], 0.05) {
+    println!("<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"/>", u0, v0, u1, v1);
+}
+```
+*/
+pub fn isotherm_lines<C: StandardObserver>(ccts: &[f64], duv: f64) -> Vec<[[f64; 2]; 2]> {
+    ccts.iter()
+        .map(|&t| {
+            let (u0, v0) = uv_from_cct_duv::<C>(t, -duv);
+            let (u1, v1) = uv_from_cct_duv::<C>(t, duv);
+            [[u0, v0], [u1, v1]]
+        })
+        .collect()
+}
+
+#[test]
+fn test_isotherm_lines() {
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let lines = isotherm_lines::<CieObs1931>(&[2000.0, 3000.0, 6500.0, 20000.0], 0.02);
+    assert_eq!(lines.len(), 4);
+    for ([[u0, v0], [u1, v1]], t) in lines.iter().zip([2000.0, 3000.0, 6500.0, 20000.0]) {
+        // centered on, and normal to, the Planckian locus, and below to above
+        let [u, v, du, dv] = planck_du_dv::<CieObs1931>(t, C2);
+        assert_abs_diff_eq!((u0 + u1) / 2.0, u, epsilon = 1E-8);
+        assert_abs_diff_eq!((v0 + v1) / 2.0, v, epsilon = 1E-8);
+        assert_abs_diff_eq!((u1 - u0).hypot(v1 - v0), 0.04, epsilon = 1E-10);
+        assert_abs_diff_eq!(
+            ((u1 - u0) * du + (v1 - v0) * dv) / du.hypot(dv),
+            0.0,
+            epsilon = 1E-6
+        );
+        assert!(v1 > v0);
+    }
+}
+
 /**
     Multiplicative increasing temperature scale as used in Ohno's method
 */
@@ -398,15 +1150,38 @@ impl CctLadder {
         It includes and ranges beyond the given end temperature.
         Fails if `start` is larger or equal than `end`, or if the multiplication factor `mul` is less or equal than 1.0.
     */
+    #[deprecated(note = "use `CctLadder::try_new`, which returns an error instead of panicking")]
     pub fn new(start: f64, end: f64, mul: f64) -> Self {
-        if start <= 0.0 || end < start || mul <= 1.0 {
-            panic!("CctLadder Error: end value should be larger than start value, and the multiplicaton factor should be larger than 1.0");
+        match Self::try_new(start, end, mul) {
+            Ok(ladder) => ladder,
+            Err(_) => panic!("CctLadder Error: end value should be larger than start value, and the multiplicaton factor should be larger than 1.0"),
         }
-        Self {
+    }
+
+    /**
+        Creates a multiplicative temperature scale, as `new`, but returns a `DomainError` for a start temperature
+        which is not positive, an end temperature less than the start temperature, or a multiplication factor which
+        is not larger than 1.0.
+    */
+    pub fn try_new(start: f64, end: f64, mul: f64) -> Result<Self, DomainError> {
+        if !(start > 0.0 && start.is_finite()) {
+            return Err(DomainError::OutOfRange(start));
+        }
+        if !(end >= start && end.is_finite()) {
+            return Err(DomainError::EmptyRange);
+        }
+        if !(mul > 1.0 && mul.is_finite()) {
+            return Err(DomainError::InvalidStep(mul));
+        }
+        let imax = ((end / start).log10() / mul.log10()).ceil();
+        if imax > i32::MAX as f64 {
+            return Err(DomainError::InvalidStep(mul));
+        }
+        Ok(Self {
             cct_min: start,
             cct_mul: mul,
-            imax: ((end / start).log10() / mul.log10()).ceil() as i32,
-        }
+            imax: imax as i32,
+        })
     }
 
     pub fn cct(&self, i: i32) -> Result<f64, Box<dyn Error>> {
@@ -438,6 +1213,7 @@ impl Iterator for CctLadder {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_cct() -> Result<(), Box<dyn Error>> {
     use approx::assert_abs_diff_eq;
     let vcct: Vec<f64> = CctLadder::default().into_iter().collect();
@@ -456,11 +1232,49 @@ fn test_cct() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn test_cct_ladder_try_new() {
+    assert_eq!(
+        CctLadder::try_new(1000.0, 20186.22, 1.01).unwrap().imax,
+        303
+    );
+    assert!(matches!(
+        CctLadder::try_new(0.0, 1000.0, 1.01),
+        Err(DomainError::OutOfRange(_))
+    ));
+    assert!(matches!(
+        CctLadder::try_new(2000.0, 1000.0, 1.01),
+        Err(DomainError::EmptyRange)
+    ));
+    assert!(matches!(
+        CctLadder::try_new(1000.0, 2000.0, 1.0),
+        Err(DomainError::InvalidStep(_))
+    ));
+}
+
 #[doc(hidden)]
 /**
     A convenience object, used to implement various methods to calculate CCT and Duv's.
+    Its temperatures, and their CIE 1960 Yuv values, are shared, so they can be cached.
 */
-struct PlanckianTable<C: StandardObserver>(Vec<f64>, CieYuv1960<C>);
+struct PlanckianTable<C: StandardObserver>(
+    Arc<Vec<f64>>,
+    Arc<Matrix3xX<f64>>,
+    PhantomData<fn() -> C>,
+);
+
+type PlanckianTableKey = (&'static str, u64, u64, i32);
+type PlanckianTableValues = (Arc<Vec<f64>>, Arc<Matrix3xX<f64>>);
+
+static PLANCKIAN_TABLE_CACHE: OnceLock<Mutex<HashMap<PlanckianTableKey, PlanckianTableValues>>> =
+    OnceLock::new();
+
+/// Removes all cached Planckian tables, as used by the `Ohno2014`, and `Ohno2014Cascade` methods.
+pub fn clear_planckian_table_cache() {
+    if let Some(cache) = PLANCKIAN_TABLE_CACHE.get() {
+        cache.lock().unwrap().clear();
+    }
+}
 
 impl<C> PlanckianTable<C>
 where
@@ -469,7 +1283,30 @@ where
     fn new(l: Option<CctLadder>) -> Self {
         let tpv: Vec<f64> = l.unwrap_or_default().into_iter().collect();
         let cct_uv: CieYuv1960<C> = Planckian::new(tpv.clone()).into();
-        Self(tpv, cct_uv)
+        Self(Arc::new(tpv), Arc::new(cct_uv.data), PhantomData)
+    }
+
+    /**
+        Table for a ladder, shared with all the other tables for the same observer and ladder in the process, and
+        only calculated on first use.
+        Use this for the start tables of the methods, and `new` for the tables of intermediate results, such as the
+        zoomed tables of the cascade method, which would fill the cache.
+    */
+    fn cached(l: CctLadder) -> Self {
+        let key = (
+            type_name::<C>(),
+            l.cct_min.to_bits(),
+            l.cct_mul.to_bits(),
+            l.imax,
+        );
+        let cache = PLANCKIAN_TABLE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        if let Some((t, uv)) = cache.lock().unwrap().get(&key) {
+            return Self(t.clone(), uv.clone(), PhantomData);
+        }
+        // calculate without holding the lock, as for the color matching functions cache
+        let Self(t, uv, _) = Self::new(Some(l));
+        let (t, uv) = cache.lock().unwrap().entry(key).or_insert((t, uv)).clone();
+        Self(t, uv, PhantomData)
     }
 
     /**
@@ -477,7 +1314,8 @@ where
     */
     fn sq_distances(&self, u: f64, v: f64) -> DVector<f64> {
         let mut d2v: Vec<f64> = Vec::with_capacity(self.0.len());
-        for CieYuv1960Values { y: _, u: ur, v: vr } in &self.1 {
+        for c in self.1.column_iter() {
+            let (ur, vr) = (c.y, c.z);
             d2v.push((u - ur) * (u - ur) + (v - vr) * (v - vr));
         }
         DVector::from_vec(d2v)
@@ -488,8 +1326,8 @@ where
         let dp2 = duv2values[i - 1];
         let tn = self.0[i + 1];
         let dn2 = duv2values[i + 1];
-        let l2 = (self.1.data[(1, i + 1)] - self.1.data[(1, i - 1)]).powi(2)
-            + (self.1.data[(2, i + 1)] - self.1.data[(2, i - 1)]).powi(2);
+        let l2 = (self.1[(1, i + 1)] - self.1[(1, i - 1)]).powi(2)
+            + (self.1[(2, i + 1)] - self.1[(2, i - 1)]).powi(2);
         let l = l2.sqrt();
         let x = (dp2 - dn2 + l2) / (2. * l);
         let t = tp + (tn - tp) * x / l;
@@ -524,7 +1362,7 @@ where
             } else {
                 self.parabolic(imin, d2m)
             };
-            if v < self.1.data[(2, imin)] {
+            if v < self.1[(2, imin)] {
                 [t, -d]
             } else {
                 [t, d]
@@ -532,10 +1370,17 @@ where
         }
     }
 
+    /// Finer table around the minimum distance; at the ends of the table the interval is limited to the table, and
+    /// the test point will be reported as out of range by the final table.
     fn zoom(&self, u: f64, v: f64, mul: f64) -> CctLadder {
         let d2m = self.sq_distances(u, v);
         let imin = d2m.imin();
-        CctLadder::new(self.0[imin - 1], self.0[imin + 1], mul)
+        CctLadder::try_new(
+            self.0[imin.saturating_sub(1)],
+            self.0[(imin + 1).min(self.0.len() - 1)],
+            mul,
+        )
+        .expect("table temperatures are increasing")
     }
 }
 
@@ -554,7 +1399,7 @@ where
     C: StandardObserver,
 {
     fn default() -> Self {
-        Self::new(None)
+        Self::cached(CctLadder::default())
     }
 }
 
@@ -567,6 +1412,19 @@ impl<C: StandardObserver> Ohno2014<C> {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /**
+        Ohno's 1% step table method, with a table covering correlated color temperatures from `start` to `end`, in
+        Kelvin, instead of the default range from 1000 to 20186K.
+        It has been tested for temperatures from 500 to 100_000K; outside the range of the table `f64::NAN` values are
+        reported.
+    */
+    pub fn with_range(start: f64, end: f64) -> Self {
+        Self(PlanckianTable::cached(
+            CctLadder::try_new(ladder_start(start, 1.01), end * 1.01 * 1.01, 1.01)
+                .expect("start temperature should be positive, and less than the end temperature"),
+        ))
+    }
 }
 
 impl<C> CctDuvCalc for Ohno2014<C>
@@ -609,12 +1467,35 @@ fn test_ohno_trait() {
     println!("{}", cct.0);
 }
 
+/// Lower limit of the default temperature range of `Ohno2014Cascade`, in Kelvin.
+pub const OHNO_CASCADE_CCT_MIN: f64 = 500.0;
+
+/// Upper limit of the default temperature range of `Ohno2014Cascade`, in Kelvin.
+pub const OHNO_CASCADE_CCT_MAX: f64 = 100_000.0;
+
 pub struct Ohno2014Cascade<C: StandardObserver = DefaultObserver>(PlanckianTable<C>);
 
 impl<C: StandardObserver> Ohno2014Cascade<C> {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /**
+        Cascade method, with a 15% step start table covering correlated color temperatures from `start` to `end`, in
+        Kelvin. Outside this range `f64::NAN` values are reported.
+    */
+    pub fn with_range(start: f64, end: f64) -> Self {
+        Self(PlanckianTable::cached(
+            CctLadder::try_new(ladder_start(start, 1.15), end * 1.15 * 1.15, 1.0 + 0.15)
+                .expect("start temperature should be positive, and less than the end temperature"),
+        ))
+    }
+}
+
+/// Start of a table, at least one step below `start`, with its temperatures on the same multiplicative scale as
+/// Ohno's tables, starting at 1000K.
+fn ladder_start(start: f64, mul: f64) -> f64 {
+    1000.0 * mul.powf(((start / 1000.0).ln() / mul.ln()).floor() - 1.0)
 }
 
 impl<C> Default for Ohno2014Cascade<C>
@@ -622,8 +1503,7 @@ where
     C: StandardObserver,
 {
     fn default() -> Self {
-        let pt = PlanckianTable::<C>::new(Some(CctLadder::new(1000.0, 32000.0, 1.0 + 0.15)));
-        Self(pt)
+        Self::with_range(OHNO_CASCADE_CCT_MIN, OHNO_CASCADE_CCT_MAX)
     }
 }
 
@@ -767,3 +1647,179 @@ fn test_ohno_cascade_from_cctduv() {
     let td_calc = oc.cct_duv(yuv);
     assert_abs_diff_eq!(tds, td_calc, epsilon = (5E-3, 1E-8));
 }
+
+#[test]
+fn test_ohno_range() {
+    use crate::illuminants::{CctDuvCalc, Ohno2014, Ohno2014Cascade};
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let tds: CctDuv<CieObs1931> = CctDuv::new(vec![
+        [500.0, 0.0],
+        [600.0, -0.01],
+        [1800.0, 0.02],
+        [25000.0, 0.01],
+        [50000.0, -0.01],
+        [100_000.0, 0.0],
+    ]);
+    let yuv: CieYuv1960<_> = tds.clone().into();
+    let oc: Ohno2014Cascade<CieObs1931> = Ohno2014Cascade::default();
+    assert_abs_diff_eq!(oc.cct_duv(yuv.clone()), tds, epsilon = (5E-2, 1E-7));
+
+    // 1% steps are less accurate, in particular at high temperatures, where the steps are large
+    let ohno = Ohno2014::<CieObs1931>::with_range(500.0, 100_000.0);
+    for (td, tdc) in tds.clone().into_iter().zip(ohno.cct_duv(yuv)) {
+        assert_abs_diff_eq!(td.t, tdc.t, epsilon = td.t * 2E-4);
+        assert_abs_diff_eq!(td.d, tdc.d, epsilon = 1E-5);
+    }
+
+    // outside the range of the tables, without panics
+    let yuv: CieYuv1960<CieObs1931> = CctDuv::new(vec![[400.0, 0.0], [200_000.0, 0.0]]).into();
+    for td in oc.cct_duv(yuv.clone()) {
+        assert!(td.t.is_nan() && td.d.is_nan());
+    }
+    for td in Ohno2014::<CieObs1931>::new().cct_duv(yuv) {
+        assert!(td.t.is_nan() && td.d.is_nan());
+    }
+}
+
+#[test]
+fn test_try_cct_duv() {
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let tds: CctDuv<CieObs1931> = CctDuv::new(vec![
+        [900.0, 0.0],
+        [3000.0, 0.06],
+        [6500.0, -0.01],
+        [25000.0, 0.0],
+    ]);
+    let yuv: CieYuv1960<_> = tds.into();
+
+    let r = Robertson::<CieObs1931>::new().try_cct_duv(yuv.clone());
+    assert_eq!(r[0], Err(CctError::NoCrossing));
+    match r[1] {
+        Err(CctError::DuvTooLarge { t, d }) => {
+            assert_abs_diff_eq!(t, 3000.0, epsilon = 20.0);
+            assert_abs_diff_eq!(d, 0.06, epsilon = 1E-3);
+        }
+        _ => panic!("{:?}", r[1]),
+    }
+    assert_abs_diff_eq!(r[2].unwrap().t, 6500.0, epsilon = 5.0);
+    assert!(r[3].is_ok());
+
+    let r = Ohno2014::<CieObs1931>::new().try_cct_duv(yuv.clone());
+    assert_eq!(r[0], Err(CctError::OutOfRange));
+    assert!(matches!(r[1], Err(CctError::DuvTooLarge { .. })));
+    assert!(r[2].is_ok());
+    assert_eq!(r[3], Err(CctError::OutOfRange));
+
+    let r = CctNewton::new(Ohno2014Cascade::<CieObs1931>::new()).try_cct_duv(yuv.clone());
+    assert_abs_diff_eq!(r[0].unwrap().t, 900.0, epsilon = 1E-3);
+    match r[1] {
+        Err(CctError::DuvTooLarge { t, d }) => {
+            assert_abs_diff_eq!(t, 3000.0, epsilon = 5E-3);
+            assert_abs_diff_eq!(d, 0.06, epsilon = 1E-8);
+        }
+        _ => panic!("{:?}", r[1]),
+    }
+    let r = CctNewton::new(Ohno2014Cascade::<CieObs1931>::new())
+        .set_max_iterations(1)
+        .try_cct_duv(yuv);
+    assert!(matches!(
+        r[2],
+        Err(CctError::NoConvergence { iterations: 1, .. })
+    ));
+}
+
+#[test]
+fn test_cct_duv_chromaticity() {
+    use crate::illuminants::CieIllA;
+    use crate::models::CieYuv1960;
+    use crate::observers::CieObs1931;
+    use crate::SpectralDistribution;
+    use approx::assert_abs_diff_eq;
+
+    // chromaticity of CIE illuminant A, with a temperature of 2856K, on the Planckian locus
+    let tds: CctDuv<CieObs1931> = CctDuv::new(vec![[2856.0, 0.0], [5000.0, 0.01]]);
+    let xy = tds.xy();
+    assert_abs_diff_eq!(xy.data[(1, 0)], 0.44757, epsilon = 5E-5);
+    assert_abs_diff_eq!(xy.data[(2, 0)], 0.40745, epsilon = 5E-5);
+    let yxy_a: CieYxy<CieObs1931> = CieIllA.into();
+    assert_abs_diff_eq!(xy.data[(1, 0)], yxy_a.data[(1, 0)], epsilon = 5E-5);
+    assert_abs_diff_eq!(xy.data[(2, 0)], yxy_a.data[(2, 0)], epsilon = 5E-5);
+
+    let uv: CieYuv1960<CieObs1931> = tds.uv();
+    let uvp = tds.uv_prime();
+    assert_abs_diff_eq!(uvp.data.row(1), uv.data.row(1), epsilon = 1E-15);
+    assert_abs_diff_eq!(
+        uvp.data.row(2) / 1.5,
+        uv.data.row(2).into_owned(),
+        epsilon = 1E-15
+    );
+
+    let (_, m) = tds.reference(ReferenceSelection::Cri).spd();
+    assert_eq!(m.ncols(), 2);
+}
+
+#[test]
+fn test_planckian_table_cache() {
+    use crate::observers::{CieObs1931, CieObs1964};
+
+    let a = Ohno2014::<CieObs1931>::new();
+    let b = Ohno2014::<CieObs1931>::default();
+    assert!(Arc::ptr_eq(&a.0 .1, &b.0 .1));
+    let c = Ohno2014::<CieObs1964>::new();
+    assert!(!Arc::ptr_eq(&a.0 .1, &c.0 .1));
+    let d = Ohno2014::<CieObs1931>::with_range(500.0, 100_000.0);
+    assert!(!Arc::ptr_eq(&a.0 .1, &d.0 .1));
+    assert!(Arc::ptr_eq(
+        &Ohno2014Cascade::<CieObs1931>::new().0 .0,
+        &Ohno2014Cascade::<CieObs1931>::new().0 .0
+    ));
+}
+
+#[test]
+fn test_duv_metric() {
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let tds: CctDuv<CieObs1931> =
+        CctDuv::new(vec![[2700.0, 0.003], [6500.0, -0.002], [4000.0, 0.0]]);
+    assert_eq!(tds.cct()[1], 6500.0);
+    assert_eq!(tds.duv(DuvMetric::default()), tds.0.row(1));
+    assert_abs_diff_eq!(tds.duv(DuvMetric::Tint)[0], 3.0, epsilon = 1E-12);
+
+    // the u'v' distance is between 1 and 1.5 times the uv distance, with the same sign
+    let d76 = tds.duv(DuvMetric::UvPrime1976);
+    for (a, b) in d76.iter().zip(tds.0.row(1).iter()) {
+        assert!(a.abs() >= b.abs() * (1.0 - 1E-9) && a.abs() <= 1.5 * b.abs() * (1.0 + 1E-9));
+        assert!(a * b >= 0.0);
+    }
+    assert!(d76[2].abs() < 1E-12);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_cct_duv_batch() {
+    use crate::observers::CieObs1931;
+
+    let tds: CctDuv<CieObs1931> = CctDuv::new(
+        (0..600)
+            .map(|i| {
+                [
+                    2000.0 + 20.0 * i as f64,
+                    0.04 * ((i % 11) as f64 / 5.0 - 1.0),
+                ]
+            })
+            .collect(),
+    );
+    let yuv: CieYuv1960<CieObs1931> = tds.uv();
+    let ohno = Ohno2014::<CieObs1931>::new();
+    assert_eq!(
+        ohno.cct_duv_batch(CieYuv1960::new(yuv.data.clone())),
+        ohno.cct_duv(CieYuv1960::new(yuv.data.clone()))
+    );
+    let r = Robertson::<CieObs1931>::new().cct_duv_batch(yuv);
+    assert_eq!(r.len(), 600);
+}
@@ -0,0 +1,130 @@
+use nalgebra::DMatrix;
+
+use crate::illuminants::Illuminant;
+use crate::models::CieXYZ;
+use crate::observers::StandardObserver;
+use crate::{Domain, SpectralDistribution, WavelengthStep};
+
+/**
+Spectral distributions of one or more illuminants, loaded at runtime.
+
+Use this to work with lamp measurements, as read from a file, instead of defining a type with the `illuminant!`
+macro. Each column in the data matrix holds a spectral distribution, on a wavelength domain, and can be identified by
+an optional key.
+
+When used as an illuminant type parameter, for example in `CieLab<IlluminantData, CieObs1931>`, its default value, the
+equal energy illuminant E, is used as reference white.
+
+# Examples
+```
+use nalgebra::DMatrix;
+use scot::illuminants::IlluminantData;
+use scot::models::CieYxy;
+use scot::observers::CieObs1931;
+use scot::{Domain, SpectralDistribution, NM5};
+
+let d = Domain::new(380 / 5, 780 / 5, NM5);
+let m = DMatrix::from_fn(d.len(), 2, |i, j| 50.0 + (i * (j + 1)) as f64);
+let lamps = IlluminantData::new(d, m).set_keys(&["lamp 1", "lamp 2"]);
+let yxy: CieYxy<CieObs1931> = lamps.into();
+assert_eq!(yxy.data.ncols(), 2);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct IlluminantData {
+    domain: Domain<WavelengthStep>,
+    data: DMatrix<f64>,
+    keys: Option<Vec<String>>,
+    description: Option<String>,
+}
+
+impl IlluminantData {
+    pub fn new(domain: Domain<WavelengthStep>, data: DMatrix<f64>) -> Self {
+        assert!(
+            domain.len() == data.nrows(),
+            "data length does not match domain"
+        );
+        Self {
+            domain,
+            data,
+            keys: None,
+            description: None,
+        }
+    }
+
+    /// Sets the keys, one for each of the spectral distributions in the collection.
+    pub fn set_keys(mut self, keys: &[&str]) -> Self {
+        assert!(
+            keys.len() == self.data.ncols(),
+            "number of keys does not match the number of spectra"
+        );
+        self.keys = Some(keys.iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    pub fn set_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn data(&self) -> &DMatrix<f64> {
+        &self.data
+    }
+}
+
+impl Default for IlluminantData {
+    fn default() -> Self {
+        let domain = Domain::default();
+        let data = DMatrix::from_element(domain.len(), 1, 1.0);
+        Self::new(domain, data).set_description("Equal Energy Illuminant")
+    }
+}
+
+impl SpectralDistribution for IlluminantData {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        (self.domain.clone(), self.data.clone())
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.data.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        self.keys.clone()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+}
+
+impl Illuminant for IlluminantData {}
+
+impl<C: StandardObserver> From<IlluminantData> for CieXYZ<C> {
+    fn from(ill: IlluminantData) -> Self {
+        ill.xyz().normalize(100.0)
+    }
+}
+
+#[test]
+fn test_illuminant_data() {
+    use crate::illuminants::CieIllD65;
+    use crate::models::CieYxy;
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let (d, m) = CieIllD65.spd();
+    let d65 = IlluminantData::new(d, DMatrix::from_column_slice(m.nrows(), 1, m.as_slice()))
+        .set_keys(&["D65"]);
+    assert_eq!(d65.keys().unwrap(), vec!["D65".to_string()]);
+
+    let yxy: CieYxy<CieObs1931> = d65.into();
+    let yxy_ref: CieYxy<CieObs1931> = CieIllD65.into();
+    assert_abs_diff_eq!(yxy.data, yxy_ref.data, epsilon = 1E-10);
+
+    let e: CieYxy<CieObs1931> = IlluminantData::default().into();
+    assert_abs_diff_eq!(e.data[(1, 0)], 1.0 / 3.0, epsilon = 1E-4);
+}
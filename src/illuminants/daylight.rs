@@ -40,16 +40,21 @@ CIE D illumiant algorithm. To recreate the values as originally defined, the fol
 
 # CIE D Illuminant, for aribitrary CCTs in the range from 4000 to 25_000 Kelvin.
 
+Correlated color temperatures outside this range are clamped to its limits, unless the `CieDaylight` illuminant is set
+to its extended mode, in which the CIE daylight locus, and its basis functions, are extrapolated down to 2500K.
+This is not part of the CIE recommendation, and is only intended to simulate the color of daylight at dawn and dusk;
+use `CieDaylight::extrapolated` to check which of the spectral distributions are outside the CIE range.
+
  */
 
-use nalgebra::{Const, Dynamic, Matrix, Matrix3xX, SMatrixSlice, VecStorage};
+use nalgebra::{Const, DVector, Dynamic, Matrix, Matrix3xX, SMatrixSlice, VecStorage};
 
 use crate::illuminants::cct_parameters::CctParameters;
 use crate::models::CieXYZ;
 use crate::observers::StandardObserver;
 use crate::Domain;
 use crate::SpectralDistribution;
-use crate::{WavelengthStep, NM5};
+use crate::{WavelengthStep, NM, NM5};
 
 use super::Illuminant;
 
@@ -59,15 +64,44 @@ pub type D65 = CieIllD65;
 pub type D75 = CieIllD75;
 pub type CieD = CieDaylight;
 
+/// Lower correlated color temperature limit, in Kelvin, of `CieDaylight` in its extended mode.
+pub const CIE_DAYLIGHT_EXTENDED_MIN: f64 = 2500.0;
+
 #[derive(Debug, Clone)]
 pub struct CieDaylight {
     pub ccts: CctParameters,
+    pub extended: bool,
 }
 
 impl CieDaylight {
     pub fn new(parameters: impl Into<CctParameters>) -> CieDaylight {
         CieDaylight {
             ccts: parameters.into(),
+            extended: false,
+        }
+    }
+
+    /// Extrapolates the CIE daylight locus for correlated color temperatures from 2500 to 4000K, instead of
+    /// clamping these to 4000K.
+    pub fn set_extended(mut self, extended: bool) -> Self {
+        self.extended = extended;
+        self
+    }
+
+    /// For each of the correlated color temperatures, true if its spectral distribution is extrapolated, outside
+    /// the range of the CIE daylight illuminant.
+    pub fn extrapolated(&self) -> Vec<bool> {
+        (&self.ccts)
+            .into_iter()
+            .map(|t| self.extended && self.cct(t) < 4000.0)
+            .collect()
+    }
+
+    fn cct(&self, t: f64) -> f64 {
+        if self.extended {
+            t.clamp(CIE_DAYLIGHT_EXTENDED_MIN, 25000.0)
+        } else {
+            t.clamp(4000.0, 25000.0)
         }
     }
 }
@@ -91,7 +125,7 @@ impl SpectralDistribution for CieDaylight {
     fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
         let mut mvec: Vec<f64> = Vec::with_capacity(3 * N);
         for t in &self.ccts {
-            let cct = t.clamp(4000.0, 25000.0);
+            let cct = self.cct(t);
             let xd = match cct {
                 t if t < 7000.0 => {
                     0.244063 + 0.09911E3 / t + 2.9678E6 / t.powi(2) - 4.607E9 / t.powi(3)
@@ -273,6 +307,282 @@ fn test_d65() {
     assert_abs_diff_eq!(y, 0.32903, epsilon = 1E-5); // CIE 15:2004, Table T.3. D65 y value
 }
 
+#[test]
+fn test_daylight_extended() {
+    use crate::models::CieYxy;
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    // clamped to 4000K, unless extended
+    let (_, clamped) = CieDaylight::new(3000.0).spd();
+    let (_, v4000) = CieDaylight::new(4000.0).spd();
+    assert_eq!(clamped, v4000);
+    assert_eq!(CieDaylight::new(3000.0).extrapolated(), vec![false]);
+
+    let d = CieDaylight::new(vec![3000.0, 5000.0]).set_extended(true);
+    assert_eq!(d.extrapolated(), vec![true, false]);
+    let yxy: CieYxy<CieObs1931> = CieDaylight::new(3000.0).set_extended(true).into();
+    assert_abs_diff_eq!(yxy.data.column(0).y, 0.4362, epsilon = 1E-3); // extrapolated daylight locus
+    assert_abs_diff_eq!(yxy.data.column(0).z, 0.4061, epsilon = 1E-3);
+}
+
+/**
+   D50 and D65 tables in steps of 1nm, from 300 to 830nm, as used in ISO/CIE 11664-2.
+
+   These are obtained by linear interpolation of the 5nm CIE 15 tables, extended to 830nm.
+   Use these when results have to agree with the ISO reference values, which are calculated with the observers'
+   color matching functions at 1nm resolution.
+*/
+const NDATA1: usize = 531;
+
+illuminant!(
+    CieIllD50Iso,
+    NDATA1,
+    "CIE D50 Illuminant, 1nm",
+    Domain::new(300, 830, NM),
+    D50_1NM_DATA
+);
+
+static D50_1NM_DATA: [f64; NDATA1] = [
+    0.019, 0.2222, 0.4254, 0.6286, 0.8318, 1.035, 1.2382, 1.4414, 1.6446, 1.8478, 2.051, 2.6236,
+    3.1962, 3.7688, 4.3414, 4.914, 5.4868, 6.0596, 6.6324, 7.2052, 7.778, 8.475, 9.172, 9.869,
+    10.566, 11.263, 11.96, 12.657, 13.354, 14.051, 14.748, 15.068, 15.388, 15.708, 16.028, 16.348,
+    16.668, 16.988, 17.308, 17.628, 17.948, 18.2542, 18.5604, 18.8666, 19.1728, 19.479, 19.7852,
+    20.0914, 20.3976, 20.7038, 21.01, 21.3032, 21.5964, 21.8896, 22.1828, 22.476, 22.7692, 23.0624,
+    23.3556, 23.6488, 23.942, 24.2438, 24.5456, 24.8474, 25.1492, 25.451, 25.753, 26.055, 26.357,
+    26.659, 26.961, 26.7136, 26.4662, 26.2188, 25.9714, 25.724, 25.4768, 25.2296, 24.9824, 24.7352,
+    24.488, 25.0262, 25.5644, 26.1026, 26.6408, 27.179, 27.7174, 28.2558, 28.7942, 29.3326, 29.871,
+    31.8146, 33.7582, 35.7018, 37.6454, 39.589, 41.5328, 43.4766, 45.4204, 47.3642, 49.308,
+    50.0284, 50.7488, 51.4692, 52.1896, 52.91, 53.6306, 54.3512, 55.0718, 55.7924, 56.513, 56.865,
+    57.217, 57.569, 57.921, 58.273, 58.6252, 58.9774, 59.3296, 59.6818, 60.034, 59.8124, 59.5908,
+    59.3692, 59.1476, 58.926, 58.7044, 58.4828, 58.2612, 58.0396, 57.818, 59.5186, 61.2192,
+    62.9198, 64.6204, 66.321, 68.0218, 69.7226, 71.4234, 73.1242, 74.825, 76.0672, 77.3094,
+    78.5516, 79.7938, 81.036, 82.2782, 83.5204, 84.7626, 86.0048, 87.247, 87.5836, 87.9202,
+    88.2568, 88.5934, 88.93, 89.2664, 89.6028, 89.9392, 90.2756, 90.612, 90.6876, 90.7632, 90.8388,
+    90.9144, 90.99, 91.0656, 91.1412, 91.2168, 91.2924, 91.368, 91.742, 92.116, 92.49, 92.864,
+    93.238, 93.6122, 93.9864, 94.3606, 94.7348, 95.109, 94.7944, 94.4798, 94.1652, 93.8506, 93.536,
+    93.2214, 92.9068, 92.5922, 92.2776, 91.963, 92.339, 92.715, 93.091, 93.467, 93.843, 94.2192,
+    94.5954, 94.9716, 95.3478, 95.724, 95.813, 95.902, 95.991, 96.08, 96.169, 96.2578, 96.3466,
+    96.4354, 96.5242, 96.613, 96.6646, 96.7162, 96.7678, 96.8194, 96.871, 96.9226, 96.9742,
+    97.0258, 97.0774, 97.129, 97.626, 98.123, 98.62, 99.117, 99.614, 100.111, 100.608, 101.105,
+    101.602, 102.099, 101.9646, 101.8302, 101.6958, 101.5614, 101.427, 101.2926, 101.1582,
+    101.0238, 100.8894, 100.755, 100.9112, 101.0674, 101.2236, 101.3798, 101.536, 101.6922,
+    101.8484, 102.0046, 102.1608, 102.317, 102.0854, 101.8538, 101.6222, 101.3906, 101.159,
+    100.9272, 100.6954, 100.4636, 100.2318, 100.0, 99.7736, 99.5472, 99.3208, 99.0944, 98.868,
+    98.6414, 98.4148, 98.1882, 97.9616, 97.735, 97.8534, 97.9718, 98.0902, 98.2086, 98.327,
+    98.4452, 98.5634, 98.6816, 98.7998, 98.918, 98.376, 97.834, 97.292, 96.75, 96.208, 95.6662,
+    95.1244, 94.5826, 94.0408, 93.499, 93.9178, 94.3366, 94.7554, 95.1742, 95.593, 96.012, 96.431,
+    96.85, 97.269, 97.688, 97.846, 98.004, 98.162, 98.32, 98.478, 98.6362, 98.7944, 98.9526,
+    99.1108, 99.269, 99.2462, 99.2234, 99.2006, 99.1778, 99.155, 99.1324, 99.1098, 99.0872,
+    99.0646, 99.042, 98.71, 98.378, 98.046, 97.714, 97.382, 97.05, 96.718, 96.386, 96.054, 95.722,
+    96.0356, 96.3492, 96.6628, 96.9764, 97.29, 97.6034, 97.9168, 98.2302, 98.5436, 98.857, 98.538,
+    98.219, 97.9, 97.581, 97.262, 96.943, 96.624, 96.305, 95.986, 95.667, 95.9194, 96.1718,
+    96.4242, 96.6766, 96.929, 97.1812, 97.4334, 97.6856, 97.9378, 98.19, 98.6714, 99.1528, 99.6342,
+    100.1156, 100.597, 101.0782, 101.5594, 102.0406, 102.5218, 103.003, 102.616, 102.229, 101.842,
+    101.455, 101.068, 100.681, 100.294, 99.907, 99.52, 99.133, 97.9578, 96.7826, 95.6074, 94.4322,
+    93.257, 92.0818, 90.9066, 89.7314, 88.5562, 87.381, 87.8032, 88.2254, 88.6476, 89.0698, 89.492,
+    89.9144, 90.3368, 90.7592, 91.1816, 91.604, 91.7324, 91.8608, 91.9892, 92.1176, 92.246,
+    92.3746, 92.5032, 92.6318, 92.7604, 92.889, 91.2856, 89.6822, 88.0788, 86.4754, 84.872,
+    83.2684, 81.6648, 80.0612, 78.4576, 76.854, 77.8198, 78.7856, 79.7514, 80.7172, 81.683,
+    82.6486, 83.6142, 84.5798, 85.5454, 86.511, 87.118, 87.725, 88.332, 88.939, 89.546, 90.1528,
+    90.7596, 91.3664, 91.9732, 92.58, 91.145, 89.71, 88.275, 86.84, 85.405, 83.97, 82.535, 81.1,
+    79.665, 78.23, 76.1762, 74.1224, 72.0686, 70.0148, 67.961, 65.9072, 63.8534, 61.7996, 59.7458,
+    57.692, 60.215, 62.738, 65.261, 67.784, 70.307, 72.8302, 75.3534, 77.8766, 80.3998, 82.923,
+    82.4582, 81.9934, 81.5286, 81.0638, 80.599, 80.134, 79.669, 79.204, 78.739, 78.274, 78.402,
+    78.53, 78.658, 78.786, 78.914, 79.042, 79.17, 79.298, 79.426, 79.554, 78.9388, 78.3236,
+    77.7084, 77.0932, 76.478, 75.8626, 75.2472, 74.6318, 74.0164, 73.401, 72.453, 71.505, 70.557,
+    69.609, 68.661, 67.7128, 66.7646, 65.8164, 64.8682, 63.92, 64.6058, 65.2916, 65.9774, 66.6632,
+    67.349, 68.0346, 68.7202, 69.4058, 70.0914, 70.777, 71.1434, 71.5098, 71.8762, 72.2426, 72.609,
+    72.9756, 73.3422, 73.7088, 74.0754, 74.442,
+];
+
+illuminant_single_test!(test_d50_iso, CieIllD50Iso, 0.34567, 5E-5, 0.35851, 5E-5);
+
+illuminant!(
+    CieIllD65Iso,
+    NDATA1,
+    "CIE D65 Illuminant, 1nm",
+    Domain::new(300, 830, NM),
+    D65_1NM_DATA
+);
+
+static D65_1NM_DATA: [f64; NDATA1] = [
+    0.0341, 0.36014, 0.68618, 1.01222, 1.33826, 1.6643, 1.99034, 2.31638, 2.64242, 2.96846, 3.2945,
+    4.98864, 6.68278, 8.37692, 10.07106, 11.7652, 13.45936, 15.15352, 16.84768, 18.54184, 20.236,
+    21.91774, 23.59948, 25.28122, 26.96296, 28.6447, 30.32646, 32.00822, 33.68998, 35.37174,
+    37.0535, 37.34302, 37.63254, 37.92206, 38.21158, 38.5011, 38.79064, 39.08018, 39.36972,
+    39.65926, 39.9488, 40.44508, 40.94136, 41.43764, 41.93392, 42.4302, 42.9265, 43.4228, 43.9191,
+    44.4154, 44.9117, 45.08436, 45.25702, 45.42968, 45.60234, 45.775, 45.94766, 46.12032, 46.29298,
+    46.46564, 46.6383, 47.18338, 47.72846, 48.27354, 48.81862, 49.3637, 49.90878, 50.45386,
+    50.99894, 51.54402, 52.0891, 51.87774, 51.66638, 51.45502, 51.24366, 51.0323, 50.82094,
+    50.60958, 50.39822, 50.18686, 49.9755, 50.44276, 50.91002, 51.37728, 51.84454, 52.3118,
+    52.77908, 53.24636, 53.71364, 54.18092, 54.6482, 57.45886, 60.26952, 63.08018, 65.89084,
+    68.7015, 71.51218, 74.32286, 77.13354, 79.94422, 82.7549, 83.628, 84.5011, 85.3742, 86.2473,
+    87.1204, 87.99352, 88.86664, 89.73976, 90.61288, 91.486, 91.68058, 91.87516, 92.06974,
+    92.26432, 92.4589, 92.65348, 92.84806, 93.04264, 93.23722, 93.4318, 92.75684, 92.08188,
+    91.40692, 90.73196, 90.057, 89.38206, 88.70712, 88.03218, 87.35724, 86.6823, 88.50056,
+    90.31882, 92.13708, 93.95534, 95.7736, 97.59188, 99.41016, 101.22844, 103.04672, 104.865,
+    106.0792, 107.2934, 108.5076, 109.7218, 110.936, 112.1504, 113.3648, 114.5792, 115.7936,
+    117.008, 117.0884, 117.1688, 117.2492, 117.3296, 117.41, 117.4904, 117.5708, 117.6512,
+    117.7316, 117.812, 117.5168, 117.2216, 116.9264, 116.6312, 116.336, 116.041, 115.746, 115.451,
+    115.156, 114.861, 114.9672, 115.0734, 115.1796, 115.2858, 115.392, 115.4982, 115.6044,
+    115.7106, 115.8168, 115.923, 115.2118, 114.5006, 113.7894, 113.0782, 112.367, 111.6558,
+    110.9446, 110.2334, 109.5222, 108.811, 108.8652, 108.9194, 108.9736, 109.0278, 109.082,
+    109.1364, 109.1908, 109.2452, 109.2996, 109.354, 109.1988, 109.0436, 108.8884, 108.7332,
+    108.578, 108.4228, 108.2676, 108.1124, 107.9572, 107.802, 107.5008, 107.1996, 106.8984,
+    106.5972, 106.296, 105.9948, 105.6936, 105.3924, 105.0912, 104.79, 105.0798, 105.3696,
+    105.6594, 105.9492, 106.239, 106.529, 106.819, 107.109, 107.399, 107.689, 107.3606, 107.0322,
+    106.7038, 106.3754, 106.047, 105.7186, 105.3902, 105.0618, 104.7334, 104.405, 104.369, 104.333,
+    104.297, 104.261, 104.225, 104.1892, 104.1534, 104.1176, 104.0818, 104.046, 103.6414, 103.2368,
+    102.8322, 102.4276, 102.023, 101.6184, 101.2138, 100.8092, 100.4046, 100.0, 99.63342, 99.26684,
+    98.90026, 98.53368, 98.1671, 97.80052, 97.43394, 97.06736, 96.70078, 96.3342, 96.27958,
+    96.22496, 96.17034, 96.11572, 96.0611, 96.00648, 95.95186, 95.89724, 95.84262, 95.788,
+    95.07776, 94.36752, 93.65728, 92.94704, 92.2368, 91.52656, 90.81632, 90.10608, 89.39584,
+    88.6856, 88.81766, 88.94972, 89.08178, 89.21384, 89.3459, 89.47796, 89.61002, 89.74208,
+    89.87414, 90.0062, 89.96548, 89.92476, 89.88404, 89.84332, 89.8026, 89.7619, 89.7212, 89.6805,
+    89.6398, 89.5991, 89.40906, 89.21902, 89.02898, 88.83894, 88.6489, 88.45886, 88.26882,
+    88.07878, 87.88874, 87.6987, 87.25768, 86.81666, 86.37564, 85.93462, 85.4936, 85.0526, 84.6116,
+    84.1706, 83.7296, 83.2886, 83.32966, 83.37072, 83.41178, 83.45284, 83.4939, 83.53496, 83.57602,
+    83.61708, 83.65814, 83.6992, 83.33196, 82.96472, 82.59748, 82.23024, 81.863, 81.49576,
+    81.12852, 80.76128, 80.39404, 80.0268, 80.04558, 80.06436, 80.08314, 80.10192, 80.1207,
+    80.13948, 80.15826, 80.17704, 80.19582, 80.2146, 80.42092, 80.62724, 80.83356, 81.03988,
+    81.2462, 81.45252, 81.65884, 81.86516, 82.07148, 82.2778, 81.87844, 81.47908, 81.07972,
+    80.68036, 80.281, 79.88164, 79.48228, 79.08292, 78.68356, 78.2842, 77.4279, 76.5716, 75.7153,
+    74.859, 74.0027, 73.14642, 72.29014, 71.43386, 70.57758, 69.7213, 69.91008, 70.09886, 70.28764,
+    70.47642, 70.6652, 70.85398, 71.04276, 71.23154, 71.42032, 71.6091, 71.88308, 72.15706,
+    72.43104, 72.70502, 72.979, 73.253, 73.527, 73.801, 74.075, 74.349, 73.0745, 71.8, 70.5255,
+    69.251, 67.9765, 66.702, 65.4275, 64.153, 62.8785, 61.604, 62.43216, 63.26032, 64.08848,
+    64.91664, 65.7448, 66.57296, 67.40112, 68.22928, 69.05744, 69.8856, 70.40574, 70.92588,
+    71.44602, 71.96616, 72.4863, 73.00644, 73.52658, 74.04672, 74.56686, 75.087, 73.93756,
+    72.78812, 71.63868, 70.48924, 69.3398, 68.19038, 67.04096, 65.89154, 64.74212, 63.5927,
+    61.87524, 60.15778, 58.44032, 56.72286, 55.0054, 53.28796, 51.57052, 49.85308, 48.13564,
+    46.4182, 48.45692, 50.49564, 52.53436, 54.57308, 56.6118, 58.65052, 60.68924, 62.72796,
+    64.76668, 66.8054, 66.46314, 66.12088, 65.77862, 65.43636, 65.0941, 64.75184, 64.40958,
+    64.06732, 63.72506, 63.3828, 63.47492, 63.56704, 63.65916, 63.75128, 63.8434, 63.93552,
+    64.02764, 64.11976, 64.21188, 64.304, 63.81878, 63.33356, 62.84834, 62.36312, 61.8779, 61.3927,
+    60.9075, 60.4223, 59.9371, 59.4519, 58.7026, 57.9533, 57.204, 56.4547, 55.7054, 54.95612,
+    54.20684, 53.45756, 52.70828, 51.959, 52.50716, 53.05532, 53.60348, 54.15164, 54.6998,
+    55.24796, 55.79612, 56.34428, 56.89244, 57.4406, 57.7278, 58.015, 58.3022, 58.5894, 58.8766,
+    59.16378, 59.45096, 59.73814, 60.02532, 60.3125,
+];
+
+illuminant_single_test!(test_d65_iso, CieIllD65Iso, 0.31272, 5E-5, 0.32903, 5E-5);
+
+/// Tabulation used for the D50 and D65 standard illuminants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaylightTable {
+    /// CIE 15 table, from 300 to 780nm, in steps of 5nm.
+    Cie5nm,
+    /// ISO/CIE 11664-2 table, from 300 to 830nm, in steps of 1nm.
+    Iso1nm,
+}
+
+/// D-series standard illuminants which are available in both tabulations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandardDaylight {
+    D50,
+    D65,
+}
+
+/**
+   D50 or D65 standard illuminant, with the tabulation selected at runtime.
+
+   # Examples
+   ```
+   use scot::illuminants::{CieIllDTabulated, DaylightTable, StandardDaylight};
+   use scot::models::CieYxy;
+   use scot::observers::CieObs1931;
+
+   let d65 = CieIllDTabulated::new(StandardDaylight::D65).set_table(DaylightTable::Iso1nm);
+   let yxy: CieYxy<CieObs1931> = d65.into();
+   println!("{}", yxy);
+   ```
+*/
+#[derive(Debug, Clone)]
+pub struct CieIllDTabulated {
+    pub illuminant: StandardDaylight,
+    pub table: DaylightTable,
+}
+
+impl CieIllDTabulated {
+    pub fn new(illuminant: StandardDaylight) -> Self {
+        Self {
+            illuminant,
+            table: DaylightTable::Cie5nm,
+        }
+    }
+
+    pub fn set_table(mut self, table: DaylightTable) -> Self {
+        self.table = table;
+        self
+    }
+
+    fn data(&self) -> &'static [f64] {
+        match (self.illuminant, self.table) {
+            (StandardDaylight::D50, DaylightTable::Cie5nm) => &D50_DATA,
+            (StandardDaylight::D65, DaylightTable::Cie5nm) => &D65_DATA,
+            (StandardDaylight::D50, DaylightTable::Iso1nm) => &D50_1NM_DATA,
+            (StandardDaylight::D65, DaylightTable::Iso1nm) => &D65_1NM_DATA,
+        }
+    }
+}
+
+impl Default for CieIllDTabulated {
+    fn default() -> Self {
+        Self::new(StandardDaylight::D65)
+    }
+}
+
+impl SpectralDistribution for CieIllDTabulated {
+    type MatrixType = DVector<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let domain = match self.table {
+            DaylightTable::Cie5nm => Domain::new(300 / 5, 780 / 5, NM5),
+            DaylightTable::Iso1nm => Domain::new(300, 830, NM),
+        };
+        (domain, DVector::from_column_slice(self.data()))
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.data().len(), 1)
+    }
+
+    fn description(&self) -> Option<String> {
+        let nm = match self.table {
+            DaylightTable::Cie5nm => 5,
+            DaylightTable::Iso1nm => 1,
+        };
+        Some(format!("CIE {:?} Illuminant, {}nm", self.illuminant, nm))
+    }
+}
+
+impl Illuminant for CieIllDTabulated {}
+
+impl<C: StandardObserver> From<CieIllDTabulated> for CieXYZ<C> {
+    fn from(d: CieIllDTabulated) -> Self {
+        d.xyz().normalize(100.0)
+    }
+}
+
+#[test]
+fn test_d_tables() {
+    use approx::assert_abs_diff_eq;
+
+    // the 1nm tables coincide with the 5nm tables, at multiples of 5nm
+    for (i, v) in D65_DATA.iter().enumerate() {
+        assert_abs_diff_eq!(D65_1NM_DATA[5 * i], v);
+        assert_abs_diff_eq!(D50_1NM_DATA[5 * i], D50_DATA[i]);
+    }
+
+    let d65 = CieIllDTabulated::default();
+    assert_eq!(d65.shape(), (NDATA, 1));
+    let d50 = CieIllDTabulated::new(StandardDaylight::D50).set_table(DaylightTable::Iso1nm);
+    assert_eq!(d50.spd().1.len(), NDATA1);
+    assert_eq!(d50.description().unwrap(), "CIE D50 Illuminant, 1nm");
+}
+
 illuminant!(
     CieIllD75,
     NDATA,
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use maplit::hashmap;
 
+use crate::blob::LazyBlob;
+use crate::include_blob;
 use crate::Domain;
 use crate::NM5;
 
@@ -23,7 +25,7 @@ illuminant!(
     M3,
     "CIE F3.{}",
     Domain::new(380 / 5, 780 / 5, NM5),
-    CIE_FL3_DATA
+    CIE_FL3_DATA.data()
 );
 
 #[test]
@@ -66,6 +68,28 @@ fn test_f() {
     f3test!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
 }
 
+#[test]
+fn test_fl_nth() {
+    use crate::models::CieYxy;
+    use crate::observers::CieObs1931;
+    use crate::SpectralDistribution;
+
+    let f2: CieYxy<CieObs1931> = FL::nth(2).unwrap().into();
+    let f2_ref: CieYxy<CieObs1931> = FL::<2>.into();
+    assert_eq!(f2.data.rows(1, 2), f2_ref.data.rows(1, 2));
+    assert_eq!(FL3::nth(15).unwrap().keys().unwrap(), vec!["CIE F3.15"]);
+
+    let err = FL::nth(13).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "illuminant index 13 out of range, expected 1 to 12"
+    );
+    assert!(FL3::nth(0).is_err());
+
+    assert_eq!(FL::iter().count(), FL::LEN);
+    assert_eq!(FL3::iter().count(), M3);
+}
+
 pub static FLTEST: [[f64; 4]; 12] = [
     [0.3131, 0.3371, 6430.0, 76.0], // x, y, CCT, CRI
     [0.3721, 0.3751, 4230.0, 64.0],
@@ -242,102 +266,13 @@ pub static CIE_FL_DATA: [f64; N * M] = [
     0.19, 0.15, 0.1, 0.05,
 ];
 
-static CIE_FL3_DATA: [f64; N * M3] = [
-    /* 1 */
-    2.39, 2.93, 3.82, 4.23, 4.97, 86.3, 11.65, 7.09, 7.84, 8.59, 9.44, 196.54, 10.94, 11.38, 11.89,
-    12.37, 12.81, 13.15, 13.39, 13.56, 13.59, 13.56, 14.07, 13.39, 13.29, 13.25, 13.53, 14.24,
-    15.74, 18.26, 22.28, 27.97, 35.7, 148.98, 56.55, 68.68, 79.99, 91.47, 101.32, 123.16, 129.53,
-    115.05, 113.48, 110.08, 104.28, 97.98, 89.6, 80.74, 71.92, 63.5, 55.46, 47.97, 41.39, 35.5,
-    30.32, 25.79, 21.84, 18.53, 15.67, 13.22, 11.14, 9.4, 8.65, 6.75, 5.69, 4.87, 4.29, 3.54, 3.03,
-    2.62, 2.28, 1.94, 1.7, 1.5, 1.36, 1.16, 4.91, 0.95, 1.5, 0.89, 0.68, /* 2 */
-    5.8, 6.99, 8.7, 9.89, 11.59, 94.53, 20.8, 16.52, 18.3, 20.33, 22.0, 231.9, 25.81, 27.63, 29.1,
-    30.61, 31.92, 33.11, 33.83, 34.7, 35.02, 35.22, 35.81, 35.14, 35.14, 34.9, 34.7, 35.02, 36.13,
-    37.92, 40.62, 44.7, 49.63, 154.16, 62.21, 68.92, 75.83, 81.95, 86.95, 103.54, 109.94, 91.95,
-    89.85, 87.15, 83.26, 78.93, 73.93, 68.84, 63.44, 58.84, 53.84, 49.43, 45.54, 41.53, 38.31,
-    34.62, 31.8, 29.02, 26.72, 24.22, 22.19, 20.41, 19.1, 16.79, 15.13, 13.82, 12.63, 11.39, 10.32,
-    9.21, 8.89, 7.5, 6.71, 6.11, 5.4, 4.8, 8.7, 4.01, 4.09, 3.3, 2.82, /* 3 */
-    8.94, 11.21, 14.08, 16.48, 19.63, 116.33, 32.07, 29.72, 33.39, 36.94, 40.33, 262.66, 46.87,
-    49.79, 52.46, 54.81, 56.81, 58.44, 59.52, 60.12, 60.24, 59.88, 59.88, 58.6, 57.85, 56.29,
-    54.81, 53.42, 52.7, 52.5, 53.3, 54.89, 57.61, 182.75, 65.27, 69.41, 73.28, 76.56, 78.67, 95.74,
-    97.22, 76.79, 73.36, 69.33, 64.23, 58.92, 53.38, 47.91, 42.61, 37.74, 33.11, 29.04, 25.29,
-    22.1, 19.31, 16.84, 14.68, 12.89, 11.37, 9.97, 8.82, 7.86, 7.78, 6.3, 5.67, 5.15, 4.91, 4.31,
-    3.99, 3.67, 3.43, 3.19, 2.95, 2.75, 2.63, 2.43, 7.14, 2.19, 2.71, 2.0, 1.8, /* 4 */
-    3.46, 3.86, 4.41, 4.51, 4.86, 71.22, 8.72, 5.36, 5.61, 5.91, 6.42, 192.77, 7.77, 8.37, 9.22,
-    10.18, 11.18, 12.28, 13.38, 14.54, 15.74, 17.09, 19.6, 21.05, 23.96, 27.77, 32.68, 38.29,
-    43.76, 47.72, 50.27, 51.78, 52.68, 167.36, 55.29, 56.94, 59.3, 62.15, 65.26, 84.26, 89.22,
-    75.79, 79.19, 82.8, 85.76, 88.62, 91.12, 93.43, 96.89, 101.45, 103.65, 100.3, 97.89, 96.59,
-    106.21, 109.97, 117.49, 96.04, 80.15, 70.42, 65.01, 60.15, 56.04, 50.92, 46.26, 42.6, 38.85,
-    35.09, 31.73, 28.77, 25.76, 23.16, 21.3, 18.55, 17.74, 14.74, 12.93, 13.63, 10.43, 9.67, 8.07,
-    /* 5 */
-    4.72, 5.82, 7.18, 8.39, 9.96, 58.86, 15.78, 15.1, 17.3, 19.66, 22.43, 176.0, 28.67, 31.92,
-    35.38, 38.73, 41.98, 44.92, 47.49, 49.58, 51.21, 52.36, 53.99, 53.78, 54.04, 53.88, 53.62,
-    53.25, 53.09, 52.88, 52.99, 53.15, 53.67, 167.93, 55.61, 56.82, 58.39, 60.22, 62.21, 81.45,
-    84.96, 68.71, 70.7, 73.01, 74.69, 76.26, 77.68, 78.67, 80.14, 81.71, 82.08, 79.98, 78.15,
-    76.52, 79.2, 79.51, 81.08, 70.76, 62.58, 56.87, 52.83, 49.11, 46.28, 42.24, 38.58, 35.59,
-    32.76, 29.61, 26.89, 24.53, 22.17, 20.02, 18.45, 16.09, 15.62, 13.1, 11.69, 12.42, 9.43, 8.96,
-    7.39, /* 6 */
-    5.53, 6.63, 8.07, 9.45, 11.28, 61.47, 17.8, 17.47, 20.12, 23.05, 26.37, 186.01, 33.94, 37.98,
-    42.12, 46.38, 50.3, 53.95, 56.94, 59.48, 61.36, 62.68, 64.34, 63.9, 63.85, 63.24, 62.46, 61.41,
-    60.47, 59.48, 58.65, 57.93, 57.49, 175.17, 57.27, 57.49, 57.99, 58.76, 59.64, 78.77, 81.26,
-    63.18, 64.29, 65.78, 66.77, 67.77, 68.6, 69.1, 70.15, 71.69, 71.97, 69.81, 68.05, 66.66, 69.7,
-    70.37, 72.47, 62.3, 54.45, 49.2, 45.6, 42.4, 40.02, 36.48, 33.28, 30.84, 28.3, 25.65, 23.33,
-    21.23, 19.29, 17.41, 16.31, 14.21, 14.04, 11.55, 10.39, 11.28, 8.51, 8.24, 7.02,
-    /* 7 */
-    3.79, 2.56, 1.91, 1.42, 1.51, 73.64, 7.37, 4.69, 5.33, 6.75, 8.51, 181.81, 11.71, 11.96, 12.18,
-    11.9, 11.16, 11.22, 9.83, 8.94, 12.08, 52.56, 55.42, 31.69, 16.03, 6.72, 4.59, 3.67, 3.02,
-    3.21, 4.9, 19.05, 177.64, 347.34, 116.8, 31.87, 16.37, 14.92, 14.12, 29.5, 61.4, 85.05, 64.86,
-    65.01, 53.17, 34.22, 427.27, 201.1, 58.63, 72.01, 88.19, 20.07, 13.1, 12.92, 24.54, 15.94,
-    13.56, 13.38, 8.42, 6.57, 7.18, 9.9, 11.47, 8.88, 3.05, 22.04, 42.79, 14.4, 1.88, 1.6, 1.42,
-    1.05, 1.23, 1.76, 0.74, 0.52, 4.1, 0.46, 0.99, 0.43, 0.0, /* 8 */
-    4.18, 2.93, 2.29, 1.98, 2.44, 70.7, 10.19, 9.79, 13.21, 17.79, 22.98, 191.43, 31.76, 33.35,
-    33.87, 32.89, 30.6, 28.28, 24.81, 21.6, 23.4, 68.99, 70.85, 42.29, 22.67, 11.08, 7.66, 6.07,
-    5.07, 4.88, 6.26, 20.29, 204.67, 390.25, 135.69, 34.57, 15.71, 12.6, 11.05, 25.05, 54.98,
-    82.84, 58.22, 53.06, 41.44, 25.26, 329.89, 161.29, 54.19, 66.3, 71.43, 15.74, 10.22, 10.68,
-    20.32, 14.13, 11.72, 11.75, 7.87, 6.38, 7.23, 8.94, 9.79, 7.26, 2.59, 17.03, 33.69, 12.02,
-    1.68, 1.5, 1.31, 1.01, 1.16, 1.59, 0.79, 0.67, 4.82, 0.61, 1.25, 0.79, 0.58, /* 9 */
-    3.77, 2.64, 2.06, 1.87, 2.55, 71.68, 12.05, 13.57, 19.6, 27.33, 35.39, 211.82, 49.02, 51.83,
-    52.5, 50.73, 46.93, 42.42, 37.16, 31.84, 31.94, 77.74, 79.45, 47.93, 26.24, 13.15, 8.8, 6.7,
-    5.38, 4.93, 6.06, 19.76, 215.94, 412.13, 142.39, 34.74, 14.76, 10.99, 9.25, 23.5, 53.05, 81.9,
-    54.92, 47.8, 36.65, 21.82, 285.69, 139.94, 53.37, 64.3, 64.04, 13.79, 9.06, 9.83, 18.6, 13.38,
-    10.99, 10.77, 7.57, 6.19, 7.09, 8.54, 8.77, 6.41, 2.26, 15.02, 29.39, 10.22, 1.42, 1.23, 1.1,
-    0.84, 0.97, 1.35, 0.65, 0.13, 4.22, 0.1, 0.68, 0.16, 0.0, /* 10 */
-    0.25, 0.0, 0.0, 0.0, 0.69, 21.24, 2.18, 1.86, 3.1, 5.0, 7.03, 45.08, 16.78, 12.28, 13.31, 13.66,
-    13.69, 13.13, 12.28, 11.42, 11.66, 22.04, 26.17, 18.57, 11.36, 6.83, 5.58, 4.88, 4.31, 3.76,
-    3.61, 5.62, 38.59, 100.0, 36.54, 10.57, 2.98, 2.05, 1.84, 6.09, 17.27, 21.77, 18.72, 10.15,
-    7.26, 5.17, 56.66, 49.39, 18.57, 14.21, 14.01, 5.99, 2.68, 3.14, 6.25, 5.78, 6.75, 5.16, 3.03,
-    1.57, 1.72, 1.54, 1.71, 1.1, 0.28, 3.65, 7.54, 2.34, 0.05, 0.04, 0.04, 0.03, 0.03, 0.02, 0.02,
-    0.01, 0.01, 0.0, 0.0, 0.0, 0.0, /* 11 */
-    3.85, 2.91, 2.56, 2.59, 3.63, 74.54, 14.69, 17.22, 24.99, 34.4, 44.57, 228.08, 61.53, 65.31,
-    66.35, 64.37, 59.81, 54.24, 47.42, 41.1, 40.04, 85.54, 86.55, 53.47, 30.91, 17.41, 12.56, 10.1,
-    8.48, 7.74, 8.58, 21.39, 220.12, 417.35, 146.13, 36.67, 16.51, 12.56, 10.81, 25.31, 53.31,
-    80.75, 53.56, 44.02, 33.05, 20.26, 233.61, 118.2, 51.66, 61.27, 55.15, 12.95, 8.93, 9.77,
-    17.12, 13.01, 10.45, 10.33, 7.7, 6.34, 7.35, 8.22, 7.93, 5.7, 2.23, 12.43, 24.24, 8.74, 1.39,
-    1.23, 1.1, 0.84, 0.94, 1.23, 0.68, 0.52, 4.6, 0.45, 1.04, 0.45, 0.0, /* 12 */
-    1.62, 2.06, 2.71, 3.11, 3.67, 74.6, 8.88, 4.77, 4.72, 4.72, 4.94, 150.29, 6.08, 7.13, 9.1,
-    11.76, 14.96, 18.54, 22.48, 26.76, 31.66, 40.93, 45.83, 46.0, 45.26, 43.16, 41.63, 39.75,
-    37.83, 36.16, 35.25, 37.04, 59.86, 183.53, 59.03, 47.93, 48.67, 52.69, 57.24, 77.75, 87.81,
-    80.55, 84.83, 86.84, 91.44, 96.51, 105.25, 106.74, 108.53, 106.92, 101.54, 95.2, 89.34, 82.95,
-    75.78, 68.65, 61.7, 55.23, 48.58, 42.9, 37.74, 32.93, 29.65, 25.19, 21.69, 19.28, 17.36, 14.74,
-    12.86, 11.28, 9.97, 8.88, 7.78, 7.04, 6.3, 5.55, 10.15, 4.5, 4.81, 3.72, 3.28,
-    /* 13 */
-    2.23, 2.92, 3.91, 4.55, 5.46, 77.4, 11.25, 7.69, 8.29, 8.98, 10.01, 204.45, 13.75, 16.88, 21.73,
-    27.96, 34.92, 41.96, 48.62, 54.33, 59.49, 67.91, 70.01, 66.4, 62.07, 56.95, 52.7, 48.54, 44.8,
-    41.75, 39.77, 40.5, 59.27, 184.09, 59.06, 49.95, 50.9, 54.51, 58.33, 77.49, 85.78, 76.2, 78.73,
-    78.95, 81.48, 84.57, 87.75, 89.56, 91.36, 89.0, 83.67, 78.26, 73.19, 67.61, 61.42, 55.49,
-    49.78, 44.46, 39.13, 34.45, 30.28, 26.37, 23.88, 20.1, 17.4, 15.29, 13.62, 11.68, 10.31, 9.11,
-    8.03, 7.13, 6.31, 5.67, 5.11, 4.55, 9.06, 3.74, 4.04, 3.14, 2.75, /* 14 */
-    2.87, 3.69, 4.87, 5.82, 7.17, 72.21, 13.69, 11.12, 12.43, 13.9, 15.82, 200.99, 21.72, 26.33,
-    32.85, 40.8, 49.23, 57.39, 65.26, 71.99, 78.25, 88.85, 91.67, 86.81, 80.42, 73.82, 69.12,
-    63.69, 58.44, 53.57, 49.66, 48.44, 72.56, 200.42, 65.0, 47.49, 44.14, 44.71, 46.01, 63.52,
-    71.73, 63.52, 64.13, 63.74, 66.82, 70.65, 79.29, 80.77, 83.59, 82.59, 77.6, 72.47, 68.34,
-    63.82, 58.57, 53.18, 47.97, 43.14, 38.19, 33.85, 29.94, 26.24, 23.9, 20.33, 17.42, 15.64,
-    14.34, 12.21, 10.65, 9.43, 8.34, 7.52, 6.73, 6.08, 5.52, 5.0, 9.47, 4.08, 4.43, 3.39, 3.17,
-    /* 15 */
-    300.0, 286.0, 268.0, 244.0, 304.0, 581.0, 225.0, 155.0, 152.0, 170.0, 295.0, 1417.0, 607.0,
-    343.0, 386.0, 430.0, 469.0, 502.0, 531.0, 552.0, 567.0, 572.0, 575.0, 561.0, 548.0, 527.0,
-    507.0, 482.0, 461.0, 438.0, 418.0, 404.0, 429.0, 1016.0, 581.0, 370.0, 368.0, 371.0, 377.0,
-    490.0, 525.0, 402.0, 404.0, 412.0, 418.0, 425.0, 428.0, 432.0, 433.0, 431.0, 427.0, 420.0,
-    410.0, 399.0, 385.0, 370.0, 352.0, 336.0, 317.0, 298.0, 277.0, 260.0, 242.0, 223.0, 202.0,
-    187.0, 167.0, 152.0, 136.0, 125.0, 113.0, 103.0, 93.0, 84.0, 75.0, 66.0, 58.0, 51.0, 46.0,
-    41.0, 37.0,
-];
+pub(crate) static CIE_FL3_DATA: LazyBlob = LazyBlob::new(include_blob!("cie_fl3.bin"));
+
+#[cfg(feature = "compress")]
+#[test]
+fn test_fl3_blob() {
+    use crate::blob::Blob;
+    // the compressed blob decompresses to the same data as the blob
+    let blob = Blob::parse(include_bytes!("cie_fl3.bin")).unwrap();
+    assert_eq!(Blob::parse(include_bytes!("cie_fl3.bin.z")).unwrap(), blob);
+}
@@ -0,0 +1,253 @@
+/*!
+Parametric spectral models, as a sum of Gaussian spectral distributions.
+
+The spectra of LED products, both colored and phosphor-converted white, are described well by a sum of a small number
+of Gaussian components, each with a peak wavelength, a full width at half maximum, and an amplitude. A `GaussianSum`
+can be fitted to measured spectral data, to describe these products in a compact form, and to use them as an
+illuminant, on any domain.
+*/
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::models::CieXYZ;
+use crate::observers::StandardObserver;
+use crate::{led_gaussian, Domain, SpectralDistribution, Unit, WavelengthStep};
+
+use super::{Illuminant, LedPar};
+
+const FOUR_LN2: f64 = 4.0 * std::f64::consts::LN_2;
+
+/**
+A spectral distribution, as the sum of Gaussian components.
+
+The components are given by their peak wavelengths, and widths, in meter, or in nanometer, and their amplitudes, in
+the units of the spectral distribution they represent.
+
+# Examples
+A fit of three Gaussian components to the spectrum of a white LED.
+```
+use scot::illuminants::{GaussianSum, LedPcWhite};
+
+let fit = GaussianSum::fit(&LedPcWhite::default(), 3);
+for (par, amplitude) in fit.parameters.iter().zip(fit.amplitudes.iter()) {
+    println!("{:.1}nm {:.1}nm {:.3e}", par.peak_wavelength * 1E9, par.fwhm * 1E9, amplitude);
+}
+```
+*/
+#[derive(Debug, Clone)]
+pub struct GaussianSum {
+    pub parameters: Vec<LedPar>,
+    pub amplitudes: Vec<f64>,
+    pub domain: Domain<WavelengthStep>,
+}
+
+impl GaussianSum {
+    /// Gaussian components, with peak wavelengths, widths, and amplitudes, with wavelengths and widths in meter, or in
+    /// nanometer.
+    pub fn new(components: Vec<[f64; 3]>) -> Self {
+        Self {
+            parameters: components
+                .iter()
+                .map(|&[p, w, _]| super::led::led_par(p, w))
+                .collect(),
+            amplitudes: components.iter().map(|&[_, _, a]| a).collect(),
+            domain: Domain::default(),
+        }
+    }
+
+    pub fn set_domain(mut self, domain: Domain<WavelengthStep>) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /**
+    Least squares fit of a sum of `n` Gaussian components to the first spectral distribution of a source.
+
+    The components are initialized, one by one, at the maximum of the residual spectrum, and then optimized together
+    with the Levenberg-Marquardt method. The domain of the fitted model is the domain of the source.
+    */
+    pub fn fit<S>(sd: &S, n: usize) -> Self
+    where
+        S: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let (d, s) = sd.spd();
+        let x: Vec<f64> = d.iter().map(|l| l.value() * 1E9).collect();
+        let y = DVector::from_fn(x.len(), |i, _| s[(i, 0)]);
+
+        // initial estimates, in nanometer
+        let mut p: Vec<f64> = Vec::with_capacity(3 * n);
+        let mut r = y.clone();
+        for _ in 0..n {
+            let (i, a) = r.argmax();
+            let half = |k: usize| r[k] < a / 2.0;
+            let lo = (0..i).rev().find(|&k| half(k)).unwrap_or(0);
+            let hi = (i..x.len()).find(|&k| half(k)).unwrap_or(x.len() - 1);
+            let w = (x[hi] - x[lo]).max(2.0 * (x[1] - x[0]));
+            for (k, v) in r.iter_mut().enumerate() {
+                *v -= a * gaussian(x[k], x[i], w);
+            }
+            p.extend_from_slice(&[x[i], w, a]);
+        }
+
+        levenberg_marquardt(&x, &y, &mut p);
+        Self {
+            parameters: p
+                .chunks(3)
+                .map(|c| LedPar {
+                    peak_wavelength: c[0] * 1E-9,
+                    fwhm: c[1] * 1E-9,
+                })
+                .collect(),
+            amplitudes: p.chunks(3).map(|c| c[2]).collect(),
+            domain: d,
+        }
+    }
+}
+
+#[inline]
+fn gaussian(x: f64, peak: f64, fwhm: f64) -> f64 {
+    let t = (x - peak) / fwhm;
+    (-FOUR_LN2 * t * t).exp()
+}
+
+/// Values, and Jacobian, of a sum of Gaussians, with parameters peak, width, and amplitude, for each component.
+fn model(x: &[f64], p: &[f64]) -> (DVector<f64>, DMatrix<f64>) {
+    let mut f = DVector::zeros(x.len());
+    let mut j = DMatrix::zeros(x.len(), p.len());
+    for (i, &xi) in x.iter().enumerate() {
+        for (c, par) in p.chunks(3).enumerate() {
+            let (peak, w, a) = (par[0], par[1], par[2]);
+            let t = (xi - peak) / w;
+            let e = gaussian(xi, peak, w);
+            f[i] += a * e;
+            j[(i, 3 * c)] = a * e * 2.0 * FOUR_LN2 * t / w;
+            j[(i, 3 * c + 1)] = a * e * 2.0 * FOUR_LN2 * t * t / w;
+            j[(i, 3 * c + 2)] = e;
+        }
+    }
+    (f, j)
+}
+
+fn levenberg_marquardt(x: &[f64], y: &DVector<f64>, p: &mut Vec<f64>) {
+    let min_width = 0.5 * (x[1] - x[0]).abs();
+    let (f, _) = model(x, p);
+    let mut chi2 = (y - f).norm_squared();
+    let mut lambda = 1E-3;
+    for _ in 0..200 {
+        let (f, j) = model(x, p);
+        let jt = j.transpose();
+        let jtj = &jt * &j;
+        let g = &jt * (y - f);
+        let mut improved = false;
+        while lambda < 1E12 {
+            let mut a = jtj.clone();
+            for k in 0..a.nrows() {
+                a[(k, k)] += lambda * jtj[(k, k)].max(1E-12);
+            }
+            if let Some(delta) = a.lu().solve(&g) {
+                let trial: Vec<f64> = p
+                    .iter()
+                    .zip(delta.iter())
+                    .enumerate()
+                    .map(|(k, (v, dv))| match k % 3 {
+                        1 => (v + dv).max(min_width),
+                        _ => v + dv,
+                    })
+                    .collect();
+                let (ft, _) = model(x, &trial);
+                let chi2_trial = (y - ft).norm_squared();
+                if chi2_trial < chi2 {
+                    let converged = (chi2 - chi2_trial) < 1E-12 * chi2;
+                    *p = trial;
+                    chi2 = chi2_trial;
+                    lambda = (lambda / 10.0).max(1E-12);
+                    improved = !converged;
+                    break;
+                }
+            }
+            lambda *= 10.0;
+        }
+        if !improved {
+            break;
+        }
+    }
+}
+
+impl Default for GaussianSum {
+    fn default() -> Self {
+        Self::new(vec![[550.0, 50.0, 1.0]])
+    }
+}
+
+impl SpectralDistribution for GaussianSum {
+    type StepType = WavelengthStep;
+    type MatrixType = DMatrix<f64>;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let d = self.domain.clone();
+        let m = DMatrix::from_iterator(
+            d.len(),
+            1,
+            d.iter().map(|l| {
+                self.parameters
+                    .iter()
+                    .zip(self.amplitudes.iter())
+                    .map(|(lp, a)| a * led_gaussian(l.value(), lp.peak_wavelength, lp.fwhm))
+                    .sum::<f64>()
+            }),
+        );
+        (d, m)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.domain.len(), 1)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(format!("Sum of {} Gaussians", self.parameters.len()))
+    }
+}
+
+impl Illuminant for GaussianSum {}
+
+impl<C: StandardObserver> From<GaussianSum> for CieXYZ<C> {
+    fn from(g: GaussianSum) -> Self {
+        g.xyz().normalize(100.0)
+    }
+}
+
+#[test]
+fn test_gaussian_sum_fit() {
+    use crate::illuminants::LedPcWhite;
+    use approx::assert_abs_diff_eq;
+
+    // the components of a sum of Gaussians are recovered
+    let sum = GaussianSum::new(vec![
+        [450.0, 20.0, 1.0],
+        [530.0, 35.0, 0.4],
+        [620.0, 25.0, 0.7],
+    ]);
+    let fit = GaussianSum::fit(&sum, 3);
+    let mut found: Vec<(f64, f64, f64)> = fit
+        .parameters
+        .iter()
+        .zip(fit.amplitudes.iter())
+        .map(|(lp, &a)| (lp.peak_wavelength * 1E9, lp.fwhm * 1E9, a))
+        .collect();
+    found.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    for ((p, w, a), [p0, w0, a0]) in
+        found
+            .into_iter()
+            .zip([[450.0, 20.0, 1.0], [530.0, 35.0, 0.4], [620.0, 25.0, 0.7]])
+    {
+        assert_abs_diff_eq!(p, p0, epsilon = 1E-4);
+        assert_abs_diff_eq!(w, w0, epsilon = 1E-4);
+        assert_abs_diff_eq!(a, a0, epsilon = 1E-6);
+    }
+
+    // a white LED, from two Gaussians, is reproduced
+    let led = LedPcWhite::default();
+    let (_, s) = led.spd();
+    let (_, f) = GaussianSum::fit(&led, 2).spd();
+    assert_abs_diff_eq!(f, s, epsilon = 1E-6 * s.max());
+}
@@ -2,8 +2,8 @@ use nalgebra::{Dynamic, OMatrix};
 use num::ToPrimitive;
 
 use crate::{
-    led_ohno, models::CieXYZ, observers::StandardObserver, Domain, SpectralDistribution, Step,
-    Unit, WavelengthStep, NM,
+    led_gaussian, led_ohno, models::CieXYZ, observers::StandardObserver, Domain,
+    SpectralDistribution, Step, Unit, WavelengthStep, NM,
 };
 
 //use super::Illuminant;
@@ -163,3 +163,252 @@ impl<C: StandardObserver> From<LedOhno2005> for CieXYZ<C> {
         l.xyz()
     }
 }
+
+/// Peak wavelength and width, with values larger than 1.0 taken to be in units of nanometer, and converted to meter.
+pub(crate) fn led_par(peak_wavelength: f64, fwhm: f64) -> LedPar {
+    if peak_wavelength > 1.0 {
+        LedPar {
+            peak_wavelength: peak_wavelength * 1E-9,
+            fwhm: fwhm * 1E-9,
+        }
+    } else {
+        LedPar {
+            peak_wavelength,
+            fwhm,
+        }
+    }
+}
+
+/**
+Gaussian LED Model
+
+The standard model for the emission of monochromatic LEDs, as a Gaussian spectral distribution, with a peak
+wavelength, and a full width at half maximum. It is simpler than Ohno's model, with a lower intensity in the tails of
+its distribution.
+
+# Examples
+```
+use scot::illuminants::LedGaussian;
+use scot::models::CieYxy;
+
+let leds = LedGaussian::new(vec![[450.0, 20.0], [530.0, 30.0], [630.0, 18.0]]);
+let yxy: CieYxy = leds.into();
+println!("{}", yxy);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct LedGaussian {
+    pub parameters: Vec<LedPar>,
+    pub domain: Domain<WavelengthStep>,
+}
+
+impl LedGaussian {
+    /// Gaussian LEDs, with peak wavelengths and widths in meter, or in nanometer.
+    pub fn new(parameters: Vec<[f64; 2]>) -> Self {
+        Self {
+            parameters: parameters.iter().map(|&[p, w]| led_par(p, w)).collect(),
+            domain: Domain::default(),
+        }
+    }
+
+    pub fn set_domain(mut self, domain: Domain<WavelengthStep>) -> Self {
+        self.domain = domain;
+        self
+    }
+}
+
+impl Default for LedGaussian {
+    fn default() -> Self {
+        Self::new(vec![[550.0, 50.0]])
+    }
+}
+
+impl SpectralDistribution for LedGaussian {
+    type StepType = WavelengthStep;
+    type MatrixType = OMatrix<f64, Dynamic, Dynamic>;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let d = self.domain.clone();
+        let m = Self::MatrixType::from_iterator(
+            d.len(),
+            self.parameters.len(),
+            self.parameters.iter().flat_map(|lp| {
+                d.iter()
+                    .map(move |l| led_gaussian(l.value(), lp.peak_wavelength, lp.fwhm))
+            }),
+        );
+        (d, m)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.domain.len(), self.parameters.len())
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        Some(
+            self.parameters
+                .iter()
+                .map(|lp| {
+                    format!(
+                        "Gaussian LED {:.1}/{:.1}",
+                        lp.peak_wavelength * 1E9,
+                        lp.fwhm * 1E9
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("Gaussian LED model spectra".to_string())
+    }
+}
+
+impl super::Illuminant for LedGaussian {}
+
+impl<C: StandardObserver> From<LedGaussian> for CieXYZ<C> {
+    fn from(l: LedGaussian) -> Self {
+        l.xyz().normalize(100.0)
+    }
+}
+
+/**
+Phosphor-Converted White LED Model
+
+A white LED, with a blue pump LED, and a phosphor, both modeled as Gaussian spectral distributions, and combined with
+one or more phosphor to blue radiant power ratios, each producing a spectral distribution in the collection.
+Increasing the ratio makes the LED warmer, and moves its chromaticity from the blue pump towards the phosphor.
+
+The default pump has a peak wavelength of 450nm, and a width of 20nm, and the default phosphor, representing a
+broad-band YAG:Ce phosphor, has a peak wavelength of 560nm, and a width of 120nm.
+
+# Examples
+```
+use scot::illuminants::LedPcWhite;
+use scot::models::CieYxy;
+
+let leds = LedPcWhite::new(vec![2.0, 3.0, 4.0]).set_phosphor(570.0, 110.0);
+let yxy: CieYxy = leds.into();
+println!("{}", yxy);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct LedPcWhite {
+    pub blue: LedPar,
+    pub phosphor: LedPar,
+    pub ratios: Vec<f64>,
+    pub domain: Domain<WavelengthStep>,
+}
+
+impl LedPcWhite {
+    pub fn new(ratios: Vec<f64>) -> Self {
+        Self {
+            blue: led_par(450.0, 20.0),
+            phosphor: led_par(560.0, 120.0),
+            ratios,
+            domain: Domain::default(),
+        }
+    }
+
+    /// Sets the peak wavelength, and width, of the blue pump, in meter, or in nanometer.
+    pub fn set_blue(mut self, peak_wavelength: f64, fwhm: f64) -> Self {
+        self.blue = led_par(peak_wavelength, fwhm);
+        self
+    }
+
+    /// Sets the peak wavelength, and width, of the phosphor emission, in meter, or in nanometer.
+    pub fn set_phosphor(mut self, peak_wavelength: f64, fwhm: f64) -> Self {
+        self.phosphor = led_par(peak_wavelength, fwhm);
+        self
+    }
+
+    pub fn set_domain(mut self, domain: Domain<WavelengthStep>) -> Self {
+        self.domain = domain;
+        self
+    }
+}
+
+impl Default for LedPcWhite {
+    fn default() -> Self {
+        Self::new(vec![2.5])
+    }
+}
+
+impl SpectralDistribution for LedPcWhite {
+    type StepType = WavelengthStep;
+    type MatrixType = OMatrix<f64, Dynamic, Dynamic>;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let d = self.domain.clone();
+        // Gaussians with unit area, to use the ratio as a ratio of radiant powers
+        let area =
+            |lp: &LedPar| lp.fwhm * (std::f64::consts::PI / (4.0 * std::f64::consts::LN_2)).sqrt();
+        let (ab, ap) = (area(&self.blue), area(&self.phosphor));
+        let m = Self::MatrixType::from_iterator(
+            d.len(),
+            self.ratios.len(),
+            self.ratios.iter().flat_map(|r| {
+                d.iter().map(move |l| {
+                    let l = l.value();
+                    led_gaussian(l, self.blue.peak_wavelength, self.blue.fwhm) / ab
+                        + r * led_gaussian(l, self.phosphor.peak_wavelength, self.phosphor.fwhm)
+                            / ap
+                })
+            }),
+        );
+        (d, m)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.domain.len(), self.ratios.len())
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        Some(
+            self.ratios
+                .iter()
+                .map(|r| format!("PC White LED {:.2}", r))
+                .collect(),
+        )
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("Phosphor-converted white LED model spectra".to_string())
+    }
+}
+
+impl super::Illuminant for LedPcWhite {}
+
+impl<C: StandardObserver> From<LedPcWhite> for CieXYZ<C> {
+    fn from(l: LedPcWhite) -> Self {
+        l.xyz().normalize(100.0)
+    }
+}
+
+#[test]
+fn test_gaussian_pc_white() {
+    use crate::models::{CieYxy, YxyValues};
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    // half maximum at the peak wavelength plus, or minus, half the width
+    let (_, m) = LedGaussian::new(vec![[550.0, 40.0]]).spd();
+    assert_abs_diff_eq!(m[(550 - 380, 0)], 1.0, epsilon = 1E-12);
+    assert_abs_diff_eq!(m[(530 - 380, 0)], 0.5, epsilon = 1E-12);
+    assert_abs_diff_eq!(m[(570 - 380, 0)], 0.5, epsilon = 1E-12);
+
+    let xs: Vec<f64> = CieYxy::<CieObs1931>::from(LedPcWhite::new(vec![0.0, 1.0, 2.0, 3.0]))
+        .into_iter()
+        .map(|YxyValues { l: _, x, y: _ }| x)
+        .collect();
+    assert!(xs.windows(2).all(|w| w[0] < w[1]));
+
+    // without phosphor, the chromaticity of the blue pump
+    let YxyValues { l: _, x, y } =
+        CieYxy::<CieObs1931>::from(LedGaussian::new(vec![[450.0, 20.0]]))
+            .into_iter()
+            .next()
+            .unwrap();
+    assert_abs_diff_eq!(xs[0], x, epsilon = 1E-12);
+    assert!(y < 0.1);
+}
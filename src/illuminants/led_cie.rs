@@ -58,15 +58,77 @@ illuminant!(
     LED,
     N,
     M,
-    "CIE L{}",
+    "CIE LED {}",
     Domain::new(380 / 5, 780 / 5, crate::NM5),
     CIE_LED_ILL_DATA
 );
 
-static CIE_LED_ILL_KEYS: [&str; 9] = [
+/// CIE LED-B1, phosphor-type LED, with a CCT of 2733K.
+pub type CieIllLedB1 = LED<1>;
+/// CIE LED-B2, phosphor-type LED, with a CCT of 2998K.
+pub type CieIllLedB2 = LED<2>;
+/// CIE LED-B3, phosphor-type LED, with a CCT of 4103K.
+pub type CieIllLedB3 = LED<3>;
+/// CIE LED-B4, phosphor-type LED, with a CCT of 5109K.
+pub type CieIllLedB4 = LED<4>;
+/// CIE LED-B5, phosphor-type LED, with a CCT of 6598K.
+pub type CieIllLedB5 = LED<5>;
+/// CIE LED-BH1, hybrid type, a blue LED with phosphor and an additional red LED, with a CCT of 2851K.
+pub type CieIllLedBH1 = LED<6>;
+/// CIE LED-RGB1, a mix of red, green, and blue LEDs, with a CCT of 2840K.
+pub type CieIllLedRGB1 = LED<7>;
+/// CIE LED-V1, a violet LED with phosphor, with a CCT of 2724K.
+pub type CieIllLedV1 = LED<8>;
+/// CIE LED-V2, a violet LED with phosphor, with a CCT of 4070K.
+pub type CieIllLedV2 = LED<9>;
+
+pub static CIE_LED_ILL_KEYS: [&str; M] = [
     "LED-B1", "LED-B2", "LED-B3", "LED-B4", "LED-B5", "LED-BH1", "LED-RGB1", "LED-V1", "LED-V2",
 ];
 
+/**
+Reference values for the CIE LED illuminants, as provided by CIE in CIE Technical Report 15:2018, 4th Edition, Table 10.3.
+*/
+pub static LEDTEST: [[f64; 3]; M] = [
+    [0.4560, 0.4078, 2733.0], // x, y, CCT
+    [0.4357, 0.4012, 2998.0],
+    [0.3756, 0.3723, 4103.0],
+    [0.3422, 0.3502, 5109.0],
+    [0.3118, 0.3236, 6598.0],
+    [0.4474, 0.4066, 2851.0],
+    [0.4557, 0.4211, 2840.0],
+    [0.4548, 0.4044, 2724.0],
+    [0.3781, 0.3775, 4070.0],
+];
+
+#[test]
+fn test_led() {
+    use crate::models::{CieYxy, YxyValues};
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    macro_rules! ledtest {
+        ($($I:literal),*) => {
+            $(
+                let YxyValues { l: _, x, y } = CieYxy::<CieObs1931>::from(LED::<$I>).into_iter().next().unwrap();
+                assert_abs_diff_eq!(x, LEDTEST[$I - 1][0], epsilon = 0.0001); // CIE 15:2018 table 10.3
+                assert_abs_diff_eq!(y, LEDTEST[$I - 1][1], epsilon = 0.0001);
+            )*
+        };
+    }
+    ledtest!(1, 2, 3, 4, 5, 6, 7, 8, 9);
+
+    let YxyValues { l: _, x, y } = CieYxy::<CieObs1931>::from(CieIllLedV2::default())
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_abs_diff_eq!(x, 0.3781, epsilon = 0.0001);
+    assert_abs_diff_eq!(y, 0.3775, epsilon = 0.0001);
+
+    let keys = CieIllLed::default().keys().unwrap();
+    assert_eq!(keys[6], "LED-RGB1");
+}
+
 static CIE_LED_ILL_DATA: [f64; N * M] = [
     // 380-780-5nm
     0.00, 0.01, 0.01, 0.02, 0.04, 0.07, 0.15, 0.28, 0.53, 0.92, 1.54, 2.52, 4.16, 6.39, 7.89, 7.57,
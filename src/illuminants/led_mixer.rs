@@ -0,0 +1,254 @@
+use std::marker::PhantomData;
+
+use nalgebra::{DVector, Matrix3, Vector3};
+
+use crate::illuminants::{CctDuv, IlluminantMix};
+use crate::models::CieYuv1960;
+use crate::observers::StandardObserver;
+use crate::{Domain, SpectralDistribution, Step, Unit, WavelengthStep};
+
+/**
+Finds the weights of the channels of a multi-channel LED light source, for a target chromaticity, while maximizing
+a performance metric, such as its luminous efficacy of radiation, or a color fidelity index.
+
+With three channels there is at most one set of weights producing a target chromaticity; with more channels there are
+many, and the solver searches for the combination with the highest metric value.
+The set of non-negative channel weights matching the target chromaticity is a convex polytope, with vertices formed
+by combinations of three channels. The solver searches the convex combinations of these vertices, using a pattern
+search, and the metric is calculated for the mixed spectral distributions, as an `IlluminantMix`.
+
+# Examples
+Four-channel LED, with a target correlated color temperature of 4000K on the Planckian locus, with maximum luminous
+efficacy of radiation.
+```
+use scot::illuminants::{IlluminantMix, LedMixer, LedOhno2005};
+use scot::observers::{photopic_luminous, CieObs1931};
+use scot::SpectralDistribution;
+
+let mixer = LedMixer::<CieObs1931>::new()
+    .add_channel(&LedOhno2005::from([450, 20]))
+    .add_channel(&LedOhno2005::from([530, 30]))
+    .add_channel(&LedOhno2005::from([590, 20]))
+    .add_channel(&LedOhno2005::from([630, 20]))
+    .set_target_cct(4000.0, 0.0);
+let ler = |mix: &IlluminantMix| photopic_luminous::<CieObs1931, _>(mix)[0] / mix.spd().1.sum();
+let solution = mixer.optimize(ler).unwrap();
+println!("{:?}", solution.weights);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct LedMixer<C: StandardObserver> {
+    channels: IlluminantMix,
+    xyz: Vec<Vector3<f64>>,
+    target: [f64; 2],
+    observer: PhantomData<fn() -> C>,
+}
+
+/// Channel weights, the mixed spectral distribution, and the value of the optimized metric, as found by `LedMixer`.
+#[derive(Debug, Clone)]
+pub struct LedMixerSolution {
+    pub weights: Vec<f64>,
+    pub mix: IlluminantMix,
+    pub metric: f64,
+}
+
+impl<C: StandardObserver> LedMixer<C> {
+    pub fn new() -> Self {
+        Self {
+            channels: IlluminantMix::new(),
+            xyz: Vec::new(),
+            target: [1.0 / 3.0, 1.0 / 3.0],
+            observer: PhantomData,
+        }
+    }
+
+    /// Sets the wavelength domain of the mixed spectral distributions, with a default range from 380 to 780nm, in
+    /// steps of 1nm.
+    pub fn set_domain(mut self, domain: Domain<WavelengthStep>) -> Self {
+        self.channels = self.channels.set_domain(domain);
+        self
+    }
+
+    /// Adds a channel, using the first spectral distribution of `sd`.
+    pub fn add_channel<S>(mut self, sd: &S) -> Self
+    where
+        S: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let (d, m) = sd.spd();
+        let v = DVector::from_fn(d.len(), |i, _| m[(i, 0)]);
+        let dl = d.step.unitvalue(1).value();
+        self.xyz.push(C::values_cached(&d).as_ref() * v * dl);
+        self.channels = self.channels.add(sd, 1.0);
+        self
+    }
+
+    /// Sets the target chromaticity, as CIE 1931 x and y coordinates, for observer `C`.
+    pub fn set_target_xy(mut self, x: f64, y: f64) -> Self {
+        self.target = [x, y];
+        self
+    }
+
+    /// Sets the target chromaticity, as a correlated color temperature, in Kelvin, and a distance to the Planckian
+    /// locus.
+    pub fn set_target_cct(self, cct: f64, duv: f64) -> Self {
+        let yuv: CieYuv1960<C> = CctDuv::<C>::new(vec![[cct, duv]]).into();
+        let (u, v) = (yuv.data[(1, 0)], yuv.data[(2, 0)]);
+        let den = 2.0 * u - 8.0 * v + 4.0;
+        self.set_target_xy(3.0 * u / den, 2.0 * v / den)
+    }
+
+    /// Channel weights of the vertices of the feasible set: all combinations of three channels producing the target
+    /// chromaticity with non-negative weights, scaled to a luminance, or Y value, of 1.0.
+    pub fn vertices(&self) -> Vec<Vec<f64>> {
+        let [x, y] = self.target;
+        let n = self.xyz.len();
+        let row = |c: &Vector3<f64>| {
+            let s = c.sum();
+            Vector3::new(c[0] - x * s, c[1] - y * s, c[1])
+        };
+        let mut v = Vec::new();
+        for i in 0..n {
+            for j in i + 1..n {
+                for k in j + 1..n {
+                    let a = Matrix3::from_columns(&[
+                        row(&self.xyz[i]),
+                        row(&self.xyz[j]),
+                        row(&self.xyz[k]),
+                    ]);
+                    if let Some(w) = a.lu().solve(&Vector3::new(0.0, 0.0, 1.0)) {
+                        if w.iter().all(|&wi| wi >= -1E-12 && wi.is_finite()) {
+                            let mut wv = vec![0.0; n];
+                            wv[i] = w[0].max(0.0);
+                            wv[j] = w[1].max(0.0);
+                            wv[k] = w[2].max(0.0);
+                            v.push(wv);
+                        }
+                    }
+                }
+            }
+        }
+        v
+    }
+
+    /// Finds the channel weights with the highest metric value, or `None` if the target chromaticity is out of the
+    /// gamut of the channels.
+    pub fn optimize<F>(&self, metric: F) -> Option<LedMixerSolution>
+    where
+        F: Fn(&IlluminantMix) -> f64,
+    {
+        let vertices = self.vertices();
+        if vertices.is_empty() {
+            return None;
+        }
+        let n = self.xyz.len();
+        let m = vertices.len();
+        let eval = |z: &[f64]| {
+            let zmax = z.iter().cloned().fold(f64::MIN, f64::max);
+            let e: Vec<f64> = z.iter().map(|zi| (zi - zmax).exp()).collect();
+            let es: f64 = e.iter().sum();
+            let mut w = vec![0.0; n];
+            for (ek, vk) in e.iter().zip(vertices.iter()) {
+                for (wi, vi) in w.iter_mut().zip(vk.iter()) {
+                    *wi += ek / es * vi;
+                }
+            }
+            let mix = w
+                .iter()
+                .enumerate()
+                .fold(self.channels.clone(), |mix, (i, &wi)| mix.set_weight(i, wi));
+            let f = metric(&mix);
+            (f, w, mix)
+        };
+
+        // start with the best of the vertices, and their centroid
+        let mut z = vec![0.0; m];
+        let mut best = eval(&z);
+        for k in 0..m {
+            let mut zk = vec![0.0; m];
+            zk[k] = 20.0;
+            let trial = eval(&zk);
+            if trial.0 > best.0 {
+                best = trial;
+                z = zk;
+            }
+        }
+
+        let mut step = 4.0;
+        let mut iter = 0;
+        while m > 1 && step > 1E-3 && iter < 10_000 {
+            let mut improved = false;
+            for k in 0..m {
+                for s in [step, -step] {
+                    let mut zt = z.clone();
+                    zt[k] += s;
+                    let trial = eval(&zt);
+                    iter += 1;
+                    if trial.0 > best.0 {
+                        best = trial;
+                        z = zt;
+                        improved = true;
+                    }
+                }
+            }
+            if !improved {
+                step /= 2.0;
+            }
+        }
+        let (metric, weights, mix) = best;
+        Some(LedMixerSolution {
+            weights,
+            mix,
+            metric,
+        })
+    }
+}
+
+impl<C: StandardObserver> Default for LedMixer<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn test_led_mixer() {
+    use crate::illuminants::LedOhno2005;
+    use crate::models::{CieYxy, YxyValues};
+    use crate::observers::{photopic_luminous, CieObs1931};
+    use approx::assert_abs_diff_eq;
+
+    let ler = |mix: &IlluminantMix| photopic_luminous::<CieObs1931, _>(mix)[0] / mix.spd().1.sum();
+    let mixer = LedMixer::<CieObs1931>::new()
+        .add_channel(&LedOhno2005::from([450, 20]))
+        .add_channel(&LedOhno2005::from([530, 30]))
+        .add_channel(&LedOhno2005::from([590, 20]))
+        .add_channel(&LedOhno2005::from([630, 20]))
+        .set_target_xy(0.38, 0.38);
+    let vertices = mixer.vertices();
+    assert!(!vertices.is_empty());
+
+    let solution = mixer.optimize(ler).unwrap();
+    let YxyValues { l: _, x, y } = CieYxy::<CieObs1931>::from(solution.mix.clone())
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_abs_diff_eq!(x, 0.38, epsilon = 1E-4);
+    assert_abs_diff_eq!(y, 0.38, epsilon = 1E-4);
+    assert!(solution.weights.iter().all(|&w| w >= 0.0));
+
+    // at least as good as any of the three-channel solutions
+    for v in vertices {
+        let mix = v
+            .iter()
+            .enumerate()
+            .fold(solution.mix.clone(), |mix, (i, &wi)| mix.set_weight(i, wi));
+        assert!(solution.metric >= ler(&mix) - 1E-9);
+    }
+
+    // out of gamut
+    let mixer = LedMixer::<CieObs1931>::new()
+        .add_channel(&LedOhno2005::from([450, 20]))
+        .add_channel(&LedOhno2005::from([530, 30]))
+        .add_channel(&LedOhno2005::from([630, 20]))
+        .set_target_xy(0.1, 0.8);
+    assert!(mixer.optimize(ler).is_none());
+}
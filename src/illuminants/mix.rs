@@ -0,0 +1,153 @@
+use nalgebra::{DMatrix, DVector};
+
+use crate::illuminants::Illuminant;
+use crate::models::CieXYZ;
+use crate::observers::StandardObserver;
+use crate::{lin_interp_mat_col, Domain, SpectralDistribution, WavelengthStep};
+
+/**
+A weighted, linear, combination of spectral distributions, for example daylight entering a room lit by fluorescent
+lamps, or a set of dimmable light sources.
+
+The components can be defined on different wavelength domains: they are linearly interpolated to the domain of the
+mix, with a default range from 380 to 780nm, in steps of 1nm, and are zero outside their own domain.
+Only the first spectral distribution of each component is used. The weights can be changed after the components
+have been added, to simulate dimming.
+
+# Examples
+```
+use scot::illuminants::{CieIllD65, IlluminantMix, Planckian};
+use scot::models::CieYxy;
+use scot::observers::CieObs1931;
+
+let mix = IlluminantMix::new()
+    .add(&CieIllD65, 0.3)
+    .add(&Planckian::new(3000), 1.0E-4)
+    .set_weight(0, 0.5);
+let yxy: CieYxy<CieObs1931> = mix.into();
+println!("{}", yxy);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct IlluminantMix {
+    domain: Domain<WavelengthStep>,
+    components: Vec<(Domain<WavelengthStep>, DVector<f64>)>,
+    weights: Vec<f64>,
+    keys: Vec<String>,
+}
+
+impl IlluminantMix {
+    pub fn new() -> Self {
+        Self {
+            domain: Domain::default(),
+            components: Vec::new(),
+            weights: Vec::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    pub fn set_domain(mut self, domain: Domain<WavelengthStep>) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Adds the first spectral distribution of `sd`, with a weight.
+    pub fn add<S>(mut self, sd: &S, weight: f64) -> Self
+    where
+        S: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let (d, m) = sd.spd();
+        let v = DVector::from_fn(d.len(), |i, _| m[(i, 0)]);
+        self.keys.push(
+            sd.keys()
+                .and_then(|k| k.into_iter().next())
+                .or_else(|| sd.description())
+                .unwrap_or_else(|| format!("Component {}", self.components.len() + 1)),
+        );
+        self.components.push((d, v));
+        self.weights.push(weight);
+        self
+    }
+
+    /// Changes the weight of the component with index `i`, in order as added.
+    pub fn set_weight(mut self, i: usize, weight: f64) -> Self {
+        self.weights[i] = weight;
+        self
+    }
+
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Keys, or descriptions, of the components in the mix.
+    pub fn component_keys(&self) -> &[String] {
+        &self.keys
+    }
+}
+
+impl Default for IlluminantMix {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpectralDistribution for IlluminantMix {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let mut m = DMatrix::zeros(self.domain.len(), 1);
+        for ((d, v), w) in self.components.iter().zip(self.weights.iter()) {
+            m += lin_interp_mat_col(d, &self.domain, 1, v.column(0)) * *w;
+        }
+        (self.domain.clone(), m)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.domain.len(), 1)
+    }
+
+    fn description(&self) -> Option<String> {
+        let parts: Vec<String> = self
+            .keys
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(k, w)| format!("{} × {}", w, k))
+            .collect();
+        Some(format!("Mix of {}", parts.join(" + ")))
+    }
+}
+
+impl Illuminant for IlluminantMix {}
+
+impl<C: StandardObserver> From<IlluminantMix> for CieXYZ<C> {
+    fn from(mix: IlluminantMix) -> Self {
+        mix.xyz().normalize(100.0)
+    }
+}
+
+#[test]
+fn test_illuminant_mix() {
+    use crate::illuminants::{CieIllD65, Planckian};
+    use crate::models::{CieYxy, YxyValues};
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    // a mix of a single source has its chromaticity, also on a 5nm domain
+    let d65: CieYxy<CieObs1931> = CieIllD65.into();
+    let mix: CieYxy<CieObs1931> = IlluminantMix::new().add(&CieIllD65, 2.0).into();
+    assert_abs_diff_eq!(mix.data, d65.data, epsilon = 1E-4);
+
+    // dimming one component moves the mix towards the other
+    let mix = IlluminantMix::new()
+        .add(&CieIllD65, 1.0)
+        .add(&Planckian::new(2700), 1.0E-4);
+    let x = |m: IlluminantMix| {
+        let YxyValues { l: _, x, y: _ } = CieYxy::<CieObs1931>::from(m).into_iter().next().unwrap();
+        x
+    };
+    let x_full = x(mix.clone());
+    let x_dim = x(mix.clone().set_weight(1, 0.5E-4));
+    assert!(x_dim < x_full);
+    assert_eq!(mix.component_keys()[0], "CIE D65 Illuminant");
+}
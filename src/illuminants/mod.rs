@@ -22,12 +22,49 @@ pub use self::blackbody::*;
 pub mod led;
 pub use self::led::*;
 
+pub mod gaussian_sum;
+pub use self::gaussian_sum::*;
+
+pub mod data_illuminant;
+pub use self::data_illuminant::*;
+
+pub mod mix;
+pub use self::mix::*;
+
+pub mod led_mixer;
+pub use self::led_mixer::*;
+
+pub mod reference;
+pub use self::reference::*;
+
+pub mod ssi;
+pub use self::ssi::*;
+
 /**
 Represents a type with a single spectral distrution, which values can be accessed
 by using its default constructor, and getting its first, and single row vector.
 */
 
 pub trait Illuminant: SpectralDistribution + Default {}
+
+/// Error returned when selecting an illuminant from a collection, such as `FL::nth`, with an index out of range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IlluminantIndexError {
+    pub index: usize,
+    pub len: usize,
+}
+
+impl std::fmt::Display for IlluminantIndexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "illuminant index {} out of range, expected 1 to {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl std::error::Error for IlluminantIndexError {}
 /*
 where
     Self: SpectralDistribution,
@@ -50,7 +87,7 @@ where
 #[macro_export]
 macro_rules! illuminant {
     // a single illuminant from static slice column
-    ($ILL:ident, $N:expr, $M:expr, $DESC:literal, $DOMAIN:expr, $DATA:ident) => {
+    ($ILL:ident, $N:expr, $M:expr, $DESC:literal, $DOMAIN:expr, $DATA:expr) => {
         #[derive(Debug, Default, Clone)]
         pub struct $ILL<const I: usize>;
 
@@ -79,6 +116,34 @@ macro_rules! illuminant {
 
         impl<const I: usize> $crate::illuminants::Illuminant for $ILL<I> {}
 
+        impl $ILL<{ $crate::ALL }> {
+            /// Number of illuminants in this collection.
+            pub const LEN: usize = $M;
+
+            /// Illuminant with index `i`, selected at runtime, with the same index as used for its type parameter,
+            /// starting at 1.
+            pub fn nth(
+                i: usize,
+            ) -> Result<$crate::illuminants::IlluminantData, $crate::illuminants::IlluminantIndexError> {
+                if i > 0 && i <= $M {
+                    let key = format!($DESC, i);
+                    Ok($crate::illuminants::IlluminantData::new(
+                        $DOMAIN,
+                        nalgebra::DMatrix::from_column_slice($N, 1, &$DATA[(i - 1) * $N..i * $N]),
+                    )
+                    .set_keys(&[&key])
+                    .set_description(&key))
+                } else {
+                    Err($crate::illuminants::IlluminantIndexError { index: i, len: $M })
+                }
+            }
+
+            /// Iterates over all the illuminants in this collection, in order of their index.
+            pub fn iter() -> impl Iterator<Item = $crate::illuminants::IlluminantData> {
+                (1..=$M).filter_map(|i| Self::nth(i).ok())
+            }
+        }
+
         impl<C: $crate::observers::StandardObserver, const I: usize> From<$ILL<I>>
             for $crate::models::CieXYZ<C>
         {
@@ -88,7 +153,7 @@ macro_rules! illuminant {
         }
     };
     // all illuminants as array with keys
-    ($ILL:ident, $N:expr, $M:expr, $DESC:literal, $DOMAIN:expr, $DATA:ident, $KEYS:ident) => {
+    ($ILL:ident, $N:expr, $M:expr, $DESC:literal, $DOMAIN:expr, $DATA:expr, $KEYS:expr) => {
         #[derive(Debug, Default, Clone)]
         pub struct $ILL;
 
@@ -126,7 +191,7 @@ macro_rules! illuminant {
         }
     };
     // single data illuminant
-    ($ILL:ident, $N:expr, $DESC:literal, $DOMAIN:expr, $DATA:ident) => {
+    ($ILL:ident, $N:expr, $DESC:literal, $DOMAIN:expr, $DATA:expr) => {
         #[derive(Debug, Default, Clone)]
         pub struct $ILL;
 
@@ -180,6 +245,12 @@ macro_rules! illuminant_single_test {
     };
 }
 
+#[cfg(feature = "cie_incandescent_illuminants")]
+pub mod incandescent_cie;
+
+#[cfg(feature = "cie_incandescent_illuminants")]
+pub use self::incandescent_cie::*;
+
 #[cfg(feature = "cie_fluorescent_illuminants")]
 pub mod fluorescent_cie;
 
@@ -204,7 +275,16 @@ pub mod led_cie;
 #[cfg(feature = "cie_led_illuminants")]
 pub use self::led_cie::*;
 
+#[cfg(feature = "synthetic_misc_illuminants")]
+pub mod synthetic_misc;
+
+#[cfg(feature = "synthetic_misc_illuminants")]
+pub use self::synthetic_misc::*;
+
 pub mod daylight;
 pub use self::daylight::*;
 
+pub mod solar;
+pub use self::solar::*;
+
 pub use illuminant;
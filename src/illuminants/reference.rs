@@ -0,0 +1,181 @@
+/*!
+Reference illuminants for color rendering calculations.
+
+Color rendering metrics compare the colors of test samples, illuminated by a test source, with their colors
+illuminated by a reference illuminant with the same correlated color temperature. At low temperatures this is a
+blackbody radiator, and at high temperatures a CIE daylight illuminant; the standards differ in how they switch
+between the two:
+
+- CIE 13.3, the color rendering index, uses a blackbody radiator below 5000K, and CIE daylight from 5000K;
+- ANSI/IES TM-30 uses a blackbody radiator up to 4000K, CIE daylight from 5000K, and a mix of the two in between,
+  with the weight of the daylight component increasing linearly with temperature, from 0.0 at 4000K, to 1.0 at
+  5000K;
+- EBU TLCI-2012, the television lighting consistency index, uses a blackbody radiator up to 3400K, CIE daylight
+  from 5000K, and a linear mix in between.
+*/
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::illuminants::{
+    CctParameters, CieDaylight, Illuminant, Planckian, PlanckianNormalization,
+};
+use crate::models::CieXYZ;
+use crate::observers::StandardObserver;
+use crate::{Domain, SpectralDistribution, WavelengthStep};
+
+/// Selection of the reference illuminant for a correlated color temperature, as used by a color rendering metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReferenceSelection {
+    /// CIE 13.3: a blackbody radiator below 5000K, and CIE daylight from 5000K.
+    #[default]
+    Cri,
+    /// ANSI/IES TM-30: a blackbody radiator up to 4000K, CIE daylight from 5000K, and a mix in between.
+    Tm30,
+    /// EBU TLCI-2012: a blackbody radiator up to 3400K, CIE daylight from 5000K, and a mix in between.
+    Tlci,
+}
+
+/**
+Reference illuminants for a set of correlated color temperatures, each scaled to a luminance, or tristimulus Y
+value, of 100.0, for the default observer.
+
+# Examples
+```
+use scot::illuminants::{ReferenceIlluminant, ReferenceSelection};
+use scot::models::CieYxy;
+use scot::observers::CieObs1931;
+
+let refs = ReferenceIlluminant::new(vec![3000.0, 4500.0, 6500.0], ReferenceSelection::Tm30);
+assert_eq!(refs.daylight_weight(4500.0), 0.5);
+let yxy: CieYxy<CieObs1931> = refs.into();
+println!("{}", yxy);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct ReferenceIlluminant {
+    pub ccts: CctParameters,
+    pub selection: ReferenceSelection,
+    pub domain: Domain<WavelengthStep>,
+}
+
+impl ReferenceIlluminant {
+    pub fn new(parameters: impl Into<CctParameters>, selection: ReferenceSelection) -> Self {
+        Self {
+            ccts: parameters.into(),
+            selection,
+            domain: Domain::default(),
+        }
+    }
+
+    pub fn set_domain(mut self, domain: Domain<WavelengthStep>) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Weight of the CIE daylight component in the reference illuminant, for a correlated color temperature `t`.
+    pub fn daylight_weight(&self, t: f64) -> f64 {
+        match self.selection {
+            ReferenceSelection::Cri => {
+                if t < 5000.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            ReferenceSelection::Tm30 => ((t - 4000.0) / 1000.0).clamp(0.0, 1.0),
+            ReferenceSelection::Tlci => ((t - 3400.0) / 1600.0).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Default for ReferenceIlluminant {
+    fn default() -> Self {
+        Self::new(6500.0, ReferenceSelection::default())
+    }
+}
+
+impl SpectralDistribution for ReferenceIlluminant {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let d = self.domain.clone();
+        let mut m = DMatrix::zeros(d.len(), self.ccts.len());
+        for (j, t) in (&self.ccts).into_iter().enumerate() {
+            let w = self.daylight_weight(t);
+            let mut c = DVector::zeros(d.len());
+            if w < 1.0 {
+                let (_, p) = Planckian::new(t)
+                    .set_domain(d.clone())
+                    .set_normalization(PlanckianNormalization::Y100)
+                    .spd();
+                c += p.column(0) * (1.0 - w);
+            }
+            if w > 0.0 {
+                let s = CieDaylight::new(t)
+                    .normalize_luminance(100.0)
+                    .map_domain(d.clone());
+                c += s.column(0) * w;
+            }
+            m.set_column(j, &c);
+        }
+        (d, m)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.domain.len(), self.ccts.len())
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        self.ccts.keys()
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(format!("Reference Illuminant ({:?})", self.selection))
+    }
+}
+
+impl Illuminant for ReferenceIlluminant {}
+
+impl<C: StandardObserver> From<ReferenceIlluminant> for CieXYZ<C> {
+    fn from(r: ReferenceIlluminant) -> Self {
+        r.xyz().normalize(100.0)
+    }
+}
+
+#[test]
+fn test_reference_illuminant() {
+    use crate::illuminants::{CctDuvCalc, Robertson};
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let cri = ReferenceIlluminant::new(vec![2700.0, 4500.0, 5000.0], ReferenceSelection::Cri);
+    let tm30 = ReferenceIlluminant::new(vec![2700.0, 4500.0, 5000.0], ReferenceSelection::Tm30);
+    let (_, c) = cri.spd();
+    let (_, t) = tm30.spd();
+
+    // the same below 4000K, and from 5000K
+    assert_abs_diff_eq!(c.column(0), t.column(0), epsilon = 1E-10);
+    assert_abs_diff_eq!(c.column(2), t.column(2), epsilon = 1E-10);
+
+    // at 4500K, the TM30 reference is the mean of a blackbody, and a daylight spectrum, both with Y=100
+    let p = Planckian::new(4500.0)
+        .set_normalization(PlanckianNormalization::Y100)
+        .spd()
+        .1;
+    let dl = CieDaylight::new(4500.0)
+        .normalize_luminance(100.0)
+        .map_domain(Domain::default());
+    assert_abs_diff_eq!(c.column(1), p.column(0), epsilon = 1E-10);
+    assert_abs_diff_eq!(
+        t.column(1).into_owned(),
+        (p.column(0) + dl.column(0)) / 2.0,
+        epsilon = 1E-10
+    );
+
+    // the correlated color temperatures of the references are close to their nominal values
+    let r: Robertson<CieObs1931> = Robertson::new();
+    for td in r.cct_duv(tm30) {
+        assert!(td.d.abs() < 0.005, "{}", td.d);
+    }
+}
@@ -0,0 +1,234 @@
+/*!
+Spectral distribution of daylight, from the position of the sun, and the turbidity of the atmosphere.
+
+The CIE daylight illuminants are statistical averages, which do not depend on location or time of day. For
+architectural, and horticultural simulations, `SolarDaylight` calculates the global, direct and diffuse, spectral
+irradiance on a horizontal plane with a simplified version of the Bird and Riordan (1986) clear sky model:
+
+- the extraterrestrial solar spectrum is approximated by a 5778K blackbody, scaled to a mean solar constant of
+  about 1367 W/m<sup>2</sup>, and corrected for the Earth-Sun distance;
+- the direct beam is attenuated by Rayleigh scattering, and by aerosol extinction according to Ångström's
+  turbidity formula, with the Kasten and Young (1989) relative air mass;
+- scattered light reaches the ground as diffuse skylight, with half of the Rayleigh scattered light, and 82% of the
+  light scattered by aerosols, directed downwards.
+
+Absorption by ozone, water vapor, and oxygen is not included, and neither are the Fraunhofer lines in the solar
+spectrum, so the results are smooth spectra, suitable to estimate the color, and the relative spectral composition,
+of daylight at a given location and time, but not the absorption bands of measured daylight spectra.
+*/
+
+use std::f64::consts::PI;
+
+use nalgebra::DVector;
+
+use crate::illuminants::Illuminant;
+use crate::models::CieXYZ;
+use crate::observers::StandardObserver;
+use crate::{planck_c2, C2};
+use crate::{Domain, SpectralDistribution, Unit, WavelengthStep};
+
+/// Effective blackbody temperature of the sun, in Kelvin.
+pub const SUN_TEMPERATURE: f64 = 5778.0;
+
+/// Square of the ratio of the solar radius, and the mean Earth-Sun distance.
+const SUN_SOLID_ANGLE_FACTOR: f64 = (6.957E8 / 1.496E11) * (6.957E8 / 1.496E11);
+
+/**
+Global horizontal spectral irradiance, in W/m<sup>2</sup>/m, of a clear sky, for a location, a date, and a local
+solar time.
+
+The date is specified by its day of the year, starting with 1 for January 1st, and the time as local apparent solar
+time, in hours, with the sun at its highest position at 12.0.  Atmospheric turbidity is given by the Ångström
+turbidity coefficient &beta;, the aerosol optical depth at 1&mu;m, ranging from about 0.0 for a very clean, to
+about 0.5 for a very hazy, atmosphere.
+With the sun below the horizon, all the spectral values are zero.
+
+# Examples
+Daylight in Amsterdam, at 52.4º latitude, on June 21st at 10:00 solar time.
+```
+use scot::illuminants::SolarDaylight;
+use scot::models::CieYxy;
+use scot::observers::CieObs1931;
+
+let sun = SolarDaylight::new(52.4, 172, 10.0).set_turbidity(0.15);
+assert!(sun.elevation() > 50.0);
+let yxy: CieYxy<CieObs1931> = sun.into();
+println!("{}", yxy);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct SolarDaylight {
+    pub latitude: f64,
+    pub day_of_year: u32,
+    pub solar_time: f64,
+    pub beta: f64,
+    pub alpha: f64,
+    pub domain: Domain<WavelengthStep>,
+}
+
+impl SolarDaylight {
+    pub fn new(latitude: f64, day_of_year: u32, solar_time: f64) -> Self {
+        Self {
+            latitude,
+            day_of_year,
+            solar_time,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the Ångström turbidity coefficient &beta;, with a default value of 0.1.
+    pub fn set_turbidity(mut self, beta: f64) -> Self {
+        self.beta = beta;
+        self
+    }
+
+    /// Sets the Ångström wavelength exponent &alpha;, with a default value of 1.3, for a typical continental aerosol.
+    pub fn set_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn set_domain(mut self, domain: Domain<WavelengthStep>) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Solar declination, in degrees, using Cooper's equation.
+    pub fn declination(&self) -> f64 {
+        23.45 * (2.0 * PI * (284.0 + self.day_of_year as f64) / 365.0).sin()
+    }
+
+    /// Solar elevation above the horizon, in degrees.
+    pub fn elevation(&self) -> f64 {
+        let phi = self.latitude.to_radians();
+        let delta = self.declination().to_radians();
+        let h = (15.0 * (self.solar_time - 12.0)).to_radians();
+        (phi.sin() * delta.sin() + phi.cos() * delta.cos() * h.cos())
+            .asin()
+            .to_degrees()
+    }
+
+    /// Relative optical air mass, according to Kasten and Young (1989), or `f64::INFINITY` with the sun below the
+    /// horizon.
+    pub fn air_mass(&self) -> f64 {
+        let el = self.elevation();
+        if el <= 0.0 {
+            f64::INFINITY
+        } else {
+            1.0 / (el.to_radians().sin() + 0.50572 * (el + 6.07995).powf(-1.6364))
+        }
+    }
+
+    /// Extraterrestrial spectral irradiance, in W/m<sup>2</sup>/m, at a wavelength in meter, for the Earth-Sun
+    /// distance at the day of the year.
+    pub fn extraterrestrial(&self, l: f64) -> f64 {
+        let distance = 1.0 + 0.033 * (2.0 * PI * self.day_of_year as f64 / 365.0).cos();
+        planck_c2(l, SUN_TEMPERATURE, C2) * SUN_SOLID_ANGLE_FACTOR * distance
+    }
+
+    /// Direct and diffuse spectral irradiance, in W/m<sup>2</sup>/m, on a horizontal plane, at a wavelength in
+    /// meter.
+    pub fn direct_diffuse(&self, l: f64) -> [f64; 2] {
+        let m = self.air_mass();
+        if m.is_infinite() {
+            return [0.0, 0.0];
+        }
+        let l_um = l * 1E6;
+        let tau_r = 0.008735 * l_um.powf(-4.08);
+        let tau_a = self.beta * l_um.powf(-self.alpha);
+        let (omega, fs) = (0.945, 0.82); // aerosol single scattering albedo, and forward scattering fraction
+        let tr = (-m * tau_r).exp();
+        let taa = (-m * (1.0 - omega) * tau_a).exp();
+        let tas = (-m * omega * tau_a).exp();
+        let e = self.extraterrestrial(l) * self.elevation().to_radians().sin();
+        let direct = e * tr * taa * tas;
+        let diffuse = e * taa * (0.5 * (1.0 - tr.powf(0.95)) + tr.powf(1.5) * (1.0 - tas) * fs);
+        [direct, diffuse]
+    }
+}
+
+impl Default for SolarDaylight {
+    fn default() -> Self {
+        Self {
+            latitude: 0.0,
+            day_of_year: 80,
+            solar_time: 12.0,
+            beta: 0.1,
+            alpha: 1.3,
+            domain: Domain::default(),
+        }
+    }
+}
+
+impl SpectralDistribution for SolarDaylight {
+    type MatrixType = DVector<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let d = self.domain.clone();
+        let v = DVector::from_iterator(
+            d.len(),
+            d.iter()
+                .map(|l| self.direct_diffuse(l.value()).iter().sum()),
+        );
+        (d, v)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.domain.len(), 1)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(format!(
+            "Solar Daylight {:.1}º, day {}, {:.2}h",
+            self.latitude, self.day_of_year, self.solar_time
+        ))
+    }
+}
+
+impl Illuminant for SolarDaylight {}
+
+impl<C: StandardObserver> From<SolarDaylight> for CieXYZ<C> {
+    fn from(sun: SolarDaylight) -> Self {
+        sun.xyz().normalize(100.0)
+    }
+}
+
+#[test]
+fn test_solar_daylight() {
+    use crate::illuminants::{CctDuvCalc, Robertson};
+    use crate::observers::CieObs1931;
+    use crate::Step;
+    use approx::assert_abs_diff_eq;
+
+    // summer solstice, at noon
+    let sun = SolarDaylight::new(52.0, 172, 12.0);
+    assert_abs_diff_eq!(sun.elevation(), 90.0 - 52.0 + 23.45, epsilon = 0.01);
+    assert_abs_diff_eq!(SolarDaylight::default().air_mass(), 1.0, epsilon = 0.01);
+
+    // night
+    let (_, v) = SolarDaylight::new(52.0, 172, 0.0).spd();
+    assert!(v.iter().all(|&x| x == 0.0));
+
+    // with the sun in the zenith, global irradiance is slightly above the measured values of about 1000 W/m2,
+    // as molecular absorption is not included; its color is close to D55, and a low sun, in a turbid atmosphere,
+    // is much warmer
+    let sun = SolarDaylight::default().set_domain(Domain::new(300, 3000, crate::NM));
+    let (d, v) = sun.spd();
+    let e = v.sum() * d.step.unitvalue(1).value();
+    assert!(e > 1000.0 && e < 1250.0, "{}", e);
+
+    let r: Robertson<CieObs1931> = Robertson::new();
+    let noon = r
+        .cct_duv(SolarDaylight::default())
+        .into_iter()
+        .next()
+        .unwrap();
+    let evening = r
+        .cct_duv(SolarDaylight::new(0.0, 80, 17.5).set_turbidity(0.3))
+        .into_iter()
+        .next()
+        .unwrap();
+    assert!(noon.t > 5000.0 && noon.t < 6000.0, "{}", noon.t);
+    assert!(evening.t < noon.t - 500.0, "{}", evening.t);
+}
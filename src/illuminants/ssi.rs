@@ -0,0 +1,137 @@
+/*!
+The Academy Spectral Similarity Index, SSI.
+
+The Spectral Similarity Index, developed by the Academy of Motion Picture Arts and Sciences, rates how closely the
+spectral distribution of a light source matches that of a reference source, independent of the camera, or film
+stock, used. It is used on film sets, to check if a source, typically an LED luminaire, can be combined with, or
+replace, a daylight, or a tungsten, source.
+
+The index is calculated in a number of steps:
+- both spectral distributions are linearly interpolated to a domain from 375 to 675nm, in steps of 1nm, and summed
+  into 30 bands, 10nm wide, with centers from 380 to 670nm, using weights of 0.5 for the band edge values;
+- both sets of band values are normalized to a sum of 1.0;
+- the relative differences of the test values, relative to the reference values, with an offset of 1/30 to limit
+  the effect of bands with little reference power, are weighted to reduce the contribution of the shortest, and
+  longest, wavelengths;
+- the weighted differences are smoothed with a [0.22, 0.56, 0.22] kernel, with zero padding, and the SSI is
+  calculated from the sum of their squares &Sigma;, as 100 - 32 &radic;&Sigma;.
+
+A spectral distribution identical to the reference has an SSI of 100; the Academy reports the index rounded to an
+integer.
+*/
+
+use nalgebra::{DMatrix, RowDVector};
+
+use crate::{Domain, SpectralDistribution, WavelengthStep, NM};
+
+/// Number of 10nm bands used in the Spectral Similarity Index.
+pub const SSI_BANDS: usize = 30;
+
+/// Weights of the relative differences of the bands.
+static SSI_WEIGHTS: [f64; SSI_BANDS] = [
+    12.0 / 45.0,
+    22.0 / 45.0,
+    32.0 / 45.0,
+    40.0 / 45.0,
+    44.0 / 45.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    1.0,
+    11.0 / 15.0,
+    3.0 / 15.0,
+];
+
+/**
+Spectral Similarity Indices of the spectral distributions in `test`, with respect to the first spectral distribution
+in `reference`.
+
+Any spectral distribution can be used as reference; the Academy uses CIE daylight, for daylight sources, and a
+3200K blackbody radiator, for tungsten sources.
+
+# Examples
+```
+use scot::illuminants::{ssi, CieDaylight, CieIllC, CieIllD65, Planckian, FL};
+
+let d65_c = ssi(&CieIllC, &CieIllD65);
+assert_eq!(d65_c[0].round(), 94.0);
+
+let f7 = ssi(&FL::<7>, &CieDaylight::new(6500.0));
+let f12 = ssi(&FL::<12>, &Planckian::new(3000.0));
+println!("F7 {:.0} F12 {:.0}", f7[0], f12[0]);
+```
+*/
+pub fn ssi<S, R>(test: &S, reference: &R) -> RowDVector<f64>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+    R: SpectralDistribution<StepType = WavelengthStep>,
+{
+    let d = Domain::new(375, 675, NM);
+    let r = bands(&reference.map_domain(d.clone()), 0);
+    let t = test.map_domain(d);
+    RowDVector::from_fn(t.ncols(), |_, j| {
+        let t = bands(&t, j);
+        let mut wdr = [0.0; SSI_BANDS + 2];
+        for i in 0..SSI_BANDS {
+            wdr[i + 1] = (t[i] - r[i]) / (r[i] + 1.0 / SSI_BANDS as f64) * SSI_WEIGHTS[i];
+        }
+        let sum: f64 = wdr
+            .windows(3)
+            .map(|w| (0.22 * w[0] + 0.56 * w[1] + 0.22 * w[2]).powi(2))
+            .sum();
+        100.0 - 32.0 * sum.sqrt()
+    })
+}
+
+/// Band values, normalized to a sum of 1.0, of column `j` of a spectral distribution on the 375 to 675nm domain.
+fn bands(m: &DMatrix<f64>, j: usize) -> [f64; SSI_BANDS] {
+    let mut b = [0.0; SSI_BANDS];
+    for (i, v) in b.iter_mut().enumerate() {
+        let c = m.column(j);
+        *v = c.rows(10 * i, 11).sum() - 0.5 * (c[10 * i] + c[10 * i + 10]);
+    }
+    let s: f64 = b.iter().sum();
+    b.iter_mut().for_each(|v| *v /= s);
+    b
+}
+
+#[test]
+fn test_ssi() {
+    use crate::illuminants::{CieDaylight, CieIllC, CieIllD65, Planckian, FL};
+    use approx::assert_abs_diff_eq;
+
+    // value from the colour-science reference implementation
+    assert_eq!(ssi(&CieIllC, &CieIllD65)[0].round(), 94.0);
+
+    assert_abs_diff_eq!(ssi(&CieIllD65, &CieIllD65)[0], 100.0, epsilon = 1E-10);
+    let p = ssi(
+        &Planckian::new(vec![3200.0, 3000.0, 2500.0]),
+        &Planckian::new(3200.0),
+    );
+    assert_abs_diff_eq!(p[0], 100.0, epsilon = 1E-10);
+    assert!(p[0] > p[1] && p[1] > p[2]);
+
+    // a halophosphate lamp is a poor match for daylight, a triphosphor lamp even more so
+    let fl = ssi(&FL::<1>, &CieDaylight::new(6430.0))[0];
+    let tri = ssi(&FL::<10>, &CieDaylight::new(5000.0))[0];
+    assert!(fl < 80.0 && tri < fl, "{} {}", fl, tri);
+}
@@ -0,0 +1,166 @@
+/*!
+Synthetic spectral distributions of light sources often found in forensic, and photographic, applications, which are
+not included in the CIE and IES collections: low-pressure sodium lamps, xenon flash tubes, and candle flames.
+
+These are synthetic spectral distributions, modeled and not measured, tabulated from 380 to 780nm in steps of 1nm,
+and normalized to a maximum value of 100.0:
+
+- `SyntheticLowPressureSodium` has the sodium D lines, at 589.0 and 589.6nm, with a width of 1.5nm, and the weak
+  sodium lines at 498, 515, 569, and 616nm, on a low continuum background;
+- `SyntheticXenonFlash` has a 6000K Planckian continuum, as produced by the high current density discharge in a flash
+  tube, with the strongest visible xenon lines, from 462 to 610nm, superimposed;
+- `SyntheticCandleFlame` is the incandescent radiation of soot particles, at a temperature of 1900K, with a weak
+  sodium D line emission, as found in the spectra of most candles.
+
+Use these to get the color of these sources, and their effect on the appearance of colored objects; for accurate
+results, use measured data of a particular lamp, for example using `IlluminantData`.
+*/
+
+use crate::{Domain, NM};
+
+const N: usize = 401;
+
+illuminant!(
+    SyntheticLowPressureSodium,
+    N,
+    "Synthetic Low Pressure Sodium Lamp",
+    Domain::new(380, 780, NM),
+    LPS_DATA
+);
+
+illuminant!(
+    SyntheticXenonFlash,
+    N,
+    "Synthetic Xenon Flash",
+    Domain::new(380, 780, NM),
+    XENON_FLASH_DATA
+);
+
+illuminant!(
+    SyntheticCandleFlame,
+    N,
+    "Synthetic Candle Flame",
+    Domain::new(380, 780, NM),
+    CANDLE_DATA
+);
+
+static LPS_DATA: [f64; N] = [
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.013, 0.1999, 0.0992, 0.0047, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.01, 0.1346, 0.0675, 0.0045, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.0062, 0.5786, 2.201, 0.2371, 0.0043, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.0093, 1.153,
+    28.24, 100.0, 63.68, 7.322, 0.1236, 0.0042, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.0211, 0.6447, 1.007, 0.1473, 0.0044, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+    0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004, 0.004,
+];
+
+static XENON_FLASH_DATA: [f64; N] = [
+    78.26, 78.53, 78.8, 79.06, 79.32, 79.58, 79.84, 80.09, 80.34, 80.58, 80.82, 81.06, 81.3, 81.53,
+    81.76, 81.99, 82.21, 82.43, 82.65, 82.87, 83.08, 83.29, 83.49, 83.69, 83.89, 84.09, 84.28,
+    84.47, 84.66, 84.84, 85.02, 85.2, 85.38, 85.55, 85.72, 85.88, 86.05, 86.21, 86.36, 86.52,
+    86.67, 86.82, 86.97, 87.11, 87.25, 87.39, 87.52, 87.65, 87.78, 87.91, 88.03, 88.15, 88.27,
+    88.39, 88.5, 88.61, 88.72, 88.82, 88.93, 89.03, 89.12, 89.22, 89.31, 89.4, 89.49, 89.57, 89.65,
+    89.73, 89.81, 89.88, 89.96, 90.03, 90.09, 90.16, 90.22, 90.28, 90.34, 90.39, 90.45, 90.5,
+    90.72, 92.42, 96.21, 95.61, 92.0, 91.45, 95.16, 100.0, 96.45, 91.87, 90.96, 91.07, 92.33,
+    95.16, 94.7, 91.96, 91.1, 91.05, 91.09, 91.7, 93.91, 94.72, 92.58, 92.82, 95.96, 95.39, 92.17,
+    91.14, 91.06, 91.05, 91.2, 92.64, 95.37, 94.4, 91.74, 90.99, 90.92, 90.9, 90.87, 90.85, 90.82,
+    90.79, 90.75, 90.72, 90.69, 90.65, 90.61, 90.57, 90.53, 90.49, 90.44, 90.4, 90.35, 90.3, 90.25,
+    90.2, 90.14, 90.09, 90.03, 89.98, 89.92, 89.86, 89.8, 89.73, 89.67, 89.61, 89.54, 89.62, 90.67,
+    92.34, 91.33, 89.6, 89.14, 89.04, 88.97, 88.89, 88.81, 88.73, 88.65, 88.59, 88.82, 90.27,
+    91.38, 89.69, 88.35, 88.07, 87.97, 87.88, 87.79, 87.7, 87.61, 87.51, 87.42, 87.32, 87.23,
+    87.13, 87.03, 86.93, 86.83, 86.73, 86.63, 86.53, 86.43, 86.32, 86.22, 86.11, 86.01, 85.9,
+    85.79, 85.68, 85.58, 85.47, 85.35, 85.24, 85.13, 85.02, 84.91, 84.79, 84.68, 84.56, 84.45,
+    84.33, 84.21, 84.09, 83.98, 83.86, 83.74, 83.62, 83.5, 83.38, 83.25, 83.13, 83.01, 82.89,
+    82.76, 82.67, 82.99, 84.24, 84.35, 82.82, 82.07, 81.88, 81.76, 81.78, 82.59, 83.66, 82.64,
+    81.37, 81.3, 82.4, 82.97, 81.53, 80.57, 80.33, 80.19, 80.06, 79.93, 79.79, 79.66, 79.53, 79.39,
+    79.26, 79.12, 78.99, 78.85, 78.72, 78.58, 78.44, 78.31, 78.17, 78.03, 77.9, 77.76, 77.62,
+    77.48, 77.34, 77.21, 77.07, 76.93, 76.79, 76.65, 76.51, 76.37, 76.23, 76.09, 75.95, 75.81,
+    75.67, 75.53, 75.39, 75.25, 75.11, 74.97, 74.83, 74.69, 74.54, 74.4, 74.26, 74.12, 73.98,
+    73.84, 73.69, 73.55, 73.41, 73.27, 73.13, 72.98, 72.84, 72.7, 72.56, 72.41, 72.27, 72.13,
+    71.99, 71.84, 71.7, 71.56, 71.42, 71.27, 71.13, 70.99, 70.85, 70.7, 70.56, 70.42, 70.27, 70.13,
+    69.99, 69.85, 69.7, 69.56, 69.42, 69.28, 69.13, 68.99, 68.85, 68.71, 68.57, 68.42, 68.28,
+    68.14, 68.0, 67.85, 67.71, 67.57, 67.43, 67.29, 67.15, 67.0, 66.86, 66.72, 66.58, 66.44, 66.3,
+    66.16, 66.02, 65.88, 65.74, 65.6, 65.45, 65.31, 65.17, 65.03, 64.89, 64.75, 64.61, 64.48,
+    64.34, 64.2, 64.06, 63.92, 63.78, 63.64, 63.5, 63.36, 63.22, 63.09, 62.95, 62.81, 62.67, 62.54,
+    62.4, 62.26, 62.12, 61.99, 61.85, 61.71, 61.58, 61.44, 61.3, 61.17, 61.03, 60.9, 60.76, 60.63,
+    60.49, 60.36, 60.22, 60.09, 59.95, 59.82, 59.68, 59.55, 59.42, 59.28, 59.15, 59.02, 58.88,
+    58.75, 58.62, 58.49, 58.35, 58.22, 58.09, 57.96, 57.83, 57.7, 57.56, 57.43, 57.3, 57.17,
+];
+
+static CANDLE_DATA: [f64; N] = [
+    0.1328, 0.1381, 0.1436, 0.1493, 0.1551, 0.1612, 0.1674, 0.1738, 0.1805, 0.1874, 0.1944, 0.2017,
+    0.2093, 0.217, 0.225, 0.2333, 0.2418, 0.2505, 0.2595, 0.2688, 0.2784, 0.2882, 0.2983, 0.3087,
+    0.3195, 0.3305, 0.3418, 0.3535, 0.3654, 0.3778, 0.3904, 0.4034, 0.4168, 0.4305, 0.4446, 0.459,
+    0.4739, 0.4891, 0.5048, 0.5208, 0.5373, 0.5541, 0.5715, 0.5892, 0.6074, 0.6261, 0.6452, 0.6648,
+    0.6848, 0.7054, 0.7264, 0.748, 0.7701, 0.7927, 0.8158, 0.8395, 0.8637, 0.8885, 0.9138, 0.9397,
+    0.9663, 0.9934, 1.021, 1.049, 1.078, 1.108, 1.138, 1.169, 1.201, 1.233, 1.266, 1.3, 1.334,
+    1.369, 1.405, 1.441, 1.478, 1.516, 1.555, 1.595, 1.635, 1.676, 1.718, 1.761, 1.805, 1.849,
+    1.894, 1.941, 1.988, 2.035, 2.084, 2.134, 2.185, 2.236, 2.289, 2.342, 2.397, 2.452, 2.509,
+    2.566, 2.624, 2.684, 2.744, 2.806, 2.868, 2.932, 2.997, 3.062, 3.129, 3.197, 3.266, 3.337,
+    3.408, 3.481, 3.554, 3.629, 3.705, 3.783, 3.861, 3.941, 4.022, 4.104, 4.188, 4.272, 4.359,
+    4.446, 4.535, 4.625, 4.716, 4.808, 4.902, 4.998, 5.095, 5.193, 5.292, 5.393, 5.495, 5.599,
+    5.704, 5.811, 5.919, 6.029, 6.14, 6.252, 6.366, 6.482, 6.599, 6.718, 6.838, 6.96, 7.083, 7.208,
+    7.335, 7.463, 7.592, 7.724, 7.857, 7.991, 8.128, 8.266, 8.405, 8.546, 8.689, 8.834, 8.981,
+    9.129, 9.278, 9.43, 9.583, 9.739, 9.895, 10.05, 10.21, 10.38, 10.54, 10.71, 10.87, 11.04,
+    11.22, 11.39, 11.56, 11.74, 11.92, 12.1, 12.28, 12.47, 12.65, 12.84, 13.03, 13.22, 13.42,
+    13.61, 13.81, 14.01, 14.21, 14.42, 14.62, 14.83, 15.04, 15.25, 15.47, 15.68, 15.9, 16.12,
+    16.34, 16.57, 16.79, 17.02, 17.9, 22.9, 20.37, 18.01, 18.19, 18.43, 18.67, 18.92, 19.17, 19.41,
+    19.67, 19.92, 20.17, 20.43, 20.69, 20.95, 21.22, 21.48, 21.75, 22.02, 22.29, 22.57, 22.84,
+    23.12, 23.4, 23.69, 23.97, 24.26, 24.55, 24.84, 25.13, 25.43, 25.73, 26.03, 26.33, 26.64,
+    26.94, 27.25, 27.56, 27.88, 28.19, 28.51, 28.83, 29.15, 29.48, 29.8, 30.13, 30.46, 30.8, 31.13,
+    31.47, 31.81, 32.15, 32.5, 32.84, 33.19, 33.54, 33.9, 34.25, 34.61, 34.97, 35.33, 35.7, 36.06,
+    36.43, 36.8, 37.17, 37.55, 37.93, 38.31, 38.69, 39.07, 39.46, 39.84, 40.24, 40.63, 41.02,
+    41.42, 41.82, 42.22, 42.62, 43.03, 43.44, 43.84, 44.26, 44.67, 45.09, 45.5, 45.93, 46.35,
+    46.77, 47.2, 47.63, 48.06, 48.49, 48.93, 49.36, 49.8, 50.24, 50.69, 51.13, 51.58, 52.03, 52.48,
+    52.93, 53.39, 53.85, 54.31, 54.77, 55.23, 55.7, 56.17, 56.64, 57.11, 57.58, 58.06, 58.54,
+    59.02, 59.5, 59.98, 60.47, 60.95, 61.44, 61.93, 62.43, 62.92, 63.42, 63.92, 64.42, 64.92,
+    65.43, 65.93, 66.44, 66.95, 67.46, 67.98, 68.49, 69.01, 69.53, 70.05, 70.57, 71.1, 71.62,
+    72.15, 72.68, 73.21, 73.75, 74.28, 74.82, 75.36, 75.9, 76.44, 76.98, 77.53, 78.07, 78.62,
+    79.17, 79.73, 80.28, 80.83, 81.39, 81.95, 82.51, 83.07, 83.63, 84.2, 84.77, 85.33, 85.9, 86.47,
+    87.05, 87.62, 88.19, 88.77, 89.35, 89.93, 90.51, 91.09, 91.68, 92.26, 92.85, 93.44, 94.03,
+    94.62, 95.21, 95.81, 96.4, 97.0, 97.6, 98.19, 98.8, 99.4, 100.0,
+];
+
+#[test]
+fn test_synthetic_misc_illuminants() {
+    use crate::illuminants::{CctDuvCalc, Robertson};
+    use crate::models::{CieYxy, YxyValues};
+    use crate::observers::CieObs1931;
+
+    let YxyValues { l: _, x, y } = CieYxy::<CieObs1931>::from(SyntheticLowPressureSodium)
+        .into_iter()
+        .next()
+        .unwrap();
+    // close to the spectral locus at 589nm
+    assert!(x > 0.56 && y > 0.41, "{} {}", x, y);
+
+    let r: Robertson<CieObs1931> = Robertson::new();
+    let xenon = r.cct_duv(SyntheticXenonFlash).into_iter().next().unwrap();
+    let candle = r.cct_duv(SyntheticCandleFlame).into_iter().next().unwrap();
+    assert!(xenon.t > 5500.0 && xenon.t < 6500.0, "{}", xenon.t);
+    assert!(candle.t > 1800.0 && candle.t < 2000.0, "{}", candle.t);
+}
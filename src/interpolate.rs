@@ -15,7 +15,7 @@ use std::ops::Index;
 use nalgebra::{storage::Storage, DMatrix, Dim, Matrix};
 use nalgebra::{Const, Dynamic, Matrix3xX, MatrixSlice3xX, OMatrix, VecStorage};
 
-use crate::Domain;
+use crate::{Domain, IrregularDomain, WavelengthStep};
 use crate::{Step, Unit};
 
 use super::IterInterpolateType;
@@ -236,6 +236,324 @@ fn test_interp_col2() {
     );
 }
 
+/**
+Interpolation methods, as used by `SpectralDistribution::resample`.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interp {
+    /// Linear interpolation, which does not overshoot, and is the preferred choice for peaky spectra.
+    Linear,
+    /// Sprague interpolation, as recommended by the CIE for smooth spectral data with uniform intervals.
+    Sprague,
+    /// Natural cubic spline interpolation.
+    Spline,
+    /// Piecewise cubic Hermite interpolation, which preserves the monotonicity of the data, and does not overshoot.
+    Pchip,
+    /// Linear interpolation for peaky spectra, such as the spectra of fluorescent and discharge lamps, and Sprague
+    /// interpolation for smooth spectra, as determined by `is_peaky`, unless a spectral distribution type has its own
+    /// preference, given by `SpectralDistribution::interpolation`.
+    Auto,
+}
+
+/// Ratio of the largest second difference, and the largest value, of a spectral distribution, above which it is
+/// considered to be peaky.
+pub const PEAKINESS_LIMIT: f64 = 0.5;
+
+/**
+    Checks if any of the columns of a data matrix, with `n` rows and `nc` columns, has narrow peaks, such as the
+    emission lines of fluorescent lamps, which are poorly interpolated with higher order interpolation methods.
+
+    Smooth spectra, such as daylight, and the spectra of incandescent lamps, and white LEDs, have second differences
+    which are much smaller than their peak values, while the emission lines of a fluorescent lamp, sampled at 5nm
+    intervals, have second differences which exceed their peak values.
+*/
+pub fn is_peaky<I>(n: usize, nc: usize, data: &I) -> bool
+where
+    I: Index<(usize, usize), Output = f64>,
+{
+    (0..nc).any(|c| {
+        let max = (0..n).map(|i| data[(i, c)].abs()).fold(0.0, f64::max);
+        let curvature = (1..n.max(1) - 1)
+            .map(|i| (data[(i - 1, c)] - 2.0 * data[(i, c)] + data[(i + 1, c)]).abs())
+            .fold(0.0, f64::max);
+        max > 0.0 && curvature / max > PEAKINESS_LIMIT
+    })
+}
+
+/**
+    Interpolate matrix values by column, using natural cubic splines, with values of 0.0 outside the range of the
+    from domain.
+*/
+pub fn spline_interp_mat_col<S1, S2, I>(
+    from_domain: &Domain<S1>,
+    to_domain: &Domain<S2>,
+    nc: usize,
+    data: I,
+) -> DMatrix<f64>
+where
+    S1: Step + Clone + Copy,
+    S2: Step + Clone + Copy,
+    S1::UnitValueType: From<<S2>::UnitValueType>,
+    I: Index<(usize, usize), Output = f64>,
+{
+    let n = from_domain.len();
+    // second derivatives, in units of the from domain step, solved with the Thomas algorithm
+    let mut m2 = DMatrix::<f64>::zeros(n, nc);
+    if n > 2 {
+        let mut c = vec![0.0; n];
+        for col in 0..nc {
+            let mut d = vec![0.0; n];
+            for i in 1..n - 1 {
+                let r = 6.0 * (data[(i - 1, col)] - 2.0 * data[(i, col)] + data[(i + 1, col)]);
+                let w = 4.0 - if i > 1 { c[i - 1] } else { 0.0 };
+                c[i] = 1.0 / w;
+                d[i] = (r - if i > 1 { d[i - 1] } else { 0.0 }) / w;
+            }
+            m2[(n - 2, col)] = d[n - 2];
+            for i in (1..n - 2).rev() {
+                m2[(i, col)] = d[i] - c[i] * m2[(i + 1, col)];
+            }
+        }
+    }
+    let mut mto = DMatrix::<f64>::zeros(to_domain.len(), nc);
+    for ip in from_domain.iter_interpolate(to_domain) {
+        match ip {
+            IterInterpolateType::Interpolate(j, i, h) => (0..nc).for_each(|c| {
+                let g = 1.0 - h;
+                mto[(j, c)] = g * data[(i, c)]
+                    + h * data[(i + 1, c)]
+                    + ((g * g * g - g) * m2[(i, c)] + (h * h * h - h) * m2[(i + 1, c)]) / 6.0
+            }),
+            IterInterpolateType::RangeEnd(j, i) => (0..nc).for_each(|c| mto[(j, c)] = data[(i, c)]),
+            _ => (),
+        }
+    }
+    mto
+}
+
+#[test]
+fn test_spline_interp_col() {
+    use super::{NONE, NONE5};
+    use approx::assert_abs_diff_eq;
+    use nalgebra::dvector;
+
+    // a natural cubic spline reproduces a straight line, and passes through its data points
+    let dfrom = Domain::new(0, 4, NONE5);
+    let dto = Domain::new(0, 20, NONE);
+    let m = spline_interp_mat_col(&dfrom, &dto, 1, dvector![0.0, 5.0, 10.0, 15.0, 20.0]);
+    assert_abs_diff_eq!(m, DMatrix::from_fn(21, 1, |i, _| i as f64), epsilon = 1E-12);
+    let m = spline_interp_mat_col(&dfrom, &dto, 1, dvector![0.0, 1.0, 4.0, 1.0, 0.0]);
+    assert_abs_diff_eq!(m[(10, 0)], 4.0, epsilon = 1E-12);
+    assert_abs_diff_eq!(m[(5, 0)], 1.0, epsilon = 1E-12);
+    assert!(m[(12, 0)] < 4.0 && m[(12, 0)] > 1.0);
+}
+
+/**
+    Interpolate matrix values by column, using monotone piecewise cubic Hermite interpolation (PCHIP), with the
+    derivatives as given by Fritsch and Carlson, and values of 0.0 outside the range of the from domain.
+
+    See "Monotone Piecewise Cubic Interpolation", F.N. Fritsch, and R.E. Carlson, SIAM Journal on Numerical Analysis,
+    volume 17, no 2, 1980.
+*/
+pub fn pchip_interp_mat_col<S1, S2, I>(
+    from_domain: &Domain<S1>,
+    to_domain: &Domain<S2>,
+    nc: usize,
+    data: I,
+) -> DMatrix<f64>
+where
+    S1: Step + Clone + Copy,
+    S2: Step + Clone + Copy,
+    S1::UnitValueType: From<<S2>::UnitValueType>,
+    I: Index<(usize, usize), Output = f64>,
+{
+    let n = from_domain.len();
+    // derivatives, in units of the from domain step
+    let mut dy = DMatrix::<f64>::zeros(n, nc);
+    if n > 1 {
+        for c in 0..nc {
+            let delta: Vec<f64> = (0..n - 1)
+                .map(|i| data[(i + 1, c)] - data[(i, c)])
+                .collect();
+            for i in 1..n - 1 {
+                let (d0, d1) = (delta[i - 1], delta[i]);
+                if d0 * d1 > 0.0 {
+                    dy[(i, c)] = 2.0 / (1.0 / d0 + 1.0 / d1);
+                }
+            }
+            let end = |d0: f64, d1: f64| {
+                let d = (3.0 * d0 - d1) / 2.0;
+                if d * d0 <= 0.0 {
+                    0.0
+                } else if d0 * d1 <= 0.0 && d.abs() > 3.0 * d0.abs() {
+                    3.0 * d0
+                } else {
+                    d
+                }
+            };
+            if n > 2 {
+                dy[(0, c)] = end(delta[0], delta[1]);
+                dy[(n - 1, c)] = end(delta[n - 2], delta[n - 3]);
+            } else {
+                dy[(0, c)] = delta[0];
+                dy[(1, c)] = delta[0];
+            }
+        }
+    }
+    let mut mto = DMatrix::<f64>::zeros(to_domain.len(), nc);
+    for ip in from_domain.iter_interpolate(to_domain) {
+        match ip {
+            IterInterpolateType::Interpolate(j, i, h) => (0..nc).for_each(|c| {
+                let (h2, h3) = (h * h, h * h * h);
+                mto[(j, c)] = (2.0 * h3 - 3.0 * h2 + 1.0) * data[(i, c)]
+                    + (h3 - 2.0 * h2 + h) * dy[(i, c)]
+                    + (-2.0 * h3 + 3.0 * h2) * data[(i + 1, c)]
+                    + (h3 - h2) * dy[(i + 1, c)]
+            }),
+            IterInterpolateType::RangeEnd(j, i) => (0..nc).for_each(|c| mto[(j, c)] = data[(i, c)]),
+            _ => (),
+        }
+    }
+    mto
+}
+
+#[test]
+fn test_pchip_interp_col() {
+    use super::{NONE, NONE5};
+    use approx::assert_abs_diff_eq;
+    use nalgebra::dvector;
+
+    // a step is interpolated without overshoot, and the data points are reproduced
+    let dfrom = Domain::new(0, 5, NONE5);
+    let dto = Domain::new(0, 25, NONE);
+    let m = pchip_interp_mat_col(&dfrom, &dto, 1, dvector![0.0, 0.0, 0.1, 1.0, 1.0, 1.0]);
+    assert!(m.iter().all(|&v| (0.0..=1.0).contains(&v)));
+    assert!(m.as_slice().windows(2).all(|w| w[1] >= w[0]));
+    assert_abs_diff_eq!(m[(10, 0)], 0.1, epsilon = 1E-12);
+    assert_abs_diff_eq!(m[(25, 0)], 1.0, epsilon = 1E-12);
+
+    // in contrast to a spline
+    let s = spline_interp_mat_col(&dfrom, &dto, 1, dvector![0.0, 0.0, 0.1, 1.0, 1.0, 1.0]);
+    assert!(s.max() > 1.0);
+}
+
+/**
+    Average matrix values by column, over the bands of a coarser to domain, centered at its points, and with a width
+    equal to its step size.
+
+    In contrast to interpolation, which samples the data at the points of the to domain, all the data within a band
+    contribute to its value, with each value taken to represent the band of its own domain step, so that integrals, such
+    as tristimulus values, are preserved when downsampling, for example, from 1nm to 5 or 10nm data.
+    Parts of a band outside the from domain count as zero.
+*/
+pub fn band_average_mat_col<S1, S2, I>(
+    from_domain: &Domain<S1>,
+    to_domain: &Domain<S2>,
+    nc: usize,
+    data: I,
+) -> DMatrix<f64>
+where
+    S1: Step + Clone + Copy,
+    S2: Step + Clone + Copy,
+    S1::UnitValueType: From<<S2>::UnitValueType>,
+    I: Index<(usize, usize), Output = f64>,
+{
+    let n = from_domain.len();
+    let start = from_domain.step.unitvalue(from_domain.range.start).value();
+    let div = from_domain.step.unitvalue(1).value();
+    // band centers, in units of the from domain step, relative to its start
+    let centers: Vec<f64> = to_domain
+        .iter()
+        .map(|ut| (Into::<S1::UnitValueType>::into(ut).value() - start) / div)
+        .collect();
+    let half_width = if centers.len() > 1 {
+        (centers[1] - centers[0]).abs() / 2.0
+    } else {
+        0.5
+    };
+    let mut mto = DMatrix::<f64>::zeros(to_domain.len(), nc);
+    for (j, &p) in centers.iter().enumerate() {
+        let (lo, hi) = (p - half_width, p + half_width);
+        let first = (lo + 0.5).floor().max(0.0) as usize;
+        let last = ((hi + 0.5).ceil().max(0.0) as usize).min(n);
+        for i in first..last {
+            let overlap = (hi.min(i as f64 + 0.5) - lo.max(i as f64 - 0.5)).max(0.0);
+            if overlap > 0.0 {
+                (0..nc).for_each(|c| mto[(j, c)] += data[(i, c)] * overlap / (hi - lo));
+            }
+        }
+    }
+    mto
+}
+
+#[test]
+fn test_band_average_col() {
+    use super::{NONE, NONE5};
+    use approx::assert_abs_diff_eq;
+
+    // 1 to 5 step averaging, with a ramp, and partially covered bands at the ends
+    let dfrom = Domain::new(0, 20, NONE);
+    let dto = Domain::new(0, 4, NONE5);
+    let m = band_average_mat_col(&dfrom, &dto, 1, DMatrix::from_fn(21, 1, |i, _| i as f64));
+    assert_abs_diff_eq!(m[(1, 0)], 5.0, epsilon = 1E-12);
+    assert_abs_diff_eq!(m[(3, 0)], 15.0, epsilon = 1E-12);
+    assert_abs_diff_eq!(m[(0, 0)], (0.0 + 1.0 + 2.0) / 5.0, epsilon = 1E-12);
+    assert_abs_diff_eq!(
+        m.sum() * 5.0,
+        (0..=20).sum::<usize>() as f64,
+        epsilon = 1E-9
+    );
+}
+
+/**
+    Interpolate matrix values by column, from an irregular domain to a regular wavelength domain, using linear
+    interpolation, with values of 0.0 outside the range of the irregular domain.
+*/
+pub fn lin_interp_irregular_mat_col<I>(
+    from_domain: &IrregularDomain,
+    to_domain: &Domain<WavelengthStep>,
+    nc: usize,
+    data: I,
+) -> DMatrix<f64>
+where
+    I: Index<(usize, usize), Output = f64>,
+{
+    let w = from_domain.wavelengths();
+    let n = w.len();
+    let eps = 1E-6 * to_domain.step.unitvalue(1).value();
+    let mut mto = DMatrix::<f64>::zeros(to_domain.len(), nc);
+    for (j, l) in to_domain.iter().enumerate() {
+        let l = l.value();
+        if l < w[0] - eps || l > w[n - 1] + eps {
+            continue;
+        }
+        let i = w.partition_point(|&v| v <= l).clamp(1, n.max(2) - 1);
+        if n == 1 {
+            (0..nc).for_each(|c| mto[(j, c)] = data[(0, c)]);
+        } else {
+            let h = ((l - w[i - 1]) / (w[i] - w[i - 1])).clamp(0.0, 1.0);
+            (0..nc).for_each(|c| mto[(j, c)] = data[(i - 1, c)] * (1.0 - h) + data[(i, c)] * h);
+        }
+    }
+    mto
+}
+
+#[test]
+fn test_lin_interp_irregular() {
+    use crate::NM;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::dvector;
+
+    let dfrom = IrregularDomain::from_nm(vec![380.0, 382.0, 385.0]).unwrap();
+    let dto = Domain::new(379, 386, NM);
+    let m = lin_interp_irregular_mat_col(&dfrom, &dto, 1, dvector![0.0, 2.0, 8.0]);
+    assert_abs_diff_eq!(
+        m,
+        DMatrix::from_vec(8, 1, vec![0.0, 0.0, 1.0, 2.0, 4.0, 6.0, 8.0, 0.0]),
+        epsilon = 1E-12
+    );
+}
+
 /**
 Sprague interpolation, using a 5th order polynomial fitted through 6 points.
 
@@ -0,0 +1,248 @@
+/*!
+ArgyllCMS measurement, and spectrum, files.
+
+ArgyllCMS stores its test chart measurements, in `.ti3` files, and single spectra, such as those of illuminants, in
+`.sp` files, as CGATS files, with the identifiers `CTI3`, and `SPECT`. Spectral values are stored in `SPEC_` fields,
+such as `SPEC_380`, with the wavelength range given by the `SPECTRAL_START_NM`, and `SPECTRAL_END_NM` keywords, and
+scaled by the value of the `SPECTRAL_NORM` keyword. Without this keyword, the spectra of `.ti3` files of reflective,
+or transmissive, measurements are taken to be in percent, and other spectra as is.
+
+`Argyll::parse` reads a file, with the first data set of a `.ti3` file, and its spectra can be read as a
+`SwatchLibrary`, with `Argyll::swatches`, or, for emissive measurements, such as display patches, or light sources,
+as an `IlluminantData` collection, with `Argyll::illuminants`. The device values, and the colorimetric values, of a
+characterization are available with `Argyll::device_values`, and the `Cgats` methods of the `cgats` field.
+
+Argyll uses a wavelength step size which is not a whole number of nanometer for its high resolution spectra, such as
+those of the i1Pro, with a step size of 3.333nm; these are linearly interpolated to a step size of 1nm, as described in
+the `io` module.
+
+# Examples
+```
+use scot::io::Argyll;
+use scot::SpectralDistribution;
+
+let text = r#"SPECT
+DESCRIPTOR "Argyll LED spectrum"
+KEYWORD "SPECTRAL_BANDS"
+SPECTRAL_BANDS "3"
+KEYWORD "SPECTRAL_START_NM"
+SPECTRAL_START_NM "400.000000"
+KEYWORD "SPECTRAL_END_NM"
+SPECTRAL_END_NM "500.000000"
+KEYWORD "SPECTRAL_NORM"
+SPECTRAL_NORM "2.000000"
+NUMBER_OF_FIELDS 3
+BEGIN_DATA_FORMAT
+SPEC_400 SPEC_450 SPEC_500
+END_DATA_FORMAT
+NUMBER_OF_SETS 1
+BEGIN_DATA
+0.2 2.0 0.5
+END_DATA
+"#;
+let led = Argyll::parse(text).unwrap().illuminants().unwrap();
+assert_eq!(led.spd().1.as_slice(), &[0.1, 1.0, 0.25]);
+```
+*/
+
+use nalgebra::DMatrix;
+
+use crate::illuminants::IlluminantData;
+use crate::swatches::SwatchLibrary;
+use crate::{Domain, WavelengthStep};
+
+use super::cgats::{err, unique};
+use super::{regular_domain, Cgats, CgatsError};
+
+/// CGATS identifiers of the Argyll files which can be read.
+const IDENTIFIERS: [&str; 5] = ["CTI1", "CTI2", "CTI3", "SPECT", "CCSS"];
+
+/// An ArgyllCMS file, such as a `.ti3` characterization, or a `.sp` spectrum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Argyll {
+    pub cgats: Cgats,
+}
+
+impl Argyll {
+    /// Reads an Argyll file, with the first data set of files with more than one data set.
+    pub fn parse(text: &str) -> Result<Self, CgatsError> {
+        let cgats = Cgats::parse(text)?;
+        if !IDENTIFIERS.contains(&cgats.identifier.as_str()) {
+            return Err(err(
+                1,
+                format!("not an Argyll file, identifier \"{}\"", cgats.identifier),
+            ));
+        }
+        Ok(Self { cgats })
+    }
+
+    /// Reflectance, or transmittance, spectra of the samples, keyed by their sample ids.
+    pub fn swatches(&self) -> Result<SwatchLibrary, CgatsError> {
+        let (domain, data) = self.spectra()?;
+        let keys = self.cgats.keys();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        unique(&keys).map_err(|m| err(0, m))?;
+        let lib = SwatchLibrary::from_columns(domain, data, &keys);
+        Ok(match self.cgats.keyword("DESCRIPTOR") {
+            Some(d) => lib.set_description(d),
+            None => lib,
+        })
+    }
+
+    /// Emissive spectra, of display patches, or light sources, keyed by their sample ids.
+    pub fn illuminants(&self) -> Result<IlluminantData, CgatsError> {
+        let (domain, data) = self.spectra()?;
+        let keys = self.cgats.keys();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        let ill = IlluminantData::new(domain, data).set_keys(&keys);
+        Ok(match self.cgats.keyword("DESCRIPTOR") {
+            Some(d) => ill.set_description(d),
+            None => ill,
+        })
+    }
+
+    /**
+    Device values of a characterization, with values from 0.0 to 1.0, and a column for each sample, and a row for
+    each of the device channels, in the order of their fields, such as `RGB_R`, `RGB_G`, and `RGB_B`.
+
+    The device channels are the fields starting with the device part of the `COLOR_REP` keyword, such as `RGB` for
+    `RGB_XYZ`, or `CMYK` for `CMYK_LAB`.
+    */
+    pub fn device_values(&self) -> Result<DMatrix<f64>, CgatsError> {
+        let rep = self
+            .cgats
+            .keyword("COLOR_REP")
+            .ok_or_else(|| err(0, "no COLOR_REP keyword"))?;
+        let prefix = format!("{}_", rep.split('_').next().unwrap_or(rep));
+        let channels: Vec<&String> = self
+            .cgats
+            .fields
+            .iter()
+            .filter(|f| f.starts_with(&prefix))
+            .collect();
+        if channels.is_empty() {
+            return Err(err(0, format!("no {} fields", prefix)));
+        }
+        let mut m = DMatrix::zeros(channels.len(), self.cgats.rows.len());
+        for (i, name) in channels.into_iter().enumerate() {
+            let v = self.cgats.values(name)?;
+            m.row_mut(i).copy_from_slice(&v);
+        }
+        Ok(m / 100.0)
+    }
+
+    /// Spectral values, on a regular domain, scaled by the spectral norm, with a column for each sample.
+    fn spectra(&self) -> Result<(Domain<WavelengthStep>, DMatrix<f64>), CgatsError> {
+        let fields: Vec<&String> = self
+            .cgats
+            .fields
+            .iter()
+            .filter(|f| f.to_ascii_uppercase().starts_with("SPEC_"))
+            .collect();
+        let n = fields.len();
+        if n < 2 {
+            return Err(err(0, "at least 2 spectral fields expected"));
+        }
+        let number = |name: &str| {
+            self.cgats
+                .keyword(name)
+                .and_then(|v| v.trim().parse::<f64>().ok())
+        };
+        if let Some(bands) = number("SPECTRAL_BANDS") {
+            if bands as usize != n {
+                return Err(err(
+                    0,
+                    format!("expected {} spectral bands, found {}", bands, n),
+                ));
+            }
+        }
+        // field names have wavelengths rounded to whole nanometers
+        let wavelengths: Vec<f64> = match (number("SPECTRAL_START_NM"), number("SPECTRAL_END_NM")) {
+            (Some(s), Some(e)) => (0..n)
+                .map(|i| s + (e - s) * i as f64 / (n - 1) as f64)
+                .collect(),
+            _ => fields
+                .iter()
+                .map(|f| {
+                    f[5..]
+                        .parse()
+                        .map_err(|_| err(0, format!("invalid spectral field {}", f)))
+                })
+                .collect::<Result<_, _>>()?,
+        };
+
+        let mut data = DMatrix::zeros(n, self.cgats.rows.len());
+        for (i, name) in fields.into_iter().enumerate() {
+            let v = self.cgats.values(name)?;
+            data.row_mut(i).copy_from_slice(&v);
+        }
+        let reflective = self.cgats.identifier.starts_with("CTI")
+            && self.cgats.keyword("DEVICE_CLASS") != Some("DISPLAY");
+        let norm = match number("SPECTRAL_NORM") {
+            Some(v) if v > 0.0 => v,
+            _ if reflective => 100.0,
+            _ => 1.0,
+        };
+        regular_domain(&wavelengths, data / norm).map_err(|e| err(0, e.to_string()))
+    }
+}
+
+#[test]
+fn test_argyll() {
+    use crate::SpectralDistribution;
+    use approx::assert_abs_diff_eq;
+
+    let ti3 = r#"CTI3
+
+DESCRIPTOR "Argyll Calibration Target chart information 3"
+ORIGINATOR "Argyll target"
+CREATED "Sat Jun 05 10:12:31 2021"
+DEVICE_CLASS "OUTPUT"
+COLOR_REP "RGB_XYZ"
+KEYWORD "SPECTRAL_BANDS"
+SPECTRAL_BANDS "4"
+KEYWORD "SPECTRAL_START_NM"
+SPECTRAL_START_NM "400.000000"
+KEYWORD "SPECTRAL_END_NM"
+SPECTRAL_END_NM "700.000000"
+
+NUMBER_OF_FIELDS 11
+BEGIN_DATA_FORMAT
+SAMPLE_ID RGB_R RGB_G RGB_B XYZ_X XYZ_Y XYZ_Z SPEC_400 SPEC_500 SPEC_600 SPEC_700
+END_DATA_FORMAT
+
+NUMBER_OF_SETS 2
+BEGIN_DATA
+1 100.00 100.00 100.00 87.1 90.3 74.2 85.0 90.0 91.0 90.5
+2 0.0000 50.000 100.00 20.5 18.2 40.1 60.0 40.0 5.0 3.0
+END_DATA
+"#;
+    let argyll = Argyll::parse(ti3).unwrap();
+    let lib = argyll.swatches().unwrap();
+    assert_eq!(lib.keys(), Some(vec!["1".to_string(), "2".to_string()]));
+    let (d, s) = lib.spd();
+    assert_eq!(d.len(), 4);
+    assert_abs_diff_eq!(s[(1, 1)], 0.4, epsilon = 1E-12);
+    let rgb = argyll.device_values().unwrap();
+    assert_eq!(rgb.nrows(), 3);
+    assert_abs_diff_eq!(rgb[(1, 1)], 0.5, epsilon = 1E-12);
+    assert_eq!(argyll.cgats.values("XYZ_Y").unwrap(), vec![90.3, 18.2]);
+
+    // i1Pro high resolution spectrum, with a 3.333nm step size, interpolated to 1nm
+    let header = "SPECT\nSPECTRAL_START_NM \"380.0\"\nSPECTRAL_END_NM \"390.0\"\n";
+    let sp = format!(
+        "{}BEGIN_DATA_FORMAT\nSPEC_380 SPEC_383 SPEC_387 SPEC_390\nEND_DATA_FORMAT\nBEGIN_DATA\n1.0 2.0 3.0 4.0\nEND_DATA\n",
+        header
+    );
+    let ill = Argyll::parse(&sp).unwrap().illuminants().unwrap();
+    let (d, s) = ill.spd();
+    assert_eq!(d.len(), 11);
+    assert_abs_diff_eq!(s[(5, 0)], 2.5, epsilon = 1E-9);
+
+    assert_eq!(
+        Argyll::parse("CGATS.17\nBEGIN_DATA_FORMAT\nA\nEND_DATA_FORMAT\nBEGIN_DATA\n1\nEND_DATA\n")
+            .unwrap_err()
+            .to_string(),
+        "CGATS data, line 1: not an Argyll file, identifier \"CGATS.17\""
+    );
+}
@@ -0,0 +1,564 @@
+/*!
+CGATS.17, and CxF3, measurement data files.
+
+Measurement applications, such as i1Profiler, and MeasureTool, export their measurements of test charts as CGATS.17
+text files, or as CxF3 XML documents, with reflectance spectra, and colorimetric values, for each of the samples.
+
+A CGATS file has a header, with keywords, and their values, a data format line, with the names of the fields, and a
+data table, with a row for each sample. `Cgats::parse` reads the header, and the table, of the first data set in a
+file, with its values as text; spectral data, in `SPECTRAL_NM` fields, such as `SPECTRAL_NM380`, or
+`SPECTRAL_NM_380`, is read into a `SwatchLibrary` with `Cgats::swatches`, and CIELAB, or CIE XYZ, values, in the
+`LAB_L`, `LAB_A`, `LAB_B`, and `XYZ_X`, `XYZ_Y`, `XYZ_Z` fields, with `Cgats::lab`, and `Cgats::xyz`.
+Samples are keyed by their `SAMPLE_NAME`, or, without sample names, by their `SAMPLE_ID`.
+Spectral values are taken to be reflectance values in percent, instead of fractions, if any of them is larger than 2.
+
+`Cxf3::parse` reads the reflectance spectra of the objects in a CxF3 document, keyed by their names, and
+`Cxf3::lab` their CIELAB values. Only the basic structure of CxF3 is supported: a reflectance spectrum for each
+object, with its start wavelength, and its wavelength increment, as given in its color specification.
+
+# Examples
+```
+use scot::io::Cgats;
+use scot::SpectralDistribution;
+
+let text = r#"CGATS.17
+ORIGINATOR "MeasureTool"
+NUMBER_OF_FIELDS 6
+BEGIN_DATA_FORMAT
+SAMPLE_ID SAMPLE_NAME SPECTRAL_NM400 SPECTRAL_NM410 SPECTRAL_NM420 LAB_L
+END_DATA_FORMAT
+NUMBER_OF_SETS 2
+BEGIN_DATA
+1 A1 0.10 0.12 0.15 39.5
+2 A2 0.80 0.82 0.85 92.1
+END_DATA
+"#;
+let cgats = Cgats::parse(text).unwrap();
+assert_eq!(cgats.keyword("ORIGINATOR"), Some("MeasureTool"));
+let lib = cgats.swatches().unwrap();
+assert_eq!(lib.get("A2").unwrap().spd().1[2], 0.85);
+```
+*/
+
+use std::fmt;
+
+use nalgebra::{DMatrix, Matrix3xX};
+
+use crate::models::{CieLab, CieXYZ};
+use crate::observers::StandardObserver;
+use crate::swatches::SwatchLibrary;
+use crate::Domain;
+
+use super::xml::{attribute, element, elements, unescape};
+
+/// Error for CGATS data which can not be read, with the line number, starting at 1, of the error, or 0 if it is not
+/// related to a specific line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CgatsError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for CgatsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "CGATS data, line {}: {}", self.line, self.message)
+        } else {
+            write!(f, "CGATS data: {}", self.message)
+        }
+    }
+}
+
+impl std::error::Error for CgatsError {}
+
+pub(crate) fn err(line: usize, message: impl Into<String>) -> CgatsError {
+    CgatsError {
+        line,
+        message: message.into(),
+    }
+}
+
+/// The header, and the data table, of a CGATS file, with the values as text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Cgats {
+    /// File identifier, from the first line, such as "CGATS.17", or "IT8.7/2".
+    pub identifier: String,
+    /// Header keywords, and their values, with the quotes of strings removed.
+    pub keywords: Vec<(String, String)>,
+    /// Names of the fields, from the data format.
+    pub fields: Vec<String>,
+    /// Data table, with a row of values for each sample.
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Cgats {
+    /// Reads the header, and the first data set, of a CGATS file.
+    pub fn parse(text: &str) -> Result<Self, CgatsError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, tokens(l)))
+            .filter(|(_, t)| !t.is_empty());
+        let (_, first) = lines.next().ok_or_else(|| err(1, "no data"))?;
+        let mut cgats = Cgats {
+            identifier: first.join(" "),
+            ..Default::default()
+        };
+
+        let mut section = Section::Header;
+        let mut values = Vec::new();
+        let mut last = 1;
+        for (n, t) in lines {
+            last = n;
+            match (section, t[0].as_str()) {
+                (Section::Header, "BEGIN_DATA_FORMAT") => section = Section::Format,
+                (Section::Header, "BEGIN_DATA") if cgats.fields.is_empty() => {
+                    return Err(err(n, "no fields in data format"))
+                }
+                (Section::Header, "BEGIN_DATA") => section = Section::Data,
+                (Section::Header, "KEYWORD") => {}
+                (Section::Header, _) => cgats.keywords.push((t[0].clone(), t[1..].join(" "))),
+                (Section::Format, "END_DATA_FORMAT") => section = Section::Header,
+                (Section::Format, _) => cgats.fields.extend(t),
+                (Section::Data, "END_DATA") => {
+                    section = Section::End;
+                    break;
+                }
+                (Section::Data, _) => values.extend(t.into_iter().map(|v| (n, v))),
+                (Section::End, _) => break,
+            }
+        }
+        if section != Section::End {
+            return Err(err(last, "incomplete data, no END_DATA"));
+        }
+
+        let m = cgats.fields.len();
+        if let Some(n) = cgats.number("NUMBER_OF_FIELDS") {
+            if n != m {
+                return Err(err(0, format!("expected {} fields, found {}", n, m)));
+            }
+        }
+        if values.len() % m != 0 {
+            let (n, _) = values[values.len() - values.len() % m];
+            return Err(err(n, format!("incomplete row, with {} fields", m)));
+        }
+        cgats.rows = values
+            .chunks(m)
+            .map(|r| r.iter().map(|(_, v)| v.clone()).collect())
+            .collect();
+        if let Some(n) = cgats.number("NUMBER_OF_SETS") {
+            if n != cgats.rows.len() {
+                return Err(err(
+                    0,
+                    format!("expected {} sets, found {}", n, cgats.rows.len()),
+                ));
+            }
+        }
+        Ok(cgats)
+    }
+
+    /// Value of a header keyword.
+    pub fn keyword(&self, name: &str) -> Option<&str> {
+        self.keywords
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn number(&self, name: &str) -> Option<usize> {
+        self.keyword(name).and_then(|v| v.parse().ok())
+    }
+
+    /// Index of a field.
+    pub fn field(&self, name: &str) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|f| f.eq_ignore_ascii_case(name))
+    }
+
+    /// Numerical values of a field, one for each sample.
+    pub fn values(&self, name: &str) -> Result<Vec<f64>, CgatsError> {
+        let j = self
+            .field(name)
+            .ok_or_else(|| err(0, format!("no {} field", name)))?;
+        self.rows
+            .iter()
+            .map(|r| {
+                r[j].parse()
+                    .map_err(|_| err(0, format!("invalid {} value \"{}\"", name, r[j])))
+            })
+            .collect()
+    }
+
+    /// Sample keys, from the `SAMPLE_NAME` field, or the `SAMPLE_ID` field, or row numbers, starting at 1.
+    pub fn keys(&self) -> Vec<String> {
+        match self
+            .field("SAMPLE_NAME")
+            .or_else(|| self.field("SAMPLE_ID"))
+        {
+            Some(j) => self.rows.iter().map(|r| r[j].clone()).collect(),
+            None => (1..=self.rows.len()).map(|i| i.to_string()).collect(),
+        }
+    }
+
+    /// Reflectance spectra of the samples, from the spectral fields, keyed by `Cgats::keys`.
+    pub fn swatches(&self) -> Result<SwatchLibrary, CgatsError> {
+        let spectral: Vec<(usize, f64)> = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter_map(|(j, f)| spectral_field(f).map(|w| (j, w)))
+            .collect();
+        let (start, step) = match spectral.as_slice() {
+            &[(_, start), (_, second), ..] => (start, second - start),
+            _ => return Err(err(0, "at least 2 spectral fields expected")),
+        };
+        let end = spectral[spectral.len() - 1].1;
+        let domain = Domain::from_nm(start, end, step)
+            .map_err(|e| err(0, format!("spectral fields: {}", e)))?;
+        if domain.len() != spectral.len()
+            || spectral
+                .iter()
+                .enumerate()
+                .any(|(i, (_, w))| (w - (start + i as f64 * step)).abs() > 1E-6 * step)
+        {
+            return Err(err(0, "spectral fields are not equidistant"));
+        }
+
+        let mut data = DMatrix::zeros(spectral.len(), self.rows.len());
+        for (i, &(j, _)) in spectral.iter().enumerate() {
+            let v = self.values(&self.fields[j])?;
+            data.row_mut(i).copy_from_slice(&v);
+        }
+        if data.max() > 2.0 {
+            data /= 100.0;
+        }
+        let keys = self.keys();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        unique(&keys).map_err(|m| err(0, m))?;
+        let lib = SwatchLibrary::from_columns(domain, data, &keys);
+        Ok(match self.keyword("DESCRIPTOR") {
+            Some(d) => lib.set_description(d),
+            None => lib,
+        })
+    }
+
+    /// CIELAB values of the samples, from the `LAB_L`, `LAB_A`, and `LAB_B` fields, keyed by `Cgats::keys`.
+    ///
+    /// The reference white, and observer, of the measurement are given by the type parameters, as they are
+    /// not recorded in a standard way.
+    pub fn lab<I, C>(&self) -> Result<CieLab<I, C>, CgatsError> {
+        let v = self.triplets(["LAB_L", "LAB_A", "LAB_B"])?;
+        let keys = self.keys();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        Ok(CieLab::new(v.as_slice().to_vec()).set_keys(&keys))
+    }
+
+    /// CIE XYZ values of the samples, from the `XYZ_X`, `XYZ_Y`, and `XYZ_Z` fields, keyed by `Cgats::keys`.
+    pub fn xyz<C: StandardObserver>(&self) -> Result<CieXYZ<C>, CgatsError> {
+        let v = self.triplets(["XYZ_X", "XYZ_Y", "XYZ_Z"])?;
+        let keys = self.keys();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        Ok(CieXYZ::new(v).set_keys(&keys))
+    }
+
+    fn triplets(&self, names: [&str; 3]) -> Result<Matrix3xX<f64>, CgatsError> {
+        let mut m = Matrix3xX::zeros(self.rows.len());
+        for (i, name) in names.iter().enumerate() {
+            let v = self.values(name)?;
+            m.row_mut(i).copy_from_slice(&v);
+        }
+        Ok(m)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Section {
+    Header,
+    Format,
+    Data,
+    End,
+}
+
+/// Tokens of a CGATS line, separated by white space, with quoted strings as a single token, without their quotes,
+/// and without comments, starting with a '#'.
+fn tokens(line: &str) -> Vec<String> {
+    let mut v = Vec::new();
+    let mut chars = line.trim().chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '#' {
+            break;
+        } else if c == '"' {
+            chars.next();
+            v.push(chars.by_ref().take_while(|&c| c != '"').collect());
+        } else {
+            let mut t = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                t.push(c);
+                chars.next();
+            }
+            v.push(t);
+        }
+    }
+    v
+}
+
+/// Wavelength, in nanometer, of a spectral field, such as "SPECTRAL_NM380", "SPECTRAL_NM_380", or "nm380".
+fn spectral_field(name: &str) -> Option<f64> {
+    let name = name.to_ascii_uppercase();
+    let w = name
+        .strip_prefix("SPECTRAL_NM")
+        .or_else(|| name.strip_prefix("SPECTRAL_"))
+        .or_else(|| name.strip_prefix("NM"))?;
+    w.trim_start_matches('_').parse().ok()
+}
+
+pub(crate) fn unique(keys: &[&str]) -> Result<(), String> {
+    match (1..keys.len()).find(|&i| keys[..i].contains(&keys[i])) {
+        Some(i) => Err(format!("duplicate sample key \"{}\"", keys[i])),
+        None => Ok(()),
+    }
+}
+
+/// Error for CxF3 data which can not be read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cxf3Error {
+    pub message: String,
+}
+
+impl fmt::Display for Cxf3Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CxF3 data: {}", self.message)
+    }
+}
+
+impl std::error::Error for Cxf3Error {}
+
+fn cxf_err(message: impl Into<String>) -> Cxf3Error {
+    Cxf3Error {
+        message: message.into(),
+    }
+}
+
+/// Reader for CxF3 color exchange format documents.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Cxf3;
+
+impl Cxf3 {
+    /// Reflectance spectra of the objects in a CxF3 document, keyed by their names, or their ids.
+    pub fn parse(xml: &str) -> Result<SwatchLibrary, Cxf3Error> {
+        let ranges: Vec<(&str, f64, f64)> = elements(xml, "ColorSpecification")
+            .into_iter()
+            .filter_map(|(a, c)| {
+                let (r, _) = element(c, "WavelengthRange")?;
+                let start = attribute(r, "StartWL")?.parse().ok()?;
+                let step = attribute(r, "Increment")?.parse().ok()?;
+                Some((attribute(a, "Id").unwrap_or(""), start, step))
+            })
+            .collect();
+
+        let mut keys = Vec::new();
+        let mut spectra = Vec::new();
+        let mut domain = None;
+        for (a, c) in elements(xml, "Object") {
+            let (sa, sc) = match element(c, "ReflectanceSpectrum") {
+                Some(s) => s,
+                None => continue,
+            };
+            let key = object_key(a, keys.len());
+            let spec = attribute(sa, "ColorSpecification");
+            let range = ranges
+                .iter()
+                .find(|(id, _, _)| Some(*id) == spec)
+                .or_else(|| ranges.first());
+            let start = match attribute(sa, "StartWL") {
+                Some(s) => cxf_number(s)?,
+                None => range
+                    .map(|r| r.1)
+                    .ok_or_else(|| cxf_err(format!("no start wavelength for \"{}\"", key)))?,
+            };
+            let step = range.map_or(10.0, |r| r.2);
+            let values = sc
+                .split_whitespace()
+                .map(cxf_number)
+                .collect::<Result<Vec<f64>, _>>()?;
+            if values.len() < 2 {
+                return Err(cxf_err(format!("no spectrum for \"{}\"", key)));
+            }
+            let end = start + step * (values.len() - 1) as f64;
+            let d = Domain::from_nm(start, end, step).map_err(|e| cxf_err(e.to_string()))?;
+            match &domain {
+                Some(d0) if *d0 != d => {
+                    return Err(cxf_err(format!(
+                        "spectrum of \"{}\" has a different domain",
+                        key
+                    )))
+                }
+                Some(_) => {}
+                None => domain = Some(d),
+            }
+            keys.push(key);
+            spectra.extend(values);
+        }
+        let domain = domain.ok_or_else(|| cxf_err("no objects with a reflectance spectrum"))?;
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        unique(&keys).map_err(cxf_err)?;
+        let data = DMatrix::from_column_slice(domain.len(), keys.len(), &spectra);
+        Ok(SwatchLibrary::from_columns(domain, data, &keys))
+    }
+
+    /// CIELAB values of the objects in a CxF3 document, keyed by their names, or their ids.
+    pub fn lab<I, C>(xml: &str) -> Result<CieLab<I, C>, Cxf3Error> {
+        let mut keys = Vec::new();
+        let mut values = Vec::new();
+        for (a, c) in elements(xml, "Object") {
+            let (_, lab) = match element(c, "ColorCIELab") {
+                Some(l) => l,
+                None => continue,
+            };
+            keys.push(object_key(a, keys.len()));
+            for name in &["L", "A", "B"] {
+                let (_, v) = element(lab, name)
+                    .ok_or_else(|| cxf_err(format!("no {} value in ColorCIELab", name)))?;
+                values.push(cxf_number(v)?);
+            }
+        }
+        if keys.is_empty() {
+            return Err(cxf_err("no objects with CIELAB values"));
+        }
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        unique(&keys).map_err(cxf_err)?;
+        Ok(CieLab::new(values).set_keys(&keys))
+    }
+}
+
+/// Name of an object, its id, or its position in the document, starting at 1.
+fn object_key(attributes: &str, i: usize) -> String {
+    attribute(attributes, "Name")
+        .or_else(|| attribute(attributes, "Id"))
+        .map_or_else(|| (i + 1).to_string(), unescape)
+}
+
+fn cxf_number(s: &str) -> Result<f64, Cxf3Error> {
+    s.trim()
+        .parse()
+        .map_err(|_| cxf_err(format!("invalid number \"{}\"", s.trim())))
+}
+
+#[test]
+fn test_cgats() {
+    use crate::illuminants::CieIllD50;
+    use crate::observers::CieObs1931;
+    use crate::SpectralDistribution;
+    use approx::assert_abs_diff_eq;
+
+    let text = r#"CGATS.17
+ORIGINATOR	"i1Profiler"
+DESCRIPTOR "Test chart, M1"
+KEYWORD "SAMPLE_NAME"
+# a comment
+NUMBER_OF_FIELDS 11
+BEGIN_DATA_FORMAT
+SampleID SAMPLE_NAME SPECTRAL_NM_400 SPECTRAL_NM_420 SPECTRAL_NM_440
+LAB_L LAB_A LAB_B XYZ_X XYZ_Y XYZ_Z
+END_DATA_FORMAT
+NUMBER_OF_SETS 3
+BEGIN_DATA
+1 "paper white" 88.0 90.1 91.2 95.1 1.2 -4.5 84.0 87.9 70.2
+2 cyan 40.2 55.3 60.1 55.0 -37.0 -50.0 15.0 23.0 56.0
+3 black 2.1 2.0 1.9 16.0 0.5 0.2 2.0 2.2 1.8
+END_DATA
+"#;
+    let c = Cgats::parse(text).unwrap();
+    assert_eq!(c.identifier, "CGATS.17");
+    assert_eq!(c.keyword("DESCRIPTOR"), Some("Test chart, M1"));
+    assert_eq!(c.fields.len(), 11);
+    assert_eq!(c.keys(), vec!["paper white", "cyan", "black"]);
+
+    // percent values, scaled to fractions
+    let lib = c.swatches().unwrap();
+    assert_eq!(lib.domain().len(), 3);
+    assert_abs_diff_eq!(lib.get("cyan").unwrap().spd().1[1], 0.553, epsilon = 1E-12);
+    assert_eq!(lib.description().unwrap(), "Test chart, M1");
+
+    let lab: CieLab<CieIllD50, CieObs1931> = c.lab().unwrap();
+    assert_eq!(lab.get_by_key("cyan").unwrap(), [55.0, -37.0, -50.0]);
+    let xyz: CieXYZ<CieObs1931> = c.xyz().unwrap();
+    assert_eq!(xyz.get_by_key("black").unwrap(), [2.0, 2.2, 1.8]);
+
+    assert_eq!(
+        Cgats::parse(&text.replace("3 black 2.1 ", "3 black "))
+            .unwrap_err()
+            .to_string(),
+        "CGATS data, line 15: incomplete row, with 11 fields"
+    );
+    assert_eq!(
+        Cgats::parse(&text.replace("NUMBER_OF_SETS 3", "NUMBER_OF_SETS 4"))
+            .unwrap_err()
+            .message,
+        "expected 4 sets, found 3"
+    );
+    assert_eq!(
+        Cgats::parse(&text.replace("END_DATA\n", ""))
+            .unwrap_err()
+            .line,
+        15
+    );
+    let c = Cgats::parse(&text.replace("cyan", "black")).unwrap();
+    assert_eq!(
+        c.swatches().unwrap_err().message,
+        "duplicate sample key \"black\""
+    );
+}
+
+#[test]
+fn test_cxf3() {
+    use crate::illuminants::CieIllD50;
+    use crate::observers::CieObs1931;
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<cc:CxF xmlns:cc="http://colorexchangeformat.com/CxF3-core">
+  <cc:Resources>
+    <cc:ObjectCollection>
+      <cc:Object ObjectType="Standard" Name="Red &amp; Co" Id="1">
+        <cc:ColorValues>
+          <cc:ReflectanceSpectrum ColorSpecification="CS1">0.05 0.06 0.08 0.60</cc:ReflectanceSpectrum>
+          <cc:ColorCIELab ColorSpecification="CS2"><cc:L>45.0</cc:L><cc:A>60.5</cc:A><cc:B>38.1</cc:B></cc:ColorCIELab>
+        </cc:ColorValues>
+      </cc:Object>
+      <cc:Object ObjectType="Standard" Id="2">
+        <cc:ColorValues>
+          <cc:ReflectanceSpectrum ColorSpecification="CS1">0.50 0.52 0.51 0.50</cc:ReflectanceSpectrum>
+          <cc:ColorCIELab ColorSpecification="CS2"><cc:L>76.0</cc:L><cc:A>0.5</cc:A><cc:B>-1.1</cc:B></cc:ColorCIELab>
+        </cc:ColorValues>
+      </cc:Object>
+    </cc:ObjectCollection>
+    <cc:ColorSpecificationCollection>
+      <cc:ColorSpecification Id="CS1">
+        <cc:MeasurementSpec>
+          <cc:WavelengthRange StartWL="400" Increment="20"/>
+        </cc:MeasurementSpec>
+      </cc:ColorSpecification>
+    </cc:ColorSpecificationCollection>
+  </cc:Resources>
+</cc:CxF>"#;
+    let lib = Cxf3::parse(xml).unwrap();
+    assert_eq!(lib.len(), 2);
+    assert_eq!(lib.domain().len(), 4);
+    assert_eq!(lib.data()[(3, 0)], 0.6);
+    assert!(lib.get("Red & Co").is_ok() && lib.get("2").is_ok());
+    let lab: CieLab<CieIllD50, CieObs1931> = Cxf3::lab(xml).unwrap();
+    assert_eq!(lab.get_by_key("2").unwrap(), [76.0, 0.5, -1.1]);
+
+    assert_eq!(
+        Cxf3::parse(&xml.replace("0.50 0.52 0.51 0.50", "0.50 0.52 0.51"))
+            .unwrap_err()
+            .to_string(),
+        "CxF3 data: spectrum of \"2\" has a different domain"
+    );
+}
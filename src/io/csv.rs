@@ -0,0 +1,377 @@
+/*!
+Comma separated values tables, for spectral distributions, and colorimetric values.
+
+Spectral tables have a wavelength column, in nanometer, followed by a column for each sample, and an optional header
+line, with a label for the wavelength column, which should not be a number, and the keys of the samples. Colorimetric tables, with CIELAB, or CIE
+XYZ, values, have a row for each sample, with an optional key, in the first column, followed by its three values, and
+an optional header line, with labels which should not be numbers.
+
+Fields are separated by commas, or, if the first line contains a semicolon, or a tab, by semicolons, or tabs. Fields
+can be quoted, with double quotes, and a double quote in a quoted field is written as two double quotes. Empty lines,
+and lines starting with a '#', are ignored. Tables are written with comma separators, and with a header line.
+
+# Examples
+```
+use scot::io::Csv;
+use scot::SpectralDistribution;
+
+let table = "nm,leaf,bark\n400,0.05,0.06\n410,0.05,0.07\n420,0.06,0.07\n";
+let sd = Csv::spectra(table).unwrap();
+assert_eq!(sd.keys().unwrap(), vec!["leaf", "bark"]);
+assert_eq!(Csv::write_spectra(&sd), table);
+```
+*/
+
+use std::fmt;
+
+use nalgebra::{DMatrix, Matrix3xX};
+
+use crate::models::{CieLab, CieXYZ};
+use crate::observers::StandardObserver;
+use crate::{DynamicSpectralDistribution, SpectralDistribution, Unit, WavelengthStep};
+
+use super::regular_domain;
+
+/// Error for a CSV table which can not be read, with the line number, starting at 1, of the error.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CSV data, line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+pub(crate) fn err(line: usize, message: impl Into<String>) -> CsvError {
+    CsvError {
+        line,
+        message: message.into(),
+    }
+}
+
+/// Reader, and writer, of CSV tables.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Csv;
+
+impl Csv {
+    /// Spectral distributions from a table with a wavelength column, and a column for each sample.
+    pub fn spectra(text: &str) -> Result<DynamicSpectralDistribution, CsvError> {
+        let (header, rows) = table(text, |r| r[0].parse::<f64>().is_err())?;
+        let m = match (&header, rows.first()) {
+            (Some((_, h)), _) => h.len(),
+            (None, Some((_, r))) => r.len(),
+            (None, None) => 0,
+        };
+        if m < 2 {
+            return Err(err(1, "at least 2 columns expected"));
+        }
+        let last = rows.last().map_or(1, |(n, _)| *n);
+        if rows.len() < 2 {
+            return Err(err(last, "at least 2 wavelengths expected"));
+        }
+        let mut wavelengths = Vec::with_capacity(rows.len());
+        let mut values = Vec::with_capacity(rows.len() * (m - 1));
+        for (n, r) in &rows {
+            let v = numbers(*n, r, m)?;
+            wavelengths.push(v[0]);
+            values.extend_from_slice(&v[1..]);
+        }
+        let data = DMatrix::from_row_slice(rows.len(), m - 1, &values);
+        let (domain, data) = regular_domain(&wavelengths, data)
+            .map_err(|e| err(last, format!("wavelengths: {}", e)))?;
+        let sd = DynamicSpectralDistribution::new(domain, data);
+        match header {
+            Some((n, h)) => {
+                let keys: Vec<&str> = h[1..].iter().map(String::as_str).collect();
+                unique(n, &keys)?;
+                Ok(sd.set_keys(&keys))
+            }
+            None => Ok(sd),
+        }
+    }
+
+    /// CIELAB values from a table with L\*, a\*, and b\* values, and optional keys, in a row for each sample.
+    pub fn lab<I, C>(text: &str) -> Result<CieLab<I, C>, CsvError> {
+        let (data, keys) = triplets(text)?;
+        let lab = CieLab::new(data.as_slice().to_vec());
+        Ok(match keys {
+            Some(k) => lab.set_keys(&k.iter().map(String::as_str).collect::<Vec<&str>>()),
+            None => lab,
+        })
+    }
+
+    /// CIE XYZ values from a table with X, Y, and Z values, and optional keys, in a row for each sample.
+    pub fn xyz<C: StandardObserver>(text: &str) -> Result<CieXYZ<C>, CsvError> {
+        let (data, keys) = triplets(text)?;
+        let xyz = CieXYZ::new(data);
+        Ok(match keys {
+            Some(k) => xyz.set_keys(&k.iter().map(String::as_str).collect::<Vec<&str>>()),
+            None => xyz,
+        })
+    }
+
+    /// Table of spectral distributions, with a header line with their keys, or their column numbers, starting at 1.
+    pub fn write_spectra<S>(sd: &S) -> String
+    where
+        S: SpectralDistribution<StepType = WavelengthStep> + ?Sized,
+    {
+        let (d, s) = sd.spd();
+        let m = sd.shape().1;
+        let keys = sd
+            .keys()
+            .unwrap_or_else(|| (1..=m).map(|j| j.to_string()).collect());
+        let mut text = record(std::iter::once("nm".to_string()).chain(keys));
+        for (i, l) in d.iter().enumerate() {
+            let w = (l.value() * 1E15).round() / 1E6;
+            text.push_str(&record(
+                std::iter::once(w.to_string()).chain((0..m).map(|j| s[(i, j)].to_string())),
+            ));
+        }
+        text
+    }
+
+    /// Table of CIELAB values, with their keys, if any.
+    pub fn write_lab<I, C>(lab: &CieLab<I, C>) -> String {
+        write_triplets(&lab.data, lab.keys(), ["L*", "a*", "b*"])
+    }
+
+    /// Table of CIE XYZ values, with their keys, if any.
+    pub fn write_xyz<C: StandardObserver>(xyz: &CieXYZ<C>) -> String {
+        write_triplets(&xyz.data, xyz.keys(), ["X", "Y", "Z"])
+    }
+}
+
+type Row = (usize, Vec<String>);
+
+/// The header line, if `is_header` is true for its fields, and the data rows, with their line numbers.
+fn table<F>(text: &str, is_header: F) -> Result<(Option<Row>, Vec<Row>), CsvError>
+where
+    F: Fn(&[String]) -> bool,
+{
+    let lines: Vec<(usize, &str)> = text
+        .lines()
+        .enumerate()
+        .map(|(i, l)| (i + 1, l.trim()))
+        .filter(|(_, l)| !l.is_empty() && !l.starts_with('#'))
+        .collect();
+    let separator = match lines.first() {
+        Some((_, l)) if l.contains(';') => ';',
+        Some((_, l)) if l.contains('\t') => '\t',
+        _ => ',',
+    };
+    let mut rows = lines
+        .iter()
+        .map(|&(n, l)| fields(n, l, separator).map(|f| (n, f)))
+        .collect::<Result<Vec<Row>, CsvError>>()?;
+    let has_header = rows.first().is_some_and(|(_, r)| is_header(r));
+    let header = if has_header {
+        Some(rows.remove(0))
+    } else {
+        None
+    };
+    Ok((header, rows))
+}
+
+/// Fields of a line, unquoted.
+pub(crate) fn fields(n: usize, line: &str, separator: char) -> Result<Vec<String>, CsvError> {
+    let mut v = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        while chars.peek() == Some(&' ') {
+            chars.next();
+        }
+        let mut f = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        f.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => f.push(c),
+                    None => return Err(err(n, "unterminated quoted field")),
+                }
+            }
+            while chars.peek().is_some_and(|&c| c != separator) {
+                chars.next();
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c == separator {
+                    break;
+                }
+                f.push(c);
+                chars.next();
+            }
+            f = f.trim().to_string();
+        }
+        v.push(f);
+        if chars.next().is_none() {
+            return Ok(v);
+        }
+    }
+}
+
+/// Numerical values of a row, which should have `m` fields.
+fn numbers(n: usize, row: &[String], m: usize) -> Result<Vec<f64>, CsvError> {
+    if row.len() != m {
+        return Err(err(
+            n,
+            format!("expected {} values, found {}", m, row.len()),
+        ));
+    }
+    row.iter()
+        .map(|s| {
+            s.parse()
+                .map_err(|_| err(n, format!("invalid number \"{}\"", s)))
+        })
+        .collect()
+}
+
+pub(crate) fn unique(n: usize, keys: &[&str]) -> Result<(), CsvError> {
+    match (1..keys.len()).find(|&i| keys[..i].contains(&keys[i])) {
+        Some(i) => Err(err(n, format!("duplicate key \"{}\"", keys[i]))),
+        None => Ok(()),
+    }
+}
+
+/// Values, and optional keys, of a table with three values, and an optional key, in a row for each sample.
+fn triplets(text: &str) -> Result<(Matrix3xX<f64>, Option<Vec<String>>), CsvError> {
+    let (header, rows) = table(text, |r| r[r.len() - 1].parse::<f64>().is_err())?;
+    let (n0, first) = rows.first().ok_or_else(|| err(1, "no data"))?;
+    let keyed = match first.len() {
+        3 => false,
+        4 => true,
+        k => return Err(err(*n0, format!("expected 3, or 4, fields, found {}", k))),
+    };
+    if let Some((n, h)) = &header {
+        if h.len() != first.len() {
+            return Err(err(
+                *n,
+                format!("expected {} labels, found {}", first.len(), h.len()),
+            ));
+        }
+    }
+    let mut data = Matrix3xX::zeros(rows.len());
+    let mut keys = Vec::new();
+    for (j, (n, r)) in rows.iter().enumerate() {
+        let v = if keyed {
+            if r.len() != 4 {
+                return Err(err(*n, format!("expected 4 values, found {}", r.len())));
+            }
+            keys.push(r[0].clone());
+            numbers(*n, &r[1..], 3)?
+        } else {
+            numbers(*n, r, 3)?
+        };
+        data.column_mut(j).copy_from_slice(&v);
+    }
+    if keyed {
+        let k: Vec<&str> = keys.iter().map(String::as_str).collect();
+        unique(rows[rows.len() - 1].0, &k)?;
+        Ok((data, Some(keys)))
+    } else {
+        Ok((data, None))
+    }
+}
+
+fn write_triplets(data: &Matrix3xX<f64>, keys: Option<Vec<String>>, labels: [&str; 3]) -> String {
+    let key_label = keys.as_ref().map(|_| "key".to_string());
+    let mut text = record(
+        key_label
+            .into_iter()
+            .chain(labels.iter().map(|s| s.to_string())),
+    );
+    for (j, c) in data.column_iter().enumerate() {
+        let key = keys.as_ref().map(|k| k[j].clone());
+        text.push_str(&record(
+            key.into_iter().chain(c.iter().map(|v| v.to_string())),
+        ));
+    }
+    text
+}
+
+/// A line of comma separated fields, quoted if needed.
+fn record(fields: impl Iterator<Item = String>) -> String {
+    let mut line = fields
+        .map(|f| {
+            if f.contains(',') || f.contains('"') || f.starts_with(' ') || f.ends_with(' ') {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+#[test]
+fn test_csv() {
+    use crate::illuminants::{CieIllD50, CieIllD65};
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    // semicolons, quoted keys, and no header
+    let sd =
+        Csv::spectra("nm;\"red, dark\";\"say \"\"hi\"\"\"\n380;0.1;0.2\n390;0.1;0.3\n").unwrap();
+    assert_eq!(sd.keys().unwrap(), vec!["red, dark", "say \"hi\""]);
+    assert_eq!(sd.data()[(1, 1)], 0.3);
+    assert_eq!(
+        Csv::spectra(&Csv::write_spectra(&sd)).unwrap().keys(),
+        sd.keys()
+    );
+    let sd = Csv::spectra("# no header\n380\t0.1\n385\t0.2\n390\t0.25\n").unwrap();
+    assert_eq!(sd.keys(), None);
+    assert_eq!(sd.domain().len(), 3);
+
+    // irregular wavelengths, interpolated to 1nm steps
+    let sd = Csv::spectra("380.5,0.1\n382.5,0.3\n").unwrap();
+    assert_eq!(sd.domain().len(), 2);
+    assert_abs_diff_eq!(sd.data()[(0, 0)], 0.15, epsilon = 1E-12);
+
+    // round trip of a standard illuminant
+    let d65 = Csv::spectra(&Csv::write_spectra(&CieIllD65)).unwrap();
+    assert_eq!(d65.spd().0, CieIllD65.spd().0);
+    assert_eq!(d65.data().as_slice(), CieIllD65.spd().1.as_slice());
+
+    // colorimetric tables
+    let lab: CieLab<CieIllD50, CieObs1931> =
+        Csv::lab("key,L*,a*,b*\npaper,95.1,1.2,-4.5\ncyan,55,-37,-50\n").unwrap();
+    assert_eq!(lab.get_by_key("cyan").unwrap(), [55.0, -37.0, -50.0]);
+    let text = Csv::write_lab(&lab);
+    assert_eq!(text, "key,L*,a*,b*\npaper,95.1,1.2,-4.5\ncyan,55,-37,-50\n");
+    let xyz: CieXYZ<CieObs1931> = Csv::xyz("95.04,100,108.9\n").unwrap();
+    assert_eq!(xyz.keys(), None);
+    assert_eq!(Csv::write_xyz(&xyz), "X,Y,Z\n95.04,100,108.9\n");
+
+    // errors
+    assert_eq!(
+        Csv::spectra("nm,a,b\n380,0.1,0.2\n390,0.1\n")
+            .unwrap_err()
+            .to_string(),
+        "CSV data, line 3: expected 3 values, found 2"
+    );
+    assert_eq!(
+        Csv::spectra("nm,a\n380,0.1\n370,0.1\n").unwrap_err().line,
+        3
+    );
+    assert_eq!(
+        Csv::spectra("nm,a,a\n380,0.1,0.2\n390,0.1,0.2\n")
+            .unwrap_err()
+            .message,
+        "duplicate key \"a\""
+    );
+    assert_eq!(
+        Csv::xyz::<CieObs1931>("a,1,x,3\n").unwrap_err().message,
+        "invalid number \"x\""
+    );
+}
@@ -0,0 +1,233 @@
+/*!
+X-Rite i1Pro, and ColorMunki, spectral measurement exports.
+
+The X-Rite applications for the i1Pro, and ColorMunki, spectrophotometers, such as i1Profiler, i1Share, and
+ColorMunki Design, export their reflectance measurements as text tables, with fields separated by tabs, semicolons,
+or commas, and often with a few lines of information, such as the application name, and the date, before the table.
+Two layouts are used:
+
+- a row for each patch, with a header line with the patch id, and other non-spectral fields, and a field for each
+  wavelength, with labels such as `380`, `380 nm`, `nm380`, or `R380`;
+- a column for each patch, with a wavelength column, and a header line with the patch ids.
+
+The spectral fields, or rows, are typically from 380 to 730nm, in steps of 10nm, but are listed in descending order,
+from 730 to 380nm, by some applications. `I1Pro::swatches` reads both layouts, in either order, and returns the
+spectra in a `SwatchLibrary`, on an ascending domain, keyed by the patch ids, taken from the first non-spectral field
+in the row layout. Reflectance values are taken to be in percent if any of them is larger than 2, and can have a
+decimal comma, in tab, or semicolon, separated tables.
+
+The header line of a row layout table is the first line with at least two wavelength labels, and at least one field
+which is not a number; otherwise, a column layout is assumed, starting at the first line with numbers only.
+
+# Examples
+```
+use scot::io::I1Pro;
+use scot::SpectralDistribution;
+
+let text = "i1Share 1.4\nDate: 2021-06-05\n\nPatch\t730 nm\t720 nm\t710 nm\nA1\t90.0\t89.5\t89.0\nA2\t10.0\t9.0\t8.5\n";
+let lib = I1Pro::swatches(text).unwrap();
+assert_eq!(lib.keys().unwrap(), vec!["A1", "A2"]);
+assert_eq!(lib.spd().1.column(0).as_slice(), &[0.89, 0.895, 0.90]);
+```
+*/
+
+use nalgebra::DMatrix;
+
+use crate::swatches::SwatchLibrary;
+
+use super::csv::{err, fields, unique};
+use super::{regular_domain, CsvError};
+
+/// Reader of i1Pro, and ColorMunki, spectral measurement exports.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct I1Pro;
+
+impl I1Pro {
+    /// Reflectance spectra, of a row, or column, layout table, keyed by their patch ids.
+    pub fn swatches(text: &str) -> Result<SwatchLibrary, CsvError> {
+        let lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l.trim()))
+            .filter(|(_, l)| !l.is_empty())
+            .map(|(n, l)| fields(n, l, separator(l)).map(|f| (n, f)))
+            .collect::<Result<Vec<Row>, CsvError>>()?;
+        let numeric = |r: &[String]| r.iter().all(|f| parse(f).is_some());
+        if let Some(h) = lines.iter().position(|(_, r)| {
+            r.iter().filter(|f| wavelength(f).is_some()).count() >= 2 && !numeric(r)
+        }) {
+            by_row(&lines[h..])
+        } else if let Some(s) = lines.iter().position(|(_, r)| r.len() >= 2 && numeric(r)) {
+            by_column(&lines[s.saturating_sub(1)..], s > 0)
+        } else {
+            Err(err(1, "no spectral data"))
+        }
+    }
+}
+
+type Row = (usize, Vec<String>);
+
+fn separator(line: &str) -> char {
+    if line.contains('\t') {
+        '\t'
+    } else if line.contains(';') {
+        ';'
+    } else {
+        ','
+    }
+}
+
+/// Wavelength, in nanometer, of a spectral field label, such as "380", "380 nm", "nm380", "R380", or
+/// "SPECTRAL_NM380".
+fn wavelength(label: &str) -> Option<f64> {
+    let l = label.trim().to_ascii_uppercase();
+    let l = l.strip_suffix("NM").unwrap_or(&l);
+    let l = ["SPECTRAL_NM", "SPECTRAL_", "NM", "R"]
+        .iter()
+        .find_map(|p| l.strip_prefix(p))
+        .unwrap_or(l);
+    let w: f64 = l.trim_matches(|c| c == ' ' || c == '_').parse().ok()?;
+    (300.0..=1100.0).contains(&w).then_some(w)
+}
+
+/// Spectra from a table with a header line, and a row for each patch.
+fn by_row(lines: &[Row]) -> Result<SwatchLibrary, CsvError> {
+    let (n0, header) = &lines[0];
+    let mut spectral: Vec<(usize, f64)> = header
+        .iter()
+        .enumerate()
+        .filter_map(|(j, f)| wavelength(f).map(|w| (j, w)))
+        .collect();
+    spectral.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    let id = (0..header.len()).find(|j| spectral.iter().all(|(k, _)| k != j));
+    let m = header.len();
+
+    let rows = &lines[1..];
+    if rows.is_empty() {
+        return Err(err(*n0, "no patches"));
+    }
+    let mut data = DMatrix::zeros(spectral.len(), rows.len());
+    let mut keys = Vec::with_capacity(rows.len());
+    for (c, (n, r)) in rows.iter().enumerate() {
+        if r.len() < m {
+            return Err(err(*n, format!("expected {} fields, found {}", m, r.len())));
+        }
+        for (i, &(j, _)) in spectral.iter().enumerate() {
+            data[(i, c)] = number(*n, &r[j])?;
+        }
+        keys.push(id.map_or_else(|| (c + 1).to_string(), |j| r[j].clone()));
+    }
+    let wavelengths: Vec<f64> = spectral.iter().map(|&(_, w)| w).collect();
+    library(*n0, &wavelengths, data, &keys)
+}
+
+/// Spectra from a table with a wavelength column, a column for each patch, and an optional header line.
+fn by_column(lines: &[Row], has_header: bool) -> Result<SwatchLibrary, CsvError> {
+    let (header, rows) = if has_header {
+        (Some(&lines[0]), &lines[1..])
+    } else {
+        (None, lines)
+    };
+    let (n0, first) = &rows[0];
+    let m = first.len();
+    let header = header.filter(|(_, h)| h.len() == m);
+    let mut table = Vec::new();
+    for (n, r) in rows {
+        if r.len() != m {
+            return Err(err(*n, format!("expected {} values, found {}", m, r.len())));
+        }
+        let v = r
+            .iter()
+            .map(|f| number(*n, f))
+            .collect::<Result<Vec<f64>, CsvError>>()?;
+        table.push(v);
+    }
+    if table.len() < 2 {
+        return Err(err(*n0, "at least 2 wavelengths expected"));
+    }
+    table.sort_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+    let wavelengths: Vec<f64> = table.iter().map(|r| r[0]).collect();
+    let data = DMatrix::from_fn(table.len(), m - 1, |i, j| table[i][j + 1]);
+    let keys: Vec<String> = match header {
+        Some((_, h)) => h[1..].to_vec(),
+        None => (1..m).map(|j| j.to_string()).collect(),
+    };
+    library(*n0, &wavelengths, data, &keys)
+}
+
+/// A number, with a decimal point, or a decimal comma.
+fn parse(s: &str) -> Option<f64> {
+    s.parse().ok().or_else(|| s.replace(',', ".").parse().ok())
+}
+
+/// A finite number, or an error for line `n`.
+fn number(n: usize, s: &str) -> Result<f64, CsvError> {
+    parse(s)
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| err(n, format!("invalid number \"{}\"", s)))
+}
+
+fn library(
+    n: usize,
+    wavelengths: &[f64],
+    mut data: DMatrix<f64>,
+    keys: &[String],
+) -> Result<SwatchLibrary, CsvError> {
+    if data.max() > 2.0 {
+        data /= 100.0;
+    }
+    let (domain, data) =
+        regular_domain(wavelengths, data).map_err(|e| err(n, format!("wavelengths: {}", e)))?;
+    let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+    unique(n, &keys)?;
+    Ok(SwatchLibrary::from_columns(domain, data, &keys))
+}
+
+#[test]
+fn test_i1pro() {
+    use crate::SpectralDistribution;
+    use approx::assert_abs_diff_eq;
+
+    // i1Profiler export, with a row for each patch, ascending, with lab values, quoted labels, and decimal commas
+    let text = "\"SampleID\";\"SAMPLE_NAME\";\"R380\";\"R390\";\"R400\";\"LAB_L\"\n\
+                1;A1;5,0;6,0;7,0;40,1\n\
+                2;A2;50;60;70;80,2\n";
+    let lib = I1Pro::swatches(text).unwrap();
+    assert_eq!(lib.keys().unwrap(), vec!["1", "2"]);
+    let (d, s) = lib.spd();
+    assert_eq!(d.len(), 3);
+    assert_abs_diff_eq!(s[(0, 0)], 0.05, epsilon = 1E-12);
+    assert_abs_diff_eq!(s[(2, 1)], 0.7, epsilon = 1E-12);
+
+    // ColorMunki export, with a column for each patch, descending, with a preamble
+    let text = "ColorMunki Design\nSpectral data\n\nnm,White,Black\n730,0.91,0.031\n720,0.90,0.032\n710,0.89,0.033\n";
+    let lib = I1Pro::swatches(text).unwrap();
+    assert_eq!(lib.keys().unwrap(), vec!["White", "Black"]);
+    let (d, s) = lib.spd();
+    assert_eq!(d.len(), 3);
+    assert_abs_diff_eq!(s[(0, 1)], 0.033, epsilon = 1E-12);
+    assert_abs_diff_eq!(s[(2, 0)], 0.91, epsilon = 1E-12);
+
+    // column layout without header, keyed by column number
+    let lib = I1Pro::swatches("400\t0.1\n410\t0.2\n").unwrap();
+    assert_eq!(lib.keys().unwrap(), vec!["1"]);
+
+    assert_eq!(
+        I1Pro::swatches("Patch\t380\t390\nA1\t0.1\n")
+            .unwrap_err()
+            .to_string(),
+        "CSV data, line 2: expected 3 fields, found 2"
+    );
+    assert_eq!(
+        I1Pro::swatches("Patch\t380\t390\nA1\t0.1\t0.2\nA1\t0.1\t0.2\n")
+            .unwrap_err()
+            .to_string(),
+        "CSV data, line 1: duplicate key \"A1\""
+    );
+    assert_eq!(
+        I1Pro::swatches("400\t0.1\nnan\t0.2\n")
+            .unwrap_err()
+            .to_string(),
+        "CSV data, line 2: invalid number \"nan\""
+    );
+}
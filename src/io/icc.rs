@@ -0,0 +1,647 @@
+/*!
+ICC display profiles.
+
+An `IccProfile` writes a version 4 ICC display profile, to be installed in the color management system of an operating
+system, for a display characterized by an `RgbSpace`: the chromaticities of its primaries, and of its white point, and
+its tone response curves. The profile is a matrix/TRC profile, with a media white point, a Bradford chromatic
+adaptation tag for the adaptation of the display white to the D50 profile connection space illuminant, colorant
+tags for the adapted primaries, and a parametric curve, or a table, for each channel.
+
+For displays which are not well described by a matrix, and tone response curves, a three dimensional table, with
+measured XYZ values for a regular grid of RGB values, can be added with `IccProfile::set_dtob`. It is written as a
+`D2B0` multi-process element tag, with float values, which is used by color management systems supporting it,
+instead of the matrix, and the tone response curves.
+
+`IccProfile::parse` reads the media white point, and chromatic adaptation, colorant, and tone response curve tags of
+an existing matrix/TRC display profile, of version 2 or 4, into an `RgbSpace`, with the chromaticities of the display
+primaries, and white point, before their adaptation to D50. Device to PCS tables are not read.
+
+# Examples
+```
+use scot::io::IccProfile;
+use scot::models::RgbSpace;
+
+let srgb = RgbSpace::srgb();
+let n = 9;
+let grid: Vec<[f64; 3]> = (0..n * n * n)
+    .map(|i| {
+        let v = |k: usize| (i / k % n) as f64 / (n - 1) as f64;
+        srgb.xyz([v(n * n), v(n), v(1)])
+    })
+    .collect();
+let icc = IccProfile::new(srgb)
+    .set_description("sRGB display")
+    .set_dtob(n, grid)
+    .to_bytes();
+assert_eq!(&icc[36..40], b"acsp");
+assert_eq!(u32::from_be_bytes([icc[0], icc[1], icc[2], icc[3]]) as usize, icc.len());
+```
+*/
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use nalgebra::{Matrix3, Vector3};
+
+use crate::models::{bradford, RgbSpace, ToneCurve, ICC_D50};
+
+/// ICC profile version 4.3, as written in the profile header.
+const ICC_VERSION: u32 = 0x0430_0000;
+
+/// Error for ICC profiles which can not be read, or which are not matrix/TRC RGB profiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IccError {
+    pub message: String,
+}
+
+impl fmt::Display for IccError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ICC profile: {}", self.message)
+    }
+}
+
+impl std::error::Error for IccError {}
+
+fn err(message: impl Into<String>) -> IccError {
+    IccError {
+        message: message.into(),
+    }
+}
+
+/// A display profile, for an RGB color space, with an optional measured device to PCS table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IccProfile {
+    /// Profile description, as shown by operating systems.
+    pub description: String,
+    pub copyright: String,
+    /// Creation date, and time, in UTC, as year, month, day, hours, minutes, and seconds.
+    pub date: [u16; 6],
+    pub rgb: RgbSpace,
+    /// Number of grid points, for each channel, and the XYZ values for the grid, with the red channel varying
+    /// slowest, and the blue channel fastest.
+    pub dtob: Option<(usize, Vec<[f64; 3]>)>,
+}
+
+impl IccProfile {
+    /// A matrix/TRC display profile for an RGB color space, created at the current time.
+    pub fn new(rgb: RgbSpace) -> Self {
+        Self {
+            description: "RGB display".to_string(),
+            copyright: "No copyright, use freely".to_string(),
+            date: utc_now(),
+            rgb,
+            dtob: None,
+        }
+    }
+
+    pub fn set_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    pub fn set_copyright(mut self, copyright: &str) -> Self {
+        self.copyright = copyright.to_string();
+        self
+    }
+
+    pub fn set_date(mut self, date: [u16; 6]) -> Self {
+        self.date = date;
+        self
+    }
+
+    /**
+    Adds a device to PCS table, from measured XYZ values, with Y = 1.0 for the display white, for a regular grid of
+    encoded RGB values, with `grid_points` values from 0.0 to 1.0 for each channel. The XYZ values are listed with the
+    red channel varying slowest, and the blue channel fastest, and are adapted to the D50 profile connection space,
+    as the colorant tags, when written.
+    */
+    pub fn set_dtob(mut self, grid_points: usize, xyz: Vec<[f64; 3]>) -> Self {
+        assert!(
+            (2..=255).contains(&grid_points),
+            "grid points should be in the range from 2 to 255"
+        );
+        assert_eq!(
+            xyz.len(),
+            grid_points.pow(3),
+            "a value for each grid point is required"
+        );
+        self.dtob = Some((grid_points, xyz));
+        self
+    }
+
+    /// The profile, as bytes, to be written to a file.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let chad = bradford(self.rgb.white_xyz(), ICC_D50);
+        let m = chad * self.rgb.rgb_to_xyz_matrix();
+        let column = |j: usize| [m[(0, j)], m[(1, j)], m[(2, j)]];
+        let [r, g, b] = &self.rgb.trc;
+        let mut tags = vec![
+            (*b"desc", mluc(&self.description)),
+            (*b"cprt", mluc(&self.copyright)),
+            (*b"wtpt", xyz_type(ICC_D50)),
+            (*b"chad", sf32(&chad)),
+            (*b"rXYZ", xyz_type(column(0))),
+            (*b"gXYZ", xyz_type(column(1))),
+            (*b"bXYZ", xyz_type(column(2))),
+            (*b"rTRC", curve(r)),
+            (*b"gTRC", curve(g)),
+            (*b"bTRC", curve(b)),
+        ];
+        if let Some((n, xyz)) = &self.dtob {
+            tags.push((*b"D2B0", mpet(*n, xyz, &chad)));
+        }
+
+        // tag table, and tag data, with each tag starting at a four byte boundary
+        let start = 128 + 4 + 12 * tags.len();
+        let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+        let mut data = Vec::new();
+        for (signature, bytes) in &tags {
+            table.extend(signature);
+            table.extend(((start + data.len()) as u32).to_be_bytes());
+            table.extend((bytes.len() as u32).to_be_bytes());
+            data.extend(bytes);
+            data.resize(data.len().div_ceil(4) * 4, 0);
+        }
+
+        let mut icc = Vec::with_capacity(start + data.len());
+        icc.extend(((start + data.len()) as u32).to_be_bytes());
+        icc.extend([0; 4]); // preferred CMM
+        icc.extend(ICC_VERSION.to_be_bytes());
+        icc.extend(b"mntrRGB XYZ ");
+        for v in self.date {
+            icc.extend(v.to_be_bytes());
+        }
+        icc.extend(b"acsp");
+        icc.extend([0; 28]); // platform, flags, manufacturer, model, attributes, and perceptual intent
+        for v in ICC_D50 {
+            icc.extend(s15f16(v));
+        }
+        icc.extend([0; 4]); // creator
+        icc.extend([0; 44]); // profile ID, not calculated, and reserved bytes
+        icc.extend(table);
+        icc.extend(data);
+        icc
+    }
+
+    /**
+    Reads an RGB matrix/TRC profile, with its description, copyright, creation date, and RGB space.
+
+    The display white point is obtained from the chromatic adaptation tag, or, for version 2 profiles without this
+    tag, from the media white point tag, with the colorant tags assumed to be adapted to D50 by a Bradford
+    transform.
+    */
+    pub fn parse(icc: &[u8]) -> Result<Self, IccError> {
+        if icc.len() < 132 || &icc[36..40] != b"acsp" {
+            return Err(err("not an ICC profile"));
+        }
+        if &icc[16..24] != b"RGB XYZ " {
+            return Err(err("not an RGB profile, with an XYZ connection space"));
+        }
+        let mut tags = HashMap::new();
+        for k in 0..u32_at(icc, 128)? as usize {
+            let i = 132 + 12 * k;
+            let signature = icc
+                .get(i..i + 4)
+                .ok_or_else(|| err("truncated tag table"))?;
+            let (offset, size) = (u32_at(icc, i + 4)? as usize, u32_at(icc, i + 8)? as usize);
+            let data = offset
+                .checked_add(size)
+                .and_then(|end| icc.get(offset..end))
+                .ok_or_else(|| err("tag data outside profile"))?;
+            tags.insert(signature, data);
+        }
+        let tag = |signature: &[u8]| {
+            tags.get(signature).copied().ok_or_else(|| {
+                err(format!(
+                    "no {} tag",
+                    String::from_utf8_lossy(signature).trim_end()
+                ))
+            })
+        };
+
+        let wtpt = read_xyz(tag(b"wtpt")?)?;
+        let chad = match tags.get(&b"chad"[..]) {
+            Some(t) => read_sf32(t)?,
+            None => bradford(wtpt, ICC_D50),
+        };
+        let inv = chad
+            .try_inverse()
+            .ok_or_else(|| err("singular chromatic adaptation matrix"))?;
+        let chromaticity = |xyz: Vector3<f64>| -> Result<[f64; 2], IccError> {
+            let s = xyz.sum();
+            if s > 0.0 {
+                Ok([xyz[0] / s, xyz[1] / s])
+            } else {
+                Err(err("invalid XYZ values"))
+            }
+        };
+        let mut primaries = [[0.0; 2]; 3];
+        for (p, signature) in primaries.iter_mut().zip([b"rXYZ", b"gXYZ", b"bXYZ"]) {
+            *p = chromaticity(inv * Vector3::from(read_xyz(tag(signature)?)?))?;
+        }
+        let white = chromaticity(inv * Vector3::from(ICC_D50))?;
+        let trc = [
+            read_curve(tag(b"rTRC")?)?,
+            read_curve(tag(b"gTRC")?)?,
+            read_curve(tag(b"bTRC")?)?,
+        ];
+
+        let mut date = [0u16; 6];
+        for (i, v) in date.iter_mut().enumerate() {
+            *v = u16::from_be_bytes([icc[24 + 2 * i], icc[25 + 2 * i]]);
+        }
+        let text = |signature: &[u8]| tag(signature).and_then(read_text).unwrap_or_default();
+        Ok(Self {
+            description: text(b"desc"),
+            copyright: text(b"cprt"),
+            date,
+            rgb: RgbSpace {
+                primaries,
+                white,
+                trc,
+            },
+            dtob: None,
+        })
+    }
+}
+
+/// Signed fixed point number, with 16 fractional bits.
+fn s15f16(v: f64) -> [u8; 4] {
+    ((v * 65536.0).round() as i32).to_be_bytes()
+}
+
+/// Multi-localized unicode tag, with a single US English record.
+fn mluc(text: &str) -> Vec<u8> {
+    let utf16: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+    let mut b = b"mluc\0\0\0\0".to_vec();
+    b.extend(1u32.to_be_bytes());
+    b.extend(12u32.to_be_bytes());
+    b.extend(b"enUS");
+    b.extend((utf16.len() as u32).to_be_bytes());
+    b.extend(28u32.to_be_bytes());
+    b.extend(utf16);
+    b
+}
+
+fn xyz_type(xyz: [f64; 3]) -> Vec<u8> {
+    let mut b = b"XYZ \0\0\0\0".to_vec();
+    for v in xyz {
+        b.extend(s15f16(v));
+    }
+    b
+}
+
+/// Matrix tag, with the matrix elements in row order.
+fn sf32(m: &Matrix3<f64>) -> Vec<u8> {
+    let mut b = b"sf32\0\0\0\0".to_vec();
+    for i in 0..3 {
+        for j in 0..3 {
+            b.extend(s15f16(m[(i, j)]));
+        }
+    }
+    b
+}
+
+/// Tone response curve tag: a parametric curve, of type 0 for a power function, or of type 3, or a table.
+fn curve(trc: &ToneCurve) -> Vec<u8> {
+    let para = |kind: u16, p: &[f64]| {
+        let mut b = b"para\0\0\0\0".to_vec();
+        b.extend(kind.to_be_bytes());
+        b.extend([0; 2]);
+        for &v in p {
+            b.extend(s15f16(v));
+        }
+        b
+    };
+    match trc {
+        ToneCurve::Gamma(g) => para(0, &[*g]),
+        ToneCurve::Parametric(p) => para(3, p),
+        ToneCurve::Table(t) => {
+            let mut b = b"curv\0\0\0\0".to_vec();
+            b.extend((t.len() as u32).to_be_bytes());
+            for v in t {
+                b.extend(((v.clamp(0.0, 1.0) * 65535.0).round() as u16).to_be_bytes());
+            }
+            b
+        }
+    }
+}
+
+/// Multi-process element tag, with a single float color lookup table element, for XYZ values adapted to D50.
+fn mpet(n: usize, xyz: &[[f64; 3]], chad: &Matrix3<f64>) -> Vec<u8> {
+    let mut clut = b"clut\0\0\0\0".to_vec();
+    clut.extend(3u16.to_be_bytes());
+    clut.extend(3u16.to_be_bytes());
+    let mut grid = [0u8; 16];
+    grid[..3].fill(n as u8);
+    clut.extend(grid);
+    for v in xyz {
+        let pcs = chad * Vector3::from(*v);
+        for c in pcs.iter() {
+            clut.extend((*c as f32).to_be_bytes());
+        }
+    }
+
+    let mut b = b"mpet\0\0\0\0".to_vec();
+    b.extend(3u16.to_be_bytes());
+    b.extend(3u16.to_be_bytes());
+    b.extend(1u32.to_be_bytes());
+    b.extend(24u32.to_be_bytes());
+    b.extend((clut.len() as u32).to_be_bytes());
+    b.extend(clut);
+    b
+}
+
+fn u32_at(b: &[u8], i: usize) -> Result<u32, IccError> {
+    b.get(i..i + 4)
+        .map(|v| u32::from_be_bytes([v[0], v[1], v[2], v[3]]))
+        .ok_or_else(|| err("truncated data"))
+}
+
+fn u16_at(b: &[u8], i: usize) -> Result<u16, IccError> {
+    b.get(i..i + 2)
+        .map(|v| u16::from_be_bytes([v[0], v[1]]))
+        .ok_or_else(|| err("truncated data"))
+}
+
+fn s15f16_at(b: &[u8], i: usize) -> Result<f64, IccError> {
+    Ok(u32_at(b, i)? as i32 as f64 / 65536.0)
+}
+
+fn check_type(b: &[u8], types: &[&[u8]]) -> Result<(), IccError> {
+    match b.get(0..4) {
+        Some(t) if types.contains(&t) => Ok(()),
+        Some(t) => Err(err(format!(
+            "unexpected tag type {}",
+            String::from_utf8_lossy(t)
+        ))),
+        None => Err(err("truncated data")),
+    }
+}
+
+fn read_xyz(b: &[u8]) -> Result<[f64; 3], IccError> {
+    check_type(b, &[b"XYZ "])?;
+    Ok([s15f16_at(b, 8)?, s15f16_at(b, 12)?, s15f16_at(b, 16)?])
+}
+
+fn read_sf32(b: &[u8]) -> Result<Matrix3<f64>, IccError> {
+    check_type(b, &[b"sf32"])?;
+    let mut m = Matrix3::zeros();
+    for i in 0..3 {
+        for j in 0..3 {
+            m[(i, j)] = s15f16_at(b, 8 + 12 * i + 4 * j)?;
+        }
+    }
+    Ok(m)
+}
+
+/// Text of a version 4 multi-localized unicode tag, using its first record, or of a version 2 text, or text
+/// description, tag.
+fn read_text(b: &[u8]) -> Result<String, IccError> {
+    check_type(b, &[b"mluc", b"desc", b"text"])?;
+    let ascii = |v: &[u8]| {
+        String::from_utf8_lossy(v)
+            .trim_end_matches('\0')
+            .to_string()
+    };
+    match &b[0..4] {
+        b"mluc" => {
+            let (length, offset) = (u32_at(b, 20)? as usize, u32_at(b, 24)? as usize);
+            let utf16 = offset
+                .checked_add(length)
+                .and_then(|end| b.get(offset..end))
+                .ok_or_else(|| err("truncated text"))?;
+            let units: Vec<u16> = utf16
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                .collect();
+            Ok(String::from_utf16_lossy(&units))
+        }
+        b"desc" => {
+            let length = u32_at(b, 8)? as usize;
+            Ok(ascii(
+                12usize
+                    .checked_add(length)
+                    .and_then(|end| b.get(12..end))
+                    .ok_or_else(|| err("truncated text"))?,
+            ))
+        }
+        _ => Ok(ascii(b.get(8..).ok_or_else(|| err("truncated text"))?)),
+    }
+}
+
+/**
+Tone response curve of a curve, or parametric curve, tag. Parametric curves of type 2, and type 4, which have an
+offset not supported by `ToneCurve`, are converted into a table, with 1024 values.
+*/
+fn read_curve(b: &[u8]) -> Result<ToneCurve, IccError> {
+    check_type(b, &[b"curv", b"para"])?;
+    if &b[0..4] == b"curv" {
+        let n = u32_at(b, 8)? as usize;
+        return Ok(match n {
+            0 => ToneCurve::Gamma(1.0),
+            1 => ToneCurve::Gamma(u16_at(b, 12)? as f64 / 256.0),
+            _ => ToneCurve::Table(
+                (0..n)
+                    .map(|i| Ok(u16_at(b, 12 + 2 * i)? as f64 / 65535.0))
+                    .collect::<Result<_, IccError>>()?,
+            ),
+        });
+    }
+    let kind = u16_at(b, 8)?;
+    let count = match kind {
+        0 => 1,
+        1 => 3,
+        2 => 4,
+        3 => 5,
+        4 => 7,
+        _ => return Err(err(format!("unknown parametric curve type {}", kind))),
+    };
+    let mut p = [0.0; 7];
+    for (i, v) in p.iter_mut().take(count).enumerate() {
+        *v = s15f16_at(b, 12 + 4 * i)?;
+    }
+    let [g, a, b, c, d, e, f] = p;
+    Ok(match kind {
+        0 => ToneCurve::Gamma(g),
+        1 => ToneCurve::Parametric([g, a, b, 0.0, -b / a]),
+        3 => ToneCurve::Parametric([g, a, b, c, d]),
+        _ => {
+            // type 2 has its offset c for all values, with the threshold of type 1
+            let (c, d, e, f) = if kind == 2 {
+                (0.0, -b / a, c, c)
+            } else {
+                (c, d, e, f)
+            };
+            ToneCurve::Table(
+                (0..1024)
+                    .map(|i| {
+                        let x = i as f64 / 1023.0;
+                        if x >= d {
+                            (a * x + b).max(0.0).powf(g) + e
+                        } else {
+                            c * x + f
+                        }
+                    })
+                    .collect(),
+            )
+        }
+    })
+}
+
+/// Current date, and time, in UTC, using the days to civil date algorithm of Howard Hinnant.
+fn utc_now() -> [u16; 6] {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (days, s) = (secs / 86400 + 719468, secs % 86400);
+    let era = days / 146097;
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    [year, month, day, s / 3600, s / 60 % 60, s % 60].map(|v| v as u16)
+}
+
+#[test]
+fn test_icc_profile() {
+    use approx::assert_abs_diff_eq;
+
+    let u32_at = |b: &[u8], i: usize| u32::from_be_bytes([b[i], b[i + 1], b[i + 2], b[i + 3]]);
+    let s15_at = |b: &[u8], i: usize| u32_at(b, i) as i32 as f64 / 65536.0;
+    let tag = |b: &[u8], signature: &[u8]| -> Option<(usize, usize)> {
+        (0..u32_at(b, 128) as usize)
+            .map(|k| 132 + 12 * k)
+            .find(|&i| &b[i..i + 4] == signature)
+            .map(|i| (u32_at(b, i + 4) as usize, u32_at(b, i + 8) as usize))
+    };
+
+    let icc = IccProfile::new(RgbSpace::srgb())
+        .set_date([2021, 6, 1, 12, 0, 0])
+        .to_bytes();
+    assert_eq!(u32_at(&icc, 0) as usize, icc.len());
+    assert_eq!(icc.len() % 4, 0);
+    assert_eq!(u32_at(&icc, 8), ICC_VERSION);
+    assert_eq!(&icc[12..24], b"mntrRGB XYZ ");
+    assert_eq!(&icc[24..26], 2021u16.to_be_bytes());
+    assert_eq!(&icc[36..40], b"acsp");
+    assert_eq!(u32_at(&icc, 128), 10);
+
+    // sRGB colorant tags, as in the sRGB profiles of the ICC
+    let (offset, _) = tag(&icc, b"rXYZ").unwrap();
+    assert_eq!(&icc[offset..offset + 4], b"XYZ ");
+    assert_abs_diff_eq!(s15_at(&icc, offset + 8), 0.4361, epsilon = 2E-4);
+    assert_abs_diff_eq!(s15_at(&icc, offset + 12), 0.2225, epsilon = 2E-4);
+    assert_abs_diff_eq!(s15_at(&icc, offset + 16), 0.0139, epsilon = 2E-4);
+
+    let (offset, _) = tag(&icc, b"gTRC").unwrap();
+    assert_eq!(&icc[offset..offset + 4], b"para");
+    assert_eq!(&icc[offset + 8..offset + 10], 3u16.to_be_bytes());
+    assert_abs_diff_eq!(s15_at(&icc, offset + 12), 2.4, epsilon = 1E-4);
+
+    let (offset, size) = tag(&icc, b"desc").unwrap();
+    let text: Vec<u16> = icc[offset + 28..offset + size]
+        .chunks(2)
+        .map(|c| u16::from_be_bytes([c[0], c[1]]))
+        .collect();
+    assert_eq!(String::from_utf16(&text).unwrap(), "RGB display");
+    assert!(tag(&icc, b"D2B0").is_none());
+
+    // device to PCS table, for a 2x2x2 grid, with D50 adapted white
+    let srgb = RgbSpace::srgb();
+    let xyz: Vec<[f64; 3]> = (0..8)
+        .map(|i| srgb.xyz([(i / 4) as f64, (i / 2 % 2) as f64, (i % 2) as f64]))
+        .collect();
+    let icc = IccProfile::new(srgb).set_dtob(2, xyz).to_bytes();
+    let (offset, size) = tag(&icc, b"D2B0").unwrap();
+    assert_eq!(size, 24 + 28 + 8 * 3 * 4);
+    assert_eq!(&icc[offset + 24..offset + 28], b"clut");
+    let f32_at = |i: usize| f32::from_be_bytes([icc[i], icc[i + 1], icc[i + 2], icc[i + 3]]) as f64;
+    let white = offset + 24 + 28 + 7 * 12;
+    assert_abs_diff_eq!(f32_at(white), 0.9642, epsilon = 1E-5);
+    assert_abs_diff_eq!(f32_at(white + 4), 1.0, epsilon = 1E-5);
+    assert_abs_diff_eq!(f32_at(white + 8), 0.8249, epsilon = 1E-5);
+}
+
+#[test]
+fn test_icc_parse() {
+    use approx::assert_abs_diff_eq;
+
+    for rgb in [
+        RgbSpace::srgb(),
+        RgbSpace::adobe_rgb(),
+        RgbSpace::display_p3().set_trc(ToneCurve::Table(
+            (0..=16).map(|i| (i as f64 / 16.0).powi(2)).collect(),
+        )),
+    ] {
+        let icc = IccProfile::new(rgb.clone())
+            .set_description("Test display \u{2013} calibrated")
+            .to_bytes();
+        let p = IccProfile::parse(&icc).unwrap();
+        assert_eq!(p.description, "Test display \u{2013} calibrated");
+        assert_eq!(p.copyright, "No copyright, use freely");
+        for i in 0..3 {
+            assert_abs_diff_eq!(p.rgb.primaries[i][0], rgb.primaries[i][0], epsilon = 1E-4);
+            assert_abs_diff_eq!(p.rgb.primaries[i][1], rgb.primaries[i][1], epsilon = 1E-4);
+            assert_abs_diff_eq!(
+                p.rgb.trc[i].decode(0.3),
+                rgb.trc[i].decode(0.3),
+                epsilon = 1E-4
+            );
+        }
+        assert_abs_diff_eq!(p.rgb.white[0], 0.3127, epsilon = 1E-4);
+        assert_abs_diff_eq!(p.rgb.white[1], 0.3290, epsilon = 1E-4);
+    }
+
+    // version 2 profile, without chromatic adaptation tag, and with a D65 media white point
+    let mut icc = IccProfile::new(RgbSpace::srgb()).to_bytes();
+    let tag = |icc: &[u8], signature: &[u8]| {
+        (0..10)
+            .map(|k| 132 + 12 * k)
+            .find(|&i| &icc[i..i + 4] == signature)
+            .unwrap()
+    };
+    let i = tag(&icc, b"chad");
+    icc[i..i + 4].copy_from_slice(b"xxxx");
+    let offset = u32_at(&icc, tag(&icc, b"wtpt") + 4).unwrap() as usize;
+    icc[offset..offset + 20].copy_from_slice(&xyz_type(RgbSpace::srgb().white_xyz()));
+    let p = IccProfile::parse(&icc).unwrap();
+    assert_abs_diff_eq!(p.rgb.white[0], 0.3127, epsilon = 1E-4);
+    assert_abs_diff_eq!(p.rgb.primaries[0][0], 0.64, epsilon = 1E-4);
+    assert_abs_diff_eq!(p.rgb.primaries[2][1], 0.06, epsilon = 1E-4);
+
+    // parametric curve of type 4
+    let mut b = b"para\0\0\0\0\0\x04\0\0".to_vec();
+    for v in [2.0, 1.0, 0.0, 0.5, 0.1, 0.0, 0.0] {
+        b.extend(s15f16(v));
+    }
+    let trc = read_curve(&b).unwrap();
+    assert_abs_diff_eq!(trc.decode(0.05), 0.025, epsilon = 1E-3);
+    assert_abs_diff_eq!(trc.decode(0.5), 0.25, epsilon = 1E-3);
+
+    assert_eq!(
+        IccProfile::parse(&icc[..100]).unwrap_err().to_string(),
+        "ICC profile: not an ICC profile"
+    );
+    let i = tag(&icc, b"rTRC");
+    icc[i..i + 4].copy_from_slice(b"yyyy");
+    assert_eq!(
+        IccProfile::parse(&icc).unwrap_err().to_string(),
+        "ICC profile: no rTRC tag"
+    );
+
+    // truncated, and overflowing, text tags are errors
+    assert_eq!(read_text(b"text\0\0").unwrap_err().to_string(), "ICC profile: truncated text");
+    let mut b = b"desc\0\0\0\0".to_vec();
+    b.extend(u32::MAX.to_be_bytes());
+    assert!(read_text(&b).is_err());
+    let mut b = b"mluc\0\0\0\0\0\0\0\x01\0\0\0\x0cenUS".to_vec();
+    b.extend(u32::MAX.to_be_bytes());
+    b.extend(u32::MAX.to_be_bytes());
+    assert!(read_text(&b).is_err());
+}
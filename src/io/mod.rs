@@ -0,0 +1,63 @@
+/*!
+Reading, and writing, of spectral data files, as used to exchange measurements between instruments, and
+applications, and of ICC color profiles, and reading of spectrometer text exports.
+
+Files are read from, and written to, strings, leaving the handling of files, and their encoding, to the application.
+
+Spectral data read from a file, on a regular wavelength grid with a whole number step size, in nanometer, is used as
+is; data on other grids is linearly interpolated to a regular domain, with a step size of 1nm, covering the range of
+the measured wavelengths.
+*/
+
+use nalgebra::DMatrix;
+
+use crate::{Domain, DomainError, DynamicSpectralDistribution, IrregularDomain, WavelengthStep};
+
+mod xml;
+
+pub mod tm27;
+pub use self::tm27::*;
+
+pub mod cgats;
+pub use self::cgats::*;
+
+pub mod csv;
+pub use self::csv::*;
+
+pub mod argyll;
+pub use self::argyll::*;
+
+pub mod i1pro;
+pub use self::i1pro::*;
+
+pub mod spectrometer;
+pub use self::spectrometer::*;
+
+pub mod icc;
+pub use self::icc::*;
+
+/// Regular domain for spectral values, with a row for each wavelength, in nanometer, and the values on that domain.
+pub(crate) fn regular_domain(
+    wavelengths: &[f64],
+    data: DMatrix<f64>,
+) -> Result<(Domain<WavelengthStep>, DMatrix<f64>), DomainError> {
+    let w = wavelengths;
+    let n = w.len();
+    if n < 2 {
+        return Err(DomainError::EmptyRange);
+    }
+    let step = w[1] - w[0];
+    let regular = step > 0.0
+        && (step - step.round()).abs() < 1E-6
+        && w.iter()
+            .enumerate()
+            .all(|(i, v)| (v - (w[0] + i as f64 * step)).abs() < 1E-6 * step);
+    if regular {
+        if let Ok(d) = Domain::from_nm(w[0], w[n - 1], step.round()) {
+            return Ok((d, data));
+        }
+    }
+    let d = IrregularDomain::from_nm(w.to_vec())?;
+    let sd = DynamicSpectralDistribution::from_irregular(&d, data, 1.0)?;
+    Ok((sd.domain().clone(), sd.data().clone()))
+}
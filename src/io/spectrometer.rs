@@ -0,0 +1,280 @@
+/*!
+Text exports of spectrometers, such as the data files of the Ocean Optics SpectraSuite, and OceanView, applications,
+and the tab, or comma, separated dumps of other spectrometer software.
+
+These files have a wavelength column, in nanometer, with a row for each pixel of the spectrometer's detector, on an
+irregular wavelength grid, followed by one, or more, columns with measured values, such as raw counts, or calibrated
+spectral irradiance values. The table is typically preceded by a header, with lines such as
+`Integration Time (sec): 1.000000E-1`, and sometimes by a line with column labels, and followed by an end marker, such
+as `>>>>>End Processed Spectral Data<<<<<`.
+
+`Spectrometer::parse` reads these files without knowledge of their layout:
+
+- the table is the longest run of lines with the same number of numbers, at least two, separated by tabs, semicolons,
+  commas, or spaces, and with a decimal point, or, if not comma separated, a decimal comma;
+- a line before the table, with a label for each column, is used for the keys of the spectra;
+- other header lines, with a key, and a value, separated by a colon, are kept as metadata;
+- a leading column of pixel numbers, with consecutive integers, starting at 0, or 1, is skipped;
+- rows are sorted by wavelength, so tables in descending order can be read too.
+
+The spectra are available as a `DynamicSpectralDistribution`, with `Spectrometer::spectra`, interpolated to a regular
+domain as described in the `io` module, or with `Spectrometer::resample`, interpolated onto a domain of choice, with
+any of the interpolation methods of `SpectralDistribution::resample`, ready for use in colorimetric calculations,
+such as correlated color temperatures, or TM-30 color rendering reports.
+
+# Examples
+```
+use scot::io::Spectrometer;
+use scot::{Domain, Interp, SpectralDistribution, NM};
+
+let text = "Data from capture.txt Node\n\
+            Integration Time (sec): 1.000000E-1\n\
+            >>>>>Begin Spectral Data<<<<<\n\
+            399.71\t10.0\n400.18\t12.0\n400.66\t14.0\n401.13\t16.0\n401.61\t18.0\n";
+let capture = Spectrometer::parse(text).unwrap();
+assert_eq!(capture.metadata("Integration Time (sec)"), Some("1.000000E-1"));
+let sd = capture.resample(&Domain::new(400, 401, NM), Interp::Linear).unwrap();
+assert_eq!(sd.shape(), (2, 1));
+```
+*/
+
+use nalgebra::DMatrix;
+
+use crate::{Domain, DynamicSpectralDistribution, Interp, SpectralDistribution, WavelengthStep};
+
+use super::csv::{err, fields};
+use super::{regular_domain, CsvError};
+
+/// Spectral data, and header information, of a spectrometer text export.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spectrometer {
+    /// Header lines with a key, and a value, separated by a colon.
+    pub metadata: Vec<(String, String)>,
+    /// Labels of the value columns, empty for tables without a label line.
+    pub labels: Vec<String>,
+    /// Wavelengths, in nanometer, in increasing order.
+    pub wavelengths: Vec<f64>,
+    /// Measured values, with a row for each wavelength, and a column for each value column.
+    pub data: DMatrix<f64>,
+}
+
+impl Spectrometer {
+    /// Reads the spectral table, the column labels, and the metadata, of a spectrometer text export.
+    pub fn parse(text: &str) -> Result<Self, CsvError> {
+        let lines: Vec<(usize, &str)> = text
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l.trim()))
+            .filter(|(_, l)| !l.is_empty())
+            .collect();
+        let rows: Vec<Option<(char, Vec<f64>)>> = lines.iter().map(|(_, l)| numbers(l)).collect();
+        let width = |i: usize| rows[i].as_ref().map(|(_, v)| v.len());
+
+        // the longest run of numeric lines, with the same number of values
+        let (mut start, mut len, mut i) = (0, 0, 0);
+        while i < rows.len() {
+            let k = match width(i) {
+                Some(m) => (i..rows.len()).take_while(|&j| width(j) == Some(m)).count(),
+                None => 1,
+            };
+            if width(i).is_some() && k > len {
+                start = i;
+                len = k;
+            }
+            i += k;
+        }
+        if len < 2 {
+            return Err(err(1, "no spectral data"));
+        }
+        let (separator, first) = rows[start].clone().unwrap();
+        let m = first.len();
+        let mut table: Vec<(usize, &Vec<f64>)> = (start..start + len)
+            .map(|i| (lines[i].0, &rows[i].as_ref().unwrap().1))
+            .collect();
+
+        for (n, r) in &table {
+            if let Some(v) = r.iter().find(|v| !v.is_finite()) {
+                return Err(err(*n, format!("invalid number {}", v)));
+            }
+        }
+
+        let p0 = first[0];
+        let pixels = m > 2
+            && (p0 == 0.0 || p0 == 1.0)
+            && table
+                .iter()
+                .enumerate()
+                .all(|(i, (_, r))| r[0] == p0 + i as f64);
+        let w = if pixels { 1 } else { 0 };
+        table.sort_by(|a, b| a.1[w].partial_cmp(&b.1[w]).unwrap());
+        if let Some(pair) = table.windows(2).find(|p| p[0].1[w] == p[1].1[w]) {
+            return Err(err(
+                pair[1].0,
+                format!("duplicate wavelength {}", pair[1].1[w]),
+            ));
+        }
+        let wavelengths: Vec<f64> = table.iter().map(|(_, r)| r[w]).collect();
+        let data = DMatrix::from_fn(len, m - w - 1, |i, j| table[i].1[w + 1 + j]);
+
+        let mut header = &lines[..start];
+        let mut labels = Vec::new();
+        if let Some((n, l)) = header.last() {
+            let f = if separator == ' ' {
+                l.split_whitespace().map(str::to_string).collect()
+            } else {
+                fields(*n, l, separator)?
+            };
+            if f.len() == m {
+                labels = f[w + 1..].to_vec();
+                header = &header[..header.len() - 1];
+            }
+        }
+        let metadata = header
+            .iter()
+            .filter_map(|(_, l)| l.split_once(':'))
+            .filter(|(k, _)| !k.trim().is_empty())
+            .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+            .collect();
+
+        Ok(Self {
+            metadata,
+            labels,
+            wavelengths,
+            data,
+        })
+    }
+
+    /// The value of a metadata key, such as "Integration Time (sec)".
+    pub fn metadata(&self, key: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Measured spectra, keyed by their column labels, on a regular domain.
+    pub fn spectra(&self) -> Result<DynamicSpectralDistribution, CsvError> {
+        let (domain, data) = regular_domain(&self.wavelengths, self.data.clone())
+            .map_err(|e| err(0, format!("wavelengths: {}", e)))?;
+        let sd = DynamicSpectralDistribution::new(domain, data);
+        if self.labels.is_empty() {
+            Ok(sd)
+        } else {
+            let keys: Vec<&str> = self.labels.iter().map(String::as_str).collect();
+            Ok(sd.set_keys(&keys))
+        }
+    }
+
+    /**
+    Measured spectra, interpolated onto a domain, with an interpolation method, and values of zero outside the
+    measured range.
+
+    Spectra on an irregular wavelength grid are interpolated linearly to a regular domain, with a step size of 1nm,
+    first, which keeps the shape of spectra measured with a resolution of 1nm, or better, as for most spectrometers.
+    */
+    pub fn resample(
+        &self,
+        to: &Domain<WavelengthStep>,
+        method: Interp,
+    ) -> Result<DynamicSpectralDistribution, CsvError> {
+        Ok(self.spectra()?.resample(to, method))
+    }
+}
+
+/// Separator, and values, of a line with at least two numbers, separated by tabs, semicolons, commas, or spaces.
+fn numbers(line: &str) -> Option<(char, Vec<f64>)> {
+    ['\t', ';', ',', ' '].iter().find_map(|&sep| {
+        let mut f: Vec<&str> = if sep == ' ' {
+            line.split_whitespace().collect()
+        } else {
+            line.split(sep).collect()
+        };
+        while f.last().is_some_and(|s| s.trim().is_empty()) {
+            f.pop();
+        }
+        if f.len() < 2 {
+            return None;
+        }
+        f.iter()
+            .map(|s| number(s.trim().trim_matches('"'), sep != ','))
+            .collect::<Option<Vec<f64>>>()
+            .map(|v| (sep, v))
+    })
+}
+
+/// A number, with a decimal point, or, if allowed, a decimal comma.
+fn number(s: &str, decimal_comma: bool) -> Option<f64> {
+    s.parse().ok().or_else(|| {
+        if decimal_comma {
+            s.replace(',', ".").parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+#[test]
+fn test_spectrometer() {
+    use crate::illuminants::{CctDuvCalc, Robertson};
+    use crate::observers::CieObs1931;
+    use crate::NM;
+    use approx::assert_abs_diff_eq;
+
+    // SpectraSuite export of a 3000K blackbody, on an irregular grid, from 340 to about 980nm
+    let mut text = String::from(
+        "SpectraSuite Data File\n++++++++++++++++++++++++++++++++++++\nDate: Mon Jun 07 10:12:31 EDT 2021\n\
+         Spectrometer Serial Number: USB4C01234\nIntegration Time (usec): 100000 (USB4C01234)\n\
+         >>>>>Begin Processed Spectral Data<<<<<\n",
+    );
+    for i in 0..2000 {
+        let l = 340.0 + 0.3 * i as f64 + 1E-5 * (i * i) as f64;
+        text += &format!(
+            "{:.3}\t{:.6}\n",
+            l,
+            crate::planck(l * 1E-9, 3000.0, 1.0) * 1E-6
+        );
+    }
+    text += ">>>>>End Processed Spectral Data<<<<<\n";
+    let s = Spectrometer::parse(&text).unwrap();
+    assert_eq!(s.wavelengths.len(), 2000);
+    assert!(s.labels.is_empty());
+    assert_eq!(s.metadata("Date"), Some("Mon Jun 07 10:12:31 EDT 2021"));
+    assert_eq!(s.metadata("Spectrometer Serial Number"), Some("USB4C01234"));
+    let sd = s
+        .resample(&Domain::new(380, 780, NM), Interp::Sprague)
+        .unwrap();
+    let r: Robertson<CieObs1931> = Robertson::new();
+    let cct = r.cct_duv(sd).into_iter().next().unwrap();
+    assert_abs_diff_eq!(cct.t, 3000.0, epsilon = 2.0);
+
+    // pixel numbers, labels, descending wavelengths, and decimal commas
+    let text = "Pixel;Wavelength;Dark;Sample\n1;500,5;10;120\n2;500,0;11;110\n3;499,5;9;100\n";
+    let s = Spectrometer::parse(text).unwrap();
+    assert_eq!(s.wavelengths, vec![499.5, 500.0, 500.5]);
+    assert_eq!(s.labels, vec!["Dark", "Sample"]);
+    assert_eq!(s.data.column(1).as_slice(), &[100.0, 110.0, 120.0]);
+
+    // space separated, without header
+    let s = Spectrometer::parse("  400.0  0.5\n  401.0  0.6\n  402.0  0.7\n").unwrap();
+    let sd = s.spectra().unwrap();
+    assert_eq!(sd.shape(), (3, 1));
+
+    assert_eq!(
+        Spectrometer::parse("Integration Time: 100\n400\n")
+            .unwrap_err()
+            .to_string(),
+        "CSV data, line 1: no spectral data"
+    );
+    assert_eq!(
+        Spectrometer::parse("400,1\n401,2\n401,3\n")
+            .unwrap_err()
+            .to_string(),
+        "CSV data, line 3: duplicate wavelength 401"
+    );
+    assert_eq!(
+        Spectrometer::parse("400,1\nnan,2\n402,3\n")
+            .unwrap_err()
+            .to_string(),
+        "CSV data, line 2: invalid number NaN"
+    );
+}
@@ -0,0 +1,363 @@
+/*!
+IES TM-27-14 spectral data files.
+
+IES TM-27-14 defines an XML format for the exchange of the spectral distribution of a light source, or of a
+material, with a header describing the source, and the measurement. It is the standard format for lamp spectra in
+lighting software, and is read, and written, by most spectroradiometer applications.
+
+`Tm27::parse` reads a file, in a `Tm27` record, with its header fields, and the spectral data, which can be converted
+into a `DynamicSpectralDistribution`, or an `IlluminantData` collection. `Tm27::new` creates a record from a spectral
+distribution, to be written with `Tm27::to_xml`.
+
+Spectral data on a regular wavelength grid, with a whole number step size, is used as is; other data is linearly
+interpolated to a regular domain, with a step size of 1nm, as described in the `io` module.
+
+# Examples
+```
+use scot::illuminants::CieIllA;
+use scot::io::Tm27;
+use scot::SpectralDistribution;
+
+let xml = Tm27::new(&CieIllA, 0).to_xml();
+let a = Tm27::parse(&xml).unwrap().dynamic().unwrap();
+assert_eq!(a.spd().1.as_slice(), CieIllA.spd().1.as_slice());
+```
+*/
+
+use std::fmt;
+
+use nalgebra::DMatrix;
+
+use crate::illuminants::IlluminantData;
+use crate::{
+    Domain, DynamicSpectralDistribution, SpectralDistribution, SpectralQuantity, Unit,
+    WavelengthStep,
+};
+
+use super::regular_domain;
+use super::xml::{attribute, element, elements, escape, unescape};
+
+/// Error for TM-27 data which can not be read, or converted into a spectral distribution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tm27Error {
+    pub message: String,
+}
+
+impl fmt::Display for Tm27Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TM-27 data: {}", self.message)
+    }
+}
+
+impl std::error::Error for Tm27Error {}
+
+fn err(message: impl Into<String>) -> Tm27Error {
+    Tm27Error {
+        message: message.into(),
+    }
+}
+
+/// Header elements of a TM-27-14 file, all optional, and kept as text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tm27Header {
+    pub manufacturer: Option<String>,
+    pub catalog_number: Option<String>,
+    pub description: Option<String>,
+    pub document_creator: Option<String>,
+    pub unique_identifier: Option<String>,
+    pub measurement_equipment: Option<String>,
+    pub laboratory: Option<String>,
+    pub report_number: Option<String>,
+    pub report_date: Option<String>,
+    pub document_creation_date: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl Tm27Header {
+    /// The header fields, with their element names, in the order of the standard.
+    fn fields(&self) -> [(&'static str, &Option<String>); 11] {
+        [
+            ("Manufacturer", &self.manufacturer),
+            ("CatalogNumber", &self.catalog_number),
+            ("Description", &self.description),
+            ("DocumentCreator", &self.document_creator),
+            ("UniqueIdentifier", &self.unique_identifier),
+            ("MeasurementEquipment", &self.measurement_equipment),
+            ("Laboratory", &self.laboratory),
+            ("ReportNumber", &self.report_number),
+            ("ReportDate", &self.report_date),
+            ("DocumentCreationDate", &self.document_creation_date),
+            ("Comments", &self.comments),
+        ]
+    }
+
+    fn parse(xml: &str) -> Self {
+        let field = |name: &str| {
+            element(xml, name)
+                .map(|(_, content)| unescape(content.trim()))
+                .filter(|s| !s.is_empty())
+        };
+        Self {
+            manufacturer: field("Manufacturer"),
+            catalog_number: field("CatalogNumber"),
+            description: field("Description"),
+            document_creator: field("DocumentCreator"),
+            unique_identifier: field("UniqueIdentifier"),
+            measurement_equipment: field("MeasurementEquipment"),
+            laboratory: field("Laboratory"),
+            report_number: field("ReportNumber"),
+            report_date: field("ReportDate"),
+            document_creation_date: field("DocumentCreationDate"),
+            comments: field("Comments"),
+        }
+    }
+}
+
+/**
+A TM-27-14 spectral data record: a header, and a spectral distribution, with its wavelengths in nanometer.
+
+The spectral quantity is kept as text, as TM-27 has quantities, such as "reflectance", and "transmittance", which
+have no `SpectralQuantity` equivalent; these are relative values.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tm27 {
+    pub header: Tm27Header,
+    pub spectral_quantity: String,
+    pub bandwidth_fwhm: Option<f64>,
+    pub bandwidth_corrected: Option<bool>,
+    pub wavelengths: Vec<f64>,
+    pub values: Vec<f64>,
+}
+
+impl Tm27 {
+    /// A record for a spectral distribution in a collection, with its description, and quantity.
+    pub fn new<S>(sd: &S, column: usize) -> Self
+    where
+        S: SpectralDistribution<StepType = WavelengthStep> + ?Sized,
+    {
+        let (d, s) = sd.spd();
+        assert!(column < sd.shape().1, "spectral distribution not found");
+        let spectral_quantity = match sd.quantity() {
+            SpectralQuantity::Relative => "relative",
+            SpectralQuantity::Irradiance => "irradiance",
+            SpectralQuantity::Radiance => "radiance",
+            SpectralQuantity::Flux => "flux",
+            SpectralQuantity::Intensity => "intensity",
+            SpectralQuantity::PhotonIrradiance => "other",
+        };
+        let description = match (sd.keys(), sd.description()) {
+            (Some(keys), _) if keys.len() > column => Some(keys[column].clone()),
+            (_, description) => description,
+        };
+        Self {
+            header: Tm27Header {
+                description,
+                ..Default::default()
+            },
+            spectral_quantity: spectral_quantity.to_string(),
+            bandwidth_fwhm: None,
+            bandwidth_corrected: None,
+            wavelengths: d.iter().map(|l| (l.value() * 1E15).round() / 1E6).collect(),
+            values: (0..d.len()).map(|i| s[(i, column)]).collect(),
+        }
+    }
+
+    pub fn set_header(mut self, header: Tm27Header) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Reads a TM-27-14 XML document.
+    pub fn parse(xml: &str) -> Result<Self, Tm27Error> {
+        let (_, root) = element(xml, "IESTM2714").ok_or_else(|| err("no IESTM2714 element"))?;
+        let header = element(root, "Header")
+            .map(|(_, h)| Tm27Header::parse(h))
+            .unwrap_or_default();
+        let (_, sd) = element(root, "SpectralDistribution")
+            .ok_or_else(|| err("no SpectralDistribution element"))?;
+        let spectral_quantity = element(sd, "SpectralQuantity")
+            .map(|(_, q)| q.trim().to_string())
+            .ok_or_else(|| err("no SpectralQuantity element"))?;
+        let bandwidth_fwhm = match element(sd, "BandwidthFWHM") {
+            Some((_, v)) => Some(number(v)?),
+            None => None,
+        };
+        let bandwidth_corrected =
+            element(sd, "BandwidthCorrected").map(|(_, v)| v.trim().eq_ignore_ascii_case("true"));
+
+        let mut wavelengths = Vec::new();
+        let mut values = Vec::new();
+        for (attributes, content) in elements(sd, "SpectralData") {
+            let w = attribute(attributes, "wavelength")
+                .ok_or_else(|| err("SpectralData without wavelength"))?;
+            wavelengths.push(number(w)?);
+            values.push(number(content)?);
+        }
+        if wavelengths.len() < 2 {
+            return Err(err("at least 2 SpectralData elements expected"));
+        }
+        Ok(Self {
+            header,
+            spectral_quantity,
+            bandwidth_fwhm,
+            bandwidth_corrected,
+            wavelengths,
+            values,
+        })
+    }
+
+    /// TM-27-14 XML document of this record.
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<IESTM2714 xmlns=\"iestm2714\" version=\"1.0\">\n",
+        );
+        xml.push_str("\t<Header>\n");
+        for (name, value) in self.header.fields().iter() {
+            if let Some(v) = value {
+                xml.push_str(&format!("\t\t<{0}>{1}</{0}>\n", name, escape(v)));
+            }
+        }
+        xml.push_str("\t</Header>\n\t<SpectralDistribution>\n");
+        xml.push_str(&format!(
+            "\t\t<SpectralQuantity>{}</SpectralQuantity>\n",
+            escape(&self.spectral_quantity)
+        ));
+        if let Some(b) = self.bandwidth_fwhm {
+            xml.push_str(&format!("\t\t<BandwidthFWHM>{}</BandwidthFWHM>\n", b));
+        }
+        if let Some(b) = self.bandwidth_corrected {
+            xml.push_str(&format!(
+                "\t\t<BandwidthCorrected>{}</BandwidthCorrected>\n",
+                b
+            ));
+        }
+        for (w, v) in self.wavelengths.iter().zip(self.values.iter()) {
+            xml.push_str(&format!(
+                "\t\t<SpectralData wavelength=\"{}\">{:e}</SpectralData>\n",
+                w, v
+            ));
+        }
+        xml.push_str("\t</SpectralDistribution>\n</IESTM2714>\n");
+        xml
+    }
+
+    /// Spectral quantity of the values; quantities without a `SpectralQuantity` equivalent are relative.
+    pub fn quantity(&self) -> SpectralQuantity {
+        match self.spectral_quantity.to_ascii_lowercase().as_str() {
+            "irradiance" => SpectralQuantity::Irradiance,
+            "radiance" => SpectralQuantity::Radiance,
+            "flux" => SpectralQuantity::Flux,
+            "intensity" => SpectralQuantity::Intensity,
+            _ => SpectralQuantity::Relative,
+        }
+    }
+
+    /// The spectral values, with the header's description, and its catalog number as key.
+    pub fn dynamic(&self) -> Result<DynamicSpectralDistribution, Tm27Error> {
+        let (domain, data) = self.domain_data()?;
+        let mut sd = DynamicSpectralDistribution::new(domain, data).set_quantity(self.quantity());
+        if let Some(d) = &self.header.description {
+            sd = sd.set_description(d);
+        }
+        if let Some(c) = &self.header.catalog_number {
+            sd = sd.set_keys(&[c]);
+        }
+        Ok(sd)
+    }
+
+    /// The spectral values as an illuminant, with the header's description, and its catalog number as key.
+    pub fn illuminant(&self) -> Result<IlluminantData, Tm27Error> {
+        let (domain, data) = self.domain_data()?;
+        let mut ill = IlluminantData::new(domain, data);
+        if let Some(d) = &self.header.description {
+            ill = ill.set_description(d);
+        }
+        if let Some(c) = &self.header.catalog_number {
+            ill = ill.set_keys(&[c]);
+        }
+        Ok(ill)
+    }
+
+    fn domain_data(&self) -> Result<(Domain<WavelengthStep>, DMatrix<f64>), Tm27Error> {
+        if self.wavelengths.len() != self.values.len() {
+            return Err(err("number of wavelengths and values do not match"));
+        }
+        let n = self.wavelengths.len();
+        regular_domain(
+            &self.wavelengths,
+            DMatrix::from_column_slice(n, 1, &self.values),
+        )
+        .map_err(|e| err(e.to_string()))
+    }
+}
+
+fn number(s: &str) -> Result<f64, Tm27Error> {
+    s.trim()
+        .parse()
+        .map_err(|_| err(format!("invalid number \"{}\"", s.trim())))
+}
+
+#[test]
+fn test_tm27() {
+    use crate::illuminants::CieIllD65;
+    use approx::assert_abs_diff_eq;
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<tm:IESTM2714 xmlns:tm="iestm2714" version="1.0">
+  <tm:Header>
+    <tm:Manufacturer>Lamps &amp; Co</tm:Manufacturer>
+    <tm:CatalogNumber>WW-3000</tm:CatalogNumber>
+    <tm:Description>Warm white LED</tm:Description>
+    <tm:Laboratory/>
+  </tm:Header>
+  <tm:SpectralDistribution>
+    <tm:SpectralQuantity>irradiance</tm:SpectralQuantity>
+    <tm:BandwidthFWHM>2.5</tm:BandwidthFWHM>
+    <tm:BandwidthCorrected>true</tm:BandwidthCorrected>
+    <tm:SpectralData wavelength="380">0.01</tm:SpectralData>
+    <tm:SpectralData wavelength="385">0.02</tm:SpectralData>
+    <tm:SpectralData wavelength='390'>1.5E-2</tm:SpectralData>
+  </tm:SpectralDistribution>
+</tm:IESTM2714>"#;
+    let t = Tm27::parse(xml).unwrap();
+    assert_eq!(t.header.manufacturer.as_deref(), Some("Lamps & Co"));
+    assert_eq!(t.header.laboratory, None);
+    assert_eq!(t.bandwidth_fwhm, Some(2.5));
+    assert_eq!(t.bandwidth_corrected, Some(true));
+    assert_eq!(t.wavelengths, vec![380.0, 385.0, 390.0]);
+    let sd = t.dynamic().unwrap();
+    assert_eq!(sd.domain().len(), 3);
+    assert_eq!(sd.data()[(2, 0)], 0.015);
+    assert_eq!(sd.quantity(), SpectralQuantity::Irradiance);
+    assert_eq!(sd.keys().unwrap(), vec!["WW-3000"]);
+    let ill = t.illuminant().unwrap();
+    assert_eq!(ill.description().unwrap(), "Warm white LED");
+
+    // round trip, without loss
+    let t2 = Tm27::parse(&t.to_xml()).unwrap();
+    assert_eq!(t2, t);
+    let d65 = Tm27::parse(&Tm27::new(&CieIllD65, 0).to_xml()).unwrap();
+    let (d, v) = d65.dynamic().unwrap().spd();
+    assert_eq!(d, CieIllD65.spd().0);
+    assert_eq!(v.as_slice(), CieIllD65.spd().1.as_slice());
+
+    // irregular wavelengths, interpolated to 1nm steps
+    let mut t3 = t.clone();
+    t3.wavelengths = vec![380.5, 383.0, 390.0];
+    let sd = t3.dynamic().unwrap();
+    assert_eq!(sd.domain().len(), 10); // 381 to 390nm
+    assert_abs_diff_eq!(sd.data()[(0, 0)], 0.012, epsilon = 1E-12);
+
+    assert_eq!(
+        Tm27::parse("<IESTM2714></IESTM2714>")
+            .unwrap_err()
+            .to_string(),
+        "TM-27 data: no SpectralDistribution element"
+    );
+    assert_eq!(
+        Tm27::parse(&xml.replace("0.02", "0,02"))
+            .unwrap_err()
+            .message,
+        "invalid number \"0,02\""
+    );
+}
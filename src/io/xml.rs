@@ -0,0 +1,98 @@
+/*!
+Minimal XML reading, and escaping, for the XML based file formats.
+
+Only what these formats need is supported: elements are found by their name, without their namespace prefix, and
+elements with the same name can not be nested. Comments, and CDATA sections, are not recognized.
+*/
+
+/// Attributes, and content, of the first element with a name, ignoring namespace prefixes.
+pub(crate) fn element<'a>(xml: &'a str, name: &str) -> Option<(&'a str, &'a str)> {
+    elements(xml, name).into_iter().next()
+}
+
+/// Attributes, and content, of all the elements with a name, which can not be nested.
+pub(crate) fn elements<'a>(xml: &'a str, name: &str) -> Vec<(&'a str, &'a str)> {
+    let mut v = Vec::new();
+    let mut pos = 0;
+    while let Some(i) = xml[pos..].find('<').map(|i| pos + i) {
+        pos = i + 1;
+        let tag = local_name(&xml[pos..]);
+        if !tag.starts_with(name)
+            || !tag[name.len()..].starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        {
+            continue;
+        }
+        let start = xml.len() - tag.len() + name.len();
+        let end = match xml[start..].find('>') {
+            Some(e) => start + e,
+            None => break,
+        };
+        if xml[..end].ends_with('/') {
+            v.push((xml[start..end - 1].trim(), ""));
+            pos = end;
+            continue;
+        }
+        let content_end = match find_end_tag(&xml[end + 1..], name) {
+            Some(c) => end + 1 + c,
+            None => break,
+        };
+        v.push((xml[start..end].trim(), &xml[end + 1..content_end]));
+        pos = content_end;
+    }
+    v
+}
+
+/// Position of the end tag of an element, with, or without, a namespace prefix.
+fn find_end_tag(xml: &str, name: &str) -> Option<usize> {
+    let mut pos = 0;
+    while let Some(i) = xml[pos..].find("</").map(|i| pos + i) {
+        let tag = local_name(&xml[i + 2..]);
+        if tag.starts_with(name) && tag[name.len()..].trim_start().starts_with('>') {
+            return Some(i);
+        }
+        pos = i + 2;
+    }
+    None
+}
+
+/// Tag, starting with its element name, without a namespace prefix.
+fn local_name(tag: &str) -> &str {
+    match tag.find(|c: char| !c.is_alphanumeric()) {
+        Some(c) if tag[c..].starts_with(':') => &tag[c + 1..],
+        _ => tag,
+    }
+}
+
+/// Value of an attribute, in single, or double, quotes.
+pub(crate) fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attributes;
+    while let Some(i) = rest.find('=') {
+        let key = rest[..i].trim();
+        let value = rest[i + 1..].trim_start();
+        let q = value.chars().next()?;
+        if q != '"' && q != '\'' {
+            return None;
+        }
+        let e = value[1..].find(q)? + 1;
+        if key == name || key.ends_with(&format!(":{}", name)) {
+            return Some(&value[1..e]);
+        }
+        rest = &value[e + 1..];
+    }
+    None
+}
+
+pub(crate) fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub(crate) fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
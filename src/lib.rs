@@ -33,11 +33,36 @@ pub mod illuminants;
 #[macro_use]
 pub mod swatches;
 
+/// Transmission filters, and filtered spectral distributions
+pub mod filters;
+
 /**
 CIE Standard Observers
  */
 pub mod observers;
 
+/// Photometric quantities from absolutely scaled spectral distributions
+pub mod photometry;
+
+/// Photon flux metrics for horticultural lighting
+pub mod horticulture;
+
+/// Photobiological safety weighting functions of IEC 62471
+pub mod hazard;
+
+/// Savitzky–Golay, and Gaussian kernel, smoothing of noisy spectral data
+pub mod smoothing;
+
+/// Reading, and writing, of spectral data files, ICC profiles, and spectrometer exports
+pub mod io;
+
+/// Compact binary format for large embedded datasets
+pub mod blob;
+
+/// Chromaticity diagrams, as SVG images
+#[cfg(feature = "svg")]
+pub mod plot;
+
 pub mod domain;
 pub use self::domain::*;
 
@@ -58,3 +83,9 @@ pub use self::math::*;
 
 pub mod spectra;
 pub use self::spectra::*;
+
+pub mod dynamic;
+pub use self::dynamic::*;
+
+pub mod density;
+pub use self::density::*;
@@ -58,7 +58,7 @@ impl<V, I, C> CieCamJCh<V, I, C> {
             *j = x; *c = y; *h = z; // overwrite data
         }
         // move data into CieLab container after calculating lab values
-        CieLab::<I2, C> { data: xyz_to_lab(xyz_n.data.column(0), self.data), illuminant: PhantomData, cmf: PhantomData }
+        CieLab::<I2, C> { data: xyz_to_lab(xyz_n.data.column(0), self.data), keys: None, illuminant: PhantomData, cmf: PhantomData }
     }
 }
 
@@ -342,7 +342,7 @@ impl<I, C: StandardObserver> CieCamEnv<I, C> {
         [lightness, chroma, hue_angle, red_green, blue_yellow]
     }
 
-    pub(super) fn xyz_into_ucs_jab(&self, x:f64, y:f64, z:f64) -> [f64;3] {
+    pub fn xyz_into_ucs_jab(&self, x:f64, y:f64, z:f64) -> [f64;3] {
         let [lightness, chroma, hue_angle, ..] = self.xyz_into_jchab(x, y, z);
         let colorfulness = self.colorfulness(chroma);
         let (ap, bp) = self.ucs_ab_prime(colorfulness, hue_angle);
@@ -417,13 +417,17 @@ impl<I, C: StandardObserver> CieCamEnv<I, C> {
     0.0		0.8		0.525	0.8	Dark
 */
 
-impl<C, I, const LA: usize, const YB: usize, const SR1000: usize, const D100: isize>
-    From<ViewConditions<LA, YB, SR1000, D100>> for CieCamEnv<I, C>
-where
-    C: StandardObserver,
-    I: Illuminant + Default + Into<CieXYZ<C>>,
-{
-    fn from(_: ViewConditions<LA, YB, SR1000, D100>) -> Self {
+impl<I, C: StandardObserver> CieCamEnv<I, C> {
+    /**
+        Viewing Environment Parameters For A White Point Given By Its Tristimulus Values
+
+        Use this for white points which are only known at runtime, such as the test sources and reference
+        illuminants in color rendering calculations; the illuminant type `I` is only used as a label.
+    */
+    pub fn with_white<const LA: usize, const YB: usize, const SR1000: usize, const D100: isize>(
+        _: ViewConditions<LA, YB, SR1000, D100>,
+        xyz_w: [f64; 3],
+    ) -> Self {
         // Surround dependent parameters
         let s_r = SR1000 as f64 / 1000.0;
         let c = if s_r >= 0.15 {
@@ -453,13 +457,13 @@ where
         let y_b = YB as f64;
 
         // Further Illuminant and Viewing Environment derived parameters
-        let xyz_w: CieXYZ<C> = I::default().into()/* .normalize(100.0)*/;
-        let y_w = xyz_w.data[(1, 0)]; // = 100.0
+        let xyz_w = Matrix3x1::from(xyz_w);
+        let y_w = xyz_w.y; // = 100.0
         let n = y_b / y_w;
         let z = n.sqrt() + 1.48;
         let n_bb = 0.725 * n.powf(-0.2);
         let n_cb = n_bb;
-        let rgb_w = MCAT02 * &xyz_w.data;
+        let rgb_w = MCAT02 * xyz_w;
         let nom = Matrix3x1::from_element(d * y_w);
         let mut d_rgb = nom.component_div(&rgb_w);
         d_rgb.add_scalar_mut(1.0 - d);
@@ -476,6 +480,18 @@ where
     }
 }
 
+impl<C, I, const LA: usize, const YB: usize, const SR1000: usize, const D100: isize>
+    From<ViewConditions<LA, YB, SR1000, D100>> for CieCamEnv<I, C>
+where
+    C: StandardObserver,
+    I: Illuminant + Default + Into<CieXYZ<C>>,
+{
+    fn from(vc: ViewConditions<LA, YB, SR1000, D100>) -> Self {
+        let xyz_w: CieXYZ<C> = I::default().into()/* .normalize(100.0)*/;
+        Self::with_white(vc, [xyz_w.data[(0, 0)], xyz_w.data[(1, 0)], xyz_w.data[(2, 0)]])
+    }
+}
+
 pub type VcAvg = ViewConditions<318, 20, SR_AVG, D_AUTO>;
 pub type VcDim = ViewConditions<318, 20, SR_DIM, D_AUTO>;
 pub type VcDark = ViewConditions<318, 20, SR_DARK, D_AUTO>;
@@ -7,6 +7,7 @@ use crate::{DefaultObserver, illuminants::D65, models::xyz_to_lab, observers::{S
 use nalgebra::{Const, Dynamic, OMatrix};
 use std::{marker::PhantomData, };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CieCamUcs<V = VcAvg, I = D65, C = DefaultObserver> {
     pub data: OMatrix<f64, Const<3>, Dynamic>,
     v: PhantomData<*const V>,
@@ -54,7 +55,7 @@ impl<V, I, C> CieCamUcs<V, I, C> {
         }
         // move data into CieLab container after calculating lab values
         let xyz_n: CieXYZ<C> = I::default().into();
-        CieLab::<I, C>{ data: xyz_to_lab(xyz_n.data.column(0), self.data), cmf: PhantomData, illuminant: PhantomData }
+        CieLab::<I, C>{ data: xyz_to_lab(xyz_n.data.column(0), self.data), keys: None, cmf: PhantomData, illuminant: PhantomData }
     }
 }
 
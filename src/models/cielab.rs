@@ -6,14 +6,17 @@
 
 use std::marker::PhantomData;
 
-use crate::{illuminants::D65, observers::StandardObserver, DefaultObserver};
+use crate::spectra::key_index;
+use crate::{illuminants::D65, observers::StandardObserver, DefaultObserver, KeyError};
 use nalgebra::{Matrix3x1, Matrix3xX};
 
 use super::CieXYZ;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CieLab<I = D65, C = DefaultObserver> {
     pub data: Matrix3xX<f64>,
+    pub(crate) keys: Option<Vec<String>>,
     pub(crate) cmf: PhantomData<*const C>, // only used through C::Default(), but needed to mark the type
     pub(crate) illuminant: PhantomData<*const I>, // only used through I:Default(), but needed to mark the type
 }
@@ -22,11 +25,29 @@ impl<I, C> CieLab<I, C> {
     pub fn new(data: Vec<f64>) -> Self {
         Self {
             data: Matrix3xX::<f64>::from_vec(data),
+            keys: None,
             cmf: PhantomData,
             illuminant: PhantomData,
         }
     }
 
+    /// Sets keys for the columns of CIELAB values, typically the keys of the swatches they were calculated from.
+    pub fn set_keys(mut self, keys: &[&str]) -> Self {
+        assert!(keys.len() == self.len(), "number of keys does not match the number of values");
+        self.keys = Some(keys.iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    pub fn keys(&self) -> Option<Vec<String>> {
+        self.keys.clone()
+    }
+
+    /// CIELAB values with a key.
+    pub fn get_by_key(&self, key: &str) -> Result<[f64; 3], KeyError> {
+        let v = self.data.column(key_index(self.keys(), key)?);
+        Ok([v.x, v.y, v.z])
+    }
+
     pub fn len(&self) -> usize {
         self.data.ncols()
     }
@@ -72,7 +93,7 @@ where
 {
     fn from(v: [f64;N]) -> Self {
         let data = Matrix3xX::<f64>::from_vec(v.to_vec());
-        Self {data, cmf: PhantomData, illuminant: PhantomData}
+        Self {data, keys: None, cmf: PhantomData, illuminant: PhantomData}
     }
 }
 
@@ -93,7 +114,7 @@ where
 {
     fn from(lab: CieLab<I, C>) -> Self {
         let xyz_n: CieXYZ<C> = I::default().into();
-        Self::new(lab_to_xyz(xyz_n.data.column(0), lab.data))
+        Self::new(lab_to_xyz(xyz_n.data.column(0), lab.data)).with_keys(lab.keys)
     }
 }
 
@@ -168,6 +189,7 @@ impl<'a, C, I> Iterator for LabIterRef<'a, I, C> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LabValues {
     pub l: f64,
     pub a: f64,
@@ -16,4 +16,7 @@ pub mod ciecam02;
 pub use crate::models::ciecam02::*;
 
 pub mod uvw;
-pub use crate::models::uvw::*;
\ No newline at end of file
+pub use crate::models::uvw::*;
+
+pub mod rgb;
+pub use crate::models::rgb::*;
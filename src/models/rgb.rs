@@ -0,0 +1,239 @@
+/*!
+RGB color spaces, defined by the chromaticities of their primaries, and of their white point, and by their tone
+response curves.
+
+An `RgbSpace` converts linear, or encoded, RGB values, with values from 0.0 to 1.0, into CIE XYZ tristimulus values,
+with a luminance value Y of 1.0 for the white point, and back. Its tone response curves, one for each channel,
+convert encoded values into linear values, and are power functions, parametric curves, as used by sRGB, or tables.
+*/
+
+use nalgebra::{matrix, Matrix3, SMatrix, Vector3};
+
+/**
+    Bradford cone response matrix, used for chromatic adaptation, as in ICC profiles.
+*/
+pub const MBRADFORD: SMatrix<f64, 3, 3> = matrix![
+     0.8951,  0.2664, -0.1614;
+    -0.7502,  1.7135,  0.0367;
+     0.0389, -0.0685,  1.0296;
+];
+
+/// Tristimulus values of CIE illuminant D50, as used as ICC profile connection space illuminant, with Y = 1.0.
+pub const ICC_D50: [f64; 3] = [0.9642, 1.0, 0.8249];
+
+/// Bradford chromatic adaptation matrix, from XYZ values for a source white, to XYZ values for a destination white.
+pub fn bradford(from: [f64; 3], to: [f64; 3]) -> Matrix3<f64> {
+    let s = MBRADFORD * Vector3::from(from);
+    let d = MBRADFORD * Vector3::from(to);
+    let scale = Matrix3::from_diagonal(&d.component_div(&s));
+    MBRADFORD.try_inverse().unwrap() * scale * MBRADFORD
+}
+
+/// A tone response curve, converting an encoded value, from 0.0 to 1.0, into a linear value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ToneCurve {
+    /// A power function, with an exponent.
+    Gamma(f64),
+    /**
+    A power function with a linear segment, as ICC parametric curve type 3, with parameters `[g, a, b, c, d]`:
+    y = (a·x + b)<sup>g</sup> for x ≥ d, and y = c·x for x < d.
+    */
+    Parametric([f64; 5]),
+    /// Linear values at equidistant encoded values, from 0.0 to 1.0, with linear interpolation.
+    Table(Vec<f64>),
+}
+
+impl ToneCurve {
+    /// The sRGB tone response curve.
+    pub fn srgb() -> Self {
+        ToneCurve::Parametric([2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045])
+    }
+
+    /// Linear value for an encoded value.
+    pub fn decode(&self, v: f64) -> f64 {
+        let v = v.clamp(0.0, 1.0);
+        match self {
+            ToneCurve::Gamma(g) => v.powf(*g),
+            ToneCurve::Parametric([g, a, b, c, d]) => {
+                if v >= *d {
+                    (a * v + b).powf(*g)
+                } else {
+                    c * v
+                }
+            }
+            ToneCurve::Table(t) => match t.len() {
+                0 => v,
+                1 => t[0],
+                n => {
+                    let x = v * (n - 1) as f64;
+                    let i = (x.floor() as usize).min(n - 2);
+                    t[i] + (x - i as f64) * (t[i + 1] - t[i])
+                }
+            },
+        }
+    }
+
+    /// Encoded value for a linear value, for increasing tone response curves.
+    pub fn encode(&self, v: f64) -> f64 {
+        let v = v.clamp(0.0, 1.0);
+        match self {
+            ToneCurve::Gamma(g) => v.powf(1.0 / g),
+            ToneCurve::Parametric([g, a, b, c, d]) => {
+                if v >= (a * d + b).powf(*g) {
+                    (v.powf(1.0 / g) - b) / a
+                } else {
+                    v / c
+                }
+            }
+            ToneCurve::Table(_) => {
+                // bisection
+                let (mut lo, mut hi) = (0.0, 1.0);
+                for _ in 0..50 {
+                    let mid = 0.5 * (lo + hi);
+                    if self.decode(mid) < v {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                0.5 * (lo + hi)
+            }
+        }
+    }
+}
+
+/**
+An RGB color space, with the chromaticity coordinates of its red, green, and blue primaries, and of its white point,
+and a tone response curve for each of its channels.
+
+# Examples
+```
+use scot::models::RgbSpace;
+
+let srgb = RgbSpace::srgb();
+let [x, y, z] = srgb.xyz([1.0, 1.0, 1.0]);
+assert!((x - 0.9505).abs() < 1E-4 && (y - 1.0).abs() < 1E-12 && (z - 1.089).abs() < 1E-3);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RgbSpace {
+    /// Chromaticity coordinates x, and y, of the red, green, and blue primaries.
+    pub primaries: [[f64; 2]; 3],
+    /// Chromaticity coordinates x, and y, of the white point.
+    pub white: [f64; 2],
+    /// Tone response curves of the red, green, and blue channels.
+    pub trc: [ToneCurve; 3],
+}
+
+impl RgbSpace {
+    /// An RGB space, with the same power function as tone response curve for all channels.
+    pub fn new(primaries: [[f64; 2]; 3], white: [f64; 2], gamma: f64) -> Self {
+        Self {
+            primaries,
+            white,
+            trc: [
+                ToneCurve::Gamma(gamma),
+                ToneCurve::Gamma(gamma),
+                ToneCurve::Gamma(gamma),
+            ],
+        }
+    }
+
+    /// The sRGB color space, of IEC 61966-2-1.
+    pub fn srgb() -> Self {
+        Self::new(
+            [[0.64, 0.33], [0.30, 0.60], [0.15, 0.06]],
+            [0.3127, 0.3290],
+            2.2,
+        )
+        .set_trc(ToneCurve::srgb())
+    }
+
+    /// The Adobe RGB (1998) color space.
+    pub fn adobe_rgb() -> Self {
+        Self::new(
+            [[0.64, 0.33], [0.21, 0.71], [0.15, 0.06]],
+            [0.3127, 0.3290],
+            563.0 / 256.0,
+        )
+    }
+
+    /// The Display P3 color space, with DCI-P3 primaries, a D65 white point, and the sRGB tone response curve.
+    pub fn display_p3() -> Self {
+        Self::new(
+            [[0.680, 0.320], [0.265, 0.690], [0.150, 0.060]],
+            [0.3127, 0.3290],
+            2.2,
+        )
+        .set_trc(ToneCurve::srgb())
+    }
+
+    /// Sets the same tone response curve for all channels.
+    pub fn set_trc(mut self, trc: ToneCurve) -> Self {
+        self.trc = [trc.clone(), trc.clone(), trc];
+        self
+    }
+
+    /// XYZ values of the white point, with Y = 1.0.
+    pub fn white_xyz(&self) -> [f64; 3] {
+        let [x, y] = self.white;
+        [x / y, 1.0, (1.0 - x - y) / y]
+    }
+
+    /// Matrix converting linear RGB values into XYZ values, with Y = 1.0 for the white point.
+    pub fn rgb_to_xyz_matrix(&self) -> Matrix3<f64> {
+        let p = Matrix3::from_fn(|i, j| {
+            let [x, y] = self.primaries[j];
+            [x / y, 1.0, (1.0 - x - y) / y][i]
+        });
+        let s = p.try_inverse().expect("primaries should not be on a line")
+            * Vector3::from(self.white_xyz());
+        p * Matrix3::from_diagonal(&s)
+    }
+
+    /// Matrix converting XYZ values, with Y = 1.0 for the white point, into linear RGB values.
+    pub fn xyz_to_rgb_matrix(&self) -> Matrix3<f64> {
+        self.rgb_to_xyz_matrix().try_inverse().unwrap()
+    }
+
+    /// XYZ values, with Y = 1.0 for the white point, of encoded RGB values.
+    pub fn xyz(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let lin = Vector3::from_fn(|i, _| self.trc[i].decode(rgb[i]));
+        (self.rgb_to_xyz_matrix() * lin).into()
+    }
+
+    /// Encoded RGB values, clamped to the range from 0.0 to 1.0, of XYZ values, with Y = 1.0 for the white point.
+    pub fn rgb(&self, xyz: [f64; 3]) -> [f64; 3] {
+        let lin = self.xyz_to_rgb_matrix() * Vector3::from(xyz);
+        [0, 1, 2].map(|i| self.trc[i].encode(lin[i]))
+    }
+}
+
+#[test]
+fn test_rgb_space() {
+    use approx::assert_abs_diff_eq;
+
+    // IEC 61966-2-1 matrix
+    let m = RgbSpace::srgb().rgb_to_xyz_matrix();
+    assert_abs_diff_eq!(m[(0, 0)], 0.4124, epsilon = 1E-4);
+    assert_abs_diff_eq!(m[(1, 1)], 0.7152, epsilon = 1E-4);
+    assert_abs_diff_eq!(m[(2, 2)], 0.9505, epsilon = 1E-4);
+
+    let srgb = RgbSpace::srgb();
+    assert_abs_diff_eq!(srgb.trc[0].decode(0.5), 0.2140, epsilon = 1E-4);
+    assert_abs_diff_eq!(srgb.trc[0].decode(0.02), 0.02 / 12.92, epsilon = 1E-12);
+    let rgb = srgb.rgb(srgb.xyz([0.2, 0.5, 0.8]));
+    assert_abs_diff_eq!(rgb[0], 0.2, epsilon = 1E-9);
+    assert_abs_diff_eq!(rgb[2], 0.8, epsilon = 1E-9);
+
+    let table = ToneCurve::Table((0..=255).map(|i| (i as f64 / 255.0).powf(2.2)).collect());
+    assert_abs_diff_eq!(table.decode(0.5), 0.5f64.powf(2.2), epsilon = 1E-4);
+    assert_abs_diff_eq!(table.encode(table.decode(0.3)), 0.3, epsilon = 1E-9);
+
+    // D65 to D50 Bradford adaptation, as in the sRGB profile of the ICC
+    let a = bradford(srgb.white_xyz(), ICC_D50);
+    assert_abs_diff_eq!(a[(0, 0)], 1.0479, epsilon = 1E-4);
+    assert_abs_diff_eq!(a[(2, 2)], 0.7517, epsilon = 1E-4);
+}
@@ -1,6 +1,7 @@
 use std::{fmt::Display, marker::PhantomData};
 
-use crate::{observers::StandardObserver, DefaultObserver};
+use crate::spectra::key_index;
+use crate::{observers::StandardObserver, DefaultObserver, KeyError};
 use nalgebra::{Const, DVector, DefaultAllocator, Dim, Matrix3xX, OMatrix};
 
 /**
@@ -21,9 +22,11 @@ use nalgebra::{Const, DVector, DefaultAllocator, Dim, Matrix3xX, OMatrix};
 
 */
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CieXYZ<C: StandardObserver = DefaultObserver> {
     pub data: Matrix3xX<f64>,  // use OMatrix here? 
     pub y: Option<DVector<f64>>,
+    keys: Option<Vec<String>>,
     cmf: PhantomData<*const C>, // only used through C::Default(), but needed to mark the type
 }
 
@@ -32,10 +35,33 @@ impl<C: StandardObserver> CieXYZ<C> {
         Self {
             data: xyz,
             y: None,
+            keys: None,
             cmf: PhantomData,
         }
     }
 
+    /// Sets keys for the columns of tristimulus values, typically the keys of the spectral distributions they were
+    /// calculated from.
+    pub fn set_keys(self, keys: &[&str]) -> Self {
+        assert!(keys.len() == self.len(), "number of keys does not match the number of values");
+        self.with_keys(Some(keys.iter().map(|s| s.to_string()).collect()))
+    }
+
+    pub(crate) fn with_keys(mut self, keys: Option<Vec<String>>) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    pub fn keys(&self) -> Option<Vec<String>> {
+        self.keys.clone()
+    }
+
+    /// Tristimulus values with a key.
+    pub fn get_by_key(&self, key: &str) -> Result<[f64; 3], KeyError> {
+        let v = self.data.column(key_index(self.keys(), key)?);
+        Ok([v.x, v.y, v.z])
+    }
+
     pub fn len(&self) -> usize {
         self.data.ncols()
     }
@@ -75,11 +101,7 @@ where
 {
     fn from(xyz: OMatrix<f64, Const<3>, C>) -> Self {
         let data = Matrix3xX::from_iterator(xyz.ncols(), xyz.into_iter().cloned());
-        Self {
-            data,
-            y: None,
-            cmf: PhantomData,
-        }
+        Self::new(data)
     }
 }
 
@@ -144,6 +166,7 @@ impl<C: StandardObserver> Iterator for XYZIter<C> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XYZValues {
     pub x: f64,
     pub y: f64,
@@ -161,8 +184,10 @@ impl<C: StandardObserver> IntoIterator for CieXYZ<C> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CieYxy<C: StandardObserver = DefaultObserver> {
     pub data: Matrix3xX<f64>,
+    keys: Option<Vec<String>>,
     cmf: PhantomData<*const C>, // only used through C::Default(), but needed to mark the type
 }
 
@@ -170,6 +195,7 @@ impl<C: StandardObserver> CieYxy<C> {
     pub fn new(yxy: Matrix3xX<f64>) -> Self {
         Self {
             data: yxy,
+            keys: None,
             cmf: PhantomData,
         }
     }
@@ -178,6 +204,15 @@ impl<C: StandardObserver> CieYxy<C> {
         let v = self.data.column(i);
         [v.x, v.y, v.z]
     }
+
+    pub fn keys(&self) -> Option<Vec<String>> {
+        self.keys.clone()
+    }
+
+    /// Luminance, and chromaticity coordinates, with a key.
+    pub fn get_by_key(&self, key: &str) -> Result<[f64; 3], KeyError> {
+        Ok(self.yxy(key_index(self.keys(), key)?))
+    }
 }
 
 impl<C, X> From<X> for CieYxy<C>
@@ -195,7 +230,9 @@ where
             v.push(xyz.x / s);
             v.push(xyz.y / s);
         }
-        Self::new(Matrix3xX::<f64>::from_vec(v))
+        let mut yxy = Self::new(Matrix3xX::<f64>::from_vec(v));
+        yxy.keys = m.keys;
+        yxy
     }
 }
 
@@ -226,6 +263,7 @@ impl<C: StandardObserver> Iterator for YxyIter<C> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct YxyValues {
     pub l: f64,
     pub x: f64,
@@ -1,7 +1,7 @@
 use std::{fmt::Display, marker::PhantomData};
 
 use crate::{
-    illuminants::{CctDuv, CctDuvValue, Planckian},
+    illuminants::{CctDuv, Planckian},
     observers::StandardObserver,
     DefaultObserver,
 };
@@ -59,8 +59,18 @@ where
     }
 }
 
-#[allow(dead_code)]
-pub(crate) fn uv_from_cct_duv<C: StandardObserver>(cct: f64, duv: f64) -> (f64, f64) {
+/**
+CIE 1960 u and v chromaticity coordinates of a correlated color temperature `cct`, in Kelvin, and a distance `duv`
+to the Planckian locus, positive above, and negative below the locus.
+```
+use scot::models::uv_from_cct_duv;
+use scot::observers::CieObs1931;
+
+let (u, v) = uv_from_cct_duv::<CieObs1931>(6500.0, 0.003);
+println!("{:.5} {:.5}", u, v);
+```
+*/
+pub fn uv_from_cct_duv<C: StandardObserver>(cct: f64, duv: f64) -> (f64, f64) {
     let CieYuv1960Values { y: _, u: u0, v: v0 } = CieYuv1960::<C>::from(Planckian::new(cct))
         .into_iter()
         .next()
@@ -80,14 +90,7 @@ where
     C: StandardObserver,
 {
     fn from(tds: CctDuv<C>) -> Self {
-        let mut mv: Vec<f64> = Vec::with_capacity(3 * tds.len());
-        for CctDuvValue { t, d } in tds {
-            let (u, v) = uv_from_cct_duv::<C>(t, d);
-            mv.push(1.0); // y
-            mv.push(u);
-            mv.push(v);
-        }
-        Self::new(Matrix3xX::<f64>::from_vec(mv))
+        tds.uv()
     }
 }
 
@@ -0,0 +1,144 @@
+/*!
+&alpha;-opic metrics, for the effects of light on the human circadian and neurophysiological systems, as defined in
+CIE S 026/E:2018.
+
+CIE S 026 defines five action spectra, one for each of the photoreceptors in the human retina: the S-, M-, and L-cones,
+the rods, and the intrinsically photosensitive retinal ganglion cells, containing the photopigment melanopsin.
+The first three are the CIE 2006 10º cone fundamentals, and the rhodopic action spectrum is the scotopic
+luminous efficiency function V&prime;(&lambda;), and these four are included in this library.
+
+The melanopic action spectrum is tabulated in CIE S 026, and is not derived from the other functions in this library.
+It is not included: approximations of it, by a visual pigment template, filtered by the ocular media, do not
+reproduce the melanopic efficacies of CIE S 026 to four significant digits.
+*/
+
+use nalgebra::{Const, Dynamic, Matrix3, Matrix3xX, MatrixSlice, RowDVector};
+use crate::illuminants::CieIllD65;
+use crate::interp_lin_cmf;
+use crate::observers::{photopic_luminous, CieScotopic1951, StandardObserver};
+use crate::{Domain, SpectralDistribution, Meter, Step, Unit, NM};
+use super::cie2006::LMS_TO_XYZ_F10;
+use super::cie_f10::{CIE_OBS_F10, N};
+
+/// The &alpha;-opic photoreceptor types of CIE S 026, except for the melanopic type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphaOpic {
+	SCone,
+	MCone,
+	LCone,
+	Rhodopic,
+}
+
+pub const ALPHA_OPIC: [AlphaOpic;4] = [AlphaOpic::SCone, AlphaOpic::MCone, AlphaOpic::LCone, AlphaOpic::Rhodopic];
+
+impl AlphaOpic {
+
+	pub fn name(&self) -> &'static str {
+		match self {
+			AlphaOpic::SCone => "S-cone-opic",
+			AlphaOpic::MCone => "M-cone-opic",
+			AlphaOpic::LCone => "L-cone-opic",
+			AlphaOpic::Rhodopic => "rhodopic",
+		}
+	}
+
+	/// Action spectrum, on a target domain, normalized to a peak value of 1.0, as a row vector.
+	pub fn values<L>(&self, target: &Domain<L>) -> RowDVector<f64>
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	{
+		match self {
+			AlphaOpic::Rhodopic => CieScotopic1951::values(target),
+			cone => {
+				let i = match cone { AlphaOpic::SCone => 2, AlphaOpic::MCone => 1, _ => 0 };
+				let lms = cone_fundamentals_10();
+				let v = interp_lin_cmf(&Domain::new(390, 830, NM), target, 3, lms.columns(0, N));
+				v.row(i).into_owned()
+			}
+		}
+	}
+
+	/// &alpha;-opic irradiances, in W/m<sup>2</sup>, for a collection of spectral irradiance distributions, in
+	/// W/m<sup>2</sup>/m.
+	pub fn irradiance<S>(&self, sd: &S) -> RowDVector<f64>
+	where
+		S: SpectralDistribution,
+		Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+	{
+		let (d, s) = sd.spd();
+		let (n, m) = sd.shape();
+		let v = self.values(&d);
+		let dl = Meter::from(d.step.unitvalue(1)).value();
+		RowDVector::from_fn(m, |_, j| (0..n).map(|i| v[i] * s[(i, j)]).sum::<f64>() * dl)
+	}
+
+	/// &alpha;-opic efficacy of luminous radiation (ELR), in W/lm, using the y&#772;(&lambda;) function of observer
+	/// `C` as photopic luminous efficiency function.
+	pub fn elr<C, S>(&self, sd: &S) -> RowDVector<f64>
+	where
+		C: StandardObserver,
+		S: SpectralDistribution,
+		Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+	{
+		self.irradiance(sd).component_div(&photopic_luminous::<C, S>(sd))
+	}
+
+	/// &alpha;-opic daylight (D65) efficacy ratio (DER), the ratio of the &alpha;-opic ELR of a light source, and
+	/// of CIE standard illuminant D65.
+	pub fn der<C, S>(&self, sd: &S) -> RowDVector<f64>
+	where
+		C: StandardObserver,
+		S: SpectralDistribution,
+		Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+	{
+		self.elr::<C, S>(sd) / self.elr_d65::<C>()
+	}
+
+	/// &alpha;-opic equivalent daylight (D65) illuminance (EDI), in lux: the illuminance of D65 producing the same
+	/// &alpha;-opic irradiance as a light source, with spectral irradiance data in W/m<sup>2</sup>/m.
+	pub fn edi<C, S>(&self, sd: &S) -> RowDVector<f64>
+	where
+		C: StandardObserver,
+		S: SpectralDistribution,
+		Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+	{
+		self.irradiance(sd) / self.elr_d65::<C>()
+	}
+
+	fn elr_d65<C: StandardObserver>(&self) -> f64 {
+		self.elr::<C, _>(&CieIllD65)[0]
+	}
+}
+
+/// CIE 2006 10º energy based cone fundamentals, obtained from the CIE 2015 10º color matching functions.
+fn cone_fundamentals_10() -> Matrix3xX<f64> {
+	let xyz = MatrixSlice::<f64, Const<3>, Dynamic>::from_slice_generic(&CIE_OBS_F10, Const::<3>, Dynamic::new(N));
+	let m = Matrix3::from_row_slice(&LMS_TO_XYZ_F10).try_inverse().unwrap();
+	let mut lms = m * xyz;
+	for mut row in lms.row_iter_mut() {
+		let max = row.max();
+		row /= max;
+	}
+	lms
+}
+
+#[test]
+fn test_alpha_opic() {
+	use approx::assert_abs_diff_eq;
+	use crate::illuminants::CieIllD65Iso;
+	use crate::observers::CieObs1931;
+
+	// CIE S 026, Table 2: α-opic ELR values for D65, in mW/lm, to four significant digits
+	let elr: Vec<f64> = ALPHA_OPIC.iter().map(|a| a.elr::<CieObs1931, _>(&CieIllD65Iso)[0] * 1000.0).collect();
+	assert_abs_diff_eq!(elr[0], 0.8173, epsilon = 2E-4);
+	assert_abs_diff_eq!(elr[1], 1.4558, epsilon = 2E-4);
+	assert_abs_diff_eq!(elr[2], 1.6289, epsilon = 2E-4);
+	assert_abs_diff_eq!(elr[3], 1.4497, epsilon = 2E-4);
+
+	for a in ALPHA_OPIC.iter() {
+		assert_abs_diff_eq!(a.der::<CieObs1931, _>(&CieIllD65)[0], 1.0, epsilon = 1E-12);
+		let v = a.values(&Domain::new(380, 780, NM));
+		assert!(v.max() > 0.99 && v.max() <= 1.0 + 1E-9);
+	}
+}
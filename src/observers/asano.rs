@@ -0,0 +1,115 @@
+/*!
+Individual colorimetric observers, according to the model by Asano, Fairchild, and Blondé.
+
+Color matching functions of individual observers differ from those of the average CIE 2006 observer, as their
+physiological parameters differ: the optical densities of their lens, macular pigment, and cone photopigments, and the
+wavelengths at which their photopigments absorb most.  Asano et al. estimated the inter-observer variability of these
+eight parameters, which allows generating populations of observers, for example to study observer metamerism of
+displays in Monte-Carlo simulations.
+
+Reference: Y. Asano, M. D. Fairchild, and L. Blondé, "Individual Colorimetric Observer Model," PLoS ONE 11(2), 2016.
+*/
+
+use nalgebra::Matrix3xX;
+use crate::{Unit, Step};
+use super::{Cie2006Error, Cie2006Observer, Cie2006Templates};
+
+/**
+Deviations of the physiological parameters of an individual observer from the CIE 2006 average observer.
+
+- `lens`, and `macula`: deviations of the lens and macular pigment optical densities, in percent,
+- `optical_density`: deviations of the peak optical densities of the L-, M-, and S-cone photopigments, in percent,
+- `peak_shift`: shifts of the L-, M-, and S-cone photopigment absorbance spectra, in nanometer.
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AsanoDeviations {
+	pub lens: f64,
+	pub macula: f64,
+	pub optical_density: [f64;3],
+	pub peak_shift: [f64;3],
+}
+
+/// Standard deviations of the physiological parameters in the Asano model.
+pub const ASANO_STD_DEV: AsanoDeviations = AsanoDeviations {
+	lens: 18.7,
+	macula: 36.5,
+	optical_density: [9.0, 9.0, 7.4],
+	peak_shift: [2.0, 1.5, 1.3],
+};
+
+impl AsanoDeviations {
+
+	/// Parameter deviations from eight independent standard normal variates, in the order lens, macula, L-, M-, and
+	/// S-cone optical densities, and L-, M-, and S-cone peak shifts.
+	pub fn from_standard_normal(z: [f64;8]) -> Self {
+		let s = ASANO_STD_DEV;
+		Self {
+			lens: z[0] * s.lens,
+			macula: z[1] * s.macula,
+			optical_density: [z[2] * s.optical_density[0], z[3] * s.optical_density[1], z[4] * s.optical_density[2]],
+			peak_shift: [z[5] * s.peak_shift[0], z[6] * s.peak_shift[1], z[7] * s.peak_shift[2]],
+		}
+	}
+
+	/// Photopigment absorbance templates, shifted along the wavelength axis by `peak_shift`, using linear
+	/// interpolation, and zero outside the template domain.
+	pub(super) fn shifted_absorbance(&self, templates: &Cie2006Templates) -> Matrix3xX<f64> {
+		let a = &templates.absorbance;
+		let step_nm = templates.domain.step.unitvalue(1).value() * 1E9;
+		let n = a.ncols();
+		Matrix3xX::from_fn(n, |r, j| {
+			let x = j as f64 - self.peak_shift[r] / step_nm;
+			if x < 0.0 || x > (n - 1) as f64 {
+				0.0
+			} else {
+				let i = (x.floor() as usize).min(n - 2);
+				let h = x - i as f64;
+				a[(r, i)] * (1.0 - h) + a[(r, i + 1)] * h
+			}
+		})
+	}
+}
+
+/**
+Generates a population of `n` individual observers of the same age and field size.
+
+The parameter deviations are drawn from `normal`, which should produce independent samples from a standard normal
+distribution, for example using the `rand_distr` crate's `StandardNormal` distribution.
+Returns an error if the age or field size are out of the range of the CIE 2006 model.
+*/
+pub fn asano_population(templates: &Cie2006Templates, age: f64, field_size: f64, n: usize, mut normal: impl FnMut() -> f64) -> Result<Vec<Cie2006Observer>, Cie2006Error> {
+	(0..n)
+		.map(|_| {
+			let mut z = [0.0; 8];
+			z.iter_mut().for_each(|v| *v = normal());
+			Cie2006Observer::individual(templates, age, field_size, &AsanoDeviations::from_standard_normal(z))
+		})
+		.collect()
+}
+
+#[test]
+fn test_asano() {
+	use approx::assert_abs_diff_eq;
+	use crate::{Domain, NM};
+
+	// Gaussian shaped absorbance templates, peaking at 560, 530, and 420nm
+	let d = Domain::new(380, 780, NM);
+	let n = d.len();
+	let g = |l: usize, c: f64| (-((380 + l) as f64 - c).powi(2) / 2000.0).exp();
+	let a = Matrix3xX::from_fn(n, |r, j| g(j, [560.0, 530.0, 420.0][r]));
+	let t = Cie2006Templates::new(d, a, vec![0.0; n], vec![0.1; n], vec![0.0; n]).unwrap();
+
+	let avg = Cie2006Observer::new(&t, 32.0, 2.0).unwrap();
+	let ind = Cie2006Observer::individual(&t, 32.0, 2.0, &AsanoDeviations::default()).unwrap();
+	assert_abs_diff_eq!(avg.cmf(), ind.cmf());
+
+	let shifted = AsanoDeviations { peak_shift: [5.0, 0.0, -3.0], ..Default::default() }.shifted_absorbance(&t);
+	assert_abs_diff_eq!(shifted[(0, 565 - 380)], 1.0, epsilon = 1E-12);
+	assert_abs_diff_eq!(shifted[(1, 530 - 380)], 1.0, epsilon = 1E-12);
+	assert_abs_diff_eq!(shifted[(2, 417 - 380)], 1.0, epsilon = 1E-12);
+
+	let mut z = [1.0, -1.0].iter().cycle();
+	let pop = asano_population(&t, 32.0, 2.0, 10, || *z.next().unwrap()).unwrap();
+	assert_eq!(pop.len(), 10);
+	assert!(pop.iter().all(|o| o.cmf() != avg.cmf()));
+}
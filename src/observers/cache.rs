@@ -0,0 +1,68 @@
+/*!
+Process wide cache of color matching functions, interpolated to spectral domains.
+
+Calculating tristimulus values requires the color matching functions of an observer to be interpolated to the domain of
+the spectral data. In loops, such as in the calculation of the tristimulus values of Planckian radiators in the CCT
+algorithms, this is done many times for the same domain: the cache keeps the interpolated matrices, keyed by observer
+and domain, and shares them between threads.
+*/
+
+use std::any::type_name;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use nalgebra::Matrix3xX;
+
+use crate::observers::StandardObserver;
+use crate::{Domain, Meter, Step, Unit};
+
+type CacheKey = (&'static str, &'static str, i32, i32, u64);
+
+static CMF_CACHE: OnceLock<Mutex<HashMap<CacheKey, Arc<Matrix3xX<f64>>>>> = OnceLock::new();
+
+/// Color matching functions of observer `C` on a `target` domain, interpolated only on first use.
+pub fn cached_values<C, L>(target: &Domain<L>) -> Arc<Matrix3xX<f64>>
+where
+	C: StandardObserver,
+	L: Step,
+	Meter: From<<L>::UnitValueType>
+{
+	let key = (
+		type_name::<C>(),
+		type_name::<L>(),
+		target.range.start,
+		target.range.end,
+		target.step.unitvalue(1).value().to_bits(),
+	);
+	let cache = CMF_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+	if let Some(m) = cache.lock().unwrap().get(&key) {
+		return m.clone();
+	}
+	// interpolate without holding the lock; a concurrent miss just computes the same matrix twice
+	let m = Arc::new(C::values(target));
+	cache.lock().unwrap().entry(key).or_insert(m).clone()
+}
+
+/// Removes all cached color matching functions.
+pub fn clear_cmf_cache() {
+	if let Some(cache) = CMF_CACHE.get() {
+		cache.lock().unwrap().clear();
+	}
+}
+
+#[test]
+fn test_cmf_cache() {
+	use crate::observers::{CieObs1931, CieObs1964};
+	use crate::{NM, NM5};
+
+	let d = Domain::new(380/5, 780/5, NM5);
+	let a = cached_values::<CieObs1931, _>(&d);
+	let b = cached_values::<CieObs1931, _>(&d);
+	assert!(Arc::ptr_eq(&a, &b));
+	assert_eq!(*a, CieObs1931::values(&d));
+
+	let c = cached_values::<CieObs1964, _>(&d);
+	assert!(!Arc::ptr_eq(&a, &c));
+	let e = cached_values::<CieObs1931, _>(&Domain::new(380, 780, NM));
+	assert_eq!(e.ncols(), 401);
+}
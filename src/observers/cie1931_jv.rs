@@ -0,0 +1,90 @@
+use nalgebra::{Const, Dynamic, Matrix3xX, MatrixSlice, MatrixSlice3xX};
+use crate::interp_lin_cmf2;
+use crate::{observers::StandardObserver};
+use crate::{Domain};
+use crate::{NM5, WavelengthStep, Meter, Step};
+
+
+
+/**
+CIE 1931 2º color matching functions, with the Judd (1951) and Vos (1978) corrections, defined from 380 to 825nm,
+in steps of 5nm.
+
+The CIE 1931 y&#772;(&lambda;) function, equal to the photopic luminous efficiency function V(&lambda;),
+underestimates the sensitivity of the eye below 460nm. These corrected functions give more accurate results for
+light sources with a large short wavelength content, such as blue pumped LEDs.
+
+Source: J.J. Vos, "Colorimetric and photometric properties of a 2º fundamental observer",
+Color Research and Application 3, 1978.
+*/
+#[derive(Debug,Clone,Default)]
+pub struct CieObs1931JuddVos {}
+
+impl StandardObserver for CieObs1931JuddVos {
+	const NAME: &'static str = "CIE 1931 Judd-Vos 2º";
+
+	fn domain() -> Domain<WavelengthStep> {
+		Domain::new( 380/5, 825/5,  NM5)
+	}
+
+	fn cmf<'a>() -> MatrixSlice3xX<'a, f64> {
+		MatrixSlice::from_slice_generic(&CIE1931_JUDD_VOS, Const::<3>, Dynamic::new(N) )
+	}
+
+	fn values<L>(target: &Domain<L>) -> Matrix3xX<f64>
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	 {
+		interp_lin_cmf2(&Self::domain(), target, Self::cmf())
+	}
+}
+
+const N: usize = 90;
+
+static CIE1931_JUDD_VOS: [f64;3*N] = [ // 380-825-5
+	2.689900E-03, 2.000000E-04, 1.226000E-02, 5.310500E-03, 3.955600E-04, 2.422200E-02, 1.078100E-02, 8.000000E-04, 4.925000E-02,
+	2.079200E-02, 1.545700E-03, 9.513500E-02, 3.798100E-02, 2.800000E-03, 1.740900E-01, 6.315700E-02, 4.656200E-03, 2.901300E-01,
+	9.994100E-02, 7.400000E-03, 4.605300E-01, 1.582400E-01, 1.177900E-02, 7.316600E-01, 2.294800E-01, 1.750000E-02, 1.065800E+00,
+	2.810800E-01, 2.267800E-02, 1.314600E+00, 3.109500E-01, 2.730000E-02, 1.467200E+00, 3.307200E-01, 3.258400E-02, 1.579600E+00,
+	3.333600E-01, 3.790000E-02, 1.616600E+00, 3.167200E-01, 4.239100E-02, 1.568200E+00, 2.888200E-01, 4.680000E-02, 1.471700E+00,
+	2.596900E-01, 5.212200E-02, 1.374000E+00, 2.327600E-01, 6.000000E-02, 1.291700E+00, 2.099900E-01, 7.294200E-02, 1.235600E+00,
+	1.747600E-01, 9.098000E-02, 1.113800E+00, 1.328700E-01, 1.128400E-01, 9.422000E-01, 9.194400E-02, 1.390200E-01, 7.559600E-01,
+	5.698500E-02, 1.698700E-01, 5.864000E-01, 3.173100E-02, 2.080200E-01, 4.466900E-01, 1.461300E-02, 2.580800E-01, 3.411600E-01,
+	4.849100E-03, 3.230000E-01, 2.643700E-01, 2.321500E-03, 4.054000E-01, 2.059400E-01, 9.289900E-03, 5.030000E-01, 1.544500E-01,
+	2.927800E-02, 6.081100E-01, 1.091800E-01, 6.379100E-02, 7.100000E-01, 7.658500E-02, 1.108100E-01, 7.951000E-01, 5.622700E-02,
+	1.669200E-01, 8.620000E-01, 4.136600E-02, 2.276800E-01, 9.150500E-01, 2.935300E-02, 2.926900E-01, 9.540000E-01, 2.004200E-02,
+	3.622500E-01, 9.800400E-01, 1.331200E-02, 4.363500E-01, 9.949500E-01, 8.782300E-03, 5.151300E-01, 1.000100E+00, 5.857300E-03,
+	5.974800E-01, 9.950000E-01, 4.049300E-03, 6.812100E-01, 9.787500E-01, 2.921700E-03, 7.642500E-01, 9.520000E-01, 2.277100E-03,
+	8.439400E-01, 9.155800E-01, 1.970600E-03, 9.163500E-01, 8.700000E-01, 1.806600E-03, 9.770300E-01, 8.162300E-01, 1.544900E-03,
+	1.023000E+00, 7.570000E-01, 1.234800E-03, 1.051300E+00, 6.948300E-01, 1.117700E-03, 1.055000E+00, 6.310000E-01, 9.056400E-04,
+	1.036200E+00, 5.665400E-01, 6.946700E-04, 9.923900E-01, 5.030000E-01, 4.288500E-04, 9.286100E-01, 4.417200E-01, 3.181700E-04,
+	8.434600E-01, 3.810000E-01, 2.559800E-04, 7.398300E-01, 3.205200E-01, 1.567900E-04, 6.328900E-01, 2.650000E-01, 9.769400E-05,
+	5.335100E-01, 2.170200E-01, 6.894400E-05, 4.406200E-01, 1.750000E-01, 5.116500E-05, 3.545300E-01, 1.381200E-01, 3.601600E-05,
+	2.786200E-01, 1.070000E-01, 2.423800E-05, 2.148500E-01, 8.165200E-02, 1.691500E-05, 1.616100E-01, 6.100000E-02, 1.190600E-05,
+	1.182000E-01, 4.432700E-02, 8.148900E-06, 8.575300E-02, 3.200000E-02, 5.600600E-06, 6.307700E-02, 2.345400E-02, 3.954400E-06,
+	4.583400E-02, 1.700000E-02, 2.791200E-06, 3.205700E-02, 1.187200E-02, 1.917600E-06, 2.218700E-02, 8.210000E-03, 1.313500E-06,
+	1.561200E-02, 5.772300E-03, 9.151900E-07, 1.109800E-02, 4.102000E-03, 6.476700E-07, 7.923300E-03, 2.929100E-03, 4.635200E-07,
+	5.653100E-03, 2.091000E-03, 3.330400E-07, 3.975600E-03, 1.470800E-03, 2.382300E-07, 2.765700E-03, 1.023300E-03, 1.702600E-07,
+	1.924400E-03, 7.119900E-04, 1.220700E-07, 1.331000E-03, 4.925300E-04, 8.710700E-08, 9.224300E-04, 3.412900E-04, 6.145500E-08,
+	6.363600E-04, 2.354300E-04, 4.316200E-08, 4.395200E-04, 1.626100E-04, 3.037900E-08, 3.038300E-04, 1.124100E-04, 2.155400E-08,
+	2.105400E-04, 7.789100E-05, 1.549300E-08, 1.456000E-04, 5.386500E-05, 1.120400E-08, 1.005700E-04, 3.720900E-05, 8.087300E-09,
+	6.938800E-05, 2.567800E-05, 5.834000E-09, 4.788100E-05, 1.771900E-05, 4.211000E-09, 3.306500E-05, 1.223600E-05, 3.038300E-09,
+	2.284200E-05, 8.451700E-06, 2.190700E-09, 1.577500E-05, 5.836500E-06, 1.577800E-09, 1.089100E-05, 4.029500E-06, 1.134800E-09,
+	7.520000E-06, 2.782400E-06, 8.156500E-10, 5.198900E-06, 1.923600E-06, 5.862300E-10, 3.594700E-06, 1.330000E-06, 4.213600E-10,
+	2.484300E-06, 9.191200E-07, 3.025800E-10, 1.716400E-06, 6.350800E-07, 2.174100E-10, 1.185900E-06, 4.387600E-07, 1.563000E-10,
+];
+
+#[test]
+fn test_judd_vos() {
+	use approx::assert_abs_diff_eq;
+	use crate::illuminants::Planckian;
+	use crate::models::CieYxy;
+	use crate::observers::CieObs1931;
+
+	// corrections are mainly at short wavelengths, and have a small effect for a 2856K blackbody
+	let jv: CieYxy<CieObs1931JuddVos> = Planckian::new(2856).into();
+	let c: CieYxy<CieObs1931> = Planckian::new(2856).into();
+	assert_abs_diff_eq!(jv.data.column(0).y, c.data.column(0).y, epsilon = 0.01);
+	assert_abs_diff_eq!(jv.data.column(0).z, c.data.column(0).z, epsilon = 0.01);
+}
@@ -0,0 +1,271 @@
+/*!
+Parametric CIE 2006 physiological observer.
+
+The CIE 170-1:2006 model describes the cone fundamentals of an average observer as a function of age, and of the
+angular size of the visual field used in a color matching experiment. In this model, the spectral sensitivity of each
+cone type is the product of the absorptance of its photopigment, whose peak optical density decreases with field size,
+and the transmittance of the ocular media in front of it: the macular pigment, with a density which also decreases
+with field size, and the lens, which gets denser with age.
+
+The model needs five template spectra, which are tabulated in CIE 170-1: the low-density absorbance spectra of the L-,
+M-, and S-cone photopigments, the relative macular pigment density spectrum, and the two components of the lens
+optical density spectrum at an age of 32 years.  These tables are not distributed with this library, and have to be
+supplied as a [`Cie2006Templates`] instance.
+*/
+
+use std::fmt;
+use nalgebra::{DMatrix, Matrix3, Matrix3xX};
+use crate::interp_lin_cmf;
+use super::AsanoDeviations;
+use crate::{Domain, WavelengthStep, Meter, Step, Unit};
+
+/// Observer age range, in years, supported by the CIE 2006 model.
+pub const CIE2006_AGE_RANGE: [f64;2] = [20.0, 80.0];
+
+/// Field size range, in degrees, supported by the CIE 2006 model.
+pub const CIE2006_FIELD_SIZE_RANGE: [f64;2] = [1.0, 10.0];
+
+/// Parameters out of the range of the CIE 2006 model, or templates which do not match their domain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cie2006Error {
+	/// Observer age, in years, outside of [`CIE2006_AGE_RANGE`].
+	Age(f64),
+	/// Field size, in degrees, outside of [`CIE2006_FIELD_SIZE_RANGE`].
+	FieldSize(f64),
+	/// Template spectrum length, different from the length of the template domain.
+	TemplateLength { len: usize, domain: usize },
+}
+
+impl fmt::Display for Cie2006Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Cie2006Error::Age(age) => write!(f, "observer age {} out of range", age),
+			Cie2006Error::FieldSize(fs) => write!(f, "field size {} out of range", fs),
+			Cie2006Error::TemplateLength { len, domain } => {
+				write!(f, "template length {} does not match domain length {}", len, domain)
+			}
+		}
+	}
+}
+
+impl std::error::Error for Cie2006Error {}
+
+/**
+Template spectra for the CIE 2006 observer model, all sampled on the same wavelength domain.
+
+- `absorbance`: low-density absorbance spectra of the L-, M-, and S-cone photopigments, as row vectors, each normalized
+  to a peak value of 1.0,
+- `macular`: optical density of the macular pigment, normalized to a peak value of 1.0,
+- `lens1`: the age-dependent component of the optical density of the lens, for a 32 year old observer,
+- `lens2`: the age-independent component of the optical density of the lens.
+*/
+#[derive(Debug, Clone)]
+pub struct Cie2006Templates {
+	pub domain: Domain<WavelengthStep>,
+	pub absorbance: Matrix3xX<f64>,
+	pub macular: Vec<f64>,
+	pub lens1: Vec<f64>,
+	pub lens2: Vec<f64>,
+}
+
+impl Cie2006Templates {
+	pub fn new(domain: Domain<WavelengthStep>, absorbance: Matrix3xX<f64>, macular: Vec<f64>, lens1: Vec<f64>, lens2: Vec<f64>) -> Result<Self, Cie2006Error> {
+		let n = domain.len();
+		for len in [absorbance.ncols(), macular.len(), lens1.len(), lens2.len()] {
+			if len != n {
+				return Err(Cie2006Error::TemplateLength { len, domain: n });
+			}
+		}
+		Ok(Self { domain, absorbance, macular, lens1, lens2 })
+	}
+}
+
+/// Peak optical density of the macular pigment, for a field size in degrees.
+pub fn cie2006_macular_density(field_size: f64) -> f64 {
+	0.485 * (-field_size / 6.132).exp()
+}
+
+/// Peak optical densities of the L-, M-, and S-cone photopigments, for a field size in degrees.
+pub fn cie2006_photopigment_density(field_size: f64) -> [f64;3] {
+	let lm = 0.38 + 0.54 * (-field_size / 1.333).exp();
+	let s = 0.30 + 0.45 * (-field_size / 1.333).exp();
+	[lm, lm, s]
+}
+
+/// Scale factor for the age-dependent component of the lens optical density, for an age in years.
+pub fn cie2006_lens_age_factor(age: f64) -> f64 {
+	if age <= 60.0 {
+		1.0 + 0.02 * (age - 32.0)
+	} else {
+		1.56 + 0.0667 * (age - 60.0)
+	}
+}
+
+/**
+Cone fundamentals, and their derived color matching functions, for an observer of a given age and field size,
+according to the CIE 170-1 model.
+
+The cone fundamentals are energy based, and normalized to a peak value of 1.0. The color matching functions are
+obtained by the linear transformations for the CIE 2015 2º and 10º observers, as defined in CIE 170-2: the 2º
+transformation is used for field sizes up to 2º, the 10º transformation for a field size of 10º, and a linear
+interpolation of the two for field sizes in between.
+The results depend on the templates supplied, which are not included in this library, and are not validated against
+the tabulated CIE 2006, or CIE 2015, observers.
+
+Unlike the standard observers, this is a runtime object, and can not be used as a type parameter for the color models
+in this library: use its `values` and `xyz_from_dom_mat` methods instead.
+*/
+#[derive(Debug, Clone)]
+pub struct Cie2006Observer {
+	pub age: f64,
+	pub field_size: f64,
+	domain: Domain<WavelengthStep>,
+	lms: Matrix3xX<f64>,
+	cmf: Matrix3xX<f64>,
+}
+
+impl Cie2006Observer {
+	pub const K: f64 = 683.0;
+
+	/// Calculates the cone fundamentals and color matching functions for an observer of `age` years, and a
+	/// `field_size` in degrees.
+	///
+	/// Returns an error if the age is not in the range from 20 to 80 years, or if the field size is not in the range
+	/// from 1 to 10 degrees.
+	pub fn new(templates: &Cie2006Templates, age: f64, field_size: f64) -> Result<Self, Cie2006Error> {
+		Self::individual(templates, age, field_size, &AsanoDeviations::default())
+	}
+
+	/// Cone fundamentals and color matching functions for an individual observer, with physiological parameters
+	/// deviating from the CIE 2006 average observer of the same age and field size, as described in
+	/// [`AsanoDeviations`].
+	pub fn individual(templates: &Cie2006Templates, age: f64, field_size: f64, dev: &AsanoDeviations) -> Result<Self, Cie2006Error> {
+		if !(CIE2006_AGE_RANGE[0]..=CIE2006_AGE_RANGE[1]).contains(&age) {
+			return Err(Cie2006Error::Age(age));
+		}
+		if !(CIE2006_FIELD_SIZE_RANGE[0]..=CIE2006_FIELD_SIZE_RANGE[1]).contains(&field_size) {
+			return Err(Cie2006Error::FieldSize(field_size));
+		}
+
+		let d_mac = cie2006_macular_density(field_size) * (1.0 + dev.macula / 100.0);
+		let d_pig = cie2006_photopigment_density(field_size);
+		let f_lens = 1.0 + dev.lens / 100.0;
+		let f_age = cie2006_lens_age_factor(age);
+
+		let absorbance = dev.shifted_absorbance(templates);
+		let mut lms = Matrix3xX::<f64>::zeros(templates.domain.len());
+		for (j, l) in templates.domain.iter().enumerate() {
+			let ocular = d_mac * templates.macular[j] + f_lens * (f_age * templates.lens1[j] + templates.lens2[j]);
+			let t = 10f64.powf(-ocular);
+			for (r, d) in d_pig.iter().enumerate() {
+				let d = d * (1.0 + dev.optical_density[r] / 100.0);
+				let alpha = 1.0 - 10f64.powf(-d * absorbance[(r, j)]);
+				lms[(r, j)] = alpha * t * l.value(); // quantal to energy based
+			}
+		}
+		for mut row in lms.row_iter_mut() {
+			let max = row.max();
+			if max > 0.0 {
+				row /= max;
+			}
+		}
+
+		let cmf = cie2006_lms_to_xyz(field_size) * &lms;
+
+		Ok(Self { age, field_size, domain: templates.domain.clone(), lms, cmf })
+	}
+
+	/// Domain of the cone fundamentals and color matching functions, as the domain of the templates used.
+	pub fn domain(&self) -> Domain<WavelengthStep> {
+		self.domain.clone()
+	}
+
+	/// Energy based L-, M-, and S-cone fundamentals, as row vectors.
+	pub fn lms(&self) -> &Matrix3xX<f64> {
+		&self.lms
+	}
+
+	/// The x&#772;(&lambda;), y&#772;(&lambda;) and z&#772;(&lambda;) color matching functions, as row vectors.
+	pub fn cmf(&self) -> &Matrix3xX<f64> {
+		&self.cmf
+	}
+
+	/// Color matching functions, linearly interpolated to a target domain.
+	pub fn values<L>(&self, target: &Domain<L>) -> Matrix3xX<f64>
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	{
+		interp_lin_cmf(&self.domain, target, 3, self.cmf.columns(0, self.cmf.ncols()))
+	}
+
+	/// Tristimulus values for spectral distributions, given as columns in matrix `m`, and defined on domain `d`.
+	pub fn xyz_from_dom_mat<L>(&self, d: Domain<L>, m: DMatrix<f64>) -> Matrix3xX<f64>
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	{
+		self.values(&d) * m * Self::K * Meter::from(d.step.unitvalue(1)).value()
+	}
+}
+
+/// Transformation from energy based cone fundamentals to color matching functions, for a field size in degrees,
+/// linearly interpolated between the CIE 170-2 2º and 10º transformations.
+pub fn cie2006_lms_to_xyz(field_size: f64) -> Matrix3<f64> {
+	let w = ((field_size - 2.0) / 8.0).clamp(0.0, 1.0);
+	Matrix3::from_row_slice(&LMS_TO_XYZ_F2) * (1.0 - w) + Matrix3::from_row_slice(&LMS_TO_XYZ_F10) * w
+}
+
+// CIE 170-2:2015, transformation from the energy based cone fundamentals to the XYZ-F color matching functions
+const LMS_TO_XYZ_F2: [f64;9] = [
+	1.94735469, -1.41445123, 0.36476327,
+	0.68990272, 0.34832189, 0.0,
+	0.0, 0.0, 1.93485343,
+];
+
+pub(super) const LMS_TO_XYZ_F10: [f64;9] = [
+	1.93986443, -1.34664359, 0.43044935,
+	0.69283932, 0.34967567, 0.0,
+	0.0, 0.0, 2.14687945,
+];
+
+#[test]
+fn test_cie2006_parameters() {
+	use approx::assert_abs_diff_eq;
+	assert_abs_diff_eq!(cie2006_macular_density(2.0), 0.3500, epsilon = 5E-5);
+	assert_abs_diff_eq!(cie2006_macular_density(10.0), 0.09495, epsilon = 1E-5);
+	let [l, m, s] = cie2006_photopigment_density(2.0);
+	assert_abs_diff_eq!(l, 0.50045, epsilon = 1E-5);
+	assert_abs_diff_eq!(m, 0.50045, epsilon = 1E-5);
+	assert_abs_diff_eq!(s, 0.40037, epsilon = 1E-5);
+	assert_abs_diff_eq!(cie2006_lens_age_factor(32.0), 1.0);
+	assert_abs_diff_eq!(cie2006_lens_age_factor(60.0), 1.56);
+	assert_abs_diff_eq!(cie2006_lens_age_factor(70.0), 2.227);
+
+	// Flat templates: a denser lens reduces all responses equally, which is normalized out again
+	let d = Domain::new(400, 700, crate::NM10);
+	let n = d.len();
+	let t = Cie2006Templates::new(d.clone(), Matrix3xX::from_element(n, 1.0), vec![0.0; n], vec![0.1; n], vec![0.0; n]).unwrap();
+	let young = Cie2006Observer::new(&t, 20.0, 2.0).unwrap();
+	let old = Cie2006Observer::new(&t, 80.0, 2.0).unwrap();
+	assert_abs_diff_eq!(young.lms(), old.lms(), epsilon = 1E-12);
+	assert_abs_diff_eq!(young.lms()[(0, n - 1)], 1.0, epsilon = 1E-12);
+
+	assert_eq!(Cie2006Observer::new(&t, 90.0, 2.0).unwrap_err(), Cie2006Error::Age(90.0));
+	assert_eq!(Cie2006Observer::new(&t, 32.0, 12.0).unwrap_err(), Cie2006Error::FieldSize(12.0));
+	assert_eq!(
+		Cie2006Templates::new(d, Matrix3xX::from_element(n, 1.0), vec![0.0; n - 1], vec![0.1; n], vec![0.0; n]).unwrap_err(),
+		Cie2006Error::TemplateLength { len: n - 1, domain: n }
+	);
+
+	// The transformation is continuous in field size, and equals the CIE 170-2 transformations at 2º and 10º
+	assert_eq!(cie2006_lms_to_xyz(1.0), Matrix3::from_row_slice(&LMS_TO_XYZ_F2));
+	assert_eq!(cie2006_lms_to_xyz(2.0), Matrix3::from_row_slice(&LMS_TO_XYZ_F2));
+	assert_eq!(cie2006_lms_to_xyz(10.0), Matrix3::from_row_slice(&LMS_TO_XYZ_F10));
+	assert_abs_diff_eq!(cie2006_lms_to_xyz(6.0), cie2006_lms_to_xyz(5.999), epsilon = 1E-3);
+	assert_abs_diff_eq!(
+		cie2006_lms_to_xyz(6.0),
+		(Matrix3::from_row_slice(&LMS_TO_XYZ_F2) + Matrix3::from_row_slice(&LMS_TO_XYZ_F10)) / 2.0,
+		epsilon = 1E-12
+	);
+}
@@ -0,0 +1,89 @@
+use nalgebra::{Const, Dynamic, Matrix3xX, MatrixSlice, MatrixSlice3xX};
+use crate::interp_lin_cmf;
+use crate::{observers::StandardObserver};
+use crate::{Domain};
+use crate::{NM, WavelengthStep, Meter, Step};
+
+use super::cie_f2::{CIE_OBS_F2, N};
+use super::cie_f10::CIE_OBS_F10;
+
+
+/**
+CIE 2015 2º cone-fundamental-based color matching functions, defined from 390 to 830nm, in steps of 1nm.
+
+These are the x&#772;<sub>F</sub>(&lambda;), y&#772;<sub>F</sub>(&lambda;), and z&#772;<sub>F</sub>(&lambda;)
+functions, linear transformations of the CIE 2006 LMS cone fundamentals, for a 2º field size.
+They share their data with [`CieObsF2`](super::CieObsF2), but use the CIE 170-2 name, so they can be used in any
+color model or dataset parametrized by a standard observer.
+
+Source: CIE 170-2:2015.
+*/
+#[allow(non_camel_case_types)]
+#[derive(Debug,Clone,Default)]
+pub struct CieObs2015_2 {}
+
+impl StandardObserver for CieObs2015_2 {
+	const NAME: &'static str = "CIE 2015 2º";
+
+	fn domain() -> Domain<WavelengthStep> {
+		Domain::new( 390, 830,  NM)
+	}
+
+	fn cmf<'a>() -> MatrixSlice3xX<'a, f64> {
+		MatrixSlice::from_slice_generic(&CIE_OBS_F2, Const::<3>, Dynamic::new(N) )
+	}
+
+	fn values<L>(target: &Domain<L>) -> Matrix3xX<f64>
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	 {
+		interp_lin_cmf(&Self::domain(), target, 3, Self::cmf())
+	}
+}
+
+/**
+CIE 2015 10º cone-fundamental-based color matching functions, defined from 390 to 830nm, in steps of 1nm.
+
+As [`CieObs2015_2`], but derived from the cone fundamentals for a 10º field size.
+Source: CIE 170-2:2015.
+*/
+#[allow(non_camel_case_types)]
+#[derive(Debug,Clone,Default)]
+pub struct CieObs2015_10 {}
+
+impl StandardObserver for CieObs2015_10 {
+	const NAME: &'static str = "CIE 2015 10º";
+
+	fn domain() -> Domain<WavelengthStep> {
+		Domain::new( 390, 830,  NM)
+	}
+
+	fn cmf<'a>() -> MatrixSlice3xX<'a, f64> {
+		MatrixSlice::from_slice_generic(&CIE_OBS_F10, Const::<3>, Dynamic::new(N) )
+	}
+
+	fn values<L>(target: &Domain<L>) -> Matrix3xX<f64>
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	 {
+		interp_lin_cmf(&Self::domain(), target, 3, Self::cmf())
+	}
+}
+
+#[test]
+fn test_cie2015_white_points() {
+	use approx::assert_abs_diff_eq;
+	use crate::models::CieYxy;
+	use crate::illuminants::CieIllD65;
+
+	// D65 white points, which differ slightly from the CIE 1931 and CIE 1964 values
+	let xy2: CieYxy<CieObs2015_2> = CieIllD65.into();
+	assert_abs_diff_eq!(xy2.data.column(0).y, 0.31342, epsilon = 5E-5);
+	assert_abs_diff_eq!(xy2.data.column(0).z, 0.33076, epsilon = 5E-5);
+
+	let xy10: CieYxy<CieObs2015_10> = CieIllD65.into();
+	assert_abs_diff_eq!(xy10.data.column(0).y, 0.31376, epsilon = 5E-5);
+	assert_abs_diff_eq!(xy10.data.column(0).z, 0.33124, epsilon = 5E-5);
+}
@@ -39,9 +39,9 @@ impl StandardObserver for CieObsF10 {
 
 }
 
-const N: usize = 441;
+pub(super) const N: usize = 441;
 
-static CIE_OBS_F10: [f64;3*N] = [
+pub(super) static CIE_OBS_F10: [f64;3*N] = [
  2.952420E-03, 4.076779E-04, 1.318752E-02, 3.577275E-03, 4.977769E-04, 1.597879E-02, 4.332146E-03, 6.064754E-04,
  1.935758E-02, 5.241609E-03, 7.370040E-04, 2.343758E-02, 6.333902E-03, 8.929388E-04, 2.835021E-02, 7.641137E-03,
  1.078166E-03, 3.424588E-02, 9.199401E-03, 1.296816E-03, 4.129467E-02, 1.104869E-02, 1.553159E-03, 4.968641E-02,
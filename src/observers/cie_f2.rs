@@ -39,9 +39,9 @@ impl StandardObserver for CieObsF2 {
 
 }
 
-const N: usize = 441;
+pub(super) const N: usize = 441;
 
-static CIE_OBS_F2: [f64;3*N] = [
+pub(super) static CIE_OBS_F2: [f64;3*N] = [
 	3.769647E-03, 4.146161E-04, 1.847260E-02, 4.532416E-03, 5.028333E-04, 2.221101E-02, 5.446553E-03, 6.084991E-04, 2.669819E-02,
 	6.538868E-03, 7.344436E-04, 3.206937E-02, 7.839699E-03, 8.837389E-04, 3.847832E-02, 9.382967E-03, 1.059646E-03, 4.609784E-02,
 	1.120608E-02, 1.265532E-03, 5.511953E-02, 1.334965E-02, 1.504753E-03, 6.575257E-02, 1.585690E-02, 1.780493E-03, 7.822113E-02,
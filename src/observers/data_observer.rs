@@ -0,0 +1,137 @@
+use nalgebra::{DMatrix, Matrix3x1, Matrix3xX};
+use crate::interp_lin_cmf;
+use crate::{observers::StandardObserver, SpectralDistribution};
+use crate::{Domain, WavelengthStep, Meter, Step, Unit};
+use super::Cie2006Observer;
+
+
+/**
+Color matching functions, or any other set of three spectral responses, loaded at runtime.
+
+The standard observers are compile-time types, used as type parameters in the color models of this library.
+A `DataObserver` is their runtime counterpart: it wraps a wavelength domain, and a 3×N matrix with the response
+functions as row vectors, for example the measured spectral sensitivities of a camera, or of a colorimeter.
+Its response functions are interpolated to any target domain in the same way as for the standard observers, and its
+tristimulus values are returned as plain matrices.
+
+# Examples
+Tristimulus values of a blackbody radiator, using the CIE 1931 observer data.
+```
+use scot::observers::{DataObserver, CieObs1931};
+use scot::illuminants::Planckian;
+
+let obs = DataObserver::from_observer::<CieObs1931>();
+let xyz = obs.xyz(&Planckian::new(3000));
+println!("{}", xyz);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct DataObserver {
+	pub name: String,
+	pub k: f64,
+	domain: Domain<WavelengthStep>,
+	data: Matrix3xX<f64>,
+}
+
+impl DataObserver {
+	pub fn new(domain: Domain<WavelengthStep>, data: Matrix3xX<f64>) -> Self {
+		assert!(domain.len() == data.ncols(), "data length does not match domain");
+		Self { name: String::from("Data Observer"), k: 683.0, domain, data }
+	}
+
+	/// Sets a name, used as an identifier for the observer.
+	pub fn set_name(mut self, name: &str) -> Self {
+		self.name = name.to_string();
+		self
+	}
+
+	/// Sets the scale factor for the tristimulus values, with a default value of 683.0 lm/W, as used for the
+	/// standard observers.
+	pub fn set_k(mut self, k: f64) -> Self {
+		self.k = k;
+		self
+	}
+
+	/// Copies the color matching functions of a standard observer.
+	pub fn from_observer<C: StandardObserver>() -> Self {
+		let cmf = C::cmf();
+		Self {
+			name: C::NAME.to_string(),
+			k: C::K,
+			domain: C::domain(),
+			data: Matrix3xX::from_iterator(cmf.ncols(), cmf.iter().cloned()),
+		}
+	}
+
+	pub fn domain(&self) -> Domain<WavelengthStep> {
+		self.domain.clone()
+	}
+
+	pub fn cmf(&self) -> &Matrix3xX<f64> {
+		&self.data
+	}
+
+	/// Response functions, linearly interpolated to a target domain, and zero outside the observer's domain.
+	pub fn values<L>(&self, target: &Domain<L>) -> Matrix3xX<f64>
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	{
+		interp_lin_cmf(&self.domain, target, 3, self.data.columns(0, self.data.ncols()))
+	}
+
+	/// Tristimulus values for spectral distributions, given as columns in matrix `m`, and defined on domain `d`.
+	pub fn xyz_from_dom_mat<L>(&self, d: Domain<L>, m: DMatrix<f64>) -> Matrix3xX<f64>
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	{
+		self.values(&d) * m * self.k * Meter::from(d.step.unitvalue(1)).value()
+	}
+
+	/// Tristimulus values of an illuminant `l`, and of reflection or transmission spectra `m`, illuminated by it,
+	/// both defined on domain `d`. Only the first column of `l` is used.
+	pub fn xyz_from_dom_ill_mat<L>(&self, d: Domain<L>, l: DMatrix<f64>, m: DMatrix<f64>) -> (Matrix3x1<f64>, Matrix3xX<f64>)
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	{
+		assert!(l.nrows()==m.nrows());
+		let c = self.values(&d);
+		let s = self.k * Meter::from(d.step.unitvalue(1)).value();
+		let m: DMatrix<f64>  = DMatrix::from_fn(l.nrows(), m.ncols(), |i, j| l[(i,0)] * m[(i,j)]);
+		(&c * l.column(0) * s, c * m * s)
+	}
+
+	/// Tristimulus values for the spectral distributions in a collection.
+	pub fn xyz<S>(&self, sd: &S) -> Matrix3xX<f64>
+	where
+		S: SpectralDistribution,
+		Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+	{
+		let (d, s) = sd.spd();
+		let (n, m) = sd.shape();
+		self.xyz_from_dom_mat(d, DMatrix::from_fn(n, m, |i, j| s[(i, j)]))
+	}
+}
+
+impl From<Cie2006Observer> for DataObserver {
+	fn from(obs: Cie2006Observer) -> Self {
+		Self::new(obs.domain(), obs.cmf().clone())
+			.set_name(&format!("CIE 2006 {}º, {} years", obs.field_size, obs.age))
+	}
+}
+
+#[test]
+fn test_data_observer() {
+	use approx::assert_abs_diff_eq;
+	use crate::illuminants::CieIllD65;
+	use crate::models::CieXYZ;
+	use crate::observers::CieObs1931;
+
+	let obs = DataObserver::from_observer::<CieObs1931>();
+	let xyz = obs.xyz(&CieIllD65);
+	let xyz_ref: CieXYZ<CieObs1931> = CieIllD65.into();
+	let y = xyz[1];
+	assert_abs_diff_eq!(xyz * (100.0 / y), xyz_ref.data, epsilon = 1E-8);
+}
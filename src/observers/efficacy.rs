@@ -0,0 +1,120 @@
+/*!
+Luminous efficacy of radiation, and the distribution of radiant and luminous power over spectral bands.
+
+The luminous efficacy of radiation, or LER, of a light source is the ratio of its luminous flux, and its radiant
+flux, in lm/W. It is the upper limit of the efficacy of a lamp, which would only be reached with a conversion of all
+its electrical power into radiation, and is, for white light sources, typically between 250 and 350 lm/W, with a
+maximum of 683 lm/W for monochromatic radiation at 555nm.
+Here the radiant flux is calculated over the domain of the spectral distributions only, which for most of the
+illuminants in this library does not extend far beyond the visible part of the spectrum.
+*/
+
+use nalgebra::{DMatrix, RowDVector};
+use crate::observers::{photopic_luminous, StandardObserver};
+use crate::{SpectralDistribution, WavelengthStep, Meter, Step, Unit};
+
+/// Radiant quantities for a collection of spectral distributions, as a row vector. With spectral irradiance data in
+/// W/m<sup>2</sup>/m, this is the irradiance, in W/m<sup>2</sup>.
+pub fn radiant<S>(sd: &S) -> RowDVector<f64>
+where
+	S: SpectralDistribution,
+	Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+{
+	let (d, s) = sd.spd();
+	let (n, m) = sd.shape();
+	let dl = Meter::from(d.step.unitvalue(1)).value();
+	RowDVector::from_fn(m, |_, j| (0..n).map(|i| s[(i, j)]).sum::<f64>() * dl)
+}
+
+/**
+Luminous efficacy of radiation, in lm/W, for a collection of spectral distributions, using the y&#772;(&lambda;)
+function of the observer as photopic luminous efficiency function.
+
+# Examples
+```
+use scot::observers::{ler, CieObs1931};
+use scot::illuminants::{CieIllA, CieIllD65};
+
+let a = ler::<CieObs1931, _>(&CieIllA);
+let d65 = ler::<CieObs1931, _>(&CieIllD65);
+println!("LER A {:.0} lm/W, D65 {:.0} lm/W", a[0], d65[0]);
+```
+*/
+pub fn ler<C, S>(sd: &S) -> RowDVector<f64>
+where
+	C: StandardObserver,
+	S: SpectralDistribution,
+	Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+{
+	photopic_luminous::<C, S>(sd).component_div(&radiant(sd))
+}
+
+/**
+Fractions of the radiant, and of the luminous, power of a collection of spectral distributions, in spectral bands.
+
+The bands are given by their start, and end, wavelengths in nanometer, with the start wavelength included in, and
+the end wavelength excluded from, a band. The results are returned as two matrices, the first with the radiant, and
+the second with the luminous, fractions, with a row for each band, and a column for each spectral distribution.
+The ratio of the luminous, and radiant, fraction of a band, multiplied by the luminous efficacy of radiation of the
+source, is the luminous efficacy of the radiation in that band.
+
+# Examples
+Fractions of radiant, and luminous, power of illuminant A, in the blue, green, and red parts of the spectrum.
+```
+use scot::observers::{band_fractions, CieObs1931};
+use scot::illuminants::CieIllA;
+
+let (radiant, luminous) = band_fractions::<CieObs1931, _>(&CieIllA, &[[380.0, 490.0], [490.0, 590.0], [590.0, 781.0]]);
+println!("{:.3}{:.3}", radiant, luminous);
+```
+*/
+pub fn band_fractions<C, S>(sd: &S, bands: &[[f64; 2]]) -> (DMatrix<f64>, DMatrix<f64>)
+where
+	C: StandardObserver,
+	S: SpectralDistribution<StepType = WavelengthStep>,
+{
+	let (d, s) = sd.spd();
+	let (n, m) = sd.shape();
+	let v = C::values(&d);
+	let l: Vec<f64> = d.iter().map(|l| l.value() * 1E9).collect();
+	let mut radiant = DMatrix::zeros(bands.len(), m);
+	let mut luminous = DMatrix::zeros(bands.len(), m);
+	for j in 0..m {
+		let e_tot: f64 = (0..n).map(|i| s[(i, j)]).sum();
+		let v_tot: f64 = (0..n).map(|i| v[(1, i)] * s[(i, j)]).sum();
+		for (k, [start, end]) in bands.iter().enumerate() {
+			let band = (0..n).filter(|&i| l[i] >= *start - 1E-9 && l[i] < *end - 1E-9);
+			let (e, lv) = band.fold((0.0, 0.0), |(e, lv), i| (e + s[(i, j)], lv + v[(1, i)] * s[(i, j)]));
+			radiant[(k, j)] = e / e_tot;
+			luminous[(k, j)] = lv / v_tot;
+		}
+	}
+	(radiant, luminous)
+}
+
+#[test]
+fn test_ler() {
+	use approx::assert_abs_diff_eq;
+	use crate::illuminants::{CieIllA, CieIllD65};
+	use crate::observers::CieObs1931;
+	use crate::{DataSpectrumFromSlice, Domain, NM};
+
+	// an equal energy spectrum, from 380 to 780nm, has an efficacy of 683 lm/W times the mean value of y-bar
+	let e = [1.0; 401];
+	let ler_e = ler::<CieObs1931, _>(&DataSpectrumFromSlice::new(Domain::new(380, 780, NM), &e));
+	assert_abs_diff_eq!(ler_e[0], 182.0, epsilon = 0.5);
+
+	// the visible part of incandescent light is redder, and has a lower efficacy, than daylight
+	let a = ler::<CieObs1931, _>(&CieIllA)[0];
+	let d65 = ler::<CieObs1931, _>(&CieIllD65)[0];
+	assert!(a < d65, "{} {}", a, d65);
+
+	// bands covering the domain of illuminant A, from 300 to 830nm
+	let bands = [[300.0, 500.0], [500.0, 600.0], [600.0, 831.0], [380.0, 781.0]];
+	let (r, l) = band_fractions::<CieObs1931, _>(&CieIllA, &bands);
+	assert_abs_diff_eq!(r[(0, 0)] + r[(1, 0)] + r[(2, 0)], 1.0, epsilon = 1E-12);
+	assert_abs_diff_eq!(l[(0, 0)] + l[(1, 0)] + l[(2, 0)], 1.0, epsilon = 1E-12);
+	assert!(r[(3, 0)] < 1.0);
+	assert_abs_diff_eq!(l[(3, 0)], 1.0, epsilon = 1E-6);
+	assert!(r[(2, 0)] > r[(1, 0)] && l[(1, 0)] > l[(2, 0)]);
+}
@@ -0,0 +1,112 @@
+/*!
+Mesopic photometry, according to CIE 191:2010.
+
+In the mesopic range, with luminances between 0.005 and 5 cd/m<sup>2</sup>, both rods and cones contribute to vision.
+The CIE 191 recommended system uses a spectral luminous efficiency function which is a linear combination of the
+photopic and scotopic functions, V<sub>mes</sub>(&lambda;) = [m V(&lambda;) + (1 - m) V&prime;(&lambda;)] / M(m), with the
+adaptation coefficient m depending on the adaptation luminance, and the S/P ratio of the light source.  Above 5
+cd/m<sup>2</sup> m has a value of 1, and vision is photopic; below 0.005 cd/m<sup>2</sup> m is 0, and vision is
+scotopic.
+*/
+
+use nalgebra::RowDVector;
+use crate::observers::{sp_ratio, CieScotopic1951, StandardObserver};
+use crate::{Domain, SpectralDistribution, WavelengthStep, Meter, Step, NM};
+
+/// Photopic luminance range, in cd/m<sup>2</sup>, of the CIE 191 mesopic system.
+pub const MESOPIC_RANGE: [f64;2] = [0.005, 5.0];
+
+const MESOPIC_A: f64 = 0.7670;
+const MESOPIC_B: f64 = 0.3334;
+const V_SCOTOPIC_555: f64 = 683.0 / 1699.0; // V'(λ0), with λ0 = 555 nm
+
+/**
+Adaptation coefficient, and mesopic luminance, for a photopic luminance and S/P ratio, according to CIE 191:2010.
+
+# Examples
+```
+use scot::observers::CieMesopic191;
+
+let mes = CieMesopic191::new(1.0, 2.0);
+assert!(mes.m > 0.0 && mes.m < 1.0);
+assert!(mes.luminance > 1.0); // a 'blue' light source looks brighter at mesopic levels
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CieMesopic191 {
+	/// Adaptation coefficient, with a value from 0.0 (scotopic), to 1.0 (photopic).
+	pub m: f64,
+	/// Mesopic luminance, in cd/m<sup>2</sup>.
+	pub luminance: f64,
+}
+
+impl CieMesopic191 {
+
+	/// Mesopic adaptation coefficient and luminance, for a photopic luminance `l_p` in cd/m<sup>2</sup>, and a light
+	/// source with an S/P ratio `sp`, obtained by iteration, starting with a value of m = 0.5.
+	pub fn new(l_p: f64, sp: f64) -> Self {
+		let l_s = sp * l_p;
+		if l_p >= MESOPIC_RANGE[1] {
+			return Self { m: 1.0, luminance: l_p };
+		}
+		if l_p <= MESOPIC_RANGE[0] {
+			return Self { m: 0.0, luminance: l_s };
+		}
+		let lmes = |m: f64| (m * l_p + (1.0 - m) * l_s * V_SCOTOPIC_555) / (m + (1.0 - m) * V_SCOTOPIC_555);
+		let mut m = 0.5;
+		for _ in 0..100 {
+			let m_next = (MESOPIC_A + MESOPIC_B * lmes(m).log10()).clamp(0.0, 1.0);
+			if (m_next - m).abs() < 1E-9 {
+				m = m_next;
+				break;
+			}
+			m = m_next;
+		}
+		Self { m, luminance: lmes(m) }
+	}
+
+	/// Mesopic luminances for a collection of spectral distributions, at a photopic adaptation luminance `l_p`, in
+	/// cd/m<sup>2</sup>, using the y&#772;(&lambda;) function of observer `C` as photopic luminous efficiency
+	/// function.
+	pub fn from_spectra<C, S>(sd: &S, l_p: f64) -> Vec<Self>
+	where
+		C: StandardObserver,
+		S: SpectralDistribution,
+		Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+	{
+		sp_ratio::<C, S>(sd).iter().map(|&sp| Self::new(l_p, sp)).collect()
+	}
+
+	/// Mesopic luminous efficiency function V<sub>mes</sub>(&lambda;), on a target domain, normalized to a peak value
+	/// of 1.0.
+	pub fn values<C, L>(&self, target: &Domain<L>) -> RowDVector<f64>
+	where
+		C: StandardObserver,
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	{
+		let d = Domain::new(380, 780, NM);
+		let norm = (C::values::<WavelengthStep>(&d).row(1) * self.m + CieScotopic1951::values::<WavelengthStep>(&d) * (1.0 - self.m)).max();
+		(C::values(target).row(1) * self.m + CieScotopic1951::values(target) * (1.0 - self.m)) / norm
+	}
+}
+
+#[test]
+fn test_mesopic() {
+	use approx::assert_abs_diff_eq;
+	use crate::observers::CieObs1931;
+
+	// for an S/P ratio of 1, mesopic and photopic luminances are the same
+	let mes = CieMesopic191::new(0.1, 1.0);
+	assert_abs_diff_eq!(mes.luminance, 0.1, epsilon = 1E-9);
+
+	assert_abs_diff_eq!(CieMesopic191::new(10.0, 2.0).m, 1.0);
+	assert_abs_diff_eq!(CieMesopic191::new(0.001, 2.0).m, 0.0);
+
+	// m increases with luminance
+	let m: Vec<f64> = [0.01, 0.1, 1.0].iter().map(|&l| CieMesopic191::new(l, 1.5).m).collect();
+	assert!(m[0] < m[1] && m[1] < m[2]);
+
+	let v = CieMesopic191 { m: 0.5, luminance: 1.0 }.values::<CieObs1931, _>(&Domain::new(380, 780, NM));
+	assert_abs_diff_eq!(v.max(), 1.0, epsilon = 1E-12);
+}
@@ -10,6 +10,9 @@ automatic transformations between the different mathematical representations of
 pub mod cie1931;
 pub use cie1931::*;
 
+pub mod cie1931_jv;
+pub use cie1931_jv::*;
+
 pub mod cie1964;
 pub use cie1964::*;
 
@@ -19,8 +22,37 @@ pub use cie_f2::*;
 pub mod cie_f10;
 pub use cie_f10::*;
 
+pub mod cie2015;
+pub use cie2015::*;
+
+pub mod cie2006;
+pub use cie2006::*;
+
+pub mod asano;
+pub use asano::*;
+
+pub mod data_observer;
+pub use data_observer::*;
+
+pub mod scotopic;
+pub use scotopic::*;
+
+pub mod efficacy;
+pub use efficacy::*;
+
+pub mod mesopic;
+pub use mesopic::*;
+
+pub mod alphaopic;
+pub use alphaopic::*;
+
+pub mod cache;
+pub use cache::*;
+
+use std::sync::Arc;
+
 use crate::{Domain, Meter, Step, WavelengthStep};
-use nalgebra::{Const, Dynamic, MatrixSlice3xX, OMatrix};
+use nalgebra::{Const, Dynamic, Matrix3xX, MatrixSlice3xX, OMatrix};
 
 /**
    Color matching functions mapped to a spectral data domain.
@@ -48,6 +80,18 @@ pub trait StandardObserver: Default {
         L: Step,
         Meter: From<<L>::UnitValueType>;
 
+    /**
+        As `values`, but returns a shared matrix from a process wide cache, which is only calculated on the first call
+        for this observer and target domain.
+    */
+    fn values_cached<L>(target: &Domain<L>) -> Arc<Matrix3xX<f64>>
+    where
+        L: Step,
+        Meter: From<<L>::UnitValueType>,
+    {
+        cached_values::<Self, L>(target)
+    }
+
     /*
         Calculate tri-stimulus values from spectral data, represented by a domain `d`,
         and a `DMatrix<f64>` data array.
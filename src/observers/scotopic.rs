@@ -0,0 +1,121 @@
+/*!
+Scotopic luminous efficiency function V&prime;(&lambda;), and the S/P ratio of light sources.
+
+At low light levels, below about 0.005 cd/m<sup>2</sup>, vision is mediated by the rods only, with a spectral
+sensitivity described by the CIE 1951 scotopic luminous efficiency function V&prime;(&lambda;), peaking at 507nm.
+The ratio of the scotopic and photopic luminous quantities of a light source, its S/P ratio, is used in the evaluation
+of street and outdoor lighting.
+*/
+
+use nalgebra::{DVectorSlice, RowDVector};
+use crate::lin_interp_mat_col;
+use crate::observers::StandardObserver;
+use crate::{Domain, SpectralDistribution, WavelengthStep, Meter, Step, Unit};
+use crate::{NM5};
+
+/// CIE 1951 scotopic luminous efficiency function, V&prime;(&lambda;), defined from 380 to 780nm, in steps of 5nm.
+#[derive(Debug,Clone,Default)]
+pub struct CieScotopic1951 {}
+
+impl CieScotopic1951 {
+	/// Maximum scotopic luminous efficacy, in lm/W.
+	pub const K: f64 = 1700.0;
+	pub const NAME: &'static str = "CIE 1951 Scotopic";
+
+	pub fn domain() -> Domain<WavelengthStep> {
+		Domain::new( 380/5, 780/5, NM5)
+	}
+
+	/// V&prime;(&lambda;) values, linearly interpolated to a target domain, as a row vector.
+	pub fn values<L>(target: &Domain<L>) -> RowDVector<f64>
+	where
+		L: Step,
+		Meter: From<<L>::UnitValueType>
+	{
+		let v = lin_interp_mat_col(&Self::domain(), target, 1, DVectorSlice::from_slice(&CIE_SCOTOPIC_1951, N));
+		RowDVector::from_iterator(v.nrows(), v.iter().cloned())
+	}
+
+	/// Scotopic luminous quantities for a collection of spectral distributions, as a row vector. With spectral
+	/// irradiance data in W/m<sup>2</sup>/m, this is the scotopic illuminance, in scotopic lux.
+	pub fn luminous<S>(sd: &S) -> RowDVector<f64>
+	where
+		S: SpectralDistribution,
+		Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+	{
+		let (d, s) = sd.spd();
+		let (n, m) = sd.shape();
+		let v = Self::values(&d);
+		let dl = Meter::from(d.step.unitvalue(1)).value();
+		RowDVector::from_fn(m, |_, j| (0..n).map(|i| v[i] * s[(i, j)]).sum::<f64>() * Self::K * dl)
+	}
+}
+
+/// Photopic luminous quantities for a collection of spectral distributions, as a row vector, using the y&#772;(&lambda;)
+/// function of the observer as photopic luminous efficiency function.
+pub fn photopic_luminous<C, S>(sd: &S) -> RowDVector<f64>
+where
+	C: StandardObserver,
+	S: SpectralDistribution,
+	Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+{
+	let (d, s) = sd.spd();
+	let (n, m) = sd.shape();
+	let v = C::values(&d);
+	let dl = Meter::from(d.step.unitvalue(1)).value();
+	RowDVector::from_fn(m, |_, j| (0..n).map(|i| v[(1, i)] * s[(i, j)]).sum::<f64>() * C::K * dl)
+}
+
+/**
+S/P ratios, the ratio of scotopic and photopic luminous quantities, for a collection of spectral distributions.
+
+# Examples
+For CIE illuminant A the S/P ratio is about 1.41, and for daylight illuminant D65 about 2.47.
+```
+use scot::observers::{sp_ratio, CieObs1931};
+use scot::illuminants::CieIllD65;
+use approx::assert_abs_diff_eq;
+
+let sp = sp_ratio::<CieObs1931, _>(&CieIllD65);
+assert_abs_diff_eq!(sp[0], 2.47, epsilon = 0.01);
+```
+*/
+pub fn sp_ratio<C, S>(sd: &S) -> RowDVector<f64>
+where
+	C: StandardObserver,
+	S: SpectralDistribution,
+	Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+{
+	CieScotopic1951::luminous(sd).component_div(&photopic_luminous::<C, S>(sd))
+}
+
+const N: usize = 81;
+
+static CIE_SCOTOPIC_1951: [f64; N] = [
+	5.890E-04, 1.108E-03, 2.209E-03, 4.530E-03, 9.290E-03, 1.852E-02, 3.484E-02, 6.040E-02, 9.660E-02, 1.436E-01,
+	1.998E-01, 2.625E-01, 3.281E-01, 3.931E-01, 4.550E-01, 5.130E-01, 5.670E-01, 6.200E-01, 6.760E-01, 7.340E-01,
+	7.930E-01, 8.510E-01, 9.040E-01, 9.490E-01, 9.820E-01, 9.980E-01, 9.970E-01, 9.750E-01, 9.350E-01, 8.800E-01,
+	8.110E-01, 7.330E-01, 6.500E-01, 5.640E-01, 4.810E-01, 4.020E-01, 3.288E-01, 2.639E-01, 2.076E-01, 1.602E-01,
+	1.212E-01, 8.990E-02, 6.550E-02, 4.664E-02, 3.315E-02, 2.312E-02, 1.593E-02, 1.088E-02, 7.370E-03, 4.970E-03,
+	3.335E-03, 2.235E-03, 1.497E-03, 1.005E-03, 6.770E-04, 4.590E-04, 3.129E-04, 2.146E-04, 1.480E-04, 1.026E-04,
+	7.150E-05, 5.010E-05, 3.533E-05, 2.501E-05, 1.780E-05, 1.273E-05, 9.140E-06, 6.600E-06, 4.780E-06, 3.482E-06,
+	2.546E-06, 1.870E-06, 1.379E-06, 1.022E-06, 7.600E-07, 5.670E-07, 4.250E-07, 3.200E-07, 2.410E-07, 1.830E-07,
+	1.390E-07,
+];
+
+#[test]
+fn test_scotopic() {
+	use approx::assert_abs_diff_eq;
+	use crate::illuminants::{Planckian, CieIllD65};
+	use crate::observers::CieObs1931;
+
+	let v = CieScotopic1951::values(&Domain::new(500, 510, crate::NM));
+	assert_abs_diff_eq!(v[5], 0.998, epsilon = 1E-6);
+	assert_abs_diff_eq!(v[10], 0.997, epsilon = 1E-6);
+
+	// S/P ratios for a 2856K blackbody (illuminant A), and for D65
+	let sp = sp_ratio::<CieObs1931, _>(&Planckian::new(2856));
+	assert_abs_diff_eq!(sp[0], 1.41, epsilon = 0.01);
+	let sp = sp_ratio::<CieObs1931, _>(&CieIllD65);
+	assert_abs_diff_eq!(sp[0], 2.47, epsilon = 0.01);
+}
@@ -0,0 +1,284 @@
+/*!
+Photometric quantities from absolutely scaled spectral distributions.
+
+Most spectral distributions in this library are relative: illuminants are typically scaled to a value of 100 at
+560nm, and colorimetric calculations normalize their results to a luminance of 100.
+To calculate absolute photometric quantities, such as an illuminance in lux, a spectral distribution has to be
+scaled in a radiometric unit, for example with data from a calibrated spectroradiometer.
+Here an `AbsoluteSpd` wraps such a spectral distribution, and records its radiometric quantity as a type parameter,
+with data per nanometer, as is common for measurement data:
+
+| Radiometric quantity | Unit | Photometric quantity | Unit |
+|---|---|---|---|
+| `SpectralIrradiance` | W·m<sup>-2</sup>·nm<sup>-1</sup> | illuminance | lx |
+| `SpectralRadiance` | W·sr<sup>-1</sup>·m<sup>-2</sup>·nm<sup>-1</sup> | luminance | cd·m<sup>-2</sup> |
+| `SpectralFlux` | W·nm<sup>-1</sup> | luminous flux | lm |
+| `SpectralIntensity` | W·sr<sup>-1</sup>·nm<sup>-1</sup> | luminous intensity | cd |
+| `SpectralPhotonIrradiance` | photons·s<sup>-1</sup>·m<sup>-2</sup>·nm<sup>-1</sup> | illuminance | lx |
+
+The photometric functions only accept spectral distributions with the matching radiometric quantity, so an
+illuminance can not be calculated from spectral radiance data by mistake.
+An `AbsoluteSpd` reports its radiometric quantity as its `SpectralQuantity`, which is used by the colorimetric
+methods of the `SpectralDistribution` trait: its tristimulus value Y is the photometric quantity of the source.
+
+# Examples
+A spectrally flat irradiance of 1 mW·m<sup>-2</sup>·nm<sup>-1</sup>, from 380 to 780nm.
+```
+use scot::photometry::{illuminance, AbsoluteSpd, SpectralIrradiance};
+use scot::observers::CieObs1931;
+use scot::{DataSpectrumFromSlice, Domain, NM};
+
+let e = [0.001; 401];
+let spd = AbsoluteSpd::<_, SpectralIrradiance>::new(DataSpectrumFromSlice::new(Domain::new(380, 780, NM), &e));
+let ev = illuminance::<CieObs1931, _, _>(&spd);
+println!("{:.1} lx, {:.3} W/m2", ev[0], spd.radiometric()[0]);
+```
+*/
+
+use std::marker::PhantomData;
+
+use nalgebra::{DMatrix, RowDVector};
+
+use crate::illuminants::IlluminantData;
+use crate::observers::{photopic_luminous, radiant, StandardObserver};
+use crate::{Domain, SpectralDistribution, SpectralQuantity, Unit, WavelengthStep};
+
+/// Radiometric quantity of an absolutely scaled spectral distribution, with data per nanometer.
+pub trait Radiometric {
+    /// Quantity tag, as reported by the spectral distribution.
+    const QUANTITY: SpectralQuantity;
+    /// Symbol of the spectral radiometric unit.
+    const SYMBOL: &'static str;
+    /// Symbol of the unit of the wavelength integrated radiometric quantity.
+    const INTEGRATED_SYMBOL: &'static str;
+    /// Symbol of the unit of the corresponding photometric quantity.
+    const PHOTOMETRIC_SYMBOL: &'static str;
+}
+
+/// Spectral irradiance, in W·m<sup>-2</sup>·nm<sup>-1</sup>, with illuminance as photometric quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpectralIrradiance;
+
+impl Radiometric for SpectralIrradiance {
+    const QUANTITY: SpectralQuantity = SpectralQuantity::Irradiance;
+    const SYMBOL: &'static str = "W/m2/nm";
+    const INTEGRATED_SYMBOL: &'static str = "W/m2";
+    const PHOTOMETRIC_SYMBOL: &'static str = "lx";
+}
+
+/// Spectral radiance, in W·sr<sup>-1</sup>·m<sup>-2</sup>·nm<sup>-1</sup>, with luminance as photometric quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpectralRadiance;
+
+impl Radiometric for SpectralRadiance {
+    const QUANTITY: SpectralQuantity = SpectralQuantity::Radiance;
+    const SYMBOL: &'static str = "W/sr/m2/nm";
+    const INTEGRATED_SYMBOL: &'static str = "W/sr/m2";
+    const PHOTOMETRIC_SYMBOL: &'static str = "cd/m2";
+}
+
+/// Spectral radiant flux, in W·nm<sup>-1</sup>, with luminous flux as photometric quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpectralFlux;
+
+impl Radiometric for SpectralFlux {
+    const QUANTITY: SpectralQuantity = SpectralQuantity::Flux;
+    const SYMBOL: &'static str = "W/nm";
+    const INTEGRATED_SYMBOL: &'static str = "W";
+    const PHOTOMETRIC_SYMBOL: &'static str = "lm";
+}
+
+/// Spectral radiant intensity, in W·sr<sup>-1</sup>·nm<sup>-1</sup>, with luminous intensity as photometric quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpectralIntensity;
+
+impl Radiometric for SpectralIntensity {
+    const QUANTITY: SpectralQuantity = SpectralQuantity::Intensity;
+    const SYMBOL: &'static str = "W/sr/nm";
+    const INTEGRATED_SYMBOL: &'static str = "W/sr";
+    const PHOTOMETRIC_SYMBOL: &'static str = "cd";
+}
+
+/// Spectral photon irradiance, in photons·s<sup>-1</sup>·m<sup>-2</sup>·nm<sup>-1</sup>, with illuminance as
+/// photometric quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpectralPhotonIrradiance;
+
+impl Radiometric for SpectralPhotonIrradiance {
+    const QUANTITY: SpectralQuantity = SpectralQuantity::PhotonIrradiance;
+    const SYMBOL: &'static str = "photons/s/m2/nm";
+    const INTEGRATED_SYMBOL: &'static str = "W/m2";
+    const PHOTOMETRIC_SYMBOL: &'static str = "lx";
+}
+
+/// Radiometric quantities of irradiance data, in energy, or photon, units.
+pub trait Irradiance: Radiometric {}
+
+impl Irradiance for SpectralIrradiance {}
+
+impl Irradiance for SpectralPhotonIrradiance {}
+
+/**
+An absolutely scaled spectral distribution, with its radiometric quantity `R`.
+
+The spectral data of the wrapped source is used as is, and is interpreted as data per nanometer, in the unit of `R`.
+*/
+#[derive(Debug, Clone)]
+pub struct AbsoluteSpd<S, R> {
+    source: S,
+    quantity: PhantomData<R>,
+}
+
+impl<S, R> AbsoluteSpd<S, R>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+    R: Radiometric,
+{
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            quantity: PhantomData,
+        }
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Spectral data converted to radiant power per meter wavelength interval, as used in the integrations of the
+    /// observer module, with photon data converted to radiant power.
+    pub fn to_radiant(&self) -> IlluminantData {
+        let (d, s) = self.source.spd();
+        let (n, m) = self.source.shape();
+        let f: Vec<f64> = d
+            .iter()
+            .map(|l| R::QUANTITY.radiant_factor(l.value()))
+            .collect();
+        IlluminantData::new(d, DMatrix::from_fn(n, m, |i, j| s[(i, j)] * f[i]))
+    }
+
+    /// Wavelength integrated radiometric quantities, in the unit `R::INTEGRATED_SYMBOL`, as a row vector.
+    pub fn radiometric(&self) -> RowDVector<f64> {
+        radiant(&self.to_radiant())
+    }
+
+    /// Photometric quantities, in the unit `R::PHOTOMETRIC_SYMBOL`, using the y&#772;(&lambda;) function of observer
+    /// `C` as photopic luminous efficiency function, as a row vector.
+    pub fn photometric<C: StandardObserver>(&self) -> RowDVector<f64> {
+        photopic_luminous::<C, _>(&self.to_radiant())
+    }
+}
+
+impl<S, R> SpectralDistribution for AbsoluteSpd<S, R>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+    R: Radiometric,
+{
+    type MatrixType = S::MatrixType;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        self.source.spd()
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.source.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        self.source.keys()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.source.description()
+    }
+
+    fn quantity(&self) -> SpectralQuantity {
+        R::QUANTITY
+    }
+}
+
+/// Illuminances, in lux, of spectral irradiance, or photon irradiance, distributions.
+pub fn illuminance<C, S, R>(spd: &AbsoluteSpd<S, R>) -> RowDVector<f64>
+where
+    C: StandardObserver,
+    S: SpectralDistribution<StepType = WavelengthStep>,
+    R: Irradiance,
+{
+    spd.photometric::<C>()
+}
+
+/// Luminances, in cd/m<sup>2</sup>, of spectral radiance distributions.
+pub fn luminance<C, S>(spd: &AbsoluteSpd<S, SpectralRadiance>) -> RowDVector<f64>
+where
+    C: StandardObserver,
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    spd.photometric::<C>()
+}
+
+/// Luminous fluxes, in lumen, of spectral radiant flux distributions.
+pub fn luminous_flux<C, S>(spd: &AbsoluteSpd<S, SpectralFlux>) -> RowDVector<f64>
+where
+    C: StandardObserver,
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    spd.photometric::<C>()
+}
+
+/// Luminous intensities, in candela, of spectral radiant intensity distributions.
+pub fn luminous_intensity<C, S>(spd: &AbsoluteSpd<S, SpectralIntensity>) -> RowDVector<f64>
+where
+    C: StandardObserver,
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    spd.photometric::<C>()
+}
+
+#[test]
+fn test_photometry() {
+    use crate::observers::CieObs1931;
+    use crate::{photon_energy, DataSpectrumFromSlice, NM, NM5};
+    use approx::assert_abs_diff_eq;
+
+    // 1 W/m2/nm over the visible domain gives 683 lm/W times the sum of the y-bar values
+    let e = [1.0; 401];
+    let ybar_sum = CieObs1931::values(&Domain::new(380, 780, NM)).row(1).sum();
+    let spd = AbsoluteSpd::<_, SpectralIrradiance>::new(DataSpectrumFromSlice::new(
+        Domain::new(380, 780, NM),
+        &e,
+    ));
+    assert_abs_diff_eq!(
+        illuminance::<CieObs1931, _, _>(&spd)[0],
+        683.0 * ybar_sum,
+        epsilon = 1E-6
+    );
+    assert_abs_diff_eq!(spd.radiometric()[0], 401.0, epsilon = 1E-9);
+
+    // the same data in 5nm steps gives approximately the same results
+    let e5 = [1.0; 81];
+    let spd5 = AbsoluteSpd::<_, SpectralRadiance>::new(DataSpectrumFromSlice::new(
+        Domain::new(76, 156, NM5),
+        &e5,
+    ));
+    assert_abs_diff_eq!(
+        luminance::<CieObs1931, _>(&spd5)[0],
+        683.0 * ybar_sum,
+        epsilon = 10.0
+    );
+    assert_eq!(SpectralRadiance::PHOTOMETRIC_SYMBOL, "cd/m2");
+
+    // photon data: 1 W/m2 at 555nm is 2.794E18 photons/s/m2, or 683 lx
+    let mut p = [0.0; 401];
+    p[175] = 1.0 / photon_energy(555E-9);
+    let spd = AbsoluteSpd::<_, SpectralPhotonIrradiance>::new(DataSpectrumFromSlice::new(
+        Domain::new(380, 780, NM),
+        &p,
+    ));
+    assert_eq!(spd.quantity(), SpectralQuantity::PhotonIrradiance);
+    assert_abs_diff_eq!(spd.radiometric()[0], 1.0, epsilon = 1E-9);
+    let ev = illuminance::<CieObs1931, _, _>(&spd)[0];
+    assert_abs_diff_eq!(ev, 683.0, epsilon = 0.5);
+    let xyz = spd.xyz::<CieObs1931>();
+    assert_abs_diff_eq!(xyz.data[(1, 0)], ev, epsilon = 1E-6);
+}
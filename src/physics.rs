@@ -35,6 +35,17 @@ pub fn planck_c2(l: f64, t: f64, c2: f64) -> f64 {
     C1 / l.powi(5) / ((c2 / (l * t)).exp() - 1.0)
 }
 
+/**
+Wien's approximation of Planck's law, with the second radiant constant as parameter.
+
+Accurate when c2 / (l * t) is large, at short wavelengths, or low temperatures: at 1000K, its relative deviation from
+Planck's law is less than 10<sup>-8</sup> in the visible part of the spectrum.
+*/
+#[inline]
+pub fn wien_c2(l: f64, t: f64, c2: f64) -> f64 {
+    C1 / l.powi(5) * (-c2 / (l * t)).exp()
+}
+
 /**
     Plank's law, differentiated to temperature.
 
@@ -52,13 +63,21 @@ pub fn planck_prime_c2(l: f64, t: f64, c2: f64) -> f64 {
 }
 
 /// The speed of light (m/s)
-const C: f64 = 299792458.0;
+pub(crate) const C: f64 = 299792458.0;
 
 /// Boltzmann constant (m<sup>2</sup> kg s<sup>-2</sup> K<sup>-1</sup>)
 const KB: f64 = 1.3806485279E-23;
 
 /// Planck constant (m<sup>2</sup> kg / s)
-const H: f64 = 6.6260700408181E-34;
+pub(crate) const H: f64 = 6.6260700408181E-34;
+
+/// Avogadro constant (mol<sup>-1</sup>)
+pub const AVOGADRO: f64 = 6.022_140_76E23;
+
+/// Energy of a photon, in Joule, with a wavelength in meter.
+pub fn photon_energy(wl: f64) -> f64 {
+    H * C / wl
+}
 
 /// First radiation constant (W m<sup>2</sup>)
 const C1: f64 = 2. * std::f64::consts::PI * H * C * C;
@@ -80,3 +99,9 @@ pub fn led_ohno(wl: f64, peak: f64, width: f64) -> f64 {
     let g = (-(t.powi(2))).exp();
     (g + 2.0 * g.powi(5)) / 3.0
 }
+
+/// Gaussian spectral distribution, with a peak value of 1.0, and a full width at half maximum `fwhm`.
+pub fn led_gaussian(wl: f64, peak: f64, fwhm: f64) -> f64 {
+    let t = (wl - peak) / fwhm;
+    (-4.0 * std::f64::consts::LN_2 * t * t).exp()
+}
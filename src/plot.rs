@@ -0,0 +1,444 @@
+/*!
+Chromaticity diagrams, as SVG images.
+
+A `ChromaticityDiagram` shows the CIE 1931 xy, or CIE 1976 u′v′, chromaticity diagram of a standard observer, with
+its spectral locus, from 380 to 700nm, with wavelength ticks, closed by the purple line, and, optionally, the
+Planckian locus, with isotemperature lines, the gamut triangles of RGB color spaces, and the chromaticity points of
+samples, or light sources, given as `CieYxy` values. The diagram is rendered as an `svg::Document`, which can be saved
+as a file, or embedded in a web page.
+
+This module requires the `svg` feature.
+
+# Examples
+```
+use scot::illuminants::FL;
+use scot::models::{CieYxy, RgbSpace};
+use scot::observers::CieObs1931;
+use scot::plot::{Chromaticity, ChromaticityDiagram};
+
+let fl: CieYxy<CieObs1931> = CieYxy::from(FL::<2>);
+let doc = ChromaticityDiagram::<CieObs1931>::new(Chromaticity::UvPrime)
+    .set_planckian(&[2700.0, 4000.0, 6500.0])
+    .add_gamut("sRGB", RgbSpace::srgb().primaries, "blue")
+    .add_points(&fl, "black")
+    .document();
+assert!(doc.to_string().starts_with("<svg"));
+// svg::save("diagram.svg", &doc).unwrap();
+```
+*/
+
+use std::marker::PhantomData;
+
+use svg::node::element::path::Data;
+use svg::node::element::{Circle, Element, Line, Path, Rectangle};
+use svg::node::{Node, Text};
+use svg::Document;
+
+use crate::illuminants::{isotherm_lines, planck_xyz};
+use crate::models::CieYxy;
+use crate::observers::StandardObserver;
+use crate::{DefaultObserver, Unit, C2};
+
+/// Wavelength range, in nanometer, of the spectral locus: beyond 700nm its chromaticity hardly changes.
+const LOCUS_RANGE: (f64, f64) = (380.0, 700.0);
+
+/// Default wavelengths, in nanometer, of the spectral locus ticks.
+const TICKS: [f64; 13] = [
+    380.0, 460.0, 470.0, 480.0, 490.0, 500.0, 510.0, 520.0, 540.0, 560.0, 580.0, 600.0, 700.0,
+];
+
+/// Distance to the Planckian locus, in the CIE 1960 uv diagram, of the ends of the isotemperature lines.
+const ISOTHERM_DUV: f64 = 0.02;
+
+/// Margin around the plot area, in pixels, for the axis labels.
+const MARGIN: f64 = 50.0;
+
+/// Chromaticity coordinates of a diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chromaticity {
+    /// CIE 1931 x and y chromaticity coordinates.
+    Xy,
+    /// CIE 1976 u′ and v′ chromaticity coordinates, of the CIELUV color space.
+    UvPrime,
+}
+
+impl Chromaticity {
+    /// Coordinates of a point with CIE 1931 xy chromaticity coordinates.
+    pub fn from_xy(&self, [x, y]: [f64; 2]) -> [f64; 2] {
+        match self {
+            Chromaticity::Xy => [x, y],
+            Chromaticity::UvPrime => {
+                let den = -2.0 * x + 12.0 * y + 3.0;
+                [4.0 * x / den, 9.0 * y / den]
+            }
+        }
+    }
+
+    /// Coordinates of a point with CIE 1960 uv chromaticity coordinates.
+    pub fn from_uv(&self, [u, v]: [f64; 2]) -> [f64; 2] {
+        match self {
+            Chromaticity::Xy => {
+                let den = 2.0 * u - 8.0 * v + 4.0;
+                [3.0 * u / den, 2.0 * v / den]
+            }
+            Chromaticity::UvPrime => [u, 1.5 * v],
+        }
+    }
+
+    /// Upper limits of the axes.
+    fn range(&self) -> [f64; 2] {
+        match self {
+            Chromaticity::Xy => [0.8, 0.9],
+            Chromaticity::UvPrime => [0.7, 0.6],
+        }
+    }
+
+    fn labels(&self) -> [&'static str; 2] {
+        match self {
+            Chromaticity::Xy => ["x", "y"],
+            Chromaticity::UvPrime => ["u′", "v′"],
+        }
+    }
+}
+
+/**
+A chromaticity diagram, for a standard observer `C`, built by setting its options, and adding gamuts, and points,
+and rendered with `document`.
+*/
+#[derive(Debug, Clone)]
+pub struct ChromaticityDiagram<C: StandardObserver = DefaultObserver> {
+    chromaticity: Chromaticity,
+    width: f64,
+    ticks: Vec<f64>,
+    isotherms: Option<Vec<f64>>,
+    gamuts: Vec<(String, [[f64; 2]; 3], String)>,
+    points: Vec<(Vec<[f64; 2]>, String)>,
+    observer: PhantomData<*const C>,
+}
+
+impl<C: StandardObserver> ChromaticityDiagram<C> {
+    pub fn new(chromaticity: Chromaticity) -> Self {
+        Self {
+            chromaticity,
+            width: 600.0,
+            ticks: TICKS.to_vec(),
+            isotherms: None,
+            gamuts: Vec::new(),
+            points: Vec::new(),
+            observer: PhantomData,
+        }
+    }
+
+    /// Sets the width of the image, in pixels, with a default of 600; its height follows from the axis ranges.
+    pub fn set_width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the wavelengths, in nanometer, of the ticks on the spectral locus.
+    pub fn set_wavelength_ticks(mut self, wavelengths: &[f64]) -> Self {
+        self.ticks = wavelengths.to_vec();
+        self
+    }
+
+    /**
+    Shows the Planckian locus, from 1000K to 100 000K, with isotemperature lines, from a Duv value of -0.02 to 0.02,
+    for the temperatures, in Kelvin.
+    */
+    pub fn set_planckian(mut self, isotherms: &[f64]) -> Self {
+        self.isotherms = Some(isotherms.to_vec());
+        self
+    }
+
+    /// Adds the gamut triangle of an RGB color space, with the xy chromaticity coordinates of its primaries.
+    pub fn add_gamut(mut self, name: &str, primaries: [[f64; 2]; 3], color: &str) -> Self {
+        self.gamuts
+            .push((name.to_string(), primaries, color.to_string()));
+        self
+    }
+
+    /// Adds chromaticity points, drawn as dots.
+    pub fn add_points(mut self, yxy: &CieYxy<C>, color: &str) -> Self {
+        let xy = yxy.data.column_iter().map(|c| [c.y, c.z]).collect();
+        self.points.push((xy, color.to_string()));
+        self
+    }
+
+    /// Scale, in pixels for a unit of the chromaticity coordinates.
+    fn scale(&self) -> f64 {
+        (self.width - 2.0 * MARGIN) / self.chromaticity.range()[0]
+    }
+
+    /// Position in the image of a point with xy chromaticity coordinates.
+    fn position(&self, xy: [f64; 2]) -> (f64, f64) {
+        let [a, b] = self.chromaticity.from_xy(xy);
+        self.pixels([a, b])
+    }
+
+    /// Position in the image of a point with chromaticity coordinates of the diagram.
+    fn pixels(&self, [a, b]: [f64; 2]) -> (f64, f64) {
+        let s = self.scale();
+        (
+            MARGIN + s * a,
+            MARGIN + s * (self.chromaticity.range()[1] - b),
+        )
+    }
+
+    /// Chromaticity coordinates of the spectral locus, with a point for each wavelength of the observer's domain.
+    fn spectral_locus(&self) -> Vec<(f64, [f64; 2])> {
+        let cmf = C::cmf();
+        C::domain()
+            .iter()
+            .zip(cmf.column_iter())
+            .map(|(l, c)| (l.value() * 1E9, c))
+            .filter(|(l, _)| *l >= LOCUS_RANGE.0 - 1E-6 && *l <= LOCUS_RANGE.1 + 1E-6)
+            .map(|(l, c)| {
+                let s = c.sum();
+                (l, self.chromaticity.from_xy([c.x / s, c.y / s]))
+            })
+            .collect()
+    }
+
+    /// The diagram, as an SVG document.
+    pub fn document(&self) -> Document {
+        let [ra, rb] = self.chromaticity.range();
+        let s = self.scale();
+        let height = 2.0 * MARGIN + s * rb;
+        let mut doc = Document::new()
+            .set("viewBox", (0, 0, self.width, height))
+            .set("width", self.width)
+            .set("height", height)
+            .add(
+                Rectangle::new()
+                    .set("width", self.width)
+                    .set("height", height)
+                    .set("fill", "white"),
+            );
+
+        // grid, and axes, with a line for each 0.1 step
+        let [la, lb] = self.chromaticity.labels();
+        for i in 0..=(ra * 10.0).round() as usize {
+            let a = i as f64 / 10.0;
+            let (x0, y0) = self.pixels([a, 0.0]);
+            let (x1, y1) = self.pixels([a, rb]);
+            doc = doc.add(line((x0, y0), (x1, y1), "#d0d0d0", 1.0)).add(text(
+                x0,
+                y0 + 20.0,
+                "middle",
+                14.0,
+                format!("{:.1}", a),
+            ));
+        }
+        for i in 0..=(rb * 10.0).round() as usize {
+            let b = i as f64 / 10.0;
+            let (x0, y0) = self.pixels([0.0, b]);
+            let (x1, y1) = self.pixels([ra, b]);
+            doc = doc.add(line((x0, y0), (x1, y1), "#d0d0d0", 1.0)).add(text(
+                x0 - 8.0,
+                y0 + 5.0,
+                "end",
+                14.0,
+                format!("{:.1}", b),
+            ));
+        }
+        doc = doc
+            .add(text(
+                self.width - MARGIN,
+                height - 8.0,
+                "end",
+                18.0,
+                la.to_string(),
+            ))
+            .add(text(8.0, MARGIN - 12.0, "start", 18.0, lb.to_string()));
+
+        // spectral locus, closed by the purple line
+        let locus = self.spectral_locus();
+        let mut data = Data::new().move_to(self.pixels(locus[0].1));
+        for &(_, p) in &locus[1..] {
+            data = data.line_to(self.pixels(p));
+        }
+        doc = doc.add(
+            Path::new()
+                .set("d", data)
+                .set("fill", "none")
+                .set("stroke", "black")
+                .set("stroke-width", 2),
+        );
+        doc = doc.add(
+            line(
+                self.pixels(locus[0].1),
+                self.pixels(locus[locus.len() - 1].1),
+                "purple",
+                2.0,
+            )
+            .set("stroke-dasharray", "6,4"),
+        );
+
+        // wavelength ticks, pointing outwards, away from the equal energy point
+        let e = self.chromaticity.from_xy([1.0 / 3.0, 1.0 / 3.0]);
+        for &w in &self.ticks {
+            let i = match locus.iter().position(|(l, _)| (l - w).abs() < 0.5) {
+                Some(i) => i,
+                None => continue,
+            };
+            let p = locus[i].1;
+            let (p0, p1) = (
+                locus[i.saturating_sub(1)].1,
+                locus[(i + 1).min(locus.len() - 1)].1,
+            );
+            let (ta, tb) = (p1[0] - p0[0], p1[1] - p0[1]);
+            let len = ta.hypot(tb);
+            let (mut na, mut nb) = if len > 0.0 {
+                (tb / len, -ta / len)
+            } else {
+                let d = (p[0] - e[0]).hypot(p[1] - e[1]);
+                ((p[0] - e[0]) / d, (p[1] - e[1]) / d)
+            };
+            if na * (p[0] - e[0]) + nb * (p[1] - e[1]) < 0.0 {
+                na = -na;
+                nb = -nb;
+            }
+            let (x0, y0) = self.pixels(p);
+            // pixel y values increase downwards
+            let (dx, dy) = (na, -nb);
+            doc = doc
+                .add(line((x0, y0), (x0 + 8.0 * dx, y0 + 8.0 * dy), "black", 1.5))
+                .add(text(
+                    x0 + 22.0 * dx,
+                    y0 + 22.0 * dy + 4.0,
+                    "middle",
+                    12.0,
+                    format!("{:.0}", w),
+                ));
+        }
+
+        // Planckian locus, with isotemperature lines
+        if let Some(isotherms) = &self.isotherms {
+            let mut data = Data::new();
+            for (i, mired) in (10..=1000).rev().step_by(5).enumerate() {
+                let [x, y, z] = planck_xyz::<C>(1E6 / mired as f64, C2);
+                let p = self.position([x / (x + y + z), y / (x + y + z)]);
+                data = if i == 0 {
+                    data.move_to(p)
+                } else {
+                    data.line_to(p)
+                };
+            }
+            doc = doc.add(
+                Path::new()
+                    .set("d", data)
+                    .set("fill", "none")
+                    .set("stroke", "#404040")
+                    .set("stroke-width", 1.5),
+            );
+            for ([uv0, uv1], t) in isotherm_lines::<C>(isotherms, ISOTHERM_DUV)
+                .into_iter()
+                .zip(isotherms)
+            {
+                let (x0, y0) = self.pixels(self.chromaticity.from_uv(uv0));
+                let (x1, y1) = self.pixels(self.chromaticity.from_uv(uv1));
+                doc = doc.add(line((x0, y0), (x1, y1), "#404040", 1.0)).add(text(
+                    x1,
+                    y1 - 4.0,
+                    "middle",
+                    11.0,
+                    format!("{:.0}K", t),
+                ));
+            }
+        }
+
+        // gamut triangles, with a legend in the upper right corner
+        for (i, (name, primaries, color)) in self.gamuts.iter().enumerate() {
+            let mut data = Data::new().move_to(self.position(primaries[0]));
+            for &p in &primaries[1..] {
+                data = data.line_to(self.position(p));
+            }
+            doc = doc.add(
+                Path::new()
+                    .set("d", data.close())
+                    .set("fill", "none")
+                    .set("stroke", color.as_str())
+                    .set("stroke-width", 2),
+            );
+            let (x, y) = (self.width - MARGIN - 120.0, MARGIN + 10.0 + 22.0 * i as f64);
+            doc = doc.add(line((x, y), (x + 30.0, y), color, 2.0)).add(text(
+                x + 38.0,
+                y + 5.0,
+                "start",
+                14.0,
+                name.clone(),
+            ));
+        }
+
+        // chromaticity points
+        for (points, color) in &self.points {
+            for &p in points {
+                let (cx, cy) = self.position(p);
+                doc = doc.add(
+                    Circle::new()
+                        .set("cx", cx)
+                        .set("cy", cy)
+                        .set("r", 3)
+                        .set("fill", color.as_str()),
+                );
+            }
+        }
+        doc
+    }
+}
+
+fn line(from: (f64, f64), to: (f64, f64), color: &str, width: f64) -> Line {
+    Line::new()
+        .set("x1", from.0)
+        .set("y1", from.1)
+        .set("x2", to.0)
+        .set("y2", to.1)
+        .set("stroke", color)
+        .set("stroke-width", width)
+}
+
+fn text(x: f64, y: f64, anchor: &str, size: f64, content: String) -> Element {
+    let mut e = Element::new("text");
+    e.assign("x", x);
+    e.assign("y", y);
+    e.assign("text-anchor", anchor);
+    e.assign("font-family", "Arial, Helvetica, sans-serif");
+    e.assign("font-size", size);
+    e.append(Text::new(content));
+    e
+}
+
+#[test]
+fn test_chromaticity_diagram() {
+    use crate::illuminants::CieIllD65;
+    use crate::models::RgbSpace;
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    let uv = Chromaticity::UvPrime.from_xy([0.3127, 0.3290]);
+    assert_abs_diff_eq!(uv[0], 0.1978, epsilon = 1E-4);
+    assert_abs_diff_eq!(uv[1], 0.4683, epsilon = 1E-4);
+    let xy = Chromaticity::Xy.from_uv([uv[0], uv[1] / 1.5]);
+    assert_abs_diff_eq!(xy[0], 0.3127, epsilon = 1E-12);
+    assert_abs_diff_eq!(xy[1], 0.3290, epsilon = 1E-12);
+
+    let d65: CieYxy<CieObs1931> = CieYxy::from(CieIllD65);
+    let diagram = ChromaticityDiagram::<CieObs1931>::new(Chromaticity::Xy)
+        .set_planckian(&[3000.0, 6500.0])
+        .add_gamut("sRGB", RgbSpace::srgb().primaries, "red")
+        .add_gamut("Display P3", RgbSpace::display_p3().primaries, "green")
+        .add_points(&d65, "black");
+    let svg = diagram.document().to_string();
+    // spectral locus, Planckian locus, and gamut triangles
+    assert_eq!(svg.matches("<path").count(), 4);
+    assert_eq!(svg.matches("<circle").count(), 1);
+    assert!(svg.contains(">6500K<"));
+    assert!(svg.contains(">520<"));
+    assert!(svg.contains(">Display P3<"));
+
+    // the spectral locus at 520nm, near the top of the diagram
+    let locus = diagram.spectral_locus();
+    let (_, [x, y]) = locus.iter().find(|(l, _)| (l - 520.0).abs() < 0.5).unwrap();
+    assert_abs_diff_eq!(*x, 0.0743, epsilon = 1E-4);
+    assert_abs_diff_eq!(*y, 0.8338, epsilon = 1E-4);
+}
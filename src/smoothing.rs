@@ -0,0 +1,213 @@
+/*!
+Smoothing of noisy spectral data.
+
+Spectra captured with compact spectrometers often show noise, in particular at the ends of their wavelength range,
+where the sensitivity of their detectors is low. This noise can be reduced before colorimetric evaluation, with two
+smoothing filters, applied to each of the spectral distributions in a collection:
+
+- `savitzky_golay`, which fits a polynomial to a moving window of data points, and retains the height, and width, of
+  spectral peaks better than a moving average;
+- `gaussian`, a weighted moving average, with a Gaussian kernel, with its width given as a standard deviation in
+  nanometer, similar to the blurring resulting from the finite bandwidth of a spectrometer.
+
+Both return a `DynamicSpectralDistribution` on the domain of the original data, with its keys, description, and
+quantity. Smoothing broadens the narrow emission lines of discharge lamps, and is best used for continuous spectra,
+such as surface reflectance spectra, and the spectra of incandescent lamps, and white LEDs.
+
+The inverse operation, a correction for the blurring by the triangular bandpass of a spectrometer, is provided by
+`bandpass_correction`.
+
+# Examples
+```
+use scot::illuminants::CieIllD65;
+use scot::smoothing::savitzky_golay;
+
+let smooth = savitzky_golay(&CieIllD65, 7, 2);
+```
+*/
+
+use nalgebra::DMatrix;
+
+use crate::{DynamicSpectralDistribution, SpectralDistribution, Step, Unit, WavelengthStep};
+
+/**
+Savitzky–Golay smoothing, with a window of `window` data points, which should be an odd number, and a fitting
+polynomial of order `order`.
+
+At both ends of the domain, the values of the polynomial fitted to the first, or last, window are used.
+*/
+pub fn savitzky_golay<S>(sd: &S, window: usize, order: usize) -> DynamicSpectralDistribution
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    assert!(window % 2 == 1, "window size should be an odd number");
+    assert!(
+        order < window,
+        "polynomial order should be less than the window size"
+    );
+    let mut smooth = sd.to_dynamic();
+    let (n, m) = smooth.shape();
+    if n < window {
+        return smooth;
+    }
+    let h = window / 2;
+
+    // least squares polynomial fit coefficients, for the data points in a window, centered at zero
+    let a = DMatrix::from_fn(window, order + 1, |i, j| {
+        (i as f64 - h as f64).powi(j as i32)
+    });
+    let at = a.transpose();
+    let c = (&at * &a).try_inverse().unwrap() * at;
+    let weights =
+        |t: f64| (0..=order).fold(c.row(0) * 0.0, |acc, j| acc + c.row(j) * t.powi(j as i32));
+
+    let center = weights(0.0);
+    let data = smooth.data().clone();
+    for i in 0..n {
+        let (start, w) = match i {
+            i if i < h => (0, weights(i as f64 - h as f64)),
+            i if i >= n - h => (n - window, weights((i + window - n) as f64 - h as f64)),
+            i => (i - h, center.clone()),
+        };
+        for j in 0..m {
+            smooth.data_mut()[(i, j)] = (0..window).map(|k| w[k] * data[(start + k, j)]).sum();
+        }
+    }
+    smooth
+}
+
+/**
+Gaussian kernel smoothing, with a kernel width given by its standard deviation, in nanometer.
+
+The kernel is truncated at four standard deviations, and normalized over the data points within the domain, to keep
+the values at the ends of the domain unbiased. A standard deviation of zero, or less, returns the original data.
+*/
+pub fn gaussian<S>(sd: &S, sigma: f64) -> DynamicSpectralDistribution
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    assert!(sigma.is_finite(), "standard deviation should be a finite number");
+    let mut smooth = sd.to_dynamic();
+    let (n, m) = smooth.shape();
+    let step = smooth.domain().step.unitvalue(1).value() * 1E9;
+    let s = sigma / step; // in units of the domain step
+    if s <= 0.0 {
+        return smooth;
+    }
+    let h = (4.0 * s).ceil() as usize;
+    let kernel: Vec<f64> = (0..=h)
+        .map(|k| (-0.5 * (k as f64 / s).powi(2)).exp())
+        .collect();
+
+    let data = smooth.data().clone();
+    for i in 0..n {
+        let (lo, hi) = (i.saturating_sub(h), (i + h).min(n - 1));
+        let norm: f64 = (lo..=hi).map(|k| kernel[k.abs_diff(i)]).sum();
+        for j in 0..m {
+            smooth.data_mut()[(i, j)] = (lo..=hi)
+                .map(|k| kernel[k.abs_diff(i)] * data[(k, j)])
+                .sum::<f64>()
+                / norm;
+        }
+    }
+    smooth
+}
+
+/**
+Stearns–Stearns bandpass correction, for spectral data measured with a triangular bandpass, with a bandwidth equal to
+the measurement interval, as recommended by ASTM E308 for 10nm data, before the calculation of tristimulus values.
+
+The corrected values are calculated as R'<sub>i</sub> = -&alpha;R<sub>i-1</sub> + (1 + 2&alpha;)R<sub>i</sub>
+-&alpha;R<sub>i+1</sub>, with &alpha; = 0.083, and R'<sub>1</sub> = (1 + &alpha;)R<sub>1</sub> - &alpha;R<sub>2</sub>,
+and R'<sub>n</sub> = (1 + &alpha;)R<sub>n</sub> - &alpha;R<sub>n-1</sub>, at the ends of the domain.
+
+See "Spectrophotometric Data Correction for Bandwidth", E.I. Stearns, and R.E. Stearns, Color Research and
+Application, volume 13, no 4, 1988.
+*/
+pub fn bandpass_correction<S>(sd: &S) -> DynamicSpectralDistribution
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    const ALPHA: f64 = 0.083;
+    let mut corrected = sd.to_dynamic();
+    let (n, m) = corrected.shape();
+    if n < 2 {
+        return corrected;
+    }
+    let data = corrected.data().clone();
+    for j in 0..m {
+        corrected.data_mut()[(0, j)] = (1.0 + ALPHA) * data[(0, j)] - ALPHA * data[(1, j)];
+        for i in 1..n - 1 {
+            corrected.data_mut()[(i, j)] = -ALPHA * data[(i - 1, j)]
+                + (1.0 + 2.0 * ALPHA) * data[(i, j)]
+                - ALPHA * data[(i + 1, j)];
+        }
+        corrected.data_mut()[(n - 1, j)] =
+            (1.0 + ALPHA) * data[(n - 1, j)] - ALPHA * data[(n - 2, j)];
+    }
+    corrected
+}
+
+#[test]
+fn test_smoothing() {
+    use crate::illuminants::CieIllA;
+    use crate::{Domain, NM};
+    use approx::assert_abs_diff_eq;
+
+    // polynomials up to the fit order, and constants, are not changed
+    let d = Domain::new(380, 780, NM);
+    let poly = DMatrix::from_fn(d.len(), 2, |i, j| {
+        let x = i as f64 / 100.0;
+        if j == 0 {
+            1.0 + x - 0.5 * x * x
+        } else {
+            0.5
+        }
+    });
+    let sd = DynamicSpectralDistribution::new(d, poly.clone()).set_keys(&["poly", "flat"]);
+    let sg = savitzky_golay(&sd, 11, 2);
+    assert_abs_diff_eq!(sg.data(), &poly, epsilon = 1E-9);
+    assert_eq!(sg.keys(), sd.keys());
+    let g = gaussian(&sd, 5.0);
+    assert_abs_diff_eq!(g.data().column(1), poly.column(1), epsilon = 1E-12);
+
+    // noise is reduced
+    let a = CieIllA.to_dynamic();
+    let (n, _) = a.shape();
+    let noise = DMatrix::from_fn(n, 1, |i, _| if i % 2 == 0 { 1.0 } else { -1.0 });
+    let noisy = DynamicSpectralDistribution::new(a.domain().clone(), a.data() + &noise);
+    for smooth in &[savitzky_golay(&noisy, 5, 2), gaussian(&noisy, 3.0)] {
+        let residual = smooth.data() - a.data();
+        assert!(residual.rows(10, n - 20).amax() < 0.5);
+    }
+}
+
+#[test]
+fn test_bandpass_correction() {
+    use crate::{Domain, NM10};
+    use approx::assert_abs_diff_eq;
+
+    // linear spectra are not changed, and peaks are sharpened
+    let d = Domain::new(38, 78, NM10);
+    let sd = DynamicSpectralDistribution::new(
+        d,
+        DMatrix::from_fn(41, 2, |i, j| {
+            if j == 0 {
+                0.1 + 0.01 * i as f64
+            } else if i == 20 {
+                1.0
+            } else {
+                0.0
+            }
+        }),
+    );
+    let c = bandpass_correction(&sd);
+    assert_abs_diff_eq!(
+        c.data().column(0).rows(1, 39),
+        sd.data().column(0).rows(1, 39),
+        epsilon = 1E-12
+    );
+    assert_abs_diff_eq!(c.data()[(0, 0)], 0.1 - 0.083 * 0.01, epsilon = 1E-12);
+    assert_abs_diff_eq!(c.data()[(20, 1)], 1.166, epsilon = 1E-12);
+    assert_abs_diff_eq!(c.data()[(19, 1)], -0.083, epsilon = 1E-12);
+}
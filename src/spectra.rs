@@ -81,13 +81,15 @@ or can be originating from spectrometer measurements.
 - [ ] map_domain -> interpolate, producing a generic DataSpectra type
 
  */
-use std::ops::{Index, Mul};
+use std::ops::{Index, Mul, RangeInclusive};
 
 use crate::{
-    lin_interp_mat_col, models::CieXYZ, observers::StandardObserver, Domain, Meter, Step, Unit,
-    WavelengthStep,
+    band_average_mat_col, filters::Filtered, illuminants::Illuminant, is_peaky, lin_interp_mat_col,
+    models::CieXYZ, observers::StandardObserver, pchip_interp_mat_col, photon_energy,
+    spline_interp_mat_col, sprague_cols_index_based, DefaultObserver, Domain,
+    DynamicSpectralDistribution, Interp, Meter, Step, Unit, WavelengthStep,
 };
-use nalgebra::{DMatrix, DVectorSlice, Matrix3xX};
+use nalgebra::{DMatrix, DVector, DVectorSlice, Matrix3xX};
 
 pub trait SpectralDistribution {
     // type ValueType: num::ToPrimitive = f64;  // mag waarschijnlijk niet hier...
@@ -111,6 +113,243 @@ pub trait SpectralDistribution {
         None
     }
 
+    /// Quantity, and unit, of the spectral values, `SpectralQuantity::Relative` unless overridden.
+    fn quantity(&self) -> SpectralQuantity {
+        SpectralQuantity::Relative
+    }
+
+    /// Preferred interpolation method for `Interp::Auto`, to be overridden by types which are known to be peaky, or
+    /// smooth; `Interp::Auto` selects a method based on the spectral data, using `is_peaky`.
+    fn interpolation(&self) -> Interp {
+        Interp::Auto
+    }
+
+    /// Spectral distributions multiplied by the transmission spectra of a filter.
+    fn filtered<F>(self, filter: F) -> Filtered<Self, F>
+    where
+        Self: Sized,
+    {
+        Filtered::new(self, filter)
+    }
+
+    /// Spectral distributions scaled to a value of 1.0 at a wavelength, in nanometer, using linear interpolation.
+    fn normalize_at(self, wavelength: f64) -> Normalized<Self>
+    where
+        Self: Sized,
+    {
+        Normalized::new(self, Normalization::At(wavelength))
+    }
+
+    /// Spectral distributions scaled to a luminance, or tristimulus Y value, for the `DefaultObserver`.
+    fn normalize_luminance(self, y: f64) -> Normalized<Self>
+    where
+        Self: Sized,
+    {
+        Normalized::new(self, Normalization::Luminance(y))
+    }
+
+    /// Spectral distributions scaled to a maximum value of 1.0.
+    fn normalize_max(self) -> Normalized<Self>
+    where
+        Self: Sized,
+    {
+        Normalized::new(self, Normalization::Max)
+    }
+
+    /// Copy of the spectral distributions, as a `DynamicSpectralDistribution`, for example to use in arithmetic.
+    fn to_dynamic(&self) -> DynamicSpectralDistribution
+    where
+        Self: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        DynamicSpectralDistribution::from_spd(self)
+    }
+
+    /**
+    Spectral distributions interpolated to another wavelength domain, using a selectable interpolation method, or
+    `Interp::Auto`, which uses linear interpolation for peaky spectra, and Sprague interpolation otherwise, with
+    values of zero outside the domain of the original data. Keys, description, and quantity, are retained.
+
+    # Examples
+    ```
+    use scot::illuminants::CieIllD65;
+    use scot::{Domain, Interp, SpectralDistribution, NM};
+
+    let d65 = CieIllD65.resample(&Domain::new(380, 780, NM), Interp::Auto);
+    assert_eq!(d65.shape(), (401, 1));
+    ```
+    */
+    fn resample(&self, to: &Domain<WavelengthStep>, method: Interp) -> DynamicSpectralDistribution
+    where
+        Self: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let (d, s) = self.spd();
+        let nc = self.shape().1;
+        let method = match (method, self.interpolation()) {
+            (Interp::Auto, Interp::Auto) if is_peaky(d.len(), nc, &s) => Interp::Linear,
+            (Interp::Auto, Interp::Auto) => Interp::Sprague,
+            (Interp::Auto, hint) => hint,
+            (method, _) => method,
+        };
+        let data = match method {
+            Interp::Linear | Interp::Auto => lin_interp_mat_col(&d, to, nc, s),
+            Interp::Sprague => sprague_cols_index_based(&d, to, s, nc),
+            Interp::Spline => spline_interp_mat_col(&d, to, nc, s),
+            Interp::Pchip => pchip_interp_mat_col(&d, to, nc, s),
+        };
+        DynamicSpectralDistribution::with_attributes_of(to.clone(), data, self)
+    }
+
+    /**
+    Spectral distributions downsampled to a coarser wavelength domain, by averaging the data over bands centered at
+    its wavelengths, with a width equal to its step size, rather than by sampling them at these wavelengths. This
+    preserves integrals, such as tristimulus values, for example when converting 1nm data to 5, or 10nm tables.
+    Keys, description, and quantity, are retained.
+    */
+    fn downsample(&self, to: &Domain<WavelengthStep>) -> DynamicSpectralDistribution
+    where
+        Self: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let (d, s) = self.spd();
+        let data = band_average_mat_col(&d, to, self.shape().1, s);
+        DynamicSpectralDistribution::with_attributes_of(to.clone(), data, self)
+    }
+
+    /**
+    Spectral distributions restricted to a wavelength range, in nanometer, for example to remove noisy data at the
+    ends of the range of a spectrometer, with keys, description, and quantity, retained.
+
+    # Examples
+    ```
+    use scot::illuminants::CieIllA;
+    use scot::SpectralDistribution;
+
+    let a = CieIllA.slice(400.0..=700.0);
+    assert_eq!(a.shape(), (301, 1));
+    ```
+    */
+    fn slice(&self, range: RangeInclusive<f64>) -> DynamicSpectralDistribution
+    where
+        Self: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let (d, s) = self.spd();
+        let inside: Vec<usize> = d
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| {
+                let l = l.value() * 1E9;
+                l >= range.start() - 1E-6 && l <= range.end() + 1E-6
+            })
+            .map(|(i, _)| i)
+            .collect();
+        let (first, n) = (inside.first().copied().unwrap_or(0), inside.len());
+        let start = d.range.start + first as i32;
+        let data = DMatrix::from_fn(n, self.shape().1, |i, j| s[(first + i, j)]);
+        DynamicSpectralDistribution::with_attributes_of(
+            Domain::new(start, start + n as i32 - 1, d.step),
+            data,
+            self,
+        )
+    }
+
+    /**
+    Iterator over the wavelengths, in nanometer, and values, of a spectral distribution in the collection, for
+    example for plotting, or export.
+
+    # Examples
+    ```
+    use scot::illuminants::CieIllD65;
+    use scot::SpectralDistribution;
+
+    for (wavelength, value) in CieIllD65.iter_points(0).step_by(10) {
+        println!("{:.0} {:.2}", wavelength, value);
+    }
+    ```
+    */
+    fn iter_points(&self, col: usize) -> std::vec::IntoIter<(f64, f64)>
+    where
+        Self: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        assert!(col < self.shape().1, "column index out of range");
+        let (d, s) = self.spd();
+        d.iter()
+            .enumerate()
+            .map(|(i, l)| (l.value() * 1E9, s[(i, col)]))
+            .collect::<Vec<(f64, f64)>>()
+            .into_iter()
+    }
+
+    /**
+    Compact, single line, summary of a collection, with its description, its number of spectral distributions, its
+    domain, and its keys, for use in logs, and error messages.
+
+    # Examples
+    ```
+    use scot::illuminants::CieIllD65;
+    use scot::SpectralDistribution;
+
+    println!("{}", CieIllD65.summary());
+    ```
+    */
+    fn summary(&self) -> String {
+        const MAX_KEYS: usize = 5;
+        let (d, _) = self.spd();
+        let m = self.shape().1;
+        let mut s = match self.description() {
+            Some(description) => format!("{}: ", description),
+            None => String::new(),
+        };
+        s.push_str(&format!(
+            "{} {} on {}",
+            m,
+            if m == 1 { "spectrum" } else { "spectra" },
+            d
+        ));
+        if let Some(keys) = self.keys() {
+            s.push_str(&format!(
+                ", keys: {}",
+                keys[..keys.len().min(MAX_KEYS)].join(", ")
+            ));
+            if keys.len() > MAX_KEYS {
+                s.push_str(&format!(", … (+{} more)", keys.len() - MAX_KEYS));
+            }
+        }
+        s
+    }
+
+    /// Values of the spectral distribution with a key, for keyed collections.
+    fn column_by_key(&self, key: &str) -> Result<DVector<f64>, KeyError> {
+        let j = key_index(self.keys(), key)?;
+        let (d, s) = self.spd();
+        Ok(DVector::from_fn(d.len(), |i, _| s[(i, j)]))
+    }
+
+    /**
+    Spectral distributions with a selection of keys, in the order of the selection, for keyed collections.
+
+    # Examples
+    ```
+    use scot::illuminants::FL;
+    use scot::SpectralDistribution;
+
+    let fl = FL::<2>.to_dynamic().set_keys(&["F2"]);
+    assert!(fl.select(&["F2"]).is_ok());
+    assert!(fl.select(&["F12"]).is_err());
+    ```
+    */
+    fn select(&self, keys: &[&str]) -> Result<DynamicSpectralDistribution, KeyError>
+    where
+        Self: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let all = self.keys();
+        let columns = keys
+            .iter()
+            .map(|key| key_index(all.clone(), key))
+            .collect::<Result<Vec<usize>, KeyError>>()?;
+        let (d, s) = self.spd();
+        let data = DMatrix::from_fn(d.len(), columns.len(), |i, j| s[(i, columns[j])]);
+        Ok(DynamicSpectralDistribution::with_attributes_of(d, data, self).set_keys(keys))
+    }
+
     fn map_domain<S2: Step>(&self, dto: Domain<S2>) -> DMatrix<f64>
     where
         <<Self as SpectralDistribution>::StepType as Step>::UnitValueType:
@@ -131,8 +370,89 @@ pub trait SpectralDistribution {
         CieXYZ<C>: From< <<Matrix3xX<f64> as Mul<<Self as SpectralDistribution>::MatrixType>>::Output as Mul< f64, >>::Output, >,
     {
         let (d, s) = self.spd();
-        let xyz = (C::values(&d) * s) * (C::K * C::domain().step.unitvalue(1).value());
-        CieXYZ::<C>::from(xyz) // xyz can be an static matrix here too.
+        let mut cmf = C::values_cached(&d).as_ref().clone();
+        let q = self.quantity();
+        if q != SpectralQuantity::Relative {
+            for (mut c, l) in cmf.column_iter_mut().zip(d.iter()) {
+                c *= q.radiant_factor(Meter::from(l).value());
+            }
+        }
+        let xyz = (cmf * s) * (C::K * d.step.unitvalue(1).value());
+        CieXYZ::<C>::from(xyz).with_keys(self.keys()) // xyz can be an static matrix here too.
+    }
+}
+
+/// Error returned when a key is not found in a keyed collection of spectral distributions, or when a collection has no
+/// keys.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyError {
+    pub key: String,
+}
+
+impl std::fmt::Display for KeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "spectral distribution with key \"{}\" not found",
+            self.key
+        )
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+pub(crate) fn key_index(keys: Option<Vec<String>>, key: &str) -> Result<usize, KeyError> {
+    keys.and_then(|k| k.iter().position(|k| k == key))
+        .ok_or_else(|| KeyError {
+            key: key.to_string(),
+        })
+}
+
+/**
+Quantity, and unit, of the values of a spectral distribution.
+
+Most spectral distributions in this library are relative, and are used as such in colorimetric calculations.
+Absolute spectral data, typically from measurements, is tagged with its quantity, with values per nanometer, so that
+integrations over wavelength, such as in `SpectralDistribution::xyz`, scale the data correctly: with spectral
+irradiance data, for example, a tristimulus value Y is an illuminance in lux, also if the data is given as a photon
+irradiance.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpectralQuantity {
+    /// Relative values, without unit.
+    Relative,
+    /// Spectral irradiance, in W·m<sup>-2</sup>·nm<sup>-1</sup>.
+    Irradiance,
+    /// Spectral radiance, in W·sr<sup>-1</sup>·m<sup>-2</sup>·nm<sup>-1</sup>.
+    Radiance,
+    /// Spectral radiant flux, in W·nm<sup>-1</sup>.
+    Flux,
+    /// Spectral radiant intensity, in W·sr<sup>-1</sup>·nm<sup>-1</sup>.
+    Intensity,
+    /// Spectral photon irradiance, in photons·s<sup>-1</sup>·m<sup>-2</sup>·nm<sup>-1</sup>.
+    PhotonIrradiance,
+}
+
+impl SpectralQuantity {
+    pub fn is_absolute(&self) -> bool {
+        *self != SpectralQuantity::Relative
+    }
+
+    pub fn is_photon(&self) -> bool {
+        *self == SpectralQuantity::PhotonIrradiance
+    }
+
+    /**
+    Factor to convert a spectral value, at a wavelength in meter, to a radiant power value per meter wavelength
+    interval, as assumed in the integrations of this library; relative values are used as they are.
+    */
+    pub fn radiant_factor(&self, wavelength: f64) -> f64 {
+        match self {
+            SpectralQuantity::Relative => 1.0,
+            SpectralQuantity::PhotonIrradiance => 1E9 * photon_energy(wavelength),
+            _ => 1E9,
+        }
     }
 }
 
@@ -165,3 +485,314 @@ impl<'a> SpectralDistribution for DataSpectrumFromSlice<'a> {
         (self.d.len(), 1)
     }
 }
+
+/// Normalization methods, as used by `Normalized`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Normalization {
+    /// A value of 1.0 at a wavelength, in nanometer.
+    At(f64),
+    /// A tristimulus Y value, for the `DefaultObserver`.
+    Luminance(f64),
+    /// A maximum value of 1.0.
+    Max,
+}
+
+/**
+Spectral distributions scaled with a `Normalization` method, as produced by the `normalize_at`, `normalize_luminance`,
+and `normalize_max` methods of the `SpectralDistribution` trait.
+
+Each of the distributions in a collection is scaled independently. Distributions with a value of zero at the
+normalization wavelength, or with zero luminance, are left unchanged.
+
+# Examples
+```
+use scot::illuminants::CieIllA;
+use scot::SpectralDistribution;
+
+let (_, v) = CieIllA.normalize_at(560.0).spd();
+assert!((v[(260, 0)] - 1.0).abs() < 1E-12);
+let (_, v) = CieIllA.normalize_max().spd();
+assert_eq!(v.max(), 1.0);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct Normalized<S> {
+    source: S,
+    normalization: Normalization,
+}
+
+impl<S> Normalized<S> {
+    pub fn new(source: S, normalization: Normalization) -> Self {
+        Self {
+            source,
+            normalization,
+        }
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    pub fn normalization(&self) -> Normalization {
+        self.normalization
+    }
+}
+
+impl<S: Default> Default for Normalized<S> {
+    fn default() -> Self {
+        Self::new(S::default(), Normalization::Max)
+    }
+}
+
+impl<S> SpectralDistribution for Normalized<S>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let (d, s) = self.source.spd();
+        let (n, m) = self.source.shape();
+        let mut v = DMatrix::from_fn(n, m, |i, j| s[(i, j)]);
+        let wl: Vec<f64> = d.iter().map(|l| l.value() * 1E9).collect();
+        let ybar = DefaultObserver::values_cached(&d);
+        let dl = d.step.unitvalue(1).value();
+        for mut c in v.column_iter_mut() {
+            let x = match self.normalization {
+                Normalization::At(w) => wl
+                    .windows(2)
+                    .zip(c.as_slice().windows(2))
+                    .find(|(l, _)| w >= l[0] && w <= l[1])
+                    .map(|(l, f)| f[0] + (f[1] - f[0]) * (w - l[0]) / (l[1] - l[0]))
+                    .unwrap_or(0.0),
+                Normalization::Luminance(y) => {
+                    let y0 = ybar.row(1).dot(&c.transpose()) * DefaultObserver::K * dl;
+                    y0 / y
+                }
+                Normalization::Max => c.max(),
+            };
+            if x != 0.0 && x.is_finite() {
+                c /= x;
+            }
+        }
+        (d, v)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.source.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        self.source.keys()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.source.description()
+    }
+}
+
+impl<S> Illuminant for Normalized<S> where S: Illuminant<StepType = WavelengthStep> {}
+
+impl<S, C> From<Normalized<S>> for CieXYZ<C>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+    C: StandardObserver,
+{
+    fn from(n: Normalized<S>) -> Self {
+        n.xyz()
+    }
+}
+
+#[test]
+fn test_normalized() {
+    use crate::illuminants::{CieIllA, CieIllD65};
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    // CIE illuminant A is tabulated with a value of 100.0 at 560nm
+    let (_, a) = CieIllA.spd();
+    let (_, v) = CieIllA.normalize_at(560.0).spd();
+    assert_abs_diff_eq!(
+        v,
+        DMatrix::from_fn(a.nrows(), 1, |i, _| a[(i, 0)] / 100.0),
+        epsilon = 1E-6
+    );
+
+    // 562.5nm is interpolated between 560 and 565nm
+    let (d, v) = CieIllD65.normalize_at(562.5).spd();
+    let i = d
+        .iter()
+        .position(|l| (l.value() * 1E9 - 560.0).abs() < 1E-6)
+        .unwrap();
+    assert_abs_diff_eq!(v[(i, 0)] + v[(i + 1, 0)], 2.0, epsilon = 1E-12);
+
+    let xyz: CieXYZ<CieObs1931> = CieIllD65.normalize_luminance(100.0).into();
+    assert_abs_diff_eq!(xyz.data[(1, 0)], 100.0, epsilon = 1E-9);
+    let xyz_ref: CieXYZ<CieObs1931> = CieIllD65.into();
+    assert_abs_diff_eq!(xyz.data, xyz_ref.data, epsilon = 1E-9);
+
+    let (_, v) = CieIllD65.normalize_max().spd();
+    assert_abs_diff_eq!(v.max(), 1.0);
+}
+
+#[test]
+fn test_xyz_step() {
+    use crate::observers::CieObs1931;
+    use crate::{NM, NM5};
+    use approx::assert_relative_eq;
+
+    // the same spectrum, at 1nm, and at 5nm, integrated with the step size of its own domain
+    let sd = |d: Domain<WavelengthStep>| {
+        let v = DMatrix::from_iterator(
+            d.len(),
+            1,
+            d.iter().map(|l| (Meter::from(l).value() / 560E-9).powi(2)),
+        );
+        DynamicSpectralDistribution::new(d, v)
+    };
+    let xyz1 = sd(Domain::new(380, 780, NM)).xyz::<CieObs1931>();
+    let xyz5 = sd(Domain::new(380 / 5, 780 / 5, NM5)).xyz::<CieObs1931>();
+    assert_relative_eq!(xyz1.data, xyz5.data, max_relative = 1E-3);
+}
+
+#[test]
+fn test_resample() {
+    use crate::illuminants::{CieIllD65, FL};
+    use crate::{NM, NM10};
+    use approx::assert_abs_diff_eq;
+
+    // D65 is tabulated in 5nm steps; all methods reproduce the tabulated values
+    let (d, v) = CieIllD65.spd();
+    let to = Domain::new(38, 78, NM10);
+    let i = d
+        .iter()
+        .position(|l| (l.value() * 1E9 - 380.0).abs() < 1E-6)
+        .unwrap();
+    for &method in &[
+        Interp::Linear,
+        Interp::Sprague,
+        Interp::Spline,
+        Interp::Pchip,
+    ] {
+        let r = CieIllD65.resample(&to, method);
+        assert_eq!(r.shape(), (41, 1));
+        assert_abs_diff_eq!(r.data()[(10, 0)], v[(i + 20, 0)], epsilon = 1E-9);
+    }
+
+    // keys are retained, and values outside the original domain are zero
+    let f = FL::<3>.to_dynamic().set_keys(&["F3"]);
+    let r = f.resample(&Domain::new(300, 900, NM), Interp::Spline);
+    assert_eq!(r.keys().unwrap(), vec!["F3".to_string()]);
+    assert_eq!(r.data()[(0, 0)], 0.0);
+    assert_eq!(r.data()[(600, 0)], 0.0);
+}
+
+#[test]
+fn test_resample_auto() {
+    use crate::illuminants::{CieIllD65, FL};
+    use crate::NM;
+    use approx::assert_abs_diff_eq;
+
+    let to = Domain::new(380, 780, NM);
+    let (_, fl) = FL::<2>.spd();
+    assert!(is_peaky(fl.nrows(), 1, &fl));
+    assert_abs_diff_eq!(
+        FL::<2>.resample(&to, Interp::Auto).data(),
+        FL::<2>.resample(&to, Interp::Linear).data()
+    );
+    let (_, d65) = CieIllD65.spd();
+    assert!(!is_peaky(d65.nrows(), 1, &d65));
+    assert_abs_diff_eq!(
+        CieIllD65.resample(&to, Interp::Auto).data(),
+        CieIllD65.resample(&to, Interp::Sprague).data()
+    );
+}
+
+#[test]
+fn test_downsample() {
+    use crate::illuminants::{CieIllA, FL};
+    use crate::observers::CieObs1931;
+    use crate::{NM10, NM5};
+    use approx::assert_abs_diff_eq;
+
+    // tristimulus values are preserved, also for a peaky spectrum
+    let a = CieIllA.to_dynamic();
+    let xyz: CieXYZ<CieObs1931> = a.xyz();
+    let xyz5: CieXYZ<CieObs1931> = a.downsample(&Domain::new(60, 166, NM5)).xyz();
+    assert_abs_diff_eq!(xyz.data, xyz5.data, epsilon = 1E-3 * xyz.data[(1, 0)]);
+
+    let f = FL::<2>.downsample(&Domain::new(38, 78, NM10));
+    assert_eq!(f.keys(), FL::<2>.keys());
+    let (_, fl) = FL::<2>.spd();
+    assert_abs_diff_eq!(f.data().sum() * 2.0, fl.sum(), epsilon = 1E-3 * fl.sum());
+}
+
+#[test]
+fn test_slice() {
+    use crate::illuminants::CieIllD65;
+
+    let d65 = CieIllD65.to_dynamic().set_keys(&["D65"]);
+    let s = d65.slice(402.5..=700.0);
+    assert_eq!(s.shape(), (60, 1));
+    assert_eq!(s.keys(), d65.keys());
+    let (d, v) = s.spd();
+    assert!((d.iter().next().unwrap().value() - 405E-9).abs() < 1E-15);
+    assert_eq!(v[(0, 0)], d65.data()[(21, 0)]);
+    assert_eq!(d65.slice(900.0..=1000.0).shape(), (0, 1));
+}
+
+#[test]
+fn test_select() {
+    use crate::illuminants::{CieIllA, CieIllD65};
+
+    let d65 = CieIllD65.to_dynamic();
+    let both = DynamicSpectralDistribution::new(
+        d65.domain().clone(),
+        DMatrix::from_fn(d65.shape().0, 2, |i, j| d65.data()[(i, 0)] * (j + 1) as f64),
+    )
+    .set_keys(&["single", "double"]);
+    let double = both.column_by_key("double").unwrap();
+    assert_eq!(double[10], 2.0 * d65.data()[(10, 0)]);
+    let sel = both.select(&["double", "single"]).unwrap();
+    assert_eq!(
+        sel.keys().unwrap(),
+        vec!["double".to_string(), "single".to_string()]
+    );
+    assert_eq!(sel.data().column(0), both.data().column(1));
+    assert_eq!(
+        both.select(&["single", "triple"]).unwrap_err(),
+        KeyError {
+            key: "triple".to_string()
+        }
+    );
+    assert!(CieIllA.column_by_key("A").is_err());
+}
+
+#[test]
+fn test_iter_points() {
+    use crate::illuminants::CieIllA;
+    use approx::assert_abs_diff_eq;
+
+    let points: Vec<(f64, f64)> = CieIllA.iter_points(0).collect();
+    assert_eq!(points.len(), CieIllA.shape().0);
+    let (l, v) = points[260];
+    assert_abs_diff_eq!(l, 560.0, epsilon = 1E-9);
+    assert_abs_diff_eq!(v, 100.0, epsilon = 1E-9);
+}
+
+#[test]
+fn test_summary() {
+    use crate::NM10;
+
+    let sd = DynamicSpectralDistribution::new(Domain::new(38, 78, NM10), DMatrix::zeros(41, 7))
+        .set_keys(&["a", "b", "c", "d", "e", "f", "g"])
+        .set_description("samples");
+    assert_eq!(
+        sd.summary(),
+        "samples: 7 spectra on 380–780 nm, 10 nm, 41 pts, keys: a, b, c, d, e, … (+2 more)"
+    );
+    assert_eq!(sd.to_string(), sd.summary());
+    let single = DynamicSpectralDistribution::new(Domain::new(38, 78, NM10), DMatrix::zeros(41, 1));
+    assert_eq!(single.summary(), "1 spectrum on 380–780 nm, 10 nm, 41 pts");
+}
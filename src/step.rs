@@ -1,4 +1,8 @@
-use super::{Electronvolt, Joule, Kelvin, Lumen, Meter, Unit, Unitless};
+use super::{
+    Electronvolt, Hertz, Joule, Kelvin, Lumen, Meter, ReciprocalMeter, Unit, Unitless,
+    ELECTRONVOLT_AS_JOULE,
+};
+use crate::physics::{C, H};
 
 /**
    Step – or interval – type and size for a set of equidistant data points.
@@ -32,7 +36,17 @@ pub const K50: CctStep = CctStep { size: 5, exp: 1 };
 pub const K100: CctStep = CctStep { size: 1, exp: 2 };
 pub const KK: CctStep = CctStep { size: 1, exp: 3 }; // kilo Kelvin, or kK
 
+pub const EV: ElectronVoltStep = PhotonEnergyStep { size: 1, exp: 0 }; // electronvolt
 pub const DEV: PhotonEnergyStep = PhotonEnergyStep { size: 1, exp: -1 }; // deci electronvolt, or 0.1 eV
+pub const CEV: ElectronVoltStep = PhotonEnergyStep { size: 1, exp: -2 }; // centi electronvolt, or 0.01 eV
+pub const MEV: ElectronVoltStep = PhotonEnergyStep { size: 1, exp: -3 }; // milli electronvolt
+
+pub const THZ: FrequencyStep = FrequencyStep { size: 1, exp: 12 }; // terahertz
+pub const THZ10: FrequencyStep = FrequencyStep { size: 1, exp: 13 };
+
+pub const CM1: WavenumberStep = WavenumberStep { size: 1, exp: 2 }; // reciprocal centimeter
+pub const CM10: WavenumberStep = WavenumberStep { size: 1, exp: 3 };
+pub const CM100: WavenumberStep = WavenumberStep { size: 1, exp: 4 };
 
 #[inline]
 fn val(i: i32, size: u32, exp: i32) -> f64 {
@@ -49,6 +63,7 @@ pub trait Step: Clone + Copy + PartialEq + Eq {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnitlessStep {
     pub size: u32,
     pub exp: i32,
@@ -64,6 +79,7 @@ impl Step for UnitlessStep {
 }
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CctStep {
     pub size: u32,
     pub exp: i32,
@@ -79,6 +95,7 @@ impl Step for CctStep {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WavelengthStep {
     pub size: u32,
     pub exp: i32,
@@ -94,6 +111,7 @@ impl Step for WavelengthStep {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LuminousFluxStep {
     pub size: u32,
     pub exp: i32,
@@ -109,6 +127,7 @@ impl Step for LuminousFluxStep {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JouleScale {
     pub size: u32,
     pub exp: i32,
@@ -124,6 +143,7 @@ impl Step for JouleScale {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PhotonEnergyStep {
     pub size: u32,
     pub exp: i32,
@@ -137,3 +157,112 @@ impl Step for PhotonEnergyStep {
         Electronvolt(val(i, self.size, self.exp))
     }
 }
+
+/// Photon energy step, in electronvolt.
+pub type ElectronVoltStep = PhotonEnergyStep;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrequencyStep {
+    pub size: u32,
+    pub exp: i32,
+}
+
+impl Step for FrequencyStep {
+    const NAME: &'static str = "Frequency";
+    type UnitValueType = Hertz;
+
+    fn unitvalue(&self, i: i32) -> Self::UnitValueType {
+        Hertz(val(i, self.size, self.exp))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WavenumberStep {
+    pub size: u32,
+    pub exp: i32,
+}
+
+impl Step for WavenumberStep {
+    const NAME: &'static str = "Wavenumber";
+    type UnitValueType = ReciprocalMeter;
+
+    fn unitvalue(&self, i: i32) -> Self::UnitValueType {
+        ReciprocalMeter(val(i, self.size, self.exp))
+    }
+}
+
+/**
+Steps for the spectral variables of light, all of which can be mapped onto a wavelength.
+
+A spectral density, given per unit of one of these variables, is converted into a density per unit of another, using
+the Jacobian of the mapping: S<sub>x</sub>(x) = S<sub>&lambda;</sub>(&lambda;(x)) |d&lambda;/dx|. For a frequency
+&nu;, for example, with &lambda; = c/&nu;, this factor is c/&nu;<sup>2</sup>.
+*/
+pub trait SpectralStep: Step {
+    /// Wavelength, in meter, for a value of the spectral variable, in its base unit.
+    fn wavelength(x: f64) -> f64;
+
+    /// Value of the spectral variable, in its base unit, for a wavelength in meter.
+    fn from_wavelength(wl: f64) -> f64;
+
+    /// Absolute value of the derivative of the wavelength, in meter, to the spectral variable, at `x`.
+    fn jacobian(x: f64) -> f64;
+}
+
+impl SpectralStep for WavelengthStep {
+    fn wavelength(x: f64) -> f64 {
+        x
+    }
+
+    fn from_wavelength(wl: f64) -> f64 {
+        wl
+    }
+
+    fn jacobian(_x: f64) -> f64 {
+        1.0
+    }
+}
+
+impl SpectralStep for FrequencyStep {
+    fn wavelength(x: f64) -> f64 {
+        C / x
+    }
+
+    fn from_wavelength(wl: f64) -> f64 {
+        C / wl
+    }
+
+    fn jacobian(x: f64) -> f64 {
+        C / (x * x)
+    }
+}
+
+impl SpectralStep for WavenumberStep {
+    fn wavelength(x: f64) -> f64 {
+        1.0 / x
+    }
+
+    fn from_wavelength(wl: f64) -> f64 {
+        1.0 / wl
+    }
+
+    fn jacobian(x: f64) -> f64 {
+        1.0 / (x * x)
+    }
+}
+
+impl SpectralStep for PhotonEnergyStep {
+    fn wavelength(x: f64) -> f64 {
+        H * C / (x * ELECTRONVOLT_AS_JOULE)
+    }
+
+    fn from_wavelength(wl: f64) -> f64 {
+        H * C / (wl * ELECTRONVOLT_AS_JOULE)
+    }
+
+    fn jacobian(x: f64) -> f64 {
+        H * C / (x * x * ELECTRONVOLT_AS_JOULE)
+    }
+}
@@ -0,0 +1,204 @@
+/*!
+Fluorescent swatches, described by their bispectral radiance factors.
+
+Many papers, textiles, and detergent-washed fabrics contain fluorescent whitening agents, which absorb ultraviolet
+and violet light, and re-emit it as blue light. The color of such a sample depends on the ultraviolet content of
+the illuminant, and can not be described by a reflectance spectrum alone.
+Here these samples are described by their reflected radiance factor, and a Donaldson matrix, with the luminescent
+radiance factors for each combination of an emission, and an excitation wavelength.
+*/
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::illuminants::Illuminant;
+use crate::models::CieLab;
+use crate::observers::StandardObserver;
+use crate::swatches::{DataSwatch, Swatch};
+use crate::{Domain, Meter, SpectralDistribution, Step, Unit, WavelengthStep};
+
+/**
+A fluorescent swatch, with a reflected radiance factor, and a Donaldson excitation-emission matrix.
+
+The rows of the Donaldson matrix correspond to the emission wavelengths, which are the wavelengths of the domain of
+the reflected component, and its columns to the excitation wavelengths, which typically extend into the ultraviolet.
+For an illuminant with spectral irradiance E(&mu;), the luminescent radiance at emission wavelength &lambda; is
+the sum of D(&lambda;,&mu;) E(&mu;) &Delta;&mu;, with &Delta;&mu; the step size of the excitation domain in
+nanometer.
+
+As a `SpectralDistribution` it only has its reflected component; use `total_radiance_factor`, or `lab`, to include
+its fluorescence for a particular illuminant.
+
+# Examples
+White paper, with a fluorescent whitening agent, excited around 350nm, and emitting around 440nm.
+```
+use scot::illuminants::{CieIllA, CieIllD65};
+use scot::models::CieLab;
+use scot::observers::CieObs1931;
+use scot::swatches::BispectralSwatch;
+use scot::{Domain, Unit, NM5};
+
+let emission = Domain::new(380 / 5, 780 / 5, NM5);
+let excitation = Domain::new(300 / 5, 780 / 5, NM5);
+let em: Vec<f64> = emission.iter().map(|l| (-((l.value() * 1E9 - 440.0) / 25.0).powi(2)).exp()).collect();
+let ex: Vec<f64> = excitation.iter().map(|l| (-((l.value() * 1E9 - 350.0) / 30.0).powi(2)).exp()).collect();
+let paper = BispectralSwatch::from_excitation_emission(emission, vec![0.85; 81], excitation, ex, em, 0.01);
+
+let lab_d65: CieLab<CieIllD65, CieObs1931> = paper.lab();
+let lab_a: CieLab<CieIllA, CieObs1931> = paper.lab();
+println!("{} {}", lab_d65.data, lab_a.data);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct BispectralSwatch {
+    domain: Domain<WavelengthStep>,
+    reflected: DVector<f64>,
+    excitation: Domain<WavelengthStep>,
+    donaldson: DMatrix<f64>,
+}
+
+impl BispectralSwatch {
+    pub fn new(
+        domain: Domain<WavelengthStep>,
+        reflected: Vec<f64>,
+        excitation: Domain<WavelengthStep>,
+        donaldson: DMatrix<f64>,
+    ) -> Self {
+        assert!(
+            domain.len() == reflected.len(),
+            "reflected data length does not match domain"
+        );
+        assert!(
+            donaldson.shape() == (domain.len(), excitation.len()),
+            "Donaldson matrix shape does not match the emission and excitation domains"
+        );
+        Self {
+            reflected: DVector::from_vec(reflected),
+            domain,
+            excitation,
+            donaldson,
+        }
+    }
+
+    /// Bispectral swatch with a single fluorescent component, with a Donaldson matrix formed by the product of an
+    /// emission, and an excitation spectrum, both normalized to a peak value of 1.0, and scaled by `scale`.
+    pub fn from_excitation_emission(
+        domain: Domain<WavelengthStep>,
+        reflected: Vec<f64>,
+        excitation: Domain<WavelengthStep>,
+        excitation_spectrum: Vec<f64>,
+        emission_spectrum: Vec<f64>,
+        scale: f64,
+    ) -> Self {
+        let ex = DVector::from_vec(excitation_spectrum);
+        let em = DVector::from_vec(emission_spectrum);
+        let d = &em * ex.transpose() * (scale / (em.max() * ex.max()));
+        Self::new(domain, reflected, excitation, d)
+    }
+
+    pub fn donaldson(&self) -> &DMatrix<f64> {
+        &self.donaldson
+    }
+
+    /// Luminescent radiance, on the domain of the swatch, for an illuminant.
+    pub fn luminescent_radiance<I>(&self, illuminant: &I) -> DVector<f64>
+    where
+        I: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let e = illuminant.map_domain(self.excitation.clone());
+        let dmu = self.excitation.step.unitvalue(1).value() * 1E9;
+        &self.donaldson * e.column(0) * dmu
+    }
+
+    /// Total, reflected and luminescent, radiance, on the domain of the swatch, for an illuminant.
+    pub fn total_radiance<I>(&self, illuminant: &I) -> DVector<f64>
+    where
+        I: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let e = illuminant.map_domain(self.domain.clone());
+        self.reflected.component_mul(&e.column(0)) + self.luminescent_radiance(illuminant)
+    }
+
+    /// Total radiance factor for an illuminant, as a swatch: the total radiance, divided by the radiance of a perfect
+    /// reflecting diffuser. Wavelengths without illuminant power get a total radiance factor equal to the reflected
+    /// radiance factor.
+    pub fn total_radiance_factor<I>(&self, illuminant: &I) -> DataSwatch
+    where
+        I: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let e = illuminant.map_domain(self.domain.clone());
+        let t = self.total_radiance(illuminant);
+        let v = t
+            .iter()
+            .zip(e.iter())
+            .zip(self.reflected.iter())
+            .map(|((&ti, &ei), &ri)| if ei > 0.0 { ti / ei } else { ri })
+            .collect();
+        DataSwatch::new(self.domain.clone(), v)
+    }
+
+    /// CIELAB values, including fluorescence, for illuminant `I`, which is also used as reference white.
+    pub fn lab<I, C>(&self) -> CieLab<I, C>
+    where
+        I: Illuminant + SpectralDistribution<StepType = WavelengthStep>,
+        C: StandardObserver,
+        <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+    {
+        self.total_radiance_factor(&I::default()).lab()
+    }
+}
+
+impl SpectralDistribution for BispectralSwatch {
+    type MatrixType = DVector<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        (self.domain.clone(), self.reflected.clone())
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.domain.len(), 1)
+    }
+}
+
+#[test]
+fn test_bispectral_swatch() {
+    use crate::illuminants::{CieIllA, CieIllD65};
+    use crate::observers::CieObs1931;
+    use crate::NM5;
+    use approx::assert_abs_diff_eq;
+
+    let emission = Domain::new(380 / 5, 780 / 5, NM5);
+    let excitation = Domain::new(300 / 5, 780 / 5, NM5);
+    let gauss = |d: &Domain<WavelengthStep>, c: f64, w: f64| -> Vec<f64> {
+        d.iter()
+            .map(|l| (-((l.value() * 1E9 - c) / w).powi(2)).exp())
+            .collect()
+    };
+
+    // without fluorescence, the result is the same as for its reflectance
+    let plain = BispectralSwatch::new(
+        emission.clone(),
+        vec![0.85; 81],
+        excitation.clone(),
+        DMatrix::zeros(81, 97),
+    );
+    let lab: CieLab<CieIllD65, CieObs1931> = plain.lab();
+    let lab_ref: CieLab<CieIllD65, CieObs1931> =
+        DataSwatch::new(emission.clone(), vec![0.85; 81]).lab();
+    assert_abs_diff_eq!(lab.data, lab_ref.data, epsilon = 1E-10);
+
+    // a whitening agent makes paper bluer, and more so in daylight, than in incandescent light
+    let paper = BispectralSwatch::from_excitation_emission(
+        emission.clone(),
+        vec![0.85; 81],
+        excitation.clone(),
+        gauss(&excitation, 350.0, 30.0),
+        gauss(&emission, 440.0, 25.0),
+        0.01,
+    );
+    let (_, rf) = paper.total_radiance_factor(&CieIllD65).spd();
+    assert!(rf.max() > 1.0);
+    let b_d65 = paper.lab::<CieIllD65, CieObs1931>().data[(2, 0)];
+    let b_a = paper.lab::<CieIllA, CieObs1931>().data[(2, 0)];
+    assert!(b_d65 < b_a && b_a < 0.0, "{} {}", b_d65, b_a);
+}
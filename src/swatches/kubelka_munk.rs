@@ -0,0 +1,379 @@
+/*!
+Kubelka-Munk color mixing, of opaque layers of colorants.
+
+The Kubelka-Munk theory describes the reflectance of an opaque layer of a material, which absorbs, and scatters,
+light, by the ratio of its absorption and scattering coefficients K and S:
+
+K/S = (1 - R)<sup>2</sup> / 2R, or R = 1 + K/S - ((K/S)<sup>2</sup> + 2 K/S)<sup>1/2</sup>.
+
+In the single-constant approximation, used for dyed textiles, and for the tint strength of paints, the scattering
+is considered to be determined by the substrate, or the base paint, only, and the K/S values of a mixture are the sum
+of the K/S values of the substrate, and the colorants, proportional to their concentrations.
+
+In the two-constant model, used for paints and coatings, each `Colorant` has its own absorption, and scattering,
+spectra, and the K and S values of a mixture are the sums of the K and S values of its components, weighted by their
+concentrations. The reflectance of a layer with incomplete hiding also depends on its thickness, and on the
+reflectance of the substrate below it. These models describe the reflectance inside a layer: the Saunderson
+correction accounts for the reflections at its surface, to compare with measured reflectance values.
+*/
+
+use crate::{DynamicSpectralDistribution, SpectralDistribution, WavelengthStep};
+
+use super::DataSwatch;
+
+/// Lowest reflectance value used in the K/S transform, to avoid infinite values for black samples.
+const R_MIN: f64 = 1E-6;
+
+/// Kubelka-Munk K/S value of an opaque layer, with reflectance `r`.
+pub fn ks(r: f64) -> f64 {
+    let r = r.clamp(R_MIN, 1.0);
+    (1.0 - r).powi(2) / (2.0 * r)
+}
+
+/// Reflectance of an opaque layer with a Kubelka-Munk K/S value.
+pub fn reflectance_from_ks(ks: f64) -> f64 {
+    let ks = ks.max(0.0);
+    1.0 + ks - (ks * ks + 2.0 * ks).sqrt()
+}
+
+/// K/S values of the first spectrum of a swatch, on the domain of the first spectrum of another swatch.
+fn ks_values<B, P>(base: &B, other: &P) -> Vec<f64>
+where
+    B: SpectralDistribution<StepType = WavelengthStep>,
+    P: SpectralDistribution<StepType = WavelengthStep>,
+{
+    let d = base.spd().0;
+    assert!(
+        other.spd().0.covers(&d),
+        "the domain of a swatch should cover the domain of the base"
+    );
+    other
+        .map_domain(d)
+        .column(0)
+        .iter()
+        .map(|&r| ks(r))
+        .collect()
+}
+
+/**
+Reflectance spectrum of a base, or substrate, with a colorant, using the single-constant Kubelka-Munk model.
+
+The colorant is characterized by the reflectance spectrum of the same base, with the colorant at a reference
+concentration, and the mixture has a concentration relative to that, with the base, at a relative concentration 0.0,
+and the colorant sample, at 1.0, as limits. Its K/S values are calculated as
+(K/S)<sub>mix</sub> = (K/S)<sub>base</sub> + c ((K/S)<sub>colorant</sub> - (K/S)<sub>base</sub>).
+The mixture is calculated on the domain of the base, and only the first spectra of the base and the colorant are used.
+
+# Examples
+A white base, and a blue tint, at half its reference concentration.
+```
+use scot::swatches::{kubelka_munk, DataSwatch};
+use scot::{Domain, SpectralDistribution, NM10};
+
+let d = Domain::new(38, 78, NM10);
+let white = DataSwatch::new(d.clone(), vec![0.9; 41]);
+let blue = DataSwatch::new(d, (0..41).map(|i| if i < 15 { 0.6 } else { 0.1 }).collect());
+let tint = kubelka_munk::mix_single_constant(&white, &blue, 0.5);
+let (_, r) = tint.spd();
+assert!(r[40] > 0.1 && r[40] < 0.9);
+```
+*/
+pub fn mix_single_constant<B, P>(base: &B, colorant: &P, concentration: f64) -> DataSwatch
+where
+    B: SpectralDistribution<StepType = WavelengthStep>,
+    P: SpectralDistribution<StepType = WavelengthStep>,
+{
+    let ks_base = ks_values(base, base);
+    let ks_colorant = ks_values(base, colorant);
+    let values = ks_base
+        .iter()
+        .zip(ks_colorant.iter())
+        .map(|(&b, &c)| reflectance_from_ks(b + concentration * (c - b)))
+        .collect();
+    DataSwatch::new(base.spd().0, values)
+}
+
+/**
+Tint strength of a sample, relative to a standard, in percent, as the ratio of their K/S values at the wavelength of
+the lowest reflectance of the standard, the wavelength of maximum absorption of its colorant.
+
+Both are evaluated on the domain of the standard, and only their first spectra are used.
+For a sample with the same colorant as the standard, in a white base with the same scattering, the tint strength is
+close to the ratio of their concentrations.
+*/
+pub fn tint_strength<A, B>(sample: &A, standard: &B) -> f64
+where
+    A: SpectralDistribution<StepType = WavelengthStep>,
+    B: SpectralDistribution<StepType = WavelengthStep>,
+{
+    let ks_standard = ks_values(standard, standard);
+    let ks_sample = ks_values(standard, sample);
+    let (i, max) =
+        ks_standard.iter().enumerate().fold(
+            (0, f64::MIN),
+            |(im, m), (i, &v)| if v > m { (i, v) } else { (im, m) },
+        );
+    100.0 * ks_sample[i] / max
+}
+
+/**
+Absorption, and scattering, spectra of a colorant, for use in the two-constant Kubelka-Munk model.
+
+Their values are per unit of concentration, and per unit of layer thickness, such as a micrometer: the thickness of
+layers, in `mix_over_substrate`, uses the same unit.
+*/
+#[derive(Debug, Clone)]
+pub struct Colorant {
+    pub k: DynamicSpectralDistribution,
+    pub s: DynamicSpectralDistribution,
+}
+
+impl Colorant {
+    /// Colorant from its absorption, and scattering spectra, which should have the same domain.
+    pub fn new(k: DynamicSpectralDistribution, s: DynamicSpectralDistribution) -> Self {
+        assert!(
+            k.domain() == s.domain(),
+            "absorption and scattering spectra should have the same domain"
+        );
+        Self { k, s }
+    }
+}
+
+/**
+Saunderson correction, for the reflection at the surface of a layer, with refractive index 1.5, in air.
+
+The coefficient `k1` is the fraction of incident light reflected at the surface, and `k2` the fraction of the light
+inside the layer reflected back at its surface, from the inside. Measured reflectance values, for a specular
+component included geometry, are calculated from the internal reflectance R<sub>i</sub>, of the Kubelka-Munk models,
+as R<sub>m</sub> = k<sub>1</sub> + (1 - k<sub>1</sub>)(1 - k<sub>2</sub>) R<sub>i</sub> / (1 - k<sub>2</sub>
+R<sub>i</sub>). For a geometry which excludes the specular reflection, use a `k1` value of 0.0.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Saunderson {
+    pub k1: f64,
+    pub k2: f64,
+}
+
+impl Default for Saunderson {
+    fn default() -> Self {
+        Self { k1: 0.04, k2: 0.6 }
+    }
+}
+
+impl Saunderson {
+    pub fn new(k1: f64, k2: f64) -> Self {
+        Self { k1, k2 }
+    }
+
+    /// Measured reflectance, from an internal reflectance.
+    pub fn measured(&self, r_internal: f64) -> f64 {
+        self.k1 + (1.0 - self.k1) * (1.0 - self.k2) * r_internal / (1.0 - self.k2 * r_internal)
+    }
+
+    /// Internal reflectance, from a measured reflectance.
+    pub fn internal(&self, r_measured: f64) -> f64 {
+        (r_measured - self.k1) / (1.0 - self.k1 - self.k2 + self.k2 * r_measured)
+    }
+}
+
+/// K and S values of a mixture of colorants, with their concentrations, on the domain of the first colorant.
+fn mixture_ks(components: &[(&Colorant, f64)]) -> (crate::Domain<WavelengthStep>, Vec<(f64, f64)>) {
+    assert!(
+        !components.is_empty(),
+        "a mixture should have at least one colorant"
+    );
+    let d = components[0].0.k.domain().clone();
+    let mut ks = vec![(0.0, 0.0); d.len()];
+    for (colorant, c) in components {
+        assert!(
+            colorant.k.domain() == &d,
+            "colorants in a mixture should have the same domain"
+        );
+        let (k, s) = (colorant.k.data(), colorant.s.data());
+        for (i, v) in ks.iter_mut().enumerate() {
+            v.0 += c * k[(i, 0)];
+            v.1 += c * s[(i, 0)];
+        }
+    }
+    (d, ks)
+}
+
+/**
+Reflectance spectrum of an opaque layer of a mixture of colorants, with their concentrations, using the two-constant
+Kubelka-Munk model, and, optionally, the Saunderson correction.
+
+The mixture is calculated on the domain of the first colorant, and all colorants should have the same domain.
+
+# Examples
+A white paint, tinted with a small amount of a blue colorant.
+```
+use nalgebra::DMatrix;
+use scot::swatches::{mix_opaque, Colorant, Saunderson};
+use scot::{Domain, DynamicSpectralDistribution, SpectralDistribution, NM10};
+
+let d = Domain::new(38, 78, NM10);
+let dsd = |f: &dyn Fn(usize) -> f64| {
+    DynamicSpectralDistribution::new(d.clone(), DMatrix::from_fn(41, 1, |i, _| f(i)))
+};
+let white = Colorant::new(dsd(&|_| 0.01), dsd(&|_| 1.0));
+let blue = Colorant::new(dsd(&|i| if i < 15 { 0.2 } else { 5.0 }), dsd(&|_| 0.1));
+let paint = mix_opaque(&[(&white, 0.95), (&blue, 0.05)], Some(Saunderson::default()));
+let (_, r) = paint.spd();
+assert!(r[0] > r[40]);
+```
+*/
+pub fn mix_opaque(components: &[(&Colorant, f64)], saunderson: Option<Saunderson>) -> DataSwatch {
+    let (d, ks) = mixture_ks(components);
+    let values = ks
+        .iter()
+        .map(|&(k, s)| {
+            let r = reflectance_from_ks(k / s);
+            saunderson.map_or(r, |sc| sc.measured(r))
+        })
+        .collect();
+    DataSwatch::new(d, values)
+}
+
+/**
+Reflectance spectrum of a layer of a mixture of colorants, with a thickness, on a substrate, using the two-constant
+Kubelka-Munk model, and, optionally, the Saunderson correction.
+
+The reflectance of the substrate is used as the reflectance at the bottom of the layer, and only its first spectrum is
+used. For a very thick layer, the reflectance approaches the reflectance of an opaque layer, as calculated by
+`mix_opaque`, and for a layer with a thickness of zero, it is the reflectance of the substrate.
+*/
+pub fn mix_over_substrate<G>(
+    components: &[(&Colorant, f64)],
+    substrate: &G,
+    thickness: f64,
+    saunderson: Option<Saunderson>,
+) -> DataSwatch
+where
+    G: SpectralDistribution<StepType = WavelengthStep>,
+{
+    let (d, ks) = mixture_ks(components);
+    assert!(
+        substrate.spd().0.covers(&d),
+        "the domain of the substrate should cover the domain of the colorants"
+    );
+    let rg = substrate.map_domain(d.clone());
+    let values = ks
+        .iter()
+        .zip(rg.column(0).iter())
+        .map(|(&(k, s), &rg)| {
+            let r = layer_reflectance(k, s, thickness, rg);
+            saunderson.map_or(r, |sc| sc.measured(r))
+        })
+        .collect();
+    DataSwatch::new(d, values)
+}
+
+/// Kubelka's reflectance of a layer, with absorption and scattering coefficients, and a thickness, on a substrate.
+fn layer_reflectance(k: f64, s: f64, thickness: f64, rg: f64) -> f64 {
+    let sx = s * thickness;
+    if sx <= 0.0 {
+        return rg;
+    }
+    let a = 1.0 + k / s;
+    let b = (a * a - 1.0).sqrt();
+    // b coth(b S X), with its limit 1 / SX for non-absorbing layers
+    let bcoth = if b * sx < 1E-9 {
+        1.0 / sx
+    } else {
+        b / (b * sx).tanh()
+    };
+    (1.0 - rg * (a - bcoth)) / (a - rg + bcoth)
+}
+
+#[test]
+fn test_kubelka_munk_single_constant() {
+    use crate::{Domain, NM5};
+    use approx::assert_abs_diff_eq;
+
+    for &r in &[0.01, 0.2, 0.5, 0.99] {
+        assert_abs_diff_eq!(reflectance_from_ks(ks(r)), r, epsilon = 1E-12);
+    }
+    assert_eq!(ks(1.0), 0.0);
+
+    let d = Domain::new(380 / 5, 780 / 5, NM5);
+    let white = DataSwatch::new(d.clone(), vec![0.85; 81]);
+    let red = DataSwatch::new(
+        Domain::new(360 / 5, 830 / 5, NM5),
+        (360..=830)
+            .step_by(5)
+            .map(|l| if l < 580 { 0.05 } else { 0.7 })
+            .collect(),
+    );
+
+    // limits, and the additivity of K/S values
+    let (_, base) = mix_single_constant(&white, &red, 0.0).spd();
+    assert_abs_diff_eq!(base[0], 0.85, epsilon = 1E-12);
+    let (_, full) = mix_single_constant(&white, &red, 1.0).spd();
+    assert_abs_diff_eq!(full[0], 0.05, epsilon = 1E-12);
+    assert_abs_diff_eq!(full[80], 0.7, epsilon = 1E-12);
+    let (_, half) = mix_single_constant(&white, &red, 0.5).spd();
+    assert_abs_diff_eq!(ks(half[0]), (ks(0.85) + ks(0.05)) / 2.0, epsilon = 1E-12);
+
+    // tint strength of a half strength tint, with the small absorption of the base included
+    let weak = mix_single_constant(&white, &red, 0.5);
+    let standard = mix_single_constant(&white, &red, 1.0);
+    let s = tint_strength(&weak, &standard);
+    let expected = 100.0 * (ks(0.85) + 0.5 * (ks(0.05) - ks(0.85))) / ks(0.05);
+    assert_abs_diff_eq!(s, expected, epsilon = 1E-9);
+    assert_abs_diff_eq!(tint_strength(&red, &standard), 100.0, epsilon = 1E-9);
+}
+
+#[test]
+fn test_kubelka_munk_two_constant() {
+    use crate::{Domain, NM10};
+    use approx::assert_abs_diff_eq;
+    use nalgebra::DMatrix;
+
+    let d = Domain::new(38, 78, NM10);
+    let dsd = |f: &dyn Fn(usize) -> f64| {
+        DynamicSpectralDistribution::new(d.clone(), DMatrix::from_fn(41, 1, |i, _| f(i)))
+    };
+    let white = Colorant::new(dsd(&|_| 0.005), dsd(&|_| 0.5));
+    let red = Colorant::new(dsd(&|i| if i < 20 { 0.8 } else { 0.02 }), dsd(&|_| 0.05));
+
+    // an opaque layer only depends on the ratio K/S
+    let (_, r) = mix_opaque(&[(&white, 0.8), (&red, 0.2)], None).spd();
+    let ks0 = (0.8 * 0.005 + 0.2 * 0.8) / (0.8 * 0.5 + 0.2 * 0.05);
+    assert_abs_diff_eq!(r[0], reflectance_from_ks(ks0), epsilon = 1E-12);
+    let (_, r2) = mix_opaque(&[(&white, 1.6), (&red, 0.4)], None).spd();
+    assert_abs_diff_eq!(r, r2, epsilon = 1E-12);
+
+    // thin, and thick, layers, over a black and a white substrate
+    let black = crate::swatches::DataSwatch::new(d.clone(), vec![0.0; 41]);
+    let paper = crate::swatches::DataSwatch::new(d.clone(), vec![0.9; 41]);
+    let mix = [(&white, 0.8), (&red, 0.2)];
+    let (_, thin) = mix_over_substrate(&mix, &paper, 0.0, None).spd();
+    assert_abs_diff_eq!(thin[0], 0.9, epsilon = 1E-12);
+    let (_, thick) = mix_over_substrate(&mix, &black, 1E4, None).spd();
+    assert_abs_diff_eq!(thick, r, epsilon = 1E-9);
+    let (_, on_black) = mix_over_substrate(&mix, &black, 5.0, None).spd();
+    let (_, on_paper) = mix_over_substrate(&mix, &paper, 5.0, None).spd();
+    assert!(on_black[30] < r[30] && on_paper[30] > r[30]);
+
+    // a non-absorbing, scattering layer, on a black substrate: R = SX / (1 + SX)
+    let (_, r) = mix_over_substrate(
+        &[(&Colorant::new(dsd(&|_| 0.0), dsd(&|_| 1.0)), 1.0)],
+        &black,
+        2.0,
+        None,
+    )
+    .spd();
+    assert_abs_diff_eq!(r[0], 2.0 / 3.0, epsilon = 1E-12);
+
+    // Saunderson correction, and its inverse
+    let sc = Saunderson::default();
+    assert_abs_diff_eq!(sc.measured(0.0), 0.04, epsilon = 1E-12);
+    assert_abs_diff_eq!(sc.measured(1.0), 1.0, epsilon = 1E-12);
+    assert_abs_diff_eq!(sc.internal(sc.measured(0.3)), 0.3, epsilon = 1E-12);
+    let (_, rs) = mix_opaque(&mix, Some(sc)).spd();
+    assert_abs_diff_eq!(
+        rs[0],
+        sc.measured(reflectance_from_ks(ks0)),
+        epsilon = 1E-12
+    );
+}
@@ -0,0 +1,165 @@
+/*!
+Swatch libraries from runtime data.
+
+Swatch collections compiled into this library, such as the color checker samples, are defined with the `swatch!`
+macro, from static data. A `SwatchLibrary` holds a keyed collection of reflectance spectra loaded, or measured, at
+runtime, optionally with the conditions of their measurement, and can be used wherever a `Swatch` is expected.
+*/
+
+use nalgebra::DMatrix;
+
+use crate::illuminants::Illuminant;
+use crate::models::CieLab;
+use crate::observers::StandardObserver;
+use crate::{Domain, KeyError, Meter, SpectralDistribution, Step, WavelengthStep};
+
+use super::{DataSwatch, MeasurementConditions, Swatch};
+
+/**
+A keyed collection of reflectance spectra, with a reflectance spectrum in each column of its data matrix.
+
+# Examples
+Two measured samples, and their CIELAB values for illuminant D65.
+```
+use nalgebra::DMatrix;
+use scot::illuminants::CieIllD65;
+use scot::models::CieLab;
+use scot::observers::CieObs1931;
+use scot::swatches::{Swatch, SwatchLibrary};
+use scot::{Domain, NM10};
+
+let d = Domain::new(38, 73, NM10);
+let data = DMatrix::from_fn(d.len(), 2, |i, j| if j == 0 { 0.8 } else { 0.1 + 0.02 * i as f64 });
+let samples = SwatchLibrary::from_columns(d, data, &["paper", "orange"]).set_description("my samples");
+let lab: CieLab<CieIllD65, CieObs1931> = samples.lab();
+let orange: CieLab<CieIllD65, CieObs1931> = samples.get("orange").unwrap().into();
+assert_eq!(lab.data.column(1), orange.data.column(0));
+```
+*/
+#[derive(Debug, Clone)]
+pub struct SwatchLibrary {
+    domain: Domain<WavelengthStep>,
+    data: DMatrix<f64>,
+    keys: Vec<String>,
+    description: Option<String>,
+    measurement: Option<MeasurementConditions>,
+}
+
+impl SwatchLibrary {
+    /// Swatch library from reflectance spectra, as columns of a matrix, and a unique key for each of them.
+    pub fn from_columns(domain: Domain<WavelengthStep>, data: DMatrix<f64>, keys: &[&str]) -> Self {
+        assert!(
+            domain.len() == data.nrows(),
+            "data length does not match domain"
+        );
+        assert!(
+            keys.len() == data.ncols(),
+            "number of keys does not match the number of spectra"
+        );
+        assert!(
+            keys.iter().enumerate().all(|(i, k)| !keys[..i].contains(k)),
+            "keys should be unique"
+        );
+        Self {
+            domain,
+            data,
+            keys: keys.iter().map(|s| s.to_string()).collect(),
+            description: None,
+            measurement: None,
+        }
+    }
+
+    pub fn set_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn set_measurement(mut self, measurement: MeasurementConditions) -> Self {
+        self.measurement = Some(measurement);
+        self
+    }
+
+    pub fn domain(&self) -> &Domain<WavelengthStep> {
+        &self.domain
+    }
+
+    pub fn data(&self) -> &DMatrix<f64> {
+        &self.data
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The swatch with a key.
+    pub fn get(&self, key: &str) -> Result<DataSwatch, KeyError> {
+        let values = self.column_by_key(key)?;
+        Ok(DataSwatch::new(
+            self.domain.clone(),
+            values.as_slice().to_vec(),
+        ))
+    }
+}
+
+impl SpectralDistribution for SwatchLibrary {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        (self.domain.clone(), self.data.clone())
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.data.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        Some(self.keys.clone())
+    }
+
+    fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+}
+
+impl Swatch for SwatchLibrary {
+    fn measurement(&self) -> Option<MeasurementConditions> {
+        self.measurement.clone()
+    }
+}
+
+impl<I, C> From<SwatchLibrary> for CieLab<I, C>
+where
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+    I: Illuminant,
+    C: StandardObserver,
+{
+    fn from(sw: SwatchLibrary) -> Self {
+        sw.lab()
+    }
+}
+
+#[test]
+fn test_swatch_library() {
+    use crate::NM5;
+
+    let d = Domain::new(76, 156, NM5);
+    let data = DMatrix::from_fn(d.len(), 3, |i, j| 0.1 * (j + 1) as f64 + 0.001 * i as f64);
+    let lib = SwatchLibrary::from_columns(d, data.clone(), &["a", "b", "c"]);
+    assert_eq!(lib.len(), 3);
+    assert_eq!(lib.keys().unwrap()[1], "b");
+    assert_eq!(lib.description(), None);
+    let (_, s) = lib.get("c").unwrap().spd();
+    assert_eq!(s, data.column(2));
+    assert_eq!(
+        lib.get("d").unwrap_err(),
+        KeyError {
+            key: "d".to_string()
+        }
+    );
+    assert_eq!(lib.select(&["b"]).unwrap().data().column(0), data.column(1));
+}
@@ -0,0 +1,188 @@
+/*!
+Measurement conditions of reflectance data.
+
+The reflectance spectra of a sample, measured with instruments with different geometries, differ: a
+spectrophotometer with a 45°/0° geometry excludes the specular reflection of a glossy sample, and an integrating
+sphere instrument, with a d/8° geometry, includes it, in specular component included (SCI) mode, or excludes it, in
+specular component excluded (SCE) mode. For samples with optical brighteners, such as most papers, the ultraviolet
+content of the instrument's light source also matters.
+
+Swatch collections can report their measurement conditions with `Swatch::measurement`, and `check_measurements`
+detects the combination of data sets measured under different conditions.
+*/
+
+use std::fmt;
+
+/// Illumination and viewing geometry of a reflectance measurement, as defined in CIE 15.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeasurementGeometry {
+    /// 45°/0°, or 0°/45°, directional illumination and viewing, which excludes specular reflection.
+    Directional45,
+    /// d/8°, diffuse illumination, with the specular component included (SCI).
+    DiffuseSci,
+    /// d/8°, diffuse illumination, with the specular component excluded (SCE).
+    DiffuseSce,
+}
+
+impl fmt::Display for MeasurementGeometry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeasurementGeometry::Directional45 => write!(f, "45/0"),
+            MeasurementGeometry::DiffuseSci => write!(f, "d/8 SCI"),
+            MeasurementGeometry::DiffuseSce => write!(f, "d/8 SCE"),
+        }
+    }
+}
+
+/// Ultraviolet content of the illumination of a reflectance measurement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UvCondition {
+    /// UV included, such as the M0, and M1, conditions in ISO 13655.
+    Included,
+    /// UV cut, or excluded, such as the M2 condition in ISO 13655.
+    Excluded,
+}
+
+impl fmt::Display for UvCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UvCondition::Included => write!(f, "UV-inc"),
+            UvCondition::Excluded => write!(f, "UV-cut"),
+        }
+    }
+}
+
+/**
+Measurement geometry, and, optionally, the ultraviolet condition, and the instrument used, for reflectance data.
+
+# Examples
+```
+use scot::swatches::{MeasurementConditions, MeasurementGeometry, UvCondition};
+
+let m = MeasurementConditions::new(MeasurementGeometry::DiffuseSci)
+    .set_uv(UvCondition::Excluded)
+    .set_instrument("CM-700d");
+assert_eq!(m.to_string(), "d/8 SCI, UV-cut, CM-700d");
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeasurementConditions {
+    pub geometry: MeasurementGeometry,
+    pub uv: Option<UvCondition>,
+    pub instrument: Option<String>,
+}
+
+impl MeasurementConditions {
+    pub fn new(geometry: MeasurementGeometry) -> Self {
+        Self {
+            geometry,
+            uv: None,
+            instrument: None,
+        }
+    }
+
+    pub fn set_uv(mut self, uv: UvCondition) -> Self {
+        self.uv = Some(uv);
+        self
+    }
+
+    pub fn set_instrument(mut self, instrument: &str) -> Self {
+        self.instrument = Some(instrument.to_string());
+        self
+    }
+
+    /// Checks if data measured under these, and other, conditions can be combined: their geometries should be the
+    /// same, and their ultraviolet conditions, if both known, too. Differences in instruments are not checked.
+    pub fn is_compatible(&self, other: &MeasurementConditions) -> bool {
+        self.geometry == other.geometry
+            && match (self.uv, other.uv) {
+                (Some(a), Some(b)) => a == b,
+                _ => true,
+            }
+    }
+}
+
+impl fmt::Display for MeasurementConditions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.geometry)?;
+        if let Some(uv) = self.uv {
+            write!(f, ", {}", uv)?;
+        }
+        if let Some(instrument) = &self.instrument {
+            write!(f, ", {}", instrument)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error for the combination of reflectance data measured under incompatible conditions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeasurementMismatch {
+    pub left: MeasurementConditions,
+    pub right: MeasurementConditions,
+}
+
+impl fmt::Display for MeasurementMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "incompatible measurement conditions: {} and {}",
+            self.left, self.right
+        )
+    }
+}
+
+impl std::error::Error for MeasurementMismatch {}
+
+/**
+Checks if the measurement conditions of two swatch collections are compatible. Collections with unknown measurement
+conditions are considered compatible with any other.
+
+# Examples
+```
+use nalgebra::DMatrix;
+use scot::swatches::{check_measurements, MeasurementConditions, MeasurementGeometry, SwatchLibrary};
+use scot::{Domain, NM10};
+
+let d = Domain::new(40, 70, NM10);
+let a = SwatchLibrary::from_columns(d.clone(), DMatrix::from_element(31, 1, 0.5), &["a"])
+    .set_measurement(MeasurementConditions::new(MeasurementGeometry::Directional45));
+let b = SwatchLibrary::from_columns(d, DMatrix::from_element(31, 1, 0.5), &["b"])
+    .set_measurement(MeasurementConditions::new(MeasurementGeometry::DiffuseSci));
+assert!(check_measurements(&a, &b).is_err());
+```
+*/
+pub fn check_measurements<A, B>(a: &A, b: &B) -> Result<(), MeasurementMismatch>
+where
+    A: super::Swatch + ?Sized,
+    B: super::Swatch + ?Sized,
+{
+    match (a.measurement(), b.measurement()) {
+        (Some(left), Some(right)) if !left.is_compatible(&right) => {
+            Err(MeasurementMismatch { left, right })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn test_measurement_conditions() {
+    let sci = MeasurementConditions::new(MeasurementGeometry::DiffuseSci);
+    let sci_cut = sci.clone().set_uv(UvCondition::Excluded);
+    let sci_inc = sci
+        .clone()
+        .set_uv(UvCondition::Included)
+        .set_instrument("i1Pro");
+    assert!(sci.is_compatible(&sci_cut));
+    assert!(!sci_cut.is_compatible(&sci_inc));
+    assert!(!sci.is_compatible(&MeasurementConditions::new(MeasurementGeometry::DiffuseSce)));
+    assert_eq!(sci_inc.to_string(), "d/8 SCI, UV-inc, i1Pro");
+    assert_eq!(
+        MeasurementMismatch {
+            left: sci_cut,
+            right: sci_inc
+        }
+        .to_string(),
+        "incompatible measurement conditions: d/8 SCI, UV-cut and d/8 SCI, UV-inc, i1Pro"
+    );
+}
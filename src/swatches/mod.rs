@@ -2,10 +2,10 @@
 
 use std::marker::PhantomData;
 
-use nalgebra::{Const, DMatrix, OMatrix};
+use nalgebra::{Const, DMatrix, Matrix3x1, Matrix3xX, OMatrix};
 
 use crate::illuminants::Illuminant;
-use crate::models::{cielab, CieLab};
+use crate::models::{cielab, CieLab, CieXYZ, CieYxy};
 use crate::observers::StandardObserver;
 use crate::{Domain, Meter, SpectralDistribution, Step, Unit, WavelengthStep, DOMAIN_DEFAULT_LEN};
 
@@ -15,6 +15,33 @@ pub use spline_swatch::*;
 pub mod data_swatch;
 pub use data_swatch::*;
 
+pub mod bispectral;
+pub use bispectral::*;
+
+pub mod pca;
+pub use pca::*;
+
+pub mod library;
+pub use library::*;
+
+pub mod measurement;
+pub use measurement::*;
+
+pub mod kubelka_munk;
+pub use kubelka_munk::*;
+
+pub mod neutral;
+pub use neutral::*;
+
+pub mod overlay;
+pub use overlay::*;
+
+pub mod reconstruct;
+pub use reconstruct::*;
+
+pub mod synthetic;
+pub use synthetic::*;
+
 
 /**
     Traits for swatches, libraries or models for color samples, to get their spectral distributions
@@ -25,6 +52,27 @@ where
     Self: SpectralDistribution,
    // Self: Default,
 {
+    /// Measurement geometry, and conditions, of the reflectance data, if known.
+    fn measurement(&self) -> Option<MeasurementConditions> {
+        None
+    }
+
+    /// Reflectance spectra seen through a transparent layer, with a transmission spectrum.
+    fn overlaid<F>(self, layer: F) -> Overlaid<Self, F>
+    where
+        Self: Sized,
+    {
+        Overlaid::new(self, layer)
+    }
+
+    /// Reflectance spectra multiplied by a scale factor, with an offset added.
+    fn scaled(self, scale: f64, offset: f64) -> Scaled<Self>
+    where
+        Self: Sized,
+    {
+        Scaled::new(self, scale, offset)
+    }
+
     fn lab<I, C>(&self) -> CieLab<I, C>
     where
         C: StandardObserver,
@@ -36,17 +84,80 @@ where
         <<I as SpectralDistribution>::StepType as Step>::UnitValueType:
             From<<<Self as SpectralDistribution>::StepType as Step>::UnitValueType>,
     {
-        let (d, s) = self.spd();
-        let c = C::values(&d);
-        let l = I::default().map_domain(d.clone());
-        //	println!("****** {} {} {}", d.len(), self.shape().0, self.shape().1);
-        let m: DMatrix<f64> =
-            DMatrix::from_fn(l.nrows(), self.shape().1, |i, j| l[(i, 0)] * s[(i, j)]);
-        let xyzn = &c * l.column(0) * C::K * d.step.unitvalue(1).value();
-        let xyz = c * m * C::K * d.step.unitvalue(1).value();
-        CieLab{ data: cielab(xyzn, xyz), cmf: PhantomData, illuminant: PhantomData}
+        let (xyzn, xyz) = tristimulus_values::<Self, I, C>(self);
+        CieLab{ data: cielab(xyzn, xyz), keys: self.keys(), cmf: PhantomData, illuminant: PhantomData}
+    }
+
+    /**
+    Tristimulus values of the swatches, illuminated by illuminant `I`, for observer `C`, scaled to a luminance value
+    of 100 for a perfect white reflector, with the tristimulus values given by `white_xyz`.
+
+    This differs from `SpectralDistribution::xyz`, which calculates tristimulus values of spectra as emission spectra.
+    */
+    fn xyz_under<I, C>(&self) -> CieXYZ<C>
+    where
+        C: StandardObserver,
+        I: Default,
+        I: SpectralDistribution,
+        Meter: From<<<Self as SpectralDistribution>::StepType as Step>::UnitValueType>,
+        <<I as SpectralDistribution>::StepType as Step>::UnitValueType:
+            From<<<Self as SpectralDistribution>::StepType as Step>::UnitValueType>,
+    {
+        let (xyzn, xyz) = tristimulus_values::<Self, I, C>(self);
+        CieXYZ::new(xyz * (100.0 / xyzn.y)).with_keys(self.keys())
+    }
+
+    /// Tristimulus values of a perfect white reflector, illuminated by illuminant `I`, for observer `C`, on the domain
+    /// of the swatches, with a luminance value of 100.
+    fn white_xyz<I, C>(&self) -> CieXYZ<C>
+    where
+        C: StandardObserver,
+        I: Default,
+        I: SpectralDistribution,
+        Meter: From<<<Self as SpectralDistribution>::StepType as Step>::UnitValueType>,
+        <<I as SpectralDistribution>::StepType as Step>::UnitValueType:
+            From<<<Self as SpectralDistribution>::StepType as Step>::UnitValueType>,
+    {
+        let (xyzn, _) = tristimulus_values::<Self, I, C>(self);
+        CieXYZ::new(Matrix3xX::from_column_slice((xyzn * (100.0 / xyzn.y)).as_slice()))
+    }
+
+    /// Luminance values, and chromaticity coordinates, of the swatches, illuminated by illuminant `I`, for observer
+    /// `C`, with a luminance value of 100 for a perfect white reflector.
+    fn yxy_under<I, C>(&self) -> CieYxy<C>
+    where
+        C: StandardObserver,
+        I: Default,
+        I: SpectralDistribution,
+        Meter: From<<<Self as SpectralDistribution>::StepType as Step>::UnitValueType>,
+        <<I as SpectralDistribution>::StepType as Step>::UnitValueType:
+            From<<<Self as SpectralDistribution>::StepType as Step>::UnitValueType>,
+    {
+        self.xyz_under::<I, C>().into()
     }
 }
+
+/// Unscaled tristimulus values of the illuminant, and of the swatches illuminated by it, on the domain of the swatches.
+fn tristimulus_values<S, I, C>(sw: &S) -> (Matrix3x1<f64>, Matrix3xX<f64>)
+where
+    S: Swatch + ?Sized,
+    C: StandardObserver,
+    I: Default,
+    I: SpectralDistribution,
+    Meter: From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType:
+        From<<<S as SpectralDistribution>::StepType as Step>::UnitValueType>,
+{
+    let (d, s) = sw.spd();
+    let c = C::values_cached(&d);
+    let l = I::default().map_domain(d.clone());
+    let m: DMatrix<f64> =
+        DMatrix::from_fn(l.nrows(), sw.shape().1, |i, j| l[(i, 0)] * s[(i, j)]);
+    let xyzn = c.as_ref() * l.column(0) * C::K * d.step.unitvalue(1).value();
+    let xyz = c.as_ref() * m * C::K * d.step.unitvalue(1).value();
+    (xyzn, xyz)
+}
+
 /**
     Macro to define a a swatch library from static data, and implement its `Swatch` traits.
 
@@ -59,7 +170,7 @@ where
 #[macro_export]
 macro_rules! swatch {
     // a single illuminant from static slice column
-    ($SWATCH:ident, $N:expr, $M:expr, $DESC:literal, $DOMAIN:expr, $DATA:ident) => {
+    ($SWATCH:ident, $N:expr, $M:expr, $DESC:literal, $DOMAIN:expr, $DATA:expr) => {
         #[derive(Debug, Default)]
         pub struct $SWATCH<const J: usize>;
 
@@ -104,7 +215,7 @@ macro_rules! swatch {
         }
     };
     // all swatches as array with keys
-    ($SWATCH:ident, $N:expr, $M:expr, $DESC:literal, $DOMAIN:expr, $DATA:ident, $KEYS:ident) => {
+    ($SWATCH:ident, $N:expr, $M:expr, $DESC:literal, $DOMAIN:expr, $DATA:expr, $KEYS:expr) => {
         #[derive(Debug, Default)]
         pub struct $SWATCH;
 
@@ -220,3 +331,66 @@ impl<const R: usize> Swatch for Gray<R> {}
 
 
 pub use swatch;
+
+#[test]
+fn test_swatch_xyz() {
+    use crate::illuminants::CieIllD65;
+    use crate::observers::CieObs1931;
+    use approx::assert_abs_diff_eq;
+
+    // a 50% gray has half the luminance, and the chromaticity, of the white point
+    let xyz = G50::default().xyz_under::<CieIllD65, CieObs1931>();
+    let white = G50::default().white_xyz::<CieIllD65, CieObs1931>();
+    assert_abs_diff_eq!(xyz.data, &white.data * 0.5, epsilon = 1E-9);
+    assert_abs_diff_eq!(white.data[(1, 0)], 100.0, epsilon = 1E-9);
+    let yxy = G50::default().yxy_under::<CieIllD65, CieObs1931>();
+    assert_abs_diff_eq!(yxy.yxy(0)[1], 0.31272, epsilon = 5E-5);
+    assert_abs_diff_eq!(yxy.yxy(0)[2], 0.32903, epsilon = 5E-5);
+
+    // consistent with the CIELAB values
+    let sw = Neutral::new(0.3).set_tilt(0.2);
+    let lab: CieLab<CieIllD65, CieObs1931> = sw.lab();
+    let xyz = sw.xyz_under::<CieIllD65, CieObs1931>();
+    let lab2 = cielab(
+        Matrix3x1::from_column_slice(sw.white_xyz::<CieIllD65, CieObs1931>().data.as_slice()),
+        xyz.data,
+    );
+    assert_abs_diff_eq!(lab.data, lab2, epsilon = 1E-9);
+
+    // `SpectralDistribution::xyz` is not ambiguous for spectra which are swatches too
+    let d65 = CieIllD65.to_dynamic().xyz::<CieObs1931>();
+    assert_eq!(d65.data, SpectralDistribution::xyz::<CieObs1931>(&CieIllD65).data);
+}
+
+#[test]
+fn test_swatch_keys() {
+    use crate::illuminants::CieIllD65;
+    use crate::observers::CieObs1931;
+
+    let ramp = NeutralRamp::linear(3, 0.2, 0.6);
+    let lab: CieLab<CieIllD65, CieObs1931> = ramp.lab();
+    assert_eq!(lab.keys(), ramp.keys());
+    assert_eq!(lab.get_by_key("40.00%").unwrap(), {
+        let c = lab.data.column(1);
+        [c.x, c.y, c.z]
+    });
+    assert_eq!(
+        lab.get_by_key("50.00%").unwrap_err(),
+        crate::KeyError {
+            key: "50.00%".to_string()
+        }
+    );
+
+    // tristimulus values, and chromaticities, keep their keys
+    let xyz = ramp.xyz_under::<CieIllD65, CieObs1931>().normalize(50.0);
+    assert_eq!(xyz.get_by_key("60.00%").unwrap()[1], 50.0);
+    let yxy: CieYxy<CieObs1931> = xyz.into();
+    assert_eq!(yxy.get_by_key("20.00%").unwrap(), yxy.yxy(0));
+    let xyz: CieXYZ<CieObs1931> = lab.into();
+    assert_eq!(xyz.keys().unwrap()[2], "60.00%");
+
+    // unkeyed values
+    let lab = CieLab::<CieIllD65, CieObs1931>::new(vec![50.0, 0.0, 0.0]);
+    assert!(lab.get_by_key("a").is_err());
+    assert_eq!(lab.set_keys(&["a"]).get_by_key("a").unwrap(), [50.0, 0.0, 0.0]);
+}
@@ -0,0 +1,292 @@
+/*!
+Neutral swatches, with a reflectance value set at runtime.
+
+`Gray` swatches have a reflectance in whole percents, set at compile time. A `Neutral` swatch has an arbitrary
+reflectance value, and, optionally, a spectral tilt, to simulate the slightly colored grays of real targets. A
+`NeutralRamp` is a collection of neutral swatches, with reflectance values in equal steps, or in equal steps of CIE
+lightness, as used for the linearization of cameras, scanners, and printers.
+
+The spectral tilt is the relative change of the reflectance per 100nm, with respect to the reflectance at 560nm: a
+tilt of 0.1 results in a reflectance which is 10% higher at 660nm, and 10% lower at 460nm. Reflectance values are
+limited to the range from 0.0 to 1.0.
+*/
+
+use nalgebra::DMatrix;
+
+use crate::illuminants::Illuminant;
+use crate::models::CieLab;
+use crate::observers::StandardObserver;
+use crate::{Domain, Meter, SpectralDistribution, Step, Unit, WavelengthStep};
+
+use super::Swatch;
+
+/// Wavelength, in meter, at which the reflectance of a tilted neutral swatch equals its nominal reflectance.
+const TILT_CENTER: f64 = 560E-9;
+
+fn tilted(reflectance: f64, tilt: f64, wavelength: f64) -> f64 {
+    (reflectance * (1.0 + tilt * (wavelength - TILT_CENTER) / 100E-9)).clamp(0.0, 1.0)
+}
+
+/**
+A neutral swatch, with a reflectance value, from 0.0 to 1.0, and an optional spectral tilt.
+
+# Examples
+An 18% gray card, and its CIE lightness.
+```
+use scot::illuminants::CieIllD65;
+use scot::models::CieLab;
+use scot::observers::CieObs1931;
+use scot::swatches::Neutral;
+
+let lab: CieLab<CieIllD65, CieObs1931> = Neutral::new(0.18).into();
+assert!((lab.data[(0, 0)] - 49.5).abs() < 0.1);
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Neutral {
+    reflectance: f64,
+    tilt: f64,
+}
+
+impl Neutral {
+    pub fn new(reflectance: f64) -> Self {
+        Self {
+            reflectance,
+            tilt: 0.0,
+        }
+    }
+
+    /// Sets the relative change of the reflectance per 100nm, with respect to its value at 560nm.
+    pub fn set_tilt(mut self, tilt: f64) -> Self {
+        self.tilt = tilt;
+        self
+    }
+
+    pub fn reflectance(&self) -> f64 {
+        self.reflectance
+    }
+
+    pub fn tilt(&self) -> f64 {
+        self.tilt
+    }
+}
+
+impl SpectralDistribution for Neutral {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let d = Domain::default();
+        let data = self.map_domain(d.clone());
+        (d, data)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (Domain::<WavelengthStep>::default().len(), 1)
+    }
+
+    fn map_domain<S2: Step>(&self, dto: Domain<S2>) -> DMatrix<f64>
+    where
+        <<Self as SpectralDistribution>::StepType as Step>::UnitValueType: From<S2::UnitValueType>,
+    {
+        DMatrix::from_iterator(
+            dto.len(),
+            1,
+            dto.iter()
+                .map(|l| tilted(self.reflectance, self.tilt, Meter::from(l).value())),
+        )
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(format!("Neutral {:.2}%", self.reflectance * 100.0))
+    }
+}
+
+impl Swatch for Neutral {}
+
+impl<I: Illuminant, C: StandardObserver> From<Neutral> for CieLab<I, C>
+where
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+{
+    fn from(n: Neutral) -> Self {
+        n.lab()
+    }
+}
+
+/**
+A collection of neutral swatches, with reflectance values in equal steps, or in equal steps of CIE lightness, and an
+optional common spectral tilt.
+
+# Examples
+A ramp of 11 patches, from black to white, in steps of 10 L\* units.
+```
+use scot::illuminants::CieIllD65;
+use scot::models::CieLab;
+use scot::observers::CieObs1931;
+use scot::swatches::NeutralRamp;
+
+let ramp = NeutralRamp::lightness(11, 0.0, 100.0);
+let lab: CieLab<CieIllD65, CieObs1931> = ramp.into();
+assert!((lab.data[(0, 5)] - 50.0).abs() < 1E-6);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct NeutralRamp {
+    levels: Vec<f64>,
+    tilt: f64,
+}
+
+impl NeutralRamp {
+    /// A ramp with reflectance values, from 0.0 to 1.0.
+    pub fn new(levels: Vec<f64>) -> Self {
+        Self { levels, tilt: 0.0 }
+    }
+
+    /// A ramp of `n` patches, with reflectance values in equal steps, from `min` to `max`.
+    pub fn linear(n: usize, min: f64, max: f64) -> Self {
+        Self::new(steps(n, min, max).collect())
+    }
+
+    /// A ramp of `n` patches, with reflectance values in equal steps of CIE lightness L\*, from `l_min` to `l_max`.
+    pub fn lightness(n: usize, l_min: f64, l_max: f64) -> Self {
+        Self::new(steps(n, l_min, l_max).map(lightness_to_y).collect())
+    }
+
+    /// Sets a spectral tilt, for all the patches in the ramp.
+    pub fn set_tilt(mut self, tilt: f64) -> Self {
+        self.tilt = tilt;
+        self
+    }
+
+    pub fn levels(&self) -> &[f64] {
+        &self.levels
+    }
+
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// The neutral swatch with index `i`.
+    pub fn get(&self, i: usize) -> Neutral {
+        Neutral::new(self.levels[i]).set_tilt(self.tilt)
+    }
+}
+
+impl SpectralDistribution for NeutralRamp {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let d = Domain::default();
+        let data = self.map_domain(d.clone());
+        (d, data)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (Domain::<WavelengthStep>::default().len(), self.levels.len())
+    }
+
+    fn map_domain<S2: Step>(&self, dto: Domain<S2>) -> DMatrix<f64>
+    where
+        <<Self as SpectralDistribution>::StepType as Step>::UnitValueType: From<S2::UnitValueType>,
+    {
+        let wl: Vec<f64> = dto.iter().map(|l| Meter::from(l).value()).collect();
+        DMatrix::from_fn(wl.len(), self.levels.len(), |i, j| {
+            tilted(self.levels[j], self.tilt, wl[i])
+        })
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        Some(
+            self.levels
+                .iter()
+                .map(|r| format!("{:.2}%", r * 100.0))
+                .collect(),
+        )
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("Neutral Ramp".to_string())
+    }
+}
+
+impl Swatch for NeutralRamp {}
+
+impl<I: Illuminant, C: StandardObserver> From<NeutralRamp> for CieLab<I, C>
+where
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+{
+    fn from(r: NeutralRamp) -> Self {
+        r.lab()
+    }
+}
+
+/// `n` values in equal steps, from `min` to `max`.
+fn steps(n: usize, min: f64, max: f64) -> impl Iterator<Item = f64> {
+    (0..n).map(move |i| {
+        if n > 1 {
+            min + (max - min) * i as f64 / (n - 1) as f64
+        } else {
+            min
+        }
+    })
+}
+
+/// Relative luminance, from 0.0 to 1.0, for a CIE lightness value L\*.
+fn lightness_to_y(l: f64) -> f64 {
+    let f = (l + 16.0) / 116.0;
+    if f > 6.0 / 29.0 {
+        f.powi(3)
+    } else {
+        3.0 * (6.0f64 / 29.0).powi(2) * (f - 4.0 / 29.0)
+    }
+}
+
+#[test]
+fn test_neutral() {
+    use crate::illuminants::{CieIllA, CieIllD65};
+    use crate::observers::CieObs1931;
+    use crate::NM5;
+    use approx::assert_abs_diff_eq;
+
+    // the same as the integer percent grays
+    let lab: CieLab<CieIllA, CieObs1931> = Neutral::new(0.2).into();
+    let lab_ref: CieLab<CieIllA, CieObs1931> = super::G20::default().into();
+    assert_abs_diff_eq!(lab.data, lab_ref.data, epsilon = 1E-9);
+
+    // tilt
+    let d = Domain::new(460 / 5, 660 / 5, NM5);
+    let r = Neutral::new(0.5).set_tilt(0.1).map_domain(d);
+    assert_abs_diff_eq!(r[0], 0.45, epsilon = 1E-12);
+    assert_abs_diff_eq!(r[20], 0.5, epsilon = 1E-12);
+    assert_abs_diff_eq!(r[40], 0.55, epsilon = 1E-12);
+    let lab: CieLab<CieIllD65, CieObs1931> = Neutral::new(0.5).set_tilt(0.2).into();
+    assert!(lab.data[(2, 0)] > 2.0); // yellowish
+    assert_eq!(
+        Neutral::new(0.9)
+            .set_tilt(1.0)
+            .map_domain(Domain::default())
+            .max(),
+        1.0
+    );
+
+    // ramps
+    let ramp = NeutralRamp::linear(5, 0.1, 0.9);
+    assert_eq!(ramp.len(), 5);
+    assert_abs_diff_eq!(ramp.levels()[2], 0.5, epsilon = 1E-12);
+    assert_eq!(ramp.keys().unwrap()[4], "90.00%");
+    assert_abs_diff_eq!(
+        ramp.set_tilt(0.1).get(1).reflectance(),
+        0.3,
+        epsilon = 1E-12
+    );
+    let lab: CieLab<CieIllD65, CieObs1931> = NeutralRamp::lightness(6, 5.0, 95.0).into();
+    for j in 0..6 {
+        assert_abs_diff_eq!(lab.data[(0, j)], 5.0 + 18.0 * j as f64, epsilon = 1E-6);
+        assert_abs_diff_eq!(lab.data[(1, j)], 0.0, epsilon = 1E-6);
+    }
+}
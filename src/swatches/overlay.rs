@@ -0,0 +1,247 @@
+/*!
+Swatches derived from other swatches, by an overlaid transparent layer, or by a linear transformation of their
+reflectance values.
+
+A varnish, a laminate, or a glass plate, on top of a sample, attenuates the light twice: once on its way to the
+sample, and once more after its reflection. `Overlaid` multiplies the reflectance spectra of a swatch by the square of
+the transmission spectrum of such a layer. `Scaled` multiplies reflectance spectra by a factor, and adds an offset,
+for example to add the reflection at the front surface of a layer, or to simulate a loss of contrast.
+Both can be combined, and are swatches themselves.
+
+# Examples
+A sample, laminated with a foil with a transmission of 90%, and a front surface reflection of 4%.
+```
+use scot::swatches::{DataSwatch, Swatch};
+use scot::{Domain, SpectralDistribution, NM10};
+
+let d = Domain::new(38, 78, NM10);
+let sample = DataSwatch::new(d.clone(), vec![0.5; 41]);
+let foil = DataSwatch::new(d, vec![0.9; 41]);
+let laminated = sample.overlaid(foil).scaled(0.96, 0.04);
+assert!((laminated.spd().1[(0, 0)] - (0.96 * 0.5 * 0.81 + 0.04)).abs() < 1E-12);
+```
+*/
+
+use nalgebra::DMatrix;
+
+use crate::illuminants::Illuminant;
+use crate::models::CieLab;
+use crate::observers::StandardObserver;
+use crate::{lin_interp_mat_col, Domain, Meter, SpectralDistribution, Step, WavelengthStep};
+
+use super::{MeasurementConditions, Swatch};
+
+/**
+Reflectance spectra of a swatch, seen through a transparent layer, such as a varnish, or a glass plate.
+
+The transmission spectra of the layer are linearly interpolated to the domain of the swatch, and are taken to be zero
+outside of their own domain. A layer with more than one transmission spectrum is treated as a stack of layers.
+Multiple reflections between the layer and the sample are ignored.
+*/
+#[derive(Debug, Clone)]
+pub struct Overlaid<S, F> {
+    swatch: S,
+    layer: F,
+}
+
+impl<S, F> Overlaid<S, F> {
+    pub fn new(swatch: S, layer: F) -> Self {
+        Self { swatch, layer }
+    }
+
+    pub fn swatch(&self) -> &S {
+        &self.swatch
+    }
+
+    pub fn layer(&self) -> &F {
+        &self.layer
+    }
+}
+
+impl<S: Default, F: Default> Default for Overlaid<S, F> {
+    fn default() -> Self {
+        Self::new(S::default(), F::default())
+    }
+}
+
+impl<S, F> SpectralDistribution for Overlaid<S, F>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+    F: SpectralDistribution<StepType = WavelengthStep>,
+{
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let (d, s) = self.swatch.spd();
+        let (n, m) = self.swatch.shape();
+        let (dl, l) = self.layer.spd();
+        let t = lin_interp_mat_col(&dl, &d, self.layer.shape().1, l);
+        let t2: Vec<f64> = t
+            .row_iter()
+            .map(|r| r.iter().product::<f64>().powi(2))
+            .collect();
+        (d, DMatrix::from_fn(n, m, |i, j| s[(i, j)] * t2[i]))
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.swatch.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        self.swatch.keys()
+    }
+
+    fn description(&self) -> Option<String> {
+        match (self.swatch.description(), self.layer.description()) {
+            (Some(s), Some(l)) => Some(format!("{}, overlaid with {}", s, l)),
+            (s, _) => s,
+        }
+    }
+}
+
+impl<S, F> Swatch for Overlaid<S, F>
+where
+    S: Swatch<StepType = WavelengthStep>,
+    F: SpectralDistribution<StepType = WavelengthStep>,
+{
+    fn measurement(&self) -> Option<MeasurementConditions> {
+        self.swatch.measurement()
+    }
+}
+
+impl<S, F, I, C> From<Overlaid<S, F>> for CieLab<I, C>
+where
+    S: Swatch<StepType = WavelengthStep>,
+    F: SpectralDistribution<StepType = WavelengthStep>,
+    I: Illuminant,
+    C: StandardObserver,
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+{
+    fn from(sw: Overlaid<S, F>) -> Self {
+        sw.lab()
+    }
+}
+
+/// Reflectance spectra of a swatch, multiplied by a scale factor, with an offset added.
+#[derive(Debug, Clone)]
+pub struct Scaled<S> {
+    swatch: S,
+    scale: f64,
+    offset: f64,
+}
+
+impl<S> Scaled<S> {
+    pub fn new(swatch: S, scale: f64, offset: f64) -> Self {
+        Self {
+            swatch,
+            scale,
+            offset,
+        }
+    }
+
+    pub fn swatch(&self) -> &S {
+        &self.swatch
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+}
+
+impl<S> SpectralDistribution for Scaled<S>
+where
+    S: SpectralDistribution<StepType = WavelengthStep>,
+{
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let (d, s) = self.swatch.spd();
+        let (n, m) = self.swatch.shape();
+        (
+            d,
+            DMatrix::from_fn(n, m, |i, j| self.scale * s[(i, j)] + self.offset),
+        )
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.swatch.shape()
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        self.swatch.keys()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.swatch.description()
+    }
+}
+
+impl<S> Swatch for Scaled<S>
+where
+    S: Swatch<StepType = WavelengthStep>,
+{
+    fn measurement(&self) -> Option<MeasurementConditions> {
+        self.swatch.measurement()
+    }
+}
+
+impl<S, I, C> From<Scaled<S>> for CieLab<I, C>
+where
+    S: Swatch<StepType = WavelengthStep>,
+    I: Illuminant,
+    C: StandardObserver,
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+{
+    fn from(sw: Scaled<S>) -> Self {
+        sw.lab()
+    }
+}
+
+#[test]
+fn test_overlay() {
+    use crate::illuminants::CieIllD65;
+    use crate::observers::CieObs1931;
+    use crate::swatches::{DataSwatch, SwatchLibrary};
+    use crate::NM5;
+    use approx::assert_abs_diff_eq;
+
+    let d = Domain::new(380 / 5, 780 / 5, NM5);
+    let data = DMatrix::from_fn(81, 2, |i, j| 0.2 + 0.3 * j as f64 + 0.001 * i as f64);
+    let lib =
+        SwatchLibrary::from_columns(d.clone(), data.clone(), &["a", "b"]).set_description("lib");
+
+    // a clear layer does not change the swatch
+    let clear = DataSwatch::new(d.clone(), vec![1.0; 81]);
+    assert_eq!(lib.clone().overlaid(clear).spd().1, data);
+
+    // a yellow varnish, blocking blue below 450nm; its transmission squared
+    let varnish = DataSwatch::new(
+        Domain::new(300 / 5, 830 / 5, NM5),
+        (300..=830)
+            .step_by(5)
+            .map(|l| if l < 450 { 0.1 } else { 0.9 })
+            .collect(),
+    );
+    let coated = lib.clone().overlaid(varnish);
+    let (_, v) = coated.spd();
+    assert_abs_diff_eq!(v[(0, 1)], data[(0, 1)] * 0.01, epsilon = 1E-12);
+    assert_abs_diff_eq!(v[(80, 0)], data[(80, 0)] * 0.81, epsilon = 1E-12);
+    assert_eq!(coated.keys().unwrap(), vec!["a", "b"]);
+    let lab: CieLab<CieIllD65, CieObs1931> = coated.into();
+    let lab_ref: CieLab<CieIllD65, CieObs1931> = lib.clone().into();
+    for j in 0..2 {
+        assert!(lab.data[(0, j)] < lab_ref.data[(0, j)]); // darker
+        assert!(lab.data[(2, j)] > lab_ref.data[(2, j)] + 10.0); // and yellower
+    }
+
+    let scaled = lib.scaled(0.5, 0.1);
+    let (_, v) = scaled.spd();
+    assert_abs_diff_eq!(v[(10, 1)], 0.5 * data[(10, 1)] + 0.1, epsilon = 1E-12);
+    assert_eq!(scaled.description().unwrap(), "lib");
+}
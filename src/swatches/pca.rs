@@ -0,0 +1,147 @@
+/*!
+Principal component analysis of swatch collections.
+
+The reflectance spectra of natural, and most man-made, colored materials are smooth, and a collection of them can be
+described, with a good approximation, by a mean spectrum, and a weighted sum of a small number of basis spectra. For
+the Munsell matt collection, for example, three to eight basis spectra are sufficient for most applications.
+This is used for compact storage of spectral data, and for spectral estimation, such as the recovery of reflectance
+spectra from camera, or colorimeter, readings.
+*/
+
+use nalgebra::{DMatrix, DVector, SymmetricEigen};
+
+use crate::swatches::Swatch;
+use crate::{Domain, DynamicSpectralDistribution, SpectralDistribution, WavelengthStep};
+
+/**
+Mean spectrum, and principal component basis spectra, of a swatch collection.
+
+The basis spectra are ordered by decreasing variance, and have unit length.
+
+# Examples
+```
+use nalgebra::DMatrix;
+use scot::swatches::PrincipalComponents;
+use scot::{Domain, DynamicSpectralDistribution, SpectralDistribution, NM10};
+
+let d = Domain::new(38, 73, NM10);
+let samples = DynamicSpectralDistribution::new(
+    d,
+    DMatrix::from_fn(36, 20, |i, j| 0.5 + 0.02 * j as f64 * ((i + j) as f64 / 10.0).sin()),
+);
+let pca = PrincipalComponents::new(&samples, 4);
+let weights = pca.weights(&samples);
+let estimate = pca.reconstruct(&weights);
+assert_eq!(estimate.shape(), (36, 20));
+```
+*/
+#[derive(Debug, Clone)]
+pub struct PrincipalComponents {
+    domain: Domain<WavelengthStep>,
+    mean: DVector<f64>,
+    components: DMatrix<f64>,
+    variances: DVector<f64>,
+    total_variance: f64,
+}
+
+impl PrincipalComponents {
+    /// Calculates the mean, and the first `k` principal components, of a collection of swatches.
+    pub fn new<S>(swatches: &S, k: usize) -> Self
+    where
+        S: Swatch<StepType = WavelengthStep>,
+    {
+        let (domain, s) = swatches.spd();
+        let (n, m) = swatches.shape();
+        let data = DMatrix::from_fn(n, m, |i, j| s[(i, j)]);
+        let mean = data.column_mean();
+        let centered = DMatrix::from_fn(n, m, |i, j| data[(i, j)] - mean[i]);
+        let covariance = &centered * centered.transpose() / (m.max(2) - 1) as f64;
+        let eigen = SymmetricEigen::new(covariance);
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| {
+            eigen.eigenvalues[b]
+                .partial_cmp(&eigen.eigenvalues[a])
+                .unwrap()
+        });
+        let k = k.min(n);
+        let components = DMatrix::from_fn(n, k, |i, j| eigen.eigenvectors[(i, order[j])]);
+        let variances = DVector::from_fn(k, |j, _| eigen.eigenvalues[order[j]].max(0.0));
+        Self {
+            domain,
+            mean,
+            components,
+            variances,
+            total_variance: eigen.eigenvalues.iter().map(|v| v.max(0.0)).sum(),
+        }
+    }
+
+    pub fn domain(&self) -> &Domain<WavelengthStep> {
+        &self.domain
+    }
+
+    /// Mean spectrum of the collection.
+    pub fn mean(&self) -> &DVector<f64> {
+        &self.mean
+    }
+
+    /// Principal component basis spectra, as columns.
+    pub fn components(&self) -> &DMatrix<f64> {
+        &self.components
+    }
+
+    /// Fraction of the total variance of the collection, explained by each of the principal components.
+    pub fn explained_variance(&self) -> DVector<f64> {
+        &self.variances / self.total_variance
+    }
+
+    /**
+    Weights of the principal components, as a matrix with a column for each of the spectral distributions in a
+    collection, which are linearly interpolated to the domain of the principal components if required.
+    */
+    pub fn weights<S>(&self, sd: &S) -> DMatrix<f64>
+    where
+        S: SpectralDistribution<StepType = WavelengthStep>,
+    {
+        let mut s = sd.map_domain(self.domain.clone());
+        for mut c in s.column_iter_mut() {
+            c -= &self.mean;
+        }
+        self.components.transpose() * s
+    }
+
+    /// Spectral distributions reconstructed from principal component weights, with a column for each spectrum.
+    pub fn reconstruct(&self, weights: &DMatrix<f64>) -> DynamicSpectralDistribution {
+        let k = weights.nrows().min(self.components.ncols());
+        let mut data = self.components.columns(0, k) * weights.rows(0, k);
+        for mut c in data.column_iter_mut() {
+            c += &self.mean;
+        }
+        DynamicSpectralDistribution::new(self.domain.clone(), data)
+    }
+}
+
+#[test]
+fn test_pca() {
+    use crate::NM5;
+    use approx::assert_abs_diff_eq;
+
+    // spectra composed of two basis functions are reconstructed from two principal components
+    let d = Domain::new(76, 156, NM5);
+    let n = d.len();
+    let data = DMatrix::from_fn(n, 30, |i, j| {
+        let x = i as f64 / n as f64;
+        0.4 + 0.01 * j as f64 * x + 0.2 * ((j % 7) as f64 / 7.0) * (6.0 * x).sin()
+    });
+    let samples = DynamicSpectralDistribution::new(d, data);
+    let pca = PrincipalComponents::new(&samples, 3);
+    let ev = pca.explained_variance();
+    assert_abs_diff_eq!(ev[0] + ev[1], 1.0, epsilon = 1E-9);
+    assert!(ev[0] >= ev[1]);
+    assert_abs_diff_eq!(pca.components().column(0).norm(), 1.0, epsilon = 1E-9);
+
+    let w = pca.weights(&samples);
+    assert_eq!(w.shape(), (3, 30));
+    let r = pca.reconstruct(&w.rows(0, 2).into_owned());
+    assert_abs_diff_eq!(r.data(), samples.data(), epsilon = 1E-9);
+}
@@ -0,0 +1,167 @@
+/*!
+Reflectance spectra reconstructed from colorimetric values.
+
+Many color collections, such as paint and coating standards, are specified by their CIELAB values only. To use them
+with other illuminants, or observers, or in spectral calculations, smooth reflectance spectra, which reproduce these
+values for the illuminant and observer of their specification, can be used as an approximation.
+*/
+
+use nalgebra::{DMatrix, DVector, Matrix3xX, Vector3};
+
+use crate::illuminants::Illuminant;
+use crate::models::lab_to_xyz;
+use crate::observers::StandardObserver;
+use crate::{Domain, Meter, SpectralDistribution, Step, Unit, WavelengthStep};
+
+/// Relative weight of the slope of the reflectance spectra, with respect to their curvature, in the reconstruction.
+const SLOPE_WEIGHT: f64 = 1E-3;
+
+/**
+Smoothest reflectance spectra, on a domain, with values between 0.0 and 1.0, with CIELAB values, for illuminant `I`,
+and observer `C`.
+
+The spectra minimize the sum of the squared second differences of their values, with a small contribution of their
+first differences, with the CIELAB values as constraints, and with values out of the range from 0.0 to 1.0 clipped, and the spectra solved again, until all
+values are in range. CIELAB values outside of the object color solid can not be reproduced, and result in spectra
+with NaN values.
+
+# Examples
+```
+use scot::illuminants::CieIllD65;
+use scot::models::CieLab;
+use scot::observers::CieObs1964;
+use scot::swatches::reflectance_from_lab;
+use scot::{Domain, DynamicSpectralDistribution, NM5};
+
+let d = Domain::new(380 / 5, 780 / 5, NM5);
+let r = reflectance_from_lab::<CieIllD65, CieObs1964>(&[[41.0, 55.0, 31.0]], &d);
+let lab: CieLab<CieIllD65, CieObs1964> = DynamicSpectralDistribution::new(d, r).into();
+assert!((lab.data[(1, 0)] - 55.0).abs() < 1E-6);
+```
+*/
+pub fn reflectance_from_lab<I, C>(lab: &[[f64; 3]], domain: &Domain<WavelengthStep>) -> DMatrix<f64>
+where
+    I: Illuminant + Default,
+    C: StandardObserver,
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+{
+    let n = domain.len();
+    let cmf = C::values_cached(domain);
+    let l = I::default().map_domain(domain.clone());
+    let dl = domain.step.unitvalue(1).value();
+    let a = DMatrix::from_fn(3, n, |r, i| cmf[(r, i)] * l[(i, 0)] * C::K * dl);
+    let xyzn = a.column_sum();
+
+    // curvature, and slope, penalty; a constant spectrum has none
+    let mut q = DMatrix::zeros(n, n);
+    let mut penalize = |d: &[(usize, f64)], w: f64| {
+        for &(j, dj) in d {
+            for &(k, dk) in d {
+                q[(j, k)] += w * dj * dk;
+            }
+        }
+    };
+    for i in 1..n {
+        penalize(&[(i - 1, -1.0), (i, 1.0)], SLOPE_WEIGHT);
+        if i + 1 < n {
+            penalize(&[(i - 1, 1.0), (i, -2.0), (i + 1, 1.0)], 1.0);
+        }
+    }
+
+    let mut spectra = DMatrix::from_element(n, lab.len(), f64::NAN);
+    for (c, &[lv, av, bv]) in lab.iter().enumerate() {
+        // tristimulus values, scaled to the white point
+        let xyz = lab_to_xyz(
+            Vector3::new(xyzn[0], xyzn[1], xyzn[2]),
+            Matrix3xX::from_column_slice(&[lv, av, bv]),
+        ) * (xyzn[1] / 100.0);
+        let mut fixed: Vec<(usize, f64)> = Vec::new();
+        while let Some(r) = constrained_solve(&q, &a, xyz.as_slice(), &fixed) {
+            let out: Vec<(usize, f64)> = r
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| !fixed.iter().any(|&(j, _)| i == j))
+                .filter_map(|(i, &v)| match v {
+                    v if v < 0.0 => Some((i, 0.0)),
+                    v if v > 1.0 => Some((i, 1.0)),
+                    _ => None,
+                })
+                .collect();
+            if out.is_empty() {
+                spectra.set_column(c, &r.map(|v| v.clamp(0.0, 1.0)));
+                break;
+            }
+            fixed.extend(out);
+        }
+    }
+    spectra
+}
+
+/// Minimizes r<sup>T</sup>Qr, with Ar = t, and with the fixed values, using the Lagrange multiplier method.
+fn constrained_solve(
+    q: &DMatrix<f64>,
+    a: &DMatrix<f64>,
+    t: &[f64],
+    fixed: &[(usize, f64)],
+) -> Option<DVector<f64>> {
+    let n = q.nrows();
+    let m = a.nrows() + fixed.len();
+    if m > n {
+        return None;
+    }
+    let mut kkt = DMatrix::zeros(n + m, n + m);
+    let mut rhs = DVector::zeros(n + m);
+    kkt.slice_mut((0, 0), (n, n)).copy_from(q);
+    for r in 0..a.nrows() {
+        for i in 0..n {
+            kkt[(n + r, i)] = a[(r, i)];
+            kkt[(i, n + r)] = a[(r, i)];
+        }
+        rhs[n + r] = t[r];
+    }
+    for (k, &(i, v)) in fixed.iter().enumerate() {
+        let r = n + a.nrows() + k;
+        kkt[(r, i)] = 1.0;
+        kkt[(i, r)] = 1.0;
+        rhs[r] = v;
+    }
+    let x = kkt.lu().solve(&rhs)?;
+    if x.iter().all(|v| v.is_finite()) {
+        Some(x.rows(0, n).into_owned())
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_reflectance_from_lab() {
+    use crate::illuminants::{CieIllA, CieIllD65};
+    use crate::models::CieLab;
+    use crate::observers::CieObs1931;
+    use crate::{DynamicSpectralDistribution, NM5};
+    use approx::assert_abs_diff_eq;
+
+    let d = Domain::new(380 / 5, 780 / 5, NM5);
+    let targets = [
+        [50.0, 0.0, 0.0],
+        [41.0, 55.0, 31.0],
+        [88.0, -5.0, 85.0],
+        [30.0, 10.0, -45.0],
+    ];
+    let r = reflectance_from_lab::<CieIllD65, CieObs1931>(&targets, &d);
+    assert!(r.iter().all(|&v| (0.0..=1.0).contains(&v)));
+    let lab: CieLab<CieIllD65, CieObs1931> = DynamicSpectralDistribution::new(d.clone(), r).into();
+    for (j, t) in targets.iter().enumerate() {
+        for (k, x) in t.iter().enumerate() {
+            assert_abs_diff_eq!(lab.data[(k, j)], x, epsilon = 1E-6);
+        }
+    }
+
+    // a neutral gray is flat
+    let gray = reflectance_from_lab::<CieIllA, CieObs1931>(&[[50.0, 0.0, 0.0]], &d);
+    assert!(gray.max() - gray.min() < 1E-3);
+
+    // colors outside of the object color solid can not be reproduced
+    let r = reflectance_from_lab::<CieIllD65, CieObs1931>(&[[95.0, 80.0, 0.0]], &d);
+    assert!(r[(0, 0)].is_nan());
+}
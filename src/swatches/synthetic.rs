@@ -0,0 +1,302 @@
+/*!
+Synthetic reflectance spectra, with simple parametric shapes.
+
+Controlled sets of test samples, for example to study the behavior of color difference formulas, or color appearance
+models, are easily constructed from reflectance spectra with a few parameters:
+
+- a Gaussian bump, with a center wavelength, and a full width at half maximum,
+- a sigmoid edge, with a transition wavelength, and a width, which is the scale of a logistic function: the
+  reflectance changes from 27% to 73% of the transition over twice this width,
+- a block dye, with a constant reflectance within a wavelength range, and another constant reflectance outside, as
+  used to construct optimal, or MacAdam, colors.
+
+Each shape has two reflectance levels: for a Gaussian bump, its base, and its peak value; for a sigmoid edge, its
+values at short, and at long, wavelengths; and for a block dye, its value outside, and inside, its range.
+All wavelengths are in nanometer.
+
+The spectra are calculated for any domain, and are exact, without interpolation.
+
+# Examples
+A set of Gaussian bumps, with increasing center wavelengths, and their CIELAB values.
+```
+use scot::illuminants::CieIllD65;
+use scot::models::CieLab;
+use scot::observers::CieObs1931;
+use scot::swatches::{Synthetic, SyntheticSet};
+
+let set: SyntheticSet = (0..8).map(|i| Synthetic::gaussian(420.0 + 40.0 * i as f64, 60.0, 0.1, 0.8)).collect();
+let lab: CieLab<CieIllD65, CieObs1931> = set.into();
+let [_, a, b] = lab.get_by_key("gaussian 500/60").unwrap();
+assert!(a < 0.0 && b.abs() < a.abs()); // bluish green
+```
+*/
+
+use nalgebra::DMatrix;
+
+use crate::illuminants::Illuminant;
+use crate::models::CieLab;
+use crate::observers::StandardObserver;
+use crate::{Domain, Meter, SpectralDistribution, Step, Unit, WavelengthStep};
+
+use super::Swatch;
+
+/// A synthetic reflectance spectrum, with a parametric shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Synthetic {
+    Gaussian {
+        center: f64,
+        fwhm: f64,
+        base: f64,
+        peak: f64,
+    },
+    Sigmoid {
+        edge: f64,
+        width: f64,
+        short: f64,
+        long: f64,
+    },
+    Block {
+        start: f64,
+        end: f64,
+        outside: f64,
+        inside: f64,
+    },
+}
+
+impl Synthetic {
+    /// A Gaussian bump, with a center wavelength, a full width at half maximum, a base, and a peak reflectance.
+    pub fn gaussian(center: f64, fwhm: f64, base: f64, peak: f64) -> Self {
+        Synthetic::Gaussian {
+            center,
+            fwhm,
+            base,
+            peak,
+        }
+    }
+
+    /// A sigmoid edge, with a transition wavelength, a width, and the reflectance values at short, and at long,
+    /// wavelengths.
+    pub fn sigmoid(edge: f64, width: f64, short: f64, long: f64) -> Self {
+        Synthetic::Sigmoid {
+            edge,
+            width,
+            short,
+            long,
+        }
+    }
+
+    /// A block dye, with a reflectance `inside` from `start` to `end`, and a reflectance `outside` elsewhere.
+    pub fn block(start: f64, end: f64, outside: f64, inside: f64) -> Self {
+        Synthetic::Block {
+            start,
+            end,
+            outside,
+            inside,
+        }
+    }
+
+    /// Reflectance at a wavelength, in nanometer.
+    pub fn value(&self, wavelength: f64) -> f64 {
+        match *self {
+            Synthetic::Gaussian {
+                center,
+                fwhm,
+                base,
+                peak,
+            } => {
+                let sigma = fwhm / (8.0 * 2f64.ln()).sqrt();
+                base + (peak - base) * (-0.5 * ((wavelength - center) / sigma).powi(2)).exp()
+            }
+            Synthetic::Sigmoid {
+                edge,
+                width,
+                short,
+                long,
+            } => short + (long - short) / (1.0 + (-(wavelength - edge) / width).exp()),
+            Synthetic::Block {
+                start,
+                end,
+                outside,
+                inside,
+            } => {
+                if wavelength >= start && wavelength <= end {
+                    inside
+                } else {
+                    outside
+                }
+            }
+        }
+    }
+
+    /// Short description of the shape, and its wavelength parameters, used as its key.
+    pub fn key(&self) -> String {
+        match *self {
+            Synthetic::Gaussian { center, fwhm, .. } => format!("gaussian {}/{}", center, fwhm),
+            Synthetic::Sigmoid { edge, width, .. } => format!("sigmoid {}/{}", edge, width),
+            Synthetic::Block { start, end, .. } => format!("block {}-{}", start, end),
+        }
+    }
+}
+
+/// Reflectance values of synthetic spectra on a domain, with a spectrum in each column.
+fn synthetic_values<S2: Step>(shapes: &[Synthetic], dto: Domain<S2>) -> DMatrix<f64>
+where
+    Meter: From<S2::UnitValueType>,
+{
+    let wl: Vec<f64> = dto.iter().map(|l| Meter::from(l).value() * 1E9).collect();
+    DMatrix::from_fn(wl.len(), shapes.len(), |i, j| shapes[j].value(wl[i]))
+}
+
+impl SpectralDistribution for Synthetic {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let d = Domain::default();
+        let data = self.map_domain(d.clone());
+        (d, data)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (Domain::<WavelengthStep>::default().len(), 1)
+    }
+
+    fn map_domain<S2: Step>(&self, dto: Domain<S2>) -> DMatrix<f64>
+    where
+        <<Self as SpectralDistribution>::StepType as Step>::UnitValueType: From<S2::UnitValueType>,
+    {
+        synthetic_values(&[*self], dto)
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        Some(vec![self.key()])
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(format!("Synthetic {}", self.key()))
+    }
+}
+
+impl Swatch for Synthetic {}
+
+impl<I: Illuminant, C: StandardObserver> From<Synthetic> for CieLab<I, C>
+where
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+{
+    fn from(s: Synthetic) -> Self {
+        s.lab()
+    }
+}
+
+/// A collection of synthetic reflectance spectra, keyed by their shapes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyntheticSet {
+    shapes: Vec<Synthetic>,
+}
+
+impl SyntheticSet {
+    pub fn new(shapes: Vec<Synthetic>) -> Self {
+        Self { shapes }
+    }
+
+    pub fn shapes(&self) -> &[Synthetic] {
+        &self.shapes
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+}
+
+impl std::iter::FromIterator<Synthetic> for SyntheticSet {
+    fn from_iter<T: IntoIterator<Item = Synthetic>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl SpectralDistribution for SyntheticSet {
+    type MatrixType = DMatrix<f64>;
+    type StepType = WavelengthStep;
+
+    fn spd(&self) -> (Domain<Self::StepType>, Self::MatrixType) {
+        let d = Domain::default();
+        let data = self.map_domain(d.clone());
+        (d, data)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (Domain::<WavelengthStep>::default().len(), self.shapes.len())
+    }
+
+    fn map_domain<S2: Step>(&self, dto: Domain<S2>) -> DMatrix<f64>
+    where
+        <<Self as SpectralDistribution>::StepType as Step>::UnitValueType: From<S2::UnitValueType>,
+    {
+        synthetic_values(&self.shapes, dto)
+    }
+
+    fn keys(&self) -> Option<Vec<String>> {
+        Some(self.shapes.iter().map(Synthetic::key).collect())
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("Synthetic Set".to_string())
+    }
+}
+
+impl Swatch for SyntheticSet {}
+
+impl<I: Illuminant, C: StandardObserver> From<SyntheticSet> for CieLab<I, C>
+where
+    <<I as SpectralDistribution>::StepType as Step>::UnitValueType: From<Meter>,
+{
+    fn from(s: SyntheticSet) -> Self {
+        s.lab()
+    }
+}
+
+#[test]
+fn test_synthetic() {
+    use crate::illuminants::CieIllD65;
+    use crate::observers::CieObs1931;
+    use crate::NM;
+    use approx::assert_abs_diff_eq;
+
+    let g = Synthetic::gaussian(550.0, 40.0, 0.1, 0.9);
+    assert_abs_diff_eq!(g.value(550.0), 0.9, epsilon = 1E-12);
+    assert_abs_diff_eq!(g.value(570.0), 0.5, epsilon = 1E-12); // half maximum
+    let s = Synthetic::sigmoid(600.0, 10.0, 0.05, 0.85);
+    assert_abs_diff_eq!(s.value(600.0), 0.45, epsilon = 1E-12);
+    assert!(s.value(400.0) < 0.051 && s.value(780.0) > 0.849);
+    let b = Synthetic::block(450.0, 550.0, 0.0, 1.0);
+    assert_eq!(
+        (
+            b.value(449.0),
+            b.value(450.0),
+            b.value(550.0),
+            b.value(551.0)
+        ),
+        (0.0, 1.0, 1.0, 0.0)
+    );
+
+    // exact values on any domain
+    let v = g.map_domain(Domain::new(500, 600, NM));
+    assert_abs_diff_eq!(v[50], 0.9, epsilon = 1E-12);
+    assert_eq!(g.spd().0, Domain::default());
+
+    // a collection
+    let set: SyntheticSet = vec![g, s, b].into_iter().collect();
+    assert_eq!(
+        set.keys().unwrap(),
+        vec!["gaussian 550/40", "sigmoid 600/10", "block 450-550"]
+    );
+    let lab: CieLab<CieIllD65, CieObs1931> = set.into();
+    let [_, a, bb] = lab.get_by_key("sigmoid 600/10").unwrap();
+    assert!(a > 20.0 && bb > 20.0); // orange
+    let [_, a, _] = lab.get_by_key("block 450-550").unwrap();
+    assert!(a < -20.0); // green
+}
@@ -92,6 +92,29 @@ impl Unit for Electronvolt {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Hertz(pub f64);
+
+impl Unit for Hertz {
+    const SYMBOL: &'static str = "Hz";
+    const NAME: &'static str = "Hertz";
+    fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+/// Wavenumber, in reciprocal meter.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ReciprocalMeter(pub f64);
+
+impl Unit for ReciprocalMeter {
+    const SYMBOL: &'static str = "m-1";
+    const NAME: &'static str = "reciprocal meter";
+    fn value(&self) -> f64 {
+        self.0
+    }
+}
+
 // Conversions
 const INCH_TO_METER: f64 = 0.0254;
 const METER_TO_INCH: f64 = 1.0 / INCH_TO_METER;